@@ -0,0 +1,26 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A flag shared between the caller of a long-running operation and the operation itself,
+/// to stop the operation when its result isn't needed anymore.
+///
+/// The cancellation is cooperative: the analyzer checks the token while it visits the syntax
+/// tree and stops early, without emitting the remaining signals. The clones of a token share
+/// the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the cancellation of the operations that received this token, or one of its clones
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
@@ -68,9 +68,12 @@ impl ActionCategory {
     ///
     /// assert!(ActionCategory::Refactor(RefactorKind::Extract).matches("refactor"));
     /// assert!(ActionCategory::Refactor(RefactorKind::Extract).matches("refactor.extract"));
+    /// assert!(!ActionCategory::Refactor(RefactorKind::Extract).matches("refactor.ext"));
     /// ```
     pub fn matches(&self, filter: &str) -> bool {
-        self.to_str().starts_with(filter)
+        self.to_str()
+            .strip_prefix(filter)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
     }
 
     /// Returns the representation of this [ActionCategory] as a `CodeActionKind` string
@@ -1,4 +1,4 @@
-use crate::{registry::RuleRoot, FromServices, Queryable, Rule, RuleKey, ServiceBag};
+use crate::{registry::RuleRoot, FromServices, QueryCache, Queryable, Rule, RuleKey, ServiceBag};
 use biome_diagnostics::{Error, Result};
 use std::ops::Deref;
 use std::path::Path;
@@ -105,6 +105,18 @@ where
             .expect("Source type is not registered")
     }
 
+    /// Returns a service registered by the analyzer of the language, if any
+    pub fn get_service<T: 'static>(&self) -> Option<&T> {
+        self.bag.get_service::<T>()
+    }
+
+    /// Returns the cache of the computations shared by the rules during this analysis
+    pub fn query_cache(&self) -> &QueryCache {
+        self.bag
+            .get_service::<QueryCache>()
+            .expect("The query cache is inserted by the analyzer")
+    }
+
     /// The file path of the current file
     pub fn file_path(&self) -> &Path {
         self.file_path
@@ -6,12 +6,14 @@ use std::collections::{BTreeMap, BinaryHeap};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops;
 
+mod cancellation;
 mod categories;
 pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
 mod query;
+mod query_cache;
 mod registry;
 mod rule;
 mod services;
@@ -22,6 +24,7 @@ mod visitor;
 // Re-exported for use in the `declare_group` macro
 pub use biome_diagnostics::category_concat;
 
+pub use crate::cancellation::CancellationToken;
 pub use crate::categories::{
     ActionCategory, RefactorKind, RuleCategories, RuleCategory, SourceActionKind,
 };
@@ -30,6 +33,7 @@ use crate::diagnostics::SuppressionDiagnostic;
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
 pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
+pub use crate::query_cache::{CacheStatistics, CachedQuery, QueryCache, QueryCacheStatistics};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
     RuleRegistry, RuleRegistryBuilder, RuleSuppressions,
@@ -116,6 +120,8 @@ where
         self.phases.entry(phase).or_default().push(visitor);
     }
 
+    /// Runs the phases of the analyzer on the root of `ctx`. The analysis stops early when the
+    /// signal handler breaks, or when the [CancellationToken] of the options is cancelled.
     pub fn run(self, mut ctx: AnalyzerContext<L>) -> Option<Break> {
         let Self {
             phases,
@@ -126,6 +132,10 @@ where
             apply_suppression_comment,
         } = self;
 
+        // The results of the shared queries are only valid for the root of this analysis
+        ctx.services
+            .insert_service(QueryCache::new(ctx.options.query_cache_statistics.clone()));
+
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
 
@@ -160,6 +170,12 @@ where
                 return Some(br);
             }
 
+            // The remaining phases and the unused suppression comments are skipped, the
+            // signals of a cancelled analysis are incomplete anyway
+            if ctx.options.cancellation.is_cancelled() {
+                return None;
+            }
+
             // Finish all the active visitors, this is executed outside of the
             // phase runner as it needs mutable access to the service bag (the
             // runner borrows the services for the entire phase)
@@ -256,6 +272,10 @@ where
     fn run_first_phase(mut self) -> ControlFlow<Break> {
         let iter = self.root.syntax().preorder_with_tokens(Direction::Next);
         for event in iter {
+            if self.options.cancellation.is_cancelled() {
+                return ControlFlow::Continue(());
+            }
+
             let node_event = match event {
                 WalkEvent::Enter(SyntaxElement::Node(node)) => WalkEvent::Enter(node),
                 WalkEvent::Leave(SyntaxElement::Node(node)) => WalkEvent::Leave(node),
@@ -296,6 +316,10 @@ where
     /// processed and cached in `run_initial_phase`
     fn run_remaining_phases(mut self) -> ControlFlow<Break> {
         for event in self.root.syntax().preorder() {
+            if self.options.cancellation.is_cancelled() {
+                return ControlFlow::Continue(());
+            }
+
             // Run all the active visitors for the phase on the event
             for visitor in self.visitors.iter_mut() {
                 let ctx = VisitorContext {
@@ -202,7 +202,7 @@ mod tests {
         MetadataRegistry, Never, Phases, QueryMatcher, RuleKey, ServiceBag, SignalEntry,
         SyntaxVisitor,
     };
-    use crate::{AnalyzerOptions, SuppressionKind};
+    use crate::{AnalyzerOptions, CancellationToken, SuppressionKind};
     use biome_diagnostics::{category, DiagnosticExt};
     use biome_diagnostics::{Diagnostic, Severity};
     use biome_rowan::{
@@ -409,4 +409,67 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn cancelled_analysis() {
+        let root = {
+            let mut builder = RawSyntaxTreeBuilder::new();
+
+            builder.start_node(RawLanguageKind::ROOT);
+            builder.start_node(RawLanguageKind::SEPARATED_EXPRESSION_LIST);
+
+            builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+            builder.token(RawLanguageKind::STRING_TOKEN, "\"warn_here\"");
+            builder.finish_node();
+
+            builder.finish_node();
+            builder.finish_node();
+
+            RawLanguageRoot::unwrap_cast(builder.finish())
+        };
+
+        let mut signal_count = 0;
+        let mut emit_signal = |_: &dyn AnalyzerSignal<RawLanguage>| -> ControlFlow<Never> {
+            signal_count += 1;
+            ControlFlow::Continue(())
+        };
+
+        fn parse_suppression_comment(
+            comment: &'_ str,
+        ) -> Vec<Result<SuppressionKind<'_>, Infallible>> {
+            vec![Ok(SuppressionKind::Rule(comment.trim_start_matches("//")))]
+        }
+
+        let mut metadata = MetadataRegistry::default();
+        metadata.insert_rule("group", "rule");
+
+        let mut analyzer = Analyzer::new(
+            &metadata,
+            SuppressionMatcher,
+            parse_suppression_comment,
+            |_| unreachable!(),
+            &mut emit_signal,
+        );
+
+        analyzer.add_visitor(Phases::Syntax, Box::<SyntaxVisitor<RawLanguage>>::default());
+
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+        let options = AnalyzerOptions {
+            cancellation,
+            ..AnalyzerOptions::default()
+        };
+        let ctx: AnalyzerContext<RawLanguage> = AnalyzerContext {
+            root,
+            range: None,
+            services: ServiceBag::default(),
+            options: &options,
+        };
+
+        let result: Option<Never> = analyzer.run(ctx);
+        assert!(result.is_none());
+
+        // The diagnostic of the literal isn't emitted
+        assert_eq!(signal_count, 0);
+    }
 }
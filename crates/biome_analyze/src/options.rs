@@ -1,6 +1,6 @@
 use rustc_hash::FxHashMap;
 
-use crate::{Rule, RuleKey};
+use crate::{CancellationToken, QueryCacheStatistics, Rule, RuleKey};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
 use std::path::PathBuf;
@@ -53,6 +53,25 @@ pub struct AnalyzerConfiguration {
     ///
     /// For example, lint rules should ignore them.
     pub globals: Vec<String>,
+
+    /// The patterns of the specifiers that are aliases of paths of the project, e.g. `@app/*`,
+    /// from the `paths` option of `tsconfig.json`
+    pub path_aliases: Vec<String>,
+
+    /// The packages that the file can import, from the `package.json` closest to it: the name
+    /// of its package and its dependencies. `None` when no `package.json` applies to the file.
+    pub declared_packages: Option<Vec<String>>,
+
+    /// The names of the packages of the project, e.g. the packages of a monorepo
+    pub project_packages: Vec<String>,
+
+    /// The range of the versions of Node.js that the file runs on, from the `engines` field of
+    /// the `package.json` closest to it, e.g. `>=18.0.0`
+    pub node_engine: Option<String>,
+
+    /// The queries of the browsers that the file runs on, from the `browserslist` field of the
+    /// `package.json` closest to it, e.g. `chrome >= 90`
+    pub browserslist: Vec<String>,
 }
 
 /// A set of information useful to the analyzer infrastructure
@@ -63,6 +82,12 @@ pub struct AnalyzerOptions {
 
     /// The file that is being analyzed
     pub file_path: PathBuf,
+
+    /// Stops the analysis when it's cancelled
+    pub cancellation: CancellationToken,
+
+    /// Receives the hits and the misses of the query cache when the analysis ends
+    pub query_cache_statistics: QueryCacheStatistics,
 }
 impl AnalyzerOptions {
     pub fn globals(&self) -> Vec<&str> {
@@ -0,0 +1,211 @@
+use rustc_hash::FxHashMap;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+/// A computation shared by several rules, whose results are stored in the [QueryCache] of the
+/// analysis.
+///
+/// The type implementing the trait only identifies the computation, the computation itself is
+/// passed to [QueryCache::get_or_compute].
+///
+/// ## Examples
+///
+/// ```
+/// use biome_analyze::CachedQuery;
+///
+/// struct LineCount;
+///
+/// impl CachedQuery for LineCount {
+///     const NAME: &'static str = "line_count";
+///     type Key = String;
+///     type Output = usize;
+/// }
+/// ```
+pub trait CachedQuery: 'static {
+    /// The name of the query in the [QueryCacheStatistics]
+    const NAME: &'static str;
+
+    /// The input of the computation, usually a syntax node
+    type Key: Eq + Hash + 'static;
+
+    /// The result of the computation, cloned for each rule that reads it
+    type Output: Clone + 'static;
+}
+
+/// Stores the results of the [CachedQuery] computed during an analysis, so the rules that need
+/// the same result compute it once per file.
+///
+/// The analyzer inserts a new cache in the services of each analysis, and the rules read it with
+/// [RuleContext::query_cache](crate::context::RuleContext::query_cache). The hits and the misses
+/// of the cache are added to the [QueryCacheStatistics] of the
+/// [AnalyzerOptions](crate::AnalyzerOptions) when the analysis ends.
+pub struct QueryCache {
+    /// The results of each query, a `FxHashMap<Q::Key, Q::Output>` by [TypeId] of the query
+    results: RefCell<FxHashMap<TypeId, Box<dyn Any>>>,
+    /// The hits and the misses of each query during this analysis
+    accesses: RefCell<BTreeMap<&'static str, CacheStatistics>>,
+    statistics: QueryCacheStatistics,
+}
+
+impl QueryCache {
+    pub fn new(statistics: QueryCacheStatistics) -> Self {
+        Self {
+            results: RefCell::default(),
+            accesses: RefCell::default(),
+            statistics,
+        }
+    }
+
+    /// Returns the result of the query `Q` for `key`, and calls `compute` when the result isn't
+    /// in the cache yet.
+    ///
+    /// `compute` can read the other queries of the cache.
+    pub fn get_or_compute<Q: CachedQuery>(
+        &self,
+        key: Q::Key,
+        compute: impl FnOnce(&Q::Key) -> Q::Output,
+    ) -> Q::Output {
+        let cached = self
+            .results
+            .borrow()
+            .get(&TypeId::of::<Q>())
+            .and_then(|results| results.downcast_ref::<FxHashMap<Q::Key, Q::Output>>())
+            .and_then(|results| results.get(&key).cloned());
+        let mut accesses = self.accesses.borrow_mut();
+        let statistics = accesses.entry(Q::NAME).or_default();
+        if let Some(output) = cached {
+            statistics.hits += 1;
+            return output;
+        }
+        statistics.misses += 1;
+        drop(accesses);
+
+        // The cache isn't borrowed while computing, a query can depend on another one
+        let output = compute(&key);
+        self.results
+            .borrow_mut()
+            .entry(TypeId::of::<Q>())
+            .or_insert_with(|| Box::<FxHashMap<Q::Key, Q::Output>>::default())
+            .downcast_mut::<FxHashMap<Q::Key, Q::Output>>()
+            .expect("The results of a query are stored by the type of the query")
+            .insert(key, output.clone());
+        output
+    }
+}
+
+impl Drop for QueryCache {
+    fn drop(&mut self) {
+        self.statistics.add(self.accesses.get_mut());
+    }
+}
+
+/// The hits and the misses of the query caches, added by each analysis that received these
+/// statistics, or one of their clones.
+///
+/// The clones share the same counters, so the caller of the analyzer can read the accesses of
+/// the analyses that it ran.
+#[derive(Debug, Clone, Default)]
+pub struct QueryCacheStatistics(Arc<Mutex<BTreeMap<&'static str, CacheStatistics>>>);
+
+impl QueryCacheStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the hits and the misses of each query, sorted by name, and resets the counters
+    pub fn take(&self) -> Vec<(&'static str, CacheStatistics)> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+            .into_iter()
+            .collect()
+    }
+
+    fn add(&self, accesses: &BTreeMap<&'static str, CacheStatistics>) {
+        if accesses.is_empty() {
+            return;
+        }
+        let mut statistics = self.0.lock().unwrap();
+        for (name, accesses) in accesses {
+            let entry = statistics.entry(name).or_default();
+            entry.hits += accesses.hits;
+            entry.misses += accesses.misses;
+        }
+    }
+}
+
+/// The accesses to the results of a [CachedQuery]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct CacheStatistics {
+    /// The accesses to a result that was already computed
+    pub hits: u64,
+    /// The accesses that computed the result
+    pub misses: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheStatistics, CachedQuery, QueryCache, QueryCacheStatistics};
+
+    struct Length;
+
+    impl CachedQuery for Length {
+        const NAME: &'static str = "length";
+        type Key = &'static str;
+        type Output = usize;
+    }
+
+    struct DoubleLength;
+
+    impl CachedQuery for DoubleLength {
+        const NAME: &'static str = "double_length";
+        type Key = &'static str;
+        type Output = usize;
+    }
+
+    #[test]
+    fn computes_each_key_once() {
+        let statistics = QueryCacheStatistics::new();
+        let cache = QueryCache::new(statistics.clone());
+        let mut computations = 0;
+        for key in ["a", "bb", "a", "a"] {
+            let length = cache.get_or_compute::<Length>(key, |key| {
+                computations += 1;
+                key.len()
+            });
+            assert_eq!(length, key.len());
+        }
+        assert_eq!(computations, 2);
+
+        // The statistics are added when the analysis ends
+        assert!(statistics.take().is_empty());
+        drop(cache);
+        assert_eq!(
+            statistics.take(),
+            vec![("length", CacheStatistics { hits: 2, misses: 2 })]
+        );
+        assert!(statistics.take().is_empty());
+    }
+
+    #[test]
+    fn query_depending_on_another_query() {
+        let statistics = QueryCacheStatistics::new();
+        let cache = QueryCache::new(statistics.clone());
+        for _ in 0..2 {
+            let length = cache.get_or_compute::<DoubleLength>("abc", |key| {
+                2 * cache.get_or_compute::<Length>(key, |key| key.len())
+            });
+            assert_eq!(length, 6);
+        }
+        assert_eq!(cache.get_or_compute::<Length>("abc", |_| unreachable!()), 3);
+        drop(cache);
+        assert_eq!(
+            statistics.take(),
+            vec![
+                ("double_length", CacheStatistics { hits: 1, misses: 1 }),
+                ("length", CacheStatistics { hits: 1, misses: 1 }),
+            ]
+        );
+    }
+}
@@ -10,8 +10,8 @@ use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::location::AsSpan;
 use biome_diagnostics::Applicability;
 use biome_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticTags, Location, LogCategory, MessageAndDescription,
-    Visit,
+    Advices, AsLabels, Category, Diagnostic, DiagnosticTags, Label, Location, LogCategory,
+    MessageAndDescription, Visit,
 };
 use biome_rowan::{AstNode, BatchMutation, BatchMutationExt, Language, TextRange};
 use std::fmt::Debug;
@@ -409,10 +409,31 @@ pub struct RuleDiagnostic {
     pub(crate) message: MessageAndDescription,
     #[tags]
     pub(crate) tags: DiagnosticTags,
+    #[label]
+    pub(crate) primary_label: Option<RuleLabel>,
+    #[label]
+    pub(crate) secondary_labels: Vec<RuleLabel>,
     #[advice]
     pub(crate) rule_advice: RuleAdvice,
 }
 
+/// A span of the analyzed file labeled with a message, see [RuleDiagnostic::primary_label]
+/// and [RuleDiagnostic::secondary_label]
+#[derive(Debug)]
+pub struct RuleLabel {
+    pub(crate) range: Option<TextRange>,
+    pub(crate) message: MarkupBuf,
+}
+
+impl AsLabels for RuleLabel {
+    fn labels(&self) -> Vec<Label<'_>> {
+        vec![Label {
+            location: Location::builder().span(&self.range).build(),
+            message: &self.message,
+        }]
+    }
+}
+
 #[derive(Debug, Default)]
 /// It contains possible advices to show when printing a diagnostic that belong to the rule
 pub struct RuleAdvice {
@@ -481,6 +502,8 @@ impl RuleDiagnostic {
             span: span.as_span(),
             message: MessageAndDescription::from(message),
             tags: DiagnosticTags::empty(),
+            primary_label: None,
+            secondary_labels: Vec::new(),
             rule_advice: RuleAdvice::default(),
         }
     }
@@ -526,6 +549,26 @@ impl RuleDiagnostic {
         self.label(span, msg)
     }
 
+    /// Prints a message next to the span of this [`RuleDiagnostic`], in its code frame.
+    pub fn primary_label(mut self, msg: impl Display) -> Self {
+        self.primary_label = Some(RuleLabel {
+            range: self.span,
+            message: markup!({ msg }).to_owned(),
+        });
+        self
+    }
+
+    /// Prints another span of the file in its own code frame, with a message next to the
+    /// span, e.g. the first declaration of a redeclared variable. The span is highlighted
+    /// as secondary only when the diagnostic has a [primary label](Self::primary_label).
+    pub fn secondary_label(mut self, span: impl AsSpan, msg: impl Display) -> Self {
+        self.secondary_labels.push(RuleLabel {
+            range: span.as_span(),
+            message: markup!({ msg }).to_owned(),
+        });
+        self
+    }
+
     /// Adds a footer to this [`RuleDiagnostic`], which will be displayed under the actual error.
     fn footer(mut self, log_category: LogCategory, msg: impl Display) -> Self {
         self.rule_advice
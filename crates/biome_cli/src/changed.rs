@@ -0,0 +1,126 @@
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_fs::FileSystem;
+use biome_service::Configuration;
+use std::ffi::OsString;
+
+/// Returns the files that changed between `since` (or the `vcs.defaultBranch` of the configuration)
+/// and the current `HEAD`.
+fn get_changed_files(
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+    since: Option<&str>,
+) -> Result<Vec<OsString>, CliDiagnostic> {
+    check_vcs_is_enabled(configuration, "--changed")?;
+
+    let default_branch = configuration
+        .vcs
+        .as_ref()
+        .and_then(|vcs| vcs.default_branch.as_deref());
+
+    let base = match (since, default_branch) {
+        (Some(since), _) => since,
+        (None, Some(default_branch)) => default_branch,
+        (None, None) => {
+            return Err(CliDiagnostic::incompatible_end_configuration(
+                "The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set `vcs.defaultBranch` in your configuration file, or use the `--since` argument.",
+            ))
+        }
+    };
+
+    let changed_files = fs
+        .get_changed_files(base)
+        .map_err(CliDiagnostic::io_error)?;
+
+    Ok(changed_files.into_iter().map(OsString::from).collect())
+}
+
+/// Returns the files that are currently staged in the VCS repository.
+fn get_staged_files(
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+) -> Result<Vec<OsString>, CliDiagnostic> {
+    check_vcs_is_enabled(configuration, "--staged")?;
+
+    let staged_files = fs.get_staged_files().map_err(CliDiagnostic::io_error)?;
+
+    Ok(staged_files.into_iter().map(OsString::from).collect())
+}
+
+/// Arguments that select the files to process using the VCS
+pub(crate) struct VcsPathsOptions<'a> {
+    /// Process only the files changed since a base reference
+    pub(crate) changed: bool,
+    /// Process only the files that are staged
+    pub(crate) staged: bool,
+    /// The base reference used by `changed`
+    pub(crate) since: Option<&'a str>,
+}
+
+/// Computes the list of paths to process, taking into account the `--changed` and `--staged` arguments.
+///
+/// It returns [None] when the VCS didn't report any file, which means there's nothing to process.
+pub(crate) fn resolve_paths(
+    session: &mut CliSession,
+    configuration: &Configuration,
+    options: VcsPathsOptions,
+    paths: Vec<OsString>,
+) -> Result<Option<Vec<OsString>>, CliDiagnostic> {
+    let VcsPathsOptions {
+        changed,
+        staged,
+        since,
+    } = options;
+
+    if changed && staged {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed",
+            "--staged",
+        ));
+    }
+    if since.is_some() && !changed {
+        return Err(CliDiagnostic::incompatible_end_configuration(
+            "The `--since` argument can only be used together with `--changed`.",
+        ));
+    }
+    if !changed && !staged {
+        return Ok(Some(paths));
+    }
+    if !paths.is_empty() {
+        let argument = if changed { "--changed" } else { "--staged" };
+        return Err(CliDiagnostic::incompatible_arguments(argument, "PATH"));
+    }
+
+    let vcs_paths = if changed {
+        get_changed_files(&*session.app.fs, configuration, since)?
+    } else {
+        get_staged_files(&*session.app.fs, configuration)?
+    };
+
+    if vcs_paths.is_empty() {
+        session.app.console.log(markup! {
+            <Info>"The VCS didn't report any file to process."</Info>
+        });
+        return Ok(None);
+    }
+
+    Ok(Some(vcs_paths))
+}
+
+fn check_vcs_is_enabled(
+    configuration: &Configuration,
+    argument: &str,
+) -> Result<(), CliDiagnostic> {
+    let is_enabled = configuration
+        .vcs
+        .as_ref()
+        .is_some_and(|vcs| vcs.is_enabled());
+
+    if is_enabled {
+        Ok(())
+    } else {
+        Err(CliDiagnostic::incompatible_end_configuration(format!(
+            "The `{argument}` flag requires the VCS integration to be enabled. Set `vcs.enabled` to `true` in your configuration file."
+        )))
+    }
+}
@@ -2,6 +2,7 @@ use crate::logging::LoggingKind;
 use crate::LoggingLevel;
 use biome_diagnostics::Severity;
 use bpaf::Bpaf;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 /// Global options applied to all commands
@@ -23,14 +24,18 @@ pub struct CliOptions {
     #[bpaf(long("config-path"), argument("PATH"), optional)]
     pub config_path: Option<String>,
 
-    /// Cap the amount of diagnostics displayed.
+    /// Apply the options of the configuration profile NAME
+    #[bpaf(env("BIOME_PROFILE"), long("profile"), argument("NAME"), optional)]
+    pub profile: Option<String>,
+
+    /// Cap the amount of diagnostics displayed. Use `none` to display all the diagnostics.
     #[bpaf(
         long("max-diagnostics"),
-        argument("NUMBER"),
-        fallback(20),
+        argument("none|NUMBER"),
+        fallback(MaxDiagnostics::default()),
         display_fallback
     )]
-    pub max_diagnostics: u16,
+    pub max_diagnostics: MaxDiagnostics,
 
     /// Skip over files containing syntax errors instead of emitting an error diagnostic.
     #[bpaf(long("skip-errors"), switch)]
@@ -48,6 +53,46 @@ pub struct CliOptions {
     #[bpaf(long("json"), switch, hide_usage, hide)]
     pub json: bool,
 
+    /// Allows to change how diagnostics and summary are reported.
+    #[bpaf(
+        long("reporter"),
+        argument("json|github|gitlab|junit|sarif|summary"),
+        optional
+    )]
+    pub reporter: Option<CliReporter>,
+
+    /// Skip the files that didn't change since the last run that didn't emit diagnostics for them.
+    #[bpaf(long("cache"), switch)]
+    pub cache: bool,
+
+    /// Set the path of the file where the cache is stored. Defaults to `.biomecache`.
+    #[bpaf(long("cache-location"), argument("PATH"), optional)]
+    pub cache_location: Option<String>,
+
+    /// Report only the diagnostics that aren't recorded in the baseline file.
+    #[bpaf(long("baseline"), argument("PATH"), optional)]
+    pub baseline: Option<String>,
+
+    /// Record the diagnostics of the current run in the file passed with `--baseline`, instead of reporting them.
+    #[bpaf(long("update-baseline"), switch)]
+    pub update_baseline: bool,
+
+    /// Show how many files were processed while the command runs. The progress is printed only when the standard error is a terminal.
+    #[bpaf(long("progress"), switch)]
+    pub progress: bool,
+
+    /// Group the diagnostics printed in the terminal by file or by rule. Each group starts with the name of the file or the rule, and the number of its diagnostics.
+    #[bpaf(long("group-by"), argument("file|rule"), optional)]
+    pub group_by: Option<GroupBy>,
+
+    /// Print the paths of the files relative to the working directory, including the files passed as absolute paths.
+    #[bpaf(long("relative-paths"), switch)]
+    pub relative_paths: bool,
+
+    /// How the diagnostics are printed in the terminal: "pretty" prints the code frames and the advices, "compact" prints each diagnostic on a single line.
+    #[bpaf(long("output-format"), argument("pretty|compact"), optional)]
+    pub output_format: Option<OutputFormat>,
+
     #[bpaf(
         long("log-level"),
         argument("none|debug|info|warn|error"),
@@ -97,3 +142,121 @@ impl FromStr for ColorsArg {
         }
     }
 }
+
+/// The maximum number of diagnostics printed by a command
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MaxDiagnostics {
+    /// All the diagnostics are printed
+    None,
+    /// Only the first diagnostics are printed, the others are counted
+    Limit(u16),
+}
+
+impl Default for MaxDiagnostics {
+    fn default() -> Self {
+        Self::Limit(20)
+    }
+}
+
+impl FromStr for MaxDiagnostics {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            _ => s.parse::<u16>().map(Self::Limit).map_err(|_| {
+                format!("value {s:?} is not valid for the --max-diagnostics argument")
+            }),
+        }
+    }
+}
+
+impl Display for MaxDiagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaxDiagnostics::None => write!(f, "none"),
+            MaxDiagnostics::Limit(limit) => write!(f, "{limit}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum CliReporter {
+    /// Reports information using the JSON format
+    Json,
+    /// Diagnostics are printed as GitHub workflow commands
+    GitHub,
+    /// Diagnostics are printed using the GitLab Code Quality format
+    GitLab,
+    /// Diagnostics are printed using the JUnit XML format
+    Junit,
+    /// Diagnostics are printed using the SARIF format
+    Sarif,
+    /// Statistics about the diagnostics are printed, grouped by rule and by directory
+    Summary,
+}
+
+impl FromStr for CliReporter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "github" => Ok(Self::GitHub),
+            "gitlab" => Ok(Self::GitLab),
+            "junit" => Ok(Self::Junit),
+            "sarif" => Ok(Self::Sarif),
+            "summary" => Ok(Self::Summary),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --reporter argument"
+            )),
+        }
+    }
+}
+
+/// How the diagnostics printed in the terminal are grouped
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GroupBy {
+    /// The diagnostics of the same file are printed together
+    File,
+    /// The diagnostics emitted by the same rule are printed together
+    Rule,
+}
+
+impl FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "file" => Ok(Self::File),
+            "rule" => Ok(Self::Rule),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --group-by argument"
+            )),
+        }
+    }
+}
+
+/// How the diagnostics are printed in the terminal
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// The diagnostics are printed with their code frames and their advices
+    #[default]
+    Pretty,
+    /// Each diagnostic is printed on a single line, as `file:line:column severity category message`
+    Compact,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(Self::Pretty),
+            "compact" => Ok(Self::Compact),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --output-format argument"
+            )),
+        }
+    }
+}
@@ -1,12 +1,18 @@
+use crate::changed::{resolve_paths, VcsPathsOptions};
 use crate::cli_options::CliOptions;
+use crate::commands::lint::FixPreviewArg;
 use crate::configuration::{load_configuration, LoadedConfiguration};
-use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::vcs::read_vcs_ignore_files;
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, StagedFiles,
+    TraversalMode,
 };
+use biome_diagnostics::DiffOptions;
 use biome_service::configuration::organize_imports::OrganizeImports;
 use biome_service::configuration::{FormatterConfiguration, LinterConfiguration};
-use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
+use biome_service::workspace::{
+    FixFileMode, PackageJsonFile, RuleSelector, TsConfigFile, UpdateSettingsParams,
+};
 use biome_service::{Configuration, MergeWith};
 use std::ffi::OsString;
 use std::path::PathBuf;
@@ -14,10 +20,18 @@ use std::path::PathBuf;
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) write: bool,
+    pub(crate) unsafe_only: Vec<RuleSelector>,
+    pub(crate) interactive: bool,
     pub(crate) cli_options: CliOptions,
     pub(crate) configuration: Option<Configuration>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) changed: bool,
+    pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
+    pub(crate) fix_preview: FixPreviewArg,
     pub(crate) formatter_enabled: Option<bool>,
     pub(crate) linter_enabled: Option<bool>,
     pub(crate) organize_imports_enabled: Option<bool>,
@@ -31,16 +45,36 @@ pub(crate) fn check(
     let CheckCommandPayload {
         apply,
         apply_unsafe,
+        write,
+        unsafe_only,
+        interactive,
         cli_options,
         configuration,
         paths,
         stdin_file_path,
+        staged,
+        changed,
+        since,
+        watch,
+        fix_preview,
         linter_enabled,
         organize_imports_enabled,
         formatter_enabled,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
+    if apply && write {
+        return Err(CliDiagnostic::incompatible_arguments("--apply", "--write"));
+    }
+    if write && apply_unsafe {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--write",
+            "--apply-unsafe",
+        ));
+    }
+    // `--write` writes the same changes as `--apply`
+    let apply = apply || write;
+
     let fix_file_mode = if apply && apply_unsafe {
         return Err(CliDiagnostic::incompatible_arguments(
             "--apply",
@@ -54,9 +88,42 @@ pub(crate) fn check(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
+    if !unsafe_only.is_empty() {
+        if apply_unsafe {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--apply-unsafe",
+                "--unsafe-only",
+            ));
+        }
+        if fix_file_mode.is_none() {
+            return Err(CliDiagnostic::missing_argument("--apply", "check"));
+        }
+    }
+
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+
+    if interactive {
+        if fix_file_mode.is_none() {
+            return Err(CliDiagnostic::missing_argument("--apply", "check"));
+        }
+        if stdin_file_path.is_some() {
+            // The content is read from the standard input, so it can't be used to answer
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--interactive",
+                "--stdin-file-path",
+            ));
+        }
+    }
+
     let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
 
     loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+    let nested_configurations = loaded_configuration.nested_configurations(&session.app.fs);
 
     let LoadedConfiguration {
         configuration: mut fs_configuration,
@@ -92,12 +159,30 @@ pub(crate) fn check(
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
-    store_path_to_ignore_from_vcs(
+    let (vcs_ignore_files, nested_vcs_ignore_files) =
+        read_vcs_ignore_files(&mut session, &fs_configuration, vcs_base_path, &cli_options)?;
+
+    // the path aliases of the project are used to sort the imports
+    let working_directory = session.app.fs.working_directory().unwrap_or_default();
+    let tsconfig_files = TsConfigFile::read_all(&*session.app.fs, &working_directory);
+    // the dependencies of the project are the packages that the files can import
+    let package_json_files = PackageJsonFile::read(&*session.app.fs, &working_directory)
+        .into_iter()
+        .collect();
+
+    let Some(paths) = resolve_paths(
         &mut session,
-        &mut fs_configuration,
-        vcs_base_path,
-        &cli_options,
-    )?;
+        &fs_configuration,
+        VcsPathsOptions {
+            changed,
+            staged,
+            since: since.as_deref(),
+        },
+        paths,
+    )?
+    else {
+        return Ok(());
+    };
 
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
@@ -113,20 +198,40 @@ pub(crate) fn check(
         None
     };
 
+    // The fixes of the staged files are staged too, so they end up in the commit. The fixes
+    // accepted with `--interactive` are applied only to the files in the working directory.
+    let staged_files = if staged && fix_file_mode.is_some() && !interactive {
+        Some(StagedFiles::load(&*session.app.fs, &paths)?)
+    } else {
+        None
+    };
+
+    let execution = Execution::new(TraversalMode::Check {
+        fix_file_mode,
+        stdin,
+        interactive,
+        unsafe_rules: unsafe_only,
+    })
+    .with_configuration(&fs_configuration)
+    .with_nested_configurations(
+        nested_configurations.with_vcs_ignore_files(nested_vcs_ignore_files),
+    )
+    .with_diff_options(DiffOptions {
+        fix_preview: fix_preview.into(),
+        ..DiffOptions::default()
+    })
+    .with_watch(watch)
+    .with_staged_files(staged_files);
+
     session
         .app
         .workspace
         .update_settings(UpdateSettingsParams {
             configuration: fs_configuration,
+            vcs_ignore_files,
+            tsconfig_files,
+            package_json_files,
         })?;
 
-    execute_mode(
-        Execution::new(TraversalMode::Check {
-            fix_file_mode,
-            stdin,
-        }),
-        session,
-        &cli_options,
-        paths,
-    )
+    execute_mode(execution, session, &cli_options, paths)
 }
@@ -1,20 +1,25 @@
+use crate::changed::{resolve_paths, VcsPathsOptions};
 use crate::cli_options::CliOptions;
 use crate::configuration::LoadedConfiguration;
-use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::vcs::read_vcs_ignore_files;
 use crate::{
     configuration::load_configuration, execute_mode, setup_cli_subscriber, CliDiagnostic,
     CliSession, Execution, TraversalMode,
 };
 use biome_service::configuration::organize_imports::OrganizeImports;
 use biome_service::configuration::{FormatterConfiguration, LinterConfiguration};
-use biome_service::workspace::UpdateSettingsParams;
+use biome_service::workspace::{PackageJsonFile, TsConfigFile, UpdateSettingsParams};
 use biome_service::{Configuration, MergeWith};
+use std::env;
 use std::ffi::OsString;
 
 pub(crate) struct CiCommandPayload {
     pub(crate) formatter_enabled: Option<bool>,
     pub(crate) linter_enabled: Option<bool>,
     pub(crate) organize_imports_enabled: Option<bool>,
+    pub(crate) changed: bool,
+    pub(crate) since: Option<String>,
+    pub(crate) allow_warnings: bool,
     pub(crate) paths: Vec<OsString>,
     pub(crate) rome_configuration: Configuration,
     pub(crate) cli_options: CliOptions,
@@ -31,6 +36,7 @@ pub(crate) fn ci(mut session: CliSession, payload: CiCommandPayload) -> Result<(
         load_configuration(&mut session, &payload.cli_options)?.with_file_path();
 
     loaded_configuration.check_for_errors(session.app.console, payload.cli_options.verbose)?;
+    let nested_configurations = loaded_configuration.nested_configurations(&session.app.fs);
     let LoadedConfiguration {
         mut configuration,
         directory_path: configuration_path,
@@ -81,22 +87,69 @@ pub(crate) fn ci(mut session: CliSession, payload: CiCommandPayload) -> Result<(
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
-    store_path_to_ignore_from_vcs(
+    let (vcs_ignore_files, nested_vcs_ignore_files) = read_vcs_ignore_files(
         &mut session,
-        &mut configuration,
+        &configuration,
         vcs_base_path,
         &payload.cli_options,
     )?;
 
+    // the path aliases of the project are used to sort the imports
+    let working_directory = session.app.fs.working_directory().unwrap_or_default();
+    let tsconfig_files = TsConfigFile::read_all(&*session.app.fs, &working_directory);
+    // the dependencies of the project are the packages that the files can import
+    let package_json_files = PackageJsonFile::read(&*session.app.fs, &working_directory)
+        .into_iter()
+        .collect();
+
+    // by default, `--changed` compares against the base branch of the pull request
+    let since = payload
+        .since
+        .or_else(|| payload.changed.then(pull_request_base).flatten());
+    let Some(paths) = resolve_paths(
+        &mut session,
+        &configuration,
+        VcsPathsOptions {
+            changed: payload.changed,
+            staged: false,
+            since: since.as_deref(),
+        },
+        payload.paths,
+    )?
+    else {
+        return Ok(());
+    };
+
+    let execution = Execution::new(TraversalMode::CI)
+        .with_configuration(&configuration)
+        .with_nested_configurations(
+            nested_configurations.with_vcs_ignore_files(nested_vcs_ignore_files),
+        );
+
     session
         .app
         .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            vcs_ignore_files,
+            tsconfig_files,
+            package_json_files,
+        })?;
 
-    execute_mode(
-        Execution::new(TraversalMode::CI),
-        session,
-        &payload.cli_options,
-        payload.paths,
-    )
+    let mut cli_options = payload.cli_options;
+    cli_options.error_on_warnings = !payload.allow_warnings;
+
+    execute_mode(execution, session, &cli_options, paths)
+}
+
+/// Returns the base branch of the pull request, when the command runs in the pipeline of a pull
+/// request on GitHub Actions or GitLab CI.
+///
+/// The CI systems only fetch the remote branches, so the branch is resolved against `origin`.
+fn pull_request_base() -> Option<String> {
+    ["GITHUB_BASE_REF", "CI_MERGE_REQUEST_TARGET_BRANCH_NAME"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|branch| !branch.is_empty())
+        .map(|branch| format!("origin/{branch}"))
 }
@@ -0,0 +1,31 @@
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_service::workspace::cache_directory;
+use std::fs;
+use std::io::ErrorKind;
+
+/// Handler for the `clean` command
+pub(crate) fn clean(session: CliSession) -> Result<(), CliDiagnostic> {
+    let Some(directory) = cache_directory() else {
+        session.app.console.log(markup! {
+            "The cache of Biome is disabled, set the environment variable "<Emphasis>"BIOME_CACHE_DIR"</Emphasis>" to enable it"
+        });
+        return Ok(());
+    };
+
+    match fs::remove_dir_all(&directory) {
+        Ok(()) => {
+            session.app.console.log(markup! {
+                "The cache of Biome was removed from "<Emphasis>{directory.display().to_string()}</Emphasis>
+            });
+        }
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            session.app.console.log(markup! {
+                "The cache of Biome is already empty"
+            });
+        }
+        Err(error) => return Err(CliDiagnostic::io_error(error)),
+    }
+
+    Ok(())
+}
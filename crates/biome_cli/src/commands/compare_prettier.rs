@@ -0,0 +1,376 @@
+use crate::cli_options::CliOptions;
+use crate::configuration::{load_configuration, LoadedConfiguration};
+use crate::vcs::read_vcs_ignore_files;
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::adapters::IoError;
+use biome_diagnostics::{
+    Diagnostic, DiagnosticExt, Error, LogAdvice, LogCategory, PrintDiagnostic,
+};
+use biome_formatter::compatibility::{
+    CompatibilityReport, ConstructSpan, FileCompatibility, LineMetrics,
+};
+use biome_fs::{
+    FileSystem, FileSystemExt, PathInterner, RomePath, TraversalContext, TraversalScope,
+};
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{JsFileSource, JsSyntaxKind};
+use biome_rowan::{AstNode, LineIndex, TextSize};
+use biome_service::configuration::nested::NestedConfigurations;
+use biome_service::file_handlers::Language;
+use biome_service::workspace::{
+    FeatureName, FeaturesBuilder, FileGuard, IsPathIgnoredParams, OpenFileParams,
+    SupportsFeatureParams, UpdateSettingsParams,
+};
+use biome_service::Workspace;
+use std::ffi::OsString;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::thread;
+
+pub(crate) struct ComparePrettierCommandPayload {
+    pub(crate) cli_options: CliOptions,
+    pub(crate) prettier_path: Option<PathBuf>,
+    pub(crate) paths: Vec<OsString>,
+}
+
+/// Handler for the hidden `__compare_prettier` command of the Biome CLI.
+///
+/// It formats the files with Biome and with Prettier, without writing them, and
+/// prints the ratio of lines that are identical in both outputs, per file and
+/// per top-level construct.
+pub(crate) fn compare_prettier(
+    mut session: CliSession,
+    payload: ComparePrettierCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    let ComparePrettierCommandPayload {
+        cli_options,
+        prettier_path,
+        mut paths,
+    } = payload;
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
+    loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+    let nested_configurations = loaded_configuration.nested_configurations(&session.app.fs);
+    let LoadedConfiguration {
+        configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
+
+    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    let (vcs_ignore_files, nested_vcs_ignore_files) =
+        read_vcs_ignore_files(&mut session, &configuration, vcs_base_path, &cli_options)?;
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            vcs_ignore_files,
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })?;
+
+    if paths.is_empty() {
+        paths.push(OsString::from("."));
+    }
+    let prettier_path = prettier_path.unwrap_or_else(|| find_prettier(&*session.app.fs));
+
+    let (interner, _) = PathInterner::new();
+    let context = CompareContext {
+        interner,
+        fs: &*session.app.fs,
+        workspace: &*session.app.workspace,
+        nested_configurations: nested_configurations.with_vcs_ignore_files(nested_vcs_ignore_files),
+        prettier_path,
+        report: Mutex::default(),
+        diagnostics: Mutex::default(),
+    };
+    session
+        .app
+        .fs
+        .traversal(Box::new(|scope: &dyn TraversalScope| {
+            for path in paths {
+                scope.spawn(&context, PathBuf::from(path));
+            }
+        }));
+
+    let CompareContext {
+        report,
+        diagnostics,
+        ..
+    } = context;
+    let console = &mut *session.app.console;
+    for diagnostic in diagnostics.into_inner().unwrap() {
+        console.error(markup! {
+            {PrintDiagnostic::simple(&diagnostic)}
+        });
+    }
+
+    let mut report = report.into_inner().unwrap();
+    report.sort();
+    console.log(markup! {
+        <Emphasis>"Files"</Emphasis>
+    });
+    for file in report.files.iter().filter(|file| !file.is_compatible()) {
+        console.log(markup! {
+            "  "{file.path}" "{Percentage(&file.metrics)}
+        });
+    }
+    console.log(markup! {
+        "\n"<Emphasis>"Constructs"</Emphasis>
+    });
+    for (name, metrics) in report.constructs() {
+        console.log(markup! {
+            "  "{name}" "{Percentage(&metrics)}
+        });
+    }
+    let compatible_files = report
+        .files
+        .iter()
+        .filter(|file| file.is_compatible())
+        .count();
+    let file_based_compatibility = format!("{:.2}%", report.file_based_compatibility() * 100_f64);
+    let line_based_compatibility = format!("{:.2}%", report.line_based_compatibility() * 100_f64);
+    console.log(markup! {
+        "\n"<Emphasis>"Compatible files"</Emphasis>": "{compatible_files}" of "{report.files.len()}"\n"
+        <Emphasis>"Average compatibility"</Emphasis>": "{file_based_compatibility}"\n"
+        <Emphasis>"Compatible lines"</Emphasis>": "{line_based_compatibility}
+    });
+
+    Ok(())
+}
+
+/// Returns the Prettier executable installed in the working directory, or the
+/// one found in the `PATH`
+fn find_prettier(fs: &dyn FileSystem) -> PathBuf {
+    let name = if cfg!(windows) {
+        "prettier.cmd"
+    } else {
+        "prettier"
+    };
+    fs.working_directory()
+        .map(|directory| directory.join("node_modules").join(".bin").join(name))
+        .filter(|path| fs.path_exists(path))
+        .unwrap_or_else(|| PathBuf::from(name))
+}
+
+struct CompareContext<'app> {
+    interner: PathInterner,
+    fs: &'app dyn FileSystem,
+    workspace: &'app dyn Workspace,
+    nested_configurations: NestedConfigurations,
+    prettier_path: PathBuf,
+    report: Mutex<CompatibilityReport>,
+    diagnostics: Mutex<Vec<Error>>,
+}
+
+impl CompareContext<'_> {
+    /// Registers the nested configuration files of `directory` and of its parents, so Biome
+    /// formats the files beneath `directory` with them
+    fn discover_nested_configurations(&self, directory: &Path) {
+        match self
+            .nested_configurations
+            .discover(self.fs, self.workspace, directory)
+        {
+            Ok(diagnostics) => self.diagnostics.lock().unwrap().extend(diagnostics),
+            Err(error) => self.push_diagnostic(error.into()),
+        }
+    }
+
+    fn compare_file(&self, path: &Path) -> Result<FileCompatibility, Error> {
+        let file_path = path.display().to_string();
+        let input = self
+            .fs
+            .read_to_string(path)
+            .map_err(|error| IoError::from(error).with_file_path(file_path.clone()))?;
+
+        let guard = FileGuard::open(
+            self.workspace,
+            OpenFileParams {
+                path: RomePath::new(path),
+                version: 0,
+                content: input.clone(),
+                language_hint: Language::default(),
+            },
+        )
+        .map_err(|error| error.with_file_path(file_path.clone()))?;
+        let biome_output = guard
+            .format_file()
+            .map_err(|error| error.with_file_path(file_path.clone()))?
+            .into_code();
+        let prettier_output = self.format_with_prettier(path, &input)?;
+
+        Ok(FileCompatibility::compare(
+            file_path,
+            &biome_output,
+            &prettier_output,
+            &top_level_constructs(path, &biome_output),
+        ))
+    }
+
+    /// Formats `input` with Prettier, which resolves its configuration from `path`
+    fn format_with_prettier(&self, path: &Path, input: &str) -> Result<String, Error> {
+        let file_path = path.display().to_string();
+        let output = Command::new(&self.prettier_path)
+            .arg("--stdin-filepath")
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .and_then(|mut child| {
+                let mut stdin = child.stdin.take().expect("the input is piped");
+                // The input is written by another thread, so that Prettier can't
+                // block on a full output pipe while the input is being written
+                thread::scope(|scope| {
+                    scope.spawn(move || stdin.write_all(input.as_bytes()));
+                    child.wait_with_output()
+                })
+            })
+            .map_err(|error| {
+                let error = if error.kind() == io::ErrorKind::NotFound {
+                    io::Error::new(
+                        error.kind(),
+                        format!(
+                            "Prettier wasn't found at {}, use the option --prettier-path",
+                            self.prettier_path.display()
+                        ),
+                    )
+                } else {
+                    error
+                };
+                IoError::from(error).with_file_path(file_path.clone())
+            })?;
+
+        if !output.status.success() {
+            return Err(PrettierDiagnostic {
+                file_path,
+                output: LogAdvice {
+                    category: LogCategory::Error,
+                    text: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                },
+            }
+            .into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+impl TraversalContext for CompareContext<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, error: Error) {
+        self.diagnostics.lock().unwrap().push(error);
+    }
+
+    fn can_handle(&self, rome_path: &RomePath) -> bool {
+        // The ignore files of the parent directories decide whether the path is ignored, and the
+        // paths passed as arguments aren't reached through their parents
+        self.discover_nested_configurations(rome_path.parent().unwrap_or(Path::new("")));
+
+        if rome_path.is_dir() {
+            let can_handle = !self
+                .workspace
+                .is_path_ignored(IsPathIgnoredParams {
+                    rome_path: rome_path.clone(),
+                    feature: FeatureName::Format,
+                })
+                .unwrap_or(true);
+            if can_handle {
+                self.discover_nested_configurations(rome_path);
+            }
+            return can_handle;
+        }
+        self.workspace
+            .file_features(SupportsFeatureParams {
+                path: rome_path.clone(),
+                feature: FeaturesBuilder::new().with_formatter().build(),
+            })
+            .is_ok_and(|file_features| file_features.supports_for(&FeatureName::Format))
+    }
+
+    fn handle_file(&self, path: &Path) {
+        match self.compare_file(path) {
+            Ok(file) => self.report.lock().unwrap().push(file),
+            Err(error) => self.push_diagnostic(error),
+        }
+    }
+}
+
+/// Returns the lines of the top-level statements of a JavaScript output, named
+/// after the kind of statement. The other languages have no constructs.
+fn top_level_constructs(path: &Path, output: &str) -> Vec<ConstructSpan> {
+    let Ok(source_type) = JsFileSource::try_from(path) else {
+        return Vec::new();
+    };
+    let parse = biome_js_parser::parse(output, source_type, JsParserOptions::default());
+    let line_index = LineIndex::new(output);
+    let line_of = |offset: TextSize| {
+        line_index
+            .line_col(offset)
+            .map_or(0, |line_col| line_col.line as usize)
+    };
+    let Some(items) = parse.tree().syntax().children().find(|child| {
+        matches!(
+            child.kind(),
+            JsSyntaxKind::JS_MODULE_ITEM_LIST | JsSyntaxKind::JS_STATEMENT_LIST
+        )
+    }) else {
+        return Vec::new();
+    };
+    items
+        .children()
+        .map(|item| {
+            let range = item.text_trimmed_range();
+            ConstructSpan {
+                name: construct_name(item.kind()).to_string(),
+                lines: line_of(range.start())..line_of(range.end()) + 1,
+            }
+        })
+        .collect()
+}
+
+fn construct_name(kind: JsSyntaxKind) -> &'static str {
+    match kind {
+        JsSyntaxKind::JS_IMPORT => "import",
+        JsSyntaxKind::JS_EXPORT => "export",
+        JsSyntaxKind::JS_FUNCTION_DECLARATION | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION => {
+            "function"
+        }
+        JsSyntaxKind::JS_CLASS_DECLARATION => "class",
+        JsSyntaxKind::JS_VARIABLE_STATEMENT => "variable",
+        JsSyntaxKind::JS_EXPRESSION_STATEMENT => "expression",
+        JsSyntaxKind::TS_INTERFACE_DECLARATION | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION => "type",
+        JsSyntaxKind::TS_ENUM_DECLARATION => "enum",
+        JsSyntaxKind::TS_MODULE_DECLARATION | JsSyntaxKind::TS_DECLARE_STATEMENT => "declaration",
+        _ => "statement",
+    }
+}
+
+/// Prints the compatibility of a set of lines as a percentage
+struct Percentage<'a>(&'a LineMetrics);
+
+impl biome_console::fmt::Display for Percentage<'_> {
+    fn fmt(&self, fmt: &mut biome_console::fmt::Formatter) -> io::Result<()> {
+        let percentage = format!("{:.2}%", self.0.compatibility() * 100_f64);
+        let total_lines = self.0.total_lines();
+        fmt.write_markup(markup! {
+            {percentage}<Dim>" ("{total_lines}" lines)"</Dim>
+        })
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "format", message = "Prettier couldn't format the file.")]
+struct PrettierDiagnostic {
+    #[location(resource)]
+    file_path: String,
+    #[advice]
+    output: LogAdvice<String>,
+}
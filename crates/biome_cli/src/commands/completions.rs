@@ -0,0 +1,115 @@
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use std::str::FromStr;
+
+/// A shell that can complete the commands and the options of Biome
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Elvish,
+    PowerShell,
+}
+
+impl Shell {
+    /// The completion script of the shell
+    const fn script(self) -> &'static str {
+        match self {
+            Shell::Bash => BASH_SCRIPT,
+            Shell::Zsh => ZSH_SCRIPT,
+            Shell::Fish => FISH_SCRIPT,
+            Shell::Elvish => ELVISH_SCRIPT,
+            Shell::PowerShell => POWERSHELL_SCRIPT,
+        }
+    }
+}
+
+// The scripts of bash, zsh, fish and elvish are the ones that bpaf prints for the flags
+// `--bpaf-complete-style-*`. The flags can't be used here: bpaf prints the script itself and
+// exits the process, so the script would bypass the console. Each shell asks for the
+// candidates with the revision of the protocol of bpaf that its script expects.
+
+const BASH_SCRIPT: &str = r#"_bpaf_dynamic_completion()
+{
+    source <( "$1" --bpaf-complete-rev=8 "${COMP_WORDS[@]:1}" )
+}
+complete -o nosort -F _bpaf_dynamic_completion biome"#;
+
+const ZSH_SCRIPT: &str = r#"#compdef biome
+source <( "${words[1]}" --bpaf-complete-rev=7 "${words[@]:1}" )"#;
+
+const FISH_SCRIPT: &str = r#"set -l current (commandline --tokenize --current-process)
+set -l tmpline $current[1] --bpaf-complete-rev=9 $current[2..]
+if test (commandline --current-process) != (string trim (commandline --current-process))
+    set tmpline $tmpline ""
+end
+source ( $tmpline | psub )"#;
+
+const ELVISH_SCRIPT: &str = r#"set edit:completion:arg-completer[biome] = { |@args| var args = $args[1..];
+     var @lines = ( biome --bpaf-complete-rev=1 $@args );
+     use str;
+     for line $lines {
+         var @arg = (str:split "\t" $line)
+         try {
+             edit:complex-candidate $arg[0] &display=( printf "%-19s %s" $arg[0] $arg[1] )
+         } catch {
+             edit:complex-candidate $line
+         }
+     }
+}"#;
+
+/// The completion script for PowerShell, which bpaf doesn't generate.
+///
+/// Like the scripts of bpaf, it asks the `biome` binary for the candidates. It uses
+/// the plain protocol of bpaf, the one of the elvish script: one candidate per line, followed
+/// by its description after a tab. The arguments after the cursor are left out, and an empty
+/// argument asks for the candidates of a new word.
+const POWERSHELL_SCRIPT: &str = r#"Register-ArgumentCompleter -Native -CommandName biome -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $arguments = @(
+        $commandAst.CommandElements |
+            Select-Object -Skip 1 |
+            Where-Object { $_.Extent.StartOffset -lt $cursorPosition } |
+            ForEach-Object { $_.Extent.Text }
+    )
+    if ($wordToComplete -eq '') {
+        $arguments += ''
+    }
+
+    & biome --bpaf-complete-rev=1 @arguments | ForEach-Object {
+        $candidate, $description = $_ -split "`t", 2
+        if (-not $description) {
+            $description = $candidate
+        }
+        [System.Management.Automation.CompletionResult]::new($candidate, $candidate, 'ParameterValue', $description)
+    }
+}"#;
+
+impl FromStr for Shell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            "elvish" => Ok(Self::Elvish),
+            "powershell" => Ok(Self::PowerShell),
+            _ => Err(format!(
+                "value {s:?} is not valid for the shell, expected one of: bash, zsh, fish, elvish, powershell"
+            )),
+        }
+    }
+}
+
+/// Handler for the `completions` command of the Biome CLI.
+///
+/// The script doesn't contain the list of the commands: when the user presses
+/// <TAB>, the shell asks the `biome` binary for the candidates, so the
+/// completions never get out of sync with the installed version.
+pub(crate) fn completions(session: CliSession, shell: Shell) -> Result<(), CliDiagnostic> {
+    session.app.console.log(markup! {{shell.script()}});
+    Ok(())
+}
@@ -5,7 +5,10 @@ use crate::{
 };
 use biome_console::{markup, ConsoleExt};
 use biome_lsp::ServerFactory;
-use biome_service::{workspace::WorkspaceClient, TransportError, WorkspaceError};
+use biome_service::metrics::MetricsLayer;
+use biome_service::workspace::{cache_directory, MetricsParams, WorkspaceClient};
+use biome_service::{TransportError, Workspace, WorkspaceError};
+use std::time::Duration;
 use std::{env, fs, path::PathBuf};
 use tokio::io;
 use tokio::runtime::Runtime;
@@ -62,14 +65,62 @@ pub(crate) fn stop(session: CliSession) -> Result<(), CliDiagnostic> {
     Ok(())
 }
 
+pub(crate) fn print_metrics(session: CliSession) -> Result<(), CliDiagnostic> {
+    let rt = Runtime::new()?;
+
+    let Some(transport) = open_transport(rt)? else {
+        session.app.console.log(markup! {
+            "The Biome server is not running"
+        });
+        return Ok(());
+    };
+
+    let client = WorkspaceClient::new(transport)?;
+    let result = client.metrics(MetricsParams {})?;
+    if !result.enabled {
+        session.app.console.log(markup! {
+            "The Biome server doesn't record the metrics, start it with the environment variable "<Emphasis>"BIOME_METRICS=1"</Emphasis>" to record them"
+        });
+        return Ok(());
+    }
+
+    for span in result.spans {
+        session.app.console.log(markup! {
+            <Emphasis>{span.name}</Emphasis>" ("{span.target}"): count = "{span.count}", mean = "{Duration::from_nanos(span.mean)}", p50 = "{Duration::from_nanos(span.p50)}", p90 = "{Duration::from_nanos(span.p90)}", p99 = "{Duration::from_nanos(span.p99)}", max = "{Duration::from_nanos(span.max)}
+        });
+    }
+
+    for cache in result.caches {
+        let accesses = cache.hits + cache.misses;
+        let hit_rate = if accesses == 0 {
+            0.0
+        } else {
+            cache.hits as f64 * 100.0 / accesses as f64
+        };
+        session.app.console.log(markup! {
+            <Emphasis>{cache.name}</Emphasis>" cache: hits = "{cache.hits}", misses = "{cache.misses}", hit rate = "{format!("{hit_rate:.1}%")}
+        });
+    }
+
+    Ok(())
+}
+
 pub(crate) fn run_server(
     stop_on_disconnect: bool,
     config_path: Option<PathBuf>,
+    source_budget: Option<usize>,
+    metrics: bool,
 ) -> Result<(), CliDiagnostic> {
-    setup_tracing_subscriber();
+    setup_tracing_subscriber(metrics);
 
     let rt = Runtime::new()?;
-    let factory = ServerFactory::new(stop_on_disconnect);
+    let mut factory = ServerFactory::new(stop_on_disconnect);
+    if let Some(cache_directory) = cache_directory() {
+        factory = factory.with_cache_directory(cache_directory);
+    }
+    if let Some(source_budget) = source_budget {
+        factory = factory.with_source_budget(source_budget.saturating_mul(1024 * 1024));
+    }
     let cancellation = factory.cancellation();
     let span = debug_span!("Running Server", pid = std::process::id());
 
@@ -182,7 +233,10 @@ pub(crate) fn read_most_recent_log_file() -> io::Result<Option<String>> {
 /// is written to log files rotated on a hourly basis (in
 /// `biome-logs/server.log.yyyy-MM-dd-HH` files inside the system temporary
 /// directory)
-fn setup_tracing_subscriber() {
+///
+/// When `metrics` is `true`, the durations of the spans are also recorded by the
+/// [MetricsLayer], and are returned by the `metrics` method of the workspace
+fn setup_tracing_subscriber(metrics: bool) {
     let file_appender = tracing_appender::rolling::hourly(rome_log_dir(), log_file_name_prefix());
 
     registry()
@@ -196,6 +250,7 @@ fn setup_tracing_subscriber() {
                 .with_writer(file_appender)
                 .with_filter(LoggingFilter),
         )
+        .with(metrics.then(MetricsLayer::new))
         .init();
 }
 
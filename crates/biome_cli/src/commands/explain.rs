@@ -0,0 +1,573 @@
+use crate::cli_options::CliOptions;
+use crate::commands::daemon::rome_log_dir;
+use crate::configuration::{load_configuration, LoadedConfiguration};
+use crate::{CliDiagnostic, CliSession};
+use biome_analyze::{
+    FixKind, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup,
+};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::PrintDiagnostic;
+use biome_js_analyze::options::PossibleOptions;
+use biome_js_syntax::JsLanguage;
+use biome_json_syntax::JsonLanguage;
+use biome_service::configuration::editorconfig::EditorConfigFile;
+use biome_service::configuration::nested::NestedConfiguration;
+use biome_service::configuration::{JavascriptConfiguration, JsonConfiguration};
+use biome_service::file_handlers::Language;
+use biome_service::Configuration;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// A topic that can be documented by the `explain` command
+#[derive(Debug, Clone)]
+pub enum Doc {
+    /// The documentation of a lint rule
+    Rule(RuleDoc),
+    /// Where the logs of the daemon are stored, and how to read them
+    DaemonLogs,
+    /// The configuration applied to a file, once the nested configuration files are merged
+    Config,
+    /// The formatter options of a file, with the section of the configuration that sets them
+    Formatter,
+}
+
+impl FromStr for Doc {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "daemon-logs" => return Ok(Self::DaemonLogs),
+            "config" => return Ok(Self::Config),
+            "formatter" => return Ok(Self::Formatter),
+            _ => {}
+        }
+
+        // The rule can be referenced by name, or by its category, e.g. `lint/suspicious/noDebugger`
+        let (group, name) = match s.trim_start_matches("lint/").split_once('/') {
+            Some((group, name)) => (Some(group), name),
+            None => (None, s),
+        };
+
+        lint_rules()
+            .into_iter()
+            .find(|rule| rule.name == name && group.map_or(true, |group| rule.group == group))
+            .map(Self::Rule)
+            .ok_or_else(|| {
+                format!("{s:?} isn't a known rule or topic. Use the name of a rule, e.g. \"noDebugger\", \"daemon-logs\", \"config\" or \"formatter\".")
+            })
+    }
+}
+
+/// Metadata of a lint rule, copied from its [biome_analyze::RuleMetadata]
+#[derive(Debug, Clone)]
+pub struct RuleDoc {
+    pub(crate) group: &'static str,
+    pub(crate) name: &'static str,
+    pub(crate) version: &'static str,
+    docs: &'static str,
+    pub(crate) recommended: bool,
+    deprecated: Option<&'static str>,
+    fix_kind: Option<&'static str>,
+    /// The JSON schema of the options of the rule, [None] when it doesn't accept any option
+    options_schema: Option<String>,
+}
+
+/// Returns the metadata of the lint rules of all the languages
+pub(crate) fn lint_rules() -> Vec<RuleDoc> {
+    let mut visitor = RuleDocsVisitor::default();
+    biome_js_analyze::visit_registry(&mut visitor);
+    biome_json_analyze::visit_registry(&mut visitor);
+    visitor.rules
+}
+
+#[derive(Default)]
+struct RuleDocsVisitor {
+    rules: Vec<RuleDoc>,
+}
+
+impl RuleDocsVisitor {
+    fn push_rule<R: Rule>(&mut self) {
+        let metadata = R::METADATA;
+        self.rules.push(RuleDoc {
+            group: <R::Group as RuleGroup>::NAME,
+            name: metadata.name,
+            version: metadata.version,
+            docs: metadata.docs,
+            recommended: metadata.recommended,
+            deprecated: metadata.deprecated,
+            fix_kind: metadata.fix_kind.map(|fix_kind| match fix_kind {
+                FixKind::Safe => "safe",
+                FixKind::Unsafe => "unsafe",
+            }),
+            options_schema: PossibleOptions::json_schema(metadata.name)
+                .and_then(|schema| serde_json::to_string_pretty(&schema).ok()),
+        });
+    }
+}
+
+impl RegistryVisitor<JsLanguage> for RuleDocsVisitor {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.push_rule::<R>();
+    }
+}
+
+impl RegistryVisitor<JsonLanguage> for RuleDocsVisitor {
+    fn record_category<C: GroupCategory<Language = JsonLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsonLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.push_rule::<R>();
+    }
+}
+
+/// Handler for the `explain` command. It prints the documentation embedded in the binary.
+pub(crate) fn explain(
+    session: CliSession,
+    cli_options: CliOptions,
+    doc: Doc,
+    path: Option<PathBuf>,
+) -> Result<(), CliDiagnostic> {
+    if path.is_some() && !matches!(doc, Doc::Config | Doc::Formatter) {
+        return Err(CliDiagnostic::unexpected_argument("PATH", "explain"));
+    }
+
+    match doc {
+        Doc::Rule(rule) => {
+            let recommended = if rule.recommended { "yes" } else { "no" };
+            let fix_kind = rule.fix_kind.unwrap_or("none");
+            session.app.console.log(markup! {
+                <Emphasis>"lint/"{rule.group}"/"{rule.name}</Emphasis>"\n\n"
+                "Since:        v"{rule.version}"\n"
+                "Recommended:  "{recommended}"\n"
+                "Fix:          "{fix_kind}
+            });
+            if let Some(reason) = rule.deprecated {
+                session.app.console.log(markup! {
+                    <Warn>"This rule is deprecated: "{reason}</Warn>
+                });
+            }
+
+            session.app.console.log(markup! {
+                "\n"{clean_code_blocks(rule.docs)}
+            });
+            if let Some(options_schema) = &rule.options_schema {
+                session.app.console.log(markup! {
+                    "\n"<Emphasis>"Options"</Emphasis>"\n\n"
+                    "The JSON schema of the options of the rule:\n\n"
+                    {options_schema}
+                });
+            }
+        }
+        Doc::DaemonLogs => {
+            let logs_directory = rome_log_dir();
+            session.app.console.log(markup! {
+                "The Biome daemon server writes its logs inside the directory "<Emphasis>{logs_directory.display().to_string()}</Emphasis>". A new file is created every hour.\n\n"
+                "The directory can be changed using the environment variable "<Emphasis>"BIOME_LOG_DIR"</Emphasis>".\n"
+                "Run "<Emphasis>"biome rage --daemon-logs"</Emphasis>" to print the most recent logs."
+            });
+        }
+        Doc::Config => {
+            let Some(path) = path else {
+                return Err(CliDiagnostic::missing_argument("PATH", "explain config"));
+            };
+            explain_config(session, &cli_options, &path)?;
+        }
+        Doc::Formatter => {
+            let Some(path) = path else {
+                return Err(CliDiagnostic::missing_argument("PATH", "explain formatter"));
+            };
+            explain_formatter(session, &cli_options, &path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The configuration files of the directories of a file
+struct DirectoryConfigurations {
+    /// The path of the file, resolved from the working directory
+    path: PathBuf,
+    nested_configurations: Vec<NestedConfiguration>,
+    /// The `.editorconfig` files, when the configuration uses them
+    editorconfig_files: Vec<EditorConfigFile>,
+}
+
+/// Returns the nested configuration files and the `.editorconfig` files that apply to the file
+/// at `path`. Their diagnostics are printed.
+fn load_directory_configurations(
+    session: &mut CliSession,
+    loaded_configuration: &LoadedConfiguration,
+    path: &Path,
+) -> DirectoryConfigurations {
+    let fs = &session.app.fs;
+    let path = match fs.working_directory() {
+        Some(working_directory) => working_directory.join(path),
+        None => path.to_path_buf(),
+    };
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let lookup = loaded_configuration.nested_configurations(fs);
+    let nested_configurations = lookup.load(&**fs, directory);
+    let editorconfig_files = lookup
+        .editorconfigs()
+        .map(|editorconfigs| editorconfigs.load(&**fs, directory))
+        .unwrap_or_default();
+    let diagnostics = nested_configurations
+        .iter()
+        .flat_map(|nested_configuration| &nested_configuration.diagnostics)
+        .chain(
+            editorconfig_files
+                .iter()
+                .flat_map(|editorconfig_file| &editorconfig_file.diagnostics),
+        );
+    for diagnostic in diagnostics {
+        session.app.console.error(markup! {
+            {PrintDiagnostic::simple(diagnostic)}
+        });
+    }
+    DirectoryConfigurations {
+        path,
+        nested_configurations,
+        editorconfig_files,
+    }
+}
+
+/// The configuration that applies to a file, once the configuration files of its directories
+/// and the overrides that match it are merged
+pub(crate) struct PathConfiguration {
+    /// The path of the file, relative to the root configuration
+    pub(crate) relative_path: PathBuf,
+    /// The configuration files applied to the file, from the lowest to the highest precedence
+    pub(crate) configuration_files: Vec<String>,
+    pub(crate) configuration: Configuration,
+}
+
+/// Merges the configuration files that apply to the file at `path`, and the overrides that match
+/// it, like the workspace does
+pub(crate) fn resolve_path_configuration(
+    session: &mut CliSession,
+    loaded_configuration: &LoadedConfiguration,
+    path: &Path,
+) -> Result<PathConfiguration, CliDiagnostic> {
+    let DirectoryConfigurations {
+        path,
+        nested_configurations,
+        editorconfig_files,
+    } = load_directory_configurations(session, loaded_configuration, path);
+
+    let mut configuration = loaded_configuration.configuration.clone();
+    // The patterns of the overrides are relative to the root configuration
+    let root_directory = loaded_configuration
+        .directory_path
+        .clone()
+        .unwrap_or_default();
+    let relative_path = path.strip_prefix(&root_directory).unwrap_or(&path);
+
+    let configuration_files: Vec<_> = editorconfig_files
+        .iter()
+        .map(|editorconfig_file| &editorconfig_file.file_path)
+        .chain(loaded_configuration.file_path.iter())
+        .chain(
+            nested_configurations
+                .iter()
+                .map(|nested_configuration| &nested_configuration.file_path),
+        )
+        .map(|file_path| file_path.display().to_string())
+        .collect();
+
+    // The patterns of the `.editorconfig` files and the nested files are anchored to their
+    // directory. The `.editorconfig` files don't set the options that the configuration sets.
+    for editorconfig_file in editorconfig_files {
+        for pattern in editorconfig_file.overrides.overrides.0 {
+            if pattern.matches_path(&path)? {
+                pattern.apply_to(&mut configuration);
+            }
+        }
+    }
+    let overrides = configuration.overrides.take().unwrap_or_default();
+    for pattern in overrides.0 {
+        if pattern.matches_path(relative_path)? {
+            pattern.apply_to(&mut configuration);
+        }
+    }
+    for nested_configuration in nested_configurations {
+        for pattern in nested_configuration.overrides {
+            if pattern.matches_path(&path)? {
+                pattern.apply_to(&mut configuration);
+            }
+        }
+    }
+
+    Ok(PathConfiguration {
+        relative_path: relative_path.to_path_buf(),
+        configuration_files,
+        configuration,
+    })
+}
+
+/// Prints the configuration files that apply to the file at `path`, from the lowest to the
+/// highest precedence, and the configuration that results from their merge
+fn explain_config(
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    path: &Path,
+) -> Result<(), CliDiagnostic> {
+    let loaded_configuration = load_configuration(&mut session, cli_options)?.with_file_path();
+    loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+    let PathConfiguration {
+        relative_path,
+        configuration_files,
+        configuration,
+    } = resolve_path_configuration(&mut session, &loaded_configuration, path)?;
+    let resolved = serde_json::to_string_pretty(&configuration)
+        .map_err(|error| CliDiagnostic::io_error(error.into()))?;
+
+    session.app.console.log(markup! {
+        "Configuration of "<Emphasis>{relative_path.display().to_string()}</Emphasis>
+    });
+    if configuration_files.is_empty() {
+        session.app.console.log(markup! {
+            "\nNo configuration file applies to this file, it uses the defaults of Biome."
+        });
+    } else {
+        session.app.console.log(markup! {
+            "\nThe configuration files applied to this file, from the lowest to the highest precedence:"
+        });
+        for configuration_file in &configuration_files {
+            session.app.console.log(markup! {
+                "- "{configuration_file}
+            });
+        }
+    }
+    session.app.console.log(markup! {
+        "\n"{resolved}
+    });
+
+    Ok(())
+}
+
+/// Prints the formatter options that the configuration sets for the file at `path`, and the
+/// section of the configuration that sets each of them.
+///
+/// The sections are applied like the workspace does: the root `formatter`, the formatter of the
+/// language of the file, e.g. `javascript.formatter`, the sections of the `.editorconfig` files,
+/// then the overrides that match the file, including the nested configuration files. In each override, the formatter of the language
+/// takes precedence over `formatter`.
+fn explain_formatter(
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    path: &Path,
+) -> Result<(), CliDiagnostic> {
+    let loaded_configuration = load_configuration(&mut session, cli_options)?.with_file_path();
+    loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+    let DirectoryConfigurations {
+        path,
+        nested_configurations,
+        editorconfig_files,
+    } = load_directory_configurations(&mut session, &loaded_configuration, path);
+
+    let LoadedConfiguration {
+        mut configuration,
+        directory_path,
+        formatter_options,
+        ..
+    } = loaded_configuration;
+    // The patterns of the overrides are relative to the root configuration
+    let root_directory = directory_path.unwrap_or_default();
+    let relative_path = path.strip_prefix(&root_directory).unwrap_or(&path);
+    let language = Language::from_path(relative_path);
+
+    // The name of an option, with its value and the section that sets it
+    let mut options: BTreeMap<String, (Value, String)> = BTreeMap::new();
+
+    // The root `formatter` always has all the options, only the options that the configuration
+    // files set are listed
+    for (name, value) in section_options(&formatter_options) {
+        options.insert(name, (value, "formatter".to_string()));
+    }
+    if let Some((section, language_options)) = language_options(
+        &language,
+        configuration.javascript.as_ref(),
+        configuration.json.as_ref(),
+    ) {
+        for (name, value) in language_options {
+            // At the root, the formatter of a language can't enable the formatter disabled
+            // by `formatter`
+            if name == "enabled" && value == Value::Bool(true) {
+                continue;
+            }
+            options.insert(name, (value, section.to_string()));
+        }
+    }
+
+    // The name of the section of each pattern that applies to the file: the patterns of the
+    // `.editorconfig` files and the nested files are anchored to their directory, and the
+    // settings of a nested file are its first pattern
+    let mut patterns = vec![];
+    for editorconfig_file in editorconfig_files {
+        let editorconfig_path = editorconfig_file.file_path;
+        let editorconfig_path = editorconfig_path
+            .strip_prefix(&root_directory)
+            .unwrap_or(&editorconfig_path);
+        for pattern in editorconfig_file.overrides.overrides.0 {
+            if pattern.matches_path(&path)? {
+                let suffix = format!(" of {}", editorconfig_path.display());
+                patterns.push((String::new(), suffix, pattern));
+            }
+        }
+    }
+    let overrides = configuration.overrides.take().unwrap_or_default();
+    for (index, pattern) in overrides.0.into_iter().enumerate() {
+        if pattern.matches_path(relative_path)? {
+            patterns.push((format!("overrides[{index}]."), String::new(), pattern));
+        }
+    }
+    for nested_configuration in nested_configurations {
+        let nested_file_path = nested_configuration.file_path;
+        let nested_file_path = nested_file_path
+            .strip_prefix(&root_directory)
+            .unwrap_or(&nested_file_path);
+        for (index, pattern) in nested_configuration.overrides.into_iter().enumerate() {
+            if pattern.matches_path(&path)? {
+                let prefix = match index {
+                    0 => String::new(),
+                    position => format!("overrides[{}].", position - 1),
+                };
+                let suffix = format!(" of {}", nested_file_path.display());
+                patterns.push((prefix, suffix, pattern));
+            }
+        }
+    }
+
+    for (prefix, suffix, pattern) in patterns {
+        if let Some(formatter) = pattern.formatter.as_ref() {
+            for (name, value) in section_options(formatter) {
+                options.insert(name, (value, format!("{prefix}formatter{suffix}")));
+            }
+        }
+        if let Some((section, language_options)) = language_options(
+            &language,
+            pattern.javascript.as_ref(),
+            pattern.json.as_ref(),
+        ) {
+            for (name, value) in language_options {
+                options.insert(name, (value, format!("{prefix}{section}{suffix}")));
+            }
+        }
+    }
+
+    session.app.console.log(markup! {
+        "Formatter options of "<Emphasis>{relative_path.display().to_string()}</Emphasis>
+    });
+    if options.is_empty() {
+        session.app.console.log(markup! {
+            "\nThe configuration doesn't set any formatter option of this file, it uses the defaults of Biome."
+        });
+        return Ok(());
+    }
+
+    session.app.console.log(markup! {
+        "\nThe options set by the configuration, with the section that sets them:"
+    });
+    let name_width = options.keys().map(String::len).max().unwrap_or_default();
+    let value_width = options
+        .values()
+        .map(|(value, _)| value.to_string().len())
+        .max()
+        .unwrap_or_default();
+    for (name, (value, source)) in &options {
+        let value = value.to_string();
+        let line = format!("{name:name_width$}  {value:value_width$}  {source}");
+        session.app.console.log(markup! {
+            {line}
+        });
+    }
+    session.app.console.log(markup! {
+        "\nThe other options use their default value."
+    });
+
+    Ok(())
+}
+
+/// Returns the formatter options of the section of the configuration of `language`, e.g.
+/// `javascript.formatter`, with the name of the section
+fn language_options(
+    language: &Language,
+    javascript: Option<&JavascriptConfiguration>,
+    json: Option<&JsonConfiguration>,
+) -> Option<(&'static str, Map<String, Value>)> {
+    if language.is_javascript_like() {
+        let formatter = javascript?.formatter.as_ref()?;
+        Some(("javascript.formatter", section_options(formatter)))
+    } else if language.is_json_like() {
+        let formatter = json?.formatter.as_ref()?;
+        Some(("json.formatter", section_options(formatter)))
+    } else {
+        None
+    }
+}
+
+/// Returns the formatter options set by a section of the configuration, by their name in the
+/// configuration file. The deprecated `indentSize` is returned as `indentWidth`, unless the
+/// section sets both.
+fn section_options(section: &impl Serialize) -> Map<String, Value> {
+    let Ok(Value::Object(section)) = serde_json::to_value(section) else {
+        return Map::new();
+    };
+    let mut options = Map::new();
+    for (name, value) in section {
+        match name.as_str() {
+            // These fields select the files, they aren't options
+            "ignore" | "include" | "useEditorconfig" => {}
+            _ if value.is_null() => {}
+            "indentSize" => {
+                options.entry("indentWidth").or_insert(value);
+            }
+            _ => {
+                options.insert(name, value);
+            }
+        }
+    }
+    options
+}
+
+/// Removes the attributes used by the documentation tests from the code blocks,
+/// e.g. "```js,expect_diagnostic" becomes "```js", and the space that follows the `///` of
+/// each line of the doc comment
+fn clean_code_blocks(docs: &str) -> String {
+    docs.lines()
+        .map(|line| {
+            let line = line.strip_prefix(' ').unwrap_or(line);
+            let trimmed = line.trim_start();
+            match trimmed
+                .strip_prefix("```")
+                .and_then(|info| info.split_once(','))
+            {
+                Some((language, _)) => format!("```{language}"),
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,20 +1,23 @@
+use crate::changed::{resolve_paths, VcsPathsOptions};
 use crate::cli_options::CliOptions;
 use crate::configuration::{load_configuration, LoadedConfiguration};
 use crate::diagnostics::DeprecatedArgument;
 use crate::execute::ReportMode;
-use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::vcs::read_vcs_ignore_files;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
 use biome_console::{markup, ConsoleExt};
-use biome_diagnostics::PrintDiagnostic;
+use biome_diagnostics::{DiffOptions, DiffStyle, PrintDiagnostic};
 use biome_service::configuration::json::JsonFormatter;
 use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{FilesConfiguration, FormatterConfiguration};
 use biome_service::workspace::UpdateSettingsParams;
 use biome_service::{JavascriptFormatter, MergeWith};
 use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
+use std::str::FromStr;
 
 pub(crate) struct FormatCommandPayload {
     pub(crate) javascript_formatter: Option<JavascriptFormatter>,
@@ -23,11 +26,91 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
     pub(crate) files_configuration: Option<FilesConfiguration>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) changed: bool,
+    pub(crate) since: Option<String>,
     pub(crate) write: bool,
+    pub(crate) diff: DiffArg,
+    pub(crate) diff_style: DiffStyleArg,
+    pub(crate) diff_context: usize,
     pub(crate) cli_options: CliOptions,
     pub(crate) paths: Vec<OsString>,
 }
 
+/// Whether the differences found in check mode are printed
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DiffArg {
+    On,
+    Off,
+}
+
+impl FromStr for DiffArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "on" => Ok(Self::On),
+            "off" => Ok(Self::Off),
+            _ => Err(format!("value {s:?} is not valid for the --diff argument")),
+        }
+    }
+}
+
+impl Display for DiffArg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiffArg::On => write!(f, "on"),
+            DiffArg::Off => write!(f, "off"),
+        }
+    }
+}
+
+/// How the differences found in check mode are presented
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DiffStyleArg {
+    /// The lines of both revisions, with the changed words highlighted
+    Default,
+    /// The hunks of a unified diff
+    Unified,
+    /// The changed lines, with the deleted and inserted words marked inline
+    Word,
+}
+
+impl FromStr for DiffStyleArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "unified" => Ok(Self::Unified),
+            "word" => Ok(Self::Word),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --diff-style argument"
+            )),
+        }
+    }
+}
+
+impl Display for DiffStyleArg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiffStyleArg::Default => write!(f, "default"),
+            DiffStyleArg::Unified => write!(f, "unified"),
+            DiffStyleArg::Word => write!(f, "word"),
+        }
+    }
+}
+
+impl From<DiffStyleArg> for DiffStyle {
+    fn from(value: DiffStyleArg) -> Self {
+        match value {
+            DiffStyleArg::Default => DiffStyle::Default,
+            DiffStyleArg::Unified => DiffStyle::Unified,
+            DiffStyleArg::Word => DiffStyle::Word,
+        }
+    }
+}
+
 /// Handler for the "format" command of the Biome CLI
 pub(crate) fn format(
     mut session: CliSession,
@@ -40,21 +123,22 @@ pub(crate) fn format(
         paths,
         cli_options,
         stdin_file_path,
+        staged,
+        changed,
+        since,
         files_configuration,
         write,
+        diff,
+        diff_style,
+        diff_context,
         json_formatter,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
-    let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
+    let mut loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
 
     loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
 
-    let LoadedConfiguration {
-        mut configuration,
-        directory_path: configuration_path,
-        ..
-    } = loaded_configuration;
     // TODO: remove in biome 2.0
     if formatter_configuration
         .as_ref()
@@ -95,24 +179,38 @@ pub(crate) fn format(
         })
     }
 
-    configuration.merge_with(javascript_formatter);
-    configuration.merge_with(json_formatter);
-    configuration.merge_with(formatter_configuration);
+    loaded_configuration.apply_formatter_arguments(
+        formatter_configuration,
+        javascript_formatter,
+        json_formatter,
+    );
+    let nested_configurations = loaded_configuration.nested_configurations(&session.app.fs);
+
+    let LoadedConfiguration {
+        mut configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
     configuration.merge_with(vcs_configuration);
     configuration.merge_with(files_configuration);
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
-    store_path_to_ignore_from_vcs(
+    let (vcs_ignore_files, nested_vcs_ignore_files) =
+        read_vcs_ignore_files(&mut session, &configuration, vcs_base_path, &cli_options)?;
+    let Some(paths) = resolve_paths(
         &mut session,
-        &mut configuration,
-        vcs_base_path,
-        &cli_options,
-    )?;
-    session
-        .app
-        .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
+        &configuration,
+        VcsPathsOptions {
+            changed,
+            staged,
+            since: since.as_deref(),
+        },
+        paths,
+    )?
+    else {
+        return Ok(());
+    };
 
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
@@ -143,7 +241,29 @@ pub(crate) fn format(
             write,
             stdin,
         })
-    };
+    }
+    .with_configuration(&configuration)
+    .with_nested_configurations(
+        nested_configurations.with_vcs_ignore_files(nested_vcs_ignore_files),
+    )
+    .with_diff_options(DiffOptions {
+        style: match diff {
+            DiffArg::On => diff_style.into(),
+            DiffArg::Off => DiffStyle::Off,
+        },
+        context_lines: diff_context,
+        ..DiffOptions::default()
+    });
+
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            vcs_ignore_files,
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })?;
 
     execute_mode(execution, session, &cli_options, paths)
 }
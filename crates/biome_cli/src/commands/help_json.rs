@@ -0,0 +1,196 @@
+use crate::{biome_command, CliDiagnostic, CliSession, VERSION};
+use biome_console::{markup, ConsoleExt};
+use serde::Serialize;
+
+/// The commands and the options of the CLI, printed by `biome --help-json`
+#[derive(Debug, Serialize)]
+struct CliSchema {
+    name: &'static str,
+    version: &'static str,
+    #[serde(flatten)]
+    help: Help,
+    commands: Vec<CommandSchema>,
+}
+
+#[derive(Debug, Serialize)]
+struct CommandSchema {
+    /// The name of the command, e.g. `check` or `migrate prettier`
+    name: String,
+    #[serde(flatten)]
+    help: Help,
+}
+
+/// The content of the help of a command, as printed by `--help`
+#[derive(Debug, Default, Serialize)]
+struct Help {
+    description: String,
+    usage: String,
+    sections: Vec<HelpSection>,
+}
+
+/// A group of options, e.g. "Global options applied to all commands"
+#[derive(Debug, Serialize)]
+struct HelpSection {
+    title: String,
+    items: Vec<HelpItem>,
+}
+
+/// An option, a positional item or a command
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HelpItem {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short: Option<String>,
+    /// The value accepted by the option, e.g. `<off|force>` for `--colors=<off|force>`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    argument: Option<String>,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<String>,
+    /// The environment variable that sets the option, e.g. `BIOME_PROFILE`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    env: Option<String>,
+}
+
+/// Handler for the `--help-json` flag of the Biome CLI.
+///
+/// The schema is read from the documentation that bpaf generates out of the metadata of the
+/// parsers, the same that generates the CLI reference of the website. It has a section for
+/// every command, nested commands included, and marks up the name, the argument, the
+/// default value and the environment variable of every item. Hidden commands and options
+/// are excluded.
+pub(crate) fn help_json(session: CliSession) -> Result<(), CliDiagnostic> {
+    let markdown = format!("\n{}", biome_command().render_markdown("biome"));
+
+    // The first section documents the `biome` command itself
+    let mut sections = markdown.split("\n## ").skip(1).map(parse_command);
+    let (_, help) = sections.next().unwrap_or_default();
+    let commands = sections
+        .map(|(name, help)| CommandSchema { name, help })
+        .collect();
+
+    let schema = CliSchema {
+        name: "biome",
+        version: VERSION,
+        help,
+        commands,
+    };
+
+    let json = serde_json::to_string_pretty(&schema)
+        .expect("the schema of the CLI can always be serialized");
+    session.app.console.log(markup! {{json}});
+
+    Ok(())
+}
+
+/// Parses the documentation of a command, starting from its title, e.g. `biome check`
+fn parse_command(section: &str) -> (String, Help) {
+    let mut lines = section.lines();
+    let title = lines.next().unwrap_or_default();
+    let name = title
+        .strip_prefix("biome")
+        .unwrap_or(title)
+        .trim()
+        .to_string();
+
+    let mut help = Help::default();
+    let mut description = vec![];
+    for line in lines.by_ref() {
+        if let Some(usage) = line.strip_prefix("**Usage**:") {
+            help.usage = strip_markup(usage);
+            break;
+        }
+        description.push(line);
+    }
+    help.description = description.join("\n").trim().to_string();
+
+    for line in lines {
+        if let Some(title) = line
+            .strip_prefix("**")
+            .and_then(|title| title.strip_suffix("**"))
+        {
+            help.sections.push(HelpSection {
+                title: title.trim_end_matches(':').to_string(),
+                items: vec![],
+            });
+            continue;
+        }
+
+        let Some(section) = help.sections.last_mut() else {
+            continue;
+        };
+        if let Some(item) = line.strip_prefix("- ") {
+            section.items.push(parse_item(item));
+            continue;
+        }
+
+        // The lines of the description are indented under their item
+        let Some(item) = section.items.last_mut() else {
+            continue;
+        };
+        let line = line.trim();
+        if let Some(default) = line
+            .strip_prefix("[default: ")
+            .and_then(|default| default.strip_suffix(']'))
+        {
+            item.default = Some(default.to_string());
+        } else if let Some(env) = line.strip_prefix("Uses environment variable ") {
+            item.env = Some(strip_markup(env));
+        } else if !line.is_empty() {
+            if !item.description.is_empty() {
+                item.description.push(' ');
+            }
+            item.description.push_str(line);
+        }
+    }
+
+    help.sections.retain(|section| !section.items.is_empty());
+    (name, help)
+}
+
+/// Parses the signature of an item, e.g.
+///
+/// ```text
+/// **`-h`**, **`--help`** &mdash;
+/// **`    --colors`**=_`<off|force>`_ &mdash;
+/// ```
+fn parse_item(item: &str) -> HelpItem {
+    let signature = item
+        .trim_end()
+        .strip_suffix("&mdash;")
+        .unwrap_or(item)
+        .trim_end();
+
+    let (names, argument) = match signature.split_once('=') {
+        Some((names, argument)) => (names, Some(strip_markup(argument))),
+        None => (signature, None),
+    };
+
+    let mut names: Vec<_> = names.split(", ").map(strip_markup).collect();
+    let name = names.pop().unwrap_or_default();
+    let short = names.pop();
+
+    HelpItem {
+        name,
+        short,
+        argument,
+        ..HelpItem::default()
+    }
+}
+
+/// Removes the emphasis, the code spans and the escapes of a markdown fragment, e.g. it
+/// turns the following usage into `biome migrate [--write]`
+///
+/// ```text
+/// **`biome`** **`migrate`** \[**`--write`**\]
+/// ```
+fn strip_markup(text: &str) -> String {
+    text.replace("**", "")
+        .replace('\\', "")
+        .replace("_`", "")
+        .replace("`_", "")
+        .replace('`', "")
+        .trim()
+        .to_string()
+}
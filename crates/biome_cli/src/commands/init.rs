@@ -1,11 +1,29 @@
 use crate::{CliDiagnostic, CliSession};
-use biome_console::{markup, ConsoleExt, HorizontalLine};
-use biome_service::configuration::Configuration;
+use biome_console::{markup, Console, ConsoleExt, HorizontalLine, LogLevel};
+use biome_fs::{BIOME_JSON, BIOME_JSONC};
+use biome_service::configuration::vcs::{VcsClientKind, VcsConfiguration};
+use biome_service::configuration::{
+    Configuration, FormatterConfiguration, JavascriptConfiguration, JavascriptFormatter,
+    LinterConfiguration, Rules,
+};
 use biome_service::create_config;
 
-pub(crate) fn init(mut session: CliSession) -> Result<(), CliDiagnostic> {
+/// Handler for the "init" command of the Biome CLI
+pub(crate) fn init(
+    mut session: CliSession,
+    interactive: bool,
+    emit_jsonc: bool,
+) -> Result<(), CliDiagnostic> {
+    let configuration = if interactive {
+        ask_configuration(&mut *session.app.console)
+    } else {
+        Configuration::default()
+    };
+
     let fs = &mut session.app.fs;
-    create_config(fs, Configuration::default())?;
+    create_config(fs, configuration, emit_jsonc)?;
+
+    let config_name = if emit_jsonc { BIOME_JSONC } else { BIOME_JSON };
 
     session.app.console.log(markup! {
 "\n"<Inverse>"Welcome to Biome! Let's get you started..."</Inverse>"
@@ -32,3 +50,117 @@ pub(crate) fn init(mut session: CliSession) -> Result<(), CliDiagnostic> {
 
     Ok(())
 }
+
+/// Asks the user some questions about the project, and returns a configuration based on the answers
+fn ask_configuration(console: &mut dyn Console) -> Configuration {
+    console.log(markup! {
+        <Info>"Press Enter to use the default answer, written first."</Info>
+    });
+
+    let indent_style = ask(
+        console,
+        "Which indentation do you prefer?",
+        &["tab", "space"],
+    );
+    let quote_style = ask(
+        console,
+        "Which quotes do you prefer for the strings of JavaScript files?",
+        &["double", "single"],
+    );
+    let rules = ask(
+        console,
+        "Which lint rules do you want to enable?",
+        &["recommended", "all", "none"],
+    );
+    let vcs = ask(
+        console,
+        "Do you want to ignore the files listed in your .gitignore?",
+        &["no", "yes"],
+    );
+
+    let mut configuration = Configuration::default();
+
+    configuration.formatter = Some(FormatterConfiguration {
+        enabled: Some(true),
+        format_with_errors: None,
+        indent_style: indent_style.parse().ok(),
+        indent_size: None,
+        indent_width: None,
+        line_ending: None,
+        line_width: None,
+        ignore: None,
+        include: None,
+        use_editorconfig: None,
+    });
+
+    configuration.javascript = Some(JavascriptConfiguration {
+        formatter: Some(JavascriptFormatter {
+            quote_style: quote_style.parse().ok(),
+            ..JavascriptFormatter::default()
+        }),
+        ..JavascriptConfiguration::default()
+    });
+
+    configuration.linter = Some(match rules {
+        "all" => LinterConfiguration {
+            rules: Some(Rules {
+                recommended: None,
+                all: Some(true),
+                ..Rules::default()
+            }),
+            ..LinterConfiguration::default()
+        },
+        "none" => LinterConfiguration {
+            enabled: Some(false),
+            rules: None,
+            ..LinterConfiguration::default()
+        },
+        _ => LinterConfiguration::default(),
+    });
+
+    if vcs == "yes" {
+        configuration.vcs = Some(VcsConfiguration {
+            client_kind: Some(VcsClientKind::Git),
+            enabled: Some(true),
+            use_ignore_file: Some(true),
+            ..VcsConfiguration::default()
+        });
+    }
+
+    configuration
+}
+
+/// Asks a question until the user picks one of the `choices`. The first choice is the
+/// default answer, returned when the user doesn't type anything.
+pub(crate) fn ask<'a>(console: &mut dyn Console, question: &str, choices: &[&'a str]) -> &'a str {
+    let choices_list = choices.join("/");
+    loop {
+        console.print(
+            LogLevel::Log,
+            markup! {
+                <Emphasis>{question}</Emphasis>" "<Dim>"("{choices_list}")"</Dim>" "
+            },
+        );
+
+        // The input was closed, there's no one to answer
+        let Some(answer) = console.read_line() else {
+            return choices[0];
+        };
+
+        let answer = answer.trim();
+        if answer.is_empty() {
+            return choices[0];
+        }
+        if let Some(choice) = choices
+            .iter()
+            .copied()
+            .find(|choice| choice.eq_ignore_ascii_case(answer))
+        {
+            return choice;
+        }
+
+        console.error(markup! {
+            <Warn>"Please answer one of: "{choices_list}</Warn>
+        });
+    }
+}
@@ -1,25 +1,105 @@
+use crate::changed::{resolve_paths, VcsPathsOptions};
 use crate::cli_options::CliOptions;
 use crate::configuration::{load_configuration, LoadedConfiguration};
-use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::vcs::read_vcs_ignore_files;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
+use biome_diagnostics::{DiffOptions, FixPreview};
 use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{FilesConfiguration, LinterConfiguration};
-use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
+use biome_service::workspace::{
+    FixFileMode, PackageJsonFile, RuleSelector, TsConfigFile, UpdateSettingsParams,
+};
 use biome_service::MergeWith;
 use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
+use std::str::FromStr;
 
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) unsafe_only: Vec<RuleSelector>,
+    pub(crate) rule: Vec<RuleSelector>,
+    pub(crate) skip_rule: Vec<RuleSelector>,
     pub(crate) cli_options: CliOptions,
     pub(crate) linter_configuration: Option<LinterConfiguration>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
     pub(crate) files_configuration: Option<FilesConfiguration>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) changed: bool,
+    pub(crate) since: Option<String>,
+    pub(crate) fix_preview: FixPreviewArg,
+}
+
+/// How the suggested fixes of the diagnostics are printed
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FixPreviewArg {
+    /// The diff of the fix
+    Full,
+    /// The changed lines, with the deleted and inserted words marked inline
+    Compact,
+    /// Only the title of the fix
+    Off,
+}
+
+impl FromStr for FixPreviewArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(Self::Full),
+            "compact" => Ok(Self::Compact),
+            "off" => Ok(Self::Off),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --fix-preview argument"
+            )),
+        }
+    }
+}
+
+impl Display for FixPreviewArg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FixPreviewArg::Full => write!(f, "full"),
+            FixPreviewArg::Compact => write!(f, "compact"),
+            FixPreviewArg::Off => write!(f, "off"),
+        }
+    }
+}
+
+/// The rules passed to `--unsafe-only`, separated by commas
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuleSelectorList(Vec<RuleSelector>);
+
+impl FromStr for RuleSelectorList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|selector| RuleSelector::from_str(selector.trim()))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl From<RuleSelectorList> for Vec<RuleSelector> {
+    fn from(list: RuleSelectorList) -> Self {
+        list.0
+    }
+}
+
+impl From<FixPreviewArg> for FixPreview {
+    fn from(value: FixPreviewArg) -> Self {
+        match value {
+            FixPreviewArg::Full => FixPreview::Full,
+            FixPreviewArg::Compact => FixPreview::Compact,
+            FixPreviewArg::Off => FixPreview::Off,
+        }
+    }
 }
 
 /// Handler for the "lint" command of the Biome CLI
@@ -30,12 +110,19 @@ pub(crate) fn lint(
     let LintCommandPayload {
         apply,
         apply_unsafe,
+        unsafe_only,
+        rule,
+        skip_rule,
         cli_options,
         linter_configuration,
         paths,
         stdin_file_path,
+        staged,
+        changed,
+        since,
         vcs_configuration,
         files_configuration,
+        fix_preview,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
@@ -52,9 +139,22 @@ pub(crate) fn lint(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
+    if !unsafe_only.is_empty() {
+        if apply_unsafe {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--apply-unsafe",
+                "--unsafe-only",
+            ));
+        }
+        if fix_file_mode.is_none() {
+            return Err(CliDiagnostic::missing_argument("--apply", "lint"));
+        }
+    }
+
     let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
 
     loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+    let nested_configurations = loaded_configuration.nested_configurations(&session.app.fs);
 
     let LoadedConfiguration {
         configuration: mut fs_configuration,
@@ -67,12 +167,22 @@ pub(crate) fn lint(
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
-    store_path_to_ignore_from_vcs(
+    let (vcs_ignore_files, nested_vcs_ignore_files) =
+        read_vcs_ignore_files(&mut session, &fs_configuration, vcs_base_path, &cli_options)?;
+
+    let Some(paths) = resolve_paths(
         &mut session,
-        &mut fs_configuration,
-        vcs_base_path,
-        &cli_options,
-    )?;
+        &fs_configuration,
+        VcsPathsOptions {
+            changed,
+            staged,
+            since: since.as_deref(),
+        },
+        paths,
+    )?
+    else {
+        return Ok(());
+    };
 
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
@@ -88,20 +198,39 @@ pub(crate) fn lint(
         None
     };
 
+    let execution = Execution::new(TraversalMode::Lint {
+        fix_file_mode,
+        stdin,
+        only: rule,
+        skip: skip_rule,
+        unsafe_rules: unsafe_only,
+    })
+    .with_configuration(&fs_configuration)
+    .with_nested_configurations(
+        nested_configurations.with_vcs_ignore_files(nested_vcs_ignore_files),
+    )
+    .with_diff_options(DiffOptions {
+        fix_preview: fix_preview.into(),
+        ..DiffOptions::default()
+    });
+
+    // the path aliases of the project are used to resolve the imports
+    let working_directory = session.app.fs.working_directory().unwrap_or_default();
+    let tsconfig_files = TsConfigFile::read_all(&*session.app.fs, &working_directory);
+    // the dependencies of the project are the packages that the files can import
+    let package_json_files = PackageJsonFile::read(&*session.app.fs, &working_directory)
+        .into_iter()
+        .collect();
+
     session
         .app
         .workspace
         .update_settings(UpdateSettingsParams {
             configuration: fs_configuration,
+            vcs_ignore_files,
+            tsconfig_files,
+            package_json_files,
         })?;
 
-    execute_mode(
-        Execution::new(TraversalMode::Lint {
-            fix_file_mode,
-            stdin,
-        }),
-        session,
-        &cli_options,
-        paths,
-    )
+    execute_mode(execution, session, &cli_options, paths)
 }
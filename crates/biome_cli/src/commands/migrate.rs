@@ -1,27 +1,38 @@
 use crate::cli_options::CliOptions;
+use crate::commands::explain::lint_rules;
 use crate::configuration::{load_configuration, LoadedConfiguration};
 use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::{execute_mode, Execution, TraversalMode};
 use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_service::Configuration;
 
 /// Handler for the "check" command of the Biome CLI
 pub(crate) fn migrate(
     mut session: CliSession,
     cli_options: CliOptions,
     write: bool,
+    new_rules: bool,
+    format_configuration: bool,
 ) -> Result<(), CliDiagnostic> {
     let LoadedConfiguration {
-        configuration: _,
+        configuration,
         diagnostics: _,
         directory_path,
         file_path,
+        extended_file_paths: _,
+        formatter_options: _,
     } = load_configuration(&mut session, &cli_options)?;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
     if let (Some(path), Some(directory_path)) = (file_path, directory_path) {
+        if new_rules {
+            return print_new_rules(session, &configuration);
+        }
         execute_mode(
             Execution::new(TraversalMode::Migrate {
                 write,
+                format_configuration,
                 configuration_file_path: path,
                 configuration_directory_path: directory_path,
             }),
@@ -35,3 +46,76 @@ pub(crate) fn migrate(
         }))
     }
 }
+
+/// Prints the lint rules added after the version of Biome of the `$schema` of the configuration
+fn print_new_rules(
+    session: CliSession,
+    configuration: &Configuration,
+) -> Result<(), CliDiagnostic> {
+    let baseline = configuration.schema.as_deref().and_then(schema_version);
+    let Some((baseline, baseline_version)) =
+        baseline.and_then(|baseline| Some((baseline, parse_version(baseline)?)))
+    else {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Biome couldn't find the version of the configuration, the $schema of the configuration file should be https://biomejs.dev/schemas/<VERSION>/schema.json".to_string(),
+        }));
+    };
+
+    // The rules that aren't released yet have the version `next`, they come after all the others
+    let rule_version =
+        |version: &str| parse_version(version).unwrap_or((u32::MAX, u32::MAX, u32::MAX));
+    let mut rules: Vec<_> = lint_rules()
+        .into_iter()
+        .filter(|rule| rule_version(rule.version) > baseline_version)
+        .collect();
+    rules.sort_by_key(|rule| (rule_version(rule.version), rule.group, rule.name));
+
+    let console = &mut *session.app.console;
+    if rules.is_empty() {
+        console.log(markup! {
+            <Info>"No rule was added after Biome "{baseline}"."</Info>
+        });
+        return Ok(());
+    }
+
+    let list = rules
+        .iter()
+        .map(|rule| {
+            let version = match rule.version {
+                "next" => "not released yet".to_string(),
+                version => format!("v{version}"),
+            };
+            let recommended = if rule.recommended {
+                ", recommended"
+            } else {
+                ""
+            };
+            format!(
+                "- lint/{}/{} ({version}{recommended})",
+                rule.group, rule.name
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    console.log(markup! {
+        "The rules added after Biome "{baseline}":\n\n"
+        {list}"\n\n"
+        "Run "<Emphasis>"biome explain <RULE>"</Emphasis>" to read the documentation of a rule."
+    });
+
+    Ok(())
+}
+
+/// Returns the version of Biome of a schema URL, e.g. `1.4.1` for
+/// `https://biomejs.dev/schemas/1.4.1/schema.json`
+fn schema_version(schema: &str) -> Option<&str> {
+    let (_, rest) = schema.split_once("/schemas/")?;
+    let (version, _) = rest.split_once('/')?;
+    Some(version)
+}
+
+/// Parses a version like `1.4.1` into its major, minor and patch numbers
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut numbers = version.splitn(3, '.').map(|number| number.parse().ok());
+    Some((numbers.next()??, numbers.next()??, numbers.next()??))
+}
@@ -1,6 +1,11 @@
 use crate::cli_options::{cli_options, CliOptions, ColorsArg};
+use crate::commands::completions::Shell;
+use crate::commands::explain::Doc;
+use crate::commands::format::{DiffArg, DiffStyleArg};
+use crate::commands::lint::{FixPreviewArg, RuleSelectorList};
 use crate::logging::LoggingKind;
 use crate::{LoggingLevel, VERSION};
+use biome_diagnostics::DiffOptions;
 use biome_service::configuration::json::JsonFormatter;
 use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{
@@ -8,6 +13,7 @@ use biome_service::configuration::{
     json::json_formatter, linter_configuration, vcs::vcs_configuration, FilesConfiguration,
     FormatterConfiguration, JavascriptFormatter, LinterConfiguration,
 };
+use biome_service::workspace::RuleSelector;
 use biome_service::Configuration;
 use bpaf::Bpaf;
 use std::ffi::OsString;
@@ -15,12 +21,18 @@ use std::path::PathBuf;
 
 pub(crate) mod check;
 pub(crate) mod ci;
+pub(crate) mod clean;
+pub(crate) mod compare_prettier;
+pub(crate) mod completions;
 pub(crate) mod daemon;
+pub(crate) mod explain;
 pub(crate) mod format;
+pub(crate) mod help_json;
 pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
+pub(crate) mod search;
 pub(crate) mod version;
 
 #[derive(Debug, Clone, Bpaf)]
@@ -38,6 +50,9 @@ pub enum BiomeCommand {
         /// Prints the Biome daemon server logs
         #[bpaf(long("daemon-logs"), switch)]
         bool,
+        /// Prints which features are enabled for these files, and the settings that apply to them
+        #[bpaf(positional("PATH"), many)]
+        Vec<OsString>,
     ),
     /// Start the Biome daemon server process
     #[bpaf(command)]
@@ -51,6 +66,10 @@ pub enum BiomeCommand {
     #[bpaf(command)]
     Stop,
 
+    /// Removes the cache of the diagnostics shared by the CLI and the daemon
+    #[bpaf(command)]
+    Clean,
+
     /// Runs formatter, linter and import sorting to the requested files.
     #[bpaf(command)]
     Check {
@@ -60,6 +79,18 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Write safe fixes, formatting and import sorting to the files
+        #[bpaf(long("write"), switch)]
+        write: bool,
+        /// Also apply the unsafe fixes of these rules, or of the rules of these groups, separated
+        /// by commas, e.g. `--unsafe-only=style/useConst,suspicious`. It requires `--apply` or
+        /// `--write`.
+        #[bpaf(long("unsafe-only"), argument("GROUP/RULE,..."), optional, hide_usage)]
+        unsafe_only: Option<RuleSelectorList>,
+        /// Asks to accept or skip each fix before applying it. The decisions taken for a rule
+        /// can be reused for all its fixes. It requires `--apply` or `--apply-unsafe`.
+        #[bpaf(long("interactive"), switch, hide_usage)]
+        interactive: bool,
         /// Allow to enable or disable the formatter check.
         #[bpaf(
             long("formatter-enabled"),
@@ -90,6 +121,32 @@ pub enum BiomeCommand {
         /// Example: `echo 'let a;' | biome check --stdin-file-path=file.js`
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
+        /// When set to true, only the files that have been staged (the ones prepared to be committed)
+        /// will be checked. With `--apply` or `--apply-unsafe`, the fixes are staged too, while
+        /// the unstaged changes of the files are never staged.
+        #[bpaf(long("staged"), switch, hide_usage)]
+        staged: bool,
+        /// When set to true, only the files that have been changed compared to your `defaultBranch`
+        /// configuration will be checked.
+        #[bpaf(long("changed"), switch, hide_usage)]
+        changed: bool,
+        /// Use this to specify the base branch to compare against when you're using the --changed
+        /// flag and the `defaultBranch` is not set in your biome.json
+        #[bpaf(long("since"), argument("REF"), hide_usage)]
+        since: Option<String>,
+        /// Keep running after the first check, and check again the files as soon as they change.
+        #[bpaf(long("watch"), switch, hide_usage)]
+        watch: bool,
+        /// How the suggested fixes are printed: "full" prints their diff, "compact" prints only
+        /// the changed lines with the changed words marked inline, "off" prints only their title.
+        #[bpaf(
+            long("fix-preview"),
+            argument("full|compact|off"),
+            fallback(FixPreviewArg::Full),
+            display_fallback,
+            hide_usage
+        )]
+        fix_preview: FixPreviewArg,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -103,6 +160,17 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Also apply the unsafe fixes of these rules, or of the rules of these groups, separated
+        /// by commas, e.g. `--unsafe-only=style/useConst,suspicious`. It requires `--apply`.
+        #[bpaf(long("unsafe-only"), argument("GROUP/RULE,..."), optional, hide_usage)]
+        unsafe_only: Option<RuleSelectorList>,
+        /// Run only this rule, or the rules of this group, even if the configuration turns them off.
+        /// It can be passed multiple times, e.g. `--rule=style/noVar --rule=suspicious`.
+        #[bpaf(long("rule"), argument("GROUP/RULE"), many, hide_usage)]
+        rule: Vec<RuleSelector>,
+        /// Don't run this rule, or the rules of this group. It can be passed multiple times.
+        #[bpaf(long("skip-rule"), argument("GROUP/RULE"), many, hide_usage)]
+        skip_rule: Vec<RuleSelector>,
         #[bpaf(external, hide_usage, optional)]
         linter_configuration: Option<LinterConfiguration>,
 
@@ -121,6 +189,28 @@ pub enum BiomeCommand {
         /// Example: `echo 'let a;' | biome lint --stdin-file-path=file.js`
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
+        /// When set to true, only the files that have been staged (the ones prepared to be committed)
+        /// will be linted.
+        #[bpaf(long("staged"), switch, hide_usage)]
+        staged: bool,
+        /// When set to true, only the files that have been changed compared to your `defaultBranch`
+        /// configuration will be linted.
+        #[bpaf(long("changed"), switch, hide_usage)]
+        changed: bool,
+        /// Use this to specify the base branch to compare against when you're using the --changed
+        /// flag and the `defaultBranch` is not set in your biome.json
+        #[bpaf(long("since"), argument("REF"), hide_usage)]
+        since: Option<String>,
+        /// How the suggested fixes are printed: "full" prints their diff, "compact" prints only
+        /// the changed lines with the changed words marked inline, "off" prints only their title.
+        #[bpaf(
+            long("fix-preview"),
+            argument("full|compact|off"),
+            fallback(FixPreviewArg::Full),
+            display_fallback,
+            hide_usage
+        )]
+        fix_preview: FixPreviewArg,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -149,6 +239,18 @@ pub enum BiomeCommand {
         /// Example: `echo 'let a;' | biome format --stdin-file-path=file.js`
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
+        /// When set to true, only the files that have been staged (the ones prepared to be committed)
+        /// will be formatted.
+        #[bpaf(long("staged"), switch, hide_usage)]
+        staged: bool,
+        /// When set to true, only the files that have been changed compared to your `defaultBranch`
+        /// configuration will be formatted.
+        #[bpaf(long("changed"), switch, hide_usage)]
+        changed: bool,
+        /// Use this to specify the base branch to compare against when you're using the --changed
+        /// flag and the `defaultBranch` is not set in your biome.json
+        #[bpaf(long("since"), argument("REF"), hide_usage)]
+        since: Option<String>,
 
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
@@ -157,13 +259,45 @@ pub enum BiomeCommand {
         #[bpaf(switch)]
         write: bool,
 
+        /// Print the differences between the files and their formatted content when `--write`
+        /// isn't passed. Use `--diff=off` to only report the files that aren't formatted.
+        #[bpaf(
+            long("diff"),
+            argument("on|off"),
+            fallback(DiffArg::On),
+            display_fallback,
+            hide_usage
+        )]
+        diff: DiffArg,
+
+        /// How the differences are printed: "default" prints the lines of both versions,
+        /// "unified" prints the hunks of a unified diff, "word" marks the changed words inline.
+        #[bpaf(
+            long("diff-style"),
+            argument("default|unified|word"),
+            fallback(DiffStyleArg::Default),
+            display_fallback,
+            hide_usage
+        )]
+        diff_style: DiffStyleArg,
+
+        /// The number of unchanged lines printed around the differences.
+        #[bpaf(
+            long("diff-context"),
+            argument("NUMBER"),
+            fallback(DiffOptions::default().context_lines),
+            display_fallback,
+            hide_usage
+        )]
+        diff_context: usize,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
     },
     /// Command to use in CI environments. Runs formatter, linter and import sorting to the requested files.
     ///
-    /// Files won't be modified, the command is a read-only operation.
+    /// Files won't be modified, the command is a read-only operation. Warnings fail the command.
     #[bpaf(command)]
     Ci {
         /// Allow to enable or disable the formatter check.
@@ -175,6 +309,17 @@ pub enum BiomeCommand {
         /// Allow to enable or disable the organize imports.
         #[bpaf(long("organize-imports-enabled"), argument("true|false"), optional)]
         organize_imports_enabled: Option<bool>,
+        /// When set to true, only the files that have been changed compared to your `defaultBranch`
+        /// configuration will be checked.
+        #[bpaf(long("changed"), switch, hide_usage)]
+        changed: bool,
+        /// Use this to specify the base branch to compare against when you're using the --changed
+        /// flag and the `defaultBranch` is not set in your biome.json
+        #[bpaf(long("since"), argument("REF"), hide_usage)]
+        since: Option<String>,
+        /// Don't exit with an error code when some diagnostics emit warnings.
+        #[bpaf(long("allow-warnings"), switch, hide_usage)]
+        allow_warnings: bool,
 
         #[bpaf(external, hide_usage)]
         configuration: Configuration,
@@ -188,7 +333,15 @@ pub enum BiomeCommand {
 
     /// Bootstraps a new biome project. Creates a configuration file with some defaults.
     #[bpaf(command)]
-    Init,
+    Init {
+        /// Asks some questions about the project (indentation, quotes, lint rules, VCS integration),
+        /// and creates a configuration file based on the answers.
+        #[bpaf(long("interactive"), switch)]
+        interactive: bool,
+        /// Creates a `biome.jsonc` file, with comments that explain the options.
+        #[bpaf(long("jsonc"), switch)]
+        emit_jsonc: bool,
+    },
     /// Acts as a server for the Language Server Protocol over stdin/stdout
     #[bpaf(command("lsp-proxy"))]
     LspProxy(
@@ -203,8 +356,69 @@ pub enum BiomeCommand {
         /// Writes the new configuration file to disk
         #[bpaf(long("write"), switch)]
         bool,
+        /// Lists the lint rules added after the version of Biome of the `$schema` of the
+        /// configuration file, instead of migrating the configuration
+        #[bpaf(long("new-rules"), switch)]
+        bool,
+        /// Sorts the sections of the configuration file and the rules of each group, and removes
+        /// the options set to their default value. The comments are kept.
+        #[bpaf(long("format-config"), switch)]
+        bool,
     ),
 
+    /// Shows the documentation of a rule, or of a topic, e.g. `daemon-logs`.
+    ///
+    /// The topic `config` shows the configuration applied to a file, e.g. `biome explain config src/index.js`.
+    ///
+    /// The topic `formatter` shows the formatter options of a file, and the section of the configuration that sets each of them, e.g. `biome explain formatter src/index.js`.
+    #[bpaf(command)]
+    Explain {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+        /// The name of the rule (e.g. `noDebugger`) or of the topic
+        #[bpaf(positional("NAME"))]
+        doc: Doc,
+        /// The path of the file explained by the topics `config` and `formatter`
+        #[bpaf(positional("PATH"), optional)]
+        path: Option<PathBuf>,
+    },
+
+    /// Searches a syntax pattern in a set of files, and prints the code that matches it.
+    ///
+    /// The pattern is a snippet of code. Identifiers that start with `$` match any node,
+    /// e.g. `console.log($message)` matches all the calls to `console.log` with one argument.
+    #[bpaf(command)]
+    Search {
+        #[bpaf(external, optional, hide_usage)]
+        vcs_configuration: Option<VcsConfiguration>,
+
+        #[bpaf(external, optional, hide_usage)]
+        files_configuration: Option<FilesConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+        /// The pattern to search, e.g. `console.log($message)`
+        #[bpaf(positional("PATTERN"))]
+        pattern: String,
+        /// Single file, single path or list of paths
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+
+    /// Prints the script that completes the commands and the options of Biome in a shell.
+    ///
+    /// For example, with bash: `biome completions bash > /etc/bash_completion.d/biome`
+    #[bpaf(command)]
+    Completions {
+        /// The shell that uses the script: bash, zsh, fish, elvish or powershell
+        #[bpaf(positional("SHELL"))]
+        shell: Shell,
+    },
+
+    /// Prints the commands and the options of the CLI as JSON, for the tools that wrap Biome
+    #[bpaf(long("help-json"))]
+    HelpJson,
+
     #[bpaf(command("__run_server"), hide)]
     RunServer {
         #[bpaf(long("stop-on-disconnect"), hide_usage)]
@@ -212,9 +426,36 @@ pub enum BiomeCommand {
         /// Allows to set a custom path when discovering the configuration file `biome.json`
         #[bpaf(env("BIOME_CONFIG_PATH"), long("config-path"), argument("PATH"))]
         config_path: Option<PathBuf>,
+        /// The size, in MiB, of the source code of the closed files whose syntax trees are kept
+        /// in memory. It counts the bytes of the content of the files, not the memory used by
+        /// the trees.
+        #[bpaf(env("BIOME_SOURCE_BUDGET"), long("source-budget"), argument("MiB"))]
+        source_budget: Option<usize>,
+        /// Records the durations of the requests and the hit rates of the caches, they're
+        /// printed by `biome __print_metrics`
+        #[bpaf(env("BIOME_METRICS"), long("metrics"), switch, hide_usage)]
+        metrics: bool,
     },
     #[bpaf(command("__print_socket"), hide)]
     PrintSocket,
+    /// Prints the metrics recorded by the running daemon
+    #[bpaf(command("__print_metrics"), hide)]
+    PrintMetrics,
+
+    /// Formats the files with Biome and with Prettier, and prints the ratio of lines that are
+    /// identical in both outputs, per file and per top-level construct. The files aren't written.
+    #[bpaf(command("__compare_prettier"), hide)]
+    ComparePrettier {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+        /// The Prettier executable. Defaults to the one installed in `node_modules`, or to the one
+        /// found in the `PATH`
+        #[bpaf(long("prettier-path"), argument("PATH"), optional)]
+        prettier_path: Option<PathBuf>,
+        /// Single file, single path or list of paths. Defaults to the working directory
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
 }
 
 impl BiomeCommand {
@@ -226,13 +467,20 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.colors.as_ref(),
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Explain { cli_options, .. }
+            | BiomeCommand::ComparePrettier { cli_options, .. }
+            | BiomeCommand::Migrate(cli_options, ..) => cli_options.colors.as_ref(),
             BiomeCommand::LspProxy(_)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Clean
+            | BiomeCommand::Init { .. }
+            | BiomeCommand::Completions { .. }
+            | BiomeCommand::HelpJson
             | BiomeCommand::RunServer { .. }
-            | BiomeCommand::PrintSocket => None,
+            | BiomeCommand::PrintSocket
+            | BiomeCommand::PrintMetrics => None,
         }
     }
 
@@ -244,13 +492,20 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.use_server,
-            BiomeCommand::Init
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Explain { cli_options, .. }
+            | BiomeCommand::ComparePrettier { cli_options, .. }
+            | BiomeCommand::Migrate(cli_options, ..) => cli_options.use_server,
+            BiomeCommand::Init { .. }
+            | BiomeCommand::Completions { .. }
+            | BiomeCommand::HelpJson
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
+            | BiomeCommand::Clean
             | BiomeCommand::LspProxy(_)
             | BiomeCommand::RunServer { .. }
-            | BiomeCommand::PrintSocket => false,
+            | BiomeCommand::PrintSocket
+            | BiomeCommand::PrintMetrics => false,
         }
     }
 
@@ -264,15 +519,22 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.verbose,
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Explain { cli_options, .. }
+            | BiomeCommand::ComparePrettier { cli_options, .. }
+            | BiomeCommand::Migrate(cli_options, ..) => cli_options.verbose,
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Clean
+            | BiomeCommand::Init { .. }
+            | BiomeCommand::Completions { .. }
+            | BiomeCommand::HelpJson
             | BiomeCommand::LspProxy(_)
             | BiomeCommand::RunServer { .. }
-            | BiomeCommand::PrintSocket => false,
+            | BiomeCommand::PrintSocket
+            | BiomeCommand::PrintMetrics => false,
         }
     }
 
@@ -282,15 +544,22 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.log_level.clone(),
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Explain { cli_options, .. }
+            | BiomeCommand::ComparePrettier { cli_options, .. }
+            | BiomeCommand::Migrate(cli_options, ..) => cli_options.log_level.clone(),
             BiomeCommand::Version(_)
             | BiomeCommand::LspProxy(_)
             | BiomeCommand::Rage(..)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Clean
+            | BiomeCommand::Init { .. }
+            | BiomeCommand::Completions { .. }
+            | BiomeCommand::HelpJson
             | BiomeCommand::RunServer { .. }
-            | BiomeCommand::PrintSocket => LoggingLevel::default(),
+            | BiomeCommand::PrintSocket
+            | BiomeCommand::PrintMetrics => LoggingLevel::default(),
         }
     }
     pub fn log_kind(&self) -> LoggingKind {
@@ -299,15 +568,22 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.log_kind.clone(),
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Explain { cli_options, .. }
+            | BiomeCommand::ComparePrettier { cli_options, .. }
+            | BiomeCommand::Migrate(cli_options, ..) => cli_options.log_kind.clone(),
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
             | BiomeCommand::LspProxy(_)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Clean
+            | BiomeCommand::Init { .. }
+            | BiomeCommand::Completions { .. }
+            | BiomeCommand::HelpJson
             | BiomeCommand::RunServer { .. }
-            | BiomeCommand::PrintSocket => LoggingKind::default(),
+            | BiomeCommand::PrintSocket
+            | BiomeCommand::PrintMetrics => LoggingKind::default(),
         }
     }
 }
@@ -2,18 +2,35 @@ use biome_console::fmt::{Display, Formatter};
 use biome_console::{fmt, markup, ConsoleExt, HorizontalLine, Markup};
 use biome_diagnostics::termcolor::{ColorChoice, WriteColor};
 use biome_diagnostics::{termcolor, PrintDescription};
-use biome_fs::FileSystem;
-use biome_service::workspace::{client, RageEntry, RageParams};
-use biome_service::{load_config, ConfigurationBasePath, DynRef, Workspace};
+use biome_fs::{FileSystem, RomePath};
+use biome_service::workspace::{
+    client, ExplainIgnoredPathParams, FeatureName, FeaturesBuilder, ProjectsParams, RageEntry,
+    RageParams, SupportKind, SupportsFeatureParams, UpdateSettingsParams,
+};
+use biome_service::{load_config, ConfigurationBasePath, DynRef, Rules, Workspace};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 use std::{env, io, ops::Deref};
 use tokio::runtime::Runtime;
 
+use crate::cli_options::CliOptions;
 use crate::commands::daemon::read_most_recent_log_file;
+use crate::commands::explain::{resolve_path_configuration, PathConfiguration};
+use crate::configuration::load_configuration;
 use crate::service::enumerate_pipes;
+use crate::vcs::read_vcs_ignore_files;
 use crate::{service, CliDiagnostic, CliSession, VERSION};
 
+/// The number of lines printed by the excerpt of the server logs
+const LOG_EXCERPT_LINES: usize = 20;
+
 /// Handler for the `rage` command
-pub(crate) fn rage(session: CliSession, daemon_logs: bool) -> Result<(), CliDiagnostic> {
+pub(crate) fn rage(
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    daemon_logs: bool,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
     let terminal_supports_colors = termcolor::BufferWriter::stdout(ColorChoice::Auto)
         .buffer()
         .supports_color();
@@ -36,8 +53,53 @@ pub(crate) fn rage(session: CliSession, daemon_logs: bool) -> Result<(), CliDiag
 
     {RageConfiguration(&session.app.fs)}
     {WorkspaceRage(session.app.workspace.deref())}
+    {ServerProjects(session.app.workspace.deref())}
     ));
 
+    if !paths.is_empty() {
+        let loaded_configuration = load_configuration(&mut session, cli_options)?.with_file_path();
+        let vcs_base_path = loaded_configuration
+            .directory_path
+            .clone()
+            .or(session.app.fs.working_directory());
+        let (vcs_ignore_files, nested_vcs_ignore_files) = read_vcs_ignore_files(
+            &mut session,
+            &loaded_configuration.configuration,
+            vcs_base_path,
+            cli_options,
+        )?;
+        session
+            .app
+            .workspace
+            .update_settings(UpdateSettingsParams {
+                configuration: loaded_configuration.configuration.clone(),
+                vcs_ignore_files,
+                tsconfig_files: vec![],
+                package_json_files: vec![],
+            })?;
+
+        // The nested configuration files and the ignore files of the directories of a path apply
+        // to it, like when its directories are traversed
+        let nested_configurations = loaded_configuration
+            .nested_configurations(&session.app.fs)
+            .with_vcs_ignore_files(nested_vcs_ignore_files);
+        let mut path_configurations = vec![];
+        for path in paths.into_iter().map(PathBuf::from) {
+            nested_configurations.discover(
+                &*session.app.fs,
+                &*session.app.workspace,
+                path.parent().unwrap_or(Path::new("")),
+            )?;
+            let path_configuration =
+                resolve_path_configuration(&mut session, &loaded_configuration, &path)?;
+            path_configurations.push((path, path_configuration));
+        }
+
+        session.app.console.log(markup!({
+            RagePaths(session.app.workspace.deref(), &path_configurations)
+        }));
+    }
+
     if daemon_logs {
         match session.app.workspace.server_info() {
             Some(_) => {
@@ -53,10 +115,107 @@ pub(crate) fn rage(session: CliSession, daemon_logs: bool) -> Result<(), CliDiag
                 session.app.console.log(markup!({ RunningRomeServer }));
             }
         }
+    } else if let Some(excerpt) = server_log_excerpt() {
+        session.app.console.log(markup!(
+            {Section("Recent Biome Server Log Errors")}
+            {excerpt}"\n"
+            <Dim>"Use the option --daemon-logs to print the whole log file."</Dim>
+        ));
     }
     Ok(())
 }
 
+/// Prints which features are enabled for each path, after applying the `ignore`, `include`
+/// and `overrides` settings of the configuration, and the settings that apply to the path
+struct RagePaths<'a>(&'a dyn Workspace, &'a [(PathBuf, PathConfiguration)]);
+
+impl Display for RagePaths<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let RagePaths(workspace, paths) = self;
+
+        for (path, path_configuration) in paths.iter() {
+            Section(&format!("Path {}", path.display())).fmt(fmt)?;
+
+            let file_features = workspace.file_features(SupportsFeatureParams {
+                path: RomePath::new(path),
+                feature: FeaturesBuilder::new()
+                    .with_formatter()
+                    .with_linter()
+                    .with_organize_imports()
+                    .build(),
+            });
+
+            let file_features = match file_features {
+                Ok(file_features) => file_features,
+                Err(err) => {
+                    KeyValuePair("Error", markup!({ format!("{err}") })).fmt(fmt)?;
+                    continue;
+                }
+            };
+
+            for (name, feature) in [
+                ("Formatter", FeatureName::Format),
+                ("Linter", FeatureName::Lint),
+                ("Organize imports", FeatureName::OrganizeImports),
+            ] {
+                let reason;
+                let status = match file_features.support_kind_for(&feature) {
+                    Some(SupportKind::Supported) => markup!("enabled"),
+                    Some(SupportKind::Ignored) => {
+                        reason = workspace
+                            .explain_ignored_path(ExplainIgnoredPathParams {
+                                path: RomePath::new(path),
+                                feature,
+                            })
+                            .ok()
+                            .and_then(|result| result.reason)
+                            .map_or("ignored".to_string(), |reason| reason.to_string());
+                        markup!(<Dim>{reason}</Dim>)
+                    }
+                    Some(SupportKind::FeatureNotEnabled) => markup!(<Dim>"disabled"</Dim>),
+                    Some(SupportKind::FileNotSupported) | None => {
+                        markup!(<Dim>"not supported"</Dim>)
+                    }
+                };
+                KeyValuePair(name, status).fmt(fmt)?;
+            }
+
+            let configuration_files = path_configuration.configuration_files.join(", ");
+            if configuration_files.is_empty() {
+                KeyValuePair("Configuration files", markup!(<Dim>"none"</Dim>)).fmt(fmt)?;
+            } else {
+                KeyValuePair("Configuration files", markup!({ configuration_files })).fmt(fmt)?;
+            }
+
+            // The settings of the root configuration merged with the nested configuration files
+            // and the overrides that match the path
+            let settings = serde_json::to_string_pretty(&path_configuration.configuration)
+                .map_err(io::Error::from)?;
+            fmt.write_str("  Settings:\n")?;
+            for line in settings.lines() {
+                writeln!(fmt, "    {line}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the last lines of the most recent server log that report an error or a warning
+fn server_log_excerpt() -> Option<String> {
+    let log = read_most_recent_log_file().ok()??;
+    let lines: Vec<_> = log
+        .lines()
+        .filter(|line| line.contains(" ERROR ") || line.contains(" WARN "))
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+
+    let start = lines.len().saturating_sub(LOG_EXCERPT_LINES);
+    Some(lines[start..].join("\n"))
+}
+
 struct WorkspaceRage<'a>(&'a dyn Workspace);
 
 impl Display for WorkspaceRage<'_> {
@@ -91,6 +250,58 @@ impl Display for WorkspaceRage<'_> {
     }
 }
 
+/// Prints the projects of the other clients of the server the workspace is connected to,
+/// e.g. the editor windows using the daemon
+struct ServerProjects<'a>(&'a dyn Workspace);
+
+impl Display for ServerProjects<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let workspace = self.0;
+        if workspace.server_info().is_none() {
+            return Ok(());
+        }
+
+        let projects = match workspace.projects(ProjectsParams {}) {
+            Ok(result) => result.projects,
+            Err(err) => {
+                writeln!(fmt)?;
+                (markup! {<Error>"\u{2716} Listing the server projects failed:"</Error>})
+                    .fmt(fmt)?;
+                return writeln!(fmt, " {err}");
+            }
+        };
+
+        Section("Server Projects").fmt(fmt)?;
+        if projects.is_empty() {
+            return KeyValuePair("Projects", markup!(<Dim>"none"</Dim>)).fmt(fmt);
+        }
+
+        for project in projects {
+            match &project.root {
+                Some(root) => {
+                    KeyValuePair("Root", markup!({ root.display().to_string() })).fmt(fmt)?
+                }
+                None => KeyValuePair("Root", markup!(<Dim>"unset"</Dim>)).fmt(fmt)?,
+            }
+
+            for session in project.sessions {
+                let client = match (&session.client_name, &session.client_version) {
+                    (Some(name), Some(version)) => format!("{name} {version}"),
+                    (Some(name), None) => name.clone(),
+                    (None, _) => String::from("unknown client"),
+                };
+                KeyValuePair(
+                    &format!("Session {}", session.key),
+                    markup!({ format!("{client}, {} open documents", session.open_documents) }),
+                )
+                .fmt(fmt)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Prints information about other running biome server instances.
 struct RunningRomeServer;
 
@@ -124,7 +335,10 @@ impl Display for RunningRomeServer {
                 .fmt(f)?;
 
                         match client(transport) {
-                            Ok(client) => WorkspaceRage(client.deref()).fmt(f)?,
+                            Ok(client) => {
+                                WorkspaceRage(client.deref()).fmt(f)?;
+                                ServerProjects(client.deref()).fmt(f)?;
+                            }
                             Err(err) => {
                                 markup!(<Error>"\u{2716} Failed to connect: "</Error>).fmt(f)?;
                                 writeln!(f, "{err}")?;
@@ -195,7 +409,14 @@ impl Display for RageConfiguration<'_, '_> {
                     {KeyValuePair("Linter disabled", markup!({DebugDisplay(configuration.is_linter_disabled())}))}
                     {KeyValuePair("Organize imports disabled", markup!({DebugDisplay(configuration.is_organize_imports_disabled())}))}
                     {KeyValuePair("VCS disabled", markup!({DebugDisplay(configuration.is_vcs_disabled())}))}
-                ).fmt(fmt)?
+                ).fmt(fmt)?;
+
+                let rules = configuration
+                    .linter
+                    .as_ref()
+                    .and_then(|linter| linter.rules.clone())
+                    .unwrap_or_default();
+                RulesDiff(&rules).fmt(fmt)?;
             }
             Err(err) => markup! (
                 {KeyValuePair("Status", markup!(<Error>"Failed to load"</Error>))}
@@ -208,6 +429,39 @@ impl Display for RageConfiguration<'_, '_> {
     }
 }
 
+/// Prints the rules that are enabled or disabled compared to the default configuration,
+/// where only the recommended rules are enabled
+struct RulesDiff<'a>(&'a Rules);
+
+impl Display for RulesDiff<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let enabled_rules = self.0.as_enabled_rules();
+        let default_rules = Rules::default().as_enabled_rules();
+
+        let added = enabled_rules
+            .difference(&default_rules)
+            .map(|rule| rule.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let removed = default_rules
+            .difference(&enabled_rules)
+            .map(|rule| rule.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        KeyValuePair("Enabled rules", rule_list(&added)).fmt(fmt)?;
+        KeyValuePair("Disabled recommended rules", rule_list(&removed)).fmt(fmt)
+    }
+}
+
+fn rule_list(rules: &str) -> Markup {
+    if rules.is_empty() {
+        markup!(<Dim>"none"</Dim>)
+    } else {
+        markup!({ rules })
+    }
+}
+
 struct DebugDisplay<T>(T);
 
 impl<T> Display for DebugDisplay<T>
@@ -0,0 +1,70 @@
+use crate::cli_options::CliOptions;
+use crate::configuration::{load_configuration, LoadedConfiguration};
+use crate::vcs::read_vcs_ignore_files;
+use crate::{
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+};
+use biome_service::configuration::vcs::VcsConfiguration;
+use biome_service::configuration::FilesConfiguration;
+use biome_service::workspace::UpdateSettingsParams;
+use biome_service::MergeWith;
+use std::ffi::OsString;
+
+pub(crate) struct SearchCommandPayload {
+    pub(crate) vcs_configuration: Option<VcsConfiguration>,
+    pub(crate) files_configuration: Option<FilesConfiguration>,
+    pub(crate) cli_options: CliOptions,
+    pub(crate) pattern: String,
+    pub(crate) paths: Vec<OsString>,
+}
+
+/// Handler for the "search" command of the Biome CLI
+pub(crate) fn search(
+    mut session: CliSession,
+    payload: SearchCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    let SearchCommandPayload {
+        vcs_configuration,
+        files_configuration,
+        cli_options,
+        pattern,
+        paths,
+    } = payload;
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
+
+    loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+    let nested_configurations = loaded_configuration.nested_configurations(&session.app.fs);
+
+    let LoadedConfiguration {
+        configuration: mut fs_configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
+    fs_configuration.merge_with(files_configuration);
+    fs_configuration.merge_with(vcs_configuration);
+
+    // check if support of git ignore files is enabled
+    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    let (vcs_ignore_files, nested_vcs_ignore_files) =
+        read_vcs_ignore_files(&mut session, &fs_configuration, vcs_base_path, &cli_options)?;
+
+    let execution = Execution::new(TraversalMode::Search { pattern })
+        .with_configuration(&fs_configuration)
+        .with_nested_configurations(
+            nested_configurations.with_vcs_ignore_files(nested_vcs_ignore_files),
+        );
+
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: fs_configuration,
+            vcs_ignore_files,
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })?;
+
+    execute_mode(execution, session, &cli_options, paths)
+}
@@ -2,16 +2,20 @@ use crate::cli_options::CliOptions;
 use crate::diagnostics::DeprecatedConfigurationFile;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, Console, ConsoleExt};
-use biome_deserialize::json::deserialize_from_json_str;
-use biome_deserialize::Deserialized;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic, Severity};
-use biome_fs::{FileSystem, OpenOptions};
-use biome_json_parser::JsonParserOptions;
-use biome_service::configuration::diagnostics::CantLoadExtendFile;
-use biome_service::configuration::ConfigurationPayload;
+use biome_fs::FileSystem;
+use biome_service::configuration::editorconfig::{configured_formatter_options, EditorConfigs};
+use biome_service::configuration::extends::{
+    load_extended_configurations, merge_extended_configurations, ExtendedConfiguration,
+};
+use biome_service::configuration::json::JsonFormatter;
+use biome_service::configuration::nested::NestedConfigurations;
+use biome_service::configuration::{
+    ConfigurationPayload, FormatterConfiguration, OverrideFormatterConfiguration,
+};
 use biome_service::{
-    load_config, Configuration, ConfigurationBasePath, ConfigurationDiagnostic, DynRef, MergeWith,
-    WorkspaceError,
+    load_config, Configuration, ConfigurationBasePath, ConfigurationDiagnostic, DynRef,
+    JavascriptFormatter, MergeWith, WorkspaceError,
 };
 use std::path::PathBuf;
 
@@ -21,6 +25,11 @@ pub struct LoadedConfiguration {
     pub(crate) file_path: Option<PathBuf>,
     pub(crate) configuration: Configuration,
     pub(crate) diagnostics: Vec<Error>,
+    /// The configuration files that `file_path` extends
+    pub(crate) extended_file_paths: Vec<PathBuf>,
+    /// The options of the top level formatter that the configuration files, the profile and
+    /// the arguments set, see [configured_formatter_options]
+    pub(crate) formatter_options: OverrideFormatterConfiguration,
 }
 
 impl LoadedConfiguration {
@@ -30,41 +39,126 @@ impl LoadedConfiguration {
     ///
     /// If a configuration can't be resolved from the file system, the operation will fail.
     pub fn apply_extends(mut self, fs: &DynRef<dyn FileSystem>) -> Result<Self, WorkspaceError> {
-        let deserialized = self.deserialize_extends(fs)?;
-        let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
+        let extended_configurations = self.load_extends(fs)?;
+        let mut extended_file_paths = vec![];
+        let (configurations, errors): (Vec<_>, Vec<_>) = extended_configurations
             .into_iter()
-            .map(|d| d.consume())
+            .map(|extended_configuration| {
+                extended_file_paths.push(extended_configuration.file_path);
+                extended_configuration.deserialized.consume()
+            })
             .map(|(config, diagnostics)| (config.unwrap_or_default(), diagnostics))
             .unzip();
 
-        let extended_configuration = configurations.into_iter().reduce(
-            |mut previous_configuration, current_configuration| {
-                previous_configuration.merge_with(current_configuration);
-                previous_configuration
-            },
-        );
-        let configuration = if let Some(mut extended_configuration) = extended_configuration {
-            // Here we want to keep only the values that aren't a default
-            extended_configuration.merge_with_if_not_default(self.configuration);
-            extended_configuration
-        } else {
-            self.configuration
-        };
+        let configuration = merge_extended_configurations(self.configuration, configurations);
         self.diagnostics
             .extend(errors.into_iter().flatten().collect::<Vec<_>>());
+        let configuration_files: Vec<_> = extended_file_paths
+            .iter()
+            .chain(self.file_path.iter())
+            .cloned()
+            .collect();
+        let formatter_options = configured_formatter_options(&**fs, &configuration_files);
 
         Ok(Self {
             configuration,
             diagnostics: self.diagnostics,
             file_path: self.file_path,
             directory_path: self.directory_path,
+            extended_file_paths,
+            formatter_options,
         })
     }
 
-    fn deserialize_extends(
+    /// Merges the profile `name` of the configuration over the configuration. The profiles
+    /// are removed from the configuration once applied.
+    ///
+    /// The operation fails if the configuration doesn't have a profile called `name`.
+    pub fn apply_profile(mut self, name: Option<&str>) -> Result<Self, WorkspaceError> {
+        let profiles = self.configuration.profiles.take().unwrap_or_default();
+        let Some(name) = name else {
+            return Ok(self);
+        };
+        let Some(profile) = profiles.get(name) else {
+            let names: Vec<_> = profiles.names().collect();
+            let message = if names.is_empty() {
+                format!(
+                    "The profile {name} doesn't exist, the configuration doesn't have profiles."
+                )
+            } else {
+                format!(
+                    "The profile {name} doesn't exist, the profiles of the configuration are: {}.",
+                    names.join(", ")
+                )
+            };
+            return Err(WorkspaceError::Configuration(
+                ConfigurationDiagnostic::invalid_configuration(message),
+            ));
+        };
+        if let Some(formatter) = &profile.formatter {
+            self.formatter_options.merge_with(formatter.clone());
+        }
+        profile.clone().apply_to(&mut self.configuration);
+
+        Ok(self)
+    }
+
+    /// Merges the options of the formatter passed as arguments over the configuration. Like the
+    /// options of the configuration files, they take precedence over the `.editorconfig` files.
+    pub fn apply_formatter_arguments(
+        &mut self,
+        formatter: Option<FormatterConfiguration>,
+        javascript: Option<JavascriptFormatter>,
+        json: Option<JsonFormatter>,
+    ) {
+        if let Some(formatter) = &formatter {
+            self.formatter_options
+                .merge_with(OverrideFormatterConfiguration {
+                    indent_style: formatter.indent_style.clone(),
+                    indent_size: formatter.indent_size,
+                    indent_width: formatter.indent_width,
+                    line_ending: formatter.line_ending,
+                    line_width: formatter.line_width,
+                    ..OverrideFormatterConfiguration::default()
+                });
+        }
+        self.configuration.merge_with(javascript);
+        self.configuration.merge_with(json);
+        self.configuration.merge_with(formatter);
+    }
+
+    /// Returns the configuration files found in the subdirectories of the project, e.g. the
+    /// `biome.json` of a package of a monorepo, and the `.editorconfig` files when
+    /// `formatter.useEditorconfig` is enabled. They're looked up while the files are handled.
+    pub fn nested_configurations(&self, fs: &DynRef<dyn FileSystem>) -> NestedConfigurations {
+        let working_directory = fs.working_directory();
+        let root_directory = self
+            .directory_path
+            .clone()
+            .or_else(|| working_directory.clone())
+            .unwrap_or_default();
+        let nested_configurations =
+            NestedConfigurations::new(root_directory, working_directory.clone());
+        let use_editorconfig = self
+            .configuration
+            .formatter
+            .as_ref()
+            .is_some_and(|formatter| formatter.use_editorconfig());
+        if use_editorconfig {
+            nested_configurations.with_editorconfigs(EditorConfigs::new(
+                working_directory,
+                self.formatter_options.clone(),
+                &self.configuration,
+            ))
+        } else {
+            nested_configurations
+        }
+    }
+
+    fn load_extends(
         &mut self,
         fs: &DynRef<dyn FileSystem>,
-    ) -> Result<Vec<Deserialized<Configuration>>, WorkspaceError> {
+    ) -> Result<Vec<ExtendedConfiguration>, WorkspaceError> {
         let Some(extends) = &self.configuration.extends else {
             return Ok(vec![]);
         };
@@ -74,34 +168,7 @@ impl LoadedConfiguration {
             .as_ref()
             .cloned()
             .unwrap_or(fs.working_directory().unwrap_or(PathBuf::from("./")));
-        let mut deserialized_configurations = vec![];
-        for path in extends.iter() {
-            let config_path = directory_path.join(path);
-            let mut file = fs
-					.open_with_options(config_path.as_path(), OpenOptions::default().read(true))
-					.map_err(|err| {
-						CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
-							markup!{
-								"Biome tried to load the configuration file "<Emphasis>{directory_path.display().to_string()}</Emphasis>" using "<Emphasis>{config_path.display().to_string()}</Emphasis>" as base path."
-							}
-						)
-					})?;
-            let mut content = String::new();
-            file.read_to_string(&mut content).map_err(|err| {
-					CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
-						markup!{
-							"It's possible that the file was created with a different user/group. Make sure you have the rights to read the file."
-						}
-					)
-
-				})?;
-            let deserialized = deserialize_from_json_str::<Configuration>(
-                content.as_str(),
-                JsonParserOptions::default(),
-            );
-            deserialized_configurations.push(deserialized)
-        }
-        Ok(deserialized_configurations)
+        load_extended_configurations(fs, &directory_path, extends)
     }
 
     /// It re
@@ -111,7 +178,11 @@ impl LoadedConfiguration {
             .diagnostics
             .into_iter()
             .map(|diagnostic| {
-                if let Some(file_path) = &self.file_path {
+                // The diagnostics of the other files, e.g. the files of `extends`, already have
+                // their path
+                if diagnostic.location().resource.is_some() {
+                    diagnostic
+                } else if let Some(file_path) = &self.file_path {
                     diagnostic.with_file_path(file_path.display().to_string())
                 } else {
                     diagnostic
@@ -179,6 +250,8 @@ impl From<Option<ConfigurationPayload>> for LoadedConfiguration {
                 diagnostics,
                 directory_path: Some(configuration_directory_path),
                 file_path: Some(configuration_file_path),
+                extended_file_paths: vec![],
+                formatter_options: OverrideFormatterConfiguration::default(),
             }
         } else {
             LoadedConfiguration::default()
@@ -200,5 +273,7 @@ pub(crate) fn load_configuration(
     let fs = &session.app.fs;
     let config = load_config(fs, base_path)?;
     let loaded_configuration = LoadedConfiguration::from(config);
-    Ok(loaded_configuration.apply_extends(fs)?)
+    Ok(loaded_configuration
+        .apply_extends(fs)?
+        .apply_profile(cli_options.profile.as_deref())?)
 }
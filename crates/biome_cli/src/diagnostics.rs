@@ -1,14 +1,23 @@
 use biome_console::fmt::{Display, Formatter};
 use biome_console::markup;
+use biome_console::MarkupBuf;
 use biome_diagnostics::adapters::{BpafError, IoError};
 use biome_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticTags, Error, Location, LogCategory,
+    Advices, Category, Diagnostic, DiagnosticTags, Error, Location, LogAdvice, LogCategory,
     MessageAndDescription, Severity, Visit,
 };
 use biome_service::WorkspaceError;
 use std::process::{ExitCode, Termination};
 use std::{env::current_exe, fmt::Debug};
 
+/// Exit code used when the command emitted error diagnostics, or when it was called with invalid arguments
+const EXIT_CODE_ERRORS: u8 = 1;
+/// Exit code used when the command emitted only warnings, and `--error-on-warnings` was passed
+const EXIT_CODE_WARNINGS: u8 = 2;
+/// Exit code used when Biome couldn't complete the command because of an internal failure,
+/// e.g. a file system error or a broken connection with the daemon
+const EXIT_CODE_INTERNAL_FAILURE: u8 = 3;
+
 fn command_name() -> String {
     current_exe()
         .ok()
@@ -57,6 +66,8 @@ pub enum CliDiagnostic {
     MigrateError(MigrationDiagnostic),
     /// When the VCS folder couldn't be found
     NoVcsFolderFound(NoVcsFolderFound),
+    /// When the baseline file passed with `--baseline` couldn't be read
+    BaselineNotFound(BaselineNotFound),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -160,6 +171,9 @@ pub struct CheckError {
 
     #[message]
     message: MessageAndDescription,
+
+    /// Whether the command emitted only warnings
+    only_warnings: bool,
 }
 
 #[derive(Debug, Diagnostic)]
@@ -263,6 +277,23 @@ pub struct NoVcsFolderFound {
     pub source: Option<Error>,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "Biome couldn't read the baseline file {path}",
+        message("Biome couldn't read the baseline file "<Emphasis>{self.path}</Emphasis>),
+    )
+)]
+pub struct BaselineNotFound {
+    #[location(resource)]
+    pub path: String,
+
+    #[advice]
+    pub advice: LogAdvice<MarkupBuf>,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
@@ -414,6 +445,7 @@ impl CliDiagnostic {
                 }
                 .to_owned(),
             ),
+            only_warnings: false,
         })
     }
 
@@ -427,6 +459,7 @@ impl CliDiagnostic {
                 }
                 .to_owned(),
             ),
+            only_warnings: true,
         })
     }
 
@@ -440,6 +473,7 @@ impl CliDiagnostic {
                 }
                 .to_owned(),
             ),
+            only_warnings: false,
         })
     }
     /// Emitted when warnings were emitted while apply code fixes
@@ -452,6 +486,7 @@ impl CliDiagnostic {
                 }
                 .to_owned(),
             ),
+            only_warnings: true,
         })
     }
 
@@ -509,6 +544,20 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when the baseline file passed with `--baseline` can't be read
+    pub fn baseline_not_found(path: impl Into<String>) -> Self {
+        Self::BaselineNotFound(BaselineNotFound {
+            path: path.into(),
+            advice: LogAdvice {
+                category: LogCategory::Info,
+                text: markup! {
+                    "Pass "<Emphasis>"--update-baseline"</Emphasis>" to create the baseline with the diagnostics of the current run."
+                }
+                .to_owned(),
+            },
+        })
+    }
+
     /// Return by the help command when it is called with a subcommand it doesn't know
     pub fn new_unknown_help(command: impl Into<String>) -> Self {
         Self::UnknownCommandHelp(UnknownCommandHelp {
@@ -537,6 +586,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.category(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.category(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.category(),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.category(),
         }
     }
 
@@ -559,6 +609,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.tags(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.tags(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.tags(),
         }
     }
 
@@ -581,6 +632,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.severity(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.severity(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.severity(),
         }
     }
 
@@ -603,6 +655,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.location(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.location(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.location(),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.location(),
         }
     }
 
@@ -625,6 +678,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.message(fmt),
         }
     }
 
@@ -647,6 +701,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.description(fmt),
         }
     }
 
@@ -669,6 +724,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.advices(visitor),
         }
     }
 
@@ -695,6 +751,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.verbose_advices(visitor),
         }
     }
 
@@ -717,6 +774,7 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.source(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.source(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.source(),
+            CliDiagnostic::BaselineNotFound(diagnostic) => diagnostic.source(),
         }
     }
 }
@@ -733,14 +791,35 @@ impl From<std::io::Error> for CliDiagnostic {
     }
 }
 
+impl CliDiagnostic {
+    /// The exit code of the process when a command fails with this diagnostic:
+    /// - `1` when the command emitted errors, or when it was called with invalid arguments;
+    /// - `2` when the command emitted only warnings, and `--error-on-warnings` was passed;
+    /// - `3` when Biome failed internally.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CliDiagnostic::CheckError(CheckError {
+                only_warnings: true,
+                ..
+            }) => EXIT_CODE_WARNINGS,
+            CliDiagnostic::IoError(_) | CliDiagnostic::ServerNotRunning(_) => {
+                EXIT_CODE_INTERNAL_FAILURE
+            }
+            // An invalid configuration is a mistake of the user, not a failure of Biome
+            CliDiagnostic::WorkspaceError(error)
+                if !matches!(error, WorkspaceError::Configuration(_)) =>
+            {
+                EXIT_CODE_INTERNAL_FAILURE
+            }
+            _ if self.severity() >= Severity::Error => EXIT_CODE_ERRORS,
+            _ => 0,
+        }
+    }
+}
+
 impl Termination for CliDiagnostic {
     fn report(self) -> ExitCode {
-        let severity = self.severity();
-        if severity >= Severity::Error {
-            ExitCode::FAILURE
-        } else {
-            ExitCode::SUCCESS
-        }
+        ExitCode::from(self.exit_code())
     }
 }
 
@@ -0,0 +1,201 @@
+use crate::CliDiagnostic;
+use biome_diagnostics::Error;
+use biome_fs::{FileSystem, FileSystemExt, OpenOptions};
+use biome_service::stable_hash::StableHasher;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// The version of the format of the baseline file
+const BASELINE_VERSION: u32 = 1;
+
+/// For each file, the number of diagnostics recorded for each category and context hash
+type BaselineEntries = BTreeMap<String, BTreeMap<String, BTreeMap<String, usize>>>;
+
+/// Diagnostics recorded by a previous run, passed with `--baseline`. They are suppressed from
+/// the output, so only the new diagnostics are reported.
+///
+/// A diagnostic is identified by its file, its category and a hash of the code it points at,
+/// so the diagnostics of a file are still matched when lines are added or removed above them.
+/// The files are identified by their path relative to the root of the project, so the baseline
+/// can be shared by the machines and the directories the command runs from.
+#[derive(Debug)]
+pub(crate) struct Baseline {
+    /// Where the baseline is stored
+    location: PathBuf,
+    /// The root of the project, the paths of the files are relative to it
+    root_directory: PathBuf,
+    /// The directory the relative paths of the files are resolved from
+    working_directory: Option<PathBuf>,
+    /// When `true`, the diagnostics of the current run are recorded instead of being reported
+    update: bool,
+    /// The recorded diagnostics that weren't matched yet by the current run
+    remaining: Mutex<BaselineEntries>,
+    /// The diagnostics emitted during the current run, stored when the baseline is updated
+    current: Mutex<BaselineEntries>,
+    /// The number of diagnostics suppressed during the current run
+    suppressed: AtomicUsize,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct BaselineFile {
+    version: u32,
+    files: BaselineEntries,
+}
+
+impl Baseline {
+    /// Loads the baseline stored at `location`. When `update` is `true`, the content of the
+    /// file is ignored, and the file is written again once the traversal is done.
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        location: PathBuf,
+        root_directory: PathBuf,
+        update: bool,
+    ) -> Result<Self, CliDiagnostic> {
+        let remaining = if update {
+            BaselineEntries::default()
+        } else {
+            fs.read_to_string(&location)
+                .ok()
+                .and_then(|content| serde_json::from_str::<BaselineFile>(&content).ok())
+                .filter(|baseline_file| baseline_file.version == BASELINE_VERSION)
+                .map(|baseline_file| baseline_file.files)
+                .ok_or_else(|| CliDiagnostic::baseline_not_found(location.display().to_string()))?
+        };
+
+        Ok(Self {
+            location,
+            root_directory,
+            working_directory: fs.working_directory(),
+            update,
+            remaining: Mutex::new(remaining),
+            current: Mutex::default(),
+            suppressed: AtomicUsize::new(0),
+        })
+    }
+
+    /// Removes the diagnostics of the file `path` that are recorded in the baseline.
+    /// When the baseline is updated, all the diagnostics are recorded and removed.
+    pub(crate) fn filter(&self, path: &Path, content: &str, diagnostics: Vec<Error>) -> Vec<Error> {
+        let path = self.relative_path(path);
+        let path = path.as_str();
+        let mut remaining = self.remaining.lock().unwrap();
+        let mut current = self.current.lock().unwrap();
+        let total = diagnostics.len();
+
+        let diagnostics: Vec<_> = diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let category = diagnostic
+                    .category()
+                    .map_or("biome", |category| category.name());
+                let context = hash_context(diagnostic, content);
+
+                if self.update {
+                    *current
+                        .entry(path.to_string())
+                        .or_default()
+                        .entry(category.to_string())
+                        .or_default()
+                        .entry(context)
+                        .or_default() += 1;
+                    return false;
+                }
+
+                let count = remaining
+                    .get_mut(path)
+                    .and_then(|categories| categories.get_mut(category))
+                    .and_then(|contexts| contexts.get_mut(&context));
+                match count {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                }
+            })
+            .collect();
+
+        self.suppressed
+            .fetch_add(total - diagnostics.len(), Ordering::Relaxed);
+        diagnostics
+    }
+
+    /// The number of diagnostics suppressed during the current run
+    pub(crate) fn suppressed(&self) -> usize {
+        self.suppressed.load(Ordering::Relaxed)
+    }
+
+    /// Whether the diagnostics of the current run are recorded in the baseline
+    pub(crate) fn is_update(&self) -> bool {
+        self.update
+    }
+
+    /// Where the baseline is stored
+    pub(crate) fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// The path of a file relative to the root of the project, with `/` separators. The paths
+    /// outside of the project are kept as they are.
+    fn relative_path(&self, path: &Path) -> String {
+        let resolved = match &self.working_directory {
+            Some(working_directory) if path.is_relative() => working_directory.join(path),
+            _ => path.to_path_buf(),
+        };
+        match resolved.strip_prefix(&self.root_directory) {
+            Ok(relative) => relative
+                .components()
+                .filter(|component| *component != Component::CurDir)
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            Err(_) => resolved.display().to_string(),
+        }
+    }
+
+    /// Writes the diagnostics of the current run to disk, when the baseline is updated
+    pub(crate) fn save(&self, fs: &dyn FileSystem) -> std::io::Result<()> {
+        if !self.update {
+            return Ok(());
+        }
+
+        let baseline_file = BaselineFile {
+            version: BASELINE_VERSION,
+            files: self.current.lock().unwrap().clone(),
+        };
+        let content = serde_json::to_string_pretty(&baseline_file)?;
+
+        let options = OpenOptions::default()
+            .write(true)
+            .create(true)
+            .truncate(true);
+        let mut file = fs.open_with_options(&self.location, options)?;
+        file.set_content(content.as_bytes())
+    }
+}
+
+/// Hashes the trimmed lines of code that the diagnostic points at. The hash doesn't depend on
+/// the position of the code in the file.
+///
+/// The hash is stored in the baseline, so it uses a [StableHasher] over the bytes of the lines.
+fn hash_context(diagnostic: &Error, content: &str) -> String {
+    let mut hasher = StableHasher::new();
+
+    if let Some(span) = diagnostic.location().span {
+        let (start, end) = (usize::from(span.start()), usize::from(span.end()));
+        if let (Some(before), Some(after)) = (content.get(..start), content.get(end..)) {
+            let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+            let line_end = after.find('\n').map_or(content.len(), |index| end + index);
+
+            for line in content[line_start..line_end].lines() {
+                hasher.write(line.trim().as_bytes());
+                hasher.write(b"\n");
+            }
+        }
+    }
+
+    hasher.finish_hex()
+}
@@ -0,0 +1,172 @@
+use crate::execute::TraversalMode;
+use crate::VERSION;
+use biome_fs::{FileSystem, FileSystemExt, OpenOptions};
+use biome_service::stable_hash::StableHasher;
+use biome_service::Configuration;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The default location of the cache file, relative to the working directory
+pub(crate) const DEFAULT_CACHE_LOCATION: &str = ".biomecache";
+
+/// Persistent cache of the files that didn't emit any diagnostic during a previous run.
+///
+/// An entry maps the path of a file to the hash of its content and of the nested configuration
+/// files that apply to it. When they don't change between two runs, the file is skipped.
+///
+/// The whole cache is discarded when its fingerprint changes. The fingerprint is computed
+/// from the version of Biome, the command that is running and the configuration, so changing
+/// a rule or an option invalidates the cache.
+#[derive(Debug)]
+pub(crate) struct ResultsCache {
+    /// Where the cache is stored
+    location: PathBuf,
+    /// Fingerprint of the command and configuration used for the current run
+    fingerprint: String,
+    /// The entries loaded from the disk
+    previous: BTreeMap<String, String>,
+    /// The entries computed during the current run
+    current: Mutex<FxHashMap<String, String>>,
+    /// Files that emitted diagnostics during the current run, they can't be cached
+    dirty: Mutex<FxHashSet<String>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheFile {
+    fingerprint: String,
+    files: BTreeMap<String, String>,
+}
+
+impl ResultsCache {
+    /// Loads the cache stored at `location`. The entries are discarded if the cache was created
+    /// with a different fingerprint, or if it can't be read.
+    ///
+    /// `configuration_fingerprint` must be computed using [configuration_fingerprint].
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        location: PathBuf,
+        configuration_fingerprint: &str,
+        traversal_mode: &TraversalMode,
+    ) -> Self {
+        let mut hasher = StableHasher::new();
+        hasher.write_str(configuration_fingerprint);
+        // The debug representation contains the arguments that change the output of the command
+        hasher.write_str(&format!("{traversal_mode:?}"));
+        let fingerprint = hasher.finish_hex();
+
+        let previous = fs
+            .read_to_string(&location)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache_file| cache_file.fingerprint == fingerprint)
+            .map(|cache_file| cache_file.files)
+            .unwrap_or_default();
+
+        Self {
+            location,
+            fingerprint,
+            previous,
+            current: Mutex::default(),
+            dirty: Mutex::default(),
+        }
+    }
+
+    /// Where the cache is stored
+    pub(crate) fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Returns `true` if the file didn't change since the last time it was processed without
+    /// emitting diagnostics. `nested_fingerprint` is the fingerprint of the nested configuration
+    /// files that apply to the file.
+    pub(crate) fn is_fresh(
+        &self,
+        fs: &dyn FileSystem,
+        path: &Path,
+        nested_fingerprint: u64,
+    ) -> bool {
+        let key = path.display().to_string();
+        let Some(previous_hash) = self.previous.get(&key) else {
+            return false;
+        };
+        let Ok(content) = fs.read_to_string(path) else {
+            return false;
+        };
+
+        if *previous_hash == hash_content(&content, nested_fingerprint) {
+            self.current
+                .lock()
+                .unwrap()
+                .insert(key, previous_hash.clone());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Stores the current content of the file, unless the file emitted diagnostics
+    pub(crate) fn track(&self, fs: &dyn FileSystem, path: &Path, nested_fingerprint: u64) {
+        let key = path.display().to_string();
+        if self.dirty.lock().unwrap().contains(&key) {
+            return;
+        }
+        if let Ok(content) = fs.read_to_string(path) {
+            self.current
+                .lock()
+                .unwrap()
+                .insert(key, hash_content(&content, nested_fingerprint));
+        }
+    }
+
+    /// Marks a file as dirty: the file emitted some diagnostics and it must be processed again
+    /// during the next run
+    pub(crate) fn mark_dirty(&self, path: impl Into<String>) {
+        let path = path.into();
+        self.current.lock().unwrap().remove(&path);
+        self.dirty.lock().unwrap().insert(path);
+    }
+
+    /// Writes the cache to disk
+    pub(crate) fn save(&self, fs: &dyn FileSystem) -> std::io::Result<()> {
+        let dirty = self.dirty.lock().unwrap();
+        let cache_file = CacheFile {
+            fingerprint: self.fingerprint.clone(),
+            files: self
+                .current
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(path, _)| !dirty.contains(*path))
+                .map(|(path, hash)| (path.clone(), hash.clone()))
+                .collect(),
+        };
+        let content = serde_json::to_string(&cache_file)?;
+
+        let options = OpenOptions::default()
+            .write(true)
+            .create(true)
+            .truncate(true);
+        let mut file = fs.open_with_options(&self.location, options)?;
+        file.set_content(content.as_bytes())
+    }
+}
+
+/// Computes the fingerprint of the configuration used by a run. It includes the version of Biome,
+/// so updating Biome invalidates the cache.
+pub(crate) fn configuration_fingerprint(configuration: &Configuration) -> String {
+    let mut hasher = StableHasher::new();
+    hasher.write_str(VERSION);
+    hasher.write_str(&serde_json::to_string(configuration).unwrap_or_default());
+    hasher.finish_hex()
+}
+
+/// The hashes are stored on disk and compared by the next run, so they use a [StableHasher]
+fn hash_content(content: &str, nested_fingerprint: u64) -> String {
+    let mut hasher = StableHasher::new();
+    hasher.write_str(content);
+    hasher.write_u64(nested_fingerprint);
+    hasher.finish_hex()
+}
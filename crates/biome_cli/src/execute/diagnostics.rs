@@ -1,5 +1,12 @@
+use biome_console::fmt::Display;
+use biome_console::markup;
 use biome_diagnostics::adapters::{IoError, StdError};
-use biome_diagnostics::{Advices, Category, Diagnostic, DiagnosticExt, Error, Severity, Visit};
+use biome_diagnostics::{
+    Advices, Category, Diagnostic, DiagnosticExt, Error, LogCategory, MessageAndDescription,
+    Severity, Visit,
+};
+use biome_rowan::TextRange;
+use biome_service::WorkspaceError;
 use biome_text_edit::TextEdit;
 use std::io;
 
@@ -66,15 +73,43 @@ pub(crate) struct MigrateDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "search",
+	severity = Information,
+	message = "Found a match of the pattern."
+)]
+pub(crate) struct SearchMatchDiagnostic {
+    #[location(span)]
+    pub(crate) span: TextRange,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(severity = Information)]
+pub(crate) struct FixReviewDiagnostic {
+    #[category]
+    pub(crate) category: &'static Category,
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[message]
+    #[description]
+    pub(crate) message: MessageAndDescription,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug)]
 pub(crate) struct ContentDiffAdvice {
     pub(crate) old: String,
     pub(crate) new: String,
+    /// The number of unchanged lines kept around the changes, so they can be printed
+    pub(crate) context_lines: usize,
 }
 
 impl Advices for ContentDiffAdvice {
     fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
-        let diff = TextEdit::from_unicode_words(&self.old, &self.new);
+        let diff =
+            TextEdit::from_unicode_words_with_context(&self.old, &self.new, self.context_lines);
         visitor.record_diff(&diff)
     }
 }
@@ -112,7 +147,43 @@ pub(crate) struct UnhandledDiagnostic;
 #[diagnostic(category = "parse", message = "Skipped file with syntax errors")]
 pub(crate) struct SkippedDiagnostic;
 
-/// Extension trait for turning [Display]-able error types into [TraversalError]
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "files/tooLarge",
+    message = "Some files were skipped because they exceed the maximum allowed size."
+)]
+pub(crate) struct FilesTooLargeDiagnostic {
+    #[advice]
+    pub(crate) files: FilesTooLargeAdvice,
+}
+
+#[derive(Debug)]
+pub(crate) struct FilesTooLargeAdvice {
+    pub(crate) file_names: Vec<String>,
+}
+
+impl Advices for FilesTooLargeAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &"The following files were not processed:",
+        )?;
+        let list: Vec<_> = self
+            .file_names
+            .iter()
+            .map(|file_name| file_name as &dyn Display)
+            .collect();
+        visitor.record_list(&list)?;
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "The limit can be raised with "<Emphasis>"files.maxSize"</Emphasis>", or for some files only with the "<Emphasis>"files.maxSize"</Emphasis>" option of an override."
+            },
+        )
+    }
+}
+
+/// Extension trait for turning [WorkspaceError] into [Error]
 pub(crate) trait ResultExt {
     type Result;
     fn with_file_path_and_code(
@@ -122,10 +193,7 @@ pub(crate) trait ResultExt {
     ) -> Result<Self::Result, Error>;
 }
 
-impl<T, E> ResultExt for Result<T, E>
-where
-    E: std::error::Error + Send + Sync + 'static,
-{
+impl<T> ResultExt for Result<T, WorkspaceError> {
     type Result = T;
 
     fn with_file_path_and_code(
@@ -133,20 +201,26 @@ where
         file_path: String,
         code: &'static Category,
     ) -> Result<Self::Result, Error> {
-        self.map_err(move |err| {
-            StdError::from(err)
+        self.map_err(move |err| match err {
+            // Files above the size limit keep their own category, so that the traversal can
+            // list them together instead of reporting them for every feature
+            WorkspaceError::FileTooLarge(_) => err.with_file_path(file_path),
+            err => StdError::from(err)
                 .with_category(code)
-                .with_file_path(file_path)
+                .with_file_path(file_path),
         })
     }
 }
 
 /// Extension trait for turning [io::Error] into [Error]
-pub(crate) trait ResultIoExt: ResultExt {
+pub(crate) trait ResultIoExt {
+    type Result;
     fn with_file_path(self, file_path: String) -> Result<Self::Result, Error>;
 }
 
 impl<T> ResultIoExt for io::Result<T> {
+    type Result = T;
+
     fn with_file_path(self, file_path: String) -> Result<Self::Result, Error> {
         self.map_err(|error| IoError::from(error).with_file_path(file_path))
     }
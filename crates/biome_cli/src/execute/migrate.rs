@@ -1,8 +1,8 @@
 use crate::execute::diagnostics::{ContentDiffAdvice, MigrateDiffDiagnostic};
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
-use biome_diagnostics::{category, PrintDiagnostic};
-use biome_fs::{FileSystemExt, OpenOptions};
+use biome_diagnostics::{category, DiffOptions, PrintDiagnostic};
+use biome_fs::{FileSystemExt, OpenOptions, BIOME_JSONC};
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonRoot;
 use biome_migrate::{migrate_configuration, ControlFlow};
@@ -15,6 +15,7 @@ use std::path::PathBuf;
 pub(crate) fn run(
     session: CliSession,
     write: bool,
+    format_configuration: bool,
     configuration_file_path: PathBuf,
     configuration_directory_path: PathBuf,
     verbose: bool,
@@ -33,8 +34,12 @@ pub(crate) fn run(
         fs.open_with_options(configuration_file_path.as_path(), open_options)?;
     let mut configuration_content = String::new();
     configuration_file.read_to_string(&mut configuration_content)?;
-    let parsed =
-        biome_json_parser::parse_json(&configuration_content, JsonParserOptions::default());
+    let parser_options = if configuration_file_path.ends_with(BIOME_JSONC) {
+        JsonParserOptions::default().with_allow_comments()
+    } else {
+        JsonParserOptions::default()
+    };
+    let parsed = biome_json_parser::parse_json(&configuration_content, parser_options);
     let mut errors = 0;
     let mut tree = parsed.tree();
     let mut actions = Vec::new();
@@ -73,6 +78,9 @@ pub(crate) fn run(
             }
         }
     }
+    if format_configuration {
+        tree = biome_migrate::format_configuration(&tree);
+    }
     let console = &mut *session.app.console;
     let new_configuration_content = tree.to_string();
 
@@ -96,6 +104,7 @@ pub(crate) fn run(
                     diff: ContentDiffAdvice {
                         old: "rome.json".to_string(),
                         new: "biome.json".to_string(),
+                        context_lines: DiffOptions::default().context_lines,
                     },
                 }
             } else {
@@ -104,6 +113,7 @@ pub(crate) fn run(
                     diff: ContentDiffAdvice {
                         old: configuration_content,
                         new: new_configuration_content,
+                        context_lines: DiffOptions::default().context_lines,
                     },
                 }
             };
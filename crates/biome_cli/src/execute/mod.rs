@@ -1,15 +1,26 @@
+mod baseline;
+mod cache;
 mod diagnostics;
 mod migrate;
 mod process_file;
+mod progress;
+mod staged;
 mod std_in;
 mod traverse;
+mod watch;
 
-use crate::cli_options::CliOptions;
+pub(crate) use staged::StagedFiles;
+
+use crate::cli_options::{CliOptions, CliReporter, MaxDiagnostics};
+use crate::execute::baseline::Baseline;
+use crate::execute::cache::{configuration_fingerprint, ResultsCache, DEFAULT_CACHE_LOCATION};
 use crate::execute::traverse::traverse;
 use crate::{CliDiagnostic, CliSession};
-use biome_diagnostics::{category, Category, MAXIMUM_DISPLAYABLE_DIAGNOSTICS};
-use biome_fs::RomePath;
-use biome_service::workspace::{FeatureName, FixFileMode};
+use biome_diagnostics::{category, Category, DiffOptions, MAXIMUM_DISPLAYABLE_DIAGNOSTICS};
+use biome_fs::{RomePath, TraversalPolicy};
+use biome_service::configuration::nested::NestedConfigurations;
+use biome_service::workspace::{FeatureName, FixFileMode, RuleSelector};
+use biome_service::Configuration;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
@@ -22,14 +33,41 @@ pub(crate) struct Execution {
     /// The modality of execution of the traversal
     traversal_mode: TraversalMode,
 
-    /// The maximum number of diagnostics that can be printed in console
-    max_diagnostics: u16,
+    /// The maximum number of diagnostics that can be printed in console, [usize::MAX] when
+    /// there's no limit
+    max_diagnostics: usize,
+
+    /// Fingerprint of the configuration used by the command, required by the cache
+    configuration_fingerprint: Option<String>,
+
+    /// The results of the previous runs, when the cache is enabled
+    cache: Option<ResultsCache>,
+
+    /// The diagnostics that aren't reported, passed with `--baseline`
+    baseline: Option<Baseline>,
+
+    /// Whether the files should be traversed again when they change
+    watch: bool,
+
+    /// How the diffs of the diagnostics are printed in the terminal
+    diff_options: DiffOptions,
+
+    /// The staged files whose fixes are staged after the traversal, with `check --staged --apply`
+    staged_files: Option<StagedFiles>,
+
+    /// How the symbolic links and the case of the paths are handled during the traversal
+    traversal_policy: TraversalPolicy,
+
+    /// The configuration files of the subdirectories of the project, looked up while the files
+    /// are handled
+    nested_configurations: Option<NestedConfigurations>,
 }
 
 impl Execution {
     pub(crate) fn as_feature_name(&self) -> FeatureName {
         match self.traversal_mode {
             TraversalMode::Format { .. } => FeatureName::Format,
+            TraversalMode::Search { .. } => FeatureName::Search,
             _ => FeatureName::Lint,
         }
     }
@@ -48,6 +86,10 @@ pub(crate) enum TraversalMode {
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<(PathBuf, String)>,
+        /// Whether the user must accept each fix before it's applied
+        interactive: bool,
+        /// The rules passed with `--unsafe-only`, whose unsafe fixes are applied
+        unsafe_rules: Vec<RuleSelector>,
     },
     /// This mode is enabled when running the command `biome lint`
     Lint {
@@ -60,6 +102,12 @@ pub(crate) enum TraversalMode {
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<(PathBuf, String)>,
+        /// The rules passed with `--rule`: when not empty, only these rules are run
+        only: Vec<RuleSelector>,
+        /// The rules passed with `--skip-rule`, they are never run
+        skip: Vec<RuleSelector>,
+        /// The rules passed with `--unsafe-only`, whose unsafe fixes are applied
+        unsafe_rules: Vec<RuleSelector>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI,
@@ -77,9 +125,16 @@ pub(crate) enum TraversalMode {
     /// This mode is enabled when running the command `biome migrate`
     Migrate {
         write: bool,
+        /// Whether the configuration file is also sorted and cleaned of its default values
+        format_configuration: bool,
         configuration_file_path: PathBuf,
         configuration_directory_path: PathBuf,
     },
+    /// This mode is enabled when running the command `biome search`
+    Search {
+        /// The structural pattern to look for, e.g. `console.log($message)`
+        pattern: String,
+    },
 }
 
 impl Display for TraversalMode {
@@ -90,6 +145,7 @@ impl Display for TraversalMode {
             TraversalMode::Format { .. } => write!(f, "format"),
             TraversalMode::Migrate { .. } => write!(f, "migrate"),
             TraversalMode::Lint { .. } => write!(f, "lint"),
+            TraversalMode::Search { .. } => write!(f, "search"),
         }
     }
 }
@@ -102,6 +158,31 @@ pub(crate) enum ReportMode {
     Terminal,
     /// Reports information in JSON format
     Json,
+    /// Reports diagnostics using the versioned JSON format of Biome
+    JsonDiagnostics,
+    /// Reports diagnostics as GitHub workflow commands
+    GitHub,
+    /// Reports diagnostics using the GitLab Code Quality format
+    GitLab,
+    /// Reports diagnostics using the JUnit XML format
+    Junit,
+    /// Reports diagnostics using the SARIF format
+    Sarif,
+    /// Reports statistics about the diagnostics, grouped by rule and by directory
+    Summary,
+}
+
+impl From<CliReporter> for ReportMode {
+    fn from(value: CliReporter) -> Self {
+        match value {
+            CliReporter::Json => Self::JsonDiagnostics,
+            CliReporter::GitHub => Self::GitHub,
+            CliReporter::GitLab => Self::GitLab,
+            CliReporter::Junit => Self::Junit,
+            CliReporter::Sarif => Self::Sarif,
+            CliReporter::Summary => Self::Summary,
+        }
+    }
 }
 
 impl Execution {
@@ -109,7 +190,15 @@ impl Execution {
         Self {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
-            max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS.into(),
+            configuration_fingerprint: None,
+            cache: None,
+            baseline: None,
+            watch: false,
+            diff_options: DiffOptions::default(),
+            staged_files: None,
+            traversal_policy: TraversalPolicy::default(),
+            nested_configurations: None,
         }
     }
 
@@ -118,20 +207,122 @@ impl Execution {
         Self {
             traversal_mode,
             report_mode,
-            max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS.into(),
+            configuration_fingerprint: None,
+            cache: None,
+            baseline: None,
+            watch: false,
+            diff_options: DiffOptions::default(),
+            staged_files: None,
+            traversal_policy: TraversalPolicy::default(),
+            nested_configurations: None,
         }
     }
 
+    /// Stores the fingerprint of the configuration used by the command. The cache can be used
+    /// only when the fingerprint is known.
+    ///
+    /// The traversal policy is also read from the configuration of the files.
+    pub(crate) fn with_configuration(mut self, configuration: &Configuration) -> Self {
+        self.configuration_fingerprint = Some(configuration_fingerprint(configuration));
+        if let Some(follow_symlinks) = configuration
+            .files
+            .as_ref()
+            .and_then(|files| files.follow_symlinks)
+        {
+            self.traversal_policy.follow_symlinks = follow_symlinks;
+        }
+        self
+    }
+
+    /// Keeps watching the files after the first traversal, and traverses again the files that change
+    pub(crate) fn with_watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    /// Changes how the diffs of the diagnostics are printed in the terminal
+    pub(crate) fn with_diff_options(mut self, diff_options: DiffOptions) -> Self {
+        self.diff_options = diff_options;
+        self
+    }
+
+    pub(crate) fn diff_options(&self) -> DiffOptions {
+        self.diff_options
+    }
+
+    /// Stages the fixes applied to these files once the traversal is done
+    pub(crate) fn with_staged_files(mut self, staged_files: Option<StagedFiles>) -> Self {
+        self.staged_files = staged_files;
+        self
+    }
+
+    /// Applies the nested configuration files to the files beneath their directories
+    pub(crate) fn with_nested_configurations(
+        mut self,
+        nested_configurations: NestedConfigurations,
+    ) -> Self {
+        self.nested_configurations = Some(nested_configurations);
+        self
+    }
+
+    /// The nested configuration files of the project, looked up while the files are handled
+    pub(crate) fn nested_configurations(&self) -> Option<&NestedConfigurations> {
+        self.nested_configurations.as_ref()
+    }
+
+    /// The cache of the results of the previous runs, if enabled
+    pub(crate) fn cache(&self) -> Option<&ResultsCache> {
+        self.cache.as_ref()
+    }
+
+    /// The diagnostics recorded in the baseline, if passed with `--baseline`
+    pub(crate) fn baseline(&self) -> Option<&Baseline> {
+        self.baseline.as_ref()
+    }
+
     /// Tells if the reporting is happening straight to terminal
     pub(crate) fn should_report_to_terminal(&self) -> bool {
         matches!(self.report_mode, ReportMode::Terminal)
     }
 
+    /// Tells if the diagnostics are collected and printed by a reporter backend
+    pub(crate) fn should_report_to_backend(&self) -> bool {
+        matches!(
+            self.report_mode,
+            ReportMode::JsonDiagnostics
+                | ReportMode::GitHub
+                | ReportMode::GitLab
+                | ReportMode::Junit
+                | ReportMode::Sarif
+                | ReportMode::Summary
+        )
+    }
+
+    /// Tells if the summary of the diagnostics is printed after the diagnostics, which is the
+    /// case of the `ci` command when it reports to the terminal
+    pub(crate) fn should_print_summary(&self) -> bool {
+        self.is_ci() && self.should_report_to_terminal()
+    }
+
+    /// Tells if the time spent processing each file should be tracked
+    pub(crate) fn should_track_durations(&self) -> bool {
+        matches!(self.report_mode, ReportMode::Summary)
+    }
+
+    pub(crate) fn report_mode(&self) -> ReportMode {
+        self.report_mode
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
 
-    pub(crate) fn get_max_diagnostics(&self) -> u16 {
+    pub(crate) fn traversal_policy(&self) -> TraversalPolicy {
+        self.traversal_policy
+    }
+
+    pub(crate) fn get_max_diagnostics(&self) -> usize {
         self.max_diagnostics
     }
 
@@ -140,9 +331,10 @@ impl Execution {
         match &self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.as_ref(),
-            TraversalMode::Format { .. } | TraversalMode::CI | TraversalMode::Migrate { .. } => {
-                None
-            }
+            TraversalMode::Format { .. }
+            | TraversalMode::CI
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. } => None,
         }
     }
 
@@ -153,6 +345,7 @@ impl Execution {
             TraversalMode::CI => category!("ci"),
             TraversalMode::Format { .. } => category!("format"),
             TraversalMode::Migrate { .. } => category!("migrate"),
+            TraversalMode::Search { .. } => category!("search"),
         }
     }
 
@@ -188,6 +381,17 @@ impl Execution {
         )
     }
 
+    /// `true` when the fixes must be accepted by the user before being applied
+    pub(crate) const fn is_interactive(&self) -> bool {
+        matches!(
+            self.traversal_mode,
+            TraversalMode::Check {
+                interactive: true,
+                ..
+            }
+        )
+    }
+
     pub(crate) const fn is_format(&self) -> bool {
         matches!(self.traversal_mode, TraversalMode::Format { .. })
     }
@@ -205,18 +409,44 @@ impl Execution {
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI => false,
+            TraversalMode::CI | TraversalMode::Search { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write: dry_run, .. } => dry_run,
         }
     }
 
+    /// The rules selected with `--rule` and the ones skipped with `--skip-rule`
+    pub(crate) fn rule_selection(&self) -> (&[RuleSelector], &[RuleSelector]) {
+        match &self.traversal_mode {
+            TraversalMode::Lint { only, skip, .. } => (only.as_slice(), skip.as_slice()),
+            TraversalMode::Check { .. }
+            | TraversalMode::CI
+            | TraversalMode::Format { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. } => (&[], &[]),
+        }
+    }
+
+    /// The rules passed with `--unsafe-only`
+    pub(crate) fn unsafe_rules(&self) -> &[RuleSelector] {
+        match &self.traversal_mode {
+            TraversalMode::Check { unsafe_rules, .. }
+            | TraversalMode::Lint { unsafe_rules, .. } => unsafe_rules,
+            TraversalMode::CI
+            | TraversalMode::Format { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. } => &[],
+        }
+    }
+
     pub(crate) fn as_stdin_file(&self) -> Option<&(PathBuf, String)> {
         match &self.traversal_mode {
             TraversalMode::Format { stdin, .. }
             | TraversalMode::Lint { stdin, .. }
             | TraversalMode::Check { stdin, .. } => stdin.as_ref(),
-            TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => None,
+            TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. } => None,
         }
     }
 }
@@ -225,25 +455,73 @@ impl Execution {
 /// or handles the stdin file.
 pub(crate) fn execute_mode(
     mut mode: Execution,
-    session: CliSession,
+    mut session: CliSession,
     cli_options: &CliOptions,
     paths: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
-    if cli_options.max_diagnostics > MAXIMUM_DISPLAYABLE_DIAGNOSTICS {
-        return Err(CliDiagnostic::overflown_argument(
-            "--max-diagnostics",
-            MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
-        ));
+    mode.max_diagnostics = match cli_options.max_diagnostics {
+        MaxDiagnostics::None => usize::MAX,
+        MaxDiagnostics::Limit(limit) if limit > MAXIMUM_DISPLAYABLE_DIAGNOSTICS => {
+            return Err(CliDiagnostic::overflown_argument(
+                "--max-diagnostics",
+                MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            ));
+        }
+        MaxDiagnostics::Limit(limit) => limit.into(),
+    };
+    if let Some(reporter) = cli_options.reporter {
+        mode.report_mode = ReportMode::from(reporter);
+    }
+    if cli_options.cache {
+        if let Some(fingerprint) = &mode.configuration_fingerprint {
+            let location = cli_options
+                .cache_location
+                .as_ref()
+                .map_or_else(|| PathBuf::from(DEFAULT_CACHE_LOCATION), PathBuf::from);
+            mode.cache = Some(ResultsCache::load(
+                &*session.app.fs,
+                location,
+                fingerprint,
+                &mode.traversal_mode,
+            ));
+        }
     }
 
-    mode.max_diagnostics = cli_options.max_diagnostics;
+    if let Some(location) = &cli_options.baseline {
+        // The paths of the baseline are relative to the root of the project
+        let root_directory = mode
+            .nested_configurations()
+            .map(|nested_configurations| nested_configurations.root_directory().to_path_buf())
+            .or_else(|| session.app.fs.working_directory())
+            .unwrap_or_default();
+        mode.baseline = Some(Baseline::load(
+            &*session.app.fs,
+            PathBuf::from(location),
+            root_directory,
+            cli_options.update_baseline,
+        )?);
+    } else if cli_options.update_baseline {
+        return Err(CliDiagnostic::missing_argument(
+            "--baseline",
+            format!("{}", mode.traversal_mode),
+        ));
+    }
 
     // don't do any traversal if there's some content coming from stdin
     if let Some((path, content)) = mode.as_stdin_file() {
+        // Editors usually pass absolute paths, while the patterns of the configuration
+        // (e.g. `overrides`) are relative to the working directory
+        let path = session
+            .app
+            .fs
+            .working_directory()
+            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+            .unwrap_or(path.as_path());
         let rome_path = RomePath::new(path);
-        std_in::run(session, &mode, rome_path, content.as_str())
+        std_in::run(session, &mode, cli_options, rome_path, content.as_str())
     } else if let TraversalMode::Migrate {
         write,
+        format_configuration,
         configuration_file_path,
         configuration_directory_path,
     } = mode.traversal_mode
@@ -251,11 +529,19 @@ pub(crate) fn execute_mode(
         migrate::run(
             session,
             write,
+            format_configuration,
             configuration_file_path,
             configuration_directory_path,
             cli_options.verbose,
         )
+    } else if mode.watch {
+        watch::run(&mode, session, cli_options, paths)
     } else {
-        traverse(mode, session, cli_options, paths)
+        let result = traverse(&mode, &mut session, cli_options, paths);
+        // The fixes are staged even when the traversal reported errors
+        if let Some(staged_files) = &mode.staged_files {
+            staged::run(&session, &mode, staged_files)?;
+        }
+        result
     }
 }
@@ -2,12 +2,17 @@ mod check;
 mod format;
 mod lint;
 mod organize_imports;
+mod review;
+mod search;
 mod workspace_file;
 
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::process_file::check::check_file;
 use crate::execute::process_file::format::format;
 use crate::execute::process_file::lint::lint;
+pub(crate) use crate::execute::process_file::review::FixDecisions;
+use crate::execute::process_file::review::FixReview;
+use crate::execute::process_file::search::search;
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
 use crate::CliDiagnostic;
@@ -46,6 +51,8 @@ pub(crate) enum Message {
         new: String,
         diff_kind: DiffKind,
     },
+    /// A fix that must be accepted or skipped by the user, when `--interactive` is passed
+    FixReview(FixReview),
 }
 
 impl Message {
@@ -121,6 +128,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                     .with_formatter()
                     .with_linter()
                     .with_organize_imports()
+                    .with_search()
                     .build(),
             })
             .with_file_path_and_code(
@@ -193,6 +201,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                 ),
             TraversalMode::Format { .. } => file_features.support_kind_for(&FeatureName::Format),
             TraversalMode::Lint { .. } => file_features.support_kind_for(&FeatureName::Lint),
+            TraversalMode::Search { .. } => file_features.support_kind_for(&FeatureName::Search),
             TraversalMode::Migrate { .. } => None,
         };
 
@@ -225,6 +234,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                 check_file(shared_context, path, &file_features, category!("check"))
             }
             TraversalMode::CI => check_file(shared_context, path, &file_features, category!("ci")),
+            TraversalMode::Search { ref pattern } => search(shared_context, path, pattern),
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
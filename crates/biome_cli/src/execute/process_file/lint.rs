@@ -1,9 +1,14 @@
 use crate::execute::diagnostics::ResultExt;
+use crate::execute::process_file::review::review_fixes;
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use crate::CliDiagnostic;
-use biome_diagnostics::{category, Error};
-use biome_service::workspace::RuleCategories;
+use biome_diagnostics::{category, DiagnosticExt, Error, Severity};
+use biome_fs::RomePath;
+use biome_service::workspace::{
+    CancellationToken, FixFileMode, FixFileResult, FixFilesParams, RuleCategories, RuleSelector,
+};
+use std::ops::Range;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 
@@ -21,49 +26,73 @@ pub(crate) fn lint_with_guard<'ctx>(
         move || {
             let mut errors = 0;
             let mut input = workspace_file.input()?;
+            let (only, skip) = ctx.execution.rule_selection();
 
             if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
-                let fix_result = workspace_file
-                    .guard()
-                    .fix_file(*fix_mode, false)
-                    .with_file_path_and_code(
-                        workspace_file.path.display().to_string(),
-                        category!("lint"),
-                    )?;
+                let is_interactive = ctx.execution.is_interactive();
+                let fix_result = if is_interactive {
+                    review_fixes(ctx, workspace_file, *fix_mode)?
+                } else {
+                    fix_file(ctx, workspace_file, *fix_mode, only, skip)?
+                };
 
                 ctx.push_message(Message::SkippedFixes {
                     skipped_suggested_fixes: fix_result.skipped_suggested_fixes,
                 });
+                errors = fix_result.errors;
 
-                if fix_result.code != input {
+                // The fixes accepted during the review are already applied
+                if !is_interactive && fix_result.code != input {
                     workspace_file.update_file(fix_result.code)?;
-                    input = workspace_file.input()?;
                 }
-                errors = fix_result.errors;
+                input = workspace_file.input()?;
             }
 
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
+            let baseline = ctx.execution.baseline();
+            // The diagnostics recorded in the baseline don't count towards the limit
+            let max_diagnostics = if baseline.is_some() {
+                u64::MAX
+            } else {
+                ctx.remaining_diagnostics.load(Ordering::Relaxed) as u64
+            };
             let pull_diagnostics_result = workspace_file
                 .guard()
-                .pull_diagnostics(RuleCategories::LINT, max_diagnostics.into())
+                .pull_diagnostics(
+                    RuleCategories::LINT,
+                    max_diagnostics,
+                    only.to_vec(),
+                    skip.to_vec(),
+                )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("lint"),
                 )?;
 
-            let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
-                && pull_diagnostics_result.skipped_diagnostics == 0;
-            errors += pull_diagnostics_result.errors;
+            let name = workspace_file.path.display().to_string();
+            let mut diagnostics: Vec<_> = pull_diagnostics_result
+                .diagnostics
+                .into_iter()
+                .map(Error::from)
+                .collect();
+            if let Some(baseline) = baseline {
+                diagnostics = baseline.filter(&workspace_file.path, &input, diagnostics);
+                // Only the errors that aren't in the baseline fail the command
+                errors = diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity() >= Severity::Error)
+                    .count();
+            } else {
+                errors += pull_diagnostics_result.errors;
+            }
+
+            let no_diagnostics =
+                diagnostics.is_empty() && pull_diagnostics_result.skipped_diagnostics == 0;
 
             if !no_diagnostics {
                 ctx.push_message(Message::Diagnostics {
-                    name: workspace_file.path.display().to_string(),
+                    name,
                     content: input,
-                    diagnostics: pull_diagnostics_result
-                        .diagnostics
-                        .into_iter()
-                        .map(Error::from)
-                        .collect(),
+                    diagnostics,
                     skipped_diagnostics: pull_diagnostics_result.skipped_diagnostics,
                 });
             }
@@ -90,3 +119,48 @@ pub(crate) fn lint_with_guard<'ctx>(
         },
     )
 }
+
+/// Applies the fixes to the file with [biome_service::Workspace::fix_files], which also fixes
+/// the files of the workspace in the LSP, so both apply the same fixes
+fn fix_file<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    workspace_file: &WorkspaceFile,
+    fix_file_mode: FixFileMode,
+    only: &[RuleSelector],
+    skip: &[RuleSelector],
+) -> Result<FixFileResult, Error> {
+    let file_path = workspace_file.path.display().to_string();
+    let mut code = workspace_file
+        .input()
+        .with_file_path_and_code(file_path.clone(), category!("lint"))?;
+    let mut result = ctx
+        .workspace
+        .fix_files(FixFilesParams {
+            paths: vec![RomePath::new(&workspace_file.path)],
+            fix_file_mode,
+            should_format: false,
+            only: only.to_vec(),
+            skip: skip.to_vec(),
+            unsafe_rules: ctx.execution.unsafe_rules().to_vec(),
+            cancellation: CancellationToken::default(),
+        })
+        .with_file_path_and_code(file_path.clone(), category!("lint"))?;
+    if let Some(error) = result.errors.pop() {
+        return Err(error.error.with_file_path(file_path));
+    }
+
+    let mut actions = vec![];
+    for file in result.files {
+        code.replace_range(
+            Range::<usize>::from(file.replacement.range),
+            &file.replacement.text,
+        );
+        actions = file.actions;
+    }
+    Ok(FixFileResult {
+        code,
+        actions,
+        errors: result.remaining_errors,
+        skipped_suggested_fixes: result.skipped_suggested_fixes,
+    })
+}
@@ -0,0 +1,182 @@
+use crate::commands::init::ask;
+use crate::execute::diagnostics::{ContentDiffAdvice, FixReviewDiagnostic, ResultExt};
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use crate::execute::process_file::{Message, SharedTraversalOptions};
+use biome_analyze::ActionCategory;
+use biome_console::{markup, Console, ConsoleExt, MarkupBuf};
+use biome_diagnostics::{category, Applicability, Category, DiffOptions, Error, PrintDiagnostic};
+use biome_rowan::{TextRange, TextSize};
+use biome_service::workspace::{FixFileMode, FixFileResult};
+use crossbeam::channel::{bounded, Sender};
+use rustc_hash::FxHashMap;
+
+/// A fix proposed to the user, sent to the console thread when `--interactive` is passed
+#[derive(Debug)]
+pub(crate) struct FixReview {
+    file_name: String,
+    /// The name of the rule that emitted the fix, e.g. `lint/suspicious/noDebugger`
+    rule_name: String,
+    message: MarkupBuf,
+    old: String,
+    new: String,
+    /// Where the console thread sends `true` if the fix was accepted
+    answer: Sender<bool>,
+}
+
+/// Proposes the fixes of a file one at a time, following their order in the file, and
+/// applies the fixes that the user accepts.
+///
+/// Applying a fix changes only the code that comes after the start of the fix, so the
+/// fixes that come before it don't need to be proposed again.
+///
+/// Once every fix was reviewed, it returns the [FixFileResult] of the reviewed file, which
+/// counts the errors and the skipped fixes like the non-interactive path. Its code isn't
+/// meant to be applied.
+pub(crate) fn review_fixes<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    workspace_file: &mut WorkspaceFile,
+    fix_file_mode: FixFileMode,
+) -> Result<FixFileResult, Error> {
+    let file_name = workspace_file.path.display().to_string();
+    let mut input = workspace_file.input()?;
+    let mut position = TextSize::from(0);
+    // The rules already proposed at `position`
+    let mut reviewed_rules: Vec<String> = vec![];
+    let (only, skip) = ctx.execution.rule_selection();
+    let unsafe_rules = ctx.execution.unsafe_rules();
+
+    loop {
+        let result = workspace_file
+            .guard()
+            .pull_actions(TextRange::up_to(TextSize::of(input.as_str())))
+            .with_file_path_and_code(file_name.clone(), category!("lint"))?;
+
+        let next_fix = result
+            .actions
+            .into_iter()
+            .filter(|action| matches!(action.category, ActionCategory::QuickFix))
+            .filter(|action| match action.suggestion.applicability {
+                Applicability::Always => true,
+                Applicability::MaybeIncorrect => {
+                    matches!(fix_file_mode, FixFileMode::SafeAndUnsafeFixes)
+                        || action.rule_name.as_ref().is_some_and(|(group, name)| {
+                            unsafe_rules
+                                .iter()
+                                .any(|selector| selector.matches(group, name))
+                        })
+                }
+            })
+            .filter_map(|action| {
+                let (group, name) = action.rule_name?;
+                let is_selected = (only.is_empty()
+                    || only.iter().any(|selector| selector.matches(&group, &name)))
+                    && !skip.iter().any(|selector| selector.matches(&group, &name));
+                is_selected.then(|| (format!("lint/{group}/{name}"), action.suggestion))
+            })
+            .filter(|(rule_name, suggestion)| {
+                let start = suggestion.span.start();
+                start > position || (start == position && !reviewed_rules.contains(rule_name))
+            })
+            .min_by(|(left_rule, left), (right_rule, right)| {
+                left.span
+                    .start()
+                    .cmp(&right.span.start())
+                    .then_with(|| left_rule.cmp(right_rule))
+            });
+
+        let Some((rule_name, suggestion)) = next_fix else {
+            return workspace_file
+                .guard()
+                .fix_file(
+                    fix_file_mode,
+                    false,
+                    only.to_vec(),
+                    skip.to_vec(),
+                    unsafe_rules.to_vec(),
+                )
+                .with_file_path_and_code(file_name, category!("lint"));
+        };
+
+        if suggestion.span.start() != position {
+            position = suggestion.span.start();
+            reviewed_rules.clear();
+        }
+        reviewed_rules.push(rule_name.clone());
+
+        let new = suggestion.suggestion.new_string(&input);
+        let (sender, receiver) = bounded(1);
+        ctx.push_message(Message::FixReview(FixReview {
+            file_name: file_name.clone(),
+            rule_name,
+            message: suggestion.msg,
+            old: input.clone(),
+            new: new.clone(),
+            answer: sender,
+        }));
+
+        // The console thread drops the sender without answering only if it's shutting down
+        if receiver.recv().unwrap_or(false) {
+            workspace_file.update_file(new)?;
+            input = workspace_file.input()?;
+        }
+    }
+}
+
+/// The answers given for all the fixes of a rule, kept for the whole session
+#[derive(Debug, Default)]
+pub(crate) struct FixDecisions {
+    by_rule: FxHashMap<String, bool>,
+}
+
+impl FixDecisions {
+    /// Asks the user whether the fix should be applied, unless they already answered
+    /// for all the fixes of the rule, and sends the answer to the worker
+    pub(crate) fn review(&mut self, console: &mut dyn Console, review: FixReview, verbose: bool) {
+        let FixReview {
+            file_name,
+            rule_name,
+            message,
+            old,
+            new,
+            answer,
+        } = review;
+
+        let accepted = match self.by_rule.get(&rule_name) {
+            Some(accepted) => *accepted,
+            None => {
+                let category = rule_name
+                    .parse::<&'static Category>()
+                    .unwrap_or(category!("lint"));
+                let diagnostic = Error::from(FixReviewDiagnostic {
+                    category,
+                    file_name,
+                    message: message.into(),
+                    diff: ContentDiffAdvice {
+                        old,
+                        new,
+                        context_lines: DiffOptions::default().context_lines,
+                    },
+                });
+                console.error(markup! {
+                    {if verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
+                });
+
+                // "all" and "none" answer for every fix of the rule
+                match ask(console, "Apply this fix?", &["no", "yes", "all", "none"]) {
+                    "yes" => true,
+                    "all" => {
+                        self.by_rule.insert(rule_name, true);
+                        true
+                    }
+                    "none" => {
+                        self.by_rule.insert(rule_name, false);
+                        false
+                    }
+                    _ => false,
+                }
+            }
+        };
+
+        answer.send(accepted).ok();
+    }
+}
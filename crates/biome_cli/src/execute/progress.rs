@@ -0,0 +1,57 @@
+use biome_console::{markup, Console, LogLevel};
+use std::time::{Duration, Instant};
+
+/// How often the progress indicator is refreshed
+pub(crate) const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A single-line progress indicator shown as the status of the error stream of the console
+/// while the files are traversed.
+///
+/// The indicator is drawn only when the error stream is a terminal, so it never ends up
+/// in logs or in the output of a CI.
+pub(crate) struct ProgressIndicator {
+    enabled: bool,
+    start: Instant,
+    last_draw: Option<Instant>,
+}
+
+impl ProgressIndicator {
+    pub(crate) fn new(requested: bool, console: &dyn Console) -> Self {
+        Self {
+            enabled: requested && console.is_terminal(LogLevel::Error),
+            start: Instant::now(),
+            last_draw: None,
+        }
+    }
+
+    /// Redraws the indicator, unless it was drawn less than [PROGRESS_INTERVAL] ago
+    pub(crate) fn tick(&mut self, console: &mut dyn Console, processed: usize) {
+        if !self.enabled {
+            return;
+        }
+        let now = Instant::now();
+        if self
+            .last_draw
+            .is_some_and(|last_draw| now.duration_since(last_draw) < PROGRESS_INTERVAL)
+        {
+            return;
+        }
+        self.last_draw = Some(now);
+
+        let elapsed = now.duration_since(self.start).as_secs();
+        console.set_status(
+            LogLevel::Error,
+            Some(markup! {
+                "Processed "{processed}" file(s) in "{elapsed}"s"
+            }),
+        );
+    }
+
+    /// Erases the indicator, so the diagnostics and the summary start from an empty line
+    pub(crate) fn finish(&mut self, console: &mut dyn Console) {
+        if self.enabled && self.last_draw.is_some() {
+            console.set_status(LogLevel::Error, None);
+        }
+        self.last_draw = None;
+    }
+}
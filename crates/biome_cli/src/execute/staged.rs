@@ -0,0 +1,157 @@
+//! In here, there are the operations that write the fixes of `check --staged --apply` back to
+//! the index of the VCS
+//!
+use crate::execute::Execution;
+use crate::{CliDiagnostic, CliSession};
+use biome_fs::{FileSystem, RomePath};
+use biome_service::workspace::{
+    CancellationToken, ChangeFileParams, CloseFileParams, FeatureName, FeaturesBuilder,
+    FixFileParams, FormatFileParams, Language, OpenFileParams, OrganizeImportsParams,
+    SupportsFeatureParams,
+};
+use biome_service::{Workspace, WorkspaceError};
+use std::ffi::OsString;
+
+/// The staged files processed by the command, split by whether they have unstaged changes
+#[derive(Debug, Default)]
+pub(crate) struct StagedFiles {
+    /// Files whose changes are all staged: the fixed file can be staged as is
+    fully_staged: Vec<String>,
+    /// Files that also have unstaged changes: only their staged content is fixed, so
+    /// the unstaged changes don't end up in the commit
+    partially_staged: Vec<String>,
+}
+
+impl StagedFiles {
+    /// Splits the staged `paths` using the files that have unstaged changes.
+    ///
+    /// It must be called before the traversal: once the fixes are written, every fixed
+    /// file has unstaged changes.
+    pub(crate) fn load(fs: &dyn FileSystem, paths: &[OsString]) -> Result<Self, CliDiagnostic> {
+        let unstaged_files = fs.get_unstaged_files().map_err(CliDiagnostic::io_error)?;
+
+        let mut staged_files = StagedFiles::default();
+        for path in paths {
+            let path = path.to_string_lossy().into_owned();
+            if unstaged_files.contains(&path) {
+                staged_files.partially_staged.push(path);
+            } else {
+                staged_files.fully_staged.push(path);
+            }
+        }
+
+        Ok(staged_files)
+    }
+}
+
+/// Stages the fixes applied to the staged files.
+///
+/// The files that are fully staged are staged again after the traversal wrote their fixes.
+/// The partially staged files are fixed again starting from their staged content, and the
+/// result replaces the staged content. Their unstaged changes stay in the working directory.
+pub(crate) fn run(
+    session: &CliSession,
+    mode: &Execution,
+    staged_files: &StagedFiles,
+) -> Result<(), CliDiagnostic> {
+    let fs = &*session.app.fs;
+    let workspace = &*session.app.workspace;
+
+    fs.stage_files(&staged_files.fully_staged)
+        .map_err(CliDiagnostic::io_error)?;
+
+    for path in &staged_files.partially_staged {
+        let content = fs
+            .read_staged_content(path)
+            .map_err(CliDiagnostic::io_error)?;
+        let new_content = fix_content(workspace, mode, RomePath::new(path), &content)?;
+        if new_content != content {
+            fs.stage_content(path, &new_content)
+                .map_err(CliDiagnostic::io_error)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies the fixes, the import sorting and the formatting of the `check` command to `content`
+fn fix_content(
+    workspace: &dyn Workspace,
+    mode: &Execution,
+    rome_path: RomePath,
+    content: &str,
+) -> Result<String, WorkspaceError> {
+    let Some(fix_file_mode) = mode.as_fix_file_mode() else {
+        return Ok(content.to_string());
+    };
+
+    let file_features = workspace.file_features(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeaturesBuilder::new()
+            .with_linter()
+            .with_organize_imports()
+            .with_formatter()
+            .build(),
+    })?;
+
+    workspace.open_file(OpenFileParams {
+        path: rome_path.clone(),
+        version: 0,
+        content: content.into(),
+        language_hint: Language::default(),
+    })?;
+
+    let mut version = 0;
+    let mut new_content = content.to_string();
+
+    if file_features.supports_for(&FeatureName::Lint) {
+        let fix_file_result = workspace.fix_file(FixFileParams {
+            fix_file_mode: *fix_file_mode,
+            path: rome_path.clone(),
+            should_format: file_features.supports_for(&FeatureName::Format),
+            only: vec![],
+            skip: vec![],
+            unsafe_rules: mode.unsafe_rules().to_vec(),
+            cancellation: CancellationToken::default(),
+        })?;
+        if fix_file_result.code != new_content {
+            version += 1;
+            workspace.change_file(ChangeFileParams {
+                content: fix_file_result.code.clone(),
+                path: rome_path.clone(),
+                version,
+            })?;
+            new_content = fix_file_result.code;
+        }
+    }
+
+    if file_features.supports_for(&FeatureName::OrganizeImports) {
+        let result = workspace.organize_imports(OrganizeImportsParams {
+            path: rome_path.clone(),
+        })?;
+        if result.code != new_content {
+            version += 1;
+            workspace.change_file(ChangeFileParams {
+                content: result.code.clone(),
+                path: rome_path.clone(),
+                version,
+            })?;
+            new_content = result.code;
+        }
+    }
+
+    if file_features.supports_for(&FeatureName::Format) {
+        match workspace.format_file(FormatFileParams {
+            path: rome_path.clone(),
+        }) {
+            Ok(printed) => new_content = printed.into_code(),
+            // The syntax errors are already reported by the traversal of the file
+            Err(WorkspaceError::FormatWithErrorsDisabled(_)) => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    workspace.close_file(CloseFileParams { path: rome_path })?;
+
+    Ok(new_content)
+}
@@ -1,21 +1,25 @@
 //! In here, there are the operations that run via standard input
 //!
+use crate::cli_options::{CliOptions, OutputFormat};
 use crate::execute::diagnostics::{ContentDiffAdvice, FormatDiffDiagnostic};
 use crate::execute::Execution;
+use crate::reports::compact::CompactDiagnostic;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
-use biome_diagnostics::PrintDiagnostic;
+use biome_diagnostics::{Diagnostic, PrintDiagnostic};
 use biome_fs::RomePath;
 use biome_service::workspace::{
-    ChangeFileParams, FeatureName, FeaturesBuilder, FixFileParams, FormatFileParams, Language,
-    OpenFileParams, OrganizeImportsParams, PullDiagnosticsParams, RuleCategories,
-    SupportsFeatureParams,
+    CancellationToken, ChangeFileParams, FeatureName, FeaturesBuilder, FixFileParams,
+    FormatFileParams, IsPathIgnoredParams, Language, OpenFileParams, OrganizeImportsParams,
+    PullDiagnosticsParams, RuleCategories, SupportsFeatureParams,
 };
 use std::borrow::Cow;
+use std::path::Path;
 
 pub(crate) fn run<'a>(
     session: CliSession,
     mode: &'a Execution,
+    cli_options: &CliOptions,
     rome_path: RomePath,
     content: &'a str,
 ) -> Result<(), CliDiagnostic> {
@@ -23,6 +27,27 @@ pub(crate) fn run<'a>(
     let console = &mut *session.app.console;
     let mut version = 0;
 
+    // The nested configuration files apply to the content like to a file at `rome_path`
+    if let Some(nested_configurations) = mode.nested_configurations() {
+        let directory = rome_path.parent().unwrap_or(Path::new(""));
+        for diagnostic in nested_configurations.discover(&*session.app.fs, workspace, directory)? {
+            console.error(markup! {
+                {PrintDiagnostic::simple(&diagnostic)}
+            });
+        }
+    }
+
+    // The content of ignored files is printed back as is, so editors don't lose it
+    if mode.is_lint()
+        && workspace.is_path_ignored(IsPathIgnoredParams {
+            rome_path: rome_path.clone(),
+            feature: FeatureName::Lint,
+        })?
+    {
+        console.append(markup! {{content}});
+        return Ok(());
+    }
+
     if mode.is_format() {
         let file_features = workspace.file_features(SupportsFeatureParams {
             path: rome_path.clone(),
@@ -74,6 +99,10 @@ pub(crate) fn run<'a>(
                     path: rome_path.clone(),
                     should_format: mode.is_check()
                         && file_features.supports_for(&FeatureName::Format),
+                    only: mode.rule_selection().0.to_vec(),
+                    skip: mode.rule_selection().1.to_vec(),
+                    unsafe_rules: mode.unsafe_rules().to_vec(),
+                    cancellation: CancellationToken::default(),
                 })?;
                 if fix_file_result.code != new_content {
                     version += 1;
@@ -102,11 +131,14 @@ pub(crate) fn run<'a>(
             }
         }
 
-        if !mode.is_check_apply_unsafe() {
+        if !mode.is_check_apply_unsafe() && file_features.supports_for(&FeatureName::Lint) {
             let result = workspace.pull_diagnostics(PullDiagnosticsParams {
                 categories: RuleCategories::LINT | RuleCategories::SYNTAX,
                 path: rome_path.clone(),
-                max_diagnostics: mode.max_diagnostics.into(),
+                max_diagnostics: mode.max_diagnostics as u64,
+                only: mode.rule_selection().0.to_vec(),
+                skip: mode.rule_selection().1.to_vec(),
+                cancellation: CancellationToken::default(),
             })?;
             diagnostics.extend(result.diagnostics);
         }
@@ -125,6 +157,7 @@ pub(crate) fn run<'a>(
                     diff: ContentDiffAdvice {
                         new: printed.as_code().to_string(),
                         old: content.to_string(),
+                        context_lines: mode.diff_options().context_lines,
                     },
                 };
                 diagnostics.push(biome_diagnostics::serde::Diagnostic::new(diagnostic));
@@ -143,10 +176,22 @@ pub(crate) fn run<'a>(
                 });
             }
         }
-        if !diagnostics.is_empty() {
-            for diag in diagnostics {
+        // The content is printed to stdout, so the diagnostics are printed to stderr
+        for diagnostic in diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= cli_options.diagnostic_level)
+        {
+            if cli_options.output_format == Some(OutputFormat::Compact) {
+                console.error(markup! {
+                    {CompactDiagnostic::new(diagnostic)}
+                })
+            } else if cli_options.verbose {
+                console.error(markup! {
+                    {PrintDiagnostic::verbose(diagnostic)}
+                })
+            } else {
                 console.error(markup! {
-                    {PrintDiagnostic::simple(&diag)}
+                    {PrintDiagnostic::simple(diagnostic)}
                 })
             }
         }
@@ -1,9 +1,14 @@
-use super::process_file::{process_file, DiffKind, FileStatus, Message};
-use crate::cli_options::CliOptions;
+use super::process_file::{process_file, DiffKind, FileStatus, FixDecisions, Message};
+use crate::cli_options::{CliOptions, GroupBy, OutputFormat};
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
-    FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    FilesTooLargeAdvice, FilesTooLargeDiagnostic, FormatDiffDiagnostic,
+    OrganizeImportsDiffDiagnostic, PanicDiagnostic,
 };
+use crate::execute::progress::{ProgressIndicator, PROGRESS_INTERVAL};
+use crate::execute::ReportMode;
+use crate::reports::compact::CompactDiagnostic;
+use crate::reports::ReporterDiagnostic;
 use crate::{
     CliDiagnostic, CliSession, Execution, FormatterReportFileDetail, FormatterReportSummary,
     Report, ReportDiagnostic, ReportDiff, ReportErrorKind, ReportKind, TraversalMode,
@@ -14,14 +19,14 @@ use biome_diagnostics::{
     Resource, Severity,
 };
 use biome_fs::{FileSystem, PathInterner, RomePath};
-use biome_fs::{TraversalContext, TraversalScope};
+use biome_fs::{TraversalContext, TraversalPolicy, TraversalScope};
 use biome_service::workspace::{FeaturesBuilder, IsPathIgnoredParams};
 use biome_service::{
     workspace::{FeatureName, SupportsFeatureParams},
     Workspace, WorkspaceError,
 };
 use crossbeam::{
-    channel::{unbounded, Receiver, Sender},
+    channel::{bounded, Receiver, Sender},
     select,
 };
 use rustc_hash::FxHashSet;
@@ -31,13 +36,19 @@ use std::{
     panic::catch_unwind,
     path::{Path, PathBuf},
     sync::{
-        atomic::{AtomicU16, AtomicUsize, Ordering},
+        atomic::{AtomicUsize, Ordering},
         Once,
     },
     thread,
     time::{Duration, Instant},
 };
 
+/// The maximum number of messages and reports waiting to be handled by the console thread.
+///
+/// When the limit is reached, the workers wait before sending new ones, so the content of the
+/// files that emitted diagnostics doesn't pile up in memory when traversing large repositories.
+const MAX_IN_FLIGHT_MESSAGES: usize = 512;
+
 struct CheckResult {
     count: usize,
     duration: Duration,
@@ -54,13 +65,82 @@ impl fmt::Display for CheckResult {
     }
 }
 
-///
+/// Traverses the inputs, prints the diagnostics and returns an error if the traversal emitted
+/// error diagnostics
 pub(crate) fn traverse(
-    execution: Execution,
-    session: CliSession,
+    execution: &Execution,
+    session: &mut CliSession,
     cli_options: &CliOptions,
     inputs: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
+    let summary = run_traversal(execution, session, cli_options, inputs)?;
+
+    if execution.should_report_to_terminal() || execution.should_report_to_backend() {
+        summary.into_result(execution, cli_options)
+    } else {
+        Ok(())
+    }
+}
+
+/// Information collected during a traversal
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct TraversalSummary {
+    /// The number of processed files
+    pub(crate) count: usize,
+    /// The number of files that couldn't be processed
+    pub(crate) skipped: usize,
+    /// The number of error diagnostics
+    pub(crate) errors: usize,
+    /// The number of warning diagnostics
+    pub(crate) warnings: usize,
+}
+
+impl TraversalSummary {
+    /// Returns an error if the traversal didn't process any file, or if it emitted error
+    /// diagnostics (or warnings, when `--error-on-warnings` is passed)
+    pub(crate) fn into_result(
+        self,
+        execution: &Execution,
+        cli_options: &CliOptions,
+    ) -> Result<(), CliDiagnostic> {
+        let TraversalSummary {
+            count,
+            skipped,
+            errors,
+            warnings,
+        } = self;
+
+        let should_exit_on_warnings = warnings > 0 && cli_options.error_on_warnings;
+        // Processing emitted error diagnostics, exit with a non-zero code
+        if count.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
+            Err(CliDiagnostic::no_files_processed())
+        } else if errors > 0 {
+            let category = execution.as_diagnostic_category();
+            if execution.is_check_apply() {
+                Err(CliDiagnostic::apply_error(category))
+            } else {
+                Err(CliDiagnostic::check_error(category))
+            }
+        } else if should_exit_on_warnings {
+            let category = execution.as_diagnostic_category();
+            if execution.is_check_apply() {
+                Err(CliDiagnostic::apply_warnings(category))
+            } else {
+                Err(CliDiagnostic::check_warnings(category))
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Traverses the inputs and prints the diagnostics, without checking the outcome
+pub(crate) fn run_traversal(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    inputs: Vec<OsString>,
+) -> Result<TraversalSummary, CliDiagnostic> {
     init_thread_pool();
     if inputs.is_empty() && execution.as_stdin_file().is_none() {
         return Err(CliDiagnostic::missing_argument(
@@ -70,8 +150,8 @@ pub(crate) fn traverse(
     }
 
     let (interner, recv_files) = PathInterner::new();
-    let (send_msgs, recv_msgs) = unbounded();
-    let (sender_reports, recv_reports) = unbounded();
+    let (send_msgs, recv_msgs) = bounded(MAX_IN_FLIGHT_MESSAGES);
+    let (sender_reports, recv_reports) = bounded(MAX_IN_FLIGHT_MESSAGES);
 
     let processed = AtomicUsize::new(0);
     let skipped = AtomicUsize::new(0);
@@ -81,7 +161,7 @@ pub(crate) fn traverse(
     let console = &mut *session.app.console;
 
     let max_diagnostics = execution.get_max_diagnostics();
-    let remaining_diagnostics = AtomicU16::new(max_diagnostics);
+    let remaining_diagnostics = AtomicUsize::new(max_diagnostics);
 
     let mut errors: usize = 0;
     let mut warnings: usize = 0;
@@ -92,7 +172,7 @@ pub(crate) fn traverse(
             .name(String::from("biome::console"))
             .spawn_scoped(s, || {
                 process_messages(ProcessMessagesOptions {
-                    execution: &execution,
+                    execution,
                     console,
                     recv_reports,
                     recv_files,
@@ -104,6 +184,14 @@ pub(crate) fn traverse(
                     verbose: cli_options.verbose,
                     warnings: &mut warnings,
                     diagnostic_level: &cli_options.diagnostic_level,
+                    processed: &processed,
+                    progress: cli_options.progress,
+                    group_by: cli_options.group_by,
+                    output_format: cli_options.output_format.unwrap_or_default(),
+                    working_directory: cli_options
+                        .relative_paths
+                        .then(|| fs.working_directory())
+                        .flatten(),
                 });
             })
             .expect("failed to spawn console thread");
@@ -116,7 +204,7 @@ pub(crate) fn traverse(
             &TraversalOptions {
                 fs,
                 workspace,
-                execution: &execution,
+                execution,
                 interner,
                 processed: &processed,
                 skipped: &skipped,
@@ -127,6 +215,16 @@ pub(crate) fn traverse(
         )
     });
 
+    if let Some(cache) = execution.cache() {
+        if let Err(err) = cache.save(fs) {
+            tracing::warn!("Failed to save the cache: {err}");
+        }
+    }
+
+    if let Some(baseline) = execution.baseline() {
+        baseline.save(fs).map_err(CliDiagnostic::io_error)?;
+    }
+
     let count = processed.load(Ordering::Relaxed);
     let skipped = skipped.load(Ordering::Relaxed);
 
@@ -148,6 +246,15 @@ pub(crate) fn traverse(
                 }
             }
             TraversalMode::CI { .. } => {
+                let summary = report
+                    .as_reporter_output(ReportMode::Summary)
+                    .filter(|_| report.has_reporter_diagnostics());
+                if let Some(summary) = summary {
+                    let summary = summary?;
+                    console.log(markup! {
+                        {summary.trim_end()}
+                    });
+                }
                 console.log(markup!({
                     CheckResult {
                         count,
@@ -178,7 +285,18 @@ pub(crate) fn traverse(
                     <Info>"Migrated your configuration file in "{duration}</Info>
                 });
             }
+
+            TraversalMode::Search { .. } => {
+                console.log(markup! {
+                    <Info>"Searched "{count}" file(s) in "{duration}</Info>
+                });
+            }
         }
+    } else if let Some(output) = report.as_reporter_output(execution.report_mode()) {
+        let output = output?;
+        console.log(markup! {
+            {output}
+        });
     } else {
         if let TraversalMode::Format { write, .. } = execution.traversal_mode() {
             let mut summary = FormatterReportSummary::default();
@@ -194,35 +312,36 @@ pub(crate) fn traverse(
         console.log(markup! {
             {to_print}
         });
-        return Ok(());
     }
 
-    if skipped > 0 {
+    if skipped > 0 && execution.should_report_to_terminal() {
         console.log(markup! {
             <Warn>"Skipped "{skipped}" file(s)"</Warn>
         });
     }
 
-    let should_exit_on_warnings = warnings > 0 && cli_options.error_on_warnings;
-    // Processing emitted error diagnostics, exit with a non-zero code
-    if count.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
-        Err(CliDiagnostic::no_files_processed())
-    } else if errors > 0 || should_exit_on_warnings {
-        let category = execution.as_diagnostic_category();
-        if should_exit_on_warnings {
-            if execution.is_check_apply() {
-                Err(CliDiagnostic::apply_warnings(category))
-            } else {
-                Err(CliDiagnostic::check_warnings(category))
-            }
-        } else if execution.is_check_apply() {
-            Err(CliDiagnostic::apply_error(category))
-        } else {
-            Err(CliDiagnostic::check_error(category))
+    let baseline = execution
+        .baseline()
+        .filter(|_| execution.should_report_to_terminal());
+    if let Some(baseline) = baseline {
+        let suppressed = baseline.suppressed();
+        if baseline.is_update() {
+            console.log(markup! {
+                <Info>"Recorded "{suppressed}" diagnostic(s) in the baseline"</Info>
+            });
+        } else if suppressed > 0 {
+            console.log(markup! {
+                <Info>"Suppressed "{suppressed}" diagnostic(s) recorded in the baseline"</Info>
+            });
         }
-    } else {
-        Ok(())
     }
+
+    Ok(TraversalSummary {
+        count,
+        skipped,
+        errors,
+        warnings,
+    })
 }
 
 /// This function will setup the global Rayon thread pool the first time it's called
@@ -264,10 +383,10 @@ struct ProcessMessagesOptions<'ctx> {
     /// Receiver channel that expects info when a message is sent
     recv_msgs: Receiver<Message>,
     /// The maximum number of diagnostics the console thread is allowed to print
-    max_diagnostics: u16,
+    max_diagnostics: usize,
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
-    remaining_diagnostics: &'ctx AtomicU16,
+    remaining_diagnostics: &'ctx AtomicUsize,
     /// Mutable reference to a boolean flag tracking whether the console thread
     /// printed any error-level message
     errors: &'ctx mut usize,
@@ -281,6 +400,16 @@ struct ProcessMessagesOptions<'ctx> {
     verbose: bool,
     /// The diagnostic level the console thread should print
     diagnostic_level: &'ctx Severity,
+    /// Shared atomic counter storing the number of processed files
+    processed: &'ctx AtomicUsize,
+    /// Whether the console thread should print the progress of the traversal
+    progress: bool,
+    /// How the diagnostics printed in the terminal are grouped
+    group_by: Option<GroupBy>,
+    /// How the diagnostics are printed in the terminal
+    output_format: OutputFormat,
+    /// When set, the paths of the files are printed relative to this directory
+    working_directory: Option<PathBuf>,
 }
 
 /// This thread receives [Message]s from the workers through the `recv_msgs`
@@ -299,17 +428,30 @@ fn process_messages(options: ProcessMessagesOptions) {
         verbose,
         warnings,
         diagnostic_level,
+        processed,
+        progress,
+        group_by,
+        output_format,
+        working_directory,
     } = options;
 
+    let mut progress =
+        ProgressIndicator::new(progress && mode.should_report_to_terminal(), console);
+
     let mut paths: FxHashSet<String> = FxHashSet::default();
-    let mut printed_diagnostics: u16 = 0;
+    let mut printed_diagnostics: usize = 0;
     let mut not_printed_diagnostics = 0;
     let mut total_skipped_suggested_fixes = 0;
+    let mut fix_decisions = FixDecisions::default();
+    let context_lines = mode.diff_options().context_lines;
+    // Files above the size limit, listed together once the traversal is done
+    let mut files_too_large: Vec<String> = vec![];
 
     let mut is_msg_open = true;
     let mut is_report_open = true;
     let mut diagnostics_to_print = vec![];
     while is_msg_open || is_report_open {
+        progress.tick(console, processed.load(Ordering::Relaxed));
         let msg = select! {
             recv(recv_msgs) -> msg => match msg {
                 Ok(msg) => msg,
@@ -329,6 +471,7 @@ fn process_messages(options: ProcessMessagesOptions) {
                 }
                 continue;
             }
+            default(PROGRESS_INTERVAL) => continue,
         };
 
         match msg {
@@ -338,6 +481,12 @@ fn process_messages(options: ProcessMessagesOptions) {
                 total_skipped_suggested_fixes += skipped_suggested_fixes;
             }
 
+            Message::FixReview(review) => {
+                // The prompt must start from an empty line
+                progress.finish(console);
+                fix_decisions.review(console, review, verbose);
+            }
+
             Message::ApplyError(error) => {
                 *errors += 1;
                 let should_print = printed_diagnostics < max_diagnostics;
@@ -352,6 +501,21 @@ fn process_messages(options: ProcessMessagesOptions) {
                 }
                 if mode.should_report_to_terminal() && should_print {
                     diagnostics_to_print.push(Error::from(error));
+                } else if mode.should_report_to_backend() {
+                    let error = Error::from(error);
+                    report.push_reporter_diagnostic(ReporterDiagnostic::new(&error, None, None));
+                }
+            }
+
+            Message::Error(err)
+                if err.category() == Some(category!("files/tooLarge"))
+                    && (mode.should_report_to_terminal() || mode.should_report_to_backend()) =>
+            {
+                // The same file is reported by every feature that tried to process it
+                if let Some(Resource::File(file_path)) = err.location().resource {
+                    if !files_too_large.iter().any(|file| file == file_path) {
+                        files_too_large.push(file_path.to_string());
+                    }
                 }
             }
 
@@ -401,6 +565,8 @@ fn process_messages(options: ProcessMessagesOptions) {
                     if should_print {
                         diagnostics_to_print.push(err);
                     }
+                } else if mode.should_report_to_backend() {
+                    report.push_reporter_diagnostic(ReporterDiagnostic::new(&err, None, None));
                 } else {
                     let location = err.location();
                     let path = match &location.resource {
@@ -442,8 +608,16 @@ fn process_messages(options: ProcessMessagesOptions) {
                             *warnings += 1;
                         }
 
-                        let diag = diag.with_file_path(&name).with_file_source_code(&content);
-                        diagnostics_to_print.push(diag);
+                        if mode.should_report_to_backend() {
+                            report.push_reporter_diagnostic(ReporterDiagnostic::new(
+                                &diag,
+                                Some(&name),
+                                Some(&content),
+                            ));
+                        } else {
+                            let diag = diag.with_file_path(&name).with_file_source_code(&content);
+                            diagnostics_to_print.push(diag);
+                        }
                     }
                 } else {
                     for diag in diagnostics {
@@ -472,6 +646,12 @@ fn process_messages(options: ProcessMessagesOptions) {
                                     diag.with_file_path(&name).with_file_source_code(&content);
                                 diagnostics_to_print.push(diag)
                             }
+                        } else if mode.should_report_to_backend() {
+                            report.push_reporter_diagnostic(ReporterDiagnostic::new(
+                                &diag,
+                                Some(&name),
+                                Some(&content),
+                            ));
                         } else {
                             report.push_detail_report(ReportKind::Error(
                                 name.to_string(),
@@ -517,6 +697,7 @@ fn process_messages(options: ProcessMessagesOptions) {
                                         diff: ContentDiffAdvice {
                                             old: old.clone(),
                                             new: new.clone(),
+                                            context_lines,
                                         },
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
@@ -527,6 +708,7 @@ fn process_messages(options: ProcessMessagesOptions) {
                                         diff: ContentDiffAdvice {
                                             old: old.clone(),
                                             new: new.clone(),
+                                            context_lines,
                                         },
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
@@ -540,6 +722,7 @@ fn process_messages(options: ProcessMessagesOptions) {
                                         diff: ContentDiffAdvice {
                                             old: old.clone(),
                                             new: new.clone(),
+                                            context_lines,
                                         },
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
@@ -550,6 +733,7 @@ fn process_messages(options: ProcessMessagesOptions) {
                                         diff: ContentDiffAdvice {
                                             old: old.clone(),
                                             new: new.clone(),
+                                            context_lines,
                                         },
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
@@ -557,6 +741,29 @@ fn process_messages(options: ProcessMessagesOptions) {
                             };
                         }
                     }
+                } else if mode.should_report_to_backend() {
+                    let diff = ContentDiffAdvice {
+                        old: old.clone(),
+                        new: new.clone(),
+                        context_lines,
+                    };
+                    let diag = match diff_kind {
+                        DiffKind::Format => Error::from(FormatDiffDiagnostic {
+                            file_name: file_name.clone(),
+                            diff,
+                        }),
+                        DiffKind::OrganizeImports => Error::from(OrganizeImportsDiffDiagnostic {
+                            file_name: file_name.clone(),
+                            diff,
+                        }),
+                    };
+                    report.push_reporter_diagnostic(
+                        ReporterDiagnostic::new(&diag, Some(&file_name), None).with_diff(
+                            &old,
+                            &new,
+                            context_lines,
+                        ),
+                    );
                 } else {
                     report.push_detail_report(ReportKind::Error(
                         file_name,
@@ -570,23 +777,85 @@ fn process_messages(options: ProcessMessagesOptions) {
             }
         }
     }
+    progress.finish(console);
+
+    if !files_too_large.is_empty() {
+        let diagnostic = Error::from(FilesTooLargeDiagnostic {
+            files: FilesTooLargeAdvice {
+                file_names: files_too_large,
+            },
+        });
+        if mode.should_report_to_terminal() {
+            diagnostics_to_print.push(diagnostic);
+        } else {
+            report.push_reporter_diagnostic(ReporterDiagnostic::new(&diagnostic, None, None));
+        }
+    }
+
+    let mut diagnostics_to_print: Vec<_> = diagnostics_to_print
+        .into_iter()
+        .filter(|diagnostic| diagnostic.severity() >= *diagnostic_level)
+        .map(|diagnostic| match &working_directory {
+            Some(working_directory) => relative_to(diagnostic, working_directory),
+            None => diagnostic,
+        })
+        .collect();
+
+    if mode.should_print_summary() {
+        for diagnostic in &diagnostics_to_print {
+            report.push_reporter_diagnostic(ReporterDiagnostic::new(diagnostic, None, None));
+        }
+    }
+
+    // The diagnostics without a file or a rule are printed last, without a header
+    if let Some(group_by) = group_by {
+        diagnostics_to_print.sort_by_cached_key(|diagnostic| {
+            let key = group_key(diagnostic, group_by);
+            (key.is_empty(), key)
+        });
+    }
+
+    let mut current_group = None;
+    for (index, diagnostic) in diagnostics_to_print.iter().enumerate() {
+        if let Some(group_by) = group_by {
+            let key = group_key(diagnostic, group_by);
+            if !key.is_empty() && current_group.as_ref() != Some(&key) {
+                let count = diagnostics_to_print[index..]
+                    .iter()
+                    .take_while(|diagnostic| group_key(diagnostic, group_by) == key)
+                    .count();
+                console.error(markup! {
+                    <Emphasis>{key}</Emphasis>" ("{count}" diagnostic(s))"
+                });
+                current_group = Some(key);
+            }
+        }
 
-    for diagnostic in diagnostics_to_print {
-        if diagnostic.severity() >= *diagnostic_level {
+        if output_format == OutputFormat::Compact {
             console.error(markup! {
-                {if verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
+                {CompactDiagnostic::new(diagnostic)}
             });
+            continue;
         }
+
+        let diagnostic = if verbose {
+            PrintDiagnostic::verbose(diagnostic)
+        } else {
+            PrintDiagnostic::simple(diagnostic)
+        };
+        console.error(markup! {
+            {diagnostic.with_diff_options(mode.diff_options())}
+        });
     }
 
-    if mode.is_check() && total_skipped_suggested_fixes > 0 {
+    if mode.is_check() && mode.should_report_to_terminal() && total_skipped_suggested_fixes > 0 {
         console.log(markup! {
             <Warn>"Skipped "{total_skipped_suggested_fixes}" suggested fixes.\n"</Warn>
             <Info>"If you wish to apply the suggested (unsafe) fixes, use the command "<Emphasis>"biome check --apply-unsafe\n"</Emphasis></Info>
         })
     }
 
-    if !mode.is_ci() && not_printed_diagnostics > 0 {
+    if !mode.is_ci() && mode.should_report_to_terminal() && not_printed_diagnostics > 0 {
         console.log(markup! {
             <Warn>"The number of diagnostics exceeds the number allowed by Biome.\n"</Warn>
             <Info>"Diagnostics not shown: "</Info><Emphasis>{not_printed_diagnostics}</Emphasis><Info>"."</Info>
@@ -594,6 +863,36 @@ fn process_messages(options: ProcessMessagesOptions) {
     }
 }
 
+/// The name of the group of a diagnostic printed with `--group-by`
+fn group_key(diagnostic: &Error, group_by: GroupBy) -> String {
+    match group_by {
+        GroupBy::File => match diagnostic.location().resource {
+            Some(Resource::File(file)) => file.to_string(),
+            _ => String::new(),
+        },
+        GroupBy::Rule => diagnostic
+            .category()
+            .map(|category| category.name().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// Replaces the path of the file of the diagnostic with a path relative to `working_directory`
+fn relative_to(diagnostic: Error, working_directory: &Path) -> Error {
+    let relative_path = match diagnostic.location().resource {
+        Some(Resource::File(file)) => Path::new(file)
+            .strip_prefix(working_directory)
+            .ok()
+            .map(|path| path.display().to_string()),
+        _ => None,
+    };
+
+    match relative_path {
+        Some(path) => diagnostic.with_file_path(path.as_str()),
+        None => diagnostic,
+    }
+}
+
 /// Context object shared between directory traversal tasks
 pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// Shared instance of [FileSystem]
@@ -614,7 +913,7 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     sender_reports: Sender<ReportKind>,
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
-    pub(crate) remaining_diagnostics: &'ctx AtomicU16,
+    pub(crate) remaining_diagnostics: &'ctx AtomicUsize,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -624,7 +923,20 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
 
     /// Send a message to the display thread
     pub(crate) fn push_message(&self, msg: impl Into<Message>) {
-        self.messages.send(msg.into()).ok();
+        let msg = msg.into();
+        if let Some(cache) = self.execution.cache() {
+            match &msg {
+                Message::Diagnostics { name, .. } => cache.mark_dirty(name.as_str()),
+                Message::Diff { file_name, .. } => cache.mark_dirty(file_name.as_str()),
+                Message::Error(error) => {
+                    if let Some(Resource::File(file_path)) = error.location().resource {
+                        cache.mark_dirty(file_path);
+                    }
+                }
+                Message::SkippedFixes { .. } | Message::ApplyError(_) | Message::FixReview(_) => {}
+            }
+        }
+        self.messages.send(msg).ok();
     }
 
     pub(crate) fn push_format_stat(&self, path: String, stat: FormatterReportFileDetail) {
@@ -633,6 +945,38 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
             .ok();
     }
 
+    pub(crate) fn push_file_duration(&self, path: String, duration: Duration) {
+        self.sender_reports
+            .send(ReportKind::Duration(path, duration))
+            .ok();
+    }
+
+    /// Registers the nested configuration files of `directory` and of its parents, before the
+    /// files beneath `directory` are handled
+    fn discover_nested_configurations(&self, directory: &Path) {
+        let Some(nested_configurations) = self.execution.nested_configurations() else {
+            return;
+        };
+        match nested_configurations.discover(self.fs, self.workspace, directory) {
+            Ok(diagnostics) => {
+                for diagnostic in diagnostics {
+                    self.push_diagnostic(diagnostic);
+                }
+            }
+            Err(err) => self.push_diagnostic(err.into()),
+        }
+    }
+
+    /// The fingerprint of the nested configuration files and the `.editorconfig` files that
+    /// apply to the file at `path`
+    fn nested_fingerprint(&self, path: &Path) -> u64 {
+        self.execution
+            .nested_configurations()
+            .map_or(0, |nested_configurations| {
+                nested_configurations.fingerprint(path.parent().unwrap_or(Path::new("")))
+            })
+    }
+
     pub(crate) fn miss_handler_err(&self, err: WorkspaceError, rome_path: &RomePath) {
         self.push_diagnostic(
             StdError::from(err)
@@ -652,6 +996,10 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     }
 
     fn can_handle(&self, rome_path: &RomePath) -> bool {
+        // The ignore files of the parent directories decide whether the path is ignored, and the
+        // paths passed as arguments aren't reached through their parents
+        self.discover_nested_configurations(rome_path.parent().unwrap_or(Path::new("")));
+
         if rome_path.is_dir() {
             let can_handle = !self
                 .workspace
@@ -663,6 +1011,9 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
                     self.push_diagnostic(err.into());
                     false
                 });
+            if can_handle {
+                self.discover_nested_configurations(rome_path);
+            }
             return can_handle;
         }
 
@@ -672,6 +1023,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
                 .with_linter()
                 .with_formatter()
                 .with_organize_imports()
+                .with_search()
                 .build(),
         });
 
@@ -697,6 +1049,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
             }
             TraversalMode::Format { .. } => file_features.supports_for(&FeatureName::Format),
             TraversalMode::Lint { .. } => file_features.supports_for(&FeatureName::Lint),
+            TraversalMode::Search { .. } => file_features.supports_for(&FeatureName::Search),
             // Imagine if Biome can't handle its own configuration file...
             TraversalMode::Migrate { .. } => true,
         }
@@ -705,14 +1058,35 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     fn handle_file(&self, path: &Path) {
         handle_file(self, path)
     }
+
+    fn traversal_policy(&self) -> TraversalPolicy {
+        self.execution.traversal_policy()
+    }
 }
 
 /// This function wraps the [process_file] function implementing the traversal
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
 fn handle_file(ctx: &TraversalOptions, path: &Path) {
-    match catch_unwind(move || process_file(ctx, path)) {
-        Ok(Ok(FileStatus::Success)) => {}
+    let cache = ctx.execution.cache();
+    let nested_fingerprint = ctx.nested_fingerprint(path);
+    if cache.is_some_and(|cache| cache.is_fresh(ctx.fs, path, nested_fingerprint)) {
+        ctx.increment_processed();
+        return;
+    }
+
+    let start = Instant::now();
+    let result = catch_unwind(move || process_file(ctx, path));
+    if ctx.execution.should_track_durations() {
+        ctx.push_file_duration(path.display().to_string(), start.elapsed());
+    }
+
+    match result {
+        Ok(Ok(FileStatus::Success)) => {
+            if let Some(cache) = cache {
+                cache.track(ctx.fs, path, nested_fingerprint);
+            }
+        }
         Ok(Ok(FileStatus::Message(msg))) => {
             ctx.push_message(msg);
         }
@@ -0,0 +1,251 @@
+//! Implementation of the watch mode: the files are traversed once, then Biome waits for
+//! changes and traverses only the files that changed.
+use crate::cli_options::CliOptions;
+use crate::execute::traverse::{run_traversal, TraversalSummary};
+use crate::execute::Execution;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_service::workspace::cache_directory;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use rustc_hash::FxHashSet;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::time::Duration;
+
+/// Editors and tools usually emit many events when saving a file, they are grouped together
+/// when they are emitted within this delay
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(50);
+
+pub(crate) fn run(
+    execution: &Execution,
+    session: CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let (sender, receiver) = channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+    for path in &paths {
+        watcher
+            .watch(Path::new(path), RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+    }
+
+    let ignored_paths = ignored_paths(execution, &session);
+    watch_loop(
+        execution,
+        session,
+        cli_options,
+        paths,
+        &receiver,
+        &ignored_paths,
+    )
+}
+
+/// Runs the first traversal, then traverses the files that changed until the watcher stops
+fn watch_loop(
+    execution: &Execution,
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+    receiver: &Receiver<notify::Result<Event>>,
+    ignored_paths: &[PathBuf],
+) -> Result<(), CliDiagnostic> {
+    // The session, and so the workspace, is kept alive between the runs: the files that
+    // didn't change aren't parsed again
+    run_once(execution, &mut session, cli_options, paths)?;
+
+    while let Some(changed_paths) = wait_for_changes(receiver) {
+        let inputs: Vec<OsString> = changed_paths
+            .into_iter()
+            .filter(|path| path.is_file())
+            .filter(|path| {
+                !ignored_paths
+                    .iter()
+                    .any(|ignored_path| path.starts_with(ignored_path))
+            })
+            .map(OsString::from)
+            .collect();
+
+        if !inputs.is_empty() {
+            run_once(execution, &mut session, cli_options, inputs)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the paths that Biome writes at the end of each run: the cache of the results, the
+/// baseline and the directory of the cache of the diagnostics, when it's enabled. Their
+/// changes must not trigger another run, otherwise each run would trigger the next one.
+fn ignored_paths(execution: &Execution, session: &CliSession) -> Vec<PathBuf> {
+    let working_directory = session.app.fs.working_directory().unwrap_or_default();
+    let mut ignored_paths: Vec<_> = cache_directory().into_iter().collect();
+    if let Some(cache) = execution.cache() {
+        ignored_paths.push(working_directory.join(cache.location()));
+    }
+    if let Some(baseline) = execution.baseline() {
+        ignored_paths.push(working_directory.join(baseline.location()));
+    }
+    ignored_paths
+}
+
+/// Traverses the inputs and prints a summary of the current errors. Error diagnostics
+/// emitted by the traversal don't stop the watcher.
+fn run_once(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    inputs: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let TraversalSummary {
+        count,
+        errors,
+        warnings,
+        ..
+    } = run_traversal(execution, session, cli_options, inputs)?;
+
+    session.app.console.log(markup! {
+        <Info>"Watching for changes. Last run: "{count}" file(s), "</Info>
+        <Error>{errors}" error(s)"</Error><Info>", "</Info>
+        <Warn>{warnings}" warning(s)"</Warn><Info>"."</Info>
+    });
+
+    Ok(())
+}
+
+/// Blocks until some files change, and returns their paths. It returns [None] when the
+/// watcher stopped.
+fn wait_for_changes(receiver: &Receiver<notify::Result<Event>>) -> Option<FxHashSet<PathBuf>> {
+    let mut changed_paths = FxHashSet::default();
+
+    let event = receiver.recv().ok()?;
+    collect_paths(event, &mut changed_paths);
+    while let Ok(event) = receiver.recv_timeout(DEBOUNCE_DELAY) {
+        collect_paths(event, &mut changed_paths);
+    }
+
+    Some(changed_paths)
+}
+
+fn collect_paths(event: notify::Result<Event>, changed_paths: &mut FxHashSet<PathBuf>) {
+    match event {
+        Ok(event) => {
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                changed_paths.extend(event.paths);
+            }
+        }
+        Err(error) => {
+            tracing::warn!("Failed to watch the file system: {error}");
+        }
+    }
+}
+
+fn watch_error(error: notify::Error) -> CliDiagnostic {
+    CliDiagnostic::io_error(io::Error::new(io::ErrorKind::Other, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ignored_paths, watch_loop};
+    use crate::cli_options::cli_options;
+    use crate::execute::cache::ResultsCache;
+    use crate::execute::{Execution, TraversalMode};
+    use crate::CliSession;
+    use biome_console::{BufferConsole, MarkupBuf};
+    use biome_fs::OsFileSystem;
+    use biome_service::{workspace, App, DynRef, WorkspaceRef};
+    use bpaf::{Args, Parser};
+    use notify::event::ModifyKind;
+    use notify::{Event, EventKind};
+    use std::ffi::OsString;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::channel;
+
+    /// Runs `check --watch --cache` on a file of a new directory, with the events returned by
+    /// `events`, and returns how many times the files were checked
+    fn count_runs(name: &str, events: impl Fn(&Path, &Path) -> Vec<PathBuf>) -> usize {
+        let directory = std::env::temp_dir().join(format!("biome_{name}_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let file_path = directory.join("file.js");
+        fs::write(&file_path, "statement();\n").unwrap();
+        let cache_path = directory.join(".biomecache");
+
+        let cli_options = cli_options()
+            .to_options()
+            .run_inner(Args::from(
+                ["--cache", "--cache-location", cache_path.to_str().unwrap()].as_slice(),
+            ))
+            .unwrap();
+        let mut execution = Execution::new(TraversalMode::Check {
+            fix_file_mode: None,
+            stdin: None,
+            interactive: false,
+            unsafe_rules: vec![],
+        });
+        execution.cache = Some(ResultsCache::load(
+            &OsFileSystem,
+            cache_path.clone(),
+            "",
+            &execution.traversal_mode,
+        ));
+
+        let mut console = BufferConsole::default();
+        let session = CliSession {
+            app: App::new(
+                DynRef::Owned(Box::new(OsFileSystem)),
+                &mut console,
+                WorkspaceRef::Owned(workspace::server()),
+            ),
+        };
+        let ignored_paths = ignored_paths(&execution, &session);
+
+        // The watcher stops once all the events are received
+        let (sender, receiver) = channel();
+        for path in events(&file_path, &cache_path) {
+            let event = Event::new(EventKind::Modify(ModifyKind::Any)).add_path(path);
+            sender.send(Ok(event)).unwrap();
+        }
+        drop(sender);
+
+        let result = watch_loop(
+            &execution,
+            session,
+            &cli_options,
+            vec![OsString::from(&file_path)],
+            &receiver,
+            &ignored_paths,
+        );
+        fs::remove_dir_all(&directory).unwrap();
+        assert!(result.is_ok(), "watch_loop returned {result:?}");
+
+        console
+            .out_buffer
+            .iter()
+            .filter(|message| {
+                let MarkupBuf(nodes) = &message.content;
+                nodes
+                    .iter()
+                    .any(|node| node.content.contains("Watching for changes"))
+            })
+            .count()
+    }
+
+    #[test]
+    fn checks_again_the_changed_files() {
+        let runs = count_runs("watch_changed_file", |file_path, _| {
+            vec![file_path.to_path_buf()]
+        });
+        assert_eq!(runs, 2);
+    }
+
+    #[test]
+    fn doesnt_check_again_when_the_cache_is_written() {
+        let runs = count_runs("watch_cache_file", |_, cache_path| {
+            vec![cache_path.to_path_buf()]
+        });
+        assert_eq!(runs, 1);
+    }
+}
@@ -11,6 +11,7 @@ use biome_fs::OsFileSystem;
 use biome_service::{App, DynRef, Workspace, WorkspaceRef};
 use std::env;
 
+mod changed;
 mod cli_options;
 mod commands;
 mod configuration;
@@ -26,12 +27,14 @@ mod vcs;
 use crate::cli_options::ColorsArg;
 use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
+use crate::commands::compare_prettier::ComparePrettierCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
+use crate::commands::search::SearchCommandPayload;
 pub use crate::commands::{biome_command, BiomeCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
-pub(crate) use execute::{execute_mode, Execution, TraversalMode};
+pub(crate) use execute::{execute_mode, Execution, StagedFiles, TraversalMode};
 pub use panic::setup_panic_handler;
 pub use reports::{
     formatter::{FormatterReport, FormatterReportFileDetail, FormatterReportSummary},
@@ -73,12 +76,18 @@ impl<'app> CliSession<'app> {
 
         let result = match command {
             BiomeCommand::Version(_) => commands::version::full_version(self),
-            BiomeCommand::Rage(_, daemon_logs) => commands::rage::rage(self, daemon_logs),
+            BiomeCommand::Rage(cli_options, daemon_logs, paths) => {
+                commands::rage::rage(self, &cli_options, daemon_logs, paths)
+            }
             BiomeCommand::Start(config_path) => commands::daemon::start(self, config_path),
             BiomeCommand::Stop => commands::daemon::stop(self),
+            BiomeCommand::Clean => commands::clean::clean(self),
             BiomeCommand::Check {
                 apply,
                 apply_unsafe,
+                write,
+                unsafe_only,
+                interactive,
                 cli_options,
                 configuration: rome_configuration,
                 paths,
@@ -86,15 +95,28 @@ impl<'app> CliSession<'app> {
                 linter_enabled,
                 organize_imports_enabled,
                 formatter_enabled,
+                staged,
+                changed,
+                since,
+                watch,
+                fix_preview,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
                     apply_unsafe,
                     apply,
+                    write,
+                    unsafe_only: unsafe_only.map(Vec::from).unwrap_or_default(),
+                    interactive,
                     cli_options,
                     configuration: rome_configuration,
                     paths,
                     stdin_file_path,
+                    staged,
+                    changed,
+                    since,
+                    watch,
+                    fix_preview,
                     linter_enabled,
                     organize_imports_enabled,
                     formatter_enabled,
@@ -103,29 +125,46 @@ impl<'app> CliSession<'app> {
             BiomeCommand::Lint {
                 apply,
                 apply_unsafe,
+                unsafe_only,
+                rule,
+                skip_rule,
                 cli_options,
                 linter_configuration,
                 paths,
                 stdin_file_path,
                 vcs_configuration,
                 files_configuration,
+                staged,
+                changed,
+                since,
+                fix_preview,
             } => commands::lint::lint(
                 self,
                 LintCommandPayload {
                     apply_unsafe,
                     apply,
+                    unsafe_only: unsafe_only.map(Vec::from).unwrap_or_default(),
+                    rule,
+                    skip_rule,
                     cli_options,
                     linter_configuration,
                     paths,
                     stdin_file_path,
                     vcs_configuration,
                     files_configuration,
+                    staged,
+                    changed,
+                    since,
+                    fix_preview,
                 },
             ),
             BiomeCommand::Ci {
                 linter_enabled,
                 formatter_enabled,
                 organize_imports_enabled,
+                changed,
+                since,
+                allow_warnings,
                 configuration: rome_configuration,
                 paths,
                 cli_options,
@@ -135,6 +174,9 @@ impl<'app> CliSession<'app> {
                     linter_enabled,
                     formatter_enabled,
                     organize_imports_enabled,
+                    changed,
+                    since,
+                    allow_warnings,
                     rome_configuration,
                     paths,
                     cli_options,
@@ -145,11 +187,17 @@ impl<'app> CliSession<'app> {
                 formatter_configuration,
                 stdin_file_path,
                 write,
+                diff,
+                diff_style,
+                diff_context,
                 cli_options,
                 paths,
                 vcs_configuration,
                 files_configuration,
                 json_formatter,
+                staged,
+                changed,
+                since,
             } => commands::format::format(
                 self,
                 FormatCommandPayload {
@@ -157,23 +205,81 @@ impl<'app> CliSession<'app> {
                     formatter_configuration,
                     stdin_file_path,
                     write,
+                    diff,
+                    diff_style,
+                    diff_context,
                     cli_options,
                     paths,
                     vcs_configuration,
                     files_configuration,
                     json_formatter,
+                    staged,
+                    changed,
+                    since,
+                },
+            ),
+            BiomeCommand::Init {
+                interactive,
+                emit_jsonc,
+            } => commands::init::init(self, interactive, emit_jsonc),
+            BiomeCommand::Explain {
+                cli_options,
+                doc,
+                path,
+            } => commands::explain::explain(self, cli_options, doc, path),
+            BiomeCommand::Completions { shell } => commands::completions::completions(self, shell),
+            BiomeCommand::HelpJson => commands::help_json::help_json(self),
+            BiomeCommand::Search {
+                vcs_configuration,
+                files_configuration,
+                cli_options,
+                pattern,
+                paths,
+            } => commands::search::search(
+                self,
+                SearchCommandPayload {
+                    vcs_configuration,
+                    files_configuration,
+                    cli_options,
+                    pattern,
+                    paths,
                 },
             ),
-            BiomeCommand::Init => commands::init::init(self),
             BiomeCommand::LspProxy(config_path) => commands::daemon::lsp_proxy(config_path),
-            BiomeCommand::Migrate(cli_options, write) => {
-                commands::migrate::migrate(self, cli_options, write)
+            BiomeCommand::Migrate(cli_options, write, new_rules, format_configuration) => {
+                commands::migrate::migrate(
+                    self,
+                    cli_options,
+                    write,
+                    new_rules,
+                    format_configuration,
+                )
             }
             BiomeCommand::RunServer {
                 stop_on_disconnect,
                 config_path,
-            } => commands::daemon::run_server(stop_on_disconnect, config_path),
+                source_budget,
+                metrics,
+            } => commands::daemon::run_server(
+                stop_on_disconnect,
+                config_path,
+                source_budget,
+                metrics,
+            ),
             BiomeCommand::PrintSocket => commands::daemon::print_socket(),
+            BiomeCommand::PrintMetrics => commands::daemon::print_metrics(self),
+            BiomeCommand::ComparePrettier {
+                cli_options,
+                prettier_path,
+                paths,
+            } => commands::compare_prettier::compare_prettier(
+                self,
+                ComparePrettierCommandPayload {
+                    cli_options,
+                    prettier_path,
+                    paths,
+                },
+            ),
         };
 
         if has_metrics {
@@ -63,7 +63,10 @@ fn run_workspace(console: &mut EnvConsole, command: BiomeCommand) -> Result<(),
             None => return Err(CliDiagnostic::server_not_running()),
         }
     } else {
-        workspace::server()
+        match workspace::cache_directory() {
+            Some(cache_directory) => workspace::server_with_cache(cache_directory),
+            None => workspace::server(),
+        }
     };
 
     let session = CliSession::new(&*workspace, console)?;
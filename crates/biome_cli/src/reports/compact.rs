@@ -0,0 +1,54 @@
+use crate::reports::ReporterDiagnostic;
+use biome_console::fmt::{Display, Formatter};
+use biome_console::markup;
+use biome_diagnostics::{Error, Severity};
+use std::io;
+
+/// Prints a diagnostic on a single line, as `file:line:column severity category message`.
+/// It's used when the diagnostics are printed with `--output-format=compact`.
+pub(crate) struct CompactDiagnostic(ReporterDiagnostic);
+
+impl CompactDiagnostic {
+    pub(crate) fn new(diagnostic: &Error) -> Self {
+        let source_code = diagnostic
+            .location()
+            .source_code
+            .map(|source_code| source_code.text);
+        Self(ReporterDiagnostic::new(diagnostic, None, source_code))
+    }
+}
+
+impl Display for CompactDiagnostic {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let diagnostic = &self.0;
+
+        if let Some(file_path) = &diagnostic.file_path {
+            match diagnostic.span {
+                Some(span) => {
+                    let (line, column) = (span.start.line, span.start.column);
+                    fmt.write_markup(markup! {
+                        <Emphasis>{file_path}":"{line}":"{column}</Emphasis>" "
+                    })?;
+                }
+                None => fmt.write_markup(markup! {
+                    <Emphasis>{file_path}</Emphasis>" "
+                })?,
+            }
+        }
+
+        let severity = diagnostic.severity.to_string();
+        match diagnostic.severity {
+            Severity::Error | Severity::Fatal => {
+                fmt.write_markup(markup! { <Error>{severity}</Error> })?
+            }
+            Severity::Warning => fmt.write_markup(markup! { <Warn>{severity}</Warn> })?,
+            Severity::Information | Severity::Hint => {
+                fmt.write_markup(markup! { <Info>{severity}</Info> })?
+            }
+        }
+
+        fmt.write_markup(markup! {
+            " "<Dim>{diagnostic.category_name()}</Dim>" "{diagnostic.message}
+        })
+    }
+}
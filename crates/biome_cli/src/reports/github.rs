@@ -0,0 +1,69 @@
+use crate::reports::{ReporterBackend, ReporterDiagnostic};
+use biome_diagnostics::Severity;
+use biome_service::WorkspaceError;
+use std::fmt::Write;
+
+/// Prints diagnostics as [GitHub workflow commands](https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions),
+/// so they are shown as annotations of the pull request.
+pub(crate) struct GitHubReporter;
+
+impl ReporterBackend for GitHubReporter {
+    fn render(&self, diagnostics: &[ReporterDiagnostic]) -> Result<String, WorkspaceError> {
+        let mut output = String::new();
+        for diagnostic in diagnostics {
+            let command = match diagnostic.severity {
+                Severity::Hint | Severity::Information => "notice",
+                Severity::Warning => "warning",
+                Severity::Error | Severity::Fatal => "error",
+            };
+
+            let mut properties = vec![format!(
+                "title={}",
+                escape_property(diagnostic.category_name())
+            )];
+            if let Some(file_path) = &diagnostic.file_path {
+                properties.push(format!("file={}", escape_property(file_path)));
+            }
+            if let Some(span) = &diagnostic.span {
+                properties.push(format!("line={}", span.start.line));
+                properties.push(format!("endLine={}", span.end.line));
+                properties.push(format!("col={}", span.start.column));
+                properties.push(format!("endColumn={}", span.end.column));
+            }
+
+            writeln!(
+                output,
+                "::{command} {}::{}",
+                properties.join(","),
+                escape_data(&diagnostic.message)
+            )
+            .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))?;
+        }
+
+        Ok(output)
+    }
+}
+
+/// Escapes the message of a workflow command
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes the value of a property of a workflow command
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_data, escape_property};
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(escape_data("100%\nsure"), "100%25%0Asure");
+        assert_eq!(escape_property("lint/a:b,c"), "lint/a%3Ab%2Cc");
+    }
+}
@@ -0,0 +1,70 @@
+use crate::reports::{ReporterBackend, ReporterDiagnostic};
+use biome_diagnostics::Severity;
+use biome_service::stable_hash::StableHasher;
+use biome_service::WorkspaceError;
+use serde::Serialize;
+
+/// Prints diagnostics using the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool)
+/// JSON format.
+pub(crate) struct GitLabReporter;
+
+#[derive(Debug, Serialize)]
+struct GitLabIssue<'a> {
+    description: &'a str,
+    check_name: &'a str,
+    fingerprint: String,
+    severity: &'static str,
+    location: GitLabLocation<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabLocation<'a> {
+    path: &'a str,
+    lines: GitLabLines,
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabLines {
+    begin: usize,
+}
+
+impl ReporterBackend for GitLabReporter {
+    fn render(&self, diagnostics: &[ReporterDiagnostic]) -> Result<String, WorkspaceError> {
+        let issues: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let path = diagnostic.file_path.as_deref().unwrap_or("<unknown>");
+                let begin = diagnostic.span.map_or(1, |span| span.start.line);
+                GitLabIssue {
+                    description: &diagnostic.message,
+                    check_name: diagnostic.category_name(),
+                    fingerprint: fingerprint(diagnostic, path, begin),
+                    severity: match diagnostic.severity {
+                        Severity::Hint | Severity::Information => "info",
+                        Severity::Warning => "minor",
+                        Severity::Error => "major",
+                        Severity::Fatal => "critical",
+                    },
+                    location: GitLabLocation {
+                        path,
+                        lines: GitLabLines { begin },
+                    },
+                }
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&issues)
+            .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
+    }
+}
+
+/// GitLab uses the fingerprint to track an issue across pipelines, so it must be stable, see
+/// [StableHasher]
+fn fingerprint(diagnostic: &ReporterDiagnostic, path: &str, line: usize) -> String {
+    let mut hasher = StableHasher::new();
+    hasher.write_str(path);
+    hasher.write_u64(line as u64);
+    hasher.write_str(diagnostic.category_name());
+    hasher.write_str(&diagnostic.message);
+    hasher.finish_hex()
+}
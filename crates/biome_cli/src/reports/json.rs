@@ -0,0 +1,148 @@
+use crate::reports::{LineColumn, ReporterBackend, ReporterDiagnostic, ReporterHunk};
+use biome_diagnostics::{Applicability, Severity};
+use biome_service::WorkspaceError;
+use serde::Serialize;
+
+/// The version of the format printed by the [JsonReporter]. It changes only when a field is
+/// removed, renamed or changes meaning: the fields added later are optional for the readers.
+const JSON_REPORT_VERSION: u32 = 1;
+
+/// Prints diagnostics using the JSON format of Biome, which is documented and versioned
+/// so other tools can parse it across releases.
+pub(crate) struct JsonReporter;
+
+#[derive(Debug, Serialize)]
+struct JsonReport<'a> {
+    version: u32,
+    summary: JsonSummary,
+    diagnostics: Vec<JsonDiagnostic<'a>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct JsonSummary {
+    errors: usize,
+    warnings: usize,
+    fixable: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonDiagnostic<'a> {
+    /// The name of the category, e.g. `lint/suspicious/noDebugger`. The names of the
+    /// categories don't change between releases, except when a rule is renamed.
+    category: Option<&'static str>,
+    severity: Severity,
+    description: &'a str,
+    /// Whether the code fix can be applied without review, `null` if the diagnostic
+    /// doesn't have a fix
+    fix: Option<JsonFix>,
+    location: Option<JsonLocation<'a>>,
+    /// The hunks of the diff between the file and its expected content, only for the
+    /// diagnostics that report such a difference, e.g. the files that aren't formatted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hunks: Option<Vec<JsonHunk<'a>>>,
+}
+
+/// A hunk of a unified diff. The lines start from `1`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonHunk<'a> {
+    old_start: usize,
+    old_lines: usize,
+    new_start: usize,
+    new_lines: usize,
+    /// The lines of the hunk, prefixed with `-`, `+` or a space
+    lines: &'a [String],
+}
+
+impl<'a> From<&'a ReporterHunk> for JsonHunk<'a> {
+    fn from(hunk: &'a ReporterHunk) -> Self {
+        Self {
+            old_start: hunk.old_start,
+            old_lines: hunk.old_lines,
+            new_start: hunk.new_start,
+            new_lines: hunk.new_lines,
+            lines: &hunk.lines,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum JsonFix {
+    Safe,
+    Unsafe,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonLocation<'a> {
+    path: &'a str,
+    /// `null` if the diagnostic doesn't point to a range of the file
+    start: Option<JsonPosition>,
+    end: Option<JsonPosition>,
+}
+
+/// A position inside a file. Both line and column start from `1`, the column counts
+/// characters.
+#[derive(Debug, Serialize)]
+struct JsonPosition {
+    line: usize,
+    column: usize,
+}
+
+impl From<LineColumn> for JsonPosition {
+    fn from(position: LineColumn) -> Self {
+        Self {
+            line: position.line,
+            column: position.column,
+        }
+    }
+}
+
+impl ReporterBackend for JsonReporter {
+    fn render(&self, diagnostics: &[ReporterDiagnostic]) -> Result<String, WorkspaceError> {
+        let mut summary = JsonSummary::default();
+        let diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                match diagnostic.severity {
+                    Severity::Error | Severity::Fatal => summary.errors += 1,
+                    Severity::Warning => summary.warnings += 1,
+                    Severity::Hint | Severity::Information => {}
+                }
+                if diagnostic.fixable {
+                    summary.fixable += 1;
+                }
+
+                JsonDiagnostic {
+                    category: diagnostic.category.map(|category| category.name()),
+                    severity: diagnostic.severity,
+                    description: &diagnostic.message,
+                    fix: diagnostic
+                        .fixable
+                        .then(|| match diagnostic.fix_applicability {
+                            Some(Applicability::Always) => JsonFix::Safe,
+                            Some(Applicability::MaybeIncorrect) | None => JsonFix::Unsafe,
+                        }),
+                    location: diagnostic.file_path.as_deref().map(|path| JsonLocation {
+                        path,
+                        start: diagnostic.span.map(|span| span.start.into()),
+                        end: diagnostic.span.map(|span| span.end.into()),
+                    }),
+                    hunks: diagnostic
+                        .hunks
+                        .as_ref()
+                        .map(|hunks| hunks.iter().map(JsonHunk::from).collect()),
+                }
+            })
+            .collect();
+
+        let report = JsonReport {
+            version: JSON_REPORT_VERSION,
+            summary,
+            diagnostics,
+        };
+
+        serde_json::to_string_pretty(&report)
+            .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
+    }
+}
@@ -0,0 +1,111 @@
+use crate::reports::{ReporterBackend, ReporterDiagnostic};
+use biome_service::WorkspaceError;
+use indexmap::IndexMap;
+use std::fmt::Write;
+
+/// Prints diagnostics as a JUnit XML report. Each file is a test suite, each diagnostic a
+/// failed test case.
+pub(crate) struct JunitReporter;
+
+impl ReporterBackend for JunitReporter {
+    fn render(&self, diagnostics: &[ReporterDiagnostic]) -> Result<String, WorkspaceError> {
+        let mut suites: IndexMap<&str, Vec<&ReporterDiagnostic>> = IndexMap::new();
+        for diagnostic in diagnostics {
+            let path = diagnostic.file_path.as_deref().unwrap_or("<unknown>");
+            suites.entry(path).or_default().push(diagnostic);
+        }
+
+        write_report(&suites)
+            .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
+    }
+}
+
+fn write_report(
+    suites: &IndexMap<&str, Vec<&ReporterDiagnostic>>,
+) -> Result<String, std::fmt::Error> {
+    let total: usize = suites.values().map(Vec::len).sum();
+    let mut output = String::new();
+
+    writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        output,
+        r#"<testsuites name="Biome" tests="{total}" failures="{total}">"#
+    )?;
+    for (path, diagnostics) in suites {
+        writeln!(
+            output,
+            r#"  <testsuite name="{}" tests="{}" failures="{}">"#,
+            escape(path),
+            diagnostics.len(),
+            diagnostics.len()
+        )?;
+        for diagnostic in diagnostics {
+            let category = escape(diagnostic.category_name());
+            let message = escape(&diagnostic.message);
+            match &diagnostic.span {
+                Some(span) => {
+                    writeln!(
+                        output,
+                        r#"    <testcase name="{category}" classname="{}" line="{}" column="{}">"#,
+                        escape(path),
+                        span.start.line,
+                        span.start.column
+                    )?;
+                    writeln!(
+                        output,
+                        r#"      <failure message="{message}">{}:{}:{} {message}</failure>"#,
+                        escape(path),
+                        span.start.line,
+                        span.start.column
+                    )?;
+                }
+                None => {
+                    writeln!(
+                        output,
+                        r#"    <testcase name="{category}" classname="{}">"#,
+                        escape(path)
+                    )?;
+                    writeln!(
+                        output,
+                        r#"      <failure message="{message}">{} {message}</failure>"#,
+                        escape(path)
+                    )?;
+                }
+            }
+            writeln!(output, "    </testcase>")?;
+        }
+        writeln!(output, "  </testsuite>")?;
+    }
+    writeln!(output, "</testsuites>")?;
+
+    Ok(output)
+}
+
+/// Escapes the characters that have a special meaning in XML
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape;
+
+    #[test]
+    fn escapes_xml_characters() {
+        assert_eq!(
+            escape(r#"<a href="b">c & d's</a>"#),
+            "&lt;a href=&quot;b&quot;&gt;c &amp; d&apos;s&lt;/a&gt;"
+        );
+    }
+}
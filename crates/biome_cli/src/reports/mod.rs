@@ -1,11 +1,28 @@
+pub(crate) mod compact;
 pub mod formatter;
+pub(crate) mod github;
+pub(crate) mod gitlab;
+pub(crate) mod json;
+pub(crate) mod junit;
+pub(crate) mod sarif;
+pub(crate) mod summary;
 
+use crate::execute::ReportMode;
 use crate::reports::formatter::{FormatterReportFileDetail, FormatterReportSummary};
-use biome_diagnostics::{Category, Severity};
+use biome_console::fmt;
+use biome_diagnostics::{
+    Applicability, Category, DiagnosticTags, Error, PrintDescription, Resource, Severity, Visit,
+};
 use biome_service::WorkspaceError;
+use biome_text_edit::TextEdit;
+use biome_text_size::{LineIndex, TextSize, WideEncoding};
 use formatter::FormatterReport;
 use rustc_hash::FxHashMap;
 use serde::Serialize;
+use similar::{ChangeTag, TextDiff};
+use std::io;
+use std::ops::Range;
+use std::time::Duration;
 
 #[derive(Debug, Default, Serialize)]
 pub struct Report {
@@ -16,6 +33,14 @@ pub struct Report {
     ///
     /// The key is the path of the file where the diagnostics occurred
     diagnostics: FxHashMap<String, ReportErrorKind>,
+
+    /// Diagnostics tracked for the [reporter backends](ReporterBackend)
+    #[serde(skip)]
+    reporter_diagnostics: Vec<ReporterDiagnostic>,
+
+    /// How long it took to process each file, tracked only by the reporters that need it
+    #[serde(skip)]
+    file_durations: Vec<(String, Duration)>,
 }
 
 #[derive(Debug, Serialize)]
@@ -61,6 +86,7 @@ impl Default for ReportDiagnostic {
 pub enum ReportKind {
     Formatter(String, FormatterReportFileDetail),
     Error(String, ReportErrorKind),
+    Duration(String, Duration),
 }
 
 impl Report {
@@ -73,6 +99,9 @@ impl Report {
             ReportKind::Error(path, error) => {
                 self.diagnostics.insert(path, error);
             }
+            ReportKind::Duration(path, duration) => {
+                self.file_durations.push((path, duration));
+            }
         }
     }
 
@@ -89,4 +118,232 @@ impl Report {
         serde_json::to_string(&self)
             .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
     }
+
+    /// It tracks a diagnostic that will be printed by a [ReporterBackend]
+    pub(crate) fn push_reporter_diagnostic(&mut self, diagnostic: ReporterDiagnostic) {
+        self.reporter_diagnostics.push(diagnostic);
+    }
+
+    /// Whether some diagnostics are tracked for a [ReporterBackend]
+    pub(crate) fn has_reporter_diagnostics(&self) -> bool {
+        !self.reporter_diagnostics.is_empty()
+    }
+
+    /// Renders the tracked diagnostics using the backend that belongs to `report_mode`.
+    ///
+    /// It returns [None] if `report_mode` doesn't have a backend.
+    pub(crate) fn as_reporter_output(
+        &self,
+        report_mode: ReportMode,
+    ) -> Option<Result<String, WorkspaceError>> {
+        let backend: &dyn ReporterBackend = match report_mode {
+            ReportMode::Terminal | ReportMode::Json => return None,
+            ReportMode::JsonDiagnostics => &json::JsonReporter,
+            ReportMode::GitHub => &github::GitHubReporter,
+            ReportMode::GitLab => &gitlab::GitLabReporter,
+            ReportMode::Junit => &junit::JunitReporter,
+            ReportMode::Sarif => &sarif::SarifReporter,
+            ReportMode::Summary => &summary::SummaryReporter {
+                file_durations: &self.file_durations,
+            },
+        };
+
+        Some(backend.render(&self.reporter_diagnostics))
+    }
+}
+
+/// A backend that turns the diagnostics emitted during a traversal into a format
+/// understood by a third-party tool, usually a CI system.
+pub(crate) trait ReporterBackend {
+    /// Renders the whole list of diagnostics at once
+    fn render(&self, diagnostics: &[ReporterDiagnostic]) -> Result<String, WorkspaceError>;
+}
+
+/// Information computed from a [diagnostic][biome_diagnostics::Diagnostic], used by the [reporter backends](ReporterBackend)
+#[derive(Debug)]
+pub(crate) struct ReporterDiagnostic {
+    /// The path of the file where the diagnostic was emitted, if any
+    pub(crate) file_path: Option<String>,
+    /// The category of the diagnostic
+    pub(crate) category: Option<&'static Category>,
+    /// Severity of the diagnostic
+    pub(crate) severity: Severity,
+    /// The description of the diagnostic
+    pub(crate) message: String,
+    /// The position of the diagnostic in the file, if the source code is known
+    pub(crate) span: Option<ReporterSpan>,
+    /// Whether the diagnostic has a code fix
+    pub(crate) fixable: bool,
+    /// The applicability of the code fix, if the advices of the diagnostic tell it
+    pub(crate) fix_applicability: Option<Applicability>,
+    /// The hunks of the diff between the content of the file and its expected content, when
+    /// the diagnostic reports such a difference, e.g. a file that isn't formatted
+    pub(crate) hunks: Option<Vec<ReporterHunk>>,
+}
+
+impl ReporterDiagnostic {
+    /// Creates a new [ReporterDiagnostic]. Lines and columns are computed only when
+    /// `source_code` is provided.
+    pub(crate) fn new(
+        diagnostic: &Error,
+        file_path: Option<&str>,
+        source_code: Option<&str>,
+    ) -> Self {
+        let location = diagnostic.location();
+        let file_path = file_path
+            .map(String::from)
+            .or_else(|| match location.resource {
+                Some(Resource::File(file)) => Some(file.to_string()),
+                _ => None,
+            });
+        let span = location
+            .span
+            .zip(source_code.map(LineIndex::new))
+            .map(|(span, line_index)| ReporterSpan {
+                start: LineColumn::from_offset(&line_index, span.start()),
+                end: LineColumn::from_offset(&line_index, span.end()),
+            });
+
+        Self {
+            file_path,
+            category: diagnostic.category(),
+            severity: diagnostic.severity(),
+            message: PrintDescription(diagnostic).to_string(),
+            span,
+            fixable: diagnostic.tags().contains(DiagnosticTags::FIXABLE),
+            fix_applicability: FixVisitor::applicability(diagnostic),
+            hunks: None,
+        }
+    }
+
+    /// Attaches the hunks of the diff between `old` and `new`, with `context_lines` unchanged
+    /// lines around the changes
+    pub(crate) fn with_diff(mut self, old: &str, new: &str, context_lines: usize) -> Self {
+        self.hunks = Some(ReporterHunk::from_diff(old, new, context_lines));
+        self
+    }
+
+    /// The name of the category, or `"biome"` if the diagnostic doesn't have one
+    pub(crate) fn category_name(&self) -> &str {
+        self.category.map_or("biome", |category| category.name())
+    }
+}
+
+/// Finds the applicability of the code fix of a diagnostic, from the applicability of its
+/// code suggestions
+#[derive(Default)]
+struct FixVisitor {
+    applicability: Option<Applicability>,
+}
+
+impl FixVisitor {
+    fn applicability(diagnostic: &Error) -> Option<Applicability> {
+        let mut visitor = Self::default();
+        diagnostic.advices(&mut visitor).ok()?;
+        visitor.applicability
+    }
+}
+
+impl Visit for FixVisitor {
+    fn record_suggestion(
+        &mut self,
+        applicability: Applicability,
+        _title: &dyn fmt::Display,
+        _diff: &TextEdit,
+    ) -> io::Result<()> {
+        // An unsafe fix makes the whole fix unsafe
+        if applicability == Applicability::MaybeIncorrect || self.applicability.is_none() {
+            self.applicability = Some(applicability);
+        }
+        Ok(())
+    }
+}
+
+/// A hunk of a unified diff, the same format printed by `git diff`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct ReporterHunk {
+    /// The first line of the hunk in the old content, starting from `1`
+    pub(crate) old_start: usize,
+    /// The number of lines of the hunk in the old content
+    pub(crate) old_lines: usize,
+    /// The first line of the hunk in the new content, starting from `1`
+    pub(crate) new_start: usize,
+    /// The number of lines of the hunk in the new content
+    pub(crate) new_lines: usize,
+    /// The lines of the hunk, without their line break. They start with `-` when they're
+    /// removed, `+` when they're inserted and a space when they're unchanged.
+    pub(crate) lines: Vec<String>,
+}
+
+impl ReporterHunk {
+    fn from_diff(old: &str, new: &str, context_lines: usize) -> Vec<Self> {
+        let diff = TextDiff::from_lines(old, new);
+        diff.grouped_ops(context_lines)
+            .iter()
+            .filter_map(|group| {
+                let (first, last) = (group.first()?, group.last()?);
+                let old_range = first.old_range().start..last.old_range().end;
+                let new_range = first.new_range().start..last.new_range().end;
+                let lines = group
+                    .iter()
+                    .flat_map(|op| diff.iter_changes(op))
+                    .map(|change| {
+                        let sign = match change.tag() {
+                            ChangeTag::Delete => '-',
+                            ChangeTag::Insert => '+',
+                            ChangeTag::Equal => ' ',
+                        };
+                        let text = change.value();
+                        format!("{sign}{}", text.strip_suffix('\n').unwrap_or(text))
+                    })
+                    .collect();
+
+                // Like `git diff`, an empty range starts at the line that precedes it
+                Some(Self {
+                    old_start: hunk_start(&old_range),
+                    old_lines: old_range.len(),
+                    new_start: hunk_start(&new_range),
+                    new_lines: new_range.len(),
+                    lines,
+                })
+            })
+            .collect()
+    }
+}
+
+fn hunk_start(range: &Range<usize>) -> usize {
+    if range.is_empty() {
+        range.start
+    } else {
+        range.start + 1
+    }
+}
+
+/// Start and end positions of a diagnostic
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReporterSpan {
+    pub(crate) start: LineColumn,
+    pub(crate) end: LineColumn,
+}
+
+/// A position inside a file. Both line and column start from `1`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct LineColumn {
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+impl LineColumn {
+    /// Computes the line and the column of `offset` with the line index of the
+    /// source code. The column counts the characters, not the bytes.
+    pub(crate) fn from_offset(line_index: &LineIndex, offset: TextSize) -> Self {
+        let position = line_index
+            .line_col(offset)
+            .and_then(|line_col| line_index.to_wide(WideEncoding::Utf32, line_col));
+
+        position.map_or(Self { line: 1, column: 1 }, |position| Self {
+            line: position.line as usize + 1,
+            column: position.col as usize + 1,
+        })
+    }
 }
@@ -0,0 +1,157 @@
+use crate::reports::{ReporterBackend, ReporterDiagnostic};
+use crate::VERSION;
+use biome_diagnostics::Severity;
+use biome_service::WorkspaceError;
+use indexmap::IndexMap;
+use serde::Serialize;
+
+/// Prints diagnostics using the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// format.
+pub(crate) struct SarifReporter;
+
+#[derive(Debug, Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun<'a> {
+    tool: SarifTool<'a>,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool<'a> {
+    driver: SarifDriver<'a>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifRule<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRule<'a> {
+    id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "helpUri")]
+    help_uri: Option<&'static str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'a str,
+    level: &'static str,
+    message: SarifMessage<'a>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage<'a> {
+    text: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation<'a> {
+    physical_location: SarifPhysicalLocation<'a>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation<'a> {
+    artifact_location: SarifArtifactLocation<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation<'a> {
+    uri: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl ReporterBackend for SarifReporter {
+    fn render(&self, diagnostics: &[ReporterDiagnostic]) -> Result<String, WorkspaceError> {
+        // The link of every rule, keyed by the rule id
+        let mut rules = IndexMap::new();
+        let mut results = Vec::with_capacity(diagnostics.len());
+
+        for diagnostic in diagnostics {
+            let rule_id = diagnostic.category_name();
+            rules
+                .entry(rule_id)
+                .or_insert_with(|| diagnostic.category.and_then(|category| category.link()));
+
+            let locations = diagnostic
+                .file_path
+                .as_deref()
+                .map(|uri| SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: SarifArtifactLocation { uri },
+                        region: diagnostic.span.map(|span| SarifRegion {
+                            start_line: span.start.line,
+                            start_column: span.start.column,
+                            end_line: span.end.line,
+                            end_column: span.end.column,
+                        }),
+                    },
+                })
+                .into_iter()
+                .collect();
+
+            results.push(SarifResult {
+                rule_id,
+                level: match diagnostic.severity {
+                    Severity::Hint | Severity::Information => "note",
+                    Severity::Warning => "warning",
+                    Severity::Error | Severity::Fatal => "error",
+                },
+                message: SarifMessage {
+                    text: &diagnostic.message,
+                },
+                locations,
+            });
+        }
+
+        let rules = rules
+            .into_iter()
+            .map(|(id, help_uri)| SarifRule { id, help_uri })
+            .collect();
+
+        let log = SarifLog {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: VERSION,
+                        rules,
+                    },
+                },
+                results,
+            }],
+        };
+
+        serde_json::to_string_pretty(&log)
+            .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
+    }
+}
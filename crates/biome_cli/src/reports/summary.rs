@@ -0,0 +1,180 @@
+use crate::reports::{ReporterBackend, ReporterDiagnostic};
+use biome_console::fmt::{Formatter, Termcolor};
+use biome_console::{markup, Table};
+use biome_diagnostics::termcolor::NoColor;
+use biome_service::WorkspaceError;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// The number of files listed in the "Slowest files" section
+const SLOWEST_FILES: usize = 10;
+
+/// Prints statistics about the diagnostics instead of the diagnostics themselves: how many
+/// diagnostics each rule emitted, how many of them can be fixed, and which directories
+/// and files need the most work.
+pub(crate) struct SummaryReporter<'a> {
+    /// How long it took to process each file
+    pub(crate) file_durations: &'a [(String, Duration)],
+}
+
+#[derive(Default)]
+struct Count {
+    diagnostics: usize,
+    fixable: usize,
+}
+
+impl Count {
+    fn track(&mut self, diagnostic: &ReporterDiagnostic) {
+        self.diagnostics += 1;
+        if diagnostic.fixable {
+            self.fixable += 1;
+        }
+    }
+}
+
+impl ReporterBackend for SummaryReporter<'_> {
+    fn render(&self, diagnostics: &[ReporterDiagnostic]) -> Result<String, WorkspaceError> {
+        let mut by_rule: BTreeMap<&str, Count> = BTreeMap::new();
+        let mut by_directory: BTreeMap<String, Count> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            by_rule
+                .entry(diagnostic.category_name())
+                .or_default()
+                .track(diagnostic);
+
+            let directory = diagnostic
+                .file_path
+                .as_deref()
+                .and_then(|file_path| Path::new(file_path).parent())
+                .map(|parent| parent.display().to_string())
+                .filter(|parent| !parent.is_empty())
+                .unwrap_or_else(|| String::from("."));
+            by_directory.entry(directory).or_default().track(diagnostic);
+        }
+
+        let mut output = String::new();
+        write_section(&mut output, "Diagnostics by rule", sorted(by_rule))?;
+        write_section(
+            &mut output,
+            "Diagnostics by directory",
+            sorted(by_directory),
+        )?;
+
+        // The durations aren't tracked when the summary follows the diagnostics of `biome ci`
+        if self.file_durations.is_empty() {
+            return Ok(output);
+        }
+
+        let mut slowest_files: Vec<_> = self.file_durations.iter().collect();
+        slowest_files.sort_by(|(left_path, left), (right_path, right)| {
+            right.cmp(left).then_with(|| left_path.cmp(right_path))
+        });
+        let rows: Vec<_> = slowest_files
+            .into_iter()
+            .take(SLOWEST_FILES)
+            .map(|(path, duration)| (path.as_str(), format!("{duration:?}")))
+            .collect();
+        write_rows(&mut output, "Slowest files", &rows)?;
+
+        Ok(output)
+    }
+}
+
+/// Sorts the entries by number of diagnostics, the entries with the most diagnostics first
+fn sorted<K: AsRef<str>>(counts: BTreeMap<K, Count>) -> Vec<(K, Count)> {
+    let mut entries: Vec<_> = counts.into_iter().collect();
+    entries.sort_by(|(_, left), (_, right)| right.diagnostics.cmp(&left.diagnostics));
+    entries
+}
+
+fn write_section<K: AsRef<str>>(
+    output: &mut String,
+    title: &str,
+    entries: Vec<(K, Count)>,
+) -> Result<(), WorkspaceError> {
+    let rows: Vec<_> = entries
+        .iter()
+        .map(|(name, count)| {
+            (
+                name.as_ref(),
+                format!("{} ({} fixable)", count.diagnostics, count.fixable),
+            )
+        })
+        .collect();
+    write_rows(output, title, &rows)
+}
+
+fn write_rows(
+    output: &mut String,
+    title: &str,
+    rows: &[(&str, String)],
+) -> Result<(), WorkspaceError> {
+    let mut table = Table::new().with_indent(2);
+    for (name, value) in rows {
+        table.add_row([markup!({ name }), markup!({ value })]);
+    }
+
+    let mut buffer = Vec::new();
+    Formatter::new(&mut Termcolor(NoColor::new(&mut buffer)))
+        .write_markup(markup! {{table}})
+        .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))?;
+
+    let mut write = || -> std::fmt::Result {
+        writeln!(output, "{title}:")?;
+        if table.is_empty() {
+            writeln!(output, "  none")?;
+        }
+        output.push_str(&String::from_utf8_lossy(&buffer));
+        writeln!(output)
+    };
+
+    write().map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SummaryReporter;
+    use crate::reports::{ReporterBackend, ReporterDiagnostic};
+    use biome_diagnostics::Severity;
+    use std::time::Duration;
+
+    fn diagnostic(file_path: &str, fixable: bool) -> ReporterDiagnostic {
+        ReporterDiagnostic {
+            file_path: Some(file_path.to_string()),
+            category: None,
+            severity: Severity::Error,
+            message: String::new(),
+            span: None,
+            fixable,
+            fix_applicability: None,
+            hunks: None,
+        }
+    }
+
+    #[test]
+    fn aggregates_by_rule_and_directory() {
+        let durations = [
+            (String::from("src/a.js"), Duration::from_millis(1)),
+            (String::from("src/lib/b.js"), Duration::from_millis(5)),
+        ];
+        let reporter = SummaryReporter {
+            file_durations: &durations,
+        };
+        let output = reporter
+            .render(&[
+                diagnostic("src/a.js", true),
+                diagnostic("src/lib/b.js", false),
+                diagnostic("src/lib/b.js", true),
+            ])
+            .unwrap();
+
+        assert!(output.contains("  biome  3 (2 fixable)\n"), "{output}");
+        assert!(output.contains("  src/lib  2 (1 fixable)\n"), "{output}");
+        assert!(
+            output.ends_with("Slowest files:\n  src/lib/b.js  5ms\n  src/a.js      1ms\n\n"),
+            "{output}"
+        );
+    }
+}
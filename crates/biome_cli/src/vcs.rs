@@ -2,87 +2,171 @@ use crate::cli_options::CliOptions;
 use crate::diagnostics::DisabledVcs;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
-use biome_deserialize::StringSet;
 use biome_diagnostics::PrintDiagnostic;
-use biome_service::configuration::vcs::VcsConfiguration;
-use biome_service::configuration::FilesConfiguration;
-use biome_service::{Configuration, WorkspaceError};
-use std::path::PathBuf;
+use biome_fs::{FileSystem, FileSystemExt};
+use biome_service::configuration::vcs::{NestedVcsIgnoreFiles, VcsClientKind};
+use biome_service::workspace::VcsIgnoreFile;
+use biome_service::{Configuration, DynRef, WorkspaceError};
+use std::env;
+use std::path::{Component, Path, PathBuf};
 
 /// This function will check if the configuration is set to use the VCS integration and try to
-/// read the ignored files.
-pub(crate) fn store_path_to_ignore_from_vcs(
+/// read the ignore files: the global excludes of the client and the ignore files at the root of
+/// the VCS, from the least to the most specific. The ignore files of the subdirectories are
+/// looked up while the files are handled, with the returned [NestedVcsIgnoreFiles], so the
+/// ignored directories aren't traversed.
+pub(crate) fn read_vcs_ignore_files(
     session: &mut CliSession,
-    configuration: &mut Configuration,
+    configuration: &Configuration,
     vcs_base_path: Option<PathBuf>,
     cli_options: &CliOptions,
-) -> Result<(), CliDiagnostic> {
+) -> Result<(Vec<VcsIgnoreFile>, Option<NestedVcsIgnoreFiles>), CliDiagnostic> {
     let Some(vcs) = &configuration.vcs else {
-        return Ok(());
+        return Ok((vec![], None));
     };
-    if vcs.is_enabled() {
-        let vcs_base_path = match (vcs_base_path, &vcs.root) {
-            (Some(vcs_base_path), Some(root)) => vcs_base_path.join(root),
-            (None, Some(root)) => PathBuf::from(root),
-            (Some(vcs_base_path), None) => vcs_base_path,
-            (None, None) => {
-                let console = &mut session.app.console;
-                let diagnostic = DisabledVcs {};
-                console.error(markup! {
-					{if cli_options.verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
-                });
-                return Ok(());
-            }
-        };
+    if vcs.is_disabled() {
+        return Ok((vec![], None));
+    }
+    let vcs_base_path = match (vcs_base_path, &vcs.root) {
+        (Some(vcs_base_path), Some(root)) => vcs_base_path.join(root),
+        (None, Some(root)) => PathBuf::from(root),
+        (Some(vcs_base_path), None) => vcs_base_path,
+        (None, None) => {
+            let console = &mut session.app.console;
+            let diagnostic = DisabledVcs {};
+            console.error(markup! {
+                {if cli_options.verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
+            });
+            return Ok((vec![], None));
+        }
+    };
+    let Some(client_kind) = &vcs.client_kind else {
+        return Ok((vec![], None));
+    };
+    if vcs.ignore_file_disabled() {
+        return Ok((vec![], None));
+    }
 
-        let files_to_ignore = read_vcs_ignore_file(session, vcs_base_path, vcs)?;
+    let fs = &session.app.fs;
+    // The paths of the traversal are relative to the working directory, and so must be the
+    // directories of the ignore files
+    let working_directory = fs.working_directory();
+    let relative_to_working_directory = |path: &Path| {
+        working_directory
+            .as_deref()
+            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+            .unwrap_or(path)
+            .to_path_buf()
+    };
 
-        if !files_to_ignore.is_empty() {
-            let files = configuration
-                .files
-                .get_or_insert_with(FilesConfiguration::default);
-            let ignored_files = files.ignore.get_or_insert_with(StringSet::default);
-            ignored_files.extend(files_to_ignore);
+    let mut ignore_files = vec![];
+    for path in global_ignore_files(fs, client_kind, &vcs_base_path) {
+        if let Ok(content) = fs.read_to_string(&path) {
+            ignore_files.push(VcsIgnoreFile {
+                path,
+                directory: relative_to_working_directory(&vcs_base_path),
+                content,
+            });
         }
     }
-    Ok(())
-}
 
-pub(crate) fn read_vcs_ignore_file(
-    session: &mut CliSession,
-    current_directory: PathBuf,
-    configuration: &VcsConfiguration,
-) -> Result<Vec<String>, CliDiagnostic> {
-    if !configuration.is_enabled() {
-        return Ok(vec![]);
+    // The root ignore file can be in a parent directory, e.g. when `biome.json` is in a package
+    // of a monorepo
+    let root_ignore_file = fs
+        .auto_search(vcs_base_path.clone(), client_kind.ignore_file(), false)
+        .map_err(WorkspaceError::from)?;
+    let root_ignore_file_path = root_ignore_file
+        .as_ref()
+        .map(|result| normalize(&result.file_path));
+    if let Some(result) = root_ignore_file {
+        ignore_files.push(VcsIgnoreFile {
+            path: result.file_path,
+            directory: relative_to_working_directory(&result.directory_path),
+            content: result.content,
+        });
     }
-    let file_system = &session.app.fs;
 
-    if let Some(client_kind) = &configuration.client_kind {
-        if !configuration.ignore_file_disabled() {
-            let result = file_system
-                .auto_search(current_directory, client_kind.ignore_file(), false)
-                .map_err(WorkspaceError::from)?;
+    // The other ignore files of the root, e.g. `.ignore`
+    for file_name in client_kind.ignore_files() {
+        let path = vcs_base_path.join(file_name);
+        if root_ignore_file_path.as_ref() == Some(&normalize(&path)) || !fs.path_exists(&path) {
+            continue;
+        }
+        if let Ok(content) = fs.read_to_string(&path) {
+            ignore_files.push(VcsIgnoreFile {
+                path,
+                directory: relative_to_working_directory(&vcs_base_path),
+                content,
+            });
+        }
+    }
+    let nested_ignore_files =
+        NestedVcsIgnoreFiles::new(vcs_base_path, working_directory, client_kind);
+
+    Ok((ignore_files, Some(nested_ignore_files)))
+}
 
-            if let Some(result) = result {
-                return Ok(result
-                    .content
-                    .lines()
-                    // remove empty lines
-                    .filter(|line| !line.is_empty())
-                    .filter_map(|item| {
-                        let line = item.to_string();
-                        // remove comments
-                        if !line.starts_with('#') {
-                            Some(line)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<Vec<String>>());
+/// Returns the ignore files of the client that aren't in the working tree, from the least to
+/// the most specific. Their patterns are relative to the root of the repository.
+fn global_ignore_files(
+    fs: &DynRef<dyn FileSystem>,
+    client_kind: &VcsClientKind,
+    vcs_base_path: &Path,
+) -> Vec<PathBuf> {
+    match client_kind {
+        VcsClientKind::Git => {
+            let home = env::var_os("HOME")
+                .or_else(|| env::var_os("USERPROFILE"))
+                .map(PathBuf::from);
+            let config_home = env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+            // Like git, `~/.gitconfig` takes precedence over `$XDG_CONFIG_HOME/git/config`
+            let excludes_file = [
+                home.as_ref().map(|path| path.join(".gitconfig")),
+                config_home.as_ref().map(|path| path.join("git/config")),
+            ]
+            .into_iter()
+            .flatten()
+            .filter_map(|path| fs.read_to_string(&path).ok())
+            .find_map(|content| git_excludes_file(&content, home.as_deref()))
+            .or_else(|| config_home.map(|path| path.join("git/ignore")));
+
+            excludes_file
+                .into_iter()
+                .chain([vcs_base_path.join(".git/info/exclude")])
+                .collect()
+        }
+    }
+}
+
+/// Returns the value of `core.excludesFile` in a configuration file of git
+fn git_excludes_file(content: &str, home: Option<&Path>) -> Option<PathBuf> {
+    let mut section = String::new();
+    let mut excludes_file = None;
+    for line in content.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix('[') {
+            section = name.trim_end_matches(']').trim().to_lowercase();
+        } else if section == "core" {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            if key.trim().eq_ignore_ascii_case("excludesfile") {
+                let value = value.trim().trim_matches('"');
+                excludes_file = Some(match (value.strip_prefix("~/"), home) {
+                    (Some(path), Some(home)) => home.join(path),
+                    _ => PathBuf::from(value),
+                });
             }
         }
     }
+    excludes_file
+}
 
-    Ok(vec![])
+/// Removes the `.` components of a path, e.g. `./.gitignore` gives `.gitignore`
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
 }
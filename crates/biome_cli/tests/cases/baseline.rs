@@ -0,0 +1,151 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const DEBUGGER_CONTENTS: &str = "debugger;";
+
+/// Records the diagnostics of `file.js` in `baseline.json`
+fn record_baseline(fs: &mut MemoryFileSystem, console: &mut BufferConsole) {
+    let result = run_cli(
+        DynRef::Borrowed(fs),
+        console,
+        Args::from(
+            [
+                "lint",
+                "--baseline=baseline.json",
+                "--update-baseline",
+                "file.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn records_the_diagnostics_in_the_baseline() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER_CONTENTS.as_bytes());
+
+    record_baseline(&mut fs, &mut console);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "records_the_diagnostics_in_the_baseline",
+        fs,
+        console,
+        Ok(()),
+    ));
+}
+
+#[test]
+fn suppresses_the_diagnostics_of_the_baseline() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER_CONTENTS.as_bytes());
+
+    record_baseline(&mut fs, &mut BufferConsole::default());
+
+    // The diagnostic is still matched when it moves to another line
+    fs.insert(
+        Path::new("file.js").into(),
+        format!("// moved to the second line\n{DEBUGGER_CONTENTS}").as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--baseline=baseline.json", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppresses_the_diagnostics_of_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn matches_the_files_relative_to_the_root_of_the_project() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER_CONTENTS.as_bytes());
+
+    record_baseline(&mut fs, &mut BufferConsole::default());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--baseline=baseline.json", "./file.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "matches_the_files_relative_to_the_root_of_the_project",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_the_diagnostics_missing_from_the_baseline() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER_CONTENTS.as_bytes());
+
+    record_baseline(&mut fs, &mut BufferConsole::default());
+
+    fs.insert(Path::new("other.js").into(), DEBUGGER_CONTENTS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--baseline=baseline.json", "file.js", "other.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_the_diagnostics_missing_from_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn fails_when_the_baseline_does_not_exist() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER_CONTENTS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--baseline=baseline.json", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fails_when_the_baseline_does_not_exist",
+        fs,
+        console,
+        result,
+    ));
+}
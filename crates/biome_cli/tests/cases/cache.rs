@@ -0,0 +1,159 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const CLEAN_CONTENTS: &str = "const a = 1;\n";
+const DEBUGGER_CONTENTS: &str = "debugger;";
+
+/// Replaces the hashes of the cache file with placeholders: the fingerprint contains the
+/// version of Biome and the whole configuration, so it changes with every release
+fn redact_cache(fs: &mut MemoryFileSystem, location: &str) {
+    let mut file = fs
+        .open(Path::new(location))
+        .expect("the cache file should have been created");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+    drop(file);
+
+    let mut cache: serde_json::Value = serde_json::from_str(&content).unwrap();
+    cache["fingerprint"] = "<FINGERPRINT>".into();
+    for hash in cache["files"].as_object_mut().unwrap().values_mut() {
+        *hash = "<HASH>".into();
+    }
+
+    fs.insert(Path::new(location).into(), cache.to_string().as_bytes());
+}
+
+#[test]
+fn stores_files_without_diagnostics() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("clean.js").into(), CLEAN_CONTENTS.as_bytes());
+    fs.insert(
+        Path::new("debugger.js").into(),
+        DEBUGGER_CONTENTS.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--cache", "clean.js", "debugger.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    redact_cache(&mut fs, ".biomecache");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "stores_files_without_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn uses_the_custom_cache_location() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("clean.js").into(), CLEAN_CONTENTS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--cache",
+                "--cache-location=custom-cache.json",
+                "clean.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    redact_cache(&mut fs, "custom-cache.json");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "uses_the_custom_cache_location",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn processes_files_whose_content_changed() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), CLEAN_CONTENTS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from(["lint", "--cache", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), DEBUGGER_CONTENTS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--cache", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    redact_cache(&mut fs, ".biomecache");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "processes_files_whose_content_changed",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn discards_the_cache_when_the_command_changes() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "  statement(  )  ".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from(["lint", "--cache", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--cache", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    redact_cache(&mut fs, ".biomecache");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "discards_the_cache_when_the_command_changes",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -266,3 +266,135 @@ fn applies_extended_values_in_current_config() {
         result,
     ));
 }
+
+#[test]
+fn extends_resolves_the_configuration_of_an_npm_package() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["@acme/biome-config", "shared-config/strict"] }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/package.json").into(),
+        r#"{ "name": "@acme/biome-config", "exports": { ".": "./biome.json" } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/shared-config/package.json").into(),
+        r#"{ "name": "shared-config", "exports": { "./strict": { "default": "./strict.json" } } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/shared-config/strict.json").into(),
+        r#"{ "linter": { "rules": { "style": { "noVar": "off" } } } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "debugger;\nvar a = 1;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn extends_ignores_the_main_file_of_an_npm_package() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["@acme/biome-config"] }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/package.json").into(),
+        r#"{ "name": "@acme/biome-config", "main": "./index.js" }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/index.js").into(),
+        "module.exports = {};",
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "debugger;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn extends_should_raise_an_error_for_a_package_that_doesnt_export_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["@acme/biome-config"] }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/package.json").into(),
+        r#"{ "name": "@acme/biome-config", "exports": { ".": "./index.js" } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/index.js").into(),
+        "module.exports = {};",
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "statement();\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn extends_should_raise_an_error_for_a_remote_url() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["https://example.com/biome.json"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "statement();\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_should_raise_an_error_for_a_remote_url",
+        fs,
+        console,
+        result,
+    ));
+}
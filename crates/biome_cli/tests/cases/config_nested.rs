@@ -0,0 +1,137 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "function f() {\nreturn 1;\n}\n";
+
+#[test]
+fn nested_configuration_applies_to_the_files_beneath_it() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space" } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "formatter": { "indentWidth": 4 } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/nested/biome.json").into(),
+        r#"{ "formatter": { "indentWidth": 8 } }"#,
+    );
+
+    let root_file = Path::new("index.js");
+    fs.insert(root_file.into(), UNFORMATTED);
+    let package_file = Path::new("packages/a/index.js");
+    fs.insert(package_file.into(), UNFORMATTED);
+    let nested_file = Path::new("packages/a/nested/index.js");
+    fs.insert(nested_file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                root_file.as_os_str().to_str().unwrap(),
+                package_file.as_os_str().to_str().unwrap(),
+                nested_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, root_file, "function f() {\n  return 1;\n}\n");
+    assert_file_contents(&fs, package_file, "function f() {\n    return 1;\n}\n");
+    assert_file_contents(&fs, nested_file, "function f() {\n        return 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_applies_to_the_files_beneath_it",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_configuration_applies_its_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), r#"{}"#);
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "overrides": [{ "include": ["generated/**"], "formatter": { "enabled": false } }] }"#,
+    );
+
+    let package_file = Path::new("packages/a/index.js");
+    fs.insert(package_file.into(), UNFORMATTED);
+    let generated_file = Path::new("packages/a/generated/index.js");
+    fs.insert(generated_file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                package_file.as_os_str().to_str().unwrap(),
+                generated_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, package_file, "function f() {\n  return 1;\n}\n");
+    assert_file_contents(&fs, generated_file, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_applies_its_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn explain_config_prints_the_configuration_of_a_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space" } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "formatter": { "indentWidth": 4 } }"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("explain"), ("config"), ("packages/a/index.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "explain_config_prints_the_configuration_of_a_file",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -69,3 +69,45 @@ fn logs_the_appropriate_messages_according_to_set_diagonstics_level() {
         result,
     ));
 }
+
+/// Runs `biome lint` with the given `--fix-preview` and snapshots what was printed
+fn assert_lint_with_fix_preview(test_name: &str, fix_preview: &str) {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), TEST_CONTENTS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", fix_preview, file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        test_name,
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_full_fix_preview() {
+    assert_lint_with_fix_preview("prints_the_full_fix_preview", "--fix-preview=full");
+}
+
+#[test]
+fn prints_a_compact_fix_preview() {
+    assert_lint_with_fix_preview("prints_a_compact_fix_preview", "--fix-preview=compact");
+}
+
+#[test]
+fn prints_only_the_title_of_the_fix_when_the_preview_is_off() {
+    assert_lint_with_fix_preview(
+        "prints_only_the_title_of_the_fix_when_the_preview_is_off",
+        "--fix-preview=off",
+    );
+}
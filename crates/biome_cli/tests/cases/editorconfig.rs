@@ -0,0 +1,248 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "function f() {\nreturn 1;\n}\n";
+
+const EDITORCONFIG: &str = r#"root = true
+
+[*]
+indent_style = space
+indent_size = 4
+
+[scripts/**]
+indent_size = 8
+"#;
+
+#[test]
+fn uses_the_editorconfig_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "useEditorconfig": true } }"#,
+    );
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG);
+
+    let file = Path::new("index.js");
+    fs.insert(file.into(), UNFORMATTED);
+    let script_file = Path::new("scripts/build.js");
+    fs.insert(script_file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file.as_os_str().to_str().unwrap(),
+                script_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file, "function f() {\n    return 1;\n}\n");
+    assert_file_contents(&fs, script_file, "function f() {\n        return 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "uses_the_editorconfig_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn the_configuration_takes_precedence_over_the_editorconfig_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "useEditorconfig": true, "indentStyle": "tab" } }"#,
+    );
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG);
+
+    let file = Path::new("index.js");
+    fs.insert(file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file, "function f() {\n\treturn 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "the_configuration_takes_precedence_over_the_editorconfig_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignores_the_editorconfig_file_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG);
+
+    let file = Path::new("index.js");
+    fs.insert(file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file, "function f() {\n\treturn 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignores_the_editorconfig_file_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn the_options_set_to_their_default_take_precedence_over_the_editorconfig_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "useEditorconfig": true, "indentWidth": 2 } }"#,
+    );
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG);
+
+    let file = Path::new("index.js");
+    fs.insert(file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file, "function f() {\n  return 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "the_options_set_to_their_default_take_precedence_over_the_editorconfig_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn the_arguments_take_precedence_over_the_editorconfig_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "useEditorconfig": true } }"#,
+    );
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG);
+
+    let file = Path::new("index.js");
+    fs.insert(file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                ("--indent-width"),
+                ("2"),
+                file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file, "function f() {\n  return 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "the_arguments_take_precedence_over_the_editorconfig_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn uses_the_editorconfig_files_of_the_subdirectories() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "useEditorconfig": true } }"#,
+    );
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG);
+    fs.insert(
+        Path::new("packages/a/.editorconfig").into(),
+        "[*.js]\nindent_size = 6\n",
+    );
+    fs.insert(
+        Path::new("packages/b/.editorconfig").into(),
+        "root = true\n\n[*.js]\nindent_size = 6\n",
+    );
+
+    let file = Path::new("packages/a/index.js");
+    fs.insert(file.into(), UNFORMATTED);
+    let root_file = Path::new("packages/b/index.js");
+    fs.insert(root_file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file.as_os_str().to_str().unwrap(),
+                root_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    // The file of the subdirectory takes precedence, the other options come from the parent
+    assert_file_contents(&fs, file, "function f() {\n      return 1;\n}\n");
+    // The file with `root = true` ignores the file of the parent: the indent style is a tab
+    assert_file_contents(&fs, root_file, "function f() {\n\treturn 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "uses_the_editorconfig_files_of_the_subdirectories",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,10 +1,17 @@
 //! Add here test cases that are not related directly to a command, but to specific
 //! case that affects many commands
 
+mod baseline;
 mod biome_json_support;
+mod cache;
 mod config_extends;
+mod config_nested;
 mod diagnostics;
+mod editorconfig;
 mod included_files;
 mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
+mod profiles;
+mod reporters;
+mod vcs_ignore_files;
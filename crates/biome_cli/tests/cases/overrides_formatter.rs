@@ -1,6 +1,8 @@
 use crate::run_cli;
-use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
-use biome_console::BufferConsole;
+use crate::snap_test::{
+    assert_cli_snapshot, assert_file_contents, markup_to_string, SnapshotPayload,
+};
+use biome_console::{markup, BufferConsole};
 use biome_fs::MemoryFileSystem;
 use biome_service::DynRef;
 use bpaf::Args;
@@ -345,3 +347,160 @@ fn does_include_file_with_different_languages_and_files() {
         result,
     ));
 }
+
+#[test]
+fn does_apply_the_line_ending_of_the_language() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "formatter": { "indentStyle": "space" },
+  "overrides": [
+    { "include": ["test.js"], "formatter": { "indentWidth": 4 }, "javascript": { "formatter": { "lineEnding": "crlf" } } }
+  ]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), UNFORMATTED_LINE_WIDTH.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                ("--line-width=20"),
+                test.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        test,
+        "const a = [\r\n    \"loreum\",\r\n    \"ipsum\",\r\n];\r\n",
+    );
+}
+
+#[test]
+fn does_disable_the_formatter_of_a_language() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "overrides": [
+    { "include": ["special/**"], "formatter": { "enabled": true }, "javascript": { "formatter": { "enabled": false } } }
+  ]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), UNFORMATTED.as_bytes());
+
+    let test2 = Path::new("special/test2.js");
+    fs.insert(test2.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                test.as_os_str().to_str().unwrap(),
+                test2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test2, UNFORMATTED);
+    assert_file_contents(&fs, test, FORMATTED);
+}
+
+#[test]
+fn explains_the_source_of_the_formatter_options() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "formatter": { "indentStyle": "space" },
+  "javascript": { "formatter": { "quoteStyle": "single", "lineWidth": 100 } },
+  "overrides": [
+    { "include": ["src/**"], "formatter": { "lineWidth": 120 } },
+    { "include": ["test/**"], "formatter": { "indentWidth": 8 } }
+  ]
+}
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/biome.json").into(),
+        r#"{ "javascript": { "formatter": { "semicolons": "asNeeded" } } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("explain"), ("formatter"), ("src/index.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = console
+        .out_buffer
+        .iter()
+        .map(|message| {
+            markup_to_string(markup! {
+                {message.content}
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    // The line of an option: its name, its value and the section that sets it
+    let line = |option: &str| {
+        content
+            .lines()
+            .find(|line| line.split_whitespace().next() == Some(option))
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+    };
+
+    assert_eq!(
+        line("indentStyle").as_deref(),
+        Some("indentStyle \"space\" formatter"),
+        "{content}"
+    );
+    assert_eq!(
+        line("lineWidth").as_deref(),
+        Some("lineWidth 120 overrides[0].formatter"),
+        "{content}"
+    );
+    assert_eq!(
+        line("quoteStyle").as_deref(),
+        Some("quoteStyle \"single\" javascript.formatter"),
+        "{content}"
+    );
+    assert!(
+        line("semicolons").is_some_and(|line| line
+            .starts_with("semicolons \"asNeeded\" javascript.formatter of ")
+            && line.ends_with("biome.json")),
+        "{content}"
+    );
+    assert_eq!(line("indentWidth"), None, "{content}");
+}
@@ -1,6 +1,6 @@
 use crate::run_cli;
 use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
-use biome_console::BufferConsole;
+use biome_console::{BufferConsole, LogLevel};
 use biome_fs::MemoryFileSystem;
 use biome_service::DynRef;
 use bpaf::Args;
@@ -327,3 +327,156 @@ fn does_override_the_rules() {
         result,
     ));
 }
+
+#[test]
+fn does_apply_overrides_to_stdin_content() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "overrides": [{ "include": ["special/**"], "linter": { "rules": {
+    "suspicious": { "noDebugger": "off" }
+  } } }]
+}
+"#
+        .as_bytes(),
+    );
+
+    console.in_buffer.push(DEBUGGER_BEFORE.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--stdin-file-path", "special/test.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(
+        console
+            .out_buffer
+            .iter()
+            .all(|message| message.level != LogLevel::Error),
+        "the override should disable the rule"
+    );
+}
+
+#[test]
+fn does_print_back_ignored_stdin_content() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": { "ignore": ["ignored/**"] }
+}
+"#
+        .as_bytes(),
+    );
+
+    console.in_buffer.push(DEBUGGER_BEFORE.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--apply-unsafe",
+                "--stdin-file-path",
+                "ignored/test.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(console.out_buffer.len(), 1);
+    assert_eq!(console.out_buffer[0].level, LogLevel::Log);
+}
+
+#[test]
+fn does_not_enable_the_recommended_rules_when_overriding_a_rule() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": { "rules": { "recommended": false } },
+  "overrides": [{ "include": ["test.js"], "linter": { "rules": {
+    "suspicious": { "noDebugger": "error" }
+  } } }]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.js");
+    fs.insert(
+        test.into(),
+        format!("{DEBUGGER_BEFORE}\n{SIMPLE_NUMBERS_BEFORE}").as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply-unsafe"),
+                test.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test, &format!("\n{SIMPLE_NUMBERS_BEFORE}"));
+}
+
+#[test]
+fn does_apply_the_last_matching_override() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "overrides": [
+    { "include": ["*.js"], "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } },
+    { "include": ["test.js"], "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }
+  ]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), DEBUGGER_BEFORE.as_bytes());
+
+    let test2 = Path::new("test2.js");
+    fs.insert(test2.into(), DEBUGGER_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply-unsafe"),
+                test.as_os_str().to_str().unwrap(),
+                test2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test, DEBUGGER_AFTER);
+    assert_file_contents(&fs, test2, DEBUGGER_BEFORE);
+}
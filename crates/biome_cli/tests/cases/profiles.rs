@@ -0,0 +1,162 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "function f() {\nreturn 1;\n}";
+const FORMATTED_WITH_SPACES: &str = "function f() {\n  return 1;\n}\n";
+const FORMATTED_WITH_TABS: &str = "function f() {\n\treturn 1;\n}\n";
+
+const BIOME_JSON: &str = r#"{
+    "formatter": {
+        "indentStyle": "space"
+    },
+    "profiles": {
+        "ci": {
+            "formatter": {
+                "indentStyle": "tab"
+            }
+        }
+    }
+}"#;
+
+#[test]
+fn applies_the_selected_profile() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), BIOME_JSON);
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--profile=ci"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, FORMATTED_WITH_TABS);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_the_selected_profile",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignores_the_profiles_when_none_is_selected() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), BIOME_JSON);
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, FORMATTED_WITH_SPACES);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignores_the_profiles_when_none_is_selected",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn fails_when_the_profile_does_not_exist() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), BIOME_JSON);
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--profile=local"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fails_when_the_profile_does_not_exist",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn explain_config_applies_the_selected_profile() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), BIOME_JSON);
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("explain"),
+                ("--profile=ci"),
+                ("config"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "explain_config_applies_the_selected_profile",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,113 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const TEST_CONTENTS: &str = "debugger;\n";
+
+/// Runs `biome lint` with the argument that picks how the diagnostics are printed, e.g.
+/// `--reporter=github`, and snapshots what was printed
+fn assert_lint_with_reporter(test_name: &str, argument: &str) {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), TEST_CONTENTS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", argument, file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        test_name,
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_diagnostics_as_github_annotations() {
+    assert_lint_with_reporter(
+        "reports_diagnostics_as_github_annotations",
+        "--reporter=github",
+    );
+}
+
+#[test]
+fn reports_diagnostics_using_gitlab_code_quality() {
+    assert_lint_with_reporter(
+        "reports_diagnostics_using_gitlab_code_quality",
+        "--reporter=gitlab",
+    );
+}
+
+#[test]
+fn reports_diagnostics_using_junit() {
+    assert_lint_with_reporter("reports_diagnostics_using_junit", "--reporter=junit");
+}
+
+#[test]
+fn reports_diagnostics_using_sarif() {
+    assert_lint_with_reporter("reports_diagnostics_using_sarif", "--reporter=sarif");
+}
+
+#[test]
+fn reports_diagnostics_using_json() {
+    assert_lint_with_reporter("reports_diagnostics_using_json", "--reporter=json");
+}
+
+#[test]
+fn reports_a_summary_of_the_diagnostics() {
+    assert_lint_with_reporter("reports_a_summary_of_the_diagnostics", "--reporter=summary");
+}
+
+#[test]
+fn prints_the_diagnostics_on_a_single_line() {
+    assert_lint_with_reporter(
+        "prints_the_diagnostics_on_a_single_line",
+        "--output-format=compact",
+    );
+}
+
+#[test]
+fn reports_the_hunks_of_the_unformatted_files() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "let a=1;\nlet b = 2;\nlet c = 3;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--reporter=json",
+                "--diff-context=1",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_the_hunks_of_the_unformatted_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,132 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "  statement(  )  ";
+const FORMATTED: &str = "statement();\n";
+
+const BIOME_JSON: &str = r#"{
+    "vcs": {
+        "enabled": true,
+        "clientKind": "git",
+        "useIgnoreFile": true
+    }
+}"#;
+
+#[test]
+fn honors_the_ignore_files_of_the_subdirectories() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), BIOME_JSON);
+    fs.insert(Path::new(".gitignore").into(), "*.gen.js\n");
+    fs.insert(
+        Path::new("packages/a/.gitignore").into(),
+        "/build.js\n!kept.gen.js\n",
+    );
+    fs.insert(Path::new("packages/a/.ignore").into(), "scripts/\n");
+
+    let ignored_build = Path::new("packages/a/build.js");
+    fs.insert(ignored_build.into(), UNFORMATTED);
+    let ignored_generated = Path::new("packages/a/other.gen.js");
+    fs.insert(ignored_generated.into(), UNFORMATTED);
+    let ignored_script = Path::new("packages/a/scripts/release.js");
+    fs.insert(ignored_script.into(), UNFORMATTED);
+    let kept_generated = Path::new("packages/a/kept.gen.js");
+    fs.insert(kept_generated.into(), UNFORMATTED);
+    let nested_build = Path::new("packages/a/src/build.js");
+    fs.insert(nested_build.into(), UNFORMATTED);
+    let other_build = Path::new("packages/b/build.js");
+    fs.insert(other_build.into(), UNFORMATTED);
+    let other_generated = Path::new("packages/b/other.gen.js");
+    fs.insert(other_generated.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("packages")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, ignored_build, UNFORMATTED);
+    assert_file_contents(&fs, ignored_generated, UNFORMATTED);
+    assert_file_contents(&fs, ignored_script, UNFORMATTED);
+    assert_file_contents(&fs, other_generated, UNFORMATTED);
+    assert_file_contents(&fs, kept_generated, FORMATTED);
+    assert_file_contents(&fs, nested_build, FORMATTED);
+    assert_file_contents(&fs, other_build, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "honors_the_ignore_files_of_the_subdirectories",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn honors_the_excludes_of_the_repository() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), BIOME_JSON);
+    fs.insert(Path::new(".git/info/exclude").into(), "local/\n");
+
+    let ignored = Path::new("src/local/index.js");
+    fs.insert(ignored.into(), UNFORMATTED);
+    let formatted = Path::new("src/index.js");
+    fs.insert(formatted.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, ignored, UNFORMATTED);
+    assert_file_contents(&fs, formatted, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "honors_the_excludes_of_the_repository",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn honors_the_ignore_files_of_the_parents_of_the_arguments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), BIOME_JSON);
+    fs.insert(Path::new("packages/a/.gitignore").into(), "generated/\n");
+
+    let ignored = Path::new("packages/a/generated/index.js");
+    fs.insert(ignored.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("packages/a/generated")].as_slice()),
+    );
+
+    // The only file is ignored, so no file is processed
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, ignored, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "honors_the_ignore_files_of_the_parents_of_the_arguments",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1553,6 +1553,97 @@ import * as something from "../something";
     ));
 }
 
+#[test]
+fn writes_organize_imports() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config = r#"{ "organizeImports": { "enabled": true } }"#;
+    let file_path = Path::new("biome.json");
+    fs.insert(file_path.into(), config.as_bytes());
+
+    let file_path = Path::new("check.js");
+    let content = r#"import * as something from "../something";
+import { lorem, foom, bar } from "foo";
+"#;
+    let expected = r#"import { bar, foom, lorem } from "foo";
+import * as something from "../something";
+"#;
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, expected);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "writes_organize_imports",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn organize_imports_sorts_the_path_aliases() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("tsconfig.json").into(),
+        r#"{ "compilerOptions": { "paths": { "@app/*": ["./src/*"] } } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("check.ts");
+    let content = r#"import { a } from "./a";
+import { button } from "@app/button";
+import React from "react";
+"#;
+    let expected = r#"import React from "react";
+import { button } from "@app/button";
+import { a } from "./a";
+"#;
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, expected);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "organize_imports_sorts_the_path_aliases",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn shows_organize_imports_diff_on_check() {
     let mut fs = MemoryFileSystem::default();
@@ -2730,3 +2821,230 @@ fn use_literal_keys_should_emit_correct_ast_issue_266() {
         result,
     ));
 }
+
+#[test]
+fn watch_and_stdin_file_path_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    console
+        .in_buffer
+        .push("function f() {return{}}".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["check", "--watch", "--stdin-file-path", "mock.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+const INTERACTIVE_BEFORE: &str = "1 >= -0;\n2 >= -0;\n";
+
+#[test]
+fn interactive_applies_only_the_accepted_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), INTERACTIVE_BEFORE.as_bytes());
+    console.in_buffer.push("no".to_string());
+    console.in_buffer.push("yes".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply"),
+                ("--interactive"),
+                ("--formatter-enabled=false"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    // The skipped fix leaves an error in the file
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(console.in_buffer.is_empty());
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(buffer, "1 >= -0;\n2 >= 0;\n");
+}
+
+#[test]
+fn interactive_remembers_the_answer_for_all_the_fixes_of_a_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), INTERACTIVE_BEFORE.as_bytes());
+    console.in_buffer.push("all".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply"),
+                ("--interactive"),
+                ("--formatter-enabled=false"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(buffer, "1 >= 0;\n2 >= 0;\n");
+
+    let prompts = console
+        .out_buffer
+        .iter()
+        .filter(|message| {
+            markup_to_string(markup! {{message.content}}).starts_with("Apply this fix?")
+        })
+        .count();
+    assert_eq!(prompts, 1);
+}
+
+#[test]
+fn interactive_reports_the_skipped_suggested_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), "1 >= -0;\ndebugger;\n".as_bytes());
+    console.in_buffer.push("yes".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply"),
+                ("--interactive"),
+                ("--formatter-enabled=false"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    // The unsafe fix of `noDebugger` isn't proposed, so its error remains
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(console.in_buffer.is_empty());
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(buffer, "1 >= 0;\ndebugger;\n");
+
+    assert!(console.out_buffer.iter().any(|message| {
+        markup_to_string(markup! {{message.content}}).contains("Skipped 1 suggested fixes.")
+    }));
+}
+
+#[test]
+fn interactive_requires_apply() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), INTERACTIVE_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--interactive"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn stages_the_fixes_of_the_staged_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "git" } }"#.as_bytes(),
+    );
+    fs.insert(Path::new("staged.js").into(), "staged(  );\n".as_bytes());
+    fs.insert_staged_content("staged.js", "staged(  );\n");
+    fs.set_staged_files(vec![String::from("staged.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--staged"), ("--apply")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, Path::new("staged.js"), "staged();\n");
+    assert_eq!(
+        fs.staged_content("staged.js").as_deref(),
+        Some("staged();\n")
+    );
+}
+
+#[test]
+fn stages_only_the_staged_content_of_partially_staged_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "git" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("partial.js").into(),
+        "staged(  );\nunstaged(  );\n".as_bytes(),
+    );
+    fs.insert_staged_content("partial.js", "staged(  );\n");
+    fs.set_staged_files(vec![String::from("partial.js")]);
+    fs.set_unstaged_files(vec![String::from("partial.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--staged"), ("--apply")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The unstaged changes are fixed in the working directory, but they aren't staged
+    assert_file_contents(&fs, Path::new("partial.js"), "staged();\nunstaged();\n");
+    assert_eq!(
+        fs.staged_content("partial.js").as_deref(),
+        Some("staged();\n")
+    );
+}
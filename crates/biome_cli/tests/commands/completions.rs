@@ -0,0 +1,136 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, markup_to_string, SnapshotPayload};
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+
+fn printed_output(console: &BufferConsole) -> String {
+    console
+        .out_buffer
+        .iter()
+        .map(|message| {
+            markup_to_string(markup! {
+                {message.content}
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Runs `biome completions <shell>` and snapshots the script
+fn assert_completions(test_name: &str, shell: &str) {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("completions"), shell].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        test_name,
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_script_of_bash() {
+    assert_completions("prints_the_script_of_bash", "bash");
+}
+
+#[test]
+fn prints_the_script_of_zsh() {
+    assert_completions("prints_the_script_of_zsh", "zsh");
+}
+
+#[test]
+fn prints_the_script_of_fish() {
+    assert_completions("prints_the_script_of_fish", "fish");
+}
+
+#[test]
+fn prints_the_script_of_elvish() {
+    assert_completions("prints_the_script_of_elvish", "elvish");
+}
+
+#[test]
+fn prints_the_script_of_powershell() {
+    assert_completions("prints_the_script_of_powershell", "powershell");
+}
+
+#[test]
+fn unsupported_shell() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("completions"), ("tcsh")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "unsupported_shell",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_cli_as_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("--help-json")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let output = printed_output(&console);
+    let schema: serde_json::Value = serde_json::from_str(&output).expect("valid JSON");
+    assert_eq!(schema["name"], "biome");
+
+    let commands = schema["commands"].as_array().expect("a list of commands");
+    let check = commands
+        .iter()
+        .find(|command| command["name"] == "check")
+        .expect("the check command");
+    let options: Vec<_> = check["sections"]
+        .as_array()
+        .expect("a list of sections")
+        .iter()
+        .flat_map(|section| section["items"].as_array().expect("a list of items"))
+        .collect();
+
+    let max_diagnostics = options
+        .iter()
+        .find(|option| option["name"] == "--max-diagnostics")
+        .expect("the --max-diagnostics option");
+    assert_eq!(max_diagnostics["argument"], "<none|NUMBER>");
+    assert_eq!(max_diagnostics["default"], "20");
+    assert_eq!(max_diagnostics["short"], serde_json::Value::Null);
+    assert!(options.iter().any(|option| option["name"] == "--apply"));
+
+    let profile = options
+        .iter()
+        .find(|option| option["name"] == "--profile")
+        .expect("the --profile option");
+    assert_eq!(profile["env"], "BIOME_PROFILE");
+    assert!(commands
+        .iter()
+        .all(|command| command["name"] != "__run_server"));
+}
@@ -0,0 +1,76 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+
+/// Runs `biome explain <name>` and snapshots the documentation
+fn assert_explain(test_name: &str, name: &str) {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["explain", name].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        test_name,
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn explains_a_rule() {
+    assert_explain("explains_a_rule", "noDebugger");
+}
+
+#[test]
+fn explains_a_rule_using_its_category() {
+    assert_explain(
+        "explains_a_rule_using_its_category",
+        "lint/suspicious/noDebugger",
+    );
+}
+
+#[test]
+fn explains_the_options_of_a_rule() {
+    assert_explain(
+        "explains_the_options_of_a_rule",
+        "noExcessiveCognitiveComplexity",
+    );
+}
+
+#[test]
+fn explains_the_daemon_logs() {
+    assert_explain("explains_the_daemon_logs", "daemon-logs");
+}
+
+#[test]
+fn does_not_explain_unknown_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["explain", "noSuchRule"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_explain_unknown_rules",
+        fs,
+        console,
+        result,
+    ));
+}
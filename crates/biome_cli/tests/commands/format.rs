@@ -182,6 +182,74 @@ fn print() {
     ));
 }
 
+#[test]
+fn print_unified_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--diff-style=unified"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "print_unified_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn print_without_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--diff=off"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "print_without_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn write() {
     let mut fs = MemoryFileSystem::default();
@@ -1403,6 +1471,51 @@ fn file_too_large_config_limit() {
     ));
 }
 
+#[test]
+fn file_too_large_override_limit() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        PathBuf::from("biome.json"),
+        r#"{
+  "overrides": [
+    {
+      "include": ["generated.js"],
+      "files": {
+        "maxSize": 16
+      }
+    }
+  ]
+}"#,
+    );
+
+    fs.insert(
+        Path::new("format.js").into(),
+        "statement1();\nstatement2();\n",
+    );
+    fs.insert(
+        Path::new("generated.js").into(),
+        "statement1();\nstatement2();\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("format.js"), ("generated.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "file_too_large_override_limit",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn file_too_large_cli_limit() {
     let mut fs = MemoryFileSystem::default();
@@ -1955,6 +2068,104 @@ fn treat_known_json_files_as_jsonc_files() {
     ));
 }
 
+#[test]
+fn treat_tsconfig_and_vscode_files_as_jsonc_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let code = r#"{
+	// comment
+	"a": 1,
+}
+"#;
+    let tsconfig = Path::new("tsconfig.json");
+    fs.insert(tsconfig.into(), code.as_bytes());
+    let tsconfig_build = Path::new("tsconfig.build.json");
+    fs.insert(tsconfig_build.into(), code.as_bytes());
+    let vscode_settings = Path::new(".vscode/settings.json");
+    fs.insert(vscode_settings.into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                tsconfig.as_os_str().to_str().unwrap(),
+                tsconfig_build.as_os_str().to_str().unwrap(),
+                vscode_settings.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "treat_tsconfig_and_vscode_files_as_jsonc_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn sort_package_json_keys() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{
+    "json": {
+        "formatter": {
+            "sortPackageJsonKeys": true
+        }
+    }
+}"#,
+    );
+
+    let code = r#"{
+	"scripts": { "build": "tsc" },
+	"version": "1.0.0",
+	"custom": true,
+	"name": "app",
+	"dependencies": {}
+}
+"#;
+    let package_json = Path::new("package.json");
+    fs.insert(package_json.into(), code.as_bytes());
+    let other_json = Path::new("other.json");
+    fs.insert(other_json.into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                package_json.as_os_str().to_str().unwrap(),
+                other_json.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "sort_package_json_keys",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_apply_different_formatting() {
     let mut fs = MemoryFileSystem::default();
@@ -2,7 +2,7 @@ use crate::configs::{CONFIG_INIT_DEFAULT, CONFIG_INIT_DEFAULT_WHEN_INSTALLED};
 use crate::run_cli;
 use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
 use biome_console::BufferConsole;
-use biome_fs::MemoryFileSystem;
+use biome_fs::{FileSystemExt, MemoryFileSystem};
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
 use biome_service::DynRef;
@@ -99,3 +99,99 @@ fn creates_config_file_when_biome_installed_via_package_manager() {
         result,
     ));
 }
+
+fn read_file(fs: &MemoryFileSystem, path: &str) -> String {
+    let mut file = fs
+        .open(Path::new(path))
+        .expect("the configuration file should have been created");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    content
+}
+
+#[test]
+fn creates_jsonc_config_file_with_comments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["init", "--jsonc"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = read_file(&fs, "biome.jsonc");
+    assert!(
+        content.contains("// The linter analyzes the code"),
+        "{content}"
+    );
+    assert!(content.contains("\"recommended\": true"), "{content}");
+    assert!(fs.open(Path::new("biome.json")).is_err());
+}
+
+#[test]
+fn does_not_create_jsonc_config_file_when_biome_json_exists() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("biome.json").into(), *b"{}");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["init", "--jsonc"].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn creates_config_file_from_the_answers() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    console.in_buffer.extend(
+        ["space", "single", "all", "yes"]
+            .into_iter()
+            .map(String::from),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["init", "--interactive"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = read_file(&fs, "biome.json");
+    let configuration: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(configuration["formatter"]["indentStyle"], "space");
+    assert_eq!(
+        configuration["javascript"]["formatter"]["quoteStyle"],
+        "single"
+    );
+    assert_eq!(configuration["linter"]["rules"]["all"], true);
+    assert_eq!(configuration["vcs"]["useIgnoreFile"], true);
+}
+
+#[test]
+fn uses_the_default_answers() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["init", "--interactive"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = read_file(&fs, "biome.json");
+    let configuration: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(configuration["formatter"]["indentStyle"], "tab");
+    assert_eq!(configuration["linter"]["rules"]["recommended"], true);
+    assert!(configuration.get("vcs").is_none());
+}
@@ -1092,6 +1092,55 @@ fn fs_files_ignore_symlink() {
     ));
 }
 
+#[test]
+fn fs_files_follow_symlinks_disabled() {
+    let mut console = BufferConsole::default();
+
+    let root_path = temp_dir().join("lint_rome_test_files_follow_symlinks_disabled");
+    let src_path = root_path.join("src");
+    let linked_path = root_path.join("linked");
+
+    let _ = remove_dir_all(&root_path);
+    create_dir_all(&src_path).unwrap();
+    create_dir(&linked_path).unwrap();
+
+    for file_path in [src_path.join("test.js"), linked_path.join("test.js")] {
+        let mut file = File::create(file_path).unwrap();
+        file.write_all(FORMATTED.as_bytes()).unwrap();
+    }
+
+    #[cfg(target_family = "unix")]
+    {
+        symlink(&linked_path, src_path.join("symlink")).unwrap();
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        check_windows_symlink!(symlink_dir(&linked_path, src_path.join("symlink")));
+    }
+
+    let result = run_cli(
+        DynRef::Owned(Box::new(OsFileSystem)),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--files-follow-symlinks=false"),
+                (src_path.display().to_string().as_str()),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    remove_dir_all(root_path).unwrap();
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(console
+        .out_buffer
+        .iter()
+        .any(|msg| markup_to_string(markup! {{msg.content}}).starts_with("Checked 1 file(s)")));
+}
+
 #[test]
 fn file_too_large() {
     let mut fs = MemoryFileSystem::default();
@@ -2328,3 +2377,463 @@ A = 0;
         result,
     ));
 }
+
+#[test]
+fn should_only_process_changed_files_if_changed_flag_is_set() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "git", "defaultBranch": "main" } }"#
+            .as_bytes(),
+    );
+    fs.insert(Path::new("changed.js").into(), FORMATTED.as_bytes());
+    fs.insert(Path::new("unchanged.js").into(), NO_DEBUGGER.as_bytes());
+    fs.set_changed_files(vec![String::from("changed.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_process_changed_files_if_changed_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_lint_files_changed_since_the_given_reference() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "git" } }"#.as_bytes(),
+    );
+    fs.insert(Path::new("changed.js").into(), NO_DEBUGGER.as_bytes());
+    fs.set_changed_files(vec![String::from("changed.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_lint_files_changed_since_the_given_reference",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_changed_flag_is_set_without_default_branch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "git" } }"#.as_bytes(),
+    );
+    fs.set_changed_files(vec![String::from("changed.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_changed_flag_is_set_without_default_branch",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_changed_flag_is_set_and_vcs_is_disabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_changed_files(vec![String::from("changed.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_changed_flag_is_set_and_vcs_is_disabled",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_changed_and_staged_flags_are_set() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "git", "defaultBranch": "main" } }"#
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed", "--staged"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_changed_and_staged_flags_are_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_only_process_staged_files_if_staged_flag_is_set() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "git" } }"#.as_bytes(),
+    );
+    fs.insert(Path::new("staged.js").into(), FORMATTED.as_bytes());
+    fs.insert(Path::new("unstaged.js").into(), NO_DEBUGGER.as_bytes());
+    fs.set_staged_files(vec![String::from("staged.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--staged"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_process_staged_files_if_staged_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_not_error_if_no_files_are_staged() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "git" } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--staged"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_not_error_if_no_files_are_staged",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn loads_the_configuration_from_biome_jsonc() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.jsonc").into(),
+        r#"{
+  // the debugger statements are allowed
+  "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn max_diagnostics_none() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    for i in 0..50 {
+        let file_path = PathBuf::from(format!("src/file_{i}.js"));
+        fs.insert(file_path, LINT_ERROR.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--max-diagnostics=none"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let diagnostic_count = console
+        .out_buffer
+        .iter()
+        .filter(|msg| {
+            let MarkupBuf(nodes) = &msg.content;
+            nodes.iter().any(|node| node.content.contains("lint"))
+        })
+        .count();
+
+    assert!(diagnostic_count >= 50, "{diagnostic_count}");
+}
+
+#[test]
+fn exits_with_distinct_codes_for_errors_and_warnings() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } } }"#.as_bytes(),
+    );
+    fs.insert(Path::new("warning.js").into(), "debugger;\n".as_bytes());
+    fs.insert(
+        Path::new("error.js").into(),
+        "class A {};\nA = 0;\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--error-on-warnings"), ("warning.js")].as_slice()),
+    );
+    assert_eq!(result.unwrap_err().exit_code(), 2);
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--error-on-warnings"),
+                ("warning.js"),
+                ("error.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert_eq!(result.unwrap_err().exit_code(), 1);
+}
+
+#[test]
+fn progress_doesnt_change_the_output() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    for i in 0..10 {
+        let file_path = PathBuf::from(format!("src/file_{i}.js"));
+        fs.insert(file_path, LINT_ERROR.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--progress"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(console
+        .out_buffer
+        .iter()
+        .any(|msg| markup_to_string(markup! {{msg.content}}).starts_with("Checked 10 file(s)")));
+}
+
+#[test]
+fn progress_is_shown_as_the_status_of_a_terminal() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole {
+        is_terminal: true,
+        ..BufferConsole::default()
+    };
+
+    for i in 0..10 {
+        let file_path = PathBuf::from(format!("src/file_{i}.js"));
+        fs.insert(file_path, LINT_ERROR.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--progress"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let statuses: Vec<_> = console
+        .status_buffer
+        .iter()
+        .map(|status| {
+            status
+                .as_ref()
+                .map(|status| markup_to_string(markup! {{status}}))
+        })
+        .collect();
+    assert!(
+        statuses.first().is_some_and(|status| status
+            .as_ref()
+            .is_some_and(|status| status.starts_with("Processed "))),
+        "{statuses:?}"
+    );
+    // The progress is erased before the summary is printed
+    assert_eq!(statuses.last(), Some(&None), "{statuses:?}");
+    assert!(!console
+        .out_buffer
+        .iter()
+        .any(|msg| markup_to_string(markup! {{msg.content}}).starts_with("Processed ")));
+}
+
+#[test]
+fn runs_only_the_selected_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#.as_bytes(),
+    );
+    fs.insert(Path::new("file.js").into(), b"debugger;\nif (a == b) {}\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--rule=suspicious/noDebugger"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let diagnostics: Vec<_> = console
+        .out_buffer
+        .iter()
+        .filter(|message| message.level == LogLevel::Error)
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect();
+    assert!(diagnostics
+        .iter()
+        .any(|content| content.contains("lint/suspicious/noDebugger")));
+    assert!(!diagnostics
+        .iter()
+        .any(|content| content.contains("lint/suspicious/noDoubleEquals")));
+}
+
+#[test]
+fn skips_the_selected_group() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), NO_DEBUGGER_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--skip-rule=suspicious"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn reports_an_unknown_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), NO_DEBUGGER_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--rule=suspicious/noSuchRule"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn groups_the_diagnostics_by_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("first.js").into(), b"debugger;\nif (a == b) {}\n");
+    fs.insert(Path::new("second.js").into(), b"debugger;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--group-by=rule"), ("first.js"), ("second.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .filter(|message| message.level == LogLevel::Error)
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect();
+    let headers: Vec<_> = messages
+        .iter()
+        .filter(|content| content.ends_with("diagnostic(s))"))
+        .collect();
+    assert_eq!(
+        headers,
+        [
+            "lint/suspicious/noDebugger (2 diagnostic(s))",
+            "lint/suspicious/noDoubleEquals (1 diagnostic(s))",
+        ],
+        "{messages:#?}"
+    );
+    assert!(messages[1].contains("lint/suspicious/noDebugger"));
+    assert!(messages[2].contains("lint/suspicious/noDebugger"));
+}
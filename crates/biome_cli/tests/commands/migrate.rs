@@ -132,3 +132,108 @@ fn should_create_biome_json_file() {
         result,
     ));
 }
+
+#[test]
+fn migrate_new_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "$schema": "https://biomejs.dev/schemas/1.4.0/schema.json" }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--new-rules"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, configuration_path, configuration);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_new_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_new_rules_without_schema_version() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--new-rules"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_new_rules_without_schema_version",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_format_config() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{
+	"linter": {
+		"rules": {
+			"style": { "useConst": "off", "noVar": "error" }
+		}
+	},
+	"formatter": { "enabled": true, "lineWidth": 100 },
+	"$schema": "https://biomejs.dev/schemas/1.4.0/schema.json"
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--format-config", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        configuration_path,
+        r#"{
+	"$schema": "https://biomejs.dev/schemas/1.4.0/schema.json",
+	"formatter": { "lineWidth": 100 },
+	"linter": {
+		"rules": {
+			"style": { "noVar": "error", "useConst": "off" }
+		}
+	}
+}"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_format_config",
+        fs,
+        console,
+        result,
+    ));
+}
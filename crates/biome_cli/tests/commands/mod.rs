@@ -1,9 +1,12 @@
 mod check;
 mod ci;
+mod completions;
+mod explain;
 mod format;
 mod init;
 mod lint;
 mod lsp_proxy;
 mod migrate;
 mod rage;
+mod search;
 mod version;
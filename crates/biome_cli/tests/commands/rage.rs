@@ -1,7 +1,7 @@
 use crate::run_cli;
-use crate::snap_test::{assert_cli_snapshot, CliSnapshot, SnapshotPayload};
+use crate::snap_test::{assert_cli_snapshot, markup_to_string, CliSnapshot, SnapshotPayload};
 use biome_cli::CliDiagnostic;
-use biome_console::{BufferConsole, Console};
+use biome_console::{markup, BufferConsole, Console};
 use biome_fs::{FileSystem, MemoryFileSystem};
 use biome_service::DynRef;
 use bpaf::Args;
@@ -181,6 +181,102 @@ Not most recent log file
     ));
 }
 
+#[test]
+fn with_paths_and_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").to_path_buf(),
+        r#"{
+  "linter": {
+    "ignore": ["ignored.js"],
+    "rules": {
+      "style": { "useBlockStatements": "error" },
+      "suspicious": { "noDebugger": "off" }
+    }
+  }
+}"#,
+    );
+    fs.insert(Path::new("file.js").to_path_buf(), "debugger;");
+    fs.insert(Path::new("ignored.js").to_path_buf(), "debugger;");
+
+    let result = run_rage(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rage"), ("file.js"), ("ignored.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(
+        content.contains("  Enabled rules:                style/useBlockStatements\n"),
+        "{content}"
+    );
+    assert!(
+        content.contains("  Disabled recommended rules:   suspicious/noDebugger\n"),
+        "{content}"
+    );
+    assert!(
+        content.contains("Path file.js:\n  Formatter:                    enabled\n  Linter:                       enabled\n"),
+        "{content}"
+    );
+    assert!(
+        content.contains("Path ignored.js:\n  Formatter:                    enabled\n  Linter:                       ignored by linter.ignore\n"),
+        "{content}"
+    );
+    assert!(
+        content.contains("biome.json\n  Settings:\n    {\n"),
+        "{content}"
+    );
+    assert!(
+        content.contains("\"useBlockStatements\": \"error\""),
+        "{content}"
+    );
+}
+
+#[test]
+fn with_paths_ignored_by_the_vcs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").to_path_buf(),
+        r#"{
+  "vcs": { "enabled": true, "clientKind": "git", "useIgnoreFile": true }
+}"#,
+    );
+    fs.insert(Path::new(".gitignore").to_path_buf(), "# build\ndist/\n");
+    fs.insert(Path::new("dist/index.js").to_path_buf(), "debugger;");
+
+    let result = run_rage(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rage"), ("dist/index.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(
+        content.contains(
+            "  Formatter:                    ignored by the pattern dist/ of .gitignore:2\n"
+        ),
+        "{content}"
+    );
+}
+
 /// Runs the `rage` command mocking out the log directory.
 fn run_rage<'app>(
     fs: DynRef<'app, dyn FileSystem>,
@@ -204,7 +300,7 @@ fn assert_rage_snapshot(payload: SnapshotPayload<'_>) {
             .map(|line| match line.trim_start().split_once(':') {
                 Some((
                     "CPU Architecture" | "OS" | "NO_COLOR" | "TERM" | "BIOME_LOG_DIR"
-                    | "Color support",
+                    | "Color support" | "Memory Usage",
                     value,
                 )) => line.replace(value.trim_start(), "**PLACEHOLDER**"),
                 _ => line.to_string(),
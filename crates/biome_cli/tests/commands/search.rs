@@ -0,0 +1,99 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const CONSOLE_CALLS: &str = r#"console.log("first");
+console.info("not a match");
+function f() {
+    return console.log(value, 1);
+}
+console.log(  "third"  );
+"#;
+
+#[test]
+fn prints_the_matches_of_the_pattern() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), CONSOLE_CALLS.as_bytes());
+    fs.insert(Path::new("other.ts").into(), b"let a: string = 'a';");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                ("console.log($message)"),
+                ("file.js"),
+                ("other.ts"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prints_the_matches_of_the_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn metavariables_must_match_the_same_code() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("file.js").into(),
+        b"if (a === a) {}\nif (a === b) {}\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("search"), ("$left === $left"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "metavariables_must_match_the_same_code",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_an_invalid_pattern() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), CONSOLE_CALLS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("search"), ("console.log("), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_an_invalid_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -137,6 +137,24 @@ fn redact_snapshot(input: &str) -> Cow<'_, str> {
             .replace_range(start + PATTERN.len().., "<TIME>");
     }
 
+    // The summary reporter prints how long each file took to process, after its path
+    const SLOWEST_FILES: &str = "Slowest files:\n";
+    if let Some(start) = output.find(SLOWEST_FILES) {
+        let start = start + SLOWEST_FILES.len();
+        let end = output[start..]
+            .find("\n\n")
+            .map_or(output.len(), |end| start + end);
+        let rows = output[start..end]
+            .lines()
+            .map(|row| match row.rsplit_once("  ") {
+                Some((path, _)) => format!("{path}  <TIME>"),
+                None => row.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        output.to_mut().replace_range(start..end, &rows);
+    }
+
     // Normalize the name of the current executable to "biome"
     let current_exe = current_exe()
         .ok()
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, fmt, io, time::Duration};
 
-pub use crate::write::{Termcolor, Write, HTML};
+pub use crate::write::{Termcolor, ThemedTermcolor, Write, HTML};
 use crate::{markup, Markup, MarkupElement};
 
 /// A stack-allocated linked-list of [MarkupElement] slices
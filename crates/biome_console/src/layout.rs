@@ -0,0 +1,396 @@
+//! Layout primitives, used to print aligned data without padding it manually
+
+use std::io;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::fmt::{Display, Formatter};
+use crate::markup::MarkupNodeBuf;
+use crate::{Markup, MarkupBuf, MarkupElement};
+
+/// The number of spaces printed between two columns of a [Table]
+const COLUMN_GAP: usize = 2;
+
+/// The narrowest width a piece of text is wrapped to. Wrapping to fewer
+/// columns makes the text harder to read than letting it overflow.
+const MIN_WRAP_WIDTH: usize = 20;
+
+/// A table whose columns are aligned, printed without borders.
+///
+/// The width of each column is the width of its widest cell. When the table
+/// has a maximum width, the cells of the last column are wrapped so that the
+/// rows fit in it.
+///
+/// # Example
+/// ```
+/// use biome_console::{markup, Table};
+///
+/// let mut table = Table::new().with_indent(2);
+/// table.add_row([markup!("Version"), markup!(<Emphasis>"1.5.0"</Emphasis>)]);
+/// table.add_row([markup!("Color support"), markup!("true")]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    rows: Vec<Vec<MarkupBuf>>,
+    indent: usize,
+    max_width: Option<usize>,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prints each row after `indent` spaces
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Wraps the cells of the last column so that the rows, including the
+    /// indentation, fit in `max_width` columns
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Appends a row to the table. The rows don't need to have the same number of cells.
+    pub fn add_row<'a>(&mut self, cells: impl IntoIterator<Item = Markup<'a>>) {
+        self.rows
+            .push(cells.into_iter().map(|cell| cell.to_owned()).collect());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// The width of each column, computed from its widest cell
+    fn column_widths(&self) -> Vec<usize> {
+        let mut widths = Vec::new();
+        for row in &self.rows {
+            for (index, cell) in row.iter().enumerate() {
+                let cell_width = split_lines(cell).iter().map(width).max().unwrap_or(0);
+                match widths.get_mut(index) {
+                    Some(column_width) => *column_width = cell_width.max(*column_width),
+                    None => widths.push(cell_width),
+                }
+            }
+        }
+        widths
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let widths = self.column_widths();
+
+        for row in &self.rows {
+            let last = row.len().saturating_sub(1);
+            let cells: Vec<_> = row
+                .iter()
+                .enumerate()
+                .map(|(index, cell)| {
+                    let offset = self.indent
+                        + widths[..index]
+                            .iter()
+                            .map(|width| width + COLUMN_GAP)
+                            .sum::<usize>();
+                    match self.max_width {
+                        Some(max_width) if index == last => {
+                            wrap(cell, max_width.saturating_sub(offset))
+                        }
+                        _ => split_lines(cell),
+                    }
+                })
+                .collect();
+
+            let height = cells.iter().map(Vec::len).max().unwrap_or(1);
+            for line in 0..height {
+                // The padding is printed only before the cells that have content,
+                // so the lines don't end with whitespace
+                let filled = cells
+                    .iter()
+                    .rposition(|cell| cell.get(line).is_some_and(|line| !line.is_empty()));
+
+                write_spaces(fmt, self.indent)?;
+                for (index, cell) in cells.iter().enumerate() {
+                    if Some(index) > filled {
+                        break;
+                    }
+
+                    let content = cell.get(line);
+                    if let Some(content) = content {
+                        content.fmt(fmt)?;
+                    }
+                    if Some(index) < filled {
+                        let used = content.map_or(0, width);
+                        write_spaces(fmt, widths[index] - used + COLUMN_GAP)?;
+                    }
+                }
+                fmt.write_str("\n")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A tree printed with box-drawing characters, one node per line.
+///
+/// When the tree has a maximum width, the labels are wrapped so that the
+/// lines fit in it, and the wrapped lines are aligned with the label.
+///
+/// # Example
+/// ```
+/// use biome_console::{markup, Tree};
+///
+/// let tree = Tree::new(markup!("biome.json")).with_child(
+///     Tree::new(markup!("linter")).with_child(Tree::new(markup!("recommended"))),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tree {
+    label: MarkupBuf,
+    children: Vec<Tree>,
+    max_width: Option<usize>,
+}
+
+impl Tree {
+    pub fn new(label: Markup) -> Self {
+        Self {
+            label: label.to_owned(),
+            children: Vec::new(),
+            max_width: None,
+        }
+    }
+
+    /// Wraps the labels so that the lines, including the branches, fit in `max_width` columns
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    pub fn with_child(mut self, child: Tree) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn add_child(&mut self, child: Tree) {
+        self.children.push(child);
+    }
+
+    /// Prints the label of the node after `prefix`, then its children.
+    ///
+    /// `prefix` contains the branches of the ancestors, `branch` is printed
+    /// before the first line of the label, and `continuation` before the
+    /// following lines and the branches of the children.
+    fn fmt_node(
+        &self,
+        fmt: &mut Formatter,
+        max_width: Option<usize>,
+        prefix: &str,
+        branch: &str,
+        continuation: &str,
+    ) -> io::Result<()> {
+        let offset = prefix.width() + branch.width();
+        let lines = match max_width {
+            Some(max_width) => wrap(&self.label, max_width.saturating_sub(offset)),
+            None => split_lines(&self.label),
+        };
+
+        for (index, line) in lines.iter().enumerate() {
+            fmt.write_str(prefix)?;
+            fmt.write_str(if index == 0 { branch } else { continuation })?;
+            line.fmt(fmt)?;
+            fmt.write_str("\n")?;
+        }
+
+        let prefix = format!("{prefix}{continuation}");
+        let last = self.children.len().saturating_sub(1);
+        for (index, child) in self.children.iter().enumerate() {
+            if index == last {
+                child.fmt_node(fmt, max_width, &prefix, "\u{2514}\u{2500}\u{2500} ", "    ")?;
+            } else {
+                child.fmt_node(
+                    fmt,
+                    max_width,
+                    &prefix,
+                    "\u{251c}\u{2500}\u{2500} ",
+                    "\u{2502}   ",
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Tree {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        self.fmt_node(fmt, self.max_width, "", "", "")
+    }
+}
+
+fn write_spaces(fmt: &mut Formatter, count: usize) -> io::Result<()> {
+    for _ in 0..count {
+        fmt.write_str(" ")?;
+    }
+    Ok(())
+}
+
+/// The number of columns taken by the text of `markup` in the terminal
+fn width(markup: &MarkupBuf) -> usize {
+    markup.0.iter().map(|node| node.content.width()).sum()
+}
+
+/// Appends `content` to `markup`, merging it with the last node when it has the same elements
+fn push(markup: &mut MarkupBuf, elements: &[MarkupElement<'static>], content: &str) {
+    if content.is_empty() {
+        return;
+    }
+
+    if let Some(last) = markup.0.last_mut() {
+        if last.elements == elements {
+            last.content.push_str(content);
+            return;
+        }
+    }
+
+    markup.0.push(MarkupNodeBuf {
+        elements: elements.to_vec(),
+        content: content.to_string(),
+    });
+}
+
+/// Splits `markup` on its line breaks, keeping the elements of each piece of text
+fn split_lines(markup: &MarkupBuf) -> Vec<MarkupBuf> {
+    let mut lines = vec![MarkupBuf::default()];
+    for node in &markup.0 {
+        for (index, line) in node.content.split('\n').enumerate() {
+            if index > 0 {
+                lines.push(MarkupBuf::default());
+            }
+            if let Some(current) = lines.last_mut() {
+                push(current, &node.elements, line);
+            }
+        }
+    }
+    lines
+}
+
+/// Splits `markup` on its line breaks, then wraps the lines longer than `max_width`
+/// on their spaces. The words longer than `max_width` aren't split.
+fn wrap(markup: &MarkupBuf, max_width: usize) -> Vec<MarkupBuf> {
+    let max_width = max_width.max(MIN_WRAP_WIDTH);
+    let mut lines = Vec::new();
+
+    for line in split_lines(markup) {
+        let mut current = MarkupBuf::default();
+        let mut current_width = 0;
+        // The spaces are printed only if a word follows them on the same line
+        let mut spaces: Vec<(&[MarkupElement<'static>], &str)> = Vec::new();
+
+        for node in &line.0 {
+            for (index, word) in node.content.split(' ').enumerate() {
+                if index > 0 {
+                    spaces.push((&node.elements, " "));
+                }
+                if word.is_empty() {
+                    continue;
+                }
+
+                let spaces_width = spaces.len();
+                let word_width = word.width();
+                if current_width > 0 && current_width + spaces_width + word_width > max_width {
+                    lines.push(std::mem::take(&mut current));
+                    current_width = 0;
+                } else {
+                    for (elements, space) in &spaces {
+                        push(&mut current, elements, space);
+                    }
+                    current_width += spaces_width;
+                }
+                spaces.clear();
+
+                push(&mut current, &node.elements, word);
+                current_width += word_width;
+            }
+        }
+
+        lines.push(current);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_markup::markup;
+
+    use crate as biome_console;
+    use crate::fmt::Formatter;
+    use crate::{MarkupBuf, Table, Tree};
+
+    fn to_string(markup: biome_console::Markup) -> String {
+        let mut buffer = MarkupBuf::default();
+        Formatter::new(&mut buffer).write_markup(markup).unwrap();
+        buffer.0.into_iter().map(|node| node.content).collect()
+    }
+
+    #[test]
+    fn aligns_the_columns() {
+        let mut table = Table::new().with_indent(2);
+        table.add_row([markup!("a"), markup!("first"), markup!("1")]);
+        table.add_row([markup!("longer"), markup!("second")]);
+        table.add_row([
+            markup!(""),
+            markup!(<Emphasis>"third"</Emphasis>),
+            markup!("3"),
+        ]);
+
+        assert_eq!(
+            to_string(markup!({ table })),
+            "  a       first   1\n  longer  second\n          third   3\n"
+        );
+    }
+
+    #[test]
+    fn wraps_the_last_column() {
+        let mut table = Table::new().with_max_width(30);
+        table.add_row([
+            markup!("key"),
+            markup!("the value is "<Emphasis>"long enough"</Emphasis>" to be wrapped"),
+        ]);
+
+        assert_eq!(
+            to_string(markup!({ table })),
+            "key  the value is long enough\n     to be wrapped\n"
+        );
+    }
+
+    #[test]
+    fn prints_the_tree() {
+        let tree = Tree::new(markup!("root"))
+            .with_child(Tree::new(markup!("first")).with_child(Tree::new(markup!("nested"))))
+            .with_child(Tree::new(markup!("second")));
+
+        assert_eq!(
+            to_string(markup!({ tree })),
+            "root\n\u{251c}\u{2500}\u{2500} first\n\u{2502}   \u{2514}\u{2500}\u{2500} nested\n\u{2514}\u{2500}\u{2500} second\n"
+        );
+    }
+
+    #[test]
+    fn wraps_the_labels_of_the_tree() {
+        let tree = Tree::new(markup!("root"))
+            .with_max_width(30)
+            .with_child(Tree::new(markup!(
+                "a label that doesn't fit on a single line"
+            )));
+
+        assert_eq!(
+            to_string(markup!({ tree })),
+            "root\n\u{2514}\u{2500}\u{2500} a label that doesn't fit\n    on a single line\n"
+        );
+    }
+}
@@ -1,16 +1,21 @@
 use std::io;
-use std::io::{stderr, stdin, stdout, IsTerminal, Read, Stdin, Write};
+use std::io::{stderr, stdin, stdout, BufRead, IsTerminal, Read, Stdin, Write};
 use std::panic::RefUnwindSafe;
 use termcolor::{ColorChoice, StandardStream};
-use write::Termcolor;
+use write::ThemedTermcolor;
 
 pub mod fmt;
+mod layout;
 mod markup;
+mod theme;
 mod write;
 
+pub use self::layout::{Table, Tree};
 pub use self::markup::{Markup, MarkupBuf, MarkupElement, MarkupNode};
+pub use self::theme::{ParseThemeError, Theme};
 use crate::fmt::Formatter;
 pub use biome_markup::markup;
+pub use termcolor::Color;
 
 /// Determines the "output stream" a message should get printed to
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -36,6 +41,24 @@ pub trait Console: Send + Sync + RefUnwindSafe {
 
     /// It reads from a source, and if this source contains something, it's converted into a [String]
     fn read(&mut self) -> Option<String>;
+
+    /// It reads a single line typed by the user, without the line terminator.
+    ///
+    /// It returns [None] if the source was closed.
+    fn read_line(&mut self) -> Option<String>;
+
+    /// Returns `true` if the messages with the given `level` are printed to an interactive
+    /// terminal
+    fn is_terminal(&self, _level: LogLevel) -> bool {
+        false
+    }
+
+    /// Shows a transient status, e.g. a progress indicator, on the last line of the stream
+    /// of the given `level`. It replaces the previous status, and [None] erases it. The status
+    /// is also erased before printing a message.
+    ///
+    /// The status is shown only when the stream is a terminal.
+    fn set_status(&mut self, _level: LogLevel, _status: Option<Markup>) {}
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -76,6 +99,10 @@ pub struct EnvConsole {
     err: StandardStream,
     /// Channel to read arbitrary input
     r#in: Stdin,
+    /// The colors used to print the markup
+    theme: Theme,
+    /// The stream where a status is currently shown
+    status: Option<LogLevel>,
 }
 
 #[derive(Debug, Clone)]
@@ -86,15 +113,29 @@ pub enum ColorMode {
     Disabled,
     /// Print colors if stdout / stderr are determined to be TTY / Console
     /// streams, and the `TERM=dumb` and `NO_COLOR` environment variables are
-    /// not set
+    /// not set. Colors are always printed when the `FORCE_COLOR` environment
+    /// variable is set, unless `NO_COLOR` is set too.
     Auto,
 }
 
+/// Whether `NO_COLOR` is set to a non-empty value, see <https://no-color.org/>
+fn is_no_color_set() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Whether `FORCE_COLOR` is set to a value other than an empty string, `0` or `false`
+fn is_force_color_set() -> bool {
+    std::env::var_os("FORCE_COLOR")
+        .is_some_and(|value| !value.is_empty() && value != "0" && value != "false")
+}
+
 impl EnvConsole {
     fn compute_color(colors: ColorMode) -> (ColorChoice, ColorChoice) {
         match colors {
             ColorMode::Enabled => (ColorChoice::Always, ColorChoice::Always),
             ColorMode::Disabled => (ColorChoice::Never, ColorChoice::Never),
+            ColorMode::Auto if is_no_color_set() => (ColorChoice::Never, ColorChoice::Never),
+            ColorMode::Auto if is_force_color_set() => (ColorChoice::Always, ColorChoice::Always),
             ColorMode::Auto => {
                 let stdout = if stdout().is_terminal() {
                     ColorChoice::Auto
@@ -120,6 +161,8 @@ impl EnvConsole {
             out: StandardStream::stdout(out_mode),
             err: StandardStream::stderr(err_mode),
             r#in: io::stdin(),
+            theme: Theme::from_env(),
+            status: None,
         }
     }
 
@@ -128,6 +171,25 @@ impl EnvConsole {
         self.out = StandardStream::stdout(out_mode);
         self.err = StandardStream::stderr(err_mode);
     }
+
+    /// Changes the colors used to print the markup
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Erases the status, if one is shown
+    fn clear_status(&mut self) {
+        let Some(level) = self.status.take() else {
+            return;
+        };
+        let mut out = match level {
+            LogLevel::Error => self.err.lock(),
+            LogLevel::Log => self.out.lock(),
+        };
+        // Moves to the start of the line and clears it
+        write!(out, "\r\x1b[2K").unwrap();
+        out.flush().unwrap();
+    }
 }
 
 impl Default for EnvConsole {
@@ -138,12 +200,13 @@ impl Default for EnvConsole {
 
 impl Console for EnvConsole {
     fn println(&mut self, level: LogLevel, args: Markup) {
+        self.clear_status();
         let mut out = match level {
             LogLevel::Error => self.err.lock(),
             LogLevel::Log => self.out.lock(),
         };
 
-        fmt::Formatter::new(&mut Termcolor(&mut out))
+        fmt::Formatter::new(&mut ThemedTermcolor::new(&mut out, self.theme))
             .write_markup(args)
             .unwrap();
 
@@ -151,12 +214,13 @@ impl Console for EnvConsole {
     }
 
     fn print(&mut self, level: LogLevel, args: Markup) {
+        self.clear_status();
         let mut out = match level {
             LogLevel::Error => self.err.lock(),
             LogLevel::Log => self.out.lock(),
         };
 
-        fmt::Formatter::new(&mut Termcolor(&mut out))
+        fmt::Formatter::new(&mut ThemedTermcolor::new(&mut out, self.theme))
             .write_markup(args)
             .unwrap();
 
@@ -181,6 +245,45 @@ impl Console for EnvConsole {
             None
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // The prompt is usually printed without a new line, so it must be flushed
+        // before waiting for the user
+        self.out.flush().ok()?;
+
+        let mut buffer = String::new();
+        match self.r#in.lock().read_line(&mut buffer) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(buffer.trim_end_matches(['\n', '\r']).to_string()),
+        }
+    }
+
+    fn is_terminal(&self, level: LogLevel) -> bool {
+        match level {
+            LogLevel::Error => stderr().is_terminal(),
+            LogLevel::Log => stdout().is_terminal(),
+        }
+    }
+
+    fn set_status(&mut self, level: LogLevel, status: Option<Markup>) {
+        self.clear_status();
+        let Some(status) = status else {
+            return;
+        };
+        if !self.is_terminal(level) {
+            return;
+        }
+
+        let mut out = match level {
+            LogLevel::Error => self.err.lock(),
+            LogLevel::Log => self.out.lock(),
+        };
+        fmt::Formatter::new(&mut ThemedTermcolor::new(&mut out, self.theme))
+            .write_markup(status)
+            .unwrap();
+        out.flush().unwrap();
+        self.status = Some(level);
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
@@ -188,6 +291,10 @@ impl Console for EnvConsole {
 pub struct BufferConsole {
     pub out_buffer: Vec<Message>,
     pub in_buffer: Vec<String>,
+    /// Whether the console behaves like an interactive terminal
+    pub is_terminal: bool,
+    /// The statuses shown by the console, [None] when the status was erased
+    pub status_buffer: Vec<Option<MarkupBuf>>,
 }
 
 /// Individual message entry printed to a [BufferConsole]
@@ -220,6 +327,26 @@ impl Console for BufferConsole {
             Some(self.in_buffer[0].clone())
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // every message of the buffer is an answer to a different prompt
+        if self.in_buffer.is_empty() {
+            None
+        } else {
+            Some(self.in_buffer.remove(0))
+        }
+    }
+
+    fn is_terminal(&self, _level: LogLevel) -> bool {
+        self.is_terminal
+    }
+
+    fn set_status(&mut self, _level: LogLevel, status: Option<Markup>) {
+        if self.is_terminal {
+            self.status_buffer
+                .push(status.map(|status| status.to_owned()));
+        }
+    }
 }
 
 /// A horizontal line with the given print width
@@ -5,9 +5,10 @@ use std::{
 };
 
 use biome_text_size::TextSize;
-use termcolor::{Color, ColorSpec};
+use termcolor::ColorSpec;
 
 use crate::fmt::{Display, Formatter, MarkupElements, Write};
+use crate::Theme;
 
 /// Enumeration of all the supported markup elements
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -46,8 +47,8 @@ impl fmt::Display for MarkupElement<'_> {
 
 impl<'fmt> MarkupElement<'fmt> {
     /// Mutate a [ColorSpec] object in place to apply this element's associated
-    /// style to it
-    pub(crate) fn update_color(&self, color: &mut ColorSpec) {
+    /// style to it, using the colors of `theme`
+    pub(crate) fn update_color(&self, color: &mut ColorSpec, theme: &Theme) {
         match self {
             // Text Styles
             MarkupElement::Emphasis => {
@@ -65,28 +66,22 @@ impl<'fmt> MarkupElement<'fmt> {
 
             // Text Colors
             MarkupElement::Error => {
-                color.set_fg(Some(Color::Red));
+                color.set_fg(Some(theme.error));
             }
             MarkupElement::Success => {
-                color.set_fg(Some(Color::Green));
+                color.set_fg(Some(theme.success));
             }
             MarkupElement::Warn => {
-                color.set_fg(Some(Color::Yellow));
+                color.set_fg(Some(theme.warn));
             }
             MarkupElement::Info => {
-                color.set_fg(Some(Color::Green));
+                color.set_fg(Some(theme.info));
             }
             MarkupElement::Trace => {
-                color.set_fg(Some(Color::Magenta));
+                color.set_fg(Some(theme.trace));
             }
             MarkupElement::Debug => {
-                // Blue is really difficult to see on the standard windows command line
-                #[cfg(windows)]
-                const BLUE: Color = Color::Cyan;
-                #[cfg(not(windows))]
-                const BLUE: Color = Color::Blue;
-
-                color.set_fg(Some(BLUE));
+                color.set_fg(Some(theme.debug));
             }
 
             MarkupElement::Inverse | MarkupElement::Hyperlink { .. } => {}
@@ -0,0 +1,226 @@
+use std::{env, fmt, str::FromStr};
+
+use termcolor::Color;
+
+/// The colors used to print the markup elements that have a color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// The color of [MarkupElement::Error](crate::MarkupElement::Error)
+    pub error: Color,
+    /// The color of [MarkupElement::Warn](crate::MarkupElement::Warn)
+    pub warn: Color,
+    /// The color of [MarkupElement::Info](crate::MarkupElement::Info)
+    pub info: Color,
+    /// The color of [MarkupElement::Success](crate::MarkupElement::Success)
+    pub success: Color,
+    /// The color of [MarkupElement::Debug](crate::MarkupElement::Debug)
+    pub debug: Color,
+    /// The color of [MarkupElement::Trace](crate::MarkupElement::Trace)
+    pub trace: Color,
+}
+
+impl Theme {
+    /// The colors used when the theme isn't customized. They only use the
+    /// 8 basic ANSI colors, so they are supported by every terminal.
+    pub const DEFAULT: Self = Self {
+        error: Color::Red,
+        warn: Color::Yellow,
+        info: Color::Green,
+        success: Color::Green,
+        // Blue is really difficult to see on the standard windows command line
+        #[cfg(windows)]
+        debug: Color::Cyan,
+        #[cfg(not(windows))]
+        debug: Color::Blue,
+        trace: Color::Magenta,
+    };
+
+    /// The name of the environment variable used to customize the theme
+    pub const ENV_VAR: &'static str = "BIOME_COLORS";
+
+    /// Reads the theme from the environment variable `BIOME_COLORS`. The default
+    /// theme is returned when the variable isn't set or can't be parsed.
+    ///
+    /// When the terminal doesn't advertise the support of 256 colors or true
+    /// colors, the colors of the theme fall back to the 8 basic ANSI colors.
+    pub fn from_env() -> Self {
+        let theme = env::var(Self::ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default();
+
+        if supports_extended_colors() {
+            theme
+        } else {
+            theme.to_basic_colors()
+        }
+    }
+
+    /// Replaces the 256 colors and the true colors of the theme with the
+    /// closest of the 8 basic ANSI colors
+    pub fn to_basic_colors(self) -> Self {
+        Self {
+            error: to_basic_color(self.error),
+            warn: to_basic_color(self.warn),
+            info: to_basic_color(self.info),
+            success: to_basic_color(self.success),
+            debug: to_basic_color(self.debug),
+            trace: to_basic_color(self.trace),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Parses a list of `element=color` pairs separated by `:`, for instance
+/// `error=magenta:warn=208:info=0,135,255`.
+///
+/// The elements are `error`, `warn`, `info`, `success`, `debug` and `trace`. The
+/// color is either the name of a basic ANSI color, a number of the 256 colors
+/// palette, or three comma-separated RGB components. The elements that aren't
+/// listed keep their default color.
+impl FromStr for Theme {
+    type Err = ParseThemeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut theme = Self::DEFAULT;
+
+        for entry in value.split(':').filter(|entry| !entry.is_empty()) {
+            let (element, color) = entry
+                .split_once('=')
+                .ok_or_else(|| ParseThemeError(entry.to_string()))?;
+            let color = color
+                .trim()
+                .parse::<Color>()
+                .map_err(|_| ParseThemeError(entry.to_string()))?;
+
+            let slot = match element.trim() {
+                "error" => &mut theme.error,
+                "warn" => &mut theme.warn,
+                "info" => &mut theme.info,
+                "success" => &mut theme.success,
+                "debug" => &mut theme.debug,
+                "trace" => &mut theme.trace,
+                _ => return Err(ParseThemeError(entry.to_string())),
+            };
+            *slot = color;
+        }
+
+        Ok(theme)
+    }
+}
+
+/// Error returned when a [Theme] can't be parsed, it contains the invalid entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseThemeError(String);
+
+impl fmt::Display for ParseThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color entry {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseThemeError {}
+
+/// Whether the terminal advertises the support of more than the 8 basic ANSI
+/// colors, using the conventional `COLORTERM` and `TERM` environment variables
+fn supports_extended_colors() -> bool {
+    let colorterm = env::var("COLORTERM").unwrap_or_default();
+    let term = env::var("TERM").unwrap_or_default();
+
+    matches!(colorterm.as_str(), "truecolor" | "24bit")
+        || term.contains("256color")
+        || term.contains("direct")
+        // Windows Terminal supports true colors but doesn't set `COLORTERM`
+        || env::var_os("WT_SESSION").is_some()
+}
+
+/// Finds the basic ANSI color closest to `color`
+fn to_basic_color(color: Color) -> Color {
+    match color {
+        Color::Ansi256(index @ 0..=15) => basic_color_from_index(index % 8),
+        Color::Ansi256(index @ 16..=231) => {
+            // The 6x6x6 color cube
+            let index = index - 16;
+            let component = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            basic_color_from_rgb(
+                component(index / 36),
+                component((index / 6) % 6),
+                component(index % 6),
+            )
+        }
+        Color::Ansi256(index) => {
+            // The grayscale ramp
+            let level = 8 + (index - 232) * 10;
+            basic_color_from_rgb(level, level, level)
+        }
+        Color::Rgb(red, green, blue) => basic_color_from_rgb(red, green, blue),
+        color => color,
+    }
+}
+
+/// The basic ANSI colors, in the order of their SGR codes
+fn basic_color_from_index(index: u8) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+/// Keeps the components that are at least half as bright as the brightest one,
+/// so the hue of the color is preserved even when it's dark
+fn basic_color_from_rgb(red: u8, green: u8, blue: u8) -> Color {
+    let max = red.max(green).max(blue);
+    if max < 64 {
+        return Color::Black;
+    }
+
+    let threshold = max / 2;
+    let bit = |value: u8, shift: u8| u8::from(value > threshold) << shift;
+    basic_color_from_index(bit(red, 0) | bit(green, 1) | bit(blue, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use termcolor::Color;
+
+    use super::{to_basic_color, Theme};
+
+    #[test]
+    fn parses_the_theme() {
+        let theme: Theme = "error=magenta:warn=208:info=0,135,255".parse().unwrap();
+
+        assert_eq!(theme.error, Color::Magenta);
+        assert_eq!(theme.warn, Color::Ansi256(208));
+        assert_eq!(theme.info, Color::Rgb(0, 135, 255));
+        assert_eq!(theme.success, Theme::DEFAULT.success);
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        assert!("error".parse::<Theme>().is_err());
+        assert!("error=not-a-color".parse::<Theme>().is_err());
+        assert!("unknown=red".parse::<Theme>().is_err());
+    }
+
+    #[test]
+    fn falls_back_to_basic_colors() {
+        assert_eq!(to_basic_color(Color::Red), Color::Red);
+        assert_eq!(to_basic_color(Color::Ansi256(9)), Color::Red);
+        assert_eq!(to_basic_color(Color::Ansi256(208)), Color::Yellow);
+        assert_eq!(to_basic_color(Color::Ansi256(16)), Color::Black);
+        assert_eq!(to_basic_color(Color::Ansi256(255)), Color::White);
+        assert_eq!(to_basic_color(Color::Rgb(0, 135, 255)), Color::Cyan);
+        assert_eq!(to_basic_color(Color::Rgb(135, 0, 0)), Color::Red);
+    }
+}
@@ -5,7 +5,10 @@ use std::{fmt, io};
 
 use crate::fmt::MarkupElements;
 
-pub use self::{html::HTML, termcolor::Termcolor};
+pub use self::{
+    html::HTML,
+    termcolor::{Termcolor, ThemedTermcolor},
+};
 
 pub trait Write {
     fn write_str(&mut self, elements: &MarkupElements, content: &str) -> io::Result<()>;
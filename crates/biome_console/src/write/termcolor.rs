@@ -7,7 +7,7 @@ use termcolor::{Color, ColorSpec, WriteColor};
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::{fmt::MarkupElements, MarkupElement};
+use crate::{fmt::MarkupElements, MarkupElement, Theme};
 
 use super::Write;
 
@@ -19,56 +19,107 @@ where
     W: WriteColor,
 {
     fn write_str(&mut self, elements: &MarkupElements, content: &str) -> io::Result<()> {
-        with_format(&mut self.0, elements, |writer| {
-            let mut adapter = SanitizeAdapter {
-                writer,
-                error: Ok(()),
-            };
+        write_str(&mut self.0, &Theme::DEFAULT, elements, content)
+    }
 
-            match adapter.write_str(content) {
-                Ok(()) => Ok(()),
-                Err(..) => {
-                    if adapter.error.is_err() {
-                        adapter.error
-                    } else {
-                        // SanitizeAdapter can only fail if the underlying
-                        // writer returns an error
-                        unreachable!()
-                    }
-                }
-            }
-        })
+    fn write_fmt(&mut self, elements: &MarkupElements, content: fmt::Arguments) -> io::Result<()> {
+        write_fmt(&mut self.0, &Theme::DEFAULT, elements, content)
+    }
+}
+
+/// Adapter struct implementing [Write] over types implementing [WriteColor],
+/// printing the markup elements with the colors of a [Theme]
+pub struct ThemedTermcolor<W> {
+    writer: W,
+    theme: Theme,
+}
+
+impl<W> ThemedTermcolor<W> {
+    pub fn new(writer: W, theme: Theme) -> Self {
+        Self { writer, theme }
+    }
+}
+
+impl<W> Write for ThemedTermcolor<W>
+where
+    W: WriteColor,
+{
+    fn write_str(&mut self, elements: &MarkupElements, content: &str) -> io::Result<()> {
+        write_str(&mut self.writer, &self.theme, elements, content)
     }
 
     fn write_fmt(&mut self, elements: &MarkupElements, content: fmt::Arguments) -> io::Result<()> {
-        with_format(&mut self.0, elements, |writer| {
-            let mut adapter = SanitizeAdapter {
-                writer,
-                error: Ok(()),
-            };
+        write_fmt(&mut self.writer, &self.theme, elements, content)
+    }
+}
 
-            match adapter.write_fmt(content) {
-                Ok(()) => Ok(()),
-                Err(..) => {
-                    if adapter.error.is_err() {
-                        adapter.error
-                    } else {
-                        Err(io::Error::new(
-                            io::ErrorKind::Other,
-                            "a Display formatter returned an error",
-                        ))
-                    }
+fn write_str<W>(
+    writer: &mut W,
+    theme: &Theme,
+    elements: &MarkupElements,
+    content: &str,
+) -> io::Result<()>
+where
+    W: WriteColor,
+{
+    with_format(writer, elements, theme, |writer| {
+        let mut adapter = SanitizeAdapter {
+            writer,
+            error: Ok(()),
+        };
+
+        match adapter.write_str(content) {
+            Ok(()) => Ok(()),
+            Err(..) => {
+                if adapter.error.is_err() {
+                    adapter.error
+                } else {
+                    // SanitizeAdapter can only fail if the underlying
+                    // writer returns an error
+                    unreachable!()
                 }
             }
-        })
-    }
+        }
+    })
+}
+
+fn write_fmt<W>(
+    writer: &mut W,
+    theme: &Theme,
+    elements: &MarkupElements,
+    content: fmt::Arguments,
+) -> io::Result<()>
+where
+    W: WriteColor,
+{
+    with_format(writer, elements, theme, |writer| {
+        let mut adapter = SanitizeAdapter {
+            writer,
+            error: Ok(()),
+        };
+
+        match adapter.write_fmt(content) {
+            Ok(()) => Ok(()),
+            Err(..) => {
+                if adapter.error.is_err() {
+                    adapter.error
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        "a Display formatter returned an error",
+                    ))
+                }
+            }
+        }
+    })
 }
 
-/// Applies the current format in `state` to `writer`, calls `func` to
-/// print a piece of text, then reset the printing format
+/// Applies the current format in `state` to `writer` with the colors of
+/// `theme`, calls `func` to print a piece of text, then reset the printing format
 fn with_format<W>(
     writer: &mut W,
     state: &MarkupElements,
+    theme: &Theme,
     func: impl FnOnce(&mut W) -> io::Result<()>,
 ) -> io::Result<()>
 where
@@ -88,7 +139,7 @@ where
                     link = Some(href);
                 }
                 _ => {
-                    element.update_color(&mut color);
+                    element.update_color(&mut color, theme);
                 }
             }
         }
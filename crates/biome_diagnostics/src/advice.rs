@@ -39,12 +39,42 @@ pub trait Visit {
         Ok(())
     }
 
+    /// Prints a set of labeled spans, possibly located in different files.
+    /// The first label is the primary one, the others are secondary labels.
+    ///
+    /// Visitors that don't support labels get each label as an info log
+    /// followed by a code frame.
+    fn record_labels(&mut self, labels: &[Label<'_>]) -> io::Result<()> {
+        for label in labels {
+            self.record_log(LogCategory::Info, label.message)?;
+            self.record_frame(label.location)?;
+        }
+        Ok(())
+    }
+
     /// Prints the diff between the `prev` and `next` strings.
     fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
         let _ = diff;
         Ok(())
     }
 
+    /// Prints a suggested fix: its title, e.g. `Safe fix: Remove debugger`,
+    /// and the diff of the changes applied by the fix. The `applicability`
+    /// tells whether the fix is safe.
+    ///
+    /// Visitors that don't support suggestions get the title as an info log
+    /// followed by the diff.
+    fn record_suggestion(
+        &mut self,
+        applicability: Applicability,
+        title: &dyn fmt::Display,
+        diff: &TextEdit,
+    ) -> io::Result<()> {
+        let _ = applicability;
+        self.record_log(LogCategory::Info, title)?;
+        self.record_diff(diff)
+    }
+
     /// Prints a Rust backtrace.
     fn record_backtrace(
         &mut self,
@@ -126,6 +156,86 @@ where
     }
 }
 
+/// A span of source code labeled with a message, see [Visit::record_labels].
+#[derive(Clone, Copy)]
+pub struct Label<'a> {
+    /// The labeled location.
+    pub location: Location<'a>,
+    /// The message printed next to the span.
+    pub message: &'a dyn fmt::Display,
+}
+
+/// Utility type implementing [Advices] that emits a labels advice with the
+/// provided labels. The first label is the primary one.
+#[derive(Debug)]
+pub struct LabelsAdvice<Path, Span, SourceCode, Message> {
+    pub labels: Vec<LabelAdvice<Path, Span, SourceCode, Message>>,
+}
+
+/// A single label of a [LabelsAdvice].
+#[derive(Debug)]
+pub struct LabelAdvice<Path, Span, SourceCode, Message> {
+    pub path: Path,
+    pub span: Span,
+    pub source_code: SourceCode,
+    pub message: Message,
+}
+
+impl<Path, Span, SourceCode, Message> Advices for LabelsAdvice<Path, Span, SourceCode, Message>
+where
+    Path: AsResource,
+    Span: AsSpan,
+    SourceCode: AsSourceCode,
+    Message: Display,
+{
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        let labels = self.labels.labels();
+        if labels.is_empty() {
+            return Ok(());
+        }
+
+        visitor.record_labels(&labels)
+    }
+}
+
+/// The type of the fields marked with `#[label]` when deriving [Diagnostic](crate::Diagnostic):
+/// the labels of all these fields are recorded together, in the order of the
+/// fields, before the other advices.
+pub trait AsLabels {
+    fn labels(&self) -> Vec<Label<'_>>;
+}
+
+impl<Path, Span, SourceCode, Message> AsLabels for LabelAdvice<Path, Span, SourceCode, Message>
+where
+    Path: AsResource,
+    Span: AsSpan,
+    SourceCode: AsSourceCode,
+    Message: Display,
+{
+    fn labels(&self) -> Vec<Label<'_>> {
+        vec![Label {
+            location: Location::builder()
+                .resource(&self.path)
+                .span(&self.span)
+                .source_code(&self.source_code)
+                .build(),
+            message: &self.message,
+        }]
+    }
+}
+
+impl<T: AsLabels> AsLabels for Option<T> {
+    fn labels(&self) -> Vec<Label<'_>> {
+        self.as_ref().map_or_else(Vec::new, AsLabels::labels)
+    }
+}
+
+impl<T: AsLabels> AsLabels for Vec<T> {
+    fn labels(&self) -> Vec<Label<'_>> {
+        self.iter().flat_map(AsLabels::labels).collect()
+    }
+}
+
 /// Utility type implementing [Advices] that emits a diff advice with the
 /// provided prev and next text.
 #[derive(Debug)]
@@ -177,13 +287,12 @@ where
             Applicability::MaybeIncorrect => "Unsafe fix",
         };
 
-        visitor.record_log(
-            LogCategory::Info,
+        visitor.record_suggestion(
+            self.applicability,
             &markup! {
                 {applicability}": "{self.msg}
             },
-        )?;
-
-        visitor.record_diff(&self.suggestion)
+            &self.suggestion,
+        )
     }
 }
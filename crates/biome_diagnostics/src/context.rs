@@ -255,7 +255,7 @@ mod internal {
 
     use crate::{
         diagnostic::internal::AsDiagnostic, Advices, Backtrace, Category, Diagnostic,
-        DiagnosticTags, LineIndex, LineIndexBuf, Location, LogCategory, Resource, Severity,
+        DiagnosticTags, Label, LineIndex, LineIndexBuf, Location, LogCategory, Resource, Severity,
         SourceCode, Visit,
     };
 
@@ -634,6 +634,24 @@ mod internal {
             })
         }
 
+        fn record_labels(&mut self, labels: &[Label<'_>]) -> io::Result<()> {
+            // The labels in another file don't share the source code of the diagnostic
+            let labels: Vec<_> = labels
+                .iter()
+                .map(|label| Label {
+                    location: Location {
+                        source_code: match label.location.resource {
+                            None => Some(label.location.source_code.unwrap_or(self.source_code)),
+                            Some(_) => label.location.source_code,
+                        },
+                        ..label.location
+                    },
+                    message: label.message,
+                })
+                .collect();
+            self.visitor.record_labels(&labels)
+        }
+
         fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
             self.visitor.record_diff(diff)
         }
@@ -9,13 +9,14 @@ mod diff;
 mod frame;
 mod message;
 
-use crate::display::frame::SourceFile;
+use crate::display::frame::{FrameLabel, SourceFile};
 use crate::{
-    diagnostic::internal::AsDiagnostic, Advices, Diagnostic, DiagnosticTags, Location, LogCategory,
-    Resource, Severity, Visit,
+    diagnostic::internal::AsDiagnostic, Advices, Applicability, Diagnostic, DiagnosticTags, Label,
+    Location, LogCategory, Resource, Severity, Visit,
 };
 
 pub use self::backtrace::{set_bottom_frame, Backtrace};
+pub use self::diff::{DiffOptions, DiffStyle, FixPreview};
 pub use self::message::MessageAndDescription;
 
 /// Helper struct from printing the description of a diagnostic into any
@@ -36,6 +37,7 @@ impl<'fmt, D: AsDiagnostic + ?Sized> std::fmt::Display for PrintDescription<'fmt
 pub struct PrintDiagnostic<'fmt, D: ?Sized> {
     diag: &'fmt D,
     verbose: bool,
+    diff_options: DiffOptions,
 }
 
 impl<'fmt, D: AsDiagnostic + ?Sized> PrintDiagnostic<'fmt, D> {
@@ -43,6 +45,7 @@ impl<'fmt, D: AsDiagnostic + ?Sized> PrintDiagnostic<'fmt, D> {
         Self {
             diag,
             verbose: false,
+            diff_options: DiffOptions::default(),
         }
     }
 
@@ -50,8 +53,15 @@ impl<'fmt, D: AsDiagnostic + ?Sized> PrintDiagnostic<'fmt, D> {
         Self {
             diag,
             verbose: true,
+            diff_options: DiffOptions::default(),
         }
     }
+
+    /// Changes how the diff advices of the diagnostic are printed
+    pub fn with_diff_options(mut self, diff_options: DiffOptions) -> Self {
+        self.diff_options = diff_options;
+        self
+    }
 }
 
 impl<'fmt, D: AsDiagnostic + ?Sized> fmt::Display for PrintDiagnostic<'fmt, D> {
@@ -66,7 +76,7 @@ impl<'fmt, D: AsDiagnostic + ?Sized> fmt::Display for PrintDiagnostic<'fmt, D> {
         // Wrap the formatter with an indentation level and print the advices
         let mut slot = None;
         let mut fmt = IndentWriter::wrap(fmt, &mut slot, true, "  ");
-        let mut visitor = PrintAdvices(&mut fmt);
+        let mut visitor = PrintAdvices(&mut fmt, self.diff_options);
 
         print_advices(&mut visitor, diagnostic, self.verbose)
     }
@@ -254,6 +264,21 @@ impl Visit for FrameVisitor<'_> {
         Ok(())
     }
 
+    fn record_labels(&mut self, labels: &[Label<'_>]) -> io::Result<()> {
+        // The labels without a resource are in the file of the diagnostic
+        let same_location = |label: &Label<'_>| {
+            let location = Location {
+                resource: label.location.resource.or(self.location.resource),
+                ..label.location
+            };
+            location == self.location
+        };
+        if labels.iter().any(same_location) {
+            self.skip_frame = true;
+        }
+        Ok(())
+    }
+
     fn record_backtrace(&mut self, _: &dyn fmt::Display, _: &Backtrace) -> io::Result<()> {
         self.skip_frame = true;
         Ok(())
@@ -328,7 +353,7 @@ impl<'fmt, D: Diagnostic + ?Sized> fmt::Display for PrintCauseChain<'fmt, D> {
 }
 
 /// Implementation of [Visitor] that prints the advices for a diagnostic.
-struct PrintAdvices<'a, 'b>(&'a mut fmt::Formatter<'b>);
+struct PrintAdvices<'a, 'b>(&'a mut fmt::Formatter<'b>, DiffOptions);
 
 impl PrintAdvices<'_, '_> {
     fn print_log(
@@ -385,8 +410,54 @@ impl Visit for PrintAdvices<'_, '_> {
         frame::print_frame(self.0, location)
     }
 
+    fn record_labels(&mut self, labels: &[Label<'_>]) -> io::Result<()> {
+        // Group the labels by file, in the order in which the files first appear
+        let mut groups: Vec<(Option<Resource<&str>>, Vec<(usize, &Label<'_>)>)> = Vec::new();
+        for (index, label) in labels.iter().enumerate() {
+            let resource = label.location.resource;
+            match groups.iter_mut().find(|(group, _)| *group == resource) {
+                Some((_, group)) => group.push((index, label)),
+                None => groups.push((resource, vec![(index, label)])),
+            }
+        }
+
+        for (resource, group) in groups {
+            if let Some(Resource::File(path)) = resource {
+                self.0.write_markup(markup! {
+                    <Emphasis>{path}</Emphasis>"\n\n"
+                })?;
+            }
+
+            for (index, label) in group {
+                let has_frame =
+                    label.location.span.is_some() && label.location.source_code.is_some();
+                if has_frame {
+                    let label_message = FrameLabel {
+                        message: label.message,
+                        is_primary: index == 0,
+                    };
+                    frame::print_labeled_frame(self.0, label.location, Some(label_message))?;
+                } else {
+                    self.record_log(LogCategory::Info, label.message)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
-        diff::print_diff(self.0, diff)
+        diff::print_diff(self.0, diff, self.1)
+    }
+
+    fn record_suggestion(
+        &mut self,
+        _: Applicability,
+        title: &dyn fmt::Display,
+        diff: &TextEdit,
+    ) -> io::Result<()> {
+        self.record_log(LogCategory::Info, title)?;
+        diff::print_suggestion_diff(self.0, diff, self.1)
     }
 
     fn record_backtrace(
@@ -419,7 +490,7 @@ impl Visit for PrintAdvices<'_, '_> {
 
         let mut slot = None;
         let mut fmt = IndentWriter::wrap(self.0, &mut slot, true, "  ");
-        let mut visitor = PrintAdvices(&mut fmt);
+        let mut visitor = PrintAdvices(&mut fmt, self.1);
         advice.record(&mut visitor)
     }
 }
@@ -466,11 +537,26 @@ impl Visit for CountAdvices {
         Ok(())
     }
 
+    fn record_labels(&mut self, _: &[Label<'_>]) -> io::Result<()> {
+        self.0 += 1;
+        Ok(())
+    }
+
     fn record_diff(&mut self, _: &TextEdit) -> io::Result<()> {
         self.0 += 1;
         Ok(())
     }
 
+    fn record_suggestion(
+        &mut self,
+        _: Applicability,
+        _: &dyn fmt::Display,
+        _: &TextEdit,
+    ) -> io::Result<()> {
+        self.0 += 1;
+        Ok(())
+    }
+
     fn record_backtrace(&mut self, _: &dyn fmt::Display, _: &Backtrace) -> io::Result<()> {
         self.0 += 1;
         Ok(())
@@ -573,7 +659,8 @@ mod tests {
 
     use crate::{self as biome_diagnostics};
     use crate::{
-        Advices, Diagnostic, Location, LogCategory, PrintDiagnostic, Resource, SourceCode, Visit,
+        Advices, Diagnostic, Label, LabelAdvice, Location, LogCategory, PrintDiagnostic, Resource,
+        SourceCode, Visit,
     };
 
     #[derive(Debug)]
@@ -697,6 +784,45 @@ mod tests {
         }
     }
 
+    #[derive(Debug)]
+    struct LabelsAdvice;
+
+    impl Advices for LabelsAdvice {
+        fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+            let source_code = SourceCode {
+                text: "context location context",
+                line_starts: None,
+            };
+
+            visitor.record_labels(&[
+                Label {
+                    location: Location {
+                        resource: Some(Resource::File("first_path")),
+                        span: Some(TextRange::new(TextSize::from(8), TextSize::from(16))),
+                        source_code: Some(source_code),
+                    },
+                    message: &"defined here",
+                },
+                Label {
+                    location: Location {
+                        resource: Some(Resource::File("other_path")),
+                        span: Some(TextRange::new(TextSize::from(0), TextSize::from(7))),
+                        source_code: Some(source_code),
+                    },
+                    message: &"imported here",
+                },
+                Label {
+                    location: Location {
+                        resource: Some(Resource::File("first_path")),
+                        span: Some(TextRange::new(TextSize::from(17), TextSize::from(24))),
+                        source_code: Some(source_code),
+                    },
+                    message: &"used here",
+                },
+            ])
+        }
+    }
+
     #[derive(Debug)]
     struct DiffAdvice;
 
@@ -850,6 +976,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_labels_advice() {
+        let diag = TestDiagnostic {
+            advice: Some(LabelsAdvice),
+            ..TestDiagnostic::empty()
+        };
+
+        let diag = markup!({ PrintDiagnostic::verbose(&diag) }).to_owned();
+
+        let expected = markup!{
+            "internalError/io "<Inverse>" FIXABLE "</Inverse>" ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n"
+            "\n"
+            <Emphasis><Error>"  ✖"</Error></Emphasis>" "<Error>"diagnostic message"</Error>"\n"
+            "  \n"
+            <Emphasis>"  first_path"</Emphasis>"\n"
+            "  \n"
+            <Emphasis><Error>"  >"</Error></Emphasis>" "<Emphasis>"1 │ "</Emphasis>"context location context\n"
+            "   "<Emphasis>"   │ "</Emphasis>"        "<Emphasis><Error>"^^^^^^^^"</Error></Emphasis>" "<Error>"defined here"</Error>"\n"
+            "  \n"
+            <Emphasis><Info>"  >"</Info></Emphasis>" "<Emphasis>"1 │ "</Emphasis>"context location context\n"
+            "   "<Emphasis>"   │ "</Emphasis>"                 "<Emphasis><Info>"-------"</Info></Emphasis>" "<Info>"used here"</Info>"\n"
+            "  \n"
+            <Emphasis>"  other_path"</Emphasis>"\n"
+            "  \n"
+            <Emphasis><Info>"  >"</Info></Emphasis>" "<Emphasis>"1 │ "</Emphasis>"context location context\n"
+            "   "<Emphasis>"   │ "</Emphasis><Emphasis><Info>"-------"</Info></Emphasis>" "<Info>"imported here"</Info>"\n"
+            "  \n"
+        }.to_owned();
+
+        assert_eq!(
+            diag, expected,
+            "\nactual:\n{diag:#?}\nexpected:\n{expected:#?}"
+        );
+    }
+
+    #[derive(Debug, Diagnostic)]
+    #[diagnostic(category = "internalError/io", message = "diagnostic message")]
+    struct LabeledDiagnostic {
+        #[location(resource)]
+        path: &'static str,
+        #[location(span)]
+        span: TextRange,
+        #[location(source_code)]
+        source_code: &'static str,
+        #[label]
+        declaration: LabelAdvice<Option<&'static str>, TextRange, &'static str, &'static str>,
+        #[label]
+        uses: Vec<LabelAdvice<Option<&'static str>, TextRange, &'static str, &'static str>>,
+    }
+
+    #[test]
+    fn test_derived_labels() {
+        let source_code = "context location context";
+        let diag = LabeledDiagnostic {
+            path: "path",
+            span: TextRange::new(TextSize::from(8), TextSize::from(16)),
+            source_code,
+            declaration: LabelAdvice {
+                path: None,
+                span: TextRange::new(TextSize::from(8), TextSize::from(16)),
+                source_code,
+                message: "declared here",
+            },
+            uses: vec![LabelAdvice {
+                path: None,
+                span: TextRange::new(TextSize::from(17), TextSize::from(24)),
+                source_code,
+                message: "used here",
+            }],
+        };
+
+        let diag = markup!({ PrintDiagnostic::verbose(&diag) }).to_owned();
+
+        // The primary label is at the location of the diagnostic, so its frame replaces the
+        // frame of the location
+        let expected = markup!{
+            "path:1:9 internalError/io ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n"
+            "\n"
+            <Emphasis><Error>"  ✖"</Error></Emphasis>" "<Error>"diagnostic message"</Error>"\n"
+            "  \n"
+            <Emphasis><Error>"  >"</Error></Emphasis>" "<Emphasis>"1 │ "</Emphasis>"context location context\n"
+            "   "<Emphasis>"   │ "</Emphasis>"        "<Emphasis><Error>"^^^^^^^^"</Error></Emphasis>" "<Error>"declared here"</Error>"\n"
+            "  \n"
+            <Emphasis><Info>"  >"</Info></Emphasis>" "<Emphasis>"1 │ "</Emphasis>"context location context\n"
+            "   "<Emphasis>"   │ "</Emphasis>"                 "<Emphasis><Info>"-------"</Info></Emphasis>" "<Info>"used here"</Info>"\n"
+            "  \n"
+        }.to_owned();
+
+        assert_eq!(
+            diag, expected,
+            "\nactual:\n{diag:#?}\nexpected:\n{expected:#?}"
+        );
+    }
+
     #[test]
     fn test_diff_advice() {
         let diag = TestDiagnostic {
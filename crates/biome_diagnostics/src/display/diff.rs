@@ -13,7 +13,83 @@ use super::frame::{
 
 const MAX_PATCH_LINES: usize = 150;
 
-pub(super) fn print_diff(fmt: &mut fmt::Formatter<'_>, diff: &TextEdit) -> io::Result<()> {
+/// How the diff advices of a diagnostic are printed
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum DiffStyle {
+    /// The lines of both revisions side by side with their line numbers, and the
+    /// changed words highlighted
+    #[default]
+    Default,
+    /// The hunks of a unified diff, the same format printed by `git diff`
+    Unified,
+    /// The changed lines are printed once, with the deleted and inserted words
+    /// marked inline
+    Word,
+    /// The diff isn't printed
+    Off,
+}
+
+/// How the suggested fixes of a diagnostic are previewed
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum FixPreview {
+    /// The diff of the fix is printed like the other diff advices
+    #[default]
+    Full,
+    /// Only the changed lines of the fix are printed, with the deleted and
+    /// inserted words marked inline
+    Compact,
+    /// Only the title of the fix is printed
+    Off,
+}
+
+/// Options that control the printing of the diff advices
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DiffOptions {
+    pub style: DiffStyle,
+    /// The number of unchanged lines printed around the changes. A diff can't print
+    /// more context lines than the ones retained by its [TextEdit].
+    pub context_lines: usize,
+    /// How the diffs of the suggested fixes are printed
+    pub fix_preview: FixPreview,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            style: DiffStyle::default(),
+            context_lines: CODE_FRAME_CONTEXT_LINES.get(),
+            fix_preview: FixPreview::default(),
+        }
+    }
+}
+
+/// Prints the diff of a suggested fix, according to the [FixPreview] of the options
+pub(super) fn print_suggestion_diff(
+    fmt: &mut fmt::Formatter<'_>,
+    diff: &TextEdit,
+    options: DiffOptions,
+) -> io::Result<()> {
+    match options.fix_preview {
+        FixPreview::Full => print_diff(fmt, diff, options),
+        FixPreview::Compact => print_word_diff(fmt, diff, 0),
+        FixPreview::Off => Ok(()),
+    }
+}
+
+pub(super) fn print_diff(
+    fmt: &mut fmt::Formatter<'_>,
+    diff: &TextEdit,
+    options: DiffOptions,
+) -> io::Result<()> {
+    // No more lines than the ones of a patch can be printed anyway
+    let context_lines = options.context_lines.min(MAX_PATCH_LINES);
+
+    match options.style {
+        DiffStyle::Off => return Ok(()),
+        DiffStyle::Word => return print_word_diff(fmt, diff, context_lines),
+        DiffStyle::Default | DiffStyle::Unified => {}
+    }
+
     // Before printing, we need to preprocess the list of DiffOps it's made of to classify them by line
     let mut modified_lines = BTreeSet::new();
     let mut inserted_lines = BTreeMap::new();
@@ -72,7 +148,8 @@ pub(super) fn print_diff(fmt: &mut fmt::Formatter<'_>, diff: &TextEdit) -> io::R
         None
     };
 
-    if let Some((key, entry)) = modified_line {
+    // A unified diff always prints its hunks, even for a single line
+    if let (Some((key, entry)), DiffStyle::Default) = (modified_line, options.style) {
         return print_short_diff(fmt, key, entry);
     }
 
@@ -88,8 +165,13 @@ pub(super) fn print_diff(fmt: &mut fmt::Formatter<'_>, diff: &TextEdit) -> io::R
         &mut shown_line_indexes,
         before_line_count,
         after_line_count,
+        context_lines,
     );
 
+    if options.style == DiffStyle::Unified {
+        return print_unified_diff(fmt, &diffs_by_line, &shown_line_indexes);
+    }
+
     // Finally when have a flat list of lines we can now print
     print_full_diff(
         fmt,
@@ -275,6 +357,7 @@ fn process_diff_lines<'lines, 'diff>(
     shown_line_indexes: &mut BTreeSet<usize>,
     before_line_count: OneIndexed,
     after_line_count: OneIndexed,
+    context_lines: usize,
 ) {
     // Merge identical lines
     for before_line in IntoIter::new(OneIndexed::MIN..=before_line_count) {
@@ -345,12 +428,13 @@ fn process_diff_lines<'lines, 'diff>(
                 shown_line_indexes,
                 last_printed_after,
                 after_line,
+                context_lines,
             );
 
             last_printed_after = after_line.get();
         }
 
-        push_displayed_line(diffs_by_line, shown_line_indexes, line);
+        push_displayed_line(diffs_by_line, shown_line_indexes, line, context_lines);
     }
 
     catch_up_after(
@@ -359,6 +443,7 @@ fn process_diff_lines<'lines, 'diff>(
         shown_line_indexes,
         last_printed_after,
         after_line_count,
+        context_lines,
     );
 }
 
@@ -366,13 +451,14 @@ fn push_displayed_line<'input, 'group>(
     diffs_by_line: &mut Vec<&'group GroupDiffsLine<'input>>,
     shown_line_indexes: &mut BTreeSet<usize>,
     line: &'group GroupDiffsLine<'input>,
+    context_lines: usize,
 ) {
     let i = diffs_by_line.len();
     diffs_by_line.push(line);
 
     if line.before_line.is_none() || line.after_line.is_none() {
-        let first = i.saturating_sub(CODE_FRAME_CONTEXT_LINES.get());
-        let last = i + CODE_FRAME_CONTEXT_LINES.get();
+        let first = i.saturating_sub(context_lines);
+        let last = i.saturating_add(context_lines);
         shown_line_indexes.extend(first..=last);
     }
 }
@@ -383,13 +469,14 @@ fn catch_up_after<'input, 'lines>(
     shown_line_indexes: &mut BTreeSet<usize>,
     last_printed_after: usize,
     after_line: OneIndexed,
+    context_lines: usize,
 ) {
     let iter = IntoIter::new(OneIndexed::from_zero_indexed(last_printed_after)..=after_line);
 
     for i in iter {
         let key = LineKey::after(i);
         if let Some(line) = inserted_lines.get(&key) {
-            push_displayed_line(diffs_by_line, shown_line_indexes, line);
+            push_displayed_line(diffs_by_line, shown_line_indexes, line, context_lines);
         }
     }
 }
@@ -601,6 +688,230 @@ fn print_full_diff(
     fmt.write_str("\n")
 }
 
+fn print_unified_diff(
+    fmt: &mut fmt::Formatter<'_>,
+    diffs_by_line: &[&'_ GroupDiffsLine<'_>],
+    shown_line_indexes: &BTreeSet<usize>,
+) -> io::Result<()> {
+    // The empty line that follows the final newline of the file isn't part of the hunks
+    let diffs_by_line = match diffs_by_line.split_last() {
+        Some((last, rest))
+            if last.before_line.is_some() && last.after_line.is_some() && last.diffs.is_empty() =>
+        {
+            rest
+        }
+        _ => diffs_by_line,
+    };
+
+    let mut displayed_lines = 0;
+    let mut truncated = false;
+    let mut last_displayed_line = None;
+    let mut hunk = Vec::new();
+
+    for (i, line) in diffs_by_line.iter().enumerate() {
+        if !shown_line_indexes.contains(&i) {
+            continue;
+        }
+
+        displayed_lines += 1;
+
+        if displayed_lines > MAX_PATCH_LINES {
+            truncated = true;
+            continue;
+        }
+
+        // Lines that aren't contiguous start a new hunk
+        if let Some(last_displayed_line) = last_displayed_line {
+            if last_displayed_line + 1 != i {
+                print_hunk(fmt, &hunk)?;
+                hunk.clear();
+            }
+        }
+
+        last_displayed_line = Some(i);
+        hunk.push(*line);
+    }
+
+    print_hunk(fmt, &hunk)?;
+
+    if truncated {
+        fmt.write_markup(markup! {
+            <Dim>{displayed_lines.saturating_sub(MAX_PATCH_LINES)}" more lines truncated\n"</Dim>
+        })?;
+    }
+
+    fmt.write_str("\n")
+}
+
+/// Prints the header of a hunk, e.g. `@@ -1,4 +1,5 @@`, followed by its lines
+fn print_hunk(fmt: &mut fmt::Formatter<'_>, lines: &[&'_ GroupDiffsLine<'_>]) -> io::Result<()> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+
+    let before = HunkRange::new(lines.iter().filter_map(|line| line.before_line));
+    let after = HunkRange::new(lines.iter().filter_map(|line| line.after_line));
+
+    fmt.write_markup(markup! {
+        "  "<Info>{format_args!("@@ -{before} +{after} @@")}</Info>"\n"
+    })?;
+
+    for line in lines {
+        let text: String = line.diffs.iter().map(|(_, text)| *text).collect();
+
+        match (line.before_line, line.after_line) {
+            (Some(_), None) => fmt.write_markup(markup! {
+                "  "<Error>"-"{text}</Error>"\n"
+            })?,
+            (None, Some(_)) => fmt.write_markup(markup! {
+                "  "<Success>"+"{text}</Success>"\n"
+            })?,
+            _ => fmt.write_markup(markup! {
+                "   "{text}"\n"
+            })?,
+        }
+    }
+
+    Ok(())
+}
+
+/// The lines of a revision covered by a hunk
+struct HunkRange {
+    start: usize,
+    count: usize,
+}
+
+impl HunkRange {
+    fn new(mut lines: impl Iterator<Item = OneIndexed>) -> Self {
+        match lines.next() {
+            Some(start) => Self {
+                start: start.get(),
+                count: 1 + lines.count(),
+            },
+            // The hunk only inserts or only deletes lines
+            None => Self { start: 0, count: 0 },
+        }
+    }
+}
+
+impl std::fmt::Display for HunkRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.start, self.count)
+    }
+}
+
+/// Prints the lines that contain changes only once, with the deleted words
+/// wrapped in `[-` `-]` and the inserted words wrapped in `{+` `+}`
+fn print_word_diff(
+    fmt: &mut fmt::Formatter<'_>,
+    diff: &TextEdit,
+    context_lines: usize,
+) -> io::Result<()> {
+    // The words of both revisions, grouped by line
+    let mut lines: Vec<Vec<(ChangeTag, &str)>> = vec![Vec::new()];
+
+    for (op_index, op) in diff.iter().enumerate() {
+        let op = match op {
+            CompressedOp::DiffOp(op) => op,
+            CompressedOp::EqualLines { line_count } => {
+                // The compressed lines are never shown, only their count matters
+                let line_count = if op_index == 0 {
+                    line_count.get()
+                } else {
+                    line_count.get() + 1
+                };
+                lines.extend((0..line_count).map(|_| Vec::new()));
+                continue;
+            }
+        };
+
+        let tag = op.tag();
+        for (index, text) in op.text(diff).split('\n').enumerate() {
+            if index > 0 {
+                lines.push(Vec::new());
+            }
+
+            if !text.is_empty() {
+                if let Some(line) = lines.last_mut() {
+                    line.push((tag, text));
+                }
+            }
+        }
+    }
+
+    let mut shown_line_indexes = BTreeSet::new();
+    for (i, line) in lines.iter().enumerate() {
+        if line.iter().any(|(tag, _)| *tag != ChangeTag::Equal) {
+            let first = i.saturating_sub(context_lines);
+            let last = i.saturating_add(context_lines).min(lines.len() - 1);
+            shown_line_indexes.extend(first..=last);
+        }
+    }
+
+    let mut displayed_lines = 0;
+    let mut truncated = false;
+    let mut last_displayed_line = None;
+
+    for i in shown_line_indexes {
+        displayed_lines += 1;
+
+        if displayed_lines > MAX_PATCH_LINES {
+            truncated = true;
+            continue;
+        }
+
+        if let Some(last_displayed_line) = last_displayed_line {
+            if last_displayed_line + 1 != i {
+                fmt.write_markup(markup! {
+                    <Emphasis>"  \u{b7}\u{b7}\u{b7}\n"</Emphasis>
+                })?;
+            }
+        }
+
+        last_displayed_line = Some(i);
+
+        fmt.write_str("  ")?;
+
+        let line = &lines[i];
+        let last_index = line.len().saturating_sub(1);
+
+        for (index, (tag, text)) in line.iter().enumerate() {
+            let (element, open, close) = match tag {
+                ChangeTag::Equal => {
+                    fmt.write_str(text)?;
+                    continue;
+                }
+                ChangeTag::Delete => (MarkupElement::Error, "[-", "-]"),
+                ChangeTag::Insert => (MarkupElement::Success, "{+", "+}"),
+            };
+
+            let options = PrintInvisiblesOptions {
+                ignore_leading_tabs: false,
+                ignore_lone_spaces: false,
+                ignore_trailing_carriage_return: true,
+                at_line_start: index == 0,
+                at_line_end: index == last_index,
+            };
+
+            let mut slot = None;
+            let mut fmt = ElementWrapper::wrap(fmt, &mut slot, element);
+            fmt.write_str(open)?;
+            print_invisibles(&mut fmt, text, options)?;
+            fmt.write_str(close)?;
+        }
+
+        fmt.write_str("\n")?;
+    }
+
+    if truncated {
+        fmt.write_markup(markup! {
+            <Dim>{displayed_lines.saturating_sub(MAX_PATCH_LINES)}" more lines truncated\n"</Dim>
+        })?;
+    }
+
+    fmt.write_str("\n")
+}
+
 struct FormatDiffLine<'a> {
     is_equal: bool,
     ops: &'a [(ChangeTag, &'a str)],
@@ -668,7 +979,7 @@ impl<W: fmt::Write + ?Sized> fmt::Write for ElementWrapper<'_, W> {
 
 #[cfg(test)]
 mod tests {
-    use super::print_diff;
+    use super::{print_diff, print_suggestion_diff, DiffOptions, DiffStyle, FixPreview};
     use biome_console::{fmt, markup, MarkupBuf};
     use biome_text_edit::TextEdit;
     use termcolor::Buffer;
@@ -702,7 +1013,12 @@ mod tests {
         let diff = TextEdit::from_unicode_words("before", "after");
 
         let mut output = MarkupBuf::default();
-        print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions::default(),
+        )
+        .unwrap();
 
         let expected = markup! {
             <Error>"-"</Error>" "<Error><Emphasis>"before"</Emphasis></Error>"\n"
@@ -714,12 +1030,132 @@ mod tests {
         assert_eq_markup(&output, &expected);
     }
 
+    #[test]
+    fn test_off() {
+        let diff = TextEdit::from_unicode_words("before", "after");
+
+        let mut output = MarkupBuf::default();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions {
+                style: DiffStyle::Off,
+                ..DiffOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq_markup(&output, &MarkupBuf::default());
+    }
+
+    #[test]
+    fn test_unified() {
+        let diff = TextEdit::from_unicode_words("a\nb\nc\n", "a\nB\nc\n");
+
+        let mut output = MarkupBuf::default();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions {
+                style: DiffStyle::Unified,
+                ..DiffOptions::default()
+            },
+        )
+        .unwrap();
+
+        let expected = markup! {
+            "  "<Info>"@@ -1,3 +1,3 @@"</Info>"\n"
+            "   a\n"
+            "  "<Error>"-b"</Error>"\n"
+            "  "<Success>"+B"</Success>"\n"
+            "   c\n"
+            "\n"
+        }
+        .to_owned();
+
+        assert_eq_markup(&output, &expected);
+    }
+
+    #[test]
+    fn test_word() {
+        let diff =
+            TextEdit::from_unicode_words("let a = 1;\nlet b = 2;\n", "let a = 1;\nlet b = 3;\n");
+
+        let mut output = MarkupBuf::default();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions {
+                style: DiffStyle::Word,
+                context_lines: 0,
+                ..DiffOptions::default()
+            },
+        )
+        .unwrap();
+
+        let expected = markup! {
+            "  let b = "<Error>"[-2-]"</Error><Success>"{+3+}"</Success>";\n"
+            "\n"
+        }
+        .to_owned();
+
+        assert_eq_markup(&output, &expected);
+    }
+
+    #[test]
+    fn test_compact_fix_preview() {
+        let diff =
+            TextEdit::from_unicode_words("let a = 1;\nlet b = 2;\n", "let a = 1;\nlet b = 3;\n");
+
+        let mut output = MarkupBuf::default();
+        print_suggestion_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions {
+                fix_preview: FixPreview::Compact,
+                ..DiffOptions::default()
+            },
+        )
+        .unwrap();
+
+        let expected = markup! {
+            "  let b = "<Error>"[-2-]"</Error><Success>"{+3+}"</Success>";\n"
+            "\n"
+        }
+        .to_owned();
+
+        assert_eq_markup(&output, &expected);
+    }
+
+    #[test]
+    fn test_fix_preview_off() {
+        let diff = TextEdit::from_unicode_words("before", "after");
+
+        let mut output = MarkupBuf::default();
+        print_suggestion_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions {
+                fix_preview: FixPreview::Off,
+                ..DiffOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq_markup(&output, &MarkupBuf::default());
+    }
+
     #[test]
     fn test_single_line() {
         let diff = TextEdit::from_unicode_words("start before end\n", "start after end \n");
 
         let mut output = MarkupBuf::default();
-        print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions::default(),
+        )
+        .unwrap();
 
         let expected = markup! {
             "  "<Emphasis>"1"</Emphasis>"  "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"start"</Error><Error><Dim>"·"</Dim></Error><Error><Emphasis>"before"</Emphasis></Error><Error><Dim>"·"</Dim></Error><Error>"end"</Error>"\n"
@@ -777,7 +1213,12 @@ function name(args) {
         let diff = TextEdit::from_unicode_words(SOURCE_LEFT, SOURCE_RIGHT);
 
         let mut output = MarkupBuf::default();
-        print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions::default(),
+        )
+        .unwrap();
 
         let expected = markup! {
             "  "<Emphasis>" 4"</Emphasis>" "<Emphasis>" 4 │ "</Emphasis>"  sit\n"
@@ -825,7 +1266,12 @@ function name(args) {
         let diff = TextEdit::from_unicode_words(SOURCE_LEFT, SOURCE_RIGHT);
 
         let mut output = MarkupBuf::default();
-        print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions::default(),
+        )
+        .unwrap();
 
         let expected = markup! {
             "  "<Emphasis>"1"</Emphasis>" "<Emphasis>"1 │ "</Emphasis>"  declare module \"test\" {\n"
@@ -862,7 +1308,12 @@ function name(args) {
         let diff = TextEdit::from_unicode_words(SOURCE_LEFT, SOURCE_RIGHT);
 
         let mut output = MarkupBuf::default();
-        print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions::default(),
+        )
+        .unwrap();
 
         let expected = markup! {
             "  "<Emphasis>"1"</Emphasis>" "<Emphasis>"1 │ "</Emphasis>"  declare module \"test\" {\n"
@@ -899,7 +1350,12 @@ function name(args) {
         let diff = TextEdit::from_unicode_words(SOURCE_LEFT, SOURCE_RIGHT);
 
         let mut output = MarkupBuf::default();
-        print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions::default(),
+        )
+        .unwrap();
 
         let expected = markup! {
             "  "<Emphasis>"1"</Emphasis>" "<Emphasis>"1 │ "</Emphasis>"  declare module \"test\" {\n"
@@ -936,7 +1392,12 @@ function name(args) {
         let diff = TextEdit::from_unicode_words(SOURCE_LEFT, SOURCE_RIGHT);
 
         let mut output = MarkupBuf::default();
-        print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions::default(),
+        )
+        .unwrap();
 
         let expected = markup! {
             "  "<Emphasis>"1"</Emphasis>" "<Emphasis>"1 │ "</Emphasis>"  declare module \"test\" {\n"
@@ -969,7 +1430,12 @@ console.log(\"test\");
         let diff = TextEdit::from_unicode_words(SOURCE_LEFT, SOURCE_RIGHT);
 
         let mut output = MarkupBuf::default();
-        print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
+        print_diff(
+            &mut fmt::Formatter::new(&mut output),
+            &diff,
+            DiffOptions::default(),
+        )
+        .unwrap();
 
         let expected = markup! {
             "  "<Emphasis>"1"</Emphasis>"  "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"for"</Error><Error><Dim>"·"</Dim></Error><Error>"(;"</Error><Error><Dim><Emphasis>"·"</Emphasis></Dim></Error><Error>";)"</Error><Error><Dim>"·"</Dim></Error><Error>"{"</Error>"\n"
@@ -6,7 +6,7 @@ use std::{
     ops::{Bound, RangeBounds},
 };
 
-use biome_console::{fmt, markup};
+use biome_console::{fmt, markup, Markup, MarkupElement, MarkupNode};
 use biome_text_size::{TextLen, TextRange, TextSize};
 use unicode_width::UnicodeWidthChar;
 
@@ -30,8 +30,27 @@ pub(super) const CODE_FRAME_CONTEXT_LINES: NonZeroUsize = unwrap(NonZeroUsize::n
 const MAX_CODE_FRAME_LINES: usize = 8;
 const HALF_MAX_CODE_FRAME_LINES: usize = MAX_CODE_FRAME_LINES / 2;
 
+/// A message printed next to the marker of a code frame
+#[derive(Clone, Copy)]
+pub(super) struct FrameLabel<'a> {
+    pub(super) message: &'a dyn fmt::Display,
+    /// Primary labels are highlighted as errors with `^` markers, secondary
+    /// labels are highlighted as information with `-` markers
+    pub(super) is_primary: bool,
+}
+
 /// Prints a code frame advice
 pub(super) fn print_frame(fmt: &mut fmt::Formatter<'_>, location: Location<'_>) -> io::Result<()> {
+    print_labeled_frame(fmt, location, None)
+}
+
+/// Prints a code frame advice, with an optional label printed after the
+/// marker of the last line of the span
+pub(super) fn print_labeled_frame(
+    fmt: &mut fmt::Formatter<'_>,
+    location: Location<'_>,
+    label: Option<FrameLabel<'_>>,
+) -> io::Result<()> {
     let source_span = location
         .source_code
         .and_then(|source_code| Some((source_code, location.span?)));
@@ -99,6 +118,13 @@ pub(super) fn print_frame(fmt: &mut fmt::Formatter<'_>, location: Location<'_>)
         None
     };
 
+    let (highlight, marker_char) = match label {
+        Some(FrameLabel {
+            is_primary: false, ..
+        }) => (MarkupElement::Info, '-'),
+        _ => (MarkupElement::Error, '^'),
+    };
+
     // Calculate the maximum width of the line number
     let max_gutter_len = calculate_print_width(context_end);
     let mut printed_lines = false;
@@ -151,9 +177,11 @@ pub(super) fn print_frame(fmt: &mut fmt::Formatter<'_>, location: Location<'_>)
         }
 
         if should_highlight {
-            fmt.write_markup(markup! {
-                <Emphasis><Error>'>'</Error></Emphasis>' '
-            })?;
+            fmt.write_markup(Markup(&[MarkupNode {
+                elements: &[MarkupElement::Emphasis, highlight.clone()],
+                content: &'>' as &dyn fmt::Display,
+            }]))?;
+            fmt.write_str(" ")?;
         } else {
             fmt.write_str("  ")?;
         }
@@ -241,9 +269,18 @@ pub(super) fn print_frame(fmt: &mut fmt::Formatter<'_>, location: Location<'_>)
 
                 let marker_width = text_width(&line_text[marker]);
                 for _ in 0..marker_width {
-                    fmt.write_markup(markup! {
-                        <Emphasis><Error>'^'</Error></Emphasis>
-                    })?;
+                    fmt.write_markup(Markup(&[MarkupNode {
+                        elements: &[MarkupElement::Emphasis, highlight.clone()],
+                        content: &marker_char as &dyn fmt::Display,
+                    }]))?;
+                }
+
+                if let Some(label) = label.filter(|_| is_last_line) {
+                    fmt.write_str(" ")?;
+                    fmt.write_markup(Markup(&[MarkupNode {
+                        elements: &[highlight.clone()],
+                        content: label.message,
+                    }]))?;
                 }
 
                 fmt.write_str("\n")?;
@@ -26,12 +26,14 @@ pub use biome_diagnostics_categories::{category, category_concat, Category};
 pub use biome_diagnostics_macros::Diagnostic;
 
 pub use crate::advice::{
-    Advices, CodeFrameAdvice, CommandAdvice, DiffAdvice, LogAdvice, LogCategory, Visit,
+    Advices, AsLabels, CodeFrameAdvice, CommandAdvice, DiffAdvice, Label, LabelAdvice,
+    LabelsAdvice, LogAdvice, LogCategory, Visit,
 };
 pub use crate::context::{Context, DiagnosticExt};
 pub use crate::diagnostic::{Diagnostic, DiagnosticTags, Severity};
 pub use crate::display::{
-    set_bottom_frame, Backtrace, MessageAndDescription, PrintDescription, PrintDiagnostic,
+    set_bottom_frame, Backtrace, DiffOptions, DiffStyle, FixPreview, MessageAndDescription,
+    PrintDescription, PrintDiagnostic,
 };
 pub use crate::error::{Error, Result};
 pub use crate::location::{LineIndex, LineIndexBuf, Location, Resource, SourceCode};
@@ -9,8 +9,8 @@ use serde::{
 };
 
 use crate::{
-    diagnostic::internal::AsDiagnostic, diagnostic::DiagnosticTag, Advices as _, Backtrace,
-    Category, DiagnosticTags, LogCategory, Resource, Severity, SourceCode, Visit,
+    diagnostic::internal::AsDiagnostic, diagnostic::DiagnosticTag, Advices as _, Applicability,
+    Backtrace, Category, DiagnosticTags, LogCategory, Resource, Severity, SourceCode, Visit,
 };
 
 /// Serializable representation for a [Diagnostic](super::Diagnostic).
@@ -149,6 +149,28 @@ impl From<super::Location<'_>> for Location {
     }
 }
 
+impl Location {
+    fn as_location(&self) -> super::Location<'_> {
+        super::Location {
+            resource: self.path.as_ref().map(super::Resource::as_deref),
+            span: self.span,
+            source_code: self.source_code.as_deref().map(|text| SourceCode {
+                text,
+                line_starts: None,
+            }),
+        }
+    }
+}
+
+/// Serializable representation of a [Label](super::Label)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(test, derive(Eq, PartialEq))]
+struct Label {
+    location: Location,
+    message: MarkupBuf,
+}
+
 /// Implementation of [Visitor] collecting serializable [Advice] into a vector.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -186,11 +208,38 @@ impl Visit for Advices {
         Ok(())
     }
 
+    fn record_labels(&mut self, labels: &[super::Label<'_>]) -> io::Result<()> {
+        self.advices.push(Advice::Labels(
+            labels
+                .iter()
+                .map(|label| Label {
+                    location: label.location.into(),
+                    message: markup!({ label.message }).to_owned(),
+                })
+                .collect(),
+        ));
+        Ok(())
+    }
+
     fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
         self.advices.push(Advice::Diff(diff.clone()));
         Ok(())
     }
 
+    fn record_suggestion(
+        &mut self,
+        applicability: Applicability,
+        title: &dyn fmt::Display,
+        diff: &TextEdit,
+    ) -> io::Result<()> {
+        self.advices.push(Advice::Suggestion(
+            applicability,
+            markup!({ title }).to_owned(),
+            diff.clone(),
+        ));
+        Ok(())
+    }
+
     fn record_backtrace(
         &mut self,
         title: &dyn fmt::Display,
@@ -243,7 +292,9 @@ enum Advice {
     Log(LogCategory, MarkupBuf),
     List(Vec<MarkupBuf>),
     Frame(Location),
+    Labels(Vec<Label>),
     Diff(TextEdit),
+    Suggestion(Applicability, MarkupBuf, TextEdit),
     Backtrace(MarkupBuf, Backtrace),
     Command(String),
     Group(MarkupBuf, Advices),
@@ -258,15 +309,21 @@ impl super::Advices for Advice {
                     list.iter().map(|item| item as &dyn fmt::Display).collect();
                 visitor.record_list(&as_display)
             }
-            Advice::Frame(location) => visitor.record_frame(super::Location {
-                resource: location.path.as_ref().map(super::Resource::as_deref),
-                span: location.span,
-                source_code: location.source_code.as_deref().map(|text| SourceCode {
-                    text,
-                    line_starts: None,
-                }),
-            }),
+            Advice::Frame(location) => visitor.record_frame(location.as_location()),
+            Advice::Labels(labels) => {
+                let labels: Vec<_> = labels
+                    .iter()
+                    .map(|label| super::Label {
+                        location: label.location.as_location(),
+                        message: &label.message,
+                    })
+                    .collect();
+                visitor.record_labels(&labels)
+            }
             Advice::Diff(diff) => visitor.record_diff(diff),
+            Advice::Suggestion(applicability, title, diff) => {
+                visitor.record_suggestion(*applicability, title, diff)
+            }
             Advice::Backtrace(title, backtrace) => visitor.record_backtrace(title, backtrace),
             Advice::Command(command) => visitor.record_command(command),
             Advice::Group(title, advice) => visitor.record_group(title, advice),
@@ -100,19 +100,42 @@ define_categories! {
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
     "lint/nursery/noApproximativeNumericConstant": "https://biomejs.dev/linter/rules/no-approximative-numeric-constant",
     "lint/nursery/noAriaHiddenOnFocusable": "https://biomejs.dev/linter/rules/no-aria-hidden-on-focusable",
+    "lint/nursery/noBarrelFile": "https://biomejs.dev/linter/rules/no-barrel-file",
+    "lint/nursery/noConditionalExpect": "https://biomejs.dev/linter/rules/no-conditional-expect",
     "lint/nursery/noDefaultExport": "https://biomejs.dev/lint/rules/no-default-export",
+    "lint/nursery/noDeprecatedUsage": "https://biomejs.dev/linter/rules/no-deprecated-usage",
+    "lint/nursery/noDoneCallback": "https://biomejs.dev/linter/rules/no-done-callback",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
+    "lint/nursery/noDynamicFunctionConstructor": "https://biomejs.dev/linter/rules/no-dynamic-function-constructor",
     "lint/nursery/noEmptyBlockStatements": "https://biomejs.dev/linter/rules/no-empty-block-statements",
+    "lint/nursery/noExcessiveFileLength": "https://biomejs.dev/linter/rules/no-excessive-file-length",
+    "lint/nursery/noExcessiveParams": "https://biomejs.dev/linter/rules/no-excessive-params",
+    "lint/nursery/noExcessivelyLongFunctions": "https://biomejs.dev/linter/rules/no-excessively-long-functions",
+    "lint/nursery/noHooksInServerComponents": "https://biomejs.dev/linter/rules/no-hooks-in-server-components",
+    "lint/nursery/noIdenticalTestTitles": "https://biomejs.dev/linter/rules/no-identical-test-titles",
     "lint/nursery/noImplicitAnyLet": "https://biomejs.dev/lint/rules/no-implicit-any-let",
+    "lint/nursery/noJsxLiteralText": "https://biomejs.dev/linter/rules/no-jsx-literal-text",
+    "lint/nursery/noMisusedPromises": "https://biomejs.dev/linter/rules/no-misused-promises",
+    "lint/nursery/noReExportAll": "https://biomejs.dev/linter/rules/no-re-export-all",
+    "lint/nursery/noScriptUrl": "https://biomejs.dev/linter/rules/no-script-url",
+    "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
+    "lint/nursery/noUnsafeInnerHtml": "https://biomejs.dev/linter/rules/no-unsafe-inner-html",
+    "lint/nursery/noUnsupportedBrowserApis": "https://biomejs.dev/linter/rules/no-unsupported-browser-apis",
+    "lint/nursery/noUnsupportedNodeBuiltins": "https://biomejs.dev/linter/rules/no-unsupported-node-builtins",
     "lint/nursery/noUnusedImports": "https://biomejs.dev/linter/rules/no-unused-imports",
     "lint/nursery/noUnusedPrivateClassMembers": "https://biomejs.dev/linter/rules/no-unused-private-class-members",
     "lint/nursery/noUselessLoneBlockStatements": "https://biomejs.dev/linter/rules/no-useless-lone-block-statements",
     "lint/nursery/useAwait": "https://biomejs.dev/linter/rules/use-await",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
+    "lint/nursery/useConsistentArrayType": "https://biomejs.dev/linter/rules/use-consistent-array-type",
+    "lint/nursery/useConsistentRecordType": "https://biomejs.dev/linter/rules/use-consistent-record-type",
     "lint/nursery/useGroupedTypeImport": "https://biomejs.dev/linter/rules/use-grouped-type-import",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
+    "lint/nursery/useJsdocParamMatch": "https://biomejs.dev/linter/rules/use-jsdoc-param-match",
     "lint/nursery/useRegexLiterals": "https://biomejs.dev/linter/rules/use-regex-literals",
+    "lint/nursery/useTopLevelDescribe": "https://biomejs.dev/linter/rules/use-top-level-describe",
     "lint/nursery/useValidAriaRole": "https://biomejs.dev/lint/rules/use-valid-aria-role",
+    "lint/nursery/useValidJsdoc": "https://biomejs.dev/linter/rules/use-valid-jsdoc",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
     "lint/security/noDangerouslySetInnerHtml": "https://biomejs.dev/linter/rules/no-dangerously-set-inner-html",
@@ -196,17 +219,27 @@ define_categories! {
     ;
     // General categories
     "files/missingHandler",
+    "files/tooLarge",
     "format",
     "check",
     "ci",
     "configuration",
     "organizeImports",
     "migrate",
+    "search",
     "deserialize",
     "project",
+    "project/barrelImport",
+    "project/importCycle",
+    "project/moduleBoundary",
+    "project/serverOnlyImport",
+    "project/unknownClass",
+    "project/unusedClass",
+    "project/unusedExport",
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
+    "internalError/cancelled",
     // parse categories
     "parse",
     "parse/noSuperWithoutExtends",
@@ -174,14 +174,28 @@ fn generate_message(input: &DeriveInput) -> TokenStream {
 }
 
 fn generate_advices(input: &DeriveInput) -> TokenStream {
-    if input.advices.is_empty() {
+    if input.labels.is_empty() && input.advices.is_empty() {
         return quote!();
     }
 
+    let labels = if input.labels.is_empty() {
+        quote!()
+    } else {
+        let labels = input.labels.iter();
+        quote! {
+            let mut labels = Vec::new();
+            #( labels.extend(biome_diagnostics::AsLabels::labels(&self.#labels)); )*
+            if !labels.is_empty() {
+                visitor.record_labels(&labels)?;
+            }
+        }
+    };
+
     let advices = input.advices.iter();
 
     quote! {
         fn advices(&self, visitor: &mut dyn biome_diagnostics::Visit) -> ::std::io::Result<()> {
+            #labels
             #( biome_diagnostics::Advices::record(&self.#advices, visitor)?; )*
             Ok(())
         }
@@ -13,6 +13,7 @@ mod parse;
         category,
         description,
         message,
+        label,
         advice,
         verbose_advice,
         location,
@@ -17,6 +17,7 @@ pub(crate) struct DeriveInput {
     pub(crate) category: Option<StaticOrDynamic<syn::LitStr>>,
     pub(crate) description: Option<StaticOrDynamic<StringOrMarkup>>,
     pub(crate) message: Option<StaticOrDynamic<StringOrMarkup>>,
+    pub(crate) labels: Vec<TokenStream>,
     pub(crate) advices: Vec<TokenStream>,
     pub(crate) verbose_advices: Vec<TokenStream>,
     pub(crate) location: Vec<(TokenStream, LocationField)>,
@@ -34,6 +35,7 @@ impl DeriveInput {
             category: None,
             description: None,
             message: None,
+            labels: Vec::new(),
             advices: Vec::new(),
             verbose_advices: Vec::new(),
             location: Vec::new(),
@@ -134,6 +136,11 @@ impl DeriveInput {
                     continue;
                 }
 
+                if attr.path.is_ident("label") {
+                    result.labels.push(ident.clone());
+                    continue;
+                }
+
                 if attr.path.is_ident("advice") {
                     result.advices.push(ident.clone());
                     continue;
@@ -2222,6 +2222,20 @@ where
         });
     }
 
+    /// Adds a new node with the specified formatted content to the output, separated from the
+    /// previous node by an empty line regardless of the new lines before the node in the input source.
+    pub fn entry_with_empty_line(&mut self, content: &dyn Format<Context>) {
+        self.result = self.result.and_then(|_| {
+            if self.has_elements {
+                write!(self.fmt, [empty_line()])?;
+            }
+
+            self.has_elements = true;
+
+            write!(self.fmt, [content])
+        });
+    }
+
     /// Writes an entry without adding a separating line break or empty line.
     pub fn entry_no_separator(&mut self, content: &dyn Format<Context>) {
         self.result = self.result.and_then(|_| {
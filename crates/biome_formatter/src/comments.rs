@@ -260,6 +260,7 @@ pub struct DecoratedComment<L: Language> {
     lines_after: u32,
     comment: SyntaxTriviaPieceComments<L>,
     kind: CommentKind,
+    after_removed_l_paren: bool,
 }
 
 impl<L: Language> DecoratedComment<L> {
@@ -454,6 +455,21 @@ impl<L: Language> DecoratedComment<L> {
     pub fn following_token(&self) -> Option<&SyntaxToken<L>> {
         self.following_token.as_ref()
     }
+
+    /// Returns `true` if the comment directly follows a `(` that the transform of the syntax tree removed,
+    /// on the same line as the `(`.
+    ///
+    /// ```ignore
+    /// call(a, ( // comment
+    ///   b
+    /// ));
+    /// ```
+    ///
+    /// Returns `true` because the comment is right after the removed `(`. The [`preceding_node`](DecoratedComment::preceding_node)
+    /// of the comment is `a`, even though the comment was written inside of the parentheses around `b`.
+    pub fn is_after_removed_l_paren(&self) -> bool {
+        self.after_removed_l_paren
+    }
 }
 
 impl<L: Language> From<DecoratedComment<L>> for SourceComment<L> {
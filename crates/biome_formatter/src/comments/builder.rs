@@ -162,6 +162,10 @@ where
                 break;
             } else if let Some(comment) = leading.as_comments() {
                 let kind = Style::get_comment_kind(&comment);
+                let after_removed_l_paren = !position.is_own_line()
+                    && self
+                        .parentheses
+                        .is_after_l_paren(comment.text_range().start());
 
                 self.queue_comment(DecoratedComment {
                     enclosing: self.enclosing_node().clone(),
@@ -173,6 +177,7 @@ where
                     text_position: position,
                     kind,
                     comment,
+                    after_removed_l_paren,
                 });
 
                 lines_before = 0;
@@ -291,6 +296,7 @@ where
                     text_position: position,
                     kind: Style::get_comment_kind(&comment),
                     comment,
+                    after_removed_l_paren: false,
                 });
 
                 lines_before = 0;
@@ -375,13 +381,13 @@ impl<L: Language> CommentsBuilder<L> {
     fn add_comment(&mut self, placement: CommentPlacement<L>) {
         match placement {
             CommentPlacement::Leading { node, comment } => {
-                self.push_leading_comment(&node, comment);
+                self.push_leading_comment(&node, comment, "placed by the language");
             }
             CommentPlacement::Trailing { node, comment } => {
-                self.push_trailing_comment(&node, comment);
+                self.push_trailing_comment(&node, comment, "placed by the language");
             }
             CommentPlacement::Dangling { node, comment } => {
-                self.push_dangling_comment(&node, comment)
+                self.push_dangling_comment(&node, comment, "placed by the language")
             }
             CommentPlacement::Default(mut comment) => {
                 match comment.text_position {
@@ -394,18 +400,31 @@ impl<L: Language> CommentsBuilder<L> {
                                 // a; // comment
                                 // b
                                 // ```
-                                self.push_trailing_comment(&preceding, comment);
+                                self.push_trailing_comment(
+                                    &preceding,
+                                    comment,
+                                    "end of line comment after the preceding node",
+                                );
                             }
                             (Some(preceding), None) => {
-                                self.push_trailing_comment(&preceding, comment);
+                                self.push_trailing_comment(
+                                    &preceding,
+                                    comment,
+                                    "end of line comment without following node",
+                                );
                             }
                             (None, Some(following)) => {
-                                self.push_leading_comment(&following, comment);
+                                self.push_leading_comment(
+                                    &following,
+                                    comment,
+                                    "end of line comment without preceding node",
+                                );
                             }
                             (None, None) => {
                                 self.push_dangling_comment(
                                     &comment.enclosing_node().clone(),
                                     comment,
+                                    "end of line comment without preceding and following nodes",
                                 );
                             }
                         }
@@ -420,15 +439,24 @@ impl<L: Language> CommentsBuilder<L> {
                             // ```
                             // attach the comment to the `b` expression statement
                             (_, Some(following)) => {
-                                self.push_leading_comment(&following, comment);
+                                self.push_leading_comment(
+                                    &following,
+                                    comment,
+                                    "own line comment before the following node",
+                                );
                             }
                             (Some(preceding), None) => {
-                                self.push_trailing_comment(&preceding, comment);
+                                self.push_trailing_comment(
+                                    &preceding,
+                                    comment,
+                                    "own line comment without following node",
+                                );
                             }
                             (None, None) => {
                                 self.push_dangling_comment(
                                     &comment.enclosing_node().clone(),
                                     comment,
+                                    "own line comment without preceding and following nodes",
                                 );
                             }
                         }
@@ -445,21 +473,38 @@ impl<L: Language> CommentsBuilder<L> {
                                 if preceding.text_range().end()
                                     == comment.piece().as_piece().token().text_range().end()
                                 {
-                                    self.push_trailing_comment(&preceding, comment);
+                                    self.push_trailing_comment(
+                                        &preceding,
+                                        comment,
+                                        "comment directly after the preceding node",
+                                    );
                                 } else {
-                                    self.push_leading_comment(&following, comment);
+                                    self.push_leading_comment(
+                                        &following,
+                                        comment,
+                                        "comment separated from the preceding node by a token",
+                                    );
                                 }
                             }
                             (Some(preceding), None) => {
-                                self.push_trailing_comment(&preceding, comment);
+                                self.push_trailing_comment(
+                                    &preceding,
+                                    comment,
+                                    "comment without following node",
+                                );
                             }
                             (None, Some(following)) => {
-                                self.push_leading_comment(&following, comment);
+                                self.push_leading_comment(
+                                    &following,
+                                    comment,
+                                    "comment without preceding node",
+                                );
                             }
                             (None, None) => {
                                 self.push_dangling_comment(
                                     &comment.enclosing_node().clone(),
                                     comment,
+                                    "comment without preceding and following nodes",
                                 );
                             }
                         }
@@ -473,24 +518,37 @@ impl<L: Language> CommentsBuilder<L> {
         self.skipped.insert(token.key());
     }
 
-    fn push_leading_comment(&mut self, node: &SyntaxNode<L>, comment: impl Into<SourceComment<L>>) {
-        self.comments.push_leading(node.key(), comment.into());
+    fn push_leading_comment(
+        &mut self,
+        node: &SyntaxNode<L>,
+        comment: impl Into<SourceComment<L>>,
+        reason: &str,
+    ) {
+        let comment = comment.into();
+        trace_comment_attachment("Leading", node, &comment, reason);
+        self.comments.push_leading(node.key(), comment);
     }
 
     fn push_dangling_comment(
         &mut self,
         node: &SyntaxNode<L>,
         comment: impl Into<SourceComment<L>>,
+        reason: &str,
     ) {
-        self.comments.push_dangling(node.key(), comment.into());
+        let comment = comment.into();
+        trace_comment_attachment("Dangling", node, &comment, reason);
+        self.comments.push_dangling(node.key(), comment);
     }
 
     fn push_trailing_comment(
         &mut self,
         node: &SyntaxNode<L>,
         comment: impl Into<SourceComment<L>>,
+        reason: &str,
     ) {
-        self.comments.push_trailing(node.key(), comment.into());
+        let comment = comment.into();
+        trace_comment_attachment("Trailing", node, &comment, reason);
+        self.comments.push_trailing(node.key(), comment);
     }
 
     fn finish(
@@ -512,6 +570,24 @@ impl<L: Language> Default for CommentsBuilder<L> {
     }
 }
 
+/// Logs which node a comment is attached to, and why.
+///
+/// The logs are printed by the CLI with `--log-level=debug`. They help to understand where a comment
+/// moves when reporting a formatting bug. The ranges are the ones of the transformed syntax tree.
+fn trace_comment_attachment<L: Language>(
+    attachment: &str,
+    node: &SyntaxNode<L>,
+    comment: &SourceComment<L>,
+    reason: &str,
+) {
+    tracing::debug!(
+        "{attachment} comment of {:?}@{:?} ({reason}): {}",
+        node.kind(),
+        node.text_trimmed_range(),
+        comment.piece().text()
+    );
+}
+
 enum SourceParentheses<'a> {
     Empty,
     SourceMap {
@@ -575,6 +651,30 @@ impl<'a> SourceParentheses<'a> {
         }
     }
 
+    /// Tests if the original source document had a `(` right before `offset`, only separated by whitespace.
+    ///
+    /// Must be called with offsets in increasing order, together with [SourceParentheses::r_paren_source_range].
+    fn is_after_l_paren(&mut self, offset: TextSize) -> bool {
+        match self {
+            SourceParentheses::Empty => false,
+            SourceParentheses::SourceMap { next, tail, .. } => {
+                while let Some(range) = next {
+                    #[allow(clippy::comparison_chain)]
+                    if range.transformed == offset {
+                        // The deleted range contains the `(` and the whitespace that follows it
+                        return range.text.trim_end().ends_with('(');
+                    } else if range.transformed > offset {
+                        return false;
+                    } else {
+                        *next = tail.next();
+                    }
+                }
+
+                false
+            }
+        }
+    }
+
     /// Searches the outer most node that still is inside of the parentheses specified by the `parentheses_source_range`.
     fn outer_most_parenthesized_node<L: Language>(
         &self,
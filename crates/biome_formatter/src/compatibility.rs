@@ -0,0 +1,223 @@
+//! Measures how much the output of the Biome formatter diverges from the output
+//! of Prettier, per file and per syntax construct.
+//!
+//! The compatibility of a file is the number of lines that are identical in both
+//! outputs, divided by the number of lines of the longest output.
+
+use similar::{utils::diff_lines, Algorithm, ChangeTag};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::ops::Range;
+
+/// The lines of the output of Biome that format a syntax construct, e.g. a class
+/// declaration or an import.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConstructSpan {
+    /// The name of the construct, used to group the metrics of the files
+    pub name: String,
+    /// The zero-based indices of the lines
+    pub lines: Range<usize>,
+}
+
+/// How many lines of two outputs are identical
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LineMetrics {
+    pub biome_lines: usize,
+    pub prettier_lines: usize,
+    pub matched_lines: usize,
+}
+
+impl LineMetrics {
+    /// The number of lines of the longest output
+    pub fn total_lines(&self) -> usize {
+        self.biome_lines.max(self.prettier_lines)
+    }
+
+    /// The ratio of identical lines, between 0 and 1. Two empty outputs are compatible.
+    pub fn compatibility(&self) -> f64 {
+        match self.total_lines() {
+            0 => 1_f64,
+            total_lines => self.matched_lines as f64 / total_lines as f64,
+        }
+    }
+
+    fn add(&mut self, other: &LineMetrics) {
+        self.biome_lines += other.biome_lines;
+        self.prettier_lines += other.prettier_lines;
+        self.matched_lines += other.matched_lines;
+    }
+}
+
+/// The comparison of the outputs of Biome and Prettier for a single file
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FileCompatibility {
+    pub path: String,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub metrics: LineMetrics,
+    /// The metrics of the lines of each construct of the file
+    pub constructs: BTreeMap<String, LineMetrics>,
+    /// The line diff from the output of Prettier to the output of Biome, when they differ
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub diff: Option<String>,
+}
+
+impl FileCompatibility {
+    /// Compares the outputs of Biome and Prettier for the file at `path`.
+    ///
+    /// The lines are attributed to the constructs of `constructs`, which are the
+    /// spans of the output of Biome. A line that only exists in the output of
+    /// Prettier is attributed to the construct of the next line of Biome.
+    pub fn compare(
+        path: impl Into<String>,
+        biome_output: &str,
+        prettier_output: &str,
+        constructs: &[ConstructSpan],
+    ) -> Self {
+        let mut metrics = LineMetrics::default();
+        let mut construct_metrics = BTreeMap::<String, LineMetrics>::new();
+        let mut diff = String::new();
+        let mut biome_line = 0;
+
+        for (tag, line) in diff_lines(Algorithm::default(), prettier_output, biome_output) {
+            let line_metrics = LineMetrics {
+                biome_lines: usize::from(tag != ChangeTag::Delete),
+                prettier_lines: usize::from(tag != ChangeTag::Insert),
+                matched_lines: usize::from(tag == ChangeTag::Equal),
+            };
+            metrics.add(&line_metrics);
+            if let Some(construct) = constructs
+                .iter()
+                .find(|construct| construct.lines.contains(&biome_line))
+            {
+                construct_metrics
+                    .entry(construct.name.clone())
+                    .or_default()
+                    .add(&line_metrics);
+            }
+            if tag != ChangeTag::Delete {
+                biome_line += 1;
+            }
+
+            let line = line.strip_suffix('\n').unwrap_or(line);
+            writeln!(diff, "{tag}{line}").unwrap();
+        }
+
+        Self {
+            path: path.into(),
+            metrics,
+            constructs: construct_metrics,
+            diff: (biome_output != prettier_output).then_some(diff),
+        }
+    }
+
+    /// Returns `true` if both outputs are identical
+    pub fn is_compatible(&self) -> bool {
+        self.metrics.matched_lines == self.metrics.total_lines()
+    }
+}
+
+/// The comparison of the outputs of Biome and Prettier for a set of files
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CompatibilityReport {
+    pub files: Vec<FileCompatibility>,
+}
+
+impl CompatibilityReport {
+    pub fn push(&mut self, file: FileCompatibility) {
+        self.files.push(file);
+    }
+
+    /// Sorts the files by path, so the report doesn't depend on the order in
+    /// which the files were compared
+    pub fn sort(&mut self) {
+        self.files.sort_by(|a, b| a.path.cmp(&b.path));
+    }
+
+    /// The average of the compatibility of the files
+    pub fn file_based_compatibility(&self) -> f64 {
+        if self.files.is_empty() {
+            return 1_f64;
+        }
+        let sum: f64 = self
+            .files
+            .iter()
+            .map(|file| file.metrics.compatibility())
+            .sum();
+        sum / self.files.len() as f64
+    }
+
+    /// The compatibility of all the lines of the files
+    pub fn line_based_compatibility(&self) -> f64 {
+        let mut metrics = LineMetrics::default();
+        for file in &self.files {
+            metrics.add(&file.metrics);
+        }
+        metrics.compatibility()
+    }
+
+    /// The metrics of the lines of each construct, across all the files
+    pub fn constructs(&self) -> BTreeMap<&str, LineMetrics> {
+        let mut constructs = BTreeMap::<&str, LineMetrics>::new();
+        for file in &self.files {
+            for (name, metrics) in &file.constructs {
+                constructs.entry(name.as_str()).or_default().add(metrics);
+            }
+        }
+        constructs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_outputs_are_compatible() {
+        let file = FileCompatibility::compare("a.js", "a;\nb;\n", "a;\nb;\n", &[]);
+        assert!(file.is_compatible());
+        assert_eq!(file.metrics.compatibility(), 1_f64);
+        assert_eq!(file.diff, None);
+    }
+
+    #[test]
+    fn attributes_the_lines_to_their_construct() {
+        let constructs = [
+            ConstructSpan {
+                name: String::from("import"),
+                lines: 0..1,
+            },
+            ConstructSpan {
+                name: String::from("call"),
+                lines: 1..3,
+            },
+        ];
+        let file = FileCompatibility::compare(
+            "a.js",
+            "import a from \"a\";\nf(\n  a);\n",
+            "import a from \"a\";\nf(a);\n",
+            &constructs,
+        );
+
+        assert!(!file.is_compatible());
+        assert_eq!(file.metrics.compatibility(), 1_f64 / 3_f64);
+        assert_eq!(
+            file.constructs["import"],
+            LineMetrics {
+                biome_lines: 1,
+                prettier_lines: 1,
+                matched_lines: 1,
+            }
+        );
+        assert_eq!(
+            file.constructs["call"],
+            LineMetrics {
+                biome_lines: 2,
+                prettier_lines: 1,
+                matched_lines: 0,
+            }
+        );
+    }
+}
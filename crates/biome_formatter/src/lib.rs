@@ -25,6 +25,7 @@ mod arguments;
 mod buffer;
 mod builders;
 pub mod comments;
+pub mod compatibility;
 pub mod diagnostics;
 pub mod format_element;
 mod format_extensions;
@@ -1,4 +1,4 @@
-use similar::{utils::diff_lines, Algorithm, ChangeTag};
+use biome_formatter::compatibility::{CompatibilityReport, FileCompatibility};
 use std::sync::Mutex;
 use std::{env, fmt::Write, fs::write, os::raw::c_int, str::FromStr, sync::Once};
 
@@ -18,12 +18,6 @@ struct SingleFileMetricData {
     diff: Option<String>,
 }
 
-impl SingleFileMetricData {
-    fn is_compatible(&self) -> bool {
-        (self.single_file_compatibility * 100_f64) >= 100.00
-    }
-}
-
 #[derive(Clone, Debug, Default, Serialize)]
 struct PrettierCompatibilityMetricData {
     file_based_average_prettier_similarity: f64,
@@ -311,82 +305,55 @@ impl DiffReport {
         let mut state = self.state.lock().unwrap();
         state.sort_by_key(|DiffReportItem { file_name, .. }| *file_name);
 
-        let mut report_metric_data = PrettierCompatibilityMetricData::default();
-        let mut file_ratio_sum = 0_f64;
-        let mut total_lines = 0;
-        let mut total_matched_lines = 0;
-        let mut file_count = 0;
+        let report_metric_data = Self::metric_data(&state, incompatible_only);
+        let content = match report_type {
+            ReportType::Json => Self::report_json(report_metric_data),
+            ReportType::Markdown => Self::report_markdown(report_metric_data),
+        };
+        write(report_filename, content).unwrap();
+    }
 
+    fn metric_data(
+        items: &[DiffReportItem],
+        incompatible_only: bool,
+    ) -> PrettierCompatibilityMetricData {
+        let mut report = CompatibilityReport::default();
         for DiffReportItem {
             file_name,
             biome_formatted_result,
             prettier_formatted_result,
-        } in state.iter()
+        } in items
         {
-            file_count += 1;
-
-            let biome_lines = biome_formatted_result.lines().count();
-            let prettier_lines = prettier_formatted_result.lines().count();
-
-            let (matched_lines, ratio, diff) =
-                if biome_formatted_result == prettier_formatted_result {
-                    (biome_lines, 1f64, None)
-                } else {
-                    let mut matched_lines = 0;
-                    let mut diff = String::new();
-
-                    for (tag, line) in diff_lines(
-                        Algorithm::default(),
-                        prettier_formatted_result,
-                        biome_formatted_result,
-                    ) {
-                        if matches!(tag, ChangeTag::Equal) {
-                            matched_lines += 1;
-                        }
-
-                        let line = line.strip_suffix('\n').unwrap_or(line);
-                        writeln!(diff, "{}{}", tag, line).unwrap();
-                    }
-
-                    let ratio = matched_lines as f64 / biome_lines.max(prettier_lines) as f64;
-
-                    (matched_lines, ratio, Some(diff))
-                };
-
-            total_lines += biome_lines.max(prettier_lines);
-            total_matched_lines += matched_lines;
-            file_ratio_sum += ratio;
-
-            let single_file_metric_data = SingleFileMetricData {
-                diff,
-                filename: (*file_name).to_string(),
-                single_file_compatibility: ratio,
-            };
+            report.push(FileCompatibility::compare(
+                *file_name,
+                biome_formatted_result,
+                prettier_formatted_result,
+                &[],
+            ));
+        }
 
+        let mut report_metric_data = PrettierCompatibilityMetricData {
+            file_based_average_prettier_similarity: report.file_based_compatibility(),
+            line_based_average_prettier_similarity: report.line_based_compatibility(),
+            files: Vec::new(),
+        };
+        for file in report.files {
             // We'll skip compatible tests and only track incompatible ones
-            if incompatible_only && single_file_metric_data.is_compatible() {
+            if incompatible_only && file.is_compatible() {
                 continue;
             }
 
-            report_metric_data.files.push(single_file_metric_data);
+            report_metric_data.files.push(SingleFileMetricData {
+                filename: file.path,
+                single_file_compatibility: file.metrics.compatibility(),
+                diff: file.diff,
+            });
         }
 
-        report_metric_data.file_based_average_prettier_similarity =
-            file_ratio_sum / f64::from(file_count);
-        report_metric_data.line_based_average_prettier_similarity =
-            total_matched_lines as f64 / total_lines as f64;
-
-        match report_type {
-            ReportType::Json => self.report_json(report_filename, report_metric_data),
-            ReportType::Markdown => self.report_markdown(report_filename, report_metric_data),
-        }
+        report_metric_data
     }
 
-    fn report_markdown(
-        &self,
-        report_filename: String,
-        report_metric_data: PrettierCompatibilityMetricData,
-    ) {
+    fn report_markdown(report_metric_data: PrettierCompatibilityMetricData) -> String {
         let mut report = String::new();
 
         for SingleFileMetricData {
@@ -454,16 +421,88 @@ impl DiffReport {
 ## Test cases",
             );
 
-        let report = format!("{header}\n\n{report}");
-        write(report_filename, report).unwrap();
+        format!("{header}\n\n{report}")
     }
 
-    fn report_json(
-        &self,
-        report_filename: String,
-        report_metric_data: PrettierCompatibilityMetricData,
-    ) {
-        let json_content = serde_json::to_string(&report_metric_data).unwrap();
-        write(report_filename, json_content).unwrap();
+    fn report_json(report_metric_data: PrettierCompatibilityMetricData) -> String {
+        serde_json::to_string(&report_metric_data).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(
+        file_name: &'static str,
+        biome_formatted_result: &str,
+        prettier_formatted_result: &str,
+    ) -> DiffReportItem {
+        DiffReportItem {
+            file_name,
+            biome_formatted_result: biome_formatted_result.to_owned(),
+            prettier_formatted_result: prettier_formatted_result.to_owned(),
+        }
+    }
+
+    #[test]
+    fn parses_the_report_types() {
+        assert_eq!(ReportType::from_str("json"), Ok(ReportType::Json));
+        assert_eq!(ReportType::from_str("markdown"), Ok(ReportType::Markdown));
+        assert!(ReportType::from_str("html").is_err());
+    }
+
+    #[test]
+    fn ignores_the_experimental_syntaxes() {
+        assert!(DiffReport::is_ignored(
+            "js/pipeline-operator/block-comments.js"
+        ));
+        assert!(!DiffReport::is_ignored("js/arrows/currying.js"));
+    }
+
+    #[test]
+    fn skips_the_compatible_files_when_incompatible_only() {
+        let items = [
+            item("a.js", "a;\n", "a;\n"),
+            item("b.js", "f(\n  b);\n", "f(b);\n"),
+        ];
+
+        let all = DiffReport::metric_data(&items, false);
+        assert_eq!(all.files.len(), 2);
+        assert_eq!(all.file_based_average_prettier_similarity, 0.5);
+        assert_eq!(all.line_based_average_prettier_similarity, 1_f64 / 3_f64);
+
+        let incompatible = DiffReport::metric_data(&items, true);
+        assert_eq!(incompatible.files.len(), 1);
+        assert_eq!(incompatible.files[0].filename, "b.js");
+        assert_eq!(incompatible.files[0].single_file_compatibility, 0_f64);
+        // The averages still cover all the files
+        assert_eq!(incompatible.file_based_average_prettier_similarity, 0.5);
+    }
+
+    #[test]
+    fn markdown_report_contains_the_diff_of_the_incompatible_files() {
+        let items = [
+            item("a.js", "a;\n", "a;\n"),
+            item("b.js", "f(\n  b);\n", "f(b);\n"),
+        ];
+        let report = DiffReport::report_markdown(DiffReport::metric_data(&items, false));
+
+        assert!(report.starts_with("## Overall Metrics"));
+        assert!(report.contains("**Average compatibility**: 50.00"));
+        assert!(report.contains("**Compatible lines**: 33.33"));
+        assert!(report.contains("### a.js\n\n**Prettier Similarity**: 100.00%"));
+        assert!(report.contains("### b.js\n```diff\n-f(b);\n+f(\n+  b);\n"));
+    }
+
+    #[test]
+    fn json_report_omits_the_diffs() {
+        let items = [item("b.js", "f(\n  b);\n", "f(b);\n")];
+        let report = DiffReport::report_json(DiffReport::metric_data(&items, false));
+
+        assert_eq!(
+            report,
+            r#"{"file_based_average_prettier_similarity":0.0,"line_based_average_prettier_similarity":0.0,"files":[{"filename":"b.js","single_file_compatibility":0.0}]}"#
+        );
     }
 }
@@ -3,6 +3,7 @@ use biome_diagnostics::{console, Advices, Diagnostic, LogCategory, Visit};
 use biome_diagnostics::{Error, Severity};
 pub use memory::{ErrorEntry, MemoryFileSystem};
 pub use os::OsFileSystem;
+pub use overlay::OverlayFileSystem;
 use serde::{Deserialize, Serialize};
 use std::io;
 use std::panic::RefUnwindSafe;
@@ -12,9 +13,11 @@ use tracing::{error, info};
 
 mod memory;
 mod os;
+mod overlay;
 
 pub const ROME_JSON: &str = "rome.json";
 pub const BIOME_JSON: &str = "biome.json";
+pub const BIOME_JSONC: &str = "biome.jsonc";
 
 pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// It opens a file with the given set of options
@@ -43,6 +46,28 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path exists in the file system
     fn path_exists(&self, path: &Path) -> bool;
 
+    /// Returns the paths, relative to the working directory, of the files that changed between
+    /// `base` and the current `HEAD` of the VCS repository
+    fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
+
+    /// Returns the paths, relative to the working directory, of the files that are currently
+    /// staged in the VCS repository
+    fn get_staged_files(&self) -> io::Result<Vec<String>>;
+
+    /// Returns the paths, relative to the working directory, of the files that have changes
+    /// that aren't staged in the VCS repository
+    fn get_unstaged_files(&self) -> io::Result<Vec<String>>;
+
+    /// Returns the content of the file at `path` as it's currently staged in the VCS repository
+    fn read_staged_content(&self, path: &str) -> io::Result<String>;
+
+    /// Replaces the staged content of the file at `path` with `content`, without touching
+    /// the file in the working directory
+    fn stage_content(&self, path: &str, content: &str) -> io::Result<()>;
+
+    /// Stages the current content of the files at `paths`
+    fn stage_files(&self, paths: &[String]) -> io::Result<()>;
+
     /// Method that takes a path to a folder `file_path`, and a `file_name`. It attempts to find
     /// and read the file from that folder and if not found, it reads the parent directories recursively
     /// until:
@@ -210,6 +235,15 @@ pub trait FileSystemExt: FileSystem {
         self.open_with_options(path, OpenOptions::default().read(true))
     }
 
+    /// Reads the whole content of a file
+    ///
+    /// Equivalent to [std::fs::read_to_string]
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let mut content = String::new();
+        self.open(path)?.read_to_string(&mut content)?;
+        Ok(content)
+    }
+
     /// Open a file with the `write` and `create` options
     ///
     /// Equivalent to [std::fs::File::create]
@@ -269,6 +303,39 @@ pub trait TraversalContext: Sync {
     /// This method will be called by the traversal for each file it finds
     /// where [TraversalContext::can_handle] returned true
     fn handle_file(&self, path: &Path);
+
+    /// How the traversal handles the symbolic links and the case of the paths
+    fn traversal_policy(&self) -> TraversalPolicy {
+        TraversalPolicy::default()
+    }
+}
+
+/// How a traversal of the file system handles the symbolic links, and the paths
+/// that differ only by their case.
+///
+/// Each file and directory is traversed once, even when it's reached through
+/// several paths, e.g. through symbolic links created by pnpm or by Bazel. A
+/// symbolic link to one of its parent directories doesn't cause an infinite
+/// traversal.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TraversalPolicy {
+    /// Whether the symbolic links found inside the traversed directories are
+    /// followed. When `false`, they are skipped. The paths passed to the
+    /// traversal are always followed.
+    pub follow_symlinks: bool,
+    /// Whether two paths that differ only by their case point at the same
+    /// file. It's the case by default on Windows and macOS, whose file systems
+    /// are usually case-insensitive.
+    pub case_insensitive: bool,
+}
+
+impl Default for TraversalPolicy {
+    fn default() -> Self {
+        Self {
+            follow_symlinks: true,
+            case_insensitive: cfg!(any(windows, target_os = "macos")),
+        }
+    }
 }
 
 impl<T> FileSystem for Arc<T>
@@ -290,6 +357,30 @@ where
     fn path_exists(&self, path: &Path) -> bool {
         T::path_exists(self, path)
     }
+
+    fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
+        T::get_changed_files(self, base)
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        T::get_staged_files(self)
+    }
+
+    fn get_unstaged_files(&self) -> io::Result<Vec<String>> {
+        T::get_unstaged_files(self)
+    }
+
+    fn read_staged_content(&self, path: &str) -> io::Result<String> {
+        T::read_staged_content(self, path)
+    }
+
+    fn stage_content(&self, path: &str, content: &str) -> io::Result<()> {
+        T::stage_content(self, path, content)
+    }
+
+    fn stage_files(&self, paths: &[String]) -> io::Result<()> {
+        T::stage_files(self, paths)
+    }
 }
 
 #[derive(Debug, Diagnostic, Deserialize, Serialize)]
@@ -19,6 +19,11 @@ pub struct MemoryFileSystem {
     files: AssertUnwindSafe<RwLock<FxHashMap<PathBuf, FileEntry>>>,
     errors: FxHashMap<PathBuf, ErrorEntry>,
     allow_write: bool,
+    changed_files: Vec<String>,
+    staged_files: Vec<String>,
+    unstaged_files: Vec<String>,
+    /// The content of the files in the index of the VCS, by path
+    staged_contents: AssertUnwindSafe<RwLock<FxHashMap<String, String>>>,
 }
 
 impl Default for MemoryFileSystem {
@@ -27,6 +32,10 @@ impl Default for MemoryFileSystem {
             files: Default::default(),
             errors: Default::default(),
             allow_write: true,
+            changed_files: Vec::new(),
+            staged_files: Vec::new(),
+            unstaged_files: Vec::new(),
+            staged_contents: Default::default(),
         }
     }
 }
@@ -81,6 +90,32 @@ impl MemoryFileSystem {
         self.errors.insert(path, kind);
     }
 
+    /// Sets the list of files returned by [FileSystem::get_changed_files]
+    pub fn set_changed_files(&mut self, changed_files: Vec<String>) {
+        self.changed_files = changed_files;
+    }
+
+    /// Sets the list of files returned by [FileSystem::get_staged_files]
+    pub fn set_staged_files(&mut self, staged_files: Vec<String>) {
+        self.staged_files = staged_files;
+    }
+
+    /// Sets the list of files returned by [FileSystem::get_unstaged_files]
+    pub fn set_unstaged_files(&mut self, unstaged_files: Vec<String>) {
+        self.unstaged_files = unstaged_files;
+    }
+
+    /// Create or update the staged content of a file
+    pub fn insert_staged_content(&mut self, path: impl Into<String>, content: impl Into<String>) {
+        let staged_contents = self.staged_contents.0.get_mut();
+        staged_contents.insert(path.into(), content.into());
+    }
+
+    /// Returns the staged content of a file
+    pub fn staged_content(&self, path: &str) -> Option<String> {
+        self.staged_contents.0.read().get(path).cloned()
+    }
+
     /// Remove a file from the filesystem
     pub fn remove(&mut self, path: &Path) {
         self.files.0.write().remove(path);
@@ -166,6 +201,49 @@ impl FileSystem for MemoryFileSystem {
         let files = self.files.0.read();
         files.get(path).is_some()
     }
+
+    fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
+        Ok(self.changed_files.clone())
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        Ok(self.staged_files.clone())
+    }
+
+    fn get_unstaged_files(&self) -> io::Result<Vec<String>> {
+        Ok(self.unstaged_files.clone())
+    }
+
+    fn read_staged_content(&self, path: &str) -> io::Result<String> {
+        self.staged_content(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("the file {path} isn't staged"),
+            )
+        })
+    }
+
+    fn stage_content(&self, path: &str, content: &str) -> io::Result<()> {
+        let mut staged_contents = self.staged_contents.0.write();
+        staged_contents.insert(path.to_string(), content.to_string());
+        Ok(())
+    }
+
+    fn stage_files(&self, paths: &[String]) -> io::Result<()> {
+        let files = self.files.0.read();
+        let mut staged_contents = self.staged_contents.0.write();
+        for path in paths {
+            let entry = files.get(Path::new(path)).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("the file {path} doesn't exist"),
+                )
+            })?;
+            let content = String::from_utf8_lossy(&entry.lock()).into_owned();
+            staged_contents.insert(path.clone(), content);
+        }
+        Ok(())
+    }
 }
 
 struct MemoryFile {
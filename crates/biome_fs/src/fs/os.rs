@@ -2,12 +2,13 @@
 use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
 use crate::fs::OpenOptions;
 use crate::{
-    fs::{TraversalContext, TraversalScope},
+    fs::{TraversalContext, TraversalPolicy, TraversalScope},
     FileSystem, RomePath,
 };
 use biome_diagnostics::{adapters::IoError, DiagnosticExt, Error, Severity};
 use rayon::{scope, Scope};
 use std::fs::{DirEntry, FileType};
+use std::process::{Command, Output, Stdio};
 use std::{
     env,
     ffi::OsStr,
@@ -47,6 +48,139 @@ impl FileSystem for OsFileSystem {
     fn path_exists(&self, path: &Path) -> bool {
         path.exists()
     }
+
+    fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
+        let output = Command::new("git")
+            .arg("diff")
+            .arg("--name-only")
+            .arg("--relative")
+            // A: added
+            // C: copied
+            // M: modified
+            // R: renamed
+            // Source: https://git-scm.com/docs/git-diff
+            .arg("--diff-filter=ACMR")
+            .arg(format!("{base}...HEAD"))
+            .output()?;
+
+        parse_git_output(output)
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        let output = Command::new("git")
+            .arg("diff")
+            .arg("--name-only")
+            .arg("--relative")
+            .arg("--staged")
+            // Deleted files can't be processed, see `get_changed_files`
+            .arg("--diff-filter=ACMR")
+            .output()?;
+
+        parse_git_output(output)
+    }
+
+    fn get_unstaged_files(&self) -> io::Result<Vec<String>> {
+        let output = Command::new("git")
+            .arg("diff")
+            .arg("--name-only")
+            .arg("--relative")
+            .arg("--diff-filter=ACMR")
+            .output()?;
+
+        parse_git_output(output)
+    }
+
+    fn read_staged_content(&self, path: &str) -> io::Result<String> {
+        // `:./<path>` is the staged version of a path relative to the working directory
+        let output = Command::new("git")
+            .arg("show")
+            .arg(format!(":./{path}"))
+            .output()?;
+
+        Ok(String::from_utf8_lossy(&check_git_output(output)?.stdout).into_owned())
+    }
+
+    fn stage_content(&self, path: &str, content: &str) -> io::Result<()> {
+        // The staged file keeps its mode, e.g. the executable bit
+        let output = Command::new("git")
+            .arg("ls-files")
+            .arg("--stage")
+            .arg("--")
+            .arg(path)
+            .output()?;
+        let stage = String::from_utf8_lossy(&check_git_output(output)?.stdout).into_owned();
+        let mode = stage.split_whitespace().next().ok_or_else(|| {
+            io::Error::new(
+                IoErrorKind::NotFound,
+                format!("The file {path} isn't staged."),
+            )
+        })?;
+
+        let mut hash_object = Command::new("git")
+            .arg("hash-object")
+            .arg("-w")
+            .arg("--stdin")
+            .arg(format!("--path={path}"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = hash_object.stdin.take() {
+            stdin.write_all(content.as_bytes())?;
+        }
+        let output = hash_object.wait_with_output()?;
+        let object = String::from_utf8_lossy(&check_git_output(output)?.stdout)
+            .trim()
+            .to_string();
+
+        let output = Command::new("git")
+            .arg("update-index")
+            .arg("--cacheinfo")
+            .arg(format!("{mode},{object},{path}"))
+            .output()?;
+        check_git_output(output)?;
+
+        Ok(())
+    }
+
+    fn stage_files(&self, paths: &[String]) -> io::Result<()> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+
+        let output = Command::new("git")
+            .arg("add")
+            .arg("--")
+            .args(paths)
+            .output()?;
+        check_git_output(output)?;
+
+        Ok(())
+    }
+}
+
+/// Returns an error containing the standard error of a `git` command that failed
+fn check_git_output(output: Output) -> io::Result<Output> {
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::new(
+            IoErrorKind::Other,
+            stderr.trim().to_string(),
+        ));
+    }
+
+    Ok(output)
+}
+
+/// Turns the output of a `git diff --name-only` command into a list of paths
+fn parse_git_output(output: Output) -> io::Result<Vec<String>> {
+    let output = check_git_output(output)?;
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
 }
 
 struct OsFile {
@@ -128,6 +262,15 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
 
         let _ = ctx.interner().intern_path(path.clone());
 
+        // The same file or directory can be passed several times, e.g. with a
+        // different case or through a symbolic link
+        let identity = path_identity(&path, ctx.traversal_policy());
+        if (file_type.is_file() || file_type.is_dir())
+            && !ctx.interner().insert_identity(identity.clone())
+        {
+            return;
+        }
+
         if file_type.is_file() {
             self.scope.spawn(move |_| {
                 ctx.handle_file(&path);
@@ -137,7 +280,7 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
 
         if file_type.is_dir() {
             self.scope.spawn(move |scope| {
-                handle_dir(scope, ctx, &path, None);
+                handle_dir(scope, ctx, &path, None, &identity);
             });
             return;
         }
@@ -161,6 +304,8 @@ fn handle_dir<'scope>(
     path: &Path,
     // The unresolved origin path in case the directory is behind a symbolic link
     origin_path: Option<PathBuf>,
+    // The identity of the directory, see [path_identity]
+    identity: &Path,
 ) {
     if let Some(file_name) = path.file_name().and_then(OsStr::to_str) {
         if DEFAULT_IGNORE.contains(&file_name) {
@@ -178,7 +323,7 @@ fn handle_dir<'scope>(
 
     for entry in iter {
         match entry {
-            Ok(entry) => handle_dir_entry(scope, ctx, entry, origin_path.clone()),
+            Ok(entry) => handle_dir_entry(scope, ctx, entry, origin_path.clone(), identity),
             Err(err) => {
                 ctx.push_diagnostic(IoError::from(err).with_file_path(path.display().to_string()));
             }
@@ -194,8 +339,11 @@ fn handle_dir_entry<'scope>(
     entry: DirEntry,
     // The unresolved origin path in case the directory is behind a symbolic link
     mut origin_path: Option<PathBuf>,
+    // The identity of the parent directory, see [path_identity]
+    parent_identity: &Path,
 ) {
     let mut path = entry.path();
+    let policy = ctx.traversal_policy();
 
     let mut file_type = match entry.file_type() {
         Ok(file_type) => file_type,
@@ -207,7 +355,16 @@ fn handle_dir_entry<'scope>(
         }
     };
 
+    // The identity of an entry is derived from the identity of its directory,
+    // unless it's a symbolic link
+    let mut identity = None;
+
     if file_type.is_symlink() {
+        if !policy.follow_symlinks {
+            tracing::info!("Skipping symlink: {path:?}");
+            return;
+        }
+
         let Ok((target_path, target_file_type)) = expand_symbolic_link(path.clone(), ctx) else {
             return;
         };
@@ -217,10 +374,14 @@ fn handle_dir_entry<'scope>(
             origin_path = Some(path);
         }
 
+        identity = Some(path_identity(&target_path, policy));
         path = target_path;
         file_type = target_file_type;
     }
 
+    let identity =
+        identity.unwrap_or_else(|| fold_case(parent_identity.join(entry.file_name()), policy));
+
     let inserted = ctx.interner().intern_path(path.clone());
 
     if !inserted {
@@ -230,9 +391,13 @@ fn handle_dir_entry<'scope>(
     }
 
     if file_type.is_dir() {
-        if ctx.can_handle(&RomePath::new(path.clone())) {
+        // A directory that was already traversed can be reached again through
+        // a symbolic link, possibly one of its own descendants
+        if ctx.can_handle(&RomePath::new(path.clone()))
+            && ctx.interner().insert_identity(identity.clone())
+        {
             scope.spawn(move |scope| {
-                handle_dir(scope, ctx, &path, origin_path);
+                handle_dir(scope, ctx, &path, origin_path, &identity);
             });
         }
         return;
@@ -262,7 +427,7 @@ fn handle_dir_entry<'scope>(
         // doing a directory traversal, but printing an error message if the
         // user explicitly requests an unsupported file to be handled.
         // This check also works for symbolic links.
-        if !ctx.can_handle(&rome_path) {
+        if !ctx.can_handle(&rome_path) || !ctx.interner().insert_identity(identity) {
             return;
         }
 
@@ -279,6 +444,22 @@ fn handle_dir_entry<'scope>(
     }));
 }
 
+/// Computes the identity of a path: the paths that point at the same file or
+/// directory have the same identity, according to the [TraversalPolicy]
+fn path_identity(path: &Path, policy: TraversalPolicy) -> PathBuf {
+    let canonical_path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    fold_case(canonical_path, policy)
+}
+
+/// Lowercases the path when the file system is case-insensitive
+fn fold_case(path: PathBuf, policy: TraversalPolicy) -> PathBuf {
+    if policy.case_insensitive {
+        PathBuf::from(path.to_string_lossy().to_lowercase())
+    } else {
+        path
+    }
+}
+
 /// Indicates a symbolic link could not be expanded.
 ///
 /// Has no fields, since the diagnostics are already generated inside
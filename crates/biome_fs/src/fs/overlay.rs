@@ -0,0 +1,229 @@
+use rustc_hash::FxHashMap;
+use std::io;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::str;
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::fs::OpenOptions;
+use crate::FileSystem;
+
+use super::{BoxedTraversal, File};
+
+/// File system that serves the content of the documents opened in an editor,
+/// including their unsaved changes, on top of the file system it wraps.
+///
+/// The files that aren't open in the editor are read from the wrapped file
+/// system. This way, the operations that read other files of the workspace,
+/// like loading the configuration or indexing the project, see the same
+/// content as the user.
+pub struct OverlayFileSystem<F> {
+    base: F,
+    documents: AssertUnwindSafe<RwLock<FxHashMap<PathBuf, DocumentEntry>>>,
+}
+
+/// The content of a document, shared with the [DocumentFile] handles so they
+/// can outlive the borrow of the file system
+type DocumentEntry = Arc<Mutex<String>>;
+
+impl<F> OverlayFileSystem<F> {
+    pub fn new(base: F) -> Self {
+        Self {
+            base,
+            documents: Default::default(),
+        }
+    }
+
+    /// Sets the content of the document at `path`. It's served instead of the
+    /// content of the file in the wrapped file system, until the document is removed.
+    pub fn set_document(&self, path: PathBuf, content: String) {
+        self.documents
+            .0
+            .write()
+            .insert(path, Arc::new(Mutex::new(content)));
+    }
+
+    /// Removes the document at `path`, the content of the file is read from the
+    /// wrapped file system again
+    pub fn remove_document(&self, path: &Path) {
+        self.documents.0.write().remove(path);
+    }
+
+    /// Whether the content of the file at `path` is served from a document
+    pub fn has_document(&self, path: &Path) -> bool {
+        self.documents.0.read().contains_key(path)
+    }
+}
+
+impl<F> FileSystem for OverlayFileSystem<F>
+where
+    F: FileSystem,
+{
+    fn open_with_options(&self, path: &Path, options: OpenOptions) -> io::Result<Box<dyn File>> {
+        let document = self.documents.0.read().get(path).cloned();
+        let Some(document) = document else {
+            return self.base.open_with_options(path, options);
+        };
+
+        if options.create_new {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("path {path:?} is already open in the editor"),
+            ));
+        }
+
+        // The changes are applied to the document, the editor is responsible
+        // for saving it
+        if options.create || options.truncate {
+            document.lock().clear();
+        }
+
+        Ok(Box::new(DocumentFile {
+            content: document,
+            can_read: options.read,
+            can_write: options.write,
+            version: 0,
+        }))
+    }
+
+    fn traversal<'scope>(&'scope self, func: BoxedTraversal<'_, 'scope>) {
+        self.base.traversal(func)
+    }
+
+    fn deprecated_config_name(&self) -> &str {
+        self.base.deprecated_config_name()
+    }
+
+    fn config_name(&self) -> &str {
+        self.base.config_name()
+    }
+
+    fn working_directory(&self) -> Option<PathBuf> {
+        self.base.working_directory()
+    }
+
+    fn path_exists(&self, path: &Path) -> bool {
+        self.has_document(path) || self.base.path_exists(path)
+    }
+
+    fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
+        self.base.get_changed_files(base)
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        self.base.get_staged_files()
+    }
+
+    fn get_unstaged_files(&self) -> io::Result<Vec<String>> {
+        self.base.get_unstaged_files()
+    }
+
+    fn read_staged_content(&self, path: &str) -> io::Result<String> {
+        self.base.read_staged_content(path)
+    }
+
+    fn stage_content(&self, path: &str, content: &str) -> io::Result<()> {
+        self.base.stage_content(path, content)
+    }
+
+    fn stage_files(&self, paths: &[String]) -> io::Result<()> {
+        self.base.stage_files(paths)
+    }
+}
+
+struct DocumentFile {
+    content: DocumentEntry,
+    can_read: bool,
+    can_write: bool,
+    version: i32,
+}
+
+impl File for DocumentFile {
+    fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()> {
+        if !self.can_read {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "this file wasn't open with read access",
+            ));
+        }
+
+        buffer.push_str(&self.content.lock());
+        Ok(())
+    }
+
+    fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
+        if !self.can_write {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "this file wasn't open with write access",
+            ));
+        }
+
+        let content = str::from_utf8(content)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        *self.content.lock() = content.to_string();
+        self.version += 1;
+        Ok(())
+    }
+
+    fn file_version(&self) -> i32 {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{FileSystem, FileSystemExt, MemoryFileSystem, OverlayFileSystem};
+
+    fn read(fs: &dyn FileSystem, path: &Path) -> String {
+        let mut content = String::new();
+        fs.open(path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .expect("the file should be read without error");
+        content
+    }
+
+    #[test]
+    fn serves_the_content_of_the_documents() {
+        let mut base = MemoryFileSystem::default();
+        base.insert(PathBuf::from("saved.js"), "saved");
+        base.insert(PathBuf::from("open.js"), "saved");
+        let fs = OverlayFileSystem::new(base);
+
+        fs.set_document(PathBuf::from("open.js"), String::from("unsaved"));
+        fs.set_document(PathBuf::from("untitled.js"), String::from("untitled"));
+
+        assert_eq!(read(&fs, Path::new("saved.js")), "saved");
+        assert_eq!(read(&fs, Path::new("open.js")), "unsaved");
+        assert_eq!(read(&fs, Path::new("untitled.js")), "untitled");
+        assert!(fs.path_exists(Path::new("untitled.js")));
+
+        fs.remove_document(Path::new("open.js"));
+        fs.remove_document(Path::new("untitled.js"));
+
+        assert_eq!(read(&fs, Path::new("open.js")), "saved");
+        assert!(!fs.path_exists(Path::new("untitled.js")));
+    }
+
+    #[test]
+    fn writes_to_the_documents() {
+        let mut base = MemoryFileSystem::default();
+        base.insert(PathBuf::from("open.js"), "saved");
+        let fs = OverlayFileSystem::new(base);
+        fs.set_document(PathBuf::from("open.js"), String::from("unsaved"));
+
+        fs.create(Path::new("open.js"))
+            .and_then(|mut file| file.set_content(b"fixed"))
+            .expect("the file should be written without error");
+
+        assert_eq!(read(&fs, Path::new("open.js")), "fixed");
+        assert!(fs.create_new(Path::new("open.js")).is_err());
+
+        fs.remove_document(Path::new("open.js"));
+        assert_eq!(read(&fs, Path::new("open.js")), "saved");
+    }
+}
@@ -1,5 +1,6 @@
 use crossbeam::channel::{unbounded, Receiver, Sender};
 use indexmap::IndexSet;
+use rustc_hash::FxHashSet;
 use std::path::PathBuf;
 use std::sync::RwLock;
 
@@ -8,6 +9,9 @@ use std::sync::RwLock;
 /// The path interner stores an instance of [PathBuf]
 pub struct PathInterner {
     storage: RwLock<IndexSet<PathBuf>>,
+    /// The identities of the files and the directories already traversed,
+    /// see [TraversalPolicy](crate::TraversalPolicy)
+    identities: RwLock<FxHashSet<PathBuf>>,
     handler: Sender<PathBuf>,
 }
 
@@ -16,6 +20,7 @@ impl PathInterner {
         let (send, recv) = unbounded();
         let interner = Self {
             storage: RwLock::new(IndexSet::new()),
+            identities: RwLock::default(),
             handler: send,
         };
 
@@ -30,4 +35,12 @@ impl PathInterner {
         }
         result
     }
+
+    /// Records the identity of a file or a directory, computed according to
+    /// the [TraversalPolicy](crate::TraversalPolicy). It returns `false` if the
+    /// identity was already recorded, e.g. when the file was reached through a
+    /// symbolic link.
+    pub fn insert_identity(&self, identity: PathBuf) -> bool {
+        self.identities.write().unwrap().insert(identity)
+    }
 }
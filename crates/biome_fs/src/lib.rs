@@ -4,8 +4,8 @@ mod path;
 
 pub use fs::{
     AutoSearchResult, ErrorEntry, File, FileSystem, FileSystemDiagnostic, FileSystemExt,
-    MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext, TraversalScope, BIOME_JSON,
-    ROME_JSON,
+    MemoryFileSystem, OpenOptions, OsFileSystem, OverlayFileSystem, TraversalContext,
+    TraversalScope, BIOME_JSON, BIOME_JSONC, ROME_JSON,
 };
 pub use interner::PathInterner;
 pub use path::RomePath;
@@ -255,7 +255,7 @@ impl Visitor for AnyThisScopeVisitor {
 }
 
 /// Get a minimal arrow function body from a regular function body.
-fn to_arrow_body(body: JsFunctionBody) -> AnyJsFunctionBody {
+pub(crate) fn to_arrow_body(body: JsFunctionBody) -> AnyJsFunctionBody {
     let body_statements = body.statements();
     // () => { ... }
     let early_result = AnyJsFunctionBody::from(body);
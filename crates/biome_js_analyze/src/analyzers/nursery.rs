@@ -2,29 +2,63 @@
 
 use biome_analyze::declare_group;
 
+pub(crate) mod no_barrel_file;
+pub(crate) mod no_conditional_expect;
 pub(crate) mod no_default_export;
+pub(crate) mod no_done_callback;
 pub(crate) mod no_empty_block_statements;
+pub(crate) mod no_excessive_file_length;
+pub(crate) mod no_excessive_params;
+pub(crate) mod no_excessively_long_functions;
+pub(crate) mod no_identical_test_titles;
 pub(crate) mod no_implicit_any_let;
+pub(crate) mod no_jsx_literal_text;
+pub(crate) mod no_re_export_all;
+pub(crate) mod no_script_url;
+pub(crate) mod no_undeclared_dependencies;
+pub(crate) mod no_unsafe_inner_html;
 pub(crate) mod no_unused_private_class_members;
 pub(crate) mod no_useless_lone_block_statements;
 pub(crate) mod use_await;
+pub(crate) mod use_consistent_array_type;
+pub(crate) mod use_consistent_record_type;
 pub(crate) mod use_grouped_type_import;
 pub(crate) mod use_import_restrictions;
+pub(crate) mod use_jsdoc_param_match;
 pub(crate) mod use_regex_literals;
+pub(crate) mod use_top_level_describe;
+pub(crate) mod use_valid_jsdoc;
 
 declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_barrel_file :: NoBarrelFile ,
+            self :: no_conditional_expect :: NoConditionalExpect ,
             self :: no_default_export :: NoDefaultExport ,
+            self :: no_done_callback :: NoDoneCallback ,
             self :: no_empty_block_statements :: NoEmptyBlockStatements ,
+            self :: no_excessive_file_length :: NoExcessiveFileLength ,
+            self :: no_excessive_params :: NoExcessiveParams ,
+            self :: no_excessively_long_functions :: NoExcessivelyLongFunctions ,
+            self :: no_identical_test_titles :: NoIdenticalTestTitles ,
             self :: no_implicit_any_let :: NoImplicitAnyLet ,
+            self :: no_jsx_literal_text :: NoJsxLiteralText ,
+            self :: no_re_export_all :: NoReExportAll ,
+            self :: no_script_url :: NoScriptUrl ,
+            self :: no_undeclared_dependencies :: NoUndeclaredDependencies ,
+            self :: no_unsafe_inner_html :: NoUnsafeInnerHtml ,
             self :: no_unused_private_class_members :: NoUnusedPrivateClassMembers ,
             self :: no_useless_lone_block_statements :: NoUselessLoneBlockStatements ,
             self :: use_await :: UseAwait ,
+            self :: use_consistent_array_type :: UseConsistentArrayType ,
+            self :: use_consistent_record_type :: UseConsistentRecordType ,
             self :: use_grouped_type_import :: UseGroupedTypeImport ,
             self :: use_import_restrictions :: UseImportRestrictions ,
+            self :: use_jsdoc_param_match :: UseJsdocParamMatch ,
             self :: use_regex_literals :: UseRegexLiterals ,
+            self :: use_top_level_describe :: UseTopLevelDescribe ,
+            self :: use_valid_jsdoc :: UseValidJsdoc ,
         ]
      }
 }
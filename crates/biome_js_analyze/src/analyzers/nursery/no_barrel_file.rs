@@ -0,0 +1,98 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsExportClause, AnyJsModuleItem, JsExport, JsModule};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow barrel files.
+    ///
+    /// A barrel file is a module that only re-exports the bindings of other modules, e.g. an
+    /// `index.js` that gathers the components of a directory. Importing a single binding from a
+    /// barrel file loads all the modules that it re-exports, and all the modules that they import.
+    /// Bundlers can't always remove the modules that aren't used, and the tools that don't bundle,
+    /// such as test runners, load all of them.
+    ///
+    /// The type-only re-exports, e.g. `export type { Props } from "./button"`, are erased at
+    /// compile time: a module that only re-exports types isn't a barrel file.
+    ///
+    /// Source: https://github.com/thepassle/eslint-plugin-barrel-files/blob/main/docs/rules/avoid-barrel-files.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// export * from "./button";
+    /// export { Input } from "./input";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// export { Button } from "./button";
+    /// export const Input = () => {};
+    /// ```
+    ///
+    /// ```ts
+    /// export type * from "./button";
+    /// export type { InputProps } from "./input";
+    /// ```
+    ///
+    pub(crate) NoBarrelFile {
+        version: "next",
+        name: "noBarrelFile",
+        recommended: false,
+    }
+}
+
+impl Rule for NoBarrelFile {
+    type Query = Ast<JsModule>;
+    /// The first re-export of a value
+    type State = JsExport;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut first_value_reexport = None;
+        for item in ctx.query().items() {
+            let AnyJsModuleItem::JsExport(export) = item else {
+                return None;
+            };
+            let is_type_only = match export.export_clause().ok()? {
+                AnyJsExportClause::JsExportFromClause(clause) => clause.type_token().is_some(),
+                AnyJsExportClause::JsExportNamedFromClause(clause) => {
+                    // `export {} from "./mod"` runs the module
+                    let specifiers = clause.specifiers();
+                    clause.type_token().is_some()
+                        || (!specifiers.is_empty()
+                            && specifiers.iter().all(|specifier| {
+                                specifier.is_ok_and(|specifier| specifier.type_token().is_some())
+                            }))
+                }
+                _ => return None,
+            };
+            if !is_type_only && first_value_reexport.is_none() {
+                first_value_reexport = Some(export);
+            }
+        }
+        first_value_reexport
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, export: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                export.range(),
+                markup! {
+                    "This module is a barrel file: it only re-exports the bindings of other modules."
+                },
+            )
+            .note(markup! {
+                "Importing any binding from this module loads all the modules that it re-exports, and all the modules that they import."
+            })
+            .note(markup! {
+                "Import the bindings from the modules that declare them, and remove this module."
+            }),
+        )
+    }
+}
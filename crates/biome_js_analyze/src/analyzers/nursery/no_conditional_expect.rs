@@ -0,0 +1,161 @@
+use crate::utils::test_frameworks::{test_call_of_callback, TestCallKind, TestFile};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsFunction, JsCallExpression, JsCatchClause, JsConditionalExpression,
+    JsIfStatement, JsLogicalExpression, JsSwitchStatement, JsSyntaxNode, TextRange,
+};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow calling `expect` conditionally in the tests.
+    ///
+    /// An assertion in a branch of an `if`, of a `switch`, of a conditional or a logical
+    /// expression, or in a `catch` clause, only runs when the branch runs. When it doesn't, the
+    /// test passes without checking anything.
+    ///
+    /// Assert the condition instead, and use `expect().rejects` or `expect().toThrow()` to check
+    /// the errors.
+    ///
+    /// The calls of `expect` are checked in the callbacks of the tests, including the functions
+    /// nested in them. A helper declared outside of the tests can still assert conditionally.
+    ///
+    /// Source: https://github.com/jest-community/eslint-plugin-jest/blob/main/docs/rules/no-conditional-expect.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { expect, test } from "vitest";
+    ///
+    /// test("parses the user", () => {
+    ///     const user = parse(input);
+    ///     if (user) {
+    ///         expect(user.name).toBe("Ada");
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { expect, test } from "vitest";
+    ///
+    /// test("rejects the invalid input", async () => {
+    ///     try {
+    ///         await parse("{");
+    ///     } catch (error) {
+    ///         expect(error.message).toMatch("Unexpected");
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { expect, test } from "vitest";
+    ///
+    /// test("parses the user", () => {
+    ///     const user = parse(input);
+    ///     expect(user?.name).toBe("Ada");
+    /// });
+    ///
+    /// test("rejects the invalid input", async () => {
+    ///     await expect(parse("{")).rejects.toThrow("Unexpected");
+    /// });
+    /// ```
+    ///
+    pub(crate) NoConditionalExpect {
+        version: "next",
+        name: "noConditionalExpect",
+        recommended: false,
+    }
+}
+
+impl Rule for NoConditionalExpect {
+    type Query = Ast<JsCallExpression>;
+    /// The range of the condition that the call of `expect` depends on
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.get_service::<TestFile>()?.framework()?;
+        let call = ctx.query();
+        let callee = call.callee().ok()?.omit_parentheses();
+        if !callee.as_js_reference_identifier()?.has_name("expect") {
+            return None;
+        }
+        let mut condition = None;
+        let mut node = call.syntax().clone();
+        while let Some(parent) = node.parent() {
+            if condition.is_none() {
+                condition = condition_range(&parent, &node);
+            }
+            if AnyJsFunction::can_cast(parent.kind()) {
+                if let Some((_, TestCallKind::Test)) = test_call_of_callback(&parent) {
+                    return condition;
+                }
+            }
+            node = parent;
+        }
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, condition: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Avoid calling "<Emphasis>"expect"</Emphasis>" conditionally."
+                },
+            )
+            .detail(
+                condition,
+                markup! {
+                    "The assertion runs only when this branch runs:"
+                },
+            )
+            .note(markup! {
+                "The test passes without checking anything when the branch doesn't run. Assert the condition instead, or use "<Emphasis>"expect().rejects"</Emphasis>" to check the errors."
+            }),
+        )
+    }
+}
+
+/// Returns the range of the condition of `parent`, when `child` is one of its branches, e.g. the
+/// test of an `if` for its consequent
+fn condition_range(parent: &JsSyntaxNode, child: &JsSyntaxNode) -> Option<TextRange> {
+    let test = if let Some(statement) = JsIfStatement::cast_ref(parent) {
+        statement.test().ok()?
+    } else if let Some(expression) = JsConditionalExpression::cast_ref(parent) {
+        expression.test().ok()?
+    } else if let Some(expression) = JsLogicalExpression::cast_ref(parent) {
+        expression.left().ok()?
+    } else if let Some(statement) = JsSwitchStatement::cast_ref(parent) {
+        statement.discriminant().ok()?
+    } else if let Some(clause) = JsCatchClause::cast_ref(parent) {
+        return Some(clause.catch_token().ok()?.text_trimmed_range());
+    } else if AnyJsFunction::can_cast(parent.kind()) {
+        return catch_callback_range(parent);
+    } else {
+        return None;
+    };
+    (test.syntax() != child).then(|| test.range())
+}
+
+/// Returns the range of `catch` when `function` is the callback of `promise.catch()`
+fn catch_callback_range(function: &JsSyntaxNode) -> Option<TextRange> {
+    // The callback is an argument of the call
+    let call = function
+        .parent()?
+        .parent()?
+        .parent()
+        .and_then(JsCallExpression::cast)?;
+    let AnyJsExpression::JsStaticMemberExpression(callee) = call.callee().ok()?.omit_parentheses()
+    else {
+        return None;
+    };
+    let member = callee.member().ok()?;
+    (member.text() == "catch").then(|| member.range())
+}
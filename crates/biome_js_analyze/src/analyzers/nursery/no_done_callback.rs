@@ -0,0 +1,165 @@
+use crate::utils::test_frameworks::{TestCallKind, TestFile, TestFramework};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument,
+    AnyJsExpression, AnyJsFormalParameter, AnyJsParameter, JsCallExpression, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow the `done` callback of the tests and the hooks.
+    ///
+    /// Jest passes a `done` callback to the tests and the hooks that declare a parameter, and
+    /// waits until it's called. When `done` isn't called, e.g. because an assertion threw before
+    /// it, the test fails with a timeout instead of the error. Return a promise, or use an async
+    /// function, instead.
+    ///
+    /// Vitest doesn't support the `done` callback: the first parameter of the tests and the hooks
+    /// is the context of the test. In the files that import `vitest`, the rule reports the
+    /// parameters named `done`.
+    ///
+    /// The first parameter of the callbacks of `test`, `it` and the hooks, e.g. `beforeEach`, is
+    /// checked. The tables of `test.each` aren't: their callbacks receive the rows of the table.
+    ///
+    /// Source: https://github.com/jest-community/eslint-plugin-jest/blob/main/docs/rules/no-done-callback.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { test } from "@jest/globals";
+    ///
+    /// test("loads the user", (done) => {
+    ///     load("ada").then((user) => {
+    ///         expect(user.name).toBe("Ada");
+    ///         done();
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { test } from "@jest/globals";
+    ///
+    /// test("loads the user", async () => {
+    ///     const user = await load("ada");
+    ///     expect(user.name).toBe("Ada");
+    /// });
+    /// ```
+    ///
+    /// ```js
+    /// import { test } from "vitest";
+    ///
+    /// test("loads the user", async ({ expect }) => {
+    ///     expect(await load("ada")).toBeDefined();
+    /// });
+    /// ```
+    ///
+    pub(crate) NoDoneCallback {
+        version: "next",
+        name: "noDoneCallback",
+        recommended: false,
+    }
+}
+
+pub(crate) struct DoneCallback {
+    /// The range of the parameter
+    range: TextRange,
+    framework: TestFramework,
+}
+
+impl Rule for NoDoneCallback {
+    type Query = Ast<JsCallExpression>;
+    type State = DoneCallback;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let framework = ctx.get_service::<TestFile>()?.framework()?;
+        let call = ctx.query();
+        if !matches!(
+            TestCallKind::of_call(call)?,
+            TestCallKind::Test | TestCallKind::Hook
+        ) {
+            return None;
+        }
+        let callback = call
+            .arguments()
+            .ok()?
+            .args()
+            .iter()
+            .flatten()
+            .find_map(|argument| match argument {
+                AnyJsCallArgument::AnyJsExpression(
+                    expression @ (AnyJsExpression::JsArrowFunctionExpression(_)
+                    | AnyJsExpression::JsFunctionExpression(_)),
+                ) => Some(expression),
+                _ => None,
+            })?;
+        let (range, name) = first_parameter(&callback)?;
+        match framework {
+            TestFramework::Jest => Some(DoneCallback { range, framework }),
+            TestFramework::Vitest => {
+                (name.as_deref() == Some("done")).then_some(DoneCallback { range, framework })
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                "Avoid the "<Emphasis>"done"</Emphasis>" callback."
+            },
+        );
+        let diagnostic = match state.framework {
+            TestFramework::Jest => diagnostic.note(markup! {
+                "When "<Emphasis>"done"</Emphasis>" isn't called, e.g. because an assertion threw before it, the test fails with a timeout instead of the error."
+            }),
+            TestFramework::Vitest => diagnostic.note(markup! {
+                "Vitest doesn't pass a "<Emphasis>"done"</Emphasis>" callback: the parameter is the context of the test."
+            }),
+        };
+        Some(diagnostic.note(markup! {
+            "Return a promise, or use an async function, instead."
+        }))
+    }
+}
+
+/// Returns the range of the first parameter of the function, and its name when it's an
+/// identifier
+fn first_parameter(function: &AnyJsExpression) -> Option<(TextRange, Option<String>)> {
+    let parameter = match function {
+        AnyJsExpression::JsArrowFunctionExpression(function) => match function.parameters().ok()? {
+            AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+                return Some((binding.range(), identifier_name(&binding)));
+            }
+            AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+                parameters.items().first()?.ok()?
+            }
+        },
+        AnyJsExpression::JsFunctionExpression(function) => {
+            function.parameters().ok()?.items().first()?.ok()?
+        }
+        _ => return None,
+    };
+    let name = match &parameter {
+        AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+            parameter,
+        )) => match parameter.binding().ok()? {
+            AnyJsBindingPattern::AnyJsBinding(binding) => identifier_name(&binding),
+            _ => None,
+        },
+        _ => None,
+    };
+    Some((parameter.range(), name))
+}
+
+fn identifier_name(binding: &AnyJsBinding) -> Option<String> {
+    let identifier = binding.as_js_identifier_binding()?;
+    Some(identifier.name_token().ok()?.text_trimmed().to_string())
+}
@@ -0,0 +1,204 @@
+use crate::utils::line_count::{counted_lines, LineCountOptions};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_js_syntax::{AnyJsRoot, TextRange};
+use biome_rowan::AstNode;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::{num::NonZeroU16, str::FromStr};
+
+declare_rule! {
+    /// Disallow the files that have more lines than a given maximum.
+    ///
+    /// Long files are hard to navigate, and usually contain several modules that can be split
+    /// into their own files.
+    ///
+    /// The blank lines and the comments can be skipped, so that documenting the code doesn't
+    /// make a file exceed the maximum. The rule reports the first line that exceeds the maximum.
+    ///
+    /// The rule isn't recommended: it's meant to find the files to refactor, and is usually
+    /// enabled with the `warn` level.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/max-lines
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxLines": 300,
+    ///         "skipBlankLines": true,
+    ///         "skipComments": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### maxLines
+    ///
+    /// The maximum number of lines of a file. The default is 300.
+    ///
+    /// ### skipBlankLines
+    ///
+    /// Don't count the lines that only contain whitespace. The default is `false`.
+    ///
+    /// ### skipComments
+    ///
+    /// Don't count the lines that only contain comments. The default is `false`.
+    ///
+    pub(crate) NoExcessiveFileLength {
+        version: "next",
+        name: "noExcessiveFileLength",
+        recommended: false,
+    }
+}
+
+pub(crate) struct ExcessiveFileLength {
+    /// The number of counted lines
+    lines: usize,
+    /// The range of the first line that exceeds the maximum
+    first_excessive_line: TextRange,
+}
+
+impl Rule for NoExcessiveFileLength {
+    type Query = Ast<AnyJsRoot>;
+    type State = ExcessiveFileLength;
+    type Signals = Option<Self::State>;
+    type Options = NoExcessiveFileLengthOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let options = ctx.options();
+        let lines = counted_lines(
+            root.syntax(),
+            root.syntax().text_range(),
+            options.line_count_options(),
+        );
+        let first_excessive_line = *lines.get(usize::from(options.max_lines.get()))?;
+        Some(ExcessiveFileLength {
+            lines: lines.len(),
+            first_excessive_line,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let max_lines = ctx.options().max_lines;
+        let lines = state.lines;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.first_excessive_line,
+                markup! {
+                    "This file has "{lines}" lines, which exceeds the maximum of "{max_lines.get()}"."
+                },
+            )
+            .note(markup! {
+                "Split the file into smaller modules."
+            }),
+        )
+    }
+}
+
+/// Options for the rule `noExcessiveFileLength`.
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoExcessiveFileLengthOptions {
+    /// The maximum number of lines of a file.
+    #[bpaf(hide)]
+    pub max_lines: NonZeroU16,
+    /// Don't count the lines that only contain whitespace.
+    #[bpaf(hide)]
+    pub skip_blank_lines: bool,
+    /// Don't count the lines that only contain comments.
+    #[bpaf(hide)]
+    pub skip_comments: bool,
+}
+
+impl NoExcessiveFileLengthOptions {
+    fn line_count_options(&self) -> LineCountOptions {
+        LineCountOptions {
+            skip_blank_lines: self.skip_blank_lines,
+            skip_comments: self.skip_comments,
+        }
+    }
+}
+
+impl Default for NoExcessiveFileLengthOptions {
+    fn default() -> Self {
+        Self {
+            max_lines: NonZeroU16::new(300).unwrap(),
+            skip_blank_lines: false,
+            skip_comments: false,
+        }
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for NoExcessiveFileLengthOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl Deserializable for NoExcessiveFileLengthOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(NoExcessiveFileLengthOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct NoExcessiveFileLengthOptionsVisitor;
+impl DeserializationVisitor for NoExcessiveFileLengthOptionsVisitor {
+    type Output = NoExcessiveFileLengthOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["maxLines", "skipBlankLines", "skipComments"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "maxLines" => {
+                    if let Some(val) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                        result.max_lines = val;
+                    }
+                }
+                "skipBlankLines" => {
+                    if let Some(val) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                        result.skip_blank_lines = val;
+                    }
+                }
+                "skipComments" => {
+                    if let Some(val) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                        result.skip_comments = val;
+                    }
+                }
+                text => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    text,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
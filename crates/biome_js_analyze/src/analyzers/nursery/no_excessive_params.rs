@@ -0,0 +1,182 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_js_syntax::parameter_ext::{AnyJsParameterList, AnyJsParameters, AnyParameter};
+use biome_js_syntax::{AnyJsParameter, TextRange};
+use biome_rowan::AstNode;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::{num::NonZeroU8, str::FromStr};
+
+declare_rule! {
+    /// Disallow the functions that have more parameters than a given maximum.
+    ///
+    /// The functions that have many parameters are hard to call: the arguments must be passed
+    /// in the right order, and the calls don't tell which argument is which. Many parameters
+    /// also hint that the function does several things.
+    ///
+    /// Group the related parameters in an object, whose properties are named at the call
+    /// sites, or split the function. The `this` parameter of TypeScript isn't counted.
+    ///
+    /// The rule isn't recommended: it's meant to find the functions to refactor, and is usually
+    /// enabled with the `warn` level.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/max-params
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function createUser(name, email, role, team) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function createUser({ name, email, role, team }) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxParameters": 3
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### maxParameters
+    ///
+    /// The maximum number of parameters of a function. The default is 3.
+    ///
+    pub(crate) NoExcessiveParams {
+        version: "next",
+        name: "noExcessiveParams",
+        recommended: false,
+    }
+}
+
+impl Rule for NoExcessiveParams {
+    type Query = Ast<AnyJsParameters>;
+    /// The number of parameters
+    type State = usize;
+    type Signals = Option<Self::State>;
+    type Options = NoExcessiveParamsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let parameters = match ctx.query() {
+            AnyJsParameters::JsParameters(parameters) => {
+                AnyJsParameterList::from(parameters.items())
+            }
+            AnyJsParameters::JsConstructorParameters(parameters) => {
+                AnyJsParameterList::from(parameters.parameters())
+            }
+        };
+        let count = parameters
+            .iter()
+            .flatten()
+            .filter(|parameter| {
+                !matches!(
+                    parameter,
+                    AnyParameter::AnyJsParameter(AnyJsParameter::TsThisParameter(_))
+                )
+            })
+            .count();
+        (count > usize::from(ctx.options().max_parameters.get())).then_some(count)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, count: &Self::State) -> Option<RuleDiagnostic> {
+        let max_parameters = ctx.options().max_parameters;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This function has "{count}" parameters, which exceeds the maximum of "{max_parameters.get()}"."
+                },
+            )
+            .note(markup! {
+                "Group the related parameters in an object, or split the function."
+            }),
+        )
+    }
+}
+
+/// Options for the rule `noExcessiveParams`.
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoExcessiveParamsOptions {
+    /// The maximum number of parameters of a function.
+    #[bpaf(hide)]
+    pub max_parameters: NonZeroU8,
+}
+
+impl Default for NoExcessiveParamsOptions {
+    fn default() -> Self {
+        Self {
+            max_parameters: NonZeroU8::new(3).unwrap(),
+        }
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for NoExcessiveParamsOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl Deserializable for NoExcessiveParamsOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(NoExcessiveParamsOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct NoExcessiveParamsOptionsVisitor;
+impl DeserializationVisitor for NoExcessiveParamsOptionsVisitor {
+    type Output = NoExcessiveParamsOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["maxParameters"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "maxParameters" => {
+                    if let Some(val) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                        result.max_parameters = val;
+                    }
+                }
+                text => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    text,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
@@ -0,0 +1,220 @@
+use crate::utils::line_count::{counted_lines, LineCountOptions};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_js_syntax::{AnyFunctionLike, TextRange};
+use biome_rowan::AstNode;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::{num::NonZeroU16, str::FromStr};
+
+declare_rule! {
+    /// Disallow the functions that have more lines than a given maximum.
+    ///
+    /// Long functions are hard to read, to test and to reuse. They usually do several things,
+    /// which can be split into smaller functions that each have a name.
+    ///
+    /// The lines are counted from the first line of the function to its last line. The blank
+    /// lines and the comments can be skipped, so that documenting a function doesn't make it
+    /// exceed the maximum.
+    ///
+    /// The rule isn't recommended: it's meant to find the functions to refactor, and is usually
+    /// enabled with the `warn` level.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/max-lines-per-function
+    ///
+    /// ## Examples
+    ///
+    /// With the option `"maxLines": 3`, this function is reported, because it has four lines:
+    ///
+    /// ```js
+    /// function greet(user) {
+    ///     const name = user.name;
+    ///     console.log(`Hello ${name}`);
+    /// }
+    /// ```
+    ///
+    /// This function has three lines:
+    ///
+    /// ```js
+    /// function greet(user) {
+    ///     console.log(`Hello ${user.name}`);
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxLines": 50,
+    ///         "skipBlankLines": true,
+    ///         "skipComments": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### maxLines
+    ///
+    /// The maximum number of lines of a function. The default is 50.
+    ///
+    /// ### skipBlankLines
+    ///
+    /// Don't count the lines that only contain whitespace. The default is `false`.
+    ///
+    /// ### skipComments
+    ///
+    /// Don't count the lines that only contain comments. The default is `false`.
+    ///
+    pub(crate) NoExcessivelyLongFunctions {
+        version: "next",
+        name: "noExcessivelyLongFunctions",
+        recommended: false,
+    }
+}
+
+impl Rule for NoExcessivelyLongFunctions {
+    type Query = Ast<AnyFunctionLike>;
+    /// The number of counted lines
+    type State = usize;
+    type Signals = Option<Self::State>;
+    type Options = NoExcessivelyLongFunctionsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let options = ctx.options();
+        let lines = counted_lines(
+            function.syntax(),
+            function.syntax().text_trimmed_range(),
+            options.line_count_options(),
+        )
+        .len();
+        (lines > usize::from(options.max_lines.get())).then_some(lines)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, lines: &Self::State) -> Option<RuleDiagnostic> {
+        let function = ctx.query();
+        let max_lines = ctx.options().max_lines;
+        let range = function
+            .name_range()
+            .or_else(|| function.function_token().map(|token| token.text_range()))
+            .or_else(|| function.fat_arrow_token().map(|token| token.text_range()))
+            .unwrap_or_else(|| function.range());
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This function has "{lines}" lines, which exceeds the maximum of "{max_lines.get()}"."
+                },
+            )
+            .note(markup! {
+                "Split the function into smaller functions that each do one thing."
+            }),
+        )
+    }
+}
+
+/// Options for the rule `noExcessivelyLongFunctions`.
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoExcessivelyLongFunctionsOptions {
+    /// The maximum number of lines of a function.
+    #[bpaf(hide)]
+    pub max_lines: NonZeroU16,
+    /// Don't count the lines that only contain whitespace.
+    #[bpaf(hide)]
+    pub skip_blank_lines: bool,
+    /// Don't count the lines that only contain comments.
+    #[bpaf(hide)]
+    pub skip_comments: bool,
+}
+
+impl NoExcessivelyLongFunctionsOptions {
+    fn line_count_options(&self) -> LineCountOptions {
+        LineCountOptions {
+            skip_blank_lines: self.skip_blank_lines,
+            skip_comments: self.skip_comments,
+        }
+    }
+}
+
+impl Default for NoExcessivelyLongFunctionsOptions {
+    fn default() -> Self {
+        Self {
+            max_lines: NonZeroU16::new(50).unwrap(),
+            skip_blank_lines: false,
+            skip_comments: false,
+        }
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for NoExcessivelyLongFunctionsOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl Deserializable for NoExcessivelyLongFunctionsOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(NoExcessivelyLongFunctionsOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct NoExcessivelyLongFunctionsOptionsVisitor;
+impl DeserializationVisitor for NoExcessivelyLongFunctionsOptionsVisitor {
+    type Output = NoExcessivelyLongFunctionsOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["maxLines", "skipBlankLines", "skipComments"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "maxLines" => {
+                    if let Some(val) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                        result.max_lines = val;
+                    }
+                }
+                "skipBlankLines" => {
+                    if let Some(val) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                        result.skip_blank_lines = val;
+                    }
+                }
+                "skipComments" => {
+                    if let Some(val) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                        result.skip_comments = val;
+                    }
+                }
+                text => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    text,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::utils::test_frameworks::{TestCallKind, TestFile};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsCallArgument, JsCallExpression, JsExpressionStatement, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList, Direction};
+
+declare_rule! {
+    /// Disallow the tests and the `describe` blocks that have the title of a sibling.
+    ///
+    /// The reports of the test frameworks identify the tests by their titles, and the titles of
+    /// their `describe` blocks. When two tests of the same block have the same title, a failure
+    /// can't be attributed to one of them, and the snapshots of the tests overwrite each other.
+    ///
+    /// The titles are compared only when they are literals, and only between the siblings of a
+    /// block: two tests of different `describe` blocks can have the same title.
+    ///
+    /// Source: https://github.com/jest-community/eslint-plugin-jest/blob/main/docs/rules/no-identical-title.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { describe, test } from "vitest";
+    ///
+    /// describe("parse", () => {
+    ///     test("reads the numbers", () => {});
+    ///     test("reads the numbers", () => {});
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { describe, test } from "vitest";
+    ///
+    /// describe("parse", () => {
+    ///     test("reads the numbers", () => {});
+    ///     test("reads the strings", () => {});
+    /// });
+    ///
+    /// describe("format", () => {
+    ///     test("reads the numbers", () => {});
+    /// });
+    /// ```
+    ///
+    pub(crate) NoIdenticalTestTitles {
+        version: "next",
+        name: "noIdenticalTestTitles",
+        recommended: false,
+    }
+}
+
+pub(crate) struct DuplicateTitle {
+    kind: TestCallKind,
+    /// The range of the title
+    range: TextRange,
+    /// The range of the title of the first sibling that has it
+    first_range: TextRange,
+}
+
+impl Rule for NoIdenticalTestTitles {
+    type Query = Ast<JsCallExpression>;
+    type State = DuplicateTitle;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.get_service::<TestFile>()?.framework()?;
+        let call = ctx.query();
+        let kind = TestCallKind::of_call(call)?;
+        if kind == TestCallKind::Hook {
+            return None;
+        }
+        let (title, range) = static_title(call)?;
+        let statement = call.parent::<JsExpressionStatement>()?;
+        // The siblings are the previous statements of the same block, or of the module
+        statement
+            .syntax()
+            .siblings(Direction::Prev)
+            .skip(1)
+            .filter_map(JsExpressionStatement::cast)
+            .filter_map(|sibling| {
+                let sibling = sibling.expression().ok()?;
+                JsCallExpression::cast(sibling.into_syntax())
+            })
+            .filter(|sibling| TestCallKind::of_call(sibling) == Some(kind))
+            .filter_map(|sibling| static_title(&sibling))
+            .filter(|(sibling_title, _)| *sibling_title == title)
+            .last()
+            .map(|(_, first_range)| DuplicateTitle {
+                kind,
+                range,
+                first_range,
+            })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (subject, first) = match state.kind {
+            TestCallKind::Describe => ("describe block", "The first describe block"),
+            _ => ("test", "The first test"),
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This "{subject}" has the title of a previous "{subject}" of the same block."
+                },
+            )
+            .detail(
+                state.first_range,
+                markup! {
+                    {first}" with this title is here:"
+                },
+            )
+            .note(markup! {
+                "Give a distinct title to each "{subject}", so that its failures can be told apart in the reports."
+            }),
+        )
+    }
+}
+
+/// Returns the title of a call of the test frameworks, when it's a literal, and its range
+fn static_title(call: &JsCallExpression) -> Option<(String, TextRange)> {
+    let first = call.arguments().ok()?.args().first()?.ok()?;
+    let AnyJsCallArgument::AnyJsExpression(first) = first else {
+        return None;
+    };
+    let title = first.as_static_value()?.as_string_constant()?.to_string();
+    Some((title, first.range()))
+}
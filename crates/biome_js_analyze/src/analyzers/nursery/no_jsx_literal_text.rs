@@ -0,0 +1,383 @@
+use std::str::FromStr;
+
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_js_syntax::{
+    AnyJsTemplateElement, JsCallExpression, JsConditionalExpression, JsLogicalExpression,
+    JsParenthesizedExpression, JsStringLiteralExpression, JsSyntaxNode, JsTemplateExpression,
+    JsxAttribute, JsxAttributeInitializerClause, JsxElement, JsxExpressionAttributeValue,
+    JsxExpressionChild, JsxSelfClosingElement, JsxString, JsxText, TextRange, TextSize,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow the literal texts in JSX that aren't localized.
+    ///
+    /// In an application that is translated, the texts that the users read must come from the
+    /// localization library, not from literal strings in the markup. The rule reports the texts
+    /// of the JSX elements, the literal strings rendered as children, e.g. `{"Save"}`, and the
+    /// literal values of the attributes that the users read: `alt`, `aria-label`, `label`,
+    /// `placeholder`, `title` and the other ARIA attributes that contain a text.
+    ///
+    /// The texts without letters, e.g. `-` or `42`, and the contents of `<script>` and `<style>`
+    /// are ignored.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/jsx-no-literals.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const Title = () => <h1>Welcome back</h1>;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const Search = () => <input placeholder="Search the catalog" />;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const Status = ({ online }) => <span>{online ? "Online" : "Offline"}</span>;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// const Title = () => <h1>{t("home.welcome")}</h1>;
+    /// const Separator = () => <span> - </span>;
+    /// const Logo = () => <img src="/logo.svg" alt={t("home.logo")} />;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The texts rendered by the components and the functions of the localization library
+    /// can be allowed, as well as the texts that don't need to be translated:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowedPatterns": ["©*", "Biome"],
+    ///         "wrapperComponents": ["Trans"],
+    ///         "wrapperFunctions": ["msg", "i18n._"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### allowedPatterns
+    ///
+    /// The texts that don't need to be localized, e.g. the names of brands. The pattern must
+    /// match the whole text, without its leading and trailing whitespace. A `*` matches any
+    /// text.
+    ///
+    /// ### wrapperComponents
+    ///
+    /// The components whose texts are localized, e.g. `<Trans>Welcome back</Trans>`. The texts
+    /// of their descendants and of their attributes are allowed.
+    ///
+    /// ### wrapperFunctions
+    ///
+    /// The functions whose arguments are localized, e.g. `msg(<b>Welcome back</b>)`. The JSX
+    /// passed to them is allowed.
+    ///
+    pub(crate) NoJsxLiteralText {
+        version: "next",
+        name: "noJsxLiteralText",
+        recommended: false,
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoJsxLiteralTextOptions {
+    /// The patterns of the texts that don't need to be localized. A `*` matches any text.
+    #[bpaf(hide, argument::<String>("PATTERN"), many)]
+    allowed_patterns: Vec<String>,
+    /// The components whose texts are localized, e.g. `Trans`.
+    #[bpaf(hide, argument::<String>("COMPONENT"), many)]
+    wrapper_components: Vec<String>,
+    /// The functions whose arguments are localized, e.g. `i18n._`.
+    #[bpaf(hide, argument::<String>("FUNCTION"), many)]
+    wrapper_functions: Vec<String>,
+}
+
+// Required by [Bpaf].
+impl FromStr for NoJsxLiteralTextOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(NoJsxLiteralTextOptions::default())
+    }
+}
+
+impl Deserializable for NoJsxLiteralTextOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(NoJsxLiteralTextOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct NoJsxLiteralTextOptionsVisitor;
+impl DeserializationVisitor for NoJsxLiteralTextOptionsVisitor {
+    type Output = NoJsxLiteralTextOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            let field = match key_text.text() {
+                "allowedPatterns" => &mut result.allowed_patterns,
+                "wrapperComponents" => &mut result.wrapper_components,
+                "wrapperFunctions" => &mut result.wrapper_functions,
+                unknown_key => {
+                    const ALLOWED_KEYS: &[&str] =
+                        &["allowedPatterns", "wrapperComponents", "wrapperFunctions"];
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        unknown_key,
+                        key.range(),
+                        ALLOWED_KEYS,
+                    ));
+                    continue;
+                }
+            };
+            if let Some(values) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                *field = values;
+            }
+        }
+        Some(result)
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsxLiteralText =
+        JsxText | JsxString | JsStringLiteralExpression | JsTemplateExpression
+}
+
+/// The attributes whose values are read by the users, or by the assistive technologies
+const VISIBLE_ATTRIBUTES: &[&str] = &[
+    "alt",
+    "aria-description",
+    "aria-label",
+    "aria-placeholder",
+    "aria-roledescription",
+    "aria-valuetext",
+    "label",
+    "placeholder",
+    "title",
+];
+
+impl Rule for NoJsxLiteralText {
+    type Query = Ast<AnyJsxLiteralText>;
+    /// The range of the text, without its leading and trailing whitespace
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = NoJsxLiteralTextOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        let (text, range) = match node {
+            AnyJsxLiteralText::JsxText(text) => {
+                let token = text.value_token().ok()?;
+                let trimmed = token.text_trimmed().trim_start();
+                let leading_whitespace = token.text_trimmed().len() - trimmed.len();
+                let start = token.text_trimmed_range().start()
+                    + TextSize::try_from(leading_whitespace).ok()?;
+                let trimmed = trimmed.trim_end();
+                let length = TextSize::try_from(trimmed.len()).ok()?;
+                (trimmed.to_string(), TextRange::at(start, length))
+            }
+            AnyJsxLiteralText::JsxString(string) => {
+                let clause = string.parent::<JsxAttributeInitializerClause>()?;
+                if !is_visible_attribute(clause.syntax()) {
+                    return None;
+                }
+                (
+                    string.inner_string_text().ok()?.text().to_string(),
+                    string.range(),
+                )
+            }
+            AnyJsxLiteralText::JsStringLiteralExpression(string) => {
+                if !is_rendered(string.syntax()) {
+                    return None;
+                }
+                (
+                    string.inner_string_text().ok()?.text().to_string(),
+                    string.range(),
+                )
+            }
+            AnyJsxLiteralText::JsTemplateExpression(template) => {
+                if template.tag().is_some() || !is_rendered(template.syntax()) {
+                    return None;
+                }
+                let mut text = String::new();
+                for element in template.elements().iter() {
+                    // The templates with substitutions are built by the code
+                    let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element else {
+                        return None;
+                    };
+                    text.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+                }
+                (text, template.range())
+            }
+        };
+
+        let text = text.trim();
+        if !text.chars().any(char::is_alphabetic)
+            || options
+                .allowed_patterns
+                .iter()
+                .any(|pattern| matches_pattern(pattern, text))
+            || is_localized(node.syntax(), options)
+        {
+            return None;
+        }
+        Some(range)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This text isn't localized."
+                },
+            )
+            .note(markup! {
+                "Render the text with the localization library of the project, so that it can be translated."
+            }),
+        )
+    }
+}
+
+/// Whether the expression is rendered as a child of an element, or as the value of a visible
+/// attribute, e.g. `{isOpen ? "Close" : "Open"}`
+fn is_rendered(node: &JsSyntaxNode) -> bool {
+    let mut node = node.clone();
+    while let Some(parent) = node.parent() {
+        if JsParenthesizedExpression::can_cast(parent.kind()) {
+            node = parent;
+        } else if let Some(conditional) = JsConditionalExpression::cast_ref(&parent) {
+            // The test of the condition isn't rendered
+            if conditional.test().is_ok_and(|test| test.syntax() == &node) {
+                return false;
+            }
+            node = parent;
+        } else if let Some(logical) = JsLogicalExpression::cast_ref(&parent) {
+            // `isEmpty && "No results"` renders its right operand
+            if !logical.right().is_ok_and(|right| right.syntax() == &node) {
+                return false;
+            }
+            node = parent;
+        } else if JsxExpressionChild::can_cast(parent.kind()) {
+            return true;
+        } else if JsxExpressionAttributeValue::can_cast(parent.kind()) {
+            return parent
+                .parent()
+                .is_some_and(|clause| is_visible_attribute(&clause));
+        } else {
+            return false;
+        }
+    }
+    false
+}
+
+/// Whether the initializer clause is the value of an attribute that the users read
+fn is_visible_attribute(clause: &JsSyntaxNode) -> bool {
+    clause
+        .parent()
+        .and_then(JsxAttribute::cast)
+        .and_then(|attribute| attribute.name().ok())
+        .is_some_and(|name| {
+            VISIBLE_ATTRIBUTES.contains(&name.syntax().text_trimmed().to_string().as_str())
+        })
+}
+
+/// Whether the text is rendered by a wrapper of the localization library, or isn't read by the
+/// users, e.g. the content of `<style>`
+fn is_localized(node: &JsSyntaxNode, options: &NoJsxLiteralTextOptions) -> bool {
+    node.ancestors().skip(1).any(|ancestor| {
+        let name = if let Some(element) = JsxElement::cast_ref(&ancestor) {
+            element
+                .opening_element()
+                .and_then(|opening| opening.name())
+                .map(|name| name.syntax().text_trimmed().to_string())
+        } else if let Some(element) = JsxSelfClosingElement::cast_ref(&ancestor) {
+            element
+                .name()
+                .map(|name| name.syntax().text_trimmed().to_string())
+        } else if let Some(call) = JsCallExpression::cast_ref(&ancestor) {
+            let Ok(callee) = call.callee() else {
+                return false;
+            };
+            let callee = callee.syntax().text_trimmed().to_string();
+            return options.wrapper_functions.contains(&callee);
+        } else {
+            return false;
+        };
+        name.is_ok_and(|name| {
+            matches!(name.as_str(), "script" | "style")
+                || options.wrapper_components.contains(&name)
+        })
+    })
+}
+
+/// Whether the whole text matches the pattern, where `*` matches any text
+fn matches_pattern(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return false;
+    };
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<_> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // The pattern doesn't contain `*`
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_pattern;
+
+    #[test]
+    fn matches_the_whole_text() {
+        assert!(matches_pattern("Biome", "Biome"));
+        assert!(!matches_pattern("Biome", "Biome is fast"));
+        assert!(matches_pattern("©*", "© 2024 Biome"));
+        assert!(matches_pattern("*:", "Name:"));
+        assert!(matches_pattern("v*.*", "v1.4"));
+        assert!(!matches_pattern("v*.*", "version"));
+        assert!(matches_pattern("*", ""));
+    }
+}
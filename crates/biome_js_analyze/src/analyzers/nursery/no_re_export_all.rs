@@ -0,0 +1,84 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{JsExport, JsExportFromClause};
+use biome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Disallow `export *` re-exports.
+    ///
+    /// `export * from "mod"` re-exports all the exports of another module. Importing a single
+    /// binding from the re-exporting module loads the re-exported module, and all the modules
+    /// that it imports, even when the binding is declared elsewhere. Bundlers can't always remove
+    /// the modules that aren't used, and the tools that don't bundle, such as test runners, load
+    /// all of them.
+    ///
+    /// Moreover, the names of the re-exported bindings don't appear in the module: they can't be
+    /// found by searching for them, and two re-exported modules may export the same name.
+    ///
+    /// Type-only re-exports, e.g. `export type * from "mod"`, are ignored because they are
+    /// erased at compile time.
+    ///
+    /// Source: https://github.com/thepassle/eslint-plugin-barrel-files/blob/main/docs/rules/avoid-re-export-all.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// export * from "./button";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export * as components from "./components";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// export { Button } from "./button";
+    /// ```
+    ///
+    /// ```ts
+    /// export type * from "./types";
+    /// ```
+    ///
+    pub(crate) NoReExportAll {
+        version: "next",
+        name: "noReExportAll",
+        recommended: false,
+    }
+}
+
+impl Rule for NoReExportAll {
+    type Query = Ast<JsExportFromClause>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let clause = ctx.query();
+        if clause.type_token().is_some() {
+            return None;
+        }
+        let export = clause.parent::<JsExport>()?;
+        Some(export.range())
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid re-exporting all the exports of a module with "<Emphasis>"export *"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Importing any binding from this module loads the re-exported module, and all the modules that it imports."
+            })
+            .note(markup! {
+                "Re-export the bindings by name, or import them from the module that declares them."
+            }),
+        )
+    }
+}
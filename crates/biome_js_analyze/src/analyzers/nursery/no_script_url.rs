@@ -0,0 +1,118 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::JsxAttribute;
+use biome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Disallow the `javascript:` URLs in the links and the sources of the JSX elements.
+    ///
+    /// The browser runs the code of a `javascript:` URL when the link is followed, or when the
+    /// resource is loaded. When the URL contains data that comes from the users, it can expose
+    /// them to cross-site scripting (XSS) attacks. React warns about these URLs, and plans to
+    /// block them.
+    ///
+    /// The rule checks the literal values of the attributes `href`, `src`, `action`,
+    /// `formAction` and `xlink:href`. The URL is read like the browsers do: the scheme is
+    /// case-insensitive, and the leading whitespace and the tabs and the newlines are ignored.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/jsx-no-script-url.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <a href="javascript:void(0)">Open</a>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <iframe src={" JavaScript:alert(document.cookie)"} />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <a href="/docs">Open</a>
+    /// ```
+    ///
+    /// ```jsx
+    /// <button type="button" onClick={open}>Open</button>
+    /// ```
+    ///
+    pub(crate) NoScriptUrl {
+        version: "next",
+        name: "noScriptUrl",
+        recommended: false,
+    }
+}
+
+/// The attributes whose value is a URL that the browser follows or loads
+const URL_ATTRIBUTES: &[&str] = &["action", "formAction", "href", "src"];
+
+impl Rule for NoScriptUrl {
+    type Query = Ast<JsxAttribute>;
+    /// The range of the value of the attribute
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let attribute = ctx.query();
+        // `xlink:href` is matched by its name `href`
+        let name = attribute.name_value_token()?;
+        if !URL_ATTRIBUTES.contains(&name.text_trimmed()) {
+            return None;
+        }
+        let value = attribute.as_static_value()?;
+        if !is_script_url(value.as_string_constant()?) {
+            return None;
+        }
+        Some(attribute.initializer()?.value().ok()?.range())
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid the "<Emphasis>"javascript:"</Emphasis>" URLs."
+                },
+            )
+            .note(markup! {
+                "The browser runs the code of the URL, which can expose the users to cross-site scripting (XSS) attacks."
+            })
+            .note(markup! {
+                "Use an event handler, e.g. "<Emphasis>"onClick"</Emphasis>", to run the code instead."
+            }),
+        )
+    }
+}
+
+/// Whether the URL has the `javascript:` scheme, once the browsers have removed the leading
+/// controls and spaces, and the tabs and the newlines
+fn is_script_url(url: &str) -> bool {
+    let mut scheme = url
+        .trim_start_matches(|c: char| c.is_ascii_control() || c == ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'));
+    "javascript:"
+        .chars()
+        .all(|expected| scheme.next().map(|c| c.to_ascii_lowercase()) == Some(expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_script_url;
+
+    #[test]
+    fn reads_the_scheme_like_the_browsers() {
+        assert!(is_script_url("javascript:void(0)"));
+        assert!(is_script_url("  JavaScript:alert(1)"));
+        assert!(is_script_url("java\tscript:alert(1)"));
+        assert!(is_script_url("\u{1}javascript:alert(1)"));
+        assert!(!is_script_url("https://www.javascript.com"));
+        assert!(!is_script_url("/javascript:"));
+        assert!(!is_script_url("javascript"));
+    }
+}
@@ -0,0 +1,260 @@
+use std::str::FromStr;
+
+use crate::utils::packages::DeclaredPackages;
+use crate::utils::path_aliases::PathAliasPatterns;
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_js_syntax::{
+    AnyJsImportClause, JsExportFromClause, JsExportNamedFromClause, JsModuleSource, TextRange,
+};
+use biome_resolver::is_node_builtin;
+use biome_rowan::AstNode;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow the imports of packages that aren't declared in the `package.json`.
+    ///
+    /// A package that isn't declared as a dependency may be installed only because another
+    /// package depends on it, or because it's installed at the root of a monorepo. The import
+    /// breaks when the package is installed alone, or when its dependencies change.
+    ///
+    /// The rule checks the specifiers of the imports and of the re-exports against the closest
+    /// `package.json`: the package must be declared in its `dependencies`, `devDependencies`,
+    /// `peerDependencies` or `optionalDependencies`. The relative imports, the subpath imports,
+    /// e.g. `#utils`, the built-in modules of Node.js and the path aliases of `tsconfig.json`
+    /// are ignored. A type-only import can also be declared by the package of its types, e.g.
+    /// `@types/react` for `import type { FC } from "react"`.
+    ///
+    /// The rule only reports the imports of the files that have a `package.json`.
+    ///
+    /// Source: https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-extraneous-dependencies.md
+    ///
+    /// ## Examples
+    ///
+    /// With this `package.json`:
+    ///
+    /// ```json
+    /// {
+    ///     "name": "app",
+    ///     "dependencies": { "react": "^18.0.0" },
+    ///     "devDependencies": { "@types/node": "^20.0.0" }
+    /// }
+    /// ```
+    ///
+    /// The import of `lodash` is reported, because `lodash` isn't declared:
+    ///
+    /// ```js
+    /// import { debounce } from "lodash";
+    /// ```
+    ///
+    /// The declared packages, the package itself and the built-in modules can be imported:
+    ///
+    /// ```js
+    /// import { useState } from "react";
+    /// import { Button } from "app/components";
+    /// import { readFile } from "node:fs/promises";
+    /// import { join } from "path";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// In a monorepo, the packages of the workspace are often available to each other without
+    /// being declared. The rule can allow them:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowWorkspacePackages": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### allowWorkspacePackages
+    ///
+    /// When `true`, the packages of the project, i.e. the packages whose `package.json` is
+    /// in the project, can be imported without being declared.
+    ///
+    /// Default: `false`
+    ///
+    pub(crate) NoUndeclaredDependencies {
+        version: "next",
+        name: "noUndeclaredDependencies",
+        recommended: false,
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoUndeclaredDependenciesOptions {
+    /// Allow the imports of the packages of the project that aren't declared.
+    #[bpaf(hide)]
+    allow_workspace_packages: bool,
+}
+
+// Required by [Bpaf].
+impl FromStr for NoUndeclaredDependenciesOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(NoUndeclaredDependenciesOptions::default())
+    }
+}
+
+impl Deserializable for NoUndeclaredDependenciesOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(NoUndeclaredDependenciesOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct NoUndeclaredDependenciesOptionsVisitor;
+impl DeserializationVisitor for NoUndeclaredDependenciesOptionsVisitor {
+    type Output = NoUndeclaredDependenciesOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "allowWorkspacePackages" => {
+                    if let Some(value) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.allow_workspace_packages = value;
+                    }
+                }
+                unknown_key => {
+                    const ALLOWED_KEYS: &[&str] = &["allowWorkspacePackages"];
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        unknown_key,
+                        key.range(),
+                        ALLOWED_KEYS,
+                    ));
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+impl Rule for NoUndeclaredDependencies {
+    type Query = Ast<JsModuleSource>;
+    /// The name of the imported package
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = NoUndeclaredDependenciesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let packages = ctx.get_service::<DeclaredPackages>()?;
+        if !packages.has_manifest() {
+            return None;
+        }
+
+        let source = ctx.query();
+        let specifier = source.inner_string_text().ok()?;
+        let specifier = specifier.text();
+        // The relative and the absolute paths, the subpath imports and the protocols, e.g.
+        // `node:fs` or `bun:test`, don't import a package
+        if specifier.starts_with(['.', '/', '#']) || specifier.contains(':') {
+            return None;
+        }
+        let is_path_alias = ctx
+            .get_service::<PathAliasPatterns>()
+            .is_some_and(|path_aliases| path_aliases.matches(specifier));
+        if is_path_alias {
+            return None;
+        }
+
+        let name = package_name(specifier)?;
+        if is_node_builtin(specifier) || is_node_builtin(name) || packages.is_declared(name) {
+            return None;
+        }
+        if ctx.options().allow_workspace_packages && packages.is_project_package(name) {
+            return None;
+        }
+        if is_type_only(source) && packages.is_declared(&types_package_name(name)) {
+            return None;
+        }
+
+        Some(name.to_string())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The package "<Emphasis>{name}</Emphasis>" isn't declared in the "<Emphasis>"package.json"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Add it to the "<Emphasis>"dependencies"</Emphasis>" or the "<Emphasis>"devDependencies"</Emphasis>" of the closest "<Emphasis>"package.json"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns the name of the package of a specifier, e.g. `@scope/name` for
+/// `@scope/name/feature`
+fn package_name(specifier: &str) -> Option<&str> {
+    let mut separators = specifier.match_indices('/').map(|(index, _)| index);
+    let end = if specifier.starts_with('@') {
+        separators.nth(1)
+    } else {
+        separators.next()
+    };
+    let name = &specifier[..end.unwrap_or(specifier.len())];
+    (!name.is_empty() && name != "@").then_some(name)
+}
+
+/// Returns the name of the package of the types of `name`, e.g. `@types/scope__name` for
+/// `@scope/name`
+fn types_package_name(name: &str) -> String {
+    format!(
+        "@types/{}",
+        name.strip_prefix('@').unwrap_or(name).replace('/', "__")
+    )
+}
+
+/// Whether the source is the source of a type-only import or re-export, e.g.
+/// `import type { A } from "a"`
+fn is_type_only(source: &JsModuleSource) -> bool {
+    let Some(parent) = source.syntax().parent() else {
+        return false;
+    };
+    if let Some(clause) = AnyJsImportClause::cast_ref(&parent) {
+        let type_token = match clause {
+            AnyJsImportClause::JsImportBareClause(_) => None,
+            AnyJsImportClause::JsImportDefaultClause(clause) => clause.type_token(),
+            AnyJsImportClause::JsImportNamedClause(clause) => clause.type_token(),
+            AnyJsImportClause::JsImportNamespaceClause(clause) => clause.type_token(),
+        };
+        type_token.is_some()
+    } else if let Some(clause) = JsExportFromClause::cast_ref(&parent) {
+        clause.type_token().is_some()
+    } else if let Some(clause) = JsExportNamedFromClause::cast_ref(&parent) {
+        clause.type_token().is_some()
+    } else {
+        false
+    }
+}
@@ -0,0 +1,116 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsExpression, AnyJsName, AnyJsTemplateElement,
+    JsAssignmentExpression, JsAssignmentOperator, TextRange,
+};
+use biome_rowan::{AstNode, AstNodeList};
+
+declare_rule! {
+    /// Disallow assigning the HTML built with a template literal to `innerHTML` or `outerHTML`.
+    ///
+    /// The browser parses the HTML assigned to `innerHTML` and `outerHTML`, and runs the
+    /// scripts of its event handlers, e.g. `<img onerror="...">`. When the HTML is built by
+    /// interpolating values in a template literal, a value that comes from the users can inject
+    /// markup and expose them to cross-site scripting (XSS) attacks.
+    ///
+    /// The templates without substitutions, and the tagged templates, which can escape the
+    /// values, are allowed.
+    ///
+    /// Source: https://github.com/mozilla/eslint-plugin-no-unsanitized/blob/master/docs/rules/property.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// element.innerHTML = `<p>Hello ${name}</p>`;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// list.innerHTML += `<li>${item.label}</li>`;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// element.textContent = `Hello ${name}`;
+    /// element.innerHTML = `<p>Hello</p>`;
+    /// element.innerHTML = html`<p>Hello ${name}</p>`;
+    /// ```
+    ///
+    pub(crate) NoUnsafeInnerHtml {
+        version: "next",
+        name: "noUnsafeInnerHtml",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnsafeInnerHtml {
+    type Query = Ast<JsAssignmentExpression>;
+    /// The range of the template literal
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let assignment = ctx.query();
+        if !matches!(
+            assignment.operator(),
+            Ok(JsAssignmentOperator::Assign | JsAssignmentOperator::AddAssign)
+        ) {
+            return None;
+        }
+        let AnyJsAssignmentPattern::AnyJsAssignment(left) = assignment.left().ok()? else {
+            return None;
+        };
+        let property = match left {
+            AnyJsAssignment::JsStaticMemberAssignment(member) => match member.member().ok()? {
+                AnyJsName::JsName(name) => name.value_token().ok()?.text_trimmed().to_string(),
+                AnyJsName::JsPrivateName(_) => return None,
+            },
+            AnyJsAssignment::JsComputedMemberAssignment(member) => member
+                .member()
+                .ok()?
+                .as_static_value()?
+                .as_string_constant()?
+                .to_string(),
+            _ => return None,
+        };
+        if !matches!(property.as_str(), "innerHTML" | "outerHTML") {
+            return None;
+        }
+        let AnyJsExpression::JsTemplateExpression(template) =
+            assignment.right().ok()?.omit_parentheses()
+        else {
+            return None;
+        };
+        let has_substitutions = template
+            .elements()
+            .iter()
+            .any(|element| matches!(element, AnyJsTemplateElement::JsTemplateElement(_)));
+        if template.tag().is_some() || !has_substitutions {
+            return None;
+        }
+        Some(template.range())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        let left = ctx.query().left().ok()?.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid building the HTML assigned to "<Emphasis>{left}</Emphasis>" with a template literal."
+                },
+            )
+            .note(markup! {
+                "The values interpolated in the template can inject markup and scripts, which exposes the users to cross-site scripting (XSS) attacks."
+            })
+            .note(markup! {
+                "Create the elements with the DOM APIs and assign the values to "<Emphasis>"textContent"</Emphasis>", or escape them with a tagged template."
+            }),
+        )
+    }
+}
@@ -1,10 +1,26 @@
+use std::str::FromStr;
+
+use crate::JsRuleAction;
 use biome_analyze::{
-    context::RuleContext, declare_rule, AddVisitor, Phases, QueryMatch, Queryable, Rule,
-    RuleDiagnostic, ServiceBag, Visitor, VisitorContext,
+    context::RuleContext, declare_rule, ActionCategory, AddVisitor, FixKind, Phases, QueryMatch,
+    Queryable, Rule, RuleDiagnostic, ServiceBag, Visitor, VisitorContext,
 };
 use biome_console::markup;
-use biome_js_syntax::{AnyFunctionLike, JsAwaitExpression, JsLanguage, TextRange, WalkEvent};
-use biome_rowan::{AstNode, AstNodeList, Language, SyntaxNode, TextSize};
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsClass, AnyJsMethodModifier, JsAwaitExpression, JsClassMemberList,
+    JsForOfStatement, JsLanguage, JsMethodClassMember, TextRange, WalkEvent,
+};
+use biome_rowan::{
+    chain_trivia_pieces, trim_leading_trivia_pieces, AstNode, AstNodeList, BatchMutationExt,
+    Language, SyntaxNode, TextSize,
+};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
 
 declare_rule! {
     /// Ensure `async` functions utilize `await`.
@@ -44,18 +60,136 @@ declare_rule! {
     ///
     /// // Nor does it warn about empty `async` functions
     /// async function noop() { }
+    ///
+    /// // A `for await` loop awaits the values of the iterable
+    /// async function logChunks(stream) {
+    ///   for await (const chunk of stream) {
+    ///     console.log(chunk);
+    ///   }
+    /// }
     /// ```
+    ///
+    /// ## Options
+    ///
+    /// A method is sometimes declared `async` only to match the signature of the method that it
+    /// overrides, or of the interface that its class implements. The rule can ignore these methods:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreOverriddenMethods": true,
+    ///         "ignoreInterfaceImplementations": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### ignoreOverriddenMethods
+    ///
+    /// When `true`, the rule ignores the class methods marked with the TypeScript `override` modifier.
+    ///
+    /// Default: `false`
+    ///
+    /// ### ignoreInterfaceImplementations
+    ///
+    /// When `true`, the rule ignores the methods of the classes that have an `implements` clause.
+    ///
+    /// Default: `false`
+    ///
+    /// ## Fix
+    ///
+    /// The unsafe fix removes the `async` modifier. The function then returns its value instead of
+    /// a promise, and the errors that it throws aren't turned into rejected promises anymore.
+    /// The fix isn't offered for `async` generators.
     pub(crate) UseAwait {
         version: "1.4.0",
         name: "useAwait",
         recommended: true,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseAwaitOptions {
+    /// Ignore the class methods marked with the `override` modifier.
+    #[bpaf(hide)]
+    ignore_overridden_methods: bool,
+    /// Ignore the methods of the classes that implement an interface.
+    #[bpaf(hide)]
+    ignore_interface_implementations: bool,
+}
+
+// Required by [Bpaf].
+impl FromStr for UseAwaitOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(UseAwaitOptions::default())
+    }
+}
+
+impl Deserializable for UseAwaitOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(UseAwaitOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct UseAwaitOptionsVisitor;
+impl DeserializationVisitor for UseAwaitOptionsVisitor {
+    type Output = UseAwaitOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "ignoreOverriddenMethods" => {
+                    if let Some(value) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.ignore_overridden_methods = value;
+                    }
+                }
+                "ignoreInterfaceImplementations" => {
+                    if let Some(value) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.ignore_interface_implementations = value;
+                    }
+                }
+                unknown_key => {
+                    const ALLOWED_KEYS: &[&str] =
+                        &["ignoreOverriddenMethods", "ignoreInterfaceImplementations"];
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        unknown_key,
+                        key.range(),
+                        ALLOWED_KEYS,
+                    ));
+                }
+            }
+        }
+        Some(result)
     }
 }
 
 #[derive(Default)]
 struct MissingAwaitVisitor {
     /// Vector to hold a function node and a boolean indicating whether the function
-    /// contains an `await` expression or not.
+    /// contains an `await` expression or a `for await` loop.
     stack: Vec<(TextSize, bool)>,
 }
 
@@ -75,7 +209,10 @@ impl Visitor for MissingAwaitVisitor {
                     }
                 }
 
-                if JsAwaitExpression::can_cast(node.kind()) {
+                if JsAwaitExpression::can_cast(node.kind())
+                    || JsForOfStatement::cast_ref(node)
+                        .is_some_and(|statement| statement.await_token().is_some())
+                {
                     if let Some((_, has_await)) = self.stack.last_mut() {
                         *has_await = true;
                     }
@@ -127,13 +264,22 @@ impl Rule for UseAwait {
     type Query = MissingAwait;
     type State = ();
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = UseAwaitOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let query = ctx.query();
         if query.statements()?.is_empty() {
             return None;
         }
+        if let AnyFunctionLike::JsMethodClassMember(method) = query {
+            let options = ctx.options();
+            if options.ignore_overridden_methods && is_override(method) {
+                return None;
+            }
+            if options.ignore_interface_implementations && is_interface_implementation(method) {
+                return None;
+            }
+        }
         Some(())
     }
 
@@ -153,4 +299,43 @@ impl Rule for UseAwait {
             }),
         )
     }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let query = ctx.query();
+        // Removing `async` from an `async` generator turns it into a synchronous generator
+        if query.is_generator() {
+            return None;
+        }
+        let async_token = query.async_token()?;
+        let next_token = async_token.next_token()?;
+        let new_next_token = next_token.prepend_trivia_pieces(chain_trivia_pieces(
+            async_token.leading_trivia().pieces(),
+            trim_leading_trivia_pieces(async_token.trailing_trivia().pieces()),
+        ));
+        let mut mutation = ctx.root().begin();
+        mutation.remove_token(async_token);
+        mutation.replace_token_discard_trivia(next_token, new_next_token);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove the "<Emphasis>"async"</Emphasis>" modifier." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `method` has the `override` modifier
+fn is_override(method: &JsMethodClassMember) -> bool {
+    method
+        .modifiers()
+        .iter()
+        .any(|modifier| matches!(modifier, AnyJsMethodModifier::TsOverrideModifier(_)))
+}
+
+/// Returns `true` if `method` belongs to a class with an `implements` clause
+fn is_interface_implementation(method: &JsMethodClassMember) -> bool {
+    method
+        .parent::<JsClassMemberList>()
+        .and_then(|members| members.parent::<AnyJsClass>())
+        .is_some_and(|class| class.implements_clause().is_some())
 }
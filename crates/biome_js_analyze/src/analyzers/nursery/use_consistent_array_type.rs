@@ -0,0 +1,385 @@
+use std::str::FromStr;
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyTsName, AnyTsType, TextRange, TriviaPieceKind, TsArrayType, TsIndexedAccessType,
+    TsReferenceType, TsTypeOperatorType, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Require a consistent syntax for the array types.
+    ///
+    /// TypeScript provides two equivalent syntaxes for the array types: the shorthand syntax
+    /// `T[]` and the generic syntax `Array<T>`. The read-only arrays are written `readonly T[]`
+    /// and `ReadonlyArray<T>`. This rule enforces one of the syntaxes for both.
+    ///
+    /// The shorthand syntax is enforced by default. Unlike [useShorthandArrayType](https://biomejs.dev/linter/rules/use-shorthand-array-type),
+    /// the rule rewrites the arrays of any type, and adds the parentheses that the
+    /// shorthand syntax requires, e.g. `Array<A | B>` is rewritten `(A | B)[]`.
+    ///
+    /// Source: https://typescript-eslint.io/rules/array-type
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// let names: Array<string>;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// let ids: ReadonlyArray<number | string>;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// let names: string[];
+    /// let ids: readonly (number | string)[];
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The generic syntax can be enforced instead:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "syntax": "generic"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### syntax
+    ///
+    /// The syntax of the array types: `"shorthand"`, the default, for `T[]` and
+    /// `readonly T[]`, or `"generic"` for `Array<T>` and `ReadonlyArray<T>`.
+    ///
+    pub(crate) UseConsistentArrayType {
+        version: "next",
+        name: "useConsistentArrayType",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseConsistentArrayTypeOptions {
+    /// The syntax of the array types.
+    #[bpaf(hide)]
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub syntax: ArrayTypeSyntax,
+}
+
+fn is_default<T: Default + Eq>(value: &T) -> bool {
+    value == &T::default()
+}
+
+// Required by [Bpaf].
+impl FromStr for UseConsistentArrayTypeOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(UseConsistentArrayTypeOptions::default())
+    }
+}
+
+impl Deserializable for UseConsistentArrayTypeOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(UseConsistentArrayTypeOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct UseConsistentArrayTypeOptionsVisitor;
+impl DeserializationVisitor for UseConsistentArrayTypeOptionsVisitor {
+    type Output = UseConsistentArrayTypeOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["syntax"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "syntax" => {
+                    if let Some(syntax) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.syntax = syntax;
+                    }
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
+
+/// The syntaxes of the array types.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ArrayTypeSyntax {
+    /// `T[]` and `readonly T[]`
+    #[serde(rename = "shorthand")]
+    #[default]
+    Shorthand,
+
+    /// `Array<T>` and `ReadonlyArray<T>`
+    #[serde(rename = "generic")]
+    Generic,
+}
+
+impl FromStr for ArrayTypeSyntax {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "shorthand" => Ok(Self::Shorthand),
+            "generic" => Ok(Self::Generic),
+            _ => Err("Value not supported for the syntax of the array types"),
+        }
+    }
+}
+
+impl Deserializable for ArrayTypeSyntax {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["shorthand", "generic"];
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.parse::<Self>() {
+            Some(value)
+        } else {
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
+
+/// An array type written in the syntax that isn't enforced
+pub(crate) enum InconsistentArrayType {
+    /// `Array<T>`, or `ReadonlyArray<T>` when `readonly` is true
+    Generic {
+        reference: TsReferenceType,
+        element: AnyTsType,
+        readonly: bool,
+    },
+    /// `T[]`
+    Shorthand(TsArrayType),
+    /// `readonly T[]`
+    ReadonlyShorthand(TsTypeOperatorType, TsArrayType),
+}
+
+impl InconsistentArrayType {
+    fn is_readonly(&self) -> bool {
+        match self {
+            InconsistentArrayType::Generic { readonly, .. } => *readonly,
+            InconsistentArrayType::Shorthand(_) => false,
+            InconsistentArrayType::ReadonlyShorthand(..) => true,
+        }
+    }
+
+    fn range(&self) -> TextRange {
+        match self {
+            InconsistentArrayType::Generic { reference, .. } => reference.range(),
+            InconsistentArrayType::Shorthand(array) => array.range(),
+            InconsistentArrayType::ReadonlyShorthand(operator, _) => operator.range(),
+        }
+    }
+}
+
+impl Rule for UseConsistentArrayType {
+    type Query = Ast<AnyTsType>;
+    type State = InconsistentArrayType;
+    type Signals = Option<Self::State>;
+    type Options = UseConsistentArrayTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        match (ctx.options().syntax, node) {
+            (ArrayTypeSyntax::Shorthand, AnyTsType::TsReferenceType(reference)) => {
+                let AnyTsName::JsReferenceIdentifier(name) = reference.name().ok()? else {
+                    return None;
+                };
+                let readonly = match name.value_token().ok()?.text_trimmed() {
+                    "Array" => false,
+                    "ReadonlyArray" => true,
+                    _ => return None,
+                };
+                let arguments = reference.type_arguments()?.ts_type_argument_list();
+                if arguments.len() != 1 {
+                    return None;
+                }
+                let element = arguments.first()?.ok()?;
+                Some(InconsistentArrayType::Generic {
+                    reference: reference.clone(),
+                    element,
+                    readonly,
+                })
+            }
+            (ArrayTypeSyntax::Generic, AnyTsType::TsArrayType(array)) => {
+                match array.parent::<TsTypeOperatorType>() {
+                    Some(operator) if operator.operator_token().ok()?.kind() == T![readonly] => {
+                        Some(InconsistentArrayType::ReadonlyShorthand(
+                            operator,
+                            array.clone(),
+                        ))
+                    }
+                    _ => Some(InconsistentArrayType::Shorthand(array.clone())),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (used, enforced) = match (state, state.is_readonly()) {
+            (InconsistentArrayType::Generic { .. }, false) => ("Array<T>", "T[]"),
+            (InconsistentArrayType::Generic { .. }, true) => ("ReadonlyArray<T>", "readonly T[]"),
+            (_, false) => ("T[]", "Array<T>"),
+            (_, true) => ("readonly T[]", "ReadonlyArray<T>"),
+        };
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            state.range(),
+            markup! {
+                "Use "<Emphasis>{enforced}</Emphasis>" instead of "<Emphasis>{used}</Emphasis>"."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let (syntax, readonly) = match state {
+            InconsistentArrayType::Generic {
+                reference,
+                element,
+                readonly,
+            } => {
+                let array = AnyTsType::TsArrayType(make::ts_array_type(
+                    parenthesize_element(element.clone().trim_trivia()?),
+                    make::token(T!['[']),
+                    make::token(T![']']),
+                ));
+                let replacement = if *readonly {
+                    let operator = AnyTsType::TsTypeOperatorType(make::ts_type_operator_type(
+                        make::token(T![readonly])
+                            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                        array,
+                    ));
+                    // `Array<ReadonlyArray<T>>` is rewritten `(readonly T[])[]`
+                    let is_operand = reference.parent::<TsArrayType>().is_some()
+                        || reference.parent::<TsIndexedAccessType>().is_some();
+                    if is_operand {
+                        AnyTsType::TsParenthesizedType(make::ts_parenthesized_type(
+                            make::token(T!['(']),
+                            operator,
+                            make::token(T![')']),
+                        ))
+                    } else {
+                        operator
+                    }
+                } else {
+                    array
+                };
+                mutation.replace_node(AnyTsType::TsReferenceType(reference.clone()), replacement);
+                ("shorthand", *readonly)
+            }
+            InconsistentArrayType::Shorthand(array) => {
+                let replacement = generic_array_type("Array", array)?;
+                mutation.replace_node(AnyTsType::TsArrayType(array.clone()), replacement);
+                ("generic", false)
+            }
+            InconsistentArrayType::ReadonlyShorthand(operator, array) => {
+                let replacement = generic_array_type("ReadonlyArray", array)?;
+                mutation.replace_node(AnyTsType::TsTypeOperatorType(operator.clone()), replacement);
+                ("generic", true)
+            }
+        };
+        let subject = if readonly { "read-only array" } else { "array" };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "Use the "{syntax}" syntax for the "{subject}" type." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Wraps the element type of a shorthand array in parentheses when it's required, e.g. for the
+/// unions
+fn parenthesize_element(element: AnyTsType) -> AnyTsType {
+    match element {
+        AnyTsType::TsUnionType(_)
+        | AnyTsType::TsIntersectionType(_)
+        | AnyTsType::TsFunctionType(_)
+        | AnyTsType::TsConstructorType(_)
+        | AnyTsType::TsConditionalType(_)
+        | AnyTsType::TsTypeOperatorType(_)
+        | AnyTsType::TsInferType(_) => AnyTsType::TsParenthesizedType(make::ts_parenthesized_type(
+            make::token(T!['(']),
+            element,
+            make::token(T![')']),
+        )),
+        _ => element,
+    }
+}
+
+/// Returns the generic type `name<T>` for the shorthand array `T[]`
+fn generic_array_type(name: &str, array: &TsArrayType) -> Option<AnyTsType> {
+    let mut element = array.element_type().ok()?;
+    // The parentheses aren't needed in the type arguments
+    while let AnyTsType::TsParenthesizedType(parenthesized) = element {
+        element = parenthesized.ty().ok()?;
+    }
+    let reference = make::ts_reference_type(AnyTsName::JsReferenceIdentifier(
+        make::js_reference_identifier(make::ident(name)),
+    ))
+    .with_type_arguments(make::ts_type_arguments(
+        make::token(T![<]),
+        make::ts_type_argument_list([element.trim_trivia()?], []),
+        make::token(T![>]),
+    ))
+    .build();
+    Some(AnyTsType::TsReferenceType(reference))
+}
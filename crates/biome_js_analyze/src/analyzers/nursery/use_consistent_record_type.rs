@@ -0,0 +1,389 @@
+use std::str::FromStr;
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyTsName, AnyTsType, AnyTsTypeMember, TextRange, TriviaPieceKind, TsIndexSignatureTypeMember,
+    TsObjectType, TsReferenceType, TsTypeAliasDeclaration, TsTypeAnnotation, T,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Require a consistent syntax for the object types that only have an index signature.
+    ///
+    /// The object type `{ [key: string]: number }` is equivalent to `Record<string, number>`.
+    /// This rule enforces one of the syntaxes.
+    ///
+    /// The `Record` type is enforced by default. The object types that only have an index
+    /// signature are rewritten to a `Record`, unless the type of their values references the
+    /// type alias that declares them, because a `Record` can't be recursive.
+    ///
+    /// Source: https://typescript-eslint.io/rules/consistent-indexed-object-style
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// let scores: { [name: string]: number };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// let scores: Record<string, number>;
+    /// ```
+    ///
+    /// ```ts
+    /// type Tree = { [name: string]: Tree };
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The index signatures can be enforced instead:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "syntax": "indexSignature"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### syntax
+    ///
+    /// The syntax of the object types: `"record"`, the default, for `Record<K, V>`, or
+    /// `"indexSignature"` for `{ [key: K]: V }`. A `Record` is rewritten only when its keys
+    /// are `string`, `number` or `symbol`, the types that an index signature accepts.
+    ///
+    pub(crate) UseConsistentRecordType {
+        version: "next",
+        name: "useConsistentRecordType",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseConsistentRecordTypeOptions {
+    /// The syntax of the object types that only have an index signature.
+    #[bpaf(hide)]
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub syntax: RecordTypeSyntax,
+}
+
+fn is_default<T: Default + Eq>(value: &T) -> bool {
+    value == &T::default()
+}
+
+// Required by [Bpaf].
+impl FromStr for UseConsistentRecordTypeOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(UseConsistentRecordTypeOptions::default())
+    }
+}
+
+impl Deserializable for UseConsistentRecordTypeOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(UseConsistentRecordTypeOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct UseConsistentRecordTypeOptionsVisitor;
+impl DeserializationVisitor for UseConsistentRecordTypeOptionsVisitor {
+    type Output = UseConsistentRecordTypeOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["syntax"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "syntax" => {
+                    if let Some(syntax) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.syntax = syntax;
+                    }
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
+
+/// The syntaxes of the object types that only have an index signature.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RecordTypeSyntax {
+    /// `Record<K, V>`
+    #[serde(rename = "record")]
+    #[default]
+    Record,
+
+    /// `{ [key: K]: V }`
+    #[serde(rename = "indexSignature")]
+    IndexSignature,
+}
+
+impl FromStr for RecordTypeSyntax {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "record" => Ok(Self::Record),
+            "indexSignature" => Ok(Self::IndexSignature),
+            _ => Err("Value not supported for the syntax of the record types"),
+        }
+    }
+}
+
+impl Deserializable for RecordTypeSyntax {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["record", "indexSignature"];
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.parse::<Self>() {
+            Some(value)
+        } else {
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
+
+/// An object type written in the syntax that isn't enforced
+pub(crate) enum InconsistentRecordType {
+    /// `{ [key: K]: V }`
+    IndexSignature {
+        object: TsObjectType,
+        member: TsIndexSignatureTypeMember,
+    },
+    /// `Record<K, V>`
+    Record {
+        reference: TsReferenceType,
+        key: AnyTsType,
+        value: AnyTsType,
+    },
+}
+
+impl Rule for UseConsistentRecordType {
+    type Query = Ast<AnyTsType>;
+    type State = InconsistentRecordType;
+    type Signals = Option<Self::State>;
+    type Options = UseConsistentRecordTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        match (ctx.options().syntax, node) {
+            (RecordTypeSyntax::Record, AnyTsType::TsObjectType(object)) => {
+                let members = object.members();
+                if members.len() != 1 {
+                    return None;
+                }
+                let AnyTsTypeMember::TsIndexSignatureTypeMember(member) = members.first()? else {
+                    return None;
+                };
+                let value = member.type_annotation().ok()?.ty().ok()?;
+                if is_recursive(object, &value) {
+                    return None;
+                }
+                Some(InconsistentRecordType::IndexSignature {
+                    object: object.clone(),
+                    member,
+                })
+            }
+            (RecordTypeSyntax::IndexSignature, AnyTsType::TsReferenceType(reference)) => {
+                let AnyTsName::JsReferenceIdentifier(name) = reference.name().ok()? else {
+                    return None;
+                };
+                if !name.has_name("Record") {
+                    return None;
+                }
+                let arguments = reference.type_arguments()?.ts_type_argument_list();
+                if arguments.len() != 2 {
+                    return None;
+                }
+                let mut arguments = arguments.iter();
+                let key = arguments.next()?.ok()?;
+                let value = arguments.next()?.ok()?;
+                // The keys of an index signature can't be a union of literals, e.g. `"a" | "b"`
+                if !matches!(
+                    key,
+                    AnyTsType::TsStringType(_)
+                        | AnyTsType::TsNumberType(_)
+                        | AnyTsType::TsSymbolType(_)
+                ) {
+                    return None;
+                }
+                Some(InconsistentRecordType::Record {
+                    reference: reference.clone(),
+                    key,
+                    value,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = match state {
+            InconsistentRecordType::IndexSignature { .. } => {
+                markup! { "Use a "<Emphasis>"Record"</Emphasis>" type instead of an index signature." }
+            }
+            InconsistentRecordType::Record { .. } => {
+                markup! { "Use an index signature instead of a "<Emphasis>"Record"</Emphasis>" type." }
+            }
+        };
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            message,
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let message = match state {
+            InconsistentRecordType::IndexSignature { object, member } => {
+                let parameter = member.parameter().ok()?;
+                let key = parameter.type_annotation().ok()?.ty().ok()?;
+                let value = member.type_annotation().ok()?.ty().ok()?;
+                let record = generic_type("Record", vec![key.trim_trivia()?, value.trim_trivia()?]);
+                // `{ readonly [key: K]: V }` is rewritten `Readonly<Record<K, V>>`
+                let replacement = if member.readonly_token().is_some() {
+                    generic_type("Readonly", vec![record])
+                } else {
+                    record
+                };
+                mutation.replace_node(AnyTsType::TsObjectType(object.clone()), replacement);
+                markup! { "Use a "<Emphasis>"Record"</Emphasis>" type." }.to_owned()
+            }
+            InconsistentRecordType::Record {
+                reference,
+                key,
+                value,
+            } => {
+                let member = make::ts_index_signature_type_member(
+                    make::token(T!['[']),
+                    make::ts_index_signature_parameter(
+                        make::js_identifier_binding(make::ident("key")),
+                        type_annotation(key.clone().trim_trivia()?),
+                    ),
+                    make::token(T![']']),
+                    type_annotation(value.clone().trim_trivia()?),
+                )
+                .build();
+                let object = make::ts_object_type(
+                    make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    make::ts_type_member_list([AnyTsTypeMember::TsIndexSignatureTypeMember(
+                        member,
+                    )]),
+                    make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                );
+                mutation.replace_node(
+                    AnyTsType::TsReferenceType(reference.clone()),
+                    AnyTsType::TsObjectType(object),
+                );
+                markup! { "Use an index signature." }.to_owned()
+            }
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message,
+            mutation,
+        })
+    }
+}
+
+/// Whether `value` references the type alias whose type is `object`, e.g.
+/// `type Tree = { [name: string]: Tree }`
+fn is_recursive(object: &TsObjectType, value: &AnyTsType) -> bool {
+    let Some(alias) = object.parent::<TsTypeAliasDeclaration>() else {
+        return false;
+    };
+    let Some(alias_name) = alias
+        .binding_identifier()
+        .ok()
+        .and_then(|binding| binding.name_token().ok())
+    else {
+        return false;
+    };
+    value
+        .syntax()
+        .descendants()
+        .filter_map(TsReferenceType::cast)
+        .filter_map(|reference| reference.name().ok())
+        .any(|name| match name {
+            AnyTsName::JsReferenceIdentifier(name) => name.has_name(alias_name.text_trimmed()),
+            AnyTsName::TsQualifiedName(_) => false,
+        })
+}
+
+/// Returns the generic type `name<arguments>`
+fn generic_type(name: &str, arguments: Vec<AnyTsType>) -> AnyTsType {
+    let separators = (1..arguments.len())
+        .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+    let reference = make::ts_reference_type(AnyTsName::JsReferenceIdentifier(
+        make::js_reference_identifier(make::ident(name)),
+    ))
+    .with_type_arguments(make::ts_type_arguments(
+        make::token(T![<]),
+        make::ts_type_argument_list(arguments, separators),
+        make::token(T![>]),
+    ))
+    .build();
+    AnyTsType::TsReferenceType(reference)
+}
+
+/// Returns the type annotation `: ty`
+fn type_annotation(ty: AnyTsType) -> TsTypeAnnotation {
+    make::ts_type_annotation(
+        make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        ty,
+    )
+}
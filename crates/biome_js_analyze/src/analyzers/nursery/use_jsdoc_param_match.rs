@@ -0,0 +1,269 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::jsdoc::JsDoc;
+use biome_js_syntax::parameter_ext::{AnyJsParameterList, AnyJsParameters, AnyParameter};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsParameter, JsSyntaxKind, JsSyntaxNode, TextRange,
+};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Enforce that the `@param` tags of a JSDoc comment match the parameters of the function.
+    ///
+    /// The `@param` tags drift from the signature when the parameters are renamed, reordered or
+    /// removed, and the editors then show a wrong documentation. The rule reports:
+    ///
+    /// - a tag whose name isn't a parameter of the function;
+    /// - a tag that isn't at the position of its parameter;
+    /// - a parameter documented twice;
+    /// - a parameter that isn't documented, when the other parameters are.
+    ///
+    /// A destructured parameter can be documented with any name, e.g. `@param options`, and its
+    /// properties with the name of the parameter followed by the name of the property, e.g.
+    /// `@param options.name`. The comments without `@param` tags aren't checked.
+    ///
+    /// Source: https://github.com/gajus/eslint-plugin-jsdoc/blob/main/docs/rules/check-param-names.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {string} name
+    ///  * @param {string} greeting
+    ///  */
+    /// function greet(name) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {number} a
+    ///  */
+    /// function sum(a, b) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /**
+    ///  * @param {number} a
+    ///  * @param {number} b
+    ///  */
+    /// function sum(a, b) {}
+    /// ```
+    ///
+    /// ```js
+    /// /**
+    ///  * @param {Object} options
+    ///  * @param {string} options.name
+    ///  */
+    /// const greet = ({ name }) => {};
+    /// ```
+    ///
+    pub(crate) UseJsdocParamMatch {
+        version: "next",
+        name: "useJsdocParamMatch",
+        recommended: false,
+    }
+}
+
+pub(crate) enum ParamMismatch {
+    /// The function doesn't have a parameter with the name of the tag
+    UnknownParameter { name: String, range: TextRange },
+    /// The parameter exists, but at another position
+    WrongPosition { name: String, range: TextRange },
+    /// A tag of the parameter precedes this one
+    Duplicate { name: String, range: TextRange },
+    /// The parameter doesn't have a tag
+    Undocumented { name: String, range: TextRange },
+}
+
+/// A parameter of the function
+enum Parameter {
+    Named {
+        name: String,
+        range: TextRange,
+    },
+    /// A parameter declared with an object or an array pattern, which doesn't have a name
+    Destructured,
+}
+
+impl Parameter {
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Named { name, .. } => Some(name),
+            Self::Destructured => None,
+        }
+    }
+}
+
+impl Rule for UseJsdocParamMatch {
+    type Query = Ast<AnyJsParameters>;
+    type State = ParamMismatch;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Some(doc) = node
+            .syntax()
+            .parent()
+            .and_then(|function| function_jsdoc(&function))
+        else {
+            return vec![];
+        };
+        let tags: Vec<_> = doc
+            .params()
+            .filter_map(|tag| tag.parameter.as_ref())
+            .filter(|parameter| !parameter.is_property())
+            .collect();
+        if tags.is_empty() {
+            return vec![];
+        }
+        let Some(parameters) = parameters(node) else {
+            return vec![];
+        };
+        let is_parameter_name = |name: &str| {
+            parameters
+                .iter()
+                .any(|parameter| parameter.name() == Some(name))
+        };
+
+        let mut signals = vec![];
+        let mut documented: Vec<&str> = vec![];
+        for (index, tag) in tags.iter().enumerate() {
+            let name = tag.name.as_str();
+            if documented.contains(&name) {
+                signals.push(ParamMismatch::Duplicate {
+                    name: tag.name.clone(),
+                    range: tag.range,
+                });
+                continue;
+            }
+            documented.push(name);
+            let expected = parameters.get(index);
+            if is_parameter_name(name) {
+                if expected.and_then(Parameter::name) != Some(name) {
+                    signals.push(ParamMismatch::WrongPosition {
+                        name: tag.name.clone(),
+                        range: tag.range,
+                    });
+                }
+            } else if !matches!(expected, Some(Parameter::Destructured)) {
+                // Any name documents a destructured parameter
+                signals.push(ParamMismatch::UnknownParameter {
+                    name: tag.name.clone(),
+                    range: tag.range,
+                });
+            }
+        }
+
+        for parameter in &parameters {
+            if let Parameter::Named { name, range } = parameter {
+                if !documented.contains(&name.as_str()) {
+                    signals.push(ParamMismatch::Undocumented {
+                        name: name.clone(),
+                        range: *range,
+                    });
+                }
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            ParamMismatch::UnknownParameter { name, range } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The function doesn't have a parameter named "<Emphasis>{name}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Rename the tag to the name of the parameter, or remove it."
+            }),
+            ParamMismatch::WrongPosition { name, range } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The parameter "<Emphasis>{name}</Emphasis>" isn't documented at its position."
+                },
+            )
+            .note(markup! {
+                "Order the "<Emphasis>"@param"</Emphasis>" tags like the parameters of the function."
+            }),
+            ParamMismatch::Duplicate { name, range } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The parameter "<Emphasis>{name}</Emphasis>" is documented twice."
+                },
+            )
+            .note(markup! {
+                "Remove one of the tags."
+            }),
+            ParamMismatch::Undocumented { name, range } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The parameter "<Emphasis>{name}</Emphasis>" isn't documented."
+                },
+            )
+            .note(markup! {
+                "Add a "<Emphasis>"@param"</Emphasis>" tag for the parameter, like the other parameters of the function."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the JSDoc comment of a function, or of the variable, the class property or the
+/// object property whose value is the function
+fn function_jsdoc(function: &JsSyntaxNode) -> Option<JsDoc> {
+    JsDoc::for_node(function).or_else(|| {
+        let parent = function.parent()?;
+        match parent.kind() {
+            JsSyntaxKind::JS_INITIALIZER_CLAUSE => JsDoc::for_node(&parent.parent()?),
+            JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER | JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION => {
+                JsDoc::for_node(&parent)
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Returns the parameters of the function, without `this`, or [None] if one of them is bogus
+fn parameters(parameters: &AnyJsParameters) -> Option<Vec<Parameter>> {
+    let list = match parameters {
+        AnyJsParameters::JsParameters(parameters) => AnyJsParameterList::from(parameters.items()),
+        AnyJsParameters::JsConstructorParameters(parameters) => {
+            AnyJsParameterList::from(parameters.parameters())
+        }
+    };
+    let mut result = vec![];
+    for parameter in list.iter() {
+        let parameter = parameter.ok()?;
+        if matches!(
+            parameter,
+            AnyParameter::AnyJsParameter(AnyJsParameter::TsThisParameter(_))
+        ) {
+            continue;
+        }
+        let parameter = match parameter.binding()? {
+            AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) => {
+                let name = binding.name_token().ok()?;
+                Parameter::Named {
+                    name: name.text_trimmed().to_string(),
+                    range: name.text_trimmed_range(),
+                }
+            }
+            AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsBogusBinding(_)) => return None,
+            AnyJsBindingPattern::JsArrayBindingPattern(_)
+            | AnyJsBindingPattern::JsObjectBindingPattern(_) => Parameter::Destructured,
+        };
+        result.push(parameter);
+    }
+    Some(result)
+}
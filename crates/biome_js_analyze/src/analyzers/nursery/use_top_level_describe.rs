@@ -0,0 +1,101 @@
+use crate::utils::test_frameworks::{test_call_of_callback, TestCallKind, TestFile};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsFunction, JsCallExpression};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Require the tests and the hooks to be in a `describe` block.
+    ///
+    /// The hooks declared at the top level of a file, e.g. `beforeEach()`, run for all the tests
+    /// of the file, which is easy to miss when the file grows. Grouping the tests and their hooks
+    /// in `describe` blocks makes the scope of the hooks explicit, and titles the tests of the
+    /// file in the reports.
+    ///
+    /// A test is in a `describe` block when it's called in the callback of a `describe`, at any
+    /// depth, so the nested `describe` blocks are allowed.
+    ///
+    /// Source: https://github.com/jest-community/eslint-plugin-jest/blob/main/docs/rules/require-top-level-describe.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { test } from "vitest";
+    ///
+    /// test("parses the numbers", () => {});
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { beforeEach, describe } from "vitest";
+    ///
+    /// beforeEach(() => reset());
+    ///
+    /// describe("parse", () => {});
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { beforeEach, describe, test } from "vitest";
+    ///
+    /// describe("parse", () => {
+    ///     beforeEach(() => reset());
+    ///
+    ///     test("parses the numbers", () => {});
+    /// });
+    /// ```
+    ///
+    pub(crate) UseTopLevelDescribe {
+        version: "next",
+        name: "useTopLevelDescribe",
+        recommended: false,
+    }
+}
+
+impl Rule for UseTopLevelDescribe {
+    type Query = Ast<JsCallExpression>;
+    type State = TestCallKind;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.get_service::<TestFile>()?.framework()?;
+        let call = ctx.query();
+        let kind = TestCallKind::of_call(call)?;
+        if kind == TestCallKind::Describe {
+            return None;
+        }
+        let is_in_describe = call
+            .syntax()
+            .ancestors()
+            .filter(|ancestor| AnyJsFunction::can_cast(ancestor.kind()))
+            .any(|function| {
+                matches!(
+                    test_call_of_callback(&function),
+                    Some((_, TestCallKind::Describe))
+                )
+            });
+        (!is_in_describe).then_some(kind)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, kind: &Self::State) -> Option<RuleDiagnostic> {
+        let subject = match kind {
+            TestCallKind::Hook => "hook",
+            _ => "test",
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().callee().ok()?.range(),
+                markup! {
+                    "This "{subject}" isn't in a "<Emphasis>"describe"</Emphasis>" block."
+                },
+            )
+            .note(markup! {
+                "Group the tests and the hooks of the file in a "<Emphasis>"describe"</Emphasis>" block, so that the scope of the hooks is explicit."
+            }),
+        )
+    }
+}
@@ -0,0 +1,96 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::jsdoc::{JsDoc, JsDocError, JsDocErrorKind};
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::{AstNode, Direction};
+
+declare_rule! {
+    /// Enforce the syntax of the tags of the JSDoc comments.
+    ///
+    /// The editors and the documentation generators ignore the tags that they can't parse, or
+    /// read them differently than intended. The rule reports:
+    ///
+    /// - a `@` that isn't followed by the name of a tag;
+    /// - a type whose braces are empty, or that doesn't have its closing brace;
+    /// - a `@param` or a `@property` tag without the name that it documents;
+    /// - an optional name, `[name]`, that doesn't have its closing bracket.
+    ///
+    /// The tags inside the code blocks of the description aren't checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {string name The name of the user
+    ///  */
+    /// function greet(name) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {string}
+    ///  */
+    /// function greet(name) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /**
+    ///  * @param {string} [name="World"] The name of the user
+    ///  */
+    /// function greet(name) {}
+    /// ```
+    ///
+    pub(crate) UseValidJsdoc {
+        version: "next",
+        name: "useValidJsdoc",
+        recommended: false,
+    }
+}
+
+impl Rule for UseValidJsdoc {
+    type Query = Ast<AnyJsRoot>;
+    type State = JsDocError;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.query()
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .flat_map(|token| {
+                token
+                    .leading_trivia()
+                    .pieces()
+                    .chain(token.trailing_trivia().pieces())
+            })
+            .filter_map(|piece| piece.as_comments())
+            .filter_map(|comment| JsDoc::parse(comment.text(), comment.text_range().start()))
+            .flat_map(|doc| doc.errors)
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, error: &Self::State) -> Option<RuleDiagnostic> {
+        let note = match error.kind {
+            JsDocErrorKind::MissingTagName => markup! {
+                "Add the name of the tag after "<Emphasis>"@"</Emphasis>", or escape it with a backslash."
+            },
+            JsDocErrorKind::UnclosedType => markup! {
+                "Close the type with "<Emphasis>"}"</Emphasis>"."
+            },
+            JsDocErrorKind::EmptyType => markup! {
+                "Write the type between the braces, or remove them."
+            },
+            JsDocErrorKind::MissingParameterName => markup! {
+                "Add the name after the type, e.g. "<Emphasis>"@param {string} name"</Emphasis>"."
+            },
+            JsDocErrorKind::UnclosedParameterName => markup! {
+                "Close the optional name with "<Emphasis>"]"</Emphasis>"."
+            },
+        };
+        Some(RuleDiagnostic::new(rule_category!(), error.range, error.kind.message()).note(note))
+    }
+}
@@ -63,14 +63,7 @@ impl Rule for UseTemplate {
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
         // Do not handle binary operations contained in a binary operation with operator `+`
-        if node
-            .syntax()
-            .ancestors()
-            .skip(1) // skip node
-            .find(|x| !JsParenthesizedExpression::can_cast(x.kind()))
-            .and_then(JsBinaryExpression::cast)
-            .is_some_and(|parent| parent.operator() == Ok(JsBinaryOperator::Plus))
-        {
+        if is_in_concatenation(node) {
             return None;
         }
         can_be_template_literal(node)?.then_some(())
@@ -104,6 +97,16 @@ impl Rule for UseTemplate {
     }
 }
 
+/// Returns true if `node` is an operand of a binary expression with the operator `+`
+pub(crate) fn is_in_concatenation(node: &JsBinaryExpression) -> bool {
+    node.syntax()
+        .ancestors()
+        .skip(1) // skip node
+        .find(|x| !JsParenthesizedExpression::can_cast(x.kind()))
+        .and_then(JsBinaryExpression::cast)
+        .is_some_and(|parent| parent.operator() == Ok(JsBinaryOperator::Plus))
+}
+
 /// Returns true if `node` can be converted to a template literal.
 ///
 /// This is the case, if:
@@ -112,7 +115,7 @@ impl Rule for UseTemplate {
 /// - the binary expression contains a string-like literal and a non-string-like
 ///
 /// String-like literals are string literals and untagged template literals.
-fn can_be_template_literal(node: &JsBinaryExpression) -> Option<bool> {
+pub(crate) fn can_be_template_literal(node: &JsBinaryExpression) -> Option<bool> {
     let mut iter = node.syntax().preorder();
     let mut has_constant_string_constituent = false;
     let mut has_non_constant_string_constituent = false;
@@ -147,7 +150,7 @@ fn can_be_template_literal(node: &JsBinaryExpression) -> Option<bool> {
     Some(false)
 }
 
-fn template_expression_from_binary_expression(
+pub(crate) fn template_expression_from_binary_expression(
     node: &JsBinaryExpression,
 ) -> Option<JsTemplateExpression> {
     // While `template_elements` is empty, we keep track of the last left node.
@@ -1,4 +1,5 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 pub(crate) mod correctness;
-::biome_analyze::declare_category! { pub (crate) Assists { kind : Action , groups : [self :: correctness :: Correctness ,] } }
+pub(crate) mod refactor;
+::biome_analyze::declare_category! { pub (crate) Assists { kind : Action , groups : [self :: correctness :: Correctness , self :: refactor :: Refactor ,] } }
@@ -15,6 +15,7 @@ use biome_rowan::{
     BatchMutationExt, SyntaxTriviaPiece, TokenText, TriviaPiece,
 };
 
+use crate::utils::path_aliases::PathAliasPatterns;
 use crate::JsRuleAction;
 
 declare_rule! {
@@ -57,6 +58,10 @@ impl Rule for OrganizeImports {
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let root = ctx.query();
+        let default_path_aliases = PathAliasPatterns::default();
+        let path_aliases = ctx
+            .get_service::<PathAliasPatterns>()
+            .unwrap_or(&default_path_aliases);
         let mut groups = Vec::new();
         let mut first_node = None;
         let mut nodes = BTreeMap::new();
@@ -90,9 +95,9 @@ impl Rule for OrganizeImports {
             }
 
             nodes
-                .entry(ImportKey(import.source_text().ok()?))
+                .entry(ImportKey::new(import.source_text().ok()?, path_aliases))
                 .or_default()
-                .push(ImportNode::from(import));
+                .push(ImportNode::new(import, path_aliases));
         }
 
         // Flush the remaining nodes
@@ -291,8 +296,8 @@ struct ImportNode {
     specifiers: BTreeMap<ImportKey, (AnyJsNamedImportSpecifier, Option<JsSyntaxToken>)>,
 }
 
-impl From<JsImport> for ImportNode {
-    fn from(node: JsImport) -> Self {
+impl ImportNode {
+    fn new(node: JsImport, path_aliases: &PathAliasPatterns) -> Self {
         let import_clause = node.import_clause().ok();
 
         let mut separator_count = 0;
@@ -316,7 +321,10 @@ impl From<JsImport> for ImportNode {
                 let trailing_separator = element.trailing_separator.ok()?;
                 separator_count += usize::from(trailing_separator.is_some());
 
-                result.insert(ImportKey(key), (node, trailing_separator));
+                result.insert(
+                    ImportKey::new(key, path_aliases),
+                    (node, trailing_separator),
+                );
             }
 
             Some(result)
@@ -328,9 +336,7 @@ impl From<JsImport> for ImportNode {
             specifiers: specifiers.unwrap_or_default(),
         }
     }
-}
 
-impl ImportNode {
     /// Returns `true` if the named import specifiers of this import node are sorted
     fn is_sorted(&self) -> bool {
         let mut iter = self
@@ -553,18 +559,28 @@ fn leading_trivia_iter(
 }
 
 #[derive(Debug)]
-struct ImportKey(TokenText);
+struct ImportKey {
+    category: ImportCategory,
+    text: TokenText,
+}
+
+impl ImportKey {
+    fn new(text: TokenText, path_aliases: &PathAliasPatterns) -> Self {
+        Self {
+            category: ImportCategory::new(text.text(), path_aliases),
+            text,
+        }
+    }
+}
 
 impl Ord for ImportKey {
     fn cmp(&self, other: &Self) -> Ordering {
-        let own_category = ImportCategory::from(self.0.text());
-        let other_category = ImportCategory::from(other.0.text());
-        if own_category != other_category {
-            return own_category.cmp(&other_category);
+        if self.category != other.category {
+            return self.category.cmp(&other.category);
         }
 
         // Sort imports using natural ordering
-        natord::compare(&self.0, &other.0)
+        natord::compare(&self.text, &other.text)
     }
 }
 
@@ -578,7 +594,7 @@ impl Eq for ImportKey {}
 
 impl PartialEq for ImportKey {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+        self.text == other.text
     }
 }
 
@@ -586,7 +602,7 @@ impl PartialEq for ImportKey {
 ///
 /// The rationale for this is that imports "further away" from the source file
 /// are listed before imports closer to the source file.
-#[derive(Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
 enum ImportCategory {
     /// Anything with an explicit `bun:` prefix.
     Bun,
@@ -599,10 +615,11 @@ enum ImportCategory {
     /// Imports from an absolute URL such as supported by browsers.
     Url,
     /// Anything without explicit protocol specifier is assumed to be a library
-    /// import. Because we currently do not have configuration for this, this
-    /// may (incorrectly) include source imports through custom import mappings
-    /// as well.
+    /// import, unless it matches a path alias.
     Library,
+    /// Imports that match a pattern of the `paths` option of `tsconfig.json`,
+    /// such as `@app/*`. They point at the files of the project.
+    Alias,
     /// Absolute file imports `/<path>`.
     Absolute,
     /// Node allows specifying an import map with name prefixed with `#`.
@@ -615,8 +632,8 @@ enum ImportCategory {
     Other,
 }
 
-impl From<&str> for ImportCategory {
-    fn from(value: &str) -> Self {
+impl ImportCategory {
+    fn new(value: &str, path_aliases: &PathAliasPatterns) -> Self {
         if value.starts_with('.') {
             Self::Relative
         } else if let Some((protocol, _)) = value.split_once(':') {
@@ -633,6 +650,8 @@ impl From<&str> for ImportCategory {
             Self::Absolute
         } else if NODE_BUILTINS.binary_search(&value).is_ok() {
             Self::NodeBuiltin
+        } else if path_aliases.matches(value) {
+            Self::Alias
         } else {
             Self::Library
         }
@@ -699,3 +718,19 @@ fn test_order() {
         assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
     }
 }
+
+#[test]
+fn test_path_alias_category() {
+    let path_aliases = PathAliasPatterns::new(vec![String::from("@app/*")]);
+
+    assert_eq!(
+        ImportCategory::new("@app/button", &path_aliases),
+        ImportCategory::Alias
+    );
+    assert_eq!(
+        ImportCategory::new("@application/button", &path_aliases),
+        ImportCategory::Library
+    );
+    assert!(ImportCategory::Library < ImportCategory::Alias);
+    assert!(ImportCategory::Alias < ImportCategory::Relative);
+}
@@ -0,0 +1,26 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub(crate) mod convert_to_arrow_function;
+pub(crate) mod convert_to_function_declaration;
+pub(crate) mod convert_to_string_literal;
+pub(crate) mod convert_to_template_literal;
+pub(crate) mod extract_to_constant;
+pub(crate) mod inline_variable;
+pub(crate) mod toggle_quote_style;
+
+declare_group! {
+    pub (crate) Refactor {
+        name : "refactor" ,
+        rules : [
+            self :: convert_to_arrow_function :: ConvertToArrowFunction ,
+            self :: convert_to_function_declaration :: ConvertToFunctionDeclaration ,
+            self :: convert_to_string_literal :: ConvertToStringLiteral ,
+            self :: convert_to_template_literal :: ConvertToTemplateLiteral ,
+            self :: extract_to_constant :: ExtractToConstant ,
+            self :: inline_variable :: InlineVariable ,
+            self :: toggle_quote_style :: ToggleQuoteStyle ,
+        ]
+     }
+}
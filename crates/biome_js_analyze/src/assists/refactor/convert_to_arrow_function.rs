@@ -0,0 +1,168 @@
+use std::iter;
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsExpression, AnyJsStatement, JsFunctionDeclaration, JsLanguage,
+    JsModuleItemList, JsStatementList, TriviaPieceKind, TsDeclareFunctionDeclaration, T,
+};
+use biome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt};
+
+use crate::analyzers::complexity::use_arrow_function::to_arrow_body;
+use crate::semantic_services::Semantic;
+use crate::utils::queries::all_references;
+use crate::utils::uses_function_context;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert a function declaration to a constant
+    /// initialized with an arrow function.
+    ///
+    /// The function must not use `this`, `arguments`, `super` or `new.target`, which
+    /// an arrow function inherits from its enclosing function. Because a constant
+    /// isn't hoisted, the function must only be referenced after its declaration.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// function double(value) {
+    ///     return value * 2;
+    /// }
+    /// ```
+    pub(crate) ConvertToArrowFunction {
+        version: "next",
+        name: "convertToArrowFunction",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ConvertToArrowFunction {
+    type Query = Semantic<JsFunctionDeclaration>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let model = ctx.model();
+        if function.star_token().is_some() {
+            return None;
+        }
+        let AnyJsBinding::JsIdentifierBinding(id) = function.id().ok()? else {
+            return None;
+        };
+        let is_in_list = function.syntax().parent().is_some_and(|list| {
+            JsStatementList::can_cast(list.kind()) || JsModuleItemList::can_cast(list.kind())
+        });
+        let is_overloaded = function
+            .syntax()
+            .prev_sibling()
+            .is_some_and(|sibling| TsDeclareFunctionDeclaration::can_cast(sibling.kind()));
+        if !is_in_list || is_overloaded || uses_function_context(function.syntax()) {
+            return None;
+        }
+        let has_this_parameter = function.parameters().ok()?.items().iter().any(|parameter| {
+            parameter.is_ok_and(|parameter| parameter.as_ts_this_parameter().is_some())
+        });
+        if has_this_parameter {
+            return None;
+        }
+        // A constant can't be reassigned, nor used before its declaration
+        let declaration_end = function.range().end();
+        all_references(ctx.query_cache(), &id, model)
+            .iter()
+            .all(|reference| {
+                reference.is_read()
+                    && reference.syntax().text_trimmed_range().start() >= declaration_end
+            })
+            .then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let function = ctx.query();
+        let AnyJsBinding::JsIdentifierBinding(id) = function.id().ok()? else {
+            return None;
+        };
+        let name = id.name_token().ok()?;
+
+        let parameters = function.parameters().ok()?;
+        let return_type_annotation = function.return_type_annotation();
+        let mut arrow_function_builder = make::js_arrow_function_expression(
+            if return_type_annotation.is_some() {
+                parameters.into()
+            } else {
+                with_trailing_space(parameters)?.into()
+            },
+            make::token(T![=>]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            to_arrow_body(function.body().ok()?),
+        );
+        if function.async_token().is_some() {
+            arrow_function_builder = arrow_function_builder.with_async_token(
+                make::token(T![async]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            );
+        }
+        if let Some(type_parameters) = function.type_parameters() {
+            arrow_function_builder = arrow_function_builder.with_type_parameters(type_parameters);
+        }
+        if let Some(return_type_annotation) = return_type_annotation {
+            arrow_function_builder = arrow_function_builder
+                .with_return_type_annotation(with_trailing_space(return_type_annotation)?);
+        }
+        let arrow_function = AnyJsExpression::from(arrow_function_builder.build())
+            .with_trailing_trivia_pieces(iter::empty())?;
+
+        let statement = make::js_variable_statement(
+            make::js_variable_declaration(
+                make::token(T![const])
+                    .with_leading_trivia_pieces(function.syntax().first_leading_trivia()?.pieces())
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_variable_declarator_list(
+                    [make::js_variable_declarator(
+                        make::js_identifier_binding(make::ident(name.text_trimmed())).into(),
+                    )
+                    .with_initializer(make::js_initializer_clause(
+                        make::token_decorated_with_space(T![=]),
+                        arrow_function,
+                    ))
+                    .build()],
+                    [],
+                ),
+            )
+            .build(),
+        )
+        .with_semicolon_token(
+            make::token(T![;])
+                .with_trailing_trivia_pieces(function.syntax().last_trailing_trivia()?.pieces()),
+        )
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation
+            .replace_node_discard_trivia(AnyJsStatement::from(function.clone()), statement.into());
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Convert to an "<Emphasis>"arrow function"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `node` separated from the next token by a single space
+pub(crate) fn with_trailing_space<N>(node: N) -> Option<N>
+where
+    N: AstNode<Language = JsLanguage>,
+{
+    let last_token = node.syntax().last_token()?;
+    node.replace_token_discard_trivia(
+        last_token.clone(),
+        last_token.with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    )
+}
@@ -0,0 +1,191 @@
+use std::iter;
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsExpression,
+    AnyJsFormalParameter, AnyJsFunctionBody, AnyJsParameter, AnyJsStatement,
+    JsArrowFunctionExpression, JsModuleItemList, JsStatementList, JsVariableStatement,
+    TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+use crate::assists::refactor::convert_to_arrow_function::with_trailing_space;
+use crate::utils::uses_function_context;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert a constant initialized with an arrow
+    /// function to a function declaration.
+    ///
+    /// The arrow function must not use `this`, `arguments`, `super` or `new.target`,
+    /// which it inherits from its enclosing function, unlike a function declaration.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const double = (value) => value * 2;
+    /// ```
+    pub(crate) ConvertToFunctionDeclaration {
+        version: "next",
+        name: "convertToFunctionDeclaration",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub(crate) struct ConvertToFunctionDeclarationState {
+    name: AnyJsBinding,
+    arrow_function: JsArrowFunctionExpression,
+}
+
+impl Rule for ConvertToFunctionDeclaration {
+    type Query = Ast<JsVariableStatement>;
+    type State = ConvertToFunctionDeclarationState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let statement = ctx.query();
+        let declaration = statement.declaration().ok()?;
+        let declarators = declaration.declarators();
+        if !declaration.is_const() || declarators.len() != 1 {
+            return None;
+        }
+        let is_in_list = statement.syntax().parent().is_some_and(|list| {
+            JsStatementList::can_cast(list.kind()) || JsModuleItemList::can_cast(list.kind())
+        });
+        if !is_in_list {
+            return None;
+        }
+        let declarator = declarators.first()?.ok()?;
+        let AnyJsBindingPattern::AnyJsBinding(name @ AnyJsBinding::JsIdentifierBinding(_)) =
+            declarator.id().ok()?
+        else {
+            return None;
+        };
+        if declarator.variable_annotation().is_some() {
+            // The type of the constant would be lost
+            return None;
+        }
+        let AnyJsExpression::JsArrowFunctionExpression(arrow_function) =
+            declarator.initializer()?.expression().ok()?
+        else {
+            return None;
+        };
+        if uses_function_context(arrow_function.syntax()) {
+            return None;
+        }
+        Some(ConvertToFunctionDeclarationState {
+            name,
+            arrow_function,
+        })
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let statement = ctx.query();
+        let ConvertToFunctionDeclarationState {
+            name,
+            arrow_function,
+        } = state;
+        let leading_trivia = statement.syntax().first_leading_trivia()?;
+
+        let parameters = match arrow_function.parameters().ok()? {
+            AnyJsArrowFunctionParameters::JsParameters(parameters) => parameters,
+            // `value => value` has no parentheses
+            AnyJsArrowFunctionParameters::AnyJsBinding(binding) => make::js_parameters(
+                make::token(T!['(']),
+                make::js_parameter_list(
+                    [AnyJsParameter::AnyJsFormalParameter(
+                        AnyJsFormalParameter::JsFormalParameter(
+                            make::js_formal_parameter(
+                                make::js_decorator_list([]),
+                                AnyJsBindingPattern::AnyJsBinding(
+                                    binding.with_trailing_trivia_pieces(iter::empty())?,
+                                ),
+                            )
+                            .build(),
+                        ),
+                    )],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        };
+        let return_type_annotation = arrow_function.return_type_annotation();
+        let body = match arrow_function.body().ok()? {
+            AnyJsFunctionBody::JsFunctionBody(body) => body,
+            // `() => value` becomes `{ return value; }`
+            AnyJsFunctionBody::AnyJsExpression(expression) => make::js_function_body(
+                make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_directive_list([]),
+                make::js_statement_list([make::js_return_statement(
+                    make::token(T![return])
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                )
+                .with_argument(
+                    expression
+                        .with_leading_trivia_pieces(iter::empty())?
+                        .with_trailing_trivia_pieces(iter::empty())?,
+                )
+                .with_semicolon_token(
+                    make::token(T![;]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                )
+                .build()
+                .into()]),
+                make::token(T!['}']),
+            ),
+        };
+        let body =
+            body.with_trailing_trivia_pieces(statement.syntax().last_trailing_trivia()?.pieces())?;
+
+        let function_token =
+            make::token(T![function]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+        let mut function_builder = make::js_function_declaration(
+            if arrow_function.async_token().is_some() {
+                function_token
+            } else {
+                function_token.with_leading_trivia_pieces(leading_trivia.pieces())
+            },
+            name.clone().with_trailing_trivia_pieces(iter::empty())?,
+            if return_type_annotation.is_some() {
+                parameters.with_trailing_trivia_pieces(iter::empty())?
+            } else {
+                with_trailing_space(parameters)?
+            },
+            body,
+        );
+        if arrow_function.async_token().is_some() {
+            function_builder = function_builder.with_async_token(
+                make::token(T![async])
+                    .with_leading_trivia_pieces(leading_trivia.pieces())
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            );
+        }
+        if let Some(type_parameters) = arrow_function.type_parameters() {
+            function_builder = function_builder.with_type_parameters(type_parameters);
+        }
+        if let Some(return_type_annotation) = return_type_annotation {
+            function_builder = function_builder
+                .with_return_type_annotation(with_trailing_space(return_type_annotation)?);
+        }
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(
+            AnyJsStatement::from(statement.clone()),
+            function_builder.build().into(),
+        );
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Convert to a "<Emphasis>"function declaration"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, JsSyntaxKind, JsSyntaxToken, JsTemplateExpression,
+};
+use biome_rowan::BatchMutationExt;
+
+use crate::assists::refactor::toggle_quote_style::requote;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert a template literal without interpolation
+    /// to a string literal.
+    ///
+    /// The template literal must not be tagged, nor span multiple lines.
+    /// The string literal uses double quotes, unless the template literal contains
+    /// double quotes and no single quotes.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const greeting = `Hello`;
+    /// ```
+    pub(crate) ConvertToStringLiteral {
+        version: "next",
+        name: "convertToStringLiteral",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ConvertToStringLiteral {
+    type Query = Ast<JsTemplateExpression>;
+    /// The escaped content of the template literal
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let template = ctx.query();
+        if !template.is_constant() {
+            return None;
+        }
+        let content: String = template
+            .quasis()
+            .map(|chunk| chunk.text().to_string())
+            .collect();
+        // A string literal can't contain line breaks
+        (!content.contains(['\n', '\r'])).then_some(content)
+    }
+
+    fn action(ctx: &RuleContext<Self>, content: &Self::State) -> Option<JsRuleAction> {
+        let template = ctx.query();
+        let quote = if content.contains('"') && !content.contains('\'') {
+            '\''
+        } else {
+            '"'
+        };
+        let string = make::js_string_literal_expression(JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &format!("{quote}{}{quote}", requote(content, quote)),
+            [],
+            [],
+        ));
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::JsTemplateExpression(template.clone()),
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(string),
+            ),
+        );
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Convert to a "<Emphasis>"string literal"</Emphasis>"." }.to_owned(),
+            mutation,
+        })
+    }
+}
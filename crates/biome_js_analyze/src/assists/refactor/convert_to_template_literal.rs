@@ -0,0 +1,66 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsExpression, JsBinaryExpression};
+use biome_rowan::BatchMutationExt;
+
+use crate::analyzers::style::use_template::{
+    can_be_template_literal, is_in_concatenation, template_expression_from_binary_expression,
+};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert a string concatenation to a template literal.
+    ///
+    /// The concatenation must contain both a string and an expression that isn't a string.
+    /// It is the same conversion as the fix of the rule `useTemplate`, available even
+    /// when the rule is disabled.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const greeting = "Hello, " + name + "!";
+    /// ```
+    pub(crate) ConvertToTemplateLiteral {
+        version: "next",
+        name: "convertToTemplateLiteral",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ConvertToTemplateLiteral {
+    type Query = Ast<JsBinaryExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        // Only the whole concatenation is converted
+        if is_in_concatenation(node) {
+            return None;
+        }
+        can_be_template_literal(node)?.then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let template = template_expression_from_binary_expression(node)?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::JsBinaryExpression(node.clone()),
+            AnyJsExpression::JsTemplateExpression(template),
+        );
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Convert to a "<Emphasis>"template literal"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,305 @@
+use std::iter;
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsModuleItem, AnyJsOptionalChainExpression, AnyJsStatement,
+    JsAssignmentExpression, JsAssignmentOperator, JsCallExpression, JsClassExpression,
+    JsComputedMemberExpression, JsConditionalExpression, JsExpressionStatement,
+    JsInitializerClause, JsLogicalExpression, JsModuleItemList, JsStatementList, JsSyntaxKind,
+    JsSyntaxNode, JsTemplateExpression, JsUnaryExpression, JsUnaryOperator, JsVariableDeclarator,
+    TriviaPieceKind, T,
+};
+use biome_rowan::{
+    AstNode, AstNodeExt, AstNodeList, AstSeparatedList, BatchMutationExt, Direction,
+};
+
+use crate::control_flow::AnyJsControlFlowRoot;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to extract an expression to a constant, declared
+    /// right before the statement that contains the expression.
+    ///
+    /// The constant is named `extracted`, followed by a number when this name is
+    /// already used in the file. The extracted expression is evaluated before the
+    /// rest of its statement, which can change the order of the side effects.
+    ///
+    /// The expressions that aren't always evaluated by their statement can't be
+    /// extracted, e.g. the right operand of `&&`, the branches of a conditional
+    /// expression, or the body of an arrow function.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// function area(radius) {
+    ///     return Math.PI * radius ** 2;
+    /// }
+    /// ```
+    pub(crate) ExtractToConstant {
+        version: "next",
+        name: "extractToConstant",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ExtractToConstant {
+    type Query = Ast<AnyJsExpression>;
+    /// The statement that contains the expression
+    type State = AnyJsStatement;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let expression = ctx.query();
+        if !is_extractable(expression) {
+            return None;
+        }
+        enclosing_statement(expression)
+    }
+
+    fn action(ctx: &RuleContext<Self>, statement: &Self::State) -> Option<JsRuleAction> {
+        let expression = ctx.query();
+        let name = unused_name(ctx.root().syntax());
+
+        let leading_trivia = statement.syntax().first_leading_trivia()?;
+        let declaration = make::js_variable_statement(
+            make::js_variable_declaration(
+                make::token(T![const])
+                    .with_leading_trivia_pieces(leading_trivia.pieces())
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_variable_declarator_list(
+                    [make::js_variable_declarator(
+                        make::js_identifier_binding(make::ident(&name)).into(),
+                    )
+                    .with_initializer(make::js_initializer_clause(
+                        make::token_decorated_with_space(T![=]),
+                        expression
+                            .clone()
+                            .with_leading_trivia_pieces(iter::empty())?
+                            .with_trailing_trivia_pieces(iter::empty())?,
+                    ))
+                    .build()],
+                    [],
+                ),
+            )
+            .build(),
+        )
+        .with_semicolon_token(make::token(T![;]))
+        .build();
+
+        let reference =
+            make::js_identifier_expression(make::js_reference_identifier(make::ident(&name)));
+        let next_statement = statement
+            .clone()
+            .replace_node(expression.clone(), reference.into())?;
+
+        // The statement is moved to a new line, with the same indentation. It stays
+        // on the same line when it shares its line with the previous statement.
+        let pieces: Vec<_> = leading_trivia.pieces().collect();
+        let indentation = pieces
+            .iter()
+            .rposition(|piece| piece.is_newline())
+            .map(|index| {
+                pieces[index + 1..]
+                    .iter()
+                    .take_while(|piece| piece.is_whitespace())
+                    .map(|piece| piece.text())
+                    .collect::<String>()
+            });
+        let separator = match &indentation {
+            Some(indentation) if indentation.is_empty() => vec![(TriviaPieceKind::Newline, "\n")],
+            Some(indentation) => vec![
+                (TriviaPieceKind::Newline, "\n"),
+                (TriviaPieceKind::Whitespace, indentation.as_str()),
+            ],
+            None if statement.syntax().first_token()?.prev_token().is_some() => {
+                vec![(TriviaPieceKind::Whitespace, " ")]
+            }
+            None => vec![(TriviaPieceKind::Newline, "\n")],
+        };
+        let first_token = next_statement.syntax().first_token()?;
+        let next_statement = next_statement.replace_token_discard_trivia(
+            first_token.clone(),
+            first_token.with_leading_trivia(separator),
+        )?;
+
+        let mut mutation = ctx.root().begin();
+        let list = statement.syntax().parent()?;
+        if let Some(list) = JsStatementList::cast_ref(&list) {
+            let mut items = Vec::with_capacity(list.len() + 1);
+            for item in list.iter() {
+                if &item == statement {
+                    items.push(declaration.clone().into());
+                    items.push(next_statement.clone());
+                } else {
+                    items.push(item);
+                }
+            }
+            mutation.replace_node_discard_trivia(list, make::js_statement_list(items));
+        } else {
+            let list = JsModuleItemList::cast(list)?;
+            let mut items = Vec::with_capacity(list.len() + 1);
+            for item in list.iter() {
+                if item.syntax() == statement.syntax() {
+                    items.push(AnyJsModuleItem::AnyJsStatement(declaration.clone().into()));
+                    items.push(AnyJsModuleItem::AnyJsStatement(next_statement.clone()));
+                } else {
+                    items.push(item);
+                }
+            }
+            mutation.replace_node_discard_trivia(list, make::js_module_item_list(items));
+        }
+
+        let text = expression.syntax().text_trimmed().to_string();
+        let message = if text.len() <= 30 && !text.contains('\n') {
+            markup! { "Extract "<Emphasis>{text}</Emphasis>" to a constant." }.to_owned()
+        } else {
+            markup! { "Extract the expression to a constant." }.to_owned()
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Extract),
+            applicability: Applicability::MaybeIncorrect,
+            message,
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if extracting `expression` to a constant is both useful and
+/// doesn't change the meaning of its parent.
+fn is_extractable(expression: &AnyJsExpression) -> bool {
+    if matches!(
+        expression,
+        AnyJsExpression::JsBogusExpression(_)
+            | AnyJsExpression::JsIdentifierExpression(_)
+            | AnyJsExpression::JsImportMetaExpression(_)
+            | AnyJsExpression::JsNewTargetExpression(_)
+            | AnyJsExpression::JsParenthesizedExpression(_)
+            | AnyJsExpression::JsSequenceExpression(_)
+            | AnyJsExpression::JsSuperExpression(_)
+            | AnyJsExpression::JsThisExpression(_)
+    ) {
+        return false;
+    }
+    let Some(parent) = expression.syntax().parent() else {
+        return false;
+    };
+    if JsExpressionStatement::can_cast(parent.kind()) {
+        // The expression is the whole statement
+        return false;
+    }
+    if JsInitializerClause::cast_ref(&parent)
+        .is_some_and(|initializer| initializer.parent::<JsVariableDeclarator>().is_some())
+    {
+        // The expression is already the value of a variable
+        return false;
+    }
+    if let Some(call) = JsCallExpression::cast_ref(&parent) {
+        // `a.b()` calls `b` with `a` as `this`, `extracted()` doesn't
+        if call.callee().ok().as_ref() == Some(expression) {
+            return false;
+        }
+    }
+    if let Some(template) = JsTemplateExpression::cast_ref(&parent) {
+        if template.tag().as_ref() == Some(expression) {
+            return false;
+        }
+    }
+    if let Some(unary) = JsUnaryExpression::cast_ref(&parent) {
+        if unary.operator() == Ok(JsUnaryOperator::Delete) {
+            return false;
+        }
+    }
+    if let Some(chain) = AnyJsOptionalChainExpression::cast_ref(&parent) {
+        // `a?.b.c` doesn't evaluate `.c` when `a` is nullish, `extracted.c` does
+        if chain.is_optional_chain() && chain.object().ok().as_ref() == Some(expression) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns the statement that contains `expression`, when the expression is
+/// evaluated exactly once by the statement, and a constant can be declared
+/// before the statement.
+fn enclosing_statement(expression: &AnyJsExpression) -> Option<AnyJsStatement> {
+    let mut child = expression.syntax().clone();
+    for parent in expression.syntax().ancestors().skip(1) {
+        if let Some(statement) = AnyJsStatement::cast_ref(&parent) {
+            let is_supported = match &statement {
+                AnyJsStatement::JsExpressionStatement(_)
+                | AnyJsStatement::JsIfStatement(_)
+                | AnyJsStatement::JsReturnStatement(_)
+                | AnyJsStatement::JsThrowStatement(_) => true,
+                // The other declarators could be used by the expression
+                AnyJsStatement::JsVariableStatement(statement) => statement
+                    .declaration()
+                    .is_ok_and(|declaration| declaration.declarators().len() == 1),
+                _ => false,
+            };
+            let is_in_list = parent.parent().is_some_and(|list| {
+                JsStatementList::can_cast(list.kind()) || JsModuleItemList::can_cast(list.kind())
+            });
+            return (is_supported && is_in_list).then_some(statement);
+        }
+        if AnyJsControlFlowRoot::can_cast(parent.kind())
+            || JsClassExpression::can_cast(parent.kind())
+            || is_conditionally_evaluated(&parent, &child)
+        {
+            return None;
+        }
+        child = parent;
+    }
+    None
+}
+
+/// Returns `true` if `parent` doesn't always evaluate its child `child`
+fn is_conditionally_evaluated(parent: &JsSyntaxNode, child: &JsSyntaxNode) -> bool {
+    if let Some(logical) = JsLogicalExpression::cast_ref(parent) {
+        return logical.right().is_ok_and(|right| right.syntax() == child);
+    }
+    if let Some(conditional) = JsConditionalExpression::cast_ref(parent) {
+        return conditional.test().is_ok_and(|test| test.syntax() != child);
+    }
+    if let Some(assignment) = JsAssignmentExpression::cast_ref(parent) {
+        return matches!(
+            assignment.operator(),
+            Ok(JsAssignmentOperator::LogicalAndAssign
+                | JsAssignmentOperator::LogicalOrAssign
+                | JsAssignmentOperator::NullishCoalescingAssign)
+        ) && assignment
+            .right()
+            .is_ok_and(|right| right.syntax() == child);
+    }
+    if let Some(call) = JsCallExpression::cast_ref(parent) {
+        return call.is_optional_chain() && child.kind() == JsSyntaxKind::JS_CALL_ARGUMENTS;
+    }
+    if let Some(member) = JsComputedMemberExpression::cast_ref(parent) {
+        return member.is_optional_chain()
+            && member.member().is_ok_and(|member| member.syntax() == child);
+    }
+    // The default values of the parameters and the binding patterns
+    JsInitializerClause::can_cast(parent.kind())
+        && !parent
+            .parent()
+            .is_some_and(|declarator| JsVariableDeclarator::can_cast(declarator.kind()))
+}
+
+/// Returns `extracted`, or `extracted` followed by the first number that makes
+/// it unused in the file.
+fn unused_name(root: &JsSyntaxNode) -> String {
+    let is_used = |name: &str| {
+        root.descendants_tokens(Direction::Next)
+            .any(|token| token.text_trimmed() == name)
+    };
+    iter::once(String::from("extracted"))
+        .chain((1..).map(|index| format!("extracted{index}")))
+        .find(|name| !is_used(name))
+        .unwrap_or_default()
+}
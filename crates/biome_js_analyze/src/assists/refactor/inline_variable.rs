@@ -0,0 +1,205 @@
+use std::iter;
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, JsIdentifierBinding,
+    JsIdentifierExpression, JsModuleItemList, JsStatementList, JsSyntaxKind, JsVariableDeclaration,
+    JsVariableDeclarator, JsVariableDeclaratorList, JsVariableStatement,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+use crate::semantic_services::Semantic;
+use crate::utils::queries::{all_references, is_constant_expression};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to inline a variable: its references are replaced
+    /// with its initializer, and its declaration is removed.
+    ///
+    /// The variable must be a `const`, or a `let` that is never reassigned. It must be
+    /// the only variable of its declaration, and it must only be read after it.
+    /// A variable read more than once is inlined only when its initializer is a
+    /// constant, e.g. `60 * 1000`, or an identifier, so the side effects of the
+    /// initializer aren't repeated.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const total = price * quantity;
+    /// console.log(total);
+    /// ```
+    pub(crate) InlineVariable {
+        version: "next",
+        name: "inlineVariable",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub(crate) struct InlineVariableState {
+    /// The statement that declares the variable
+    statement: JsVariableStatement,
+    /// The value of the variable
+    initializer: AnyJsExpression,
+    /// The expressions that read the variable
+    references: Vec<JsIdentifierExpression>,
+}
+
+impl Rule for InlineVariable {
+    type Query = Semantic<JsVariableDeclarator>;
+    type State = InlineVariableState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declarator = ctx.query();
+        let model = ctx.model();
+        let id = declared_identifier(declarator)?;
+        if declarator.variable_annotation().is_some() {
+            // The type of the variable would be lost
+            return None;
+        }
+        let initializer = declarator.initializer()?.expression().ok()?;
+        let list = declarator.parent::<JsVariableDeclaratorList>()?;
+        if list.len() != 1 {
+            return None;
+        }
+        let declaration = list.parent::<JsVariableDeclaration>()?;
+        if !declaration.is_const() && !declaration.is_let() {
+            return None;
+        }
+        let statement = declaration.parent::<JsVariableStatement>()?;
+        let is_in_list = statement.syntax().parent().is_some_and(|list| {
+            JsStatementList::can_cast(list.kind()) || JsModuleItemList::can_cast(list.kind())
+        });
+        if !is_in_list {
+            return None;
+        }
+
+        let mut references = Vec::new();
+        for reference in all_references(ctx.query_cache(), &id, model).iter() {
+            if !reference.is_read()
+                || reference.syntax().text_trimmed_range().start() < statement.range().end()
+            {
+                return None;
+            }
+            // The shorthand properties and the JSX tags aren't supported
+            references.push(JsIdentifierExpression::cast(reference.syntax().parent()?)?);
+        }
+        let is_trivial = is_constant_expression(ctx.query_cache(), &initializer)
+            || matches!(initializer, AnyJsExpression::JsIdentifierExpression(_));
+        if references.is_empty() || (references.len() > 1 && !is_trivial) {
+            return None;
+        }
+
+        Some(InlineVariableState {
+            statement,
+            initializer,
+            references,
+        })
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let InlineVariableState {
+            statement,
+            initializer,
+            references,
+        } = state;
+        let id = declared_identifier(ctx.query())?;
+        let name = id.name_token().ok()?;
+        let mut mutation = ctx.root().begin();
+
+        // The next statement takes the place of the removed statement, unless it
+        // has its own comments
+        let leading_trivia = statement.syntax().first_leading_trivia()?;
+        let next_token = statement.syntax().last_token()?.next_token()?;
+        let replaces_leading_trivia = !next_token.has_leading_comments();
+        let mut is_next_token_replaced = false;
+
+        let value = initializer
+            .clone()
+            .with_leading_trivia_pieces(iter::empty())?
+            .with_trailing_trivia_pieces(iter::empty())?;
+        for reference in references {
+            let mut replacement = if needs_parentheses(&value, reference) {
+                make::parenthesized(value.clone()).into()
+            } else {
+                value.clone()
+            };
+            let first_token = reference.syntax().first_token()?;
+            let reference_leading_trivia = if first_token == next_token && replaces_leading_trivia {
+                is_next_token_replaced = true;
+                leading_trivia.clone()
+            } else {
+                first_token.leading_trivia()
+            };
+            replacement = replacement
+                .with_leading_trivia_pieces(reference_leading_trivia.pieces())?
+                .with_trailing_trivia_pieces(reference.syntax().last_trailing_trivia()?.pieces())?;
+            mutation
+                .replace_node_discard_trivia(AnyJsExpression::from(reference.clone()), replacement);
+        }
+        if replaces_leading_trivia && !is_next_token_replaced {
+            mutation.replace_token_discard_trivia(
+                next_token.clone(),
+                next_token.with_leading_trivia_pieces(leading_trivia.pieces()),
+            );
+        }
+        mutation.remove_node(statement.clone());
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Inline),
+            applicability: Applicability::MaybeIncorrect,
+            message:
+                markup! { "Inline the variable "<Emphasis>{name.text_trimmed()}</Emphasis>"." }
+                    .to_owned(),
+            mutation,
+        })
+    }
+}
+
+fn declared_identifier(declarator: &JsVariableDeclarator) -> Option<JsIdentifierBinding> {
+    match declarator.id().ok()? {
+        AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(id)) => Some(id),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `value` must be parenthesized to replace `reference`
+fn needs_parentheses(value: &AnyJsExpression, reference: &JsIdentifierExpression) -> bool {
+    let is_primary = matches!(
+        value,
+        AnyJsExpression::AnyJsLiteralExpression(_)
+            | AnyJsExpression::JsArrayExpression(_)
+            | AnyJsExpression::JsCallExpression(_)
+            | AnyJsExpression::JsComputedMemberExpression(_)
+            | AnyJsExpression::JsIdentifierExpression(_)
+            | AnyJsExpression::JsImportCallExpression(_)
+            | AnyJsExpression::JsImportMetaExpression(_)
+            | AnyJsExpression::JsParenthesizedExpression(_)
+            | AnyJsExpression::JsStaticMemberExpression(_)
+            | AnyJsExpression::JsTemplateExpression(_)
+            | AnyJsExpression::JsThisExpression(_)
+    );
+    // The other expressions don't need to be parenthesized when they are a whole
+    // argument, element, or value
+    !is_primary
+        && !reference.syntax().parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_ARRAY_ELEMENT_LIST
+                    | JsSyntaxKind::JS_CALL_ARGUMENT_LIST
+                    | JsSyntaxKind::JS_INITIALIZER_CLAUSE
+                    | JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER
+                    | JsSyntaxKind::JS_RETURN_STATEMENT
+                    | JsSyntaxKind::JS_TEMPLATE_ELEMENT
+                    | JsSyntaxKind::JSX_EXPRESSION_ATTRIBUTE_VALUE
+                    | JsSyntaxKind::JSX_EXPRESSION_CHILD
+            )
+        })
+}
@@ -0,0 +1,98 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{JsStringLiteralExpression, JsSyntaxKind, JsSyntaxToken};
+use biome_rowan::BatchMutationExt;
+
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to switch a string literal between single and double quotes.
+    ///
+    /// The quotes of the new style are escaped in the string, and the quotes of the
+    /// previous style are no longer escaped.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const message = "It's done";
+    /// ```
+    pub(crate) ToggleQuoteStyle {
+        version: "next",
+        name: "toggleQuoteStyle",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ToggleQuoteStyle {
+    type Query = Ast<JsStringLiteralExpression>;
+    /// The token of the string literal
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.query().value_token().ok()
+    }
+
+    fn action(ctx: &RuleContext<Self>, token: &Self::State) -> Option<JsRuleAction> {
+        let text = token.text_trimmed();
+        let quote = match text.chars().next()? {
+            '"' => '\'',
+            '\'' => '"',
+            _ => return None,
+        };
+        let content = text.get(1..text.len() - 1)?;
+        let new_token = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &format!("{quote}{}{quote}", requote(content, quote)),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(token.clone(), new_token);
+
+        let message = if quote == '"' {
+            markup! { "Use "<Emphasis>"double quotes"</Emphasis>"." }
+        } else {
+            markup! { "Use "<Emphasis>"single quotes"</Emphasis>"." }
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::MaybeIncorrect,
+            message: message.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the escaped `content` of a string literal or a template literal,
+/// escaped again for a string literal delimited by `quote`.
+///
+/// The escape sequences are kept, except the escaped quotes that no longer
+/// need to be escaped.
+pub(crate) fn requote(content: &str, quote: char) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next() {
+                Some(escaped @ ('\'' | '"' | '`')) if escaped != quote => result.push(escaped),
+                Some(escaped) => {
+                    result.push('\\');
+                    result.push(escaped);
+                }
+                None => result.push('\\'),
+            },
+            _ if char == quote => {
+                result.push('\\');
+                result.push(char);
+            }
+            _ => result.push(char),
+        }
+    }
+    result
+}
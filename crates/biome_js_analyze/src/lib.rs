@@ -1,6 +1,11 @@
 #![warn(clippy::needless_pass_by_value)]
 
 use crate::suppression_action::apply_suppression_comment;
+use crate::utils::browser_versions::BrowserTargets;
+use crate::utils::node_versions::NodeEngine;
+use crate::utils::packages::DeclaredPackages;
+use crate::utils::path_aliases::PathAliasPatterns;
+use crate::utils::test_frameworks::TestFile;
 use biome_analyze::{
     AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
     InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
@@ -8,8 +13,8 @@ use biome_analyze::{
 };
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Diagnostic, Error as DiagnosticError};
-use biome_js_syntax::suppression::SuppressionDiagnostic;
-use biome_js_syntax::{suppression::parse_suppression_comment, JsFileSource, JsLanguage};
+use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic, SuppressionScope};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::{borrow::Cow, error::Error};
@@ -74,6 +79,8 @@ where
 
         for comment in parse_suppression_comment(text) {
             let categories = match comment {
+                // The ranges of suppressed code aren't supported yet
+                Ok(comment) if comment.scope != SuppressionScope::Next => continue,
                 Ok(comment) => {
                     if comment.is_legacy {
                         result.push(Ok(SuppressionKind::Deprecated));
@@ -86,15 +93,15 @@ where
                 }
             };
 
-            for (key, value) in categories {
-                if key == category!("lint") {
-                    if let Some(value) = value {
+            for suppressed in categories {
+                if suppressed.category == category!("lint") {
+                    if let Some(value) = suppressed.value {
                         result.push(Ok(SuppressionKind::MaybeLegacy(value)));
                     } else {
                         result.push(Ok(SuppressionKind::Everything));
                     }
                 } else {
-                    let category = key.name();
+                    let category = suppressed.category.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
                         result.push(Ok(SuppressionKind::Rule(rule)));
                     }
@@ -130,6 +137,18 @@ where
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(AriaProperties));
     services.insert_service(source_type);
+    services.insert_service(PathAliasPatterns::new(
+        options.configuration.path_aliases.clone(),
+    ));
+    services.insert_service(DeclaredPackages::new(
+        options.configuration.declared_packages.clone(),
+        options.configuration.project_packages.clone(),
+    ));
+    services.insert_service(NodeEngine::new(options.configuration.node_engine.clone()));
+    services.insert_service(BrowserTargets::from_queries(
+        &options.configuration.browserslist,
+    ));
+    services.insert_service(TestFile::detect(root, &options.file_path));
     (
         analyzer.run(AnalyzerContext {
             root: root.clone(),
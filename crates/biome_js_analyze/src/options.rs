@@ -3,12 +3,40 @@
 use crate::analyzers::complexity::no_excessive_cognitive_complexity::{
     complexity_options, ComplexityOptions,
 };
+use crate::analyzers::nursery::no_excessive_file_length::{
+    no_excessive_file_length_options, NoExcessiveFileLengthOptions,
+};
+use crate::analyzers::nursery::no_excessive_params::{
+    no_excessive_params_options, NoExcessiveParamsOptions,
+};
+use crate::analyzers::nursery::no_excessively_long_functions::{
+    no_excessively_long_functions_options, NoExcessivelyLongFunctionsOptions,
+};
+use crate::analyzers::nursery::no_jsx_literal_text::{
+    no_jsx_literal_text_options, NoJsxLiteralTextOptions,
+};
+use crate::analyzers::nursery::no_undeclared_dependencies::{
+    no_undeclared_dependencies_options, NoUndeclaredDependenciesOptions,
+};
+use crate::analyzers::nursery::use_await::{use_await_options, UseAwaitOptions};
+use crate::analyzers::nursery::use_consistent_array_type::{
+    use_consistent_array_type_options, UseConsistentArrayTypeOptions,
+};
+use crate::analyzers::nursery::use_consistent_record_type::{
+    use_consistent_record_type_options, UseConsistentRecordTypeOptions,
+};
 use crate::aria_analyzers::nursery::use_valid_aria_role::{
     valid_aria_role_options, ValidAriaRoleOptions,
 };
 use crate::semantic_analyzers::correctness::use_exhaustive_dependencies::{
     hooks_options, HooksOptions,
 };
+use crate::semantic_analyzers::nursery::no_unsupported_browser_apis::{
+    no_unsupported_browser_apis_options, NoUnsupportedBrowserApisOptions,
+};
+use crate::semantic_analyzers::nursery::no_unsupported_node_builtins::{
+    no_unsupported_node_builtins_options, NoUnsupportedNodeBuiltinsOptions,
+};
 use crate::semantic_analyzers::style::no_restricted_globals::{
     restricted_globals_options, RestrictedGlobalsOptions,
 };
@@ -35,8 +63,47 @@ pub enum PossibleOptions {
     Hooks(#[bpaf(external(hooks_options), hide)] HooksOptions),
     /// Options for `useNamingConvention` rule
     NamingConvention(#[bpaf(external(naming_convention_options), hide)] NamingConventionOptions),
+    /// Options for `noExcessiveFileLength` rule
+    NoExcessiveFileLength(
+        #[bpaf(external(no_excessive_file_length_options), hide)] NoExcessiveFileLengthOptions,
+    ),
+    /// Options for `noExcessiveParams` rule
+    NoExcessiveParams(
+        #[bpaf(external(no_excessive_params_options), hide)] NoExcessiveParamsOptions,
+    ),
+    /// Options for `noExcessivelyLongFunctions` rule
+    NoExcessivelyLongFunctions(
+        #[bpaf(external(no_excessively_long_functions_options), hide)]
+        NoExcessivelyLongFunctionsOptions,
+    ),
+    /// Options for `noJsxLiteralText` rule
+    NoJsxLiteralText(#[bpaf(external(no_jsx_literal_text_options), hide)] NoJsxLiteralTextOptions),
+    /// Options for `noUndeclaredDependencies` rule
+    NoUndeclaredDependencies(
+        #[bpaf(external(no_undeclared_dependencies_options), hide)] NoUndeclaredDependenciesOptions,
+    ),
+    /// Options for `noUnsupportedBrowserApis` rule
+    NoUnsupportedBrowserApis(
+        #[bpaf(external(no_unsupported_browser_apis_options), hide)]
+        NoUnsupportedBrowserApisOptions,
+    ),
+    /// Options for `noUnsupportedNodeBuiltins` rule
+    NoUnsupportedNodeBuiltins(
+        #[bpaf(external(no_unsupported_node_builtins_options), hide)]
+        NoUnsupportedNodeBuiltinsOptions,
+    ),
     /// Options for `noRestrictedGlobals` rule
     RestrictedGlobals(#[bpaf(external(restricted_globals_options), hide)] RestrictedGlobalsOptions),
+    /// Options for `useAwait` rule
+    UseAwait(#[bpaf(external(use_await_options), hide)] UseAwaitOptions),
+    /// Options for `useConsistentArrayType` rule
+    UseConsistentArrayType(
+        #[bpaf(external(use_consistent_array_type_options), hide)] UseConsistentArrayTypeOptions,
+    ),
+    /// Options for `useConsistentRecordType` rule
+    UseConsistentRecordType(
+        #[bpaf(external(use_consistent_record_type_options), hide)] UseConsistentRecordTypeOptions,
+    ),
     /// Options for `useValidAriaRole` rule
     ValidAriaRole(#[bpaf(external(valid_aria_role_options), hide)] ValidAriaRoleOptions),
 }
@@ -74,6 +141,55 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            "noExcessiveFileLength" => {
+                let options = match self {
+                    PossibleOptions::NoExcessiveFileLength(options) => options.clone(),
+                    _ => NoExcessiveFileLengthOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noExcessiveParams" => {
+                let options = match self {
+                    PossibleOptions::NoExcessiveParams(options) => options.clone(),
+                    _ => NoExcessiveParamsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noExcessivelyLongFunctions" => {
+                let options = match self {
+                    PossibleOptions::NoExcessivelyLongFunctions(options) => options.clone(),
+                    _ => NoExcessivelyLongFunctionsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noJsxLiteralText" => {
+                let options = match self {
+                    PossibleOptions::NoJsxLiteralText(options) => options.clone(),
+                    _ => NoJsxLiteralTextOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noUndeclaredDependencies" => {
+                let options = match self {
+                    PossibleOptions::NoUndeclaredDependencies(options) => options.clone(),
+                    _ => NoUndeclaredDependenciesOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noUnsupportedBrowserApis" => {
+                let options = match self {
+                    PossibleOptions::NoUnsupportedBrowserApis(options) => options.clone(),
+                    _ => NoUnsupportedBrowserApisOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noUnsupportedNodeBuiltins" => {
+                let options = match self {
+                    PossibleOptions::NoUnsupportedNodeBuiltins(options) => options.clone(),
+                    _ => NoUnsupportedNodeBuiltinsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
             "noRestrictedGlobals" => {
                 let options = match self {
                     PossibleOptions::RestrictedGlobals(options) => options.clone(),
@@ -81,6 +197,27 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            "useAwait" => {
+                let options = match self {
+                    PossibleOptions::UseAwait(options) => options.clone(),
+                    _ => UseAwaitOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useConsistentArrayType" => {
+                let options = match self {
+                    PossibleOptions::UseConsistentArrayType(options) => options.clone(),
+                    _ => UseConsistentArrayTypeOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useConsistentRecordType" => {
+                let options = match self {
+                    PossibleOptions::UseConsistentRecordType(options) => options.clone(),
+                    _ => UseConsistentRecordTypeOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
             "useValidAriaRole" => {
                 let options = match self {
                     PossibleOptions::ValidAriaRole(options) => options.clone(),
@@ -94,6 +231,37 @@ impl PossibleOptions {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl PossibleOptions {
+    /// Returns the JSON schema of the options of a rule, `None` when the rule doesn't accept
+    /// any options
+    pub fn json_schema(rule_name: &str) -> Option<schemars::schema::RootSchema> {
+        let schema = match rule_name {
+            "noExcessiveCognitiveComplexity" => schemars::schema_for!(ComplexityOptions),
+            "noExcessiveFileLength" => schemars::schema_for!(NoExcessiveFileLengthOptions),
+            "noExcessiveParams" => schemars::schema_for!(NoExcessiveParamsOptions),
+            "noExcessivelyLongFunctions" => {
+                schemars::schema_for!(NoExcessivelyLongFunctionsOptions)
+            }
+            "noJsxLiteralText" => schemars::schema_for!(NoJsxLiteralTextOptions),
+            "noRestrictedGlobals" => schemars::schema_for!(RestrictedGlobalsOptions),
+            "noUndeclaredDependencies" => schemars::schema_for!(NoUndeclaredDependenciesOptions),
+            "noUnsupportedBrowserApis" => schemars::schema_for!(NoUnsupportedBrowserApisOptions),
+            "noUnsupportedNodeBuiltins" => schemars::schema_for!(NoUnsupportedNodeBuiltinsOptions),
+            "useAwait" => schemars::schema_for!(UseAwaitOptions),
+            "useConsistentArrayType" => schemars::schema_for!(UseConsistentArrayTypeOptions),
+            "useConsistentRecordType" => schemars::schema_for!(UseConsistentRecordTypeOptions),
+            "useExhaustiveDependencies" | "useHookAtTopLevel" => {
+                schemars::schema_for!(HooksOptions)
+            }
+            "useNamingConvention" => schemars::schema_for!(NamingConventionOptions),
+            "useValidAriaRole" => schemars::schema_for!(ValidAriaRoleOptions),
+            _ => return None,
+        };
+        Some(schema)
+    }
+}
+
 impl Deserializable for PossibleOptions {
     fn deserialize(
         value: &impl DeserializableValue,
@@ -104,8 +272,37 @@ impl Deserializable for PossibleOptions {
             "noExcessiveCognitiveComplexity" => {
                 Deserializable::deserialize(value, "options", diagnostics).map(Self::Complexity)
             }
+            "noExcessiveFileLength" => Deserializable::deserialize(value, "options", diagnostics)
+                .map(Self::NoExcessiveFileLength),
+            "noExcessiveParams" => Deserializable::deserialize(value, "options", diagnostics)
+                .map(Self::NoExcessiveParams),
+            "noExcessivelyLongFunctions" => {
+                Deserializable::deserialize(value, "options", diagnostics)
+                    .map(Self::NoExcessivelyLongFunctions)
+            }
+            "noJsxLiteralText" => Deserializable::deserialize(value, "options", diagnostics)
+                .map(Self::NoJsxLiteralText),
             "noRestrictedGlobals" => Deserializable::deserialize(value, "options", diagnostics)
                 .map(Self::RestrictedGlobals),
+            "noUndeclaredDependencies" => {
+                Deserializable::deserialize(value, "options", diagnostics)
+                    .map(Self::NoUndeclaredDependencies)
+            }
+            "noUnsupportedBrowserApis" => {
+                Deserializable::deserialize(value, "options", diagnostics)
+                    .map(Self::NoUnsupportedBrowserApis)
+            }
+            "noUnsupportedNodeBuiltins" => {
+                Deserializable::deserialize(value, "options", diagnostics)
+                    .map(Self::NoUnsupportedNodeBuiltins)
+            }
+            "useAwait" => {
+                Deserializable::deserialize(value, "options", diagnostics).map(Self::UseAwait)
+            }
+            "useConsistentArrayType" => Deserializable::deserialize(value, "options", diagnostics)
+                .map(Self::UseConsistentArrayType),
+            "useConsistentRecordType" => Deserializable::deserialize(value, "options", diagnostics)
+                .map(Self::UseConsistentRecordType),
             "useExhaustiveDependencies" | "useHookAtTopLevel" => {
                 Deserializable::deserialize(value, "options", diagnostics).map(Self::Hooks)
             }
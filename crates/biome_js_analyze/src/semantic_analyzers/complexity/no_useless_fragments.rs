@@ -1,5 +1,5 @@
-use crate::react::{jsx_member_name_is_react_fragment, jsx_reference_identifier_is_fragment};
 use crate::semantic_services::Semantic;
+use crate::utils::queries::is_react_fragment;
 use crate::JsRuleAction;
 use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic};
@@ -9,8 +9,8 @@ use biome_js_factory::make::{
     ident, js_expression_statement, js_string_literal_expression, jsx_string, jsx_tag_expression,
 };
 use biome_js_syntax::{
-    AnyJsxChild, AnyJsxElementName, AnyJsxTag, JsLanguage, JsParenthesizedExpression, JsSyntaxKind,
-    JsxChildList, JsxElement, JsxExpressionAttributeValue, JsxFragment, JsxTagExpression,
+    AnyJsxChild, AnyJsxTag, JsLanguage, JsParenthesizedExpression, JsSyntaxKind, JsxChildList,
+    JsxElement, JsxExpressionAttributeValue, JsxFragment, JsxTagExpression,
 };
 use biome_rowan::{declare_node_union, AstNode, AstNodeList, BatchMutation, BatchMutationExt};
 
@@ -158,15 +158,7 @@ impl Rule for NoUselessFragments {
                 let opening_element = element.opening_element().ok()?;
                 let name = opening_element.name().ok()?;
 
-                let is_valid_react_fragment = match name {
-                    AnyJsxElementName::JsxMemberName(member_name) => {
-                        jsx_member_name_is_react_fragment(&member_name, model)?
-                    }
-                    AnyJsxElementName::JsxReferenceIdentifier(identifier) => {
-                        jsx_reference_identifier_is_fragment(&identifier, model)?
-                    }
-                    AnyJsxElementName::JsxName(_) | AnyJsxElementName::JsxNamespaceName(_) => false,
-                };
+                let is_valid_react_fragment = is_react_fragment(ctx.query_cache(), &name, model)?;
 
                 if is_valid_react_fragment {
                     let child_list = element.children();
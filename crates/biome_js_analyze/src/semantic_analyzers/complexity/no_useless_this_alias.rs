@@ -1,3 +1,4 @@
+use crate::utils::queries::all_references;
 use crate::{control_flow::AnyJsControlFlowRoot, semantic_services::Semantic, JsRuleAction};
 use biome_analyze::{
     context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
@@ -5,7 +6,6 @@ use biome_analyze::{
 use biome_console::markup;
 use biome_diagnostics::Applicability;
 use biome_js_factory::make;
-use biome_js_semantic::ReferencesExtensions;
 use biome_js_syntax::{
     AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, JsArrowFunctionExpression,
     JsAssignmentExpression, JsExpressionStatement, JsIdentifierBinding, JsIdentifierExpression,
@@ -98,7 +98,7 @@ impl Rule for NoUselessThisAlias {
         if !is_this_alias {
             return None;
         }
-        for reference in id.all_references(model) {
+        for reference in all_references(ctx.query_cache(), &id, model).iter() {
             let current_this_scope = reference
                 .syntax()
                 .ancestors()
@@ -1,3 +1,4 @@
+use crate::utils::queries::all_references;
 use crate::JsRuleAction;
 use crate::{semantic_services::Semantic, utils::rename::RenameSymbolExtensions};
 use biome_analyze::{
@@ -5,7 +6,6 @@ use biome_analyze::{
 };
 use biome_console::markup;
 use biome_diagnostics::Applicability;
-use biome_js_semantic::ReferencesExtensions;
 use biome_js_syntax::binding_ext::{
     AnyJsBindingDeclaration, AnyJsIdentifierBinding, JsAnyParameterParentFunction,
 };
@@ -286,8 +286,8 @@ impl Rule for NoUnusedVariables {
         // We need to check if all uses of this binding are somehow recursive or unused
         let declaration = binding.declaration()?;
         let declaration = declaration.syntax();
-        binding
-            .all_references(model)
+        all_references(ctx.query_cache(), binding, model)
+            .iter()
             .filter_map(|reference| {
                 let ref_parent = reference.syntax().parent()?;
                 if reference.is_write() {
@@ -2,12 +2,24 @@
 
 use biome_analyze::declare_group;
 
+pub(crate) mod no_deprecated_usage;
+pub(crate) mod no_dynamic_function_constructor;
+pub(crate) mod no_hooks_in_server_components;
+pub(crate) mod no_misused_promises;
+pub(crate) mod no_unsupported_browser_apis;
+pub(crate) mod no_unsupported_node_builtins;
 pub(crate) mod no_unused_imports;
 
 declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_deprecated_usage :: NoDeprecatedUsage ,
+            self :: no_dynamic_function_constructor :: NoDynamicFunctionConstructor ,
+            self :: no_hooks_in_server_components :: NoHooksInServerComponents ,
+            self :: no_misused_promises :: NoMisusedPromises ,
+            self :: no_unsupported_browser_apis :: NoUnsupportedBrowserApis ,
+            self :: no_unsupported_node_builtins :: NoUnsupportedNodeBuiltins ,
             self :: no_unused_imports :: NoUnusedImports ,
         ]
      }
@@ -0,0 +1,136 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::jsdoc::JsDoc;
+use biome_js_syntax::{AnyJsExpression, JsCallExpression, JsNewExpression, TextRange};
+use biome_rowan::{declare_node_union, AstNode};
+
+declare_rule! {
+    /// Disallow calling the functions and the classes marked with the JSDoc tag `@deprecated`.
+    ///
+    /// A deprecated API is kept for compatibility, and is usually removed in a later version.
+    /// The rule reports the calls, and the instantiations with `new`, of the functions, the
+    /// classes and the variables declared in the same file whose JSDoc comment has a
+    /// `@deprecated` tag. The text of the tag, which usually names the replacement, is shown
+    /// with the diagnostic.
+    ///
+    /// The calls inside the deprecated declaration itself, e.g. the recursive calls, aren't
+    /// reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @deprecated Use `fetchUser` instead.
+    ///  */
+    /// function getUser(id) {}
+    ///
+    /// getUser(1);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /** @deprecated */
+    /// class Cache {}
+    ///
+    /// new Cache();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /**
+    ///  * @param {number} id
+    ///  */
+    /// function fetchUser(id) {}
+    ///
+    /// fetchUser(1);
+    /// ```
+    ///
+    pub(crate) NoDeprecatedUsage {
+        version: "next",
+        name: "noDeprecatedUsage",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) JsNewOrCallExpression = JsNewExpression | JsCallExpression
+}
+
+pub(crate) struct DeprecatedUsage {
+    name: String,
+    /// The range of the callee
+    range: TextRange,
+    /// The range of the `@deprecated` tag
+    tag_range: TextRange,
+    /// The text of the `@deprecated` tag
+    reason: String,
+}
+
+impl Rule for NoDeprecatedUsage {
+    type Query = Semantic<JsNewOrCallExpression>;
+    type State = DeprecatedUsage;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let callee = match node {
+            JsNewOrCallExpression::JsNewExpression(node) => node.callee().ok()?,
+            JsNewOrCallExpression::JsCallExpression(node) => node.callee().ok()?,
+        };
+        let AnyJsExpression::JsIdentifierExpression(identifier) = callee.omit_parentheses() else {
+            return None;
+        };
+        let reference = identifier.name().ok()?;
+        let declaration = ctx.model().binding(&reference)?.tree().declaration()?;
+        if !matches!(
+            declaration,
+            AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+                | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+                | AnyJsBindingDeclaration::JsClassDeclaration(_)
+                | AnyJsBindingDeclaration::JsVariableDeclarator(_)
+                | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+                | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_)
+        ) || declaration.range().contains_range(node.range())
+        {
+            return None;
+        }
+
+        let doc = JsDoc::for_node(declaration.syntax())?;
+        let tag = doc.deprecated()?;
+        Some(DeprecatedUsage {
+            name: reference.value_token().ok()?.text_trimmed().to_string(),
+            range: identifier.range(),
+            tag_range: tag.range,
+            reason: tag.description.clone(),
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                <Emphasis>{name}</Emphasis>" is deprecated."
+            },
+        )
+        .detail(
+            state.tag_range,
+            markup! {
+                "The declaration is marked as deprecated here."
+            },
+        );
+        if !state.reason.is_empty() {
+            let reason = &state.reason;
+            diagnostic = diagnostic.note(markup! {
+                "The deprecation says: "{reason}
+            });
+        }
+        Some(diagnostic)
+    }
+}
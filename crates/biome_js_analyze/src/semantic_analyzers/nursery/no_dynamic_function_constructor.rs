@@ -0,0 +1,113 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    global_identifier, AnyJsCallArgument, JsCallArguments, JsCallExpression, JsNewExpression,
+    TextRange,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow calling the `Function` constructor with arguments that aren't literals.
+    ///
+    /// The `Function` constructor evaluates the code passed as its arguments, like `eval`. When
+    /// the code is built from values computed at runtime, a value that comes from the users can
+    /// run arbitrary code, and the code can't be analyzed by the tools.
+    ///
+    /// The rule reports the first argument of `new Function()` or `Function()` that isn't a
+    /// literal string, e.g. a variable, a concatenation or a template with substitutions. The
+    /// constructor accessed through `globalThis` or `window` is also checked, unless `Function`
+    /// is shadowed by a declaration of the file.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-new-func
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const handler = new Function("event", body);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const getter = Function(`return this.${property}`);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const add = new Function("a", "b", "return a + b");
+    /// ```
+    ///
+    /// ```js
+    /// function Function(code) {}
+    /// Function(code);
+    /// ```
+    ///
+    pub(crate) NoDynamicFunctionConstructor {
+        version: "next",
+        name: "noDynamicFunctionConstructor",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) JsNewOrCallExpression = JsNewExpression | JsCallExpression
+}
+
+impl Rule for NoDynamicFunctionConstructor {
+    type Query = Semantic<JsNewOrCallExpression>;
+    /// The range of the first argument that isn't a literal
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let model = ctx.model();
+        let (callee, arguments) = match node {
+            JsNewOrCallExpression::JsNewExpression(node) => (node.callee().ok()?, node.arguments()),
+            JsNewOrCallExpression::JsCallExpression(node) => {
+                (node.callee().ok()?, node.arguments().ok())
+            }
+        };
+        let (reference, name) = global_identifier(&callee.omit_parentheses())?;
+        if name.text() != "Function" || model.binding(&reference).is_some() {
+            return None;
+        }
+        find_dynamic_argument(&arguments?)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The code passed to the "<Emphasis>"Function"</Emphasis>" constructor is built at runtime."
+                },
+            )
+            .note(markup! {
+                "The constructor evaluates its arguments like "<Emphasis>"eval"</Emphasis>", so a value that comes from the users can run arbitrary code."
+            })
+            .note(markup! {
+                "Declare the function in the code instead, and pass the values as its arguments."
+            }),
+        )
+    }
+}
+
+/// Returns the range of the first argument that isn't a literal, including the spread arguments
+fn find_dynamic_argument(arguments: &JsCallArguments) -> Option<TextRange> {
+    arguments
+        .args()
+        .iter()
+        .flatten()
+        .find(|argument| match argument {
+            AnyJsCallArgument::AnyJsExpression(expression) => {
+                expression.omit_parentheses().as_static_value().is_none()
+            }
+            AnyJsCallArgument::JsSpread(_) => true,
+        })
+        .map(|argument| argument.range())
+}
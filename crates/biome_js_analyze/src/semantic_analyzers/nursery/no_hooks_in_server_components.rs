@@ -0,0 +1,165 @@
+use crate::react::{is_react_call_api, ReactLibrary};
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsExpression, AnyJsMemberExpression, JsCallExpression, TextRange};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow the hooks of React that need a client in the Server Components.
+    ///
+    /// With the React Server Components, e.g. in the `app` directory of Next.js, a module is a
+    /// Server Component unless its directive prologue contains `"use client"`. A Server Component
+    /// renders once on the server, so it can't have a state, effects nor read a context: the hooks
+    /// `useState`, `useReducer`, `useEffect`, `useLayoutEffect`, `useInsertionEffect`, `useRef`,
+    /// `useContext`, `useTransition`, `useDeferredValue`, `useImperativeHandle` and
+    /// `useSyncExternalStore` fail when they are called.
+    ///
+    /// The rule reports the calls of these hooks, imported from `react`, in the modules without
+    /// the `"use client"` directive. The hooks that the Server Components can call, e.g. `useId`
+    /// or `useMemo`, aren't reported.
+    ///
+    /// Every module without the directive is a Server Component for the rule, so it should only
+    /// be enabled for the files of the framework that renders the Server Components, e.g. with
+    /// the `overrides`:
+    ///
+    /// ```json
+    /// {
+    ///     "overrides": [{
+    ///         "include": ["app/**"],
+    ///         "linter": {
+    ///             "rules": {
+    ///                 "nursery": {
+    ///                     "noHooksInServerComponents": "error"
+    ///                 }
+    ///             }
+    ///         }
+    ///     }]
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useState } from "react";
+    ///
+    /// export default function Counter() {
+    ///     const [count, setCount] = useState(0);
+    ///     return <p>{count}</p>;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// "use client";
+    /// import { useState } from "react";
+    ///
+    /// export default function Counter() {
+    ///     const [count, setCount] = useState(0);
+    ///     return <p>{count}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// import { useId } from "react";
+    ///
+    /// export default function Field() {
+    ///     const id = useId();
+    ///     return <input id={id} />;
+    /// }
+    /// ```
+    ///
+    pub(crate) NoHooksInServerComponents {
+        version: "next",
+        name: "noHooksInServerComponents",
+        recommended: false,
+    }
+}
+
+/// The hooks of React that aren't available in the Server Components
+const CLIENT_HOOKS: [&str; 11] = [
+    "useContext",
+    "useDeferredValue",
+    "useEffect",
+    "useImperativeHandle",
+    "useInsertionEffect",
+    "useLayoutEffect",
+    "useReducer",
+    "useRef",
+    "useState",
+    "useSyncExternalStore",
+    "useTransition",
+];
+
+/// Returns the hook of [CLIENT_HOOKS] named `name`
+fn client_hook(name: &str) -> Option<&'static str> {
+    CLIENT_HOOKS
+        .binary_search(&name)
+        .ok()
+        .map(|index| CLIENT_HOOKS[index])
+}
+
+pub(crate) struct ClientHookCall {
+    name: &'static str,
+    /// The range of the callee
+    range: TextRange,
+}
+
+impl Rule for NoHooksInServerComponents {
+    type Query = Semantic<JsCallExpression>;
+    type State = ClientHookCall;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let callee = ctx.query().callee().ok()?;
+        let name = match callee.omit_parentheses() {
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                client_hook(identifier.name().ok()?.value_token().ok()?.text_trimmed())
+            }
+            expression => client_hook(
+                AnyJsMemberExpression::cast_ref(expression.syntax())?
+                    .member_name()?
+                    .text(),
+            ),
+        }?;
+
+        // The directive makes the module, and the modules that it imports, client modules
+        let is_client_module = ctx
+            .root()
+            .directives()
+            .is_some_and(|directives| directives.contains("use client"));
+        if is_client_module
+            || !is_react_call_api(callee.clone(), ctx.model(), ReactLibrary::React, name)
+        {
+            return None;
+        }
+
+        Some(ClientHookCall {
+            name,
+            range: callee.range(),
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The hook "<Emphasis>{name}</Emphasis>" can't be called in a Server Component."
+                },
+            )
+            .note(markup! {
+                "A Server Component renders once on the server, so it can't have a state, effects nor read a context."
+            })
+            .note(markup! {
+                "Add the "<Emphasis>"\"use client\""</Emphasis>" directive at the top of the module to make it a Client Component, or move the hook to a Client Component."
+            }),
+        )
+    }
+}
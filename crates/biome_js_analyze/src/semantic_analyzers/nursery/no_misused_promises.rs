@@ -0,0 +1,419 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, global_identifier, static_value::StaticValue,
+    AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression, JsCallExpression,
+    JsConditionalExpression, JsDoWhileStatement, JsForStatement, JsIfStatement,
+    JsLogicalExpression, JsLogicalOperator, JsParenthesizedExpression, JsReferenceIdentifier,
+    JsSyntaxNode, JsUnaryExpression, JsUnaryOperator, JsWhileStatement,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange};
+
+declare_rule! {
+    /// Disallow promises in places that aren't designed to handle them.
+    ///
+    /// A promise is an object, so it's always truthy: using a promise as a condition
+    /// tests whether the promise exists, not the value it resolves to. This is almost
+    /// always a forgotten `await`.
+    ///
+    /// Likewise, an `async` function passed to an API that ignores the return value of
+    /// its callback, such as `Array.prototype.forEach`, returns a promise that is never
+    /// awaited: the callbacks don't run one after the other, and their rejections are
+    /// unhandled. An `async` function passed as a predicate, e.g. to `Array.prototype.filter`,
+    /// always returns a truthy value.
+    ///
+    /// The rule doesn't have access to types: it recognizes the expressions that
+    /// evaluate to a promise from their shape. This includes `new Promise(...)`, the static
+    /// methods of `Promise`, calls to `then`, `catch` and `finally`, calls to `fetch`, calls
+    /// to `async` functions declared in the file, and constants initialized with one of
+    /// these expressions.
+    ///
+    /// Source: [no-misused-promises](https://typescript-eslint.io/rules/no-misused-promises)
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const promise = Promise.resolve(true);
+    /// if (promise) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function isReady() {
+    ///     return true;
+    /// }
+    /// while (!isReady()) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const response = fetch("/data") && process();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// items.forEach(async (item) => {
+    ///     await save(item);
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const available = items.filter(async (item) => await isAvailable(item));
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const promise = Promise.resolve(true);
+    /// if (await promise) {}
+    /// ```
+    ///
+    /// ```js
+    /// for (const item of items) {
+    ///     await save(item);
+    /// }
+    /// await Promise.all(items.map(async (item) => await save(item)));
+    /// ```
+    ///
+    pub(crate) NoMisusedPromises {
+        version: "next",
+        name: "noMisusedPromises",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) NoMisusedPromisesQuery =
+        JsIfStatement
+        | JsWhileStatement
+        | JsDoWhileStatement
+        | JsForStatement
+        | JsConditionalExpression
+        | JsLogicalExpression
+        | JsUnaryExpression
+        | JsCallExpression
+}
+
+pub(crate) enum MisusedPromise {
+    /// A promise is used as a condition
+    Condition(TextRange),
+    /// An `async` function is passed to a method that ignores the return value of its callback
+    VoidCallback {
+        range: TextRange,
+        method: StaticValue,
+    },
+    /// An `async` function is passed to a method that expects a predicate
+    Predicate {
+        range: TextRange,
+        method: StaticValue,
+    },
+}
+
+/// The methods of arrays that ignore the return value of their callback
+const VOID_CALLBACK_METHODS: &[&str] = &["forEach"];
+
+/// The methods of arrays that use the return value of their callback as a condition
+const PREDICATE_METHODS: &[&str] = &[
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "some",
+];
+
+/// The static methods of `Promise` that return a promise
+const PROMISE_STATIC_METHODS: &[&str] = &["all", "allSettled", "any", "race", "reject", "resolve"];
+
+/// The methods of a promise that return a promise
+const PROMISE_METHODS: &[&str] = &["catch", "finally", "then"];
+
+/// The number of constants that are followed to find the initializer of an identifier
+const MAX_REFERENCE_DEPTH: u8 = 3;
+
+impl Rule for NoMisusedPromises {
+    type Query = Semantic<NoMisusedPromisesQuery>;
+    type State = MisusedPromise;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let mut conditions = Vec::new();
+        match ctx.query() {
+            NoMisusedPromisesQuery::JsIfStatement(statement) => {
+                if let Ok(test) = statement.test() {
+                    collect_conditions(&test, true, &mut conditions);
+                }
+            }
+            NoMisusedPromisesQuery::JsWhileStatement(statement) => {
+                if let Ok(test) = statement.test() {
+                    collect_conditions(&test, true, &mut conditions);
+                }
+            }
+            NoMisusedPromisesQuery::JsDoWhileStatement(statement) => {
+                if let Ok(test) = statement.test() {
+                    collect_conditions(&test, true, &mut conditions);
+                }
+            }
+            NoMisusedPromisesQuery::JsForStatement(statement) => {
+                if let Some(test) = statement.test() {
+                    collect_conditions(&test, true, &mut conditions);
+                }
+            }
+            NoMisusedPromisesQuery::JsConditionalExpression(expression) => {
+                if let Ok(test) = expression.test() {
+                    collect_conditions(&test, true, &mut conditions);
+                }
+            }
+            NoMisusedPromisesQuery::JsLogicalExpression(expression) => {
+                if is_outermost_condition(expression.syntax()) {
+                    collect_conditions(&expression.clone().into(), false, &mut conditions);
+                }
+            }
+            NoMisusedPromisesQuery::JsUnaryExpression(expression) => {
+                if expression.operator() == Ok(JsUnaryOperator::LogicalNot)
+                    && is_outermost_condition(expression.syntax())
+                {
+                    collect_conditions(&expression.clone().into(), false, &mut conditions);
+                }
+            }
+            NoMisusedPromisesQuery::JsCallExpression(call) => {
+                return misused_callback(call, model).into_iter().collect();
+            }
+        }
+        conditions
+            .into_iter()
+            .filter(|condition| is_promise(condition, model, 0))
+            .map(|condition| MisusedPromise::Condition(condition.range()))
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            MisusedPromise::Condition(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This promise is used as a condition, but a promise is always truthy."
+                },
+            )
+            .note(markup! {
+                "The condition doesn't depend on the value that the promise resolves to. Did you forget to "<Emphasis>"await"</Emphasis>" it?"
+            }),
+            MisusedPromise::VoidCallback { range, method } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This async function is passed to "<Emphasis>{method.text()}</Emphasis>", which ignores the promise that it returns."
+                },
+            )
+            .note(markup! {
+                "The promises aren't awaited: the callbacks don't run one after the other, and their rejections are unhandled."
+            })
+            .note(markup! {
+                "Use a "<Emphasis>"for...of"</Emphasis>" loop, or pass the promises returned by "<Emphasis>"map"</Emphasis>" to "<Emphasis>"Promise.all"</Emphasis>"."
+            }),
+            MisusedPromise::Predicate { range, method } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This async function is passed to "<Emphasis>{method.text()}</Emphasis>", which expects a predicate."
+                },
+            )
+            .note(markup! {
+                "The function returns a promise, which is always truthy: the predicate doesn't depend on the value that the promise resolves to."
+            })
+            .note(markup! {
+                "Resolve the values first, e.g. with "<Emphasis>"Promise.all"</Emphasis>", and pass a synchronous predicate."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns `true` if the logical or `!` expression `node` isn't an operand of another
+/// condition, which reports the promises of `node`.
+fn is_outermost_condition(node: &JsSyntaxNode) -> bool {
+    let mut node = node.clone();
+    while let Some(parent) = node.parent() {
+        if JsParenthesizedExpression::can_cast(parent.kind()) {
+            node = parent;
+            continue;
+        }
+        let test = if JsLogicalExpression::can_cast(parent.kind()) {
+            return false;
+        } else if let Some(expression) = JsUnaryExpression::cast_ref(&parent) {
+            return expression.operator() != Ok(JsUnaryOperator::LogicalNot);
+        } else if let Some(statement) = JsIfStatement::cast_ref(&parent) {
+            statement.test().ok()
+        } else if let Some(statement) = JsWhileStatement::cast_ref(&parent) {
+            statement.test().ok()
+        } else if let Some(statement) = JsDoWhileStatement::cast_ref(&parent) {
+            statement.test().ok()
+        } else if let Some(statement) = JsForStatement::cast_ref(&parent) {
+            statement.test()
+        } else if let Some(expression) = JsConditionalExpression::cast_ref(&parent) {
+            expression.test().ok()
+        } else {
+            None
+        };
+        return test.map_or(true, |test| test.syntax() != &node);
+    }
+    true
+}
+
+/// Collects the operands of `expression` that are used as conditions.
+///
+/// `is_tested` is `true` when the value of `expression` is itself used as a condition.
+fn collect_conditions(
+    expression: &AnyJsExpression,
+    is_tested: bool,
+    conditions: &mut Vec<AnyJsExpression>,
+) {
+    let expression = expression.clone().omit_parentheses();
+    match &expression {
+        AnyJsExpression::JsLogicalExpression(logical) => {
+            let Ok(operator) = logical.operator() else {
+                return;
+            };
+            let is_left_tested = operator != JsLogicalOperator::NullishCoalescing;
+            if let Ok(left) = logical.left() {
+                collect_conditions(&left, is_left_tested, conditions);
+            }
+            if let Ok(right) = logical.right() {
+                collect_conditions(&right, is_tested, conditions);
+            }
+        }
+        AnyJsExpression::JsUnaryExpression(unary)
+            if unary.operator() == Ok(JsUnaryOperator::LogicalNot) =>
+        {
+            if let Ok(argument) = unary.argument() {
+                collect_conditions(&argument, true, conditions);
+            }
+        }
+        _ => {
+            if is_tested {
+                conditions.push(expression);
+            }
+        }
+    }
+}
+
+/// Returns the misused `async` callback passed to `call`, if any
+fn misused_callback(call: &JsCallExpression, model: &SemanticModel) -> Option<MisusedPromise> {
+    let callee = call.callee().ok()?.omit_parentheses();
+    let method = AnyJsMemberExpression::cast_ref(callee.syntax())?.member_name()?;
+    let is_void_callback = VOID_CALLBACK_METHODS.contains(&method.text());
+    if !is_void_callback && !PREDICATE_METHODS.contains(&method.text()) {
+        return None;
+    }
+    let first_argument = call.arguments().ok()?.args().first()?.ok()?;
+    let AnyJsCallArgument::AnyJsExpression(callback) = first_argument else {
+        return None;
+    };
+    if !is_async_function(&callback.clone().omit_parentheses(), model) {
+        return None;
+    }
+    let range = callback.range();
+    Some(if is_void_callback {
+        MisusedPromise::VoidCallback { range, method }
+    } else {
+        MisusedPromise::Predicate { range, method }
+    })
+}
+
+/// Returns `true` if `expression` evaluates to a promise
+fn is_promise(expression: &AnyJsExpression, model: &SemanticModel, depth: u8) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsNewExpression(expression) => expression
+            .callee()
+            .is_ok_and(|callee| is_global(&callee.omit_parentheses(), "Promise", model)),
+        AnyJsExpression::JsCallExpression(call) => {
+            let Ok(callee) = call.callee().map(AnyJsExpression::omit_parentheses) else {
+                return false;
+            };
+            if let Some(member) = AnyJsMemberExpression::cast_ref(callee.syntax()) {
+                let Some(name) = member.member_name() else {
+                    return false;
+                };
+                return PROMISE_METHODS.contains(&name.text())
+                    || (PROMISE_STATIC_METHODS.contains(&name.text())
+                        && member.object().is_ok_and(|object| {
+                            is_global(&object.omit_parentheses(), "Promise", model)
+                        }));
+            }
+            is_global(&callee, "fetch", model) || is_async_function(&callee, model)
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) if depth < MAX_REFERENCE_DEPTH => {
+            identifier
+                .name()
+                .ok()
+                .and_then(|reference| const_initializer(&reference, model))
+                .is_some_and(|initializer| is_promise(&initializer, model, depth + 1))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expression` is an `async` function, or a reference to an
+/// `async` function declaration or to a constant initialized with an `async` function
+fn is_async_function(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    let AnyJsExpression::JsIdentifierExpression(identifier) = expression else {
+        return is_async_function_expression(expression);
+    };
+    let Ok(reference) = identifier.name() else {
+        return false;
+    };
+    match model
+        .binding(&reference)
+        .and_then(|binding| binding.tree().declaration())
+    {
+        Some(AnyJsBindingDeclaration::JsFunctionDeclaration(declaration)) => {
+            declaration.async_token().is_some()
+        }
+        Some(AnyJsBindingDeclaration::JsVariableDeclarator(_)) => {
+            const_initializer(&reference, model)
+                .is_some_and(|initializer| is_async_function_expression(&initializer))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expression` is an `async` arrow function or function expression
+fn is_async_function_expression(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsArrowFunctionExpression(function) => function.async_token().is_some(),
+        AnyJsExpression::JsFunctionExpression(function) => function.async_token().is_some(),
+        _ => false,
+    }
+}
+
+/// Returns the initializer of the constant that `reference` refers to, if the
+/// constant isn't declared with a destructuring pattern
+fn const_initializer(
+    reference: &JsReferenceIdentifier,
+    model: &SemanticModel,
+) -> Option<AnyJsExpression> {
+    let binding = model.binding(reference)?.tree();
+    if binding.is_under_pattern_binding()? {
+        return None;
+    }
+    let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) = binding.declaration()? else {
+        return None;
+    };
+    if !declarator.declaration()?.is_const() {
+        return None;
+    }
+    declarator.initializer()?.expression().ok()
+}
+
+/// Returns `true` if `expression` is a reference to the global `name`, which isn't
+/// shadowed by a declaration of the file
+fn is_global(expression: &AnyJsExpression, name: &str, model: &SemanticModel) -> bool {
+    global_identifier(expression).is_some_and(|(reference, global_name)| {
+        global_name.text() == name && model.binding(&reference).is_none()
+    })
+}
@@ -0,0 +1,348 @@
+use std::str::FromStr;
+
+use crate::semantic_services::Semantic;
+use crate::utils::browser_versions::{
+    browser_api, Browser, BrowserFeature, BrowserTargets, BrowserVersion, BIGINT_LITERALS,
+    CLASS_STATIC_BLOCKS, HAS_SELECTOR, LOGICAL_ASSIGNMENT, NULLISH_COALESCING, NUMERIC_SEPARATORS,
+    OPTIONAL_CHAINING, PRIVATE_CLASS_MEMBERS,
+};
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression, JsAssignmentExpression,
+    JsAssignmentOperator, JsBigintLiteralExpression, JsCallExpression, JsComputedMemberExpression,
+    JsIdentifierExpression, JsLogicalExpression, JsLogicalOperator, JsNumberLiteralExpression,
+    JsPrivateClassMemberName, JsReferenceIdentifier, JsStaticInitializationBlockClassMember,
+    JsStaticMemberExpression, JsUnaryExpression, JsUnaryOperator,
+};
+use biome_rowan::{declare_node_union, AstNode, TextRange};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow the syntax and the APIs that the browsers targeted by the project don't support.
+    ///
+    /// The rule reads the browsers that the project supports from the `browserslist` field of
+    /// the closest `package.json`, or from the option `targets`, and reports the syntax, e.g. the
+    /// optional chaining, and the APIs, e.g. `structuredClone`, that one of them doesn't support.
+    /// It also reports the selectors that use `:has()` in the calls of `querySelector`,
+    /// `querySelectorAll`, `matches` and `closest`.
+    ///
+    /// The versions of the browsers that support the features come from a table embedded in
+    /// Biome, which covers Chrome, Edge, Firefox, Opera, Safari, Safari on iOS and Samsung
+    /// Internet.
+    ///
+    /// Only the queries that name the versions of a browser are read, e.g. `chrome >= 90`,
+    /// `safari 15.4` or `firefox 100-110`. The queries that depend on the usage statistics or on
+    /// the release dates, e.g. `> 0.5%`, `last 2 versions` or `defaults`, need the data of
+    /// browserslist and are ignored. The rule doesn't report anything when no browser is
+    /// targeted.
+    ///
+    /// Source: https://github.com/amilajack/eslint-plugin-compat
+    ///
+    /// ## Examples
+    ///
+    /// With this `package.json`:
+    ///
+    /// ```json
+    /// {
+    ///     "browserslist": ["chrome >= 79", "safari >= 13"]
+    /// }
+    /// ```
+    ///
+    /// The following code is reported, because Chrome 79 doesn't support the optional chaining,
+    /// and Safari 13 doesn't support `structuredClone`:
+    ///
+    /// ```js
+    /// const name = user?.profile.name;
+    /// const copy = structuredClone(settings);
+    /// ```
+    ///
+    /// The syntax and the APIs that all the targeted browsers support can be used:
+    ///
+    /// ```js
+    /// const name = user && user.profile.name;
+    /// const entries = Object.entries(settings);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `targets` sets the queries of the browsers that the project supports. It takes
+    /// precedence over the `browserslist` field of the `package.json`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "targets": ["chrome >= 100", "firefox >= 100", "safari >= 15.4"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoUnsupportedBrowserApis {
+        version: "next",
+        name: "noUnsupportedBrowserApis",
+        recommended: false,
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoUnsupportedBrowserApisOptions {
+    /// The queries of browserslist of the browsers that the project supports, e.g.
+    /// `chrome >= 100`. It defaults to the `browserslist` field of the closest `package.json`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("QUERY"), many, optional)]
+    targets: Option<Vec<String>>,
+}
+
+// Required by [Bpaf].
+impl FromStr for NoUnsupportedBrowserApisOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(NoUnsupportedBrowserApisOptions::default())
+    }
+}
+
+impl Deserializable for NoUnsupportedBrowserApisOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(NoUnsupportedBrowserApisOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct NoUnsupportedBrowserApisOptionsVisitor;
+impl DeserializationVisitor for NoUnsupportedBrowserApisOptionsVisitor {
+    type Output = NoUnsupportedBrowserApisOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "targets" => {
+                    result.targets = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                unknown_key => {
+                    const ALLOWED_KEYS: &[&str] = &["targets"];
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        unknown_key,
+                        key.range(),
+                        ALLOWED_KEYS,
+                    ));
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+declare_node_union! {
+    pub(crate) NoUnsupportedBrowserApisQuery =
+        JsReferenceIdentifier
+        | JsStaticMemberExpression
+        | JsComputedMemberExpression
+        | JsCallExpression
+        | JsLogicalExpression
+        | JsAssignmentExpression
+        | JsNumberLiteralExpression
+        | JsBigintLiteralExpression
+        | JsPrivateClassMemberName
+        | JsStaticInitializationBlockClassMember
+}
+
+/// A feature that a targeted browser doesn't support
+pub(crate) struct UnsupportedFeature {
+    range: TextRange,
+    feature: &'static BrowserFeature,
+    /// The browser that doesn't support the feature, and its lowest targeted version
+    browser: Browser,
+    version: BrowserVersion,
+}
+
+impl Rule for NoUnsupportedBrowserApis {
+    type Query = Semantic<NoUnsupportedBrowserApisQuery>;
+    type State = UnsupportedFeature;
+    type Signals = Option<Self::State>;
+    type Options = NoUnsupportedBrowserApisOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options_targets;
+        let targets = match &ctx.options().targets {
+            Some(queries) => {
+                options_targets = BrowserTargets::from_queries(queries);
+                &options_targets
+            }
+            None => ctx.get_service::<BrowserTargets>()?,
+        };
+        if targets.is_empty() {
+            return None;
+        }
+
+        let (range, feature) = used_feature(ctx.query(), ctx.model())?;
+        let (browser, version) = targets.find_unsupported(feature)?;
+        Some(UnsupportedFeature {
+            range,
+            feature,
+            browser,
+            version,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    <Emphasis>{state.feature.name}</Emphasis>" isn't supported by "{state.browser.to_string()}" "{state.version.to_string()}", which the project supports."
+                },
+            )
+            .note(markup! {
+                "Raise the versions of the browsers in the "<Emphasis>"browserslist"</Emphasis>" field of the "<Emphasis>"package.json"</Emphasis>", or use a syntax or an API that they support."
+            }),
+        )
+    }
+}
+
+/// Returns the feature of the browsers that the node uses, with the range to report
+fn used_feature(
+    node: &NoUnsupportedBrowserApisQuery,
+    model: &SemanticModel,
+) -> Option<(TextRange, &'static BrowserFeature)> {
+    match node {
+        NoUnsupportedBrowserApisQuery::JsReferenceIdentifier(reference) => {
+            let expression = reference.parent::<JsIdentifierExpression>()?;
+            if model.binding(reference).is_some() || is_typeof_argument(&expression) {
+                return None;
+            }
+            let name = reference.value_token().ok()?;
+            let feature = browser_api(name.text_trimmed())?;
+            Some((reference.range(), feature))
+        }
+        NoUnsupportedBrowserApisQuery::JsStaticMemberExpression(member) => {
+            if member.is_optional() {
+                let token = member.operator_token().ok()?;
+                return Some((token.text_trimmed_range(), &OPTIONAL_CHAINING));
+            }
+            // A static member of a global, e.g. `Object.hasOwn`
+            let object = member.object().ok()?;
+            let object = object.as_js_reference_identifier()?;
+            if model.binding(&object).is_some() {
+                return None;
+            }
+            let name = member.member().ok()?;
+            let name = name.as_js_name()?.value_token().ok()?;
+            let object_name = object.value_token().ok()?;
+            let feature = browser_api(&format!(
+                "{}.{}",
+                object_name.text_trimmed(),
+                name.text_trimmed()
+            ))?;
+            Some((member.range(), feature))
+        }
+        NoUnsupportedBrowserApisQuery::JsComputedMemberExpression(member) => {
+            let token = member.optional_chain_token()?;
+            Some((token.text_trimmed_range(), &OPTIONAL_CHAINING))
+        }
+        NoUnsupportedBrowserApisQuery::JsCallExpression(call) => {
+            if let Some(token) = call.optional_chain_token() {
+                return Some((token.text_trimmed_range(), &OPTIONAL_CHAINING));
+            }
+            let range = has_selector_argument(call)?;
+            Some((range, &HAS_SELECTOR))
+        }
+        NoUnsupportedBrowserApisQuery::JsLogicalExpression(expression) => {
+            if !matches!(
+                expression.operator(),
+                Ok(JsLogicalOperator::NullishCoalescing)
+            ) {
+                return None;
+            }
+            let token = expression.operator_token().ok()?;
+            Some((token.text_trimmed_range(), &NULLISH_COALESCING))
+        }
+        NoUnsupportedBrowserApisQuery::JsAssignmentExpression(assignment) => {
+            let operator = assignment.operator().ok()?;
+            if !matches!(
+                operator,
+                JsAssignmentOperator::LogicalAndAssign
+                    | JsAssignmentOperator::LogicalOrAssign
+                    | JsAssignmentOperator::NullishCoalescingAssign
+            ) {
+                return None;
+            }
+            let token = assignment.operator_token().ok()?;
+            Some((token.text_trimmed_range(), &LOGICAL_ASSIGNMENT))
+        }
+        NoUnsupportedBrowserApisQuery::JsNumberLiteralExpression(literal) => {
+            let token = literal.value_token().ok()?;
+            token
+                .text_trimmed()
+                .contains('_')
+                .then(|| (token.text_trimmed_range(), &NUMERIC_SEPARATORS))
+        }
+        NoUnsupportedBrowserApisQuery::JsBigintLiteralExpression(literal) => {
+            Some((literal.range(), &BIGINT_LITERALS))
+        }
+        NoUnsupportedBrowserApisQuery::JsPrivateClassMemberName(name) => {
+            Some((name.range(), &PRIVATE_CLASS_MEMBERS))
+        }
+        NoUnsupportedBrowserApisQuery::JsStaticInitializationBlockClassMember(block) => {
+            let token = block.static_token().ok()?;
+            Some((token.text_trimmed_range(), &CLASS_STATIC_BLOCKS))
+        }
+    }
+}
+
+/// Whether the expression is checked with `typeof`, e.g. `typeof structuredClone === "function"`
+fn is_typeof_argument(expression: &JsIdentifierExpression) -> bool {
+    expression
+        .parent::<JsUnaryExpression>()
+        .is_some_and(|unary| matches!(unary.operator(), Ok(JsUnaryOperator::Typeof)))
+}
+
+/// Returns the range of the selector that uses `:has()` in a call like
+/// `document.querySelector("li:has(a)")`
+fn has_selector_argument(call: &JsCallExpression) -> Option<TextRange> {
+    let callee = AnyJsMemberExpression::cast(call.callee().ok()?.into_syntax())?;
+    let method = callee.member_name()?;
+    if !matches!(
+        method.text(),
+        "querySelector" | "querySelectorAll" | "matches" | "closest"
+    ) {
+        return None;
+    }
+    let [Some(AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(literal)))] =
+        call.get_arguments_by_index([0])
+    else {
+        return None;
+    };
+    let selector = literal.as_js_string_literal_expression()?;
+    selector
+        .inner_string_text()
+        .ok()?
+        .text()
+        .contains(":has(")
+        .then(|| selector.range())
+}
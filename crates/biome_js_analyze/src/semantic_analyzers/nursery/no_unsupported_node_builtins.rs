@@ -0,0 +1,447 @@
+use std::str::FromStr;
+
+use crate::semantic_services::Semantic;
+use crate::utils::node_versions::{node_api_since, node_module_since, NodeEngine, NodeVersion};
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    inner_string_text, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument, AnyJsExpression,
+    AnyJsImportClause, AnyJsMemberExpression, AnyJsNamedImport, AnyJsNamedImportSpecifier,
+    AnyJsObjectBindingPatternMember, JsCallExpression, JsExportNamedFromClause,
+    JsInitializerClause, JsLiteralExportName, JsModuleSource, JsVariableDeclarator,
+};
+use biome_resolver::is_node_builtin;
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow the built-in modules and APIs of Node.js that aren't available in the versions
+    /// that the project supports.
+    ///
+    /// The rule reads the versions of Node.js that the project supports from the `engines` field
+    /// of the closest `package.json`, or from the option `version`, and reports the imports of
+    /// the built-in modules, and the uses of their exports, that were added in a later version.
+    /// For example, `fs.cp` was added in Node.js 16.7.0, so a project that supports Node.js 14
+    /// can't use it.
+    ///
+    /// The versions of the modules and of the APIs come from a table embedded in Biome. The APIs
+    /// that were backported to an older release line, e.g. `crypto.randomUUID` in Node.js
+    /// 14.17.0, are allowed in the versions of that line that have them.
+    ///
+    /// The rule checks the imports, e.g. `import { cp } from "node:fs"`, the members of the
+    /// imported modules, e.g. `fs.cp`, and the calls of `require`. It doesn't report anything
+    /// when the versions of Node.js aren't known.
+    ///
+    /// Source: https://github.com/eslint-community/eslint-plugin-n/blob/master/docs/rules/no-unsupported-features/node-builtins.md
+    ///
+    /// ## Examples
+    ///
+    /// With this `package.json`:
+    ///
+    /// ```json
+    /// {
+    ///     "engines": { "node": ">=14.0.0" }
+    /// }
+    /// ```
+    ///
+    /// The following code is reported, because `fs.cp` was added in Node.js 16.7.0, and
+    /// `node:test` in Node.js 18.0.0:
+    ///
+    /// ```js
+    /// import fs from "node:fs";
+    /// import { test } from "node:test";
+    ///
+    /// await fs.promises.cp("src", "dist", { recursive: true });
+    /// fs.cp("src", "dist", { recursive: true }, callback);
+    /// ```
+    ///
+    /// The APIs that are available since Node.js 14 can be used:
+    ///
+    /// ```js
+    /// import { rm } from "node:fs/promises";
+    ///
+    /// await rm("dist", { recursive: true, force: true });
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `version` sets the range of the versions of Node.js that the project supports.
+    /// It takes precedence over the `engines` field of the `package.json`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "version": ">=18.0.0"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoUnsupportedNodeBuiltins {
+        version: "next",
+        name: "noUnsupportedNodeBuiltins",
+        recommended: false,
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoUnsupportedNodeBuiltinsOptions {
+    /// The range of the versions of Node.js that the project supports, e.g. `>=18.0.0`. It
+    /// defaults to the `engines` field of the closest `package.json`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("VERSION"), optional)]
+    version: Option<String>,
+}
+
+// Required by [Bpaf].
+impl FromStr for NoUnsupportedNodeBuiltinsOptions {
+    type Err = ();
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(NoUnsupportedNodeBuiltinsOptions::default())
+    }
+}
+
+impl Deserializable for NoUnsupportedNodeBuiltinsOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(NoUnsupportedNodeBuiltinsOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct NoUnsupportedNodeBuiltinsOptionsVisitor;
+impl DeserializationVisitor for NoUnsupportedNodeBuiltinsOptionsVisitor {
+    type Output = NoUnsupportedNodeBuiltinsOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "version" => {
+                    let version: Option<String> =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                    if let Some(version) = version {
+                        if NodeVersion::minimum_of_range(&version).is_some() {
+                            result.version = Some(version);
+                        } else {
+                            diagnostics.push(
+                                DeserializationDiagnostic::new(markup! {
+                                    "The range of versions "<Emphasis>{version}</Emphasis>" doesn't have a minimum version."
+                                })
+                                .with_range(value.range()),
+                            );
+                        }
+                    }
+                }
+                unknown_key => {
+                    const ALLOWED_KEYS: &[&str] = &["version"];
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        unknown_key,
+                        key.range(),
+                        ALLOWED_KEYS,
+                    ));
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+declare_node_union! {
+    pub(crate) NoUnsupportedNodeBuiltinsQuery = JsModuleSource | JsCallExpression
+}
+
+/// A built-in module, or an export of a built-in module, that isn't available
+pub(crate) struct UnsupportedBuiltin {
+    range: TextRange,
+    /// The name of the module, e.g. `node:test`, or of the export, e.g. `fs.cp`
+    name: String,
+    /// The versions that added the module or the export
+    since: &'static [&'static str],
+    /// The lowest version that the project supports
+    minimum: NodeVersion,
+}
+
+impl Rule for NoUnsupportedNodeBuiltins {
+    type Query = Semantic<NoUnsupportedNodeBuiltinsQuery>;
+    type State = UnsupportedBuiltin;
+    type Signals = Vec<Self::State>;
+    type Options = NoUnsupportedNodeBuiltinsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let range = match ctx.options().version.as_deref() {
+            Some(version) => Some(version),
+            None => ctx
+                .get_service::<NodeEngine>()
+                .and_then(|engine| engine.range()),
+        };
+        let Some(minimum) = range.and_then(NodeVersion::minimum_of_range) else {
+            return Vec::new();
+        };
+
+        let mut checker = BuiltinChecker {
+            model: ctx.model(),
+            minimum,
+            unsupported: Vec::new(),
+        };
+        match ctx.query() {
+            NoUnsupportedNodeBuiltinsQuery::JsModuleSource(source) => {
+                checker.check_import(source);
+            }
+            NoUnsupportedNodeBuiltinsQuery::JsCallExpression(call) => {
+                checker.check_require(call);
+            }
+        }
+        checker.unsupported
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (release, backports) = state.since.split_last()?;
+        let availability = if backports.is_empty() {
+            format!("It's available since Node.js {release}.")
+        } else {
+            format!(
+                "It's available since Node.js {release}, and since {} on the older release lines.",
+                backports.join(", ")
+            )
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    <Emphasis>{state.name}</Emphasis>" isn't available in Node.js "{state.minimum.to_string()}", the lowest version that the project supports."
+                },
+            )
+            .note(markup! { {availability} })
+            .note(markup! {
+                "Raise the version of Node.js in the "<Emphasis>"engines"</Emphasis>" field of the "<Emphasis>"package.json"</Emphasis>", or use another API."
+            }),
+        )
+    }
+}
+
+struct BuiltinChecker<'a> {
+    model: &'a SemanticModel,
+    minimum: NodeVersion,
+    unsupported: Vec<UnsupportedBuiltin>,
+}
+
+impl BuiltinChecker<'_> {
+    /// Checks an import or a re-export of a built-in module, and the exports that it uses
+    fn check_import(&mut self, source: &JsModuleSource) -> Option<()> {
+        let specifier = source.inner_string_text().ok()?;
+        let module = self.check_module(specifier.text(), source.range())?;
+
+        let parent = source.syntax().parent()?;
+        if let Some(clause) = JsExportNamedFromClause::cast_ref(&parent) {
+            for specifier in clause.specifiers().iter().flatten() {
+                if let Ok(name) = specifier.source_name() {
+                    self.check_export_name(module, &name);
+                }
+            }
+            return Some(());
+        }
+
+        match AnyJsImportClause::cast(parent)? {
+            AnyJsImportClause::JsImportBareClause(_) => {}
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                self.check_members(module, &clause.local_name().ok()?);
+            }
+            AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                self.check_members(module, &clause.local_name().ok()?);
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                if let Some(default_specifier) = clause.default_specifier() {
+                    if let Ok(binding) = default_specifier.local_name() {
+                        self.check_members(module, &binding);
+                    }
+                }
+                match clause.named_import().ok()? {
+                    AnyJsNamedImport::JsNamedImportSpecifiers(specifiers) => {
+                        for specifier in specifiers.specifiers().iter().flatten() {
+                            self.check_import_specifier(module, &specifier);
+                        }
+                    }
+                    AnyJsNamedImport::JsNamespaceImportSpecifier(specifier) => {
+                        self.check_members(module, &specifier.local_name().ok()?);
+                    }
+                }
+            }
+        }
+        Some(())
+    }
+
+    /// Checks a call of `require` that loads a built-in module, and the exports that it uses
+    fn check_require(&mut self, call: &JsCallExpression) -> Option<()> {
+        let callee = call.callee().ok()?;
+        let reference = callee.as_js_reference_identifier()?;
+        if !reference.has_name("require") || self.model.binding(&reference).is_some() {
+            return None;
+        }
+        let [Some(AnyJsCallArgument::AnyJsExpression(argument))] = call.get_arguments_by_index([0])
+        else {
+            return None;
+        };
+        let AnyJsExpression::AnyJsLiteralExpression(literal) = argument else {
+            return None;
+        };
+        let literal = literal.as_js_string_literal_expression()?;
+        let specifier = literal.inner_string_text().ok()?;
+        let module = self.check_module(specifier.text(), literal.range())?;
+
+        let parent = call.syntax().parent()?;
+        if let Some(member) = AnyJsMemberExpression::cast_ref(&parent) {
+            // require("fs").cp
+            if let Some(name) = member.member_name() {
+                self.check_api(module, name.text(), member.range());
+            }
+        } else if let Some(declarator) = JsInitializerClause::cast(parent)
+            .and_then(|initializer| initializer.parent::<JsVariableDeclarator>())
+        {
+            match declarator.id().ok()? {
+                AnyJsBindingPattern::AnyJsBinding(binding) => {
+                    self.check_members(module, &binding);
+                }
+                AnyJsBindingPattern::JsObjectBindingPattern(pattern) => {
+                    for property in pattern.properties().iter().flatten() {
+                        self.check_destructured_property(module, &property);
+                    }
+                }
+                AnyJsBindingPattern::JsArrayBindingPattern(_) => {}
+            }
+        }
+        Some(())
+    }
+
+    /// Checks that the built-in module of `specifier` is available. It returns the name of the
+    /// module without the `node:` prefix, or `None` if it isn't an available built-in module.
+    fn check_module<'s>(&mut self, specifier: &'s str, range: TextRange) -> Option<&'s str> {
+        if !is_node_builtin(specifier) {
+            return None;
+        }
+        let module = specifier.strip_prefix("node:").unwrap_or(specifier);
+        if let Some(since) = node_module_since(module) {
+            if !self.minimum.supports(since) {
+                self.report(specifier.to_string(), since, range);
+                return None;
+            }
+        }
+        Some(module)
+    }
+
+    fn check_import_specifier(&mut self, module: &str, specifier: &AnyJsNamedImportSpecifier) {
+        match specifier {
+            AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                if let Ok(name) = specifier.name() {
+                    self.check_export_name(module, &name);
+                }
+            }
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                let name = specifier
+                    .local_name()
+                    .ok()
+                    .and_then(|binding| binding.as_js_identifier_binding()?.name_token().ok());
+                if let Some(name) = name {
+                    self.check_api(module, name.text_trimmed(), specifier.range());
+                }
+            }
+            AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
+        }
+    }
+
+    fn check_export_name(&mut self, module: &str, name: &JsLiteralExportName) {
+        if let Ok(token) = name.value() {
+            self.check_api(module, inner_string_text(&token).text(), name.range());
+        }
+    }
+
+    fn check_destructured_property(
+        &mut self,
+        module: &str,
+        property: &AnyJsObjectBindingPatternMember,
+    ) {
+        match property {
+            AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(property) => {
+                let name = property
+                    .identifier()
+                    .ok()
+                    .and_then(|binding| binding.as_js_identifier_binding()?.name_token().ok());
+                if let Some(name) = name {
+                    self.check_api(module, name.text_trimmed(), property.range());
+                }
+            }
+            AnyJsObjectBindingPatternMember::JsObjectBindingPatternProperty(property) => {
+                let name = property.member().ok().and_then(|member| member.name());
+                if let Some(name) = name {
+                    self.check_api(module, name.text(), property.range());
+                }
+            }
+            AnyJsObjectBindingPatternMember::JsObjectBindingPatternRest(_)
+            | AnyJsObjectBindingPatternMember::JsBogusBinding(_) => {}
+        }
+    }
+
+    /// Checks the members read on the binding of a module, e.g. `fs.cp` for `import fs from "fs"`
+    fn check_members(&mut self, module: &str, binding: &AnyJsBinding) {
+        let Some(binding) = binding.as_js_identifier_binding() else {
+            return;
+        };
+        for reference in binding.all_references(self.model) {
+            let member = reference
+                .syntax()
+                .parent()
+                .and_then(|expression| expression.parent())
+                .and_then(AnyJsMemberExpression::cast);
+            let Some(member) = member else {
+                continue;
+            };
+            // The reference must be the object of the member, e.g. not `object[fs]`
+            let is_object = member
+                .object()
+                .is_ok_and(|object| object.syntax().text_trimmed_range() == *reference.range());
+            if let (true, Some(name)) = (is_object, member.member_name()) {
+                self.check_api(module, name.text(), member.range());
+            }
+        }
+    }
+
+    fn check_api(&mut self, module: &str, name: &str, range: TextRange) {
+        if let Some(since) = node_api_since(module, name) {
+            if !self.minimum.supports(since) {
+                self.report(format!("{module}.{name}"), since, range);
+            }
+        }
+    }
+
+    fn report(&mut self, name: String, since: &'static [&'static str], range: TextRange) {
+        self.unsupported.push(UnsupportedBuiltin {
+            range,
+            name,
+            since,
+            minimum: self.minimum,
+        });
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::queries::all_references;
 use crate::{semantic_services::Semantic, utils::batch::JsBatchMutation, JsRuleAction};
 use biome_analyze::{
     context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
@@ -5,7 +6,6 @@ use biome_analyze::{
 use biome_console::markup;
 use biome_diagnostics::Applicability;
 use biome_js_factory::make;
-use biome_js_semantic::ReferencesExtensions;
 use biome_js_syntax::{
     binding_ext::AnyJsBindingDeclaration, AnyJsImportClause, JsIdentifierBinding, JsImport,
     JsImportNamedClause, JsLanguage, JsNamedImportSpecifierList, T,
@@ -85,7 +85,9 @@ impl Rule for NoUnusedImports {
         }
 
         let model = ctx.model();
-        binding.all_references(model).next().is_none().then_some(())
+        all_references(ctx.query_cache(), binding, model)
+            .is_empty()
+            .then_some(())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
@@ -1,5 +1,5 @@
-use crate::react::{jsx_member_name_is_react_fragment, jsx_reference_identifier_is_fragment};
 use crate::semantic_services::Semantic;
+use crate::utils::queries::is_react_fragment;
 use crate::JsRuleAction;
 use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic};
@@ -8,7 +8,7 @@ use biome_diagnostics::Applicability;
 use biome_js_factory::make::{
     jsx_child_list, jsx_closing_fragment, jsx_fragment, jsx_opening_fragment,
 };
-use biome_js_syntax::{AnyJsxElementName, JsxElement};
+use biome_js_syntax::JsxElement;
 use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
 
 declare_rule! {
@@ -47,15 +47,7 @@ impl Rule for UseFragmentSyntax {
 
         let opening_element = node.opening_element().ok()?;
         let name = opening_element.name().ok()?;
-        let maybe_invalid = match name {
-            AnyJsxElementName::JsxMemberName(member_name) => {
-                jsx_member_name_is_react_fragment(&member_name, model)?
-            }
-            AnyJsxElementName::JsxReferenceIdentifier(identifier) => {
-                jsx_reference_identifier_is_fragment(&identifier, model)?
-            }
-            AnyJsxElementName::JsxName(_) | AnyJsxElementName::JsxNamespaceName(_) => false,
-        };
+        let maybe_invalid = is_react_fragment(ctx.query_cache(), &name, model)?;
 
         if maybe_invalid && opening_element.attributes().is_empty() {
             return Some(());
@@ -101,10 +101,13 @@ impl Rule for NoRedeclare {
                "Shouldn't redeclare '"{ name }"'. Consider to delete it or rename it."
             },
         )
-        .detail(
+        .primary_label(markup! {
+            "'"{ name }"' is redeclared here"
+        })
+        .secondary_label(
             declaration,
             markup! {
-               "'"{ name }"' is defined here:"
+               "'"{ name }"' is defined here"
             },
         );
         Some(diag)
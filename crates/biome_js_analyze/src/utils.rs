@@ -1,10 +1,22 @@
-use biome_js_syntax::{inner_string_text, AnyJsExpression, JsBinaryExpression, JsSyntaxNode};
+use crate::control_flow::AnyJsControlFlowRoot;
+use biome_js_syntax::{
+    inner_string_text, AnyJsExpression, JsArrowFunctionExpression, JsBinaryExpression,
+    JsNewTargetExpression, JsReferenceIdentifier, JsSuperExpression, JsSyntaxNode,
+    JsThisExpression,
+};
 use biome_rowan::{AstNode, Direction, WalkEvent};
 use std::iter;
 
 pub mod batch;
+pub mod browser_versions;
 pub mod case;
+pub mod line_count;
+pub mod node_versions;
+pub mod packages;
+pub mod path_aliases;
+pub(crate) mod queries;
 pub mod rename;
+pub mod test_frameworks;
 #[cfg(test)]
 pub mod tests;
 
@@ -71,6 +83,33 @@ pub(crate) fn is_node_equal(a_node: &JsSyntaxNode, b_node: &JsSyntaxNode) -> boo
     true
 }
 
+/// Returns `true` if `function` uses `this`, `super`, `arguments` or `new.target`
+/// of its own function context.
+///
+/// The nested functions have their own context, except the arrow functions that
+/// inherit it. When `function` is itself an arrow function, this returns whether
+/// it uses the context of its enclosing function.
+pub(crate) fn uses_function_context(function: &JsSyntaxNode) -> bool {
+    function.descendants().skip(1).any(|node| {
+        let is_context = JsThisExpression::can_cast(node.kind())
+            || JsSuperExpression::can_cast(node.kind())
+            || JsNewTargetExpression::can_cast(node.kind())
+            || JsReferenceIdentifier::cast_ref(&node)
+                .and_then(|reference| reference.value_token().ok())
+                .is_some_and(|token| token.text_trimmed() == "arguments");
+        is_context
+            && node
+                .ancestors()
+                .skip(1)
+                .find(|ancestor| {
+                    ancestor == function
+                        || (AnyJsControlFlowRoot::can_cast(ancestor.kind())
+                            && !JsArrowFunctionExpression::can_cast(ancestor.kind()))
+                })
+                .is_some_and(|ancestor| &ancestor == function)
+    })
+}
+
 #[derive(Debug, PartialEq)]
 pub(crate) enum VariablePosition {
     Right,
@@ -0,0 +1,342 @@
+use std::fmt;
+
+/// A browser of the compatibility tables, with its name in the queries of browserslist
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Browser {
+    Chrome,
+    Edge,
+    Firefox,
+    Opera,
+    Safari,
+    IosSafari,
+    Samsung,
+}
+
+impl Browser {
+    /// Returns the browser of a name of browserslist, e.g. `ios_saf` or `ff`. The names are
+    /// case-insensitive.
+    fn from_query_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "chrome" => Some(Browser::Chrome),
+            "edge" => Some(Browser::Edge),
+            "firefox" | "ff" => Some(Browser::Firefox),
+            "opera" => Some(Browser::Opera),
+            "safari" => Some(Browser::Safari),
+            "ios_saf" | "ios" => Some(Browser::IosSafari),
+            "samsung" => Some(Browser::Samsung),
+            _ => None,
+        }
+    }
+
+    /// The index of the browser in the versions of [Support]
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Browser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Browser::Chrome => "Chrome",
+            Browser::Edge => "Edge",
+            Browser::Firefox => "Firefox",
+            Browser::Opera => "Opera",
+            Browser::Safari => "Safari",
+            Browser::IosSafari => "Safari on iOS",
+            Browser::Samsung => "Samsung Internet",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A version of a browser, e.g. `15.4` for Safari or `90` for Chrome
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct BrowserVersion {
+    major: u16,
+    minor: u16,
+}
+
+impl BrowserVersion {
+    fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = match parts.next() {
+            Some(minor) => minor.parse().ok()?,
+            None => 0,
+        };
+        // The patch versions don't change the features
+        Some(Self { major, minor })
+    }
+}
+
+impl fmt::Display for BrowserVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.minor == 0 {
+            write!(f, "{}", self.major)
+        } else {
+            write!(f, "{}.{}", self.major, self.minor)
+        }
+    }
+}
+
+/// The lowest versions of the browsers that a file runs on, from the queries of browserslist,
+/// registered as a service of the analyzer.
+#[derive(Debug, Clone, Default)]
+pub struct BrowserTargets {
+    /// The lowest version of each targeted browser, sorted by browser
+    targets: Vec<(Browser, BrowserVersion)>,
+}
+
+impl BrowserTargets {
+    /// Reads the queries of browserslist that target the versions of a browser, e.g.
+    /// `chrome >= 90`, `safari 15.4` or `firefox 100-110`. A query may contain several queries
+    /// separated by commas or by `or`.
+    ///
+    /// The queries that depend on the usage statistics or on the release dates, e.g. `> 0.5%`,
+    /// `last 2 versions` or `defaults`, can't be resolved without the data of browserslist and
+    /// are ignored. The queries that start with `not` only exclude versions, so they don't change
+    /// the lowest versions.
+    pub fn from_queries(queries: &[impl AsRef<str>]) -> Self {
+        let mut targets: Vec<(Browser, BrowserVersion)> = Vec::new();
+        let queries = queries
+            .iter()
+            .flat_map(|query| query.as_ref().split(','))
+            .flat_map(|query| query.split(" or "));
+        for (browser, version) in queries.filter_map(parse_query) {
+            match targets.iter_mut().find(|(target, _)| *target == browser) {
+                Some((_, minimum)) => *minimum = version.min(*minimum),
+                None => targets.push((browser, version)),
+            }
+        }
+        targets.sort_unstable();
+        Self { targets }
+    }
+
+    /// Returns the first targeted browser that doesn't support the feature, with its lowest
+    /// version
+    pub fn find_unsupported(&self, feature: &BrowserFeature) -> Option<(Browser, BrowserVersion)> {
+        self.targets
+            .iter()
+            .find(|(browser, version)| {
+                match BrowserVersion::parse(feature.support[browser.index()]) {
+                    Some(since) => *version < since,
+                    // An empty version means that the browser doesn't support the feature
+                    None => true,
+                }
+            })
+            .copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+/// Parses a query that targets the versions of a browser, returning the lowest version
+fn parse_query(query: &str) -> Option<(Browser, BrowserVersion)> {
+    let query = query.trim();
+    if query.is_empty() || query.to_ascii_lowercase().starts_with("not ") {
+        return None;
+    }
+    let name_end = query
+        .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '='))
+        .unwrap_or(query.len());
+    let browser = Browser::from_query_name(&query[..name_end])?;
+    let comparison = query[name_end..].trim_start();
+    let (operator, version) = match comparison.find(|c: char| c.is_ascii_digit()) {
+        Some(start) => (comparison[..start].trim(), &comparison[start..]),
+        None => return None,
+    };
+    // The ranges, e.g. `firefox 100-110`, start with their lowest version
+    let version = version.split('-').next()?;
+    let version = BrowserVersion::parse(version)?;
+    match operator {
+        "" | ">=" => Some((browser, version)),
+        // The next version after `safari > 15` is `15.1`
+        ">" => Some((
+            browser,
+            BrowserVersion {
+                minor: version.minor + 1,
+                ..version
+            },
+        )),
+        // `chrome < 90` targets all the versions before 90
+        "<" | "<=" => Some((browser, BrowserVersion { major: 0, minor: 0 })),
+        _ => None,
+    }
+}
+
+/// The versions that support a feature, in the order of Chrome, Edge, Firefox, Opera, Safari,
+/// Safari on iOS and Samsung Internet. An empty version means that the browser doesn't support
+/// the feature.
+type Support = [&'static str; 7];
+
+/// A feature of the language or an API of the browsers, and the versions that support it
+#[derive(Debug)]
+pub struct BrowserFeature {
+    /// The name of the feature, e.g. `The optional chaining` or `structuredClone`
+    pub name: &'static str,
+    support: Support,
+}
+
+macro_rules! feature {
+    ($name:literal, $support:expr) => {
+        BrowserFeature {
+            name: $name,
+            support: $support,
+        }
+    };
+}
+
+pub static OPTIONAL_CHAINING: BrowserFeature = feature!(
+    "The optional chaining",
+    ["80", "80", "74", "67", "13.1", "13.4", "13.0"]
+);
+pub static NULLISH_COALESCING: BrowserFeature = feature!(
+    "The nullish coalescing operator",
+    ["80", "80", "72", "67", "13.1", "13.4", "13.0"]
+);
+pub static LOGICAL_ASSIGNMENT: BrowserFeature = feature!(
+    "The logical assignment operators",
+    ["85", "85", "79", "71", "14", "14", "14.0"]
+);
+pub static NUMERIC_SEPARATORS: BrowserFeature = feature!(
+    "The numeric separators",
+    ["75", "79", "70", "62", "13", "13", "11.0"]
+);
+pub static BIGINT_LITERALS: BrowserFeature = feature!(
+    "The BigInt literals",
+    ["67", "79", "68", "54", "14", "14", "9.0"]
+);
+pub static PRIVATE_CLASS_MEMBERS: BrowserFeature = feature!(
+    "The private class members",
+    ["84", "84", "90", "70", "15", "15", "14.0"]
+);
+pub static CLASS_STATIC_BLOCKS: BrowserFeature = feature!(
+    "The static blocks of classes",
+    ["94", "94", "93", "80", "16.4", "16.4", "17.0"]
+);
+pub static HAS_SELECTOR: BrowserFeature = feature!(
+    "The selector :has()",
+    ["105", "105", "121", "91", "15.4", "15.4", "20.0"]
+);
+
+/// The globals of the browsers and their static members that were added after 2018, sorted by
+/// name
+const BROWSER_APIS: &[BrowserFeature] = &[
+    feature!(
+        "AbortController",
+        ["66", "16", "57", "53", "12.1", "12.2", "9.0"]
+    ),
+    feature!(
+        "AggregateError",
+        ["85", "85", "79", "71", "14", "14", "14.0"]
+    ),
+    feature!(
+        "Array.fromAsync",
+        ["121", "121", "115", "107", "16.4", "16.4", "25.0"]
+    ),
+    feature!(
+        "BroadcastChannel",
+        ["54", "79", "38", "41", "15.4", "15.4", "6.0"]
+    ),
+    feature!(
+        "FinalizationRegistry",
+        ["84", "84", "79", "70", "14.1", "14.5", "14.0"]
+    ),
+    feature!(
+        "Object.fromEntries",
+        ["73", "79", "63", "60", "12.1", "12.2", "11.0"]
+    ),
+    feature!(
+        "Object.groupBy",
+        ["117", "117", "119", "103", "17.4", "17.4", "24.0"]
+    ),
+    feature!(
+        "Object.hasOwn",
+        ["93", "93", "92", "79", "15.4", "15.4", "17.0"]
+    ),
+    feature!(
+        "Promise.allSettled",
+        ["76", "79", "71", "63", "13", "13", "12.0"]
+    ),
+    feature!("Promise.any", ["85", "85", "79", "71", "14", "14", "14.0"]),
+    feature!(
+        "Promise.withResolvers",
+        ["119", "119", "121", "105", "17.4", "17.4", "25.0"]
+    ),
+    feature!(
+        "ResizeObserver",
+        ["64", "79", "69", "51", "13.1", "13.4", "9.0"]
+    ),
+    feature!("WeakRef", ["84", "84", "79", "70", "14.1", "14.5", "14.0"]),
+    feature!(
+        "globalThis",
+        ["71", "79", "65", "58", "12.1", "12.2", "10.0"]
+    ),
+    feature!(
+        "queueMicrotask",
+        ["71", "79", "69", "58", "12.1", "12.2", "10.0"]
+    ),
+    feature!(
+        "structuredClone",
+        ["98", "98", "94", "84", "15.4", "15.4", "18.0"]
+    ),
+];
+
+/// Returns the global of the browsers, e.g. `structuredClone`, or the static member of a global,
+/// e.g. `Object.hasOwn`. It returns `None` when the API is older than the table.
+pub fn browser_api(name: &str) -> Option<&'static BrowserFeature> {
+    BROWSER_APIS
+        .binary_search_by_key(&name, |feature| feature.name)
+        .ok()
+        .map(|index| &BROWSER_APIS[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{browser_api, BrowserTargets, BROWSER_APIS, OPTIONAL_CHAINING};
+
+    #[test]
+    fn the_table_is_sorted() {
+        assert!(BROWSER_APIS
+            .windows(2)
+            .all(|window| window[0].name < window[1].name));
+    }
+
+    #[test]
+    fn reads_the_lowest_versions_of_the_queries() {
+        let targets = BrowserTargets::from_queries(&[
+            "Chrome >= 90, chrome 85",
+            "safari>15 or ios_saf 15.4-15.6",
+            "not firefox < 100",
+            "last 2 versions",
+            "> 0.5%",
+        ]);
+        let targets: Vec<_> = targets
+            .targets
+            .iter()
+            .map(|(browser, version)| format!("{browser} {version}"))
+            .collect();
+
+        assert_eq!(targets, ["Chrome 85", "Safari 15.1", "Safari on iOS 15.4"]);
+        assert!(BrowserTargets::from_queries(&["defaults"]).is_empty());
+    }
+
+    #[test]
+    fn finds_the_unsupported_browsers() {
+        let structured_clone = browser_api("structuredClone").unwrap();
+        let modern = BrowserTargets::from_queries(&["chrome >= 100", "safari >= 16"]);
+        let legacy = BrowserTargets::from_queries(&["chrome >= 100", "safari >= 13"]);
+
+        assert!(modern.find_unsupported(structured_clone).is_none());
+        assert_eq!(
+            legacy
+                .find_unsupported(structured_clone)
+                .map(|(browser, version)| format!("{browser} {version}")),
+            Some("Safari 13".to_string())
+        );
+        assert!(legacy.find_unsupported(&OPTIONAL_CHAINING).is_none());
+        assert!(browser_api("fetch").is_none());
+    }
+}
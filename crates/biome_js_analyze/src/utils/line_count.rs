@@ -0,0 +1,105 @@
+use biome_js_syntax::{JsSyntaxNode, TextRange, TextSize};
+use biome_rowan::Direction;
+
+/// Which lines are skipped when the lines of the code are counted
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LineCountOptions {
+    /// Skip the lines that only contain whitespace
+    pub skip_blank_lines: bool,
+    /// Skip the lines that only contain comments
+    pub skip_comments: bool,
+}
+
+/// Returns the ranges of the lines of `range` that are counted, without their leading and
+/// trailing whitespace. `range` must be a range of `node`.
+///
+/// A line that contains code and comments is always counted. The empty line that follows the
+/// last line break of `range` isn't counted.
+pub fn counted_lines(
+    node: &JsSyntaxNode,
+    range: TextRange,
+    options: LineCountOptions,
+) -> Vec<TextRange> {
+    let text = node.text().to_string();
+    let node_start = node.text_range().start();
+    let start = usize::from(range.start() - node_start);
+    let end = usize::from(range.end() - node_start);
+
+    // The start of each line, and the range of its text without the surrounding whitespace
+    let mut lines = Vec::new();
+    let mut offset = range.start();
+    for line in text[start..end].split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let leading = content.len() - content.trim_start().len();
+        let trimmed = content.trim();
+        let trimmed_start = offset + TextSize::from(leading as u32);
+        lines.push((
+            offset,
+            TextRange::at(trimmed_start, TextSize::from(trimmed.len() as u32)),
+        ));
+        offset += TextSize::from(line.len() as u32);
+    }
+
+    let mut has_code = vec![false; lines.len()];
+    if options.skip_comments {
+        let line_index =
+            |offset: TextSize| lines.partition_point(|(start, _)| *start <= offset) - 1;
+        for token in node.descendants_tokens(Direction::Next) {
+            let Some(token_range) = token.text_trimmed_range().intersect(range) else {
+                continue;
+            };
+            if token_range.is_empty() {
+                continue;
+            }
+            let first = line_index(token_range.start());
+            let last = line_index(token_range.end() - TextSize::from(1));
+            for line in &mut has_code[first..=last] {
+                *line = true;
+            }
+        }
+    }
+
+    lines
+        .iter()
+        .zip(has_code)
+        .filter(|((_, content), has_code)| {
+            if content.is_empty() {
+                !options.skip_blank_lines
+            } else {
+                // Without code, the line only contains comments
+                *has_code || !options.skip_comments
+            }
+        })
+        .map(|((_, content), _)| *content)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{counted_lines, LineCountOptions};
+    use biome_js_parser::JsParserOptions;
+    use biome_js_syntax::JsFileSource;
+
+    fn count(source: &str, skip_blank_lines: bool, skip_comments: bool) -> usize {
+        let parsed = biome_js_parser::parse(
+            source,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let root = parsed.syntax();
+        let options = LineCountOptions {
+            skip_blank_lines,
+            skip_comments,
+        };
+        counted_lines(&root, root.text_range(), options).len()
+    }
+
+    #[test]
+    fn counts_the_lines() {
+        let source = "// header\n\nconst a = 1; // one\n/*\n * two\n */\nconst b = `\n\n`;\n";
+        assert_eq!(count(source, false, false), 9);
+        assert_eq!(count(source, true, false), 7);
+        assert_eq!(count(source, false, true), 5);
+        assert_eq!(count(source, true, true), 3);
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt;
+
+/// The range of the versions of Node.js that the analyzed file runs on, from the `engines`
+/// field of the `package.json` closest to it, registered as a service of the analyzer.
+#[derive(Debug, Clone, Default)]
+pub struct NodeEngine {
+    range: Option<String>,
+}
+
+impl NodeEngine {
+    pub fn new(range: Option<String>) -> Self {
+        Self { range }
+    }
+
+    /// The range of the versions, e.g. `>=18.0.0`, `None` when the `package.json` doesn't
+    /// declare it
+    pub fn range(&self) -> Option<&str> {
+        self.range.as_deref()
+    }
+}
+
+/// A version of Node.js, e.g. `18.12.0`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct NodeVersion {
+    major: u16,
+    minor: u16,
+    patch: u16,
+}
+
+impl NodeVersion {
+    /// Parses a version, e.g. `18.12.0`, `v18` or `18.x`. The missing parts are `0`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let text = text.strip_prefix('v').unwrap_or(text);
+        // The pre-releases and the build metadata don't change the release line
+        let text = text.split(['-', '+']).next()?;
+        let mut parts = text.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let mut next_part = || match parts.next() {
+            None | Some("x" | "X" | "*") => Some(0),
+            Some(part) => part.parse().ok(),
+        };
+        let minor = next_part()?;
+        let patch = next_part()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+
+    /// Returns the lowest version allowed by a range of versions, e.g. `14.17.0` for
+    /// `^14.17.0 || >=16`.
+    ///
+    /// The range is read like npm does, but only its lower bounds are used: `>14` is read as
+    /// `>=14`. It returns `None` when the range allows any version, e.g. `*`.
+    pub fn minimum_of_range(range: &str) -> Option<Self> {
+        range
+            .split("||")
+            .map(|alternative| {
+                let alternative = alternative.trim();
+                // `<18` allows all the versions before 18
+                if alternative.starts_with('<') {
+                    return NodeVersion::parse("0");
+                }
+                // The hyphen ranges, e.g. `14 - 16`, start with their lower bound
+                let comparator = alternative.trim_start_matches(['>', '=', '^', '~', 'v']);
+                NodeVersion::parse(comparator.split_whitespace().next()?)
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .min()
+    }
+
+    /// Whether the API is available in this version, given the versions that added it.
+    ///
+    /// The last version of `since` is the release that added the API, the versions before it
+    /// are the backports to the older release lines, e.g. `["14.17.0", "15.6.0"]`.
+    pub fn supports(&self, since: &[&str]) -> bool {
+        let mut versions = since
+            .iter()
+            .filter_map(|version| NodeVersion::parse(version));
+        let Some(release) = versions.next_back() else {
+            return true;
+        };
+        *self >= release
+            || versions.any(|backport| backport.major == self.major && *self >= backport)
+    }
+}
+
+impl fmt::Display for NodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The built-in modules of Node.js that were added after Node.js 8, and the versions that
+/// added them, sorted by name. The name is the specifier without the `node:` prefix.
+const NODE_MODULES: &[(&str, &[&str])] = &[
+    ("assert/strict", &["15.0.0"]),
+    ("diagnostics_channel", &["14.17.0", "15.1.0"]),
+    ("dns/promises", &["15.0.0"]),
+    ("fs/promises", &["14.0.0"]),
+    ("http2", &["8.4.0"]),
+    ("inspector/promises", &["19.0.0"]),
+    ("path/posix", &["15.3.0"]),
+    ("path/win32", &["15.3.0"]),
+    ("perf_hooks", &["8.5.0"]),
+    ("readline/promises", &["17.0.0"]),
+    ("sea", &["20.12.0", "21.7.0"]),
+    ("sqlite", &["22.5.0"]),
+    ("stream/consumers", &["16.7.0"]),
+    ("stream/promises", &["15.0.0"]),
+    ("stream/web", &["16.5.0"]),
+    ("test", &["16.17.0", "18.0.0"]),
+    ("timers/promises", &["15.0.0"]),
+    ("trace_events", &["10.0.0"]),
+    ("util/types", &["15.0.0"]),
+    ("wasi", &["12.16.0", "13.3.0"]),
+    ("worker_threads", &["10.5.0"]),
+];
+
+/// The exports of the built-in modules of Node.js that were added after Node.js 10, and the
+/// versions that added them, sorted by module and by name.
+const NODE_APIS: &[(&str, &str, &[&str])] = &[
+    ("buffer", "Blob", &["15.7.0"]),
+    ("buffer", "File", &["18.13.0", "19.2.0"]),
+    ("buffer", "isAscii", &["18.15.0", "19.6.0"]),
+    ("buffer", "isUtf8", &["18.14.0", "19.4.0"]),
+    ("buffer", "resolveObjectURL", &["16.7.0"]),
+    ("crypto", "X509Certificate", &["15.6.0"]),
+    ("crypto", "checkPrime", &["15.8.0"]),
+    ("crypto", "generatePrime", &["15.8.0"]),
+    ("crypto", "getRandomValues", &["17.4.0"]),
+    ("crypto", "hash", &["20.12.0", "21.7.0"]),
+    ("crypto", "hkdf", &["15.0.0"]),
+    ("crypto", "randomUUID", &["14.17.0", "15.6.0"]),
+    ("crypto", "subtle", &["17.4.0"]),
+    ("crypto", "webcrypto", &["15.0.0"]),
+    ("events", "addAbortListener", &["18.18.0", "20.5.0"]),
+    ("events", "getEventListeners", &["14.17.0", "15.2.0"]),
+    ("events", "getMaxListeners", &["18.17.0", "19.9.0"]),
+    ("events", "on", &["12.16.0", "13.6.0"]),
+    ("events", "once", &["10.16.0", "11.13.0"]),
+    ("events", "setMaxListeners", &["15.4.0"]),
+    ("fs", "cp", &["16.7.0"]),
+    ("fs", "cpSync", &["16.7.0"]),
+    ("fs", "glob", &["22.0.0"]),
+    ("fs", "globSync", &["22.0.0"]),
+    ("fs", "lutimes", &["12.19.0", "14.5.0"]),
+    ("fs", "openAsBlob", &["19.8.0"]),
+    ("fs", "opendir", &["12.12.0"]),
+    ("fs", "readv", &["12.17.0", "13.13.0"]),
+    ("fs", "rm", &["14.14.0"]),
+    ("fs", "rmSync", &["14.14.0"]),
+    ("fs", "statfs", &["18.15.0", "19.6.0"]),
+    ("fs", "statfsSync", &["18.15.0", "19.6.0"]),
+    ("fs/promises", "constants", &["18.4.0"]),
+    ("fs/promises", "cp", &["16.7.0"]),
+    ("fs/promises", "glob", &["22.0.0"]),
+    ("fs/promises", "rm", &["14.14.0"]),
+    ("fs/promises", "statfs", &["18.15.0", "19.6.0"]),
+    ("module", "findSourceMap", &["12.17.0", "13.7.0"]),
+    ("module", "isBuiltin", &["16.17.0", "18.6.0"]),
+    ("module", "register", &["20.6.0"]),
+    ("os", "availableParallelism", &["18.14.0", "19.4.0"]),
+    ("os", "devNull", &["16.3.0"]),
+    ("os", "machine", &["16.18.0", "18.9.0"]),
+    ("os", "version", &["12.17.0", "13.11.0"]),
+    ("path", "matchesGlob", &["22.5.0"]),
+    ("process", "availableMemory", &["22.0.0"]),
+    ("process", "constrainedMemory", &["19.6.0"]),
+    ("process", "getActiveResourcesInfo", &["17.3.0"]),
+    ("process", "getBuiltinModule", &["22.3.0"]),
+    ("process", "loadEnvFile", &["20.12.0", "21.7.0"]),
+    ("stream", "addAbortSignal", &["15.4.0"]),
+    ("stream", "compose", &["16.9.0"]),
+    ("stream", "duplexPair", &["22.6.0"]),
+    ("stream", "getDefaultHighWaterMark", &["19.9.0"]),
+    ("stream", "isDisturbed", &["16.8.0"]),
+    ("timers/promises", "scheduler", &["16.14.0", "17.3.0"]),
+    ("timers/promises", "setInterval", &["15.9.0"]),
+    ("url", "urlToHttpOptions", &["14.18.0", "15.7.0"]),
+    ("util", "MIMEType", &["18.13.0", "19.1.0"]),
+    ("util", "aborted", &["19.7.0"]),
+    ("util", "getSystemErrorMap", &["14.17.0", "16.0.0"]),
+    ("util", "parseArgs", &["16.17.0", "18.3.0"]),
+    ("util", "parseEnv", &["21.7.0"]),
+    ("util", "stripVTControlCharacters", &["16.11.0"]),
+    ("util", "styleText", &["20.12.0", "21.7.0"]),
+    ("util", "toUSVString", &["16.8.0"]),
+    ("util", "transferableAbortController", &["18.11.0"]),
+    ("worker_threads", "BroadcastChannel", &["15.4.0"]),
+    ("worker_threads", "getEnvironmentData", &["15.12.0"]),
+    ("worker_threads", "markAsUntransferable", &["14.5.0"]),
+    ("zlib", "crc32", &["20.15.0", "22.2.0"]),
+];
+
+/// Returns the versions of Node.js that added the built-in module `name`, e.g. `test` for
+/// `node:test`. It returns `None` when the module is older than the table.
+pub fn node_module_since(name: &str) -> Option<&'static [&'static str]> {
+    NODE_MODULES
+        .binary_search_by_key(&name, |(module, _)| module)
+        .ok()
+        .map(|index| NODE_MODULES[index].1)
+}
+
+/// Returns the versions of Node.js that added the export `name` of the built-in module
+/// `module`, e.g. `cp` of `fs`. It returns `None` when the export is older than the table.
+pub fn node_api_since(module: &str, name: &str) -> Option<&'static [&'static str]> {
+    NODE_APIS
+        .binary_search_by(|(api_module, api_name, _)| (*api_module, *api_name).cmp(&(module, name)))
+        .ok()
+        .map(|index| NODE_APIS[index].2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{node_api_since, NodeVersion, NODE_APIS, NODE_MODULES};
+
+    #[test]
+    fn the_tables_are_sorted() {
+        assert!(NODE_MODULES
+            .windows(2)
+            .all(|window| window[0].0 < window[1].0));
+        assert!(NODE_APIS
+            .windows(2)
+            .all(|window| (window[0].0, window[0].1) < (window[1].0, window[1].1)));
+    }
+
+    #[test]
+    fn finds_the_minimum_of_the_ranges() {
+        let minimum = |range| NodeVersion::minimum_of_range(range).map(|v| v.to_string());
+
+        assert_eq!(minimum(">=14.17.0").as_deref(), Some("14.17.0"));
+        assert_eq!(minimum("^16 || ^14.18").as_deref(), Some("14.18.0"));
+        assert_eq!(minimum(">= 18.x").as_deref(), Some("18.0.0"));
+        assert_eq!(minimum("14 - 16").as_deref(), Some("14.0.0"));
+        assert_eq!(minimum("<18").as_deref(), Some("0.0.0"));
+        assert_eq!(minimum("*"), None);
+        assert_eq!(minimum("latest"), None);
+    }
+
+    #[test]
+    fn supports_the_backports() {
+        let random_uuid = node_api_since("crypto", "randomUUID").unwrap();
+        let version = |text| NodeVersion::parse(text).unwrap();
+
+        assert!(version("14.17.0").supports(random_uuid));
+        assert!(!version("15.0.0").supports(random_uuid));
+        assert!(version("16.0.0").supports(random_uuid));
+        assert!(!version("14.0.0").supports(random_uuid));
+        assert_eq!(node_api_since("fs", "readFile"), None);
+    }
+}
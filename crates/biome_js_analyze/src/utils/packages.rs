@@ -0,0 +1,53 @@
+/// The packages that the analyzed file can import, from the `package.json` closest to it,
+/// registered as a service of the analyzer.
+#[derive(Debug, Clone, Default)]
+pub struct DeclaredPackages {
+    /// The name of the package of the file and its dependencies, `None` when no
+    /// `package.json` applies to the file
+    declared: Option<Vec<String>>,
+    /// The names of the packages of the project, e.g. the packages of a monorepo
+    project: Vec<String>,
+}
+
+impl DeclaredPackages {
+    pub fn new(declared: Option<Vec<String>>, project: Vec<String>) -> Self {
+        Self { declared, project }
+    }
+
+    /// Whether a `package.json` applies to the file. Without it, no package is declared.
+    pub fn has_manifest(&self) -> bool {
+        self.declared.is_some()
+    }
+
+    /// Whether the `package.json` of the file declares the package `name`
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.declared
+            .iter()
+            .flatten()
+            .any(|declared| declared == name)
+    }
+
+    /// Whether `name` is a package of the project
+    pub fn is_project_package(&self, name: &str) -> bool {
+        self.project.iter().any(|package| package == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeclaredPackages;
+
+    #[test]
+    fn finds_the_declared_packages() {
+        let packages = DeclaredPackages::new(
+            Some(vec![String::from("app"), String::from("react")]),
+            vec![String::from("app"), String::from("@acme/utils")],
+        );
+
+        assert!(packages.has_manifest());
+        assert!(packages.is_declared("react"));
+        assert!(!packages.is_declared("@acme/utils"));
+        assert!(packages.is_project_package("@acme/utils"));
+        assert!(!DeclaredPackages::default().has_manifest());
+    }
+}
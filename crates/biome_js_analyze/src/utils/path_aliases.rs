@@ -0,0 +1,41 @@
+/// The patterns of the path aliases of the project, e.g. `@app/*`, registered as a
+/// service of the analyzer.
+///
+/// A pattern contains at most one `*`, which matches any text.
+#[derive(Debug, Clone, Default)]
+pub struct PathAliasPatterns(Vec<String>);
+
+impl PathAliasPatterns {
+    pub fn new(patterns: Vec<String>) -> Self {
+        Self(patterns)
+    }
+
+    /// Whether `specifier` matches one of the patterns
+    pub fn matches(&self, specifier: &str) -> bool {
+        self.0.iter().any(|pattern| match pattern.split_once('*') {
+            Some((prefix, suffix)) => {
+                !suffix.contains('*')
+                    && specifier.len() >= prefix.len() + suffix.len()
+                    && specifier.starts_with(prefix)
+                    && specifier.ends_with(suffix)
+            }
+            None => pattern == specifier,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathAliasPatterns;
+
+    #[test]
+    fn matches_the_patterns() {
+        let patterns = PathAliasPatterns::new(vec![String::from("@app/*"), String::from("~")]);
+
+        assert!(patterns.matches("@app/button"));
+        assert!(patterns.matches("~"));
+        assert!(!patterns.matches("@application"));
+        assert!(!patterns.matches("~/button"));
+        assert!(!PathAliasPatterns::default().matches("@app/button"));
+    }
+}
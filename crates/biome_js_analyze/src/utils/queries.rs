@@ -0,0 +1,73 @@
+//! The computations shared by several rules, whose results are stored in the [QueryCache] of
+//! the analysis, see `RuleContext::query_cache`.
+
+use crate::react::{jsx_member_name_is_react_fragment, jsx_reference_identifier_is_fragment};
+use biome_analyze::{CachedQuery, QueryCache};
+use biome_js_semantic::{IsBindingAstNode, Reference, ReferencesExtensions, SemanticModel};
+use biome_js_syntax::classification;
+use biome_js_syntax::{AnyJsExpression, AnyJsxElementName, JsSyntaxNode};
+use biome_rowan::AstNode;
+use std::rc::Rc;
+
+struct References;
+
+impl CachedQuery for References {
+    const NAME: &'static str = "js_references";
+    type Key = JsSyntaxNode;
+    type Output = Rc<[Reference]>;
+}
+
+/// Returns the references of `binding`, in the order of the source, see
+/// [ReferencesExtensions::all_references]
+pub(crate) fn all_references<T: IsBindingAstNode>(
+    cache: &QueryCache,
+    binding: &T,
+    model: &SemanticModel,
+) -> Rc<[Reference]> {
+    cache.get_or_compute::<References>(binding.syntax().clone(), |_| {
+        binding.all_references(model).collect()
+    })
+}
+
+struct ReactFragment;
+
+impl CachedQuery for ReactFragment {
+    const NAME: &'static str = "js_react_fragment";
+    type Key = AnyJsxElementName;
+    type Output = Option<bool>;
+}
+
+/// Whether the name of a JSX element refers to the `Fragment` component of React, e.g.
+/// `<Fragment>` or `<React.Fragment>`. Returns [None] if the name is bogus.
+pub(crate) fn is_react_fragment(
+    cache: &QueryCache,
+    name: &AnyJsxElementName,
+    model: &SemanticModel,
+) -> Option<bool> {
+    cache.get_or_compute::<ReactFragment>(name.clone(), |name| match name {
+        AnyJsxElementName::JsxMemberName(member_name) => {
+            jsx_member_name_is_react_fragment(member_name, model)
+        }
+        AnyJsxElementName::JsxReferenceIdentifier(identifier) => {
+            jsx_reference_identifier_is_fragment(identifier, model)
+        }
+        AnyJsxElementName::JsxName(_) | AnyJsxElementName::JsxNamespaceName(_) => Some(false),
+    })
+}
+
+struct ConstantExpression;
+
+impl CachedQuery for ConstantExpression {
+    const NAME: &'static str = "js_constant_expression";
+    type Key = AnyJsExpression;
+    type Output = bool;
+}
+
+/// Whether `expression` always evaluates to the same value, see
+/// [classification::is_constant_expression]
+pub(crate) fn is_constant_expression(cache: &QueryCache, expression: &AnyJsExpression) -> bool {
+    cache.get_or_compute::<ConstantExpression>(
+        expression.clone(),
+        classification::is_constant_expression,
+    )
+}
@@ -0,0 +1,149 @@
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsModuleItem, AnyJsRoot, JsCallExpression, JsSyntaxNode,
+};
+use biome_rowan::{AstNode, AstNodeList};
+use std::fmt;
+use std::path::Path;
+
+/// A test framework whose API is checked by the rules of the tests
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TestFramework {
+    Jest,
+    Vitest,
+}
+
+impl TestFramework {
+    /// Returns the framework of an imported module, e.g. `vitest`
+    fn from_import_source(source: &str) -> Option<Self> {
+        match source {
+            "@jest/globals" => Some(TestFramework::Jest),
+            "vitest" => Some(TestFramework::Vitest),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TestFramework {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TestFramework::Jest => f.write_str("Jest"),
+            TestFramework::Vitest => f.write_str("Vitest"),
+        }
+    }
+}
+
+/// The test framework that runs the analyzed file, registered as a service of the analyzer.
+///
+/// The framework is the one imported by the file. A file that doesn't import a framework is a
+/// test of Jest, which declares its API as globals, when its name is a name of test file, e.g.
+/// `button.test.js`, `button.spec.tsx` or `__tests__/button.js`. The rules of the tests don't
+/// run on the other files.
+#[derive(Debug, Clone, Default)]
+pub struct TestFile {
+    framework: Option<TestFramework>,
+}
+
+impl TestFile {
+    pub fn detect(root: &AnyJsRoot, path: &Path) -> Self {
+        let imported = match root {
+            AnyJsRoot::JsModule(module) => module.items().iter().find_map(|item| {
+                let AnyJsModuleItem::JsImport(import) = item else {
+                    return None;
+                };
+                TestFramework::from_import_source(import.source_text().ok()?.text())
+            }),
+            _ => None,
+        };
+        let framework = imported.or_else(|| is_test_path(path).then_some(TestFramework::Jest));
+        Self { framework }
+    }
+
+    /// The framework of the file, `None` when the file isn't a test file
+    pub fn framework(&self) -> Option<TestFramework> {
+        self.framework
+    }
+}
+
+/// Whether the name of the file, or of one of its directories, is a name of test file
+fn is_test_path(path: &Path) -> bool {
+    let is_test_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            name.split('.')
+                .skip(1)
+                .any(|part| matches!(part, "test" | "spec"))
+        });
+    is_test_name
+        || path
+            .components()
+            .any(|component| component.as_os_str() == "__tests__")
+}
+
+/// A call of the API of the test frameworks
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TestCallKind {
+    /// `describe()`, which groups the tests
+    Describe,
+    /// `test()` or `it()`
+    Test,
+    /// `beforeAll()`, `beforeEach()`, `afterAll()` or `afterEach()`
+    Hook,
+}
+
+impl TestCallKind {
+    /// Returns the kind of the call, e.g. [TestCallKind::Test] for `test.only("title", () => {})`.
+    ///
+    /// The calls of the tables, e.g. `test.each(table)("title", (a, b) => {})`, return `None`,
+    /// because the callbacks receive the rows of the table.
+    pub fn of_call(call: &JsCallExpression) -> Option<Self> {
+        let mut callee = call.callee().ok()?.omit_parentheses();
+        // The modifiers, e.g. `test.concurrent.only`
+        while let AnyJsExpression::JsStaticMemberExpression(member) = &callee {
+            let name = member.member().ok()?;
+            if !matches!(
+                name.text().as_str(),
+                "only" | "skip" | "concurrent" | "sequential" | "fails" | "todo"
+            ) {
+                return None;
+            }
+            callee = member.object().ok()?.omit_parentheses();
+        }
+        let name = callee.as_js_reference_identifier()?.name().ok()?;
+        match name.text() {
+            "describe" | "fdescribe" | "xdescribe" => Some(TestCallKind::Describe),
+            "test" | "it" | "fit" | "xit" | "xtest" => Some(TestCallKind::Test),
+            "beforeAll" | "beforeEach" | "afterAll" | "afterEach" => Some(TestCallKind::Hook),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the call of the API of the test frameworks whose callback is `function`, e.g. the call
+/// of `test` for the arrow function of `test("title", () => {})`
+pub fn test_call_of_callback(function: &JsSyntaxNode) -> Option<(JsCallExpression, TestCallKind)> {
+    // The callback is an argument of the call
+    let call = function
+        .parent()?
+        .parent()?
+        .parent()
+        .and_then(JsCallExpression::cast)?;
+    let kind = TestCallKind::of_call(&call)?;
+    Some((call, kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_test_path;
+    use std::path::Path;
+
+    #[test]
+    fn detects_the_names_of_test_files() {
+        assert!(is_test_path(Path::new("src/button.test.js")));
+        assert!(is_test_path(Path::new("src/button.spec.tsx")));
+        assert!(is_test_path(Path::new("src/__tests__/button.js")));
+        assert!(!is_test_path(Path::new("src/button.js")));
+        assert!(!is_test_path(Path::new("src/test.js")));
+        assert!(!is_test_path(Path::new("src/testing/button.js")));
+    }
+}
@@ -0,0 +1,3 @@
+export * from "./button";
+export { Input } from "./input";
+export * as forms from "./forms";
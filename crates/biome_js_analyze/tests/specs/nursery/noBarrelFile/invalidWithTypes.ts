@@ -0,0 +1,3 @@
+export type { ButtonProps } from "./button";
+export { type InputProps } from "./input";
+export { Button } from "./button";
@@ -0,0 +1,2 @@
+export { Button } from "./button";
+export const Input = () => {};
@@ -0,0 +1,3 @@
+export type * from "./button";
+export type { InputProps } from "./input";
+export { type FormProps } from "./form";
@@ -0,0 +1,34 @@
+test("parses the user", () => {
+	const user = parse(input);
+	if (user) {
+		expect(user.name).toBe("Ada");
+	}
+});
+
+test("parses the users", () => {
+	for (const user of parse(input)) {
+		user.admin ? expect(user.role).toBe("admin") : expect(user.role).toBe("user");
+	}
+});
+
+it("parses the role", () => {
+	const user = parse(input);
+	user.admin && expect(user.role).toBe("admin");
+	switch (user.kind) {
+		case "bot":
+			expect(user.name).toMatch(/bot$/);
+			break;
+	}
+});
+
+test("rejects the invalid input", async () => {
+	try {
+		await parse("{");
+	} catch (error) {
+		expect(error.message).toMatch("Unexpected");
+	}
+});
+
+test("rejects the invalid input", () => {
+	return parse("{").catch((error) => expect(error.message).toMatch("Unexpected"));
+});
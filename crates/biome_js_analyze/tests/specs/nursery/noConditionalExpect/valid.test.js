@@ -0,0 +1,25 @@
+test("parses the user", () => {
+	const user = parse(input);
+	expect(user?.name).toBe("Ada");
+	expect(user.admin ? "admin" : "user").toBe("admin");
+	if (expect) {
+		reset();
+	}
+});
+
+test("rejects the invalid input", async () => {
+	await expect(parse("{")).rejects.toThrow("Unexpected");
+	expect(() => parse("{")).toThrow("Unexpected");
+});
+
+function check(user) {
+	if (user) {
+		expect(user.name).toBe("Ada");
+	}
+}
+
+if (process.env.CI) {
+	test("runs in the CI", () => {
+		expect(process.env.CI).toBeTruthy();
+	});
+}
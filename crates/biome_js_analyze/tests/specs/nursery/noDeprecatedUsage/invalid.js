@@ -0,0 +1,25 @@
+/**
+ * @deprecated Use `fetchUser` instead.
+ */
+function getUser(id) {}
+
+getUser(1);
+
+/** @deprecated */
+class Cache {}
+
+new Cache();
+
+/**
+ * @deprecated Use `format` instead.
+ */
+const print = (value) => String(value);
+
+print(1);
+
+/**
+ * @deprecated
+ */
+export function parse(text) {}
+
+(parse)("{}");
@@ -0,0 +1,23 @@
+/**
+ * @param {number} id
+ */
+function fetchUser(id) {}
+
+fetchUser(1);
+
+/**
+ * @deprecated Use `count` instead.
+ */
+function size(items) {
+	return items.length === 0 ? 0 : 1 + size(items.slice(1));
+}
+
+function shadow() {
+	const size = () => 0;
+	size();
+}
+
+/** @deprecated */
+const legacy = {};
+
+legacy.run();
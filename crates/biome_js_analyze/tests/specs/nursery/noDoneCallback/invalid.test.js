@@ -0,0 +1,18 @@
+test("loads the user", (done) => {
+	load("ada").then(() => done());
+});
+
+it("loads the user", function (callback) {
+	load("ada").then(callback);
+});
+
+beforeEach((done) => {
+	reset(done);
+});
+
+test.concurrent("loads the users", async (done) => {
+	await load("ada");
+	done();
+});
+
+afterAll(function ({ done }) {});
@@ -0,0 +1,14 @@
+test("loads the user", async () => {
+	const user = await load("ada");
+	expect(user.name).toBe("Ada");
+});
+
+test("loads the user", () => load("ada"));
+
+beforeEach(() => reset());
+
+test.each([1, 2])("loads %d", (id) => load(id));
+
+describe("load", () => {});
+
+load("ada", (done) => done());
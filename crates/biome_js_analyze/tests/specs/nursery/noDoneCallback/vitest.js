@@ -0,0 +1,17 @@
+import { beforeEach, test } from "vitest";
+
+test("loads the user", async ({ expect }) => {
+	expect(await load("ada")).toBeDefined();
+});
+
+test("loads the user", (context) => {
+	context.skip();
+});
+
+test("loads the user", (done) => {
+	load("ada").then(() => done());
+});
+
+beforeEach((done) => {
+	reset(done);
+});
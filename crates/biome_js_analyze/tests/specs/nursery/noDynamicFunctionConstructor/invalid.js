@@ -0,0 +1,13 @@
+new Function(body);
+
+new Function("event", body);
+
+Function(`return this.${property}`);
+
+new Function("a", "b", "return " + expression);
+
+new Function(...parts);
+
+new globalThis.Function("value", code);
+
+window.Function(source)();
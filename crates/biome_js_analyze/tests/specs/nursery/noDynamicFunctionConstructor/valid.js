@@ -0,0 +1,15 @@
+new Function("a", "b", "return a + b");
+
+Function(`return this`);
+
+new Function();
+
+new RegExp(pattern);
+
+function shadowed() {
+	function Function(code) {}
+	new Function(code);
+}
+
+const obj = { Function };
+obj.Function(code);
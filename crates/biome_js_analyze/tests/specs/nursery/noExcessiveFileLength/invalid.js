@@ -0,0 +1,5 @@
+import { createUser } from "./user.js";
+
+const user = createUser("Ada");
+console.log(user.name);
+console.log(user.email);
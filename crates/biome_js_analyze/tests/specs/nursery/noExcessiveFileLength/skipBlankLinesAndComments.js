@@ -0,0 +1,10 @@
+// The user is created on startup
+import { createUser } from "./user.js";
+
+/**
+ * The first user
+ */
+const user = createUser("Ada");
+
+console.log(user.name); // The name is always defined
+console.log(user.email);
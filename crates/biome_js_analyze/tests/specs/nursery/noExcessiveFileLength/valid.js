@@ -0,0 +1,4 @@
+import { createUser } from "./user.js";
+
+const user = createUser("Ada");
+console.log(user.name);
@@ -0,0 +1,11 @@
+function createUser(name, email, role, team) {}
+
+const sum = function (a, b, c, d) {};
+
+const log = (level, message, context, ...rest) => {};
+
+class Service {
+	constructor(client, cache, logger, config) {}
+
+	request(method, url, body, headers) {}
+}
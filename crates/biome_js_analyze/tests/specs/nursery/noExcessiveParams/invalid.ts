@@ -0,0 +1,11 @@
+declare function createUser(name: string, email: string, role: string, team: string): void;
+
+type Handler = (request: Request, response: Response, next: () => void, error: Error) => void;
+
+interface Router {
+	route(method: string, path: string, handler: Handler, options: object): void;
+}
+
+class Service {
+	constructor(private client: Client, private cache: Cache, private logger: Logger, config: Config) {}
+}
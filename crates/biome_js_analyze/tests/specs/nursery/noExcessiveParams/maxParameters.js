@@ -0,0 +1,3 @@
+function createUser(name, email) {}
+
+function createTeam(name, members, owner) {}
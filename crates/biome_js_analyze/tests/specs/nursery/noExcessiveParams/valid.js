@@ -0,0 +1,11 @@
+function createUser({ name, email, role, team }) {}
+
+const sum = function (a, b, c) {};
+
+const log = (level, message, ...rest) => {};
+
+class Service {
+	constructor(client, cache, logger) {}
+
+	request() {}
+}
@@ -0,0 +1,3 @@
+function handle(this: Window, event: Event, target: Element, options: object) {}
+
+type Handler = (request: Request, response: Response, next: () => void) => void;
@@ -0,0 +1,28 @@
+function greet(user) {
+	const name = user.name;
+	console.log(`Hello ${name}`);
+}
+
+const sum = function (a, b) {
+	const result = a + b;
+	return result;
+};
+
+const double = (value) => {
+	const result = value * 2;
+	return result;
+};
+
+class Counter {
+	increment() {
+		this.count += 1;
+		return this.count;
+	}
+}
+
+const logger = {
+	log(message) {
+		const line = `[log] ${message}`;
+		console.log(line);
+	},
+};
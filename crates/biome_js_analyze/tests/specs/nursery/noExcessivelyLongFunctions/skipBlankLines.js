@@ -0,0 +1,10 @@
+function greet(user) {
+
+	console.log(`Hello ${user.name}`);
+
+}
+
+function farewell(user) {
+	// The name is always defined
+	console.log(`Goodbye ${user.name}`);
+}
@@ -0,0 +1,18 @@
+function greet(user) {
+	// The name is always defined
+
+	console.log(`Hello ${user.name}`);
+}
+
+function farewell(user) {
+	/*
+	 * The name is always defined
+	 */
+	console.log(`Goodbye ${user.name}`);
+}
+
+function welcome(user) {
+	const name = user.name; // The name is always defined
+
+	console.log(`Welcome ${name}`);
+}
@@ -0,0 +1,18 @@
+function greet(user) {
+	console.log(`Hello ${user.name}`);
+}
+
+const double = (value) => value * 2;
+
+class Counter {
+	increment() {
+		return ++this.count;
+	}
+}
+
+/**
+ * The comments before a function aren't counted.
+ */
+function sum(a, b) {
+	return a + b;
+}
@@ -0,0 +1,9 @@
+"use strict";
+import React, { useEffect, useState } from "react";
+
+export default function Counter() {
+	const [count, setCount] = useState(0);
+	useEffect(() => {}, []);
+	const ref = React.useRef(null);
+	return <p ref={ref}>{count}</p>;
+}
@@ -0,0 +1,8 @@
+"use client";
+import { useEffect, useState } from "react";
+
+export function Counter() {
+	const [count, setCount] = useState(0);
+	useEffect(() => {}, []);
+	return <p>{count}</p>;
+}
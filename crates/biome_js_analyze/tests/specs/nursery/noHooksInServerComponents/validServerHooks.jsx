@@ -0,0 +1,12 @@
+import { useId, useMemo } from "react";
+import { useState } from "./state";
+
+function useRef() {}
+
+export default function Field({ items }) {
+	const id = useId();
+	const sorted = useMemo(() => items.toSorted(), [items]);
+	const [value] = useState();
+	useRef();
+	return <input id={id} value={value} />;
+}
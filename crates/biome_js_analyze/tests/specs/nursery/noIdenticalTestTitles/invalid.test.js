@@ -0,0 +1,15 @@
+describe("parse", () => {
+	test("reads the numbers", () => {});
+	it("reads the numbers", () => {});
+
+	test.skip("reads the strings", () => {});
+	test("reads the strings", () => {});
+
+	describe("errors", () => {});
+	describe("errors", () => {});
+});
+
+describe("parse", () => {});
+
+test(`formats the dates`, () => {});
+test("formats the dates", () => {});
@@ -0,0 +1,2 @@
+test("reads the numbers", () => {});
+test("reads the numbers", () => {});
@@ -0,0 +1,19 @@
+describe("parse", () => {
+	beforeEach(() => reset());
+	beforeEach(() => load());
+
+	test("reads the numbers", () => {});
+	test("reads the strings", () => {});
+
+	describe("reads the numbers", () => {});
+});
+
+describe("format", () => {
+	test("reads the numbers", () => {});
+});
+
+test(title, () => {});
+test(title, () => {});
+
+test.each([1, 2])("reads %d", (value) => {});
+test.each([3, 4])("reads %d", (value) => {});
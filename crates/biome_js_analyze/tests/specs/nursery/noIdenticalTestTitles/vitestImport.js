@@ -0,0 +1,6 @@
+import { describe, test } from "vitest";
+
+describe("parse", () => {
+	test("reads the numbers", () => {});
+	test("reads the numbers", () => {});
+});
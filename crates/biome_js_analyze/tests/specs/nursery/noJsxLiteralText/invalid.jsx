@@ -0,0 +1,17 @@
+const Title = () => <h1>Welcome back</h1>;
+
+const Greeting = ({ name }) => (
+	<p>
+		Hello {name}
+	</p>
+);
+
+const Search = () => <input placeholder="Search the catalog" aria-label="Search" />;
+
+const Logo = () => <img src="/logo.svg" alt={"The logo of the company"} />;
+
+const Status = ({ online }) => <span>{online ? "Online" : "Offline"}</span>;
+
+const Empty = ({ items }) => <ul>{items.length === 0 && `No items`}</ul>;
+
+const Link = () => <a title='Open the documentation' href="/docs">{("Documentation")}</a>;
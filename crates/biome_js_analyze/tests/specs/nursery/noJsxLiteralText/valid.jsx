@@ -0,0 +1,19 @@
+const Title = () => <h1>{t("home.welcome")}</h1>;
+
+const Separator = () => <span> - </span>;
+
+const Counter = ({ count }) => <span>{count} / 100</span>;
+
+const Logo = () => <img src="/logo.svg" alt={t("home.logo")} className="logo" />;
+
+const Input = () => <input type="text" name="email" data-testid="email-input" />;
+
+const Message = ({ name }) => <p>{`${greeting}, ${name}`}</p>;
+
+const Status = ({ online }) => <span className={online ? "online" : "offline"} />;
+
+const Styles = () => <style>{".title { color: red; }"}</style>;
+
+const Script = () => <script>{"window.analytics = []"}</script>;
+
+const Choice = ({ a }) => <span>{a === "yes" ? t("yes") : t("no")}</span>;
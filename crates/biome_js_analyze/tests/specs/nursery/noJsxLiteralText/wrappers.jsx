@@ -0,0 +1,15 @@
+const Title = () => (
+	<Trans>
+		Welcome back, <strong>{name}</strong>
+	</Trans>
+);
+
+const Hint = () => <span>{msg(<b>Press enter to continue</b>)}</span>;
+
+const Deferred = () => <span>{i18n._(<b>Loading</b>)}</span>;
+
+const Brand = () => <footer>Biome</footer>;
+
+const Copyright = () => <footer>(c) 2023 Biome</footer>;
+
+const Other = () => <footer>Made with love</footer>;
@@ -0,0 +1,38 @@
+const promise = Promise.resolve(true);
+if (promise) {}
+
+if (new Promise((resolve) => resolve(true))) {}
+
+while (fetch("/status")) {}
+
+do {} while (Promise.all([]));
+
+for (; request.then(check); ) {}
+
+async function isReady() {
+	return true;
+}
+if (!isReady()) {}
+
+const isValid = async () => true;
+const valid = isValid() ? "yes" : "no";
+
+const response = fetch("/data") && process();
+
+if (ready || promise) {}
+
+const notReady = !(ready && isReady());
+
+items.forEach(async (item) => {
+	await save(item);
+});
+
+items.filter(async function (item) {
+	return await isAvailable(item);
+});
+
+async function check(item) {
+	return await isAvailable(item);
+}
+items.some(check);
+items.every(isValid);
@@ -0,0 +1,31 @@
+const promise = Promise.resolve(true);
+if (await promise) {}
+
+async function isReady() {
+	return true;
+}
+while (!(await isReady())) {}
+
+const value = (await fetch("/data")) || fallback;
+
+let reassigned = Promise.resolve(true);
+if (reassigned) {}
+
+function isValid() {
+	return true;
+}
+if (isValid()) {}
+
+const response = cached ?? fetch("/data");
+const result = fetch("/data") ?? fallback;
+
+for (const item of items) {
+	await save(item);
+}
+await Promise.all(items.map(async (item) => await save(item)));
+items.forEach((item) => save(item));
+items.filter((item) => item.available);
+
+function scope(Promise) {
+	if (new Promise()) {}
+}
@@ -0,0 +1,3 @@
+export * from "./button";
+export * as forms from "./forms";
+export * from "./data.json" assert { type: "json" };
@@ -0,0 +1,3 @@
+export { Button } from "./button";
+export type * from "./types";
+export type * as types from "./types";
@@ -0,0 +1,15 @@
+<a href="javascript:void(0)">Open</a>;
+
+<a href='JAVASCRIPT:open()'>Open</a>;
+
+<iframe src={" javascript:alert(document.cookie)"} />;
+
+<form action={`javascript:submit()`} />;
+
+<button formAction="javascript:save()">Save</button>;
+
+<svg>
+	<use xlink:href="javascript:alert(1)" />
+</svg>;
+
+<Link href="javascript:void(0)">Open</Link>;
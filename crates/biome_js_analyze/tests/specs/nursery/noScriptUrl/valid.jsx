@@ -0,0 +1,11 @@
+<a href="/docs">Open</a>;
+
+<a href="https://www.javascript.com">Open</a>;
+
+<a href={url}>Open</a>;
+
+<a href={`javascript:${code}`}>Open</a>;
+
+<img src="/logo.svg" alt="javascript:" />;
+
+<button type="button" onClick={open}>Open</button>;
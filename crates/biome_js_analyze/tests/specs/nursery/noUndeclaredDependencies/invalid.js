@@ -0,0 +1,5 @@
+import { debounce } from "lodash";
+import map from "lodash/map";
+import { Button } from "@acme/ui/button";
+import "reflect-metadata";
+export { format } from "date-fns";
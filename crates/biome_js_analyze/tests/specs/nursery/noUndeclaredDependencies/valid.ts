@@ -0,0 +1,11 @@
+import { useState } from "react";
+import { createRoot } from "react-dom/client";
+import { Button } from "@acme/ui/button";
+import { config } from "@acme/app/config";
+import type { DebouncedFunc } from "lodash";
+export type { Dictionary } from "lodash";
+import { readFile } from "node:fs/promises";
+import { join } from "path";
+import { test } from "bun:test";
+import { helper } from "./helper";
+import { internal } from "#internal/utils";
@@ -0,0 +1,9 @@
+element.innerHTML = `<p>Hello ${name}</p>`;
+
+list.innerHTML += `<li>${item.label}</li>`;
+
+node.outerHTML = (`<span class="${className}">${label}</span>`);
+
+element["innerHTML"] = `<b>${message}</b>`;
+
+document.body.innerHTML = `<main>${content}</main>`;
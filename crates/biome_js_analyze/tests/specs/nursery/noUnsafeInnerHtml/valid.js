@@ -0,0 +1,15 @@
+element.textContent = `Hello ${name}`;
+
+element.innerHTML = `<p>Hello</p>`;
+
+element.innerHTML = html`<p>Hello ${name}</p>`;
+
+element.innerHTML = sanitize(`<p>Hello ${name}</p>`);
+
+element.innerHTML = "";
+
+element.innerHTML ||= `<p>${fallback}</p>`;
+
+element[innerHTML] = `<p>${name}</p>`;
+
+const innerHTML = `<p>${name}</p>`;
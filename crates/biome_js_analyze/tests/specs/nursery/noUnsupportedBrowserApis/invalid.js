@@ -0,0 +1,18 @@
+const name = user?.profile.name;
+const first = items?.[0];
+callback?.();
+const port = options.port ?? 8080;
+options.retries ||= 3;
+const timeout = 1_000;
+const big = 10n;
+class Counter {
+	#count = 0;
+	static {
+		Counter.instances = 0;
+	}
+}
+const copy = structuredClone(settings);
+const observer = new ResizeObserver(() => {});
+const own = Object.hasOwn(settings, "port");
+await Promise.any([first, copy]);
+document.querySelectorAll("li:has(> a)");
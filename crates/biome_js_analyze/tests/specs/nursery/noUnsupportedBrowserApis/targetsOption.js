@@ -0,0 +1,2 @@
+const name = user?.profile.name;
+const copy = structuredClone(settings);
@@ -0,0 +1,14 @@
+const name = user?.profile.name;
+const port = options.port ?? 8080;
+options.retries ||= 3;
+const copy = structuredClone(settings);
+const own = Object.hasOwn(settings, "port");
+document.querySelectorAll("li > a");
+
+if (typeof queueMicrotask === "function") {
+	queueMicrotask(flush);
+}
+
+function structuredClone(value) {
+	return JSON.parse(JSON.stringify(value));
+}
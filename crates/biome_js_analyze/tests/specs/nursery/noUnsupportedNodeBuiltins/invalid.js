@@ -0,0 +1,14 @@
+import fs from "node:fs";
+import * as crypto from "crypto";
+import { cp, readFile } from "fs/promises";
+import { test } from "node:test";
+export { styleText } from "node:util";
+
+fs.cp("src", "dist", { recursive: true }, () => {});
+fs.readFileSync("package.json");
+crypto.randomUUID();
+
+const { parseArgs } = require("node:util");
+const os = require("os");
+os.availableParallelism();
+require("fs").rmSync("dist");
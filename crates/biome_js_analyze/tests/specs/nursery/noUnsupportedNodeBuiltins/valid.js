@@ -0,0 +1,15 @@
+import fs from "node:fs";
+import { randomUUID } from "node:crypto";
+import { rm } from "fs/promises";
+import { channel } from "node:diagnostics_channel";
+
+fs.rmSync("dist", { recursive: true, force: true });
+await rm("dist", { recursive: true, force: true });
+randomUUID();
+
+// Not the built-in modules
+import { glob } from "glob";
+const { test } = require("./test");
+function load(require) {
+	return require("fs").glob;
+}
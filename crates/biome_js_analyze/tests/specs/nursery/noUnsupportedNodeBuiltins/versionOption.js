@@ -0,0 +1,4 @@
+import { styleText } from "node:util";
+import { cp } from "node:fs/promises";
+
+console.log(styleText("green", "Done"));
@@ -0,0 +1,11 @@
+class CachedFetcher implements Fetcher {
+	async fetchData() {
+		return this.cache.get('/data');
+	}
+}
+
+class Cache {
+	async get(key) {
+		return this.entries.get(key);
+	}
+}
@@ -0,0 +1,9 @@
+class CachedFetcher extends Fetcher {
+	override async fetchData() {
+		return this.cache.get('/data');
+	}
+
+	async clear() {
+		this.cache.clear();
+	}
+}
@@ -55,3 +55,11 @@ async function complexFunction() {
 		return fetch('condition-data');
 	}
 }
+
+const fetchItem = async (id) => {
+	return fetch(`/items/${id}`);
+};
+
+async function* generateIds() {
+	yield 1;
+}
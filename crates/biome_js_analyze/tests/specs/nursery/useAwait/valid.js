@@ -49,3 +49,9 @@ class NoOperationClass {
 async function wrapperFetchData() {
 	return await fetchDataAsync();
 }
+
+async function logChunks(stream) {
+	for await (const chunk of stream) {
+		console.log(chunk);
+	}
+}
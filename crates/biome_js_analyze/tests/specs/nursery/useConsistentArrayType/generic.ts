@@ -0,0 +1,5 @@
+let names: string[];
+let ids: readonly (number | string)[];
+let grid: number[][];
+let frozen: readonly string[][];
+let list: Array<string>;
@@ -0,0 +1,6 @@
+let names: Array<string>;
+let ids: ReadonlyArray<number | string>;
+let callbacks: Array<() => void>;
+let keys: Array<keyof Options>;
+let grid: Array<Array<number>>;
+let frozen: Array<ReadonlyArray<string>>;
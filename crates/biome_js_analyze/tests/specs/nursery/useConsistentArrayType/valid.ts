@@ -0,0 +1,6 @@
+let names: string[];
+let ids: readonly (number | string)[];
+let grid: number[][];
+let items: Array;
+let pairs: Array<string, number>;
+let custom: Collections.Array<string>;
@@ -0,0 +1,4 @@
+let scores: Record<string, number>;
+let handlers: Record<symbol, () => void>;
+let tags: Record<"a" | "b", string>;
+let nested: Readonly<Record<number, string>>;
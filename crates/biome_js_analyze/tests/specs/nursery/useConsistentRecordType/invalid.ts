@@ -0,0 +1,4 @@
+let scores: { [name: string]: number };
+let handlers: { [event: string]: () => void };
+let cache: { readonly [id: number]: string };
+type Flags = { [flag: string]: boolean };
@@ -0,0 +1,7 @@
+let scores: Record<string, number>;
+let point: { x: number; y: number };
+let mixed: { [name: string]: number; length: number };
+type Tree = { [name: string]: Tree };
+interface Dictionary {
+	[key: string]: string;
+}
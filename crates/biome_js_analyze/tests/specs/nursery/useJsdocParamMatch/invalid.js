@@ -0,0 +1,45 @@
+/**
+ * @param {string} name
+ */
+function greet(username) {}
+
+/**
+ * @param {number} b
+ * @param {number} a
+ */
+function sum(a, b) {}
+
+/**
+ * @param {number} a
+ */
+function multiply(a, b) {}
+
+/**
+ * @param {string} message
+ * @param {string} message
+ */
+function log(message) {}
+
+/**
+ * @param {string} id
+ */
+const remove = function (key) {};
+
+/**
+ * @param {string} id
+ */
+export const find = (key) => {};
+
+const api = {
+	/**
+	 * @param {string} url
+	 */
+	get(path) {},
+};
+
+class Client {
+	/**
+	 * @param {string} url
+	 */
+	constructor(baseUrl) {}
+}
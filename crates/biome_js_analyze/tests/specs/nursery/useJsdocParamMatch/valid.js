@@ -0,0 +1,27 @@
+/**
+ * @param {number} a
+ * @param {number} b
+ */
+function sum(a, b) {}
+
+/**
+ * @param {Object} options
+ * @param {string} options.name
+ */
+const greet = ({ name }) => {};
+
+/**
+ * Logs the message.
+ */
+function log(message) {}
+
+/**
+ * @param {string} message
+ * @param {...unknown} args
+ */
+function format(message, ...args) {}
+
+/**
+ * @param {string} [name="World"]
+ */
+function welcome(name = "World") {}
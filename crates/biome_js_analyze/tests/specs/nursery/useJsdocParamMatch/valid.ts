@@ -0,0 +1,11 @@
+/**
+ * @param {string} name
+ */
+function greet(this: Window, name: string) {}
+
+class Client {
+	/**
+	 * @param url
+	 */
+	constructor(private url: string) {}
+}
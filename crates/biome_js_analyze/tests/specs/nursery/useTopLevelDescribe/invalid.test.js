@@ -0,0 +1,11 @@
+beforeEach(() => reset());
+
+test("parses the numbers", () => {});
+
+it.only("parses the strings", () => {});
+
+describe("format", () => {
+	test("formats the numbers", () => {});
+});
+
+afterAll(() => close());
@@ -0,0 +1,15 @@
+describe("parse", () => {
+	beforeEach(() => reset());
+
+	test("parses the numbers", () => {});
+
+	describe.each([1, 2])("with %d", () => {});
+
+	describe("errors", () => {
+		it("rejects the invalid input", () => {});
+	});
+});
+
+test.each([1, 2])("parses %d", () => {});
+
+setup(() => {});
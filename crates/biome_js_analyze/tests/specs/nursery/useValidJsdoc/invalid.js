@@ -0,0 +1,37 @@
+/**
+ * @param {string name The name of the user
+ */
+function greet(name) {}
+
+/**
+ * @param {string}
+ */
+function farewell(name) {}
+
+/**
+ * @param {} options
+ */
+function configure(options) {}
+
+/**
+ * @param {number} [count The number of retries
+ */
+function retry(count) {}
+
+/**
+ * Sends the message.
+ * @ to the recipient
+ */
+function send() {}
+
+/**
+ * @returns {Promise<string The greeting
+ */
+async function welcome() {}
+
+class User {
+	/**
+	 * @property {string}
+	 */
+	name;
+}
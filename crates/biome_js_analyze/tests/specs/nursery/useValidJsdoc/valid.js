@@ -0,0 +1,24 @@
+/**
+ * @param {string} [name="World"] The name of the user
+ * @returns {string}
+ */
+function greet(name) {}
+
+/**
+ * Sends an email to user@example.com, e.g.
+ *
+ * ```js
+ * @decorator
+ * class Email {}
+ * ```
+ *
+ * @param {string} to
+ * @param {string[]} [cc=[]]
+ */
+function send(to, cc) {}
+
+/* @param {string */
+function plain() {}
+
+// @param {
+function line() {}
@@ -0,0 +1,9 @@
+function double(value) {
+    return value * 2;
+}
+
+async function log(message) {
+    console.log(message);
+}
+
+log(double(2));
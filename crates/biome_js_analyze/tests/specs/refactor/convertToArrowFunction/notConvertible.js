@@ -0,0 +1,17 @@
+hoisted();
+function hoisted() {}
+
+function usesThis() {
+    return this.value;
+}
+
+function usesArguments() {
+    return arguments.length;
+}
+
+function* generator() {}
+
+export function exported() {}
+
+function reassigned() {}
+reassigned = null;
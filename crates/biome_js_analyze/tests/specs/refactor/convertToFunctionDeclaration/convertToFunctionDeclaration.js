@@ -0,0 +1,5 @@
+const double = (value) => value * 2;
+
+const log = async message => {
+    console.log(message);
+};
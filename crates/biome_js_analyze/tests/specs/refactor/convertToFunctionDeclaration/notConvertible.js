@@ -0,0 +1,11 @@
+let mutable = () => {};
+
+const usesThis = () => this.value;
+
+const usesArguments = () => arguments.length;
+
+const functionExpression = function () {};
+
+const first = () => {}, second = () => {};
+
+export const exported = () => {};
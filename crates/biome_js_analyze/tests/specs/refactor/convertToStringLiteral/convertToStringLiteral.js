@@ -0,0 +1,3 @@
+const greeting = `Hello`;
+const quoted = `Say "hi"`;
+const mixed = `It's "done"`;
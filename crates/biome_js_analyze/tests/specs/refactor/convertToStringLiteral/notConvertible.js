@@ -0,0 +1,4 @@
+const interpolated = `Hello ${name}`;
+const tagged = String.raw`Hello`;
+const multiline = `Hello
+world`;
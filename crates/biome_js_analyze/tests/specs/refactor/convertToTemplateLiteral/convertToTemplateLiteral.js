@@ -0,0 +1,2 @@
+const greeting = "Hello, " + name + "!";
+const total = "Total: " + (price * quantity);
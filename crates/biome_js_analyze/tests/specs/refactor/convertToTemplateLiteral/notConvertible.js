@@ -0,0 +1,3 @@
+const joined = "a" + "b";
+const sum = a + b;
+const product = "a" * b;
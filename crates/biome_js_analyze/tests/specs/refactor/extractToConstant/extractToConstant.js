@@ -0,0 +1,5 @@
+function area(radius) {
+    return radius * Math.PI;
+}
+
+console.log(area(1));
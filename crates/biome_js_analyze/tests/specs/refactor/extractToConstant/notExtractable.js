@@ -0,0 +1,6 @@
+a && b + 1;
+const c = d ? e + 1 : 2;
+for (let i = 0; i < f + 1; i++) {}
+g?.h(i + 1);
+delete j.k;
+function l(m = n + 1) {}
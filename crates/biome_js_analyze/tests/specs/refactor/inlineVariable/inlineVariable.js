@@ -0,0 +1,12 @@
+function total(price, quantity) {
+    const subtotal = price * quantity;
+    return subtotal + 1;
+}
+
+const greeting = "Hello";
+console.log(greeting);
+console.log(greeting);
+
+const timeout = 60 * 1000;
+setTimeout(run, timeout);
+clearAfter(timeout);
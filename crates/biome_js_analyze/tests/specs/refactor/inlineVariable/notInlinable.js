@@ -0,0 +1,14 @@
+let counter = 0;
+counter += 1;
+const value = compute();
+use(value, value);
+const { a } = b;
+use(a);
+use(early);
+const early = 1;
+var legacy = 1;
+use(legacy);
+const shorthand = 1;
+use({ shorthand });
+const pattern = /a/g;
+use(pattern, pattern);
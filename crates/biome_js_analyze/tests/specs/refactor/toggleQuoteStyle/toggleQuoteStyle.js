@@ -0,0 +1,4 @@
+import value from "module";
+const double = "It's";
+const single = 'Say "hi"';
+const escaped = 'It\'s';
@@ -9,7 +9,6 @@ use biome_formatter::{
     },
     write,
 };
-use biome_js_syntax::suppression::parse_suppression_comment;
 use biome_js_syntax::JsSyntaxKind::JS_EXPORT;
 use biome_js_syntax::{
     AnyJsClass, AnyJsName, AnyJsRoot, AnyJsStatement, JsArrayHole, JsArrowFunctionExpression,
@@ -19,6 +18,7 @@ use biome_js_syntax::{
     TsInterfaceDeclaration,
 };
 use biome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments, TextLen};
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 pub type JsComments = Comments<JsLanguage>;
 
@@ -78,8 +78,9 @@ impl CommentStyle for JsCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.scope == SuppressionScope::Next)
             .flat_map(|suppression| suppression.categories)
-            .any(|(key, _)| key == category!("format"))
+            .any(|suppression| suppression.category == category!("format"))
     }
 
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> CommentKind {
@@ -100,6 +101,7 @@ impl CommentStyle for JsCommentStyle {
     ) -> CommentPlacement<Self::Language> {
         match comment.text_position() {
             CommentTextPosition::EndOfLine => handle_typecast_comment(comment)
+                .or_else(handle_parenthesized_element_comment)
                 .or_else(handle_function_declaration_comment)
                 .or_else(handle_conditional_comment)
                 .or_else(handle_if_statement_comment)
@@ -158,6 +160,32 @@ fn handle_typecast_comment(comment: DecoratedComment<JsLanguage>) -> CommentPlac
     }
 }
 
+/// Keeps the end of line comments that follow the removed `(` of an argument or an array element
+/// with the parenthesized element, instead of attaching them to the previous element.
+///
+/// ```javascript
+/// call(a, ( // comment
+///   b
+/// ));
+/// ```
+///
+/// The comment is a leading comment of `b`. As a trailing comment of `a`, it would move to the line of `a`
+/// when the arguments are printed on their own lines.
+fn handle_parenthesized_element_comment(
+    comment: DecoratedComment<JsLanguage>,
+) -> CommentPlacement<JsLanguage> {
+    if !comment.is_after_removed_l_paren() || comment.preceding_node().is_none() {
+        return CommentPlacement::Default(comment);
+    }
+
+    match (comment.enclosing_node().kind(), comment.following_node()) {
+        (JsSyntaxKind::JS_CALL_ARGUMENTS | JsSyntaxKind::JS_ARRAY_EXPRESSION, Some(following)) => {
+            CommentPlacement::leading(following.clone(), comment)
+        }
+        _ => CommentPlacement::Default(comment),
+    }
+}
+
 /// Move the arrow function's comment to the same position as the prettier
 fn handle_after_arrow_fat_arrow_comment(
     comment: DecoratedComment<JsLanguage>,
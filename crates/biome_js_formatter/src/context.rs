@@ -7,7 +7,8 @@ use biome_formatter::{
     CstFormatContext, FormatContext, FormatElement, FormatOptions, IndentStyle, IndentWidth,
     LineEnding, LineWidth, TransformSourceMap,
 };
-use biome_js_syntax::{AnyJsFunctionBody, JsFileSource, JsLanguage};
+use biome_js_syntax::{AnyJsFunctionBody, JsFileSource, JsLanguage, JsSyntaxNode};
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::rc::Rc;
@@ -44,6 +45,13 @@ pub struct JsFormatContext {
     /// This should be rare enough for us not to care about it.
     cached_function_body: Option<(AnyJsFunctionBody, FormatElement)>,
 
+    /// Whether the member names of an object or a type literal require quotes, by member list.
+    ///
+    /// Used by [QuoteProperties::Consistent], where every member name of a list is checked when one
+    /// of its names is formatted. The lists are cached by node because the formatting of a nested
+    /// object happens between the formatting of two names of the outer list.
+    quoted_member_lists: HashMap<JsSyntaxNode, bool>,
+
     source_map: Option<TransformSourceMap>,
 }
 
@@ -53,6 +61,7 @@ impl JsFormatContext {
             options,
             comments: Rc::new(comments),
             cached_function_body: None,
+            quoted_member_lists: HashMap::new(),
             source_map: None,
         }
     }
@@ -87,6 +96,20 @@ impl JsFormatContext {
         self.cached_function_body = Some((body.clone(), formatted))
     }
 
+    /// Returns whether the member names of `list` require quotes, if it has already been computed.
+    ///
+    /// See [JsFormatContext::quoted_member_lists] for more in depth documentation.
+    pub(crate) fn get_cached_quoted_member_list(&self, list: &JsSyntaxNode) -> Option<bool> {
+        self.quoted_member_lists.get(list).copied()
+    }
+
+    /// Caches whether the member names of `list` require quotes.
+    ///
+    /// See [JsFormatContext::quoted_member_lists] for more in depth documentation.
+    pub(crate) fn set_cached_quoted_member_list(&mut self, list: &JsSyntaxNode, quoted: bool) {
+        self.quoted_member_lists.insert(list.clone(), quoted);
+    }
+
     pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
         self.source_map = source_map;
         self
@@ -168,6 +191,24 @@ pub struct JsFormatOptions {
     /// Whether to hug the closing bracket of multiline HTML/JSX tags to the end of the last line, rather than being alone on the following line. Defaults to false.
     bracket_same_line: BracketSameLine,
 
+    /// Whether to keep short if statements on a single line.
+    single_line_if_statements: SingleLineIfStatements,
+
+    /// Whether to add a blank line after the imports.
+    blank_line_after_imports: BlankLineAfterImports,
+
+    /// Whether to add a blank line before return statements.
+    blank_line_before_return: BlankLineBeforeReturn,
+
+    /// Whether multiline unions start with a `|`.
+    union_leading_separator: UnionLeadingSeparator,
+
+    /// Unions with more members than this always break.
+    union_break_threshold: UnionBreakThreshold,
+
+    /// Whether the formatter keeps the rendered text of the JSX elements unchanged.
+    jsx_whitespace_fidelity: JsxWhitespaceFidelity,
+
     /// Information related to the current file
     source_type: JsFileSource,
 }
@@ -188,6 +229,12 @@ impl JsFormatOptions {
             arrow_parentheses: ArrowParentheses::default(),
             bracket_spacing: BracketSpacing::default(),
             bracket_same_line: BracketSameLine::default(),
+            single_line_if_statements: SingleLineIfStatements::default(),
+            blank_line_after_imports: BlankLineAfterImports::default(),
+            blank_line_before_return: BlankLineBeforeReturn::default(),
+            union_leading_separator: UnionLeadingSeparator::default(),
+            union_break_threshold: UnionBreakThreshold::default(),
+            jsx_whitespace_fidelity: JsxWhitespaceFidelity::default(),
         }
     }
 
@@ -206,6 +253,54 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_single_line_if_statements(
+        mut self,
+        single_line_if_statements: SingleLineIfStatements,
+    ) -> Self {
+        self.single_line_if_statements = single_line_if_statements;
+        self
+    }
+
+    pub fn with_blank_line_after_imports(
+        mut self,
+        blank_line_after_imports: BlankLineAfterImports,
+    ) -> Self {
+        self.blank_line_after_imports = blank_line_after_imports;
+        self
+    }
+
+    pub fn with_blank_line_before_return(
+        mut self,
+        blank_line_before_return: BlankLineBeforeReturn,
+    ) -> Self {
+        self.blank_line_before_return = blank_line_before_return;
+        self
+    }
+
+    pub fn with_union_leading_separator(
+        mut self,
+        union_leading_separator: UnionLeadingSeparator,
+    ) -> Self {
+        self.union_leading_separator = union_leading_separator;
+        self
+    }
+
+    pub fn with_union_break_threshold(
+        mut self,
+        union_break_threshold: UnionBreakThreshold,
+    ) -> Self {
+        self.union_break_threshold = union_break_threshold;
+        self
+    }
+
+    pub fn with_jsx_whitespace_fidelity(
+        mut self,
+        jsx_whitespace_fidelity: JsxWhitespaceFidelity,
+    ) -> Self {
+        self.jsx_whitespace_fidelity = jsx_whitespace_fidelity;
+        self
+    }
+
     pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
         self.indent_style = indent_style;
         self
@@ -263,6 +358,39 @@ impl JsFormatOptions {
         self.bracket_same_line = bracket_same_line;
     }
 
+    pub fn set_single_line_if_statements(
+        &mut self,
+        single_line_if_statements: SingleLineIfStatements,
+    ) {
+        self.single_line_if_statements = single_line_if_statements;
+    }
+
+    pub fn set_blank_line_after_imports(
+        &mut self,
+        blank_line_after_imports: BlankLineAfterImports,
+    ) {
+        self.blank_line_after_imports = blank_line_after_imports;
+    }
+
+    pub fn set_blank_line_before_return(
+        &mut self,
+        blank_line_before_return: BlankLineBeforeReturn,
+    ) {
+        self.blank_line_before_return = blank_line_before_return;
+    }
+
+    pub fn set_union_leading_separator(&mut self, union_leading_separator: UnionLeadingSeparator) {
+        self.union_leading_separator = union_leading_separator;
+    }
+
+    pub fn set_union_break_threshold(&mut self, union_break_threshold: UnionBreakThreshold) {
+        self.union_break_threshold = union_break_threshold;
+    }
+
+    pub fn set_jsx_whitespace_fidelity(&mut self, jsx_whitespace_fidelity: JsxWhitespaceFidelity) {
+        self.jsx_whitespace_fidelity = jsx_whitespace_fidelity;
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -311,6 +439,30 @@ impl JsFormatOptions {
         self.bracket_same_line
     }
 
+    pub fn single_line_if_statements(&self) -> SingleLineIfStatements {
+        self.single_line_if_statements
+    }
+
+    pub fn blank_line_after_imports(&self) -> BlankLineAfterImports {
+        self.blank_line_after_imports
+    }
+
+    pub fn blank_line_before_return(&self) -> BlankLineBeforeReturn {
+        self.blank_line_before_return
+    }
+
+    pub fn union_leading_separator(&self) -> UnionLeadingSeparator {
+        self.union_leading_separator
+    }
+
+    pub fn union_break_threshold(&self) -> UnionBreakThreshold {
+        self.union_break_threshold
+    }
+
+    pub fn jsx_whitespace_fidelity(&self) -> JsxWhitespaceFidelity {
+        self.jsx_whitespace_fidelity
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
@@ -375,7 +527,37 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Semicolons: {}", self.semicolons)?;
         writeln!(f, "Arrow parentheses: {}", self.arrow_parentheses)?;
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
-        writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())
+        writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
+        writeln!(
+            f,
+            "Single line if statements: {}",
+            self.single_line_if_statements.value()
+        )?;
+        writeln!(
+            f,
+            "Blank line after imports: {}",
+            self.blank_line_after_imports.value()
+        )?;
+        writeln!(
+            f,
+            "Blank line before return: {}",
+            self.blank_line_before_return.value()
+        )?;
+        writeln!(
+            f,
+            "Union leading separator: {}",
+            self.union_leading_separator.value()
+        )?;
+        writeln!(
+            f,
+            "Union break threshold: {}",
+            self.union_break_threshold.value()
+        )?;
+        writeln!(
+            f,
+            "JSX whitespace fidelity: {}",
+            self.jsx_whitespace_fidelity.value()
+        )
     }
 }
 
@@ -499,6 +681,8 @@ pub enum QuoteProperties {
     #[default]
     AsNeeded,
     Preserve,
+    /// Quotes all the properties of an object or a type if one of them requires quotes
+    Consistent,
 }
 
 impl FromStr for QuoteProperties {
@@ -508,6 +692,7 @@ impl FromStr for QuoteProperties {
         match s {
             "as-needed" | "AsNeeded" => Ok(Self::AsNeeded),
             "preserve" | "Preserve" => Ok(Self::Preserve),
+            "consistent" | "Consistent" => Ok(Self::Consistent),
             // TODO: replace this error with a diagnostic
             _ => Err("Value not supported for QuoteProperties"),
         }
@@ -519,6 +704,7 @@ impl fmt::Display for QuoteProperties {
         match self {
             QuoteProperties::AsNeeded => write!(f, "As needed"),
             QuoteProperties::Preserve => write!(f, "Preserve"),
+            QuoteProperties::Consistent => write!(f, "Consistent"),
         }
     }
 }
@@ -532,8 +718,9 @@ impl Deserializable for QuoteProperties {
         match Text::deserialize(value, name, diagnostics)?.text() {
             "asNeeded" => Some(QuoteProperties::AsNeeded),
             "preserve" => Some(QuoteProperties::Preserve),
+            "consistent" => Some(QuoteProperties::Consistent),
             unknown_variant => {
-                const ALLOWED_VARIANTS: &[&str] = &["preserve", "asNeeded"];
+                const ALLOWED_VARIANTS: &[&str] = &["preserve", "asNeeded", "consistent"];
                 diagnostics.push(DeserializationDiagnostic::new_unknown_value(
                     unknown_variant,
                     value.range(),
@@ -723,3 +910,139 @@ impl From<bool> for BracketSameLine {
         Self(value)
     }
 }
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct SingleLineIfStatements(bool);
+
+impl SingleLineIfStatements {
+    /// Return the boolean value for this [SingleLineIfStatements]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for SingleLineIfStatements {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct BlankLineAfterImports(bool);
+
+impl BlankLineAfterImports {
+    /// Return the boolean value for this [BlankLineAfterImports]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for BlankLineAfterImports {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct BlankLineBeforeReturn(bool);
+
+impl BlankLineBeforeReturn {
+    /// Return the boolean value for this [BlankLineBeforeReturn]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for BlankLineBeforeReturn {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct UnionLeadingSeparator(bool);
+
+impl UnionLeadingSeparator {
+    /// Return the boolean value for this [UnionLeadingSeparator]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl Default for UnionLeadingSeparator {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl From<bool> for UnionLeadingSeparator {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+/// The number of members above which a union always breaks, `0` when the unions only break
+/// when they don't fit on the line.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct UnionBreakThreshold(u8);
+
+impl UnionBreakThreshold {
+    /// Return the number of members for this [UnionBreakThreshold]
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for UnionBreakThreshold {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+/// Whether the whitespace of the JSX texts that changes their rendered text, e.g. two spaces
+/// between two words, is kept as is.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct JsxWhitespaceFidelity(bool);
+
+impl JsxWhitespaceFidelity {
+    /// Return the boolean value for this [JsxWhitespaceFidelity]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for JsxWhitespaceFidelity {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
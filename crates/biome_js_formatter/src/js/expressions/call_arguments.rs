@@ -84,7 +84,9 @@ impl FormatNodeRule<JsCallArguments> for FormatJsCallArguments {
                 let leading_lines = element
                     .node()
                     .map_or(0, |node| get_lines_before(node.syntax()));
-                has_empty_line = has_empty_line || leading_lines > 1;
+                // An empty line after the opening parenthesis doesn't prevent hugging the first
+                // argument, only the empty lines between two arguments do.
+                has_empty_line = has_empty_line || (index > 0 && leading_lines > 1);
 
                 FormatCallArgument::Default {
                     element,
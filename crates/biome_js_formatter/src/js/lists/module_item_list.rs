@@ -8,7 +8,9 @@ impl FormatRule<JsModuleItemList> for FormatJsModuleItemList {
     type Context = JsFormatContext;
 
     fn fmt(&self, node: &JsModuleItemList, f: &mut JsFormatter) -> FormatResult<()> {
+        let blank_line_after_imports = f.options().blank_line_after_imports().value();
         let mut join = f.join_nodes_with_hardline();
+        let mut follows_import = false;
 
         for module_item in node {
             match module_item {
@@ -16,10 +18,17 @@ impl FormatRule<JsModuleItemList> for FormatJsModuleItemList {
                     join.entry_no_separator(&empty.format());
                 }
                 _ => {
-                    join.entry(
-                        module_item.syntax(),
-                        &format_or_verbatim(module_item.format()),
-                    );
+                    let is_import = matches!(module_item, AnyJsModuleItem::JsImport(_));
+                    let content = format_or_verbatim(module_item.format());
+
+                    // The first item after the import block is always separated by an empty line
+                    if blank_line_after_imports && follows_import && !is_import {
+                        join.entry_with_empty_line(&content);
+                    } else {
+                        join.entry(module_item.syntax(), &content);
+                    }
+
+                    follows_import = is_import;
                 }
             }
         }
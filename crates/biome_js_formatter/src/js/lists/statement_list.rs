@@ -8,6 +8,7 @@ impl FormatRule<JsStatementList> for FormatJsStatementList {
     type Context = JsFormatContext;
 
     fn fmt(&self, node: &JsStatementList, f: &mut JsFormatter) -> FormatResult<()> {
+        let blank_line_before_return = f.options().blank_line_before_return().value();
         let mut join = f.join_nodes_with_hardline();
 
         for statement in node.iter() {
@@ -15,6 +16,10 @@ impl FormatRule<JsStatementList> for FormatJsStatementList {
                 AnyJsStatement::JsEmptyStatement(empty) => {
                     join.entry_no_separator(&empty.format());
                 }
+                AnyJsStatement::JsReturnStatement(_) if blank_line_before_return => {
+                    // The empty line is only added when the `return` isn't the first statement
+                    join.entry_with_empty_line(&format_or_verbatim(statement.format()));
+                }
                 _ => {
                     join.entry(statement.syntax(), &format_or_verbatim(statement.format()));
                 }
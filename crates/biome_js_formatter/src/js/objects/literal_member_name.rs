@@ -1,10 +1,14 @@
+use crate::context::QuoteProperties;
 use crate::prelude::*;
-use crate::utils::{FormatLiteralStringToken, StringLiteralParentKind};
+use crate::utils::{
+    member_name_quote_properties, FormatLiteralStringToken, StringLiteralParentKind,
+};
 
 use biome_formatter::token::number::format_number_token;
 use biome_formatter::write;
 use biome_js_syntax::JsLiteralMemberNameFields;
 use biome_js_syntax::{JsLiteralMemberName, JsSyntaxKind};
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsLiteralMemberName;
@@ -17,15 +21,32 @@ impl FormatNodeRule<JsLiteralMemberName> for FormatJsLiteralMemberName {
 
         match value.kind() {
             JsSyntaxKind::JS_STRING_LITERAL => {
+                let quote_properties = member_name_quote_properties(node, f);
                 write![
                     f,
-                    [FormatLiteralStringToken::new(
+                    [
+                        FormatLiteralStringToken::new(&value, StringLiteralParentKind::Member)
+                            .with_quote_properties(quote_properties)
+                    ]
+                ]
+            }
+            JsSyntaxKind::JS_NUMBER_LITERAL => format_number_token(&value).fmt(f),
+            _ if member_name_quote_properties(node, f) == QuoteProperties::Consistent => {
+                // Another name of the object requires quotes, the identifier is quoted too
+                let quote = f.options().quote_style().as_char();
+                let quoted = std::format!("{quote}{}{quote}", value.text_trimmed());
+                write![
+                    f,
+                    [format_replaced(
                         &value,
-                        StringLiteralParentKind::Member
+                        &syntax_token_cow_slice(
+                            Cow::Owned(quoted),
+                            &value,
+                            value.text_trimmed_range().start()
+                        )
                     )]
                 ]
             }
-            JsSyntaxKind::JS_NUMBER_LITERAL => format_number_token(&value).fmt(f),
             _ => write![f, [value.format()]],
         }
     }
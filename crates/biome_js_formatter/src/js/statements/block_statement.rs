@@ -1,3 +1,4 @@
+use crate::js::statements::if_statement::is_single_line_consequent;
 use crate::prelude::*;
 use biome_formatter::{write, Buffer, CstFormatContext};
 use biome_js_syntax::JsBlockStatement;
@@ -41,6 +42,11 @@ impl FormatNodeRule<JsBlockStatement> for FormatJsBlockStatement {
             } else if is_non_collapsible(node) {
                 write!(f, [hard_line_break()])?;
             }
+        } else if is_single_line_consequent(node, f) {
+            write!(
+                f,
+                [group(&soft_space_or_block_indent(&statements.format()))]
+            )?;
         } else {
             write!(f, [block_indent(&statements.format())])?;
         }
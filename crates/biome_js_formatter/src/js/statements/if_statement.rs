@@ -2,8 +2,8 @@ use crate::prelude::*;
 use biome_formatter::{format_args, write, CstFormatContext};
 
 use crate::utils::FormatStatementBody;
-use biome_js_syntax::JsIfStatement;
 use biome_js_syntax::JsIfStatementFields;
+use biome_js_syntax::{AnyJsStatement, JsBlockStatement, JsIfStatement};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsIfStatement;
@@ -81,3 +81,37 @@ impl FormatNodeRule<JsIfStatement> for FormatJsIfStatement {
         Ok(())
     }
 }
+
+/// Returns `true` if `block` is the consequent of an `if` statement without an `else` clause that
+/// stays on a single line, e.g. `if (x) { return; }`.
+///
+/// It's only the case when the option `singleLineIfStatements` is enabled, the block contains a
+/// single statement without comments and the block is on a single line in the source.
+/// The block still breaks when it doesn't fit on the line.
+pub(crate) fn is_single_line_consequent(block: &JsBlockStatement, f: &JsFormatter) -> bool {
+    if !f.options().single_line_if_statements().value() {
+        return false;
+    }
+
+    let is_consequent = block
+        .parent::<JsIfStatement>()
+        .is_some_and(|if_statement| if_statement.else_clause().is_none());
+    if !is_consequent {
+        return false;
+    }
+
+    let mut statements = block.statements().iter();
+    let (Some(statement), None) = (statements.next(), statements.next()) else {
+        return false;
+    };
+
+    let comments = f.context().comments();
+    !matches!(
+        statement,
+        AnyJsStatement::JsBlockStatement(_)
+            | AnyJsStatement::JsEmptyStatement(_)
+            | AnyJsStatement::JsIfStatement(_)
+    ) && !comments.has_dangling_comments(block.syntax())
+        && !comments.has_comments(statement.syntax())
+        && !block.syntax().text_trimmed().contains_char('\n')
+}
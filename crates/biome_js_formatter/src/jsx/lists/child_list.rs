@@ -1,7 +1,8 @@
 use crate::prelude::*;
 use crate::utils::jsx::{
-    is_meaningful_jsx_text, is_whitespace_jsx_expression, jsx_split_children, JsxChild,
-    JsxChildrenIterator, JsxRawSpace, JsxSpace,
+    is_meaningful_jsx_text, is_whitespace_jsx_expression, jsx_split_children,
+    jsx_whitespace_string, JsxChild, JsxChildrenIterator, JsxRawSpace, JsxSignificantSpace,
+    JsxSpace,
 };
 use crate::JsFormatter;
 use biome_formatter::format_element::tag::{GroupMode, Tag};
@@ -60,7 +61,7 @@ impl FormatJsxChildList {
     ) -> FormatResult<FormatChildrenResult> {
         self.disarm_debug_assertions(list, f);
 
-        let children_meta = self.children_meta(list, f.context().comments());
+        let children_meta = self.children_meta(list, f);
         let layout = self.layout(children_meta);
 
         let multiline_layout = if children_meta.meaningful_text {
@@ -74,7 +75,11 @@ impl FormatJsxChildList {
 
         let mut force_multiline = layout.is_multiline();
 
-        let mut children = jsx_split_children(list, f.context().comments())?;
+        let mut children = jsx_split_children(
+            list,
+            f.context().comments(),
+            f.options().jsx_whitespace_fidelity(),
+        )?;
 
         // Trim trailing new lines
         if let Some(JsxChild::EmptyLine | JsxChild::Newline) = children.last() {
@@ -109,6 +114,13 @@ impl FormatJsxChildList {
                             ) || word.is_ascii_punctuation(),
                         }),
 
+                        // Last word before a whitespace that's printed as an expression
+                        Some(JsxChild::SignificantWhitespace(_)) => {
+                            Some(WordSeparator::EndOfText {
+                                is_soft_line_break: true,
+                            })
+                        }
+
                         Some(JsxChild::Newline | JsxChild::Whitespace | JsxChild::EmptyLine) => {
                             None
                         }
@@ -228,6 +240,29 @@ impl FormatJsxChildList {
                     multiline.write_separator(&empty_line(), f);
                 }
 
+                // Whitespace that renders as more than a single space: `<div>a<b />   c</div>`
+                JsxChild::SignificantWhitespace(whitespace) => {
+                    let format_separator = match children_iter.peek() {
+                        Some(
+                            JsxChild::Word(_)
+                            | JsxChild::NonText(_)
+                            | JsxChild::SignificantWhitespace(_),
+                        ) => Some(soft_line_break()),
+                        Some(JsxChild::Newline | JsxChild::Whitespace | JsxChild::EmptyLine)
+                        | None => None,
+                    };
+                    let whitespace = JsxSignificantSpace(whitespace);
+
+                    flat.write(&format_args![whitespace, format_separator], f);
+
+                    if let Some(format_separator) = format_separator {
+                        multiline.write_with_separator(&whitespace, &format_separator, f);
+                    } else {
+                        // it's safe to write without a separator because None means that next element is a separator or end of the iterator
+                        multiline.write_content(&whitespace, f);
+                    }
+                }
+
                 // Any child that isn't text
                 JsxChild::NonText(non_text) => {
                     let line_mode = match children_iter.peek() {
@@ -253,6 +288,8 @@ impl FormatJsxChildList {
                         // Add a hard line break if what comes after the element is not a text or is all whitespace
                         Some(JsxChild::NonText(_)) => Some(LineMode::Hard),
 
+                        Some(JsxChild::SignificantWhitespace(_)) => Some(LineMode::Soft),
+
                         Some(JsxChild::Newline | JsxChild::Whitespace | JsxChild::EmptyLine) => {
                             None
                         }
@@ -321,10 +358,15 @@ impl FormatJsxChildList {
         use biome_js_syntax::{AnyJsExpression, AnyJsLiteralExpression};
         use AnyJsxChild::*;
 
+        let keep_whitespace = f.options().jsx_whitespace_fidelity().value();
+
         for child in node {
             match child {
                 JsxExpressionChild(expression)
-                    if is_whitespace_jsx_expression(&expression, f.context().comments()) =>
+                    if is_whitespace_jsx_expression(&expression, f.context().comments())
+                        || (keep_whitespace
+                            && jsx_whitespace_string(&expression, f.context().comments())
+                                .is_some()) =>
                 {
                     f.context()
                         .comments()
@@ -381,7 +423,9 @@ impl FormatJsxChildList {
     }
 
     /// Computes additional meta data about the children by iterating once over all children.
-    fn children_meta(&self, list: &JsxChildList, comments: &JsComments) -> ChildrenMeta {
+    fn children_meta(&self, list: &JsxChildList, f: &JsFormatter) -> ChildrenMeta {
+        let comments = f.context().comments();
+        let keep_whitespace = f.options().jsx_whitespace_fidelity().value();
         let mut has_expression = false;
 
         let mut meta = ChildrenMeta::default();
@@ -392,7 +436,11 @@ impl FormatJsxChildList {
             match child {
                 JsxElement(_) | JsxFragment(_) | JsxSelfClosingElement(_) => meta.any_tag = true,
                 JsxExpressionChild(expression) => {
-                    if is_whitespace_jsx_expression(&expression, comments) {
+                    // The whitespace strings are printed as text with the JSX whitespace fidelity
+                    if is_whitespace_jsx_expression(&expression, comments)
+                        || (keep_whitespace
+                            && jsx_whitespace_string(&expression, comments).is_some())
+                    {
                         meta.meaningful_text = true;
                     } else {
                         meta.multiple_expressions = has_expression;
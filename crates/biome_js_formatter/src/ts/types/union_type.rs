@@ -39,6 +39,11 @@ impl FormatNodeRule<TsUnionType> for FormatTsUnionType {
 
         let has_leading_comments = f.comments().has_leading_comments(node.syntax());
 
+        // Unions with more members than the threshold break even if they fit on the line
+        let break_threshold = f.options().union_break_threshold().value();
+        let should_expand = break_threshold > 0 && types.len() > usize::from(break_threshold);
+        let print_leading_separator = f.options().union_leading_separator().value();
+
         let should_indent = {
             let parent_kind = node.syntax().parent().kind();
 
@@ -71,6 +76,7 @@ impl FormatNodeRule<TsUnionType> for FormatTsUnionType {
                         separator: "|",
                         leading_separator: leading_separator_token.as_ref(),
                         leading_soft_line_break_or_space: should_indent && !has_leading_comments,
+                        print_leading_separator,
                     },
                     types.format()
                 ]
@@ -109,7 +115,7 @@ impl FormatNodeRule<TsUnionType> for FormatTsUnionType {
             }
         });
 
-        write!(f, [group(&content)])
+        write!(f, [group(&content).should_expand(should_expand)])
     }
 
     fn needs_parentheses(&self, item: &TsUnionType) -> bool {
@@ -142,11 +148,19 @@ pub struct FormatTypeSetLeadingSeparator<'a> {
     separator: &'static str,
     leading_separator: Option<&'a JsSyntaxToken>,
     leading_soft_line_break_or_space: bool,
+    /// Whether the separator is printed before the first type when the group breaks
+    print_leading_separator: bool,
 }
 
 impl Format<JsFormatContext> for FormatTypeSetLeadingSeparator<'_> {
     fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
         match &self.leading_separator {
+            Some(token) if !self.print_leading_separator => {
+                if self.leading_soft_line_break_or_space {
+                    write!(f, [if_group_breaks(&soft_line_break())])?;
+                }
+                write!(f, [format_removed(token)])
+            }
             Some(token) => {
                 let content = format_with(|f| {
                     if self.leading_soft_line_break_or_space {
@@ -156,6 +170,12 @@ impl Format<JsFormatContext> for FormatTypeSetLeadingSeparator<'_> {
                 });
                 format_only_if_breaks(token, &content).fmt(f)
             }
+            None if !self.print_leading_separator => {
+                if self.leading_soft_line_break_or_space {
+                    write!(f, [if_group_breaks(&soft_line_break())])?;
+                }
+                Ok(())
+            }
             None => {
                 let content = format_with(|f| {
                     if self.leading_soft_line_break_or_space {
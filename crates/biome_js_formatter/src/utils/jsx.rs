@@ -1,11 +1,11 @@
-use crate::context::QuoteStyle;
+use crate::context::{JsxWhitespaceFidelity, QuoteStyle};
 use crate::prelude::*;
 use crate::JsCommentStyle;
 use biome_formatter::{comments::CommentStyle, format_args, write};
 use biome_js_syntax::{
     AnyJsExpression, AnyJsLiteralExpression, AnyJsxChild, AnyJsxTag, JsComputedMemberExpression,
-    JsStaticMemberExpression, JsSyntaxKind, JsxChildList, JsxExpressionChild, JsxTagExpression,
-    JsxText, TextLen,
+    JsStaticMemberExpression, JsSyntaxKind, JsSyntaxNode, JsxChildList, JsxExpressionChild,
+    JsxTagExpression, JsxText, TextLen,
 };
 use biome_rowan::{Direction, SyntaxResult, TextRange, TextSize, TokenText};
 use std::iter::{FusedIterator, Peekable};
@@ -216,13 +216,67 @@ pub(crate) fn is_whitespace_jsx_expression(
     }
 }
 
+/// Whether a whitespace chunk of a JSX text that doesn't contain a line break changes the
+/// rendered text if it's replaced by a single space.
+fn is_significant_jsx_whitespace(whitespace: &str) -> bool {
+    whitespace != " " && !whitespace.contains(['\n', '\r'])
+}
+
+/// Returns the content of an expression child that only contains a string of whitespace, e.g.
+/// the two spaces of `{"  "}`
+pub(crate) fn jsx_whitespace_string(
+    child: &JsxExpressionChild,
+    comments: &JsComments,
+) -> Option<JsxWord> {
+    let Some(AnyJsExpression::AnyJsLiteralExpression(
+        AnyJsLiteralExpression::JsStringLiteralExpression(literal),
+    )) = child.expression()
+    else {
+        return None;
+    };
+    let value_token = literal.value_token().ok()?;
+    let r_curly_token = child.r_curly_token().ok()?;
+
+    if child.l_curly_token().is_err()
+        || comments.has_skipped(&r_curly_token)
+        || comments.has_comments(literal.syntax())
+    {
+        return None;
+    }
+
+    let text = value_token.text_trimmed();
+    // Skip the quotes
+    let content = text.get(1..text.len().saturating_sub(1))?;
+    if content.is_empty() || !content.chars().all(|c| JSX_WHITESPACE_CHARS.contains(&c)) {
+        return None;
+    }
+
+    let start = value_token.text_trimmed_range().start() - value_token.text_range().start()
+        + TextSize::from(1);
+    let range = TextRange::at(start, content.text_len());
+
+    Some(JsxWord::new(
+        value_token.token_text().slice(range),
+        value_token.text_range().start() + start,
+    ))
+}
+
+/// Splits the children into words, whitespace, line breaks and the other children.
+///
+/// With [JsxWhitespaceFidelity], the whitespace that doesn't render as a single space is kept:
+/// the whitespace between two words is kept inside a single word, the leading and trailing
+/// whitespace of a text becomes a [JsxChild::SignificantWhitespace], and so do the expressions
+/// that only contain a string of whitespace, like `{"  "}`, unless it's a `{" "}` that doesn't
+/// follow another whitespace.
 pub(crate) fn jsx_split_children<I>(
     children: I,
     comments: &JsComments,
+    whitespace_fidelity: JsxWhitespaceFidelity,
 ) -> SyntaxResult<Vec<JsxChild>>
 where
     I: IntoIterator<Item = AnyJsxChild>,
 {
+    let keep_whitespace = whitespace_fidelity.value();
     let mut builder = JsxSplitChildrenBuilder::new();
 
     for child in children {
@@ -233,11 +287,17 @@ where
 
                 let value_token = text.value_token()?;
                 let mut chunks = JsxSplitChunksIterator::new(value_token.text()).peekable();
+                let jsx_word = |range: TextRange| {
+                    JsxWord::new(
+                        value_token.token_text().slice(range),
+                        value_token.text_range().start() + range.start(),
+                    )
+                };
 
                 // Text starting with a whitespace
                 if let Some((_, JsxTextChunk::Whitespace(_whitespace))) = chunks.peek() {
                     match chunks.next() {
-                        Some((_, JsxTextChunk::Whitespace(whitespace))) => {
+                        Some((relative_start, JsxTextChunk::Whitespace(whitespace))) => {
                             if whitespace.contains('\n') {
                                 if chunks.peek().is_none() {
                                     // A text only consisting of whitespace that also contains a new line isn't considered meaningful text.
@@ -260,6 +320,12 @@ where
                                 }
 
                                 builder.entry(JsxChild::Newline)
+                            } else if keep_whitespace
+                                && (is_significant_jsx_whitespace(whitespace)
+                                    || builder.ends_with_whitespace())
+                            {
+                                let range = TextRange::at(relative_start, whitespace.text_len());
+                                builder.entry(JsxChild::SignificantWhitespace(jsx_word(range)))
                             } else {
                                 builder.entry(JsxChild::Whitespace)
                             }
@@ -268,13 +334,36 @@ where
                     }
                 }
 
+                // The range of the last word, that the next word extends if the whitespace
+                // between them is kept
+                let mut last_word: Option<TextRange> = None;
+
                 while let Some(chunk) = chunks.next() {
                     match chunk {
-                        (_, JsxTextChunk::Whitespace(whitespace)) => {
+                        (relative_start, JsxTextChunk::Whitespace(whitespace)) => {
+                            let is_trailing = chunks.peek().is_none();
+
+                            if !is_trailing
+                                && keep_whitespace
+                                && is_significant_jsx_whitespace(whitespace)
+                            {
+                                continue;
+                            }
+
+                            if let Some(range) = last_word.take() {
+                                builder.entry(JsxChild::Word(jsx_word(range)));
+                            }
+
                             // Only handle trailing whitespace. Words must always be joined by new lines
-                            if chunks.peek().is_none() {
+                            if is_trailing {
                                 if whitespace.contains('\n') {
                                     builder.entry(JsxChild::Newline);
+                                } else if keep_whitespace
+                                    && is_significant_jsx_whitespace(whitespace)
+                                {
+                                    let range =
+                                        TextRange::at(relative_start, whitespace.text_len());
+                                    builder.entry(JsxChild::SignificantWhitespace(jsx_word(range)))
                                 } else {
                                     builder.entry(JsxChild::Whitespace)
                                 }
@@ -282,22 +371,33 @@ where
                         }
 
                         (relative_start, JsxTextChunk::Word(word)) => {
-                            let text = value_token
-                                .token_text()
-                                .slice(TextRange::at(relative_start, word.text_len()));
-                            let source_position = value_token.text_range().start() + relative_start;
-
-                            builder.entry(JsxChild::Word(JsxWord::new(text, source_position)));
+                            let range = TextRange::at(relative_start, word.text_len());
+                            last_word = Some(last_word.map_or(range, |last| last.cover(range)));
                         }
                     }
                 }
+
+                if let Some(range) = last_word {
+                    builder.entry(JsxChild::Word(jsx_word(range)));
+                }
             }
 
             AnyJsxChild::JsxExpressionChild(child) => {
-                if is_whitespace_jsx_expression(&child, comments) {
-                    builder.entry(JsxChild::Whitespace)
-                } else {
-                    builder.entry(JsxChild::NonText(child.into()))
+                let whitespace = keep_whitespace
+                    .then(|| jsx_whitespace_string(&child, comments))
+                    .flatten();
+
+                match whitespace {
+                    // `{"  "}`, or a `{" "}` that renders a second space after another whitespace
+                    Some(whitespace)
+                        if whitespace.text != " " || builder.ends_with_whitespace() =>
+                    {
+                        builder.entry(JsxChild::SignificantWhitespace(whitespace))
+                    }
+                    _ if is_whitespace_jsx_expression(&child, comments) => {
+                        builder.entry(JsxChild::Whitespace)
+                    }
+                    _ => builder.entry(JsxChild::NonText(child.into())),
                 }
             }
             child => {
@@ -328,7 +428,10 @@ impl JsxSplitChildrenBuilder {
             Some(last @ (JsxChild::EmptyLine | JsxChild::Newline | JsxChild::Whitespace)) => {
                 if matches!(child, JsxChild::Whitespace) {
                     *last = child;
-                } else if matches!(child, JsxChild::NonText(_) | JsxChild::Word(_)) {
+                } else if matches!(
+                    child,
+                    JsxChild::NonText(_) | JsxChild::Word(_) | JsxChild::SignificantWhitespace(_)
+                ) {
                     self.buffer.push(child);
                 }
             }
@@ -336,6 +439,14 @@ impl JsxSplitChildrenBuilder {
         }
     }
 
+    /// Whether the last child renders as whitespace
+    fn ends_with_whitespace(&self) -> bool {
+        matches!(
+            self.buffer.last(),
+            Some(JsxChild::Whitespace | JsxChild::SignificantWhitespace(_))
+        )
+    }
+
     fn finish(self) -> Vec<JsxChild> {
         self.buffer
     }
@@ -388,6 +499,17 @@ pub(crate) enum JsxChild {
     /// The text between `<div>` and `<test />` is an empty line text.
     EmptyLine,
 
+    /// Whitespace at the start or the end of a [JsxText], or a string of whitespace like `{"  "}`,
+    /// that the [JsxWhitespaceFidelity] mode keeps because it would otherwise be printed as a
+    /// single space, or change the layout of the children. It's printed as a string expression.
+    ///
+    /// ```javascript
+    /// <div>a<b />   c</div>
+    /// ```
+    ///
+    /// The three spaces before `c` are a significant whitespace, printed as `{"   "}`.
+    SignificantWhitespace(JsxWord),
+
     /// Any other content that isn't a text. Should be formatted as is.
     NonText(AnyJsxChild),
 }
@@ -432,6 +554,21 @@ impl Format<JsFormatContext> for JsxWord {
     }
 }
 
+/// Prints a [JsxChild::SignificantWhitespace] as a string expression, e.g. `{"\t"}`
+pub(crate) struct JsxSignificantSpace<'a>(pub(crate) &'a JsxWord);
+
+impl Format<JsFormatContext> for JsxSignificantSpace<'_> {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        let quote = match f.options().quote_style() {
+            QuoteStyle::Double => '"',
+            QuoteStyle::Single => '\'',
+        };
+        let expression = std::format!("{{{quote}{}{quote}}}", self.0.text.text());
+
+        write!(f, [dynamic_text(&expression, self.0.source_position)])
+    }
+}
+
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
 enum JsxTextChunk<'a> {
     Whitespace(&'a str),
@@ -577,8 +714,109 @@ impl<I: Iterator> Iterator for JsxChildrenIterator<I> {
     }
 }
 
+/// A part of the rendered content of a [JsxChildList]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum JsxRenderedChild {
+    /// The rendered text of the adjacent texts and whitespace string expressions, e.g. `a b `
+    /// for `a{" "}\n  b{" "}`
+    Text(String),
+    /// An element, a fragment or any other expression
+    Other,
+}
+
+/// Returns the rendered content of each [JsxChildList] of `root`, in the order of the lists.
+///
+/// Two trees that render the same text have the same content, even if their JSX whitespace is
+/// printed differently, e.g. `<div>a b</div>` and `<div>\n  a{" "}\n  b\n</div>`.
+///
+/// ```
+/// use biome_js_formatter::utils::jsx::{jsx_rendered_children, JsxRenderedChild};
+/// use biome_js_parser::{parse, JsParserOptions};
+/// use biome_js_syntax::JsFileSource;
+///
+/// let parse = parse(
+///     "<div>\n  a{' '}\n  b   c<br />\n</div>",
+///     JsFileSource::jsx(),
+///     JsParserOptions::default(),
+/// );
+///
+/// assert_eq!(
+///     jsx_rendered_children(&parse.syntax()),
+///     vec![vec![
+///         JsxRenderedChild::Text("a b   c".to_string()),
+///         JsxRenderedChild::Other
+///     ]]
+/// );
+/// ```
+pub fn jsx_rendered_children(root: &JsSyntaxNode) -> Vec<Vec<JsxRenderedChild>> {
+    root.descendants()
+        .filter_map(JsxChildList::cast)
+        .map(|list| {
+            let mut rendered = Vec::new();
+
+            for child in list {
+                let text = match child {
+                    AnyJsxChild::JsxText(text) => text
+                        .value_token()
+                        .map(|token| rendered_jsx_text(token.text()))
+                        .ok(),
+                    AnyJsxChild::JsxExpressionChild(child) => match child.expression() {
+                        Some(AnyJsExpression::AnyJsLiteralExpression(
+                            AnyJsLiteralExpression::JsStringLiteralExpression(literal),
+                        )) => literal
+                            .inner_string_text()
+                            .ok()
+                            .filter(|text| text.chars().all(|c| JSX_WHITESPACE_CHARS.contains(&c)))
+                            .map(|text| text.to_string()),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                match (text, rendered.last_mut()) {
+                    (Some(text), _) if text.is_empty() => {}
+                    (Some(text), Some(JsxRenderedChild::Text(last))) => last.push_str(&text),
+                    (Some(text), _) => rendered.push(JsxRenderedChild::Text(text)),
+                    (None, _) => rendered.push(JsxRenderedChild::Other),
+                }
+            }
+
+            rendered
+        })
+        .collect()
+}
+
+/// Returns the text that a [JsxText] renders: the lines are trimmed, the empty lines are removed
+/// and the other lines are joined by a space.
+fn rendered_jsx_text(text: &str) -> String {
+    let lines: Vec<_> = text.split(['\n', '\r']).collect();
+    let last_non_empty_line = lines
+        .iter()
+        .rposition(|line| line.contains(|c| c != ' ' && c != '\t'));
+    let mut rendered = String::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let mut line = *line;
+        if index > 0 {
+            line = line.trim_start_matches([' ', '\t']);
+        }
+        if index < lines.len() - 1 {
+            line = line.trim_end_matches([' ', '\t']);
+        }
+        if !line.is_empty() {
+            rendered.push_str(line);
+            if Some(index) != last_non_empty_line {
+                rendered.push(' ');
+            }
+        }
+    }
+
+    rendered
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::context::JsxWhitespaceFidelity;
     use crate::utils::jsx::{
         jsx_split_children, JsxChild, JsxChildrenIterator, JsxSplitChunksIterator, JsxTextChunk,
     };
@@ -710,7 +948,12 @@ mod tests {
     fn split_children_words_only() {
         let child_list = parse_jsx_children("a b c");
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(3, children.len());
         assert_word(&children[0], "a");
@@ -722,7 +965,12 @@ mod tests {
     fn split_non_meaningful_text() {
         let child_list = parse_jsx_children("  \n ");
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(children, vec![]);
     }
@@ -731,7 +979,12 @@ mod tests {
     fn split_non_meaningful_leading_multiple_lines() {
         let child_list = parse_jsx_children("  \n  \n ");
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(children, vec![JsxChild::EmptyLine]);
     }
@@ -740,7 +993,12 @@ mod tests {
     fn split_meaningful_whitespace() {
         let child_list = parse_jsx_children("  ");
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(children, vec![JsxChild::Whitespace]);
     }
@@ -749,7 +1007,12 @@ mod tests {
     fn split_children_leading_newlines() {
         let child_list = parse_jsx_children("  \n a b");
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(3, children.len());
         assert_eq!(children[0], JsxChild::Newline);
@@ -761,7 +1024,12 @@ mod tests {
     fn split_children_trailing_whitespace() {
         let child_list = parse_jsx_children("a b    \t ");
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(3, children.len());
         assert_word(&children[0], "a");
@@ -773,7 +1041,12 @@ mod tests {
     fn split_children_trailing_newline() {
         let child_list = parse_jsx_children("a b \n   \t ");
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(3, children.len());
         assert_word(&children[0], "a");
@@ -785,7 +1058,12 @@ mod tests {
     fn split_children_empty_expression() {
         let child_list = parse_jsx_children(r#"a{' '}c{" "}"#);
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(
             4,
@@ -802,7 +1080,12 @@ mod tests {
     fn split_children_remove_in_row_jsx_whitespaces() {
         let child_list = parse_jsx_children(r#"a{' '}{' '}{' '}c{" "}{' '}{" "}"#);
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(
             4,
@@ -823,7 +1106,12 @@ mod tests {
             "#,
         );
 
-        let children = jsx_split_children(&child_list, &Comments::default()).unwrap();
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::default(),
+        )
+        .unwrap();
 
         assert_eq!(
             4,
@@ -836,6 +1124,62 @@ mod tests {
         assert_eq!(children[3], JsxChild::Whitespace);
     }
 
+    #[test]
+    fn split_children_keep_significant_whitespace() {
+        let child_list = parse_jsx_children("\ta  b{' '} c");
+
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::from(true),
+        )
+        .unwrap();
+
+        assert_eq!(
+            5,
+            children.len(),
+            "Expected to contain five elements. Actual:\n{children:#?} "
+        );
+        assert_significant_whitespace(&children[0], "\t");
+        assert_word(&children[1], "a  b");
+        assert_eq!(children[2], JsxChild::Whitespace);
+        assert_significant_whitespace(&children[3], " ");
+        assert_word(&children[4], "c");
+    }
+
+    #[test]
+    fn split_children_keep_in_row_jsx_whitespaces() {
+        let child_list = parse_jsx_children(r#"a {' '}{" "}"#);
+
+        let children = jsx_split_children(
+            &child_list,
+            &Comments::default(),
+            JsxWhitespaceFidelity::from(true),
+        )
+        .unwrap();
+
+        assert_eq!(
+            4,
+            children.len(),
+            "Expected to contain four elements. Actual:\n{children:#?} "
+        );
+        assert_word(&children[0], "a");
+        assert_eq!(children[1], JsxChild::Whitespace);
+        assert_significant_whitespace(&children[2], " ");
+        assert_significant_whitespace(&children[3], " ");
+    }
+
+    fn assert_significant_whitespace(child: &JsxChild, text: &str) {
+        match child {
+            JsxChild::SignificantWhitespace(whitespace) => {
+                assert_eq!(whitespace.text.text(), text)
+            }
+            child => {
+                panic!("Expected a significant whitespace but found {child:#?}");
+            }
+        }
+    }
+
     fn assert_word(child: &JsxChild, text: &str) {
         match child {
             JsxChild::Word(word) => {
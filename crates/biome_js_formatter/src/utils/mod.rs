@@ -37,6 +37,7 @@ use biome_js_syntax::{
 use biome_rowan::{AstNode, AstNodeList};
 use biome_text_size::TextSize;
 pub(crate) use conditional::{AnyJsConditional, ConditionalJsxChain};
+pub(crate) use object::member_name_quote_properties;
 pub(crate) use object_like::JsObjectLike;
 pub(crate) use object_pattern_like::JsObjectPatternLike;
 pub(crate) use string_utils::*;
@@ -1,9 +1,14 @@
+use crate::context::QuoteProperties;
 use crate::prelude::*;
-use crate::utils::FormatLiteralStringToken;
-use crate::utils::StringLiteralParentKind;
+use crate::utils::{can_unquote_member_name, FormatLiteralStringToken};
+use crate::utils::{SourceFileKind, StringLiteralParentKind};
 use biome_formatter::write;
-use biome_js_syntax::JsSyntaxKind::JS_STRING_LITERAL;
-use biome_js_syntax::{AnyJsClassMemberName, AnyJsObjectMemberName};
+use biome_js_syntax::JsSyntaxKind::{
+    JS_NUMBER_LITERAL, JS_OBJECT_MEMBER_LIST, JS_STRING_LITERAL, TS_TYPE_MEMBER_LIST,
+};
+use biome_js_syntax::{
+    AnyJsClassMemberName, AnyJsObjectMemberName, JsLiteralMemberName, JsSyntaxNode,
+};
 use biome_rowan::{declare_node_union, AstNode};
 use unicode_width::UnicodeWidthStr;
 
@@ -36,9 +41,11 @@ pub(crate) fn write_member_name(
             AnyJsObjectMemberName::JsLiteralMemberName(literal),
         )) => {
             let value = literal.value()?;
+            let quote_properties = member_name_quote_properties(literal, f);
 
             if value.kind() == JS_STRING_LITERAL {
-                let format = FormatLiteralStringToken::new(&value, StringLiteralParentKind::Member);
+                let format = FormatLiteralStringToken::new(&value, StringLiteralParentKind::Member)
+                    .with_quote_properties(quote_properties);
                 let cleaned = format.clean_text(f.options());
 
                 write!(
@@ -54,7 +61,16 @@ pub(crate) fn write_member_name(
             } else {
                 write!(f, [name])?;
 
-                Ok(value.text_trimmed().width())
+                // The identifiers are written with quotes when the names of the object are quoted
+                let quotes_width = if value.kind() != JS_NUMBER_LITERAL
+                    && quote_properties == QuoteProperties::Consistent
+                {
+                    2
+                } else {
+                    0
+                };
+
+                Ok(value.text_trimmed().width() + quotes_width)
             }
         }
         name => {
@@ -63,3 +79,55 @@ pub(crate) fn write_member_name(
         }
     }
 }
+
+/// Returns the quote properties to apply to the literal member name `name`.
+///
+/// With [QuoteProperties::Consistent], the names of an object or a type literal are all quoted if
+/// one of them can't be written without quotes. The function then returns [QuoteProperties::Consistent]
+/// when the names of the object of `name` are quoted, and [QuoteProperties::AsNeeded] when they aren't
+/// or when `name` isn't the name of an object or a type member, e.g. a class member.
+pub(crate) fn member_name_quote_properties(
+    name: &JsLiteralMemberName,
+    f: &mut JsFormatter,
+) -> QuoteProperties {
+    let quote_properties = f.options().quote_properties();
+    if quote_properties != QuoteProperties::Consistent {
+        return quote_properties;
+    }
+
+    let Some(list) = name
+        .syntax()
+        .grand_parent()
+        .filter(|list| matches!(list.kind(), JS_OBJECT_MEMBER_LIST | TS_TYPE_MEMBER_LIST))
+    else {
+        return QuoteProperties::AsNeeded;
+    };
+
+    if member_list_needs_quotes(&list, f) {
+        QuoteProperties::Consistent
+    } else {
+        QuoteProperties::AsNeeded
+    }
+}
+
+/// Returns `true` if one of the literal member names of `list` can't be written without quotes
+fn member_list_needs_quotes(list: &JsSyntaxNode, f: &mut JsFormatter) -> bool {
+    if let Some(needs_quotes) = f.context().get_cached_quoted_member_list(list) {
+        return needs_quotes;
+    }
+
+    let file_source: SourceFileKind = f.options().source_type().into();
+    let needs_quotes = list
+        .children()
+        .filter_map(|member| member.children().find_map(JsLiteralMemberName::cast))
+        .filter_map(|name| name.value().ok())
+        .any(|value| {
+            let text = value.text_trimmed();
+            value.kind() == JS_STRING_LITERAL
+                && !can_unquote_member_name(&text[1..text.len() - 1], file_source)
+        });
+
+    f.context_mut()
+        .set_cached_quoted_member_list(list, needs_quotes);
+    needs_quotes
+}
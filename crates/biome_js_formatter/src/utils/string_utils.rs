@@ -25,11 +25,24 @@ pub(crate) struct FormatLiteralStringToken<'token> {
 
     /// The parent that holds the token
     parent_kind: StringLiteralParentKind,
+
+    /// Overrides the quote properties of the options, see [QuoteProperties::Consistent]
+    quote_properties: Option<QuoteProperties>,
 }
 
 impl<'token> FormatLiteralStringToken<'token> {
     pub fn new(token: &'token JsSyntaxToken, parent_kind: StringLiteralParentKind) -> Self {
-        Self { token, parent_kind }
+        Self {
+            token,
+            parent_kind,
+            quote_properties: None,
+        }
+    }
+
+    /// Uses `quote_properties` instead of the quote properties of the options
+    pub fn with_quote_properties(mut self, quote_properties: QuoteProperties) -> Self {
+        self.quote_properties = Some(quote_properties);
+        self
     }
 
     fn token(&self) -> &'token JsSyntaxToken {
@@ -48,7 +61,9 @@ impl<'token> FormatLiteralStringToken<'token> {
             JSX_STRING_LITERAL => options.jsx_quote_style(),
             _ => options.quote_style(),
         };
-        let chosen_quote_properties = options.quote_properties();
+        let chosen_quote_properties = self
+            .quote_properties
+            .unwrap_or_else(|| options.quote_properties());
 
         let mut string_cleaner =
             LiteralStringNormaliser::new(self, chosen_quote_style, chosen_quote_properties);
@@ -180,7 +195,7 @@ struct LiteralStringNormaliser<'token> {
 
 /// Convenience enum to map [biome_js_syntax::JsFileSource] by just reading
 /// the type of file
-#[derive(Eq, PartialEq)]
+#[derive(Eq, PartialEq, Clone, Copy)]
 pub(crate) enum SourceFileKind {
     TypeScript,
     JavaScript,
@@ -196,6 +211,26 @@ impl From<JsFileSource> for SourceFileKind {
     }
 }
 
+/// Returns `true` if the member name `text`, without its quotes, can be written without quotes
+pub(crate) fn can_unquote_member_name(text: &str, file_source: SourceFileKind) -> bool {
+    if text.chars().next().map_or(false, |c| c.is_ascii_digit()) {
+        if let Ok(parsed) = text.parse::<f64>() {
+            // In TypeScript, numbers like members have different meaning from numbers.
+            // Hence, if we see a number, we bail straightaway
+            if file_source == SourceFileKind::TypeScript {
+                return false;
+            }
+
+            // Rule out inexact floats and octal literals
+            return parsed.to_string() == text;
+        }
+
+        return false;
+    }
+
+    is_js_ident(text)
+}
+
 impl<'token> LiteralStringNormaliser<'token> {
     pub fn new(
         token: &'token FormatLiteralStringToken<'_>,
@@ -240,33 +275,15 @@ impl<'token> LiteralStringNormaliser<'token> {
     }
 
     /// We can change the text only if there are alphanumeric or alphabetic characters, depending on the file source
+    ///
+    /// With [QuoteProperties::Consistent], the caller has already checked that the names of the
+    /// object keep their quotes, see [crate::utils::member_name_quote_properties].
     fn can_remove_quotes(&self, file_source: SourceFileKind) -> bool {
-        if self.chosen_quote_properties == QuoteProperties::Preserve {
-            return false;
-        }
-
-        let text_to_check = self.raw_content();
-
-        if text_to_check
-            .chars()
-            .next()
-            .map_or(false, |c| c.is_ascii_digit())
-        {
-            if let Ok(parsed) = text_to_check.parse::<f64>() {
-                // In TypeScript, numbers like members have different meaning from numbers.
-                // Hence, if we see a number, we bail straightaway
-                if file_source == SourceFileKind::TypeScript {
-                    return false;
-                }
-
-                // Rule out inexact floats and octal literals
-                return parsed.to_string() == text_to_check;
-            }
-
+        if self.chosen_quote_properties != QuoteProperties::AsNeeded {
             return false;
         }
 
-        is_js_ident(text_to_check)
+        can_unquote_member_name(self.raw_content(), file_source)
     }
 
     fn normalise_type_member(
@@ -4,8 +4,9 @@ use biome_formatter::{
 use biome_formatter_test::TestFormatLanguage;
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
-    ArrowParentheses, BracketSameLine, BracketSpacing, JsFormatContext, JsFormatOptions,
-    QuoteProperties, QuoteStyle, Semicolons,
+    ArrowParentheses, BlankLineAfterImports, BlankLineBeforeReturn, BracketSameLine,
+    BracketSpacing, JsFormatContext, JsFormatOptions, JsxWhitespaceFidelity, QuoteProperties,
+    QuoteStyle, Semicolons, SingleLineIfStatements, UnionBreakThreshold, UnionLeadingSeparator,
 };
 use biome_js_formatter::{format_node, format_range, JsFormatLanguage};
 use biome_js_parser::{parse, JsParserOptions};
@@ -136,6 +137,7 @@ impl From<JsSerializableQuoteStyle> for QuoteStyle {
 pub enum JsSerializableQuoteProperties {
     AsNeeded,
     Preserve,
+    Consistent,
 }
 
 impl From<JsSerializableQuoteProperties> for QuoteProperties {
@@ -143,6 +145,7 @@ impl From<JsSerializableQuoteProperties> for QuoteProperties {
         match test {
             JsSerializableQuoteProperties::AsNeeded => QuoteProperties::AsNeeded,
             JsSerializableQuoteProperties::Preserve => QuoteProperties::Preserve,
+            JsSerializableQuoteProperties::Consistent => QuoteProperties::Consistent,
         }
     }
 }
@@ -231,6 +234,24 @@ pub struct JsSerializableFormatOptions {
 
     /// Whether to hug the closing bracket of multiline HTML/JSX tags to the end of the last line, rather than being alone on the following line. Defaults to false.
     pub bracket_same_line: Option<bool>,
+
+    /// Whether to keep short if statements on a single line.
+    pub single_line_if_statements: Option<bool>,
+
+    /// Whether to add a blank line after the imports.
+    pub blank_line_after_imports: Option<bool>,
+
+    /// Whether to add a blank line before return statements.
+    pub blank_line_before_return: Option<bool>,
+
+    /// Whether multiline unions start with a `|`.
+    pub union_leading_separator: Option<bool>,
+
+    /// Unions with more members than this always break.
+    pub union_break_threshold: Option<u8>,
+
+    /// Whether the formatter keeps the rendered text of the JSX elements unchanged.
+    pub jsx_whitespace_fidelity: Option<bool>,
 }
 
 impl JsSerializableFormatOptions {
@@ -280,6 +301,30 @@ impl JsSerializableFormatOptions {
                 self.bracket_same_line
                     .map_or_else(BracketSameLine::default, |value| value.into()),
             )
+            .with_single_line_if_statements(
+                self.single_line_if_statements
+                    .map_or_else(SingleLineIfStatements::default, |value| value.into()),
+            )
+            .with_blank_line_after_imports(
+                self.blank_line_after_imports
+                    .map_or_else(BlankLineAfterImports::default, |value| value.into()),
+            )
+            .with_blank_line_before_return(
+                self.blank_line_before_return
+                    .map_or_else(BlankLineBeforeReturn::default, |value| value.into()),
+            )
+            .with_union_leading_separator(
+                self.union_leading_separator
+                    .map_or_else(UnionLeadingSeparator::default, |value| value.into()),
+            )
+            .with_union_break_threshold(
+                self.union_break_threshold
+                    .map_or_else(UnionBreakThreshold::default, |value| value.into()),
+            )
+            .with_jsx_whitespace_fidelity(
+                self.jsx_whitespace_fidelity
+                    .map_or_else(JsxWhitespaceFidelity::default, |value| value.into()),
+            )
     }
 }
 
@@ -0,0 +1,24 @@
+import a from "a";
+import { b } from "b";
+const c = a + b;
+
+
+function f() {
+	return c;
+}
+
+function g(x) {
+	const y = x * 2;
+	return y;
+}
+
+function h(x) {
+	if (x) {
+		x++;
+
+
+		return x;
+	}
+	// done
+	return 0;
+}
@@ -0,0 +1,16 @@
+foo({ alpha: "aaaaaaaaaaaaaaaaaa", beta: "bbbbbbbbbbbbbbbbbbbb", gamma: "cccccccccccccccc" });
+
+foo([ "aaaaaaaaaaaaaaaaaaaaa", "bbbbbbbbbbbbbbbbbbbbbbbb", "cccccccccccccccccccccccc" ]);
+
+new Foo({ alpha: "aaaaaaaaaaaaaaaaaa", beta: "bbbbbbbbbbbbbbbbbbbb", gamma: "cccccccc" });
+
+foo(
+
+	{ alpha: "aaaaaaaaaaaaaaaaaa", beta: "bbbbbbbbbbbbbbbbbbbb", gamma: "cccccccccccccccc" }
+);
+
+foo(
+	{ alpha: "aaaaaaaaaaaaaaaaaa" },
+
+	{ beta: "bbbbbbbbbbbbbbbbbbbb" },
+);
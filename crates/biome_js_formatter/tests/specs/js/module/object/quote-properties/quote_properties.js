@@ -0,0 +1,7 @@
+const a = { b: 1, "c-d": 2, 'e': 3, 1: 4 };
+const f = { g: 1, "h": 2 };
+const i = {
+	j: { "k-l": 1, m: 2 },
+	n: 3,
+};
+class O { "p" = 1; q = 2 }
@@ -0,0 +1,7 @@
+call(a, ( // about b
+	b
+));
+
+const list = [a, ( // about b
+	b
+)];
@@ -0,0 +1,15 @@
+if (a) return;
+
+if (a) { return; }
+
+if (a) {
+	return;
+}
+
+if (a) { b(); c(); }
+
+if (a) { return; } else { b(); }
+
+if (a) { /* comment */ return; }
+
+if (someVeryLongCondition && anotherVeryLongCondition) { return someValue + anotherValue; }
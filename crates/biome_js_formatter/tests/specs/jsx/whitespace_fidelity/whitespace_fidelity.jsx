@@ -0,0 +1,4 @@
+<div>Hello  world</div>;
+<div>a{x}   c</div>;
+<div>	indented</div>;
+<span>a {" "}</span>;
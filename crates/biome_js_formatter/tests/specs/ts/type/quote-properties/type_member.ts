@@ -0,0 +1,3 @@
+type A = { b: string; "c-d": number };
+interface E { f: string; "1": number }
+type G = { h: string; "i": number };
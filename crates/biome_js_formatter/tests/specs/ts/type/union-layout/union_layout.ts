@@ -0,0 +1,5 @@
+type A = "a" | "b" | "c";
+
+type LongUnion = "aaaaaaaaaaaa" | "bbbbbbbbbbbbb" | "ccccccccccccc" | "dddddddddddd" | "eeeeeeeeeee";
+
+type B = | "a" | "b";
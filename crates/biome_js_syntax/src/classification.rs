@@ -0,0 +1,410 @@
+//! Predicates that classify the expressions, shared by the lint rules and the assists.
+//!
+//! The predicates are syntactic: they don't resolve the references, so a local variable that
+//! shadows a global, e.g. `Math`, isn't detected. When the syntax doesn't tell, they answer
+//! conservatively: the expression isn't constant, the call isn't pure, and the expression
+//! may throw.
+
+use crate::{
+    global_identifier, AnyJsArrayElement, AnyJsExpression, AnyJsLiteralExpression,
+    AnyJsMemberExpression, AnyJsObjectMember, AnyJsObjectMemberName, AnyJsTemplateElement,
+    AnyTsType, JsBinaryOperator, JsCallExpression, JsSyntaxKind, JsSyntaxNode, JsUnaryOperator,
+};
+use biome_rowan::AstNode;
+
+/// Returns `true` if `expression` evaluates to the same primitive value every time, without
+/// side effects: a literal, a template without tag, or an operator applied to constants.
+///
+/// The references, including `undefined`, aren't constant because they can be shadowed. The
+/// regular expressions, the arrays and the objects aren't constant either: each evaluation
+/// creates a new object.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_js_factory::make;
+/// use biome_js_syntax::classification::is_constant_expression;
+/// use biome_js_syntax::{AnyJsExpression, AnyJsLiteralExpression, T};
+///
+/// let one = make::js_number_literal_expression(make::js_number_literal("1"));
+/// let one = AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::from(one));
+/// let x = make::js_identifier_expression(make::js_reference_identifier(make::ident("x")));
+///
+/// let sum = make::js_binary_expression(one.clone(), make::token(T![+]), one.clone());
+/// assert!(is_constant_expression(&sum.into()));
+///
+/// let sum = make::js_binary_expression(one, make::token(T![+]), x.into());
+/// assert!(!is_constant_expression(&sum.into()));
+/// ```
+pub fn is_constant_expression(expression: &AnyJsExpression) -> bool {
+    let is_constant = |expression: Option<AnyJsExpression>| {
+        expression.is_some_and(|expression| is_constant_expression(&expression))
+    };
+    match expression {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => {
+            !matches!(literal, AnyJsLiteralExpression::JsRegexLiteralExpression(_))
+        }
+        AnyJsExpression::JsTemplateExpression(template) => {
+            template.tag().is_none()
+                && template
+                    .elements()
+                    .into_iter()
+                    .all(|element| match element {
+                        AnyJsTemplateElement::JsTemplateChunkElement(_) => true,
+                        AnyJsTemplateElement::JsTemplateElement(element) => {
+                            is_constant(element.expression().ok())
+                        }
+                    })
+        }
+        AnyJsExpression::JsUnaryExpression(unary) => {
+            unary.operator() != Ok(JsUnaryOperator::Delete) && is_constant(unary.argument().ok())
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            is_constant(binary.left().ok()) && is_constant(binary.right().ok())
+        }
+        AnyJsExpression::JsLogicalExpression(logical) => {
+            is_constant(logical.left().ok()) && is_constant(logical.right().ok())
+        }
+        AnyJsExpression::JsConditionalExpression(conditional) => {
+            is_constant(conditional.test().ok())
+                && is_constant(conditional.consequent().ok())
+                && is_constant(conditional.alternate().ok())
+        }
+        AnyJsExpression::JsSequenceExpression(sequence) => {
+            is_constant(sequence.left().ok()) && is_constant(sequence.right().ok())
+        }
+        AnyJsExpression::JsParenthesizedExpression(_)
+        | AnyJsExpression::TsAsExpression(_)
+        | AnyJsExpression::TsNonNullAssertionExpression(_)
+        | AnyJsExpression::TsSatisfiesExpression(_)
+        | AnyJsExpression::TsTypeAssertionExpression(_) => {
+            is_constant(inner_expression(expression))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `call` calls a function that doesn't have side effects, e.g. `Math.max`
+/// or `Number`, or if the call is annotated with `/*#__PURE__*/` or `/*@__PURE__*/`, like
+/// the bundlers do.
+///
+/// The arguments aren't checked: `Math.max(next())` is a pure call whose argument has side
+/// effects. The conversion functions, e.g. `String`, are pure although they may call the
+/// methods `toString` and `valueOf` of their argument.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_js_factory::make;
+/// use biome_js_syntax::classification::is_pure_call;
+/// use biome_js_syntax::T;
+///
+/// let arguments = || {
+///     make::js_call_arguments(
+///         make::token(T!['(']),
+///         make::js_call_argument_list([], []),
+///         make::token(T![')']),
+///     )
+/// };
+///
+/// let math = make::js_identifier_expression(make::js_reference_identifier(make::ident("Math")));
+/// let member = make::js_name(make::ident("random"));
+/// let random = make::js_static_member_expression(math.into(), make::token(T![.]), member.into());
+/// assert!(!is_pure_call(&make::js_call_expression(random.into(), arguments()).build()));
+///
+/// let math = make::js_identifier_expression(make::js_reference_identifier(make::ident("Math")));
+/// let member = make::js_name(make::ident("max"));
+/// let max = make::js_static_member_expression(math.into(), make::token(T![.]), member.into());
+/// assert!(is_pure_call(&make::js_call_expression(max.into(), arguments()).build()));
+/// ```
+pub fn is_pure_call(call: &JsCallExpression) -> bool {
+    if has_pure_annotation(call) {
+        return true;
+    }
+    let Ok(callee) = call.callee() else {
+        return false;
+    };
+    let callee = callee.omit_parentheses();
+    if let Some((_, name)) = global_identifier(&callee) {
+        return is_pure_global_function(name.text());
+    }
+    let Some(member) = AnyJsMemberExpression::cast_ref(callee.syntax()) else {
+        return false;
+    };
+    let Some(name) = member.member_name() else {
+        return false;
+    };
+    let Some((_, object)) = member
+        .object()
+        .ok()
+        .and_then(|object| global_identifier(&object.omit_parentheses()))
+    else {
+        return false;
+    };
+    is_pure_global_method(object.text(), name.text())
+}
+
+/// Returns `true` if the comments before `call` include `#__PURE__` or `@__PURE__`
+fn has_pure_annotation(call: &JsCallExpression) -> bool {
+    let Some(token) = call.syntax().first_token() else {
+        return false;
+    };
+    // The comments on the line of the previous token are its trailing trivia
+    let previous_trivia = token
+        .prev_token()
+        .map(|previous| previous.trailing_trivia().pieces());
+    token
+        .leading_trivia()
+        .pieces()
+        .chain(previous_trivia.into_iter().flatten())
+        .filter_map(|piece| piece.as_comments())
+        .any(|comment| {
+            let text = comment.text();
+            text.contains("#__PURE__") || text.contains("@__PURE__")
+        })
+}
+
+/// Returns `true` if the global function `name` doesn't have side effects
+fn is_pure_global_function(name: &str) -> bool {
+    matches!(
+        name,
+        "BigInt"
+            | "Boolean"
+            | "Number"
+            | "String"
+            | "Symbol"
+            | "decodeURI"
+            | "decodeURIComponent"
+            | "encodeURI"
+            | "encodeURIComponent"
+            | "isFinite"
+            | "isNaN"
+            | "parseFloat"
+            | "parseInt"
+    )
+}
+
+/// Returns `true` if the method `name` of the global object `object` doesn't have side effects
+fn is_pure_global_method(object: &str, name: &str) -> bool {
+    match object {
+        // `Math.random` changes the state of the generator
+        "Math" => name != "random",
+        "Number" => matches!(
+            name,
+            "isFinite" | "isInteger" | "isNaN" | "isSafeInteger" | "parseFloat" | "parseInt"
+        ),
+        "Array" => matches!(name, "isArray" | "of"),
+        "Object" => matches!(name, "is" | "keys"),
+        "String" => matches!(name, "fromCharCode" | "fromCodePoint"),
+        _ => false,
+    }
+}
+
+/// Returns `true` if evaluating `expression` may throw an exception.
+///
+/// An expression may throw when it calls a function, including the getters, the setters and
+/// the conversions of its operands, e.g. `a + b` calls `valueOf` on objects. The references
+/// are considered safe, although they throw in their temporal dead zone.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_js_factory::make;
+/// use biome_js_syntax::classification::may_throw;
+/// use biome_js_syntax::{AnyJsExpression, AnyJsLiteralExpression, T};
+///
+/// let one = make::js_number_literal_expression(make::js_number_literal("1"));
+/// let one = AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::from(one));
+/// let x = make::js_identifier_expression(make::js_reference_identifier(make::ident("x")));
+///
+/// let comparison = make::js_binary_expression(x.clone().into(), make::token(T![===]), one.clone());
+/// assert!(!may_throw(&comparison.into()));
+///
+/// let sum = make::js_binary_expression(x.into(), make::token(T![+]), one);
+/// assert!(may_throw(&sum.into()));
+/// ```
+pub fn may_throw(expression: &AnyJsExpression) -> bool {
+    let may_throw = |expression: Option<AnyJsExpression>| {
+        expression.map_or(true, |expression| may_throw(&expression))
+    };
+    match expression {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_)
+        | AnyJsExpression::JsIdentifierExpression(_)
+        | AnyJsExpression::JsImportMetaExpression(_)
+        | AnyJsExpression::JsNewTargetExpression(_)
+        | AnyJsExpression::JsThisExpression(_) => false,
+        AnyJsExpression::JsTemplateExpression(template) => {
+            // The substitutions are converted to strings
+            template.tag().is_some()
+                || template
+                    .elements()
+                    .into_iter()
+                    .any(|element| match element {
+                        AnyJsTemplateElement::JsTemplateChunkElement(_) => false,
+                        AnyJsTemplateElement::JsTemplateElement(element) => {
+                            may_throw_on_conversion(element.expression().ok())
+                        }
+                    })
+        }
+        AnyJsExpression::JsUnaryExpression(unary) => match unary.operator() {
+            Ok(JsUnaryOperator::LogicalNot | JsUnaryOperator::Typeof | JsUnaryOperator::Void) => {
+                may_throw(unary.argument().ok())
+            }
+            // `delete` throws on the properties that can't be configured
+            Ok(JsUnaryOperator::Delete) | Err(_) => true,
+            // `+1n` throws because the big integers can't be converted to numbers
+            Ok(operator) => {
+                may_throw_on_conversion(unary.argument().ok())
+                    || (operator == JsUnaryOperator::Plus && is_bigint(unary.argument().ok()))
+            }
+        },
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            if matches!(
+                binary.operator(),
+                Ok(JsBinaryOperator::StrictEquality | JsBinaryOperator::StrictInequality)
+            ) {
+                may_throw(binary.left().ok()) || may_throw(binary.right().ok())
+            } else {
+                // The operators throw on the big integers mixed with numbers, and on the
+                // divisions by zero
+                may_throw_on_conversion(binary.left().ok())
+                    || may_throw_on_conversion(binary.right().ok())
+                    || is_bigint(binary.left().ok())
+                    || is_bigint(binary.right().ok())
+            }
+        }
+        AnyJsExpression::JsLogicalExpression(logical) => {
+            may_throw(logical.left().ok()) || may_throw(logical.right().ok())
+        }
+        AnyJsExpression::JsConditionalExpression(conditional) => {
+            may_throw(conditional.test().ok())
+                || may_throw(conditional.consequent().ok())
+                || may_throw(conditional.alternate().ok())
+        }
+        AnyJsExpression::JsSequenceExpression(sequence) => {
+            may_throw(sequence.left().ok()) || may_throw(sequence.right().ok())
+        }
+        AnyJsExpression::JsArrayExpression(array) => {
+            array.elements().into_iter().any(|element| match element {
+                Ok(AnyJsArrayElement::AnyJsExpression(expression)) => may_throw(&expression),
+                Ok(AnyJsArrayElement::JsArrayHole(_)) => false,
+                // The spread calls the iterator of its argument
+                Ok(AnyJsArrayElement::JsSpread(_)) | Err(_) => true,
+            })
+        }
+        AnyJsExpression::JsObjectExpression(object) => {
+            object.members().into_iter().any(|member| match member {
+                Ok(AnyJsObjectMember::JsPropertyObjectMember(member)) => {
+                    may_throw_on_member_name(member.name().ok()) || may_throw(member.value().ok())
+                }
+                Ok(AnyJsObjectMember::JsMethodObjectMember(member)) => {
+                    may_throw_on_member_name(member.name().ok())
+                }
+                Ok(AnyJsObjectMember::JsGetterObjectMember(member)) => {
+                    may_throw_on_member_name(member.name().ok())
+                }
+                Ok(AnyJsObjectMember::JsSetterObjectMember(member)) => {
+                    may_throw_on_member_name(member.name().ok())
+                }
+                Ok(AnyJsObjectMember::JsShorthandPropertyObjectMember(_)) => false,
+                // The spread calls the getters of its argument
+                Ok(AnyJsObjectMember::JsSpread(_) | AnyJsObjectMember::JsBogusMember(_))
+                | Err(_) => true,
+            })
+        }
+        AnyJsExpression::JsParenthesizedExpression(_)
+        | AnyJsExpression::TsAsExpression(_)
+        | AnyJsExpression::TsNonNullAssertionExpression(_)
+        | AnyJsExpression::TsSatisfiesExpression(_)
+        | AnyJsExpression::TsTypeAssertionExpression(_) => may_throw(inner_expression(expression)),
+        _ => true,
+    }
+}
+
+/// Returns `true` if converting `expression` to a primitive may throw, e.g. because it calls
+/// the method `valueOf` of an object
+fn may_throw_on_conversion(expression: Option<AnyJsExpression>) -> bool {
+    match expression.map(AnyJsExpression::omit_parentheses) {
+        Some(AnyJsExpression::AnyJsLiteralExpression(literal)) => {
+            matches!(literal, AnyJsLiteralExpression::JsRegexLiteralExpression(_))
+        }
+        _ => true,
+    }
+}
+
+/// Returns `true` if `name` is computed with an expression that may throw
+fn may_throw_on_member_name(name: Option<AnyJsObjectMemberName>) -> bool {
+    match name {
+        Some(AnyJsObjectMemberName::JsLiteralMemberName(_)) => false,
+        Some(AnyJsObjectMemberName::JsComputedMemberName(name)) => {
+            may_throw_on_conversion(name.expression().ok())
+        }
+        None => true,
+    }
+}
+
+fn is_bigint(expression: Option<AnyJsExpression>) -> bool {
+    matches!(
+        expression.map(AnyJsExpression::omit_parentheses),
+        Some(AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsBigintLiteralExpression(_)
+        ))
+    )
+}
+
+/// Returns the expression wrapped by parentheses or by a TypeScript assertion
+fn inner_expression(expression: &AnyJsExpression) -> Option<AnyJsExpression> {
+    match expression {
+        AnyJsExpression::JsParenthesizedExpression(expression) => expression.expression().ok(),
+        AnyJsExpression::TsAsExpression(expression) => expression.expression().ok(),
+        AnyJsExpression::TsNonNullAssertionExpression(expression) => expression.expression().ok(),
+        AnyJsExpression::TsSatisfiesExpression(expression) => expression.expression().ok(),
+        AnyJsExpression::TsTypeAssertionExpression(expression) => expression.expression().ok(),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `node` is part of a type, which is erased from the emitted JavaScript:
+/// a type annotation, the type arguments or the type parameters, a type alias, an interface,
+/// an `implements` clause or an index signature.
+///
+/// The references of a `typeof` type, e.g. `typeof value`, are also in a type context. The
+/// type-only imports and exports are checked with
+/// [AnyJsNamedImportSpecifier::imports_only_types](crate::AnyJsNamedImportSpecifier::imports_only_types)
+/// and
+/// [AnyJsExportNamedSpecifier::exports_only_types](crate::AnyJsExportNamedSpecifier::exports_only_types).
+///
+/// ## Examples
+///
+/// ```
+/// use biome_js_factory::make;
+/// use biome_js_syntax::classification::is_in_type_context;
+/// use biome_js_syntax::T;
+/// use biome_rowan::AstNode;
+///
+/// let string = make::ts_string_type(make::token(T![string]));
+/// let annotation = make::ts_type_annotation(make::token(T![:]), string.into());
+/// let string = annotation.ty().unwrap();
+/// assert!(is_in_type_context(string.syntax()));
+///
+/// let x = make::js_identifier_expression(make::js_reference_identifier(make::ident("x")));
+/// assert!(!is_in_type_context(x.syntax()));
+/// ```
+pub fn is_in_type_context(node: &JsSyntaxNode) -> bool {
+    node.ancestors().any(|ancestor| {
+        AnyTsType::can_cast(ancestor.kind())
+            || matches!(
+                ancestor.kind(),
+                JsSyntaxKind::TS_TYPE_ANNOTATION
+                    | JsSyntaxKind::TS_RETURN_TYPE_ANNOTATION
+                    | JsSyntaxKind::TS_TYPE_ARGUMENTS
+                    | JsSyntaxKind::TS_TYPE_PARAMETERS
+                    | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+                    | JsSyntaxKind::TS_INTERFACE_DECLARATION
+                    | JsSyntaxKind::TS_IMPLEMENTS_CLAUSE
+                    | JsSyntaxKind::TS_INDEX_SIGNATURE_CLASS_MEMBER
+            )
+    })
+}
@@ -1,6 +1,8 @@
-use biome_rowan::{SyntaxResult, TokenText};
+use biome_rowan::{AstNodeList, SyntaxResult, TokenText};
 
-use crate::{inner_string_text, JsDirective};
+use crate::{
+    inner_string_text, AnyJsRoot, JsDirective, JsDirectiveList, JsSyntaxKind, JsSyntaxToken,
+};
 
 impl JsDirective {
     /// Get the inner text of a string not including the quotes
@@ -25,9 +27,100 @@ impl JsDirective {
     }
 }
 
+impl JsDirectiveList {
+    /// Returns the first directive of the prologue whose text, without the quotes, is `text`,
+    /// e.g. `use client`
+    pub fn find(&self, text: &str) -> Option<JsDirective> {
+        self.iter().find(|directive| {
+            directive
+                .inner_string_text()
+                .is_ok_and(|directive_text| directive_text.text() == text)
+        })
+    }
+
+    /// Whether the prologue contains the directive `text`, e.g. `use strict`
+    pub fn contains(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+}
+
+impl AnyJsRoot {
+    /// Returns the directive prologue of the script or the module, or [None] for an expression
+    /// snippet, which doesn't have one
+    pub fn directives(&self) -> Option<JsDirectiveList> {
+        match self {
+            AnyJsRoot::JsExpressionSnipped(_) => None,
+            AnyJsRoot::JsModule(module) => Some(module.directives()),
+            AnyJsRoot::JsScript(script) => Some(script.directives()),
+        }
+    }
+
+    /// Returns the hashbang line that starts the script or the module, e.g. `#!/usr/bin/env node`
+    pub fn hashbang(&self) -> Option<JsHashbang> {
+        let token = match self {
+            AnyJsRoot::JsExpressionSnipped(_) => None,
+            AnyJsRoot::JsModule(module) => module.interpreter_token(),
+            AnyJsRoot::JsScript(script) => script.interpreter_token(),
+        };
+        token.and_then(JsHashbang::cast)
+    }
+}
+
+/// The hashbang line that starts an executable script, e.g. `#!/usr/bin/env node`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsHashbang(JsSyntaxToken);
+
+impl JsHashbang {
+    /// Returns the hashbang of the token, or [None] if it isn't a hashbang
+    pub fn cast(token: JsSyntaxToken) -> Option<Self> {
+        (token.kind() == JsSyntaxKind::JS_SHEBANG).then_some(Self(token))
+    }
+
+    pub fn token(&self) -> &JsSyntaxToken {
+        &self.0
+    }
+
+    /// Returns the command of the hashbang, without `#!` and the surrounding spaces, e.g.
+    /// `/usr/bin/env node`
+    pub fn command(&self) -> &str {
+        self.0.text_trimmed().trim_start_matches("#!").trim()
+    }
+
+    /// Returns the name of the program that runs the file, e.g. `node` for `#!/usr/bin/env node`
+    /// or `#!/usr/local/bin/node --harmony`. The options and the variables passed to `env` are
+    /// skipped.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_js_syntax::directive_ext::JsHashbang;
+    /// use biome_js_syntax::{JsSyntaxKind, JsSyntaxToken};
+    ///
+    /// let token = JsSyntaxToken::new_detached(JsSyntaxKind::JS_SHEBANG, "#!/usr/bin/env -S deno run", [], []);
+    /// let hashbang = JsHashbang::cast(token).unwrap();
+    /// assert_eq!(hashbang.command(), "/usr/bin/env -S deno run");
+    /// assert_eq!(hashbang.interpreter(), Some("deno"));
+    /// ```
+    pub fn interpreter(&self) -> Option<&str> {
+        fn program_name(path: &str) -> &str {
+            path.rsplit('/').next().unwrap_or(path)
+        }
+
+        let mut words = self.command().split_whitespace();
+        let program = program_name(words.next()?);
+        if program != "env" {
+            return Some(program);
+        }
+        words
+            .find(|word| !word.starts_with('-') && !word.contains('='))
+            .map(program_name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use biome_js_factory::syntax::{JsDirective, JsSyntaxKind::*};
+    use super::JsHashbang;
+    use biome_js_factory::syntax::{JsDirective, JsDirectiveList, JsSyntaxKind::*};
     use biome_js_factory::JsSyntaxTreeBuilder;
     use biome_rowan::AstNode;
 
@@ -46,4 +139,62 @@ mod tests {
             assert_eq!(text, "use strict")
         }
     }
+
+    #[test]
+    fn js_directive_list_find() {
+        let mut tree_builder = JsSyntaxTreeBuilder::new();
+        tree_builder.start_node(JS_DIRECTIVE_LIST);
+        for token in ["\"use strict\"", "'use client'"] {
+            tree_builder.start_node(JS_DIRECTIVE);
+            tree_builder.token(JS_STRING_LITERAL, token);
+            tree_builder.finish_node();
+        }
+        tree_builder.finish_node();
+
+        let node = tree_builder.finish();
+        let directives = JsDirectiveList::cast(node).unwrap();
+        assert!(directives.contains("use strict"));
+        assert!(directives.contains("use client"));
+        assert!(!directives.contains("use server"));
+        assert_eq!(
+            directives
+                .find("use client")
+                .unwrap()
+                .value_token()
+                .unwrap()
+                .text(),
+            "'use client'"
+        );
+    }
+
+    #[test]
+    fn js_hashbang_interpreter() {
+        use crate::{JsSyntaxKind, JsSyntaxToken};
+
+        let cases = [
+            ("#!/usr/bin/env node", "/usr/bin/env node", Some("node")),
+            (
+                "#! /usr/local/bin/node --harmony ",
+                "/usr/local/bin/node --harmony",
+                Some("node"),
+            ),
+            (
+                "#!/usr/bin/env -S NODE_ENV=production bun",
+                "/usr/bin/env -S NODE_ENV=production bun",
+                Some("bun"),
+            ),
+            ("#!/usr/bin/env", "/usr/bin/env", None),
+            ("#!", "", None),
+        ];
+        for (text, command, interpreter) in cases {
+            let token = JsSyntaxToken::new_detached(JsSyntaxKind::JS_SHEBANG, text, [], []);
+            let hashbang = JsHashbang::cast(token).unwrap();
+            assert_eq!(hashbang.command(), command);
+            assert_eq!(hashbang.interpreter(), interpreter);
+        }
+
+        let token =
+            JsSyntaxToken::new_detached(JsSyntaxKind::JS_STRING_LITERAL, "\"use strict\"", [], []);
+        assert_eq!(JsHashbang::cast(token), None);
+    }
 }
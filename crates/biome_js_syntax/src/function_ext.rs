@@ -47,6 +47,18 @@ impl AnyFunctionLike {
         }
     }
 
+    pub fn async_token(&self) -> Option<JsSyntaxToken> {
+        match self {
+            AnyFunctionLike::AnyJsFunction(any_js_function) => any_js_function.async_token(),
+            AnyFunctionLike::JsMethodClassMember(method_class_member) => {
+                method_class_member.async_token()
+            }
+            AnyFunctionLike::JsMethodObjectMember(method_obj_member) => {
+                method_obj_member.async_token()
+            }
+        }
+    }
+
     pub fn is_generator(&self) -> bool {
         match self {
             AnyFunctionLike::AnyJsFunction(any_js_function) => any_js_function.is_generator(),
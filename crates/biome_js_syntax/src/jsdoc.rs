@@ -0,0 +1,913 @@
+//! Parser of the JSDoc comments, `/** ... */`, into their description and their block tags.
+//!
+//! The parser is tolerant: it always returns a [JsDoc] for a JSDoc comment, and the parts of the
+//! comment that it couldn't make sense of are listed in [JsDoc::errors].
+
+use crate::{JsSyntaxKind, JsSyntaxNode, JsSyntaxToken};
+use biome_rowan::{TextRange, TextSize};
+
+/// A JSDoc comment
+///
+/// ```js
+/// /**
+///  * Adds two numbers.
+///  * @param {number} a The first number
+///  * @param {number} [b=0] The second number
+///  * @returns {number}
+///  * @deprecated Use `sum` instead
+///  */
+/// ```
+///
+/// All the ranges are absolute: they include the offset passed to [JsDoc::parse].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsDoc {
+    /// The text before the first block tag, without the leading `*` of the lines
+    pub description: String,
+    pub tags: Vec<JsDocTag>,
+    /// The parts of the comment that don't follow the syntax of JSDoc
+    pub errors: Vec<JsDocError>,
+    /// The range of the comment, from `/**` to `*/`
+    pub range: TextRange,
+}
+
+/// A block tag of a JSDoc comment, e.g. `@param {string} name The name of the user`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsDocTag {
+    pub kind: JsDocTagKind,
+    /// The name of the tag, without `@`
+    pub name: String,
+    /// The range of the name of the tag, including `@`
+    pub name_range: TextRange,
+    /// The type between braces, e.g. `{string}`
+    pub type_expression: Option<JsDocTypeExpression>,
+    /// The name documented by `@param` and `@property`
+    pub parameter: Option<JsDocParameter>,
+    /// The text after the type and the name, without the `-` that may separate them
+    pub description: String,
+    /// The range of the tag, from `@` to the end of its description
+    pub range: TextRange,
+}
+
+/// The tags that have a meaning for the analyzer, the aliases of a tag have the same kind
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum JsDocTagKind {
+    /// `@param`, `@arg` or `@argument`
+    Param,
+    /// `@property` or `@prop`
+    Property,
+    /// `@returns` or `@return`
+    Returns,
+    /// `@deprecated`
+    Deprecated,
+    /// `@type`
+    Type,
+    /// `@throws` or `@exception`
+    Throws,
+    /// Any other tag
+    Other,
+}
+
+impl JsDocTagKind {
+    fn from_name(name: &str) -> Self {
+        match name {
+            "param" | "arg" | "argument" => Self::Param,
+            "property" | "prop" => Self::Property,
+            "returns" | "return" => Self::Returns,
+            "deprecated" => Self::Deprecated,
+            "type" => Self::Type,
+            "throws" | "exception" => Self::Throws,
+            _ => Self::Other,
+        }
+    }
+
+    /// Whether the tag documents a name after its type
+    const fn has_parameter(&self) -> bool {
+        matches!(self, Self::Param | Self::Property)
+    }
+}
+
+/// The name documented by a `@param` or a `@property` tag
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsDocParameter {
+    /// The name, e.g. `options.name` for a property of a parameter
+    pub name: String,
+    /// The range of the name, without the brackets
+    pub range: TextRange,
+    /// Whether the name is between brackets: `[name]` or `[name=default]`
+    pub optional: bool,
+    /// The default value after `=`, when the name is between brackets
+    pub default: Option<String>,
+}
+
+impl JsDocParameter {
+    /// The name of the parameter that is documented, `options` for `options.name`
+    pub fn root_name(&self) -> &str {
+        self.name
+            .split_once('.')
+            .map_or(self.name.as_str(), |(root, _)| root)
+    }
+
+    /// Whether the tag documents a property of a parameter, e.g. `options.name`
+    pub fn is_property(&self) -> bool {
+        self.name.contains('.')
+    }
+}
+
+/// A type between braces
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsDocTypeExpression {
+    pub ty: JsDocType,
+    /// The text between the braces
+    pub text: String,
+    /// The range of the type, including the braces
+    pub range: TextRange,
+}
+
+/// The type of a [JsDocTypeExpression]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum JsDocType {
+    /// `*`
+    Any,
+    /// `?`
+    Unknown,
+    /// A name, e.g. `string` or `Foo.Bar`
+    Name(String),
+    /// A string or a number literal, with its quotes
+    Literal(String),
+    /// `A | B`
+    Union(Vec<JsDocType>),
+    /// `T[]`
+    Array(Box<JsDocType>),
+    /// `Array<T>` or `Array.<T>`
+    Generic {
+        name: String,
+        arguments: Vec<JsDocType>,
+    },
+    /// `?T`
+    Nullable(Box<JsDocType>),
+    /// `!T`
+    NonNullable(Box<JsDocType>),
+    /// `T=`
+    Optional(Box<JsDocType>),
+    /// `...T`
+    Rest(Box<JsDocType>),
+    /// A type that isn't broken down, e.g. a function type or a record type
+    Other(String),
+}
+
+/// A part of a JSDoc comment that doesn't follow the syntax of JSDoc
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsDocError {
+    pub kind: JsDocErrorKind,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum JsDocErrorKind {
+    /// `@` isn't followed by the name of a tag
+    MissingTagName,
+    /// The type of a tag doesn't have its closing brace
+    UnclosedType,
+    /// The braces of a type are empty
+    EmptyType,
+    /// A `@param` or a `@property` tag doesn't have a name
+    MissingParameterName,
+    /// An optional name doesn't have its closing bracket
+    UnclosedParameterName,
+}
+
+impl JsDocErrorKind {
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::MissingTagName => "The tag doesn't have a name.",
+            Self::UnclosedType => "The type doesn't have its closing brace.",
+            Self::EmptyType => "The type is empty.",
+            Self::MissingParameterName => "The tag doesn't have the name that it documents.",
+            Self::UnclosedParameterName => "The optional name doesn't have its closing bracket.",
+        }
+    }
+}
+
+impl JsDoc {
+    /// Parses the text of a comment, including `/**` and `*/`, that starts at `offset`.
+    ///
+    /// Returns [None] when the comment isn't a JSDoc comment, e.g. `/* ... */` or `/*** ... */`.
+    pub fn parse(comment: &str, offset: TextSize) -> Option<Self> {
+        let content = comment.strip_prefix("/**")?.strip_suffix("*/")?;
+        if content.starts_with('*') {
+            return None;
+        }
+
+        let mut description = vec![];
+        let mut tags: Vec<(&str, usize, Vec<Line>)> = vec![];
+        let mut errors = vec![];
+        let mut in_code_block = false;
+        for line in lines(content, 3) {
+            let trimmed = line.trim_start();
+            if trimmed.text.starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if let Some(rest) = trimmed.text.strip_prefix('@').filter(|_| !in_code_block) {
+                let name_len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                    .unwrap_or(rest.len());
+                if name_len > 0 {
+                    let body = Line {
+                        text: &rest[name_len..],
+                        start: trimmed.start + 1 + name_len,
+                    };
+                    tags.push((&rest[..name_len], trimmed.start, vec![body]));
+                    continue;
+                }
+                errors.push(JsDocError {
+                    kind: JsDocErrorKind::MissingTagName,
+                    range: range(offset, trimmed.start, trimmed.start + 1),
+                });
+            }
+            match tags.last_mut() {
+                Some((_, _, body)) => body.push(line),
+                None => description.push(line),
+            }
+        }
+
+        let tags = tags
+            .into_iter()
+            .map(|(name, start, body)| parse_tag(name, start, body, offset, &mut errors))
+            .collect();
+
+        Some(Self {
+            description: join_lines(&description),
+            tags,
+            errors,
+            range: range(offset, 0, comment.len()),
+        })
+    }
+
+    /// Returns the JSDoc comment of `node`: the last JSDoc comment before the node, or before
+    /// the statement that starts with the node, e.g. `export function f() {}` or `const f = 1`
+    pub fn for_node(node: &JsSyntaxNode) -> Option<Self> {
+        let mut node = node.clone();
+        loop {
+            if let Some(doc) = node.first_token().and_then(|token| Self::for_token(&token)) {
+                return Some(doc);
+            }
+            let parent = node.parent()?;
+            let is_first_child = parent.first_child().as_ref() == Some(&node);
+            let is_declaration_wrapper = matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_EXPORT
+                    | JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+                    | JsSyntaxKind::JS_VARIABLE_DECLARATOR_LIST
+                    | JsSyntaxKind::JS_VARIABLE_DECLARATION
+                    | JsSyntaxKind::JS_VARIABLE_STATEMENT
+                    | JsSyntaxKind::TS_DECLARE_STATEMENT
+            );
+            if !(is_first_child && is_declaration_wrapper) {
+                return None;
+            }
+            node = parent;
+        }
+    }
+
+    /// Returns the last JSDoc comment of the leading trivia of `token`
+    pub fn for_token(token: &JsSyntaxToken) -> Option<Self> {
+        token
+            .leading_trivia()
+            .pieces()
+            .rev()
+            .filter_map(|piece| piece.as_comments())
+            .find_map(|comment| Self::parse(comment.text(), comment.text_range().start()))
+    }
+
+    /// The `@param` tags, in their order in the comment
+    pub fn params(&self) -> impl Iterator<Item = &JsDocTag> {
+        self.tags_of_kind(JsDocTagKind::Param)
+    }
+
+    /// The `@returns` tag
+    pub fn returns(&self) -> Option<&JsDocTag> {
+        self.tags_of_kind(JsDocTagKind::Returns).next()
+    }
+
+    /// The `@deprecated` tag
+    pub fn deprecated(&self) -> Option<&JsDocTag> {
+        self.tags_of_kind(JsDocTagKind::Deprecated).next()
+    }
+
+    pub fn tags_of_kind(&self, kind: JsDocTagKind) -> impl Iterator<Item = &JsDocTag> {
+        self.tags.iter().filter(move |tag| tag.kind == kind)
+    }
+}
+
+impl JsDocType {
+    /// Parses the text between the braces of a type. The types that aren't broken down are
+    /// returned as [JsDocType::Other].
+    pub fn parse(text: &str) -> Self {
+        let mut parser = TypeParser { text, position: 0 };
+        match parser.parse_union() {
+            Some(ty) if parser.at_end() => ty,
+            _ => Self::Other(text.trim().to_string()),
+        }
+    }
+}
+
+/// A line of a comment, without its leading `*`
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+    text: &'a str,
+    /// The position of the first character of the line in the comment
+    start: usize,
+}
+
+impl Line<'_> {
+    fn trim_start(self) -> Self {
+        let text = self.text.trim_start();
+        Self {
+            text,
+            start: self.start + self.text.len() - text.len(),
+        }
+    }
+}
+
+/// Splits the content of a comment into its lines, without the leading `*` of each line
+fn lines(content: &str, start: usize) -> impl Iterator<Item = Line<'_>> {
+    let mut line_start = start;
+    content.split('\n').enumerate().map(move |(index, text)| {
+        let line = Line {
+            text: text.strip_suffix('\r').unwrap_or(text),
+            start: line_start,
+        };
+        line_start += text.len() + 1;
+        if index == 0 {
+            return line.trim_start();
+        }
+        let line = line.trim_start();
+        match line.text.strip_prefix('*') {
+            Some(text) => {
+                let text = text.strip_prefix(' ').unwrap_or(text);
+                Line {
+                    text,
+                    start: line.start + line.text.len() - text.len(),
+                }
+            }
+            None => line,
+        }
+    })
+}
+
+fn join_lines(lines: &[Line]) -> String {
+    lines
+        .iter()
+        .map(|line| line.text.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+fn range(offset: TextSize, start: usize, end: usize) -> TextRange {
+    TextRange::new(
+        offset + TextSize::from(start as u32),
+        offset + TextSize::from(end as u32),
+    )
+}
+
+fn parse_tag(
+    name: &str,
+    start: usize,
+    body: Vec<Line>,
+    offset: TextSize,
+    errors: &mut Vec<JsDocError>,
+) -> JsDocTag {
+    let kind = JsDocTagKind::from_name(name);
+    let name_range = range(offset, start, start + 1 + name.len());
+    let mut cursor = TagCursor {
+        lines: &body,
+        line: 0,
+        column: 0,
+    };
+
+    let errors_before = errors.len();
+    cursor.skip_whitespace();
+    let type_expression = if cursor.peek() == Some('{') {
+        parse_type_expression(&mut cursor, offset, errors)
+    } else {
+        None
+    };
+
+    let parameter = if kind.has_parameter() {
+        cursor.skip_whitespace();
+        let parameter = parse_parameter(&mut cursor, offset, errors);
+        // An unclosed type or name already consumed the name
+        let is_unclosed = errors[errors_before..].iter().any(|error| {
+            matches!(
+                error.kind,
+                JsDocErrorKind::UnclosedType | JsDocErrorKind::UnclosedParameterName
+            )
+        });
+        if parameter.is_none() && !is_unclosed {
+            errors.push(JsDocError {
+                kind: JsDocErrorKind::MissingParameterName,
+                range: name_range,
+            });
+        }
+        parameter
+    } else {
+        None
+    };
+
+    let end = body
+        .iter()
+        .rev()
+        .find(|line| !line.text.trim().is_empty())
+        .map_or(name_range.end(), |line| {
+            offset + TextSize::from((line.start + line.text.trim_end().len()) as u32)
+        });
+
+    JsDocTag {
+        kind,
+        name: name.to_string(),
+        name_range,
+        type_expression,
+        parameter,
+        description: cursor.description(),
+        range: TextRange::new(name_range.start(), end.max(name_range.end())),
+    }
+}
+
+/// Parses a type between braces, the cursor is at `{`
+fn parse_type_expression(
+    cursor: &mut TagCursor,
+    offset: TextSize,
+    errors: &mut Vec<JsDocError>,
+) -> Option<JsDocTypeExpression> {
+    let start = cursor.position();
+    let line = cursor.rest_of_line();
+    let line_end = line.start + line.text.trim_end().len();
+    cursor.bump();
+    let mut text = String::new();
+    let mut depth = 1;
+    loop {
+        let Some(c) = cursor.peek() else {
+            errors.push(JsDocError {
+                kind: JsDocErrorKind::UnclosedType,
+                range: range(offset, start, line_end),
+            });
+            return None;
+        };
+        cursor.bump();
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ => {}
+        }
+        text.push(c);
+    }
+
+    let range = range(offset, start, cursor.position());
+    if text.trim().is_empty() {
+        errors.push(JsDocError {
+            kind: JsDocErrorKind::EmptyType,
+            range,
+        });
+        return None;
+    }
+    Some(JsDocTypeExpression {
+        ty: JsDocType::parse(&text),
+        text,
+        range,
+    })
+}
+
+/// Parses the name after the type of a `@param` or a `@property` tag
+fn parse_parameter(
+    cursor: &mut TagCursor,
+    offset: TextSize,
+    errors: &mut Vec<JsDocError>,
+) -> Option<JsDocParameter> {
+    let line = cursor.rest_of_line();
+    if let Some(inner) = line.text.strip_prefix('[') {
+        // The default value may contain brackets, e.g. `[values=[]]`
+        let mut depth = 1;
+        let Some(end) = inner.find(|c| {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                _ => {}
+            }
+            depth == 0
+        }) else {
+            errors.push(JsDocError {
+                kind: JsDocErrorKind::UnclosedParameterName,
+                range: range(offset, line.start, line.start + line.text.trim_end().len()),
+            });
+            cursor.skip_line();
+            return None;
+        };
+        let (name, default) = match inner[..end].split_once('=') {
+            Some((name, default)) => (name, Some(default.trim().to_string())),
+            None => (&inner[..end], None),
+        };
+        let name_start = line.start + 1 + (name.len() - name.trim_start().len());
+        let name = name.trim();
+        cursor.advance(end + 2);
+        if name.is_empty() {
+            return None;
+        }
+        return Some(JsDocParameter {
+            name: name.to_string(),
+            range: range(offset, name_start, name_start + name.len()),
+            optional: true,
+            default,
+        });
+    }
+
+    let name_len = line
+        .text
+        .find(char::is_whitespace)
+        .unwrap_or(line.text.len());
+    let name = &line.text[..name_len];
+    if name.is_empty() || name.starts_with('-') {
+        return None;
+    }
+    cursor.advance(name_len);
+    Some(JsDocParameter {
+        name: name.to_string(),
+        range: range(offset, line.start, line.start + name_len),
+        optional: false,
+        default: None,
+    })
+}
+
+/// A position in the lines of a tag
+struct TagCursor<'a, 'b> {
+    lines: &'b [Line<'a>],
+    line: usize,
+    /// The byte offset in the current line
+    column: usize,
+}
+
+impl<'a, 'b> TagCursor<'a, 'b> {
+    /// The current character, `\n` at the end of a line that isn't the last one
+    fn peek(&self) -> Option<char> {
+        let line = self.lines.get(self.line)?;
+        match line.text[self.column..].chars().next() {
+            Some(c) => Some(c),
+            None if self.line + 1 < self.lines.len() => Some('\n'),
+            None => None,
+        }
+    }
+
+    fn bump(&mut self) {
+        let Some(line) = self.lines.get(self.line) else {
+            return;
+        };
+        match line.text[self.column..].chars().next() {
+            Some(c) => self.column += c.len_utf8(),
+            None => self.skip_line(),
+        }
+    }
+
+    fn skip_line(&mut self) {
+        self.line += 1;
+        self.column = 0;
+    }
+
+    fn advance(&mut self, len: usize) {
+        self.column += len;
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// The position of the cursor in the comment
+    fn position(&self) -> usize {
+        match self.lines.get(self.line) {
+            Some(line) => line.start + self.column,
+            None => self.end(),
+        }
+    }
+
+    /// The position of the end of the tag in the comment
+    fn end(&self) -> usize {
+        self.lines
+            .last()
+            .map_or(0, |line| line.start + line.text.len())
+    }
+
+    fn rest_of_line(&self) -> Line<'a> {
+        match self.lines.get(self.line) {
+            Some(line) => Line {
+                text: &line.text[self.column..],
+                start: line.start + self.column,
+            },
+            None => Line {
+                text: "",
+                start: self.end(),
+            },
+        }
+    }
+
+    /// The text from the cursor to the end of the tag, without the `-` before it and the
+    /// indentation of the lines
+    fn description(&self) -> String {
+        let mut lines = vec![];
+        let first = self.rest_of_line().trim_start();
+        lines.push(Line {
+            text: first.text.strip_prefix('-').unwrap_or(first.text),
+            start: first.start,
+        });
+        lines.extend(
+            self.lines
+                .iter()
+                .skip(self.line + 1)
+                .map(|line| line.trim_start()),
+        );
+        join_lines(&lines)
+    }
+}
+
+/// Parses the text of a type
+struct TypeParser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> TypeParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().is_empty()
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_union(&mut self) -> Option<JsDocType> {
+        let mut types = vec![self.parse_prefix()?];
+        while self.eat("|") {
+            types.push(self.parse_prefix()?);
+        }
+        if types.len() == 1 {
+            types.pop()
+        } else {
+            Some(JsDocType::Union(types))
+        }
+    }
+
+    fn parse_prefix(&mut self) -> Option<JsDocType> {
+        if self.eat("...") {
+            return Some(JsDocType::Rest(Box::new(self.parse_prefix()?)));
+        }
+        if self.eat("!") {
+            return Some(JsDocType::NonNullable(Box::new(self.parse_prefix()?)));
+        }
+        if self.eat("?") {
+            self.skip_whitespace();
+            // `?` alone is the unknown type
+            if self.rest().is_empty() || self.rest().starts_with(['|', ')', ',', '>', '=']) {
+                return Some(JsDocType::Unknown);
+            }
+            return Some(JsDocType::Nullable(Box::new(self.parse_prefix()?)));
+        }
+        self.parse_postfix()
+    }
+
+    fn parse_postfix(&mut self) -> Option<JsDocType> {
+        let mut ty = self.parse_primary()?;
+        loop {
+            if self.eat("[]") {
+                ty = JsDocType::Array(Box::new(ty));
+            } else if self.eat("=") {
+                ty = JsDocType::Optional(Box::new(ty));
+            } else {
+                return Some(ty);
+            }
+        }
+    }
+
+    fn parse_primary(&mut self) -> Option<JsDocType> {
+        if self.eat("*") {
+            return Some(JsDocType::Any);
+        }
+        if self.eat("(") {
+            let ty = self.parse_union()?;
+            return self.eat(")").then_some(ty);
+        }
+
+        let rest = self.rest();
+        let first = rest.chars().next()?;
+        if first == '"' || first == '\'' {
+            let len = rest[1..].find(first)? + 2;
+            self.position += len;
+            return Some(JsDocType::Literal(rest[..len].to_string()));
+        }
+        if first.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                .unwrap_or(rest.len());
+            self.position += len;
+            return Some(JsDocType::Literal(rest[..len].to_string()));
+        }
+
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '$' | '.')))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return None;
+        }
+        self.position += len;
+        let name = &rest[..len];
+        if self.eat("<") {
+            // `Array.<string>` is the legacy syntax of `Array<string>`
+            let name = name.strip_suffix('.').unwrap_or(name);
+            let mut arguments = vec![self.parse_union()?];
+            while self.eat(",") {
+                arguments.push(self.parse_union()?);
+            }
+            return self.eat(">").then(|| JsDocType::Generic {
+                name: name.to_string(),
+                arguments,
+            });
+        }
+        if name.ends_with('.') {
+            return None;
+        }
+        Some(JsDocType::Name(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsDoc, JsDocErrorKind, JsDocTagKind, JsDocType};
+    use biome_rowan::{TextRange, TextSize};
+
+    fn parse(comment: &str) -> JsDoc {
+        JsDoc::parse(comment, TextSize::from(0)).expect("not a JSDoc comment")
+    }
+
+    fn name(name: &str) -> JsDocType {
+        JsDocType::Name(name.to_string())
+    }
+
+    #[test]
+    fn not_jsdoc_comments() {
+        for comment in ["/* comment */", "/**/", "/*** separator ***/", "// comment"] {
+            assert_eq!(JsDoc::parse(comment, TextSize::from(0)), None, "{comment}");
+        }
+    }
+
+    #[test]
+    fn description_and_tags() {
+        let comment = "/**
+ * Adds two numbers.
+ *
+ * ```js
+ * @decorator
+ * ```
+ * @param {number} a - The first number
+ * @param {number} [b=0] The second
+ *   number
+ * @returns {number}
+ * @deprecated Use `sum` instead
+ */";
+        let doc = parse(comment);
+        assert_eq!(
+            doc.description,
+            "Adds two numbers.\n\n```js\n@decorator\n```"
+        );
+        assert!(doc.errors.is_empty(), "{:?}", doc.errors);
+
+        let params: Vec<_> = doc.params().collect();
+        assert_eq!(params.len(), 2);
+
+        let a = params[0].parameter.as_ref().unwrap();
+        assert_eq!(a.name, "a");
+        assert!(!a.optional);
+        assert_eq!(&comment[a.range], "a");
+        assert_eq!(params[0].description, "The first number");
+        let ty = params[0].type_expression.as_ref().unwrap();
+        assert_eq!(ty.ty, name("number"));
+        assert_eq!(&comment[ty.range], "{number}");
+
+        let b = params[1].parameter.as_ref().unwrap();
+        assert_eq!(b.name, "b");
+        assert!(b.optional);
+        assert_eq!(b.default.as_deref(), Some("0"));
+        assert_eq!(&comment[b.range], "b");
+        assert_eq!(params[1].description, "The second\nnumber");
+        assert_eq!(
+            &comment[params[1].range],
+            "@param {number} [b=0] The second\n *   number"
+        );
+
+        assert_eq!(doc.returns().unwrap().name, "returns");
+        let deprecated = doc.deprecated().unwrap();
+        assert_eq!(deprecated.kind, JsDocTagKind::Deprecated);
+        assert_eq!(deprecated.description, "Use `sum` instead");
+        assert_eq!(&comment[deprecated.name_range], "@deprecated");
+    }
+
+    #[test]
+    fn single_line() {
+        let doc = JsDoc::parse("/** @type {string} */", TextSize::from(10)).unwrap();
+        assert_eq!(doc.description, "");
+        assert_eq!(doc.range, TextRange::new(10.into(), 31.into()));
+        let tag = &doc.tags[0];
+        assert_eq!(tag.kind, JsDocTagKind::Type);
+        assert_eq!(tag.name_range, TextRange::new(14.into(), 19.into()));
+        assert_eq!(tag.type_expression.as_ref().unwrap().ty, name("string"));
+    }
+
+    #[test]
+    fn invalid_tags() {
+        let comment = "/**
+ * @
+ * @param {string
+ * @param {}
+ * @param {string}
+ * @param [name
+ */";
+        let doc = parse(comment);
+        let errors: Vec<_> = doc
+            .errors
+            .iter()
+            .map(|error| (error.kind, &comment[error.range]))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (JsDocErrorKind::MissingTagName, "@"),
+                (JsDocErrorKind::UnclosedType, "{string"),
+                (JsDocErrorKind::EmptyType, "{}"),
+                (JsDocErrorKind::MissingParameterName, "@param"),
+                (JsDocErrorKind::MissingParameterName, "@param"),
+                (JsDocErrorKind::UnclosedParameterName, "[name"),
+            ]
+        );
+    }
+
+    #[test]
+    fn type_expressions() {
+        assert_eq!(JsDocType::parse("*"), JsDocType::Any);
+        assert_eq!(JsDocType::parse("?"), JsDocType::Unknown);
+        assert_eq!(
+            JsDocType::parse("string | Foo.Bar"),
+            JsDocType::Union(vec![name("string"), name("Foo.Bar")])
+        );
+        assert_eq!(
+            JsDocType::parse("?number[]"),
+            JsDocType::Nullable(Box::new(JsDocType::Array(Box::new(name("number")))))
+        );
+        assert_eq!(
+            JsDocType::parse("...!string="),
+            JsDocType::Rest(Box::new(JsDocType::NonNullable(Box::new(
+                JsDocType::Optional(Box::new(name("string")))
+            ))))
+        );
+        assert_eq!(
+            JsDocType::parse("Array.<string>"),
+            JsDocType::Generic {
+                name: "Array".to_string(),
+                arguments: vec![name("string")]
+            }
+        );
+        assert_eq!(
+            JsDocType::parse("Map<string, (number | 'a')>"),
+            JsDocType::Generic {
+                name: "Map".to_string(),
+                arguments: vec![
+                    name("string"),
+                    JsDocType::Union(vec![name("number"), JsDocType::Literal("'a'".to_string())])
+                ]
+            }
+        );
+        assert_eq!(
+            JsDocType::parse("(a: string) => void"),
+            JsDocType::Other("(a: string) => void".to_string())
+        );
+    }
+}
@@ -6,6 +6,7 @@
 mod generated;
 pub mod assign_ext;
 pub mod binding_ext;
+pub mod classification;
 pub mod declaration_ext;
 pub mod directive_ext;
 pub mod export_ext;
@@ -14,13 +15,13 @@ pub mod file_source;
 pub mod function_ext;
 pub mod identifier_ext;
 pub mod import_ext;
+pub mod jsdoc;
 pub mod jsx_ext;
 pub mod modifier_ext;
 pub mod numbers;
 pub mod parameter_ext;
 pub mod static_value;
 pub mod stmt_ext;
-pub mod suppression;
 mod syntax_node;
 pub mod type_ext;
 mod union_ext;
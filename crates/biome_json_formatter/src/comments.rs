@@ -7,7 +7,7 @@ use biome_formatter::formatter::Formatter;
 use biome_formatter::{write, FormatResult, FormatRule};
 use biome_json_syntax::{JsonLanguage, TextLen};
 use biome_rowan::SyntaxTriviaPieceComments;
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 pub type JsonComments = Comments<JsonLanguage>;
 
@@ -67,8 +67,9 @@ impl CommentStyle for JsonCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.scope == SuppressionScope::Next)
             .flat_map(|suppression| suppression.categories)
-            .any(|(key, _)| key == category!("format"))
+            .any(|suppression| suppression.category == category!("format"))
     }
 
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
@@ -1,13 +1,22 @@
 use crate::converters::{negotiated_encoding, PositionEncoding, WideEncoding};
+use crate::handlers::analysis::FIX_ALL_IN_WORKSPACE_COMMAND;
+use serde_json::json;
 use tower_lsp::lsp_types::{
-    ClientCapabilities, CodeActionProviderCapability, PositionEncodingKind, ServerCapabilities,
-    TextDocumentSyncCapability, TextDocumentSyncKind,
+    ClientCapabilities, CodeActionProviderCapability, DiagnosticOptions,
+    DiagnosticServerCapabilities, ExecuteCommandOptions, OneOf, PositionEncodingKind,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
 };
 
 /// The capabilities to send from server as part of [`InitializeResult`]
 ///
+/// The server confirms that it runs in untrusted mode when the client doesn't trust the
+/// workspace, see [crate::InitializationOptions].
+///
 /// [`InitializeResult`]: lspower::lsp::InitializeResult
-pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCapabilities {
+pub(crate) fn server_capabilities(
+    capabilities: &ClientCapabilities,
+    workspace_trusted: bool,
+) -> ServerCapabilities {
     ServerCapabilities {
         position_encoding: Some(match negotiated_encoding(capabilities) {
             PositionEncoding::Utf8 => PositionEncodingKind::UTF8,
@@ -20,7 +29,28 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
             TextDocumentSyncKind::INCREMENTAL,
         )),
         code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![FIX_ALL_IN_WORKSPACE_COMMAND.to_string()],
+            work_done_progress_options: Default::default(),
+        }),
         rename_provider: None,
+        document_symbol_provider: Some(OneOf::Left(true)),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
+        // The diagnostics are pushed to the clients that can't pull them
+        diagnostic_provider: capabilities
+            .text_document
+            .as_ref()
+            .and_then(|text_document| text_document.diagnostic.as_ref())
+            .map(|_| {
+                DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                    identifier: Some(String::from("biome")),
+                    // The project rules, e.g. the import cycles, depend on the other files
+                    inter_file_dependencies: true,
+                    workspace_diagnostics: false,
+                    work_done_progress_options: Default::default(),
+                })
+            }),
+        experimental: (!workspace_trusted).then(|| json!({ "untrustedWorkspace": true })),
         ..Default::default()
     }
 }
@@ -1,5 +1,4 @@
-use crate::converters::line_index::LineIndex;
-use crate::converters::{LineCol, PositionEncoding, WideLineCol};
+use crate::converters::{LineCol, LineIndex, PositionEncoding, WideLineCol};
 use anyhow::{Context, Result};
 use biome_rowan::{TextRange, TextSize};
 use tower_lsp::lsp_types;
@@ -1,8 +1,7 @@
-use biome_rowan::TextSize;
+pub(crate) use biome_rowan::{LineCol, LineIndex, WideEncoding, WideLineCol};
 use tower_lsp::lsp_types::{ClientCapabilities, PositionEncodingKind};
 
 pub(crate) mod from_proto;
-pub(crate) mod line_index;
 pub(crate) mod to_proto;
 
 pub(crate) fn negotiated_encoding(capabilities: &ClientCapabilities) -> PositionEncoding {
@@ -29,66 +28,12 @@ pub enum PositionEncoding {
     Wide(WideEncoding),
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub enum WideEncoding {
-    Utf16,
-    Utf32,
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub(crate) struct LineCol {
-    /// Zero-based
-    pub(crate) line: u32,
-    /// Zero-based utf8 offset
-    pub(crate) col: u32,
-}
-
-/// Deliberately not a generic type and different from `LineCol`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct WideLineCol {
-    /// Zero-based
-    pub line: u32,
-    /// Zero-based
-    pub col: u32,
-}
-
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub(crate) struct WideChar {
-    /// Start offset of a character inside a line, zero-based
-    pub(crate) start: TextSize,
-    /// End offset of a character inside a line, zero-based
-    pub(crate) end: TextSize,
-}
-
-impl WideChar {
-    /// Returns the length in 8-bit UTF-8 code units.
-    fn len(&self) -> TextSize {
-        self.end - self.start
-    }
-
-    /// Returns the length in UTF-16 or UTF-32 code units.
-    fn wide_len(&self, enc: WideEncoding) -> usize {
-        match enc {
-            WideEncoding::Utf16 => {
-                if self.len() == TextSize::from(4) {
-                    2
-                } else {
-                    1
-                }
-            }
-
-            WideEncoding::Utf32 => 1,
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::converters::from_proto::offset;
-    use crate::converters::line_index::LineIndex;
     use crate::converters::to_proto::position;
     use crate::converters::WideEncoding::{Utf16, Utf32};
-    use crate::converters::{LineCol, PositionEncoding, WideEncoding};
+    use crate::converters::{LineCol, LineIndex, PositionEncoding, WideEncoding};
     use biome_rowan::TextSize;
     use tower_lsp::lsp_types::Position;
 
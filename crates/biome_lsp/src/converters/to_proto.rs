@@ -1,7 +1,7 @@
-use crate::converters::line_index::LineIndex;
-use crate::converters::PositionEncoding;
+use crate::converters::{LineIndex, PositionEncoding};
 use anyhow::{Context, Result};
 use biome_rowan::{TextRange, TextSize};
+use biome_service::workspace::SymbolKind;
 use tower_lsp::lsp_types;
 
 /// The function is used to convert TextSize to a LSP position.
@@ -37,3 +37,22 @@ pub(crate) fn range(
     let end = position(line_index, range.end(), position_encoding)?;
     Ok(lsp_types::Range::new(start, end))
 }
+
+/// The function is used to convert the kind of a symbol to a LSP symbol kind.
+pub(crate) fn symbol_kind(kind: SymbolKind) -> lsp_types::SymbolKind {
+    match kind {
+        SymbolKind::Class => lsp_types::SymbolKind::CLASS,
+        SymbolKind::Constant => lsp_types::SymbolKind::CONSTANT,
+        SymbolKind::Constructor => lsp_types::SymbolKind::CONSTRUCTOR,
+        SymbolKind::Enum => lsp_types::SymbolKind::ENUM,
+        SymbolKind::EnumMember => lsp_types::SymbolKind::ENUM_MEMBER,
+        SymbolKind::Function => lsp_types::SymbolKind::FUNCTION,
+        SymbolKind::Interface => lsp_types::SymbolKind::INTERFACE,
+        SymbolKind::Method => lsp_types::SymbolKind::METHOD,
+        SymbolKind::Namespace => lsp_types::SymbolKind::NAMESPACE,
+        SymbolKind::Property => lsp_types::SymbolKind::PROPERTY,
+        // LSP has no kind for the type aliases
+        SymbolKind::TypeAlias => lsp_types::SymbolKind::TYPE_PARAMETER,
+        SymbolKind::Variable => lsp_types::SymbolKind::VARIABLE,
+    }
+}
@@ -46,6 +46,7 @@ pub(crate) async fn handle_lsp_error<T>(
         LspError::WorkspaceError(err) => match err {
             // diagnostics that shouldn't raise an hard error, but send a message to the user
             WorkspaceError::FormatWithErrorsDisabled(_)
+            | WorkspaceError::JsxTextChanged(_)
             | WorkspaceError::FileIgnored(_)
             | WorkspaceError::FileTooLarge(_) => {
                 let message = format!("{}", err);
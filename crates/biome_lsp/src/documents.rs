@@ -1,4 +1,5 @@
-use crate::converters::line_index::LineIndex;
+use crate::converters::LineIndex;
+use biome_service::workspace::CancellationToken;
 
 /// Represents an open [`textDocument`]. Can be cheaply cloned.
 ///
@@ -7,6 +8,9 @@ use crate::converters::line_index::LineIndex;
 pub(crate) struct Document {
     pub(crate) version: i32,
     pub(crate) line_index: LineIndex,
+    /// Cancelled when a newer version of the document replaces this one, or when the
+    /// document is closed, so the analysis of this version stops early
+    pub(crate) cancellation: CancellationToken,
 }
 
 impl Document {
@@ -14,6 +18,7 @@ impl Document {
         Self {
             version,
             line_index: LineIndex::new(text),
+            cancellation: CancellationToken::new(),
         }
     }
 }
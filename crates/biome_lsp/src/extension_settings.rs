@@ -15,10 +15,29 @@ pub struct WorkspaceSettings {
     /// Enable rename capability
     pub rename: Option<bool>,
 
+    /// Show the names of the parameters before the literal arguments of the calls
+    pub inlay_hints: Option<bool>,
+
     /// Only run Biome if a `biome.json` configuration file exists.
     pub require_configuration: Option<bool>,
 }
 
+/// The options sent by the client in the "initialize" request
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializationOptions {
+    /// Whether the user trusts the workspace, `true` by default. In an untrusted workspace,
+    /// the server doesn't resolve the `extends` of the configuration and doesn't scan the
+    /// files of the workspace: it only reads the configuration file and the open documents.
+    pub workspace_trusted: Option<bool>,
+}
+
+impl InitializationOptions {
+    pub(crate) fn is_workspace_trusted(&self) -> bool {
+        self.workspace_trusted.unwrap_or(true)
+    }
+}
+
 /// The `biome.*` extension settings
 #[derive(Debug)]
 pub(crate) struct ExtensionSettings {
@@ -45,4 +64,8 @@ impl ExtensionSettings {
     pub(crate) fn requires_configuration(&self) -> bool {
         self.settings.require_configuration.unwrap_or_default()
     }
+
+    pub(crate) fn inlay_hints(&self) -> bool {
+        self.settings.inlay_hints.unwrap_or_default()
+    }
 }
@@ -1,25 +1,36 @@
-use crate::converters::from_proto;
-use crate::converters::line_index::LineIndex;
+use crate::converters::LineIndex;
+use crate::converters::{from_proto, to_proto};
 use crate::session::Session;
 use crate::utils;
 use anyhow::{Context, Result};
 use biome_analyze::{ActionCategory, SourceActionKind};
 use biome_diagnostics::Applicability;
-use biome_fs::RomePath;
+use biome_fs::{FileSystemExt, RomePath};
 use biome_service::workspace::{
-    FeatureName, FeaturesBuilder, FixFileMode, FixFileParams, PullActionsParams,
+    CancellationToken, CloseFilesParams, CodeAction, FeatureName, FeaturesBuilder, FileEdits,
+    FixFileMode, FixFileParams, FixFilesParams, Language, OpenFileParams, OpenFilesParams,
+    ProjectAction, PullActionsParams, PullProjectActionsParams, RuleSelector,
     SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
+use serde_json::Value;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use tower_lsp::lsp_types::{
     self as lsp, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
 };
-use tracing::debug;
+use tracing::{debug, warn};
 
 const FIX_ALL_CATEGORY: ActionCategory = ActionCategory::Source(SourceActionKind::FixAll);
 
+/// The number of files opened and fixed together by [fix_all_in_workspace], so the contents of
+/// the whole workspace aren't held in memory at once
+const FIX_ALL_IN_WORKSPACE_BATCH_SIZE: usize = 100;
+
+/// The command that fixes the issues of a rule in all the files of the workspace, see
+/// [fix_all_in_workspace]
+pub(crate) const FIX_ALL_IN_WORKSPACE_COMMAND: &str = "biome.fixAllInWorkspace";
+
 fn fix_all_kind() -> CodeActionKind {
     match FIX_ALL_CATEGORY.to_str() {
         Cow::Borrowed(kind) => CodeActionKind::from(kind),
@@ -61,8 +72,9 @@ pub(crate) fn code_actions(
             let kind = kind.as_str();
             if FIX_ALL_CATEGORY.matches(kind) {
                 has_fix_all = true;
-            } else if ActionCategory::QuickFix.to_str() == kind {
-                // The action is a on-save quick-fixes
+            } else if utils::matches_code_action_kind(kind, &ActionCategory::QuickFix.to_str()) {
+                // The editors request the quick fixes of Biome, or of one of its rules, to
+                // apply them on save
                 has_quick_fix = true;
             }
             filters.push(kind);
@@ -99,6 +111,20 @@ pub(crate) fn code_actions(
 
     debug!("Pull actions result: {:?}", result);
 
+    // The actions of the project analysis also edit the other files of the project, e.g. the
+    // modules that import the file
+    let project_actions = if file_features.supports_for(&FeatureName::Lint) {
+        session
+            .workspace
+            .pull_project_actions(PullProjectActionsParams {
+                path: rome_path.clone(),
+                range: cursor_range,
+            })?
+            .actions
+    } else {
+        vec![]
+    };
+
     // Generate an additional code action to apply all safe fixes on the
     // document if the action category "source.fixAll" was explicitly requested
     // by the language client
@@ -108,6 +134,14 @@ pub(crate) fn code_actions(
         None
     };
 
+    // The actions that fix a rule in the whole workspace are only listed when the user asks
+    // for the actions, not when the editor applies the fixes on save
+    let workspace_fixes = if filters.is_empty() {
+        fix_all_in_workspace_actions(&result.actions, &diagnostics)
+    } else {
+        vec![]
+    };
+
     let mut has_fixes = false;
     let mut actions: Vec<_> = result
         .actions
@@ -122,9 +156,12 @@ pub(crate) fn code_actions(
             {
                 return None;
             }
-            // Remove actions that do not match the categories requested by the
+            // Remove actions that do not match the kinds requested by the
             // language client
-            let matches_filters = filters.iter().any(|filter| action.category.matches(filter));
+            let kind = utils::code_action_kind(&action);
+            let matches_filters = filters
+                .iter()
+                .any(|filter| utils::matches_code_action_kind(&kind, filter));
             if !filters.is_empty() && !matches_filters {
                 return None;
             }
@@ -141,7 +178,20 @@ pub(crate) fn code_actions(
             has_fixes |= action.diagnostics.is_some();
             Some(CodeActionOrCommand::CodeAction(action))
         })
+        .chain(project_actions.into_iter().filter_map(|action| {
+            let kind = utils::project_action_kind(&action);
+            let matches_filters = filters
+                .iter()
+                .any(|filter| utils::matches_code_action_kind(&kind, filter));
+            if !filters.is_empty() && !matches_filters {
+                return None;
+            }
+
+            let action = project_action_to_lsp(session, action).ok()?;
+            Some(CodeActionOrCommand::CodeAction(action))
+        }))
         .chain(fix_all)
+        .chain(workspace_fixes)
         .collect();
 
     // If any actions is marked as fixing a diagnostic, hide other actions
@@ -161,6 +211,204 @@ pub(crate) fn code_actions(
     Ok(Some(actions))
 }
 
+/// Converts a [ProjectAction] to a code action that edits all the files of the action
+fn project_action_to_lsp(session: &Session, action: ProjectAction) -> Result<lsp::CodeAction> {
+    let kind = utils::project_action_kind(&action);
+
+    Ok(lsp::CodeAction {
+        title: utils::print_markup(&action.message),
+        kind: Some(CodeActionKind::from(kind)),
+        diagnostics: None,
+        edit: Some(workspace_edit(session, action.edits)?),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    })
+}
+
+/// Converts the edits of several files of the workspace to a [lsp::WorkspaceEdit]
+fn workspace_edit(session: &Session, edits: Vec<FileEdits>) -> Result<lsp::WorkspaceEdit> {
+    let position_encoding = session.position_encoding();
+
+    let mut changes = HashMap::new();
+    for file_edits in edits {
+        let url = session
+            .file_url(file_edits.path.as_path())
+            .with_context(|| format!("invalid path {}", file_edits.path.display()))?;
+        let line_index = session
+            .read_line_index(&url)
+            .with_context(|| format!("failed to read {url}"))?;
+        let edits = file_edits
+            .replacements
+            .into_iter()
+            .map(|replacement| {
+                Ok(lsp::TextEdit {
+                    range: to_proto::range(&line_index, replacement.range, position_encoding)?,
+                    new_text: replacement.text,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        changes.insert(url, edits);
+    }
+
+    Ok(lsp::WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    })
+}
+
+/// Generates an action for each rule that has a fix at the cursor, which fixes the issues of
+/// the rule in all the files of the workspace. The fixes are computed when the action is
+/// executed, see [fix_all_in_workspace]
+fn fix_all_in_workspace_actions(
+    actions: &[CodeAction],
+    diagnostics: &[lsp::Diagnostic],
+) -> Vec<CodeActionOrCommand> {
+    let mut rules: Vec<(String, FixFileMode)> = Vec::new();
+    for action in actions {
+        let Some((group_name, rule_name)) = &action.rule_name else {
+            continue;
+        };
+        // The suppression comments aren't fixes
+        if !matches!(action.category, ActionCategory::QuickFix) {
+            continue;
+        }
+        let rule = format!("{group_name}/{rule_name}");
+        if rules.iter().any(|(name, _)| *name == rule) {
+            continue;
+        }
+        let fix_file_mode = if action.suggestion.applicability == Applicability::Always {
+            FixFileMode::SafeFixes
+        } else {
+            FixFileMode::SafeAndUnsafeFixes
+        };
+        rules.push((rule, fix_file_mode));
+    }
+
+    rules
+        .into_iter()
+        .filter_map(|(rule, fix_file_mode)| {
+            let code = format!("lint/{rule}");
+            let diagnostics: Vec<_> = diagnostics
+                .iter()
+                .filter(|d| {
+                    matches!(&d.code, Some(lsp::NumberOrString::String(d_code)) if *d_code == code)
+                })
+                .cloned()
+                .collect();
+            if diagnostics.is_empty() {
+                return None;
+            }
+
+            let title = format!("Fix all the issues of {rule} in the workspace");
+            let arguments = vec![
+                Value::String(rule),
+                serde_json::to_value(fix_file_mode).ok()?,
+            ];
+            Some(CodeActionOrCommand::CodeAction(lsp::CodeAction {
+                title: title.clone(),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(diagnostics),
+                edit: None,
+                command: Some(lsp::Command {
+                    title,
+                    command: FIX_ALL_IN_WORKSPACE_COMMAND.to_string(),
+                    arguments: Some(arguments),
+                }),
+                is_preferred: None,
+                disabled: None,
+                data: None,
+            }))
+        })
+        .collect()
+}
+
+/// Fixes the issues of a rule in all the files of the workspace. The `arguments` of the
+/// command are the rule, e.g. `style/noVar`, and the [FixFileMode] of its fixes.
+///
+/// The files are fixed in batches: the files of a batch that aren't open are opened in the
+/// workspace while they're fixed, then closed. The fixes of all the files are returned in a
+/// single edit. It reads the whole workspace, so it must
+/// run outside of the async runtime. Returns `None` when there's nothing to fix.
+pub(crate) fn fix_all_in_workspace(
+    session: &Session,
+    arguments: Vec<Value>,
+) -> Result<Option<lsp::WorkspaceEdit>> {
+    let mut arguments = arguments.into_iter();
+    let rule = arguments
+        .next()
+        .and_then(|rule| rule.as_str().map(str::to_string))
+        .context("the command requires the name of the rule")?;
+    let rule = rule.parse::<RuleSelector>().map_err(anyhow::Error::msg)?;
+    let fix_file_mode: FixFileMode = serde_json::from_value(
+        arguments
+            .next()
+            .context("the command requires the mode of the fixes")?,
+    )?;
+
+    let project_files = session.project_files();
+    let mut edits = Vec::new();
+    for batch in project_files.chunks(FIX_ALL_IN_WORKSPACE_BATCH_SIZE) {
+        let mut paths = Vec::new();
+        let mut closed_files = Vec::new();
+        for path in batch {
+            let Some(url) = session.file_url(path) else {
+                continue;
+            };
+            let rome_path = session.file_path(&url)?;
+            if session.document(&url).is_err() {
+                let content = match session.fs.read_to_string(path) {
+                    Ok(content) => content,
+                    Err(err) => {
+                        warn!("Couldn't read {}: {err}", path.display());
+                        continue;
+                    }
+                };
+                closed_files.push(OpenFileParams {
+                    path: rome_path.clone(),
+                    content,
+                    version: 0,
+                    language_hint: Language::default(),
+                });
+            }
+            paths.push(rome_path);
+        }
+
+        let closed_paths: Vec<_> = closed_files.iter().map(|file| file.path.clone()).collect();
+        session.workspace.open_files(OpenFilesParams {
+            files: closed_files,
+        })?;
+        let fixed = session.workspace.fix_files(FixFilesParams {
+            paths,
+            fix_file_mode,
+            should_format: false,
+            only: vec![rule.clone()],
+            skip: vec![],
+            unsafe_rules: vec![],
+            cancellation: CancellationToken::default(),
+        });
+        session.workspace.close_files(CloseFilesParams {
+            paths: closed_paths,
+        })?;
+
+        let fixed = fixed?;
+        for error in &fixed.errors {
+            debug!("Couldn't fix {}: {:?}", error.path.display(), error.error);
+        }
+        edits.extend(fixed.files.into_iter().map(|file| FileEdits {
+            path: file.path,
+            replacements: vec![file.replacement],
+        }));
+    }
+    if edits.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(workspace_edit(session, edits)?))
+}
+
 /// Generate a "fix all" code action for the given document
 #[tracing::instrument(level = "debug", skip(session), err)]
 fn fix_all(
@@ -181,6 +429,13 @@ fn fix_all(
         path: rome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
+        only: vec![],
+        skip: vec![],
+        unsafe_rules: vec![],
+        cancellation: session
+            .document(url)
+            .map(|document| document.cancellation)
+            .unwrap_or_default(),
     })?;
 
     if fixed.actions.is_empty() {
@@ -0,0 +1,47 @@
+use crate::session::Session;
+use anyhow::Result;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use tower_lsp::lsp_types::{
+    Diagnostic, DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportResult,
+    FullDocumentDiagnosticReport, RelatedFullDocumentDiagnosticReport,
+    RelatedUnchangedDocumentDiagnosticReport, UnchangedDocumentDiagnosticReport,
+};
+
+/// Computes the diagnostics of a document pulled by the client. The report is identified
+/// by the hash of the diagnostics, so the client is told when the diagnostics didn't change
+/// since its previous request instead of receiving them again.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_diagnostics(
+    session: &Session,
+    params: DocumentDiagnosticParams,
+) -> Result<DocumentDiagnosticReportResult> {
+    let url = params.text_document.uri;
+    let doc = session.document(&url)?;
+    let diagnostics = session.compute_diagnostics(&url, &doc)?;
+    let result_id = result_id(&diagnostics)?;
+
+    let report = if params.previous_result_id.as_ref() == Some(&result_id) {
+        DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+            related_documents: None,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        })
+    } else {
+        DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items: diagnostics,
+            },
+        })
+    };
+
+    Ok(DocumentDiagnosticReportResult::Report(report))
+}
+
+/// The identifier of a report: the hash of its diagnostics
+fn result_id(diagnostics: &[Diagnostic]) -> Result<String> {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(diagnostics)?.hash(&mut hasher);
+    Ok(format!("{:x}", hasher.finish()))
+}
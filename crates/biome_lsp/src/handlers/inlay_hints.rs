@@ -0,0 +1,63 @@
+use crate::converters::{from_proto, to_proto};
+use crate::session::Session;
+use anyhow::{Context, Result};
+use biome_service::workspace::InlayHintsParams;
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, InlayHintParams};
+
+/// Returns the names of the parameters before the literal arguments of the calls in the
+/// requested range
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn inlay_hints(
+    session: &Session,
+    params: InlayHintParams,
+) -> Result<Option<Vec<InlayHint>>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let range = from_proto::text_range(&doc.line_index, params.range, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access range {:?} in document {url}",
+                params.range
+            )
+        })?;
+
+    let result = match session.workspace.inlay_hints(InlayHintsParams {
+        path: rome_path,
+        range,
+    }) {
+        Ok(result) => result,
+        Err(err) => {
+            return if matches!(
+                err,
+                WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)
+            ) {
+                Ok(None)
+            } else {
+                Err(err.into())
+            }
+        }
+    };
+
+    let hints = result
+        .hints
+        .into_iter()
+        .map(|hint| {
+            Ok(InlayHint {
+                position: to_proto::position(&doc.line_index, hint.offset, position_encoding)?,
+                label: InlayHintLabel::String(format!("{}:", hint.parameter_name)),
+                kind: Some(InlayHintKind::PARAMETER),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: Some(true),
+                data: None,
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Some(hints))
+}
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
-use crate::converters::from_proto;
+use crate::converters::{from_proto, to_proto};
 use crate::{session::Session, utils};
 use anyhow::{Context, Result};
-use tower_lsp::lsp_types::{RenameParams, WorkspaceEdit};
+use tower_lsp::lsp_types::{
+    PrepareRenameResponse, RenameParams, TextDocumentPositionParams, WorkspaceEdit,
+};
 use tracing::trace;
 
 #[tracing::instrument(level = "debug", skip(session), err)]
@@ -49,3 +51,37 @@ pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<W
 
     Ok(Some(workspace_edit))
 }
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let cursor_range = from_proto::offset(&doc.line_index, params.position, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access position {:?} in document {url}",
+                params.position
+            )
+        })?;
+
+    let result =
+        session
+            .workspace
+            .prepare_rename(biome_service::workspace::PrepareRenameParams {
+                path: rome_path,
+                symbol_at: cursor_range,
+            })?;
+
+    let Some(range) = result.range else {
+        return Ok(None);
+    };
+
+    let range = to_proto::range(&doc.line_index, range, position_encoding)?;
+    Ok(Some(PrepareRenameResponse::Range(range)))
+}
@@ -0,0 +1,118 @@
+use crate::converters::LineIndex;
+use crate::converters::{to_proto, PositionEncoding};
+use crate::session::Session;
+use anyhow::Result;
+use biome_service::workspace::{self, DocumentSymbolsParams, WorkspaceSymbolsParams};
+use biome_service::WorkspaceError;
+use rustc_hash::FxHashMap;
+use tower_lsp::lsp_types::{
+    DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, Location, SymbolInformation, Url,
+    WorkspaceSymbolParams,
+};
+
+/// The maximum number of symbols returned by `workspace/symbol`, the best matches first
+const MAX_WORKSPACE_SYMBOLS: u32 = 256;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_symbols(
+    session: &Session,
+    params: DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+
+    let result = match session
+        .workspace
+        .document_symbols(DocumentSymbolsParams { path: rome_path })
+    {
+        Ok(result) => result,
+        Err(err) => {
+            return if matches!(
+                err,
+                WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)
+            ) {
+                Ok(None)
+            } else {
+                Err(err.into())
+            }
+        }
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbols = result
+        .symbols
+        .into_iter()
+        .map(|symbol| document_symbol(&doc.line_index, symbol, position_encoding))
+        .collect::<Result<_>>()?;
+
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+#[allow(deprecated)]
+fn document_symbol(
+    line_index: &LineIndex,
+    symbol: workspace::DocumentSymbol,
+    position_encoding: PositionEncoding,
+) -> Result<DocumentSymbol> {
+    let children = symbol
+        .children
+        .into_iter()
+        .map(|child| document_symbol(line_index, child, position_encoding))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(DocumentSymbol {
+        name: symbol.name,
+        detail: None,
+        kind: to_proto::symbol_kind(symbol.kind),
+        tags: None,
+        deprecated: None,
+        range: to_proto::range(line_index, symbol.range, position_encoding)?,
+        selection_range: to_proto::range(line_index, symbol.selection_range, position_encoding)?,
+        children: (!children.is_empty()).then_some(children),
+    })
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn workspace_symbols(
+    session: &Session,
+    params: WorkspaceSymbolParams,
+) -> Result<Option<Vec<SymbolInformation>>> {
+    let result = session
+        .workspace
+        .workspace_symbols(WorkspaceSymbolsParams {
+            query: params.query,
+            max_results: Some(MAX_WORKSPACE_SYMBOLS),
+        })?;
+
+    let position_encoding = session.position_encoding();
+    // The files that aren't open are read once, for all their symbols
+    let mut line_indexes: FxHashMap<Url, Option<LineIndex>> = FxHashMap::default();
+    let mut symbols = Vec::with_capacity(result.symbols.len());
+    for symbol in result.symbols {
+        let Some(url) = session.file_url(&symbol.path) else {
+            continue;
+        };
+        let line_index = line_indexes
+            .entry(url.clone())
+            .or_insert_with(|| session.read_line_index(&url));
+        let Some(line_index) = line_index else {
+            continue;
+        };
+
+        #[allow(deprecated)]
+        symbols.push(SymbolInformation {
+            name: symbol.name,
+            kind: to_proto::symbol_kind(symbol.kind),
+            tags: None,
+            deprecated: None,
+            location: Location::new(
+                url,
+                to_proto::range(line_index, symbol.range, position_encoding)?,
+            ),
+            container_name: symbol.container_name,
+        });
+    }
+
+    Ok(Some(symbols))
+}
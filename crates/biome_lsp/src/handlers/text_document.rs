@@ -2,6 +2,7 @@ use anyhow::Result;
 use biome_service::workspace::{
     ChangeFileParams, CloseFileParams, GetFileContentParams, Language, OpenFileParams,
 };
+use std::path::Path;
 use tower_lsp::lsp_types;
 use tracing::{error, field};
 
@@ -22,14 +23,15 @@ pub(crate) async fn did_open(
     let rome_path = session.file_path(&url)?;
     let doc = Document::new(version, &content);
 
+    session.discover_nested_configurations(rome_path.parent().unwrap_or(Path::new("")));
     session.workspace.open_file(OpenFileParams {
         path: rome_path,
         version,
-        content,
+        content: content.clone(),
         language_hint,
     })?;
 
-    session.insert_document(url.clone(), doc);
+    session.insert_document(url.clone(), doc, &content);
 
     if let Err(err) = session.update_diagnostics(url).await {
         error!("Failed to update diagnostics: {}", err);
@@ -63,7 +65,7 @@ pub(crate) async fn did_change(
 
     tracing::trace!("new document: {:?}", text);
 
-    session.insert_document(url.clone(), Document::new(version, &text));
+    session.insert_document(url.clone(), Document::new(version, &text), &text);
 
     session.workspace.change_file(ChangeFileParams {
         path: rome_path,
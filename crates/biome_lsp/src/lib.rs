@@ -9,5 +9,5 @@ mod server;
 mod session;
 mod utils;
 
-pub use crate::extension_settings::WorkspaceSettings;
+pub use crate::extension_settings::{InitializationOptions, WorkspaceSettings};
 pub use crate::server::{LSPServer, ServerConnection, ServerFactory};
@@ -0,0 +1,96 @@
+use crate::session::Session;
+use anyhow::Result;
+use biome_service::workspace::{
+    EnabledRule, EnabledRulesParams, ExplainIgnoredPathParams, FeatureName, FeaturesBuilder,
+    SupportKind, SupportsFeatureParams,
+};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tower_lsp::lsp_types::{TextDocumentIdentifier, Url};
+use tracing::info;
+
+pub const EXPLAIN_FILE_REQUEST: &str = "biome/explainFile";
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainFilePayload {
+    pub text_document: TextDocumentIdentifier,
+}
+
+/// How Biome handles a file, for the "Biome: explain this file" command of the editors
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainFileResult {
+    /// The configuration file of the workspace, followed by the files it extends. Empty
+    /// when the workspace doesn't have a configuration file.
+    pub configuration_files: Vec<PathBuf>,
+    pub features: Vec<FeatureExplanation>,
+    /// The lint rules enabled for the file, empty when the file isn't linted
+    pub rules: Vec<EnabledRule>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureExplanation {
+    pub feature: FeatureName,
+    pub support: SupportKind,
+    /// Why the file is ignored by the feature, when it is
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_reason: Option<String>,
+}
+
+pub(crate) fn explain_file(session: &Session, url: &Url) -> Result<ExplainFileResult> {
+    info!("Explaining file");
+    let rome_path = session.file_path(url)?;
+    let file_features = session.workspace.file_features(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeaturesBuilder::new()
+            .with_formatter()
+            .with_linter()
+            .with_organize_imports()
+            .build(),
+    })?;
+
+    let mut features = vec![];
+    for feature in [
+        FeatureName::Format,
+        FeatureName::Lint,
+        FeatureName::OrganizeImports,
+    ] {
+        let Some(support) = file_features.features_supported.get(&feature).cloned() else {
+            continue;
+        };
+        let ignore_reason = if support == SupportKind::Ignored {
+            session
+                .workspace
+                .explain_ignored_path(ExplainIgnoredPathParams {
+                    path: rome_path.clone(),
+                    feature: feature.clone(),
+                })?
+                .reason
+                .map(|reason| reason.to_string())
+        } else {
+            None
+        };
+        features.push(FeatureExplanation {
+            feature,
+            support,
+            ignore_reason,
+        });
+    }
+
+    let rules = if file_features.supports_for(&FeatureName::Lint) {
+        session
+            .workspace
+            .enabled_rules(EnabledRulesParams { path: rome_path })?
+            .rules
+    } else {
+        vec![]
+    };
+
+    Ok(ExplainFileResult {
+        configuration_files: session.configuration_files(),
+        features,
+        rules,
+    })
+}
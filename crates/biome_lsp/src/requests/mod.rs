@@ -1 +1,2 @@
+pub(crate) mod explain_file;
 pub(crate) mod syntax_tree;
@@ -1,5 +1,7 @@
 use crate::capabilities::server_capabilities;
 use crate::diagnostics::{handle_lsp_error, LspError};
+use crate::extension_settings::InitializationOptions;
+use crate::requests::explain_file::{ExplainFilePayload, ExplainFileResult, EXPLAIN_FILE_REQUEST};
 use crate::requests::syntax_tree::{SyntaxTreePayload, SYNTAX_TREE_REQUEST};
 use crate::session::{
     CapabilitySet, CapabilityStatus, ClientInformation, Session, SessionHandle, SessionKey,
@@ -8,15 +10,19 @@ use crate::utils::{into_lsp_error, panic_to_lsp_error};
 use crate::{handlers, requests};
 use biome_console::markup;
 use biome_diagnostics::panic::PanicError;
-use biome_fs::{BIOME_JSON, ROME_JSON};
-use biome_service::workspace::{RageEntry, RageParams, RageResult};
+use biome_fs::{BIOME_JSON, BIOME_JSONC, ROME_JSON};
+use biome_service::workspace::{
+    ProjectSessions, ProjectsParams, ProjectsResult, RageEntry, RageParams, RageResult,
+    SessionInfo, TSCONFIG_FILE_NAMES,
+};
 use biome_service::{workspace, Workspace};
 use futures::future::ready;
 use futures::FutureExt;
 use rustc_hash::FxHashMap;
 use serde_json::json;
+use std::collections::BTreeMap;
 use std::panic::RefUnwindSafe;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::io::{AsyncRead, AsyncWrite};
@@ -67,6 +73,20 @@ impl LSPServer {
         requests::syntax_tree::syntax_tree(&self.session, &url).map_err(into_lsp_error)
     }
 
+    async fn explain_file_request(
+        &self,
+        params: ExplainFilePayload,
+    ) -> LspResult<ExplainFileResult> {
+        trace!(
+            "Calling method: {}\n with params: {:?}",
+            EXPLAIN_FILE_REQUEST,
+            &params
+        );
+
+        let url = params.text_document.uri;
+        requests::explain_file::explain_file(&self.session, &url).map_err(into_lsp_error)
+    }
+
     #[tracing::instrument(skip(self), name = "biome/rage", level = "debug")]
     async fn rage(&self, params: RageParams) -> LspResult<RageResult> {
         let mut entries = vec![
@@ -115,6 +135,107 @@ impl LSPServer {
         Ok(RageResult { entries })
     }
 
+    /// Lists the projects of the other sessions connected to the same [ServerFactory],
+    /// grouped by project root
+    #[tracing::instrument(skip(self), name = "workspace/projects", level = "debug")]
+    async fn projects(&self, _: ProjectsParams) -> LspResult<ProjectsResult> {
+        let mut projects: BTreeMap<Option<PathBuf>, Vec<SessionInfo>> = BTreeMap::new();
+        if let Ok(sessions) = self.sessions.lock() {
+            for (key, session) in sessions.iter() {
+                // The client that asks for the projects, e.g. the CLI, isn't listed
+                if &self.session.key == key {
+                    continue;
+                }
+
+                projects
+                    .entry(session.base_path())
+                    .or_default()
+                    .push(session.session_info());
+            }
+        }
+
+        let projects = projects
+            .into_iter()
+            .map(|(root, mut sessions)| {
+                sessions.sort_by_key(|session| session.key);
+                ProjectSessions { root, sessions }
+            })
+            .collect();
+
+        Ok(ProjectsResult { projects })
+    }
+
+    /// Returns `true` if `file_path` is a configuration file at the root of the workspace,
+    /// or one of the configuration files loaded by the session
+    fn is_configuration_file(&self, file_path: &Path) -> bool {
+        if self.session.is_configuration_file(file_path) {
+            return true;
+        }
+        self.session.base_path().is_some_and(|base_path| {
+            file_path
+                .strip_prefix(&base_path)
+                .is_ok_and(|possible_rome_json| {
+                    let possible_rome_json = possible_rome_json.display().to_string();
+                    possible_rome_json == ROME_JSON
+                        || possible_rome_json == BIOME_JSON
+                        || possible_rome_json == BIOME_JSONC
+                })
+        })
+    }
+
+    /// Loads the configuration again, then updates the diagnostics of the documents
+    /// affected by the change and tells the user what changed.
+    ///
+    /// `nested_file` is the nested configuration file that changed, if any: the documents
+    /// beneath its directory are affected.
+    async fn reload_workspace_settings(&self, nested_file: Option<&Path>) {
+        let mut change = self.session.load_workspace_settings().await;
+        change.nested_directory = nested_file
+            .and_then(Path::parent)
+            .zip(self.session.base_path())
+            .and_then(|(directory, base_path)| {
+                directory
+                    .strip_prefix(base_path)
+                    .ok()
+                    .map(Path::to_path_buf)
+            });
+        if change.is_empty() {
+            return;
+        }
+
+        self.setup_capabilities().await;
+        let updated = self.session.update_affected_diagnostics(&change).await;
+
+        let mut message = if change.fields.is_empty() {
+            match nested_file {
+                Some(nested_file) => format!(
+                    "Biome reloaded the configuration file {}",
+                    nested_file.display()
+                ),
+                None => String::from("Biome reloaded the configuration"),
+            }
+        } else {
+            let fields = change
+                .fields
+                .iter()
+                .map(|field| format!("`{field}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("Biome reloaded the configuration, these sections changed: {fields}")
+        };
+        match updated {
+            0 => message.push_str(". No open file is affected."),
+            1 => message.push_str(". The diagnostics of 1 open file were updated."),
+            _ => message.push_str(&format!(
+                ". The diagnostics of {updated} open files were updated."
+            )),
+        }
+        self.session
+            .client
+            .show_message(MessageType::INFO, message)
+            .await;
+    }
+
     async fn setup_capabilities(&self) {
         let mut capabilities = CapabilitySet::default();
 
@@ -132,24 +253,53 @@ impl LSPServer {
             "biome_did_change_workspace_settings",
             "workspace/didChangeWatchedFiles",
             if let Some(base_path) = self.session.base_path() {
-                CapabilityStatus::Enable(Some(json!(DidChangeWatchedFilesRegistrationOptions {
-                    watchers: vec![
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.json",
-                                base_path.display()
-                            )),
-                            kind: Some(WatchKind::all()),
-                        },
-                        // TODO: Biome 2.0 remove it
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/rome.json",
-                                base_path.display()
-                            )),
+                let mut watchers = vec![
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/biome.json",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                    // TODO: Biome 2.0 remove it
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/rome.json",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                    // The nested configuration files and the `.editorconfig` files
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/**/{{biome.json,biome.jsonc,.editorconfig}}",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                    // The manifests that resolve the imports of the modules
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/**/{{package.json,{}}}",
+                            base_path.display(),
+                            TSCONFIG_FILE_NAMES.join(",")
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                ];
+                // The configuration files outside the workspace folder, and the files they extend
+                watchers.extend(
+                    self.session
+                        .configuration_files()
+                        .into_iter()
+                        .filter(|file_path| file_path.parent() != Some(base_path.as_path()))
+                        .map(|file_path| FileSystemWatcher {
+                            glob_pattern: GlobPattern::String(file_path.display().to_string()),
                             kind: Some(WatchKind::all()),
-                        }
-                    ],
+                        }),
+                );
+                CapabilityStatus::Enable(Some(json!(DidChangeWatchedFilesRegistrationOptions {
+                    watchers
                 })))
             } else {
                 CapabilityStatus::Disable
@@ -183,7 +333,7 @@ impl LSPServer {
                 CapabilityStatus::Enable(Some(json!(DocumentOnTypeFormattingRegistrationOptions {
                     document_selector: None,
                     first_trigger_character: String::from("}"),
-                    more_trigger_character: Some(vec![String::from("]"), String::from(")")]),
+                    more_trigger_character: Some(vec![String::from(";"), String::from("\n")]),
                 })))
             },
         );
@@ -197,6 +347,25 @@ impl LSPServer {
             "biome_rename",
             "textDocument/rename",
             if rename {
+                CapabilityStatus::Enable(Some(json!(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })))
+            } else {
+                CapabilityStatus::Disable
+            },
+        );
+
+        let inlay_hints = self
+            .session
+            .extension_settings
+            .read()
+            .is_ok_and(|config| config.inlay_hints());
+
+        capabilities.add_capability(
+            "biome_inlay_hint",
+            "textDocument/inlayHint",
+            if inlay_hints {
                 CapabilityStatus::Enable(None)
             } else {
                 CapabilityStatus::Disable
@@ -240,7 +409,21 @@ impl LanguageServer for LSPServer {
         info!("Starting Biome Language Server...");
         self.is_initialized.store(true, Ordering::Relaxed);
 
-        let server_capabilities = server_capabilities(&params.capabilities);
+        let initialization_options = params
+            .initialization_options
+            .map(serde_json::from_value::<InitializationOptions>)
+            .transpose()
+            .unwrap_or_else(|err| {
+                error!("Couldn't read the initialization options: {err}");
+                None
+            })
+            .unwrap_or_default();
+        let workspace_trusted = initialization_options.is_workspace_trusted();
+        if !workspace_trusted {
+            info!("The workspace isn't trusted, the `extends` of the configuration and the indexing of the workspace are disabled");
+        }
+
+        let server_capabilities = server_capabilities(&params.capabilities, workspace_trusted);
         self.session.initialize(
             params.capabilities,
             params.client_info.map(|client_info| ClientInformation {
@@ -248,6 +431,7 @@ impl LanguageServer for LSPServer {
                 version: client_info.version,
             }),
             params.root_uri,
+            workspace_trusted,
         );
 
         if params.root_path.is_some() {
@@ -291,6 +475,16 @@ impl LanguageServer for LSPServer {
 
         // Diagnostics are disabled by default, so update them after fetching workspace config
         self.session.update_all_diagnostics().await;
+
+        // The project rules need the modules that aren't open, they are reported while the
+        // indexed files are analyzed
+        let session = self.session.clone();
+        tokio::spawn(async move {
+            let indexer = session.clone();
+            if let Ok(true) = spawn_blocking(move || indexer.index_project()).await {
+                session.analyze_project_in_background();
+            }
+        });
     }
 
     async fn shutdown(&self) -> LspResult<()> {
@@ -315,20 +509,22 @@ impl LanguageServer for LSPServer {
         for file_path in file_paths {
             match file_path {
                 Ok(file_path) => {
-                    let base_path = self.session.base_path();
-                    if let Some(base_path) = base_path {
-                        let possible_rome_json = file_path.strip_prefix(&base_path);
-                        if let Ok(possible_rome_json) = possible_rome_json {
-                            if possible_rome_json.display().to_string() == ROME_JSON
-                                || possible_rome_json.display().to_string() == BIOME_JSON
-                            {
-                                self.session.load_workspace_settings().await;
-                                self.setup_capabilities().await;
-                                self.session.update_all_diagnostics().await;
-                                // for now we are only interested to the configuration file,
-                                // so it's OK to exist the loop
-                                break;
-                            }
+                    if self.is_configuration_file(&file_path) {
+                        self.reload_workspace_settings(None).await;
+                        // for now we are only interested to the configuration files,
+                        // so it's OK to exist the loop
+                        break;
+                    }
+                    if self.session.is_nested_configuration_file(&file_path) {
+                        self.reload_workspace_settings(Some(&file_path)).await;
+                        break;
+                    }
+                    if self.session.is_project_manifest(&file_path) {
+                        self.session.reindex_project_manifest(&file_path);
+                        // The manifests of the workspace folder are also part of its settings
+                        if self.session.is_settings_manifest(&file_path) {
+                            self.reload_workspace_settings(None).await;
+                            break;
                         }
                     }
                 }
@@ -344,12 +540,15 @@ impl LanguageServer for LSPServer {
         handlers::text_document::did_open(&self.session, params)
             .await
             .ok();
+        // The diagnostics of the modules that import the document may change
+        self.session.analyze_project_in_background();
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         handlers::text_document::did_change(&self.session, params)
             .await
             .ok();
+        self.session.analyze_project_in_background();
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
@@ -365,6 +564,45 @@ impl LanguageServer for LSPServer {
         .map_err(into_lsp_error)?
     }
 
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> LspResult<Option<serde_json::Value>> {
+        if params.command != handlers::analysis::FIX_ALL_IN_WORKSPACE_COMMAND {
+            return Err(into_lsp_error(format!(
+                "Unknown command {}",
+                params.command
+            )));
+        }
+
+        // The files of the whole workspace are read and fixed, the panics of the blocking task
+        // are returned as errors
+        let session = self.session.clone();
+        let edit = spawn_blocking(move || {
+            handlers::analysis::fix_all_in_workspace(&session, params.arguments)
+        })
+        .await
+        .map_err(into_lsp_error)?
+        .map_err(into_lsp_error)?;
+
+        if let Some(edit) = edit {
+            let response = self
+                .session
+                .client
+                .apply_edit(edit)
+                .await
+                .map_err(into_lsp_error)?;
+            if !response.applied {
+                warn!(
+                    "The client didn't apply the fixes: {}",
+                    response.failure_reason.unwrap_or_default()
+                );
+            }
+        }
+
+        Ok(None)
+    }
+
     async fn formatting(
         &self,
         params: DocumentFormattingParams,
@@ -398,6 +636,37 @@ impl LanguageServer for LSPServer {
         self.map_op_error(result).await
     }
 
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReportResult> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::diagnostics::document_diagnostics(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::document_symbols(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LspResult<Option<Vec<SymbolInformation>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::workspace_symbols(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
     async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
         biome_diagnostics::panic::catch_unwind(move || {
             let rename_enabled = self
@@ -416,6 +685,45 @@ impl LanguageServer for LSPServer {
         })
         .map_err(into_lsp_error)?
     }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> LspResult<Option<Vec<InlayHint>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            let inlay_hints_enabled = self
+                .session
+                .extension_settings
+                .read()
+                .is_ok_and(|config| config.inlay_hints());
+
+            if inlay_hints_enabled {
+                handlers::inlay_hints::inlay_hints(&self.session, params).map_err(into_lsp_error)
+            } else {
+                Ok(None)
+            }
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            let rename_enabled = self
+                .session
+                .extension_settings
+                .read()
+                .ok()
+                .and_then(|config| config.settings.rename)
+                .unwrap_or(false);
+
+            if rename_enabled {
+                handlers::rename::prepare_rename(&self.session, params).map_err(into_lsp_error)
+            } else {
+                Ok(None)
+            }
+        })
+        .map_err(into_lsp_error)?
+    }
 }
 
 impl Drop for LSPServer {
@@ -481,7 +789,10 @@ pub struct ServerFactory {
     /// Optional [Workspace] instance shared between all clients. Currently
     /// this field is always [None] (meaning each connection will get its own
     /// workspace) until we figure out how to handle concurrent access to the
-    /// same workspace from multiple client
+    /// same workspace from multiple client. The editor windows connected to the
+    /// same daemon don't share their settings nor their open files, even when
+    /// they have the same project root: the sessions aren't keyed by project
+    /// root, the `workspace/projects` request only groups them by root
     workspace: Option<Arc<dyn Workspace>>,
 
     /// The sessions of the connected clients indexed by session key.
@@ -496,6 +807,12 @@ pub struct ServerFactory {
     /// This shared flag is set to true once at least one sessions has been
     /// initialized on this server instance
     is_initialized: Arc<AtomicBool>,
+    /// The size, in bytes, of the content of the closed files whose syntax trees
+    /// each workspace keeps. The workspaces use their default when it's [None]
+    source_budget: Option<usize>,
+    /// The directory of the cache of the diagnostics shared with the CLI. The
+    /// cache is disabled when it's [None]
+    cache_directory: Option<PathBuf>,
 }
 
 impl ServerFactory {
@@ -507,15 +824,30 @@ impl ServerFactory {
             next_session_key: AtomicU64::new(0),
             stop_on_disconnect,
             is_initialized: Arc::default(),
+            source_budget: None,
+            cache_directory: None,
         }
     }
 
+    /// Sets the size, in bytes, of the content of the closed files whose syntax
+    /// trees the workspace of each connection keeps
+    pub fn with_source_budget(mut self, source_budget: usize) -> Self {
+        self.source_budget = Some(source_budget);
+        self
+    }
+
+    /// Sets the directory of the cache of the diagnostics that the workspace of
+    /// each connection shares with the other processes of Biome
+    pub fn with_cache_directory(mut self, cache_directory: PathBuf) -> Self {
+        self.cache_directory = Some(cache_directory);
+        self
+    }
+
     /// Create a new [ServerConnection] from this factory
     pub fn create(&self, config_path: Option<PathBuf>) -> ServerConnection {
-        let workspace = self
-            .workspace
-            .clone()
-            .unwrap_or_else(workspace::server_sync);
+        let workspace = self.workspace.clone().unwrap_or_else(|| {
+            workspace::server_sync_with_options(self.source_budget, self.cache_directory.clone())
+        });
 
         let session_key = SessionKey(self.next_session_key.fetch_add(1, Ordering::Relaxed));
 
@@ -539,6 +871,7 @@ impl ServerFactory {
         });
 
         builder = builder.custom_method(SYNTAX_TREE_REQUEST, LSPServer::syntax_tree_request);
+        builder = builder.custom_method(EXPLAIN_FILE_REQUEST, LSPServer::explain_file_request);
 
         // "shutdown" is not part of the Workspace API
         builder = builder.custom_method("biome/shutdown", |server: &LSPServer, (): ()| {
@@ -548,11 +881,18 @@ impl ServerFactory {
         });
 
         builder = builder.custom_method("biome/rage", LSPServer::rage);
+        builder = builder.custom_method("workspace/projects", LSPServer::projects);
 
         workspace_method!(builder, file_features);
         workspace_method!(builder, project_features);
         workspace_method!(builder, is_path_ignored);
+        workspace_method!(builder, explain_ignored_path);
+        workspace_method!(builder, enabled_rules);
+        workspace_method!(builder, rule_docs);
+        workspace_method!(builder, capabilities);
+        workspace_method!(builder, metrics);
         workspace_method!(builder, update_settings);
+        workspace_method!(builder, register_nested_configurations);
         workspace_method!(builder, project_features);
         workspace_method!(builder, open_file);
         workspace_method!(builder, get_syntax_tree);
@@ -568,7 +908,21 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, prepare_rename);
+        workspace_method!(builder, inlay_hints);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, search_pattern);
+        workspace_method!(builder, index_file);
+        workspace_method!(builder, analyze_project);
+        workspace_method!(builder, pull_project_diagnostics);
+        workspace_method!(builder, pull_project_actions);
+        workspace_method!(builder, document_symbols);
+        workspace_method!(builder, workspace_symbols);
+        workspace_method!(builder, open_files);
+        workspace_method!(builder, change_files);
+        workspace_method!(builder, close_files);
+        workspace_method!(builder, pull_files_diagnostics);
+        workspace_method!(builder, fix_files);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
@@ -1,3 +1,4 @@
+use crate::converters::LineIndex;
 use crate::converters::{negotiated_encoding, PositionEncoding, WideEncoding};
 use crate::documents::Document;
 use crate::extension_settings::ExtensionSettings;
@@ -6,24 +7,43 @@ use crate::utils;
 use anyhow::Result;
 use biome_analyze::RuleCategories;
 use biome_console::markup;
-use biome_fs::{FileSystem, OsFileSystem, RomePath};
+use biome_diagnostics::Error;
+use biome_fs::{
+    FileSystem, FileSystemExt, OsFileSystem, OverlayFileSystem, PathInterner, RomePath,
+    TraversalContext, TraversalScope, BIOME_JSON, BIOME_JSONC,
+};
+use biome_service::configuration::editorconfig::{
+    configured_formatter_options, EditorConfigs, EDITORCONFIG,
+};
+use biome_service::configuration::extends::{
+    load_extended_configurations, merge_extended_configurations,
+};
+use biome_service::configuration::nested::NestedConfigurations;
+use biome_service::file_handlers::is_css_module;
+use biome_service::workspace::{
+    AnalyzeProjectParams, CancellationToken, FeatureName, FeaturesBuilder, IndexFileParams,
+    PullDiagnosticsParams, PullProjectDiagnosticsParams, SupportsFeatureParams,
+};
 use biome_service::workspace::{
-    FeatureName, FeaturesBuilder, PullDiagnosticsParams, SupportsFeatureParams,
+    IsPathIgnoredParams, PackageJsonFile, RageEntry, RageParams, RageResult, SessionInfo,
+    TsConfigFile, UpdateSettingsParams, TSCONFIG_FILE_NAMES,
 };
-use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
-use biome_service::{load_config, ConfigurationBasePath, Workspace};
+use biome_service::{load_config, Configuration, ConfigurationBasePath, Workspace};
 use biome_service::{DynRef, WorkspaceError};
+use biome_service::{MatchOptions, Matcher};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
 use rustc_hash::FxHashMap;
 use serde_json::Value;
-use std::path::PathBuf;
-use std::sync::atomic::AtomicU8;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, AtomicU8};
 use std::sync::Arc;
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 use tokio::sync::Notify;
 use tokio::sync::OnceCell;
+use tokio::task::spawn_blocking;
 use tower_lsp::lsp_types;
 use tower_lsp::lsp_types::Registration;
 use tower_lsp::lsp_types::Unregistration;
@@ -59,14 +79,34 @@ pub(crate) struct Session {
     pub(crate) workspace: Arc<dyn Workspace>,
     configuration_status: AtomicU8,
 
+    /// The configuration of the workspace, with its `extends` applied
+    configuration: RwLock<Option<Configuration>>,
+
+    /// The configuration file of the workspace and the files it extends
+    configuration_files: RwLock<Vec<PathBuf>>,
+
+    /// The configuration files of the subdirectories of the workspace, looked up when the
+    /// documents are opened
+    nested_configurations: RwLock<Option<NestedConfigurations>>,
+
     /// File system to read files inside the workspace
     pub(crate) fs: DynRef<'static, dyn FileSystem>,
 
+    /// The open documents, served by [Session::fs] instead of the files on the disk
+    overlay: Arc<OverlayFileSystem<OsFileSystem>>,
+
     documents: RwLock<FxHashMap<lsp_types::Url, Document>>,
 
     pub(crate) cancellation: Arc<Notify>,
 
     pub(crate) config_path: Option<PathBuf>,
+
+    /// Whether the files queued by the workspace must be analyzed, see
+    /// [Session::analyze_project_in_background]
+    project_analysis_requested: AtomicBool,
+
+    /// Whether a task of the runtime is analyzing the files queued by the workspace
+    project_analysis_running: AtomicBool,
 }
 
 /// The parameters provided by the client in the "initialize" request
@@ -75,9 +115,12 @@ struct InitializeParams {
     client_capabilities: lsp_types::ClientCapabilities,
     client_information: Option<ClientInformation>,
     root_uri: Option<Url>,
+    /// Whether the client trusts the workspace, see [crate::InitializationOptions]
+    workspace_trusted: bool,
 }
 
 #[repr(u8)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 enum ConfigurationStatus {
     /// The configuration file was properly loaded
     Loaded = 0,
@@ -102,6 +145,11 @@ impl TryFrom<u8> for ConfigurationStatus {
 
 pub(crate) type SessionHandle = Arc<Session>;
 
+/// The number of files analyzed by each call to [Workspace::analyze_project] made by
+/// [Session::analyze_project_in_background], so the diagnostics of the open documents are
+/// updated while the rest of the project is analyzed
+const PROJECT_ANALYSIS_BATCH_SIZE: u32 = 100;
+
 /// Holds the set of capabilities supported by the Language Server
 /// instance and whether they are enabled or not
 #[derive(Default)]
@@ -137,17 +185,24 @@ impl Session {
     ) -> Self {
         let documents = Default::default();
         let config = RwLock::new(ExtensionSettings::new());
+        let overlay = Arc::new(OverlayFileSystem::new(OsFileSystem));
         Self {
             key,
             client,
             initialize_params: OnceCell::default(),
             workspace,
             configuration_status: AtomicU8::new(ConfigurationStatus::Missing as u8),
+            configuration: RwLock::default(),
+            configuration_files: RwLock::default(),
+            nested_configurations: RwLock::default(),
             documents,
             extension_settings: config,
-            fs: DynRef::Owned(Box::new(OsFileSystem)),
+            fs: DynRef::Owned(Box::new(overlay.clone())),
+            overlay,
             cancellation,
             config_path: None,
+            project_analysis_requested: AtomicBool::new(false),
+            project_analysis_running: AtomicBool::new(false),
         }
     }
 
@@ -161,11 +216,13 @@ impl Session {
         client_capabilities: lsp_types::ClientCapabilities,
         client_information: Option<ClientInformation>,
         root_uri: Option<Url>,
+        workspace_trusted: bool,
     ) {
         let result = self.initialize_params.set(InitializeParams {
             client_capabilities,
             client_information,
             root_uri,
+            workspace_trusted,
         });
 
         if let Err(err) = result {
@@ -236,16 +293,27 @@ impl Session {
             .ok_or_else(WorkspaceError::not_found)
     }
 
-    /// Set the [`Document`] for the provided [`lsp_types::Url`]
+    /// Set the [`Document`] for the provided [`lsp_types::Url`], and its `content`
+    /// to the operations that read the files through [Session::fs]
     ///
     /// Used by [`handlers::text_document] to synchronize documents with the client.
-    pub(crate) fn insert_document(&self, url: lsp_types::Url, document: Document) {
-        self.documents.write().unwrap().insert(url, document);
+    pub(crate) fn insert_document(&self, url: lsp_types::Url, document: Document, content: &str) {
+        if let Ok(path) = url.to_file_path() {
+            self.overlay.set_document(path, content.to_string());
+        }
+        if let Some(previous) = self.documents.write().unwrap().insert(url, document) {
+            previous.cancellation.cancel();
+        }
     }
 
     /// Remove the [`Document`] matching the provided [`lsp_types::Url`]
     pub(crate) fn remove_document(&self, url: &lsp_types::Url) {
-        self.documents.write().unwrap().remove(url);
+        if let Ok(path) = url.to_file_path() {
+            self.overlay.remove_document(&path);
+        }
+        if let Some(document) = self.documents.write().unwrap().remove(url) {
+            document.cancellation.cancel();
+        }
     }
 
     pub(crate) fn file_path(&self, url: &lsp_types::Url) -> Result<RomePath> {
@@ -271,13 +339,67 @@ impl Session {
         Ok(RomePath::new(path_to_file))
     }
 
+    /// Returns the URL of a path of the workspace, the reverse of [Session::file_path]
+    pub(crate) fn file_url(&self, path: &Path) -> Option<lsp_types::Url> {
+        let path = match self.base_path() {
+            Some(base_path) if path.is_relative() => base_path.join(path),
+            _ => path.to_path_buf(),
+        };
+        lsp_types::Url::from_file_path(path).ok()
+    }
+
+    /// Returns the line index of an open document, or of a file read from the disk
+    pub(crate) fn read_line_index(&self, url: &Url) -> Option<LineIndex> {
+        if let Ok(doc) = self.document(url) {
+            return Some(doc.line_index);
+        }
+
+        let path = url.to_file_path().ok()?;
+        match self.fs.read_to_string(&path) {
+            Ok(content) => Some(LineIndex::new(&content)),
+            Err(err) => {
+                warn!("Couldn't read {}: {err}", path.display());
+                None
+            }
+        }
+    }
+
     /// Computes diagnostics for the file matching the provided url and publishes
     /// them to the client. Called from [`handlers::text_document`] when a file's
     /// contents changes.
+    ///
+    /// The clients that pull the diagnostics request them when a document changes, so
+    /// nothing is published to them.
     #[tracing::instrument(level = "debug", skip_all, fields(url = display(&url), diagnostic_count), err)]
     pub(crate) async fn update_diagnostics(&self, url: lsp_types::Url) -> Result<()> {
-        let rome_path = self.file_path(&url)?;
+        if self.supports_pull_diagnostics() {
+            return Ok(());
+        }
+
         let doc = self.document(&url)?;
+        let diagnostics = match self.compute_diagnostics(&url, &doc) {
+            // The diagnostics of the newer version are published instead
+            Err(_) if doc.cancellation.is_cancelled() => return Ok(()),
+            result => result?,
+        };
+
+        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+
+        self.client
+            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Computes the diagnostics of a document, pushed by [Session::update_diagnostics] or
+    /// pulled by the client with `textDocument/diagnostic`
+    pub(crate) fn compute_diagnostics(
+        &self,
+        url: &lsp_types::Url,
+        doc: &Document,
+    ) -> Result<Vec<lsp_types::Diagnostic>> {
+        let rome_path = self.file_path(url)?;
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             feature: FeaturesBuilder::new()
                 .with_linter()
@@ -286,72 +408,268 @@ impl Session {
             path: rome_path.clone(),
         })?;
 
-        let diagnostics = if self.is_linting_and_formatting_disabled() {
+        if self.is_linting_and_formatting_disabled() {
             tracing::trace!("Linting disabled because Biome configuration is missing and `requireConfiguration` is true.");
-            vec![]
-        } else if !file_features.supports_for(&FeatureName::Lint)
+            return Ok(vec![]);
+        }
+
+        if !file_features.supports_for(&FeatureName::Lint)
             && !file_features.supports_for(&FeatureName::OrganizeImports)
         {
             tracing::trace!("linting and import sorting are not supported: {file_features:?}");
             // Sending empty vector clears published diagnostics
-            vec![]
-        } else {
-            let mut categories = RuleCategories::SYNTAX;
-            if file_features.supports_for(&FeatureName::Lint) {
-                categories |= RuleCategories::LINT
+            return Ok(vec![]);
+        }
+
+        let mut categories = RuleCategories::SYNTAX;
+        if file_features.supports_for(&FeatureName::Lint) {
+            categories |= RuleCategories::LINT
+        }
+        if file_features.supports_for(&FeatureName::OrganizeImports) {
+            categories |= RuleCategories::ACTION
+        }
+        let mut result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
+            path: rome_path.clone(),
+            categories,
+            max_diagnostics: u64::MAX,
+            only: vec![],
+            skip: vec![],
+            cancellation: doc.cancellation.clone(),
+        })?;
+        if file_features.supports_for(&FeatureName::Lint) {
+            let project_result = self
+                .workspace
+                .pull_project_diagnostics(PullProjectDiagnosticsParams { path: rome_path })?;
+            result.diagnostics.extend(project_result.diagnostics);
+        }
+
+        tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
+
+        let result = result
+            .diagnostics
+            .into_iter()
+            .filter_map(|d| {
+                match utils::diagnostic_to_lsp(d, url, &doc.line_index, self.position_encoding()) {
+                    Ok(diag) => Some(diag),
+                    Err(err) => {
+                        tracing::error!("failed to convert diagnostic to LSP: {err:?}");
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        tracing::trace!("lsp diagnostics: {:#?}", result);
+
+        Ok(result)
+    }
+
+    /// Updates diagnostics for every [`Document`] in this [`Session`].
+    ///
+    /// The clients that pull the diagnostics are asked to pull them again instead.
+    pub(crate) async fn update_all_diagnostics(&self) {
+        if self.supports_pull_diagnostics() {
+            if self.supports_diagnostic_refresh() {
+                let result = self
+                    .client
+                    .send_request::<lsp_types::request::WorkspaceDiagnosticRefresh>(())
+                    .await;
+                if let Err(e) = result {
+                    error!("Error while refreshing diagnostics: {}", e);
+                }
+            }
+            return;
+        }
+
+        let mut futures: FuturesUnordered<_> = self
+            .documents
+            .read()
+            .unwrap()
+            .keys()
+            .map(|url| self.update_diagnostics(url.clone()))
+            .collect();
+
+        while let Some(result) = futures.next().await {
+            if let Err(e) = result {
+                error!("Error while updating diagnostics: {}", e);
             }
-            if file_features.supports_for(&FeatureName::OrganizeImports) {
-                categories |= RuleCategories::ACTION
+        }
+    }
+
+    /// Updates the diagnostics of the [`Document`]s affected by a change of the configuration,
+    /// and returns how many of them were affected.
+    ///
+    /// The clients that pull the diagnostics are asked to pull them again instead: the
+    /// diagnostics of the other documents are reported as unchanged.
+    pub(crate) async fn update_affected_diagnostics(&self, change: &ConfigurationChange) -> usize {
+        let urls: Vec<_> = self
+            .documents
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|url| {
+                self.file_path(url)
+                    .map_or(true, |rome_path| change.affects(&rome_path))
+            })
+            .cloned()
+            .collect();
+
+        if urls.is_empty() {
+            return 0;
+        }
+
+        if self.supports_pull_diagnostics() {
+            self.update_all_diagnostics().await;
+            return urls.len();
+        }
+
+        let mut futures: FuturesUnordered<_> = urls
+            .iter()
+            .map(|url| self.update_diagnostics(url.clone()))
+            .collect();
+
+        while let Some(result) = futures.next().await {
+            if let Err(e) = result {
+                error!("Error while updating diagnostics: {}", e);
             }
-            let result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
-                path: rome_path,
-                categories,
-                max_diagnostics: u64::MAX,
-            })?;
-
-            tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
-
-            let result = result
-                .diagnostics
-                .into_iter()
-                .filter_map(|d| {
-                    match utils::diagnostic_to_lsp(
-                        d,
-                        &url,
-                        &doc.line_index,
-                        self.position_encoding(),
-                    ) {
-                        Ok(diag) => Some(diag),
-                        Err(err) => {
-                            tracing::error!("failed to convert diagnostic to LSP: {err:?}");
-                            None
-                        }
-                    }
-                })
-                .collect();
+        }
 
-            tracing::trace!("lsp diagnostics: {:#?}", result);
+        urls.len()
+    }
 
-            result
+    /// Indexes the files of the workspace, so the project rules, e.g. the detection of the
+    /// import cycles, know the modules that aren't open. The indexed files are analyzed by
+    /// [Session::analyze_project_in_background].
+    ///
+    /// It reads the whole workspace, so it must run outside of the async runtime. Returns
+    /// `false` if the session has no workspace folder, or if the workspace isn't trusted.
+    pub(crate) fn index_project(&self) -> bool {
+        if !self.is_workspace_trusted() {
+            info!("The workspace isn't trusted, its files aren't indexed");
+            return false;
+        }
+        let Some(base_path) = self.base_path() else {
+            return false;
         };
 
-        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+        self.walk_project(&base_path, &|path| {
+            let content = match self.fs.read_to_string(path) {
+                Ok(content) => content,
+                Err(err) => {
+                    warn!("Couldn't read {}: {err}", path.display());
+                    return;
+                }
+            };
 
-        self.client
-            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            let result = self.workspace.index_file(IndexFileParams {
+                path: Self::workspace_path(&base_path, path),
+                content,
+            });
+            if let Err(err) = result {
+                warn!("Couldn't index {}: {err}", path.display());
+            }
+        });
+
+        true
+    }
+
+    /// Analyzes the files queued by the workspace in a task of the runtime, e.g. the files
+    /// that were indexed, opened or changed, and updates the diagnostics of the documents
+    /// affected by the analysis, e.g. the modules that import a changed module.
+    ///
+    /// A single task analyzes the files: when it's already running, it analyzes the files
+    /// queued by this call once it's done with the current ones.
+    pub(crate) fn analyze_project_in_background(self: &Arc<Self>) {
+        self.project_analysis_requested
+            .store(true, Ordering::Release);
+        if self.project_analysis_running.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let session = self.clone();
+        tokio::spawn(async move {
+            while session
+                .project_analysis_requested
+                .swap(false, Ordering::AcqRel)
+            {
+                session.analyze_project().await;
+            }
+            session
+                .project_analysis_running
+                .store(false, Ordering::Release);
+
+            // The request made between the last check and the end of the task isn't lost
+            if session.project_analysis_requested.load(Ordering::Acquire) {
+                session.analyze_project_in_background();
+            }
+        });
+    }
+
+    /// Analyzes the files queued by the workspace in batches, and updates the diagnostics of
+    /// the documents affected by each batch
+    async fn analyze_project(self: &Arc<Self>) {
+        loop {
+            let analyzer = self.clone();
+            let result = spawn_blocking(move || {
+                analyzer.workspace.analyze_project(AnalyzeProjectParams {
+                    max_files: Some(PROJECT_ANALYSIS_BATCH_SIZE),
+                    cancellation: CancellationToken::default(),
+                })
+            })
             .await;
 
-        Ok(())
+            let result = match result {
+                Ok(Ok(result)) => result,
+                Ok(Err(err)) => {
+                    error!("Couldn't analyze the project: {}", err);
+                    return;
+                }
+                Err(err) => {
+                    error!("The analysis of the project panicked: {}", err);
+                    return;
+                }
+            };
+            info!(
+                "Analyzed {} files of the project, {} are pending",
+                result.analyzed, result.pending
+            );
+
+            self.update_project_diagnostics(result.affected).await;
+            if result.pending == 0 {
+                return;
+            }
+        }
     }
 
-    /// Updates diagnostics for every [`Document`] in this [`Session`]
-    pub(crate) async fn update_all_diagnostics(&self) {
-        let mut futures: FuturesUnordered<_> = self
+    /// Updates the diagnostics of the open documents at `paths`, whose diagnostics of the
+    /// project rules may have changed.
+    ///
+    /// The clients that pull the diagnostics are asked to pull them again instead.
+    async fn update_project_diagnostics(&self, paths: Vec<RomePath>) {
+        if paths.is_empty() {
+            return;
+        }
+
+        if self.supports_pull_diagnostics() {
+            self.update_all_diagnostics().await;
+            return;
+        }
+
+        let urls: Vec<_> = self
             .documents
             .read()
             .unwrap()
             .keys()
-            .map(|url| self.update_diagnostics(url.clone()))
+            .filter(|url| {
+                self.file_path(url)
+                    .is_ok_and(|rome_path| paths.contains(&rome_path))
+            })
+            .cloned()
+            .collect();
+
+        let mut futures: FuturesUnordered<_> = urls
+            .into_iter()
+            .map(|url| self.update_diagnostics(url))
             .collect();
 
         while let Some(result) = futures.next().await {
@@ -361,6 +679,56 @@ impl Session {
         }
     }
 
+    /// Returns the files of the workspace that the project analysis handles, e.g. to fix the
+    /// issues of a rule in the whole workspace.
+    ///
+    /// Like [Session::index_project], it reads the whole workspace, so it must run outside of
+    /// the async runtime. It's empty if the session has no workspace folder, or if the
+    /// workspace isn't trusted.
+    pub(crate) fn project_files(&self) -> Vec<PathBuf> {
+        if !self.is_workspace_trusted() {
+            return vec![];
+        }
+        let Some(base_path) = self.base_path() else {
+            return vec![];
+        };
+
+        let files = Mutex::new(Vec::new());
+        self.walk_project(&base_path, &|path| {
+            files.lock().unwrap().push(path.to_path_buf());
+        });
+        let mut files = files.into_inner().unwrap();
+        files.sort();
+        files
+    }
+
+    /// Calls `handle_file` with each file of the workspace that the project analysis handles
+    fn walk_project(&self, base_path: &Path, handle_file: &(dyn Fn(&Path) + Sync)) {
+        let (interner, _) = PathInterner::new();
+        let walker = ProjectWalker {
+            session: self,
+            base_path,
+            interner,
+            handle_file,
+        };
+        self.fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+            scope.spawn(&walker, base_path.to_path_buf());
+        }));
+    }
+
+    /// Returns the path of a file of the workspace, relative to the root of the workspace
+    fn workspace_path(base_path: &Path, path: &Path) -> RomePath {
+        RomePath::new(path.strip_prefix(base_path).unwrap_or(path))
+    }
+
+    /// True if the client trusts the workspace. The workspaces are trusted unless the client
+    /// says otherwise, see [crate::InitializationOptions]
+    pub(crate) fn is_workspace_trusted(&self) -> bool {
+        self.initialize_params
+            .get()
+            .map_or(true, |params| params.workspace_trusted)
+    }
+
     /// True if the client supports dynamic registration of "workspace/didChangeConfiguration" requests
     pub(crate) fn can_register_did_change_configuration(&self) -> bool {
         self.initialize_params
@@ -371,6 +739,25 @@ impl Session {
             == Some(true)
     }
 
+    /// True if the client pulls the diagnostics with "textDocument/diagnostic" requests
+    pub(crate) fn supports_pull_diagnostics(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .and_then(|c| c.diagnostic.as_ref())
+            .is_some()
+    }
+
+    /// True if the client supports "workspace/diagnostic/refresh" requests
+    fn supports_diagnostic_refresh(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.workspace.as_ref())
+            .and_then(|c| c.diagnostic.as_ref())
+            .and_then(|c| c.refresh_support)
+            == Some(true)
+    }
+
     /// Returns the base path of the workspace on the filesystem if it has one
     pub(crate) fn base_path(&self) -> Option<PathBuf> {
         let initialize_params = self.initialize_params.get()?;
@@ -392,10 +779,24 @@ impl Session {
         self.initialize_params.get()?.client_information.as_ref()
     }
 
+    /// Returns the information about this session listed by the `workspace/projects` request
+    pub(crate) fn session_info(&self) -> SessionInfo {
+        let client_information = self.client_information();
+        SessionInfo {
+            key: self.key.0,
+            client_name: client_information.map(|information| information.name.clone()),
+            client_version: client_information.and_then(|information| information.version.clone()),
+            open_documents: self.documents.read().unwrap().len(),
+        }
+    }
+
     /// This function attempts to read the `biome.json` configuration file from
-    /// the root URI and update the workspace settings accordingly
+    /// the root URI, resolves the files it extends and updates the workspace settings
+    /// accordingly.
+    ///
+    /// Returns what changed compared to the configuration loaded previously.
     #[tracing::instrument(level = "debug", skip(self))]
-    pub(crate) async fn load_workspace_settings(&self) {
+    pub(crate) async fn load_workspace_settings(&self) -> ConfigurationChange {
         let base_path = if let Some(config_path) = &self.config_path {
             ConfigurationBasePath::FromUser(config_path.clone())
         } else {
@@ -405,38 +806,248 @@ impl Session {
             }
         };
 
-        let status = match load_config(&self.fs, base_path) {
+        let (status, configuration) = match load_config(&self.fs, base_path) {
             Ok(Some(payload)) => {
-                let (configuration, diagnostics) = payload.deserialized.consume();
-                let configuration = configuration.unwrap_or_default();
-                if !diagnostics.is_empty() {
-                    warn!("The deserialization of the configuration resulted in errors. Biome will use its defaults where possible.");
-                }
+                let (configuration, mut diagnostics) = payload.deserialized.consume();
+                let mut configuration = configuration.unwrap_or_default();
+                let mut configuration_files = vec![payload.configuration_file_path];
+
+                let extended_configurations = match &configuration.extends {
+                    Some(_) if !self.is_workspace_trusted() => {
+                        warn!("The workspace isn't trusted, the `extends` of the configuration are ignored.");
+                        Ok(vec![])
+                    }
+                    Some(extends) => load_extended_configurations(
+                        &self.fs,
+                        &payload.configuration_directory_path,
+                        extends,
+                    ),
+                    None => Ok(vec![]),
+                };
+
+                let status = match extended_configurations {
+                    Ok(extended_configurations) => {
+                        let mut configurations = vec![];
+                        for extended_configuration in extended_configurations {
+                            let (extended, extended_diagnostics) =
+                                extended_configuration.deserialized.consume();
+                            configurations.push(extended.unwrap_or_default());
+                            diagnostics.extend(extended_diagnostics);
+                            configuration_files.push(extended_configuration.file_path);
+                        }
+                        configuration =
+                            merge_extended_configurations(configuration, configurations);
+
+                        if !diagnostics.is_empty() {
+                            warn!("The deserialization of the configuration resulted in errors. Biome will use its defaults where possible.");
+                        }
 
-                info!("Loaded workspace settings: {configuration:#?}");
+                        info!("Loaded workspace settings: {configuration:#?}");
+
+                        let result = self.workspace.update_settings(UpdateSettingsParams {
+                            configuration: configuration.clone(),
+                            vcs_ignore_files: vec![],
+                            tsconfig_files: self
+                                .base_path()
+                                .map(|base_path| TsConfigFile::read_all(&*self.fs, &base_path))
+                                .unwrap_or_default(),
+                            package_json_files: self
+                                .base_path()
+                                .and_then(|base_path| PackageJsonFile::read(&*self.fs, &base_path))
+                                .into_iter()
+                                .collect(),
+                        });
+
+                        if let Err(error) = result {
+                            error!("Failed to set workspace settings: {}", error);
+                            ConfigurationStatus::Error
+                        } else {
+                            // The update removed the nested configuration files registered
+                            // before, they're registered again for the open documents
+                            *self.nested_configurations.write().unwrap() =
+                                Some(self.new_nested_configurations(
+                                    &configuration,
+                                    payload.configuration_directory_path.clone(),
+                                    &configuration_files,
+                                ));
+                            let paths: Vec<_> = self
+                                .documents
+                                .read()
+                                .unwrap()
+                                .keys()
+                                .filter_map(|url| self.file_path(url).ok())
+                                .collect();
+                            for path in paths {
+                                self.discover_nested_configurations(
+                                    path.parent().unwrap_or(Path::new("")),
+                                );
+                            }
+                            ConfigurationStatus::Loaded
+                        }
+                    }
+                    Err(err) => {
+                        error!(
+                            "Couldn't load the extended configurations, reason:\n {}",
+                            err
+                        );
+                        ConfigurationStatus::Error
+                    }
+                };
 
-                let result = self
-                    .workspace
-                    .update_settings(UpdateSettingsParams { configuration });
-
-                if let Err(error) = result {
-                    error!("Failed to set workspace settings: {}", error);
-                    ConfigurationStatus::Error
-                } else {
-                    ConfigurationStatus::Loaded
-                }
+                *self.configuration_files.write().unwrap() = configuration_files;
+                let configuration =
+                    (status == ConfigurationStatus::Loaded).then_some(configuration);
+                (status, configuration)
             }
             Ok(None) => {
                 // Ignore, load_config already logs an error in this case
-                ConfigurationStatus::Missing
+                (ConfigurationStatus::Missing, None)
             }
             Err(err) => {
                 error!("Couldn't load the workspace settings, reason:\n {}", err);
-                ConfigurationStatus::Error
+                (ConfigurationStatus::Error, None)
             }
         };
 
+        let previous_status = self.configuration_status();
         self.set_configuration_status(status);
+
+        let mut current = self.configuration.write().unwrap();
+        let mut change = ConfigurationChange::new(current.as_ref(), configuration.as_ref());
+        change.status_changed = previous_status != status;
+        *current = configuration;
+
+        change
+    }
+
+    /// Returns the lookup of the nested configuration files of the workspace, and of the
+    /// `.editorconfig` files when the configuration uses them. `configuration_files` are the
+    /// configuration file of the workspace followed by the files it extends.
+    fn new_nested_configurations(
+        &self,
+        configuration: &Configuration,
+        configuration_directory: PathBuf,
+        configuration_files: &[PathBuf],
+    ) -> NestedConfigurations {
+        let nested_configurations =
+            NestedConfigurations::new(configuration_directory, self.base_path());
+        let use_editorconfig = configuration
+            .formatter
+            .as_ref()
+            .is_some_and(|formatter| formatter.use_editorconfig());
+        if !use_editorconfig {
+            return nested_configurations;
+        }
+        // The files of `extends` have a lower precedence than the file that extends them
+        let file_paths: Vec<_> = configuration_files
+            .iter()
+            .skip(1)
+            .chain(configuration_files.first())
+            .cloned()
+            .collect();
+        nested_configurations.with_editorconfigs(EditorConfigs::new(
+            self.base_path(),
+            configured_formatter_options(&*self.fs, &file_paths),
+            configuration,
+        ))
+    }
+
+    /// Registers the nested configuration files that apply to the files beneath `directory`, a
+    /// path of the workspace, e.g. before a document is opened
+    pub(crate) fn discover_nested_configurations(&self, directory: &Path) {
+        let nested_configurations = self.nested_configurations.read().unwrap();
+        let Some(nested_configurations) = nested_configurations.as_ref() else {
+            return;
+        };
+        match nested_configurations.discover(&*self.fs, &*self.workspace, directory) {
+            Ok(diagnostics) if !diagnostics.is_empty() => {
+                warn!("The nested configuration files resulted in errors. Biome will use its defaults where possible: {diagnostics:?}");
+            }
+            Ok(_) => {}
+            Err(error) => error!("Couldn't apply the nested configuration files: {error}"),
+        }
+    }
+
+    /// Returns `true` if `path` is the configuration file of a subdirectory of the workspace,
+    /// see [NestedConfigurations], or one of its `.editorconfig` files
+    pub(crate) fn is_nested_configuration_file(&self, path: &Path) -> bool {
+        let Some(base_path) = self.base_path() else {
+            return false;
+        };
+        path.starts_with(&base_path)
+            && !self.is_configuration_file(path)
+            && path.file_name().is_some_and(|file_name| {
+                file_name == BIOME_JSON || file_name == BIOME_JSONC || file_name == EDITORCONFIG
+            })
+            && !path
+                .components()
+                .any(|component| component.as_os_str() == "node_modules")
+    }
+
+    /// Returns `true` if `path` is a `package.json` or a `tsconfig.json` of the workspace, which
+    /// resolve the imports of the modules
+    pub(crate) fn is_project_manifest(&self, path: &Path) -> bool {
+        let Some(base_path) = self.base_path() else {
+            return false;
+        };
+        path.starts_with(&base_path)
+            && path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .is_some_and(|file_name| {
+                    file_name == "package.json" || TSCONFIG_FILE_NAMES.contains(&file_name)
+                })
+    }
+
+    /// Returns `true` if `path` is one of the manifests passed with the settings of the
+    /// workspace: the `package.json` of the workspace folder, its `tsconfig.json` and the files
+    /// that the `tsconfig.json` extends
+    pub(crate) fn is_settings_manifest(&self, path: &Path) -> bool {
+        let Some(base_path) = self.base_path() else {
+            return false;
+        };
+        path == base_path.join("package.json")
+            || TsConfigFile::read_all(&*self.fs, &base_path)
+                .iter()
+                .any(|file| base_path.join(&file.path) == path)
+    }
+
+    /// Indexes again a `package.json` or a `tsconfig.json` that changed on disk, so the imports
+    /// are resolved with its new content, and analyzes the project again. A deleted file is
+    /// indexed as empty.
+    pub(crate) fn reindex_project_manifest(self: &Arc<Self>, path: &Path) {
+        if !self.is_workspace_trusted() {
+            return;
+        }
+        let Some(base_path) = self.base_path() else {
+            return;
+        };
+
+        let content = self.fs.read_to_string(path).unwrap_or_default();
+        let result = self.workspace.index_file(IndexFileParams {
+            path: Self::workspace_path(&base_path, path),
+            content,
+        });
+        if let Err(err) = result {
+            warn!("Couldn't index {}: {err}", path.display());
+            return;
+        }
+        self.analyze_project_in_background();
+    }
+
+    /// Returns `true` if `path` is the configuration file of the workspace, or one
+    /// of the files it extends
+    pub(crate) fn is_configuration_file(&self, path: &Path) -> bool {
+        self.configuration_files
+            .read()
+            .unwrap()
+            .iter()
+            .any(|configuration_file| configuration_file == path)
+    }
+
+    /// Returns the configuration file of the workspace and the files it extends
+    pub(crate) fn configuration_files(&self) -> Vec<PathBuf> {
+        self.configuration_files.read().unwrap().clone()
     }
 
     /// Requests "workspace/configuration" from client and updates Session config
@@ -521,3 +1132,257 @@ impl Session {
             .unwrap_or(PositionEncoding::Wide(WideEncoding::Utf16))
     }
 }
+
+/// What changed when the configuration was reloaded, see [Session::load_workspace_settings]
+pub(crate) struct ConfigurationChange {
+    /// The top-level fields of the configuration that changed, e.g. `linter`
+    pub(crate) fields: Vec<String>,
+    /// Whether the configuration file was found, missing, or couldn't be loaded
+    pub(crate) status_changed: bool,
+    /// The files affected by the change when only some `overrides` changed, one matcher per
+    /// override. `None` if all the files are affected.
+    overrides: Option<Vec<Matcher>>,
+    /// The directory of the nested configuration file that changed, the files beneath it are
+    /// affected
+    pub(crate) nested_directory: Option<PathBuf>,
+}
+
+impl ConfigurationChange {
+    fn new(previous: Option<&Configuration>, current: Option<&Configuration>) -> Self {
+        let previous_fields = configuration_fields(previous);
+        let current_fields = configuration_fields(current);
+        let mut fields: Vec<_> = previous_fields
+            .keys()
+            .chain(
+                current_fields
+                    .keys()
+                    .filter(|key| !previous_fields.contains_key(*key)),
+            )
+            .filter(|key| previous_fields.get(*key) != current_fields.get(*key))
+            .cloned()
+            .collect();
+        fields.sort();
+
+        let overrides = if fields == ["overrides"] {
+            changed_overrides_matchers(previous, current)
+        } else {
+            None
+        };
+
+        Self {
+            fields,
+            status_changed: false,
+            overrides,
+            nested_directory: None,
+        }
+    }
+
+    /// Returns `true` if nothing changed
+    pub(crate) fn is_empty(&self) -> bool {
+        self.fields.is_empty() && !self.status_changed && self.nested_directory.is_none()
+    }
+
+    /// Returns `true` if the settings of the file at `path` may have changed
+    pub(crate) fn affects(&self, path: &Path) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        if self.status_changed {
+            return true;
+        }
+        if self
+            .nested_directory
+            .as_ref()
+            .is_some_and(|directory| path.starts_with(directory))
+        {
+            return true;
+        }
+        if self.fields.is_empty() {
+            return false;
+        }
+        self.overrides.as_ref().map_or(true, |matchers| {
+            matchers.iter().any(|matcher| matcher.matches_path(path))
+        })
+    }
+}
+
+/// Returns the top-level fields of the configuration with their serialized value
+fn configuration_fields(configuration: Option<&Configuration>) -> serde_json::Map<String, Value> {
+    match configuration.map(serde_json::to_value) {
+        Some(Ok(Value::Object(fields))) => fields,
+        _ => serde_json::Map::new(),
+    }
+}
+
+/// Returns a matcher of the files included by each override that was added, removed or
+/// modified. The overrides are compared by position, because the order decides which one takes
+/// precedence: when two overrides are swapped, the files of both are affected. Returns `None` if
+/// one of them applies to all the files.
+fn changed_overrides_matchers(
+    previous: Option<&Configuration>,
+    current: Option<&Configuration>,
+) -> Option<Vec<Matcher>> {
+    let previous = previous.and_then(|configuration| configuration.overrides.as_ref());
+    let current = current.and_then(|configuration| configuration.overrides.as_ref());
+    let previous_patterns = previous.map_or(&[][..], |overrides| overrides.0.as_slice());
+    let current_patterns = current.map_or(&[][..], |overrides| overrides.0.as_slice());
+
+    let mut matchers = vec![];
+    for index in 0..previous_patterns.len().max(current_patterns.len()) {
+        let previous_pattern = previous_patterns.get(index);
+        let current_pattern = current_patterns.get(index);
+        if previous_pattern == current_pattern {
+            continue;
+        }
+        for pattern in previous_pattern.into_iter().chain(current_pattern) {
+            let mut matcher = Matcher::new(MatchOptions {
+                case_sensitive: true,
+                require_literal_leading_dot: false,
+                require_literal_separator: false,
+            });
+            for include in pattern.include.as_ref()?.iter() {
+                matcher.add_pattern(include).ok()?;
+            }
+            matchers.push(matcher);
+        }
+    }
+
+    Some(matchers)
+}
+
+/// Visits the files of the workspace that the project analysis handles, see
+/// [Session::index_project] and [Session::project_files]
+struct ProjectWalker<'a> {
+    session: &'a Session,
+    /// The root of the workspace: the paths of the workspace are relative to it
+    base_path: &'a Path,
+    interner: PathInterner,
+    handle_file: &'a (dyn Fn(&Path) + Sync),
+}
+
+impl TraversalContext for ProjectWalker<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, error: Error) {
+        warn!("Couldn't visit a file of the project: {error:?}");
+    }
+
+    fn can_handle(&self, path: &RomePath) -> bool {
+        let rome_path = Session::workspace_path(self.base_path, path);
+        // The CSS modules aren't linted, but the modules read their classes
+        if path.is_dir() || is_css_module(path) {
+            let is_ignored = self
+                .session
+                .workspace
+                .is_path_ignored(IsPathIgnoredParams {
+                    rome_path: rome_path.clone(),
+                    feature: FeatureName::Lint,
+                })
+                .unwrap_or(true);
+            if path.is_dir() && !is_ignored {
+                self.session.discover_nested_configurations(&rome_path);
+            }
+            return !is_ignored;
+        }
+
+        self.session
+            .workspace
+            .file_features(SupportsFeatureParams {
+                path: rome_path,
+                feature: FeaturesBuilder::new().with_linter().build(),
+            })
+            .map(|file_features| file_features.supports_for(&FeatureName::Lint))
+            .unwrap_or(false)
+    }
+
+    fn handle_file(&self, path: &Path) {
+        (self.handle_file)(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigurationChange;
+    use biome_service::Configuration;
+    use serde_json::json;
+    use std::path::Path;
+
+    fn configuration(value: serde_json::Value) -> Configuration {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn configuration_change_lists_the_changed_fields() {
+        let previous = configuration(json!({
+            "formatter": { "indentStyle": "space" },
+            "linter": { "enabled": true }
+        }));
+        let current = configuration(json!({
+            "formatter": { "indentStyle": "space" },
+            "linter": { "enabled": false },
+            "files": { "maxSize": 1024 }
+        }));
+
+        let change = ConfigurationChange::new(Some(&previous), Some(&current));
+
+        assert_eq!(change.fields, ["files", "linter"]);
+        assert!(change.affects(Path::new("src/index.js")));
+    }
+
+    #[test]
+    fn configuration_change_of_overrides_affects_the_included_files() {
+        let previous = configuration(json!({
+            "overrides": [
+                { "include": ["src/**"], "linter": { "enabled": false } },
+                { "include": ["test/**"], "formatter": { "enabled": false } }
+            ]
+        }));
+        let current = configuration(json!({
+            "overrides": [
+                { "include": ["src/**"], "linter": { "enabled": false } },
+                { "include": ["test/**"], "formatter": { "enabled": true } }
+            ]
+        }));
+
+        let change = ConfigurationChange::new(Some(&previous), Some(&current));
+
+        assert_eq!(change.fields, ["overrides"]);
+        assert!(change.affects(Path::new("test/index.test.js")));
+        assert!(!change.affects(Path::new("src/index.js")));
+    }
+
+    #[test]
+    fn configuration_change_of_the_order_of_overrides_affects_their_files() {
+        let previous = configuration(json!({
+            "overrides": [
+                { "include": ["src/**"], "formatter": { "indentWidth": 2 } },
+                { "include": ["src/legacy/**"], "formatter": { "indentWidth": 4 } }
+            ]
+        }));
+        let current = configuration(json!({
+            "overrides": [
+                { "include": ["src/legacy/**"], "formatter": { "indentWidth": 4 } },
+                { "include": ["src/**"], "formatter": { "indentWidth": 2 } }
+            ]
+        }));
+
+        let change = ConfigurationChange::new(Some(&previous), Some(&current));
+
+        assert_eq!(change.fields, ["overrides"]);
+        assert!(change.affects(Path::new("src/legacy/index.js")));
+        assert!(change.affects(Path::new("src/index.js")));
+        assert!(!change.affects(Path::new("test/index.test.js")));
+    }
+
+    #[test]
+    fn configuration_change_is_empty_without_changes() {
+        let previous = configuration(json!({ "linter": { "enabled": true } }));
+
+        let change = ConfigurationChange::new(Some(&previous), Some(&previous));
+
+        assert!(change.is_empty());
+        assert!(!change.affects(Path::new("src/index.js")));
+    }
+}
@@ -1,16 +1,16 @@
-use crate::converters::line_index::LineIndex;
+use crate::converters::LineIndex;
 use crate::converters::{from_proto, to_proto, PositionEncoding};
 use anyhow::{ensure, Context, Result};
 use biome_analyze::ActionCategory;
 use biome_console::fmt::Termcolor;
 use biome_console::fmt::{self, Formatter};
-use biome_console::MarkupBuf;
 use biome_diagnostics::termcolor::NoColor;
 use biome_diagnostics::{
-    Applicability, {Diagnostic, DiagnosticTags, Location, PrintDescription, Severity, Visit},
+    Applicability, Label, Resource,
+    {Diagnostic, DiagnosticTags, Location, LogCategory, PrintDescription, Severity, Visit},
 };
-use biome_rowan::TextSize;
-use biome_service::workspace::CodeAction;
+use biome_rowan::{TextRange, TextSize};
+use biome_service::workspace::{CodeAction, ProjectAction};
 use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
 use std::any::Any;
 use std::collections::HashMap;
@@ -121,17 +121,7 @@ pub(crate) fn code_fix_to_lsp(
         })
         .unwrap_or_default();
 
-    let kind = action.category.to_str();
-    let mut kind = kind.into_owned();
-
-    if !matches!(action.category, ActionCategory::Source(_)) {
-        if let Some((group, rule)) = action.rule_name {
-            kind.push('.');
-            kind.push_str(group.as_ref());
-            kind.push('.');
-            kind.push_str(rule.as_ref());
-        }
-    }
+    let kind = code_action_kind(&action);
 
     let suggestion = action.suggestion;
 
@@ -166,6 +156,38 @@ pub(crate) fn code_fix_to_lsp(
     })
 }
 
+/// Returns the `CodeActionKind` of an action: the kind of its category, followed by the
+/// rule of the action, e.g. `quickfix.biome.suspicious.noDoubleEquals`, so the clients can
+/// request the actions of a single rule
+pub(crate) fn code_action_kind(action: &CodeAction) -> String {
+    let mut kind = action.category.to_str().into_owned();
+
+    if !matches!(action.category, ActionCategory::Source(_)) {
+        if let Some((group, rule)) = &action.rule_name {
+            kind.push('.');
+            kind.push_str(group.as_ref());
+            kind.push('.');
+            kind.push_str(rule.as_ref());
+        }
+    }
+
+    kind
+}
+
+/// Returns the `CodeActionKind` of an action of the project analysis, e.g.
+/// `refactor.rewrite.biome.project.convertToNamedExport`
+pub(crate) fn project_action_kind(action: &ProjectAction) -> String {
+    let (group, rule) = &action.rule_name;
+    format!("{}.{group}.{rule}", action.category.to_str())
+}
+
+/// Returns true if the `CodeActionKind` `kind` is `filter` or one of its sub-kinds, e.g.
+/// `source.fixAll.biome` matches `source` and `source.fixAll`, but not `source.fix`
+pub(crate) fn matches_code_action_kind(kind: &str, filter: &str) -> bool {
+    kind.strip_prefix(filter)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
 /// Convert an [biome_diagnostics::Diagnostic] to a [lsp::Diagnostic], using the span
 /// of the diagnostic's primary label as the diagnostic range.
 /// Requires a [LineIndex] to convert a byte offset range to the line/col range
@@ -207,8 +229,11 @@ pub(crate) fn diagnostic_to_lsp<D: Diagnostic>(
     let mut related_information = None;
     let mut visitor = RelatedInformationVisitor {
         url,
+        resource: location.resource,
+        span: location.span,
         line_index,
         position_encoding,
+        last_log: None,
         related_information: &mut related_information,
     };
 
@@ -248,18 +273,40 @@ pub(crate) fn diagnostic_to_lsp<D: Diagnostic>(
 
 struct RelatedInformationVisitor<'a> {
     url: &'a lsp::Url,
+    /// The file of the diagnostic, the frames of the other files are skipped
+    resource: Option<Resource<&'a str>>,
+    /// The range of the diagnostic, a label of this range isn't related information
+    span: Option<TextRange>,
     line_index: &'a LineIndex,
     position_encoding: PositionEncoding,
+    /// The last log recorded before a frame, e.g. `'a' is defined here:`, used as the
+    /// message of the related information of the frame
+    last_log: Option<String>,
     related_information: &'a mut Option<Vec<lsp::DiagnosticRelatedInformation>>,
 }
 
 impl Visit for RelatedInformationVisitor<'_> {
+    fn record_log(&mut self, _category: LogCategory, text: &dyn fmt::Display) -> io::Result<()> {
+        self.last_log = Some(print_markup(text));
+        Ok(())
+    }
+
     fn record_frame(&mut self, location: Location<'_>) -> io::Result<()> {
+        let message = self.last_log.take().unwrap_or_default();
+
         let span = match location.span {
             Some(span) => span,
             None => return Ok(()),
         };
 
+        if let (Some(Resource::File(file)), Some(Resource::File(own_file))) =
+            (&location.resource, &self.resource)
+        {
+            if file != own_file {
+                return Ok(());
+            }
+        }
+
         let range = match to_proto::range(self.line_index, span, self.position_encoding) {
             Ok(range) => range,
             Err(_) => return Ok(()),
@@ -272,15 +319,28 @@ impl Visit for RelatedInformationVisitor<'_> {
                 uri: self.url.clone(),
                 range,
             },
-            message: String::new(),
+            message: message.trim_end_matches(':').to_string(),
         });
 
         Ok(())
     }
+
+    fn record_labels(&mut self, labels: &[Label<'_>]) -> io::Result<()> {
+        for label in labels {
+            // The labels without a file are in the file of the diagnostic
+            let resource = label.location.resource.or(self.resource);
+            if resource == self.resource && label.location.span == self.span {
+                continue;
+            }
+            self.last_log = Some(print_markup(label.message));
+            self.record_frame(label.location)?;
+        }
+        Ok(())
+    }
 }
 
 /// Convert a piece of markup into a String
-fn print_markup(markup: &MarkupBuf) -> String {
+pub(crate) fn print_markup(markup: &dyn fmt::Display) -> String {
     let mut message = Termcolor(NoColor::new(Vec::new()));
     fmt::Display::fmt(markup, &mut Formatter::new(&mut message))
         // SAFETY: Writing to a memory buffer should never fail
@@ -376,7 +436,7 @@ pub(crate) fn apply_document_changes(
 #[cfg(test)]
 mod tests {
     use super::apply_document_changes;
-    use crate::converters::line_index::LineIndex;
+    use crate::converters::LineIndex;
     use crate::converters::{PositionEncoding, WideEncoding};
     use biome_text_edit::TextEdit;
     use tower_lsp::lsp_types as lsp;
@@ -3,11 +3,14 @@ use anyhow::Context;
 use anyhow::Error;
 use anyhow::Result;
 use biome_fs::RomePath;
+use biome_lsp::InitializationOptions;
 use biome_lsp::LSPServer;
 use biome_lsp::ServerFactory;
 use biome_lsp::WorkspaceSettings;
 use biome_service::workspace::GetSyntaxTreeResult;
-use biome_service::workspace::{GetFileContentParams, GetSyntaxTreeParams};
+use biome_service::workspace::{
+    GetFileContentParams, GetSyntaxTreeParams, ProjectsParams, ProjectsResult,
+};
 use futures::channel::mpsc::{channel, Sender};
 use futures::Sink;
 use futures::SinkExt;
@@ -15,7 +18,7 @@ use futures::Stream;
 use futures::StreamExt;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::{from_value, to_value};
+use serde_json::{from_value, json, to_value};
 use std::any::type_name;
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -142,28 +145,46 @@ impl Server {
     // The `root_path` field is deprecated, but we still need to specify it
     #[allow(deprecated)]
     async fn initialize(&mut self) -> Result<()> {
-        let _res: InitializeResult = self
-            .request(
-                "initialize",
-                "_init",
-                InitializeParams {
-                    process_id: None,
-                    root_path: None,
-                    root_uri: Some(url!("")),
-                    initialization_options: None,
-                    capabilities: ClientCapabilities::default(),
-                    trace: None,
-                    workspace_folders: None,
-                    client_info: None,
-                    locale: None,
-                },
-            )
-            .await?
-            .context("initialize returned None")?;
+        self.initialize_with_options(None).await?;
 
         Ok(())
     }
 
+    /// Sends the `initialize` request with the given `initializationOptions`
+    async fn initialize_with_options(
+        &mut self,
+        initialization_options: Option<InitializationOptions>,
+    ) -> Result<InitializeResult> {
+        self.initialize_with_root(url!(""), initialization_options)
+            .await
+    }
+
+    /// Sends the `initialize` request for the workspace at `root_uri`, with the given
+    /// `initializationOptions`
+    async fn initialize_with_root(
+        &mut self,
+        root_uri: Url,
+        initialization_options: Option<InitializationOptions>,
+    ) -> Result<InitializeResult> {
+        self.request(
+            "initialize",
+            "_init",
+            InitializeParams {
+                process_id: None,
+                root_path: None,
+                root_uri: Some(root_uri),
+                initialization_options: initialization_options.map(to_value).transpose()?,
+                capabilities: ClientCapabilities::default(),
+                trace: None,
+                workspace_folders: None,
+                client_info: None,
+                locale: None,
+            },
+        )
+        .await?
+        .context("initialize returned None")
+    }
+
     /// Basic implementation of the `initialized` notification for tests
     async fn initialized(&mut self) -> Result<()> {
         self.notify("initialized", InitializedParams {}).await
@@ -303,6 +324,7 @@ where
         let res = match req.method() {
             "workspace/configuration" => {
                 let settings = WorkspaceSettings {
+                    inlay_hints: Some(true),
                     ..WorkspaceSettings::default()
                 };
 
@@ -607,7 +629,7 @@ async fn pull_diagnostics() -> Result<()> {
                                 },
                             },
                         },
-                        message: String::new(),
+                        message: String::from("== is only allowed when comparing against null"),
                     }]),
                     tags: None,
                     data: None,
@@ -624,6 +646,84 @@ async fn pull_diagnostics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn pull_document_diagnostics() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a == b) {}").await?;
+
+    let params = |previous_result_id: Option<String>| lsp::DocumentDiagnosticParams {
+        text_document: lsp::TextDocumentIdentifier {
+            uri: url!("document.js"),
+        },
+        identifier: None,
+        previous_result_id,
+        work_done_progress_params: lsp::WorkDoneProgressParams {
+            work_done_token: None,
+        },
+        partial_result_params: lsp::PartialResultParams {
+            partial_result_token: None,
+        },
+    };
+
+    let report: lsp::DocumentDiagnosticReportResult = server
+        .request("textDocument/diagnostic", "pull_diagnostics", params(None))
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Full(report)) =
+        report
+    else {
+        bail!("expected a full report, got {report:?}");
+    };
+    let report = report.full_document_diagnostic_report;
+    assert_eq!(report.items.len(), 1);
+    assert_eq!(
+        report.items[0].code,
+        Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDoubleEquals"
+        )))
+    );
+    let result_id = report.result_id.context("the report has no result id")?;
+
+    let report: lsp::DocumentDiagnosticReportResult = server
+        .request(
+            "textDocument/diagnostic",
+            "pull_diagnostics_again",
+            params(Some(result_id.clone())),
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+
+    assert_eq!(
+        report,
+        lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Unchanged(
+            lsp::RelatedUnchangedDocumentDiagnosticReport {
+                related_documents: None,
+                unchanged_document_diagnostic_report: lsp::UnchangedDocumentDiagnosticReport {
+                    result_id
+                },
+            }
+        ))
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 fn fixable_diagnostic(line: u32) -> Result<lsp::Diagnostic> {
     Ok(lsp::Diagnostic {
         range: lsp::Range {
@@ -953,6 +1053,90 @@ async fn pull_biome_quick_fixes() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn pull_quick_fixes_of_a_rule() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a === -0) {}").await?;
+
+    let params = |kind: &str| lsp::CodeActionParams {
+        text_document: lsp::TextDocumentIdentifier {
+            uri: url!("document.js"),
+        },
+        range: lsp::Range {
+            start: lsp::Position {
+                line: 0,
+                character: 6,
+            },
+            end: lsp::Position {
+                line: 0,
+                character: 10,
+            },
+        },
+        context: lsp::CodeActionContext {
+            diagnostics: vec![fixable_diagnostic(0).unwrap()],
+            only: Some(vec![lsp::CodeActionKind::new(kind)]),
+            ..Default::default()
+        },
+        work_done_progress_params: lsp::WorkDoneProgressParams {
+            work_done_token: None,
+        },
+        partial_result_params: lsp::PartialResultParams {
+            partial_result_token: None,
+        },
+    };
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            params("quickfix.biome.suspicious.noCompareNegZero"),
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let kinds: Vec<_> = res
+        .iter()
+        .filter_map(|action| match action {
+            lsp::CodeActionOrCommand::CodeAction(action) => action.kind.clone(),
+            lsp::CodeActionOrCommand::Command(_) => None,
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![lsp::CodeActionKind::new(
+            "quickfix.biome.suspicious.noCompareNegZero"
+        )]
+    );
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            params("quickfix.biome.suspicious.noCompare"),
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    assert_eq!(res, vec![]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pull_quick_fixes_include_unsafe() -> Result<()> {
     let factory = ServerFactory::default();
@@ -1085,7 +1269,7 @@ async fn pull_quick_fixes_include_unsafe() -> Result<()> {
         kind: Some(lsp::CodeActionKind::new(
             "quickfix.suppressRule.biome.suspicious.noDoubleEquals",
         )),
-        diagnostics: Some(vec![unsafe_fixable]),
+        diagnostics: Some(vec![unsafe_fixable.clone()]),
         edit: Some(lsp::WorkspaceEdit {
             changes: Some(suppression_changes),
             document_changes: None,
@@ -1097,7 +1281,34 @@ async fn pull_quick_fixes_include_unsafe() -> Result<()> {
         data: None,
     });
 
-    assert_eq!(res, vec![expected_code_action, expected_suppression_action]);
+    // The fixes of the rule in the whole workspace are computed by a command
+    let title = String::from("Fix all the issues of suspicious/noDoubleEquals in the workspace");
+    let expected_workspace_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: title.clone(),
+        kind: Some(lsp::CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![unsafe_fixable]),
+        edit: None,
+        command: Some(lsp::Command {
+            title,
+            command: String::from("biome.fixAllInWorkspace"),
+            arguments: Some(vec![
+                json!("suspicious/noDoubleEquals"),
+                json!("SafeAndUnsafeFixes"),
+            ]),
+        }),
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(
+        res,
+        vec![
+            expected_code_action,
+            expected_suppression_action,
+            expected_workspace_action
+        ]
+    );
 
     server.close_document().await?;
 
@@ -1524,10 +1735,10 @@ async fn pull_refactors() -> Result<()> {
         ],
     );
 
-    let _expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
-        title: String::from("Inline variable"),
+    let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Inline the variable variable."),
         kind: Some(lsp::CodeActionKind::new(
-            "refactor.inline.biome.correctness.inlineVariable",
+            "refactor.inline.biome.refactor.inlineVariable",
         )),
         diagnostics: None,
         edit: Some(lsp::WorkspaceEdit {
@@ -1536,12 +1747,113 @@ async fn pull_refactors() -> Result<()> {
             change_annotations: None,
         }),
         command: None,
-        is_preferred: Some(true),
+        is_preferred: None,
         disabled: None,
         data: None,
     });
 
-    assert_eq!(res, vec![]);
+    assert_eq!(res, vec![expected_action]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_project_refactors() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("export default function greet() {}\n")
+        .await?;
+    server
+        .open_named_document(
+            "import greet from \"./document\";\ngreet();\n",
+            url!("main.js"),
+            "javascript",
+        )
+        .await?;
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: lsp::TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: lsp::Range {
+                    start: lsp::Position {
+                        line: 0,
+                        character: 26,
+                    },
+                    end: lsp::Position {
+                        line: 0,
+                        character: 26,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![],
+                    only: Some(vec![lsp::CodeActionKind::REFACTOR_REWRITE]),
+                    ..Default::default()
+                },
+                work_done_progress_params: lsp::WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let mut changes = HashMap::default();
+    changes.insert(
+        url!("document.js"),
+        vec![lsp::TextEdit {
+            range: range((0, 7), (0, 15)),
+            new_text: String::new(),
+        }],
+    );
+    changes.insert(
+        url!("main.js"),
+        vec![lsp::TextEdit {
+            range: range((0, 7), (0, 12)),
+            new_text: String::from("{ greet }"),
+        }],
+    );
+
+    let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Convert to a named export."),
+        kind: Some(lsp::CodeActionKind::new(
+            "refactor.rewrite.biome.project.convertToNamedExport",
+        )),
+        diagnostics: None,
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(res, vec![expected_action]);
 
     server.close_document().await?;
 
@@ -1721,6 +2033,79 @@ isSpreadAssignment;
     Ok(())
 }
 
+#[tokio::test]
+async fn format_on_type() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("statement( );\nconst  x  =  1;\nif(a){b()}\n")
+        .await?;
+
+    let params = |line: u32, character: u32, ch: &str| lsp::DocumentOnTypeFormattingParams {
+        text_document_position: lsp::TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier {
+                uri: url!("document.js"),
+            },
+            position: Position { line, character },
+        },
+        ch: String::from(ch),
+        options: FormattingOptions {
+            tab_size: 4,
+            insert_spaces: false,
+            properties: HashMap::default(),
+            trim_trailing_whitespace: None,
+            insert_final_newline: None,
+            trim_final_newlines: None,
+        },
+    };
+
+    // Only the statement completed by `;` is formatted
+    let res: Option<Vec<TextEdit>> = server
+        .request(
+            "textDocument/onTypeFormatting",
+            "on_type_formatting",
+            params(1, 15, ";"),
+        )
+        .await?
+        .context("onTypeFormatting returned None")?;
+
+    let edits = res.context("onTypeFormatting did not return an edit list")?;
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].range.start.line, 1);
+    assert_eq!(edits[0].new_text.trim(), "const x = 1;");
+
+    // After a newline, the statement of the previous line is formatted
+    let res: Option<Vec<TextEdit>> = server
+        .request(
+            "textDocument/onTypeFormatting",
+            "on_type_formatting",
+            params(3, 0, "\n"),
+        )
+        .await?
+        .context("onTypeFormatting returned None")?;
+
+    let edits = res.context("onTypeFormatting did not return an edit list")?;
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].range.start.line, 2);
+    assert_eq!(edits[0].new_text.trim(), "if (a) {\n\tb();\n}");
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn format_with_syntax_errors() -> Result<()> {
     let factory = ServerFactory::default();
@@ -1770,6 +2155,145 @@ async fn format_with_syntax_errors() -> Result<()> {
     Ok(())
 }
 
+const SYMBOLS_DOCUMENT: &str = "class Store {\n    load() {}\n}\nfunction main() {}\n";
+
+fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+    Range {
+        start: Position::new(start.0, start.1),
+        end: Position::new(end.0, end.1),
+    }
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn document_symbols() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document(SYMBOLS_DOCUMENT).await?;
+
+    let res: Option<lsp::DocumentSymbolResponse> = server
+        .request(
+            "textDocument/documentSymbol",
+            "document_symbol",
+            lsp::DocumentSymbolParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("documentSymbol returned None")?;
+
+    let method = lsp::DocumentSymbol {
+        name: String::from("load"),
+        detail: None,
+        kind: lsp::SymbolKind::METHOD,
+        tags: None,
+        deprecated: None,
+        range: range((1, 4), (1, 13)),
+        selection_range: range((1, 4), (1, 8)),
+        children: None,
+    };
+    let expected = lsp::DocumentSymbolResponse::Nested(vec![
+        lsp::DocumentSymbol {
+            name: String::from("Store"),
+            detail: None,
+            kind: lsp::SymbolKind::CLASS,
+            tags: None,
+            deprecated: None,
+            range: range((0, 0), (2, 1)),
+            selection_range: range((0, 6), (0, 11)),
+            children: Some(vec![method]),
+        },
+        lsp::DocumentSymbol {
+            name: String::from("main"),
+            detail: None,
+            kind: lsp::SymbolKind::FUNCTION,
+            tags: None,
+            deprecated: None,
+            range: range((3, 0), (3, 18)),
+            selection_range: range((3, 9), (3, 13)),
+            children: None,
+        },
+    ]);
+
+    assert_eq!(res, Some(expected));
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn workspace_symbols() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document(SYMBOLS_DOCUMENT).await?;
+
+    let res: Option<Vec<lsp::SymbolInformation>> = server
+        .request(
+            "workspace/symbol",
+            "symbol",
+            lsp::WorkspaceSymbolParams {
+                query: String::from("lod"),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("symbol returned None")?;
+
+    let expected = vec![lsp::SymbolInformation {
+        name: String::from("load"),
+        kind: lsp::SymbolKind::METHOD,
+        tags: None,
+        deprecated: None,
+        location: lsp::Location::new(url!("document.js"), range((1, 4), (1, 13))),
+        container_name: Some(String::from("Store")),
+    }];
+
+    assert_eq!(res, Some(expected));
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn server_shutdown() -> Result<()> {
     let factory = ServerFactory::default();
@@ -1794,3 +2318,284 @@ async fn server_shutdown() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn lists_the_projects_of_the_other_sessions() -> Result<()> {
+    let factory = ServerFactory::default();
+
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut editor = Server::new(service);
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let editor_reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut cli = Server::new(service);
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let cli_reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    editor.initialize().await?;
+    editor.initialized().await?;
+    editor.open_document("statement();").await?;
+
+    cli.initialize().await?;
+
+    let result: ProjectsResult = cli
+        .request("workspace/projects", "projects", ProjectsParams {})
+        .await?
+        .context("workspace/projects returned None")?;
+
+    assert_eq!(result.projects.len(), 1);
+    let project = &result.projects[0];
+    assert_eq!(project.root, Some(url!("").to_file_path().unwrap()));
+    assert_eq!(project.sessions.len(), 1);
+    assert_eq!(project.sessions[0].open_documents, 1);
+
+    editor.shutdown().await?;
+    cli.shutdown().await?;
+    editor_reader.abort();
+    cli_reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn untrusted_workspace() -> Result<()> {
+    // The workspace is on disk: its configuration extends a file that disables the linter, and
+    // a file that isn't open imports the open document
+    let root = std::env::temp_dir().join(format!("biome_lsp_untrusted_{}", std::process::id()));
+    std::fs::create_dir_all(&root)?;
+    std::fs::write(root.join("biome.json"), r#"{ "extends": ["./base.json"] }"#)?;
+    std::fs::write(
+        root.join("base.json"),
+        r#"{ "linter": { "enabled": false } }"#,
+    )?;
+    std::fs::write(
+        root.join("b.js"),
+        "import { a } from \"./a.js\";\nexport const b = a;\n",
+    )?;
+    let root_uri = Url::from_directory_path(&root).unwrap();
+    let document_uri = root_uri.join("a.js")?;
+
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    let result = server
+        .initialize_with_root(
+            root_uri,
+            Some(InitializationOptions {
+                workspace_trusted: Some(false),
+            }),
+        )
+        .await?;
+    assert_eq!(
+        result.capabilities.experimental,
+        Some(serde_json::json!({ "untrustedWorkspace": true }))
+    );
+
+    server.initialized().await?;
+
+    server
+        .open_named_document(
+            "import { b } from \"./b.js\";\nexport const a = b;\nif (a == b) {}\n",
+            document_uri.clone(),
+            "javascript",
+        )
+        .await?;
+
+    let notification = tokio::select! {
+        msg = receiver.next() => msg,
+        _ = sleep(Duration::from_secs(1)) => {
+            panic!("timed out waiting for the server to send diagnostics")
+        }
+    };
+    let Some(ServerNotification::PublishDiagnostics(params)) = notification else {
+        bail!("expected the diagnostics of the document, got {notification:?}");
+    };
+    assert_eq!(params.uri, document_uri);
+    let codes: Vec<_> = params
+        .diagnostics
+        .iter()
+        .filter_map(|diagnostic| match &diagnostic.code {
+            Some(lsp::NumberOrString::String(code)) => Some(code.as_str()),
+            _ => None,
+        })
+        .collect();
+    // The `extends` of the configuration isn't followed, so the linter is still enabled
+    assert!(
+        codes.contains(&"lint/suspicious/noDoubleEquals"),
+        "{codes:?}"
+    );
+    // The workspace isn't indexed, so the import cycle through `b.js` is never found
+    assert!(!codes.contains(&"project/importCycle"), "{codes:?}");
+    tokio::select! {
+        msg = receiver.next() => {
+            panic!("the diagnostics changed without an edit, the workspace was indexed: {msg:?}")
+        }
+        _ = sleep(Duration::from_secs(1)) => {}
+    };
+
+    server
+        .notify(
+            "textDocument/didClose",
+            DidCloseTextDocumentParams {
+                text_document: TextDocumentIdentifier { uri: document_uri },
+            },
+        )
+        .await?;
+
+    server.shutdown().await?;
+    reader.abort();
+    std::fs::remove_dir_all(&root)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn trusted_workspace_by_default() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    let result = server.initialize_with_options(None).await?;
+    assert_eq!(result.capabilities.experimental, None);
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn explain_file() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if (a == b) {}").await?;
+
+    let res: serde_json::Value = server
+        .request(
+            "biome/explainFile",
+            "explain_file",
+            serde_json::json!({
+                "textDocument": TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+            }),
+        )
+        .await?
+        .context("explainFile returned None")?;
+
+    assert_eq!(res["configurationFiles"], serde_json::json!([]));
+    assert_eq!(
+        res["features"],
+        serde_json::json!([
+            { "feature": "Format", "support": "Supported" },
+            { "feature": "Lint", "support": "Supported" },
+            { "feature": "OrganizeImports", "support": "Supported" },
+        ])
+    );
+
+    let rules = res["rules"]
+        .as_array()
+        .context("explainFile did not return a list of rules")?;
+    assert!(rules.contains(&serde_json::json!({
+        "group": "suspicious",
+        "rule": "noDoubleEquals",
+        "severity": "error",
+    })));
+    assert!(!rules
+        .iter()
+        .any(|rule| rule["rule"] == "useNamingConvention"));
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inlay_hints() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            r#"function connect(url, retries) {}
+connect("localhost", 3);
+connect(url, 3);
+undeclared(1);
+"#,
+        )
+        .await?;
+
+    let res: Option<Vec<lsp::InlayHint>> = server
+        .request(
+            "textDocument/inlayHint",
+            "inlay_hint",
+            lsp::InlayHintParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: range((0, 0), (4, 0)),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("inlayHint returned None")?;
+
+    let hints: Vec<_> = res
+        .context("inlayHint did not return a list of hints")?
+        .into_iter()
+        .map(|hint| match hint.label {
+            lsp::InlayHintLabel::String(label) => (hint.position, label),
+            lsp::InlayHintLabel::LabelParts(_) => panic!("expected a string label"),
+        })
+        .collect();
+
+    assert_eq!(
+        hints,
+        vec![
+            (Position::new(1, 8), String::from("url:")),
+            (Position::new(1, 21), String::from("retries:")),
+            (Position::new(2, 13), String::from("retries:")),
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
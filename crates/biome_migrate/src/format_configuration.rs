@@ -0,0 +1,394 @@
+use biome_json_factory::make::{json_array_element_list, json_member_list, token};
+use biome_json_syntax::{
+    AnyJsonValue, JsonArrayValue, JsonMember, JsonObjectValue, JsonRoot, JsonSyntaxToken, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// The sections of the configuration, in their canonical order. The sections that Biome doesn't
+/// know follow them, in their original order.
+const SECTIONS: &[&str] = &[
+    "$schema",
+    "extends",
+    "vcs",
+    "files",
+    "formatter",
+    "organizeImports",
+    "linter",
+    "javascript",
+    "json",
+    "project",
+    "overrides",
+    "profiles",
+];
+
+/// The options of a group of rules, which precede its rules
+const GROUP_OPTIONS: &[&str] = &["recommended", "all", "level"];
+
+/// The options removed when they are set to their default value, with the text of this value
+const DEFAULT_VALUES: &[(&[&str], &str)] = &[
+    (&["files", "maxSize"], "1048576"),
+    (&["files", "ignoreUnknown"], "false"),
+    (&["formatter", "enabled"], "true"),
+    (&["formatter", "formatWithErrors"], "false"),
+    (&["formatter", "indentStyle"], "\"tab\""),
+    (&["formatter", "indentWidth"], "2"),
+    (&["formatter", "lineEnding"], "\"lf\""),
+    (&["formatter", "lineWidth"], "80"),
+    (&["organizeImports", "enabled"], "true"),
+    (&["linter", "enabled"], "true"),
+    (&["javascript", "formatter", "quoteStyle"], "\"double\""),
+    (&["javascript", "formatter", "jsxQuoteStyle"], "\"double\""),
+    (
+        &["javascript", "formatter", "quoteProperties"],
+        "\"asNeeded\"",
+    ),
+    (&["javascript", "formatter", "trailingComma"], "\"all\""),
+    (&["javascript", "formatter", "semicolons"], "\"always\""),
+    (
+        &["javascript", "formatter", "arrowParentheses"],
+        "\"always\"",
+    ),
+    (&["javascript", "formatter", "bracketSpacing"], "true"),
+    (&["javascript", "formatter", "bracketSameLine"], "false"),
+];
+
+/// Returns the canonical form of the configuration file `root`:
+/// - the sections are sorted in the order of the documentation, `$schema` first;
+/// - the rules of each group are sorted by name, after the options of the group;
+/// - the options set to their default value are removed, with the sections that become empty.
+///
+/// The comments move with the members they are attached to, and an option that has a comment
+/// is never removed. The defaults are kept when the configuration extends other files, because
+/// they override the options of these files. For the same reason, the defaults of `overrides`
+/// and `profiles` are kept, and the options of the formatter that `.editorconfig` can set are
+/// kept when `formatter.useEditorconfig` is enabled.
+pub fn format_configuration(root: &JsonRoot) -> JsonRoot {
+    let Ok(AnyJsonValue::JsonObjectValue(object)) = root.value() else {
+        return root.clone();
+    };
+    let extends = object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .any(|member| member_name(&member).as_deref() == Some("extends"));
+    let formatted = format_object(&object, &[], !extends);
+    if formatted.syntax() == object.syntax() {
+        return root.clone();
+    }
+    root.clone()
+        .with_value(AnyJsonValue::JsonObjectValue(formatted))
+}
+
+/// A member of an object, with the comma that follows it
+struct Entry {
+    name: String,
+    member: JsonMember,
+    separator: Option<JsonSyntaxToken>,
+    /// The position of the member in the original object
+    index: usize,
+}
+
+fn format_object(
+    object: &JsonObjectValue,
+    path: &[&str],
+    removes_defaults: bool,
+) -> JsonObjectValue {
+    let list = object.json_member_list();
+    let mut entries = Vec::with_capacity(list.len());
+    for (index, element) in list.elements().enumerate() {
+        // A bogus member can't be moved safely
+        let (Ok(member), Ok(separator)) = (element.node(), element.trailing_separator()) else {
+            return object.clone();
+        };
+        let Some(name) = member_name(member) else {
+            return object.clone();
+        };
+        entries.push(Entry {
+            name,
+            member: member.clone(),
+            separator: separator.cloned(),
+            index,
+        });
+    }
+
+    // `.editorconfig` only sets the options that the configuration doesn't set
+    let removes_defaults = removes_defaults
+        && !(path == ["formatter"]
+            && entries.iter().any(|entry| {
+                entry.name == "useEditorconfig" && value_text_is(&entry.member, "true")
+            }));
+
+    let mut changed = false;
+    let mut formatted_entries = Vec::with_capacity(entries.len());
+    for mut entry in entries {
+        let has_comments = entry.member.syntax().has_comments_descendants()
+            || entry
+                .separator
+                .as_ref()
+                .is_some_and(|separator| separator.has_trailing_comments());
+        let member_path: Vec<&str> = path.iter().copied().chain([entry.name.as_str()]).collect();
+        if removes_defaults && !has_comments && is_default_value(&member_path, &entry.member) {
+            changed = true;
+            continue;
+        }
+        let Ok(value) = entry.member.value() else {
+            formatted_entries.push(entry);
+            continue;
+        };
+        let formatted_value = format_value(&value, &member_path, removes_defaults);
+        if formatted_value.syntax() == value.syntax() {
+            formatted_entries.push(entry);
+            continue;
+        }
+        changed = true;
+        // The sections whose options were all removed are removed too
+        let is_empty_object = matches!(
+            &formatted_value,
+            AnyJsonValue::JsonObjectValue(object) if object.json_member_list().is_empty()
+        );
+        if is_empty_object && !has_comments {
+            continue;
+        }
+        entry.member = entry.member.with_value(formatted_value);
+        formatted_entries.push(entry);
+    }
+
+    formatted_entries
+        .sort_by(|left, right| sort_key(path, &left.name).cmp(&sort_key(path, &right.name)));
+    changed |= formatted_entries
+        .windows(2)
+        .any(|entries| entries[0].index > entries[1].index);
+    if !changed {
+        return object.clone();
+    }
+
+    let has_trailing_separator = list.trailing_separator().is_some();
+    let last = formatted_entries.len().saturating_sub(1);
+    let mut members = Vec::with_capacity(formatted_entries.len());
+    let mut separators = Vec::with_capacity(formatted_entries.len());
+    for (position, entry) in formatted_entries.into_iter().enumerate() {
+        let needs_separator = position < last || has_trailing_separator;
+        match (entry.separator, needs_separator) {
+            (Some(separator), true) => {
+                members.push(entry.member);
+                separators.push(separator);
+            }
+            (Some(separator), false) => {
+                // The comments that followed the comma now follow the member
+                let trivia: Vec<_> = separator.trailing_trivia().pieces().collect();
+                members.push(
+                    entry
+                        .member
+                        .clone()
+                        .append_trivia_pieces(trivia)
+                        .unwrap_or(entry.member),
+                );
+            }
+            (None, true) => {
+                // The comments that followed the member now follow the comma
+                let trivia: Vec<_> = entry
+                    .member
+                    .syntax()
+                    .last_token()
+                    .map(|last_token| last_token.trailing_trivia().pieces().collect())
+                    .unwrap_or_default();
+                members.push(
+                    entry
+                        .member
+                        .clone()
+                        .with_trailing_trivia_pieces([])
+                        .unwrap_or(entry.member),
+                );
+                separators.push(token(T![,]).with_trailing_trivia_pieces(trivia));
+            }
+            (None, false) => members.push(entry.member),
+        }
+    }
+    object
+        .clone()
+        .with_json_member_list(Some(json_member_list(members, separators)))
+}
+
+fn format_value(value: &AnyJsonValue, path: &[&str], removes_defaults: bool) -> AnyJsonValue {
+    match value {
+        AnyJsonValue::JsonObjectValue(object) => {
+            AnyJsonValue::JsonObjectValue(format_object(object, path, removes_defaults))
+        }
+        AnyJsonValue::JsonArrayValue(array) if path == ["overrides"] => {
+            AnyJsonValue::JsonArrayValue(format_overrides(array))
+        }
+        _ => value.clone(),
+    }
+}
+
+/// Sorts the rules of each override. Its options are kept, even when they are set to their
+/// default value, because they override the options of the configuration.
+fn format_overrides(array: &JsonArrayValue) -> JsonArrayValue {
+    let mut changed = false;
+    let mut values = vec![];
+    let mut separators = vec![];
+    for element in array.elements().elements() {
+        let (Ok(value), Ok(separator)) = (element.node(), element.trailing_separator()) else {
+            return array.clone();
+        };
+        let formatted = format_value(value, &["overrides", "*"], false);
+        changed |= formatted.syntax() != value.syntax();
+        values.push(formatted);
+        separators.extend(separator.cloned());
+    }
+    if !changed {
+        return array.clone();
+    }
+    array
+        .clone()
+        .with_elements(Some(json_array_element_list(values, separators)))
+}
+
+/// The key of a member when sorting the members of the object at `path`. The objects that
+/// aren't sorted have the same key for all their members.
+fn sort_key<'a>(path: &[&str], name: &'a str) -> (usize, &'a str) {
+    match path {
+        [] => (
+            SECTIONS
+                .iter()
+                .position(|section| *section == name)
+                .unwrap_or(SECTIONS.len()),
+            "",
+        ),
+        [.., "linter", "rules", _] => match GROUP_OPTIONS.iter().position(|option| *option == name)
+        {
+            Some(position) => (position, ""),
+            None => (GROUP_OPTIONS.len(), name),
+        },
+        _ => (0, ""),
+    }
+}
+
+fn is_default_value(path: &[&str], member: &JsonMember) -> bool {
+    DEFAULT_VALUES
+        .iter()
+        .find(|(default_path, _)| *default_path == path)
+        .is_some_and(|(_, default_value)| value_text_is(member, default_value))
+}
+
+fn value_text_is(member: &JsonMember, text: &str) -> bool {
+    member
+        .value()
+        .is_ok_and(|value| value.syntax().text_trimmed() == text)
+}
+
+fn member_name(member: &JsonMember) -> Option<String> {
+    let name = member.name().ok()?.inner_string_text().ok()?;
+    Some(name.text().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_configuration;
+    use biome_json_parser::{parse_json, JsonParserOptions};
+
+    fn format(source: &str) -> String {
+        let parsed = parse_json(source, JsonParserOptions::default().with_allow_comments());
+        assert!(!parsed.has_errors(), "{source}");
+        format_configuration(&parsed.tree()).to_string()
+    }
+
+    #[test]
+    fn sorts_the_sections() {
+        assert_eq!(
+            format(
+                r#"{
+	"linter": { "enabled": false },
+	"unknown": {},
+	"formatter": { "enabled": false },
+	"$schema": "./schema.json"
+}"#
+            ),
+            r#"{
+	"$schema": "./schema.json",
+	"formatter": { "enabled": false },
+	"linter": { "enabled": false },
+	"unknown": {}
+}"#
+        );
+    }
+
+    #[test]
+    fn sorts_the_rules_of_the_groups() {
+        assert_eq!(
+            format(
+                r#"{
+	"linter": {
+		"rules": {
+			"style": { "useConst": "off", "noVar": "error", "level": "warn", "recommended": false }
+		}
+	},
+	"overrides": [
+		{ "linter": { "rules": { "style": { "useConst": "off", "noVar": "off" } } } }
+	]
+}"#
+            ),
+            r#"{
+	"linter": {
+		"rules": {
+			"style": { "recommended": false, "level": "warn", "noVar": "error", "useConst": "off" }
+		}
+	},
+	"overrides": [
+		{ "linter": { "rules": { "style": { "noVar": "off", "useConst": "off" } } } }
+	]
+}"#
+        );
+    }
+
+    #[test]
+    fn removes_the_defaults() {
+        assert_eq!(
+            format(
+                r#"{
+	"formatter": { "enabled": true, "lineWidth": 80 },
+	"linter": { "enabled": true, "rules": { "recommended": true } },
+	"overrides": [{ "include": ["*.ts"], "formatter": { "lineWidth": 80 } }]
+}"#
+            ),
+            r#"{
+	"linter": { "rules": { "recommended": true } },
+	"overrides": [{ "include": ["*.ts"], "formatter": { "lineWidth": 80 } }]
+}"#
+        );
+    }
+
+    #[test]
+    fn keeps_the_defaults_of_an_extending_configuration() {
+        let source = r#"{
+	"extends": ["./base.json"],
+	"formatter": { "lineWidth": 80 }
+}"#;
+        assert_eq!(format(source), source);
+    }
+
+    #[test]
+    fn keeps_the_comments() {
+        assert_eq!(
+            format(
+                r#"{
+	"linter": { "enabled": false }, // lint nothing
+	// The formatter of the team
+	"formatter": {
+		// Keep it, the editors disagree
+		"lineWidth": 80,
+		"indentWidth": 2
+	}
+}"#
+            ),
+            r#"{
+	// The formatter of the team
+	"formatter": {
+		// Keep it, the editors disagree
+		"lineWidth": 80
+	},
+	"linter": { "enabled": false } // lint nothing
+}"#
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod analyzers;
+mod format_configuration;
 mod macros;
 mod registry;
 
@@ -10,6 +11,7 @@ use biome_analyze::{
 };
 use biome_diagnostics::Error;
 use biome_json_syntax::JsonLanguage;
+pub use format_configuration::format_configuration;
 use std::convert::Infallible;
 use std::path::{Path, PathBuf};
 
@@ -1,6 +1,6 @@
 mod package_json;
 
-pub use crate::node_js_project::package_json::PackageJson;
+pub use crate::node_js_project::package_json::{PackageJson, PackageTarget};
 use crate::{Manifest, Project, ProjectAnalyzeDiagnostic, ProjectAnalyzeResult, LICENSE_LIST};
 use biome_diagnostics::Error;
 use biome_json_syntax::JsonRoot;
@@ -1,23 +1,65 @@
 use crate::{LanguageRoot, Manifest};
-use biome_deserialize::json::deserialize_from_json_ast;
+use biome_deserialize::json::{deserialize_from_json_ast, deserialize_from_json_str};
 use biome_deserialize::{
     Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
     Deserialized, Text, VisitableType,
 };
+use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
 use biome_text_size::{TextRange, TextSize};
 use rustc_hash::FxHashMap;
 use std::ops::Add;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct PackageJson {
     pub version: Option<Version>,
+    /// The name of the package, also used when the package imports itself
     pub name: Option<String>,
     pub description: Option<String>,
     pub dependencies: Dependencies,
     pub dev_dependencies: Dependencies,
+    pub peer_dependencies: Dependencies,
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
+    /// The entry point of the package, used when it doesn't have `exports`
+    pub main: Option<String>,
+    /// The entry point of the types of the package, `typings` is an alias
+    pub types: Option<String>,
+    /// The entry points of the package: a path, a list of paths, or a map of the
+    /// subpaths and the conditions to their targets
+    pub exports: Option<PackageTarget>,
+    /// The private mappings of the package, whose keys start with `#`
+    pub imports: Option<PackageTarget>,
+    /// The range of the versions of Node.js that the package supports, from the `node` field of
+    /// `engines`, e.g. `>=18.0.0`
+    pub node_engine: Option<String>,
+    /// The queries of the browsers that the package supports, from the `browserslist` field,
+    /// e.g. `chrome >= 90`. The queries of a map of environments are the ones of `production`.
+    pub browserslist: Vec<String>,
+}
+
+impl PackageJson {
+    /// Parses the content of a `package.json` file. It returns `None` when the
+    /// content isn't a JSON object.
+    ///
+    /// The fields that can't be deserialized are ignored, e.g. the resolution of the imports
+    /// is best-effort.
+    pub fn parse(content: &str) -> Option<Self> {
+        deserialize_from_json_str(content, JsonParserOptions::default()).into_deserialized()
+    }
+
+    /// The names of the packages declared in `dependencies`, `devDependencies`,
+    /// `peerDependencies` and `optionalDependencies`
+    pub fn dependency_names(&self) -> impl Iterator<Item = &str> {
+        [
+            &self.dependencies,
+            &self.dev_dependencies,
+            &self.peer_dependencies,
+            &self.optional_dependencies,
+        ]
+        .into_iter()
+        .flat_map(|dependencies| dependencies.0.keys().map(String::as_str))
+    }
 }
 
 impl Manifest for PackageJson {
@@ -28,10 +70,12 @@ impl Manifest for PackageJson {
     }
 }
 
-#[derive(Debug, Default)]
-pub struct Dependencies(FxHashMap<String, Version>);
+/// The packages of a field like `dependencies`. A package whose version can't be parsed is
+/// kept, without its version.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Dependencies(FxHashMap<String, Option<Version>>);
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Version(node_semver::Version);
 
 impl Deserializable for PackageJson {
@@ -97,6 +141,35 @@ impl DeserializationVisitor for PackageJsonVisitor {
                         result.optional_dependencies = deps;
                     }
                 }
+                "peerDependencies" => {
+                    if let Some(deps) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.peer_dependencies = deps;
+                    }
+                }
+                "main" => {
+                    result.main = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "types" | "typings" => {
+                    result.types = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "imports" => {
+                    result.imports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "engines" => {
+                    result.node_engine =
+                        value.deserialize(NodeEngineVisitor, &key_text, diagnostics);
+                }
+                "browserslist" => {
+                    if let Some(queries) =
+                        value.deserialize(BrowserslistVisitor, &key_text, diagnostics)
+                    {
+                        result.browserslist = queries;
+                    }
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Rome deems important
@@ -113,11 +186,32 @@ impl Deserializable for Dependencies {
         name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self> {
-        Some(Dependencies(Deserializable::deserialize(
-            value,
-            name,
-            diagnostics,
-        )?))
+        value.deserialize(DependenciesVisitor, name, diagnostics)
+    }
+}
+
+struct DependenciesVisitor;
+impl DeserializationVisitor for DependenciesVisitor {
+    type Output = Dependencies;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = FxHashMap::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            let version = Deserializable::deserialize(&value, &key_text, diagnostics);
+            result.insert(key_text.text().to_string(), version);
+        }
+        Some(Dependencies(result))
     }
 }
 
@@ -146,3 +240,252 @@ impl Deserializable for Version {
         }
     }
 }
+
+/// Extracts the range of the versions of Node.js from the `engines` field. The other engines,
+/// e.g. `npm`, aren't used.
+struct NodeEngineVisitor;
+impl DeserializationVisitor for NodeEngineVisitor {
+    type Output = String;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        members.flatten().find_map(|(key, value)| {
+            let key_text = Text::deserialize(&key, "", diagnostics)?;
+            if key_text.text() == "node" {
+                Deserializable::deserialize(&value, &key_text, diagnostics)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Extracts the queries of the `browserslist` field: a string of queries separated by commas, a
+/// list of queries, or a map of the environments to their queries. Browserslist uses the
+/// `production` environment by default.
+struct BrowserslistVisitor;
+impl DeserializationVisitor for BrowserslistVisitor {
+    type Output = Vec<String>;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::STR
+        .union(VisitableType::ARRAY)
+        .union(VisitableType::MAP);
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(
+            value
+                .text()
+                .split(',')
+                .map(str::trim)
+                .filter(|query| !query.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(
+            items
+                .flatten()
+                .filter_map(|item| item.deserialize(BrowserslistVisitor, name, diagnostics))
+                .flatten()
+                .collect(),
+        )
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        members.flatten().find_map(|(key, value)| {
+            let key_text = Text::deserialize(&key, "", diagnostics)?;
+            if key_text.text() == "production" {
+                value.deserialize(BrowserslistVisitor, &key_text, diagnostics)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// A target of the `exports` or the `imports` field of a `package.json`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PackageTarget {
+    /// The path of a file of the package, e.g. `./dist/index.js`
+    Path(String),
+    /// The subpaths or the conditions mapped to their targets. The order of the
+    /// entries is kept, the first condition that matches wins.
+    Map(Vec<(String, PackageTarget)>),
+    /// The first target that can be resolved is used
+    List(Vec<PackageTarget>),
+    /// Excludes a subpath from the package
+    Null,
+}
+
+impl Deserializable for PackageTarget {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageTargetVisitor, name, diagnostics)
+    }
+}
+
+struct PackageTargetVisitor;
+impl DeserializationVisitor for PackageTargetVisitor {
+    type Output = PackageTarget;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::NULL
+        .union(VisitableType::STR)
+        .union(VisitableType::ARRAY)
+        .union(VisitableType::MAP);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageTarget::Null)
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageTarget::Path(value.text().to_string()))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageTarget::List(
+            items
+                .flatten()
+                .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+                .collect(),
+        ))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut entries = vec![];
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(target) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                entries.push((key_text.text().to_string(), target));
+            }
+        }
+        Some(PackageTarget::Map(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackageJson;
+
+    #[test]
+    fn parses_the_fields_used_by_the_resolution() {
+        let manifest = PackageJson::parse(
+            r#"{ "name": "lib", "version": "1.0.0", "main": "index.js", "typings": "index.d.ts" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.name.as_deref(), Some("lib"));
+        assert_eq!(manifest.main.as_deref(), Some("index.js"));
+        assert_eq!(manifest.types.as_deref(), Some("index.d.ts"));
+        assert_eq!(manifest.exports, None);
+        assert_eq!(PackageJson::parse("[]"), None);
+    }
+
+    #[test]
+    fn parses_the_names_of_the_dependencies() {
+        let manifest = PackageJson::parse(
+            r#"{
+                "dependencies": { "react": "^18.0.0" },
+                "devDependencies": { "@types/react": "^18.0.0", "vitest": "latest" },
+                "peerDependencies": { "react-dom": "*" },
+                "optionalDependencies": { "fsevents": "2.3.3" }
+            }"#,
+        )
+        .unwrap();
+
+        // The versions that aren't exact, e.g. `^18.0.0`, don't drop the names
+        let mut names: Vec<_> = manifest.dependency_names().collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            ["@types/react", "fsevents", "react", "react-dom", "vitest"]
+        );
+    }
+
+    #[test]
+    fn parses_the_node_engine() {
+        let manifest =
+            PackageJson::parse(r#"{ "engines": { "npm": ">=9", "node": ">=18.12.0" } }"#).unwrap();
+
+        assert_eq!(manifest.node_engine.as_deref(), Some(">=18.12.0"));
+        assert_eq!(PackageJson::parse("{}").unwrap().node_engine, None);
+    }
+
+    #[test]
+    fn parses_the_browserslist() {
+        let queries = |content: &str| {
+            PackageJson::parse(&format!(r#"{{ "browserslist": {content} }}"#))
+                .unwrap()
+                .browserslist
+        };
+
+        assert_eq!(
+            queries(r#""chrome >= 90, safari >= 15""#),
+            ["chrome >= 90", "safari >= 15"]
+        );
+        assert_eq!(
+            queries(r#"["chrome >= 90", "not dead"]"#),
+            ["chrome >= 90", "not dead"]
+        );
+        assert_eq!(
+            queries(
+                r#"{ "development": ["last 1 chrome version"], "production": ["firefox > 100"] }"#
+            ),
+            ["firefox > 100"]
+        );
+    }
+}
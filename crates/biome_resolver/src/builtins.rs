@@ -0,0 +1,73 @@
+/// The built-in modules of Node.js that can be imported without the `node:` prefix, sorted
+const NODE_BUILTIN_MODULES: &[&str] = &[
+    "assert",
+    "assert/strict",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "dns/promises",
+    "domain",
+    "events",
+    "fs",
+    "fs/promises",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "path/posix",
+    "path/win32",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "readline/promises",
+    "repl",
+    "stream",
+    "stream/consumers",
+    "stream/promises",
+    "stream/web",
+    "string_decoder",
+    "sys",
+    "timers",
+    "timers/promises",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "util/types",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// Whether `specifier` imports a built-in module of Node.js, e.g. `fs` or `node:test`
+pub fn is_node_builtin(specifier: &str) -> bool {
+    specifier.starts_with("node:") || NODE_BUILTIN_MODULES.binary_search(&specifier).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NODE_BUILTIN_MODULES;
+
+    #[test]
+    fn the_builtin_modules_are_sorted() {
+        assert!(NODE_BUILTIN_MODULES
+            .windows(2)
+            .all(|window| window[0] < window[1]));
+    }
+}
@@ -0,0 +1,734 @@
+//! Resolution of the specifiers of the imports, e.g. `./utils` or `react/jsx-runtime`, to the
+//! files they point at.
+//!
+//! The resolution follows the algorithm of Node.js: the relative specifiers are resolved from
+//! the directory of the importer, and the specifiers of packages are looked up in the
+//! `node_modules` directories, using the `exports` field of their `package.json`. Like
+//! TypeScript, the resolver also probes the extensions of the source files, resolves `./a.js`
+//! to `./a.ts`, and supports the `paths` and `baseUrl` options of the closest `tsconfig.json`
+//! or `jsconfig.json`, including the options of the files it extends.
+//!
+//! The resolver doesn't access the file system: it asks a [ResolverHost] whether the files
+//! exist, so the workspace can resolve the specifiers against the files it indexed. The
+//! `package.json` files are the manifests of [biome_project].
+
+mod builtins;
+mod package_json;
+mod tsconfig;
+
+pub use crate::builtins::is_node_builtin;
+pub use crate::package_json::resolve_exports;
+pub use crate::tsconfig::{resolve_path_aliases, TsConfigJson, TSCONFIG_FILE_NAMES};
+
+use crate::package_json::resolve_imports;
+use biome_project::PackageJson;
+use std::borrow::Cow;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+
+/// The extensions probed, in order, when a specifier doesn't point at a file as is
+pub const DEFAULT_EXTENSIONS: &[&str] =
+    &["ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// The conditions of `exports` and `imports` matched by default, in addition to `default`
+pub const DEFAULT_CONDITIONS: &[&str] = &["types", "import", "require", "node"];
+
+/// Answers the questions of the [Resolver] about the files of the project
+pub trait ResolverHost {
+    /// Whether a file exists at `path`
+    fn is_file(&self, path: &Path) -> bool;
+
+    /// The `package.json` file at `path`, `None` when it doesn't exist or can't be parsed
+    fn package_json(&self, path: &Path) -> Option<&PackageJson>;
+
+    /// The `tsconfig.json` or `jsconfig.json` file at `path`, `None` when it doesn't exist or
+    /// can't be parsed. The hosts that don't provide these files don't support the path aliases,
+    /// unless they are set in the [ResolveOptions].
+    fn tsconfig_json(&self, _path: &Path) -> Option<&TsConfigJson> {
+        None
+    }
+}
+
+/// What a specifier points at
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Resolution {
+    /// A file of the project or of an installed package
+    File(PathBuf),
+    /// A built-in module of Node.js, e.g. `node:fs`
+    Builtin,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ResolveError {
+    /// No file matches the specifier
+    NotFound,
+    /// The package was found, but the `exports` field of its `package.json` doesn't
+    /// export the imported subpath
+    NotExported,
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveError::NotFound => write!(f, "the module can't be found"),
+            ResolveError::NotExported => write!(f, "the module isn't exported by its package"),
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+/// The `paths` and the `baseUrl` options of a `tsconfig.json`
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct PathAliases {
+    /// When it's set, the non-relative specifiers are also looked up from this directory
+    pub base_url: Option<PathBuf>,
+    /// The directory the substitutions of `paths` are relative to: `baseUrl` when it's
+    /// set, the directory of the `tsconfig.json` otherwise
+    pub paths_base: PathBuf,
+    /// The patterns of the specifiers with their substitutions, tried in order, e.g.
+    /// `@app/*` mapped to `src/app/*`
+    pub paths: Vec<(String, Vec<String>)>,
+}
+
+impl PathAliases {
+    /// Whether `specifier` matches one of the patterns of `paths`
+    pub fn matches(&self, specifier: &str) -> bool {
+        find_pattern(
+            self.paths.iter().map(|(pattern, _)| (pattern.as_str(), ())),
+            specifier,
+        )
+        .is_some()
+    }
+
+    /// The patterns of `paths`, e.g. `@app/*`
+    pub fn patterns(&self) -> impl Iterator<Item = &str> {
+        self.paths.iter().map(|(pattern, _)| pattern.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResolveOptions {
+    /// The conditions matched in `exports` and `imports`, in addition to `default`
+    pub conditions: Vec<String>,
+    /// The extensions probed, in order, when a specifier doesn't point at a file as is
+    pub extensions: Vec<String>,
+    /// The path aliases of the project, see [PathAliases]. When they aren't set, they are
+    /// read from the `tsconfig.json` or the `jsconfig.json` closest to the importer.
+    pub path_aliases: Option<PathAliases>,
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        Self {
+            conditions: DEFAULT_CONDITIONS.iter().map(ToString::to_string).collect(),
+            extensions: DEFAULT_EXTENSIONS.iter().map(ToString::to_string).collect(),
+            path_aliases: None,
+        }
+    }
+}
+
+/// Resolves the specifiers of the imports, see the documentation of the crate
+#[derive(Debug, Clone, Default)]
+pub struct Resolver {
+    options: ResolveOptions,
+}
+
+impl Resolver {
+    pub fn new(options: ResolveOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &ResolveOptions {
+        &self.options
+    }
+
+    /// Resolves `specifier`, imported by a module of the directory `importer_dir`
+    pub fn resolve(
+        &self,
+        host: &dyn ResolverHost,
+        importer_dir: &Path,
+        specifier: &str,
+    ) -> Result<Resolution, ResolveError> {
+        if is_relative(specifier) || Path::new(specifier).is_absolute() {
+            return self
+                .resolve_path(host, &normalize(&importer_dir.join(specifier)))
+                .map(Resolution::File)
+                .ok_or(ResolveError::NotFound);
+        }
+
+        if specifier.starts_with('#') {
+            return self.resolve_package_imports(host, importer_dir, specifier);
+        }
+
+        if specifier.starts_with("node:") {
+            return Ok(Resolution::Builtin);
+        }
+
+        if let Some(path) = self.resolve_path_alias(host, importer_dir, specifier) {
+            return Ok(Resolution::File(path));
+        }
+
+        if is_node_builtin(specifier) {
+            return Ok(Resolution::Builtin);
+        }
+
+        self.resolve_package(host, importer_dir, specifier)
+    }
+
+    /// Resolves a path to a file, then to a directory
+    fn resolve_path(&self, host: &dyn ResolverHost, path: &Path) -> Option<PathBuf> {
+        self.resolve_file(host, path)
+            .or_else(|| self.resolve_directory(host, path))
+    }
+
+    fn resolve_file(&self, host: &dyn ResolverHost, path: &Path) -> Option<PathBuf> {
+        if host.is_file(path) {
+            return Some(path.to_path_buf());
+        }
+
+        // Like TypeScript, an import of `./a.js` can point at `./a.ts`
+        let source_extensions: &[&str] = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("js" | "jsx") => &["ts", "tsx", "d.ts"],
+            Some("mjs") => &["mts", "d.mts"],
+            Some("cjs") => &["cts", "d.cts"],
+            _ => &[],
+        };
+        let source_file = source_extensions
+            .iter()
+            .map(|extension| path.with_extension(extension))
+            .find(|candidate| host.is_file(candidate));
+        if source_file.is_some() {
+            return source_file;
+        }
+
+        self.options
+            .extensions
+            .iter()
+            .map(|extension| {
+                let mut file_name = path.as_os_str().to_os_string();
+                file_name.push(".");
+                file_name.push(extension);
+                PathBuf::from(file_name)
+            })
+            .find(|candidate| host.is_file(candidate))
+    }
+
+    /// Resolves the entry point of a directory: the `types` or the `main` field of its
+    /// `package.json`, or its index file
+    fn resolve_directory(&self, host: &dyn ResolverHost, path: &Path) -> Option<PathBuf> {
+        if let Some(manifest) = host.package_json(&path.join("package.json")) {
+            let entry_point = [&manifest.types, &manifest.main]
+                .into_iter()
+                .flatten()
+                .find_map(|entry_point| {
+                    let entry_point = normalize(&path.join(entry_point));
+                    self.resolve_file(host, &entry_point)
+                        .or_else(|| self.resolve_index(host, &entry_point))
+                });
+            if entry_point.is_some() {
+                return entry_point;
+            }
+        }
+
+        self.resolve_index(host, path)
+    }
+
+    fn resolve_index(&self, host: &dyn ResolverHost, path: &Path) -> Option<PathBuf> {
+        self.options
+            .extensions
+            .iter()
+            .map(|extension| path.join(format!("index.{extension}")))
+            .find(|candidate| host.is_file(candidate))
+    }
+
+    /// Resolves a specifier that starts with `#` with the `imports` field of the closest
+    /// `package.json`
+    fn resolve_package_imports(
+        &self,
+        host: &dyn ResolverHost,
+        importer_dir: &Path,
+        specifier: &str,
+    ) -> Result<Resolution, ResolveError> {
+        let (package_dir, manifest) = importer_dir
+            .ancestors()
+            .find_map(|dir| Some((dir, host.package_json(&dir.join("package.json"))?)))
+            .ok_or(ResolveError::NotFound)?;
+
+        let target = manifest
+            .imports
+            .as_ref()
+            .and_then(|imports| resolve_imports(imports, specifier, &self.options.conditions))
+            .ok_or(ResolveError::NotFound)?;
+
+        if target.starts_with("./") {
+            self.resolve_file(host, &normalize(&package_dir.join(target)))
+                .map(Resolution::File)
+                .ok_or(ResolveError::NotFound)
+        } else if target.starts_with('#') || is_relative(&target) {
+            Err(ResolveError::NotFound)
+        } else {
+            // The target is the specifier of another package
+            self.resolve(host, package_dir, &target)
+        }
+    }
+
+    /// Resolves a specifier with the `paths` option of `tsconfig.json`, then from its `baseUrl`
+    fn resolve_path_alias(
+        &self,
+        host: &dyn ResolverHost,
+        importer_dir: &Path,
+        specifier: &str,
+    ) -> Option<PathBuf> {
+        let path_aliases = self.path_aliases(host, importer_dir)?;
+
+        let matched = find_pattern(
+            path_aliases
+                .paths
+                .iter()
+                .map(|(pattern, substitutions)| (pattern.as_str(), substitutions)),
+            specifier,
+        );
+        if let Some((substitutions, wildcard)) = matched {
+            let resolved = substitutions.iter().find_map(|substitution| {
+                let substitution = match wildcard {
+                    Some(wildcard) => substitution.replace('*', wildcard),
+                    None => substitution.clone(),
+                };
+                self.resolve_path(
+                    host,
+                    &normalize(&path_aliases.paths_base.join(substitution)),
+                )
+            });
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+
+        let base_url = path_aliases.base_url.as_ref()?;
+        self.resolve_path(host, &normalize(&base_url.join(specifier)))
+    }
+
+    /// The path aliases of the options, or of the configuration file of TypeScript closest
+    /// to `importer_dir`
+    pub fn path_aliases(
+        &self,
+        host: &dyn ResolverHost,
+        importer_dir: &Path,
+    ) -> Option<Cow<PathAliases>> {
+        if let Some(path_aliases) = &self.options.path_aliases {
+            return Some(Cow::Borrowed(path_aliases));
+        }
+
+        let tsconfig_path = importer_dir.ancestors().find_map(|dir| {
+            TSCONFIG_FILE_NAMES
+                .iter()
+                .map(|file_name| dir.join(file_name))
+                .find(|path| host.tsconfig_json(path).is_some())
+        })?;
+        resolve_path_aliases(host, &tsconfig_path).map(Cow::Owned)
+    }
+
+    /// Resolves the specifier of a package: from the package itself when it imports its own
+    /// name, then from the `node_modules` directories of the importer and of its ancestors
+    fn resolve_package(
+        &self,
+        host: &dyn ResolverHost,
+        importer_dir: &Path,
+        specifier: &str,
+    ) -> Result<Resolution, ResolveError> {
+        let (name, subpath) = split_package_specifier(specifier).ok_or(ResolveError::NotFound)?;
+
+        let own_package = importer_dir.ancestors().find_map(|dir| {
+            let manifest = host.package_json(&dir.join("package.json"))?;
+            Some((dir, manifest))
+        });
+        if let Some((package_dir, manifest)) = own_package {
+            if manifest.name.as_deref() == Some(name) && manifest.exports.is_some() {
+                return self
+                    .resolve_package_dir(host, package_dir, subpath)?
+                    .map(Resolution::File)
+                    .ok_or(ResolveError::NotFound);
+            }
+        }
+
+        // The packages of the types are named `@types/scope__name` for `@scope/name`
+        let types_name = format!(
+            "@types/{}",
+            name.strip_prefix('@').unwrap_or(name).replace('/', "__")
+        );
+        for dir in importer_dir.ancestors() {
+            let node_modules = dir.join("node_modules");
+            for package_dir in [node_modules.join(name), node_modules.join(&types_name)] {
+                if let Some(path) = self.resolve_package_dir(host, &package_dir, subpath)? {
+                    return Ok(Resolution::File(path));
+                }
+            }
+        }
+
+        Err(ResolveError::NotFound)
+    }
+
+    /// Resolves `subpath` in the package of `package_dir`. It returns `None` when the
+    /// package doesn't exist.
+    fn resolve_package_dir(
+        &self,
+        host: &dyn ResolverHost,
+        package_dir: &Path,
+        subpath: &str,
+    ) -> Result<Option<PathBuf>, ResolveError> {
+        let manifest = host.package_json(&package_dir.join("package.json"));
+
+        if let Some(exports) = manifest.and_then(|manifest| manifest.exports.as_ref()) {
+            let subpath = if subpath.is_empty() {
+                String::from(".")
+            } else {
+                format!("./{subpath}")
+            };
+            let target = resolve_exports(exports, &subpath, &self.options.conditions)
+                .ok_or(ResolveError::NotExported)?;
+            // The targets of `exports` can't point outside of the package
+            let target = target.strip_prefix("./").ok_or(ResolveError::NotExported)?;
+
+            return self
+                .resolve_file(host, &normalize(&package_dir.join(target)))
+                .map(Some)
+                .ok_or(ResolveError::NotFound);
+        }
+
+        if subpath.is_empty() {
+            Ok(self.resolve_path(host, package_dir))
+        } else {
+            Ok(self.resolve_path(host, &package_dir.join(subpath)))
+        }
+    }
+}
+
+/// Finds the entry whose key matches `specifier`. The keys contain at most one `*`,
+/// which matches any text.
+///
+/// Like Node.js and TypeScript, a key without `*` that is equal to the specifier wins,
+/// then the pattern with the longest prefix before the `*`. It returns the value of the
+/// entry, and the text matched by the `*`.
+pub(crate) fn find_pattern<'a, 's, V>(
+    entries: impl IntoIterator<Item = (&'a str, V)>,
+    specifier: &'s str,
+) -> Option<(V, Option<&'s str>)> {
+    let mut best_match: Option<(usize, V, &'s str)> = None;
+
+    for (key, value) in entries {
+        let Some((prefix, suffix)) = key.split_once('*') else {
+            if key == specifier {
+                return Some((value, None));
+            }
+            continue;
+        };
+
+        let is_match = !suffix.contains('*')
+            && specifier.len() >= prefix.len() + suffix.len()
+            && specifier.starts_with(prefix)
+            && specifier.ends_with(suffix);
+        let is_longest = match &best_match {
+            Some((best_prefix, ..)) => prefix.len() > *best_prefix,
+            None => true,
+        };
+        if is_match && is_longest {
+            let wildcard = &specifier[prefix.len()..specifier.len() - suffix.len()];
+            best_match = Some((prefix.len(), value, wildcard));
+        }
+    }
+
+    best_match.map(|(_, value, wildcard)| (value, Some(wildcard)))
+}
+
+/// Splits the specifier of a package into the name of the package and the imported subpath,
+/// e.g. `@scope/name` and `feature` for `@scope/name/feature`
+fn split_package_specifier(specifier: &str) -> Option<(&str, &str)> {
+    let name_len = if specifier.starts_with('@') {
+        let scope_end = specifier.find('/')?;
+        specifier[scope_end + 1..]
+            .find('/')
+            .map_or(specifier.len(), |index| scope_end + 1 + index)
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+
+    let name = &specifier[..name_len];
+    let subpath = specifier[name_len..].trim_start_matches('/');
+    (!name.is_empty()).then_some((name, subpath))
+}
+
+fn is_relative(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+}
+
+/// Removes the `.` and `..` components of a path, without accessing the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct TestHost {
+        files: Vec<PathBuf>,
+        manifests: HashMap<PathBuf, PackageJson>,
+        tsconfigs: HashMap<PathBuf, TsConfigJson>,
+    }
+
+    impl TestHost {
+        fn with_file(mut self, path: &str) -> Self {
+            self.files.push(PathBuf::from(path));
+            self
+        }
+
+        fn with_manifest(mut self, path: &str, content: &str) -> Self {
+            self.manifests
+                .insert(PathBuf::from(path), PackageJson::parse(content).unwrap());
+            self
+        }
+
+        fn with_tsconfig(mut self, path: &str, content: &str) -> Self {
+            self.tsconfigs
+                .insert(PathBuf::from(path), TsConfigJson::parse(content).unwrap());
+            self
+        }
+    }
+
+    impl ResolverHost for TestHost {
+        fn is_file(&self, path: &Path) -> bool {
+            self.files.iter().any(|file| file == path)
+        }
+
+        fn package_json(&self, path: &Path) -> Option<&PackageJson> {
+            self.manifests.get(path)
+        }
+
+        fn tsconfig_json(&self, path: &Path) -> Option<&TsConfigJson> {
+            self.tsconfigs.get(path)
+        }
+    }
+
+    fn resolve(
+        resolver: &Resolver,
+        host: &TestHost,
+        importer_dir: &str,
+        specifier: &str,
+    ) -> Result<Resolution, ResolveError> {
+        resolver.resolve(host, Path::new(importer_dir), specifier)
+    }
+
+    fn file(path: &str) -> Result<Resolution, ResolveError> {
+        Ok(Resolution::File(PathBuf::from(path)))
+    }
+
+    #[test]
+    fn resolves_the_relative_specifiers() {
+        let host = TestHost::default()
+            .with_file("src/a.ts")
+            .with_file("src/utils/index.js")
+            .with_file("src/lib/dist/main.js")
+            .with_manifest("src/lib/package.json", r#"{ "main": "./dist/main" }"#);
+        let resolver = Resolver::default();
+
+        assert_eq!(resolve(&resolver, &host, "src", "./a"), file("src/a.ts"));
+        assert_eq!(resolve(&resolver, &host, "src", "./a.js"), file("src/a.ts"));
+        assert_eq!(
+            resolve(&resolver, &host, "src", "./utils"),
+            file("src/utils/index.js")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src/utils", "../a"),
+            file("src/a.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "./lib"),
+            file("src/lib/dist/main.js")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "./missing"),
+            Err(ResolveError::NotFound)
+        );
+    }
+
+    #[test]
+    fn resolves_the_packages_with_their_exports() {
+        let host = TestHost::default()
+            .with_file("node_modules/lib/dist/index.mjs")
+            .with_file("node_modules/lib/dist/feature.d.ts")
+            .with_manifest(
+                "node_modules/lib/package.json",
+                r#"{
+                    "exports": {
+                        ".": { "import": "./dist/index.mjs", "require": "./dist/index.cjs" },
+                        "./feature": { "types": "./dist/feature.d.ts", "default": "./dist/feature.js" }
+                    }
+                }"#,
+            )
+            .with_file("node_modules/@scope/legacy/lib/index.js")
+            .with_manifest(
+                "node_modules/@scope/legacy/package.json",
+                r#"{ "main": "lib/index.js" }"#,
+            )
+            .with_file("node_modules/@types/untyped/index.d.ts");
+        let resolver = Resolver::default();
+
+        assert_eq!(
+            resolve(&resolver, &host, "src/deep", "lib"),
+            file("node_modules/lib/dist/index.mjs")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "lib/feature"),
+            file("node_modules/lib/dist/feature.d.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "lib/dist/index.mjs"),
+            Err(ResolveError::NotExported)
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "@scope/legacy"),
+            file("node_modules/@scope/legacy/lib/index.js")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "untyped"),
+            file("node_modules/@types/untyped/index.d.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "missing"),
+            Err(ResolveError::NotFound)
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "node:fs"),
+            Ok(Resolution::Builtin)
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "fs/promises"),
+            Ok(Resolution::Builtin)
+        );
+    }
+
+    #[test]
+    fn resolves_the_imports_and_the_self_references() {
+        let host = TestHost::default()
+            .with_file("src/internal/a.ts")
+            .with_file("src/index.ts")
+            .with_manifest(
+                "package.json",
+                r##"{
+                    "name": "app",
+                    "exports": { ".": "./src/index.js" },
+                    "imports": { "#internal/*": "./src/internal/*.js" }
+                }"##,
+            );
+        let resolver = Resolver::default();
+
+        assert_eq!(
+            resolve(&resolver, &host, "src/nested", "#internal/a"),
+            file("src/internal/a.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src/nested", "app"),
+            file("src/index.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "src", "#missing"),
+            Err(ResolveError::NotFound)
+        );
+    }
+
+    #[test]
+    fn resolves_the_path_aliases() {
+        let host = TestHost::default()
+            .with_file("src/app/button.tsx")
+            .with_file("src/shared/button.ts")
+            .with_file("src/config.ts");
+        let resolver = Resolver::new(ResolveOptions {
+            path_aliases: Some(PathAliases {
+                base_url: Some(PathBuf::from("src")),
+                paths_base: PathBuf::from("src"),
+                paths: vec![
+                    (
+                        String::from("@app/*"),
+                        vec![String::from("app/*"), String::from("shared/*")],
+                    ),
+                    (
+                        String::from("@app/shared/*"),
+                        vec![String::from("shared/*")],
+                    ),
+                ],
+            }),
+            ..ResolveOptions::default()
+        });
+
+        assert_eq!(
+            resolve(&resolver, &host, "test", "@app/button"),
+            file("src/app/button.tsx")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "test", "@app/shared/button"),
+            file("src/shared/button.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "test", "config"),
+            file("src/config.ts")
+        );
+    }
+
+    #[test]
+    fn reads_the_path_aliases_of_the_closest_tsconfig() {
+        let host = TestHost::default()
+            .with_file("app/src/button.ts")
+            .with_file("web/lib/button.js")
+            .with_tsconfig(
+                "app/tsconfig.json",
+                r#"{ "compilerOptions": { "paths": { "@app/*": ["./src/*"] } } }"#,
+            )
+            .with_tsconfig(
+                "web/jsconfig.json",
+                r#"{ "compilerOptions": { "baseUrl": "./lib" } }"#,
+            );
+        let resolver = Resolver::default();
+
+        assert_eq!(
+            resolve(&resolver, &host, "app/src/nested", "@app/button"),
+            file("app/src/button.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "web", "button"),
+            file("web/lib/button.js")
+        );
+        assert_eq!(
+            resolve(&resolver, &host, "web", "@app/button"),
+            Err(ResolveError::NotFound)
+        );
+    }
+
+    #[test]
+    fn splits_the_package_specifiers() {
+        assert_eq!(split_package_specifier("lib"), Some(("lib", "")));
+        assert_eq!(split_package_specifier("lib/a/b"), Some(("lib", "a/b")));
+        assert_eq!(
+            split_package_specifier("@scope/lib/a"),
+            Some(("@scope/lib", "a"))
+        );
+        assert_eq!(split_package_specifier("@scope"), None);
+    }
+}
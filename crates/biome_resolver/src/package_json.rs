@@ -0,0 +1,150 @@
+use crate::find_pattern;
+use biome_project::PackageTarget;
+
+/// Resolves a subpath of a package, `.` for its main entry point or `./feature`,
+/// with its `exports` field. It returns the target, relative to the package.
+pub fn resolve_exports(
+    exports: &PackageTarget,
+    subpath: &str,
+    conditions: &[String],
+) -> Option<String> {
+    match exports {
+        // The keys of the subpaths start with `.`, the other keys are conditions
+        PackageTarget::Map(entries) if entries.iter().any(|(key, _)| key.starts_with('.')) => {
+            resolve_map(entries, subpath, conditions)
+        }
+        _ if subpath == "." => resolve_target(exports, None, conditions),
+        _ => None,
+    }
+}
+
+/// Resolves a specifier that starts with `#` with the `imports` field. The target
+/// is either relative to the package or the specifier of another package.
+pub(crate) fn resolve_imports(
+    imports: &PackageTarget,
+    specifier: &str,
+    conditions: &[String],
+) -> Option<String> {
+    match imports {
+        PackageTarget::Map(entries) => resolve_map(entries, specifier, conditions),
+        _ => None,
+    }
+}
+
+fn resolve_map(
+    entries: &[(String, PackageTarget)],
+    key: &str,
+    conditions: &[String],
+) -> Option<String> {
+    let (target, wildcard) = find_pattern(
+        entries
+            .iter()
+            .map(|(pattern, target)| (pattern.as_str(), target)),
+        key,
+    )?;
+    resolve_target(target, wildcard, conditions)
+}
+
+/// Resolves a target of `exports` or `imports`. The conditions are tried in the
+/// order of the map, and the first target of a list that can be resolved is used.
+fn resolve_target(
+    target: &PackageTarget,
+    wildcard: Option<&str>,
+    conditions: &[String],
+) -> Option<String> {
+    match target {
+        PackageTarget::Path(path) => Some(match wildcard {
+            Some(wildcard) => path.replace('*', wildcard),
+            None => path.clone(),
+        }),
+        PackageTarget::Map(entries) => entries
+            .iter()
+            .filter(|(condition, _)| {
+                condition == "default" || conditions.iter().any(|enabled| enabled == condition)
+            })
+            .find_map(|(_, target)| resolve_target(target, wildcard, conditions)),
+        PackageTarget::List(targets) => targets
+            .iter()
+            .find_map(|target| resolve_target(target, wildcard, conditions)),
+        PackageTarget::Null => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_exports, resolve_imports};
+    use biome_project::{PackageJson, PackageTarget};
+
+    fn conditions() -> Vec<String> {
+        vec![String::from("import"), String::from("types")]
+    }
+
+    fn exports(content: &str) -> PackageTarget {
+        PackageJson::parse(&format!(r#"{{ "exports": {content} }}"#))
+            .and_then(|manifest| manifest.exports)
+            .unwrap()
+    }
+
+    #[test]
+    fn resolves_the_subpaths_and_the_conditions() {
+        let exports = exports(
+            r#"{
+                ".": { "require": "./index.cjs", "import": "./index.mjs" },
+                "./feature": ["./feature.js"],
+                "./utils/*": "./dist/utils/*.js",
+                "./utils/internal/*": null
+            }"#,
+        );
+
+        assert_eq!(
+            resolve_exports(&exports, ".", &conditions()).as_deref(),
+            Some("./index.mjs")
+        );
+        assert_eq!(
+            resolve_exports(&exports, "./feature", &conditions()).as_deref(),
+            Some("./feature.js")
+        );
+        assert_eq!(
+            resolve_exports(&exports, "./utils/string", &conditions()).as_deref(),
+            Some("./dist/utils/string.js")
+        );
+        assert_eq!(
+            resolve_exports(&exports, "./utils/internal/secret", &conditions()),
+            None
+        );
+        assert_eq!(resolve_exports(&exports, "./missing", &conditions()), None);
+    }
+
+    #[test]
+    fn resolves_the_main_export() {
+        let exports = exports(r#"{ "types": "./index.d.ts", "default": "./index.js" }"#);
+
+        assert_eq!(
+            resolve_exports(&exports, ".", &conditions()).as_deref(),
+            Some("./index.d.ts")
+        );
+        assert_eq!(
+            resolve_exports(&exports, ".", &[]).as_deref(),
+            Some("./index.js")
+        );
+        assert_eq!(resolve_exports(&exports, "./feature", &conditions()), None);
+    }
+
+    #[test]
+    fn resolves_the_imports() {
+        let imports = PackageJson::parse(
+            r##"{ "imports": { "#internal/*": "./src/internal/*.js", "#dep": "dep" } }"##,
+        )
+        .and_then(|manifest| manifest.imports)
+        .unwrap();
+
+        assert_eq!(
+            resolve_imports(&imports, "#internal/a", &conditions()).as_deref(),
+            Some("./src/internal/a.js")
+        );
+        assert_eq!(
+            resolve_imports(&imports, "#dep", &conditions()).as_deref(),
+            Some("dep")
+        );
+    }
+}
@@ -0,0 +1,450 @@
+use crate::{is_relative, normalize, PathAliases, ResolverHost};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
+use std::path::{Path, PathBuf};
+
+/// The names of the configuration files of TypeScript, looked up from the directory of the
+/// importer to the root of the project
+pub const TSCONFIG_FILE_NAMES: &[&str] = &["tsconfig.json", "jsconfig.json"];
+
+/// The fields of a `tsconfig.json` or a `jsconfig.json` file that are used to resolve the
+/// specifiers
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TsConfigJson {
+    /// The configuration files this one extends, from the least to the most specific
+    pub extends: Vec<String>,
+    /// The `compilerOptions.baseUrl` option, relative to the directory of the file
+    pub base_url: Option<String>,
+    /// The `compilerOptions.paths` option: the patterns of the specifiers with their
+    /// substitutions, in the order of the file
+    pub paths: Option<Vec<(String, Vec<String>)>>,
+}
+
+impl TsConfigJson {
+    /// Parses the content of a `tsconfig.json` file, which can contain comments and
+    /// trailing commas. It returns `None` when the content isn't a JSON object.
+    ///
+    /// The fields that can't be deserialized are ignored, the resolution is best-effort.
+    pub fn parse(content: &str) -> Option<Self> {
+        deserialize_from_json_str(
+            content,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+        )
+        .into_deserialized()
+    }
+
+    /// The paths where the file extended by `extends` can be, in order, when it's extended
+    /// by the configuration file at `path`.
+    ///
+    /// Like TypeScript, a relative path can omit the `.json` extension, and the other
+    /// specifiers are looked up in the `node_modules` directories, e.g. `@tsconfig/node20`
+    /// points at `node_modules/@tsconfig/node20/tsconfig.json`.
+    pub fn extended_paths(path: &Path, extends: &str) -> Vec<PathBuf> {
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+        if is_relative(extends) || Path::new(extends).is_absolute() {
+            let extended = normalize(&dir.join(extends));
+            return if extends.ends_with(".json") {
+                vec![extended]
+            } else {
+                vec![with_json_extension(&extended), extended]
+            };
+        }
+
+        dir.ancestors()
+            .flat_map(|dir| {
+                let extended = dir.join("node_modules").join(extends);
+                if extends.ends_with(".json") {
+                    vec![extended]
+                } else {
+                    vec![
+                        with_json_extension(&extended),
+                        extended.join("tsconfig.json"),
+                    ]
+                }
+            })
+            .collect()
+    }
+}
+
+impl Deserializable for TsConfigJson {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(TsConfigJsonVisitor, name, diagnostics)
+    }
+}
+
+struct TsConfigJsonVisitor;
+impl DeserializationVisitor for TsConfigJsonVisitor {
+    type Output = TsConfigJson;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "extends" => {
+                    // Since TypeScript 5.0, a configuration can extend several files
+                    result.extends = Deserializable::deserialize(&value, &key_text, diagnostics)
+                        .map(|Extends(extends)| extends)
+                        .unwrap_or_default();
+                }
+                "compilerOptions" => {
+                    if let Some(CompilerOptions { base_url, paths }) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.base_url = base_url;
+                        result.paths = paths;
+                    }
+                }
+                // The other fields of the configuration aren't used by the resolution
+                _ => {}
+            }
+        }
+        Some(result)
+    }
+}
+
+/// The `extends` field: a path, or a list of paths
+struct Extends(Vec<String>);
+
+impl Deserializable for Extends {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(ExtendsVisitor, name, diagnostics)
+    }
+}
+
+struct ExtendsVisitor;
+impl DeserializationVisitor for ExtendsVisitor {
+    type Output = Extends;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::ARRAY);
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(Extends(vec![value.text().to_string()]))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(Extends(
+            items
+                .flatten()
+                .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+                .collect(),
+        ))
+    }
+}
+
+#[derive(Default)]
+struct CompilerOptions {
+    base_url: Option<String>,
+    paths: Option<Vec<(String, Vec<String>)>>,
+}
+
+impl Deserializable for CompilerOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(CompilerOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct CompilerOptionsVisitor;
+impl DeserializationVisitor for CompilerOptionsVisitor {
+    type Output = CompilerOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "baseUrl" => {
+                    result.base_url = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "paths" => {
+                    result.paths = Deserializable::deserialize(&value, &key_text, diagnostics)
+                        .map(|Paths(paths)| paths);
+                }
+                _ => {}
+            }
+        }
+        Some(result)
+    }
+}
+
+/// The `paths` option, whose order is kept
+struct Paths(Vec<(String, Vec<String>)>);
+
+impl Deserializable for Paths {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PathsVisitor, name, diagnostics)
+    }
+}
+
+struct PathsVisitor;
+impl DeserializationVisitor for PathsVisitor {
+    type Output = Paths;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut paths = vec![];
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(substitutions) = Deserializable::deserialize(&value, &key_text, diagnostics)
+            {
+                paths.push((key_text.text().to_string(), substitutions));
+            }
+        }
+        Some(Paths(paths))
+    }
+}
+
+/// Computes the path aliases of the configuration file at `path`, merged with the files it
+/// extends. The options of a file override the options of the files it extends.
+///
+/// It returns `None` when the file doesn't exist, or when neither `paths` nor `baseUrl` are set.
+pub fn resolve_path_aliases(host: &dyn ResolverHost, path: &Path) -> Option<PathAliases> {
+    let mut options = MergedOptions::default();
+    merge_options(host, path, &mut options, &mut vec![])?;
+
+    let (paths_dir, paths) = options.paths.unwrap_or_default();
+    if paths.is_empty() && options.base_url.is_none() {
+        return None;
+    }
+
+    Some(PathAliases {
+        // The substitutions of `paths` are relative to `baseUrl` when it's set
+        paths_base: options.base_url.clone().unwrap_or(paths_dir),
+        base_url: options.base_url,
+        paths,
+    })
+}
+
+#[derive(Default)]
+struct MergedOptions {
+    base_url: Option<PathBuf>,
+    /// The `paths` option, with the directory of the file that sets it
+    paths: Option<(PathBuf, Vec<(String, Vec<String>)>)>,
+}
+
+/// Merges the options of the file at `path` into `options`, after the options of the
+/// files it extends. `visited` contains the files being merged, so the cycles are ignored.
+fn merge_options(
+    host: &dyn ResolverHost,
+    path: &Path,
+    options: &mut MergedOptions,
+    visited: &mut Vec<PathBuf>,
+) -> Option<()> {
+    if visited.iter().any(|visited| visited == path) {
+        return None;
+    }
+    let tsconfig = host.tsconfig_json(path)?;
+    visited.push(path.to_path_buf());
+
+    for extends in &tsconfig.extends {
+        for extended in TsConfigJson::extended_paths(path, extends) {
+            if merge_options(host, &extended, options, visited).is_some() {
+                break;
+            }
+        }
+    }
+
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    if let Some(base_url) = &tsconfig.base_url {
+        options.base_url = Some(normalize(&dir.join(base_url)));
+    }
+    if let Some(paths) = &tsconfig.paths {
+        options.paths = Some((dir.to_path_buf(), paths.clone()));
+    }
+
+    visited.pop();
+    Some(())
+}
+
+fn with_json_extension(path: &Path) -> PathBuf {
+    let mut file_name = path.as_os_str().to_os_string();
+    file_name.push(".json");
+    PathBuf::from(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_path_aliases, TsConfigJson};
+    use crate::{PackageJson, ResolverHost};
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    struct TestHost {
+        tsconfigs: HashMap<PathBuf, TsConfigJson>,
+    }
+
+    impl TestHost {
+        fn with_tsconfig(mut self, path: &str, content: &str) -> Self {
+            self.tsconfigs
+                .insert(PathBuf::from(path), TsConfigJson::parse(content).unwrap());
+            self
+        }
+    }
+
+    impl ResolverHost for TestHost {
+        fn is_file(&self, _path: &Path) -> bool {
+            false
+        }
+
+        fn package_json(&self, _path: &Path) -> Option<&PackageJson> {
+            None
+        }
+
+        fn tsconfig_json(&self, path: &Path) -> Option<&TsConfigJson> {
+            self.tsconfigs.get(path)
+        }
+    }
+
+    #[test]
+    fn parses_the_fields_used_by_the_resolution() {
+        let tsconfig = TsConfigJson::parse(
+            r#"{
+                // The comments and the trailing commas are allowed
+                "extends": "./base",
+                "compilerOptions": {
+                    "strict": true,
+                    "baseUrl": "src",
+                    "paths": { "@app/*": ["app/*"], "~": ["index.ts"], },
+                },
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(tsconfig.extends, ["./base"]);
+        assert_eq!(tsconfig.base_url.as_deref(), Some("src"));
+        assert_eq!(
+            tsconfig.paths,
+            Some(vec![
+                (String::from("@app/*"), vec![String::from("app/*")]),
+                (String::from("~"), vec![String::from("index.ts")]),
+            ])
+        );
+        assert_eq!(
+            TsConfigJson::parse(r#"{ "extends": ["a", "b"] }"#)
+                .unwrap()
+                .extends,
+            ["a", "b"]
+        );
+    }
+
+    #[test]
+    fn lists_the_paths_of_the_extended_files() {
+        assert_eq!(
+            TsConfigJson::extended_paths(Path::new("app/tsconfig.json"), "../tsconfig.base"),
+            [
+                PathBuf::from("tsconfig.base.json"),
+                PathBuf::from("tsconfig.base")
+            ]
+        );
+        assert_eq!(
+            TsConfigJson::extended_paths(Path::new("app/tsconfig.json"), "@tsconfig/node20"),
+            [
+                PathBuf::from("app/node_modules/@tsconfig/node20.json"),
+                PathBuf::from("app/node_modules/@tsconfig/node20/tsconfig.json"),
+                PathBuf::from("node_modules/@tsconfig/node20.json"),
+                PathBuf::from("node_modules/@tsconfig/node20/tsconfig.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn merges_the_extended_files() {
+        let host = TestHost::default()
+            .with_tsconfig(
+                "tsconfig.base.json",
+                r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@lib/*": ["lib/*"] } } }"#,
+            )
+            .with_tsconfig(
+                "app/tsconfig.json",
+                r#"{ "extends": "../tsconfig.base", "compilerOptions": { "paths": { "@app/*": ["src/*"] } } }"#,
+            )
+            .with_tsconfig(
+                "web/tsconfig.json",
+                r#"{ "extends": ["../tsconfig.base.json", "./tsconfig.json"] }"#,
+            );
+
+        let path_aliases = resolve_path_aliases(&host, Path::new("app/tsconfig.json")).unwrap();
+        // The `baseUrl` of the extended file is relative to the extended file
+        assert_eq!(path_aliases.base_url, Some(PathBuf::from("")));
+        assert_eq!(path_aliases.paths_base, PathBuf::from(""));
+        assert_eq!(
+            path_aliases.paths,
+            [(String::from("@app/*"), vec![String::from("src/*")])]
+        );
+
+        // The cycle is ignored
+        let path_aliases = resolve_path_aliases(&host, Path::new("web/tsconfig.json")).unwrap();
+        assert_eq!(
+            path_aliases.paths,
+            [(String::from("@lib/*"), vec![String::from("lib/*")])]
+        );
+
+        assert_eq!(resolve_path_aliases(&host, Path::new("missing.json")), None);
+    }
+}
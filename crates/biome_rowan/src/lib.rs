@@ -33,7 +33,9 @@ mod syntax_factory;
 mod token_text;
 mod tree_builder;
 
-pub use biome_text_size::{TextLen, TextRange, TextSize};
+pub use biome_text_size::{
+    LineCol, LineIndex, TextLen, TextRange, TextSize, WideEncoding, WideLineCol,
+};
 
 pub use crate::{
     ast::*,
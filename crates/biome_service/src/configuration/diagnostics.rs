@@ -283,6 +283,30 @@ pub struct InvalidConfiguration {
     message: MessageAndDescription,
 }
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "configuration",
+    severity = Warning,
+    message(
+        description = "The nested configuration file {path} has fields that only the root configuration supports: {fields}. They are ignored.",
+        message("The nested configuration file "<Emphasis>{self.path}</Emphasis>" has fields that only the root configuration supports: "{self.fields}". They are ignored."),
+    )
+)]
+pub struct RootOnlyConfigurationFields {
+    #[location(resource)]
+    path: String,
+    fields: String,
+}
+
+impl RootOnlyConfigurationFields {
+    pub fn new(path: impl Into<String>, fields: &[&str]) -> Self {
+        Self {
+            path: path.into(),
+            fields: fields.join(", "),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::configuration::diagnostics::ConfigurationDiagnostic;
@@ -323,7 +347,7 @@ mod test {
             require_literal_separator: false,
         });
 
-        let pattern = "*******";
+        let pattern = "src/[a";
         if let Err(error) = matcher.add_pattern(pattern) {
             snap_diagnostic(
                 "incorrect_pattern",
@@ -0,0 +1,659 @@
+//! Support for the `.editorconfig` files, see <https://editorconfig.org>.
+//!
+//! The files are looked up like the nested configuration files, one directory at a time, from
+//! the directory of a file to its parents, until a file with `root = true`. The sections of a
+//! file become overrides of the formatter anchored to its directory. The workspace applies the
+//! files that apply to a path before the overrides of the configuration, from the shallowest to
+//! the deepest. The options set by the configuration take precedence over the files: they are
+//! removed from the overrides. Only these properties are supported:
+//! - `indent_style`, mapped to `formatter.indentStyle`;
+//! - `indent_size`, mapped to `formatter.indentWidth`;
+//! - `end_of_line`, mapped to `formatter.lineEnding`;
+//! - `max_line_length`, mapped to `formatter.lineWidth`;
+//! - `insert_final_newline`: the formatter always inserts a final newline, so only `true` is
+//!   supported.
+
+use crate::configuration::diagnostics::CantLoadExtendFile;
+use crate::configuration::json::JsonFormatter;
+use crate::configuration::merge::MergeWith;
+use crate::configuration::overrides::{OverrideFormatterConfiguration, OverridePattern};
+use crate::configuration::{
+    Configuration, JavascriptConfiguration, JavascriptFormatter, JsonConfiguration, Overrides,
+    PlainIndentStyle,
+};
+use crate::stable_hash::StableHasher;
+use crate::workspace::EditorConfigOverrides;
+use crate::Pattern;
+use biome_console::markup;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::StringSet;
+use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, MessageAndDescription};
+use biome_formatter::{LineEnding, LineWidth};
+use biome_fs::{FileSystem, BIOME_JSONC};
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::{TextRange, TextSize};
+use rustc_hash::FxHashMap;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// The name of the file
+pub const EDITORCONFIG: &str = ".editorconfig";
+
+/// The content of an `.editorconfig` file
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct EditorConfig {
+    /// Whether the file has `root = true`: the files of the parent directories are ignored
+    root: bool,
+    sections: Vec<EditorConfigSection>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct EditorConfigSection {
+    /// The glob of the header of the section, e.g. `*.{js,ts}`
+    glob: String,
+    options: OverrideFormatterConfiguration,
+}
+
+/// A property of an `.editorconfig` file that Biome doesn't support, or that has an invalid value
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "configuration", severity = Warning)]
+pub struct EditorConfigDiagnostic {
+    #[location(span)]
+    span: TextRange,
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+}
+
+impl EditorConfigDiagnostic {
+    fn invalid_value(span: TextRange, key: &str, value: &str) -> Self {
+        Self {
+            span,
+            message: MessageAndDescription::from(
+                markup! {
+                    "The value "<Emphasis>{value}</Emphasis>" of "<Emphasis>{key}</Emphasis>" isn't supported, the property is ignored."
+                }
+                .to_owned(),
+            ),
+        }
+    }
+
+    fn final_newline(span: TextRange) -> Self {
+        Self {
+            span,
+            message: MessageAndDescription::from(
+                markup! {
+                    "The formatter always inserts a final newline, "<Emphasis>"insert_final_newline = false"</Emphasis>" is ignored."
+                }
+                .to_owned(),
+            ),
+        }
+    }
+}
+
+impl EditorConfig {
+    /// Parses the content of an `.editorconfig` file.
+    ///
+    /// The properties that Biome doesn't know are skipped, the properties that it knows but
+    /// whose value isn't supported are skipped and reported.
+    pub fn parse(content: &str) -> (Self, Vec<EditorConfigDiagnostic>) {
+        let mut editorconfig = EditorConfig::default();
+        let mut diagnostics = vec![];
+        let mut offset = 0;
+
+        for line in content.split_inclusive('\n') {
+            let start = offset;
+            offset += line.len();
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            let span = TextRange::at(
+                TextSize::from(start as u32),
+                TextSize::from(line.len() as u32),
+            );
+
+            if let Some(glob) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                editorconfig.sections.push(EditorConfigSection {
+                    glob: glob.to_string(),
+                    options: OverrideFormatterConfiguration::default(),
+                });
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim().to_lowercase();
+            // The properties before the first section don't apply to files
+            let Some(section) = editorconfig.sections.last_mut() else {
+                if key == "root" {
+                    editorconfig.root = value == "true";
+                }
+                continue;
+            };
+            if value == "unset" {
+                continue;
+            }
+
+            let options = &mut section.options;
+            let is_valid = match key.as_str() {
+                "indent_style" => {
+                    options.indent_style = PlainIndentStyle::from_str(&value).ok();
+                    options.indent_style.is_some()
+                }
+                // The width of the tabs isn't an option of the formatter
+                "indent_size" if value == "tab" => true,
+                "indent_size" => {
+                    options.indent_width = value.parse().ok();
+                    options.indent_width.is_some()
+                }
+                "end_of_line" => {
+                    options.line_ending = LineEnding::from_str(&value).ok();
+                    options.line_ending.is_some()
+                }
+                "max_line_length" if value == "off" => true,
+                "max_line_length" => {
+                    options.line_width = value
+                        .parse::<u16>()
+                        .ok()
+                        .and_then(|value| LineWidth::try_from(value).ok());
+                    options.line_width.is_some()
+                }
+                "insert_final_newline" => {
+                    if value == "false" {
+                        diagnostics.push(EditorConfigDiagnostic::final_newline(span));
+                        continue;
+                    }
+                    value == "true"
+                }
+                _ => true,
+            };
+            if !is_valid {
+                diagnostics.push(EditorConfigDiagnostic::invalid_value(span, &key, &value));
+            }
+        }
+
+        (editorconfig, diagnostics)
+    }
+
+    /// Converts the sections to overrides, in the same order.
+    ///
+    /// `directory` is the directory of the file, spelled like the paths of the files beneath it.
+    /// It's `None` when the file is above the directory the paths are relative to: the sections
+    /// whose glob is relative to the directory of the file are skipped.
+    ///
+    /// `formatter` has the options of the top level formatter that the configuration sets: they
+    /// take precedence over the sections, so they are removed from the overrides. The options
+    /// that the configuration only sets for a language are moved to the formatter of the other
+    /// language, for the same reason.
+    pub fn into_overrides(
+        self,
+        directory: Option<&Path>,
+        formatter: &OverrideFormatterConfiguration,
+        configuration: &Configuration,
+    ) -> Vec<OverridePattern> {
+        let javascript = configuration
+            .javascript
+            .as_ref()
+            .and_then(|javascript| javascript.formatter.as_ref());
+        let json = configuration
+            .json
+            .as_ref()
+            .and_then(|json| json.formatter.as_ref());
+
+        self.sections
+            .into_iter()
+            .filter_map(|section| {
+                let pattern = to_pattern(&section.glob, directory)?;
+                let options = section.options;
+                let mut pattern_formatter = OverrideFormatterConfiguration::default();
+                let mut javascript_formatter = JavascriptFormatter::default();
+                let mut json_formatter = JsonFormatter::default();
+
+                assign(
+                    options.indent_style,
+                    [
+                        formatter.indent_style.is_some(),
+                        javascript.is_some_and(|javascript| javascript.indent_style.is_some()),
+                        json.is_some_and(|json| json.indent_style.is_some()),
+                    ],
+                    [
+                        &mut pattern_formatter.indent_style,
+                        &mut javascript_formatter.indent_style,
+                        &mut json_formatter.indent_style,
+                    ],
+                );
+                assign(
+                    options.indent_width,
+                    [
+                        formatter.indent_width.or(formatter.indent_size).is_some(),
+                        javascript.is_some_and(|javascript| {
+                            javascript.indent_width.or(javascript.indent_size).is_some()
+                        }),
+                        json.is_some_and(|json| json.indent_width.or(json.indent_size).is_some()),
+                    ],
+                    [
+                        &mut pattern_formatter.indent_width,
+                        &mut javascript_formatter.indent_width,
+                        &mut json_formatter.indent_width,
+                    ],
+                );
+                assign(
+                    options.line_ending,
+                    [
+                        formatter.line_ending.is_some(),
+                        javascript.is_some_and(|javascript| javascript.line_ending.is_some()),
+                        json.is_some_and(|json| json.line_ending.is_some()),
+                    ],
+                    [
+                        &mut pattern_formatter.line_ending,
+                        &mut javascript_formatter.line_ending,
+                        &mut json_formatter.line_ending,
+                    ],
+                );
+                assign(
+                    options.line_width,
+                    [
+                        formatter.line_width.is_some(),
+                        javascript.is_some_and(|javascript| javascript.line_width.is_some()),
+                        json.is_some_and(|json| json.line_width.is_some()),
+                    ],
+                    [
+                        &mut pattern_formatter.line_width,
+                        &mut javascript_formatter.line_width,
+                        &mut json_formatter.line_width,
+                    ],
+                );
+
+                let pattern = OverridePattern {
+                    include: Some(StringSet::new([pattern].into_iter().collect())),
+                    formatter: (pattern_formatter != OverrideFormatterConfiguration::default())
+                        .then_some(pattern_formatter),
+                    javascript: (javascript_formatter != JavascriptFormatter::default()).then(
+                        || JavascriptConfiguration {
+                            formatter: Some(javascript_formatter),
+                            ..JavascriptConfiguration::default()
+                        },
+                    ),
+                    json: (json_formatter != JsonFormatter::default()).then(|| JsonConfiguration {
+                        formatter: Some(json_formatter),
+                        ..JsonConfiguration::default()
+                    }),
+                    ..OverridePattern::default()
+                };
+                (pattern.formatter.is_some()
+                    || pattern.javascript.is_some()
+                    || pattern.json.is_some())
+                .then_some(pattern)
+            })
+            .collect()
+    }
+}
+
+/// Assigns the value of a section to the formatter of the override, unless the configuration
+/// sets it. `is_set` tells whether the configuration sets it for all the files, for the
+/// JavaScript files and for the JSON files.
+fn assign<T>(
+    value: Option<T>,
+    [is_set, is_set_for_javascript, is_set_for_json]: [bool; 3],
+    [target, javascript_target, json_target]: [&mut Option<T>; 3],
+) {
+    if is_set {
+        return;
+    }
+    match (is_set_for_javascript, is_set_for_json) {
+        (false, false) => *target = value,
+        (true, false) => *json_target = value,
+        (false, true) => *javascript_target = value,
+        (true, true) => {}
+    }
+}
+
+/// Converts the glob of a section of the file of `directory` to the pattern of an override, see
+/// [EditorConfig::into_overrides].
+///
+/// A glob without `/` matches the name of a file in any directory beneath `directory`. A glob
+/// with `/` is relative to `directory`.
+fn to_pattern(glob: &str, directory: Option<&Path>) -> Option<String> {
+    let directory = directory.map(|directory| Pattern::escape(&directory.to_string_lossy()));
+    let (glob, is_relative) = match glob.strip_prefix('/') {
+        Some(glob) => (glob, true),
+        None => (glob, glob.contains('/')),
+    };
+    let glob = if is_relative {
+        glob.to_string()
+    } else {
+        format!("**/{glob}")
+    };
+    match directory {
+        Some(directory) if directory.is_empty() => Some(glob),
+        Some(directory) => Some(format!("{directory}/{glob}")),
+        None if is_relative => None,
+        None => Some(glob),
+    }
+}
+
+/// Returns the options of the top level formatter that the configuration files at `file_paths`
+/// set, from the file with the lowest precedence to the file with the highest, e.g. the files
+/// of `extends` then the file that extends them.
+///
+/// The configuration can't tell them apart from the defaults: the options that the files don't
+/// set take their default value.
+pub fn configured_formatter_options(
+    fs: &dyn FileSystem,
+    file_paths: &[PathBuf],
+) -> OverrideFormatterConfiguration {
+    let mut options = OverrideFormatterConfiguration::default();
+    for file_path in file_paths {
+        let Ok(content) = fs.read_to_string(file_path) else {
+            continue;
+        };
+        let parser_options = if file_path.ends_with(BIOME_JSONC) {
+            JsonParserOptions::default().with_allow_comments()
+        } else {
+            JsonParserOptions::default()
+        };
+        // The configuration is read as an override: the fields of an override that aren't in
+        // the file stay empty
+        if let Some(formatter) =
+            deserialize_from_json_str::<OverridePattern>(&content, parser_options)
+                .into_deserialized()
+                .and_then(|pattern| pattern.formatter)
+        {
+            options.merge_with(formatter);
+        }
+    }
+    options
+}
+
+/// The `.editorconfig` files that apply to the files of a project, looked up lazily
+#[derive(Debug)]
+pub struct EditorConfigs {
+    /// The directory the relative paths are resolved from
+    working_directory: Option<PathBuf>,
+    /// The options of the formatter that the configuration sets, see
+    /// [configured_formatter_options]
+    formatter: OverrideFormatterConfiguration,
+    /// The configuration of the languages, whose formatter options take precedence over the
+    /// files too
+    configuration: Configuration,
+    /// The directories already looked up, with the fingerprint of the files that apply to the
+    /// files beneath them
+    directories: Mutex<FxHashMap<PathBuf, u64>>,
+}
+
+/// An `.editorconfig` file found while looking up a directory
+#[derive(Debug)]
+pub struct EditorConfigFile {
+    pub file_path: PathBuf,
+    /// The directory of the file, with its sections, see [EditorConfigOverrides]
+    pub overrides: EditorConfigOverrides,
+    /// The properties of the file that are ignored
+    pub diagnostics: Vec<Error>,
+}
+
+impl EditorConfigs {
+    pub fn new(
+        working_directory: Option<PathBuf>,
+        formatter: OverrideFormatterConfiguration,
+        configuration: &Configuration,
+    ) -> Self {
+        Self {
+            working_directory,
+            formatter,
+            configuration: Configuration {
+                javascript: configuration.javascript.clone(),
+                json: configuration.json.clone(),
+                ..Configuration::default()
+            },
+            directories: Mutex::default(),
+        }
+    }
+
+    /// Returns the files that apply to the files beneath `directory` and weren't looked up yet,
+    /// from the shallowest to the deepest. The lookup stops at the first file with `root = true`.
+    pub fn load(&self, fs: &dyn FileSystem, directory: &Path) -> Vec<EditorConfigFile> {
+        let mut directories = self.directories.lock().unwrap();
+
+        // The directories of the path, then the directories above it
+        let mut pending: Vec<(PathBuf, Option<&Path>)> = vec![];
+        let mut last = directory;
+        for ancestor in directory.ancestors() {
+            pending.push((ancestor.to_path_buf(), Some(ancestor)));
+            last = ancestor;
+            // The paths that start with `./` don't have an empty ancestor
+            if ancestor == Path::new(".")
+                || ancestor.components().last() == Some(Component::ParentDir)
+            {
+                break;
+            }
+        }
+        if let Some(resolved) = self.resolve(last).parent() {
+            pending.extend(
+                resolved
+                    .ancestors()
+                    .map(|ancestor| (ancestor.to_path_buf(), None)),
+            );
+        }
+
+        let mut fingerprint = 0;
+        let mut files = vec![];
+        let mut looked_up = vec![];
+        for (key, directory) in pending {
+            if let Some(known) = directories.get(&key) {
+                fingerprint = *known;
+                break;
+            }
+            let resolved = match directory {
+                Some(directory) => self.resolve(directory),
+                None => key.clone(),
+            };
+            let file = self.load_directory(fs, directory, &resolved);
+            let is_root = file.as_ref().is_some_and(|(file, _)| file.overrides.root);
+            looked_up.push((key, file));
+            if is_root {
+                break;
+            }
+        }
+
+        for (key, file) in looked_up.into_iter().rev() {
+            if let Some((file, content)) = file {
+                let mut hasher = StableHasher::new();
+                hasher.write_u64(fingerprint);
+                hasher.write_str(&content);
+                fingerprint = hasher.finish();
+                files.push(file);
+            }
+            directories.insert(key, fingerprint);
+        }
+        files
+    }
+
+    /// Returns the fingerprint of the files that apply to the files beneath `directory`, once
+    /// it's looked up
+    pub fn fingerprint(&self, directory: &Path) -> u64 {
+        let directories = self.directories.lock().unwrap();
+        directories.get(directory).copied().unwrap_or_default()
+    }
+
+    /// Loads the `.editorconfig` file of `directory`, if it has one, with the content of the
+    /// file. `resolved_directory` is where the file is read, `directory` is how the paths of the
+    /// files beneath it are spelled.
+    fn load_directory(
+        &self,
+        fs: &dyn FileSystem,
+        directory: Option<&Path>,
+        resolved_directory: &Path,
+    ) -> Option<(EditorConfigFile, String)> {
+        let file_path = resolved_directory.join(EDITORCONFIG);
+        if !fs.path_exists(&file_path) {
+            return None;
+        }
+        let content = match fs.read_to_string(&file_path) {
+            Ok(content) => content,
+            Err(error) => {
+                let diagnostic =
+                    CantLoadExtendFile::new(file_path.display().to_string(), error.to_string());
+                let file = EditorConfigFile {
+                    file_path,
+                    overrides: EditorConfigOverrides::default(),
+                    diagnostics: vec![Error::from(diagnostic)],
+                };
+                return Some((file, String::new()));
+            }
+        };
+
+        let (editorconfig, diagnostics) = EditorConfig::parse(&content);
+        let diagnostics = diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                Error::from(diagnostic)
+                    .with_file_path(file_path.display().to_string())
+                    .with_file_source_code(&content)
+            })
+            .collect();
+        let root = editorconfig.root;
+        let overrides =
+            editorconfig.into_overrides(directory, &self.formatter, &self.configuration);
+        let file = EditorConfigFile {
+            file_path,
+            overrides: EditorConfigOverrides {
+                directory: directory.map(Path::to_path_buf).unwrap_or_default(),
+                root,
+                overrides: Overrides(overrides),
+            },
+            diagnostics,
+        };
+        Some((file, content))
+    }
+
+    /// Resolves `directory` from the working directory
+    fn resolve(&self, directory: &Path) -> PathBuf {
+        match &self.working_directory {
+            Some(working_directory) if directory.is_relative() => working_directory.join(directory),
+            _ => directory.to_path_buf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_supported_properties() {
+        let (editorconfig, diagnostics) = EditorConfig::parse(
+            r#"root = true
+
+[*]
+indent_style = space
+indent_size = 4
+end_of_line = CRLF
+charset = utf-8
+
+# The generated files
+[*.{js,json}]
+max_line_length = 120
+insert_final_newline = false
+indent_size = large
+"#,
+        );
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(editorconfig.sections.len(), 2);
+        assert_eq!(
+            editorconfig.sections[0].options,
+            OverrideFormatterConfiguration {
+                indent_style: Some(PlainIndentStyle::Space),
+                indent_width: Some(4),
+                line_ending: Some(LineEnding::Crlf),
+                ..OverrideFormatterConfiguration::default()
+            }
+        );
+        assert_eq!(
+            editorconfig.sections[1].options.line_width,
+            LineWidth::try_from(120).ok()
+        );
+    }
+
+    #[test]
+    fn parses_the_root_property() {
+        let (editorconfig, _) = EditorConfig::parse("root = true\n\n[*]\nindent_size = 4\n");
+        assert!(editorconfig.root);
+
+        let (editorconfig, _) = EditorConfig::parse("[*]\nroot = true\n");
+        assert!(!editorconfig.root);
+    }
+
+    #[test]
+    fn converts_the_globs_to_patterns() {
+        let root = Some(Path::new(""));
+        assert_eq!(to_pattern("*", root).as_deref(), Some("**/*"));
+        assert_eq!(
+            to_pattern("*.{js,ts}", root).as_deref(),
+            Some("**/*.{js,ts}")
+        );
+        assert_eq!(to_pattern("/src/**.js", root).as_deref(), Some("src/**.js"));
+
+        let directory = Some(Path::new("./packages/a"));
+        assert_eq!(
+            to_pattern("*.js", directory).as_deref(),
+            Some("./packages/a/**/*.js")
+        );
+        assert_eq!(
+            to_pattern("lib/*.js", directory).as_deref(),
+            Some("./packages/a/lib/*.js")
+        );
+
+        assert_eq!(to_pattern("*.js", None).as_deref(), Some("**/*.js"));
+        assert_eq!(to_pattern("lib/*.js", None), None);
+    }
+
+    #[test]
+    fn keeps_the_options_set_by_the_configuration() {
+        let (editorconfig, _) = EditorConfig::parse(
+            "[*]\nindent_style = space\nindent_size = 4\nmax_line_length = 100\n",
+        );
+        let configuration = Configuration {
+            javascript: Some(JavascriptConfiguration {
+                formatter: Some(JavascriptFormatter {
+                    indent_width: Some(8),
+                    ..JavascriptFormatter::default()
+                }),
+                ..JavascriptConfiguration::default()
+            }),
+            ..Configuration::default()
+        };
+        let formatter = OverrideFormatterConfiguration {
+            line_width: LineWidth::try_from(120).ok(),
+            ..OverrideFormatterConfiguration::default()
+        };
+
+        let overrides =
+            editorconfig.into_overrides(Some(Path::new("")), &formatter, &configuration);
+
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(
+            overrides[0].formatter,
+            Some(OverrideFormatterConfiguration {
+                indent_style: Some(PlainIndentStyle::Space),
+                ..OverrideFormatterConfiguration::default()
+            })
+        );
+        assert_eq!(overrides[0].javascript, None);
+        assert_eq!(
+            overrides[0]
+                .json
+                .as_ref()
+                .and_then(|json| json.formatter.as_ref())
+                .and_then(|formatter| formatter.indent_width),
+            Some(4)
+        );
+    }
+}
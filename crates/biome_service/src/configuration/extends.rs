@@ -0,0 +1,179 @@
+//! The resolution of the `extends` field of the configuration: the extended files are
+//! paths relative to the configuration file, or npm packages that export a configuration.
+//!
+//! The configurations published on remote URLs aren't supported: they must be published as
+//! npm packages, so that they are installed and pinned by the package manager.
+
+use crate::configuration::diagnostics::CantLoadExtendFile;
+use crate::{Configuration, DynRef, MergeWith, WorkspaceError};
+use biome_console::markup;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{Deserialized, StringSet};
+use biome_fs::{FileSystem, FileSystemExt, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_project::PackageJson;
+use biome_resolver::resolve_exports;
+use std::path::{Path, PathBuf};
+
+/// The conditions of the `exports` of a package matched when it's extended, in addition to
+/// `default`
+const EXPORTS_CONDITIONS: &[&str] = &["import", "require"];
+
+/// A configuration file listed in `extends`
+#[derive(Debug)]
+pub struct ExtendedConfiguration {
+    /// The resolved path of the file
+    pub file_path: PathBuf,
+    pub deserialized: Deserialized<Configuration>,
+}
+
+/// Reads the configuration files of `extends`, in order. The paths are relative to
+/// `directory_path`, the directory of the configuration file that extends them.
+///
+/// If a configuration can't be resolved from the file system, the operation fails.
+pub fn load_extended_configurations(
+    fs: &DynRef<dyn FileSystem>,
+    directory_path: &Path,
+    extends: &StringSet,
+) -> Result<Vec<ExtendedConfiguration>, WorkspaceError> {
+    let mut extended_configurations = vec![];
+    for path in extends.iter() {
+        if is_remote_url(path) {
+            return Err(CantLoadExtendFile::new(
+                path.as_str(),
+                "Biome can't load a configuration from a remote URL.",
+            )
+            .with_verbose_advice(markup! {
+                "Publish the configuration as an npm package, install it, and add the name of the package to "<Emphasis>"extends"</Emphasis>"."
+            })
+            .into());
+        }
+
+        let mut config_path = directory_path.join(path);
+        if !fs.path_exists(config_path.as_path()) && is_bare_specifier(path) {
+            if let Some(package_config_path) = resolve_package(fs, directory_path, path) {
+                if !is_json_file(&package_config_path) {
+                    return Err(CantLoadExtendFile::new(
+                        path.as_str(),
+                        "The package doesn't export a JSON file.",
+                    )
+                    .with_verbose_advice(markup! {
+                        "The package exports "<Emphasis>{package_config_path.display().to_string()}</Emphasis>". Map it to a "<Emphasis>".json"</Emphasis>" or a "<Emphasis>".jsonc"</Emphasis>" file in the "<Emphasis>"exports"</Emphasis>" of its "<Emphasis>"package.json"</Emphasis>"."
+                    })
+                    .into());
+                }
+                config_path = package_config_path;
+            }
+        }
+        let mut file = fs
+            .open_with_options(config_path.as_path(), OpenOptions::default().read(true))
+            .map_err(|err| {
+                CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
+                    markup!{
+                        "Biome tried to load the configuration file "<Emphasis>{directory_path.display().to_string()}</Emphasis>" using "<Emphasis>{config_path.display().to_string()}</Emphasis>" as base path."
+                    }
+                )
+            })?;
+        let mut content = String::new();
+        file.read_to_string(&mut content).map_err(|err| {
+            CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
+                markup!{
+                    "It's possible that the file was created with a different user/group. Make sure you have the rights to read the file."
+                }
+            )
+        })?;
+        let deserialized = deserialize_from_json_str::<Configuration>(
+            content.as_str(),
+            JsonParserOptions::default(),
+        );
+        extended_configurations.push(ExtendedConfiguration {
+            file_path: config_path,
+            deserialized,
+        });
+    }
+    Ok(extended_configurations)
+}
+
+/// Applies the extended configurations from left to right, then `configuration` over them.
+/// Only the values of `configuration` that aren't a default are kept.
+pub fn merge_extended_configurations(
+    configuration: Configuration,
+    extended_configurations: impl IntoIterator<Item = Configuration>,
+) -> Configuration {
+    let extended_configuration = extended_configurations.into_iter().reduce(
+        |mut previous_configuration, current_configuration| {
+            previous_configuration.merge_with(current_configuration);
+            previous_configuration
+        },
+    );
+    match extended_configuration {
+        Some(mut extended_configuration) => {
+            extended_configuration.merge_with_if_not_default(configuration);
+            extended_configuration
+        }
+        None => configuration,
+    }
+}
+
+fn is_remote_url(specifier: &str) -> bool {
+    specifier.starts_with("https://") || specifier.starts_with("http://")
+}
+
+/// Returns `true` if the configuration file at `path` is a JSON file
+fn is_json_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "json" || extension == "jsonc")
+}
+
+/// Returns `true` if `specifier` can be the name of an npm package, with an optional
+/// subpath, e.g. `@acme/biome-config` or `@acme/biome-config/strict`
+fn is_bare_specifier(specifier: &str) -> bool {
+    !specifier.starts_with('.') && !Path::new(specifier).has_root()
+}
+
+/// Resolves the configuration file exported by an installed npm package, looking for the
+/// package in the `node_modules` directories of `directory_path` and of its ancestors,
+/// like Node.js does.
+///
+/// The file is the target of the `exports` of the package that matches the subpath of
+/// `specifier`. Without `exports`, it's the file at the subpath, or the `biome.json` of the
+/// package. The `main` field is ignored: it's the entry point of the JavaScript code of the
+/// package, not a configuration.
+fn resolve_package(
+    fs: &DynRef<dyn FileSystem>,
+    directory_path: &Path,
+    specifier: &str,
+) -> Option<PathBuf> {
+    // The name of a scoped package has two segments, e.g. `@acme/biome-config`
+    let mut segments = specifier.splitn(if specifier.starts_with('@') { 3 } else { 2 }, '/');
+    let name = if specifier.starts_with('@') {
+        format!("{}/{}", segments.next()?, segments.next()?)
+    } else {
+        segments.next()?.to_string()
+    };
+    let subpath = segments.next();
+
+    for directory in directory_path.ancestors() {
+        let package_path = directory.join("node_modules").join(&name);
+        let manifest_path = package_path.join("package.json");
+        if !fs.path_exists(manifest_path.as_path()) {
+            continue;
+        }
+
+        let content = fs.read_to_string(manifest_path.as_path()).ok()?;
+        let manifest = PackageJson::parse(&content)?;
+        let target = match &manifest.exports {
+            Some(exports) => {
+                let subpath =
+                    subpath.map_or_else(|| String::from("."), |subpath| format!("./{subpath}"));
+                let conditions: Vec<_> =
+                    EXPORTS_CONDITIONS.iter().map(ToString::to_string).collect();
+                resolve_exports(exports, &subpath, &conditions)?
+            }
+            None => subpath.unwrap_or("biome.json").to_string(),
+        };
+        return Some(package_path.join(target.trim_start_matches("./")));
+    }
+
+    None
+}
@@ -61,12 +61,22 @@ pub struct FormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub include: Option<StringSet>,
+
+    /// Use the `.editorconfig` file of the project to set the options of the formatter that
+    /// the configuration doesn't set. `false` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub use_editorconfig: Option<bool>,
 }
 
 impl FormatterConfiguration {
     pub const fn is_disabled(&self) -> bool {
         matches!(self.enabled, Some(false))
     }
+
+    pub const fn use_editorconfig(&self) -> bool {
+        matches!(self.use_editorconfig, Some(true))
+    }
 }
 
 impl Default for FormatterConfiguration {
@@ -81,6 +91,7 @@ impl Default for FormatterConfiguration {
             line_width: Some(LineWidth::default()),
             ignore: None,
             include: None,
+            use_editorconfig: None,
         }
     }
 }
@@ -125,6 +136,10 @@ impl MergeWith<FormatterConfiguration> for FormatterConfiguration {
         if let Some(include) = other.include {
             self.include = Some(include)
         }
+
+        if let Some(use_editorconfig) = other.use_editorconfig {
+            self.use_editorconfig = Some(use_editorconfig);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: FormatterConfiguration)
@@ -21,7 +21,11 @@ pub struct JavascriptFormatter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_quote_style: Option<QuoteStyle>,
     /// When properties in objects are quoted. Defaults to asNeeded.
-    #[bpaf(long("quote-properties"), argument("preserve|as-needed"), optional)]
+    #[bpaf(
+        long("quote-properties"),
+        argument("preserve|as-needed|consistent"),
+        optional
+    )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quote_properties: Option<QuoteProperties>,
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
@@ -44,6 +48,30 @@ pub struct JavascriptFormatter {
     #[bpaf(long("bracket-same-line"), argument("true|false"), optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bracket_same_line: Option<bool>,
+    /// Whether to keep short if statements on a single line.
+    #[bpaf(long("single-line-if-statements"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_line_if_statements: Option<bool>,
+    /// Whether to add a blank line after the imports.
+    #[bpaf(long("blank-line-after-imports"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blank_line_after_imports: Option<bool>,
+    /// Whether to add a blank line before return statements.
+    #[bpaf(long("blank-line-before-return"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blank_line_before_return: Option<bool>,
+    /// Whether multiline unions start with a `|`.
+    #[bpaf(long("union-leading-separator"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub union_leading_separator: Option<bool>,
+    /// Unions with more members than this always break.
+    #[bpaf(long("union-break-threshold"), argument("NUMBER"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub union_break_threshold: Option<u8>,
+    /// Whether the formatter keeps the rendered text of the JSX elements unchanged.
+    #[bpaf(long("jsx-whitespace-fidelity"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jsx_whitespace_fidelity: Option<bool>,
 
     /// Control the formatter for JavaScript (and its super languages) files.
     #[bpaf(long("javascript-formatter-enabled"), argument("true|false"), optional)]
@@ -103,6 +131,24 @@ impl MergeWith<JavascriptFormatter> for JavascriptFormatter {
         if let Some(bracket_same_line) = other.bracket_same_line {
             self.bracket_same_line = Some(bracket_same_line);
         }
+        if let Some(single_line_if_statements) = other.single_line_if_statements {
+            self.single_line_if_statements = Some(single_line_if_statements);
+        }
+        if let Some(blank_line_after_imports) = other.blank_line_after_imports {
+            self.blank_line_after_imports = Some(blank_line_after_imports);
+        }
+        if let Some(blank_line_before_return) = other.blank_line_before_return {
+            self.blank_line_before_return = Some(blank_line_before_return);
+        }
+        if let Some(union_leading_separator) = other.union_leading_separator {
+            self.union_leading_separator = Some(union_leading_separator);
+        }
+        if let Some(union_break_threshold) = other.union_break_threshold {
+            self.union_break_threshold = Some(union_break_threshold);
+        }
+        if let Some(jsx_whitespace_fidelity) = other.jsx_whitespace_fidelity {
+            self.jsx_whitespace_fidelity = Some(jsx_whitespace_fidelity);
+        }
         if let Some(quote_properties) = other.quote_properties {
             self.quote_properties = Some(quote_properties);
         }
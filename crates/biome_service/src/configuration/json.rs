@@ -114,6 +114,15 @@ pub struct JsonFormatter {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("json-formatter-line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
+
+    /// Sort the keys of `package.json` files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(
+        long("json-formatter-sort-package-json-keys"),
+        argument("true|false"),
+        optional
+    )]
+    pub sort_package_json_keys: Option<bool>,
 }
 
 impl MergeWith<JsonFormatter> for JsonFormatter {
@@ -130,6 +139,9 @@ impl MergeWith<JsonFormatter> for JsonFormatter {
         if let Some(line_width) = other.line_width {
             self.line_width = Some(line_width);
         }
+        if let Some(sort_package_json_keys) = other.sort_package_json_keys {
+            self.sort_package_json_keys = Some(sort_package_json_keys);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: JsonFormatter)
@@ -140,6 +140,36 @@ impl RuleConfiguration {
     pub fn is_enabled(&self) -> bool {
         !self.is_disabled()
     }
+
+    /// The fixes of the rule that are applied, if the configuration sets them
+    pub fn fix_kind(&self) -> Option<RuleFixKind> {
+        match self {
+            Self::Plain(_) => None,
+            Self::WithOptions(rule) => rule.fix,
+        }
+    }
+
+    /// Whether the rule applies to a file, according to its `directives` option.
+    /// `has_directive` tells whether the directive prologue of the file contains a directive,
+    /// e.g. `use client`
+    pub fn applies_to_directives(&self, has_directive: impl Fn(&str) -> bool) -> bool {
+        let Self::WithOptions(RuleWithOptions {
+            directives: Some(directives),
+            ..
+        }) = self
+        else {
+            return true;
+        };
+        let (excluded, required): (Vec<_>, Vec<_>) = directives
+            .iter()
+            .partition(|directive| directive.starts_with('!'));
+        let has_required =
+            required.is_empty() || required.iter().any(|directive| has_directive(directive));
+        has_required
+            && excluded
+                .iter()
+                .all(|directive| !has_directive(&directive[1..]))
+    }
 }
 impl Default for RuleConfiguration {
     fn default() -> Self {
@@ -192,11 +222,103 @@ impl FromStr for RulePlainConfiguration {
     }
 }
 
+/// The severity of the rules of a group that don't set their own level
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum RuleGroupLevel {
+    Warn,
+    Error,
+}
+
+impl FromStr for RuleGroupLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "warn" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            _ => Err("Invalid level for group".to_string()),
+        }
+    }
+}
+
+impl From<RuleGroupLevel> for Severity {
+    fn from(level: RuleGroupLevel) -> Self {
+        match level {
+            RuleGroupLevel::Warn => Severity::Warning,
+            RuleGroupLevel::Error => Severity::Error,
+        }
+    }
+}
+
+/// The configuration of the `nursery` group: its rules, or a preset
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum NurseryConfiguration {
+    Preset(NurseryPreset),
+    Rules(Nursery),
+}
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum NurseryPreset {
+    /// Enables the nursery rules that will be recommended once they are stable, with the
+    /// severity `warn`. They report errors when they leave the nursery.
+    WarnOnRecommended,
+}
+
+impl FromStr for NurseryPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "warnOnRecommended" => Ok(Self::WarnOnRecommended),
+            _ => Err("Invalid preset for the nursery group".to_string()),
+        }
+    }
+}
+
+impl From<NurseryConfiguration> for Nursery {
+    fn from(configuration: NurseryConfiguration) -> Self {
+        match configuration {
+            NurseryConfiguration::Preset(NurseryPreset::WarnOnRecommended) => Self {
+                recommended: Some(true),
+                level: Some(RuleGroupLevel::Warn),
+                ..Self::default()
+            },
+            NurseryConfiguration::Rules(nursery) => nursery,
+        }
+    }
+}
+
+/// Deserializes the `nursery` group, which is either an object or the name of a preset
+pub(crate) fn deserialize_nursery<'de, D>(deserializer: D) -> Result<Option<Nursery>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let configuration = Option::<NurseryConfiguration>::deserialize(deserializer)?;
+    Ok(configuration.map(Nursery::from))
+}
+
 #[derive(Default, Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct RuleWithOptions {
     pub level: RulePlainConfiguration,
+    /// The fixes of the rule that `--apply` and `--apply-unsafe` apply. By default, `--apply`
+    /// applies the safe fixes and `--apply-unsafe` applies all of them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub fix: Option<RuleFixKind>,
+    /// The rule only applies to the JavaScript files whose directive prologue contains one of
+    /// these directives, e.g. `use client`. A directive that starts with `!`, e.g.
+    /// `!use client`, excludes the files that contain it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub directives: Option<StringSet>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(possible_options), hide, optional)]
     pub options: Option<PossibleOptions>,
@@ -207,7 +329,32 @@ impl FromStr for RuleWithOptions {
     fn from_str(_s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             level: RulePlainConfiguration::default(),
+            fix: None,
+            directives: None,
             options: None,
         })
     }
 }
+
+/// The fixes of a rule that are applied
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum RuleFixKind {
+    /// Only the safe fixes, even with `--apply-unsafe`
+    Safe,
+    /// The safe and the unsafe fixes, even with `--apply`
+    Unsafe,
+}
+
+impl FromStr for RuleFixKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "safe" => Ok(Self::Safe),
+            "unsafe" => Ok(Self::Unsafe),
+            _ => Err("Invalid fix kind for rule".to_string()),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+use crate::configuration::linter::{deserialize_nursery, RuleGroupLevel};
 use crate::{MergeWith, RuleConfiguration};
 use biome_analyze::RuleFilter;
 use biome_diagnostics::{Category, Severity};
@@ -29,7 +30,15 @@ pub struct Rules {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external, hide, optional)]
     pub correctness: Option<Correctness>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_nursery",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(with = "Option<crate::configuration::linter::NurseryConfiguration>")
+    )]
     #[bpaf(external, hide, optional)]
     pub nursery: Option<Nursery>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -112,6 +121,17 @@ impl MergeWith<Rules> for Rules {
     }
 }
 impl Rules {
+    #[doc = r" The groups of rules, with the names of their rules"]
+    pub(crate) const GROUPS: &'static [(&'static str, &'static [&'static str])] = &[
+        ("a11y", &A11y::GROUP_RULES),
+        ("complexity", &Complexity::GROUP_RULES),
+        ("correctness", &Correctness::GROUP_RULES),
+        ("nursery", &Nursery::GROUP_RULES),
+        ("performance", &Performance::GROUP_RULES),
+        ("security", &Security::GROUP_RULES),
+        ("style", &Style::GROUP_RULES),
+        ("suspicious", &Suspicious::GROUP_RULES),
+    ];
     #[doc = r" Checks if the code coming from [biome_diagnostics::Diagnostic] corresponds to a rule."]
     #[doc = r" Usually the code is built like {category}/{rule_name}"]
     pub fn matches_diagnostic_code<'a>(
@@ -149,8 +169,7 @@ impl Rules {
                 "a11y" => self
                     .a11y
                     .as_ref()
-                    .and_then(|a11y| a11y.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|a11y| a11y.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if A11y::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -161,8 +180,7 @@ impl Rules {
                 "complexity" => self
                     .complexity
                     .as_ref()
-                    .and_then(|complexity| complexity.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|complexity| complexity.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Complexity::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -173,8 +191,7 @@ impl Rules {
                 "correctness" => self
                     .correctness
                     .as_ref()
-                    .and_then(|correctness| correctness.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|correctness| correctness.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Correctness::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -185,8 +202,7 @@ impl Rules {
                 "nursery" => self
                     .nursery
                     .as_ref()
-                    .and_then(|nursery| nursery.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|nursery| nursery.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Nursery::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -197,8 +213,7 @@ impl Rules {
                 "performance" => self
                     .performance
                     .as_ref()
-                    .and_then(|performance| performance.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|performance| performance.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Performance::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -209,8 +224,7 @@ impl Rules {
                 "security" => self
                     .security
                     .as_ref()
-                    .and_then(|security| security.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|security| security.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Security::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -221,8 +235,7 @@ impl Rules {
                 "style" => self
                     .style
                     .as_ref()
-                    .and_then(|style| style.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|style| style.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Style::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -233,8 +246,7 @@ impl Rules {
                 "suspicious" => self
                     .suspicious
                     .as_ref()
-                    .and_then(|suspicious| suspicious.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|suspicious| suspicious.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Suspicious::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -249,6 +261,49 @@ impl Rules {
             None
         }
     }
+    #[doc = r" Returns the configuration of the rule `rule_name` of the group `group`, if the"]
+    #[doc = r" configuration sets it"]
+    pub(crate) fn get_rule_configuration(
+        &self,
+        group: &str,
+        rule_name: &str,
+    ) -> Option<&RuleConfiguration> {
+        match group {
+            "a11y" => self
+                .a11y
+                .as_ref()
+                .and_then(|a11y| a11y.get_rule_configuration(rule_name)),
+            "complexity" => self
+                .complexity
+                .as_ref()
+                .and_then(|complexity| complexity.get_rule_configuration(rule_name)),
+            "correctness" => self
+                .correctness
+                .as_ref()
+                .and_then(|correctness| correctness.get_rule_configuration(rule_name)),
+            "nursery" => self
+                .nursery
+                .as_ref()
+                .and_then(|nursery| nursery.get_rule_configuration(rule_name)),
+            "performance" => self
+                .performance
+                .as_ref()
+                .and_then(|performance| performance.get_rule_configuration(rule_name)),
+            "security" => self
+                .security
+                .as_ref()
+                .and_then(|security| security.get_rule_configuration(rule_name)),
+            "style" => self
+                .style
+                .as_ref()
+                .and_then(|style| style.get_rule_configuration(rule_name)),
+            "suspicious" => self
+                .suspicious
+                .as_ref()
+                .and_then(|suspicious| suspicious.get_rule_configuration(rule_name)),
+            _ => None,
+        }
+    }
     pub(crate) const fn is_recommended(&self) -> bool {
         !matches!(self.recommended, Some(false))
     }
@@ -266,6 +321,7 @@ impl Rules {
         let mut disabled_rules = IndexSet::new();
         if let Some(group) = self.a11y.as_ref() {
             group.collect_preset_rules(
+                self.is_all(),
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -281,6 +337,7 @@ impl Rules {
         }
         if let Some(group) = self.complexity.as_ref() {
             group.collect_preset_rules(
+                self.is_all(),
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -296,6 +353,7 @@ impl Rules {
         }
         if let Some(group) = self.correctness.as_ref() {
             group.collect_preset_rules(
+                self.is_all(),
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -311,6 +369,7 @@ impl Rules {
         }
         if let Some(group) = self.nursery.as_ref() {
             group.collect_preset_rules(
+                self.is_all(),
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -326,6 +385,7 @@ impl Rules {
         }
         if let Some(group) = self.performance.as_ref() {
             group.collect_preset_rules(
+                self.is_all(),
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -341,6 +401,7 @@ impl Rules {
         }
         if let Some(group) = self.security.as_ref() {
             group.collect_preset_rules(
+                self.is_all(),
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -356,6 +417,7 @@ impl Rules {
         }
         if let Some(group) = self.style.as_ref() {
             group.collect_preset_rules(
+                self.is_all(),
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -371,6 +433,7 @@ impl Rules {
         }
         if let Some(group) = self.suspicious.as_ref() {
             group.collect_preset_rules(
+                self.is_all(),
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -429,6 +492,10 @@ pub struct A11y {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r" The severity of the rules of this group that don't set their own level"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RuleGroupLevel>,
     #[doc = "Enforce that the accessKey attribute is not used on any HTML element."]
     #[bpaf(long("no-access-key"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -589,6 +656,15 @@ pub struct A11y {
 }
 impl MergeWith<A11y> for A11y {
     fn merge_with(&mut self, other: A11y) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_access_key) = other.no_access_key {
             self.no_access_key = Some(no_access_key);
         }
@@ -827,6 +903,11 @@ impl A11y {
     pub(crate) fn is_not_all(&self) -> bool {
         matches!(self.all, Some(false))
     }
+    #[doc = r" Whether the group enables all its rules when `rules.all` is `true`: it doesn't"]
+    #[doc = r" set `all` nor `recommended` itself"]
+    pub(crate) fn inherits_all(&self) -> bool {
+        self.all.is_none() && self.recommended.is_none()
+    }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_access_key.as_ref() {
@@ -1132,11 +1213,12 @@ impl A11y {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: bool,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        if self.is_all() || (parent_is_all && self.inherits_all()) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
@@ -1186,6 +1268,13 @@ impl A11y {
             _ => None,
         }
     }
+    #[doc = r" The severity of a rule of this group: its own level, or the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .filter(|rule_setting| rule_setting.is_enabled())
+            .map(Severity::from)
+            .or_else(|| self.level.map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -1200,6 +1289,10 @@ pub struct Complexity {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r" The severity of the rules of this group that don't set their own level"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RuleGroupLevel>,
     #[doc = "Disallow primitive type aliases and misleading types."]
     #[bpaf(long("no-banned-types"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1339,6 +1432,15 @@ pub struct Complexity {
 }
 impl MergeWith<Complexity> for Complexity {
     fn merge_with(&mut self, other: Complexity) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_banned_types) = other.no_banned_types {
             self.no_banned_types = Some(no_banned_types);
         }
@@ -1537,6 +1639,11 @@ impl Complexity {
     pub(crate) fn is_not_all(&self) -> bool {
         matches!(self.all, Some(false))
     }
+    #[doc = r" Whether the group enables all its rules when `rules.all` is `true`: it doesn't"]
+    #[doc = r" set `all` nor `recommended` itself"]
+    pub(crate) fn inherits_all(&self) -> bool {
+        self.all.is_none() && self.recommended.is_none()
+    }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_banned_types.as_ref() {
@@ -1808,11 +1915,12 @@ impl Complexity {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: bool,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        if self.is_all() || (parent_is_all && self.inherits_all()) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
@@ -1854,6 +1962,13 @@ impl Complexity {
             _ => None,
         }
     }
+    #[doc = r" The severity of a rule of this group: its own level, or the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .filter(|rule_setting| rule_setting.is_enabled())
+            .map(Severity::from)
+            .or_else(|| self.level.map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -1868,6 +1983,10 @@ pub struct Correctness {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r" The severity of the rules of this group that don't set their own level"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RuleGroupLevel>,
     #[doc = "Prevent passing of children as props."]
     #[bpaf(long("no-children-prop"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2073,6 +2192,15 @@ pub struct Correctness {
 }
 impl MergeWith<Correctness> for Correctness {
     fn merge_with(&mut self, other: Correctness) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_children_prop) = other.no_children_prop {
             self.no_children_prop = Some(no_children_prop);
         }
@@ -2329,6 +2457,11 @@ impl Correctness {
     pub(crate) fn is_not_all(&self) -> bool {
         matches!(self.all, Some(false))
     }
+    #[doc = r" Whether the group enables all its rules when `rules.all` is `true`: it doesn't"]
+    #[doc = r" set `all` nor `recommended` itself"]
+    pub(crate) fn inherits_all(&self) -> bool {
+        self.all.is_none() && self.recommended.is_none()
+    }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_children_prop.as_ref() {
@@ -2684,11 +2817,12 @@ impl Correctness {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: bool,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        if self.is_all() || (parent_is_all && self.inherits_all()) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
@@ -2737,6 +2871,13 @@ impl Correctness {
             _ => None,
         }
     }
+    #[doc = r" The severity of a rule of this group: its own level, or the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .filter(|rule_setting| rule_setting.is_enabled())
+            .map(Severity::from)
+            .or_else(|| self.level.map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -2751,6 +2892,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r" The severity of the rules of this group that don't set their own level"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RuleGroupLevel>,
     #[doc = "Enforce that aria-hidden=\"true\" is not set on focusable elements."]
     #[bpaf(
         long("no-aria-hidden-on-focusable"),
@@ -2760,10 +2905,26 @@ pub struct Nursery {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_aria_hidden_on_focusable: Option<RuleConfiguration>,
+    #[doc = "Disallow barrel files."]
+    #[bpaf(long("no-barrel-file"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_barrel_file: Option<RuleConfiguration>,
+    #[doc = "Disallow calling `expect` conditionally in the tests."]
+    #[bpaf(long("no-conditional-expect"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_conditional_expect: Option<RuleConfiguration>,
     #[doc = "Disallow default exports."]
     #[bpaf(long("no-default-export"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_default_export: Option<RuleConfiguration>,
+    #[doc = "Disallow calling the functions and the classes marked with the JSDoc tag @deprecated."]
+    #[bpaf(long("no-deprecated-usage"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deprecated_usage: Option<RuleConfiguration>,
+    #[doc = "Disallow the `done` callback of the tests and the hooks."]
+    #[bpaf(long("no-done-callback"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_done_callback: Option<RuleConfiguration>,
     #[doc = "Disallow two keys with the same name inside a JSON object."]
     #[bpaf(
         long("no-duplicate-json-keys"),
@@ -2773,6 +2934,15 @@ pub struct Nursery {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_json_keys: Option<RuleConfiguration>,
+    #[doc = "Disallow calling the `Function` constructor with arguments that aren't literals."]
+    #[bpaf(
+        long("no-dynamic-function-constructor"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_dynamic_function_constructor: Option<RuleConfiguration>,
     #[doc = "Disallow empty block statements and static blocks."]
     #[bpaf(
         long("no-empty-block-statements"),
@@ -2782,10 +2952,97 @@ pub struct Nursery {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_empty_block_statements: Option<RuleConfiguration>,
+    #[doc = "Disallow the files that have more lines than a given maximum."]
+    #[bpaf(
+        long("no-excessive-file-length"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_excessive_file_length: Option<RuleConfiguration>,
+    #[doc = "Disallow the functions that have more parameters than a given maximum."]
+    #[bpaf(long("no-excessive-params"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_excessive_params: Option<RuleConfiguration>,
+    #[doc = "Disallow the functions that have more lines than a given maximum."]
+    #[bpaf(
+        long("no-excessively-long-functions"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_excessively_long_functions: Option<RuleConfiguration>,
+    #[doc = "Disallow the hooks of React that need a client in the Server Components."]
+    #[bpaf(
+        long("no-hooks-in-server-components"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_hooks_in_server_components: Option<RuleConfiguration>,
+    #[doc = "Disallow the tests and the `describe` blocks that have the title of a sibling."]
+    #[bpaf(
+        long("no-identical-test-titles"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_identical_test_titles: Option<RuleConfiguration>,
     #[doc = "Disallow use of implicit any type on variable declarations."]
     #[bpaf(long("no-implicit-any-let"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_any_let: Option<RuleConfiguration>,
+    #[doc = "Disallow the literal texts in JSX that aren't localized."]
+    #[bpaf(long("no-jsx-literal-text"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_jsx_literal_text: Option<RuleConfiguration>,
+    #[doc = "Disallow promises in places that aren't designed to handle them."]
+    #[bpaf(long("no-misused-promises"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_misused_promises: Option<RuleConfiguration>,
+    #[doc = "Disallow export * re-exports."]
+    #[bpaf(long("no-re-export-all"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_re_export_all: Option<RuleConfiguration>,
+    #[doc = "Disallow the `javascript:` URLs in the links and the sources of the JSX elements."]
+    #[bpaf(long("no-script-url"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_script_url: Option<RuleConfiguration>,
+    #[doc = "Disallow the imports of packages that aren't declared in the `package.json`."]
+    #[bpaf(
+        long("no-undeclared-dependencies"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_undeclared_dependencies: Option<RuleConfiguration>,
+    #[doc = "Disallow assigning the HTML built with a template literal to `innerHTML` or `outerHTML`."]
+    #[bpaf(long("no-unsafe-inner-html"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unsafe_inner_html: Option<RuleConfiguration>,
+    #[doc = "Disallow the syntax and the APIs that the browsers targeted by the project don't support."]
+    #[bpaf(
+        long("no-unsupported-browser-apis"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unsupported_browser_apis: Option<RuleConfiguration>,
+    #[doc = "Disallow the built-in modules and APIs of Node.js that aren't available in the versions that the project supports."]
+    #[bpaf(
+        long("no-unsupported-node-builtins"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unsupported_node_builtins: Option<RuleConfiguration>,
     #[doc = "Disallow unused imports."]
     #[bpaf(long("no-unused-imports"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2812,6 +3069,24 @@ pub struct Nursery {
     #[bpaf(long("use-await"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_await: Option<RuleConfiguration>,
+    #[doc = "Require a consistent syntax for the array types."]
+    #[bpaf(
+        long("use-consistent-array-type"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_array_type: Option<RuleConfiguration>,
+    #[doc = "Require a consistent syntax for the object types that only have an index signature."]
+    #[bpaf(
+        long("use-consistent-record-type"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_record_type: Option<RuleConfiguration>,
     #[doc = "Enforce the use of import type when an import only has specifiers with type qualifier."]
     #[bpaf(
         long("use-grouped-type-import"),
@@ -2830,32 +3105,112 @@ pub struct Nursery {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_import_restrictions: Option<RuleConfiguration>,
+    #[doc = "Enforce that the @param tags of a JSDoc comment match the parameters of the function."]
+    #[bpaf(long("use-jsdoc-param-match"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_jsdoc_param_match: Option<RuleConfiguration>,
     #[doc = "Enforce the use of the regular expression literals instead of the RegExp constructor if possible."]
     #[bpaf(long("use-regex-literals"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_regex_literals: Option<RuleConfiguration>,
+    #[doc = "Require the tests and the hooks to be in a `describe` block."]
+    #[bpaf(
+        long("use-top-level-describe"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_top_level_describe: Option<RuleConfiguration>,
     #[doc = "Elements with ARIA roles must use a valid, non-abstract ARIA role."]
     #[bpaf(long("use-valid-aria-role"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_valid_aria_role: Option<RuleConfiguration>,
+    #[doc = "Enforce the syntax of the tags of the JSDoc comments."]
+    #[bpaf(long("use-valid-jsdoc"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_jsdoc: Option<RuleConfiguration>,
 }
 impl MergeWith<Nursery> for Nursery {
     fn merge_with(&mut self, other: Nursery) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_aria_hidden_on_focusable) = other.no_aria_hidden_on_focusable {
             self.no_aria_hidden_on_focusable = Some(no_aria_hidden_on_focusable);
         }
+        if let Some(no_barrel_file) = other.no_barrel_file {
+            self.no_barrel_file = Some(no_barrel_file);
+        }
+        if let Some(no_conditional_expect) = other.no_conditional_expect {
+            self.no_conditional_expect = Some(no_conditional_expect);
+        }
         if let Some(no_default_export) = other.no_default_export {
             self.no_default_export = Some(no_default_export);
         }
+        if let Some(no_deprecated_usage) = other.no_deprecated_usage {
+            self.no_deprecated_usage = Some(no_deprecated_usage);
+        }
+        if let Some(no_done_callback) = other.no_done_callback {
+            self.no_done_callback = Some(no_done_callback);
+        }
         if let Some(no_duplicate_json_keys) = other.no_duplicate_json_keys {
             self.no_duplicate_json_keys = Some(no_duplicate_json_keys);
         }
+        if let Some(no_dynamic_function_constructor) = other.no_dynamic_function_constructor {
+            self.no_dynamic_function_constructor = Some(no_dynamic_function_constructor);
+        }
         if let Some(no_empty_block_statements) = other.no_empty_block_statements {
             self.no_empty_block_statements = Some(no_empty_block_statements);
         }
+        if let Some(no_excessive_file_length) = other.no_excessive_file_length {
+            self.no_excessive_file_length = Some(no_excessive_file_length);
+        }
+        if let Some(no_excessive_params) = other.no_excessive_params {
+            self.no_excessive_params = Some(no_excessive_params);
+        }
+        if let Some(no_excessively_long_functions) = other.no_excessively_long_functions {
+            self.no_excessively_long_functions = Some(no_excessively_long_functions);
+        }
+        if let Some(no_hooks_in_server_components) = other.no_hooks_in_server_components {
+            self.no_hooks_in_server_components = Some(no_hooks_in_server_components);
+        }
+        if let Some(no_identical_test_titles) = other.no_identical_test_titles {
+            self.no_identical_test_titles = Some(no_identical_test_titles);
+        }
         if let Some(no_implicit_any_let) = other.no_implicit_any_let {
             self.no_implicit_any_let = Some(no_implicit_any_let);
         }
+        if let Some(no_jsx_literal_text) = other.no_jsx_literal_text {
+            self.no_jsx_literal_text = Some(no_jsx_literal_text);
+        }
+        if let Some(no_misused_promises) = other.no_misused_promises {
+            self.no_misused_promises = Some(no_misused_promises);
+        }
+        if let Some(no_re_export_all) = other.no_re_export_all {
+            self.no_re_export_all = Some(no_re_export_all);
+        }
+        if let Some(no_script_url) = other.no_script_url {
+            self.no_script_url = Some(no_script_url);
+        }
+        if let Some(no_undeclared_dependencies) = other.no_undeclared_dependencies {
+            self.no_undeclared_dependencies = Some(no_undeclared_dependencies);
+        }
+        if let Some(no_unsafe_inner_html) = other.no_unsafe_inner_html {
+            self.no_unsafe_inner_html = Some(no_unsafe_inner_html);
+        }
+        if let Some(no_unsupported_browser_apis) = other.no_unsupported_browser_apis {
+            self.no_unsupported_browser_apis = Some(no_unsupported_browser_apis);
+        }
+        if let Some(no_unsupported_node_builtins) = other.no_unsupported_node_builtins {
+            self.no_unsupported_node_builtins = Some(no_unsupported_node_builtins);
+        }
         if let Some(no_unused_imports) = other.no_unused_imports {
             self.no_unused_imports = Some(no_unused_imports);
         }
@@ -2868,18 +3223,33 @@ impl MergeWith<Nursery> for Nursery {
         if let Some(use_await) = other.use_await {
             self.use_await = Some(use_await);
         }
+        if let Some(use_consistent_array_type) = other.use_consistent_array_type {
+            self.use_consistent_array_type = Some(use_consistent_array_type);
+        }
+        if let Some(use_consistent_record_type) = other.use_consistent_record_type {
+            self.use_consistent_record_type = Some(use_consistent_record_type);
+        }
         if let Some(use_grouped_type_import) = other.use_grouped_type_import {
             self.use_grouped_type_import = Some(use_grouped_type_import);
         }
         if let Some(use_import_restrictions) = other.use_import_restrictions {
             self.use_import_restrictions = Some(use_import_restrictions);
         }
+        if let Some(use_jsdoc_param_match) = other.use_jsdoc_param_match {
+            self.use_jsdoc_param_match = Some(use_jsdoc_param_match);
+        }
         if let Some(use_regex_literals) = other.use_regex_literals {
             self.use_regex_literals = Some(use_regex_literals);
         }
+        if let Some(use_top_level_describe) = other.use_top_level_describe {
+            self.use_top_level_describe = Some(use_top_level_describe);
+        }
         if let Some(use_valid_aria_role) = other.use_valid_aria_role {
             self.use_valid_aria_role = Some(use_valid_aria_role);
         }
+        if let Some(use_valid_jsdoc) = other.use_valid_jsdoc {
+            self.use_valid_jsdoc = Some(use_valid_jsdoc);
+        }
     }
     fn merge_with_if_not_default(&mut self, other: Nursery)
     where
@@ -2892,20 +3262,43 @@ impl MergeWith<Nursery> for Nursery {
 }
 impl Nursery {
     const GROUP_NAME: &'static str = "nursery";
-    pub(crate) const GROUP_RULES: [&'static str; 13] = [
+    pub(crate) const GROUP_RULES: [&'static str; 36] = [
         "noAriaHiddenOnFocusable",
+        "noBarrelFile",
+        "noConditionalExpect",
         "noDefaultExport",
+        "noDeprecatedUsage",
+        "noDoneCallback",
         "noDuplicateJsonKeys",
+        "noDynamicFunctionConstructor",
         "noEmptyBlockStatements",
+        "noExcessiveFileLength",
+        "noExcessiveParams",
+        "noExcessivelyLongFunctions",
+        "noHooksInServerComponents",
+        "noIdenticalTestTitles",
         "noImplicitAnyLet",
+        "noJsxLiteralText",
+        "noMisusedPromises",
+        "noReExportAll",
+        "noScriptUrl",
+        "noUndeclaredDependencies",
+        "noUnsafeInnerHtml",
+        "noUnsupportedBrowserApis",
+        "noUnsupportedNodeBuiltins",
         "noUnusedImports",
         "noUnusedPrivateClassMembers",
         "noUselessLoneBlockStatements",
         "useAwait",
+        "useConsistentArrayType",
+        "useConsistentRecordType",
         "useGroupedTypeImport",
         "useImportRestrictions",
+        "useJsdocParamMatch",
         "useRegexLiterals",
+        "useTopLevelDescribe",
         "useValidAriaRole",
+        "useValidJsdoc",
     ];
     const RECOMMENDED_RULES: [&'static str; 6] = [
         "noAriaHiddenOnFocusable",
@@ -2917,13 +3310,13 @@ impl Nursery {
     ];
     const RECOMMENDED_RULES_AS_FILTERS: [RuleFilter<'static>; 6] = [
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]),
     ];
-    const ALL_RULES_AS_FILTERS: [RuleFilter<'static>; 13] = [
+    const ALL_RULES_AS_FILTERS: [RuleFilter<'static>; 36] = [
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
@@ -2937,6 +3330,29 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended(&self) -> bool {
@@ -2958,66 +3374,181 @@ impl Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.no_default_export.as_ref() {
+        if let Some(rule) = self.no_barrel_file.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+        if let Some(rule) = self.no_conditional_expect.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.no_empty_block_statements.as_ref() {
+        if let Some(rule) = self.no_default_export.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_implicit_any_let.as_ref() {
+        if let Some(rule) = self.no_deprecated_usage.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_unused_imports.as_ref() {
+        if let Some(rule) = self.no_done_callback.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_unused_private_class_members.as_ref() {
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_useless_lone_block_statements.as_ref() {
+        if let Some(rule) = self.no_dynamic_function_constructor.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.use_await.as_ref() {
+        if let Some(rule) = self.no_empty_block_statements.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.use_grouped_type_import.as_ref() {
+        if let Some(rule) = self.no_excessive_file_length.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_excessive_params.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.use_regex_literals.as_ref() {
+        if let Some(rule) = self.no_excessively_long_functions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.use_valid_aria_role.as_ref() {
+        if let Some(rule) = self.no_hooks_in_server_components.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
+        if let Some(rule) = self.no_identical_test_titles.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+            }
+        }
+        if let Some(rule) = self.no_implicit_any_let.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+            }
+        }
+        if let Some(rule) = self.no_jsx_literal_text.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+            }
+        }
+        if let Some(rule) = self.no_misused_promises.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+            }
+        }
+        if let Some(rule) = self.no_re_export_all.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
+        if let Some(rule) = self.no_script_url.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+            }
+        }
+        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+            }
+        }
+        if let Some(rule) = self.no_unsafe_inner_html.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+            }
+        }
+        if let Some(rule) = self.no_unsupported_browser_apis.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+            }
+        }
+        if let Some(rule) = self.no_unsupported_node_builtins.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+            }
+        }
+        if let Some(rule) = self.no_unused_imports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+            }
+        }
+        if let Some(rule) = self.no_unused_private_class_members.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        if let Some(rule) = self.no_useless_lone_block_statements.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+            }
+        }
+        if let Some(rule) = self.use_await.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_array_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_record_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.use_grouped_type_import.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.use_jsdoc_param_match.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.use_regex_literals.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.use_top_level_describe.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
+        if let Some(rule) = self.use_valid_aria_role.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+            }
+        }
+        if let Some(rule) = self.use_valid_jsdoc.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+            }
+        }
         index_set
     }
     pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
@@ -3027,66 +3558,181 @@ impl Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.no_default_export.as_ref() {
+        if let Some(rule) = self.no_barrel_file.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+        if let Some(rule) = self.no_conditional_expect.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.no_empty_block_statements.as_ref() {
+        if let Some(rule) = self.no_default_export.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_implicit_any_let.as_ref() {
+        if let Some(rule) = self.no_deprecated_usage.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_unused_imports.as_ref() {
+        if let Some(rule) = self.no_done_callback.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_unused_private_class_members.as_ref() {
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_useless_lone_block_statements.as_ref() {
+        if let Some(rule) = self.no_dynamic_function_constructor.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.use_await.as_ref() {
+        if let Some(rule) = self.no_empty_block_statements.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.use_grouped_type_import.as_ref() {
+        if let Some(rule) = self.no_excessive_file_length.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_excessive_params.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.use_regex_literals.as_ref() {
+        if let Some(rule) = self.no_excessively_long_functions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.use_valid_aria_role.as_ref() {
+        if let Some(rule) = self.no_hooks_in_server_components.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
+        if let Some(rule) = self.no_identical_test_titles.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+            }
+        }
+        if let Some(rule) = self.no_implicit_any_let.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+            }
+        }
+        if let Some(rule) = self.no_jsx_literal_text.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+            }
+        }
+        if let Some(rule) = self.no_misused_promises.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+            }
+        }
+        if let Some(rule) = self.no_re_export_all.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
+        if let Some(rule) = self.no_script_url.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+            }
+        }
+        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+            }
+        }
+        if let Some(rule) = self.no_unsafe_inner_html.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+            }
+        }
+        if let Some(rule) = self.no_unsupported_browser_apis.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+            }
+        }
+        if let Some(rule) = self.no_unsupported_node_builtins.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+            }
+        }
+        if let Some(rule) = self.no_unused_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+            }
+        }
+        if let Some(rule) = self.no_unused_private_class_members.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        if let Some(rule) = self.no_useless_lone_block_statements.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+            }
+        }
+        if let Some(rule) = self.use_await.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_array_type.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_record_type.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.use_grouped_type_import.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.use_jsdoc_param_match.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.use_regex_literals.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.use_top_level_describe.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
+        if let Some(rule) = self.use_valid_aria_role.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+            }
+        }
+        if let Some(rule) = self.use_valid_jsdoc.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -3100,12 +3746,13 @@ impl Nursery {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 6] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
-    pub(crate) fn all_rules_as_filters() -> [RuleFilter<'static>; 13] {
+    pub(crate) fn all_rules_as_filters() -> [RuleFilter<'static>; 36] {
         Self::ALL_RULES_AS_FILTERS
     }
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        _parent_is_all: bool,
         _parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
@@ -3124,21 +3771,51 @@ impl Nursery {
     pub(crate) fn get_rule_configuration(&self, rule_name: &str) -> Option<&RuleConfiguration> {
         match rule_name {
             "noAriaHiddenOnFocusable" => self.no_aria_hidden_on_focusable.as_ref(),
+            "noBarrelFile" => self.no_barrel_file.as_ref(),
+            "noConditionalExpect" => self.no_conditional_expect.as_ref(),
             "noDefaultExport" => self.no_default_export.as_ref(),
+            "noDeprecatedUsage" => self.no_deprecated_usage.as_ref(),
+            "noDoneCallback" => self.no_done_callback.as_ref(),
             "noDuplicateJsonKeys" => self.no_duplicate_json_keys.as_ref(),
+            "noDynamicFunctionConstructor" => self.no_dynamic_function_constructor.as_ref(),
             "noEmptyBlockStatements" => self.no_empty_block_statements.as_ref(),
+            "noExcessiveFileLength" => self.no_excessive_file_length.as_ref(),
+            "noExcessiveParams" => self.no_excessive_params.as_ref(),
+            "noExcessivelyLongFunctions" => self.no_excessively_long_functions.as_ref(),
+            "noHooksInServerComponents" => self.no_hooks_in_server_components.as_ref(),
+            "noIdenticalTestTitles" => self.no_identical_test_titles.as_ref(),
             "noImplicitAnyLet" => self.no_implicit_any_let.as_ref(),
+            "noJsxLiteralText" => self.no_jsx_literal_text.as_ref(),
+            "noMisusedPromises" => self.no_misused_promises.as_ref(),
+            "noReExportAll" => self.no_re_export_all.as_ref(),
+            "noScriptUrl" => self.no_script_url.as_ref(),
+            "noUndeclaredDependencies" => self.no_undeclared_dependencies.as_ref(),
+            "noUnsafeInnerHtml" => self.no_unsafe_inner_html.as_ref(),
+            "noUnsupportedBrowserApis" => self.no_unsupported_browser_apis.as_ref(),
+            "noUnsupportedNodeBuiltins" => self.no_unsupported_node_builtins.as_ref(),
             "noUnusedImports" => self.no_unused_imports.as_ref(),
             "noUnusedPrivateClassMembers" => self.no_unused_private_class_members.as_ref(),
             "noUselessLoneBlockStatements" => self.no_useless_lone_block_statements.as_ref(),
             "useAwait" => self.use_await.as_ref(),
+            "useConsistentArrayType" => self.use_consistent_array_type.as_ref(),
+            "useConsistentRecordType" => self.use_consistent_record_type.as_ref(),
             "useGroupedTypeImport" => self.use_grouped_type_import.as_ref(),
             "useImportRestrictions" => self.use_import_restrictions.as_ref(),
+            "useJsdocParamMatch" => self.use_jsdoc_param_match.as_ref(),
             "useRegexLiterals" => self.use_regex_literals.as_ref(),
+            "useTopLevelDescribe" => self.use_top_level_describe.as_ref(),
             "useValidAriaRole" => self.use_valid_aria_role.as_ref(),
+            "useValidJsdoc" => self.use_valid_jsdoc.as_ref(),
             _ => None,
         }
     }
+    #[doc = r" The severity of a rule of this group: its own level, or the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .filter(|rule_setting| rule_setting.is_enabled())
+            .map(Severity::from)
+            .or_else(|| self.level.map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -3153,6 +3830,10 @@ pub struct Performance {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r" The severity of the rules of this group that don't set their own level"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RuleGroupLevel>,
     #[doc = "Disallow the use of spread (...) syntax on accumulators."]
     #[bpaf(
         long("no-accumulating-spread"),
@@ -3169,6 +3850,15 @@ pub struct Performance {
 }
 impl MergeWith<Performance> for Performance {
     fn merge_with(&mut self, other: Performance) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_accumulating_spread) = other.no_accumulating_spread {
             self.no_accumulating_spread = Some(no_accumulating_spread);
         }
@@ -3210,6 +3900,11 @@ impl Performance {
     pub(crate) fn is_not_all(&self) -> bool {
         matches!(self.all, Some(false))
     }
+    #[doc = r" Whether the group enables all its rules when `rules.all` is `true`: it doesn't"]
+    #[doc = r" set `all` nor `recommended` itself"]
+    pub(crate) fn inherits_all(&self) -> bool {
+        self.all.is_none() && self.recommended.is_none()
+    }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_accumulating_spread.as_ref() {
@@ -3255,11 +3950,12 @@ impl Performance {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: bool,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        if self.is_all() || (parent_is_all && self.inherits_all()) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
@@ -3277,6 +3973,13 @@ impl Performance {
             _ => None,
         }
     }
+    #[doc = r" The severity of a rule of this group: its own level, or the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .filter(|rule_setting| rule_setting.is_enabled())
+            .map(Severity::from)
+            .or_else(|| self.level.map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -3291,6 +3994,10 @@ pub struct Security {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r" The severity of the rules of this group that don't set their own level"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RuleGroupLevel>,
     #[doc = "Prevent the usage of dangerous JSX props"]
     #[bpaf(
         long("no-dangerously-set-inner-html"),
@@ -3312,6 +4019,15 @@ pub struct Security {
 }
 impl MergeWith<Security> for Security {
     fn merge_with(&mut self, other: Security) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_dangerously_set_inner_html) = other.no_dangerously_set_inner_html {
             self.no_dangerously_set_inner_html = Some(no_dangerously_set_inner_html);
         }
@@ -3362,6 +4078,11 @@ impl Security {
     pub(crate) fn is_not_all(&self) -> bool {
         matches!(self.all, Some(false))
     }
+    #[doc = r" Whether the group enables all its rules when `rules.all` is `true`: it doesn't"]
+    #[doc = r" set `all` nor `recommended` itself"]
+    pub(crate) fn inherits_all(&self) -> bool {
+        self.all.is_none() && self.recommended.is_none()
+    }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_dangerously_set_inner_html.as_ref() {
@@ -3407,11 +4128,12 @@ impl Security {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: bool,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        if self.is_all() || (parent_is_all && self.inherits_all()) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
@@ -3431,6 +4153,13 @@ impl Security {
             _ => None,
         }
     }
+    #[doc = r" The severity of a rule of this group: its own level, or the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .filter(|rule_setting| rule_setting.is_enabled())
+            .map(Severity::from)
+            .or_else(|| self.level.map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -3445,6 +4174,10 @@ pub struct Style {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r" The severity of the rules of this group that don't set their own level"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RuleGroupLevel>,
     #[doc = "Disallow the use of arguments"]
     #[bpaf(long("no-arguments"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3626,6 +4359,15 @@ pub struct Style {
 }
 impl MergeWith<Style> for Style {
     fn merge_with(&mut self, other: Style) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_arguments) = other.no_arguments {
             self.no_arguments = Some(no_arguments);
         }
@@ -3857,6 +4599,11 @@ impl Style {
     pub(crate) fn is_not_all(&self) -> bool {
         matches!(self.all, Some(false))
     }
+    #[doc = r" Whether the group enables all its rules when `rules.all` is `true`: it doesn't"]
+    #[doc = r" set `all` nor `recommended` itself"]
+    pub(crate) fn inherits_all(&self) -> bool {
+        self.all.is_none() && self.recommended.is_none()
+    }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_arguments.as_ref() {
@@ -4202,11 +4949,12 @@ impl Style {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: bool,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        if self.is_all() || (parent_is_all && self.inherits_all()) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
@@ -4254,6 +5002,13 @@ impl Style {
             _ => None,
         }
     }
+    #[doc = r" The severity of a rule of this group: its own level, or the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .filter(|rule_setting| rule_setting.is_enabled())
+            .map(Severity::from)
+            .or_else(|| self.level.map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -4268,6 +5023,10 @@ pub struct Suspicious {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r" The severity of the rules of this group that don't set their own level"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RuleGroupLevel>,
     #[doc = "Usually, the definition in the standard library is more precise than what people come up with or the used constant exceeds the maximum precision of the number type."]
     #[bpaf(
         long("no-approximative-numeric-constant"),
@@ -4532,6 +5291,15 @@ pub struct Suspicious {
 }
 impl MergeWith<Suspicious> for Suspicious {
     fn merge_with(&mut self, other: Suspicious) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_approximative_numeric_constant) = other.no_approximative_numeric_constant {
             self.no_approximative_numeric_constant = Some(no_approximative_numeric_constant);
         }
@@ -4867,6 +5635,11 @@ impl Suspicious {
     pub(crate) fn is_not_all(&self) -> bool {
         matches!(self.all, Some(false))
     }
+    #[doc = r" Whether the group enables all its rules when `rules.all` is `true`: it doesn't"]
+    #[doc = r" set `all` nor `recommended` itself"]
+    pub(crate) fn inherits_all(&self) -> bool {
+        self.all.is_none() && self.recommended.is_none()
+    }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_approximative_numeric_constant.as_ref() {
@@ -5332,11 +6105,12 @@ impl Suspicious {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: bool,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        if self.is_all() || (parent_is_all && self.inherits_all()) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
@@ -5396,4 +6170,11 @@ impl Suspicious {
             _ => None,
         }
     }
+    #[doc = r" The severity of a rule of this group: its own level, or the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .filter(|rule_setting| rule_setting.is_enabled())
+            .map(Severity::from)
+            .or_else(|| self.level.map(Severity::from))
+    }
 }
@@ -3,15 +3,20 @@
 //! The configuration is divided by "tool", and then it's possible to further customise it
 //! by language. The language might further options divided by tool.
 pub mod diagnostics;
+pub mod editorconfig;
+pub mod extends;
 pub mod formatter;
 mod generated;
 pub mod javascript;
 pub mod json;
 pub mod linter;
 mod merge;
+pub mod nested;
 pub mod organize_imports;
 mod overrides;
 mod parse;
+mod profiles;
+pub mod project;
 pub mod vcs;
 
 pub use crate::configuration::diagnostics::ConfigurationDiagnostic;
@@ -19,14 +24,20 @@ pub(crate) use crate::configuration::generated::push_to_analyzer_rules;
 use crate::configuration::json::JsonFormatter;
 pub use crate::configuration::merge::MergeWith;
 use crate::configuration::organize_imports::{organize_imports, OrganizeImports};
-use crate::configuration::overrides::Overrides;
+pub use crate::configuration::overrides::{
+    OverrideFormatterConfiguration, OverridePattern, Overrides,
+};
+pub use crate::configuration::profiles::{Profile, Profiles};
+pub use crate::configuration::project::{
+    BoundaryConstraint, ModuleTags, ProjectConfiguration, ProjectRules,
+};
 use crate::configuration::vcs::{vcs_configuration, VcsConfiguration};
 use crate::settings::WorkspaceSettings;
 use crate::{DynRef, WorkspaceError, VERSION};
 use biome_analyze::AnalyzerRules;
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, StringSet};
-use biome_fs::{AutoSearchResult, FileSystem, OpenOptions};
+use biome_fs::{AutoSearchResult, FileSystem, OpenOptions, BIOME_JSON, BIOME_JSONC};
 use biome_js_analyze::metadata;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
@@ -90,7 +101,7 @@ pub struct Configuration {
     #[bpaf(external(json_configuration), optional)]
     pub json: Option<JsonConfiguration>,
 
-    /// A list of paths to other JSON files, used to extends the current configuration.
+    /// A list of paths to other JSON files, or of npm packages that export one, used to extends the current configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub extends: Option<StringSet>,
@@ -99,6 +110,17 @@ pub struct Configuration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub overrides: Option<Overrides>,
+
+    /// Named sets of options that are merged over the configuration when they are selected
+    /// with `--profile`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub profiles: Option<Profiles>,
+
+    /// The configuration of the project analysis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub project: Option<ProjectConfiguration>,
 }
 
 impl Default for Configuration {
@@ -117,6 +139,8 @@ impl Default for Configuration {
             extends: None,
             json: None,
             overrides: None,
+            profiles: None,
+            project: None,
         }
     }
 }
@@ -164,6 +188,10 @@ impl MergeWith<Configuration> for Configuration {
         self.merge_with(other_configuration.vcs);
         // overrides
         self.merge_with(other_configuration.overrides);
+        // profiles
+        self.merge_with(other_configuration.profiles);
+        // project
+        self.merge_with(other_configuration.project);
     }
 
     fn merge_with_if_not_default(&mut self, other_configuration: Configuration)
@@ -184,6 +212,10 @@ impl MergeWith<Configuration> for Configuration {
         self.merge_with_if_not_default(other_configuration.vcs);
         // overrides
         self.merge_with_if_not_default(other_configuration.overrides);
+        // profiles
+        self.merge_with_if_not_default(other_configuration.profiles);
+        // project
+        self.merge_with_if_not_default(other_configuration.project);
     }
 }
 
@@ -352,6 +384,45 @@ impl MergeWith<Option<Overrides>> for Configuration {
     }
 }
 
+impl MergeWith<Option<Profiles>> for Configuration {
+    fn merge_with(&mut self, other: Option<Profiles>) {
+        if let Some(other) = other {
+            let profiles = self.profiles.get_or_insert_with(Profiles::default);
+            profiles.merge_with(other);
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: Option<Profiles>)
+    where
+        Option<Profiles>: Default,
+    {
+        self.merge_with(other)
+    }
+}
+
+impl MergeWith<Option<ProjectConfiguration>> for Configuration {
+    fn merge_with(&mut self, other: Option<ProjectConfiguration>) {
+        if let Some(other) = other {
+            let project = self
+                .project
+                .get_or_insert_with(ProjectConfiguration::default);
+            project.merge_with(other);
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: Option<ProjectConfiguration>)
+    where
+        Option<ProjectConfiguration>: Default,
+    {
+        if let Some(other) = other {
+            let project = self
+                .project
+                .get_or_insert_with(ProjectConfiguration::default);
+            project.merge_with_if_not_default(other);
+        }
+    }
+}
+
 /// The configuration of the filesystem
 #[derive(Default, Debug, Deserialize, Serialize, Clone, Bpaf, Eq, PartialEq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -378,6 +449,12 @@ pub struct FilesConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("files-ignore-unknown"), argument("true|false"), optional)]
     pub ignore_unknown: Option<bool>,
+
+    /// Whether Biome should follow the symbolic links when it traverses the
+    /// file system. Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("files-follow-symlinks"), argument("true|false"), optional)]
+    pub follow_symlinks: Option<bool>,
 }
 
 impl MergeWith<FilesConfiguration> for FilesConfiguration {
@@ -394,6 +471,9 @@ impl MergeWith<FilesConfiguration> for FilesConfiguration {
         if let Some(ignore_unknown) = other.ignore_unknown {
             self.ignore_unknown = Some(ignore_unknown)
         }
+        if let Some(follow_symlinks) = other.follow_symlinks {
+            self.follow_symlinks = Some(follow_symlinks)
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: FilesConfiguration)
@@ -443,7 +523,7 @@ impl ConfigurationBasePath {
 ///
 /// The configuration file will be read from the `file_system`. A [base path](ConfigurationBasePath) should be provided.
 ///
-/// The function will try to traverse upwards the file system until if finds a `biome.json` file, or there
+/// The function will try to traverse upwards the file system until if finds a `biome.json` (or `biome.jsonc`) file, or there
 /// aren't directories anymore.
 ///
 /// If a the configuration base path was provided by the user, the function will error. If not, Biome will use
@@ -466,26 +546,23 @@ pub fn load_config(
     };
     let should_error = base_path.is_from_user();
 
-    let auto_search_result;
-    let result =
-        file_system.auto_search(configuration_directory.clone(), config_name, should_error);
-    if let Ok(result) = result {
-        if result.is_none() {
-            auto_search_result = file_system.auto_search(
+    // The files are searched in order: `biome.json`, `biome.jsonc` and the deprecated `rome.json`
+    let auto_search_result =
+        match file_system.auto_search(configuration_directory.clone(), config_name, should_error) {
+            Ok(Some(result)) => Some(result),
+            _ => match file_system.auto_search(
                 configuration_directory.clone(),
-                deprecated_config_name,
+                BIOME_JSONC,
                 should_error,
-            )?;
-        } else {
-            auto_search_result = result;
-        }
-    } else {
-        auto_search_result = file_system.auto_search(
-            configuration_directory.clone(),
-            deprecated_config_name,
-            should_error,
-        )?;
-    }
+            ) {
+                Ok(Some(result)) => Some(result),
+                _ => file_system.auto_search(
+                    configuration_directory.clone(),
+                    deprecated_config_name,
+                    should_error,
+                )?,
+            },
+        };
 
     if let Some(auto_search_result) = auto_search_result {
         let AutoSearchResult {
@@ -493,8 +570,12 @@ pub fn load_config(
             directory_path,
             file_path,
         } = auto_search_result;
-        let deserialized =
-            deserialize_from_json_str::<Configuration>(&content, JsonParserOptions::default());
+        let parser_options = if file_path.ends_with(BIOME_JSONC) {
+            JsonParserOptions::default().with_allow_comments()
+        } else {
+            JsonParserOptions::default()
+        };
+        let deserialized = deserialize_from_json_str::<Configuration>(&content, parser_options);
         Ok(Some(ConfigurationPayload {
             deserialized,
             configuration_file_path: file_path,
@@ -507,6 +588,9 @@ pub fn load_config(
 
 /// Creates a new configuration on file system
 ///
+/// When `emit_jsonc` is `true`, the configuration is written to `biome.jsonc`, and each
+/// top-level property is preceded by a comment that explains it.
+///
 /// ## Errors
 ///
 /// It fails if:
@@ -515,8 +599,20 @@ pub fn load_config(
 pub fn create_config(
     fs: &mut DynRef<dyn FileSystem>,
     mut configuration: Configuration,
+    emit_jsonc: bool,
 ) -> Result<(), WorkspaceError> {
-    let path = PathBuf::from(fs.config_name());
+    let path = if emit_jsonc {
+        PathBuf::from(BIOME_JSONC)
+    } else {
+        PathBuf::from(fs.config_name())
+    };
+
+    // A project can't have both `biome.json` and `biome.jsonc`
+    if fs.path_exists(Path::new(BIOME_JSON)) || fs.path_exists(Path::new(BIOME_JSONC)) {
+        return Err(WorkspaceError::Configuration(
+            ConfigurationDiagnostic::new_already_exists(),
+        ));
+    }
 
     let options = OpenOptions::default().write(true).create_new(true);
 
@@ -542,11 +638,18 @@ pub fn create_config(
         ));
     }
 
-    let contents = serde_json::to_string_pretty(&configuration).map_err(|_| {
+    let mut contents = serde_json::to_string_pretty(&configuration).map_err(|_| {
         WorkspaceError::Configuration(ConfigurationDiagnostic::new_serialization_error())
     })?;
 
-    let parsed = parse_json(&contents, JsonParserOptions::default());
+    let parser_options = if emit_jsonc {
+        contents = add_explanatory_comments(&contents);
+        JsonParserOptions::default().with_allow_comments()
+    } else {
+        JsonParserOptions::default()
+    };
+
+    let parsed = parse_json(&contents, parser_options);
     let formatted =
         biome_json_formatter::format_node(JsonFormatOptions::default(), &parsed.syntax())?
             .print()
@@ -559,6 +662,45 @@ pub fn create_config(
     Ok(())
 }
 
+/// Adds a comment before each top-level property of a configuration serialized by
+/// [serde_json::to_string_pretty]
+fn add_explanatory_comments(contents: &str) -> String {
+    let mut result = String::with_capacity(contents.len());
+    for line in contents.lines() {
+        // top-level properties are indented by two spaces
+        let property = line
+            .strip_prefix("  \"")
+            .and_then(|line| line.split_once('"'))
+            .map(|(property, _)| property);
+
+        if let Some(comment) = property.and_then(property_comment) {
+            result.push_str("  // ");
+            result.push_str(comment);
+            result.push('\n');
+        }
+        result.push_str(line);
+        result.push('\n');
+    }
+    result
+}
+
+fn property_comment(property: &str) -> Option<&'static str> {
+    let comment = match property {
+        "$schema" => "The JSON schema of the configuration, used by editors to provide autocompletion and validation",
+        "vcs" => "The integration with the version control system, e.g. to ignore the files listed in `.gitignore`",
+        "files" => "The files that Biome should process",
+        "formatter" => "The options of the formatter, applied to all the languages",
+        "organizeImports" => "Sorts the imports and the exports of the JavaScript and TypeScript files",
+        "linter" => "The linter analyzes the code to find errors and suggest fixes. See https://biomejs.dev/linter/rules",
+        "javascript" => "The options applied to the JavaScript and TypeScript files",
+        "json" => "The options applied to the JSON files",
+        "overrides" => "Different options applied to the files that match some patterns",
+        "extends" => "Other configuration files that this configuration extends",
+        _ => return None,
+    };
+    Some(comment)
+}
+
 /// Returns the rules applied to a specific [Path], given the [WorkspaceSettings]
 pub fn to_analyzer_rules(settings: &WorkspaceSettings, path: &Path) -> AnalyzerRules {
     let linter_settings = &settings.linter;
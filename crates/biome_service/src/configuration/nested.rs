@@ -0,0 +1,355 @@
+//! The configuration files found in the subdirectories of a project, e.g. the `biome.json` of a
+//! package of a monorepo.
+//!
+//! The settings of a nested file apply to the files beneath its directory: they become overrides,
+//! added after the overrides of the root configuration. The files are looked up lazily, one
+//! directory at a time, when a file beneath the directory is handled. A directory is always looked
+//! up after its parent, so the nested file closest to a file takes precedence.
+//!
+//! The `.editorconfig` files are looked up at the same time, when the configuration uses them,
+//! see [crate::configuration::editorconfig], and so are the ignore files of the VCS, see
+//! [NestedVcsIgnoreFiles].
+
+use crate::configuration::diagnostics::{CantLoadExtendFile, RootOnlyConfigurationFields};
+use crate::configuration::editorconfig::EditorConfigs;
+use crate::configuration::vcs::NestedVcsIgnoreFiles;
+use crate::configuration::{Configuration, OverridePattern, Overrides};
+use crate::stable_hash::StableHasher;
+use crate::workspace::RegisterNestedConfigurationsParams;
+use crate::{Workspace, WorkspaceError};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_diagnostics::{DiagnosticExt, Error};
+use biome_fs::{FileSystem, FileSystemExt, BIOME_JSON, BIOME_JSONC};
+use biome_json_parser::JsonParserOptions;
+use rustc_hash::FxHashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+/// The nested configuration files of a project, looked up lazily
+#[derive(Debug)]
+pub struct NestedConfigurations {
+    /// The directory of the root configuration file, the nested files are beneath it
+    root_directory: PathBuf,
+    /// The directory the relative paths are resolved from, e.g. the root of the workspace of
+    /// the LSP
+    working_directory: Option<PathBuf>,
+    /// The directories already looked up, with the fingerprint of the nested files that apply
+    /// to the files beneath them
+    directories: Mutex<FxHashMap<PathBuf, u64>>,
+    /// The `.editorconfig` files, when `formatter.useEditorconfig` is enabled
+    editorconfigs: Option<EditorConfigs>,
+    /// The ignore files of the VCS, when the VCS integration uses them
+    vcs_ignore_files: Option<NestedVcsIgnoreFiles>,
+}
+
+/// A configuration file found in a subdirectory of the project
+#[derive(Debug)]
+pub struct NestedConfiguration {
+    pub file_path: PathBuf,
+    /// The settings of the file followed by its overrides, see [OverridePattern::nested]
+    pub overrides: Vec<OverridePattern>,
+    /// The errors of the file, and the fields set by the file that only the root configuration
+    /// supports
+    pub diagnostics: Vec<Error>,
+}
+
+impl NestedConfigurations {
+    pub fn new(root_directory: PathBuf, working_directory: Option<PathBuf>) -> Self {
+        Self {
+            root_directory,
+            working_directory,
+            directories: Mutex::default(),
+            editorconfigs: None,
+            vcs_ignore_files: None,
+        }
+    }
+
+    /// Looks up the `.editorconfig` files too
+    pub fn with_editorconfigs(mut self, editorconfigs: EditorConfigs) -> Self {
+        self.editorconfigs = Some(editorconfigs);
+        self
+    }
+
+    /// Looks up the ignore files of the VCS too, when the VCS integration uses them
+    pub fn with_vcs_ignore_files(mut self, vcs_ignore_files: Option<NestedVcsIgnoreFiles>) -> Self {
+        self.vcs_ignore_files = vcs_ignore_files;
+        self
+    }
+
+    /// The directory of the root configuration file, or the working directory when the project
+    /// has no configuration file
+    pub fn root_directory(&self) -> &Path {
+        &self.root_directory
+    }
+
+    pub fn editorconfigs(&self) -> Option<&EditorConfigs> {
+        self.editorconfigs.as_ref()
+    }
+
+    /// Looks up the directories between the root of the project and `directory` that weren't
+    /// looked up yet, and registers the configuration files found in the workspace, with the
+    /// `.editorconfig` files and the ignore files of the VCS. Returns the diagnostics of the files.
+    ///
+    /// The lookup stays locked until the files are registered, so a file beneath a directory is
+    /// never handled with the settings of the workspace that miss the file of the directory.
+    pub fn discover(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        directory: &Path,
+    ) -> Result<Vec<Error>, WorkspaceError> {
+        let mut directories = self.directories.lock().unwrap();
+        let configurations = self.look_up(&mut directories, fs, directory);
+        let editorconfig_files = self
+            .editorconfigs
+            .as_ref()
+            .map(|editorconfigs| editorconfigs.load(fs, directory))
+            .unwrap_or_default();
+        let vcs_ignore_files = self
+            .vcs_ignore_files
+            .as_ref()
+            .map(|vcs_ignore_files| vcs_ignore_files.load(fs, directory))
+            .unwrap_or_default();
+        if configurations.is_empty() && editorconfig_files.is_empty() && vcs_ignore_files.is_empty()
+        {
+            return Ok(vec![]);
+        }
+
+        let mut overrides = vec![];
+        let mut diagnostics = vec![];
+        for configuration in configurations {
+            overrides.extend(configuration.overrides);
+            diagnostics.extend(configuration.diagnostics);
+        }
+        let editorconfig_files = editorconfig_files
+            .into_iter()
+            .map(|editorconfig_file| {
+                diagnostics.extend(editorconfig_file.diagnostics);
+                editorconfig_file.overrides
+            })
+            .collect();
+        workspace.register_nested_configurations(RegisterNestedConfigurationsParams {
+            overrides: Overrides(overrides),
+            editorconfig_files,
+            vcs_ignore_files,
+        })?;
+        Ok(diagnostics)
+    }
+
+    /// Returns the configuration files that apply to the files beneath `directory`, from the
+    /// shallowest to the deepest, without registering them. The directories already looked up
+    /// are skipped.
+    pub fn load(&self, fs: &dyn FileSystem, directory: &Path) -> Vec<NestedConfiguration> {
+        let mut directories = self.directories.lock().unwrap();
+        self.look_up(&mut directories, fs, directory)
+    }
+
+    /// Returns the fingerprint of the nested configuration files and the `.editorconfig` files
+    /// that apply to the files beneath `directory`, once it's looked up. It changes when the
+    /// content of one of the files changes.
+    pub fn fingerprint(&self, directory: &Path) -> u64 {
+        let directories = self.directories.lock().unwrap();
+        let fingerprint = directories.get(directory).copied().unwrap_or_default();
+        match &self.editorconfigs {
+            Some(editorconfigs) => {
+                let mut hasher = StableHasher::new();
+                hasher.write_u64(fingerprint);
+                hasher.write_u64(editorconfigs.fingerprint(directory));
+                hasher.finish()
+            }
+            None => fingerprint,
+        }
+    }
+
+    fn look_up(
+        &self,
+        directories: &mut FxHashMap<PathBuf, u64>,
+        fs: &dyn FileSystem,
+        directory: &Path,
+    ) -> Vec<NestedConfiguration> {
+        let mut fingerprint = 0;
+        let mut pending = vec![];
+        for ancestor in directory.ancestors() {
+            if let Some(known) = directories.get(ancestor) {
+                fingerprint = *known;
+                break;
+            }
+            if !self.is_nested(ancestor) {
+                break;
+            }
+            pending.push(ancestor);
+            // The paths that start with `./` don't have an empty ancestor
+            if ancestor == Path::new(".") {
+                break;
+            }
+        }
+
+        let mut configurations = vec![];
+        for directory in pending.into_iter().rev() {
+            if let Some((configuration, content)) =
+                load_directory(fs, directory, &self.resolve(directory))
+            {
+                let mut hasher = StableHasher::new();
+                hasher.write_u64(fingerprint);
+                hasher.write_str(&content);
+                fingerprint = hasher.finish();
+                configurations.push(configuration);
+            }
+            directories.insert(directory.to_path_buf(), fingerprint);
+        }
+        configurations
+    }
+
+    /// Whether `directory` is a subdirectory of the root of the project
+    fn is_nested(&self, directory: &Path) -> bool {
+        // The path can't be compared with the root once it goes up, e.g. `../packages`
+        if directory
+            .components()
+            .any(|component| component == Component::ParentDir)
+        {
+            return false;
+        }
+        let directory = self.resolve(directory);
+        directory != self.root_directory && directory.starts_with(&self.root_directory)
+    }
+
+    /// Resolves `directory` from the working directory
+    fn resolve(&self, directory: &Path) -> PathBuf {
+        match &self.working_directory {
+            Some(working_directory) if directory.is_relative() => working_directory.join(directory),
+            _ => directory.to_path_buf(),
+        }
+    }
+}
+
+/// Loads the configuration file of `directory`, if it has one, with the content of the file.
+/// `resolved_directory` is where the file is read, `directory` is how the paths of the files
+/// beneath it are spelled.
+fn load_directory(
+    fs: &dyn FileSystem,
+    directory: &Path,
+    resolved_directory: &Path,
+) -> Option<(NestedConfiguration, String)> {
+    // The configurations of the dependencies, e.g. the packages used by `extends`, aren't nested
+    if directory
+        .components()
+        .any(|component| component.as_os_str() == "node_modules")
+    {
+        return None;
+    }
+
+    let file_path = [BIOME_JSON, BIOME_JSONC]
+        .into_iter()
+        .map(|file_name| resolved_directory.join(file_name))
+        .find(|file_path| fs.path_exists(file_path))?;
+    let mut configuration = NestedConfiguration {
+        file_path,
+        overrides: vec![],
+        diagnostics: vec![],
+    };
+    let file_path = configuration.file_path.display().to_string();
+
+    let content = match fs.read_to_string(&configuration.file_path) {
+        Ok(content) => content,
+        Err(error) => {
+            configuration
+                .diagnostics
+                .push(Error::from(CantLoadExtendFile::new(
+                    file_path,
+                    error.to_string(),
+                )));
+            return Some((configuration, String::new()));
+        }
+    };
+    let parser_options = if configuration.file_path.ends_with(BIOME_JSONC) {
+        JsonParserOptions::default().with_allow_comments()
+    } else {
+        JsonParserOptions::default()
+    };
+
+    let (deserialized, diagnostics) =
+        deserialize_from_json_str::<Configuration>(&content, parser_options).consume();
+    configuration.diagnostics.extend(
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic.with_file_path(file_path.clone())),
+    );
+    let Some(deserialized) = deserialized else {
+        return Some((configuration, content));
+    };
+    let root_only_fields = root_only_fields(&deserialized);
+    if !root_only_fields.is_empty() {
+        configuration
+            .diagnostics
+            .push(Error::from(RootOnlyConfigurationFields::new(
+                file_path,
+                &root_only_fields,
+            )));
+    }
+
+    // The settings are read again as an override: unlike the configuration, the fields of an
+    // override that aren't in the file stay empty, instead of taking the defaults. The fields of
+    // the configuration that an override doesn't support were reported above.
+    let (settings, _) =
+        deserialize_from_json_str::<OverridePattern>(&content, parser_options).consume();
+    configuration.overrides = OverridePattern::nested(
+        directory,
+        settings.unwrap_or_default(),
+        deserialized.overrides,
+    );
+    Some((configuration, content))
+}
+
+/// Returns the fields set by a nested configuration file that only the root configuration
+/// supports, because they change which files are handled rather than how.
+///
+/// The `extends` field isn't reported: a nested file usually extends the root configuration,
+/// so it can also be used when Biome runs from its directory.
+fn root_only_fields(configuration: &Configuration) -> Vec<&'static str> {
+    let mut fields = vec![];
+    if configuration.vcs.is_some() {
+        fields.push("vcs");
+    }
+    if configuration.profiles.is_some() {
+        fields.push("profiles");
+    }
+    if let Some(files) = &configuration.files {
+        if files.include.is_some() {
+            fields.push("files.include");
+        }
+        if files.ignore.is_some() {
+            fields.push("files.ignore");
+        }
+        if files.ignore_unknown.is_some() {
+            fields.push("files.ignoreUnknown");
+        }
+    }
+    if let Some(formatter) = &configuration.formatter {
+        if formatter.use_editorconfig.is_some() {
+            fields.push("formatter.useEditorconfig");
+        }
+        if formatter.include.is_some() {
+            fields.push("formatter.include");
+        }
+        if formatter.ignore.is_some() {
+            fields.push("formatter.ignore");
+        }
+    }
+    if let Some(linter) = &configuration.linter {
+        if linter.include.is_some() {
+            fields.push("linter.include");
+        }
+        if linter.ignore.is_some() {
+            fields.push("linter.ignore");
+        }
+    }
+    if let Some(organize_imports) = &configuration.organize_imports {
+        if organize_imports.include.is_some() {
+            fields.push("organizeImports.include");
+        }
+        if organize_imports.ignore.is_some() {
+            fields.push("organizeImports.ignore");
+        }
+    }
+    fields
+}
@@ -1,18 +1,22 @@
 use crate::configuration::formatter::{deserialize_line_width, serialize_line_width};
 use crate::configuration::linter::rules;
+use crate::configuration::organize_imports::OrganizeImports;
 use crate::configuration::{
-    javascript_configuration, json_configuration, JavascriptConfiguration, JsonConfiguration,
-    PlainIndentStyle,
+    javascript_configuration, json_configuration, FilesConfiguration, FormatterConfiguration,
+    JavascriptConfiguration, JsonConfiguration, LinterConfiguration, PlainIndentStyle,
 };
 use crate::settings::{
-    to_matcher, LanguageListSettings, OverrideFormatSettings, OverrideLinterSettings,
-    OverrideOrganizeImportsSettings, OverrideSettingPattern, OverrideSettings,
+    to_matcher, LanguageListSettings, OverrideFilesSettings, OverrideFormatSettings,
+    OverrideLinterSettings, OverrideOrganizeImportsSettings, OverrideSettingPattern,
+    OverrideSettings,
 };
-use crate::{MergeWith, Rules, WorkspaceError};
+use crate::{Configuration, MergeWith, Pattern, Rules, WorkspaceError};
 use biome_deserialize::StringSet;
 use biome_formatter::{IndentStyle, LineEnding, LineWidth};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::num::NonZeroU64;
+use std::path::Path;
 use std::str::FromStr;
 
 #[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Bpaf)]
@@ -89,6 +93,11 @@ pub struct OverridePattern {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_organize_imports_configuration), optional, hide)]
     pub organize_imports: Option<OverrideOrganizeImportsConfiguration>,
+
+    /// Specific configuration for the filesystem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(override_files_configuration), optional, hide)]
+    pub files: Option<OverrideFilesConfiguration>,
 }
 
 impl FromStr for OverridePattern {
@@ -130,6 +139,12 @@ impl MergeWith<OverridePattern> for OverridePattern {
                 .get_or_insert(OverrideOrganizeImportsConfiguration::default());
             organize_imports.merge_with(other);
         }
+        if let Some(other) = other.files {
+            let files = self
+                .files
+                .get_or_insert(OverrideFilesConfiguration::default());
+            files.merge_with(other);
+        }
         if let Some(other) = other.javascript {
             let javascript = self
                 .javascript
@@ -174,6 +189,12 @@ impl MergeWith<OverridePattern> for OverridePattern {
                 .get_or_insert(OverrideOrganizeImportsConfiguration::default());
             organize_imports.merge_with_if_not_default(other);
         }
+        if let Some(other) = other.files {
+            let files = self
+                .files
+                .get_or_insert(OverrideFilesConfiguration::default());
+            files.merge_with_if_not_default(other);
+        }
         if let Some(other) = other.javascript {
             let javascript = self
                 .javascript
@@ -187,6 +208,128 @@ impl MergeWith<OverridePattern> for OverridePattern {
     }
 }
 
+impl OverridePattern {
+    /// Returns the patterns that apply a configuration file found in `directory`, a
+    /// subdirectory of the project, to the files beneath it: `settings`, the settings of the
+    /// file, followed by its `overrides`.
+    ///
+    /// The `include` and `ignore` patterns of the overrides are relative to `directory`, they
+    /// become prefixed by it. `directory` must be spelled like the paths of the files, e.g.
+    /// `./packages/a` when they start with `./`.
+    pub fn nested(
+        directory: &Path,
+        settings: OverridePattern,
+        overrides: Option<Overrides>,
+    ) -> Vec<Self> {
+        let directory = Pattern::escape(&directory.to_string_lossy());
+        let prefix = |patterns: StringSet| {
+            StringSet::new(
+                patterns
+                    .into_index_set()
+                    .into_iter()
+                    .map(|pattern| {
+                        let (negation, pattern) = match pattern.strip_prefix('!') {
+                            Some(pattern) => ("!", pattern),
+                            None => ("", pattern.as_str()),
+                        };
+                        let pattern = pattern.trim_start_matches("./");
+                        if directory.is_empty() {
+                            format!("{negation}{pattern}")
+                        } else {
+                            format!("{negation}{directory}/{pattern}")
+                        }
+                    })
+                    .collect(),
+            )
+        };
+
+        let mut patterns = vec![OverridePattern {
+            include: Some(prefix(StringSet::new(
+                ["**".to_string()].into_iter().collect(),
+            ))),
+            ignore: None,
+            ..settings
+        }];
+        patterns.extend(overrides.into_iter().flat_map(|overrides| overrides.0).map(
+            |mut pattern| {
+                pattern.include = pattern.include.map(prefix);
+                pattern.ignore = pattern.ignore.map(prefix);
+                pattern
+            },
+        ));
+        patterns
+    }
+
+    /// Whether the pattern applies to `path`: it's included, and it isn't ignored
+    pub fn matches_path(&self, path: &Path) -> Result<bool, WorkspaceError> {
+        let included =
+            to_matcher(self.include.as_ref())?.map_or(false, |include| include.matches_path(path));
+        let ignored =
+            to_matcher(self.ignore.as_ref())?.map_or(false, |ignore| ignore.matches_path(path));
+        Ok(included && !ignored)
+    }
+
+    /// Merges the settings of the pattern into `configuration`, like the workspace does for
+    /// the files that match the pattern
+    pub fn apply_to(self, configuration: &mut Configuration) {
+        if let Some(formatter) = self.formatter {
+            configuration
+                .formatter
+                .get_or_insert_with(FormatterConfiguration::default)
+                .merge_with(FormatterConfiguration {
+                    enabled: formatter.enabled,
+                    format_with_errors: formatter.format_with_errors,
+                    indent_style: formatter.indent_style,
+                    indent_size: formatter.indent_size,
+                    indent_width: formatter.indent_width,
+                    line_ending: formatter.line_ending,
+                    line_width: formatter.line_width,
+                    ignore: None,
+                    include: None,
+                    use_editorconfig: None,
+                });
+        }
+        if let Some(linter) = self.linter {
+            let linter_configuration = configuration
+                .linter
+                .get_or_insert_with(LinterConfiguration::default);
+            if let Some(enabled) = linter.enabled {
+                linter_configuration.enabled = Some(enabled);
+            }
+            if let Some(rules) = linter.rules {
+                linter_configuration
+                    .rules
+                    .get_or_insert_with(Rules::default)
+                    .merge_with(rules);
+            }
+        }
+        if let Some(enabled) = self.organize_imports.and_then(|config| config.enabled) {
+            configuration
+                .organize_imports
+                .get_or_insert_with(OrganizeImports::default)
+                .enabled = Some(enabled);
+        }
+        if let Some(max_size) = self.files.and_then(|files| files.max_size) {
+            configuration
+                .files
+                .get_or_insert_with(FilesConfiguration::default)
+                .max_size = Some(max_size);
+        }
+        if let Some(javascript) = self.javascript {
+            configuration
+                .javascript
+                .get_or_insert_with(JavascriptConfiguration::default)
+                .merge_with(javascript);
+        }
+        if let Some(json) = self.json {
+            configuration
+                .json
+                .get_or_insert_with(JsonConfiguration::default)
+                .merge_with(json);
+        }
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
@@ -244,6 +387,10 @@ impl MergeWith<OverrideFormatterConfiguration> for OverrideFormatterConfiguratio
             self.indent_style = Some(indent_style);
         }
 
+        if let Some(line_ending) = other.line_ending {
+            self.line_ending = Some(line_ending);
+        }
+
         if let Some(line_width) = other.line_width {
             self.line_width = Some(line_width);
         }
@@ -325,6 +472,34 @@ impl MergeWith<OverrideOrganizeImportsConfiguration> for OverrideOrganizeImports
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideFilesConfiguration {
+    /// The maximum allowed size for source code files in bytes. Files above
+    /// this limit will be ignored for performance reasons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub max_size: Option<NonZeroU64>,
+}
+
+impl MergeWith<OverrideFilesConfiguration> for OverrideFilesConfiguration {
+    fn merge_with(&mut self, other: OverrideFilesConfiguration) {
+        if let Some(max_size) = other.max_size {
+            self.max_size = Some(max_size);
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: OverrideFilesConfiguration)
+    where
+        OverrideFilesConfiguration: Default,
+    {
+        if other != OverrideFilesConfiguration::default() {
+            self.merge_with(other)
+        }
+    }
+}
+
 impl TryFrom<Overrides> for OverrideSettings {
     type Error = WorkspaceError;
 
@@ -340,6 +515,9 @@ impl TryFrom<Overrides> for OverrideSettings {
             let organize_imports = pattern.organize_imports.take().unwrap_or_default();
             let organize_imports = OverrideOrganizeImportsSettings::try_from(organize_imports)?;
 
+            let files = pattern.files.take().unwrap_or_default();
+            let files = OverrideFilesSettings::try_from(files)?;
+
             let mut languages = LanguageListSettings::default();
             if let Some(javascript) = pattern.javascript {
                 languages.javascript = javascript.into();
@@ -355,6 +533,7 @@ impl TryFrom<Overrides> for OverrideSettings {
                 formatter,
                 linter,
                 organize_imports,
+                files,
                 languages,
             };
 
@@ -369,24 +548,22 @@ impl TryFrom<OverrideFormatterConfiguration> for OverrideFormatSettings {
     type Error = WorkspaceError;
 
     fn try_from(conf: OverrideFormatterConfiguration) -> Result<Self, Self::Error> {
-        let indent_style = match conf.indent_style {
-            Some(PlainIndentStyle::Tab) => IndentStyle::Tab,
-            Some(PlainIndentStyle::Space) => IndentStyle::Space,
-            None => IndentStyle::default(),
-        };
+        let indent_style = conf.indent_style.map(|indent_style| match indent_style {
+            PlainIndentStyle::Tab => IndentStyle::Tab,
+            PlainIndentStyle::Space => IndentStyle::Space,
+        });
         let indent_width = conf
             .indent_width
             .map(Into::into)
-            .or(conf.indent_size.map(Into::into))
-            .unwrap_or_default();
+            .or(conf.indent_size.map(Into::into));
 
         Ok(Self {
             enabled: conf.enabled,
-            indent_style: Some(indent_style),
-            indent_width: Some(indent_width),
+            indent_style,
+            indent_width,
             line_ending: conf.line_ending,
             line_width: conf.line_width,
-            format_with_errors: conf.format_with_errors.unwrap_or_default(),
+            format_with_errors: conf.format_with_errors,
         })
     }
 }
@@ -411,3 +588,13 @@ impl TryFrom<OverrideOrganizeImportsConfiguration> for OverrideOrganizeImportsSe
         })
     }
 }
+
+impl TryFrom<OverrideFilesConfiguration> for OverrideFilesSettings {
+    type Error = WorkspaceError;
+
+    fn try_from(conf: OverrideFilesConfiguration) -> Result<Self, Self::Error> {
+        Ok(Self {
+            max_size: conf.max_size,
+        })
+    }
+}
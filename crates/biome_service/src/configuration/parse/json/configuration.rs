@@ -38,6 +38,8 @@ impl DeserializationVisitor for ConfigurationVisitor {
             "organizeImports",
             "extends",
             "overrides",
+            "profiles",
+            "project",
         ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
@@ -76,6 +78,12 @@ impl DeserializationVisitor for ConfigurationVisitor {
                 "overrides" => {
                     result.overrides = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "profiles" => {
+                    result.profiles = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "project" => {
+                    result.project = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -1,3 +1,4 @@
+use crate::configuration::parse::json::patterns::deserialize_patterns;
 use crate::configuration::FilesConfiguration;
 use biome_deserialize::{
     Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
@@ -28,7 +29,13 @@ impl DeserializationVisitor for FilesConfigurationVisitor {
         _name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["maxSize", "ignore", "include", "ignoreUnknown"];
+        const ALLOWED_KEYS: &[&str] = &[
+            "maxSize",
+            "ignore",
+            "include",
+            "ignoreUnknown",
+            "followSymlinks",
+        ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -39,15 +46,19 @@ impl DeserializationVisitor for FilesConfigurationVisitor {
                     result.max_size = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
                 "ignore" => {
-                    result.ignore = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.ignore = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "include" => {
-                    result.include = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.include = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "ignoreUnknown" => {
                     result.ignore_unknown =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "followSymlinks" => {
+                    result.follow_symlinks =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -1,3 +1,4 @@
+use crate::configuration::parse::json::patterns::deserialize_patterns;
 use crate::configuration::{FormatterConfiguration, PlainIndentStyle};
 use biome_deserialize::{
     Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
@@ -38,6 +39,7 @@ impl DeserializationVisitor for FormatterConfigurationVisitor {
             "lineWidth",
             "ignore",
             "include",
+            "useEditorconfig",
         ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
@@ -49,10 +51,10 @@ impl DeserializationVisitor for FormatterConfigurationVisitor {
                     result.enabled = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
                 "ignore" => {
-                    result.ignore = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.ignore = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "include" => {
-                    result.include = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.include = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "indentStyle" => {
                     result.indent_style =
@@ -82,6 +84,10 @@ impl DeserializationVisitor for FormatterConfigurationVisitor {
                     result.format_with_errors =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "useEditorconfig" => {
+                    result.use_editorconfig =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
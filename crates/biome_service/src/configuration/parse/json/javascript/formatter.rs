@@ -36,6 +36,12 @@ impl DeserializationVisitor for JavascriptFormatterVisitor {
             "arrowParentheses",
             "bracketSpacing",
             "bracketSameLine",
+            "singleLineIfStatements",
+            "blankLineAfterImports",
+            "blankLineBeforeReturn",
+            "unionLeadingSeparator",
+            "unionBreakThreshold",
+            "jsxWhitespaceFidelity",
             "enabled",
             "indentStyle",
             "indentSize",
@@ -81,6 +87,30 @@ impl DeserializationVisitor for JavascriptFormatterVisitor {
                     result.bracket_same_line =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "singleLineIfStatements" => {
+                    result.single_line_if_statements =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "blankLineAfterImports" => {
+                    result.blank_line_after_imports =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "blankLineBeforeReturn" => {
+                    result.blank_line_before_return =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "unionLeadingSeparator" => {
+                    result.union_leading_separator =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "unionBreakThreshold" => {
+                    result.union_break_threshold =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "jsxWhitespaceFidelity" => {
+                    result.jsx_whitespace_fidelity =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "enabled" => {
                     result.enabled = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
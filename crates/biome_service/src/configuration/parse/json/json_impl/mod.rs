@@ -134,6 +134,7 @@ impl DeserializationVisitor for JsonFormatterVisitor {
             "indentWidth",
             "lineEnding",
             "lineWidth",
+            "sortPackageJsonKeys",
         ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
@@ -168,6 +169,10 @@ impl DeserializationVisitor for JsonFormatterVisitor {
                 "lineWidth" => {
                     result.line_width = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "sortPackageJsonKeys" => {
+                    result.sort_package_json_keys =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => {
                     diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                         unknown_key,
@@ -1,4 +1,7 @@
-use crate::configuration::linter::{RulePlainConfiguration, RuleWithOptions};
+use crate::configuration::linter::{
+    NurseryPreset, RuleFixKind, RuleGroupLevel, RulePlainConfiguration, RuleWithOptions,
+};
+use crate::configuration::parse::json::patterns::deserialize_patterns;
 use crate::configuration::LinterConfiguration;
 use crate::RuleConfiguration;
 use biome_deserialize::{
@@ -38,10 +41,10 @@ impl DeserializationVisitor for LinterConfigurationVisitor {
             };
             match key_text.text() {
                 "ignore" => {
-                    result.ignore = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.ignore = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "include" => {
-                    result.include = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.include = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "enabled" => {
                     result.enabled = Deserializable::deserialize(&value, &key_text, diagnostics);
@@ -94,7 +97,7 @@ impl DeserializationVisitor for RuleConfigurationVisitor {
         rule_name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["level", "options"];
+        const ALLOWED_KEYS: &[&str] = &["level", "fix", "directives", "options"];
         let mut result = RuleWithOptions::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -104,6 +107,12 @@ impl DeserializationVisitor for RuleConfigurationVisitor {
                 "level" => {
                     result.level = Deserializable::deserialize(&value, &key_text, diagnostics)?;
                 }
+                "fix" => {
+                    result.fix = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "directives" => {
+                    result.directives = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "options" => {
                     result.options = Deserializable::deserialize(&value, rule_name, diagnostics);
                 }
@@ -148,3 +157,65 @@ impl Deserializable for RulePlainConfiguration {
         Self::deserialize_from_str(value_text, value.range(), diagnostics)
     }
 }
+
+impl Deserializable for RuleGroupLevel {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["warn", "error"];
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.text().parse::<Self>() {
+            Some(value)
+        } else {
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
+
+impl NurseryPreset {
+    pub(crate) fn deserialize_from_str(
+        value: Text,
+        range: TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["warnOnRecommended"];
+        if let Ok(value) = value.text().parse::<Self>() {
+            Some(value)
+        } else {
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value.text(),
+                range,
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
+
+impl Deserializable for RuleFixKind {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["safe", "unsafe"];
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.text().parse::<Self>() {
+            Some(value)
+        } else {
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
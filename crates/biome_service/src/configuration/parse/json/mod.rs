@@ -9,5 +9,9 @@ mod json_impl;
 mod linter;
 mod organize_imports;
 mod overrides;
+mod patterns;
+mod profiles;
+mod project;
 mod rules;
+mod unknown_rules;
 mod vcs;
@@ -1,4 +1,5 @@
 use crate::configuration::organize_imports::OrganizeImports;
+use crate::configuration::parse::json::patterns::deserialize_patterns;
 use biome_deserialize::{
     Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
     VisitableType,
@@ -39,10 +40,10 @@ impl DeserializationVisitor for OrganizeImportsVisitor {
                     result.enabled = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
                 "ignore" => {
-                    result.ignore = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.ignore = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "include" => {
-                    result.include = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.include = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
@@ -1,7 +1,8 @@
 use crate::configuration::overrides::{
-    OverrideFormatterConfiguration, OverrideLinterConfiguration,
+    OverrideFilesConfiguration, OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, OverridePattern, Overrides,
 };
+use crate::configuration::parse::json::patterns::deserialize_patterns;
 use biome_deserialize::{
     Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
     VisitableType,
@@ -52,6 +53,7 @@ impl DeserializationVisitor for OverridePatternVisitor {
             "organizeImports",
             "javascript",
             "json",
+            "files",
         ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
@@ -60,10 +62,10 @@ impl DeserializationVisitor for OverridePatternVisitor {
             };
             match key_text.text() {
                 "ignore" => {
-                    result.ignore = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.ignore = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "include" => {
-                    result.include = Deserializable::deserialize(&value, &key_text, diagnostics);
+                    result.include = deserialize_patterns(&value, &key_text, diagnostics);
                 }
                 "formatter" => {
                     result.formatter = Deserializable::deserialize(&value, &key_text, diagnostics);
@@ -81,6 +83,9 @@ impl DeserializationVisitor for OverridePatternVisitor {
                 "json" => {
                     result.json = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "files" => {
+                    result.files = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -266,3 +271,47 @@ impl DeserializationVisitor for OverrideOrganizeImportsConfigurationVisitor {
         Some(result)
     }
 }
+
+impl Deserializable for OverrideFilesConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(OverrideFilesConfigurationVisitor, name, diagnostics)
+    }
+}
+
+struct OverrideFilesConfigurationVisitor;
+impl DeserializationVisitor for OverrideFilesConfigurationVisitor {
+    type Output = OverrideFilesConfiguration;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: biome_rowan::TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["maxSize"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "maxSize" => {
+                    result.max_size = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                _ => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    key_text.text(),
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
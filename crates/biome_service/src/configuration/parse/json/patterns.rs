@@ -0,0 +1,53 @@
+use crate::{MatchOptions, Matcher};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
+    StringSet, Text, VisitableType,
+};
+use biome_rowan::TextRange;
+use indexmap::IndexSet;
+
+/// Deserializes a list of Unix shell style patterns, e.g. `files.ignore`.
+///
+/// The patterns that can't be parsed are reported at their position in the configuration,
+/// and skipped.
+pub(crate) fn deserialize_patterns(
+    value: &impl DeserializableValue,
+    name: &str,
+    diagnostics: &mut Vec<DeserializationDiagnostic>,
+) -> Option<StringSet> {
+    value.deserialize(PatternsVisitor, name, diagnostics)
+}
+
+struct PatternsVisitor;
+impl DeserializationVisitor for PatternsVisitor {
+    type Output = StringSet;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::ARRAY;
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut patterns = IndexSet::new();
+        for item in items.flatten() {
+            let Some(pattern) = Text::deserialize(&item, "", diagnostics) else {
+                continue;
+            };
+            if let Err(error) = Matcher::new(MatchOptions::default()).add_pattern(pattern.text()) {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(markup! {
+                        "The pattern "<Emphasis>{pattern.text()}</Emphasis>" is invalid: "{error.msg}"."
+                    })
+                    .with_range(item.range()),
+                );
+                continue;
+            }
+            patterns.insert(pattern.text().to_string());
+        }
+        Some(StringSet::new(patterns))
+    }
+}
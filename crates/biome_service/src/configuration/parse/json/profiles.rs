@@ -0,0 +1,86 @@
+use crate::configuration::profiles::{Profile, Profiles};
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+
+impl Deserializable for Profiles {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        Some(Profiles(Deserializable::deserialize(
+            value,
+            name,
+            diagnostics,
+        )?))
+    }
+}
+
+impl Deserializable for Profile {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(ProfileVisitor, name, diagnostics)
+    }
+}
+
+struct ProfileVisitor;
+impl DeserializationVisitor for ProfileVisitor {
+    type Output = Profile;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: biome_rowan::TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &[
+            "formatter",
+            "linter",
+            "organizeImports",
+            "javascript",
+            "json",
+            "files",
+        ];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "formatter" => {
+                    result.formatter = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "linter" => {
+                    result.linter = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "organizeImports" => {
+                    result.organize_imports =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "javascript" => {
+                    result.javascript = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "json" => {
+                    result.json = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "files" => {
+                    result.files = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
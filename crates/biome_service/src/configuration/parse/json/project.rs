@@ -0,0 +1,227 @@
+use crate::configuration::parse::json::patterns::deserialize_patterns;
+use crate::configuration::project::{
+    BoundaryConstraint, ModuleTags, ProjectConfiguration, ProjectRules,
+};
+use biome_console::markup;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_rowan::TextRange;
+
+impl Deserializable for ProjectConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(ProjectConfigurationVisitor, name, diagnostics)
+    }
+}
+
+struct ProjectConfigurationVisitor;
+impl DeserializationVisitor for ProjectConfigurationVisitor {
+    type Output = ProjectConfiguration;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["tags", "boundaries", "rules"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "tags" => {
+                    result.tags = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "boundaries" => {
+                    result.boundaries = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "rules" => {
+                    result.rules = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for ProjectRules {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(ProjectRulesVisitor, name, diagnostics)
+    }
+}
+
+struct ProjectRulesVisitor;
+impl DeserializationVisitor for ProjectRulesVisitor {
+    type Output = ProjectRules;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            let level = match key_text.text() {
+                "importCycle" => &mut result.import_cycle,
+                "barrelImport" => &mut result.barrel_import,
+                "moduleBoundary" => &mut result.module_boundary,
+                "serverOnlyImport" => &mut result.server_only_import,
+                "unusedExport" => &mut result.unused_export,
+                "unusedClass" => &mut result.unused_class,
+                "unknownClass" => &mut result.unknown_class,
+                unknown_key => {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        unknown_key,
+                        key.range(),
+                        ProjectRules::RULE_NAMES,
+                    ));
+                    continue;
+                }
+            };
+            *level = Deserializable::deserialize(&value, &key_text, diagnostics);
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for ModuleTags {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(ModuleTagsVisitor, name, diagnostics)
+    }
+}
+
+struct ModuleTagsVisitor;
+impl DeserializationVisitor for ModuleTagsVisitor {
+    type Output = ModuleTags;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["include", "tags"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "include" => {
+                    if let Some(include) = deserialize_patterns(&value, &key_text, diagnostics) {
+                        result.include = include;
+                    }
+                }
+                "tags" => {
+                    if let Some(tags) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.tags = tags;
+                    }
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for BoundaryConstraint {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(BoundaryConstraintVisitor, name, diagnostics)
+    }
+}
+
+struct BoundaryConstraintVisitor;
+impl DeserializationVisitor for BoundaryConstraintVisitor {
+    type Output = BoundaryConstraint;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["sourceTag", "onlyDependOnTags", "notDependOnTags"];
+        let mut source_tag = None;
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "sourceTag" => {
+                    source_tag = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "onlyDependOnTags" => {
+                    result.only_depend_on_tags =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "notDependOnTags" => {
+                    result.not_depend_on_tags =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        // A constraint without its tag doesn't apply to any module
+        let Some(source_tag) = source_tag else {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup! {
+                    "The constraint doesn't have the key "<Emphasis>"sourceTag"</Emphasis>"."
+                })
+                .with_range(range),
+            );
+            return None;
+        };
+        result.source_tag = source_tag;
+        Some(result)
+    }
+}
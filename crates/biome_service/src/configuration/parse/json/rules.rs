@@ -1,6 +1,9 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use crate::configuration::linter::*;
+use crate::configuration::parse::json::unknown_rules::{
+    unknown_group_diagnostic, unknown_rule_diagnostic,
+};
 use crate::Rules;
 use biome_console::markup;
 use biome_deserialize::{
@@ -76,7 +79,7 @@ impl Deserializable for Rules {
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_group_diagnostic(
                                 unknown_key,
                                 key.range(),
                                 &[
@@ -143,6 +146,10 @@ impl Deserializable for A11y {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noAccessKey" => {
                             result.no_access_key =
                                 Deserializable::deserialize(&value, "noAccessKey", diagnostics);
@@ -307,12 +314,14 @@ impl Deserializable for A11y {
                                 Deserializable::deserialize(&value, "useValidLang", diagnostics);
                         }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_rule_diagnostic(
+                                "a11y",
                                 unknown_key,
                                 key.range(),
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noAccessKey",
                                     "noAriaUnsupportedElements",
                                     "noAutofocus",
@@ -394,6 +403,10 @@ impl Deserializable for Complexity {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noBannedTypes" => {
                             result.no_banned_types =
                                 Deserializable::deserialize(&value, "noBannedTypes", diagnostics);
@@ -534,12 +547,14 @@ impl Deserializable for Complexity {
                             );
                         }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_rule_diagnostic(
+                                "complexity",
                                 unknown_key,
                                 key.range(),
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noBannedTypes",
                                     "noExcessiveCognitiveComplexity",
                                     "noExtraBooleanCast",
@@ -617,6 +632,10 @@ impl Deserializable for Correctness {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noChildrenProp" => {
                             result.no_children_prop =
                                 Deserializable::deserialize(&value, "noChildrenProp", diagnostics);
@@ -813,12 +832,14 @@ impl Deserializable for Correctness {
                                 Deserializable::deserialize(&value, "useYield", diagnostics);
                         }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_rule_diagnostic(
+                                "correctness",
                                 unknown_key,
                                 key.range(),
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noChildrenProp",
                                     "noConstAssign",
                                     "noConstantCondition",
@@ -879,7 +900,17 @@ impl Deserializable for Nursery {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = Nursery;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::MAP.union(VisitableType::STR);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                NurseryPreset::deserialize_from_str(value, range, diagnostics)
+                    .map(|preset| NurseryConfiguration::Preset(preset).into())
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -905,6 +936,10 @@ impl Deserializable for Nursery {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noAriaHiddenOnFocusable" => {
                             result.no_aria_hidden_on_focusable = Deserializable::deserialize(
                                 &value,
@@ -912,10 +947,32 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "noBarrelFile" => {
+                            result.no_barrel_file =
+                                Deserializable::deserialize(&value, "noBarrelFile", diagnostics);
+                        }
+                        "noConditionalExpect" => {
+                            result.no_conditional_expect = Deserializable::deserialize(
+                                &value,
+                                "noConditionalExpect",
+                                diagnostics,
+                            );
+                        }
                         "noDefaultExport" => {
                             result.no_default_export =
                                 Deserializable::deserialize(&value, "noDefaultExport", diagnostics);
                         }
+                        "noDeprecatedUsage" => {
+                            result.no_deprecated_usage = Deserializable::deserialize(
+                                &value,
+                                "noDeprecatedUsage",
+                                diagnostics,
+                            );
+                        }
+                        "noDoneCallback" => {
+                            result.no_done_callback =
+                                Deserializable::deserialize(&value, "noDoneCallback", diagnostics);
+                        }
                         "noDuplicateJsonKeys" => {
                             result.no_duplicate_json_keys = Deserializable::deserialize(
                                 &value,
@@ -923,6 +980,13 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "noDynamicFunctionConstructor" => {
+                            result.no_dynamic_function_constructor = Deserializable::deserialize(
+                                &value,
+                                "noDynamicFunctionConstructor",
+                                diagnostics,
+                            );
+                        }
                         "noEmptyBlockStatements" => {
                             result.no_empty_block_statements = Deserializable::deserialize(
                                 &value,
@@ -930,6 +994,41 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "noExcessiveFileLength" => {
+                            result.no_excessive_file_length = Deserializable::deserialize(
+                                &value,
+                                "noExcessiveFileLength",
+                                diagnostics,
+                            );
+                        }
+                        "noExcessiveParams" => {
+                            result.no_excessive_params = Deserializable::deserialize(
+                                &value,
+                                "noExcessiveParams",
+                                diagnostics,
+                            );
+                        }
+                        "noExcessivelyLongFunctions" => {
+                            result.no_excessively_long_functions = Deserializable::deserialize(
+                                &value,
+                                "noExcessivelyLongFunctions",
+                                diagnostics,
+                            );
+                        }
+                        "noHooksInServerComponents" => {
+                            result.no_hooks_in_server_components = Deserializable::deserialize(
+                                &value,
+                                "noHooksInServerComponents",
+                                diagnostics,
+                            );
+                        }
+                        "noIdenticalTestTitles" => {
+                            result.no_identical_test_titles = Deserializable::deserialize(
+                                &value,
+                                "noIdenticalTestTitles",
+                                diagnostics,
+                            );
+                        }
                         "noImplicitAnyLet" => {
                             result.no_implicit_any_let = Deserializable::deserialize(
                                 &value,
@@ -937,6 +1036,56 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "noJsxLiteralText" => {
+                            result.no_jsx_literal_text = Deserializable::deserialize(
+                                &value,
+                                "noJsxLiteralText",
+                                diagnostics,
+                            );
+                        }
+                        "noMisusedPromises" => {
+                            result.no_misused_promises = Deserializable::deserialize(
+                                &value,
+                                "noMisusedPromises",
+                                diagnostics,
+                            );
+                        }
+                        "noReExportAll" => {
+                            result.no_re_export_all =
+                                Deserializable::deserialize(&value, "noReExportAll", diagnostics);
+                        }
+                        "noScriptUrl" => {
+                            result.no_script_url =
+                                Deserializable::deserialize(&value, "noScriptUrl", diagnostics);
+                        }
+                        "noUndeclaredDependencies" => {
+                            result.no_undeclared_dependencies = Deserializable::deserialize(
+                                &value,
+                                "noUndeclaredDependencies",
+                                diagnostics,
+                            );
+                        }
+                        "noUnsafeInnerHtml" => {
+                            result.no_unsafe_inner_html = Deserializable::deserialize(
+                                &value,
+                                "noUnsafeInnerHtml",
+                                diagnostics,
+                            );
+                        }
+                        "noUnsupportedBrowserApis" => {
+                            result.no_unsupported_browser_apis = Deserializable::deserialize(
+                                &value,
+                                "noUnsupportedBrowserApis",
+                                diagnostics,
+                            );
+                        }
+                        "noUnsupportedNodeBuiltins" => {
+                            result.no_unsupported_node_builtins = Deserializable::deserialize(
+                                &value,
+                                "noUnsupportedNodeBuiltins",
+                                diagnostics,
+                            );
+                        }
                         "noUnusedImports" => {
                             result.no_unused_imports =
                                 Deserializable::deserialize(&value, "noUnusedImports", diagnostics);
@@ -959,6 +1108,20 @@ impl Deserializable for Nursery {
                             result.use_await =
                                 Deserializable::deserialize(&value, "useAwait", diagnostics);
                         }
+                        "useConsistentArrayType" => {
+                            result.use_consistent_array_type = Deserializable::deserialize(
+                                &value,
+                                "useConsistentArrayType",
+                                diagnostics,
+                            );
+                        }
+                        "useConsistentRecordType" => {
+                            result.use_consistent_record_type = Deserializable::deserialize(
+                                &value,
+                                "useConsistentRecordType",
+                                diagnostics,
+                            );
+                        }
                         "useGroupedTypeImport" => {
                             result.use_grouped_type_import = Deserializable::deserialize(
                                 &value,
@@ -973,6 +1136,13 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "useJsdocParamMatch" => {
+                            result.use_jsdoc_param_match = Deserializable::deserialize(
+                                &value,
+                                "useJsdocParamMatch",
+                                diagnostics,
+                            );
+                        }
                         "useRegexLiterals" => {
                             result.use_regex_literals = Deserializable::deserialize(
                                 &value,
@@ -980,6 +1150,13 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "useTopLevelDescribe" => {
+                            result.use_top_level_describe = Deserializable::deserialize(
+                                &value,
+                                "useTopLevelDescribe",
+                                diagnostics,
+                            );
+                        }
                         "useValidAriaRole" => {
                             result.use_valid_aria_role = Deserializable::deserialize(
                                 &value,
@@ -987,26 +1164,55 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "useValidJsdoc" => {
+                            result.use_valid_jsdoc =
+                                Deserializable::deserialize(&value, "useValidJsdoc", diagnostics);
+                        }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_rule_diagnostic(
+                                "nursery",
                                 unknown_key,
                                 key.range(),
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noAriaHiddenOnFocusable",
+                                    "noBarrelFile",
+                                    "noConditionalExpect",
                                     "noDefaultExport",
+                                    "noDeprecatedUsage",
+                                    "noDoneCallback",
                                     "noDuplicateJsonKeys",
+                                    "noDynamicFunctionConstructor",
                                     "noEmptyBlockStatements",
+                                    "noExcessiveFileLength",
+                                    "noExcessiveParams",
+                                    "noExcessivelyLongFunctions",
+                                    "noHooksInServerComponents",
+                                    "noIdenticalTestTitles",
                                     "noImplicitAnyLet",
+                                    "noJsxLiteralText",
+                                    "noMisusedPromises",
+                                    "noReExportAll",
+                                    "noScriptUrl",
+                                    "noUndeclaredDependencies",
+                                    "noUnsafeInnerHtml",
+                                    "noUnsupportedBrowserApis",
+                                    "noUnsupportedNodeBuiltins",
                                     "noUnusedImports",
                                     "noUnusedPrivateClassMembers",
                                     "noUselessLoneBlockStatements",
                                     "useAwait",
+                                    "useConsistentArrayType",
+                                    "useConsistentRecordType",
                                     "useGroupedTypeImport",
                                     "useImportRestrictions",
+                                    "useJsdocParamMatch",
                                     "useRegexLiterals",
+                                    "useTopLevelDescribe",
                                     "useValidAriaRole",
+                                    "useValidJsdoc",
                                 ],
                             ));
                         }
@@ -1060,6 +1266,10 @@ impl Deserializable for Performance {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noAccumulatingSpread" => {
                             result.no_accumulating_spread = Deserializable::deserialize(
                                 &value,
@@ -1072,10 +1282,17 @@ impl Deserializable for Performance {
                                 Deserializable::deserialize(&value, "noDelete", diagnostics);
                         }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_rule_diagnostic(
+                                "performance",
                                 unknown_key,
                                 key.range(),
-                                &["recommended", "all", "noAccumulatingSpread", "noDelete"],
+                                &[
+                                    "recommended",
+                                    "all",
+                                    "level",
+                                    "noAccumulatingSpread",
+                                    "noDelete",
+                                ],
                             ));
                         }
                     }
@@ -1128,6 +1345,10 @@ impl Deserializable for Security {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noDangerouslySetInnerHtml" => {
                             result.no_dangerously_set_inner_html = Deserializable::deserialize(
                                 &value,
@@ -1144,12 +1365,14 @@ impl Deserializable for Security {
                                 );
                         }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_rule_diagnostic(
+                                "security",
                                 unknown_key,
                                 key.range(),
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noDangerouslySetInnerHtml",
                                     "noDangerouslySetInnerHtmlWithChildren",
                                 ],
@@ -1205,6 +1428,10 @@ impl Deserializable for Style {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noArguments" => {
                             result.no_arguments =
                                 Deserializable::deserialize(&value, "noArguments", diagnostics);
@@ -1403,12 +1630,14 @@ impl Deserializable for Style {
                                 Deserializable::deserialize(&value, "useWhile", diagnostics);
                         }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_rule_diagnostic(
+                                "style",
                                 unknown_key,
                                 key.range(),
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noArguments",
                                     "noCommaOperator",
                                     "noImplicitBoolean",
@@ -1494,6 +1723,10 @@ impl Deserializable for Suspicious {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noApproximativeNumericConstant" => {
                             result.no_approximative_numeric_constant = Deserializable::deserialize(
                                 &value,
@@ -1746,12 +1979,14 @@ impl Deserializable for Suspicious {
                                 Deserializable::deserialize(&value, "useValidTypeof", diagnostics);
                         }
                         unknown_key => {
-                            diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                            diagnostics.push(unknown_rule_diagnostic(
+                                "suspicious",
                                 unknown_key,
                                 key.range(),
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noApproximativeNumericConstant",
                                     "noArrayIndexKey",
                                     "noAssignInExpressions",
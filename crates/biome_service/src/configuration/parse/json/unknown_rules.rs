@@ -0,0 +1,175 @@
+//! The diagnostics of the rules of the configuration that don't exist.
+//!
+//! Instead of listing all the rules of the group, the diagnostic tells the group of a rule
+//! configured in the wrong group, the new name of a renamed rule, or the rule with the
+//! closest name, e.g. for a typo.
+
+use crate::Rules;
+use biome_console::markup;
+use biome_deserialize::DeserializationDiagnostic;
+use biome_diagnostics::Category;
+use biome_rowan::TextRange;
+
+/// The rules that were renamed or removed, with the name of the rule that replaces them
+const RENAMED_RULES: &[(&str, Option<&str>)] = &[
+    ("noConfusingArrow", None),
+    (
+        "noExcessiveComplexity",
+        Some("noExcessiveCognitiveComplexity"),
+    ),
+    ("noExtraSemicolon", None),
+    ("useCamelCase", Some("useNamingConvention")),
+];
+
+/// Returns the diagnostic of `rule_name`, an unknown key of the group `group`
+pub(crate) fn unknown_rule_diagnostic(
+    group: &str,
+    rule_name: &str,
+    range: TextRange,
+    allowed_keys: &[&str],
+) -> DeserializationDiagnostic {
+    let diagnostic = DeserializationDiagnostic::new(markup!(
+        "Found an unknown rule `"<Emphasis>{rule_name}</Emphasis>"`."
+    ))
+    .with_range(range);
+
+    if let Some(rule_group) = find_group(rule_name) {
+        return with_documentation(
+            diagnostic.with_note(markup!(
+                "The rule "<Emphasis>{rule_name}</Emphasis>" belongs to the group "<Emphasis>{rule_group}</Emphasis>", not to the group "<Emphasis>{group}</Emphasis>"."
+            )),
+            rule_group,
+            rule_name,
+        );
+    }
+
+    if let Some((_, new_name)) = RENAMED_RULES.iter().find(|(name, _)| *name == rule_name) {
+        return match new_name.and_then(|new_name| Some((find_group(new_name)?, new_name))) {
+            Some((new_group, new_name)) => with_documentation(
+                diagnostic.with_note(markup!(
+                    "The rule "<Emphasis>{rule_name}</Emphasis>" was renamed to "<Emphasis>{new_group}"/"{new_name}</Emphasis>"."
+                )),
+                new_group,
+                new_name,
+            ),
+            None => diagnostic.with_note(markup!(
+                "The rule "<Emphasis>{rule_name}</Emphasis>" was removed from Biome."
+            )),
+        };
+    }
+
+    if let Some((similar_group, similar_name)) = find_similar_rule(group, rule_name) {
+        return with_documentation(
+            diagnostic.with_note(markup!(
+                "Did you mean "<Emphasis>{similar_group}"/"{similar_name}</Emphasis>"?"
+            )),
+            similar_group,
+            similar_name,
+        );
+    }
+
+    diagnostic.note_with_list("Accepted keys", allowed_keys)
+}
+
+/// Returns the diagnostic of `key`, an unknown key of `rules`. It's usually a rule configured
+/// outside of its group.
+pub(crate) fn unknown_group_diagnostic(
+    key: &str,
+    range: TextRange,
+    allowed_keys: &[&str],
+) -> DeserializationDiagnostic {
+    match find_group(key) {
+        Some(group) => with_documentation(
+            DeserializationDiagnostic::new(markup!(
+                "The rule "<Emphasis>{key}</Emphasis>" must be configured inside its group."
+            ))
+            .with_range(range)
+            .with_note(markup!(
+                "Move the rule in the group "<Emphasis>{group}</Emphasis>", e.g. "<Emphasis>"\""{group}"\": { \""{key}"\": \"error\" }"</Emphasis>"."
+            )),
+            group,
+            key,
+        ),
+        None => DeserializationDiagnostic::new_unknown_key(key, range, allowed_keys),
+    }
+}
+
+/// Returns the group of the rule `rule_name`
+fn find_group(rule_name: &str) -> Option<&'static str> {
+    Rules::GROUPS
+        .iter()
+        .find(|(_, rules)| rules.contains(&rule_name))
+        .map(|(group, _)| *group)
+}
+
+/// Returns the rule with the name closest to `rule_name`, if it's close enough to be a typo.
+/// The rules of `group` win over the rules of the other groups with the same distance.
+fn find_similar_rule(group: &str, rule_name: &str) -> Option<(&'static str, &'static str)> {
+    let rule_name = rule_name.to_ascii_lowercase();
+    let max_distance = rule_name.len() / 3;
+    Rules::GROUPS
+        .iter()
+        .flat_map(|(rule_group, rules)| rules.iter().map(move |rule| (*rule_group, *rule)))
+        .map(|(rule_group, rule)| {
+            let distance = edit_distance(&rule_name, &rule.to_ascii_lowercase());
+            (distance, rule_group != group, rule_group, rule)
+        })
+        .filter(|(distance, ..)| *distance <= max_distance)
+        .min_by_key(|(distance, is_other_group, ..)| (*distance, *is_other_group))
+        .map(|(_, _, rule_group, rule)| (rule_group, rule))
+}
+
+/// Adds the link to the documentation of the rule
+fn with_documentation(
+    diagnostic: DeserializationDiagnostic,
+    group: &str,
+    rule_name: &str,
+) -> DeserializationDiagnostic {
+    let link = format!("lint/{group}/{rule_name}")
+        .parse::<&'static Category>()
+        .ok()
+        .and_then(Category::link);
+    match link {
+        Some(link) => diagnostic.with_note(markup!(
+            "See the documentation of the rule: "<Hyperlink href={link}>{link}</Hyperlink>
+        )),
+        None => diagnostic,
+    }
+}
+
+/// The Levenshtein distance between two strings: the number of characters to insert, remove
+/// or replace to turn `left` into `right`
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right: Vec<_> = right.chars().collect();
+    let mut previous_row: Vec<_> = (0..=right.len()).collect();
+    for (left_index, left_char) in left.chars().enumerate() {
+        let mut current_row = vec![left_index + 1];
+        for (right_index, right_char) in right.iter().enumerate() {
+            let substitution = previous_row[right_index] + usize::from(left_char != *right_char);
+            let insertion = current_row[right_index] + 1;
+            let deletion = previous_row[right_index + 1] + 1;
+            current_row.push(substitution.min(insertion).min(deletion));
+        }
+        previous_row = current_row;
+    }
+    previous_row[right.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_similar_rules() {
+        assert_eq!(edit_distance("noDebuger", "noDebugger"), 1);
+        assert_eq!(
+            find_similar_rule("suspicious", "noDebuger"),
+            Some(("suspicious", "noDebugger"))
+        );
+        assert_eq!(
+            find_similar_rule("style", "nodebugger"),
+            Some(("suspicious", "noDebugger"))
+        );
+        assert_eq!(find_similar_rule("style", "notARealRule"), None);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::configuration::overrides::{
+    OverrideFilesConfiguration, OverrideFormatterConfiguration, OverrideLinterConfiguration,
+    OverrideOrganizeImportsConfiguration, OverridePattern,
+};
+use crate::configuration::{JavascriptConfiguration, JsonConfiguration};
+use crate::{Configuration, MergeWith};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Named sets of options, merged over the configuration when a profile is selected with
+/// `--profile` or `BIOME_PROFILE`
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Profiles(pub IndexMap<String, Profile>);
+
+impl FromStr for Profiles {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl Profiles {
+    pub fn get(&self, name: &str) -> Option<&Profile> {
+        self.0.get(name)
+    }
+
+    /// The names of the profiles, in the order of the configuration
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+impl MergeWith<Profiles> for Profiles {
+    fn merge_with(&mut self, other: Profiles) {
+        for (name, other_profile) in other.0 {
+            self.0.entry(name).or_default().merge_with(other_profile);
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: Profiles)
+    where
+        Profiles: Default,
+    {
+        for (name, other_profile) in other.0 {
+            self.0
+                .entry(name)
+                .or_default()
+                .merge_with_if_not_default(other_profile);
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct Profile {
+    /// Specific configuration for the JavaScript language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub javascript: Option<JavascriptConfiguration>,
+
+    /// Specific configuration for the Json language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json: Option<JsonConfiguration>,
+
+    /// The configuration of the formatter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<OverrideFormatterConfiguration>,
+
+    /// The configuration of the linter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linter: Option<OverrideLinterConfiguration>,
+
+    /// The configuration of the import sorting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organize_imports: Option<OverrideOrganizeImportsConfiguration>,
+
+    /// The configuration of the filesystem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<OverrideFilesConfiguration>,
+}
+
+impl Profile {
+    /// Merges the options of the profile over `configuration`
+    pub fn apply_to(self, configuration: &mut Configuration) {
+        let Profile {
+            javascript,
+            json,
+            formatter,
+            linter,
+            organize_imports,
+            files,
+        } = self;
+        OverridePattern {
+            ignore: None,
+            include: None,
+            javascript,
+            json,
+            formatter,
+            linter,
+            organize_imports,
+            files,
+        }
+        .apply_to(configuration);
+    }
+}
+
+impl MergeWith<Profile> for Profile {
+    fn merge_with(&mut self, other: Profile) {
+        if let Some(other) = other.formatter {
+            let formatter = self
+                .formatter
+                .get_or_insert(OverrideFormatterConfiguration::default());
+            formatter.merge_with(other);
+        }
+        if let Some(other) = other.linter {
+            let linter = self
+                .linter
+                .get_or_insert(OverrideLinterConfiguration::default());
+            linter.merge_with(other);
+        }
+        if let Some(other) = other.organize_imports {
+            let organize_imports = self
+                .organize_imports
+                .get_or_insert(OverrideOrganizeImportsConfiguration::default());
+            organize_imports.merge_with(other);
+        }
+        if let Some(other) = other.files {
+            let files = self
+                .files
+                .get_or_insert(OverrideFilesConfiguration::default());
+            files.merge_with(other);
+        }
+        if let Some(other) = other.javascript {
+            let javascript = self
+                .javascript
+                .get_or_insert(JavascriptConfiguration::default());
+            javascript.merge_with(other)
+        }
+        if let Some(other) = other.json {
+            let json = self.json.get_or_insert(JsonConfiguration::default());
+            json.merge_with(other)
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: Profile)
+    where
+        Profile: Default,
+    {
+        if other != Profile::default() {
+            self.merge_with(other)
+        }
+    }
+}
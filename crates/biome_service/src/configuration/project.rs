@@ -0,0 +1,246 @@
+use crate::configuration::linter::RulePlainConfiguration;
+use crate::configuration::merge::MergeWith;
+use crate::settings::{to_matcher, ProjectSettings};
+use crate::WorkspaceError;
+use biome_deserialize::StringSet;
+use biome_diagnostics::Severity;
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The configuration of the project analysis
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ProjectConfiguration {
+    /// The tags of the modules, e.g. `scope:ui`, assigned to the files and the directories
+    /// that match the patterns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<ModuleTags>>,
+
+    /// The constraints on the tags of the modules that the tagged modules can import
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boundaries: Option<Vec<BoundaryConstraint>>,
+
+    /// The severity of the diagnostics of the project rules, `"off"` disables a rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<ProjectRules>,
+}
+
+impl FromStr for ProjectConfiguration {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+/// The levels of the project rules, e.g. `"importCycle": "error"`. The diagnostics of a
+/// rule can also be suppressed with a comment, e.g. `// biome-ignore project/importCycle: <explanation>`
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ProjectRules {
+    /// The static imports that are part of an import cycle. Defaults to `"warn"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_cycle: Option<RulePlainConfiguration>,
+
+    /// The imports of the modules that re-export all the exports of other modules. Defaults
+    /// to `"warn"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub barrel_import: Option<RulePlainConfiguration>,
+
+    /// The imports that break the constraints of `boundaries`. Defaults to `"error"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_boundary: Option<RulePlainConfiguration>,
+
+    /// The imports of the client modules that load a module importing `server-only`.
+    /// Defaults to `"error"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_only_import: Option<RulePlainConfiguration>,
+
+    /// The exports that no module of the project imports. The entry points of the packages
+    /// aren't checked. Defaults to `"warn"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_export: Option<RulePlainConfiguration>,
+
+    /// The classes of the CSS modules that the modules importing them don't read. Defaults
+    /// to `"warn"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_class: Option<RulePlainConfiguration>,
+
+    /// The classes read on the imports of CSS modules that don't declare them. Defaults to
+    /// `"warn"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unknown_class: Option<RulePlainConfiguration>,
+}
+
+impl ProjectRules {
+    /// The names of the rules, as written in the configuration and after `project/` in the
+    /// categories of their diagnostics
+    pub const RULE_NAMES: &'static [&'static str] = &[
+        "importCycle",
+        "barrelImport",
+        "moduleBoundary",
+        "serverOnlyImport",
+        "unusedExport",
+        "unusedClass",
+        "unknownClass",
+    ];
+
+    /// Returns the level of the rule named `name`, `None` when it isn't configured
+    pub fn get(&self, name: &str) -> Option<&RulePlainConfiguration> {
+        match name {
+            "importCycle" => self.import_cycle.as_ref(),
+            "barrelImport" => self.barrel_import.as_ref(),
+            "moduleBoundary" => self.module_boundary.as_ref(),
+            "serverOnlyImport" => self.server_only_import.as_ref(),
+            "unusedExport" => self.unused_export.as_ref(),
+            "unusedClass" => self.unused_class.as_ref(),
+            "unknownClass" => self.unknown_class.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the severity of the diagnostics of the rule named `name`, `None` when the rule
+    /// is off. The rules that aren't configured keep the severity of their diagnostics.
+    pub fn severity(&self, name: &str, default: Severity) -> Option<Severity> {
+        match self.get(name) {
+            Some(RulePlainConfiguration::Off) => None,
+            Some(level) => Some(level.into()),
+            None => Some(default),
+        }
+    }
+}
+
+impl MergeWith<ProjectRules> for ProjectRules {
+    fn merge_with(&mut self, other: ProjectRules) {
+        let ProjectRules {
+            import_cycle,
+            barrel_import,
+            module_boundary,
+            server_only_import,
+            unused_export,
+            unused_class,
+            unknown_class,
+        } = other;
+        if import_cycle.is_some() {
+            self.import_cycle = import_cycle;
+        }
+        if barrel_import.is_some() {
+            self.barrel_import = barrel_import;
+        }
+        if module_boundary.is_some() {
+            self.module_boundary = module_boundary;
+        }
+        if server_only_import.is_some() {
+            self.server_only_import = server_only_import;
+        }
+        if unused_export.is_some() {
+            self.unused_export = unused_export;
+        }
+        if unused_class.is_some() {
+            self.unused_class = unused_class;
+        }
+        if unknown_class.is_some() {
+            self.unknown_class = unknown_class;
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: ProjectRules)
+    where
+        ProjectRules: Default,
+    {
+        if other != ProjectRules::default() {
+            self.merge_with(other)
+        }
+    }
+}
+
+/// Tags assigned to the modules that match a list of patterns
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModuleTags {
+    /// A list of Unix shell style patterns. The modules that match them, or whose directory
+    /// matches them, have the tags.
+    pub include: StringSet,
+
+    /// The tags of the modules, e.g. `scope:ui` or `type:feature`
+    pub tags: StringSet,
+}
+
+/// A constraint on the modules that the modules with a tag can import
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BoundaryConstraint {
+    /// The tag of the modules that the constraint applies to
+    pub source_tag: String,
+
+    /// The modules with the tag can only import the modules that have one of these tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_depend_on_tags: Option<StringSet>,
+
+    /// The modules with the tag can't import the modules that have one of these tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_depend_on_tags: Option<StringSet>,
+}
+
+impl BoundaryConstraint {
+    /// Whether the modules with the source tag can import a module that has `tags`
+    pub fn allows(&self, tags: &IndexSet<&str>) -> bool {
+        let has_allowed_tag = self.only_depend_on_tags.as_ref().map_or(true, |allowed| {
+            allowed.iter().any(|tag| tags.contains(tag.as_str()))
+        });
+        let has_forbidden_tag = self
+            .not_depend_on_tags
+            .as_ref()
+            .is_some_and(|forbidden| forbidden.iter().any(|tag| tags.contains(tag.as_str())));
+        has_allowed_tag && !has_forbidden_tag
+    }
+}
+
+impl MergeWith<ProjectConfiguration> for ProjectConfiguration {
+    fn merge_with(&mut self, other: ProjectConfiguration) {
+        if let Some(tags) = other.tags {
+            self.tags = Some(tags)
+        }
+        if let Some(boundaries) = other.boundaries {
+            self.boundaries = Some(boundaries)
+        }
+        if let Some(rules) = other.rules {
+            match self.rules.as_mut() {
+                Some(this) => this.merge_with(rules),
+                None => self.rules = Some(rules),
+            }
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: ProjectConfiguration)
+    where
+        ProjectConfiguration: Default,
+    {
+        if other != ProjectConfiguration::default() {
+            self.merge_with(other)
+        }
+    }
+}
+
+impl TryFrom<ProjectConfiguration> for ProjectSettings {
+    type Error = WorkspaceError;
+
+    fn try_from(project: ProjectConfiguration) -> Result<Self, Self::Error> {
+        let mut tags = vec![];
+        for module_tags in project.tags.unwrap_or_default() {
+            if let Some(matcher) = to_matcher(Some(&module_tags.include))? {
+                tags.push((matcher, module_tags.tags.into_index_set()));
+            }
+        }
+        Ok(Self {
+            tags,
+            boundaries: project.boundaries.unwrap_or_default(),
+            rules: project.rules.unwrap_or_default(),
+        })
+    }
+}
@@ -1,9 +1,15 @@
 use crate::configuration::merge::MergeWith;
+use crate::workspace::VcsIgnoreFile;
+use biome_fs::{FileSystem, FileSystemExt};
 use bpaf::Bpaf;
+use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Mutex;
 
 const GIT_IGNORE_FILE_NAME: &str = ".gitignore";
+const IGNORE_FILE_NAME: &str = ".ignore";
 
 /// Set of properties to integrate Biome with a VCS software.
 #[derive(Debug, Default, Deserialize, Serialize, Clone, Bpaf, Eq, PartialEq)]
@@ -33,6 +39,11 @@ pub struct VcsConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("vcs-root"), argument("PATH"), optional)]
     pub root: Option<String>,
+
+    /// The main branch of the project. Biome uses it as base when `--changed` is passed without `--since`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("vcs-default-branch"), argument("BRANCH"), optional)]
+    pub default_branch: Option<String>,
 }
 
 impl VcsConfiguration {
@@ -61,6 +72,9 @@ impl MergeWith<VcsConfiguration> for VcsConfiguration {
         if let Some(root) = other.root {
             self.root = Some(root);
         }
+        if let Some(default_branch) = other.default_branch {
+            self.default_branch = Some(default_branch);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: VcsConfiguration)
@@ -88,6 +102,14 @@ impl VcsClientKind {
             VcsClientKind::Git => GIT_IGNORE_FILE_NAME,
         }
     }
+
+    /// The ignore files read in the directories of the project, from the least to the most
+    /// specific: the `.ignore` files, also read by tools like ripgrep, take precedence
+    pub const fn ignore_files(&self) -> &'static [&'static str] {
+        match self {
+            VcsClientKind::Git => &[GIT_IGNORE_FILE_NAME, IGNORE_FILE_NAME],
+        }
+    }
 }
 
 impl FromStr for VcsClientKind {
@@ -100,3 +122,103 @@ impl FromStr for VcsClientKind {
         }
     }
 }
+
+/// The ignore files of the subdirectories of the VCS root, looked up while the files are handled,
+/// like the nested configuration files. The ignored directories aren't traversed, so their ignore
+/// files are never read, like with git.
+#[derive(Debug)]
+pub struct NestedVcsIgnoreFiles {
+    /// The root of the VCS, its ignore files are read before the files are handled
+    root_directory: PathBuf,
+    /// The directory the relative paths are resolved from
+    working_directory: Option<PathBuf>,
+    file_names: &'static [&'static str],
+    /// The directories already looked up
+    directories: Mutex<FxHashSet<PathBuf>>,
+}
+
+impl NestedVcsIgnoreFiles {
+    pub fn new(
+        root_directory: PathBuf,
+        working_directory: Option<PathBuf>,
+        client_kind: &VcsClientKind,
+    ) -> Self {
+        let root_directory = match &working_directory {
+            Some(working_directory) if root_directory.is_relative() => {
+                working_directory.join(root_directory)
+            }
+            _ => root_directory,
+        };
+        Self {
+            root_directory,
+            working_directory,
+            file_names: client_kind.ignore_files(),
+            directories: Mutex::default(),
+        }
+    }
+
+    /// Returns the ignore files of the directories between the root of the VCS and `directory`
+    /// that weren't looked up yet, from the least to the most specific. Their directories are
+    /// spelled like `directory`.
+    pub fn load(&self, fs: &dyn FileSystem, directory: &Path) -> Vec<VcsIgnoreFile> {
+        let mut directories = self.directories.lock().unwrap();
+        let mut pending = vec![];
+        for ancestor in directory.ancestors() {
+            if directories.contains(ancestor) || !self.is_nested(ancestor) {
+                break;
+            }
+            pending.push(ancestor);
+            // The paths that start with `./` don't have an empty ancestor
+            if ancestor == Path::new(".") {
+                break;
+            }
+        }
+
+        let mut ignore_files = vec![];
+        for directory in pending.into_iter().rev() {
+            directories.insert(directory.to_path_buf());
+            // The dependencies and the directory of the VCS aren't part of the project
+            if directory.components().any(|component| {
+                component.as_os_str() == "node_modules" || component.as_os_str() == ".git"
+            }) {
+                continue;
+            }
+            let resolved_directory = self.resolve(directory);
+            for file_name in self.file_names {
+                let path = resolved_directory.join(file_name);
+                if !fs.path_exists(&path) {
+                    continue;
+                }
+                if let Ok(content) = fs.read_to_string(&path) {
+                    ignore_files.push(VcsIgnoreFile {
+                        path,
+                        directory: directory.to_path_buf(),
+                        content,
+                    });
+                }
+            }
+        }
+        ignore_files
+    }
+
+    /// Whether `directory` is a subdirectory of the root of the VCS
+    fn is_nested(&self, directory: &Path) -> bool {
+        // The path can't be compared with the root once it goes up, e.g. `../packages`
+        if directory
+            .components()
+            .any(|component| component == Component::ParentDir)
+        {
+            return false;
+        }
+        let directory = self.resolve(directory);
+        directory != self.root_directory && directory.starts_with(&self.root_directory)
+    }
+
+    /// Resolves `directory` from the working directory
+    fn resolve(&self, directory: &Path) -> PathBuf {
+        match &self.working_directory {
+            Some(working_directory) if directory.is_relative() => working_directory.join(directory),
+            _ => directory.to_path_buf(),
+        }
+    }
+}
@@ -33,6 +33,9 @@ pub enum WorkspaceError {
     PrintError(PrintError),
     /// The file could not be formatted since it has syntax errors and `format_with_errors` is disabled
     FormatWithErrorsDisabled(FormatWithErrorsDisabled),
+    /// The formatted code doesn't render the same JSX text as the original code, and the JSX
+    /// whitespace fidelity mode is enabled
+    JsxTextChanged(JsxTextChanged),
     /// The file could not be analyzed because a rule caused an error.
     RuleError(RuleError),
     /// Thrown when Biome can't read a generic directory
@@ -51,6 +54,10 @@ pub enum WorkspaceError {
     FileTooLarge(FileTooLarge),
     /// Diagnostics emitted when querying the file system
     FileSystem(FileSystemDiagnostic),
+    /// The pattern passed to the search engine is invalid
+    InvalidPattern(InvalidPattern),
+    /// The request was cancelled before it completed
+    Cancelled(Cancelled),
 }
 
 impl WorkspaceError {
@@ -58,6 +65,10 @@ impl WorkspaceError {
         Self::FormatWithErrorsDisabled(FormatWithErrorsDisabled)
     }
 
+    pub fn jsx_text_changed() -> Self {
+        Self::JsxTextChanged(JsxTextChanged)
+    }
+
     pub fn cant_read_file(path: String) -> Self {
         Self::CantReadFile(CantReadFile { path })
     }
@@ -86,6 +97,16 @@ impl WorkspaceError {
         })
     }
 
+    pub fn invalid_pattern(reason: impl Into<String>) -> Self {
+        Self::InvalidPattern(InvalidPattern {
+            reason: reason.into(),
+        })
+    }
+
+    pub fn cancelled() -> Self {
+        Self::Cancelled(Cancelled)
+    }
+
     pub fn report_not_serializable(reason: impl Into<String>) -> Self {
         Self::ReportNotSerializable(ReportNotSerializable {
             reason: reason.into(),
@@ -117,6 +138,7 @@ impl Diagnostic for WorkspaceError {
     fn category(&self) -> Option<&'static Category> {
         match self {
             WorkspaceError::FormatWithErrorsDisabled(error) => error.category(),
+            WorkspaceError::JsxTextChanged(error) => error.category(),
             WorkspaceError::FormatError(err) => err.category(),
             WorkspaceError::PrintError(err) => err.category(),
             WorkspaceError::RuleError(error) => error.category(),
@@ -132,12 +154,15 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileIgnored(error) => error.category(),
             WorkspaceError::FileTooLarge(error) => error.category(),
             WorkspaceError::FileSystem(error) => error.category(),
+            WorkspaceError::InvalidPattern(error) => error.category(),
+            WorkspaceError::Cancelled(error) => error.category(),
         }
     }
 
     fn description(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         match self {
             WorkspaceError::FormatWithErrorsDisabled(error) => error.description(fmt),
+            WorkspaceError::JsxTextChanged(error) => error.description(fmt),
             WorkspaceError::FormatError(error) => Diagnostic::description(error, fmt),
             WorkspaceError::PrintError(error) => Diagnostic::description(error, fmt),
             WorkspaceError::RuleError(error) => Diagnostic::description(error, fmt),
@@ -153,12 +178,15 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileIgnored(error) => error.description(fmt),
             WorkspaceError::FileTooLarge(error) => error.description(fmt),
             WorkspaceError::FileSystem(error) => error.description(fmt),
+            WorkspaceError::InvalidPattern(error) => error.description(fmt),
+            WorkspaceError::Cancelled(error) => error.description(fmt),
         }
     }
 
     fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
         match self {
             WorkspaceError::FormatWithErrorsDisabled(error) => error.message(fmt),
+            WorkspaceError::JsxTextChanged(error) => error.message(fmt),
             WorkspaceError::FormatError(err) => err.message(fmt),
             WorkspaceError::PrintError(err) => err.message(fmt),
             WorkspaceError::RuleError(error) => error.message(fmt),
@@ -174,6 +202,8 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileIgnored(error) => error.message(fmt),
             WorkspaceError::FileTooLarge(error) => error.message(fmt),
             WorkspaceError::FileSystem(error) => error.message(fmt),
+            WorkspaceError::InvalidPattern(error) => error.message(fmt),
+            WorkspaceError::Cancelled(error) => error.message(fmt),
         }
     }
 
@@ -190,11 +220,14 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::NotFound(error) => error.severity(),
             WorkspaceError::SourceFileNotSupported(error) => error.severity(),
             WorkspaceError::FormatWithErrorsDisabled(error) => error.severity(),
+            WorkspaceError::JsxTextChanged(error) => error.severity(),
             WorkspaceError::CantReadDirectory(error) => error.severity(),
             WorkspaceError::CantReadFile(error) => error.severity(),
             WorkspaceError::FileIgnored(error) => error.severity(),
             WorkspaceError::FileTooLarge(error) => error.severity(),
             WorkspaceError::FileSystem(error) => error.severity(),
+            WorkspaceError::InvalidPattern(error) => error.severity(),
+            WorkspaceError::Cancelled(error) => error.severity(),
         }
     }
 
@@ -211,11 +244,14 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::NotFound(error) => error.tags(),
             WorkspaceError::SourceFileNotSupported(error) => error.tags(),
             WorkspaceError::FormatWithErrorsDisabled(error) => error.tags(),
+            WorkspaceError::JsxTextChanged(error) => error.tags(),
             WorkspaceError::CantReadDirectory(error) => error.tags(),
             WorkspaceError::CantReadFile(error) => error.tags(),
             WorkspaceError::FileIgnored(error) => error.tags(),
             WorkspaceError::FileTooLarge(error) => error.tags(),
             WorkspaceError::FileSystem(error) => error.tags(),
+            WorkspaceError::InvalidPattern(error) => error.tags(),
+            WorkspaceError::Cancelled(error) => error.tags(),
         }
     }
 
@@ -232,11 +268,14 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::NotFound(error) => error.location(),
             WorkspaceError::SourceFileNotSupported(error) => error.location(),
             WorkspaceError::FormatWithErrorsDisabled(error) => error.location(),
+            WorkspaceError::JsxTextChanged(error) => error.location(),
             WorkspaceError::CantReadDirectory(error) => error.location(),
             WorkspaceError::CantReadFile(error) => error.location(),
             WorkspaceError::FileIgnored(error) => error.location(),
             WorkspaceError::FileTooLarge(error) => error.location(),
             WorkspaceError::FileSystem(error) => error.location(),
+            WorkspaceError::InvalidPattern(error) => error.location(),
+            WorkspaceError::Cancelled(error) => error.location(),
         }
     }
 
@@ -253,11 +292,14 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::NotFound(error) => Diagnostic::source(error),
             WorkspaceError::SourceFileNotSupported(error) => Diagnostic::source(error),
             WorkspaceError::FormatWithErrorsDisabled(error) => Diagnostic::source(error),
+            WorkspaceError::JsxTextChanged(error) => Diagnostic::source(error),
             WorkspaceError::CantReadDirectory(error) => Diagnostic::source(error),
             WorkspaceError::CantReadFile(error) => Diagnostic::source(error),
             WorkspaceError::FileIgnored(error) => Diagnostic::source(error),
             WorkspaceError::FileTooLarge(error) => Diagnostic::source(error),
             WorkspaceError::FileSystem(error) => Diagnostic::source(error),
+            WorkspaceError::InvalidPattern(error) => Diagnostic::source(error),
+            WorkspaceError::Cancelled(error) => Diagnostic::source(error),
         }
     }
 
@@ -274,11 +316,14 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::NotFound(error) => error.advices(visitor),
             WorkspaceError::SourceFileNotSupported(error) => error.advices(visitor),
             WorkspaceError::FormatWithErrorsDisabled(error) => error.advices(visitor),
+            WorkspaceError::JsxTextChanged(error) => error.advices(visitor),
             WorkspaceError::CantReadDirectory(error) => error.advices(visitor),
             WorkspaceError::CantReadFile(error) => error.advices(visitor),
             WorkspaceError::FileIgnored(error) => error.advices(visitor),
             WorkspaceError::FileTooLarge(error) => error.advices(visitor),
             WorkspaceError::FileSystem(error) => error.advices(visitor),
+            WorkspaceError::InvalidPattern(error) => error.advices(visitor),
+            WorkspaceError::Cancelled(error) => error.advices(visitor),
         }
     }
     fn verbose_advices(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
@@ -294,11 +339,14 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::NotFound(error) => error.verbose_advices(visitor),
             WorkspaceError::SourceFileNotSupported(error) => error.verbose_advices(visitor),
             WorkspaceError::FormatWithErrorsDisabled(error) => error.verbose_advices(visitor),
+            WorkspaceError::JsxTextChanged(error) => error.verbose_advices(visitor),
             WorkspaceError::CantReadDirectory(error) => error.verbose_advices(visitor),
             WorkspaceError::CantReadFile(error) => error.verbose_advices(visitor),
             WorkspaceError::FileIgnored(error) => error.verbose_advices(visitor),
             WorkspaceError::FileTooLarge(error) => error.verbose_advices(visitor),
             WorkspaceError::FileSystem(error) => error.verbose_advices(visitor),
+            WorkspaceError::InvalidPattern(error) => error.verbose_advices(visitor),
+            WorkspaceError::Cancelled(error) => error.verbose_advices(visitor),
         }
     }
 }
@@ -358,6 +406,13 @@ pub struct NotFound;
 #[diagnostic(category = "format", message = "Format with errors is disabled.")]
 pub struct FormatWithErrorsDisabled;
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "format",
+    message = "The formatted code doesn't render the same JSX text as the original code, the file isn't formatted."
+)]
+pub struct JsxTextChanged;
+
 #[derive(Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
@@ -384,6 +439,26 @@ pub struct CantReadFile {
     path: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    message(
+        message("The pattern is invalid: "{self.reason}),
+        description = "The pattern is invalid: {reason}"
+    )
+)]
+pub struct InvalidPattern {
+    reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "internalError/cancelled",
+    message = "The request was cancelled.",
+    tags(INTERNAL)
+)]
+pub struct Cancelled;
+
 #[derive(Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
@@ -407,7 +482,7 @@ pub struct FileTooLarge {
 
 impl Diagnostic for FileTooLarge {
     fn category(&self) -> Option<&'static Category> {
-        Some(category!("internalError/fs"))
+        Some(category!("files/tooLarge"))
     }
 
     fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
@@ -0,0 +1,62 @@
+//! The CSS modules of a project, e.g. `button.module.css`.
+//!
+//! The CSS files aren't linted or formatted yet, but the classes of the CSS modules are
+//! extracted for the project analysis, which compares them to the classes that the
+//! JavaScript modules read, e.g. `styles.button`.
+
+use crate::module_graph::{project_suppressions, ModuleExport, StylesheetInfo};
+use biome_css_parser::{parse_css, CssParserOptions};
+use biome_css_syntax::{CssClassSelector, CssPseudoClassFunctionSelector};
+use biome_rowan::AstNode;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+
+/// Returns `true` if the file at `path` is a CSS module, whose classes are imported by the
+/// JavaScript modules
+pub fn is_css_module(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|file_name| file_name.to_str())
+        .is_some_and(|file_name| file_name.ends_with(".module.css"))
+}
+
+/// Returns the classes that the CSS module `text` exports, with the range of their first
+/// selector, and the suppression comments of the project rules.
+///
+/// The classes of `:global(.name)` keep their name, so they aren't exported.
+pub(crate) fn css_module_info(text: &str) -> StylesheetInfo {
+    let parse = parse_css(text, CssParserOptions::default());
+    let mut seen = FxHashSet::default();
+
+    let classes = parse
+        .syntax()
+        .descendants()
+        .filter_map(CssClassSelector::cast)
+        .filter(|selector| !is_global(selector))
+        .filter_map(|selector| {
+            let name = selector.name().ok()?.value_token().ok()?;
+            let name = name.text_trimmed();
+            seen.insert(name.to_string()).then(|| ModuleExport {
+                name: name.to_string(),
+                range: selector.range(),
+            })
+        })
+        .collect();
+
+    StylesheetInfo {
+        classes,
+        suppressions: project_suppressions(&parse.syntax()),
+    }
+}
+
+/// Returns `true` if the selector is inside `:global(...)`
+fn is_global(selector: &CssClassSelector) -> bool {
+    selector
+        .syntax()
+        .ancestors()
+        .filter_map(CssPseudoClassFunctionSelector::cast)
+        .any(|function| {
+            function
+                .name()
+                .is_ok_and(|name| name.text_trimmed() == "global")
+        })
+}
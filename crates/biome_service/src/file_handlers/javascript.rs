@@ -3,45 +3,69 @@ use super::{
     LintResults, Mime, ParserCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
-use crate::file_handlers::{is_diagnostic_error, Features, FixAllParams, Language as LanguageId};
+use crate::file_handlers::search::StructuralPattern;
+use crate::file_handlers::{
+    applies_unsafe_fixes, is_css_module, is_diagnostic_error, Features, FixAllParams,
+    Language as LanguageId,
+};
+use crate::module_graph::{
+    project_suppressions, ClassAccesses, ExportChange, ExportConversion, ImportBindings,
+    ImportSpecifier, ImportedNames, ModuleExport, ModuleImport, ModuleInfo,
+};
 use crate::settings::OverrideSettings;
-use crate::workspace::OrganizeImportsResult;
+use crate::workspace::{OrganizeImportsResult, TextReplacement};
 use crate::{
+    metrics,
     settings::{FormatSettings, Language, LanguageListSettings, LanguageSettings, SettingsHandle},
     workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        CodeAction, DocumentSymbol, FixAction, FixFileResult, GetSyntaxTreeResult, InlayHint,
+        PrepareRenameResult, PullActionsResult, RenameResult, SymbolKind,
     },
     Rules, WorkspaceError,
 };
 use biome_analyze::{
-    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, GroupCategory, Never,
-    QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
+    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, CancellationToken, ControlFlow,
+    GroupCategory, Never, QueryCacheStatistics, QueryMatch, RegistryVisitor, RuleCategories,
+    RuleCategory, RuleFilter, RuleGroup,
 };
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
 use biome_fs::RomePath;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
+use biome_js_analyze::utils::rename::{
+    AnyJsRenamableDeclaration, RenamableNode, RenameError, RenameSymbolExtensions,
+};
 use biome_js_analyze::{
     analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, RuleError,
 };
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
-    ArrowParentheses, BracketSameLine, BracketSpacing, JsFormatOptions, QuoteProperties,
-    QuoteStyle, Semicolons,
+    ArrowParentheses, BlankLineAfterImports, BlankLineBeforeReturn, BracketSameLine,
+    BracketSpacing, JsFormatOptions, JsxWhitespaceFidelity, QuoteProperties, QuoteStyle,
+    Semicolons, SingleLineIfStatements, UnionBreakThreshold, UnionLeadingSeparator,
 };
 use biome_js_formatter::format_node;
+use biome_js_formatter::utils::jsx::jsx_rendered_children;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_semantic::{semantic_model, SemanticModel, SemanticModelOptions};
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    inner_string_text, AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsCallArgument,
+    AnyJsClassMember, AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportDefaultDeclaration,
+    AnyJsExportNamedSpecifier, AnyJsExpression, AnyJsFormalParameter, AnyJsImportClause,
+    AnyJsLiteralExpression, AnyJsMemberExpression, AnyJsModuleItem, AnyJsNamedImport,
+    AnyJsNamedImportSpecifier, AnyJsParameter, AnyJsRoot, AnyJsStatement, AnyTsTypeMember,
+    JsCallExpression, JsExportDefaultExpressionClause, JsFileSource, JsIdentifierBinding,
+    JsImportCallExpression, JsLanguage, JsSyntaxKind, JsSyntaxNode, JsVariableDeclaration,
+    JsVariableDeclarator, TextRange, TextSize, TsEnumMember, TsModuleDeclaration,
 };
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, FileSource, NodeCache};
+use biome_rowan::{
+    AstNode, AstSeparatedList, BatchMutationExt, Direction, FileSource, NodeCache, TokenText,
+};
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::{debug, error, info, trace};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -55,6 +79,12 @@ pub struct JsFormatterSettings {
     pub arrow_parentheses: Option<ArrowParentheses>,
     pub bracket_spacing: Option<BracketSpacing>,
     pub bracket_same_line: Option<BracketSameLine>,
+    pub single_line_if_statements: Option<SingleLineIfStatements>,
+    pub blank_line_after_imports: Option<BlankLineAfterImports>,
+    pub blank_line_before_return: Option<BlankLineBeforeReturn>,
+    pub union_leading_separator: Option<UnionLeadingSeparator>,
+    pub union_break_threshold: Option<UnionBreakThreshold>,
+    pub jsx_whitespace_fidelity: Option<JsxWhitespaceFidelity>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
     pub indent_width: Option<IndentWidth>,
@@ -117,9 +147,15 @@ impl Language for JsLanguage {
         } else {
             global.indent_width.unwrap_or_default()
         };
+        let line_ending = if let Some(line_ending) = language.line_ending {
+            line_ending
+        } else {
+            global.line_ending.unwrap_or_default()
+        };
         let options = JsFormatOptions::new(path.as_path().try_into().unwrap_or_default())
             .with_indent_style(indent_style)
             .with_indent_width(indent_width)
+            .with_line_ending(line_ending)
             .with_line_width(line_width)
             .with_quote_style(language.quote_style.unwrap_or_default())
             .with_jsx_quote_style(language.jsx_quote_style.unwrap_or_default())
@@ -128,7 +164,13 @@ impl Language for JsLanguage {
             .with_semicolons(language.semicolons.unwrap_or_default())
             .with_arrow_parentheses(language.arrow_parentheses.unwrap_or_default())
             .with_bracket_spacing(language.bracket_spacing.unwrap_or_default())
-            .with_bracket_same_line(language.bracket_same_line.unwrap_or_default());
+            .with_bracket_same_line(language.bracket_same_line.unwrap_or_default())
+            .with_single_line_if_statements(language.single_line_if_statements.unwrap_or_default())
+            .with_blank_line_after_imports(language.blank_line_after_imports.unwrap_or_default())
+            .with_blank_line_before_return(language.blank_line_before_return.unwrap_or_default())
+            .with_union_leading_separator(language.union_leading_separator.unwrap_or_default())
+            .with_union_break_threshold(language.union_break_threshold.unwrap_or_default())
+            .with_jsx_whitespace_fidelity(language.jsx_whitespace_fidelity.unwrap_or_default());
 
         overrides.override_js_format_options(path, options)
     }
@@ -163,7 +205,13 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                prepare_rename: Some(prepare_rename),
+                inlay_hints: Some(inlay_hints),
                 organize_imports: Some(organize_imports),
+                search: Some(search),
+                module_info: Some(module_info),
+                document_symbols: Some(document_symbols),
+                export_conversions: Some(export_conversions),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -200,14 +248,7 @@ fn parse(
             LanguageId::TypeScriptReact => JsFileSource::tsx(),
             _ => JsFileSource::js_module(),
         });
-    let parser_settings = &settings.as_ref().languages.javascript.parser;
-    let overrides = &settings.as_ref().override_settings;
-    let options = overrides.override_js_parser_options(
-        rome_path,
-        JsParserOptions {
-            parse_class_parameter_decorators: parser_settings.parse_class_parameter_decorators,
-        },
-    );
+    let options = parser_options(rome_path, &settings);
     let parse = biome_js_parser::parse_js_with_cache(text, source_type, options, cache);
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
@@ -293,11 +334,16 @@ fn lint(params: LintParams) -> LintResults {
             skipped_diagnostics: 0,
         };
     };
-    let tree = params.parse.tree();
+    let tree: AnyJsRoot = params.parse.tree();
     let mut diagnostics = params.parse.into_diagnostics();
 
-    let analyzer_options =
+    let mut analyzer_options =
         compute_analyzer_options(&params.settings, PathBuf::from(params.path.as_path()));
+    analyzer_options.configuration.declared_packages = params.packages.declared;
+    analyzer_options.configuration.project_packages = params.packages.project;
+    analyzer_options.configuration.node_engine = params.packages.node_engine;
+    analyzer_options.configuration.browserslist = params.packages.browserslist;
+    analyzer_options.cancellation = params.cancellation;
 
     let mut diagnostic_count = diagnostics.len() as u64;
     let mut errors = diagnostics
@@ -306,14 +352,14 @@ fn lint(params: LintParams) -> LintResults {
         .count();
 
     let has_lint = params.filter.categories.contains(RuleCategories::LINT);
+    let disabled_rules =
+        with_rules_disabled_by_directives(&tree, params.rules, params.filter.disabled_rules);
+    let mut filter = params.filter;
+    filter.disabled_rules = Some(disabled_rules.as_slice());
 
     info!("Analyze file {}", params.path.display());
-    let (_, analyze_diagnostics) = analyze(
-        &tree,
-        params.filter,
-        &analyzer_options,
-        file_source,
-        |signal| {
+    let (_, analyze_diagnostics) =
+        analyze(&tree, filter, &analyzer_options, file_source, |signal| {
             if let Some(mut diagnostic) = signal.diagnostic() {
                 // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
                 if !has_lint && diagnostic.category() == Some(category!("suppressions/unused")) {
@@ -353,8 +399,8 @@ fn lint(params: LintParams) -> LintResults {
             }
 
             ControlFlow::<Never>::Continue(())
-        },
-    );
+        });
+    metrics::record_query_cache_statistics(&analyzer_options.query_cache_statistics);
 
     diagnostics.extend(
         analyze_diagnostics
@@ -407,37 +453,33 @@ fn code_actions(
     settings: SettingsHandle,
     path: &RomePath,
 ) -> PullActionsResult {
-    let tree = parse.tree();
+    let tree: AnyJsRoot = parse.tree();
 
     let mut actions = Vec::new();
 
     let mut enabled_rules = vec![];
-    if settings.as_ref().organize_imports.enabled {
-        enabled_rules.push(RuleFilter::Rule("correctness", "organizeImports"));
-    }
     if let Some(rules) = rules {
-        let rules = rules.as_enabled_rules().into_iter().collect();
-
-        // The rules in the assist category do not have configuration entries,
-        // always add them all to the enabled rules list
-        let mut visitor = ActionsVisitor {
-            enabled_rules: rules,
-        };
-        visit_registry(&mut visitor);
-
-        enabled_rules.extend(visitor.enabled_rules);
+        enabled_rules.extend(rules.as_enabled_rules());
     }
 
-    let mut filter = if !enabled_rules.is_empty() {
-        AnalysisFilter::from_enabled_rules(Some(enabled_rules.as_slice()))
+    // The rules in the assist category do not have configuration entries,
+    // always add them all to the enabled rules list
+    let mut visitor = ActionsVisitor { enabled_rules };
+    visit_registry(&mut visitor);
+    let enabled_rules = visitor.enabled_rules;
+
+    // The organize imports action has its own setting
+    let disabled_rules = if settings.as_ref().organize_imports_enabled(path.as_path()) {
+        vec![]
     } else {
-        AnalysisFilter::default()
+        vec![RuleFilter::Rule("correctness", "organizeImports")]
     };
+    let disabled_rules =
+        with_rules_disabled_by_directives(&tree, rules, Some(disabled_rules.as_slice()));
 
-    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
-    if settings.as_ref().organize_imports.enabled {
-        filter.categories |= RuleCategories::ACTION;
-    }
+    let mut filter = AnalysisFilter::from_enabled_rules(Some(enabled_rules.as_slice()));
+    filter.disabled_rules = Some(disabled_rules.as_slice());
+    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT | RuleCategories::ACTION;
     filter.range = Some(range);
 
     trace!("Filter applied for code actions: {:?}", &filter);
@@ -459,10 +501,39 @@ fn code_actions(
 
         ControlFlow::<Never>::Continue(())
     });
+    metrics::record_query_cache_statistics(&analyzer_options.query_cache_statistics);
 
     PullActionsResult { actions }
 }
 
+/// Returns `disabled_rules`, followed by the enabled rules whose `directives` option excludes
+/// the file, see [crate::RuleConfiguration::applies_to_directives]
+fn with_rules_disabled_by_directives<'a>(
+    tree: &AnyJsRoot,
+    rules: Option<&'a Rules>,
+    disabled_rules: Option<&[RuleFilter<'a>]>,
+) -> Vec<RuleFilter<'a>> {
+    let mut result = disabled_rules.map(<[_]>::to_vec).unwrap_or_default();
+    let Some(rules) = rules else {
+        return result;
+    };
+    let directives = tree.directives();
+    let has_directive = |directive: &str| {
+        directives
+            .as_ref()
+            .is_some_and(|directives| directives.contains(directive))
+    };
+    result.extend(rules.as_enabled_rules().into_iter().filter(|filter| {
+        match filter {
+            RuleFilter::Rule(group, rule_name) => rules
+                .get_rule_configuration(group, rule_name)
+                .is_some_and(|configuration| !configuration.applies_to_directives(&has_directive)),
+            RuleFilter::Group(_) => false,
+        }
+    }));
+    result
+}
+
 /// If applies all the safe fixes to the given syntax tree.
 ///
 /// If `indent_style` is [Some], it means that the formatting should be applied at the end
@@ -471,10 +542,12 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
         parse,
         rules,
         fix_file_mode,
+        unsafe_rules,
         settings,
         should_format,
         rome_path,
         mut filter,
+        cancellation,
     } = params;
 
     let file_source = parse
@@ -484,10 +557,14 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let mut actions = Vec::new();
 
     filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
+    let disabled_rules = with_rules_disabled_by_directives(&tree, rules, filter.disabled_rules);
+    filter.disabled_rules = Some(disabled_rules.as_slice());
 
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(rome_path.as_path()));
+    let mut analyzer_options =
+        compute_analyzer_options(&settings, PathBuf::from(rome_path.as_path()));
+    analyzer_options.cancellation = cancellation;
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
             let current_diagnostic = signal.diagnostic();
@@ -504,30 +581,22 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                     continue;
                 }
 
-                match fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
-                        }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
-                        }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
-                        }
+                let applies = match action.applicability {
+                    Applicability::Always => true,
+                    Applicability::MaybeIncorrect => {
+                        applies_unsafe_fixes(fix_file_mode, rules, unsafe_rules, action.rule_name)
                     }
+                };
+                if applies {
+                    errors = errors.saturating_sub(1);
+                    return ControlFlow::Break(action);
                 }
+                skipped_suggested_fixes += 1;
             }
 
             ControlFlow::Continue(())
         });
+        metrics::record_query_cache_statistics(&analyzer_options.query_cache_statistics);
 
         match action {
             Some(action) => {
@@ -553,6 +622,10 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                 }
             }
             None => {
+                // A cancelled analysis doesn't return the remaining fixes
+                if analyzer_options.cancellation.is_cancelled() {
+                    return Err(WorkspaceError::cancelled());
+                }
                 let code = if should_format {
                     format_node(
                         settings.format_options::<JsLanguage>(rome_path),
@@ -580,21 +653,54 @@ fn format(
     parse: AnyParse,
     settings: SettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
+    let parser_options = parser_options(rome_path, &settings);
     let options = settings.format_options::<JsLanguage>(rome_path);
 
     debug!("Options used for format: \n{}", options);
 
+    let jsx_whitespace_fidelity = options.jsx_whitespace_fidelity().value();
+    let source_type = options.source_type();
     let tree = parse.syntax();
     info!("Format file {}", rome_path.display());
     let formatted = format_node(options, &tree)?;
-    match formatted.print() {
-        Ok(printed) => Ok(printed),
+    let printed = match formatted.print() {
+        Ok(printed) => printed,
         Err(error) => {
             error!("The file {} couldn't be formatted", rome_path.display());
-            Err(WorkspaceError::FormatError(error.into()))
+            return Err(WorkspaceError::FormatError(error.into()));
+        }
+    };
+
+    if jsx_whitespace_fidelity {
+        // Verify that the formatted code renders the same JSX text as the original code
+        let rendered = jsx_rendered_children(&tree);
+        if !rendered.is_empty() {
+            let formatted_parse =
+                biome_js_parser::parse(printed.as_code(), source_type, parser_options);
+            if jsx_rendered_children(&formatted_parse.syntax()) != rendered {
+                error!(
+                    "The formatting of the file {} changes the rendered JSX text",
+                    rome_path.display()
+                );
+                return Err(WorkspaceError::jsx_text_changed());
+            }
         }
     }
+
+    Ok(printed)
 }
+
+fn parser_options(rome_path: &RomePath, settings: &SettingsHandle) -> JsParserOptions {
+    let parser_settings = &settings.as_ref().languages.javascript.parser;
+    let overrides = &settings.as_ref().override_settings;
+    overrides.override_js_parser_options(
+        rome_path,
+        JsParserOptions {
+            parse_class_parameter_decorators: parser_settings.parse_class_parameter_decorators,
+        },
+    )
+}
+
 #[tracing::instrument(level = "trace", skip(parse))]
 fn format_range(
     rome_path: &RomePath,
@@ -628,21 +734,44 @@ fn format_on_type(
         }));
     }
 
-    let token = match tree.token_at_offset(offset) {
-        // File is empty, do nothing
-        TokenAtOffset::None => panic!("empty file"),
-        TokenAtOffset::Single(token) => token,
-        // The cursor should be right after the closing character that was just typed,
-        // select the previous token as the correct one
-        TokenAtOffset::Between(token, _) => token,
+    // The cursor is right after the character that was just typed: `}`, `;` or a newline.
+    // After a newline, the cursor is in the trivia of the next token, so the token that
+    // was completed is the previous one.
+    let token = tree
+        .token_at_offset(offset)
+        .left_biased()
+        .and_then(|token| {
+            if offset <= token.text_trimmed_range().start() {
+                token.prev_token()
+            } else {
+                Some(token)
+            }
+        });
+    let Some(token) = token else {
+        // There's nothing before the cursor, e.g. in an empty file
+        return Ok(Printed::new(
+            String::new(),
+            Some(TextRange::empty(offset)),
+            vec![],
+            vec![],
+        ));
     };
 
-    let root_node = match token.parent() {
-        Some(node) => node,
-        None => panic!("found a token with no parent"),
-    };
+    // Format the statement, or the member, that contains the token, e.g. the whole `if`
+    // statement when its block is closed
+    let format_range = token
+        .ancestors()
+        .find(|node| {
+            AnyJsStatement::can_cast(node.kind())
+                || AnyJsClassMember::can_cast(node.kind())
+                || AnyTsTypeMember::can_cast(node.kind())
+        })
+        .map_or_else(
+            || token.text_trimmed_range(),
+            |node| node.text_trimmed_range(),
+        );
 
-    let printed = biome_js_formatter::format_sub_tree(options, &root_node)?;
+    let printed = biome_js_formatter::format_range(options, &tree, format_range)?;
     Ok(printed)
 }
 
@@ -687,7 +816,143 @@ fn rename(
     }
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+/// Returns the range of the binding or the reference at `symbol_at`. The references that
+/// can't be resolved to a binding of the file, e.g. the globals, can't be renamed.
+fn prepare_rename(
+    parse: AnyParse,
+    symbol_at: TextSize,
+) -> Result<PrepareRenameResult, WorkspaceError> {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    let range = parse
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .find(|token| token.text_range().contains(symbol_at))
+        .and_then(|token| token.parent())
+        .and_then(|node| {
+            let range = node.text_trimmed_range();
+            let declaration = AnyJsRenamableDeclaration::try_from(node).ok()?;
+            declaration.binding(&model).map(|_| range)
+        });
+
+    Ok(PrepareRenameResult { range })
+}
+
+/// Returns the names of the parameters of the literal arguments of the calls in `range`,
+/// e.g. `retries:` before `3` in `connect(url, 3)`. Only the functions declared in the file
+/// are resolved.
+fn inlay_hints(parse: AnyParse, range: TextRange) -> Vec<InlayHint> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    let mut hints = vec![];
+    for call in root
+        .syntax()
+        .descendants()
+        .filter(|node| node.text_trimmed_range().intersect(range).is_some())
+        .filter_map(JsCallExpression::cast)
+    {
+        let Some(parameters) = called_function_parameters(&call, &model) else {
+            continue;
+        };
+        let Ok(arguments) = call.arguments() else {
+            continue;
+        };
+
+        for (argument, parameter) in arguments.args().iter().zip(parameters) {
+            let Ok(argument) = argument else {
+                break;
+            };
+            let argument = match argument {
+                AnyJsCallArgument::AnyJsExpression(argument) => argument,
+                // The parameters of the arguments after a spread are unknown
+                AnyJsCallArgument::JsSpread(_) => break,
+            };
+            let Some(parameter_name) = parameter else {
+                continue;
+            };
+            if matches!(argument, AnyJsExpression::AnyJsLiteralExpression(_))
+                && range.contains_range(argument.range())
+            {
+                hints.push(InlayHint {
+                    offset: argument.range().start(),
+                    parameter_name,
+                });
+            }
+        }
+    }
+
+    hints
+}
+
+/// Returns the names of the parameters of the function called by `call`, in order, if the
+/// function is declared in the file. The parameters without a name, e.g. the destructured
+/// parameters, are `None`. The rest parameter and the parameters after it are skipped.
+fn called_function_parameters(
+    call: &JsCallExpression,
+    model: &SemanticModel,
+) -> Option<Vec<Option<String>>> {
+    let callee = call.callee().ok()?;
+    let reference = callee.as_js_identifier_expression()?.name().ok()?;
+    let declaration = model.binding(&reference)?.tree().declaration()?;
+
+    let parameters = match declaration {
+        AnyJsBindingDeclaration::JsFunctionDeclaration(function) => function.parameters().ok()?,
+        AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(function) => {
+            function.parameters().ok()?
+        }
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            match declarator.initializer()?.expression().ok()? {
+                AnyJsExpression::JsFunctionExpression(function) => function.parameters().ok()?,
+                AnyJsExpression::JsArrowFunctionExpression(function) => {
+                    match function.parameters().ok()? {
+                        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+                            return Some(vec![identifier_name(&binding)]);
+                        }
+                        AnyJsArrowFunctionParameters::JsParameters(parameters) => parameters,
+                    }
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    Some(
+        parameters
+            .items()
+            .iter()
+            .filter_map(Result::ok)
+            .filter(|parameter| !matches!(parameter, AnyJsParameter::TsThisParameter(_)))
+            .map_while(|parameter| match parameter {
+                AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+                    parameter,
+                )) => Some(
+                    parameter
+                        .binding()
+                        .ok()
+                        .and_then(|binding| identifier_name(binding.as_any_js_binding()?)),
+                ),
+                AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsBogusParameter(_)) => {
+                    Some(None)
+                }
+                AnyJsParameter::JsRestParameter(_) | AnyJsParameter::TsThisParameter(_) => None,
+            })
+            .collect(),
+    )
+}
+
+fn identifier_name(binding: &AnyJsBinding) -> Option<String> {
+    let name = binding.as_js_identifier_binding()?.name_token().ok()?;
+    Some(name.text_trimmed().to_string())
+}
+
+fn organize_imports(
+    parse: AnyParse,
+    settings: SettingsHandle,
+    path: &RomePath,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
     let filter = AnalysisFilter {
@@ -696,10 +961,11 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
         ..AnalysisFilter::default()
     };
 
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(path.as_path()));
     let (action, _) = analyze(
         &tree,
         filter,
-        &AnalyzerOptions::default(),
+        &analyzer_options,
         JsFileSource::default(),
         |signal| {
             for action in signal.actions() {
@@ -748,10 +1014,833 @@ fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> An
         } else {
             vec![]
         },
+        path_aliases: settings.as_ref().path_aliases.clone(),
+        declared_packages: None,
+        project_packages: vec![],
+        node_engine: None,
+        browserslist: vec![],
     };
 
     AnalyzerOptions {
         configuration,
         file_path,
+        cancellation: CancellationToken::default(),
+        query_cache_statistics: QueryCacheStatistics::default(),
+    }
+}
+
+fn search(parse: AnyParse, pattern: &str) -> Result<Vec<TextRange>, WorkspaceError> {
+    // JSX and TypeScript are enabled, so the same pattern can be used with any kind of file
+    let pattern_parse =
+        biome_js_parser::parse(pattern, JsFileSource::tsx(), JsParserOptions::default());
+    if pattern_parse.has_errors() {
+        return Err(WorkspaceError::invalid_pattern(
+            "it isn't valid JavaScript or TypeScript code",
+        ));
+    }
+
+    let pattern =
+        StructuralPattern::<JsLanguage>::new(pattern_parse.syntax()).ok_or_else(|| {
+            WorkspaceError::invalid_pattern("it must contain some code other than a metavariable")
+        })?;
+
+    Ok(pattern.find_matches(&parse.syntax()))
+}
+
+fn module_info(parse: AnyParse) -> ModuleInfo {
+    let tree: AnyJsRoot = parse.tree();
+    let mut info = ModuleInfo::default();
+    if let Some(directives) = tree.directives() {
+        info.directives = directives
+            .iter()
+            .filter_map(|directive| directive.inner_string_text().ok())
+            .map(|text| text.to_string())
+            .collect();
+    }
+    // Built for the imports of the CSS modules only
+    let mut model = None;
+
+    if let Some(module) = tree.as_js_module() {
+        for item in module.items() {
+            match item {
+                AnyJsModuleItem::JsImport(import) => {
+                    if let Ok(clause) = import.import_clause() {
+                        let class_accesses = css_module_binding(&clause).map(|binding| {
+                            let model = model.get_or_insert_with(|| {
+                                semantic_model(&tree, SemanticModelOptions::default())
+                            });
+                            class_accesses(model, &binding)
+                        });
+                        collect_import(&clause, class_accesses, &mut info);
+                    }
+                }
+                AnyJsModuleItem::JsExport(export) => {
+                    if let Ok(clause) = export.export_clause() {
+                        collect_export(&clause, &mut info);
+                    }
+                }
+                AnyJsModuleItem::AnyJsStatement(_) => {}
+            }
+        }
+    }
+
+    // `import("./mod")` can appear anywhere, also in scripts
+    for call in tree
+        .syntax()
+        .descendants()
+        .filter_map(JsImportCallExpression::cast)
+    {
+        let argument = call
+            .arguments()
+            .ok()
+            .and_then(|arguments| arguments.args().iter().next()?.ok());
+        if let Some(AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(specifier),
+        ))) = argument
+        {
+            if let Ok(text) = specifier.inner_string_text() {
+                info.imports.push(ModuleImport {
+                    specifier: text.to_string(),
+                    names: ImportedNames::All,
+                    is_static: false,
+                    is_reexport_all: false,
+                    range: specifier.range(),
+                    bindings: None,
+                    class_accesses: None,
+                });
+            }
+        }
+    }
+
+    info.suppressions = project_suppressions(tree.syntax());
+    info
+}
+
+fn collect_import(
+    clause: &AnyJsImportClause,
+    class_accesses: Option<ClassAccesses>,
+    info: &mut ModuleInfo,
+) {
+    let Ok(source) = clause.source() else {
+        return;
+    };
+    let Ok(specifier) = source.inner_string_text() else {
+        return;
+    };
+
+    let (names, type_token) = match clause {
+        AnyJsImportClause::JsImportBareClause(_) => (ImportedNames::Names(vec![]), None),
+        AnyJsImportClause::JsImportDefaultClause(clause) => (
+            ImportedNames::Names(vec!["default".to_string()]),
+            clause.type_token(),
+        ),
+        AnyJsImportClause::JsImportNamespaceClause(clause) => {
+            (ImportedNames::All, clause.type_token())
+        }
+        AnyJsImportClause::JsImportNamedClause(clause) => {
+            let names = match clause.named_import() {
+                Ok(AnyJsNamedImport::JsNamedImportSpecifiers(specifiers)) => {
+                    let mut names: Vec<_> = specifiers
+                        .specifiers()
+                        .iter()
+                        .filter_map(|specifier| {
+                            let name = specifier.ok()?.local_name()?;
+                            Some(inner_string_text(&name).to_string())
+                        })
+                        .collect();
+                    if clause.default_specifier().is_some() {
+                        names.push("default".to_string());
+                    }
+                    ImportedNames::Names(names)
+                }
+                Ok(AnyJsNamedImport::JsNamespaceImportSpecifier(_)) | Err(_) => ImportedNames::All,
+            };
+            (names, clause.type_token())
+        }
+    };
+
+    info.imports.push(ModuleImport {
+        specifier: specifier.to_string(),
+        names,
+        is_static: type_token.is_none(),
+        is_reexport_all: false,
+        range: source.range(),
+        bindings: import_bindings(clause),
+        class_accesses,
+    });
+}
+
+/// Returns the binding of a default or namespace import of a CSS module, e.g. `styles` in
+/// `import styles from "./button.module.css"`
+fn css_module_binding(clause: &AnyJsImportClause) -> Option<JsIdentifierBinding> {
+    let specifier = clause.source().ok()?.inner_string_text().ok()?;
+    if !is_css_module(Path::new(specifier.text())) {
+        return None;
+    }
+    let binding = match clause {
+        AnyJsImportClause::JsImportDefaultClause(clause) => clause.local_name().ok()?,
+        AnyJsImportClause::JsImportNamespaceClause(clause) => clause.local_name().ok()?,
+        AnyJsImportClause::JsImportBareClause(_) | AnyJsImportClause::JsImportNamedClause(_) => {
+            return None
+        }
+    };
+    binding.as_js_identifier_binding().cloned()
+}
+
+/// Returns the classes read on the binding of a CSS module, e.g. `button` for
+/// `styles.button` or `styles["button"]`
+fn class_accesses(model: &SemanticModel, binding: &JsIdentifierBinding) -> ClassAccesses {
+    let mut names = vec![];
+    for reference in model.as_binding(binding).all_references() {
+        let name = reference
+            .syntax()
+            .parent()
+            .filter(|parent| parent.kind() == JsSyntaxKind::JS_IDENTIFIER_EXPRESSION)
+            .and_then(|expression| {
+                let member = AnyJsMemberExpression::cast(expression.parent()?)?;
+                if member.object().ok()?.syntax() != &expression {
+                    return None;
+                }
+                member.member_name()
+            });
+        match name {
+            Some(name) => names.push((name.text().to_string(), name.range())),
+            // e.g. `styles[name]` or `classNames(styles)`
+            None => return ClassAccesses::All,
+        }
+    }
+    ClassAccesses::Names(names)
+}
+
+/// Returns the specifiers of an import, used to rewrite them
+fn import_bindings(clause: &AnyJsImportClause) -> Option<ImportBindings> {
+    match clause {
+        AnyJsImportClause::JsImportDefaultClause(clause) => {
+            let local_name = clause.local_name().ok()?;
+            Some(ImportBindings {
+                default: Some(ImportSpecifier {
+                    imported_name: "default".to_string(),
+                    local_name: local_name.text(),
+                    range: local_name.range(),
+                    removal_range: local_name.range(),
+                }),
+                ..ImportBindings::default()
+            })
+        }
+        AnyJsImportClause::JsImportNamedClause(clause) => {
+            let AnyJsNamedImport::JsNamedImportSpecifiers(specifiers) =
+                clause.named_import().ok()?
+            else {
+                return None;
+            };
+            let braces_range = specifiers.range();
+            let default = match clause.default_specifier() {
+                Some(specifier) => {
+                    let local_name = specifier.local_name().ok()?;
+                    Some(ImportSpecifier {
+                        imported_name: "default".to_string(),
+                        local_name: local_name.text(),
+                        range: local_name.range(),
+                        // `a, ` in `a, { b }`
+                        removal_range: TextRange::new(
+                            local_name.range().start(),
+                            braces_range.start(),
+                        ),
+                    })
+                }
+                None => None,
+            };
+
+            let mut named = vec![];
+            for specifier in specifiers.specifiers().iter() {
+                let specifier = specifier.ok()?;
+                let (imported_name, local_name) = match &specifier {
+                    AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => (
+                        inner_string_text(&specifier.name().ok()?.value().ok()?).to_string(),
+                        specifier.local_name().ok()?.text(),
+                    ),
+                    AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                        let local_name = specifier.local_name().ok()?.text();
+                        (local_name.clone(), local_name)
+                    }
+                    AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => return None,
+                };
+                named.push((imported_name, local_name, specifier.range()));
+            }
+
+            Some(ImportBindings {
+                default,
+                named: specifier_list(named),
+                braces_range: Some(braces_range),
+                is_reexport: false,
+            })
+        }
+        AnyJsImportClause::JsImportBareClause(_)
+        | AnyJsImportClause::JsImportNamespaceClause(_) => None,
+    }
+}
+
+/// Returns the specifiers of a list from their imported name, their local name and their
+/// range, with the ranges that remove them from the list
+fn specifier_list(specifiers: Vec<(String, String, TextRange)>) -> Vec<ImportSpecifier> {
+    let ranges: Vec<_> = specifiers.iter().map(|(_, _, range)| *range).collect();
+    specifiers
+        .into_iter()
+        .enumerate()
+        .map(|(index, (imported_name, local_name, range))| {
+            let previous = index.checked_sub(1).map(|index| ranges[index]);
+            let removal_range = match (previous, ranges.get(index + 1)) {
+                // `a, ` in `{ a, b }`
+                (_, Some(next)) => TextRange::new(range.start(), next.start()),
+                // `, b` in `{ a, b }`
+                (Some(previous), None) => TextRange::new(previous.end(), range.end()),
+                (None, None) => range,
+            };
+            ImportSpecifier {
+                imported_name,
+                local_name,
+                range,
+                removal_range,
+            }
+        })
+        .collect()
+}
+
+fn collect_export(clause: &AnyJsExportClause, info: &mut ModuleInfo) {
+    match clause {
+        AnyJsExportClause::AnyJsDeclarationClause(declaration) => {
+            collect_exported_declaration(declaration, info);
+        }
+        AnyJsExportClause::TsExportDeclareClause(clause) => {
+            if let Ok(declaration) = clause.declaration() {
+                collect_exported_declaration(&declaration, info);
+            }
+        }
+        AnyJsExportClause::JsExportDefaultDeclarationClause(_)
+        | AnyJsExportClause::JsExportDefaultExpressionClause(_)
+        | AnyJsExportClause::TsExportAssignmentClause(_) => {
+            info.exports.push(ModuleExport {
+                name: "default".to_string(),
+                range: clause.range(),
+            });
+        }
+        AnyJsExportClause::JsExportNamedClause(clause) => {
+            for specifier in clause.specifiers().iter().flatten() {
+                let name = match &specifier {
+                    AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                        specifier.name().and_then(|name| name.value_token())
+                    }
+                    AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => specifier
+                        .exported_name()
+                        .and_then(|exported_name| exported_name.value()),
+                };
+                if let Ok(name) = name {
+                    info.exports.push(ModuleExport {
+                        name: inner_string_text(&name).to_string(),
+                        range: name.text_trimmed_range(),
+                    });
+                }
+            }
+        }
+        AnyJsExportClause::JsExportNamedFromClause(clause) => {
+            let Ok(source) = clause.source() else {
+                return;
+            };
+            let Ok(specifier) = source.inner_string_text() else {
+                return;
+            };
+
+            let mut names = vec![];
+            let mut specifiers = vec![];
+            for from_specifier in clause.specifiers().iter().flatten() {
+                let Ok(source_name) = from_specifier
+                    .source_name()
+                    .and_then(|source_name| source_name.value())
+                else {
+                    continue;
+                };
+                let exported_name = from_specifier
+                    .export_as()
+                    .and_then(|export_as| export_as.exported_name().ok()?.value().ok())
+                    .unwrap_or_else(|| source_name.clone());
+
+                names.push(inner_string_text(&source_name).to_string());
+                info.exports.push(ModuleExport {
+                    name: inner_string_text(&exported_name).to_string(),
+                    range: exported_name.text_trimmed_range(),
+                });
+                specifiers.push((
+                    inner_string_text(&source_name).to_string(),
+                    inner_string_text(&exported_name).to_string(),
+                    from_specifier.range(),
+                ));
+            }
+
+            // The specifiers that can't be parsed can't be rewritten
+            let braces_range = clause
+                .l_curly_token()
+                .ok()
+                .zip(clause.r_curly_token().ok())
+                .map(|(l_curly, r_curly)| {
+                    TextRange::new(
+                        l_curly.text_trimmed_range().start(),
+                        r_curly.text_trimmed_range().end(),
+                    )
+                });
+            let bindings =
+                (specifiers.len() == clause.specifiers().len()).then(|| ImportBindings {
+                    default: None,
+                    named: specifier_list(specifiers),
+                    braces_range,
+                    is_reexport: true,
+                });
+            info.imports.push(ModuleImport {
+                specifier: specifier.to_string(),
+                names: ImportedNames::Names(names),
+                is_static: clause.type_token().is_none(),
+                is_reexport_all: false,
+                range: source.range(),
+                bindings,
+                class_accesses: None,
+            });
+        }
+        AnyJsExportClause::JsExportFromClause(clause) => {
+            let Ok(source) = clause.source() else {
+                return;
+            };
+            let Ok(specifier) = source.inner_string_text() else {
+                return;
+            };
+
+            // `export * as ns from "./mod"` exports a single name
+            if let Some(name) = clause
+                .export_as()
+                .and_then(|export_as| export_as.exported_name().ok()?.value().ok())
+            {
+                info.exports.push(ModuleExport {
+                    name: inner_string_text(&name).to_string(),
+                    range: name.text_trimmed_range(),
+                });
+            }
+
+            info.imports.push(ModuleImport {
+                specifier: specifier.to_string(),
+                names: ImportedNames::All,
+                is_static: clause.type_token().is_none(),
+                is_reexport_all: true,
+                range: source.range(),
+                bindings: None,
+                class_accesses: None,
+            });
+        }
+        AnyJsExportClause::TsExportAsNamespaceClause(_) => {}
     }
 }
+
+/// Collects the bindings declared by an exported declaration, e.g. `a` and `b` for
+/// `export const { a, b } = value`
+fn collect_exported_declaration(declaration: &AnyJsDeclarationClause, info: &mut ModuleInfo) {
+    let bindings = declaration
+        .syntax()
+        .descendants()
+        .filter_map(AnyJsIdentifierBinding::cast)
+        .filter(|binding| {
+            // Skips the bindings of the nested scopes, e.g. the parameters of a function
+            match binding.declaration() {
+                Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) => {
+                    declarator.syntax().ancestors().nth(3).as_ref() == Some(declaration.syntax())
+                }
+                Some(binding_declaration) => binding_declaration.syntax() == declaration.syntax(),
+                None => false,
+            }
+        });
+
+    for binding in bindings {
+        if let Ok(name) = binding.name_token() {
+            info.exports.push(ModuleExport {
+                name: name.text_trimmed().to_string(),
+                range: name.text_trimmed_range(),
+            });
+        }
+    }
+}
+
+/// Returns the exports in `range` that can be converted from the default export to a named
+/// export, or the opposite, with the edits of the module
+fn export_conversions(parse: AnyParse, range: TextRange) -> Vec<ExportConversion> {
+    let tree: AnyJsRoot = parse.tree();
+    let Some(module) = tree.as_js_module() else {
+        return vec![];
+    };
+
+    let mut conversions = vec![];
+    for item in module.items() {
+        let AnyJsModuleItem::JsExport(export) = item else {
+            continue;
+        };
+        if export.range().intersect(range).is_none() {
+            continue;
+        }
+        let Ok(clause) = export.export_clause() else {
+            continue;
+        };
+
+        match clause {
+            // `export function a() {}` becomes `export default function a() {}`
+            AnyJsExportClause::AnyJsDeclarationClause(declaration) => {
+                let id = match &declaration {
+                    AnyJsDeclarationClause::JsClassDeclaration(class) => class.id().ok(),
+                    AnyJsDeclarationClause::JsFunctionDeclaration(function) => function.id().ok(),
+                    _ => None,
+                };
+                if let Some(name) = id.as_ref().and_then(binding_name) {
+                    conversions.push(ExportConversion {
+                        change: ExportChange::NamedToDefault(name),
+                        edits: vec![TextReplacement::new(
+                            TextRange::empty(declaration.range().start()),
+                            "default ",
+                        )],
+                    });
+                }
+            }
+            // `export default function a() {}` becomes `export function a() {}`
+            AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+                let Ok(declaration) = clause.declaration() else {
+                    continue;
+                };
+                let id = match &declaration {
+                    AnyJsExportDefaultDeclaration::JsClassExportDefaultDeclaration(class) => {
+                        class.id()
+                    }
+                    AnyJsExportDefaultDeclaration::JsFunctionExportDefaultDeclaration(function) => {
+                        function.id()
+                    }
+                    _ => None,
+                };
+                let (Some(name), Ok(default_token)) =
+                    (id.as_ref().and_then(binding_name), clause.default_token())
+                else {
+                    continue;
+                };
+                conversions.push(ExportConversion {
+                    change: ExportChange::DefaultToNamed(name),
+                    edits: vec![TextReplacement::new(
+                        TextRange::new(
+                            default_token.text_trimmed_range().start(),
+                            declaration.range().start(),
+                        ),
+                        "",
+                    )],
+                });
+            }
+            // `export default a;` becomes `export { a };`
+            AnyJsExportClause::JsExportDefaultExpressionClause(clause) => {
+                let (Ok(AnyJsExpression::JsIdentifierExpression(expression)), Ok(default_token)) =
+                    (clause.expression(), clause.default_token())
+                else {
+                    continue;
+                };
+                let Ok(name) = expression.name().and_then(|name| name.value_token()) else {
+                    continue;
+                };
+                let name = name.text_trimmed().to_string();
+                conversions.push(ExportConversion {
+                    edits: vec![TextReplacement::new(
+                        TextRange::new(
+                            default_token.text_trimmed_range().start(),
+                            expression.range().end(),
+                        ),
+                        format!("{{ {name} }}"),
+                    )],
+                    change: ExportChange::DefaultToNamed(name),
+                });
+            }
+            // `export { a }` becomes `export { a as default }`, and the opposite
+            AnyJsExportClause::JsExportNamedClause(clause) => {
+                if clause.type_token().is_some() {
+                    continue;
+                }
+                for specifier in clause.specifiers().iter().flatten() {
+                    if specifier.range().intersect(range).is_none() {
+                        continue;
+                    }
+                    let conversion = match &specifier {
+                        AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                            if specifier.type_token().is_some() {
+                                continue;
+                            }
+                            let Ok(name) = specifier.name().and_then(|name| name.value_token())
+                            else {
+                                continue;
+                            };
+                            let name = name.text_trimmed().to_string();
+                            ExportConversion {
+                                edits: vec![TextReplacement::new(
+                                    specifier.range(),
+                                    format!("{name} as default"),
+                                )],
+                                change: ExportChange::NamedToDefault(name),
+                            }
+                        }
+                        AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                            if specifier.type_token().is_some() {
+                                continue;
+                            }
+                            let (Ok(local_name), Ok(exported_name)) = (
+                                specifier
+                                    .local_name()
+                                    .and_then(|local_name| local_name.value_token()),
+                                specifier
+                                    .exported_name()
+                                    .and_then(|exported_name| exported_name.value()),
+                            ) else {
+                                continue;
+                            };
+                            let local_name = local_name.text_trimmed().to_string();
+                            let exported_name = inner_string_text(&exported_name).to_string();
+                            if exported_name == "default" {
+                                ExportConversion {
+                                    edits: vec![TextReplacement::new(
+                                        specifier.range(),
+                                        local_name.clone(),
+                                    )],
+                                    change: ExportChange::DefaultToNamed(local_name),
+                                }
+                            } else {
+                                ExportConversion {
+                                    edits: vec![TextReplacement::new(
+                                        specifier.range(),
+                                        format!("{local_name} as default"),
+                                    )],
+                                    change: ExportChange::NamedToDefault(exported_name),
+                                }
+                            }
+                        }
+                    };
+                    conversions.push(conversion);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    conversions
+}
+
+/// Returns the name of an identifier binding
+fn binding_name(binding: &AnyJsBinding) -> Option<String> {
+    let name = binding.as_js_identifier_binding()?.name_token().ok()?;
+    Some(name.text_trimmed().to_string())
+}
+
+/// Returns the outline of a file: the functions, the classes and their members, the
+/// variables and the types declared in the file
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let tree: AnyJsRoot = parse.tree();
+    let mut symbols = vec![];
+    collect_symbols(tree.syntax(), false, &mut symbols);
+    symbols
+}
+
+/// Collects the symbols declared by the descendants of `node`, nested like their
+/// declarations. The variables declared inside a function aren't part of the outline,
+/// unless they are functions or classes.
+fn collect_symbols(node: &JsSyntaxNode, in_function: bool, symbols: &mut Vec<DocumentSymbol>) {
+    for child in node.children() {
+        let in_function = in_function
+            || matches!(
+                child.kind(),
+                JsSyntaxKind::JS_FUNCTION_BODY
+                    | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+            );
+        match symbol_declared_by(&child, in_function) {
+            Some((name, kind, selection_range)) => {
+                let mut children = vec![];
+                collect_symbols(&child, in_function, &mut children);
+                symbols.push(DocumentSymbol {
+                    name,
+                    kind,
+                    range: child.text_trimmed_range(),
+                    selection_range,
+                    children,
+                });
+            }
+            None => collect_symbols(&child, in_function, symbols),
+        }
+    }
+}
+
+/// Returns the name, the kind and the range of the name of the symbol declared by `node`
+fn symbol_declared_by(
+    node: &JsSyntaxNode,
+    in_function: bool,
+) -> Option<(String, SymbolKind, TextRange)> {
+    let kind = match node.kind() {
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+        | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_EXPORT_DEFAULT_DECLARATION => SymbolKind::Function,
+        JsSyntaxKind::JS_CLASS_DECLARATION | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION => {
+            SymbolKind::Class
+        }
+        JsSyntaxKind::TS_INTERFACE_DECLARATION => SymbolKind::Interface,
+        JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION => SymbolKind::TypeAlias,
+        JsSyntaxKind::TS_ENUM_DECLARATION => SymbolKind::Enum,
+        JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE => {
+            let clause = JsExportDefaultExpressionClause::unwrap_cast(node.clone());
+            let default_token = clause.default_token().ok()?;
+            return Some((
+                "default".to_string(),
+                SymbolKind::Variable,
+                default_token.text_trimmed_range(),
+            ));
+        }
+        JsSyntaxKind::TS_MODULE_DECLARATION => {
+            let name = TsModuleDeclaration::unwrap_cast(node.clone()).name().ok()?;
+            return Some((
+                name.syntax().text_trimmed().to_string(),
+                SymbolKind::Namespace,
+                name.range(),
+            ));
+        }
+        JsSyntaxKind::JS_VARIABLE_DECLARATOR => {
+            return variable_symbol(
+                &JsVariableDeclarator::unwrap_cast(node.clone()),
+                in_function,
+            )
+        }
+        JsSyntaxKind::TS_ENUM_MEMBER => {
+            let name = TsEnumMember::unwrap_cast(node.clone()).name().ok()?;
+            return member_symbol(name.name(), name.syntax(), SymbolKind::EnumMember);
+        }
+        _ => {
+            if let Some(member) = AnyJsClassMember::cast_ref(node) {
+                return class_member_symbol(&member);
+            }
+            if let Some(member) = AnyTsTypeMember::cast_ref(node) {
+                return type_member_symbol(&member);
+            }
+            return None;
+        }
+    };
+
+    // The declaration is named by its binding, e.g. `a` in `function a() {}`
+    let binding = node.children().find_map(AnyJsIdentifierBinding::cast);
+    match binding.and_then(|binding| binding.name_token().ok()) {
+        Some(name) => Some((
+            name.text_trimmed().to_string(),
+            kind,
+            name.text_trimmed_range(),
+        )),
+        // e.g. `export default function () {}`
+        None if node.parent().is_some_and(|parent| {
+            parent.kind() == JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+        }) =>
+        {
+            Some(("default".to_string(), kind, node.text_trimmed_range()))
+        }
+        None => None,
+    }
+}
+
+fn variable_symbol(
+    declarator: &JsVariableDeclarator,
+    in_function: bool,
+) -> Option<(String, SymbolKind, TextRange)> {
+    // The destructured variables aren't part of the outline
+    let binding = declarator.id().ok()?;
+    let name = binding
+        .as_any_js_binding()?
+        .as_js_identifier_binding()?
+        .name_token()
+        .ok()?;
+
+    let initializer = declarator
+        .initializer()
+        .and_then(|initializer| initializer.expression().ok())
+        .map(|expression| expression.omit_parentheses());
+    let kind = match initializer {
+        Some(
+            AnyJsExpression::JsArrowFunctionExpression(_)
+            | AnyJsExpression::JsFunctionExpression(_),
+        ) => SymbolKind::Function,
+        Some(AnyJsExpression::JsClassExpression(_)) => SymbolKind::Class,
+        _ if in_function => return None,
+        _ => {
+            let is_const = declarator
+                .syntax()
+                .grand_parent()
+                .and_then(JsVariableDeclaration::cast)
+                .is_some_and(|declaration| declaration.is_const());
+            if is_const {
+                SymbolKind::Constant
+            } else {
+                SymbolKind::Variable
+            }
+        }
+    };
+
+    Some((
+        name.text_trimmed().to_string(),
+        kind,
+        name.text_trimmed_range(),
+    ))
+}
+
+fn class_member_symbol(member: &AnyJsClassMember) -> Option<(String, SymbolKind, TextRange)> {
+    let kind = match member {
+        AnyJsClassMember::JsConstructorClassMember(_)
+        | AnyJsClassMember::TsConstructorSignatureClassMember(_) => SymbolKind::Constructor,
+        AnyJsClassMember::JsMethodClassMember(_)
+        | AnyJsClassMember::TsMethodSignatureClassMember(_) => SymbolKind::Method,
+        AnyJsClassMember::JsGetterClassMember(_)
+        | AnyJsClassMember::JsPropertyClassMember(_)
+        | AnyJsClassMember::JsSetterClassMember(_)
+        | AnyJsClassMember::TsGetterSignatureClassMember(_)
+        | AnyJsClassMember::TsInitializedPropertySignatureClassMember(_)
+        | AnyJsClassMember::TsPropertySignatureClassMember(_)
+        | AnyJsClassMember::TsSetterSignatureClassMember(_) => SymbolKind::Property,
+        AnyJsClassMember::JsBogusMember(_)
+        | AnyJsClassMember::JsEmptyClassMember(_)
+        | AnyJsClassMember::JsStaticInitializationBlockClassMember(_)
+        | AnyJsClassMember::TsIndexSignatureClassMember(_) => return None,
+    };
+    let name = member.name().ok()??;
+    member_symbol(name.name(), name.syntax(), kind)
+}
+
+fn type_member_symbol(member: &AnyTsTypeMember) -> Option<(String, SymbolKind, TextRange)> {
+    let (name, kind) = match member {
+        AnyTsTypeMember::TsMethodSignatureTypeMember(member) => {
+            (member.name().ok()?, SymbolKind::Method)
+        }
+        AnyTsTypeMember::TsPropertySignatureTypeMember(member) => {
+            (member.name().ok()?, SymbolKind::Property)
+        }
+        AnyTsTypeMember::TsGetterSignatureTypeMember(member) => {
+            (member.name().ok()?, SymbolKind::Property)
+        }
+        AnyTsTypeMember::TsSetterSignatureTypeMember(member) => {
+            (member.name().ok()?, SymbolKind::Property)
+        }
+        AnyTsTypeMember::JsBogusMember(_)
+        | AnyTsTypeMember::TsCallSignatureTypeMember(_)
+        | AnyTsTypeMember::TsConstructSignatureTypeMember(_)
+        | AnyTsTypeMember::TsIndexSignatureTypeMember(_) => return None,
+    };
+    member_symbol(name.name(), name.syntax(), kind)
+}
+
+/// The symbol of a member named `name`. The computed names that aren't literals, e.g.
+/// `[Symbol.iterator]`, are named as written.
+fn member_symbol(
+    name: Option<TokenText>,
+    name_node: &JsSyntaxNode,
+    kind: SymbolKind,
+) -> Option<(String, SymbolKind, TextRange)> {
+    let name = match name {
+        Some(name) => name.text().to_string(),
+        None => name_node.text_trimmed().to_string(),
+    };
+    Some((name, kind, name_node.text_trimmed_range()))
+}
@@ -14,18 +14,23 @@ use crate::workspace::{
     FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::{Configuration, Rules, WorkspaceError};
-use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategories};
+use biome_analyze::{
+    AnalyzerConfiguration, AnalyzerOptions, CancellationToken, ControlFlow, Never,
+    QueryCacheStatistics, RuleCategories,
+};
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
-use biome_fs::{RomePath, BIOME_JSON, ROME_JSON};
+use biome_fs::{RomePath, BIOME_JSON, BIOME_JSONC, ROME_JSON};
 use biome_json_analyze::analyze;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_json_syntax::{
+    JsonFileSource, JsonLanguage, JsonMember, JsonObjectValue, JsonRoot, JsonSyntaxNode,
+};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, FileSource, NodeCache};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutation, FileSource, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
 use std::path::{Path, PathBuf};
 
@@ -37,6 +42,7 @@ pub struct JsonFormatterSettings {
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub enabled: Option<bool>,
+    pub sort_package_json_keys: Option<bool>,
 }
 
 impl Language for JsonLanguage {
@@ -70,12 +76,18 @@ impl Language for JsonLanguage {
         } else {
             global.indent_width.unwrap_or_default()
         };
+        let line_ending = if let Some(line_ending) = language.line_ending {
+            line_ending
+        } else {
+            global.line_ending.unwrap_or_default()
+        };
 
         overrides.override_json_format_options(
             path,
             JsonFormatOptions::new(path.as_path().try_into().unwrap_or_default())
                 .with_indent_style(indent_style)
                 .with_indent_width(indent_width)
+                .with_line_ending(line_ending)
                 .with_line_width(line_width),
         )
     }
@@ -109,8 +121,14 @@ impl ExtensionHandler for JsonFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
+                inlay_hints: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
+                search: None,
+                module_info: None,
+                document_symbols: None,
+                export_conversions: None,
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -121,12 +139,128 @@ impl ExtensionHandler for JsonFileHandler {
     }
 }
 
+/// Returns `true` if the tools that read the file accept comments and trailing commas:
+/// the known files, the `tsconfig*.json` files and the `.json` files of a `.vscode` directory
 fn is_file_allowed(path: &Path) -> bool {
-    path.file_name()
-        .and_then(|f| f.to_str())
-        .map(|f| super::Language::KNOWN_FILES_AS_JSONC.contains(&f))
-        // default is false
-        .unwrap_or_default()
+    let Some(file_name) = path.file_name().and_then(|f| f.to_str()) else {
+        return false;
+    };
+    if super::Language::KNOWN_FILES_AS_JSONC.contains(&file_name) {
+        return true;
+    }
+    if !file_name.ends_with(".json") {
+        return false;
+    }
+    file_name.starts_with("tsconfig")
+        || path
+            .parent()
+            .and_then(Path::file_name)
+            .is_some_and(|directory| directory == ".vscode")
+}
+
+/// The canonical order of the keys of a `package.json` file. The keys that
+/// aren't listed are placed after them, in their original order.
+const PACKAGE_JSON_KEY_ORDER: &[&str] = &[
+    "$schema",
+    "name",
+    "displayName",
+    "version",
+    "private",
+    "description",
+    "categories",
+    "keywords",
+    "homepage",
+    "bugs",
+    "repository",
+    "funding",
+    "license",
+    "author",
+    "maintainers",
+    "contributors",
+    "publisher",
+    "sideEffects",
+    "type",
+    "imports",
+    "exports",
+    "main",
+    "module",
+    "browser",
+    "types",
+    "typesVersions",
+    "typings",
+    "bin",
+    "man",
+    "directories",
+    "files",
+    "workspaces",
+    "scripts",
+    "config",
+    "dependencies",
+    "devDependencies",
+    "dependenciesMeta",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "bundledDependencies",
+    "bundleDependencies",
+    "overrides",
+    "resolutions",
+    "packageManager",
+    "engines",
+    "os",
+    "cpu",
+    "publishConfig",
+];
+
+/// Returns `true` if the keys of `rome_path` are sorted before it's formatted
+fn sorts_package_json_keys(rome_path: &RomePath, settings: &SettingsHandle) -> bool {
+    if rome_path.file_name().and_then(|f| f.to_str()) != Some("package.json") {
+        return false;
+    }
+    let settings = settings.as_ref();
+    settings
+        .override_settings
+        .override_json_sort_package_json_keys(
+            rome_path,
+            settings
+                .languages
+                .json
+                .formatter
+                .sort_package_json_keys
+                .unwrap_or_default(),
+        )
+}
+
+/// Sorts the keys of the root object of a `package.json` file in their canonical order.
+///
+/// The trivia stays in place, so the comments and the blank lines of a member
+/// don't move with it.
+fn sort_package_json_keys(root: JsonSyntaxNode) -> JsonSyntaxNode {
+    let Some(object) = JsonRoot::cast_ref(&root)
+        .and_then(|root| root.value().ok())
+        .and_then(|value| JsonObjectValue::cast(value.into_syntax()))
+    else {
+        return root;
+    };
+    let members: Vec<JsonMember> = object.json_member_list().iter().flatten().collect();
+    let mut sorted_members = members.clone();
+    sorted_members.sort_by_key(|member| {
+        let name = member.name().and_then(|name| name.inner_string_text()).ok();
+        name.and_then(|name| {
+            PACKAGE_JSON_KEY_ORDER
+                .iter()
+                .position(|key| *key == name.text())
+        })
+        .unwrap_or(PACKAGE_JSON_KEY_ORDER.len())
+    });
+
+    let mut mutation = BatchMutation::new(root);
+    for (member, sorted_member) in members.into_iter().zip(sorted_members) {
+        if member != sorted_member {
+            mutation.replace_node(member, sorted_member);
+        }
+    }
+    mutation.commit()
 }
 
 fn parse(
@@ -197,7 +331,10 @@ fn format(
 
     tracing::debug!("Format with the following options: \n{}", options);
 
-    let tree = parse.syntax();
+    let mut tree = parse.syntax();
+    if sorts_package_json_keys(rome_path, &settings) {
+        tree = sort_package_json_keys(tree);
+    }
     let formatted = format_node(options, &tree)?;
 
     match formatted.print() {
@@ -261,7 +398,10 @@ fn lint(params: LintParams) -> LintResults {
 
         // if we're parsing the `biome.json` file, we deserialize it, so we can emit diagnostics for
         // malformed configuration
-        if params.path.ends_with(ROME_JSON) || params.path.ends_with(BIOME_JSON) {
+        if params.path.ends_with(ROME_JSON)
+            || params.path.ends_with(BIOME_JSON)
+            || params.path.ends_with(BIOME_JSONC)
+        {
             let deserialized = deserialize_from_json_ast::<Configuration>(&root);
             diagnostics.extend(
                 deserialized
@@ -281,8 +421,9 @@ fn lint(params: LintParams) -> LintResults {
         let skipped_diagnostics = diagnostic_count - diagnostics.len() as u64;
 
         let has_lint = params.filter.categories.contains(RuleCategories::LINT);
-        let analyzer_options =
+        let mut analyzer_options =
             compute_analyzer_options(&params.settings, PathBuf::from(params.path.as_path()));
+        analyzer_options.cancellation = params.cancellation;
 
         let (_, analyze_diagnostics) = analyze(&root, params.filter, &analyzer_options, |signal| {
             if let Some(mut diagnostic) = signal.diagnostic() {
@@ -362,7 +503,11 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     })
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+fn organize_imports(
+    parse: AnyParse,
+    _: SettingsHandle,
+    _: &RomePath,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     Ok(OrganizeImportsResult {
         code: parse.syntax::<JsonLanguage>().to_string(),
     })
@@ -372,9 +517,16 @@ fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> An
     let configuration = AnalyzerConfiguration {
         rules: to_analyzer_rules(settings.as_ref(), file_path.as_path()),
         globals: vec![],
+        path_aliases: vec![],
+        declared_packages: None,
+        project_packages: vec![],
+        node_engine: None,
+        browserslist: vec![],
     };
     AnalyzerOptions {
         configuration,
         file_path,
+        cancellation: CancellationToken::default(),
+        query_cache_statistics: QueryCacheStatistics::default(),
     }
 }
@@ -1,11 +1,16 @@
 use self::{javascript::JsFileHandler, json::JsonFileHandler, unknown::UnknownFileHandler};
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
+use crate::configuration::linter::RuleFixKind;
+use crate::module_graph::{ExportConversion, ModuleInfo, ModulePackages};
+use crate::workspace::{FixFileMode, OrganizeImportsResult, RuleSelector};
 use crate::{
     settings::SettingsHandle,
-    workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
-    Rules, WorkspaceError,
+    workspace::{
+        DocumentSymbol, FixFileResult, GetSyntaxTreeResult, InlayHint, PrepareRenameResult,
+        PullActionsResult, RenameResult,
+    },
+    RuleConfiguration, Rules, WorkspaceError,
 };
-use biome_analyze::{AnalysisFilter, AnalyzerDiagnostic};
+use biome_analyze::{AnalysisFilter, AnalyzerDiagnostic, CancellationToken};
 use biome_console::fmt::Formatter;
 use biome_console::markup;
 use biome_diagnostics::{Diagnostic, Severity};
@@ -14,12 +19,17 @@ use biome_fs::RomePath;
 use biome_js_syntax::{TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
+pub(crate) use css::css_module_info;
+pub use css::is_css_module;
 pub use javascript::JsFormatterSettings;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::Arc;
 
+mod css;
 mod javascript;
 mod json;
+mod search;
 mod unknown;
 
 /// Supported languages by Biome
@@ -168,7 +178,7 @@ impl biome_console::fmt::Display for Language {
 
 // TODO: The Css variant is unused at the moment
 #[allow(dead_code)]
-pub(crate) enum Mime {
+pub enum Mime {
     Javascript,
     Json,
     Css,
@@ -197,10 +207,14 @@ pub struct FixAllParams<'a> {
     pub(crate) rules: Option<&'a Rules>,
     pub(crate) filter: AnalysisFilter<'a>,
     pub(crate) fix_file_mode: FixFileMode,
+    /// The rules whose unsafe fixes are applied, whatever the mode
+    pub(crate) unsafe_rules: &'a [RuleSelector],
     pub(crate) settings: SettingsHandle<'a>,
     /// Whether it should format the code action
     pub(crate) should_format: bool,
     pub(crate) rome_path: &'a RomePath,
+    /// Stops applying the fixes when it's cancelled
+    pub(crate) cancellation: CancellationToken,
 }
 
 #[derive(Default)]
@@ -212,6 +226,34 @@ pub struct Capabilities {
     pub(crate) formatter: FormatterCapabilities,
 }
 
+impl Capabilities {
+    /// Returns the capabilities of the files of `language`. A new [ExtensionHandler]
+    /// can start from the capabilities of the language it's based on.
+    pub fn of_language(language: Language) -> Self {
+        match language {
+            Language::JavaScript
+            | Language::JavaScriptReact
+            | Language::TypeScript
+            | Language::TypeScriptReact => JsFileHandler {}.capabilities(),
+            Language::Json | Language::Jsonc => JsonFileHandler {}.capabilities(),
+            Language::Unknown => UnknownFileHandler::default().capabilities(),
+        }
+    }
+
+    /// Removes the capabilities of the formatter
+    pub fn without_formatter(mut self) -> Self {
+        self.formatter = FormatterCapabilities::default();
+        self
+    }
+
+    /// Removes the capabilities of the analyzer: the linter, the code actions and
+    /// the sorting of the imports
+    pub fn without_analyzer(mut self) -> Self {
+        self.analyzer = AnalyzerCapabilities::default();
+        self
+    }
+}
+
 type Parse = fn(&RomePath, Language, &str, SettingsHandle, &mut NodeCache) -> AnyParse;
 
 #[derive(Default)]
@@ -241,6 +283,10 @@ pub(crate) struct LintParams<'a> {
     pub(crate) settings: SettingsHandle<'a>,
     pub(crate) max_diagnostics: u64,
     pub(crate) path: &'a RomePath,
+    /// The packages that the file can import
+    pub(crate) packages: ModulePackages,
+    /// Stops the analysis when it's cancelled
+    pub(crate) cancellation: CancellationToken,
 }
 
 pub(crate) struct LintResults {
@@ -254,7 +300,14 @@ type CodeActions =
     fn(AnyParse, TextRange, Option<&Rules>, SettingsHandle, &RomePath) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&RomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
-type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
+type PrepareRename = fn(AnyParse, TextSize) -> Result<PrepareRenameResult, WorkspaceError>;
+type InlayHints = fn(AnyParse, TextRange) -> Vec<InlayHint>;
+type OrganizeImports =
+    fn(AnyParse, SettingsHandle, &RomePath) -> Result<OrganizeImportsResult, WorkspaceError>;
+type Search = fn(AnyParse, &str) -> Result<Vec<TextRange>, WorkspaceError>;
+type ModuleInfoExtractor = fn(AnyParse) -> ModuleInfo;
+type DocumentSymbols = fn(AnyParse) -> Vec<DocumentSymbol>;
+type ExportConversions = fn(AnyParse, TextRange) -> Vec<ExportConversion>;
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -266,8 +319,20 @@ pub struct AnalyzerCapabilities {
     pub(crate) fix_all: Option<FixAll>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
+    /// It finds the binding to rename at a position of a file
+    pub(crate) prepare_rename: Option<PrepareRename>,
+    /// It finds the names of the parameters of the arguments of the calls
+    pub(crate) inlay_hints: Option<InlayHints>,
     /// It organize imports
     pub(crate) organize_imports: Option<OrganizeImports>,
+    /// It searches a structural pattern inside a file
+    pub(crate) search: Option<Search>,
+    /// It extracts the imports and the exports of a module
+    pub(crate) module_info: Option<ModuleInfoExtractor>,
+    /// It extracts the outline of a file
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    /// It finds the exports that can be converted to a named export, or to the default export
+    pub(crate) export_conversions: Option<ExportConversions>,
 }
 
 type Format = fn(&RomePath, AnyParse, SettingsHandle) -> Result<Printed, WorkspaceError>;
@@ -287,7 +352,10 @@ pub(crate) struct FormatterCapabilities {
 }
 
 /// Main trait to use to add a new language to Biome
-pub(crate) trait ExtensionHandler {
+///
+/// The handlers of the extensions that Biome doesn't know are registered with an
+/// [ExtensionRegistry].
+pub trait ExtensionHandler: Send + Sync {
     /// The language of the file. It can be a super language.
     /// For example, a ".js" file can have [Language::Ts]
     fn language(&self) -> Language;
@@ -313,20 +381,89 @@ pub(crate) trait ExtensionHandler {
     }
 }
 
+/// The handler of an extension registered in an [ExtensionRegistry]
+#[derive(Clone)]
+enum RegisteredHandler {
+    /// The files are handled like the files of a language of Biome
+    Language(Language),
+    /// The files are handled by a handler of the application
+    Handler(Arc<dyn ExtensionHandler>),
+}
+
+/// The file extensions registered by an application that embeds the workspace,
+/// in addition to the extensions that Biome knows.
+///
+/// An extension can contain several dots, e.g. `mjs.snap`. The longest registered
+/// extension that ends the name of a file applies, and it takes precedence over
+/// the extension known by Biome.
+///
+/// # Examples
+///
+/// ```
+/// # use biome_service::file_handlers::{ExtensionRegistry, Language};
+/// let extensions = ExtensionRegistry::new()
+///     .with_language("mjs.snap", Language::JavaScript)
+///     .with_language("cts.ejs", Language::TypeScript);
+/// ```
+#[derive(Clone, Default)]
+pub struct ExtensionRegistry {
+    extensions: Vec<(String, RegisteredHandler)>,
+}
+
+impl ExtensionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handles the files with the extension `extension` like the files of `language`
+    pub fn with_language(mut self, extension: impl AsRef<str>, language: Language) -> Self {
+        self.register(extension.as_ref(), RegisteredHandler::Language(language));
+        self
+    }
+
+    /// Handles the files with the extension `extension` with `handler`
+    pub fn with_handler(
+        mut self,
+        extension: impl AsRef<str>,
+        handler: impl ExtensionHandler + 'static,
+    ) -> Self {
+        self.register(
+            extension.as_ref(),
+            RegisteredHandler::Handler(Arc::new(handler)),
+        );
+        self
+    }
+
+    fn register(&mut self, extension: &str, handler: RegisteredHandler) {
+        let extension = extension.trim_start_matches('.').to_lowercase();
+        self.extensions
+            .retain(|(registered, _)| registered != &extension);
+        self.extensions.push((extension, handler));
+    }
+
+    /// Returns the handler of the longest registered extension of the file
+    fn find(&self, path: &Path) -> Option<&RegisteredHandler> {
+        let file_name = path.file_name()?.to_str()?.to_lowercase();
+        self.extensions
+            .iter()
+            .filter(|(extension, _)| {
+                file_name.len() > extension.len() + 1
+                    && file_name.ends_with(extension.as_str())
+                    && file_name[..file_name.len() - extension.len()].ends_with('.')
+            })
+            .max_by_key(|(extension, _)| extension.len())
+            .map(|(_, handler)| handler)
+    }
+}
+
 /// Features available for each language
 pub(crate) struct Features {
-    js: JsFileHandler,
-    json: JsonFileHandler,
-    unknown: UnknownFileHandler,
+    extensions: ExtensionRegistry,
 }
 
 impl Features {
-    pub(crate) fn new() -> Self {
-        Features {
-            js: JsFileHandler {},
-            json: JsonFileHandler {},
-            unknown: UnknownFileHandler::default(),
-        }
+    pub(crate) fn new(extensions: ExtensionRegistry) -> Self {
+        Features { extensions }
     }
 
     /// Return a [Language] from a string
@@ -342,20 +479,25 @@ impl Features {
             .unwrap_or_default()
     }
 
+    /// Returns the [Language] of a file, with the extensions registered by the application
+    pub(crate) fn language_of(&self, rome_path: &RomePath) -> Language {
+        match self.extensions.find(rome_path) {
+            Some(RegisteredHandler::Language(language)) => *language,
+            Some(RegisteredHandler::Handler(handler)) => handler.language(),
+            None => Self::get_language(rome_path),
+        }
+    }
+
     /// Returns the [Capabilities] associated with a [RomePath]
     pub(crate) fn get_capabilities(
         &self,
         rome_path: &RomePath,
         language_hint: Language,
     ) -> Capabilities {
-        match Self::get_language(rome_path).or(language_hint) {
-            Language::JavaScript
-            | Language::JavaScriptReact
-            | Language::TypeScript
-            | Language::TypeScriptReact => self.js.capabilities(),
-            Language::Json | Language::Jsonc => self.json.capabilities(),
-            Language::Unknown => self.unknown.capabilities(),
+        if let Some(RegisteredHandler::Handler(handler)) = self.extensions.find(rome_path) {
+            return handler.capabilities();
         }
+        Capabilities::of_language(self.language_of(rome_path).or(language_hint))
     }
 }
 
@@ -378,3 +520,33 @@ pub(crate) fn is_diagnostic_error(
 
     severity >= Severity::Error
 }
+
+/// Checks whether the unsafe fixes of the rule `rule_name` are applied.
+///
+/// The rules of `unsafe_rules` always apply them, otherwise the `fix` option of the rule, in
+/// the configured rules, takes precedence over `fix_file_mode`.
+pub(crate) fn applies_unsafe_fixes(
+    fix_file_mode: FixFileMode,
+    rules: Option<&Rules>,
+    unsafe_rules: &[RuleSelector],
+    rule_name: Option<(&str, &str)>,
+) -> bool {
+    let applies_by_default = matches!(fix_file_mode, FixFileMode::SafeAndUnsafeFixes);
+    let Some((group, rule_name)) = rule_name else {
+        return applies_by_default;
+    };
+    if unsafe_rules
+        .iter()
+        .any(|selector| selector.matches(group, rule_name))
+    {
+        return true;
+    }
+    match rules
+        .and_then(|rules| rules.get_rule_configuration(group, rule_name))
+        .and_then(RuleConfiguration::fix_kind)
+    {
+        Some(RuleFixKind::Safe) => false,
+        Some(RuleFixKind::Unsafe) => true,
+        None => applies_by_default,
+    }
+}
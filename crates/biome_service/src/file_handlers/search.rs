@@ -0,0 +1,126 @@
+//! A structural search engine that works with any language.
+//!
+//! A pattern is a snippet of code of the same language of the files that are searched. The
+//! snippet is parsed, and its syntax tree is compared with the nodes of the file, ignoring the
+//! trivia. An identifier that starts with `$` is a metavariable: it matches any node. When the
+//! same metavariable is used more than once, all its occurrences must match the same code.
+//! The metavariable `$_` matches any node and can be used multiple times.
+
+use biome_rowan::{Language, SyntaxElement, SyntaxNode, TextRange};
+use rustc_hash::FxHashMap;
+
+/// The syntax tree of a pattern, ready to be matched against the nodes of a file
+#[derive(Debug)]
+pub(crate) struct StructuralPattern<L: Language> {
+    node: SyntaxNode<L>,
+}
+
+impl<L: Language> StructuralPattern<L> {
+    /// Creates a pattern from the root of the syntax tree of the snippet.
+    ///
+    /// The pattern starts from the innermost node that contains the whole snippet, e.g. the
+    /// pattern `foo($a)` starts from the call expression, not from the expression statement,
+    /// so it also matches calls that aren't statements.
+    ///
+    /// It returns [None] if the snippet is empty or if it contains only a metavariable.
+    pub(crate) fn new(root: SyntaxNode<L>) -> Option<Self> {
+        let text = root.text_trimmed().to_string();
+        if text.is_empty() || is_metavariable(&text) {
+            return None;
+        }
+
+        let node = root
+            .descendants()
+            .filter(|node| node.text_trimmed() == text.as_str())
+            .last()?;
+
+        Some(Self { node })
+    }
+
+    /// Returns the ranges of the nodes of `root` that match the pattern
+    pub(crate) fn find_matches(&self, root: &SyntaxNode<L>) -> Vec<TextRange> {
+        root.descendants()
+            .filter(|node| node.kind() == self.node.kind())
+            .filter(|node| match_node(&self.node, node, &mut FxHashMap::default()))
+            .map(|node| node.text_trimmed_range())
+            .collect()
+    }
+}
+
+/// Returns `true` if `text` is the name of a metavariable, e.g. `$value`
+fn is_metavariable(text: &str) -> bool {
+    text.strip_prefix('$').is_some_and(|name| {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '_')
+    })
+}
+
+fn match_node<L: Language>(
+    pattern: &SyntaxNode<L>,
+    node: &SyntaxNode<L>,
+    bindings: &mut FxHashMap<String, String>,
+) -> bool {
+    let pattern_text = pattern.text_trimmed().to_string();
+    if is_metavariable(&pattern_text) {
+        if pattern_text == "$_" {
+            return true;
+        }
+        let text = node.text_trimmed().to_string();
+        return match bindings.get(&pattern_text) {
+            Some(bound) => *bound == text,
+            None => {
+                bindings.insert(pattern_text, text);
+                true
+            }
+        };
+    }
+
+    if pattern.kind() != node.kind() {
+        return false;
+    }
+
+    let mut pattern_children = pattern.children_with_tokens();
+    let mut node_children = node.children_with_tokens();
+    loop {
+        match (pattern_children.next(), node_children.next()) {
+            (None, None) => return true,
+            (Some(pattern_child), Some(node_child)) => {
+                if !match_element(&pattern_child, &node_child, bindings) {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+}
+
+fn match_element<L: Language>(
+    pattern: &SyntaxElement<L>,
+    element: &SyntaxElement<L>,
+    bindings: &mut FxHashMap<String, String>,
+) -> bool {
+    match (pattern, element) {
+        (SyntaxElement::Node(pattern), SyntaxElement::Node(node)) => {
+            match_node(pattern, node, bindings)
+        }
+        (SyntaxElement::Token(pattern), SyntaxElement::Token(token)) => {
+            pattern.kind() == token.kind() && pattern.text_trimmed() == token.text_trimmed()
+        }
+        // A metavariable is a node, but it can match any element
+        (SyntaxElement::Node(pattern), SyntaxElement::Token(token)) => {
+            let pattern_text = pattern.text_trimmed().to_string();
+            is_metavariable(&pattern_text)
+                && (pattern_text == "$_"
+                    || match bindings.get(&pattern_text) {
+                        Some(bound) => bound == token.text_trimmed(),
+                        None => {
+                            bindings.insert(pattern_text, token.text_trimmed().to_string());
+                            true
+                        }
+                    })
+        }
+        (SyntaxElement::Token(_), SyntaxElement::Node(_)) => false,
+    }
+}
@@ -8,10 +8,14 @@ pub mod file_handlers;
 pub mod project_handlers;
 
 pub mod matcher;
+pub mod metrics;
 pub mod settings;
+pub mod stable_hash;
 pub mod workspace;
 
 mod diagnostics;
+mod module_graph;
+mod symbol_index;
 #[cfg(feature = "schema")]
 pub mod workspace_types;
 
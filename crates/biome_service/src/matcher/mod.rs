@@ -8,15 +8,27 @@ use std::path::Path;
 use std::sync::RwLock;
 
 /// A data structure to use when there's need to match a string or a path a against
-/// a unix shell style patterns
+/// a unix shell style patterns.
+///
+/// Like in a `.gitignore` file, a pattern that starts with `!` negates the patterns before it,
+/// and the last pattern that matches a path decides whether the path matches: e.g. the patterns
+/// `src/**` and `!src/generated/**` match the files of `src`, except the generated ones. The
+/// alternatives between braces are expanded, e.g. `*.{js,ts}` matches `a.js` and `a.ts`.
 #[derive(Debug)]
 pub struct Matcher {
-    patterns: Vec<Pattern>,
+    patterns: Vec<MatcherPattern>,
     options: MatchOptions,
     /// Whether the string was already checked
     already_checked: RwLock<HashMap<String, bool>>,
 }
 
+#[derive(Debug)]
+struct MatcherPattern {
+    pattern: Pattern,
+    /// Whether the pattern starts with `!`
+    is_negated: bool,
+}
+
 impl Matcher {
     /// Creates a new Matcher with given options.
     ///
@@ -29,34 +41,52 @@ impl Matcher {
         }
     }
 
-    /// It adds a unix shell style pattern
+    /// It adds a unix shell style pattern.
+    ///
+    /// A pattern that starts with `!` is negated, `\!` matches a leading `!` literally.
     pub fn add_pattern(&mut self, pattern: &str) -> Result<(), PatternError> {
-        let pattern = Pattern::new(pattern)?;
-        self.patterns.push(pattern);
+        let (pattern, is_negated) = match pattern.strip_prefix('!') {
+            Some(pattern) => (pattern, true),
+            None => (
+                pattern
+                    .strip_prefix('\\')
+                    .filter(|pattern| pattern.starts_with('!'))
+                    .unwrap_or(pattern),
+                false,
+            ),
+        };
+        for alternative in expand_braces(pattern)? {
+            self.patterns.push(MatcherPattern {
+                pattern: Pattern::new(&alternative)?,
+                is_negated,
+            });
+        }
         Ok(())
     }
 
     /// It matches the given string against the stored patterns.
     ///
-    /// It returns [true] if there's at least a match
+    /// It returns [true] if the last pattern that matches isn't negated
     pub fn matches(&self, source: &str) -> bool {
         let mut already_ignored = self.already_checked.write().unwrap();
         if let Some(matches) = already_ignored.get(source) {
             return *matches;
         }
-        for pattern in &self.patterns {
-            if pattern.matches_with(source, self.options) || source.contains(pattern.as_str()) {
-                already_ignored.insert(source.to_string(), true);
-                return true;
-            }
-        }
-        already_ignored.insert(source.to_string(), false);
-        false
+        let matches = self
+            .patterns
+            .iter()
+            .rev()
+            .find(|MatcherPattern { pattern, .. }| {
+                pattern.matches_with(source, self.options) || source.contains(pattern.as_str())
+            })
+            .is_some_and(|pattern| !pattern.is_negated);
+        already_ignored.insert(source.to_string(), matches);
+        matches
     }
 
     /// It matches the given path against the stored patterns
     ///
-    /// It returns [true] if there's a lest a match
+    /// It returns [true] if the last pattern that matches isn't negated
     pub fn matches_path(&self, source: &Path) -> bool {
         let mut already_checked = self.already_checked.write().unwrap();
         let source_as_string = source.to_str();
@@ -65,32 +95,26 @@ impl Matcher {
                 return *matches;
             }
         }
-        let matches = {
-            for pattern in &self.patterns {
-                let matches = if pattern.matches_path_with(source, self.options) {
-                    true
-                } else {
-                    // Here we cover cases where the user specifies single files inside the patterns.
-                    // The pattern library doesn't support single files, we here we just do a check
-                    // on contains
-                    //
-                    // Given the pattern `out`:
-                    // - `out/index.html` -> matches
-                    // - `out/` -> matches
-                    // - `layout.tsx` -> does not match
-                    // - `routes/foo.ts` -> does not match
-                    source
+        // Here we cover cases where the user specifies single files inside the patterns.
+        // The pattern library doesn't support single files, we here we just do a check
+        // on contains
+        //
+        // Given the pattern `out`:
+        // - `out/index.html` -> matches
+        // - `out/` -> matches
+        // - `layout.tsx` -> does not match
+        // - `routes/foo.ts` -> does not match
+        let matches = self
+            .patterns
+            .iter()
+            .rev()
+            .find(|MatcherPattern { pattern, .. }| {
+                pattern.matches_path_with(source, self.options)
+                    || source
                         .ancestors()
                         .any(|ancestor| ancestor.ends_with(pattern.as_str()))
-                };
-
-                if matches {
-                    return true;
-                }
-            }
-
-            false
-        };
+            })
+            .is_some_and(|pattern| !pattern.is_negated);
 
         if let Some(source_as_string) = source_as_string {
             already_checked.insert(source_as_string.to_string(), matches);
@@ -100,6 +124,57 @@ impl Matcher {
     }
 }
 
+/// Expands the alternatives between braces, e.g. `*.{js,ts}` gives `*.js` and `*.ts`.
+///
+/// The braces can be nested, and they are matched literally inside brackets, e.g. `[{]`.
+fn expand_braces(pattern: &str) -> Result<Vec<String>, PatternError> {
+    let mut start = None;
+    let mut depth = 0;
+    let mut in_brackets = false;
+    let mut alternative_start = 0;
+    let mut alternatives = vec![];
+    for (index, char) in pattern.char_indices() {
+        match char {
+            '[' if !in_brackets => in_brackets = true,
+            ']' if in_brackets => in_brackets = false,
+            _ if in_brackets => {}
+            '{' => {
+                if depth == 0 {
+                    start = Some(index);
+                    alternative_start = index + 1;
+                }
+                depth += 1;
+            }
+            ',' if depth == 1 => {
+                alternatives.push(&pattern[alternative_start..index]);
+                alternative_start = index + 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    alternatives.push(&pattern[alternative_start..index]);
+                    let (prefix, suffix) =
+                        (&pattern[..start.unwrap_or_default()], &pattern[index + 1..]);
+                    let mut expanded = vec![];
+                    for alternative in alternatives {
+                        expanded.extend(expand_braces(&format!("{prefix}{alternative}{suffix}"))?);
+                    }
+                    return Ok(expanded);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match start {
+        Some(pos) if depth > 0 => Err(PatternError {
+            pos,
+            msg: "unclosed brace",
+        }),
+        _ => Ok(vec![pattern.to_string()]),
+    }
+}
+
 impl Diagnostic for PatternError {
     fn description(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(fmt, "{}", self.msg)
@@ -115,6 +190,7 @@ mod test {
     use crate::matcher::pattern::MatchOptions;
     use crate::matcher::Matcher;
     use std::env;
+    use std::path::Path;
 
     #[test]
     fn matches() {
@@ -159,6 +235,34 @@ mod test {
         assert!(result);
     }
 
+    #[test]
+    fn matches_the_last_matching_pattern() {
+        let mut matcher = Matcher::new(MatchOptions::default());
+        matcher.add_pattern("src/**").unwrap();
+        matcher.add_pattern("!src/generated/**").unwrap();
+        matcher.add_pattern("src/generated/kept.js").unwrap();
+
+        assert!(matcher.matches_path(Path::new("src/index.js")));
+        assert!(!matcher.matches_path(Path::new("src/generated/index.js")));
+        assert!(matcher.matches_path(Path::new("src/generated/kept.js")));
+        assert!(!matcher.matches_path(Path::new("test/index.js")));
+    }
+
+    #[test]
+    fn matches_the_alternatives_between_braces() {
+        let mut matcher = Matcher::new(MatchOptions::default());
+        matcher.add_pattern("*.{js,ts{,x}}").unwrap();
+
+        assert!(matcher.matches("index.js"));
+        assert!(matcher.matches("index.ts"));
+        assert!(matcher.matches("index.tsx"));
+        assert!(!matcher.matches("index.json"));
+
+        assert!(Matcher::new(MatchOptions::default())
+            .add_pattern("*.{js,ts")
+            .is_err());
+    }
+
     #[test]
     fn matches_single_path() {
         let dir = "workspace.rs";
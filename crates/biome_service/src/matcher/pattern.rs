@@ -43,10 +43,10 @@ impl fmt::Display for PatternError {
 ///
 /// - `*` matches any (possibly empty) sequence of characters.
 ///
-/// - `**` matches the current directory and arbitrary subdirectories. This
-///   sequence **must** form a single path component, so both `**a` and `b**`
-///   are invalid and will result in an error.  A sequence of more than two
-///   consecutive `*` characters is also invalid.
+/// - `**` matches the current directory and arbitrary subdirectories, when it
+///   forms a single path component. Like in `.gitignore` files, the other
+///   consecutive `*` characters, e.g. in `**a`, `b**` or `***`, are regular
+///   wildcards.
 ///
 /// - `[...]` matches any character inside the brackets.  Character sequences
 ///   can also specify ranges of characters, as ordered by Unicode, so e.g.
@@ -107,9 +107,6 @@ enum MatchResult {
     EntirePatternDoesntMatch,
 }
 
-const ERROR_WILDCARDS: &str = "wildcards are either regular `*` or recursive `**`";
-const ERROR_RECURSIVE_WILDCARDS: &str = "recursive wildcards must form a single path \
-                                         component";
 const ERROR_INVALID_RANGE: &str = "invalid range pattern";
 
 impl Pattern {
@@ -159,60 +156,24 @@ impl Pattern {
                         i += 1;
                     }
 
-                    let count = i - old;
-
-                    match count {
-                        count if count > 2 => {
-                            return Err(PatternError {
-                                pos: old + 2,
-                                msg: ERROR_WILDCARDS,
-                            });
-                        }
-                        count if count == 2 => {
-                            // ** can only be an entire path component
-                            // i.e. a/**/b is valid, but a**/b or a/**b is not
-                            // invalid matches are treated literally
-                            let is_valid = if i == 2 || path::is_separator(chars[i - count - 1]) {
-                                // it ends in a '/'
-                                if i < chars.len() && path::is_separator(chars[i]) {
-                                    i += 1;
-                                    true
-                                    // or the pattern ends here
-                                    // this enables the existing globbing mechanism
-                                } else if i == chars.len() {
-                                    true
-                                    // `**` ends in non-separator
-                                } else {
-                                    return Err(PatternError {
-                                        pos: i,
-                                        msg: ERROR_RECURSIVE_WILDCARDS,
-                                    });
-                                }
-                                // `**` begins with non-separator
-                            } else {
-                                return Err(PatternError {
-                                    pos: old - 1,
-                                    msg: ERROR_RECURSIVE_WILDCARDS,
-                                });
-                            };
-
-                            if is_valid {
-                                // collapse consecutive AnyRecursiveSequence to a
-                                // single one
-
-                                let tokens_len = tokens.len();
-
-                                if !(tokens_len > 1
-                                    && tokens[tokens_len - 1] == AnyRecursiveSequence)
-                                {
-                                    is_recursive = true;
-                                    tokens.push(AnyRecursiveSequence);
-                                }
-                            }
+                    // `**` is recursive only when it's an entire path component,
+                    // i.e. `a/**/b` is recursive, but `a**/b` or `a/**b` are not
+                    let starts_component = old == 0 || path::is_separator(chars[old - 1]);
+                    let ends_component = i == chars.len() || path::is_separator(chars[i]);
+                    if i - old == 2 && starts_component && ends_component {
+                        if i < chars.len() {
+                            i += 1;
                         }
-                        _ => {
-                            tokens.push(AnySequence);
+
+                        // collapse consecutive AnyRecursiveSequence to a
+                        // single one
+                        let tokens_len = tokens.len();
+                        if !(tokens_len > 1 && tokens[tokens_len - 1] == AnyRecursiveSequence) {
+                            is_recursive = true;
+                            tokens.push(AnyRecursiveSequence);
                         }
+                    } else {
+                        tokens.push(AnySequence);
                     }
                 }
                 '[' => {
@@ -527,16 +488,20 @@ mod test {
     #[test]
     fn test_pattern_from_str() {
         assert!("a*b".parse::<Pattern>().unwrap().matches("a_b"));
-        assert!("a/**b".parse::<Pattern>().unwrap_err().pos == 4);
+        assert!("a[b".parse::<Pattern>().unwrap_err().pos == 1);
     }
 
     #[test]
-    fn test_wildcard_errors() {
-        assert!(Pattern::new("a/**b").unwrap_err().pos == 4);
-        assert!(Pattern::new("a/bc**").unwrap_err().pos == 3);
-        assert!(Pattern::new("a/*****").unwrap_err().pos == 4);
-        assert!(Pattern::new("a/b**c**d").unwrap_err().pos == 2);
-        assert!(Pattern::new("a**b").unwrap_err().pos == 0);
+    fn test_consecutive_wildcards() {
+        let pat = Pattern::new("a/**b").unwrap();
+        assert!(!pat.is_recursive);
+        assert!(pat.matches("a/b"));
+        assert!(pat.matches("a/xb"));
+
+        assert!(Pattern::new("a/bc**").unwrap().matches("a/bcd"));
+        assert!(Pattern::new("a/*****").unwrap().matches("a/b"));
+        assert!(Pattern::new("a/b**c**d").unwrap().matches("a/bxcxd"));
+        assert!(Pattern::new("a**b").unwrap().matches("axxb"));
     }
 
     #[test]
@@ -0,0 +1,1872 @@
+//! The module graph of a project: what each module imports and exports, and how the
+//! modules depend on each other.
+//!
+//! The specifiers are resolved like Node.js and TypeScript do, with [biome_resolver], against
+//! the modules, the `package.json` and the `tsconfig.json` files of the graph. The files of `node_modules` aren't
+//! indexed, so the specifiers of the installed packages, e.g. `react`, point outside the graph.
+//!
+//! The CSS modules, e.g. `button.module.css`, are in the graph too, with the classes that they
+//! declare, so that the classes read by the JavaScript modules can be checked.
+
+use crate::configuration::BoundaryConstraint;
+use crate::settings::ProjectSettings;
+use crate::workspace::TextReplacement;
+use biome_diagnostics::Category;
+use biome_js_syntax::{TextRange, TextSize};
+use biome_project::{PackageJson, PackageTarget};
+use biome_resolver::{
+    resolve_path_aliases, PathAliases, Resolution, Resolver, ResolverHost, TsConfigJson,
+};
+use biome_rowan::{Direction, Language, SyntaxNode};
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::path::{Component, Path, PathBuf};
+
+/// The names that a module imports from another module
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum ImportedNames {
+    /// Any export of the module can be used, e.g. `import * as ns from "./mod"`,
+    /// `export * from "./mod"` or `import("./mod")`
+    All,
+    /// Only these exports are used, e.g. `default` and `a` for `import b, { a } from "./mod"`.
+    ///
+    /// The list is empty for imports that run the module only, e.g. `import "./mod"`
+    Names(Vec<String>),
+}
+
+/// An import, or a re-export, of a module
+#[derive(Debug, Clone)]
+pub(crate) struct ModuleImport {
+    /// The specifier of the imported module, e.g. `./utils`
+    pub(crate) specifier: String,
+    pub(crate) names: ImportedNames,
+    /// `false` for the dynamic imports and the imports of types, which don't run the
+    /// imported module when the importer is loaded
+    pub(crate) is_static: bool,
+    /// `true` for `export * from "./mod"` and `export * as ns from "./mod"`, which re-export
+    /// all the exports of the imported module
+    pub(crate) is_reexport_all: bool,
+    /// The range of the specifier in the importer
+    pub(crate) range: TextRange,
+    /// The specifiers of the static imports and re-exports, `None` when the names can't be
+    /// rewritten, e.g. `import * as ns from "./mod"`
+    pub(crate) bindings: Option<ImportBindings>,
+    /// The classes that the importer reads on the default or namespace binding of an import
+    /// of a CSS module, `None` for the other imports
+    pub(crate) class_accesses: Option<ClassAccesses>,
+}
+
+/// The classes of a CSS module that its importer reads, e.g. `button` for `styles.button`
+/// when the importer has `import styles from "./button.module.css"`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum ClassAccesses {
+    /// The binding is used in a way that can read any class, e.g. `styles[name]`
+    All,
+    /// Only these classes are read, with the range of their name in the importer
+    Names(Vec<(String, TextRange)>),
+}
+
+/// The specifiers of an import, e.g. `a, { b as c }` in `import a, { b as c } from "./mod"`,
+/// or of a re-export, e.g. `{ b as c }` in `export { b as c } from "./mod"`
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ImportBindings {
+    /// The default specifier of an import, e.g. `a`
+    pub(crate) default: Option<ImportSpecifier>,
+    /// The specifiers between the braces, e.g. `b as c`
+    pub(crate) named: Vec<ImportSpecifier>,
+    /// The range of the braces and of the specifiers between them, `None` when there are no
+    /// braces
+    pub(crate) braces_range: Option<TextRange>,
+    /// `true` for the re-exports, `false` for the imports
+    pub(crate) is_reexport: bool,
+}
+
+/// A name imported, or re-exported, from another module
+#[derive(Debug, Clone)]
+pub(crate) struct ImportSpecifier {
+    /// The name exported by the other module, `default` for a default import
+    pub(crate) imported_name: String,
+    /// The name of the binding created by an import, or the name exported by a re-export
+    pub(crate) local_name: String,
+    /// The range of the specifier, e.g. `b as c`
+    pub(crate) range: TextRange,
+    /// The range to remove to take the specifier out of its list, with its separator, e.g.
+    /// `a, ` in `{ a, b }`
+    pub(crate) removal_range: TextRange,
+}
+
+impl ImportBindings {
+    /// Returns the edits of the specifiers when the imported module applies `change`, or
+    /// `None` when they can't be rewritten
+    fn rewrite(&self, change: &ExportChange) -> Option<Vec<TextReplacement>> {
+        let mut edits = vec![];
+        match change {
+            ExportChange::DefaultToNamed(name) => {
+                if let Some(default) = &self.default {
+                    let specifier = specifier_text(name, &default.local_name);
+                    match self.braces_range {
+                        // `a, { b }` becomes `{ name as a, b }`
+                        Some(braces_range) => {
+                            edits.push(TextReplacement::new(default.removal_range, ""));
+                            edits.push(TextReplacement::new(
+                                TextRange::empty(braces_range.start() + TextSize::from(1)),
+                                format!(" {specifier},"),
+                            ));
+                        }
+                        // `a` becomes `{ name as a }`
+                        None => edits.push(TextReplacement::new(
+                            default.range,
+                            format!("{{ {specifier} }}"),
+                        )),
+                    }
+                }
+                for specifier in &self.named {
+                    if specifier.imported_name == "default" {
+                        edits.push(TextReplacement::new(
+                            specifier.range,
+                            specifier_text(name, &specifier.local_name),
+                        ));
+                    }
+                }
+            }
+            ExportChange::NamedToDefault(name) => {
+                let specifiers: Vec<_> = self
+                    .named
+                    .iter()
+                    .filter(|specifier| &specifier.imported_name == name)
+                    .collect();
+                if self.is_reexport {
+                    // `export { name as b } from` becomes `export { default as b } from`
+                    for specifier in specifiers {
+                        edits.push(TextReplacement::new(
+                            specifier.range,
+                            specifier_text("default", &specifier.local_name),
+                        ));
+                    }
+                } else if let Some(specifier) = specifiers.first() {
+                    // An import has a single default specifier
+                    if specifiers.len() > 1 || self.default.is_some() {
+                        return None;
+                    }
+                    let braces_range = self.braces_range?;
+                    if self.named.len() == 1 {
+                        // `{ name as b }` becomes `b`
+                        edits.push(TextReplacement::new(
+                            braces_range,
+                            specifier.local_name.clone(),
+                        ));
+                    } else {
+                        // `{ a, name as b }` becomes `b, { a }`
+                        edits.push(TextReplacement::new(specifier.removal_range, ""));
+                        edits.push(TextReplacement::new(
+                            TextRange::empty(braces_range.start()),
+                            format!("{}, ", specifier.local_name),
+                        ));
+                    }
+                }
+            }
+        }
+        Some(edits)
+    }
+}
+
+/// Returns the text of a specifier that imports `imported_name` as `local_name`
+fn specifier_text(imported_name: &str, local_name: &str) -> String {
+    if imported_name == local_name {
+        imported_name.to_string()
+    } else {
+        format!("{imported_name} as {local_name}")
+    }
+}
+
+/// Collects the paths of the targets of the `exports` of a `package.json`, the patterns with
+/// `*` excluded
+fn collect_target_paths<'a>(target: &'a PackageTarget, paths: &mut Vec<&'a str>) {
+    match target {
+        PackageTarget::Path(path) if !path.contains('*') => paths.push(path),
+        PackageTarget::Path(_) | PackageTarget::Null => {}
+        PackageTarget::Map(entries) => {
+            for (_, target) in entries {
+                collect_target_paths(target, paths);
+            }
+        }
+        PackageTarget::List(targets) => {
+            for target in targets {
+                collect_target_paths(target, paths);
+            }
+        }
+    }
+}
+
+/// A name exported by a module
+#[derive(Debug, Clone)]
+pub(crate) struct ModuleExport {
+    /// The exported name, `default` for the default export
+    pub(crate) name: String,
+    /// The range of the exported name, or of the exported declaration
+    pub(crate) range: TextRange,
+}
+
+/// A static import of a module that re-exports all the exports of other modules, e.g. an
+/// import of a barrel file
+#[derive(Debug)]
+pub(crate) struct BarrelImport<'a> {
+    pub(crate) import: &'a ModuleImport,
+    /// The chain of `export *` re-exports from the imported module to the module that it
+    /// re-exports the farthest
+    pub(crate) chain: Vec<PathBuf>,
+    /// The number of modules of the graph that the import loads, including the imported module
+    pub(crate) loaded_modules: usize,
+}
+
+/// A class that a module reads on the import of a CSS module that doesn't declare it, e.g.
+/// `styles.missing`
+#[derive(Debug)]
+pub(crate) struct UnknownClass<'a> {
+    /// The specifier of the CSS module
+    pub(crate) specifier: &'a str,
+    pub(crate) name: &'a str,
+    /// The range of the name in the module
+    pub(crate) range: TextRange,
+}
+
+/// An import of a module that a constraint of the module boundaries forbids
+#[derive(Debug)]
+pub(crate) struct BoundaryViolation<'a> {
+    pub(crate) import: &'a ModuleImport,
+    pub(crate) constraint: &'a BoundaryConstraint,
+    /// The path of the imported module
+    pub(crate) imported: PathBuf,
+    /// The tags of the imported module
+    pub(crate) imported_tags: Vec<String>,
+}
+
+/// A static import of a client module that loads a server-only module
+#[derive(Debug)]
+pub(crate) struct ServerOnlyImport<'a> {
+    pub(crate) import: &'a ModuleImport,
+    /// The chain of static imports from the imported module to the module that imports
+    /// `server-only`. It's empty when the client module imports `server-only` itself.
+    pub(crate) chain: Vec<PathBuf>,
+}
+
+/// The package that a module imports to forbid its import by the client modules, e.g.
+/// because it reads the secrets of the server
+const SERVER_ONLY_PACKAGE: &str = "server-only";
+
+/// The imports and the exports of a module
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ModuleInfo {
+    pub(crate) imports: Vec<ModuleImport>,
+    pub(crate) exports: Vec<ModuleExport>,
+    /// The directives of the prologue of the module, without the quotes, e.g. `use client`
+    pub(crate) directives: Vec<String>,
+    pub(crate) suppressions: Vec<ProjectSuppression>,
+}
+
+/// The classes that a CSS module declares
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StylesheetInfo {
+    pub(crate) classes: Vec<ModuleExport>,
+    pub(crate) suppressions: Vec<ProjectSuppression>,
+}
+
+/// A suppression comment of a project rule, e.g. `// biome-ignore project/importCycle: <explanation>`
+#[derive(Debug, Clone)]
+pub(crate) struct ProjectSuppression {
+    /// The category of the suppressed diagnostics, e.g. `project/importCycle`
+    pub(crate) category: &'static Category,
+    /// The range of the line that follows the comment. The diagnostics that start in it are
+    /// suppressed.
+    pub(crate) range: TextRange,
+}
+
+/// Returns the suppression comments of the project rules in the file of `root`.
+///
+/// Like the suppression comments of the lint rules, a comment applies to the line that
+/// follows it.
+pub(crate) fn project_suppressions<L: Language>(root: &SyntaxNode<L>) -> Vec<ProjectSuppression> {
+    let mut suppressions = vec![];
+    // The categories of the comments that apply to the next line
+    let mut pending = vec![];
+    // The start of the current line, with the categories suppressed in it
+    let mut current_line: Option<(TextSize, Vec<&'static Category>)> = None;
+    let mut line_end = TextSize::from(0);
+
+    let mut close_line = |line: Option<(TextSize, Vec<&'static Category>)>, end: TextSize| {
+        if let Some((start, categories)) = line {
+            suppressions.extend(categories.into_iter().map(|category| ProjectSuppression {
+                category,
+                range: TextRange::new(start, end),
+            }));
+        }
+    };
+
+    for (index, token) in root.descendants_tokens(Direction::Next).enumerate() {
+        let leading_trivia = token.leading_trivia();
+        let starts_line = index == 0 || leading_trivia.pieces().any(|piece| piece.is_newline());
+        if starts_line {
+            close_line(current_line.take(), line_end);
+        }
+
+        for piece in leading_trivia.pieces() {
+            if let Some(comment) = piece.as_comments() {
+                pending.extend(suppressed_categories(comment.text()));
+            }
+        }
+        if starts_line && !pending.is_empty() {
+            current_line = Some((
+                token.text_trimmed_range().start(),
+                std::mem::take(&mut pending),
+            ));
+        }
+        line_end = token.text_trimmed_range().end();
+
+        // A comment at the end of a line applies to the next line
+        for piece in token.trailing_trivia().pieces() {
+            if let Some(comment) = piece.as_comments() {
+                pending.extend(suppressed_categories(comment.text()));
+            }
+        }
+    }
+    close_line(current_line, line_end);
+
+    suppressions
+}
+
+/// Returns the categories of the project rules suppressed by a comment
+fn suppressed_categories(comment: &str) -> Vec<&'static Category> {
+    if !comment.starts_with("//") && !comment.starts_with("/*") {
+        return vec![];
+    }
+
+    parse_suppression_comment(comment)
+        .flatten()
+        .filter(|suppression| suppression.scope == SuppressionScope::Next)
+        .flat_map(|suppression| suppression.categories)
+        .map(|suppressed| suppressed.category)
+        .filter(|category| category.name().starts_with("project/"))
+        .collect()
+}
+
+impl ModuleInfo {
+    fn has_directive(&self, directive: &str) -> bool {
+        self.directives.iter().any(|text| text == directive)
+    }
+
+    /// Whether the module can only run on the server: it imports the package `server-only`
+    fn is_server_only(&self) -> bool {
+        self.imports
+            .iter()
+            .any(|import| import.is_static && import.specifier == SERVER_ONLY_PACKAGE)
+    }
+}
+
+/// A change of how a module exports one of its bindings
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum ExportChange {
+    /// The default export becomes an export with this name
+    DefaultToNamed(String),
+    /// The export with this name becomes the default export
+    NamedToDefault(String),
+}
+
+/// An export that can be changed, with the edits of the module that exports it
+#[derive(Debug)]
+pub(crate) struct ExportConversion {
+    pub(crate) change: ExportChange,
+    pub(crate) edits: Vec<TextReplacement>,
+}
+
+/// The packages that a module can import, checked by the rule `noUndeclaredDependencies`, and
+/// the versions of Node.js and the browsers that it runs on, checked by the rules
+/// `noUnsupportedNodeBuiltins` and `noUnsupportedBrowserApis`
+#[derive(Debug, Clone, Default, Hash)]
+pub(crate) struct ModulePackages {
+    /// The name of the package of the module and its dependencies, from the closest
+    /// `package.json`. `None` when no `package.json` of the graph applies to the module.
+    pub(crate) declared: Option<Vec<String>>,
+    /// The names of the packages of the project, sorted
+    pub(crate) project: Vec<String>,
+    /// The range of the versions of Node.js of the closest `package.json`, e.g. `>=18`
+    pub(crate) node_engine: Option<String>,
+    /// The `browserslist` queries of the closest `package.json`, e.g. `chrome >= 90`
+    pub(crate) browserslist: Vec<String>,
+}
+
+/// The modules of a project, indexed by path
+#[derive(Debug, Default)]
+pub(crate) struct ModuleGraph {
+    modules: FxHashMap<PathBuf, ModuleInfo>,
+    /// The `package.json` files of the project, used to resolve the subpath imports, e.g.
+    /// `#internal/utils`, and the imports of a package by its own name
+    manifests: FxHashMap<PathBuf, PackageJson>,
+    /// The `tsconfig.json` and `jsconfig.json` files of the project, used to resolve the path
+    /// aliases, e.g. `@app/utils`
+    tsconfigs: FxHashMap<PathBuf, TsConfigJson>,
+    /// The classes of the CSS modules of the project
+    stylesheets: FxHashMap<PathBuf, StylesheetInfo>,
+    resolver: Resolver,
+}
+
+impl ModuleGraph {
+    /// Adds a module to the graph, or replaces the module at the same path
+    pub(crate) fn insert(&mut self, path: PathBuf, info: ModuleInfo) {
+        self.modules.insert(path, info);
+    }
+
+    pub(crate) fn remove(&mut self, path: &Path) {
+        self.modules.remove(path);
+    }
+
+    /// Adds a `package.json` file to the graph, or replaces the file at the same path
+    pub(crate) fn insert_manifest(&mut self, path: PathBuf, manifest: PackageJson) {
+        self.manifests.insert(path, manifest);
+    }
+
+    pub(crate) fn remove_manifest(&mut self, path: &Path) {
+        self.manifests.remove(path);
+    }
+
+    /// Adds a `tsconfig.json` or a `jsconfig.json` file to the graph, or replaces the file at
+    /// the same path
+    pub(crate) fn insert_tsconfig(&mut self, path: PathBuf, tsconfig: TsConfigJson) {
+        self.tsconfigs.insert(path, tsconfig);
+    }
+
+    pub(crate) fn remove_tsconfig(&mut self, path: &Path) {
+        self.tsconfigs.remove(path);
+    }
+
+    /// Adds the classes of a CSS module to the graph, or replaces the classes of the CSS
+    /// module at the same path
+    pub(crate) fn insert_stylesheet(&mut self, path: PathBuf, stylesheet: StylesheetInfo) {
+        self.stylesheets.insert(path, stylesheet);
+    }
+
+    pub(crate) fn remove_stylesheet(&mut self, path: &Path) {
+        self.stylesheets.remove(path);
+    }
+
+    pub(crate) fn is_stylesheet(&self, path: &Path) -> bool {
+        self.stylesheets.contains_key(path)
+    }
+
+    /// Whether a suppression comment of the module, or of the CSS module, at `path` suppresses
+    /// the diagnostics of `category` that start at `offset`
+    pub(crate) fn is_suppressed(&self, path: &Path, category: &Category, offset: TextSize) -> bool {
+        let suppressions = match self.modules.get(path) {
+            Some(info) => &info.suppressions,
+            None => match self.stylesheets.get(path) {
+                Some(stylesheet) => &stylesheet.suppressions,
+                None => return false,
+            },
+        };
+        suppressions.iter().any(|suppression| {
+            suppression.category.name() == category.name()
+                && suppression.range.contains_inclusive(offset)
+        })
+    }
+
+    /// Returns the path aliases of the `tsconfig.json` or `jsconfig.json` file at `path`,
+    /// merged with the files it extends
+    pub(crate) fn path_aliases(&self, path: &Path) -> Option<PathAliases> {
+        resolve_path_aliases(self, path)
+    }
+
+    /// Returns the packages that the module at `path` can import
+    pub(crate) fn module_packages(&self, path: &Path) -> ModulePackages {
+        // The paths of the CLI keep the current directory, e.g. `./src/index.js`
+        let path: PathBuf = path
+            .components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .collect();
+        let manifest = path
+            .ancestors()
+            .skip(1)
+            .find_map(|directory| self.manifests.get(&directory.join("package.json")));
+        let declared = manifest.map(|manifest| {
+            manifest
+                .name
+                .iter()
+                .map(String::as_str)
+                .chain(manifest.dependency_names())
+                .map(str::to_string)
+                .collect()
+        });
+        let node_engine = manifest.and_then(|manifest| manifest.node_engine.clone());
+        let browserslist = manifest
+            .map(|manifest| manifest.browserslist.clone())
+            .unwrap_or_default();
+        let mut project: Vec<_> = self
+            .manifests
+            .values()
+            .filter_map(|manifest| manifest.name.clone())
+            .collect();
+        project.sort_unstable();
+
+        ModulePackages {
+            declared,
+            project,
+            node_engine,
+            browserslist,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub(crate) fn get(&self, path: &Path) -> Option<&ModuleInfo> {
+        self.modules.get(path)
+    }
+
+    /// Returns the path of the module of the graph that `specifier` points to, when it's
+    /// imported by the module at `importer`.
+    ///
+    /// Like TypeScript, an import of `./a.js` can resolve to `./a.ts`.
+    pub(crate) fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        let importer_dir = importer.parent().unwrap_or_else(|| Path::new(""));
+        match self.resolver.resolve(self, importer_dir, specifier) {
+            Ok(Resolution::File(path)) => Some(path),
+            Ok(Resolution::Builtin) | Err(_) => None,
+        }
+    }
+
+    /// Returns the paths that the imports of the module at `path` resolve to
+    pub(crate) fn resolved_imports(&self, path: &Path) -> Vec<PathBuf> {
+        let Some(info) = self.modules.get(path) else {
+            return vec![];
+        };
+        info.imports
+            .iter()
+            .filter_map(|import| self.resolve(path, &import.specifier))
+            .collect()
+    }
+
+    /// Returns `true` if the module at `path`, or a file that it imports directly or not, is
+    /// one of `files`
+    pub(crate) fn depends_on_any(&self, path: &Path, files: &FxHashSet<PathBuf>) -> bool {
+        let mut visited = FxHashSet::from_iter([path.to_path_buf()]);
+        let mut queue = VecDeque::from([path.to_path_buf()]);
+
+        while let Some(importer) = queue.pop_front() {
+            if files.contains(&importer) {
+                return true;
+            }
+            for imported in self.resolved_imports(&importer) {
+                if visited.insert(imported.clone()) {
+                    queue.push_back(imported);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Returns the shortest chain of static imports that starts from the module at `path`
+    /// and comes back to it, without `path` at the end
+    pub(crate) fn find_import_cycle(&self, path: &Path) -> Option<Vec<PathBuf>> {
+        // The module that imports each visited module in the shortest chain
+        let mut importers: FxHashMap<PathBuf, PathBuf> = FxHashMap::default();
+        let mut queue = VecDeque::from([path.to_path_buf()]);
+
+        while let Some(importer) = queue.pop_front() {
+            let Some(info) = self.modules.get(&importer) else {
+                continue;
+            };
+
+            for import in info.imports.iter().filter(|import| import.is_static) {
+                let Some(imported) = self.resolve(&importer, &import.specifier) else {
+                    continue;
+                };
+
+                if imported == path {
+                    let mut cycle = vec![importer.clone()];
+                    while let Some(previous) = cycle.last().and_then(|last| importers.get(last)) {
+                        cycle.push(previous.clone());
+                    }
+                    cycle.reverse();
+                    return Some(cycle);
+                }
+
+                if !importers.contains_key(&imported) {
+                    importers.insert(imported.clone(), importer.clone());
+                    queue.push_back(imported);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the static imports of the module at `path` that point to a module of the graph
+    /// that re-exports all the exports of another module, e.g. `export * from "./button"`.
+    ///
+    /// Importing a single name from such a module loads all the modules of the chain, and
+    /// all the modules that they import.
+    pub(crate) fn find_barrel_imports(&self, path: &Path) -> Vec<BarrelImport> {
+        let Some(info) = self.modules.get(path) else {
+            return vec![];
+        };
+
+        info.imports
+            .iter()
+            .filter(|import| import.is_static)
+            .filter_map(|import| {
+                let imported = self.resolve(path, &import.specifier)?;
+                let chain = self.find_reexport_all_chain(&imported);
+                if chain.len() < 2 {
+                    return None;
+                }
+                Some(BarrelImport {
+                    import,
+                    chain,
+                    loaded_modules: self.count_loaded_modules(&imported),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the chain of `export *` re-exports from the module at `path`, which is first,
+    /// to the module that is re-exported the farthest from it. A module appears once in the
+    /// chain, even when the re-exports have a cycle.
+    fn find_reexport_all_chain(&self, path: &Path) -> Vec<PathBuf> {
+        // The module that re-exports each visited module in the shortest chain, the last
+        // visited module is the farthest
+        let mut reexporters: FxHashMap<PathBuf, PathBuf> = FxHashMap::default();
+        let mut queue = VecDeque::from([path.to_path_buf()]);
+        let mut last = path.to_path_buf();
+
+        while let Some(reexporter) = queue.pop_front() {
+            last = reexporter.clone();
+            let Some(info) = self.modules.get(&reexporter) else {
+                continue;
+            };
+
+            // `export type * from "./mod"` doesn't load the module
+            for import in info
+                .imports
+                .iter()
+                .filter(|import| import.is_static && import.is_reexport_all)
+            {
+                let Some(imported) = self.resolve(&reexporter, &import.specifier) else {
+                    continue;
+                };
+                if imported != path && !reexporters.contains_key(&imported) {
+                    reexporters.insert(imported.clone(), reexporter.clone());
+                    queue.push_back(imported);
+                }
+            }
+        }
+
+        let mut chain = vec![last];
+        while let Some(previous) = chain.last().and_then(|last| reexporters.get(last)) {
+            chain.push(previous.clone());
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Returns the number of modules of the graph that loading the module at `path` loads,
+    /// following the static imports and re-exports
+    fn count_loaded_modules(&self, path: &Path) -> usize {
+        let mut loaded = FxHashSet::from_iter([path.to_path_buf()]);
+        let mut queue = VecDeque::from([path.to_path_buf()]);
+
+        while let Some(importer) = queue.pop_front() {
+            let Some(info) = self.modules.get(&importer) else {
+                continue;
+            };
+
+            for import in info.imports.iter().filter(|import| import.is_static) {
+                if let Some(imported) = self.resolve(&importer, &import.specifier) {
+                    if self.modules.contains_key(&imported) && loaded.insert(imported.clone()) {
+                        queue.push_back(imported);
+                    }
+                }
+            }
+        }
+
+        loaded.len()
+    }
+
+    /// Returns the exports of the module at `path` that no module of the graph imports.
+    ///
+    /// The exports of the entry points of the packages, e.g. the `index.js` of a library, are
+    /// used outside the project, so none is returned for them. The modules that aren't
+    /// imported at all are considered entry points too, e.g. the scripts of the project.
+    pub(crate) fn find_unused_exports(&self, path: &Path) -> Vec<&ModuleExport> {
+        let Some(info) = self.modules.get(path) else {
+            return vec![];
+        };
+        if self.is_package_entry_point(path) {
+            return vec![];
+        }
+
+        let mut is_imported = false;
+        let mut used_names = FxHashSet::default();
+        for (importer, importer_info) in &self.modules {
+            for import in &importer_info.imports {
+                if self.resolve(importer, &import.specifier).as_deref() != Some(path) {
+                    continue;
+                }
+                is_imported = true;
+                match &import.names {
+                    ImportedNames::All => return vec![],
+                    ImportedNames::Names(names) => used_names.extend(names.iter()),
+                }
+            }
+        }
+
+        if !is_imported {
+            return vec![];
+        }
+
+        info.exports
+            .iter()
+            .filter(|export| !used_names.contains(&export.name))
+            .collect()
+    }
+
+    /// Whether a `package.json` of the graph makes the module at `path` an entry point of its
+    /// package, with the fields `main`, `types` or `exports`
+    fn is_package_entry_point(&self, path: &Path) -> bool {
+        self.manifests.iter().any(|(manifest_path, manifest)| {
+            let mut targets = vec![];
+            targets.extend(manifest.main.as_deref());
+            targets.extend(manifest.types.as_deref());
+            if let Some(exports) = &manifest.exports {
+                collect_target_paths(exports, &mut targets);
+            }
+            // Like an import of the package by its own name, `./dist/index.js` can resolve
+            // to `./dist/index.ts`
+            targets.into_iter().any(|target| {
+                let specifier = if target.starts_with("./") || target.starts_with("../") {
+                    Cow::Borrowed(target)
+                } else {
+                    Cow::Owned(format!("./{target}"))
+                };
+                self.resolve(manifest_path, &specifier).as_deref() == Some(path)
+            })
+        })
+    }
+
+    /// Returns the classes of the CSS module at `path` that none of the modules importing it
+    /// reads.
+    ///
+    /// Like the unused exports, nothing is returned when no module imports the CSS module, or
+    /// when a module can read any of its classes, e.g. `styles[name]`.
+    pub(crate) fn find_unused_classes(&self, path: &Path) -> Vec<&ModuleExport> {
+        let Some(stylesheet) = self.stylesheets.get(path) else {
+            return vec![];
+        };
+
+        let mut is_imported = false;
+        let mut used_names = FxHashSet::default();
+        for (importer, importer_info) in &self.modules {
+            for import in &importer_info.imports {
+                if self.resolve(importer, &import.specifier).as_deref() != Some(path) {
+                    continue;
+                }
+                is_imported = true;
+                match &import.class_accesses {
+                    Some(ClassAccesses::Names(names)) => {
+                        used_names.extend(names.iter().map(|(name, _)| name));
+                    }
+                    // The other imports, e.g. `export { default } from "./button.module.css"`,
+                    // can read any class
+                    Some(ClassAccesses::All) | None => return vec![],
+                }
+            }
+        }
+
+        if !is_imported {
+            return vec![];
+        }
+
+        stylesheet
+            .classes
+            .iter()
+            .filter(|class| !used_names.contains(&class.name))
+            .collect()
+    }
+
+    /// Returns the classes that the module at `path` reads on the imports of the CSS modules
+    /// of the graph, but that the CSS modules don't declare
+    pub(crate) fn find_unknown_classes(&self, path: &Path) -> Vec<UnknownClass> {
+        let Some(info) = self.modules.get(path) else {
+            return vec![];
+        };
+
+        let mut unknown = vec![];
+        for import in &info.imports {
+            let Some(ClassAccesses::Names(names)) = &import.class_accesses else {
+                continue;
+            };
+            let Some(stylesheet) = self
+                .resolve(path, &import.specifier)
+                .and_then(|imported| self.stylesheets.get(&imported))
+            else {
+                continue;
+            };
+            unknown.extend(
+                names
+                    .iter()
+                    .filter(|(name, _)| !stylesheet.classes.iter().any(|class| &class.name == name))
+                    .map(|(name, range)| UnknownClass {
+                        specifier: &import.specifier,
+                        name,
+                        range: *range,
+                    }),
+            );
+        }
+        unknown
+    }
+
+    /// Returns the imports of the module at `path` that break a constraint of the module
+    /// boundaries: the imported module has a tag that the constraint forbids, or none of the
+    /// tags that it allows.
+    ///
+    /// The constraints apply to the modules that have their source tag. The imports that point
+    /// outside the graph, e.g. the installed packages, aren't checked.
+    pub(crate) fn find_boundary_violations<'a>(
+        &'a self,
+        path: &Path,
+        project: &'a ProjectSettings,
+    ) -> Vec<BoundaryViolation<'a>> {
+        let Some(info) = self.modules.get(path) else {
+            return vec![];
+        };
+        let tags = project.module_tags(path);
+        let constraints: Vec<_> = project
+            .boundaries
+            .iter()
+            .filter(|constraint| tags.contains(constraint.source_tag.as_str()))
+            .collect();
+        if constraints.is_empty() {
+            return vec![];
+        }
+
+        info.imports
+            .iter()
+            .filter_map(|import| {
+                let imported = self.resolve(path, &import.specifier)?;
+                let imported_tags = project.module_tags(&imported);
+                let constraint = constraints
+                    .iter()
+                    .copied()
+                    .find(|constraint| !constraint.allows(&imported_tags))?;
+                Some(BoundaryViolation {
+                    import,
+                    constraint,
+                    imported_tags: imported_tags.iter().map(ToString::to_string).collect(),
+                    imported,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the static imports of the module at `path` that load a server-only module,
+    /// when it's a client module: its prologue has the directive `use client`.
+    ///
+    /// A server-only module imports the package `server-only`. It's loaded by the client
+    /// module when it's imported, directly or through other static imports. The modules with
+    /// the directive `use server` aren't followed: the client module only loads references to
+    /// their functions.
+    pub(crate) fn find_server_only_imports(&self, path: &Path) -> Vec<ServerOnlyImport> {
+        let Some(info) = self.modules.get(path) else {
+            return vec![];
+        };
+        if !info.has_directive("use client") {
+            return vec![];
+        }
+
+        info.imports
+            .iter()
+            .filter(|import| import.is_static)
+            .filter_map(|import| {
+                if import.specifier == SERVER_ONLY_PACKAGE {
+                    return Some(ServerOnlyImport {
+                        import,
+                        chain: vec![],
+                    });
+                }
+                let imported = self.resolve(path, &import.specifier)?;
+                let chain = self.find_server_only_chain(&imported)?;
+                Some(ServerOnlyImport { import, chain })
+            })
+            .collect()
+    }
+
+    /// Returns the shortest chain of static imports from the module at `path`, which is
+    /// first, to a server-only module, which is last
+    fn find_server_only_chain(&self, path: &Path) -> Option<Vec<PathBuf>> {
+        // The module that imports each visited module in the shortest chain
+        let mut importers: FxHashMap<PathBuf, PathBuf> = FxHashMap::default();
+        let mut queue = VecDeque::from([path.to_path_buf()]);
+
+        while let Some(importer) = queue.pop_front() {
+            let Some(info) = self.modules.get(&importer) else {
+                continue;
+            };
+            if info.has_directive("use server") {
+                continue;
+            }
+            if info.is_server_only() {
+                let mut chain = vec![importer];
+                while let Some(previous) = chain.last().and_then(|last| importers.get(last)) {
+                    chain.push(previous.clone());
+                }
+                chain.reverse();
+                return Some(chain);
+            }
+
+            for import in info.imports.iter().filter(|import| import.is_static) {
+                let Some(imported) = self.resolve(&importer, &import.specifier) else {
+                    continue;
+                };
+                if imported != path && !importers.contains_key(&imported) {
+                    importers.insert(imported.clone(), importer.clone());
+                    queue.push_back(imported);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the edits of the modules that import, or re-export, the module at `path`
+    /// when it applies `change`, sorted by path.
+    ///
+    /// Returns `None` when the change breaks the project: the module already exports the new
+    /// name, or a module uses all its exports, e.g. `import * as ns from "./mod"`, so its
+    /// usages of the name can't be found.
+    pub(crate) fn export_change_edits(
+        &self,
+        path: &Path,
+        change: &ExportChange,
+    ) -> Option<Vec<(PathBuf, Vec<TextReplacement>)>> {
+        let info = self.modules.get(path)?;
+        let (old_name, new_name) = match change {
+            ExportChange::DefaultToNamed(name) => ("default", name.as_str()),
+            ExportChange::NamedToDefault(name) => (name.as_str(), "default"),
+        };
+        if info.exports.iter().any(|export| export.name == new_name) {
+            return None;
+        }
+
+        let mut edits = vec![];
+        for (importer, importer_info) in &self.modules {
+            let mut importer_edits = vec![];
+            for import in &importer_info.imports {
+                if self.resolve(importer, &import.specifier).as_deref() != Some(path) {
+                    continue;
+                }
+                match &import.names {
+                    ImportedNames::All => return None,
+                    ImportedNames::Names(names) if names.iter().any(|name| name == old_name) => {
+                        importer_edits.extend(import.bindings.as_ref()?.rewrite(change)?);
+                    }
+                    ImportedNames::Names(_) => {}
+                }
+            }
+            if !importer_edits.is_empty() {
+                edits.push((importer.clone(), importer_edits));
+            }
+        }
+
+        edits.sort_by(|(first, _), (second, _)| first.cmp(second));
+        Some(edits)
+    }
+}
+
+impl ResolverHost for ModuleGraph {
+    fn is_file(&self, path: &Path) -> bool {
+        self.modules.contains_key(path) || self.stylesheets.contains_key(path)
+    }
+
+    fn package_json(&self, path: &Path) -> Option<&PackageJson> {
+        self.manifests.get(path)
+    }
+
+    fn tsconfig_json(&self, path: &Path) -> Option<&TsConfigJson> {
+        self.tsconfigs.get(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configuration::{ModuleTags, ProjectConfiguration};
+    use biome_deserialize::StringSet;
+
+    fn import(specifier: &str, names: ImportedNames) -> ModuleImport {
+        ModuleImport {
+            specifier: specifier.to_string(),
+            names,
+            is_static: true,
+            is_reexport_all: false,
+            range: TextRange::default(),
+            bindings: None,
+            class_accesses: None,
+        }
+    }
+
+    fn reexport_all(specifier: &str) -> ModuleImport {
+        ModuleImport {
+            is_reexport_all: true,
+            ..import(specifier, ImportedNames::All)
+        }
+    }
+
+    /// Returns an import of a CSS module that reads `classes` on its default binding
+    fn class_import(specifier: &str, classes: &[&str]) -> ModuleImport {
+        ModuleImport {
+            class_accesses: Some(ClassAccesses::Names(
+                classes
+                    .iter()
+                    .map(|class| (class.to_string(), TextRange::default()))
+                    .collect(),
+            )),
+            ..import(specifier, names(&["default"]))
+        }
+    }
+
+    fn export(name: &str) -> ModuleExport {
+        ModuleExport {
+            name: name.to_string(),
+            range: TextRange::default(),
+        }
+    }
+
+    fn stylesheet(classes: &[&str]) -> StylesheetInfo {
+        StylesheetInfo {
+            classes: classes.iter().map(|class| export(class)).collect(),
+            suppressions: vec![],
+        }
+    }
+
+    fn names(names: &[&str]) -> ImportedNames {
+        ImportedNames::Names(names.iter().map(|name| name.to_string()).collect())
+    }
+
+    /// Returns the range of the first occurrence of `needle` in `text`
+    fn range_of(text: &str, needle: &str) -> TextRange {
+        let start = text.find(needle).unwrap();
+        TextRange::at(
+            TextSize::from(start as u32),
+            TextSize::from(needle.len() as u32),
+        )
+    }
+
+    fn specifier(
+        text: &str,
+        (imported_name, local_name): (&str, &str),
+        range: &str,
+        removal_range: &str,
+    ) -> ImportSpecifier {
+        ImportSpecifier {
+            imported_name: imported_name.to_string(),
+            local_name: local_name.to_string(),
+            range: range_of(text, range),
+            removal_range: range_of(text, removal_range),
+        }
+    }
+
+    fn import_with_bindings(names: ImportedNames, bindings: ImportBindings) -> ModuleImport {
+        ModuleImport {
+            bindings: Some(bindings),
+            ..import("./a", names)
+        }
+    }
+
+    /// Applies the edits of `path` to `text`
+    fn apply(edits: &[(PathBuf, Vec<TextReplacement>)], path: &str, text: &str) -> String {
+        let (_, replacements) = edits
+            .iter()
+            .find(|(edited, _)| edited == Path::new(path))
+            .unwrap();
+        let mut replacements = replacements.clone();
+        replacements.sort_by_key(|replacement| replacement.range.start());
+        let mut text = text.to_string();
+        for replacement in replacements.iter().rev() {
+            text.replace_range(
+                std::ops::Range::<usize>::from(replacement.range),
+                &replacement.text,
+            );
+        }
+        text
+    }
+
+    #[test]
+    fn resolves_relative_specifiers() {
+        let mut graph = ModuleGraph::default();
+        graph.insert("src/a.ts".into(), ModuleInfo::default());
+        graph.insert("src/utils/index.js".into(), ModuleInfo::default());
+
+        let importer = Path::new("src/b.ts");
+        assert_eq!(graph.resolve(importer, "./a"), Some("src/a.ts".into()));
+        assert_eq!(graph.resolve(importer, "./a.js"), Some("src/a.ts".into()));
+        assert_eq!(
+            graph.resolve(importer, "./utils"),
+            Some("src/utils/index.js".into())
+        );
+        assert_eq!(
+            graph.resolve(Path::new("src/utils/index.js"), "../a"),
+            Some("src/a.ts".into())
+        );
+        assert_eq!(graph.resolve(importer, "a"), None);
+        assert_eq!(graph.resolve(importer, "./missing"), None);
+    }
+
+    #[test]
+    fn resolves_the_packages_of_the_project() {
+        let mut graph = ModuleGraph::default();
+        graph.insert("src/internal/a.ts".into(), ModuleInfo::default());
+        graph.insert("packages/lib/src/index.ts".into(), ModuleInfo::default());
+        graph.insert_manifest(
+            "package.json".into(),
+            PackageJson::parse(r##"{ "imports": { "#internal/*": "./src/internal/*.js" } }"##)
+                .unwrap(),
+        );
+        graph.insert_manifest(
+            "packages/lib/package.json".into(),
+            PackageJson::parse(r#"{ "name": "lib", "exports": "./src/index.js" }"#).unwrap(),
+        );
+
+        assert_eq!(
+            graph.resolve(Path::new("src/b.ts"), "#internal/a"),
+            Some("src/internal/a.ts".into())
+        );
+        assert_eq!(
+            graph.resolve(Path::new("packages/lib/src/b.ts"), "lib"),
+            Some("packages/lib/src/index.ts".into())
+        );
+        assert_eq!(graph.resolve(Path::new("src/b.ts"), "node:fs"), None);
+    }
+
+    #[test]
+    fn finds_the_packages_of_the_modules() {
+        let mut graph = ModuleGraph::default();
+        graph.insert_manifest(
+            "package.json".into(),
+            PackageJson::parse(
+                r#"{
+                    "name": "app",
+                    "dependencies": { "react": "^18.0.0" },
+                    "engines": { "node": ">=18" },
+                    "browserslist": ["chrome >= 90"]
+                }"#,
+            )
+            .unwrap(),
+        );
+        graph.insert_manifest(
+            "packages/lib/package.json".into(),
+            PackageJson::parse(r#"{ "name": "@app/lib", "devDependencies": { "vitest": "1" } }"#)
+                .unwrap(),
+        );
+
+        let packages = graph.module_packages(Path::new("src/index.ts"));
+        assert_eq!(
+            packages.declared,
+            Some(vec!["app".to_string(), "react".to_string()])
+        );
+        assert_eq!(packages.project, ["@app/lib", "app"]);
+        assert_eq!(packages.node_engine.as_deref(), Some(">=18"));
+        assert_eq!(packages.browserslist, ["chrome >= 90"]);
+        let packages = graph.module_packages(Path::new("./packages/lib/src/index.ts"));
+        assert_eq!(
+            packages.declared,
+            Some(vec!["@app/lib".to_string(), "vitest".to_string()])
+        );
+
+        graph.remove_manifest(Path::new("package.json"));
+        assert_eq!(graph.module_packages(Path::new("index.ts")).declared, None);
+    }
+
+    #[test]
+    fn resolves_the_path_aliases() {
+        let mut graph = ModuleGraph::default();
+        graph.insert("src/app/button.ts".into(), ModuleInfo::default());
+        graph.insert_tsconfig(
+            "tsconfig.base.json".into(),
+            TsConfigJson::parse(
+                r#"{ "compilerOptions": { "paths": { "@app/*": ["./src/app/*"] } } }"#,
+            )
+            .unwrap(),
+        );
+        graph.insert_tsconfig(
+            "tsconfig.json".into(),
+            TsConfigJson::parse(r#"{ "extends": "./tsconfig.base" }"#).unwrap(),
+        );
+
+        assert_eq!(
+            graph.resolve(Path::new("src/index.ts"), "@app/button"),
+            Some("src/app/button.ts".into())
+        );
+
+        graph.remove_tsconfig(Path::new("tsconfig.json"));
+        assert_eq!(
+            graph.resolve(Path::new("src/index.ts"), "@app/button"),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_the_shortest_import_cycle() {
+        let mut graph = ModuleGraph::default();
+        graph.insert(
+            "a.js".into(),
+            ModuleInfo {
+                imports: vec![import("./b", names(&["b"])), import("./c", names(&["c"]))],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "b.js".into(),
+            ModuleInfo {
+                imports: vec![import("./c", names(&["c"]))],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "c.js".into(),
+            ModuleInfo {
+                imports: vec![import("./a", names(&["a"]))],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+
+        assert_eq!(
+            graph.find_import_cycle(Path::new("a.js")),
+            Some(vec!["a.js".into(), "c.js".into()])
+        );
+        assert_eq!(
+            graph.find_import_cycle(Path::new("b.js")),
+            Some(vec!["b.js".into(), "c.js".into(), "a.js".into()])
+        );
+    }
+
+    #[test]
+    fn finds_the_imports_of_the_barrel_files() {
+        let mut graph = ModuleGraph::default();
+        graph.insert(
+            "app.js".into(),
+            ModuleInfo {
+                imports: vec![
+                    import("./components", names(&["Button"])),
+                    import("./utils", names(&["noop"])),
+                ],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "components/index.js".into(),
+            ModuleInfo {
+                imports: vec![reexport_all("./button"), reexport_all("./forms")],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "components/button.js".into(),
+            ModuleInfo {
+                imports: vec![import("../utils", names(&["noop"]))],
+                exports: vec![export("Button")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "components/forms/index.js".into(),
+            ModuleInfo {
+                imports: vec![reexport_all("./input"), reexport_all("../index")],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "components/forms/input.js".into(),
+            ModuleInfo {
+                imports: vec![],
+                exports: vec![export("Input")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "utils.js".into(),
+            ModuleInfo {
+                imports: vec![],
+                exports: vec![export("noop")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+
+        let imports = graph.find_barrel_imports(Path::new("app.js"));
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].import.specifier, "./components");
+        assert_eq!(
+            imports[0].chain,
+            [
+                PathBuf::from("components/index.js"),
+                "components/forms/index.js".into(),
+                "components/forms/input.js".into(),
+            ]
+        );
+        assert_eq!(imports[0].loaded_modules, 5);
+        // `utils.js` doesn't re-export other modules
+        assert!(graph
+            .find_barrel_imports(Path::new("components/button.js"))
+            .is_empty());
+    }
+
+    #[test]
+    fn finds_the_unused_exports() {
+        let mut graph = ModuleGraph::default();
+        graph.insert(
+            "a.js".into(),
+            ModuleInfo {
+                imports: vec![],
+                exports: vec![export("used"), export("unused"), export("default")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "b.js".into(),
+            ModuleInfo {
+                imports: vec![import("./a", names(&["used", "default"]))],
+                exports: vec![export("b")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+
+        let unused: Vec<_> = graph
+            .find_unused_exports(Path::new("a.js"))
+            .into_iter()
+            .map(|export| export.name.as_str())
+            .collect();
+        assert_eq!(unused, ["unused"]);
+        // No module imports `b.js`, it's an entry point
+        assert!(graph.find_unused_exports(Path::new("b.js")).is_empty());
+
+        graph.insert(
+            "c.js".into(),
+            ModuleInfo {
+                imports: vec![import("./a.js", ImportedNames::All)],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        assert!(graph.find_unused_exports(Path::new("a.js")).is_empty());
+    }
+
+    #[test]
+    fn doesnt_report_the_exports_of_the_entry_points_of_the_packages() {
+        let mut graph = ModuleGraph::default();
+        graph.insert(
+            "lib/src/index.ts".into(),
+            ModuleInfo {
+                exports: vec![export("api")],
+                ..ModuleInfo::default()
+            },
+        );
+        graph.insert(
+            "lib/src/utils.ts".into(),
+            ModuleInfo {
+                exports: vec![export("helper")],
+                ..ModuleInfo::default()
+            },
+        );
+        // The tests of the library import its entry point
+        graph.insert(
+            "lib/tests/index.test.ts".into(),
+            ModuleInfo {
+                imports: vec![
+                    import("../src/index.js", names(&[])),
+                    import("../src/utils.js", names(&[])),
+                ],
+                ..ModuleInfo::default()
+            },
+        );
+        graph.insert_manifest(
+            "lib/package.json".into(),
+            PackageJson::parse(
+                r#"{ "name": "lib", "exports": { ".": { "import": "./src/index.js" } } }"#,
+            )
+            .unwrap(),
+        );
+
+        assert!(graph
+            .find_unused_exports(Path::new("lib/src/index.ts"))
+            .is_empty());
+        let unused: Vec<_> = graph
+            .find_unused_exports(Path::new("lib/src/utils.ts"))
+            .into_iter()
+            .map(|export| export.name.as_str())
+            .collect();
+        assert_eq!(unused, ["helper"]);
+    }
+
+    #[test]
+    fn finds_the_suppression_comments_of_the_project_rules() {
+        let text = "import { a } from \"./a\";\n// biome-ignore project/importCycle: the cycle is lazy\nimport { b } from \"./b\";\nimport { c } from \"./c\"; // biome-ignore project/barrelImport: explanation\nimport { d } from \"./d\";\n";
+        let parse =
+            biome_js_parser::parse_module(text, biome_js_parser::JsParserOptions::default());
+        let suppressions = project_suppressions(&parse.syntax());
+
+        let suppressed: Vec<_> = suppressions
+            .iter()
+            .map(|suppression| {
+                (
+                    suppression.category.name(),
+                    &text[usize::from(suppression.range.start())
+                        ..usize::from(suppression.range.end())],
+                )
+            })
+            .collect();
+        assert_eq!(
+            suppressed,
+            [
+                ("project/importCycle", "import { b } from \"./b\";"),
+                ("project/barrelImport", "import { d } from \"./d\";"),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_the_unused_and_the_unknown_classes() {
+        let mut graph = ModuleGraph::default();
+        graph.insert_stylesheet(
+            "components/button.module.css".into(),
+            stylesheet(&["button", "primary", "unused"]),
+        );
+        graph.insert_stylesheet("components/card.module.css".into(), stylesheet(&["card"]));
+        graph.insert(
+            "components/button.js".into(),
+            ModuleInfo {
+                imports: vec![class_import("./button.module.css", &["button", "missing"])],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "components/primary-button.js".into(),
+            ModuleInfo {
+                imports: vec![class_import("./button.module.css", &["primary"])],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+
+        let unused: Vec<_> = graph
+            .find_unused_classes(Path::new("components/button.module.css"))
+            .into_iter()
+            .map(|class| class.name.as_str())
+            .collect();
+        assert_eq!(unused, ["unused"]);
+        // No module imports `card.module.css`, its classes may be used elsewhere
+        assert!(graph
+            .find_unused_classes(Path::new("components/card.module.css"))
+            .is_empty());
+
+        let unknown = graph.find_unknown_classes(Path::new("components/button.js"));
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].specifier, "./button.module.css");
+        assert_eq!(unknown[0].name, "missing");
+
+        graph.insert(
+            "components/dynamic-button.js".into(),
+            ModuleInfo {
+                imports: vec![ModuleImport {
+                    class_accesses: Some(ClassAccesses::All),
+                    ..import("./button.module.css", names(&["default"]))
+                }],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        assert!(graph
+            .find_unused_classes(Path::new("components/button.module.css"))
+            .is_empty());
+    }
+
+    #[test]
+    fn finds_the_imports_that_break_the_module_boundaries() {
+        let string_set =
+            |items: &[&str]| StringSet::new(items.iter().map(|item| item.to_string()).collect());
+        let module_tags = |include: &str, tags: &[&str]| ModuleTags {
+            include: string_set(&[include]),
+            tags: string_set(tags),
+        };
+        let project = ProjectSettings::try_from(ProjectConfiguration {
+            tags: Some(vec![
+                module_tags("ui", &["scope:ui"]),
+                module_tags("server", &["scope:server"]),
+                module_tags("shared", &["scope:shared"]),
+            ]),
+            boundaries: Some(vec![
+                BoundaryConstraint {
+                    source_tag: "scope:ui".into(),
+                    only_depend_on_tags: None,
+                    not_depend_on_tags: Some(string_set(&["scope:server"])),
+                },
+                BoundaryConstraint {
+                    source_tag: "scope:shared".into(),
+                    only_depend_on_tags: Some(string_set(&["scope:shared"])),
+                    not_depend_on_tags: None,
+                },
+            ]),
+            rules: None,
+        })
+        .unwrap();
+
+        let mut graph = ModuleGraph::default();
+        graph.insert(
+            "ui/button.js".into(),
+            ModuleInfo {
+                imports: vec![
+                    import("../server/db", names(&["query"])),
+                    import("../shared/format", names(&["format"])),
+                    import("react", names(&["useState"])),
+                ],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "shared/format.js".into(),
+            ModuleInfo {
+                imports: vec![
+                    import("./locale", names(&["locale"])),
+                    import("../ui/button", names(&["Button"])),
+                ],
+                exports: vec![export("format")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert("shared/locale.js".into(), ModuleInfo::default());
+        graph.insert("server/db.js".into(), ModuleInfo::default());
+
+        let violations = graph.find_boundary_violations(Path::new("ui/button.js"), &project);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].import.specifier, "../server/db");
+        assert_eq!(violations[0].constraint.source_tag, "scope:ui");
+        assert_eq!(violations[0].imported_tags, ["scope:server"]);
+
+        let violations = graph.find_boundary_violations(Path::new("shared/format.js"), &project);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].import.specifier, "../ui/button");
+        assert_eq!(violations[0].constraint.source_tag, "scope:shared");
+
+        // The modules without tags have no constraints
+        assert!(graph
+            .find_boundary_violations(Path::new("server/db.js"), &project)
+            .is_empty());
+    }
+
+    #[test]
+    fn finds_the_server_only_imports_of_the_client_modules() {
+        let directives = |directives: &[&str]| {
+            directives
+                .iter()
+                .map(|directive| directive.to_string())
+                .collect()
+        };
+        let mut graph = ModuleGraph::default();
+        graph.insert(
+            "app/counter.js".into(),
+            ModuleInfo {
+                imports: vec![
+                    import("./format", names(&["format"])),
+                    import("./actions", names(&["save"])),
+                    import("../lib/secrets", names(&["token"])),
+                    import("react", names(&["useState"])),
+                ],
+                exports: vec![export("default")],
+                directives: directives(&["use client"]),
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "app/format.js".into(),
+            ModuleInfo {
+                imports: vec![import("../lib/db", names(&["query"]))],
+                exports: vec![export("format")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        // The client module only loads references to the server functions
+        graph.insert(
+            "app/actions.js".into(),
+            ModuleInfo {
+                imports: vec![import("../lib/db", names(&["query"]))],
+                exports: vec![export("save")],
+                directives: directives(&["use server"]),
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "lib/db.js".into(),
+            ModuleInfo {
+                imports: vec![import("server-only", names(&[]))],
+                exports: vec![export("query")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "lib/secrets.js".into(),
+            ModuleInfo {
+                imports: vec![ModuleImport {
+                    is_static: false,
+                    ..import("server-only", names(&[]))
+                }],
+                exports: vec![export("token")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        graph.insert(
+            "app/page.js".into(),
+            ModuleInfo {
+                imports: vec![
+                    import("server-only", names(&[])),
+                    import("./format", names(&["format"])),
+                ],
+                exports: vec![export("default")],
+                directives: directives(&["use strict", "use client"]),
+                suppressions: vec![],
+            },
+        );
+
+        let imports = graph.find_server_only_imports(Path::new("app/counter.js"));
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].import.specifier, "./format");
+        assert_eq!(
+            imports[0].chain,
+            [PathBuf::from("app/format.js"), PathBuf::from("lib/db.js")]
+        );
+
+        let imports = graph.find_server_only_imports(Path::new("app/page.js"));
+        assert_eq!(imports.len(), 2);
+        assert_eq!(imports[0].import.specifier, "server-only");
+        assert!(imports[0].chain.is_empty());
+
+        // The server modules can import the server-only modules
+        assert!(graph
+            .find_server_only_imports(Path::new("app/format.js"))
+            .is_empty());
+    }
+
+    #[test]
+    fn converts_the_default_export_to_a_named_export() {
+        let mut graph = ModuleGraph::default();
+        graph.insert(
+            "a.js".into(),
+            ModuleInfo {
+                imports: vec![],
+                exports: vec![export("default")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+
+        let b = r#"import greet, { other } from "./a";"#;
+        graph.insert(
+            "b.js".into(),
+            ModuleInfo {
+                imports: vec![import_with_bindings(
+                    names(&["other", "default"]),
+                    ImportBindings {
+                        default: Some(specifier(b, ("default", "greet"), "greet", "greet, ")),
+                        named: vec![specifier(b, ("other", "other"), "other", "other")],
+                        braces_range: Some(range_of(b, "{ other }")),
+                        is_reexport: false,
+                    },
+                )],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        let c = r#"export { default as hello } from "./a";"#;
+        graph.insert(
+            "c.js".into(),
+            ModuleInfo {
+                imports: vec![import_with_bindings(
+                    names(&["default"]),
+                    ImportBindings {
+                        named: vec![specifier(
+                            c,
+                            ("default", "hello"),
+                            "default as hello",
+                            "default as hello",
+                        )],
+                        braces_range: Some(range_of(c, "{ default as hello }")),
+                        is_reexport: true,
+                        ..ImportBindings::default()
+                    },
+                )],
+                exports: vec![export("hello")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        let d = r#"import greet from "./a";"#;
+        graph.insert(
+            "d.js".into(),
+            ModuleInfo {
+                imports: vec![import_with_bindings(
+                    names(&["default"]),
+                    ImportBindings {
+                        default: Some(specifier(d, ("default", "greet"), "greet", "greet")),
+                        ..ImportBindings::default()
+                    },
+                )],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+
+        let change = ExportChange::DefaultToNamed("greet".to_string());
+        let edits = graph
+            .export_change_edits(Path::new("a.js"), &change)
+            .unwrap();
+        assert_eq!(edits.len(), 3);
+        assert_eq!(
+            apply(&edits, "b.js", b),
+            r#"import { greet, other } from "./a";"#
+        );
+        assert_eq!(
+            apply(&edits, "c.js", c),
+            r#"export { greet as hello } from "./a";"#
+        );
+        assert_eq!(apply(&edits, "d.js", d), r#"import { greet } from "./a";"#);
+
+        // The usages of `ns.default` can't be found
+        graph.insert(
+            "e.js".into(),
+            ModuleInfo {
+                imports: vec![import("./a", ImportedNames::All)],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        assert!(graph
+            .export_change_edits(Path::new("a.js"), &change)
+            .is_none());
+    }
+
+    #[test]
+    fn converts_a_named_export_to_the_default_export() {
+        let mut graph = ModuleGraph::default();
+        graph.insert(
+            "a.js".into(),
+            ModuleInfo {
+                imports: vec![],
+                exports: vec![export("greet"), export("other")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+
+        let b = r#"import { other, greet as hi } from "./a";"#;
+        graph.insert(
+            "b.js".into(),
+            ModuleInfo {
+                imports: vec![import_with_bindings(
+                    names(&["other", "greet"]),
+                    ImportBindings {
+                        named: vec![
+                            specifier(b, ("other", "other"), "other", "other, "),
+                            specifier(b, ("greet", "hi"), "greet as hi", ", greet as hi"),
+                        ],
+                        braces_range: Some(range_of(b, "{ other, greet as hi }")),
+                        ..ImportBindings::default()
+                    },
+                )],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        let c = r#"import { greet } from "./a";"#;
+        graph.insert(
+            "c.js".into(),
+            ModuleInfo {
+                imports: vec![import_with_bindings(
+                    names(&["greet"]),
+                    ImportBindings {
+                        named: vec![specifier(c, ("greet", "greet"), "greet", "greet")],
+                        braces_range: Some(range_of(c, "{ greet }")),
+                        ..ImportBindings::default()
+                    },
+                )],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        // Doesn't import `greet`
+        graph.insert(
+            "d.js".into(),
+            ModuleInfo {
+                imports: vec![import("./a", names(&["other"]))],
+                exports: vec![],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+
+        let change = ExportChange::NamedToDefault("greet".to_string());
+        let edits = graph
+            .export_change_edits(Path::new("a.js"), &change)
+            .unwrap();
+        assert_eq!(edits.len(), 2);
+        assert_eq!(
+            apply(&edits, "b.js", b),
+            r#"import hi, { other } from "./a";"#
+        );
+        assert_eq!(apply(&edits, "c.js", c), r#"import greet from "./a";"#);
+
+        // The module already has a default export
+        graph.insert(
+            "a.js".into(),
+            ModuleInfo {
+                imports: vec![],
+                exports: vec![export("greet"), export("default")],
+                directives: vec![],
+                suppressions: vec![],
+            },
+        );
+        assert!(graph
+            .export_change_edits(Path::new("a.js"), &change)
+            .is_none());
+    }
+}
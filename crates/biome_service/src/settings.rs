@@ -1,4 +1,8 @@
-use crate::configuration::{push_to_analyzer_rules, JavascriptConfiguration, JsonConfiguration};
+use crate::configuration::{
+    push_to_analyzer_rules, BoundaryConstraint, JavascriptConfiguration, JsonConfiguration,
+    Overrides, ProjectRules,
+};
+use crate::workspace::EditorConfigOverrides;
 use crate::{
     configuration::FilesConfiguration, Configuration, ConfigurationDiagnostic, MatchOptions,
     Matcher, MergeWith, Rules, WorkspaceError,
@@ -17,7 +21,7 @@ use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
 use indexmap::IndexSet;
 use std::ops::{BitOr, Sub};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::{
     num::NonZeroU64,
     sync::{RwLock, RwLockReadGuard},
@@ -38,6 +42,11 @@ pub struct WorkspaceSettings {
     pub organize_imports: OrganizeImportsSettings,
     /// overrides
     pub override_settings: OverrideSettings,
+    /// The patterns of the path aliases of the project, e.g. `@app/*`, from the `paths` option
+    /// of its `tsconfig.json`
+    pub path_aliases: Vec<String>,
+    /// The settings of the project analysis
+    pub project: ProjectSettings,
 }
 
 impl WorkspaceSettings {
@@ -74,6 +83,15 @@ impl WorkspaceSettings {
         &mut self,
         configuration: Configuration,
     ) -> Result<(), WorkspaceError> {
+        // The nested configuration files and the `.editorconfig` files are registered again
+        // once the configuration changes
+        let override_settings = &mut self.override_settings;
+        override_settings
+            .patterns
+            .truncate(override_settings.patterns.len() - override_settings.nested);
+        override_settings.nested = 0;
+        override_settings.editorconfig_files.clear();
+
         // formatter part
         if let Some(formatter) = configuration.formatter {
             self.formatter = FormatSettings::try_from(formatter)?;
@@ -97,6 +115,10 @@ impl WorkspaceSettings {
             self.override_settings = OverrideSettings::try_from(overrides)?;
         }
 
+        if let Some(project) = configuration.project {
+            self.project = ProjectSettings::try_from(project)?;
+        }
+
         // javascript settings
         if let Some(javascript) = configuration.javascript {
             self.languages.javascript = javascript.into();
@@ -109,6 +131,29 @@ impl WorkspaceSettings {
         Ok(())
     }
 
+    /// Adds the overrides of nested configuration files after the overrides of the
+    /// configuration, and the `.editorconfig` files. They are removed when the next
+    /// configuration is merged.
+    pub fn register_nested_overrides(
+        &mut self,
+        overrides: Overrides,
+        editorconfig_files: Vec<EditorConfigOverrides>,
+    ) -> Result<(), WorkspaceError> {
+        let nested = OverrideSettings::try_from(overrides)?;
+        self.override_settings.nested += nested.patterns.len();
+        self.override_settings.patterns.extend(nested.patterns);
+        for editorconfig_file in editorconfig_files {
+            self.override_settings
+                .editorconfig_files
+                .push(EditorConfigSettings {
+                    directory: editorconfig_file.directory,
+                    root: editorconfig_file.root,
+                    patterns: OverrideSettings::try_from(editorconfig_file.overrides)?.patterns,
+                });
+        }
+        Ok(())
+    }
+
     /// It retrieves the severity based on the `code` of the rule and the current configuration.
     ///
     /// The code of the has the following pattern: `{group}/{rule_name}`.
@@ -126,7 +171,23 @@ impl WorkspaceSettings {
         }
     }
 
-    /// Returns rules
+    /// Whether the formatter proceeds when the file at `path` has syntax errors, once the
+    /// overrides are applied
+    pub fn format_with_errors(&self, path: &Path) -> bool {
+        self.override_settings
+            .format_with_errors(path)
+            .unwrap_or(self.formatter.format_with_errors)
+    }
+
+    /// Whether the imports of the file at `path` are organized, once the overrides are applied
+    pub fn organize_imports_enabled(&self, path: &Path) -> bool {
+        self.override_settings
+            .organize_imports_disabled(path)
+            .map_or(self.organize_imports.enabled, |disabled| !disabled)
+    }
+
+    /// Returns the rules applied to the file at `path`: the rules of the overrides that match
+    /// the path are merged, rule by rule, over the rules of the workspace
     pub fn as_rules(&self, path: &Path) -> Option<Rules> {
         let overrides = &self.override_settings;
         self.linter
@@ -169,14 +230,15 @@ impl Default for FormatSettings {
     }
 }
 
-/// Formatter settings for the entire workspace
+/// Formatter settings for the files that match an override. The options that the override
+/// doesn't set are `None`, so they keep the value of the workspace
 #[derive(Debug)]
 pub struct OverrideFormatSettings {
     /// Enabled by default
     pub enabled: Option<bool>,
     /// Stores whether formatting should be allowed to proceed if a given file
     /// has syntax errors
-    pub format_with_errors: bool,
+    pub format_with_errors: Option<bool>,
     pub indent_style: Option<IndentStyle>,
     pub indent_width: Option<IndentWidth>,
     pub line_ending: Option<LineEnding>,
@@ -250,6 +312,12 @@ pub struct OverrideOrganizeImportsSettings {
     pub enabled: Option<bool>,
 }
 
+#[derive(Debug, Default)]
+pub struct OverrideFilesSettings {
+    /// The maximum allowed size for source code files in bytes
+    pub max_size: Option<NonZeroU64>,
+}
+
 /// Static map of language names to language-specific settings
 #[derive(Debug, Default)]
 pub struct LanguageListSettings {
@@ -271,7 +339,20 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
             language_setting.formatter.bracket_spacing = formatter.bracket_spacing.map(Into::into);
             language_setting.formatter.bracket_same_line =
                 formatter.bracket_same_line.map(Into::into);
+            language_setting.formatter.single_line_if_statements =
+                formatter.single_line_if_statements.map(Into::into);
+            language_setting.formatter.blank_line_after_imports =
+                formatter.blank_line_after_imports.map(Into::into);
+            language_setting.formatter.blank_line_before_return =
+                formatter.blank_line_before_return.map(Into::into);
+            language_setting.formatter.union_leading_separator =
+                formatter.union_leading_separator.map(Into::into);
+            language_setting.formatter.union_break_threshold =
+                formatter.union_break_threshold.map(Into::into);
+            language_setting.formatter.jsx_whitespace_fidelity =
+                formatter.jsx_whitespace_fidelity.map(Into::into);
             language_setting.formatter.enabled = formatter.enabled;
+            language_setting.formatter.line_ending = formatter.line_ending;
             language_setting.formatter.line_width = formatter.line_width;
             language_setting.formatter.indent_width = formatter
                 .indent_width
@@ -305,12 +386,14 @@ impl From<JsonConfiguration> for LanguageSettings<JsonLanguage> {
         }
         if let Some(formatter) = json.formatter {
             language_setting.formatter.enabled = formatter.enabled;
+            language_setting.formatter.line_ending = formatter.line_ending;
             language_setting.formatter.line_width = formatter.line_width;
             language_setting.formatter.indent_width = formatter
                 .indent_width
                 .map(Into::into)
                 .or(formatter.indent_size.map(Into::into));
             language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
+            language_setting.formatter.sort_package_json_keys = formatter.sort_package_json_keys;
         }
         language_setting
     }
@@ -442,12 +525,70 @@ impl<'a> SettingsHandle<'a> {
     }
 }
 
+/// The settings of the project analysis
+#[derive(Debug, Default)]
+pub struct ProjectSettings {
+    /// The tags of the modules, with the patterns of the modules that have them
+    pub tags: Vec<(Matcher, IndexSet<String>)>,
+    /// The constraints on the tags of the modules that the tagged modules can import
+    pub boundaries: Vec<BoundaryConstraint>,
+    /// The levels of the project rules
+    pub rules: ProjectRules,
+}
+
+impl ProjectSettings {
+    /// Returns the tags of the module at `path`
+    pub fn module_tags(&self, path: &Path) -> IndexSet<&str> {
+        self.tags
+            .iter()
+            .filter(|(matcher, _)| matcher.matches_path(path))
+            .flat_map(|(_, tags)| tags.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+/// The settings of the overrides. All the overrides that match a path are applied, in order:
+/// the last one takes precedence
 #[derive(Debug, Default)]
 pub struct OverrideSettings {
     pub patterns: Vec<OverrideSettingPattern>,
+    /// The number of patterns, at the end of `patterns`, added by the nested configuration files
+    pub nested: usize,
+    /// The `.editorconfig` files, from the shallowest to the deepest. Their patterns are applied
+    /// before `patterns`, see [OverrideSettings::editorconfig_patterns]
+    pub editorconfig_files: Vec<EditorConfigSettings>,
+}
+
+/// The settings of an `.editorconfig` file, see [crate::configuration::editorconfig]
+#[derive(Debug)]
+pub struct EditorConfigSettings {
+    /// The directory of the file, the file applies to the paths beneath it
+    pub directory: PathBuf,
+    /// Whether the files of the parent directories are ignored
+    pub root: bool,
+    pub patterns: Vec<OverrideSettingPattern>,
 }
 
 impl OverrideSettings {
+    /// Returns the patterns of the `.editorconfig` files that apply to `path`, from the
+    /// shallowest file to the deepest. The files above the deepest file with `root = true`
+    /// don't apply.
+    pub fn editorconfig_patterns(
+        &self,
+        path: &Path,
+    ) -> impl Iterator<Item = &OverrideSettingPattern> {
+        let mut files: Vec<_> = self
+            .editorconfig_files
+            .iter()
+            .rev()
+            .filter(|file| path.starts_with(&file.directory))
+            .collect();
+        if let Some(root) = files.iter().position(|file| file.root) {
+            files.truncate(root + 1);
+        }
+        files.into_iter().rev().flat_map(|file| &file.patterns)
+    }
+
     /// Checks whether at least one override excludes the provided `path`
     pub fn is_path_excluded(&self, path: &Path) -> Option<bool> {
         for pattern in &self.patterns {
@@ -477,7 +618,8 @@ impl OverrideSettings {
         path: &Path,
         options: JsFormatOptions,
     ) -> JsFormatOptions {
-        self.patterns.iter().fold(options, |mut options, pattern| {
+        let patterns = self.editorconfig_patterns(path).chain(&self.patterns);
+        patterns.fold(options, |mut options, pattern| {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
             let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
 
@@ -496,6 +638,9 @@ impl OverrideSettings {
                 if let Some(indent_width) = js_formatter.indent_width.or(formatter.indent_width) {
                     options.set_indent_width(indent_width)
                 }
+                if let Some(line_ending) = js_formatter.line_ending.or(formatter.line_ending) {
+                    options.set_line_ending(line_ending);
+                }
                 if let Some(line_width) = js_formatter.line_width.or(formatter.line_width) {
                     options.set_line_width(line_width);
                 }
@@ -523,6 +668,24 @@ impl OverrideSettings {
                 if let Some(bracket_same_line) = js_formatter.bracket_same_line {
                     options.set_bracket_same_line(bracket_same_line);
                 }
+                if let Some(single_line_if_statements) = js_formatter.single_line_if_statements {
+                    options.set_single_line_if_statements(single_line_if_statements);
+                }
+                if let Some(blank_line_after_imports) = js_formatter.blank_line_after_imports {
+                    options.set_blank_line_after_imports(blank_line_after_imports);
+                }
+                if let Some(blank_line_before_return) = js_formatter.blank_line_before_return {
+                    options.set_blank_line_before_return(blank_line_before_return);
+                }
+                if let Some(union_leading_separator) = js_formatter.union_leading_separator {
+                    options.set_union_leading_separator(union_leading_separator);
+                }
+                if let Some(union_break_threshold) = js_formatter.union_break_threshold {
+                    options.set_union_break_threshold(union_break_threshold);
+                }
+                if let Some(jsx_whitespace_fidelity) = js_formatter.jsx_whitespace_fidelity {
+                    options.set_jsx_whitespace_fidelity(jsx_whitespace_fidelity);
+                }
             }
 
             options
@@ -535,7 +698,8 @@ impl OverrideSettings {
         path: &Path,
         options: JsonFormatOptions,
     ) -> JsonFormatOptions {
-        self.patterns.iter().fold(options, |mut options, pattern| {
+        let patterns = self.editorconfig_patterns(path).chain(&self.patterns);
+        patterns.fold(options, |mut options, pattern| {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
             let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
             if excluded == Some(true) {
@@ -557,6 +721,11 @@ impl OverrideSettings {
                 {
                     options.set_indent_width(indent_width)
                 }
+                if let Some(line_ending) =
+                    json_formatter.line_ending.or(pattern.formatter.line_ending)
+                {
+                    options.set_line_ending(line_ending);
+                }
                 if let Some(line_width) = json_formatter.line_width.or(pattern.formatter.line_width)
                 {
                     options.set_line_width(line_width);
@@ -567,6 +736,26 @@ impl OverrideSettings {
         })
     }
 
+    /// Returns whether the keys of the `package.json` file at `path` are sorted,
+    /// once the overrides that match the path are applied
+    pub fn override_json_sort_package_json_keys(&self, path: &Path, sort: bool) -> bool {
+        self.patterns.iter().fold(sort, |sort, pattern| {
+            let included = pattern.include.as_ref().map(|p| p.matches_path(path));
+            let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
+            if excluded == Some(true) {
+                return sort;
+            }
+            if included == Some(true) {
+                if let Some(sort_package_json_keys) =
+                    pattern.languages.json.formatter.sort_package_json_keys
+                {
+                    return sort_package_json_keys;
+                }
+            }
+            sort
+        })
+    }
+
     pub fn override_js_parser_options(
         &self,
         path: &Path,
@@ -589,7 +778,7 @@ impl OverrideSettings {
     }
 
     pub fn as_json_parser_options(&self, path: &Path) -> Option<JsonParserOptions> {
-        for pattern in &self.patterns {
+        for pattern in self.patterns.iter().rev() {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
             let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
 
@@ -627,29 +816,6 @@ impl OverrideSettings {
             })
     }
 
-    /// Retrieves the enabled rules that match the given `path`
-    pub fn overrides_enabled_rules<'a>(
-        &'a self,
-        path: &Path,
-        rules: IndexSet<RuleFilter<'a>>,
-    ) -> IndexSet<RuleFilter> {
-        self.patterns.iter().fold(rules, move |mut rules, pattern| {
-            let included = pattern.include.as_ref().map(|p| p.matches_path(path));
-            let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
-
-            if included == Some(true) || excluded == Some(false) {
-                if let Some(pattern_rules) = pattern.linter.rules.as_ref() {
-                    let disabled_rules = pattern_rules.as_disabled_rules();
-                    let enabled_rules = pattern_rules.as_enabled_rules();
-
-                    rules = rules.bitor(&enabled_rules).sub(&disabled_rules);
-                }
-            }
-
-            rules
-        })
-    }
-
     pub fn override_as_rules(&self, path: &Path, rules: Rules) -> Rules {
         self.patterns.iter().fold(rules, |mut rules, pattern| {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
@@ -666,14 +832,28 @@ impl OverrideSettings {
         })
     }
 
-    /// Scans the overrides and checks if there's an override that disable the formatter for `path`
-    pub fn formatter_disabled(&self, path: &Path) -> Option<bool> {
-        for pattern in &self.patterns {
+    /// Scans the overrides and checks if there's an override that disable the formatter for `path`.
+    ///
+    /// In an override, the `enabled` option of the section of the `language`, e.g.
+    /// `javascript.formatter`, takes precedence over the one of `formatter`
+    pub fn formatter_disabled(
+        &self,
+        path: &Path,
+        language: &crate::file_handlers::Language,
+    ) -> Option<bool> {
+        for pattern in self.patterns.iter().rev() {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
             let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
 
             if included == Some(true) || excluded == Some(false) {
-                if let Some(enabled) = pattern.formatter.enabled {
+                let language_enabled = if language.is_javascript_like() {
+                    pattern.languages.javascript.formatter.enabled
+                } else if language.is_json_like() {
+                    pattern.languages.json.formatter.enabled
+                } else {
+                    None
+                };
+                if let Some(enabled) = language_enabled.or(pattern.formatter.enabled) {
                     return Some(!enabled);
                 }
             }
@@ -683,7 +863,7 @@ impl OverrideSettings {
 
     /// Scans the overrides and checks if there's an override that disable the linter for `path`
     pub fn linter_disabled(&self, path: &Path) -> Option<bool> {
-        for pattern in &self.patterns {
+        for pattern in self.patterns.iter().rev() {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
             let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
 
@@ -696,9 +876,25 @@ impl OverrideSettings {
         None
     }
 
+    /// Scans the overrides and returns whether `path` is formatted when it has syntax errors,
+    /// if an override sets it
+    pub fn format_with_errors(&self, path: &Path) -> Option<bool> {
+        for pattern in self.patterns.iter().rev() {
+            let included = pattern.include.as_ref().map(|p| p.matches_path(path));
+            let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
+
+            if included == Some(true) || excluded == Some(false) {
+                if let Some(format_with_errors) = pattern.formatter.format_with_errors {
+                    return Some(format_with_errors);
+                }
+            }
+        }
+        None
+    }
+
     /// Scans the overrides and checks if there's an override that disable the organize imports for `path`
     pub fn organize_imports_disabled(&self, path: &Path) -> Option<bool> {
-        for pattern in &self.patterns {
+        for pattern in self.patterns.iter().rev() {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
             let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
 
@@ -710,6 +906,21 @@ impl OverrideSettings {
         }
         None
     }
+
+    /// Scans the overrides and returns the maximum size of `path`, if an override sets it
+    pub fn max_size(&self, path: &Path) -> Option<NonZeroU64> {
+        for pattern in self.patterns.iter().rev() {
+            let included = pattern.include.as_ref().map(|p| p.matches_path(path));
+            let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
+
+            if included == Some(true) || excluded == Some(false) {
+                if let Some(max_size) = pattern.files.max_size {
+                    return Some(max_size);
+                }
+            }
+        }
+        None
+    }
 }
 #[derive(Debug)]
 pub struct OverrideSettingPattern {
@@ -721,6 +932,8 @@ pub struct OverrideSettingPattern {
     pub linter: OverrideLinterSettings,
     /// Linter settings applied to all files in the workspace
     pub organize_imports: OverrideOrganizeImportsSettings,
+    /// Filesystem settings applied to all files in the workspace
+    pub files: OverrideFilesSettings,
     /// Language specific settings
     pub languages: LanguageListSettings,
 }
@@ -0,0 +1,92 @@
+//! A hasher whose output doesn't change across the platforms, the versions of Rust and the
+//! versions of the dependencies of Biome.
+//!
+//! The hashes that are stored on disk, or shared between processes built separately, e.g. the
+//! keys of the caches or the fingerprints of the reports, must use it: the output of
+//! [std::collections::hash_map::DefaultHasher] and of `FxHasher` can change with any release.
+
+/// The parameters of the 64-bit FNV-1a hash
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A 64-bit FNV-1a hasher.
+///
+/// It doesn't implement [std::hash::Hasher] on purpose: the implementations of
+/// [std::hash::Hash] don't guarantee the bytes they write, so the values are written with the
+/// methods of the hasher, which fix their encoding.
+#[derive(Debug, Clone, Copy)]
+pub struct StableHasher {
+    hash: u64,
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StableHasher {
+    pub const fn new() -> Self {
+        Self {
+            hash: FNV_OFFSET_BASIS,
+        }
+    }
+
+    /// Hashes the bytes as they are, without a separator
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= u64::from(*byte);
+            self.hash = self.hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    /// Hashes the string followed by a separator, so that `("ab", "c")` and `("a", "bc")`
+    /// have different hashes
+    pub fn write_str(&mut self, value: &str) {
+        self.write(value.as_bytes());
+        self.write(&[0xff]);
+    }
+
+    /// Hashes the number as little-endian bytes
+    pub fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    /// The hash as 16 hexadecimal digits, the form in which it's stored
+    pub fn finish_hex(&self) -> String {
+        format!("{:016x}", self.hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StableHasher;
+
+    #[test]
+    fn matches_the_reference_values_of_fnv_1a() {
+        assert_eq!(StableHasher::new().finish(), 0xcbf2_9ce4_8422_2325);
+
+        let mut hasher = StableHasher::new();
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+
+        let mut hasher = StableHasher::new();
+        hasher.write(b"foobar");
+        assert_eq!(hasher.finish_hex(), "85944171f73967e8");
+    }
+
+    #[test]
+    fn separates_the_strings() {
+        let mut first = StableHasher::new();
+        first.write_str("ab");
+        first.write_str("c");
+        let mut second = StableHasher::new();
+        second.write_str("a");
+        second.write_str("bc");
+        assert_ne!(first.finish(), second.finish());
+    }
+}
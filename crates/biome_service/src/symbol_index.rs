@@ -0,0 +1,173 @@
+//! The symbols of the files of a project, searched by the editors with `workspace/symbol`.
+//!
+//! The index is filled by the project analysis, together with the module graph: the outline
+//! of each analyzed file is flattened, and each symbol remembers the name of the symbol that
+//! contains it, e.g. the class of a method.
+
+use crate::workspace::{DocumentSymbol, SymbolKind, WorkspaceSymbol};
+use biome_fs::RomePath;
+use biome_js_syntax::TextRange;
+use rustc_hash::FxHashMap;
+
+#[derive(Debug)]
+struct IndexedSymbol {
+    name: String,
+    /// The name in lowercase, the query is matched without case
+    lowercase_name: String,
+    kind: SymbolKind,
+    range: TextRange,
+    container_name: Option<String>,
+}
+
+/// The symbols of the project, indexed by path
+#[derive(Debug, Default)]
+pub(crate) struct SymbolIndex {
+    files: FxHashMap<RomePath, Vec<IndexedSymbol>>,
+}
+
+/// How a query matches the name of a symbol, from the best to the worst match
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+enum MatchKind {
+    Exact,
+    Prefix,
+    Substring,
+    /// The characters of the query appear in the name, in order, e.g. `gUN` for `getUserName`
+    Subsequence,
+}
+
+impl SymbolIndex {
+    /// Adds the symbols of a file, or replaces the symbols of the file at the same path
+    pub(crate) fn insert(&mut self, path: RomePath, symbols: Vec<DocumentSymbol>) {
+        let mut indexed = vec![];
+        flatten(symbols, None, &mut indexed);
+        self.files.insert(path, indexed);
+    }
+
+    pub(crate) fn remove(&mut self, path: &RomePath) {
+        self.files.remove(path);
+    }
+
+    /// Returns the symbols whose names match `query`, the best matches first. An empty
+    /// query matches all the symbols.
+    pub(crate) fn search(&self, query: &str, max_results: usize) -> Vec<WorkspaceSymbol> {
+        let query = query.to_lowercase();
+        let mut matches: Vec<_> = self
+            .files
+            .iter()
+            .flat_map(|(path, symbols)| symbols.iter().map(move |symbol| (path, symbol)))
+            .filter_map(|(path, symbol)| {
+                let match_kind = match_query(&query, &symbol.lowercase_name)?;
+                Some((match_kind, path, symbol))
+            })
+            .collect();
+
+        matches.sort_by(
+            |(left_kind, left_path, left), (right_kind, right_path, right)| {
+                left_kind
+                    .cmp(right_kind)
+                    .then_with(|| left.name.len().cmp(&right.name.len()))
+                    .then_with(|| left.name.cmp(&right.name))
+                    .then_with(|| left_path.as_path().cmp(right_path.as_path()))
+                    .then_with(|| left.range.start().cmp(&right.range.start()))
+            },
+        );
+
+        matches
+            .into_iter()
+            .take(max_results)
+            .map(|(_, path, symbol)| WorkspaceSymbol {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                path: path.clone(),
+                range: symbol.range,
+                container_name: symbol.container_name.clone(),
+            })
+            .collect()
+    }
+}
+
+fn flatten(
+    symbols: Vec<DocumentSymbol>,
+    container_name: Option<&str>,
+    indexed: &mut Vec<IndexedSymbol>,
+) {
+    for symbol in symbols {
+        flatten(symbol.children, Some(&symbol.name), indexed);
+        indexed.push(IndexedSymbol {
+            lowercase_name: symbol.name.to_lowercase(),
+            name: symbol.name,
+            kind: symbol.kind,
+            range: symbol.range,
+            container_name: container_name.map(str::to_string),
+        });
+    }
+}
+
+/// Matches a query with the name of a symbol, both in lowercase
+fn match_query(query: &str, name: &str) -> Option<MatchKind> {
+    if query == name {
+        Some(MatchKind::Exact)
+    } else if name.starts_with(query) {
+        Some(MatchKind::Prefix)
+    } else if name.contains(query) {
+        Some(MatchKind::Substring)
+    } else {
+        let mut name_chars = name.chars();
+        query
+            .chars()
+            .all(|query_char| name_chars.any(|name_char| name_char == query_char))
+            .then_some(MatchKind::Subsequence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str, kind: SymbolKind, children: Vec<DocumentSymbol>) -> DocumentSymbol {
+        DocumentSymbol {
+            name: name.to_string(),
+            kind,
+            range: TextRange::default(),
+            selection_range: TextRange::default(),
+            children,
+        }
+    }
+
+    #[test]
+    fn finds_the_best_matches_first() {
+        let mut index = SymbolIndex::default();
+        index.insert(
+            RomePath::new("a.ts"),
+            vec![
+                symbol(
+                    "UserService",
+                    SymbolKind::Class,
+                    vec![symbol("getUserName", SymbolKind::Method, vec![])],
+                ),
+                symbol("user", SymbolKind::Variable, vec![]),
+            ],
+        );
+        index.insert(
+            RomePath::new("b.ts"),
+            vec![symbol("createUser", SymbolKind::Function, vec![])],
+        );
+
+        let names: Vec<_> = index
+            .search("user", usize::MAX)
+            .into_iter()
+            .map(|symbol| symbol.name)
+            .collect();
+        assert_eq!(names, ["user", "UserService", "createUser", "getUserName"]);
+
+        let symbols = index.search("gUN", usize::MAX);
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "getUserName");
+        assert_eq!(symbols[0].container_name.as_deref(), Some("UserService"));
+
+        assert_eq!(index.search("", 2).len(), 2);
+
+        index.remove(&RomePath::new("a.ts"));
+        assert_eq!(index.search("user", usize::MAX).len(), 1);
+    }
+}
@@ -51,19 +51,23 @@
 //! document does not implement the required capability: for instance trying to
 //! format a file with a language that does not have a formatter
 
-use crate::file_handlers::Capabilities;
+use crate::configuration::Overrides;
+use crate::file_handlers::{Capabilities, ExtensionRegistry};
 use crate::{Configuration, Deserialize, Serialize, WorkspaceError};
-use biome_analyze::ActionCategory;
-pub use biome_analyze::RuleCategories;
+use biome_analyze::{ActionCategory, RuleFilter};
+pub use biome_analyze::{CancellationToken, RuleCategories};
 use biome_console::{markup, Markup, MarkupBuf};
-use biome_diagnostics::CodeSuggestion;
+use biome_diagnostics::{Category, CodeSuggestion, Severity};
 use biome_formatter::Printed;
-use biome_fs::RomePath;
+use biome_fs::{FileSystem, FileSystemExt, RomePath};
 use biome_js_syntax::{TextRange, TextSize};
+use biome_resolver::TsConfigJson;
+pub use biome_resolver::TSCONFIG_FILE_NAMES;
 use biome_text_edit::TextEdit;
 use std::collections::HashMap;
 use std::ffi::OsStr;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
 use tracing::debug;
 
@@ -72,7 +76,12 @@ pub use crate::file_handlers::Language;
 use crate::settings::WorkspaceSettings;
 
 mod client;
+mod closed_files;
+mod disk_cache;
+mod rule_docs;
+mod scheduler;
 mod server;
+mod vcs_ignore;
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -95,16 +104,13 @@ pub struct FileFeaturesResult {
 
 impl FileFeaturesResult {
     /// Files that should not be processed no matter the cases
-    pub(crate) const FILES_TO_NOT_PROCESS: &'static [&'static str; 11] = &[
-        "package.json",
+    pub(crate) const FILES_TO_NOT_PROCESS: &'static [&'static str; 8] = &[
         "package-lock.json",
         "npm-shrinkwrap.json",
         "yarn.lock",
         "composer.json",
         "composer.lock",
         "typescript.json",
-        "tsconfig.json",
-        "jsconfig.json",
         "deno.json",
         "deno.jsonc",
     ];
@@ -118,10 +124,11 @@ impl FileFeaturesResult {
     }
 
     /// By default, all features are not supported by a file.
-    const WORKSPACE_FEATURES: [(FeatureName, SupportKind); 3] = [
+    const WORKSPACE_FEATURES: [(FeatureName, SupportKind); 4] = [
         (FeatureName::Lint, SupportKind::FileNotSupported),
         (FeatureName::Format, SupportKind::FileNotSupported),
         (FeatureName::OrganizeImports, SupportKind::FileNotSupported),
+        (FeatureName::Search, SupportKind::FileNotSupported),
     ];
 
     pub fn new() -> Self {
@@ -143,6 +150,10 @@ impl FileFeaturesResult {
             self.features_supported
                 .insert(FeatureName::OrganizeImports, SupportKind::Supported);
         }
+        if capabilities.analyzer.search.is_some() {
+            self.features_supported
+                .insert(FeatureName::Search, SupportKind::Supported);
+        }
 
         self
     }
@@ -154,16 +165,18 @@ impl FileFeaturesResult {
         path: &Path,
     ) -> Self {
         if self.can_process(path) {
-            let formatter_disabled =
-                if let Some(disabled) = settings.override_settings.formatter_disabled(path) {
-                    disabled
-                } else if language.is_javascript_like() {
-                    !settings.formatter().enabled || settings.javascript_formatter_disabled()
-                } else if language.is_json_like() {
-                    !settings.formatter().enabled || settings.json_formatter_disabled()
-                } else {
-                    !settings.formatter().enabled
-                };
+            let formatter_disabled = if let Some(disabled) = settings
+                .override_settings
+                .formatter_disabled(path, language)
+            {
+                disabled
+            } else if language.is_javascript_like() {
+                !settings.formatter().enabled || settings.javascript_formatter_disabled()
+            } else if language.is_json_like() {
+                !settings.formatter().enabled || settings.json_formatter_disabled()
+            } else {
+                !settings.formatter().enabled
+            };
             if formatter_disabled {
                 self.features_supported
                     .insert(FeatureName::Format, SupportKind::FeatureNotEnabled);
@@ -268,12 +281,32 @@ impl SupportKind {
     }
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileCapabilitiesParams {
+    pub path: RomePath,
+}
+
+/// The features that a file supports, once the configuration and the overrides are applied
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileCapabilitiesResult {
+    pub format: SupportKind,
+    pub lint: SupportKind,
+    pub organize_imports: SupportKind,
+    /// The code actions of the file, they follow the `ignore` and `include` of the linter
+    pub assists: SupportKind,
+    /// The syntax tree, the control flow graph and the formatter IR of the file
+    pub debug: SupportKind,
+}
+
 #[derive(Debug, Clone, Hash, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum FeatureName {
     Format,
     Lint,
     OrganizeImports,
+    Search,
 }
 
 #[derive(Debug, Default)]
@@ -296,6 +329,10 @@ impl FeaturesBuilder {
         self.0.push(FeatureName::OrganizeImports);
         self
     }
+    pub fn with_search(mut self) -> Self {
+        self.0.push(FeatureName::Search);
+        self
+    }
 
     pub fn build(self) -> Vec<FeatureName> {
         self.0
@@ -306,6 +343,142 @@ impl FeaturesBuilder {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateSettingsParams {
     pub configuration: Configuration,
+    /// The ignore files of the VCS, from the least to the most specific: the patterns of a file
+    /// take precedence over the patterns of the files before it. They replace the ignore files
+    /// of the previous update.
+    #[serde(default)]
+    pub vcs_ignore_files: Vec<VcsIgnoreFile>,
+    /// The `tsconfig.json` or the `jsconfig.json` of the project, followed by the files it
+    /// extends. The patterns of its `paths` option are the path aliases of the analyzer, e.g.
+    /// to sort the imports.
+    #[serde(default)]
+    pub tsconfig_files: Vec<TsConfigFile>,
+    /// The `package.json` of the project. Its dependencies are the packages that the files of
+    /// the project can import.
+    #[serde(default)]
+    pub package_json_files: Vec<PackageJsonFile>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RegisterNestedConfigurationsParams {
+    /// The overrides that apply the nested configuration files to the files beneath their
+    /// directories, see [crate::configuration::nested]. They are added after the overrides of
+    /// the settings, until the settings are updated.
+    pub overrides: Overrides,
+    /// The `.editorconfig` files, from the shallowest to the deepest, see
+    /// [crate::configuration::editorconfig]. They are applied before the overrides of the
+    /// settings, until the settings are updated.
+    #[serde(default)]
+    pub editorconfig_files: Vec<EditorConfigOverrides>,
+    /// The ignore files of the VCS found in the subdirectories, from the least to the most
+    /// specific. They are added after the ignore files of the settings, until the settings are
+    /// updated.
+    #[serde(default)]
+    pub vcs_ignore_files: Vec<VcsIgnoreFile>,
+}
+
+/// The sections of an `.editorconfig` file, converted to overrides
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EditorConfigOverrides {
+    /// The directory of the file, spelled like the paths of the files beneath it. It's empty
+    /// for the files above the directory the paths are relative to.
+    pub directory: PathBuf,
+    /// Whether the file has `root = true`: the files of the parent directories don't apply to
+    /// the files beneath it
+    pub root: bool,
+    pub overrides: Overrides,
+}
+
+/// An ignore file of the VCS, e.g. a `.gitignore` file
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VcsIgnoreFile {
+    pub path: PathBuf,
+    /// The directory the patterns of the file are relative to, usually the directory of the
+    /// file. The global excludes of git are relative to the root of the repository.
+    pub directory: PathBuf,
+    pub content: String,
+}
+
+/// A configuration file of TypeScript, e.g. a `tsconfig.json` file
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TsConfigFile {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+impl TsConfigFile {
+    /// Reads the `tsconfig.json`, or the `jsconfig.json`, of the directory `base_path`, then the
+    /// files it extends. The paths of the files are relative to `base_path` when they are
+    /// inside it.
+    pub fn read_all(fs: &dyn FileSystem, base_path: &Path) -> Vec<Self> {
+        let mut files = vec![];
+        let root = TSCONFIG_FILE_NAMES
+            .iter()
+            .map(|file_name| base_path.join(file_name))
+            .find(|path| fs.path_exists(path));
+        if let Some(root) = root {
+            Self::read_with_extended(fs, base_path, &root, &mut files);
+        }
+        files
+    }
+
+    fn read_with_extended(
+        fs: &dyn FileSystem,
+        base_path: &Path,
+        path: &Path,
+        files: &mut Vec<Self>,
+    ) {
+        let relative_path = path.strip_prefix(base_path).unwrap_or(path).to_path_buf();
+        if files.iter().any(|file| file.path == relative_path) {
+            return;
+        }
+
+        let Ok(content) = fs.read_to_string(path) else {
+            return;
+        };
+
+        let extends = TsConfigJson::parse(&content)
+            .map(|tsconfig| tsconfig.extends)
+            .unwrap_or_default();
+        files.push(Self {
+            path: relative_path,
+            content,
+        });
+
+        for extends in extends {
+            let extended = TsConfigJson::extended_paths(path, &extends)
+                .into_iter()
+                .find(|extended| fs.path_exists(extended));
+            if let Some(extended) = extended {
+                Self::read_with_extended(fs, base_path, &extended, files);
+            }
+        }
+    }
+}
+
+/// The manifest of a package, i.e. a `package.json` file
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PackageJsonFile {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+impl PackageJsonFile {
+    /// Reads the `package.json` of the directory `base_path`. Its path is relative to
+    /// `base_path`.
+    pub fn read(fs: &dyn FileSystem, base_path: &Path) -> Option<Self> {
+        let content = fs.read_to_string(&base_path.join("package.json")).ok()?;
+
+        Some(Self {
+            path: PathBuf::from("package.json"),
+            content,
+        })
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -380,6 +553,65 @@ pub struct PullDiagnosticsParams {
     pub path: RomePath,
     pub categories: RuleCategories,
     pub max_diagnostics: u64,
+    /// When not empty, only these rules are run, even if the configuration disables them
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// These rules are never run, even if the configuration enables them
+    #[serde(default)]
+    pub skip: Vec<RuleSelector>,
+    /// Stops the analysis when it's cancelled, e.g. when the file changed before its diagnostics
+    /// were sent. The token isn't serialized, only the workspace of the same process can see it
+    #[serde(skip)]
+    pub cancellation: CancellationToken,
+}
+
+/// A lint rule or a group of lint rules, e.g. `style/noVar` or `style`
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RuleSelector {
+    Group(String),
+    Rule(String, String),
+}
+
+impl RuleSelector {
+    pub fn as_rule_filter(&self) -> RuleFilter<'_> {
+        match self {
+            RuleSelector::Group(group) => RuleFilter::Group(group),
+            RuleSelector::Rule(group, rule) => RuleFilter::Rule(group, rule),
+        }
+    }
+
+    /// Whether the rule `rule_name` of the group `group` is selected
+    pub fn matches(&self, group: &str, rule_name: &str) -> bool {
+        match self {
+            RuleSelector::Group(selected_group) => selected_group == group,
+            RuleSelector::Rule(selected_group, selected_rule) => {
+                selected_group == group && selected_rule == rule_name
+            }
+        }
+    }
+}
+
+impl FromStr for RuleSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The selector can also be the category of the rule, e.g. `lint/style/noVar`
+        let selector = s.strip_prefix("lint/").unwrap_or(s);
+
+        // Each group and each rule has its own category
+        let category = format!("lint/{selector}");
+        if category.parse::<&'static Category>().is_err() {
+            return Err(format!(
+                "{s:?} isn't a known rule or group of rules. Use the group and the name of the rule, e.g. \"style/noVar\", or the name of a group, e.g. \"style\"."
+            ));
+        }
+
+        Ok(match selector.split_once('/') {
+            Some((group, rule)) => Self::Rule(group.to_string(), rule.to_string()),
+            None => Self::Group(selector.to_string()),
+        })
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -447,6 +679,20 @@ pub struct FixFileParams {
     pub path: RomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
+    /// When not empty, only the fixes of these rules are applied, even if the configuration
+    /// disables them
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// The fixes of these rules are never applied
+    #[serde(default)]
+    pub skip: Vec<RuleSelector>,
+    /// The unsafe fixes of these rules are applied too, even with [FixFileMode::SafeFixes]
+    /// or when the configuration of the rule sets `fix` to `"safe"`
+    #[serde(default)]
+    pub unsafe_rules: Vec<RuleSelector>,
+    /// Stops applying the fixes when it's cancelled
+    #[serde(skip)]
+    pub cancellation: CancellationToken,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -490,6 +736,44 @@ pub struct RenameResult {
     pub indels: TextEdit,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrepareRenameParams {
+    pub path: RomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrepareRenameResult {
+    /// Range of the symbol to rename, or `None` if the symbol can't be renamed
+    pub range: Option<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InlayHintsParams {
+    pub path: RomePath,
+    /// The hints are computed for the calls inside this range, usually the visible part
+    /// of the file
+    pub range: TextRange,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InlayHintsResult {
+    pub hints: Vec<InlayHint>,
+}
+
+/// The name of a parameter, displayed before the argument passed to the parameter
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InlayHint {
+    /// The start of the argument
+    pub offset: TextSize,
+    pub parameter_name: String,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ServerInfo {
@@ -519,6 +803,39 @@ pub enum RageEntry {
     Markup(MarkupBuf),
 }
 
+#[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProjectsParams {}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProjectsResult {
+    /// The projects of the clients connected to the server, sorted by root
+    pub projects: Vec<ProjectSessions>,
+}
+
+/// A project open in one or more clients of the server. Each client gets its own
+/// workspace, so the settings and the files of a client aren't shared with the others
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProjectSessions {
+    /// The root of the project, `None` for the clients that didn't send one
+    pub root: Option<PathBuf>,
+    pub sessions: Vec<SessionInfo>,
+}
+
+/// A client connected to the server
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SessionInfo {
+    /// The key of the session, unique for the lifetime of the server
+    pub key: u64,
+    pub client_name: Option<String>,
+    pub client_version: Option<String>,
+    /// The number of documents that the client opened
+    pub open_documents: usize,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OrganizeImportsParams {
@@ -531,6 +848,297 @@ pub struct OrganizeImportsResult {
     pub code: String,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchPatternParams {
+    pub path: RomePath,
+    /// A snippet of code, where the identifiers that start with `$` match any node
+    pub pattern: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchResults {
+    /// The ranges of the code that matches the pattern
+    pub matches: Vec<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IndexFileParams {
+    pub path: RomePath,
+    /// The content of the file on disk
+    pub content: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AnalyzeProjectParams {
+    /// The maximum number of files to analyze, all the queued files are analyzed when it's `None`
+    pub max_files: Option<u32>,
+    /// Stops the analysis when it's cancelled, the files that weren't analyzed stay in the queue
+    #[serde(skip)]
+    pub cancellation: CancellationToken,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AnalyzeProjectResult {
+    /// The number of files analyzed by this call
+    pub analyzed: u32,
+    /// The number of files still waiting to be analyzed
+    pub pending: u32,
+    /// The open documents whose diagnostics of the project rules may have changed since the
+    /// last call, e.g. the modules that import a module analyzed by this call
+    pub affected: Vec<RomePath>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullProjectDiagnosticsParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullProjectActionsParams {
+    pub path: RomePath,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullProjectActionsResult {
+    pub actions: Vec<ProjectAction>,
+}
+
+/// A code action that edits several files of the project, e.g. a module and the modules
+/// that import it
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProjectAction {
+    pub category: ActionCategory,
+    pub rule_name: (Cow<'static, str>, Cow<'static, str>),
+    pub message: MarkupBuf,
+    /// The edits of each file, starting with the file of the action
+    pub edits: Vec<FileEdits>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileEdits {
+    pub path: RomePath,
+    /// The replacements of the file, they don't overlap
+    pub replacements: Vec<TextReplacement>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TextReplacement {
+    pub range: TextRange,
+    pub text: String,
+}
+
+impl TextReplacement {
+    pub fn new(range: TextRange, text: impl Into<String>) -> Self {
+        Self {
+            range,
+            text: text.into(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbolsParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbolsResult {
+    /// The symbols declared at the top level of the file
+    pub symbols: Vec<DocumentSymbol>,
+}
+
+/// A symbol declared in a file, e.g. a class or a function
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// The range of the whole declaration
+    pub range: TextRange,
+    /// The range of the name of the symbol
+    pub selection_range: TextRange,
+    /// The symbols declared inside this symbol, e.g. the methods of a class
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SymbolKind {
+    Class,
+    Constant,
+    Constructor,
+    Enum,
+    EnumMember,
+    Function,
+    Interface,
+    Method,
+    Namespace,
+    Property,
+    TypeAlias,
+    Variable,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorkspaceSymbolsParams {
+    /// The text matched with the names of the symbols. The characters of the query must
+    /// appear in the name, in the same order. All the symbols match an empty query
+    pub query: String,
+    /// The maximum number of symbols returned, all the matching symbols are returned when
+    /// it's `None`
+    pub max_results: Option<u32>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorkspaceSymbolsResult {
+    /// The matching symbols, the best matches first
+    pub symbols: Vec<WorkspaceSymbol>,
+}
+
+/// A symbol of a file of the project
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorkspaceSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub path: RomePath,
+    /// The range of the whole declaration
+    pub range: TextRange,
+    /// The name of the symbol that contains this symbol, e.g. the class of a method
+    pub container_name: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OpenFilesParams {
+    pub files: Vec<OpenFileParams>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChangeFilesParams {
+    pub files: Vec<ChangeFileParams>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CloseFilesParams {
+    pub paths: Vec<RomePath>,
+}
+
+/// The result of an operation on many files. A file that fails doesn't stop the
+/// operation, the other files are still processed
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FilesResult {
+    pub errors: Vec<FileError>,
+}
+
+/// The error of a file processed by an operation on many files
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileError {
+    pub path: RomePath,
+    pub error: biome_diagnostics::serde::Diagnostic,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullFilesDiagnosticsParams {
+    pub paths: Vec<RomePath>,
+    pub categories: RuleCategories,
+    /// The maximum number of diagnostics returned for each file
+    pub max_diagnostics: u64,
+    /// When not empty, only these rules are run, even if the configuration disables them
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// These rules are never run, even if the configuration enables them
+    #[serde(default)]
+    pub skip: Vec<RuleSelector>,
+    /// Stops the request when it's cancelled, the remaining files aren't analyzed
+    #[serde(skip)]
+    pub cancellation: CancellationToken,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullFilesDiagnosticsResult {
+    /// The diagnostics of the files, in the order of the paths of the request
+    pub files: Vec<FileDiagnostics>,
+    /// The errors of the files whose diagnostics couldn't be computed
+    pub errors: Vec<FileError>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileDiagnostics {
+    pub path: RomePath,
+    pub result: PullDiagnosticsResult,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FixFilesParams {
+    pub paths: Vec<RomePath>,
+    pub fix_file_mode: FixFileMode,
+    pub should_format: bool,
+    /// When not empty, only the fixes of these rules are applied, even if the configuration
+    /// disables them
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// The fixes of these rules are never applied
+    #[serde(default)]
+    pub skip: Vec<RuleSelector>,
+    /// The unsafe fixes of these rules are applied too, even with [FixFileMode::SafeFixes]
+    /// or when the configuration of the rule sets `fix` to `"safe"`
+    #[serde(default)]
+    pub unsafe_rules: Vec<RuleSelector>,
+    /// Stops the request when it's cancelled, the remaining files aren't fixed
+    #[serde(skip)]
+    pub cancellation: CancellationToken,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FixFilesResult {
+    /// The files changed by the fixes, in the order of the paths of the request. The files
+    /// without fixes aren't listed
+    pub files: Vec<FixedFile>,
+    /// The errors of the files that couldn't be fixed
+    pub errors: Vec<FileError>,
+    /// The number of errors left in the files after the fixes
+    pub remaining_errors: usize,
+    /// The number of suggested fixes that weren't applied
+    pub skipped_suggested_fixes: u32,
+}
+
+/// A file changed by an operation that fixes many files
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FixedFile {
+    pub path: RomePath,
+    /// Replaces the text between the first and the last change of the file. Each file has a
+    /// single replacement, so the edits of a batch can be applied together
+    pub replacement: TextReplacement,
+    /// The code actions applied to the file
+    pub actions: Vec<FixAction>,
+}
+
 impl RageEntry {
     pub fn section(name: &str) -> Self {
         Self::Section(name.to_string())
@@ -559,6 +1167,155 @@ pub struct IsPathIgnoredParams {
     pub feature: FeatureName,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExplainIgnoredPathParams {
+    pub path: RomePath,
+    pub feature: FeatureName,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExplainIgnoredPathResult {
+    /// Why the path is ignored, `None` when it isn't
+    pub reason: Option<IgnoreReason>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EnabledRulesParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EnabledRulesResult {
+    pub rules: Vec<EnabledRule>,
+}
+
+/// A lint rule enabled for a file
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EnabledRule {
+    pub group: String,
+    pub rule: String,
+    /// The severity of the diagnostics of the rule
+    pub severity: Severity,
+}
+
+#[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleDocsParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleDocsResult {
+    pub rules: Vec<RuleDoc>,
+}
+
+/// The metadata and the documentation of a lint rule
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleDoc {
+    pub group: String,
+    pub name: String,
+    /// The version of Biome that introduced the rule, `next` when it isn't released yet
+    pub version: String,
+    /// Whether the rule is enabled by the recommended configuration
+    pub recommended: bool,
+    /// Why the rule is deprecated, and what replaces it
+    pub deprecated: Option<String>,
+    /// The kind of code fix of the rule, `safe` or `unsafe`
+    pub fix_kind: Option<String>,
+    /// The JSON schema of the options of the rule, `None` when the rule doesn't accept any
+    /// options, or when Biome is built without the `schema` feature
+    pub options_schema: Option<serde_json::Value>,
+    /// The documentation of the rule, in Markdown
+    pub docs: String,
+}
+
+#[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricsParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricsResult {
+    /// Whether the workspace records the metrics, they're only recorded when they're enabled,
+    /// e.g. with the variable `BIOME_METRICS` when the daemon starts
+    pub enabled: bool,
+    /// The durations of the spans, such as the requests, the parsing, the analysis and the
+    /// formatting of the files
+    pub spans: Vec<SpanMetrics>,
+    pub caches: Vec<CacheMetrics>,
+}
+
+/// The durations of the spans of a callsite, in nanoseconds
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SpanMetrics {
+    pub name: String,
+    /// The module of the span, e.g. `biome_service::workspace::server`
+    pub target: String,
+    pub count: u64,
+    pub mean: u64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub max: u64,
+}
+
+/// How many times a cache of the workspace had the requested entry
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CacheMetrics {
+    /// The name of the cache, e.g. `syntax` for the syntax trees of the files, or
+    /// `js_references` for the references of the bindings shared by the lint rules
+    pub name: String,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Why a path is ignored
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum IgnoreReason {
+    /// The path matches the `ignore` list of an override
+    Overrides,
+    /// The path matches the `ignore` list of a section of the configuration, e.g. `linter`
+    Ignored { section: String },
+    /// The path doesn't match the `include` list of a section of the configuration
+    NotIncluded { section: String },
+    /// The path matches a pattern of an ignore file of the VCS
+    VcsIgnoreFile {
+        path: PathBuf,
+        /// The line of the pattern, starting from 1
+        line: u32,
+        pattern: String,
+    },
+}
+
+impl std::fmt::Display for IgnoreReason {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IgnoreReason::Overrides => write!(fmt, "ignored by overrides"),
+            IgnoreReason::Ignored { section } => write!(fmt, "ignored by {section}.ignore"),
+            IgnoreReason::NotIncluded { section } => {
+                write!(fmt, "not included by {section}.include")
+            }
+            IgnoreReason::VcsIgnoreFile {
+                path,
+                line,
+                pattern,
+            } => write!(
+                fmt,
+                "ignored by the pattern {pattern} of {}:{line}",
+                path.display()
+            ),
+        }
+    }
+}
+
 pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Checks whether a certain feature is supported. There are different conditions:
     /// - Biome doesn't recognize a file, so it can't provide the feature;
@@ -569,6 +1326,13 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: SupportsFeatureParams,
     ) -> Result<FileFeaturesResult, WorkspaceError>;
 
+    /// Returns the features that are supported and enabled for a file, once the configuration
+    /// and the overrides are applied, so that a client can skip the requests that would fail
+    fn capabilities(
+        &self,
+        params: FileCapabilitiesParams,
+    ) -> Result<FileCapabilitiesResult, WorkspaceError>;
+
     /// Given a file, the workspace tries to understand if this file is a "manifest" of a project.
     fn project_features(
         &self,
@@ -585,9 +1349,38 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// If the file path matches, than `true` is returned and it should be considered ignored.
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError>;
 
+    /// Returns why a path is ignored for a feature: by the configuration, or by an ignore
+    /// file of the VCS
+    fn explain_ignored_path(
+        &self,
+        params: ExplainIgnoredPathParams,
+    ) -> Result<ExplainIgnoredPathResult, WorkspaceError>;
+
+    /// Returns the lint rules enabled for a file, once the overrides are applied, with
+    /// the severity of their diagnostics
+    fn enabled_rules(
+        &self,
+        params: EnabledRulesParams,
+    ) -> Result<EnabledRulesResult, WorkspaceError>;
+
+    /// Returns the metadata and the documentation of all the lint rules, e.g. to list them in
+    /// the documentation or in the settings of an editor
+    fn rule_docs(&self, params: RuleDocsParams) -> Result<RuleDocsResult, WorkspaceError>;
+
+    /// Returns the durations of the requests and of the operations on the files, and the hit
+    /// rates of the caches, recorded since the workspace started
+    fn metrics(&self, params: MetricsParams) -> Result<MetricsResult, WorkspaceError>;
+
     /// Update the global settings for this workspace
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError>;
 
+    /// Applies the configuration files found in the subdirectories of the project to the
+    /// files beneath them
+    fn register_nested_configurations(
+        &self,
+        params: RegisterNestedConfigurationsParams,
+    ) -> Result<(), WorkspaceError>;
+
     /// Add a new file to the workspace
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError>;
 
@@ -642,27 +1435,147 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Return the range of the symbol at the specified position, if it can be renamed
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError>;
+
+    /// Returns the names of the parameters of the literal arguments of the calls in a range
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError>;
+
     /// Returns debug information about this workspace.
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError>;
 
     /// Returns information about the server this workspace is connected to or `None` if the workspace isn't connected to a server.
     fn server_info(&self) -> Option<&ServerInfo>;
 
+    /// Returns the projects of the clients connected to the server this workspace is
+    /// connected to. The list is empty if the workspace isn't connected to a server.
+    fn projects(&self, params: ProjectsParams) -> Result<ProjectsResult, WorkspaceError>;
+
     /// Applies import sorting
     fn organize_imports(
         &self,
         params: OrganizeImportsParams,
     ) -> Result<OrganizeImportsResult, WorkspaceError>;
+
+    /// Returns the code of a file that matches a structural pattern
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError>;
+
+    /// Adds a file of the project that isn't open to the project analysis
+    fn index_file(&self, params: IndexFileParams) -> Result<(), WorkspaceError>;
+
+    /// Analyzes the files queued since the last call, starting with the open documents,
+    /// and updates the module graph of the project
+    fn analyze_project(
+        &self,
+        params: AnalyzeProjectParams,
+    ) -> Result<AnalyzeProjectResult, WorkspaceError>;
+
+    /// Retrieves the diagnostics of the project rules, e.g. the import cycles, for a file
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullDiagnosticsResult, WorkspaceError>;
+
+    /// Retrieves the code actions of the project analysis at a range of a file, e.g. the
+    /// conversion of a default export to a named export, which also updates the modules
+    /// that import it
+    fn pull_project_actions(
+        &self,
+        params: PullProjectActionsParams,
+    ) -> Result<PullProjectActionsResult, WorkspaceError>;
+
+    /// Returns the outline of a file: its classes, functions, variables and types, with the
+    /// symbols they contain
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError>;
+
+    /// Searches the symbols of the files analyzed by the project analysis
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError>;
+
+    /// Adds many files to the current workspace, in a single request
+    fn open_files(&self, params: OpenFilesParams) -> Result<FilesResult, WorkspaceError>;
+
+    /// Changes the content of many open files, in a single request
+    fn change_files(&self, params: ChangeFilesParams) -> Result<FilesResult, WorkspaceError>;
+
+    /// Removes many files from the current workspace, in a single request
+    fn close_files(&self, params: CloseFilesParams) -> Result<FilesResult, WorkspaceError>;
+
+    /// Retrieves the diagnostics of many open files, in a single request
+    fn pull_files_diagnostics(
+        &self,
+        params: PullFilesDiagnosticsParams,
+    ) -> Result<PullFilesDiagnosticsResult, WorkspaceError>;
+
+    /// Applies the fixes of the rules to many open files, in a single request, e.g. to fix
+    /// the issues of a rule in the whole project
+    fn fix_files(&self, params: FixFilesParams) -> Result<FixFilesResult, WorkspaceError>;
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
+///
+/// The syntax trees of the closed files aren't kept: the files are processed once by the
+/// commands that run in the same process.
 pub fn server() -> Box<dyn Workspace> {
-    Box::new(server::WorkspaceServer::new())
+    Box::new(server::WorkspaceServer::new(
+        0,
+        None,
+        ExtensionRegistry::default(),
+    ))
+}
+
+/// Convenience function for constructing a server instance of [Workspace], that handles
+/// the files with the extensions registered in `extensions`, e.g. for an application
+/// that uses Biome as a library
+pub fn server_with_extensions(extensions: ExtensionRegistry) -> Box<dyn Workspace> {
+    Box::new(server::WorkspaceServer::new(0, None, extensions))
+}
+
+/// Convenience function for constructing a server instance of [Workspace], that shares the
+/// diagnostics of the files with the other processes of Biome through the cache in
+/// `cache_directory`
+pub fn server_with_cache(cache_directory: PathBuf) -> Box<dyn Workspace> {
+    Box::new(server::WorkspaceServer::new(
+        0,
+        Some(cache_directory),
+        ExtensionRegistry::default(),
+    ))
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
 pub fn server_sync() -> Arc<dyn Workspace> {
-    Arc::new(server::WorkspaceServer::new())
+    server_sync_with_options(None, None)
+}
+
+/// Convenience function for constructing a server instance of [Workspace], that keeps
+/// the syntax trees of the closed files until the size of their content exceeds
+/// `source_budget` bytes,
+/// and shares the diagnostics of the files through the cache in `cache_directory`
+pub fn server_sync_with_options(
+    source_budget: Option<usize>,
+    cache_directory: Option<PathBuf>,
+) -> Arc<dyn Workspace> {
+    Arc::new(server::WorkspaceServer::new(
+        source_budget.unwrap_or(closed_files::DEFAULT_SOURCE_BUDGET),
+        cache_directory,
+        ExtensionRegistry::default(),
+    ))
+}
+
+/// Returns the directory of the cache shared by the CLI and the daemon, set with the
+/// environment variable `BIOME_CACHE_DIR`. The cache is disabled when it isn't set.
+pub fn cache_directory() -> Option<PathBuf> {
+    std::env::var_os("BIOME_CACHE_DIR")
+        .filter(|directory| !directory.is_empty())
+        .map(PathBuf::from)
 }
 
 /// Convenience function for constructing a client instance of [Workspace]
@@ -720,11 +1633,16 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         categories: RuleCategories,
         max_diagnostics: u64,
+        only: Vec<RuleSelector>,
+        skip: Vec<RuleSelector>,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             path: self.path.clone(),
             categories,
             max_diagnostics,
+            only,
+            skip,
+            cancellation: CancellationToken::default(),
         })
     }
 
@@ -759,11 +1677,18 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         fix_file_mode: FixFileMode,
         should_format: bool,
+        only: Vec<RuleSelector>,
+        skip: Vec<RuleSelector>,
+        unsafe_rules: Vec<RuleSelector>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
             fix_file_mode,
             should_format,
+            only,
+            skip,
+            unsafe_rules,
+            cancellation: CancellationToken::default(),
         })
     }
 
@@ -772,6 +1697,13 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
         })
     }
+
+    pub fn search_pattern(&self, pattern: &str) -> Result<SearchResults, WorkspaceError> {
+        self.workspace.search_pattern(SearchPatternParams {
+            path: self.path.clone(),
+            pattern: pattern.to_string(),
+        })
+    }
 }
 
 impl<'app, W: Workspace + ?Sized> Drop for FileGuard<'app, W> {
@@ -1,7 +1,14 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, ProjectFeaturesParams, ProjectFeaturesResult, RageParams, RageResult,
-    ServerInfo,
+    AnalyzeProjectParams, AnalyzeProjectResult, ChangeFilesParams, CloseFilesParams,
+    DocumentSymbolsParams, DocumentSymbolsResult, EnabledRulesParams, EnabledRulesResult,
+    ExplainIgnoredPathParams, ExplainIgnoredPathResult, FileCapabilitiesParams,
+    FileCapabilitiesResult, FileFeaturesResult, FilesResult, FixFilesParams, FixFilesResult,
+    GetFileContentParams, IndexFileParams, IsPathIgnoredParams, MetricsParams, MetricsResult,
+    OpenFilesParams, OrganizeImportsParams, OrganizeImportsResult, ProjectFeaturesParams,
+    ProjectFeaturesResult, ProjectsParams, ProjectsResult, PullFilesDiagnosticsParams,
+    PullFilesDiagnosticsResult, PullProjectActionsParams, PullProjectActionsResult,
+    PullProjectDiagnosticsParams, RageParams, RageResult, RuleDocsParams, RuleDocsResult,
+    SearchPatternParams, SearchResults, ServerInfo, WorkspaceSymbolsParams, WorkspaceSymbolsResult,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -15,9 +22,10 @@ use std::{
 use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult,
-    SupportsFeatureParams, UpdateSettingsParams,
+    GetSyntaxTreeParams, GetSyntaxTreeResult, InlayHintsParams, InlayHintsResult, OpenFileParams,
+    PrepareRenameParams, PrepareRenameResult, PullActionsParams, PullActionsResult,
+    PullDiagnosticsParams, PullDiagnosticsResult, RegisterNestedConfigurationsParams, RenameParams,
+    RenameResult, SupportsFeatureParams, UpdateSettingsParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -108,14 +116,50 @@ where
         self.request("biome/file_features", params)
     }
 
+    fn capabilities(
+        &self,
+        params: FileCapabilitiesParams,
+    ) -> Result<FileCapabilitiesResult, WorkspaceError> {
+        self.request("biome/capabilities", params)
+    }
+
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
         self.request("biome/is_path_ignored", params)
     }
 
+    fn explain_ignored_path(
+        &self,
+        params: ExplainIgnoredPathParams,
+    ) -> Result<ExplainIgnoredPathResult, WorkspaceError> {
+        self.request("biome/explain_ignored_path", params)
+    }
+
+    fn enabled_rules(
+        &self,
+        params: EnabledRulesParams,
+    ) -> Result<EnabledRulesResult, WorkspaceError> {
+        self.request("biome/enabled_rules", params)
+    }
+
+    fn rule_docs(&self, params: RuleDocsParams) -> Result<RuleDocsResult, WorkspaceError> {
+        self.request("biome/rule_docs", params)
+    }
+
+    fn metrics(&self, params: MetricsParams) -> Result<MetricsResult, WorkspaceError> {
+        self.request("biome/metrics", params)
+    }
+
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
         self.request("biome/update_settings", params)
     }
 
+    fn register_nested_configurations(
+        &self,
+        params: RegisterNestedConfigurationsParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("biome/register_nested_configurations", params)
+    }
+
     fn project_features(
         &self,
         params: ProjectFeaturesParams,
@@ -188,6 +232,17 @@ where
         self.request("biome/rename", params)
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        self.request("biome/prepare_rename", params)
+    }
+
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError> {
+        self.request("biome/inlay_hints", params)
+    }
+
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError> {
         self.request("biome/rage", params)
     }
@@ -196,10 +251,80 @@ where
         self.server_info.as_ref()
     }
 
+    fn projects(&self, params: ProjectsParams) -> Result<ProjectsResult, WorkspaceError> {
+        self.request("workspace/projects", params)
+    }
+
     fn organize_imports(
         &self,
         params: OrganizeImportsParams,
     ) -> Result<OrganizeImportsResult, WorkspaceError> {
         self.request("biome/organize_imports", params)
     }
+
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
+        self.request("biome/search_pattern", params)
+    }
+
+    fn index_file(&self, params: IndexFileParams) -> Result<(), WorkspaceError> {
+        self.request("biome/index_file", params)
+    }
+
+    fn analyze_project(
+        &self,
+        params: AnalyzeProjectParams,
+    ) -> Result<AnalyzeProjectResult, WorkspaceError> {
+        self.request("biome/analyze_project", params)
+    }
+
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullDiagnosticsResult, WorkspaceError> {
+        self.request("biome/pull_project_diagnostics", params)
+    }
+
+    fn pull_project_actions(
+        &self,
+        params: PullProjectActionsParams,
+    ) -> Result<PullProjectActionsResult, WorkspaceError> {
+        self.request("biome/pull_project_actions", params)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        self.request("biome/document_symbols", params)
+    }
+
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError> {
+        self.request("biome/workspace_symbols", params)
+    }
+
+    fn open_files(&self, params: OpenFilesParams) -> Result<FilesResult, WorkspaceError> {
+        self.request("biome/open_files", params)
+    }
+
+    fn change_files(&self, params: ChangeFilesParams) -> Result<FilesResult, WorkspaceError> {
+        self.request("biome/change_files", params)
+    }
+
+    fn close_files(&self, params: CloseFilesParams) -> Result<FilesResult, WorkspaceError> {
+        self.request("biome/close_files", params)
+    }
+
+    fn pull_files_diagnostics(
+        &self,
+        params: PullFilesDiagnosticsParams,
+    ) -> Result<PullFilesDiagnosticsResult, WorkspaceError> {
+        self.request("biome/pull_files_diagnostics", params)
+    }
+
+    fn fix_files(&self, params: FixFilesParams) -> Result<FixFilesResult, WorkspaceError> {
+        self.request("biome/fix_files", params)
+    }
 }
@@ -0,0 +1,215 @@
+//! The files closed by the clients of the workspace.
+//!
+//! When a file is closed, its syntax tree is kept, so it isn't parsed again if the file is
+//! opened again with the same content, e.g. when the user goes back to a tab of the editor.
+//! The trees are kept within a budget of source code: when the total size of the content of
+//! the kept files exceeds the budget, the trees of the files used least recently are evicted.
+//! The hash of the content of an evicted file is still kept, so the workspace knows that the
+//! file didn't change when it's opened again. The budget counts the bytes of the content, not
+//! the memory used by the trees, which is usually a few times larger.
+
+use crate::file_handlers::Language;
+use biome_fs::RomePath;
+use biome_parser::AnyParse;
+use indexmap::IndexMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// The source budget used when the workspace isn't created with one: 32 MiB of source code
+pub(super) const DEFAULT_SOURCE_BUDGET: usize = 32 * 1024 * 1024;
+
+#[derive(Debug)]
+pub(super) struct ClosedFiles {
+    /// The maximum size, in bytes, of the content of the files whose tree is kept
+    source_budget: usize,
+    /// The size, in bytes, of the content of the files whose tree is kept
+    kept_source_size: usize,
+    /// The closed files, starting from the file used least recently, including the files
+    /// whose tree was evicted
+    files: IndexMap<RomePath, ClosedFile>,
+}
+
+#[derive(Debug)]
+struct ClosedFile {
+    content_hash: u64,
+    language_hint: Language,
+    /// The tree of the file, `None` when it was evicted
+    parse: Option<AnyParse>,
+    /// The size, in bytes, of the content of the file
+    size: usize,
+}
+
+/// What the workspace knew about a file that is opened again
+#[derive(Debug, Default)]
+pub(super) struct ReopenedFile {
+    /// Whether the file has the same content as when it was closed
+    pub(super) is_unchanged: bool,
+    /// The tree of the file, if it wasn't evicted
+    pub(super) parse: Option<AnyParse>,
+}
+
+impl ClosedFiles {
+    pub(super) fn new(source_budget: usize) -> Self {
+        Self {
+            source_budget,
+            kept_source_size: 0,
+            files: IndexMap::default(),
+        }
+    }
+
+    /// Keeps the tree of a file that was closed, and evicts the files used least recently if
+    /// the source budget is exceeded
+    pub(super) fn insert(
+        &mut self,
+        path: RomePath,
+        content: &str,
+        language_hint: Language,
+        parse: AnyParse,
+    ) {
+        self.remove(&path);
+
+        let size = content.len();
+        self.kept_source_size += size;
+        self.files.insert(
+            path,
+            ClosedFile {
+                content_hash: hash_content(content),
+                language_hint,
+                parse: Some(parse),
+                size,
+            },
+        );
+
+        self.evict();
+    }
+
+    /// Removes a file that is opened again, and returns its tree if the content of the file
+    /// didn't change since it was closed
+    pub(super) fn reopen(
+        &mut self,
+        path: &RomePath,
+        content: &str,
+        language_hint: Language,
+    ) -> ReopenedFile {
+        let Some(file) = self.remove(path) else {
+            return ReopenedFile::default();
+        };
+
+        let is_unchanged = file.content_hash == hash_content(content);
+        ReopenedFile {
+            is_unchanged,
+            parse: file
+                .parse
+                .filter(|_| is_unchanged && file.language_hint == language_hint),
+        }
+    }
+
+    /// Evicts all the files with the hashes of their content, e.g. because the settings of the
+    /// parser changed
+    pub(super) fn evict_all(&mut self) {
+        self.files.clear();
+        self.kept_source_size = 0;
+    }
+
+    /// The number of closed files whose tree is kept
+    pub(super) fn kept_trees(&self) -> usize {
+        self.files
+            .values()
+            .filter(|file| file.parse.is_some())
+            .count()
+    }
+
+    pub(super) fn kept_source_size(&self) -> usize {
+        self.kept_source_size
+    }
+
+    fn remove(&mut self, path: &RomePath) -> Option<ClosedFile> {
+        let file = self.files.shift_remove(path)?;
+        if file.parse.is_some() {
+            self.kept_source_size -= file.size;
+        }
+        Some(file)
+    }
+
+    /// Evicts the trees of the files used least recently until the source budget is respected,
+    /// the hashes of their content are kept
+    fn evict(&mut self) {
+        for file in self.files.values_mut() {
+            if self.kept_source_size <= self.source_budget {
+                break;
+            }
+            if file.parse.take().is_some() {
+                self.kept_source_size -= file.size;
+            }
+        }
+    }
+}
+
+fn hash_content(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_js_parser::JsParserOptions;
+    use biome_js_syntax::JsFileSource;
+    use biome_rowan::FileSource;
+
+    fn parse(content: &str) -> AnyParse {
+        let source_type = JsFileSource::js_module();
+        let parse = biome_js_parser::parse(content, source_type, JsParserOptions::default());
+        AnyParse::new(
+            parse.syntax().as_send().unwrap(),
+            parse.into_diagnostics(),
+            source_type.as_any_file_source(),
+        )
+    }
+
+    #[test]
+    fn evicts_the_trees_of_the_files_used_least_recently() {
+        let mut closed_files = ClosedFiles::new(10);
+        closed_files.insert(
+            RomePath::new("a.js"),
+            "a = 1;",
+            Language::JavaScript,
+            parse("a = 1;"),
+        );
+        closed_files.insert(
+            RomePath::new("b.js"),
+            "b = 2;",
+            Language::JavaScript,
+            parse("b = 2;"),
+        );
+
+        assert_eq!(closed_files.kept_trees(), 1);
+        assert_eq!(closed_files.kept_source_size(), 6);
+
+        // The hash of the content is kept after the tree is evicted
+        let a = closed_files.reopen(&RomePath::new("a.js"), "a = 1;", Language::JavaScript);
+        assert!(a.is_unchanged);
+        assert!(a.parse.is_none());
+
+        let b = closed_files.reopen(&RomePath::new("b.js"), "b = 2;", Language::JavaScript);
+        assert!(b.is_unchanged);
+        assert!(b.parse.is_some());
+        assert_eq!(closed_files.kept_source_size(), 0);
+    }
+
+    #[test]
+    fn drops_the_tree_of_a_file_that_changed() {
+        let mut closed_files = ClosedFiles::new(DEFAULT_SOURCE_BUDGET);
+        closed_files.insert(
+            RomePath::new("a.js"),
+            "a = 1;",
+            Language::JavaScript,
+            parse("a = 1;"),
+        );
+
+        let a = closed_files.reopen(&RomePath::new("a.js"), "a = 2;", Language::JavaScript);
+        assert!(!a.is_unchanged);
+        assert!(a.parse.is_none());
+    }
+}
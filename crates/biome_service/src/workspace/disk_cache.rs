@@ -0,0 +1,213 @@
+//! The cache of the diagnostics of the files, stored on disk and shared by the processes of
+//! Biome, e.g. a command of the CLI reuses the work done by the daemon for the editor.
+//!
+//! The syntax trees can't be deserialized, so the cache keeps the result of the analysis of
+//! a file instead. The entries are addressed by a hash of everything the result depends on:
+//! the version of Biome, the path and the content of the file, the settings of the workspace,
+//! with the ignore files of the VCS and the `tsconfig.json` and `package.json` files, and the
+//! parameters of the request. Entries are never updated: any change gives a new key.
+//!
+//! The cache is bounded: past [MAX_ENTRIES] entries, the least recently used ones are
+//! removed. The command `biome clean` removes the whole cache.
+
+use crate::module_graph::ModulePackages;
+use crate::stable_hash::StableHasher;
+use crate::workspace::{PullDiagnosticsParams, PullDiagnosticsResult};
+use filetime::FileTime;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The maximum number of entries of the cache
+pub(super) const MAX_ENTRIES: usize = 20_000;
+
+/// The number of insertions between two evictions, so the directory isn't listed at each
+/// insertion
+const EVICTION_INTERVAL: usize = 500;
+
+#[derive(Debug)]
+pub(super) struct DiskCache {
+    directory: PathBuf,
+    max_entries: usize,
+    /// The number of entries inserted by this process
+    insertions: AtomicUsize,
+}
+
+impl DiskCache {
+    pub(super) fn new(directory: PathBuf, max_entries: usize) -> Self {
+        Self {
+            directory,
+            max_entries,
+            insertions: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the diagnostics stored for `key`. An entry that can't be read, e.g. because
+    /// another process is writing it, is a miss.
+    pub(super) fn get(&self, key: u64) -> Option<PullDiagnosticsResult> {
+        let entry_path = self.entry_path(key);
+        let content = fs::read_to_string(&entry_path).ok()?;
+        let result = serde_json::from_str(&content).ok()?;
+        // The modification time of an entry is the time it was last used
+        let _ = filetime::set_file_mtime(&entry_path, FileTime::now());
+        Some(result)
+    }
+
+    /// Stores the diagnostics of `key`. The cache is an optimization, so the errors are ignored.
+    pub(super) fn insert(&self, key: u64, result: &PullDiagnosticsResult) {
+        let Ok(content) = serde_json::to_string(result) else {
+            return;
+        };
+        if fs::create_dir_all(&self.directory).is_err() {
+            return;
+        }
+
+        // The entry is renamed once it's complete, so the other processes never read half of it
+        let temporary_path = self
+            .directory
+            .join(format!("{key:016x}.{}.tmp", std::process::id()));
+        if fs::write(&temporary_path, content).is_ok()
+            && fs::rename(&temporary_path, self.entry_path(key)).is_err()
+        {
+            let _ = fs::remove_file(&temporary_path);
+        }
+
+        // The first insertion also removes the entries left over by the previous processes
+        if self.insertions.fetch_add(1, Ordering::Relaxed) % EVICTION_INTERVAL == 0 {
+            self.evict();
+        }
+    }
+
+    /// Removes the least recently used entries, until the cache has at most `max_entries`
+    fn evict(&self) {
+        let Ok(entries) = fs::read_dir(&self.directory) else {
+            return;
+        };
+        let mut entries: Vec<_> = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|entry| {
+                let modified = entry.metadata().ok()?.modified().ok()?;
+                Some((modified, entry.path()))
+            })
+            .collect();
+        if entries.len() <= self.max_entries {
+            return;
+        }
+
+        entries.sort_unstable();
+        let excess = entries.len() - self.max_entries;
+        for (_, path) in entries.into_iter().take(excess) {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    fn entry_path(&self, key: u64) -> PathBuf {
+        self.directory.join(format!("{key:016x}.json"))
+    }
+}
+
+/// Returns the key of the diagnostics of a file with the given content, and the given
+/// packages that it can import.
+///
+/// The keys are shared with the processes of other builds of Biome, so they use a
+/// [StableHasher]
+pub(super) fn diagnostics_key(
+    settings_hash: u64,
+    params: &PullDiagnosticsParams,
+    content: &str,
+    packages: &ModulePackages,
+) -> u64 {
+    let mut hasher = StableHasher::new();
+    hasher.write_str(crate::VERSION);
+    hasher.write_u64(settings_hash);
+    hasher.write_str(&params.path.to_string_lossy());
+    hasher.write_u64(u64::from(params.categories.bits()));
+    hasher.write_str(&serde_json::to_string(&params.only).unwrap_or_default());
+    hasher.write_str(&serde_json::to_string(&params.skip).unwrap_or_default());
+    hasher.write_str(content);
+    write_packages(&mut hasher, packages);
+    hasher.finish()
+}
+
+fn write_packages(hasher: &mut StableHasher, packages: &ModulePackages) {
+    write_optional_strings(hasher, packages.declared.as_deref());
+    write_strings(hasher, &packages.project);
+    write_optional_strings(
+        hasher,
+        packages.node_engine.as_ref().map(std::slice::from_ref),
+    );
+    write_strings(hasher, &packages.browserslist);
+}
+
+/// Hashes the number of strings before the strings, so that the strings of the lists that
+/// follow each other can't be confused
+fn write_strings(hasher: &mut StableHasher, strings: &[String]) {
+    hasher.write_u64(strings.len() as u64);
+    for string in strings {
+        hasher.write_str(string);
+    }
+}
+
+fn write_optional_strings(hasher: &mut StableHasher, strings: Option<&[String]>) {
+    match strings {
+        Some(strings) => {
+            hasher.write(&[1]);
+            write_strings(hasher, strings);
+        }
+        None => hasher.write(&[0]),
+    }
+}
+
+/// Returns the hash of the settings once `update`, the serialized parameters of an update of
+/// the settings, is applied to the settings that had the hash `settings_hash`. The parameters
+/// have the configuration, and the other files that the analysis reads: the ignore files of
+/// the VCS, the `tsconfig.json` files with their path aliases, and the `package.json` files.
+pub(super) fn merge_settings_hash(settings_hash: u64, update: &str) -> u64 {
+    let mut hasher = StableHasher::new();
+    hasher.write_u64(settings_hash);
+    hasher.write_str(update);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removes_the_least_recently_used_entries() {
+        let directory = std::env::temp_dir().join("biome-disk-cache-eviction");
+        let _ = fs::remove_dir_all(&directory);
+
+        let cache = DiskCache::new(directory.clone(), 2);
+        let result = PullDiagnosticsResult {
+            diagnostics: vec![],
+            errors: 0,
+            skipped_diagnostics: 0,
+        };
+        let set_used = |key: u64, seconds: i64| {
+            let time = FileTime::from_unix_time(seconds, 0);
+            filetime::set_file_mtime(cache.entry_path(key), time).unwrap();
+        };
+
+        // The first insertion removes the entries over the limit
+        fs::create_dir_all(&directory).unwrap();
+        for key in [1, 2, 3] {
+            fs::write(
+                cache.entry_path(key),
+                serde_json::to_string(&result).unwrap(),
+            )
+            .unwrap();
+            set_used(key, 1_000 * key as i64);
+        }
+        set_used(1, 10_000);
+        cache.insert(4, &result);
+
+        assert!(cache.get(1).is_some());
+        assert!(cache.get(2).is_none());
+        assert!(cache.get(3).is_none());
+        assert!(cache.get(4).is_some());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,85 @@
+use crate::workspace::RuleDoc;
+use biome_analyze::{
+    FixKind, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup,
+};
+use biome_js_syntax::JsLanguage;
+use biome_json_syntax::JsonLanguage;
+
+/// Returns the metadata and the documentation of the lint rules of all the languages
+pub(crate) fn rule_docs() -> Vec<RuleDoc> {
+    let mut visitor = RuleDocsVisitor::default();
+    biome_js_analyze::visit_registry(&mut visitor);
+    biome_json_analyze::visit_registry(&mut visitor);
+    visitor.rules
+}
+
+#[derive(Default)]
+struct RuleDocsVisitor {
+    rules: Vec<RuleDoc>,
+}
+
+impl RuleDocsVisitor {
+    fn push_rule<R: Rule>(&mut self) {
+        let metadata = R::METADATA;
+        self.rules.push(RuleDoc {
+            group: <R::Group as RuleGroup>::NAME.to_string(),
+            name: metadata.name.to_string(),
+            version: metadata.version.to_string(),
+            recommended: metadata.recommended,
+            deprecated: metadata.deprecated.map(String::from),
+            fix_kind: metadata.fix_kind.map(|fix_kind| match fix_kind {
+                FixKind::Safe => "safe".to_string(),
+                FixKind::Unsafe => "unsafe".to_string(),
+            }),
+            options_schema: options_schema(metadata.name),
+            docs: metadata.docs.to_string(),
+        });
+    }
+}
+
+/// Returns the JSON schema of the options of a rule. Like the JSON schema of the configuration, it
+/// needs the `schema` feature
+#[cfg(feature = "schema")]
+fn options_schema(rule_name: &str) -> Option<serde_json::Value> {
+    let schema = biome_js_analyze::options::PossibleOptions::json_schema(rule_name)?;
+    serde_json::to_value(schema).ok()
+}
+
+#[cfg(not(feature = "schema"))]
+fn options_schema(_rule_name: &str) -> Option<serde_json::Value> {
+    None
+}
+
+impl RegistryVisitor<JsLanguage> for RuleDocsVisitor {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.push_rule::<R>();
+    }
+}
+
+impl RegistryVisitor<JsonLanguage> for RuleDocsVisitor {
+    fn record_category<C: GroupCategory<Language = JsonLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsonLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.push_rule::<R>();
+    }
+}
@@ -0,0 +1,685 @@
+//! The scheduler of the project analysis.
+//!
+//! The module graph of the project is built incrementally: when a file is opened, changed
+//! or indexed, it's queued, and [Workspace::analyze_project](crate::Workspace::analyze_project)
+//! adds the imports, the exports and the symbols of the queued files to the graph. The open documents are analyzed
+//! before the indexed files, so the diagnostics of the files being edited stay fresh while
+//! the rest of the project is still being indexed.
+//!
+//! The indexed files are parsed when they're indexed, the queue only keeps the data extracted
+//! from them, not their content.
+//!
+//! The scheduler is disabled until a client asks for the project analysis, so the commands
+//! of the CLI, which never do, don't pay for it.
+
+use crate::configuration::BoundaryConstraint;
+use crate::module_graph::{
+    ExportChange, ExportConversion, ModuleGraph, ModuleInfo, ModulePackages, StylesheetInfo,
+};
+use crate::settings::ProjectSettings;
+use crate::symbol_index::SymbolIndex;
+use crate::workspace::{DocumentSymbol, FileEdits, ProjectAction, WorkspaceSymbol};
+use biome_analyze::{ActionCategory, RefactorKind};
+use biome_console::fmt::Display;
+use biome_console::markup;
+use biome_diagnostics::{
+    Advices, Diagnostic, DiagnosticExt, Error, LabelAdvice, LogCategory, Visit,
+};
+use biome_fs::RomePath;
+use biome_js_syntax::TextRange;
+use biome_project::PackageJson;
+use biome_resolver::{PathAliases, TsConfigJson};
+use indexmap::{IndexMap, IndexSet};
+use rustc_hash::FxHashSet;
+use std::borrow::Cow;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+
+#[derive(Debug, Default)]
+pub(super) struct AnalysisScheduler {
+    /// Whether a client asked for the project analysis
+    enabled: AtomicBool,
+    queue: Mutex<AnalysisQueue>,
+    graph: RwLock<ModuleGraph>,
+    symbols: RwLock<SymbolIndex>,
+    changes: Mutex<GraphChanges>,
+}
+
+#[derive(Debug, Default)]
+struct AnalysisQueue {
+    /// The open documents that changed since they were last analyzed
+    open: IndexSet<RomePath>,
+    /// The files of the project that aren't open, with the data extracted from them
+    indexed: IndexMap<RomePath, FileAnalysis>,
+}
+
+/// The changes of the module graph since the affected documents were last computed
+#[derive(Debug, Default)]
+struct GraphChanges {
+    /// The files that were analyzed
+    analyzed: FxHashSet<PathBuf>,
+    /// The files that the analyzed modules imported, before or after their analysis
+    imported: FxHashSet<PathBuf>,
+    /// Whether a `package.json` or a `tsconfig.json` file changed, which changes the
+    /// resolution of all the imports
+    resolution_changed: bool,
+    /// Whether the last indexed file was analyzed, the unused exports and classes are only
+    /// reported once the whole project is indexed
+    indexing_completed: bool,
+}
+
+/// The data extracted from a file by the project analysis
+#[derive(Debug, Default)]
+pub(super) struct FileAnalysis {
+    pub(super) resource: Option<ProjectResource>,
+    pub(super) module: Option<ModuleInfo>,
+    pub(super) symbols: Option<Vec<DocumentSymbol>>,
+}
+
+/// A file that the project rules read, besides the modules
+#[derive(Debug)]
+pub(super) enum ProjectResource {
+    /// A `package.json` file, `None` when it can't be parsed
+    Manifest(Option<PackageJson>),
+    /// A `tsconfig.json` or `jsconfig.json` file, `None` when it can't be parsed
+    TsConfig(Option<TsConfigJson>),
+    /// A CSS module
+    Stylesheet(StylesheetInfo),
+}
+
+/// A file to analyze
+pub(super) enum AnalysisTask {
+    /// An open document: its content is in the workspace
+    Open(RomePath),
+    /// A file that was indexed without being opened
+    Indexed(RomePath, FileAnalysis),
+}
+
+impl AnalysisScheduler {
+    pub(super) fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Enables the scheduler. The first time it's called, the open documents are queued.
+    pub(super) fn enable(&self, open_documents: impl FnOnce() -> Vec<RomePath>) {
+        if !self.enabled.swap(true, Ordering::Relaxed) {
+            let mut queue = self.queue.lock().unwrap();
+            queue.open.extend(open_documents());
+        }
+    }
+
+    /// Queues an open document that was opened or changed
+    pub(super) fn schedule_open(&self, path: RomePath) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let mut queue = self.queue.lock().unwrap();
+        // The content of the open document replaces the indexed content
+        queue.indexed.shift_remove(&path);
+        queue.open.insert(path);
+    }
+
+    /// Queues a file of the project that isn't open
+    pub(super) fn schedule_indexed(&self, path: RomePath, analysis: FileAnalysis) {
+        let mut queue = self.queue.lock().unwrap();
+        queue.indexed.insert(path, analysis);
+    }
+
+    /// Removes a document from the queue, and returns `true` if it was waiting to be analyzed
+    pub(super) fn unschedule_open(&self, path: &RomePath) -> bool {
+        let mut queue = self.queue.lock().unwrap();
+        queue.open.shift_remove(path)
+    }
+
+    /// Removes the open documents from the queue, and returns them
+    pub(super) fn take_open(&self) -> Vec<RomePath> {
+        let mut queue = self.queue.lock().unwrap();
+        std::mem::take(&mut queue.open).into_iter().collect()
+    }
+
+    /// Returns the next file to analyze, starting with the documents changed last
+    pub(super) fn next_task(&self) -> Option<AnalysisTask> {
+        let mut queue = self.queue.lock().unwrap();
+        if let Some(path) = queue.open.pop() {
+            return Some(AnalysisTask::Open(path));
+        }
+        let (path, analysis) = queue.indexed.pop()?;
+        if queue.indexed.is_empty() {
+            self.changes.lock().unwrap().indexing_completed = true;
+        }
+        Some(AnalysisTask::Indexed(path, analysis))
+    }
+
+    /// The number of files waiting to be analyzed
+    pub(super) fn pending(&self) -> usize {
+        let queue = self.queue.lock().unwrap();
+        queue.open.len() + queue.indexed.len()
+    }
+
+    /// Updates the module graph and the symbol index with the analysis of a file.
+    ///
+    /// A `None` module or `None` symbols remove the file from the graph or the index, e.g.
+    /// when it can't be parsed anymore.
+    pub(super) fn update_file(&self, path: &RomePath, analysis: FileAnalysis) {
+        {
+            let mut graph = self.graph.write().unwrap();
+            let mut changes = self.changes.lock().unwrap();
+            changes.analyzed.insert(path.to_path_buf());
+            // The modules imported before the analysis may have lost an importer
+            changes
+                .imported
+                .extend(graph.resolved_imports(path.as_path()));
+
+            match analysis.resource {
+                Some(ProjectResource::Manifest(manifest)) => {
+                    changes.resolution_changed = true;
+                    match manifest {
+                        Some(manifest) => graph.insert_manifest(path.to_path_buf(), manifest),
+                        None => graph.remove_manifest(path.as_path()),
+                    }
+                }
+                Some(ProjectResource::TsConfig(tsconfig)) => {
+                    changes.resolution_changed = true;
+                    match tsconfig {
+                        Some(tsconfig) => graph.insert_tsconfig(path.to_path_buf(), tsconfig),
+                        None => graph.remove_tsconfig(path.as_path()),
+                    }
+                }
+                Some(ProjectResource::Stylesheet(stylesheet)) => {
+                    graph.insert_stylesheet(path.to_path_buf(), stylesheet);
+                }
+                None => graph.remove_stylesheet(path.as_path()),
+            }
+
+            match analysis.module {
+                Some(info) => graph.insert(path.to_path_buf(), info),
+                None => graph.remove(path.as_path()),
+            }
+            changes
+                .imported
+                .extend(graph.resolved_imports(path.as_path()));
+        }
+
+        self.update_symbols(path, analysis.symbols);
+    }
+
+    /// Returns the documents among `documents` whose project diagnostics may have changed
+    /// since the last call: the analyzed documents, the documents that import an analyzed
+    /// file directly or not, and the documents that an analyzed module imported before or
+    /// after its analysis
+    pub(super) fn take_affected_documents(&self, documents: Vec<RomePath>) -> Vec<RomePath> {
+        let changes = std::mem::take(&mut *self.changes.lock().unwrap());
+        if changes.resolution_changed || changes.indexing_completed {
+            return documents;
+        }
+        if changes.analyzed.is_empty() {
+            return vec![];
+        }
+
+        let graph = self.graph.read().unwrap();
+        documents
+            .into_iter()
+            .filter(|document| {
+                changes.imported.contains(document.as_path())
+                    || graph.depends_on_any(document.as_path(), &changes.analyzed)
+            })
+            .collect()
+    }
+
+    /// Updates the `package.json` file used by the resolution of the specifiers.
+    ///
+    /// `None` removes the file from the graph, e.g. when it can't be parsed anymore.
+    pub(super) fn update_manifest(&self, path: &RomePath, manifest: Option<PackageJson>) {
+        let mut graph = self.graph.write().unwrap();
+        match manifest {
+            Some(manifest) => graph.insert_manifest(path.to_path_buf(), manifest),
+            None => graph.remove_manifest(path.as_path()),
+        }
+    }
+
+    /// Updates the `tsconfig.json` or `jsconfig.json` file used by the resolution of the
+    /// specifiers.
+    ///
+    /// `None` removes the file from the graph, e.g. when it can't be parsed anymore.
+    pub(super) fn update_tsconfig(&self, path: &RomePath, tsconfig: Option<TsConfigJson>) {
+        let mut graph = self.graph.write().unwrap();
+        match tsconfig {
+            Some(tsconfig) => graph.insert_tsconfig(path.to_path_buf(), tsconfig),
+            None => graph.remove_tsconfig(path.as_path()),
+        }
+    }
+
+    /// Returns the path aliases of the `tsconfig.json` or `jsconfig.json` file at `path`
+    pub(super) fn path_aliases(&self, path: &Path) -> Option<PathAliases> {
+        self.graph.read().unwrap().path_aliases(path)
+    }
+
+    /// Returns the packages that the module at `path` can import, from the `package.json`
+    /// files of the graph
+    pub(super) fn module_packages(&self, path: &Path) -> ModulePackages {
+        self.graph.read().unwrap().module_packages(path)
+    }
+
+    /// Updates the symbol index with the outline of a file.
+    ///
+    /// `None` removes the symbols of the file, e.g. when it can't be parsed anymore.
+    fn update_symbols(&self, path: &RomePath, symbols: Option<Vec<DocumentSymbol>>) {
+        let mut index = self.symbols.write().unwrap();
+        match symbols {
+            Some(symbols) => index.insert(path.clone(), symbols),
+            None => index.remove(path),
+        }
+    }
+
+    /// Returns the symbols of the analyzed files that match `query`
+    pub(super) fn search_symbols(&self, query: &str, max_results: usize) -> Vec<WorkspaceSymbol> {
+        self.symbols.read().unwrap().search(query, max_results)
+    }
+
+    /// The number of files in the module graph
+    pub(super) fn analyzed_files(&self) -> usize {
+        self.graph.read().unwrap().len()
+    }
+
+    /// Computes the diagnostics of the project rules for the module, or the CSS module, at
+    /// `path`. The module boundaries are checked with the tags and the constraints of `project`.
+    ///
+    /// The rules turned off in `project` and the diagnostics suppressed by a comment, e.g.
+    /// `// biome-ignore project/importCycle: the cycle is lazy`, are left out, and the
+    /// severity configured for a rule overrides the severity of its diagnostics.
+    pub(super) fn project_diagnostics(&self, path: &Path, project: &ProjectSettings) -> Vec<Error> {
+        let graph = self.graph.read().unwrap();
+        self.rule_diagnostics(&graph, path, project)
+            .into_iter()
+            .filter(|diagnostic| {
+                let (Some(category), Some(span)) =
+                    (diagnostic.category(), diagnostic.location().span)
+                else {
+                    return true;
+                };
+                !graph.is_suppressed(path, category, span.start())
+            })
+            .filter_map(|diagnostic| {
+                let Some(rule_name) = diagnostic
+                    .category()
+                    .and_then(|category| category.name().strip_prefix("project/"))
+                else {
+                    return Some(diagnostic);
+                };
+                let severity = project.rules.severity(rule_name, diagnostic.severity())?;
+                Some(diagnostic.with_severity(severity))
+            })
+            .collect()
+    }
+
+    fn rule_diagnostics(
+        &self,
+        graph: &ModuleGraph,
+        path: &Path,
+        project: &ProjectSettings,
+    ) -> Vec<Error> {
+        if graph.is_stylesheet(path) {
+            // Until the whole project is indexed, a module reading the class may be missing
+            if self.pending() > 0 {
+                return vec![];
+            }
+            return graph
+                .find_unused_classes(path)
+                .into_iter()
+                .map(|class| {
+                    Error::from(UnusedClassDiagnostic {
+                        span: class.range,
+                        name: class.name.clone(),
+                    })
+                })
+                .collect();
+        }
+        if graph.get(path).is_none() {
+            return vec![];
+        }
+
+        let mut diagnostics = vec![];
+        if let Some(cycle) = graph.find_import_cycle(path) {
+            // The static import of the module that comes next in the cycle
+            let import_range = |file: &Path, next: &Path| {
+                graph.get(file).and_then(|info| {
+                    info.imports
+                        .iter()
+                        .filter(|import| import.is_static)
+                        .find(|import| {
+                            graph.resolve(file, &import.specifier).as_deref() == Some(next)
+                        })
+                        .map(|import| import.range)
+                })
+            };
+            let next = |index: usize| cycle.get(index + 1).unwrap_or(&cycle[0]).as_path();
+            diagnostics.push(Error::from(ImportCycleDiagnostic {
+                span: import_range(path, next(0)),
+                // The import of the file itself is the location of the diagnostic
+                imports: cycle
+                    .iter()
+                    .enumerate()
+                    .skip(1)
+                    .map(|(index, file)| LabelAdvice {
+                        path: file.display().to_string(),
+                        span: import_range(file.as_path(), next(index)),
+                        source_code: None,
+                        message: format!("imports {}", next(index).display()),
+                    })
+                    .collect(),
+            }));
+        }
+
+        diagnostics.extend(graph.find_barrel_imports(path).into_iter().map(|barrel| {
+            Error::from(BarrelImportDiagnostic {
+                span: barrel.import.range,
+                loaded_modules: barrel.loaded_modules,
+                chain: ReexportChainAdvice {
+                    files: barrel
+                        .chain
+                        .iter()
+                        .map(|file| file.display().to_string())
+                        .collect(),
+                },
+            })
+        }));
+
+        diagnostics.extend(
+            graph
+                .find_boundary_violations(path, project)
+                .into_iter()
+                .map(|violation| {
+                    Error::from(ModuleBoundaryDiagnostic {
+                        span: violation.import.range,
+                        source_tag: violation.constraint.source_tag.clone(),
+                        constraint: BoundaryConstraintAdvice {
+                            constraint: violation.constraint.clone(),
+                            imported: violation.imported.display().to_string(),
+                            imported_tags: violation.imported_tags,
+                        },
+                    })
+                }),
+        );
+
+        diagnostics.extend(
+            graph
+                .find_server_only_imports(path)
+                .into_iter()
+                .map(|server_only| {
+                    Error::from(ServerOnlyImportDiagnostic {
+                        span: server_only.import.range,
+                        chain: ServerOnlyChainAdvice {
+                            files: server_only
+                                .chain
+                                .iter()
+                                .map(|file| file.display().to_string())
+                                .collect(),
+                        },
+                    })
+                }),
+        );
+
+        diagnostics.extend(graph.find_unknown_classes(path).into_iter().map(|class| {
+            Error::from(UnknownClassDiagnostic {
+                span: class.range,
+                name: class.name.to_string(),
+                specifier: class.specifier.to_string(),
+            })
+        }));
+
+        // Until the whole project is indexed, an import of the export may be missing
+        if self.pending() == 0 {
+            diagnostics.extend(graph.find_unused_exports(path).into_iter().map(|export| {
+                Error::from(UnusedExportDiagnostic {
+                    span: export.range,
+                    name: export.name.clone(),
+                })
+            }));
+        }
+
+        diagnostics
+    }
+
+    /// Returns the action that applies `conversion` to the module at `path`, and updates the
+    /// modules that import it, or `None` when the conversion breaks the project
+    pub(super) fn export_conversion_action(
+        &self,
+        path: &RomePath,
+        conversion: ExportConversion,
+    ) -> Option<ProjectAction> {
+        let graph = self.graph.read().unwrap();
+        let importer_edits = graph.export_change_edits(path.as_path(), &conversion.change)?;
+
+        let (rule_name, message) = match &conversion.change {
+            ExportChange::DefaultToNamed(_) => (
+                "convertToNamedExport",
+                markup! { "Convert to a "<Emphasis>"named export"</Emphasis>"." },
+            ),
+            ExportChange::NamedToDefault(_) => (
+                "convertToDefaultExport",
+                markup! { "Convert to the "<Emphasis>"default export"</Emphasis>"." },
+            ),
+        };
+        let edits = std::iter::once(FileEdits {
+            path: path.clone(),
+            replacements: conversion.edits,
+        })
+        .chain(
+            importer_edits
+                .into_iter()
+                .map(|(importer, replacements)| FileEdits {
+                    path: RomePath::new(importer),
+                    replacements,
+                }),
+        )
+        .collect();
+
+        Some(ProjectAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            rule_name: (Cow::Borrowed("project"), Cow::Borrowed(rule_name)),
+            message: message.to_owned(),
+            edits,
+        })
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "project/importCycle",
+    severity = Warning,
+    message = "This import is part of an import cycle."
+)]
+struct ImportCycleDiagnostic {
+    #[location(span)]
+    span: Option<TextRange>,
+    /// The imports of the other modules of the cycle, in the order of the cycle
+    #[label]
+    imports: Vec<LabelAdvice<String, Option<TextRange>, Option<String>, String>>,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "project/barrelImport",
+    severity = Warning,
+    message(
+        message("This import loads "{self.loaded_modules}" modules, because the imported module re-exports all the exports of other modules."),
+        description = "This import loads {loaded_modules} modules, because the imported module re-exports all the exports of other modules."
+    )
+)]
+struct BarrelImportDiagnostic {
+    #[location(span)]
+    span: TextRange,
+    loaded_modules: usize,
+    #[advice]
+    chain: ReexportChainAdvice,
+}
+
+#[derive(Debug)]
+struct ReexportChainAdvice {
+    /// The files of the chain of `export *` re-exports, the imported file first
+    files: Vec<String>,
+}
+
+impl Advices for ReexportChainAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! { "The modules re-export each other with "<Emphasis>"export *"</Emphasis>" in this order:" },
+        )?;
+        let files: Vec<_> = self.files.iter().map(|file| file as &dyn Display).collect();
+        visitor.record_list(&files)?;
+        visitor.record_log(
+            LogCategory::Info,
+            &"Import the bindings from the modules that declare them, so that the other modules aren't loaded.",
+        )
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "project/moduleBoundary",
+    severity = Error,
+    message(
+        message("This import breaks the boundaries of the modules tagged "<Emphasis>{self.source_tag}</Emphasis>"."),
+        description = "This import breaks the boundaries of the modules tagged {source_tag}."
+    )
+)]
+struct ModuleBoundaryDiagnostic {
+    #[location(span)]
+    span: TextRange,
+    source_tag: String,
+    #[advice]
+    constraint: BoundaryConstraintAdvice,
+}
+
+#[derive(Debug)]
+struct BoundaryConstraintAdvice {
+    /// The violated constraint
+    constraint: BoundaryConstraint,
+    /// The path of the imported module
+    imported: String,
+    /// The tags of the imported module
+    imported_tags: Vec<String>,
+}
+
+impl Advices for BoundaryConstraintAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        let source_tag = &self.constraint.source_tag;
+        if let Some(forbidden) = &self.constraint.not_depend_on_tags {
+            let forbidden = forbidden.iter().cloned().collect::<Vec<_>>().join(", ");
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The modules tagged "<Emphasis>{source_tag}</Emphasis>" can't import the modules tagged "<Emphasis>{forbidden}</Emphasis>"." },
+            )?;
+        }
+        if let Some(allowed) = &self.constraint.only_depend_on_tags {
+            let allowed = allowed.iter().cloned().collect::<Vec<_>>().join(", ");
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The modules tagged "<Emphasis>{source_tag}</Emphasis>" can only import the modules tagged "<Emphasis>{allowed}</Emphasis>"." },
+            )?;
+        }
+        if self.imported_tags.is_empty() {
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The imported module "<Emphasis>{self.imported}</Emphasis>" doesn't have tags." },
+            )
+        } else {
+            let imported_tags = self.imported_tags.join(", ");
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The imported module "<Emphasis>{self.imported}</Emphasis>" is tagged "<Emphasis>{imported_tags}</Emphasis>"." },
+            )
+        }
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "project/serverOnlyImport",
+    severity = Error,
+    message = "This import loads a server-only module in a client module."
+)]
+struct ServerOnlyImportDiagnostic {
+    #[location(span)]
+    span: TextRange,
+    #[advice]
+    chain: ServerOnlyChainAdvice,
+}
+
+#[derive(Debug)]
+struct ServerOnlyChainAdvice {
+    /// The files of the chain of static imports, the imported file first and the file that
+    /// imports `server-only` last. Empty when the client module imports `server-only`.
+    files: Vec<String>,
+}
+
+impl Advices for ServerOnlyChainAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! { "The modules with the directive "<Emphasis>"\"use client\""</Emphasis>" are bundled for the browser, with the modules that they import." },
+        )?;
+        if !self.files.is_empty() {
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The imported module loads a module that imports "<Emphasis>"server-only"</Emphasis>" through this chain of imports:" },
+            )?;
+            let files: Vec<_> = self.files.iter().map(|file| file as &dyn Display).collect();
+            visitor.record_list(&files)?;
+        }
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! { "Move this code to a Server Component, or import the server functions from a module with the directive "<Emphasis>"\"use server\""</Emphasis>"." },
+        )
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "project/unusedExport",
+    severity = Warning,
+    message(
+        message("The export "<Emphasis>{self.name}</Emphasis>" isn't imported by any module of the project."),
+        description = "The export {name} isn't imported by any module of the project."
+    )
+)]
+struct UnusedExportDiagnostic {
+    #[location(span)]
+    span: TextRange,
+    name: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "project/unusedClass",
+    severity = Warning,
+    message(
+        message("The class "<Emphasis>{self.name}</Emphasis>" isn't used by the modules that import this CSS module."),
+        description = "The class {name} isn't used by the modules that import this CSS module."
+    )
+)]
+struct UnusedClassDiagnostic {
+    #[location(span)]
+    span: TextRange,
+    name: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "project/unknownClass",
+    severity = Warning,
+    message(
+        message("The CSS module "<Emphasis>{self.specifier}</Emphasis>" doesn't declare the class "<Emphasis>{self.name}</Emphasis>"."),
+        description = "The CSS module {specifier} doesn't declare the class {name}."
+    )
+)]
+struct UnknownClassDiagnostic {
+    #[location(span)]
+    span: TextRange,
+    name: String,
+    specifier: String,
+}
@@ -1,35 +1,61 @@
+use super::closed_files::ClosedFiles;
+use super::disk_cache::{self, DiskCache};
+use super::rule_docs::rule_docs;
+use super::scheduler::{AnalysisScheduler, AnalysisTask, FileAnalysis, ProjectResource};
+use super::vcs_ignore::VcsIgnoreFiles;
 use super::{
-    ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameResult, SupportsFeatureParams,
-    UpdateSettingsParams,
+    AnalyzeProjectParams, AnalyzeProjectResult, ChangeFileParams, ChangeFilesParams,
+    CloseFileParams, CloseFilesParams, DocumentSymbolsParams, DocumentSymbolsResult, EnabledRule,
+    EnabledRulesParams, EnabledRulesResult, ExplainIgnoredPathParams, ExplainIgnoredPathResult,
+    FeatureName, FeaturesBuilder, FileCapabilitiesParams, FileCapabilitiesResult, FileDiagnostics,
+    FileError, FilesResult, FixFileParams, FixFileResult, FixFilesParams, FixFilesResult,
+    FixedFile, FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
+    GetFormatterIRParams, GetSyntaxTreeParams, GetSyntaxTreeResult, IndexFileParams,
+    InlayHintsParams, InlayHintsResult, MetricsParams, MetricsResult, OpenFileParams,
+    OpenFilesParams, PrepareRenameParams, PrepareRenameResult, PullActionsParams,
+    PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, PullFilesDiagnosticsParams,
+    PullFilesDiagnosticsResult, PullProjectActionsParams, PullProjectActionsResult,
+    PullProjectDiagnosticsParams, RegisterNestedConfigurationsParams, RenameResult, RuleDocsParams,
+    RuleDocsResult, SupportsFeatureParams, TextReplacement, UpdateSettingsParams,
+    WorkspaceSymbolsParams, WorkspaceSymbolsResult,
+};
+use crate::file_handlers::{
+    css_module_info, is_css_module, Capabilities, ExtensionRegistry, FixAllParams, Language,
+    LintParams,
 };
-use crate::file_handlers::{Capabilities, FixAllParams, Language, LintParams};
 use crate::project_handlers::{ProjectCapabilities, ProjectHandlers};
-use crate::settings::OverrideSettings;
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    FileFeaturesResult, GetFileContentParams, IgnoreReason, IsPathIgnoredParams,
+    OrganizeImportsParams, OrganizeImportsResult, ProjectsParams, ProjectsResult, RageEntry,
+    RageParams, RageResult, RuleSelector, SearchPatternParams, SearchResults, ServerInfo,
+    SupportKind,
 };
 use crate::{
     file_handlers::Features,
+    metrics,
     settings::{SettingsHandle, WorkspaceSettings},
     Rules, Workspace, WorkspaceError,
 };
 use biome_analyze::{AnalysisFilter, RuleFilter};
 use biome_diagnostics::{
-    serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
+    serde::Diagnostic as SerdeDiagnostic, Category, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
 use biome_fs::RomePath;
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_project::PackageJson;
+use biome_resolver::{TsConfigJson, TSCONFIG_FILE_NAMES};
+use biome_rowan::{NodeCache, TextRange, TextSize};
 use dashmap::{mapref::entry::Entry, DashMap};
+use rustc_hash::FxHashSet;
 use std::ffi::OsStr;
-use std::path::Path;
-use std::{panic::RefUnwindSafe, sync::RwLock};
-use tracing::{info_span, trace};
+use std::path::PathBuf;
+use std::{
+    panic::RefUnwindSafe,
+    sync::atomic::{AtomicU64, Ordering},
+    sync::{Mutex, RwLock},
+};
+use tracing::{debug_span, info_span, trace};
 
 pub(super) struct WorkspaceServer {
     /// features available throughout the application
@@ -44,6 +70,17 @@ pub(super) struct WorkspaceServer {
     file_features: DashMap<RomePath, FileFeaturesResult>,
     /// Handlers that know how to handle a specific project
     project_handlers: ProjectHandlers,
+    /// Keeps the module graph of the project up to date
+    scheduler: AnalysisScheduler,
+    /// Keeps the syntax trees of the closed files, within a budget of source code
+    closed_files: Mutex<ClosedFiles>,
+    /// The diagnostics shared with the other processes of Biome, `None` when it's disabled
+    disk_cache: Option<DiskCache>,
+    /// A hash of all the configurations merged in the settings, used by the keys of the
+    /// [DiskCache]
+    settings_hash: AtomicU64,
+    /// The ignore files of the VCS, sent with the settings
+    vcs_ignore_files: RwLock<VcsIgnoreFiles>,
 }
 
 /// The `Workspace` object is long lived, so we want it to be able to cross
@@ -68,15 +105,122 @@ impl WorkspaceServer {
     /// This is implemented as a crate-private method instead of using
     /// [Default] to disallow instances of [Workspace] from being created
     /// outside of a [crate::App]
-    pub(crate) fn new() -> Self {
+    ///
+    /// The syntax trees of the closed files are kept until the size of their content exceeds
+    /// `source_budget` bytes, see [ClosedFiles]. The diagnostics of the files are shared with the other
+    /// processes of Biome through the cache in `cache_directory`, see [DiskCache]. The files
+    /// with the extensions of `extensions` are handled like the application registered them.
+    pub(crate) fn new(
+        source_budget: usize,
+        cache_directory: Option<PathBuf>,
+        extensions: ExtensionRegistry,
+    ) -> Self {
         Self {
-            features: Features::new(),
+            features: Features::new(extensions),
             settings: RwLock::default(),
             documents: DashMap::default(),
             syntax: DashMap::default(),
             file_features: DashMap::default(),
             project_handlers: ProjectHandlers::new(),
+            scheduler: AnalysisScheduler::default(),
+            closed_files: Mutex::new(ClosedFiles::new(source_budget)),
+            disk_cache: cache_directory
+                .map(|directory| DiskCache::new(directory, disk_cache::MAX_ENTRIES)),
+            settings_hash: AtomicU64::new(0),
+            vcs_ignore_files: RwLock::default(),
+        }
+    }
+
+    /// Runs the analyzer on a file, and returns its diagnostics
+    fn lint_file(
+        &self,
+        params: PullDiagnosticsParams,
+    ) -> Result<PullDiagnosticsResult, WorkspaceError> {
+        let feature = if params.categories.is_syntax() {
+            FeatureName::Format
+        } else {
+            FeatureName::Lint
+        };
+
+        let parse = self.get_parse(params.path.clone(), Some(feature))?;
+        let settings = self.settings.read().unwrap();
+
+        let (diagnostics, errors, skipped_diagnostics) = if let Some(lint) =
+            self.get_file_capabilities(&params.path).analyzer.lint
+        {
+            let rules = settings.as_rules(params.path.as_path());
+            let rules = rules.as_ref();
+            let mut rule_filter_list = if params.only.is_empty() {
+                self.build_rule_filter_list(rules)
+            } else {
+                params
+                    .only
+                    .iter()
+                    .map(RuleSelector::as_rule_filter)
+                    .collect()
+            };
+            if settings.organize_imports_enabled(params.path.as_path())
+                && !params.categories.is_syntax()
+            {
+                rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
+            }
+            let skipped_rules: Vec<RuleFilter> = params
+                .skip
+                .iter()
+                .map(RuleSelector::as_rule_filter)
+                .collect();
+            let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+            filter.disabled_rules = Some(skipped_rules.as_slice());
+            filter.categories = params.categories;
+
+            info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
+                trace!("Analyzer filter to apply to lint: {:?}", &filter);
+
+                let results = debug_span!("analyze").in_scope(|| {
+                    lint(LintParams {
+                        parse,
+                        filter,
+                        rules,
+                        settings: self.settings(),
+                        max_diagnostics: params.max_diagnostics,
+                        path: &params.path,
+                        packages: self.scheduler.module_packages(params.path.as_path()),
+                        cancellation: params.cancellation.clone(),
+                    })
+                });
+
+                (
+                    results.diagnostics,
+                    results.errors,
+                    results.skipped_diagnostics,
+                )
+            })
+        } else {
+            let parse_diagnostics = parse.into_diagnostics();
+            let errors = parse_diagnostics
+                .iter()
+                .filter(|diag| diag.severity() <= Severity::Error)
+                .count();
+
+            (parse_diagnostics, errors, 0)
+        };
+
+        // The diagnostics of a cancelled analysis are incomplete, so they aren't cached either
+        if params.cancellation.is_cancelled() {
+            return Err(WorkspaceError::cancelled());
         }
+
+        Ok(PullDiagnosticsResult {
+            diagnostics: diagnostics
+                .into_iter()
+                .map(|diag| {
+                    let diag = diag.with_file_path(params.path.as_path().display().to_string());
+                    SerdeDiagnostic::new(diag)
+                })
+                .collect(),
+            errors,
+            skipped_diagnostics,
+        })
     }
 
     fn settings(&self) -> SettingsHandle {
@@ -84,6 +228,40 @@ impl WorkspaceServer {
     }
 
     /// Get the supported capabilities for a given file path
+    /// Computes the support of `features` for the file at `path`, with the configuration,
+    /// the overrides and the ignored files
+    fn compute_file_features(
+        &self,
+        path: &RomePath,
+        features: Vec<FeatureName>,
+    ) -> Result<FileFeaturesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(path);
+        let language = self.features.language_of(path);
+        let settings = self.settings.read().unwrap();
+        let mut file_features = FileFeaturesResult::new()
+            .with_capabilities(&capabilities)
+            .with_settings_and_language(&settings, &language, path.as_path());
+
+        if settings.files.ignore_unknown {
+            let language = self.get_language(path);
+            if language == Language::Unknown {
+                file_features.ignore_not_supported();
+            }
+        }
+
+        for feature in features {
+            let is_ignored = self.is_path_ignored(IsPathIgnoredParams {
+                rome_path: path.clone(),
+                feature: feature.clone(),
+            })?;
+
+            if is_ignored {
+                file_features.ignored(feature);
+            }
+        }
+        Ok(file_features)
+    }
+
     fn get_file_capabilities(&self, path: &RomePath) -> Capabilities {
         let language = self.get_language(path);
 
@@ -118,7 +296,7 @@ impl WorkspaceServer {
                 .map(|doc| doc.language_hint)
                 .unwrap_or_default();
 
-            let language = Features::get_language(path).or(language_hint);
+            let language = self.features.language_of(path).or(language_hint);
             WorkspaceError::source_file_not_supported(
                 language,
                 path.clone().display().to_string(),
@@ -130,17 +308,14 @@ impl WorkspaceServer {
         }
     }
 
-    fn build_rule_filter_list<'a>(
-        &'a self,
-        rules: Option<&'a Rules>,
-        overrides: &'a OverrideSettings,
-        path: &'a Path,
-    ) -> Vec<RuleFilter> {
-        let enabled_rules =
-            rules.map(|rules| overrides.overrides_enabled_rules(path, rules.as_enabled_rules()));
-
-        if let Some(enabled_rules) = enabled_rules {
-            enabled_rules.into_iter().collect::<Vec<RuleFilter>>()
+    /// Returns the rules enabled by `rules`, the rules applied to a file once the overrides
+    /// are merged, see [WorkspaceSettings::as_rules]
+    fn build_rule_filter_list<'a>(&'a self, rules: Option<&'a Rules>) -> Vec<RuleFilter> {
+        if let Some(rules) = rules {
+            rules
+                .as_enabled_rules()
+                .into_iter()
+                .collect::<Vec<RuleFilter>>()
         } else {
             vec![]
         }
@@ -172,8 +347,12 @@ impl WorkspaceServer {
         }
 
         match self.syntax.entry(rome_path) {
-            Entry::Occupied(entry) => Ok(entry.get().clone()),
+            Entry::Occupied(entry) => {
+                metrics::record_cache_access("syntax", true);
+                Ok(entry.get().clone())
+            }
             Entry::Vacant(entry) => {
+                metrics::record_cache_access("syntax", false);
                 let rome_path = entry.key();
                 let capabilities = self.get_file_capabilities(rome_path);
 
@@ -190,7 +369,11 @@ impl WorkspaceServer {
                 let size_limit = {
                     let settings = self.settings();
                     let settings = settings.as_ref();
-                    let limit = settings.files.max_size.get();
+                    let limit = settings
+                        .override_settings
+                        .max_size(rome_path.as_path())
+                        .unwrap_or(settings.files.max_size)
+                        .get();
                     usize::try_from(limit).unwrap_or(usize::MAX)
                 };
 
@@ -205,43 +388,170 @@ impl WorkspaceServer {
                 }
 
                 let settings = self.settings();
-                let parsed = parse(
-                    rome_path,
-                    document.language_hint,
-                    document.content.as_str(),
-                    settings,
-                    &mut document.node_cache,
-                );
+                let parsed = debug_span!("parse").in_scope(|| {
+                    parse(
+                        rome_path,
+                        document
+                            .language_hint
+                            .or(self.features.language_of(rome_path)),
+                        document.content.as_str(),
+                        settings,
+                        &mut document.node_cache,
+                    )
+                });
 
                 Ok(entry.insert(parsed).clone())
             }
         }
     }
 
-    /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
-    fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
-        let settings = self.settings();
+    /// Extracts the data of the project analysis from a file: the `package.json` and the
+    /// `tsconfig.json` files resolve the imports of the modules, the classes of the CSS modules
+    /// are compared to the classes that the modules read, and the modules have imports,
+    /// exports and symbols
+    fn analyze_file(
+        &self,
+        rome_path: &RomePath,
+        content: &str,
+        parse: Option<AnyParse>,
+    ) -> FileAnalysis {
+        let file_name = rome_path.file_name().and_then(OsStr::to_str);
+        let resource = if file_name == Some("package.json") {
+            Some(ProjectResource::Manifest(PackageJson::parse(content)))
+        } else if file_name.is_some_and(|file_name| TSCONFIG_FILE_NAMES.contains(&file_name)) {
+            Some(ProjectResource::TsConfig(TsConfigJson::parse(content)))
+        } else if is_css_module(rome_path) {
+            Some(ProjectResource::Stylesheet(css_module_info(content)))
+        } else {
+            None
+        };
 
-        let is_ignored_by_file_config = settings
-            .as_ref()
-            .files
-            .ignored_files
-            .as_ref()
-            .map(|matcher| matcher.matches_path(path));
-        let is_included_by_file_config = settings
-            .as_ref()
-            .files
-            .included_files
-            .as_ref()
-            .map(|matcher| matcher.matches_path(path));
-        if let Some(ignored) = is_ignored_by_file_config {
-            ignored
-        } else if let Some(included) = is_included_by_file_config {
-            !included
+        let capabilities = self.get_file_capabilities(rome_path);
+        FileAnalysis {
+            resource,
+            module: capabilities
+                .analyzer
+                .module_info
+                .zip(parse.clone())
+                .map(|(module_info, parse)| module_info(parse)),
+            symbols: capabilities
+                .analyzer
+                .document_symbols
+                .zip(parse)
+                .map(|(document_symbols, parse)| document_symbols(parse)),
+        }
+    }
+
+    /// Updates the module graph and the symbol index with the analysis of a file. The open
+    /// documents are analyzed now, the indexed files were analyzed when they were indexed.
+    fn analyze_module(&self, task: AnalysisTask) {
+        match task {
+            AnalysisTask::Open(rome_path) => {
+                let content = self
+                    .documents
+                    .get(&rome_path)
+                    .map(|document| document.content.clone());
+                let analysis = match content {
+                    Some(content) => {
+                        let parse = self
+                            .get_parse(rome_path.clone(), Some(FeatureName::Lint))
+                            .ok();
+                        self.analyze_file(&rome_path, &content, parse)
+                    }
+                    None => FileAnalysis::default(),
+                };
+                self.scheduler.update_file(&rome_path, analysis);
+            }
+            AnalysisTask::Indexed(rome_path, analysis) => {
+                self.scheduler.update_file(&rome_path, analysis);
+            }
+        }
+    }
+
+    /// Enables the project analysis, the open documents are analyzed first
+    fn enable_project_analysis(&self) {
+        self.scheduler.enable(|| {
+            self.documents
+                .iter()
+                .map(|document| document.key().clone())
+                .collect()
+        });
+    }
+
+    /// Returns why a file is ignored by the ignore files of the VCS, or by the top-level config
+    /// `files.ignore`/`files.include`
+    fn top_level_ignore_reason(&self, path: &RomePath) -> Option<IgnoreReason> {
+        if let Some(reason) = self
+            .vcs_ignore_files
+            .read()
+            .unwrap()
+            .find(path.as_path(), path.is_dir())
+        {
+            return Some(reason);
+        }
+
+        let settings = self.settings();
+        let files = &settings.as_ref().files;
+        if let Some(matcher) = files.ignored_files.as_ref() {
+            matcher
+                .matches_path(path.as_path())
+                .then(|| IgnoreReason::Ignored {
+                    section: "files".to_string(),
+                })
+        } else if let Some(matcher) = files.included_files.as_ref() {
+            (!matcher.matches_path(path.as_path())).then(|| IgnoreReason::NotIncluded {
+                section: "files".to_string(),
+            })
         } else {
-            false
+            None
         }
     }
+
+    /// Returns why a file is ignored for a feature, `None` when it isn't
+    fn ignore_reason(&self, rome_path: &RomePath, feature: &FeatureName) -> Option<IgnoreReason> {
+        let settings = self.settings();
+        let path = rome_path.as_path();
+
+        // Overrides have top priority
+        if settings.as_ref().override_settings.is_path_excluded(path) == Some(true) {
+            return Some(IgnoreReason::Overrides);
+        }
+        if settings.as_ref().override_settings.is_path_included(path) == Some(true) {
+            return None;
+        }
+
+        let (section, ignored_files, included_files) = match feature {
+            FeatureName::Format => (
+                "formatter",
+                &settings.as_ref().formatter.ignored_files,
+                &settings.as_ref().formatter.included_files,
+            ),
+            FeatureName::Lint => (
+                "linter",
+                &settings.as_ref().linter.ignored_files,
+                &settings.as_ref().linter.included_files,
+            ),
+            FeatureName::OrganizeImports => (
+                "organizeImports",
+                &settings.as_ref().organize_imports.ignored_files,
+                &settings.as_ref().organize_imports.included_files,
+            ),
+            FeatureName::Search => return self.top_level_ignore_reason(rome_path),
+        };
+        if let Some(matcher) = ignored_files.as_ref() {
+            if matcher.matches_path(path) {
+                return Some(IgnoreReason::Ignored {
+                    section: section.to_string(),
+                });
+            }
+        } else if let Some(matcher) = included_files.as_ref() {
+            if matcher.matches_path(path) {
+                return None;
+            }
+        }
+
+        self.top_level_ignore_reason(rome_path)
+    }
 }
 
 impl Workspace for WorkspaceServer {
@@ -252,109 +562,121 @@ impl Workspace for WorkspaceServer {
         let file_features_result = self.file_features.entry(params.path.clone());
         match file_features_result {
             Entry::Occupied(entry) => {
+                metrics::record_cache_access("file_features", true);
                 let result = entry.get();
                 Ok(result.clone())
             }
             Entry::Vacant(entry) => {
-                let capabilities = self.get_file_capabilities(&params.path);
-                let language = Language::from_path(&params.path);
-                let settings = self.settings.read().unwrap();
-                let mut file_features = FileFeaturesResult::new()
-                    .with_capabilities(&capabilities)
-                    .with_settings_and_language(&settings, &language, params.path.as_path());
-
-                if settings.files.ignore_unknown {
-                    let language = self.get_language(&params.path);
-                    if language == Language::Unknown {
-                        file_features.ignore_not_supported();
-                    }
-                }
-
-                for feature in params.feature {
-                    let is_ignored = self.is_path_ignored(IsPathIgnoredParams {
-                        rome_path: params.path.clone(),
-                        feature: feature.clone(),
-                    })?;
-
-                    if is_ignored {
-                        file_features.ignored(feature);
-                    }
-                }
+                metrics::record_cache_access("file_features", false);
+                let file_features = self.compute_file_features(&params.path, params.feature)?;
                 Ok(entry.insert(file_features).clone())
             }
         }
     }
 
-    fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
-        let settings = self.settings();
-        let path = params.rome_path.as_path();
+    fn capabilities(
+        &self,
+        params: FileCapabilitiesParams,
+    ) -> Result<FileCapabilitiesResult, WorkspaceError> {
+        // The cache of `file_features` only knows the features requested the first time
+        let features = self.compute_file_features(
+            &params.path,
+            FeaturesBuilder::new()
+                .with_formatter()
+                .with_linter()
+                .with_organize_imports()
+                .build(),
+        )?;
+        let support_kind = |feature: FeatureName| {
+            features
+                .support_kind_for(&feature)
+                .cloned()
+                .unwrap_or(SupportKind::FileNotSupported)
+        };
+        let lint = support_kind(FeatureName::Lint);
 
-        let excluded_by_override = settings.as_ref().override_settings.is_path_excluded(path);
-        let included_by_override = settings.as_ref().override_settings.is_path_included(path);
+        let capabilities = self.get_file_capabilities(&params.path);
+        let assists = if capabilities.analyzer.code_actions.is_none() {
+            SupportKind::FileNotSupported
+        } else if lint == SupportKind::Ignored {
+            SupportKind::Ignored
+        } else {
+            SupportKind::Supported
+        };
+        let debug = &capabilities.debug;
+        let debug = if debug.debug_syntax_tree.is_some()
+            || debug.debug_control_flow.is_some()
+            || debug.debug_formatter_ir.is_some()
+        {
+            SupportKind::Supported
+        } else {
+            SupportKind::FileNotSupported
+        };
 
-        // Overrides have top priority
-        if let Some(excluded_by_override) = excluded_by_override {
-            if excluded_by_override {
-                return Ok(true);
-            }
-        }
+        Ok(FileCapabilitiesResult {
+            format: support_kind(FeatureName::Format),
+            lint,
+            organize_imports: support_kind(FeatureName::OrganizeImports),
+            assists,
+            debug,
+        })
+    }
 
-        if let Some(included_by_override) = included_by_override {
-            if included_by_override {
-                return Ok(!included_by_override);
-            }
-        }
+    fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
+        Ok(self
+            .ignore_reason(&params.rome_path, &params.feature)
+            .is_some())
+    }
 
-        Ok(match params.feature {
-            FeatureName::Format => {
-                if let Some(matcher) = settings.as_ref().formatter.ignored_files.as_ref() {
-                    let ignored = matcher.matches_path(path);
-                    if ignored {
-                        return Ok(ignored);
-                    }
-                } else if let Some(matcher) = settings.as_ref().formatter.included_files.as_ref() {
-                    let included = matcher.matches_path(path);
-                    if included {
-                        return Ok(!included);
-                    }
-                }
-                self.is_ignored_by_top_level_config(path)
-            }
-            FeatureName::Lint => {
-                if let Some(matcher) = settings.as_ref().linter.ignored_files.as_ref() {
-                    let ignored = matcher.matches_path(path);
-                    if ignored {
-                        return Ok(ignored);
-                    }
-                } else if let Some(matcher) = settings.as_ref().linter.included_files.as_ref() {
-                    let included = matcher.matches_path(path);
-                    if included {
-                        return Ok(!included);
-                    }
-                }
+    fn explain_ignored_path(
+        &self,
+        params: ExplainIgnoredPathParams,
+    ) -> Result<ExplainIgnoredPathResult, WorkspaceError> {
+        Ok(ExplainIgnoredPathResult {
+            reason: self.ignore_reason(&params.path, &params.feature),
+        })
+    }
 
-                self.is_ignored_by_top_level_config(path)
-            }
-            FeatureName::OrganizeImports => {
-                if let Some(matcher) = settings.as_ref().organize_imports.ignored_files.as_ref() {
-                    let ignored = matcher.matches_path(path);
-                    if ignored {
-                        return Ok(ignored);
-                    }
-                } else if let Some(matcher) =
-                    settings.as_ref().organize_imports.included_files.as_ref()
-                {
-                    let included = matcher.matches_path(path);
-                    if included {
-                        return Ok(!included);
-                    }
+    fn enabled_rules(
+        &self,
+        params: EnabledRulesParams,
+    ) -> Result<EnabledRulesResult, WorkspaceError> {
+        let settings = self.settings();
+        let rules = settings
+            .as_ref()
+            .as_rules(params.path.as_path())
+            .unwrap_or_default();
+        let enabled_rules = rules
+            .as_enabled_rules()
+            .into_iter()
+            .filter_map(|filter| match filter {
+                RuleFilter::Rule(group, rule) => {
+                    let category: &Category = format!("lint/{group}/{rule}").parse().ok()?;
+                    Some(EnabledRule {
+                        group: group.to_string(),
+                        rule: rule.to_string(),
+                        severity: rules
+                            .get_severity_from_code(category)
+                            .unwrap_or(Severity::Error),
+                    })
                 }
+                RuleFilter::Group(_) => None,
+            })
+            .collect();
 
-                self.is_ignored_by_top_level_config(path)
-            }
+        Ok(EnabledRulesResult {
+            rules: enabled_rules,
         })
     }
 
+    fn rule_docs(&self, _: RuleDocsParams) -> Result<RuleDocsResult, WorkspaceError> {
+        Ok(RuleDocsResult { rules: rule_docs() })
+    }
+
+    fn metrics(&self, _: MetricsParams) -> Result<MetricsResult, WorkspaceError> {
+        Ok(crate::metrics::report())
+    }
+
     /// Update the global settings for this workspace
     ///
     /// ## Panics
@@ -362,18 +684,80 @@ impl Workspace for WorkspaceServer {
     /// by another thread having previously panicked while holding the lock
     #[tracing::instrument(level = "debug", skip(self))]
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
+        if self.disk_cache.is_some() {
+            let update = serde_json::to_string(&params).unwrap_or_default();
+            let settings_hash = self.settings_hash.load(Ordering::Relaxed);
+            self.settings_hash.store(
+                disk_cache::merge_settings_hash(settings_hash, &update),
+                Ordering::Relaxed,
+            );
+        }
+
         let mut settings = self.settings.write().unwrap();
         settings.merge_with_configuration(params.configuration)?;
+        *self.vcs_ignore_files.write().unwrap() = VcsIgnoreFiles::new(params.vcs_ignore_files);
+        for file in &params.tsconfig_files {
+            self.scheduler.update_tsconfig(
+                &RomePath::new(&file.path),
+                TsConfigJson::parse(&file.content),
+            );
+        }
+        for file in &params.package_json_files {
+            self.scheduler.update_manifest(
+                &RomePath::new(&file.path),
+                PackageJson::parse(&file.content),
+            );
+        }
+        settings.path_aliases = params
+            .tsconfig_files
+            .first()
+            .and_then(|file| self.scheduler.path_aliases(&file.path))
+            .map(|path_aliases| path_aliases.patterns().map(ToString::to_string).collect())
+            .unwrap_or_default();
         // settings changed, hence everything that is computed from the settings needs to be purged
         self.file_features.clear();
+        self.closed_files.lock().unwrap().evict_all();
+        Ok(())
+    }
+
+    fn register_nested_configurations(
+        &self,
+        params: RegisterNestedConfigurationsParams,
+    ) -> Result<(), WorkspaceError> {
+        if self.disk_cache.is_some() {
+            let update = serde_json::to_string(&params).unwrap_or_default();
+            let settings_hash = self.settings_hash.load(Ordering::Relaxed);
+            self.settings_hash.store(
+                disk_cache::merge_settings_hash(settings_hash, &update),
+                Ordering::Relaxed,
+            );
+        }
+
+        let mut settings = self.settings.write().unwrap();
+        settings.register_nested_overrides(params.overrides, params.editorconfig_files)?;
+        self.vcs_ignore_files
+            .write()
+            .unwrap()
+            .extend(params.vcs_ignore_files);
+        self.file_features.clear();
+        self.closed_files.lock().unwrap().evict_all();
         Ok(())
     }
 
     /// Add a new file to the workspace
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError> {
-        self.syntax.remove(&params.path);
+        let reopened = self.closed_files.lock().unwrap().reopen(
+            &params.path,
+            &params.content,
+            params.language_hint,
+        );
+        if let Some(parse) = reopened.parse {
+            self.syntax.insert(params.path.clone(), parse);
+        } else {
+            self.syntax.remove(&params.path);
+        }
         self.documents.insert(
-            params.path,
+            params.path.clone(),
             Document {
                 content: params.content,
                 version: params.version,
@@ -381,6 +765,10 @@ impl Workspace for WorkspaceServer {
                 node_cache: NodeCache::default(),
             },
         );
+        // The module graph already knows the content of the file
+        if !reopened.is_unchanged {
+            self.scheduler.schedule_open(params.path);
+        }
         Ok(())
     }
 
@@ -426,7 +814,7 @@ impl Workspace for WorkspaceServer {
         let settings = self.settings();
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(params.path.as_path()) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -451,18 +839,34 @@ impl Workspace for WorkspaceServer {
         debug_assert!(params.version > document.version);
         document.version = params.version;
         document.content = params.content;
+        // The scheduler reads the documents while it holds its queue
+        drop(document);
 
         self.syntax.remove(&params.path);
+        self.scheduler.schedule_open(params.path);
         Ok(())
     }
 
     /// Remove a file from the workspace
     fn close_file(&self, params: CloseFileParams) -> Result<(), WorkspaceError> {
-        self.documents
+        // The module graph keeps the last content of the document
+        if self.scheduler.unschedule_open(&params.path) {
+            self.analyze_module(AnalysisTask::Open(params.path.clone()));
+        }
+
+        let (path, document) = self
+            .documents
             .remove(&params.path)
             .ok_or_else(WorkspaceError::not_found)?;
 
-        self.syntax.remove(&params.path);
+        if let Some((_, parse)) = self.syntax.remove(&params.path) {
+            self.closed_files.lock().unwrap().insert(
+                path,
+                &document.content,
+                document.language_hint,
+                parse,
+            );
+        }
         Ok(())
     }
 
@@ -471,67 +875,38 @@ impl Workspace for WorkspaceServer {
         &self,
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
-        let feature = if params.categories.is_syntax() {
-            FeatureName::Format
-        } else {
-            FeatureName::Lint
+        let Some(disk_cache) = &self.disk_cache else {
+            return self.lint_file(params);
         };
 
-        let parse = self.get_parse(params.path.clone(), Some(feature))?;
-        let settings = self.settings.read().unwrap();
+        let key = {
+            let document = self
+                .documents
+                .get(&params.path)
+                .ok_or_else(WorkspaceError::not_found)?;
+            disk_cache::diagnostics_key(
+                self.settings_hash.load(Ordering::Relaxed),
+                &params,
+                &document.content,
+                &self.scheduler.module_packages(params.path.as_path()),
+            )
+        };
 
-        let (diagnostics, errors, skipped_diagnostics) = if let Some(lint) =
-            self.get_file_capabilities(&params.path).analyzer.lint
-        {
-            let rules = settings.linter().rules.as_ref();
-            let overrides = &settings.override_settings;
-            let mut rule_filter_list =
-                self.build_rule_filter_list(rules, overrides, params.path.as_path());
-            if settings.organize_imports.enabled && !params.categories.is_syntax() {
-                rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
+        // The entries keep all the diagnostics of a file, so they can be used as long as the
+        // request accepts all of them
+        if let Some(result) = disk_cache.get(key) {
+            if result.diagnostics.len() as u64 <= params.max_diagnostics {
+                metrics::record_cache_access("diagnostics", true);
+                return Ok(result);
             }
-            let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
-            filter.categories = params.categories;
-
-            info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
-                trace!("Analyzer filter to apply to lint: {:?}", &filter);
-
-                let results = lint(LintParams {
-                    parse,
-                    filter,
-                    rules,
-                    settings: self.settings(),
-                    max_diagnostics: params.max_diagnostics,
-                    path: &params.path,
-                });
-
-                (
-                    results.diagnostics,
-                    results.errors,
-                    results.skipped_diagnostics,
-                )
-            })
-        } else {
-            let parse_diagnostics = parse.into_diagnostics();
-            let errors = parse_diagnostics
-                .iter()
-                .filter(|diag| diag.severity() <= Severity::Error)
-                .count();
-
-            (parse_diagnostics, errors, 0)
-        };
+        }
+        metrics::record_cache_access("diagnostics", false);
 
-        Ok(PullDiagnosticsResult {
-            diagnostics: diagnostics
-                .into_iter()
-                .map(|diag| {
-                    let diag = diag.with_file_path(params.path.as_path().display().to_string());
-                    SerdeDiagnostic::new(diag)
-                })
-                .collect(),
-            errors,
-            skipped_diagnostics,
-        })
+        let result = self.lint_file(params)?;
+        if result.skipped_diagnostics == 0 {
+            disk_cache.insert(key, &result);
+        }
+        Ok(result)
     }
 
     /// Retrieves the list of code actions available for a given cursor
@@ -545,11 +920,11 @@ impl Workspace for WorkspaceServer {
 
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
         let settings = self.settings.read().unwrap();
-        let rules = settings.linter().rules.as_ref();
+        let rules = settings.as_rules(params.path.as_path());
         Ok(code_actions(
             parse,
             params.range,
-            rules,
+            rules.as_ref(),
             self.settings(),
             &params.path,
         ))
@@ -566,7 +941,7 @@ impl Workspace for WorkspaceServer {
         let settings = self.settings();
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(params.path.as_path()) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -582,7 +957,7 @@ impl Workspace for WorkspaceServer {
         let settings = self.settings();
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(params.path.as_path()) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -598,14 +973,14 @@ impl Workspace for WorkspaceServer {
 
         let settings = self.settings();
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(params.path.as_path()) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
         format_on_type(&params.path, parse, settings, params.offset)
     }
 
-    fn fix_file(&self, params: super::FixFileParams) -> Result<FixFileResult, WorkspaceError> {
+    fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError> {
         let capabilities = self.get_file_capabilities(&params.path);
         let fix_all = capabilities
             .analyzer
@@ -615,18 +990,32 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
 
         let rules = settings.as_rules(params.path.as_path());
-        let overrides = &settings.override_settings;
-        let rule_filter_list =
-            self.build_rule_filter_list(rules.as_ref(), overrides, params.path.as_path());
-        let filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+        let rule_filter_list = if params.only.is_empty() {
+            self.build_rule_filter_list(rules.as_ref())
+        } else {
+            params
+                .only
+                .iter()
+                .map(RuleSelector::as_rule_filter)
+                .collect()
+        };
+        let skipped_rules: Vec<RuleFilter> = params
+            .skip
+            .iter()
+            .map(RuleSelector::as_rule_filter)
+            .collect();
+        let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+        filter.disabled_rules = Some(skipped_rules.as_slice());
         fix_all(FixAllParams {
             parse,
             rules: rules.as_ref(),
             fix_file_mode: params.fix_file_mode,
+            unsafe_rules: &params.unsafe_rules,
             filter,
             settings: self.settings(),
             should_format: params.should_format,
             rome_path: &params.path,
+            cancellation: params.cancellation.clone(),
         })
     }
 
@@ -643,10 +1032,53 @@ impl Workspace for WorkspaceServer {
         Ok(result)
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let prepare_rename = capabilities
+            .analyzer
+            .prepare_rename
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        prepare_rename(parse, params.symbol_at)
+    }
+
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let inlay_hints = capabilities
+            .analyzer
+            .inlay_hints
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path, None)?;
+        Ok(InlayHintsResult {
+            hints: inlay_hints(parse, params.range),
+        })
+    }
+
     fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
+        let memory_usage = resident_memory().unwrap_or_else(|| String::from("unavailable"));
+        let closed_files = self.closed_files.lock().unwrap();
         let entries = vec![
             RageEntry::section("Workspace"),
             RageEntry::pair("Open Documents", &format!("{}", self.documents.len())),
+            RageEntry::pair("Parsed Files", &format!("{}", self.syntax.len())),
+            RageEntry::pair(
+                "Project Modules",
+                &format!("{}", self.scheduler.analyzed_files()),
+            ),
+            RageEntry::pair(
+                "Closed Files Kept",
+                &format!(
+                    "{} ({} bytes of source code)",
+                    closed_files.kept_trees(),
+                    closed_files.kept_source_size()
+                ),
+            ),
+            RageEntry::pair("Memory Usage", &memory_usage),
         ];
 
         Ok(RageResult { entries })
@@ -656,6 +1088,10 @@ impl Workspace for WorkspaceServer {
         None
     }
 
+    fn projects(&self, _: ProjectsParams) -> Result<ProjectsResult, WorkspaceError> {
+        Ok(ProjectsResult::default())
+    }
+
     fn organize_imports(
         &self,
         params: OrganizeImportsParams,
@@ -666,9 +1102,347 @@ impl Workspace for WorkspaceServer {
             .organize_imports
             .ok_or_else(self.build_capability_error(&params.path))?;
 
+        let parse = self.get_parse(params.path.clone(), None)?;
+        let result = organize_imports(parse, self.settings(), &params.path)?;
+
+        Ok(result)
+    }
+
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let search = capabilities
+            .analyzer
+            .search
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
         let parse = self.get_parse(params.path, None)?;
-        let result = organize_imports(parse)?;
+        let matches = search(parse, &params.pattern)?;
+
+        Ok(SearchResults { matches })
+    }
+
+    fn index_file(&self, params: IndexFileParams) -> Result<(), WorkspaceError> {
+        self.enable_project_analysis();
+
+        // The content of an open document is more recent than the content on disk
+        if self.documents.contains_key(&params.path) {
+            return Ok(());
+        }
+
+        let is_ignored = self.is_path_ignored(IsPathIgnoredParams {
+            rome_path: params.path.clone(),
+            feature: FeatureName::Lint,
+        })?;
+        if !is_ignored {
+            // Only the data extracted from the file is queued, not its content
+            let capabilities = self.get_file_capabilities(&params.path);
+            let parse = capabilities.parser.parse.map(|parse| {
+                parse(
+                    &params.path,
+                    self.features.language_of(&params.path),
+                    &params.content,
+                    self.settings(),
+                    &mut NodeCache::default(),
+                )
+            });
+            let analysis = self.analyze_file(&params.path, &params.content, parse);
+            self.scheduler.schedule_indexed(params.path, analysis);
+        }
+
+        Ok(())
+    }
+
+    fn analyze_project(
+        &self,
+        params: AnalyzeProjectParams,
+    ) -> Result<AnalyzeProjectResult, WorkspaceError> {
+        self.enable_project_analysis();
+
+        let max_files = params.max_files.unwrap_or(u32::MAX);
+        let mut analyzed = 0;
+        while analyzed < max_files && !params.cancellation.is_cancelled() {
+            let Some(task) = self.scheduler.next_task() else {
+                break;
+            };
+            self.analyze_module(task);
+            analyzed += 1;
+        }
+
+        let pending = self.scheduler.pending();
+        let documents = self
+            .documents
+            .iter()
+            .map(|document| document.key().clone())
+            .collect();
+
+        Ok(AnalyzeProjectResult {
+            analyzed,
+            pending: u32::try_from(pending).unwrap_or(u32::MAX),
+            affected: self.scheduler.take_affected_documents(documents),
+        })
+    }
+
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullDiagnosticsResult, WorkspaceError> {
+        self.enable_project_analysis();
+
+        // The file asked by the client doesn't wait for the rest of the queue
+        if self.scheduler.unschedule_open(&params.path) {
+            self.analyze_module(AnalysisTask::Open(params.path.clone()));
+        }
+
+        let settings = self.settings();
+        let diagnostics = self
+            .scheduler
+            .project_diagnostics(params.path.as_path(), &settings.as_ref().project)
+            .into_iter()
+            .map(|diagnostic| {
+                let diagnostic =
+                    diagnostic.with_file_path(params.path.as_path().display().to_string());
+                SerdeDiagnostic::new(diagnostic)
+            })
+            .collect();
+
+        Ok(PullDiagnosticsResult {
+            diagnostics,
+            errors: 0,
+            skipped_diagnostics: 0,
+        })
+    }
+
+    fn pull_project_actions(
+        &self,
+        params: PullProjectActionsParams,
+    ) -> Result<PullProjectActionsResult, WorkspaceError> {
+        self.enable_project_analysis();
+
+        // The actions edit the imports of the open documents, their ranges must be up to date
+        for path in self.scheduler.take_open() {
+            self.analyze_module(AnalysisTask::Open(path));
+        }
+        // Until the whole project is indexed, a module that imports the file may be missing
+        if self.scheduler.pending() > 0 {
+            return Ok(PullProjectActionsResult::default());
+        }
+
+        let capabilities = self.get_file_capabilities(&params.path);
+        let Some(export_conversions) = capabilities.analyzer.export_conversions else {
+            return Ok(PullProjectActionsResult::default());
+        };
+
+        let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
+        let actions = export_conversions(parse, params.range)
+            .into_iter()
+            .filter_map(|conversion| {
+                self.scheduler
+                    .export_conversion_action(&params.path, conversion)
+            })
+            .collect();
+
+        Ok(PullProjectActionsResult { actions })
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let document_symbols = capabilities
+            .analyzer
+            .document_symbols
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path, None)?;
+        Ok(DocumentSymbolsResult {
+            symbols: document_symbols(parse),
+        })
+    }
+
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError> {
+        self.enable_project_analysis();
+
+        // The symbols of the documents being edited don't wait for the rest of the queue
+        for path in self.scheduler.take_open() {
+            self.analyze_module(AnalysisTask::Open(path));
+        }
+
+        let max_results = params
+            .max_results
+            .map_or(usize::MAX, |max_results| max_results as usize);
+        Ok(WorkspaceSymbolsResult {
+            symbols: self.scheduler.search_symbols(&params.query, max_results),
+        })
+    }
+
+    fn open_files(&self, params: OpenFilesParams) -> Result<FilesResult, WorkspaceError> {
+        let errors = params
+            .files
+            .into_iter()
+            .filter_map(|file| {
+                let path = file.path.clone();
+                self.open_file(file)
+                    .err()
+                    .map(|error| file_error(path, error))
+            })
+            .collect();
+
+        Ok(FilesResult { errors })
+    }
+
+    fn change_files(&self, params: ChangeFilesParams) -> Result<FilesResult, WorkspaceError> {
+        let errors = params
+            .files
+            .into_iter()
+            .filter_map(|file| {
+                let path = file.path.clone();
+                self.change_file(file)
+                    .err()
+                    .map(|error| file_error(path, error))
+            })
+            .collect();
+
+        Ok(FilesResult { errors })
+    }
+
+    fn close_files(&self, params: CloseFilesParams) -> Result<FilesResult, WorkspaceError> {
+        let errors = params
+            .paths
+            .into_iter()
+            .filter_map(|path| {
+                self.close_file(CloseFileParams { path: path.clone() })
+                    .err()
+                    .map(|error| file_error(path, error))
+            })
+            .collect();
+
+        Ok(FilesResult { errors })
+    }
+
+    fn pull_files_diagnostics(
+        &self,
+        params: PullFilesDiagnosticsParams,
+    ) -> Result<PullFilesDiagnosticsResult, WorkspaceError> {
+        let mut result = PullFilesDiagnosticsResult::default();
+        for path in params.paths {
+            if params.cancellation.is_cancelled() {
+                return Err(WorkspaceError::cancelled());
+            }
+            let diagnostics = self.pull_diagnostics(PullDiagnosticsParams {
+                path: path.clone(),
+                categories: params.categories,
+                max_diagnostics: params.max_diagnostics,
+                only: params.only.clone(),
+                skip: params.skip.clone(),
+                cancellation: params.cancellation.clone(),
+            });
+            match diagnostics {
+                Ok(diagnostics) => result.files.push(FileDiagnostics {
+                    path,
+                    result: diagnostics,
+                }),
+                Err(error) => result.errors.push(file_error(path, error)),
+            }
+        }
 
         Ok(result)
     }
+
+    fn fix_files(&self, params: FixFilesParams) -> Result<FixFilesResult, WorkspaceError> {
+        let mut result = FixFilesResult::default();
+        // A path listed twice would get two replacements of the same text
+        let mut fixed_paths = FxHashSet::default();
+        for path in params.paths {
+            if params.cancellation.is_cancelled() {
+                return Err(WorkspaceError::cancelled());
+            }
+            if !fixed_paths.insert(path.clone()) {
+                continue;
+            }
+            let fixed = self
+                .get_file_content(GetFileContentParams { path: path.clone() })
+                .and_then(|content| {
+                    let fixed = self.fix_file(FixFileParams {
+                        path: path.clone(),
+                        fix_file_mode: params.fix_file_mode,
+                        should_format: params.should_format,
+                        only: params.only.clone(),
+                        skip: params.skip.clone(),
+                        unsafe_rules: params.unsafe_rules.clone(),
+                        cancellation: params.cancellation.clone(),
+                    })?;
+                    Ok((content, fixed))
+                });
+            match fixed {
+                Ok((content, fixed)) => {
+                    result.remaining_errors += fixed.errors;
+                    result.skipped_suggested_fixes += fixed.skipped_suggested_fixes;
+                    if let Some(replacement) = changed_text(&content, &fixed.code) {
+                        result.files.push(FixedFile {
+                            path,
+                            replacement,
+                            actions: fixed.actions,
+                        });
+                    }
+                }
+                Err(error) => result.errors.push(file_error(path, error)),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Returns the replacement of the text of `old` that differs from `new`: the text between
+/// their common prefix and their common suffix. Returns `None` when they're equal
+fn changed_text(old: &str, new: &str) -> Option<TextReplacement> {
+    if old == new {
+        return None;
+    }
+    let prefix = old
+        .char_indices()
+        .zip(new.chars())
+        .find(|((_, old_char), new_char)| old_char != new_char)
+        .map_or(old.len().min(new.len()), |((index, _), _)| index);
+    let suffix: usize = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(old_char, new_char)| old_char == new_char)
+        .map(|(old_char, _)| old_char.len_utf8())
+        .sum();
+    let range = TextRange::new(
+        TextSize::from(prefix as u32),
+        TextSize::from((old.len() - suffix) as u32),
+    );
+    Some(TextReplacement::new(
+        range,
+        &new[prefix..new.len() - suffix],
+    ))
+}
+
+fn file_error(path: RomePath, error: WorkspaceError) -> FileError {
+    FileError {
+        path,
+        error: SerdeDiagnostic::new(error),
+    }
+}
+
+/// Returns the resident memory of the current process, when the platform exposes it.
+///
+/// When the workspace runs inside the daemon, this is the memory used by the daemon.
+fn resident_memory() -> Option<String> {
+    if cfg!(target_os = "linux") {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))
+            .map(|value| value.trim().to_string())
+    } else {
+        None
+    }
 }
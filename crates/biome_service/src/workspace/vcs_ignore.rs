@@ -0,0 +1,232 @@
+//! The ignore files of the VCS, e.g. the `.gitignore` files of a project.
+//!
+//! The patterns follow the syntax of git: a pattern without a separator matches the files and
+//! the directories with that name at any depth, a pattern with a separator is relative to the
+//! directory of its ignore file, a trailing `/` matches only directories, and a leading `!`
+//! includes again what the previous patterns ignored. The last pattern that matches a path
+//! decides whether it's ignored. Like git, a file can't be included again when one of its
+//! directories is ignored.
+
+use crate::matcher::{MatchOptions, Pattern};
+use crate::workspace::{IgnoreReason, VcsIgnoreFile};
+use std::path::{Component, Path, PathBuf};
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+#[derive(Debug, Default)]
+pub(super) struct VcsIgnoreFiles {
+    files: Vec<CompiledIgnoreFile>,
+}
+
+#[derive(Debug)]
+struct CompiledIgnoreFile {
+    path: PathBuf,
+    directory: PathBuf,
+    patterns: Vec<IgnorePattern>,
+}
+
+#[derive(Debug)]
+struct IgnorePattern {
+    pattern: Pattern,
+    /// The line of the file, as written
+    source: String,
+    /// The line of the pattern, starting from 1
+    line: u32,
+    /// Whether the pattern starts with `!`
+    is_negated: bool,
+    /// Whether the pattern is relative to the directory of the file, instead of matching the
+    /// name of a file at any depth
+    is_anchored: bool,
+    /// Whether the pattern ends with `/`
+    only_directories: bool,
+}
+
+impl VcsIgnoreFiles {
+    /// Compiles the patterns of the ignore files. Like git, the invalid patterns are skipped.
+    pub(super) fn new(files: Vec<VcsIgnoreFile>) -> Self {
+        let mut ignore_files = Self::default();
+        ignore_files.extend(files);
+        ignore_files
+    }
+
+    /// Adds files that take precedence over the files already added, e.g. the ignore files of
+    /// the subdirectories found while the files are handled
+    pub(super) fn extend(&mut self, files: Vec<VcsIgnoreFile>) {
+        self.files.extend(files.into_iter().map(|file| {
+            CompiledIgnoreFile {
+                directory: normalize(&file.directory),
+                patterns: file
+                    .content
+                    .lines()
+                    .zip(1..)
+                    .filter_map(|(source, line)| IgnorePattern::parse(source, line))
+                    .collect(),
+                path: file.path,
+            }
+        }));
+    }
+
+    /// Returns the pattern that ignores `path`, or one of its directories
+    pub(super) fn find(&self, path: &Path, is_dir: bool) -> Option<IgnoreReason> {
+        if self.files.is_empty() {
+            return None;
+        }
+
+        let path = normalize(path);
+        let components: Vec<_> = path.components().collect();
+        for index in 1..=components.len() {
+            let ancestor: PathBuf = components[..index].iter().collect();
+            let is_ancestor_dir = is_dir || index < components.len();
+            let last_match = self.files.iter().rev().find_map(|file| {
+                let relative_path = ancestor.strip_prefix(&file.directory).ok()?;
+                let pattern = file
+                    .patterns
+                    .iter()
+                    .rev()
+                    .find(|pattern| pattern.matches(relative_path, is_ancestor_dir))?;
+                Some((file, pattern))
+            });
+            match last_match {
+                Some((file, pattern)) if !pattern.is_negated => {
+                    return Some(IgnoreReason::VcsIgnoreFile {
+                        path: file.path.clone(),
+                        line: pattern.line,
+                        pattern: pattern.source.clone(),
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+impl IgnorePattern {
+    fn parse(source: &str, line: u32) -> Option<Self> {
+        let source = source.trim_end();
+        if source.is_empty() || source.starts_with('#') {
+            return None;
+        }
+
+        let (pattern, is_negated) = match source.strip_prefix('!') {
+            Some(pattern) => (pattern, true),
+            // `\#` and `\!` match a leading `#` or `!` literally
+            None => (
+                source
+                    .strip_prefix('\\')
+                    .filter(|pattern| pattern.starts_with(['#', '!']))
+                    .unwrap_or(source),
+                false,
+            ),
+        };
+        let (pattern, only_directories) = match pattern.strip_suffix('/') {
+            Some(pattern) => (pattern, true),
+            None => (pattern, false),
+        };
+        let is_anchored = pattern.contains('/');
+        let pattern = if is_anchored {
+            Pattern::new(&format!("/{}", pattern.trim_start_matches('/')))
+        } else {
+            Pattern::new(pattern)
+        };
+
+        Some(Self {
+            pattern: pattern.ok()?,
+            source: source.to_string(),
+            line,
+            is_negated,
+            is_anchored,
+            only_directories,
+        })
+    }
+
+    fn matches(&self, relative_path: &Path, is_dir: bool) -> bool {
+        if self.only_directories && !is_dir {
+            return false;
+        }
+
+        if self.is_anchored {
+            self.pattern
+                .matches_path_with(&Path::new("/").join(relative_path), MATCH_OPTIONS)
+        } else {
+            relative_path.file_name().is_some_and(|file_name| {
+                self.pattern
+                    .matches_with(&file_name.to_string_lossy(), MATCH_OPTIONS)
+            })
+        }
+    }
+}
+
+/// Removes the `.` components of a path, e.g. `./src` gives `src`
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignore_file(directory: &str, content: &str) -> VcsIgnoreFile {
+        VcsIgnoreFile {
+            path: Path::new(directory).join(".gitignore"),
+            directory: PathBuf::from(directory),
+            content: content.to_string(),
+        }
+    }
+
+    fn is_ignored(files: &VcsIgnoreFiles, path: &str) -> bool {
+        files.find(Path::new(path), false).is_some()
+    }
+
+    #[test]
+    fn matches_the_patterns_like_git() {
+        let files = VcsIgnoreFiles::new(vec![ignore_file(
+            "",
+            "# comment\n*.log\n/dist\nbuild/\ndocs/*.md\n!important.log\n\\#hash.js",
+        )]);
+
+        assert!(is_ignored(&files, "a.log"));
+        assert!(is_ignored(&files, "./src/a.log"));
+        assert!(!is_ignored(&files, "src/important.log"));
+        assert!(is_ignored(&files, "dist/index.js"));
+        assert!(!is_ignored(&files, "src/dist/index.js"));
+        assert!(is_ignored(&files, "src/build/index.js"));
+        assert!(!is_ignored(&files, "src/build"));
+        assert!(is_ignored(&files, "docs/a.md"));
+        assert!(!is_ignored(&files, "docs/api/a.md"));
+        assert!(is_ignored(&files, "#hash.js"));
+        assert!(!is_ignored(&files, "comment"));
+    }
+
+    #[test]
+    fn the_nested_files_take_precedence() {
+        let files = VcsIgnoreFiles::new(vec![
+            ignore_file("", "*.js\ngenerated/"),
+            ignore_file("packages/a", "!*.js\n/local.ts"),
+        ]);
+
+        assert!(is_ignored(&files, "index.js"));
+        assert!(!is_ignored(&files, "packages/a/index.js"));
+        assert!(is_ignored(&files, "packages/b/index.js"));
+        assert!(is_ignored(&files, "packages/a/local.ts"));
+        assert!(!is_ignored(&files, "packages/a/src/local.ts"));
+        // A file can't be included again when its directory is ignored
+        assert!(is_ignored(&files, "packages/a/generated/index.js"));
+
+        assert_eq!(
+            files.find(Path::new("packages/a/local.ts"), false),
+            Some(IgnoreReason::VcsIgnoreFile {
+                path: PathBuf::from("packages/a/.gitignore"),
+                line: 2,
+                pattern: "/local.ts".to_string(),
+            })
+        );
+    }
+}
@@ -453,10 +453,11 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 18] {
+pub fn methods() -> [WorkspaceMethod; 36] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
         workspace_method!(update_settings),
+        workspace_method!(register_nested_configurations),
         workspace_method!(project_features),
         workspace_method!(open_file),
         workspace_method!(change_file),
@@ -473,5 +474,23 @@ pub fn methods() -> [WorkspaceMethod; 18] {
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(prepare_rename),
+        workspace_method!(inlay_hints),
+        workspace_method!(search_pattern),
+        workspace_method!(index_file),
+        workspace_method!(analyze_project),
+        workspace_method!(pull_project_diagnostics),
+        workspace_method!(document_symbols),
+        workspace_method!(workspace_symbols),
+        workspace_method!(open_files),
+        workspace_method!(change_files),
+        workspace_method!(close_files),
+        workspace_method!(pull_files_diagnostics),
+        workspace_method!(fix_files),
+        workspace_method!(explain_ignored_path),
+        workspace_method!(enabled_rules),
+        workspace_method!(rule_docs),
+        workspace_method!(capabilities),
+        workspace_method!(metrics),
     ]
 }
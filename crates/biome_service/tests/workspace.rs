@@ -1,6 +1,26 @@
+use biome_analyze::RuleCategories;
+use biome_deserialize::StringSet;
+use biome_diagnostics::{Diagnostic, Severity};
 use biome_fs::RomePath;
-use biome_js_syntax::TextSize;
-use biome_service::workspace::{server, FileGuard, Language, OpenFileParams};
+use biome_js_syntax::{TextRange, TextSize};
+use biome_service::configuration::linter::{
+    RuleFixKind, RulePlainConfiguration, RuleWithOptions, Suspicious,
+};
+use biome_service::configuration::{
+    BoundaryConstraint, FormatterConfiguration, LinterConfiguration, ModuleTags,
+    ProjectConfiguration, ProjectRules,
+};
+use biome_service::file_handlers::{Capabilities, ExtensionHandler, ExtensionRegistry, Mime};
+use biome_service::workspace::{
+    server, server_with_cache, server_with_extensions, AnalyzeProjectParams, CancellationToken,
+    ChangeFileParams, CloseFilesParams, FeatureName, FeaturesBuilder, FileCapabilitiesParams,
+    FileGuard, FixFileMode, FixFileParams, FixFilesParams, IndexFileParams, Language,
+    OpenFileParams, OpenFilesParams, PrepareRenameParams, PullDiagnosticsParams,
+    PullFilesDiagnosticsParams, PullProjectDiagnosticsParams, RuleDocsParams, RuleSelector,
+    SupportKind, SupportsFeatureParams, TsConfigFile, UpdateSettingsParams,
+};
+use biome_service::{Configuration, RuleConfiguration, Rules, WorkspaceError};
+use std::str::FromStr;
 
 #[test]
 fn debug_control_flow() {
@@ -27,3 +47,959 @@ fn debug_control_flow() {
 
     assert_eq!(cfg, GRAPH);
 }
+
+#[test]
+fn reports_the_import_cycles_of_the_project() {
+    let workspace = server();
+
+    workspace
+        .index_file(IndexFileParams {
+            path: RomePath::new("b.js"),
+            content: "import { a } from \"./a.js\";\nexport const b = a;".into(),
+        })
+        .unwrap();
+    workspace
+        .open_file(OpenFileParams {
+            path: RomePath::new("a.js"),
+            content: "export const a = 1;".into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        })
+        .unwrap();
+
+    let result = workspace
+        .analyze_project(AnalyzeProjectParams {
+            max_files: None,
+            cancellation: CancellationToken::default(),
+        })
+        .unwrap();
+    assert_eq!(result.analyzed, 2);
+    assert_eq!(result.pending, 0);
+
+    let pull_project_diagnostics = |path: &str| {
+        workspace
+            .pull_project_diagnostics(PullProjectDiagnosticsParams {
+                path: RomePath::new(path),
+            })
+            .unwrap()
+            .diagnostics
+    };
+    assert!(pull_project_diagnostics("a.js").is_empty());
+
+    // Only the changed file is analyzed again
+    workspace
+        .change_file(ChangeFileParams {
+            path: RomePath::new("a.js"),
+            content: "import { b } from \"./b\";\nexport const a = 1;".into(),
+            version: 1,
+        })
+        .unwrap();
+
+    let diagnostics = pull_project_diagnostics("a.js");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].category().map(|category| category.name()),
+        Some("project/importCycle")
+    );
+}
+
+#[test]
+fn applies_the_configuration_and_the_suppressions_of_the_project_rules() {
+    let workspace = server();
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: Configuration {
+                project: Some(ProjectConfiguration {
+                    rules: Some(ProjectRules {
+                        import_cycle: Some(RulePlainConfiguration::Error),
+                        barrel_import: Some(RulePlainConfiguration::Off),
+                        ..ProjectRules::default()
+                    }),
+                    ..ProjectConfiguration::default()
+                }),
+                ..Configuration::default()
+            },
+            vcs_ignore_files: vec![],
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })
+        .unwrap();
+
+    let index_file = |path: &str, content: &str| {
+        workspace
+            .index_file(IndexFileParams {
+                path: RomePath::new(path),
+                content: content.into(),
+            })
+            .unwrap();
+    };
+    index_file(
+        "a.js",
+        "import { b } from \"./b.js\";\nimport { button } from \"./components.js\";\nexport const a = [b, button];",
+    );
+    index_file(
+        "b.js",
+        "// biome-ignore project/importCycle: a is only read lazily\nimport { a } from \"./a.js\";\nexport const b = () => a;",
+    );
+    index_file("components.js", "export * from \"./button.js\";");
+    index_file("button.js", "export const button = 1;");
+
+    workspace
+        .analyze_project(AnalyzeProjectParams {
+            max_files: None,
+            cancellation: CancellationToken::default(),
+        })
+        .unwrap();
+
+    let pull_project_diagnostics = |path: &str| {
+        workspace
+            .pull_project_diagnostics(PullProjectDiagnosticsParams {
+                path: RomePath::new(path),
+            })
+            .unwrap()
+            .diagnostics
+    };
+
+    // The barrel import is turned off, and the cycle is reported with the configured severity
+    let diagnostics = pull_project_diagnostics("a.js");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].category().map(|category| category.name()),
+        Some("project/importCycle")
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Error);
+
+    // The comment suppresses the cycle in the other module
+    assert!(pull_project_diagnostics("b.js").is_empty());
+}
+
+#[test]
+fn reports_the_open_documents_affected_by_the_analysis() {
+    let workspace = server();
+
+    for (path, content) in [
+        ("a.js", "import { b } from \"./b.js\";\nexport const a = b;"),
+        ("b.js", "export const b = 1;"),
+        ("c.js", "export const c = 1;"),
+    ] {
+        workspace
+            .open_file(OpenFileParams {
+                path: RomePath::new(path),
+                content: content.into(),
+                version: 0,
+                language_hint: Language::JavaScript,
+            })
+            .unwrap();
+    }
+
+    let analyze_project = || {
+        let mut affected = workspace
+            .analyze_project(AnalyzeProjectParams {
+                max_files: None,
+                cancellation: CancellationToken::default(),
+            })
+            .unwrap()
+            .affected;
+        affected.sort_by(|a, b| a.as_path().cmp(b.as_path()));
+        affected
+    };
+    assert_eq!(
+        analyze_project(),
+        vec![
+            RomePath::new("a.js"),
+            RomePath::new("b.js"),
+            RomePath::new("c.js")
+        ]
+    );
+
+    // The module that imports the changed module is affected too
+    workspace
+        .change_file(ChangeFileParams {
+            path: RomePath::new("b.js"),
+            content: "export const b = 2;".into(),
+            version: 1,
+        })
+        .unwrap();
+    assert_eq!(
+        analyze_project(),
+        vec![RomePath::new("a.js"), RomePath::new("b.js")]
+    );
+}
+
+#[test]
+fn reports_the_imports_of_the_barrel_files() {
+    let workspace = server();
+
+    let index_file = |path: &str, content: &str| {
+        workspace
+            .index_file(IndexFileParams {
+                path: RomePath::new(path),
+                content: content.into(),
+            })
+            .unwrap();
+    };
+    index_file("components/index.js", "export * from \"./button.js\";");
+    index_file("components/button.js", "export const Button = 1;");
+    index_file(
+        "app.js",
+        "import { Button } from \"./components\";\nimport { Button as B } from \"./components/button\";",
+    );
+
+    workspace
+        .analyze_project(AnalyzeProjectParams {
+            max_files: None,
+            cancellation: CancellationToken::default(),
+        })
+        .unwrap();
+
+    let diagnostics = workspace
+        .pull_project_diagnostics(PullProjectDiagnosticsParams {
+            path: RomePath::new("app.js"),
+        })
+        .unwrap()
+        .diagnostics;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].category().map(|category| category.name()),
+        Some("project/barrelImport")
+    );
+}
+
+#[test]
+fn reports_the_imports_that_break_the_module_boundaries() {
+    let workspace = server();
+
+    let string_set =
+        |items: &[&str]| StringSet::new(items.iter().map(|item| item.to_string()).collect());
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: Configuration {
+                project: Some(ProjectConfiguration {
+                    tags: Some(vec![
+                        ModuleTags {
+                            include: string_set(&["packages/ui"]),
+                            tags: string_set(&["scope:ui"]),
+                        },
+                        ModuleTags {
+                            include: string_set(&["packages/server"]),
+                            tags: string_set(&["scope:server"]),
+                        },
+                    ]),
+                    boundaries: Some(vec![BoundaryConstraint {
+                        source_tag: "scope:ui".into(),
+                        only_depend_on_tags: None,
+                        not_depend_on_tags: Some(string_set(&["scope:server"])),
+                    }]),
+                    rules: None,
+                }),
+                ..Configuration::default()
+            },
+            vcs_ignore_files: vec![],
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })
+        .unwrap();
+
+    let index_file = |path: &str, content: &str| {
+        workspace
+            .index_file(IndexFileParams {
+                path: RomePath::new(path),
+                content: content.into(),
+            })
+            .unwrap();
+    };
+    index_file("packages/server/db.js", "export const query = 1;");
+    index_file("packages/ui/theme.js", "export const theme = 1;");
+    index_file(
+        "packages/ui/button.js",
+        "import { query } from \"../server/db.js\";\nimport { theme } from \"./theme.js\";",
+    );
+
+    workspace
+        .analyze_project(AnalyzeProjectParams {
+            max_files: None,
+            cancellation: CancellationToken::default(),
+        })
+        .unwrap();
+
+    let diagnostics = workspace
+        .pull_project_diagnostics(PullProjectDiagnosticsParams {
+            path: RomePath::new("packages/ui/button.js"),
+        })
+        .unwrap()
+        .diagnostics;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].category().map(|category| category.name()),
+        Some("project/moduleBoundary")
+    );
+}
+
+#[test]
+fn reports_the_server_only_imports_of_the_client_modules() {
+    let workspace = server();
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: Configuration {
+                project: Some(ProjectConfiguration {
+                    rules: Some(ProjectRules {
+                        server_only_import: Some(RulePlainConfiguration::Warn),
+                        ..ProjectRules::default()
+                    }),
+                    ..ProjectConfiguration::default()
+                }),
+                ..Configuration::default()
+            },
+            vcs_ignore_files: vec![],
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })
+        .unwrap();
+
+    let index_file = |path: &str, content: &str| {
+        workspace
+            .index_file(IndexFileParams {
+                path: RomePath::new(path),
+                content: content.into(),
+            })
+            .unwrap();
+    };
+    index_file(
+        "lib/db.js",
+        "import \"server-only\";\nexport const query = 1;",
+    );
+    index_file("lib/format.js", "export const format = 1;");
+    index_file(
+        "app/button.js",
+        "\"use client\";\nimport { query } from \"../lib/db.js\";\nimport { format } from \"../lib/format.js\";",
+    );
+
+    let pull_project_diagnostics = || {
+        workspace
+            .analyze_project(AnalyzeProjectParams {
+                max_files: None,
+                cancellation: CancellationToken::default(),
+            })
+            .unwrap();
+        workspace
+            .pull_project_diagnostics(PullProjectDiagnosticsParams {
+                path: RomePath::new("app/button.js"),
+            })
+            .unwrap()
+            .diagnostics
+    };
+
+    let diagnostics = pull_project_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].category().map(|category| category.name()),
+        Some("project/serverOnlyImport")
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Warning);
+
+    index_file(
+        "app/button.js",
+        "\"use client\";\n// biome-ignore project/serverOnlyImport: the constant is inlined by the bundler\nimport { query } from \"../lib/db.js\";\nimport { format } from \"../lib/format.js\";",
+    );
+    assert!(pull_project_diagnostics().is_empty());
+}
+
+#[test]
+fn reports_the_classes_of_the_css_modules() {
+    let workspace = server();
+
+    let index_file = |path: &str, content: &str| {
+        workspace
+            .index_file(IndexFileParams {
+                path: RomePath::new(path),
+                content: content.into(),
+            })
+            .unwrap();
+    };
+    index_file(
+        "button.module.css",
+        ".button { color: red; }\n.unused:hover { color: blue; }\n:global(.theme) .button { color: green; }",
+    );
+    index_file(
+        "button.js",
+        "import styles from \"./button.module.css\";\nexport const classes = [styles.button, styles[\"missing\"]];",
+    );
+
+    workspace
+        .analyze_project(AnalyzeProjectParams {
+            max_files: None,
+            cancellation: CancellationToken::default(),
+        })
+        .unwrap();
+
+    let pull_project_diagnostics = |path: &str| {
+        workspace
+            .pull_project_diagnostics(PullProjectDiagnosticsParams {
+                path: RomePath::new(path),
+            })
+            .unwrap()
+            .diagnostics
+    };
+    let diagnostics = pull_project_diagnostics("button.module.css");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].category().map(|category| category.name()),
+        Some("project/unusedClass")
+    );
+
+    let diagnostics = pull_project_diagnostics("button.js");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].category().map(|category| category.name()),
+        Some("project/unknownClass")
+    );
+}
+
+#[test]
+fn processes_a_batch_of_files() {
+    let workspace = server();
+
+    let open_file = |path: &str, content: &str| OpenFileParams {
+        path: RomePath::new(path),
+        content: content.into(),
+        version: 0,
+        language_hint: Language::JavaScript,
+    };
+    let result = workspace
+        .open_files(OpenFilesParams {
+            files: vec![open_file("a.js", "debugger;"), open_file("b.js", "b();")],
+        })
+        .unwrap();
+    assert!(result.errors.is_empty());
+
+    let result = workspace
+        .pull_files_diagnostics(PullFilesDiagnosticsParams {
+            paths: vec![RomePath::new("a.js"), RomePath::new("missing.js")],
+            categories: RuleCategories::LINT,
+            max_diagnostics: 10,
+            only: vec![],
+            skip: vec![],
+            cancellation: CancellationToken::default(),
+        })
+        .unwrap();
+    assert_eq!(result.files.len(), 1);
+    assert_eq!(result.files[0].path, RomePath::new("a.js"));
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].path, RomePath::new("missing.js"));
+
+    let result = workspace
+        .close_files(CloseFilesParams {
+            paths: vec![RomePath::new("a.js"), RomePath::new("b.js")],
+        })
+        .unwrap();
+    assert!(result.errors.is_empty());
+
+    // A file that fails doesn't stop the batch
+    let result = workspace
+        .close_files(CloseFilesParams {
+            paths: vec![RomePath::new("a.js")],
+        })
+        .unwrap();
+    assert_eq!(result.errors.len(), 1);
+}
+
+#[test]
+fn fixes_a_rule_in_a_batch_of_files() {
+    let workspace = server();
+
+    let open_file = |path: &str, content: &str| OpenFileParams {
+        path: RomePath::new(path),
+        content: content.into(),
+        version: 0,
+        language_hint: Language::JavaScript,
+    };
+    let result = workspace
+        .open_files(OpenFilesParams {
+            files: vec![
+                open_file("a.js", "a();\ndebugger;\nb();\n"),
+                open_file("b.js", "b();\n"),
+            ],
+        })
+        .unwrap();
+    assert!(result.errors.is_empty());
+
+    let result = workspace
+        .fix_files(FixFilesParams {
+            paths: vec![
+                RomePath::new("a.js"),
+                RomePath::new("b.js"),
+                RomePath::new("a.js"),
+                RomePath::new("missing.js"),
+            ],
+            fix_file_mode: FixFileMode::SafeAndUnsafeFixes,
+            should_format: false,
+            only: vec![RuleSelector::from_str("suspicious/noDebugger").unwrap()],
+            skip: vec![],
+            unsafe_rules: vec![],
+            cancellation: CancellationToken::default(),
+        })
+        .unwrap();
+
+    // The file without fixes isn't listed, and the file listed twice has a single edit
+    assert_eq!(result.files.len(), 1);
+    let fixed = &result.files[0];
+    assert_eq!(fixed.path, RomePath::new("a.js"));
+    assert_eq!(fixed.actions.len(), 1);
+    assert!(fixed.replacement.range.start() >= TextSize::from(4));
+    assert!(!fixed.replacement.text.contains("debugger"));
+    // The fixed error isn't left in the file
+    assert_eq!(result.remaining_errors, 0);
+    assert_eq!(result.skipped_suggested_fixes, 0);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].path, RomePath::new("missing.js"));
+}
+
+#[test]
+fn applies_the_unsafe_fixes_of_the_selected_rules() {
+    let workspace = server();
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: Configuration {
+                linter: Some(LinterConfiguration {
+                    rules: Some(Rules {
+                        suspicious: Some(Suspicious {
+                            no_debugger: Some(RuleConfiguration::WithOptions(RuleWithOptions {
+                                level: RulePlainConfiguration::Error,
+                                fix: Some(RuleFixKind::Unsafe),
+                                directives: None,
+                                options: None,
+                            })),
+                            ..Suspicious::default()
+                        }),
+                        ..Rules::default()
+                    }),
+                    ..LinterConfiguration::default()
+                }),
+                ..Configuration::default()
+            },
+            vcs_ignore_files: vec![],
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })
+        .unwrap();
+    workspace
+        .open_file(OpenFileParams {
+            path: RomePath::new("a.js"),
+            content: "debugger;\na == b;\n".into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        })
+        .unwrap();
+
+    let fix_file = |unsafe_rules: &[&str]| {
+        workspace
+            .fix_file(FixFileParams {
+                path: RomePath::new("a.js"),
+                fix_file_mode: FixFileMode::SafeFixes,
+                should_format: false,
+                only: vec![],
+                skip: vec![],
+                unsafe_rules: unsafe_rules
+                    .iter()
+                    .map(|rule| RuleSelector::from_str(rule).unwrap())
+                    .collect(),
+                cancellation: CancellationToken::default(),
+            })
+            .unwrap()
+    };
+
+    // The configuration of `noDebugger` applies its unsafe fix
+    let result = fix_file(&[]);
+    assert!(!result.code.contains("debugger"));
+    assert!(result.code.contains("a == b"));
+    assert_eq!(result.skipped_suggested_fixes, 1);
+
+    let result = fix_file(&["suspicious/noDoubleEquals"]);
+    assert!(!result.code.contains("debugger"));
+    assert!(result.code.contains("a === b"));
+    assert_eq!(result.skipped_suggested_fixes, 0);
+}
+
+#[test]
+fn runs_the_rules_in_the_files_with_their_directives() {
+    let workspace = server();
+    let directives = |directives: &[&str]| {
+        Some(StringSet::new(
+            directives
+                .iter()
+                .map(|directive| directive.to_string())
+                .collect(),
+        ))
+    };
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: Configuration {
+                linter: Some(LinterConfiguration {
+                    rules: Some(Rules {
+                        suspicious: Some(Suspicious {
+                            no_debugger: Some(RuleConfiguration::WithOptions(RuleWithOptions {
+                                level: RulePlainConfiguration::Error,
+                                fix: None,
+                                directives: directives(&["use client"]),
+                                options: None,
+                            })),
+                            no_double_equals: Some(RuleConfiguration::WithOptions(
+                                RuleWithOptions {
+                                    level: RulePlainConfiguration::Error,
+                                    fix: None,
+                                    directives: directives(&["!use server"]),
+                                    options: None,
+                                },
+                            )),
+                            ..Suspicious::default()
+                        }),
+                        ..Rules::default()
+                    }),
+                    ..LinterConfiguration::default()
+                }),
+                ..Configuration::default()
+            },
+            vcs_ignore_files: vec![],
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })
+        .unwrap();
+
+    let categories = |path: &str, content: &str| {
+        workspace
+            .open_file(OpenFileParams {
+                path: RomePath::new(path),
+                content: content.into(),
+                version: 0,
+                language_hint: Language::JavaScript,
+            })
+            .unwrap();
+        let mut categories: Vec<_> = workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                path: RomePath::new(path),
+                categories: RuleCategories::LINT,
+                max_diagnostics: 10,
+                only: vec![],
+                skip: vec![],
+                cancellation: CancellationToken::default(),
+            })
+            .unwrap()
+            .diagnostics
+            .iter()
+            .filter_map(|diagnostic| diagnostic.category())
+            .map(|category| category.name())
+            .collect();
+        categories.sort_unstable();
+        categories
+    };
+
+    assert_eq!(
+        categories("client.js", "\"use client\";\ndebugger;\na == b;\n"),
+        [
+            "lint/suspicious/noDebugger",
+            "lint/suspicious/noDoubleEquals"
+        ]
+    );
+    assert_eq!(
+        categories("server.js", "\"use server\";\ndebugger;\na == b;\n"),
+        Vec::<&str>::new()
+    );
+    assert_eq!(
+        categories("shared.js", "debugger;\na == b;\n"),
+        ["lint/suspicious/noDoubleEquals"]
+    );
+}
+
+#[test]
+fn stops_the_cancelled_requests() {
+    let workspace = server();
+    workspace
+        .open_file(OpenFileParams {
+            path: RomePath::new("a.js"),
+            content: "debugger;".into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        })
+        .unwrap();
+
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    let result = workspace.pull_diagnostics(PullDiagnosticsParams {
+        path: RomePath::new("a.js"),
+        categories: RuleCategories::LINT,
+        max_diagnostics: 10,
+        only: vec![],
+        skip: vec![],
+        cancellation: cancellation.clone(),
+    });
+    assert!(matches!(result, Err(WorkspaceError::Cancelled(_))));
+
+    let result = workspace.fix_file(FixFileParams {
+        path: RomePath::new("a.js"),
+        fix_file_mode: FixFileMode::SafeAndUnsafeFixes,
+        should_format: false,
+        only: vec![],
+        skip: vec![],
+        unsafe_rules: vec![],
+        cancellation,
+    });
+    assert!(matches!(result, Err(WorkspaceError::Cancelled(_))));
+}
+
+#[test]
+fn shares_the_diagnostics_through_the_disk_cache() {
+    let cache_directory =
+        std::env::temp_dir().join(format!("biome-cache-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache_directory);
+
+    let pull_diagnostics_with_tsconfig = |content: &str, tsconfig_files: Vec<TsConfigFile>| {
+        let workspace = server_with_cache(cache_directory.clone());
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration: Configuration::default(),
+                vcs_ignore_files: vec![],
+                tsconfig_files,
+                package_json_files: vec![],
+            })
+            .unwrap();
+        workspace
+            .open_file(OpenFileParams {
+                path: RomePath::new("a.js"),
+                content: content.into(),
+                version: 0,
+                language_hint: Language::JavaScript,
+            })
+            .unwrap();
+        workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                path: RomePath::new("a.js"),
+                categories: RuleCategories::LINT,
+                max_diagnostics: 10,
+                only: vec![],
+                skip: vec![],
+                cancellation: CancellationToken::default(),
+            })
+            .unwrap()
+            .diagnostics
+            .len()
+    };
+    let pull_diagnostics = |content: &str| pull_diagnostics_with_tsconfig(content, vec![]);
+    let entries = || std::fs::read_dir(&cache_directory).unwrap().count();
+
+    let diagnostics = pull_diagnostics("debugger;");
+    assert_eq!(entries(), 1);
+    // Another workspace, e.g. the daemon, finds the entry of the same content
+    assert_eq!(pull_diagnostics("debugger;"), diagnostics);
+    assert_eq!(entries(), 1);
+
+    pull_diagnostics("debugger;\ndebugger;");
+    assert_eq!(entries(), 2);
+
+    // The path aliases of the `tsconfig.json` file change the result, e.g. the order of the
+    // imports
+    pull_diagnostics_with_tsconfig(
+        "debugger;",
+        vec![TsConfigFile {
+            path: "tsconfig.json".into(),
+            content: r#"{ "compilerOptions": { "paths": { "@app/*": ["./src/*"] } } }"#.into(),
+        }],
+    );
+    assert_eq!(entries(), 3);
+
+    std::fs::remove_dir_all(&cache_directory).unwrap();
+}
+
+/// Handles the snapshots of JavaScript modules, which aren't formatted
+struct SnapshotHandler;
+
+impl ExtensionHandler for SnapshotHandler {
+    fn language(&self) -> Language {
+        Language::JavaScript
+    }
+
+    fn mime(&self) -> Mime {
+        Mime::Javascript
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::of_language(Language::JavaScript).without_formatter()
+    }
+}
+
+#[test]
+fn handles_the_registered_extensions() {
+    let workspace = server_with_extensions(
+        ExtensionRegistry::new()
+            .with_language("cts.ejs", Language::TypeScript)
+            .with_handler(".mjs.snap", SnapshotHandler),
+    );
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("template.cts.ejs"),
+            content: "let a : number=1".into(),
+            version: 0,
+            language_hint: Language::default(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        file.format_file().unwrap().as_code(),
+        "let a: number = 1;\n"
+    );
+
+    let file_features = |path: &str| {
+        workspace
+            .file_features(SupportsFeatureParams {
+                path: RomePath::new(path),
+                feature: FeaturesBuilder::new()
+                    .with_formatter()
+                    .with_linter()
+                    .build(),
+            })
+            .unwrap()
+    };
+    let snapshot_features = file_features("module.mjs.snap");
+    assert!(!snapshot_features.supports_for(&FeatureName::Format));
+    assert!(snapshot_features.supports_for(&FeatureName::Lint));
+    // The other snapshots are still unknown
+    let unknown_features = file_features("module.snap");
+    assert!(!unknown_features.supports_for(&FeatureName::Lint));
+}
+
+#[test]
+fn lists_the_docs_of_the_rules() {
+    let workspace = server();
+
+    let result = workspace.rule_docs(RuleDocsParams {}).unwrap();
+
+    let no_debugger = result
+        .rules
+        .iter()
+        .find(|rule| rule.name == "noDebugger")
+        .unwrap();
+    assert_eq!(no_debugger.group, "suspicious");
+    assert_eq!(no_debugger.version, "1.0.0");
+    assert!(no_debugger.recommended);
+    assert_eq!(no_debugger.fix_kind.as_deref(), Some("unsafe"));
+    assert!(no_debugger.docs.contains("debugger"));
+    assert!(no_debugger.options_schema.is_none());
+
+    let use_naming_convention = result
+        .rules
+        .iter()
+        .find(|rule| rule.name == "useNamingConvention")
+        .unwrap();
+    assert_eq!(
+        use_naming_convention.options_schema.is_some(),
+        cfg!(feature = "schema")
+    );
+}
+
+#[test]
+fn reports_the_capabilities_of_a_file() {
+    let workspace = server();
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: Configuration {
+                formatter: Some(FormatterConfiguration {
+                    enabled: Some(false),
+                    ..FormatterConfiguration::default()
+                }),
+                linter: Some(LinterConfiguration {
+                    ignore: Some(StringSet::new(
+                        ["dist/**".to_string()].into_iter().collect(),
+                    )),
+                    ..LinterConfiguration::default()
+                }),
+                ..Configuration::default()
+            },
+            vcs_ignore_files: vec![],
+            tsconfig_files: vec![],
+            package_json_files: vec![],
+        })
+        .unwrap();
+
+    let capabilities = |path: &str| {
+        workspace
+            .capabilities(FileCapabilitiesParams {
+                path: RomePath::new(path),
+            })
+            .unwrap()
+    };
+
+    let source = capabilities("src/index.js");
+    assert_eq!(source.format, SupportKind::FeatureNotEnabled);
+    assert_eq!(source.lint, SupportKind::Supported);
+    assert_eq!(source.organize_imports, SupportKind::Supported);
+    assert_eq!(source.assists, SupportKind::Supported);
+    assert_eq!(source.debug, SupportKind::Supported);
+
+    let bundle = capabilities("dist/index.js");
+    assert_eq!(bundle.lint, SupportKind::Ignored);
+    assert_eq!(bundle.assists, SupportKind::Ignored);
+    assert_eq!(bundle.debug, SupportKind::Supported);
+
+    let text = capabilities("README.txt");
+    assert_eq!(text.lint, SupportKind::FileNotSupported);
+    assert_eq!(text.assists, SupportKind::FileNotSupported);
+    assert_eq!(text.debug, SupportKind::FileNotSupported);
+}
+
+const RENAME_SOURCE: &str = "let value = 1;\nconsole.log(value);\nundeclared;";
+
+fn prepare_rename_at(offset: u32) -> Option<TextRange> {
+    let workspace = server();
+    let _file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: RENAME_SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    workspace
+        .prepare_rename(PrepareRenameParams {
+            path: RomePath::new("file.js"),
+            symbol_at: TextSize::from(offset),
+        })
+        .unwrap()
+        .range
+}
+
+#[test]
+fn prepare_rename_returns_the_range_of_the_symbol() {
+    // The binding, and a reference to it
+    assert_eq!(
+        prepare_rename_at(5),
+        Some(TextRange::new(TextSize::from(4), TextSize::from(9)))
+    );
+    assert_eq!(
+        prepare_rename_at(29),
+        Some(TextRange::new(TextSize::from(27), TextSize::from(32)))
+    );
+}
+
+#[test]
+fn prepare_rename_rejects_the_keywords_and_the_literals() {
+    // `let`
+    assert_eq!(prepare_rename_at(1), None);
+    // `1`
+    assert_eq!(prepare_rename_at(12), None);
+}
+
+#[test]
+fn prepare_rename_rejects_the_names_without_a_symbol() {
+    // `console`, a global
+    assert_eq!(prepare_rename_at(17), None);
+    // `undeclared`
+    assert_eq!(prepare_rename_at(38), None);
+    // `;`
+    assert_eq!(prepare_rename_at(13), None);
+}
@@ -3,7 +3,7 @@ use biome_rowan::{TextRange, TextSize};
 
 /// Single instance of a suppression comment, with the following syntax:
 ///
-/// `// rome-ignore { <category> { (<value>) }? }+: <reason>`
+/// `// biome-ignore{-start|-end}? { <category> { (<value>) }? }+: <reason>`
 ///
 /// The category broadly describes what feature is being suppressed (formatting,
 /// linting, ...) with the value being and optional, category-specific name of
@@ -14,15 +14,50 @@ use biome_rowan::{TextRange, TextSize};
 /// A suppression must specify a reason: this part has no semantic meaning but
 /// is required to document why a particular feature is being disable for this
 /// line (lint false-positive, specific formatting requirements, ...)
+///
+/// All the ranges are relative to the start of the comment text passed to
+/// [parse_suppression_comment].
 #[derive(Debug, PartialEq, Eq)]
 pub struct Suppression<'a> {
+    /// Whether the suppression applies to the code that follows it, or starts
+    /// or ends a suppressed range
+    pub scope: SuppressionScope,
     /// List of categories for this suppression
-    ///
-    /// Categories are pair of the category name +
-    /// an optional category value
-    pub categories: Vec<(&'a Category, Option<&'a str>)>,
+    pub categories: Vec<SuppressionCategory<'a>>,
     /// Reason for this suppression comment to exist
     pub reason: &'a str,
+    /// The range of the reason, empty when the reason is missing
+    pub reason_range: TextRange,
+    /// The range of the whole suppression, from `biome-ignore` to the end of the reason
+    pub range: TextRange,
+    /// If the comment is `// rome-ignore`
+    pub is_legacy: bool,
+}
+
+/// The code that a [Suppression] applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionScope {
+    /// `biome-ignore`: the suppression applies to the code that follows the comment
+    Next,
+    /// `biome-ignore-start`: the suppression applies to the code until the
+    /// matching [SuppressionScope::RangeEnd]
+    RangeStart,
+    /// `biome-ignore-end`: ends the range of a [SuppressionScope::RangeStart]
+    RangeEnd,
+}
+
+/// A category of a [Suppression] with its optional value, for instance
+/// `format`, `lint/style/noVar` or the instance `lint/style/noVar(foo)`
+#[derive(Debug, PartialEq, Eq)]
+pub struct SuppressionCategory<'a> {
+    /// The suppressed category
+    pub category: &'a Category,
+    /// The category-specific value between the parentheses
+    pub value: Option<&'a str>,
+    /// The range of the category, including its value and the parentheses
+    pub range: TextRange,
+    /// The range of the value, without the parentheses
+    pub value_range: Option<TextRange>,
 }
 
 pub fn parse_suppression_comment(
@@ -50,7 +85,25 @@ pub fn parse_suppression_comment(
             line = line.trim_start_matches('*').trim_start()
         }
 
-        const PATTERNS: [[char; 2]; 11] = [
+        line = line.trim_start();
+        let start = line;
+
+        const PATTERN: [[char; 2]; 12] = [
+            ['b', 'B'],
+            ['i', 'I'],
+            ['o', 'O'],
+            ['m', 'M'],
+            ['e', 'E'],
+            ['-', '_'],
+            ['i', 'I'],
+            ['g', 'G'],
+            ['n', 'N'],
+            ['o', 'O'],
+            ['r', 'R'],
+            ['e', 'E'],
+        ];
+        // TODO: Remove at Biome 2.0
+        const DEPRECATED_PATTERNS: [[char; 2]; 11] = [
             ['r', 'R'],
             ['o', 'O'],
             ['m', 'M'],
@@ -64,23 +117,47 @@ pub fn parse_suppression_comment(
             ['e', 'E'],
         ];
 
-        // Checks for `/rome[-_]ignore/i` without a regex, or skip the line
-        // entirely if it doesn't match
-        for pattern in PATTERNS {
-            line = line.strip_prefix(pattern)?;
+        let mut is_legacy = false;
+        // it's a biome-ignore comment
+        if line.starts_with("biome-ignore") {
+            // Checks for `/biome[-_]ignore/i` without a regex, or skip the line
+            // entirely if it doesn't match
+            for pattern in PATTERN {
+                line = line.strip_prefix(pattern)?;
+            }
+        } else {
+            // Checks for `/rome[-_]ignore/i` without a regex, or skip the line
+            // entirely if it doesn't match
+            is_legacy = true;
+            for pattern in DEPRECATED_PATTERNS {
+                line = line.strip_prefix(pattern)?;
+            }
         }
 
+        let (scope, line) = parse_suppression_scope(line);
         let line = line.trim_start();
-        Some(
-            parse_suppression_line(line).map_err(|err| SuppressionDiagnostic {
-                message: err.message,
-                // Adjust the position of the diagnostic in the whole comment
-                span: err.span + offset_from(base, line),
-            }),
-        )
+        Some(parse_suppression_line(base, start, line, scope, is_legacy))
     })
 }
 
+/// Parse the optional `-start` or `-end` suffix of `biome-ignore`
+fn parse_suppression_scope(line: &str) -> (SuppressionScope, &str) {
+    let Some(rest) = line.strip_prefix(['-', '_']) else {
+        return (SuppressionScope::Next, line);
+    };
+    let word_end = rest
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(rest.len());
+    let (word, rest) = rest.split_at(word_end);
+    if word.eq_ignore_ascii_case("start") {
+        (SuppressionScope::RangeStart, rest)
+    } else if word.eq_ignore_ascii_case("end") {
+        (SuppressionScope::RangeEnd, rest)
+    } else {
+        (SuppressionScope::Next, line)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Diagnostic)]
 #[diagnostic(category = "suppressions/parse")]
 pub struct SuppressionDiagnostic {
@@ -138,8 +215,16 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
 }
 
 /// Parse the `{ <category> { (<value>) }? }+: <reason>` section of a suppression line
-fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnostic> {
-    let mut line = base;
+///
+/// `start` is the line from its `biome-ignore` prefix, the ranges of the
+/// suppression are computed from the start of `base`
+fn parse_suppression_line<'a>(
+    base: &'a str,
+    start: &'a str,
+    mut line: &'a str,
+    scope: SuppressionScope,
+    is_legacy: bool,
+) -> Result<Suppression<'a>, SuppressionDiagnostic> {
     let mut categories = Vec::new();
 
     loop {
@@ -151,12 +236,16 @@ fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnost
                 span: TextRange::at(offset_from(base, line), TextSize::of(line)),
             })?;
 
-        let (category, rest) = line.split_at(separator);
-        let category = category.trim_end();
-        let category: Option<&'static Category> = if !category.is_empty() {
-            let category = category.parse().map_err(|()| SuppressionDiagnostic {
-                message: SuppressionDiagnosticKind::ParseCategory(category.into()),
-                span: TextRange::at(offset_from(base, category), TextSize::of(category)),
+        let (category_text, rest) = line.split_at(separator);
+        let category_text = category_text.trim_end();
+        let category_range = TextRange::at(
+            offset_from(base, category_text),
+            TextSize::of(category_text),
+        );
+        let category: Option<&'static Category> = if !category_text.is_empty() {
+            let category = category_text.parse().map_err(|()| SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::ParseCategory(category_text.into()),
+                span: category_range,
             })?;
             Some(category)
         } else {
@@ -170,7 +259,12 @@ fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnost
             // Colon token: stop parsing categories
             ":" => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push(SuppressionCategory {
+                        category,
+                        value: None,
+                        range: category_range,
+                        value_range: None,
+                    });
                 }
 
                 line = rest.trim_start();
@@ -192,15 +286,26 @@ fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnost
 
                 let (value, rest) = rest.split_at(paren);
                 let value = value.trim();
+                let rest = rest.strip_prefix(')').unwrap();
 
-                categories.push((category, Some(value)));
+                categories.push(SuppressionCategory {
+                    category,
+                    value: Some(value),
+                    range: TextRange::new(category_range.start(), offset_from(base, rest)),
+                    value_range: Some(TextRange::at(offset_from(base, value), TextSize::of(value))),
+                });
 
-                line = rest.strip_prefix(')').unwrap().trim_start();
+                line = rest.trim_start();
             }
             // Whitespace: push a category without value
             _ => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push(SuppressionCategory {
+                        category,
+                        value: None,
+                        range: category_range,
+                        value_range: None,
+                    });
                 }
 
                 line = rest.trim_start();
@@ -209,7 +314,15 @@ fn parse_suppression_line(base: &str) -> Result<Suppression, SuppressionDiagnost
     }
 
     let reason = line.trim_end();
-    Ok(Suppression { categories, reason })
+    let reason_range = TextRange::at(offset_from(base, reason), TextSize::of(reason));
+    Ok(Suppression {
+        scope,
+        categories,
+        reason,
+        reason_range,
+        range: TextRange::new(offset_from(base, start), reason_range.end()),
+        is_legacy,
+    })
 }
 
 /// Returns the byte offset of `substr` within `base`
@@ -238,155 +351,156 @@ fn offset_from(base: &str, substr: &str) -> TextSize {
 }
 
 #[cfg(test)]
-mod tests {
+mod test_utils {
+    use super::{parse_suppression_comment, SuppressionDiagnostic};
+    use biome_diagnostics::Category;
+
+    pub(crate) type Parsed<'a> =
+        Result<(Vec<(&'a Category, Option<&'a str>)>, &'a str, bool), SuppressionDiagnostic>;
+
+    /// Parses a comment into the categories, the reason and the legacy flag of its suppressions
+    pub(crate) fn parse(comment: &str) -> Vec<Parsed> {
+        parse_suppression_comment(comment)
+            .map(|suppression| {
+                suppression.map(|suppression| {
+                    let categories = suppression
+                        .categories
+                        .iter()
+                        .map(|category| (category.category, category.value))
+                        .collect();
+                    (categories, suppression.reason, suppression.is_legacy)
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests_rome_ignore {
     use biome_diagnostics::category;
     use biome_rowan::{TextRange, TextSize};
 
-    use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
-
-    use super::{parse_suppression_comment, Suppression};
+    use super::test_utils::parse;
+    use super::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
     #[test]
     fn parse_simple_suppression() {
         assert_eq!(
-            parse_suppression_comment("// rome-ignore parse: explanation1").collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![(category!("parse"), None)],
-                reason: "explanation1"
-            })],
+            parse("// rome-ignore parse: explanation1"),
+            vec![Ok((vec![(category!("parse"), None)], "explanation1", true))],
         );
 
         assert_eq!(
-            parse_suppression_comment("/** rome-ignore parse: explanation2 */").collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![(category!("parse"), None)],
-                reason: "explanation2"
-            })],
+            parse("/** rome-ignore parse: explanation2 */"),
+            vec![Ok((vec![(category!("parse"), None)], "explanation2", true))],
         );
 
         assert_eq!(
-            parse_suppression_comment(
+            parse(
                 "/**
                   * rome-ignore parse: explanation3
                   */"
-            )
-            .collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![(category!("parse"), None)],
-                reason: "explanation3"
-            })],
+            ),
+            vec![Ok((vec![(category!("parse"), None)], "explanation3", true))],
         );
 
         assert_eq!(
-            parse_suppression_comment(
+            parse(
                 "/**
                   * hello
                   * rome-ignore parse: explanation4
                   */"
-            )
-            .collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![(category!("parse"), None)],
-                reason: "explanation4"
-            })],
+            ),
+            vec![Ok((vec![(category!("parse"), None)], "explanation4", true))],
         );
     }
     #[test]
     fn parse_unclosed_block_comment_suppressions() {
         assert_eq!(
-            parse_suppression_comment("/* rome-ignore format: explanation").collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![(category!("format"), None)],
-                reason: "explanation"
-            })],
+            parse("/* rome-ignore format: explanation"),
+            vec![Ok((vec![(category!("format"), None)], "explanation", true))],
         );
 
         assert_eq!(
-            parse_suppression_comment("/* rome-ignore format: explanation *").collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![(category!("format"), None)],
-                reason: "explanation"
-            })],
+            parse("/* rome-ignore format: explanation *"),
+            vec![Ok((vec![(category!("format"), None)], "explanation", true))],
         );
 
         assert_eq!(
-            parse_suppression_comment("/* rome-ignore format: explanation /").collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![(category!("format"), None)],
-                reason: "explanation"
-            })],
+            parse("/* rome-ignore format: explanation /"),
+            vec![Ok((vec![(category!("format"), None)], "explanation", true))],
         );
     }
 
     #[test]
     fn parse_multiple_suppression() {
         assert_eq!(
-            parse_suppression_comment("// rome-ignore parse(foo) parse(dog): explanation")
-                .collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![
+            parse("// rome-ignore parse(foo) parse(dog): explanation"),
+            vec![Ok((
+                vec![
                     (category!("parse"), Some("foo")),
                     (category!("parse"), Some("dog"))
                 ],
-                reason: "explanation"
-            })],
+                "explanation",
+                true
+            ))],
         );
 
         assert_eq!(
-            parse_suppression_comment("/** rome-ignore parse(bar) parse(cat): explanation */")
-                .collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![
+            parse("/** rome-ignore parse(bar) parse(cat): explanation */"),
+            vec![Ok((
+                vec![
                     (category!("parse"), Some("bar")),
                     (category!("parse"), Some("cat"))
                 ],
-                reason: "explanation"
-            })],
+                "explanation",
+                true
+            ))],
         );
 
         assert_eq!(
-            parse_suppression_comment(
+            parse(
                 "/**
                   * rome-ignore parse(yes) parse(frog): explanation
                   */"
-            )
-            .collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![
+            ),
+            vec![Ok((
+                vec![
                     (category!("parse"), Some("yes")),
                     (category!("parse"), Some("frog"))
                 ],
-                reason: "explanation"
-            })],
+                "explanation",
+                true
+            ))],
         );
 
         assert_eq!(
-            parse_suppression_comment(
+            parse(
                 "/**
                   * hello
                   * rome-ignore parse(wow) parse(fish): explanation
                   */"
-            )
-            .collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![
+            ),
+            vec![Ok((
+                vec![
                     (category!("parse"), Some("wow")),
                     (category!("parse"), Some("fish"))
                 ],
-                reason: "explanation"
-            })],
+                "explanation",
+                true
+            ))],
         );
     }
 
     #[test]
     fn parse_multiple_suppression_categories() {
         assert_eq!(
-            parse_suppression_comment("// rome-ignore format lint: explanation")
-                .collect::<Vec<_>>(),
-            vec![Ok(Suppression {
-                categories: vec![(category!("format"), None), (category!("lint"), None)],
-                reason: "explanation"
-            })],
+            parse("// rome-ignore format lint: explanation"),
+            vec![Ok((
+                vec![(category!("format"), None), (category!("lint"), None)],
+                "explanation",
+                true
+            ))],
         );
     }
 
@@ -406,7 +520,7 @@ mod tests {
     #[test]
     fn diagnostic_missing_colon() {
         assert_eq!(
-            parse_suppression_comment("// rome-ignore format explanation").collect::<Vec<_>>(),
+            parse("// rome-ignore format explanation"),
             vec![Err(SuppressionDiagnostic {
                 message: SuppressionDiagnosticKind::MissingColon,
                 span: TextRange::new(TextSize::from(22), TextSize::from(33))
@@ -417,7 +531,7 @@ mod tests {
     #[test]
     fn diagnostic_missing_paren() {
         assert_eq!(
-            parse_suppression_comment("// rome-ignore format(:").collect::<Vec<_>>(),
+            parse("// rome-ignore format(:"),
             vec![Err(SuppressionDiagnostic {
                 message: SuppressionDiagnosticKind::MissingParen,
                 span: TextRange::new(TextSize::from(22), TextSize::from(23))
@@ -428,7 +542,7 @@ mod tests {
     #[test]
     fn diagnostic_missing_category() {
         assert_eq!(
-            parse_suppression_comment("// rome-ignore (value): explanation").collect::<Vec<_>>(),
+            parse("// rome-ignore (value): explanation"),
             vec![Err(SuppressionDiagnostic {
                 message: SuppressionDiagnosticKind::MissingCategory,
                 span: TextRange::new(TextSize::from(15), TextSize::from(16))
@@ -439,7 +553,7 @@ mod tests {
     #[test]
     fn diagnostic_unknown_category() {
         assert_eq!(
-            parse_suppression_comment("// rome-ignore unknown: explanation").collect::<Vec<_>>(),
+            parse("// rome-ignore unknown: explanation"),
             vec![Err(SuppressionDiagnostic {
                 message: SuppressionDiagnosticKind::ParseCategory(String::from("unknown")),
                 span: TextRange::new(TextSize::from(15), TextSize::from(22))
@@ -447,3 +561,331 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod tests_biome_ignore {
+    use biome_diagnostics::category;
+    use biome_rowan::{TextRange, TextSize};
+
+    use super::test_utils::parse;
+    use super::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
+
+    #[test]
+    fn parse_simple_suppression() {
+        assert_eq!(
+            parse("// biome-ignore parse: explanation1"),
+            vec![Ok((
+                vec![(category!("parse"), None)],
+                "explanation1",
+                false
+            ))],
+        );
+
+        assert_eq!(
+            parse("/** biome-ignore parse: explanation2 */"),
+            vec![Ok((
+                vec![(category!("parse"), None)],
+                "explanation2",
+                false
+            ))],
+        );
+
+        assert_eq!(
+            parse(
+                "/**
+                  * biome-ignore parse: explanation3
+                  */"
+            ),
+            vec![Ok((
+                vec![(category!("parse"), None)],
+                "explanation3",
+                false
+            ))],
+        );
+
+        assert_eq!(
+            parse(
+                "/**
+                  * hello
+                  * biome-ignore parse: explanation4
+                  */"
+            ),
+            vec![Ok((
+                vec![(category!("parse"), None)],
+                "explanation4",
+                false
+            ))],
+        );
+    }
+    #[test]
+    fn parse_unclosed_block_comment_suppressions() {
+        assert_eq!(
+            parse("/* biome-ignore format: explanation"),
+            vec![Ok((
+                vec![(category!("format"), None)],
+                "explanation",
+                false
+            ))],
+        );
+
+        assert_eq!(
+            parse("/* biome-ignore format: explanation *"),
+            vec![Ok((
+                vec![(category!("format"), None)],
+                "explanation",
+                false
+            ))],
+        );
+
+        assert_eq!(
+            parse("/* biome-ignore format: explanation /"),
+            vec![Ok((
+                vec![(category!("format"), None)],
+                "explanation",
+                false
+            ))],
+        );
+    }
+
+    #[test]
+    fn parse_multiple_suppression() {
+        assert_eq!(
+            parse("// biome-ignore parse(foo) parse(dog): explanation"),
+            vec![Ok((
+                vec![
+                    (category!("parse"), Some("foo")),
+                    (category!("parse"), Some("dog"))
+                ],
+                "explanation",
+                false
+            ))],
+        );
+
+        assert_eq!(
+            parse("/** biome-ignore parse(bar) parse(cat): explanation */"),
+            vec![Ok((
+                vec![
+                    (category!("parse"), Some("bar")),
+                    (category!("parse"), Some("cat"))
+                ],
+                "explanation",
+                false
+            ))],
+        );
+
+        assert_eq!(
+            parse(
+                "/**
+                  * biome-ignore parse(yes) parse(frog): explanation
+                  */"
+            ),
+            vec![Ok((
+                vec![
+                    (category!("parse"), Some("yes")),
+                    (category!("parse"), Some("frog"))
+                ],
+                "explanation",
+                false
+            ))],
+        );
+
+        assert_eq!(
+            parse(
+                "/**
+                  * hello
+                  * biome-ignore parse(wow) parse(fish): explanation
+                  */"
+            ),
+            vec![Ok((
+                vec![
+                    (category!("parse"), Some("wow")),
+                    (category!("parse"), Some("fish"))
+                ],
+                "explanation",
+                false
+            ))],
+        );
+    }
+
+    #[test]
+    fn parse_multiple_suppression_categories() {
+        assert_eq!(
+            parse("// biome-ignore format lint: explanation"),
+            vec![Ok((
+                vec![(category!("format"), None), (category!("lint"), None)],
+                "explanation",
+                false
+            ))],
+        );
+    }
+
+    #[test]
+    fn check_offset_from() {
+        const BASE: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua";
+
+        assert_eq!(offset_from(BASE, BASE), TextSize::from(0));
+
+        let (_, substr) = BASE.split_at(55);
+        assert_eq!(offset_from(BASE, substr), TextSize::from(55));
+
+        let (_, substr) = BASE.split_at(BASE.len());
+        assert_eq!(offset_from(BASE, substr), TextSize::of(BASE));
+    }
+
+    #[test]
+    fn diagnostic_missing_colon() {
+        assert_eq!(
+            parse("// biome-ignore format explanation"),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::MissingColon,
+                span: TextRange::new(TextSize::from(23), TextSize::from(34))
+            })],
+        );
+    }
+
+    #[test]
+    fn diagnostic_missing_paren() {
+        assert_eq!(
+            parse("// biome-ignore format(:"),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::MissingParen,
+                span: TextRange::new(TextSize::from(23), TextSize::from(24))
+            })],
+        );
+    }
+
+    #[test]
+    fn diagnostic_missing_category() {
+        assert_eq!(
+            parse("// biome-ignore (value): explanation"),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::MissingCategory,
+                span: TextRange::new(TextSize::from(16), TextSize::from(17))
+            })],
+        );
+    }
+
+    #[test]
+    fn diagnostic_unknown_category() {
+        assert_eq!(
+            parse("// biome-ignore unknown: explanation"),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::ParseCategory(String::from("unknown")),
+                span: TextRange::new(TextSize::from(16), TextSize::from(23))
+            })],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_ranges {
+    use biome_diagnostics::category;
+    use biome_rowan::{TextRange, TextSize};
+
+    use super::{
+        parse_suppression_comment, Suppression, SuppressionCategory, SuppressionDiagnostic,
+        SuppressionDiagnosticKind, SuppressionScope,
+    };
+
+    fn range(start: u32, end: u32) -> TextRange {
+        TextRange::new(TextSize::from(start), TextSize::from(end))
+    }
+
+    #[test]
+    fn parse_suppression_ranges() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore lint/style/noVar(foo) format: reason")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                scope: SuppressionScope::Next,
+                categories: vec![
+                    SuppressionCategory {
+                        category: category!("lint/style/noVar"),
+                        value: Some("foo"),
+                        range: range(16, 37),
+                        value_range: Some(range(33, 36)),
+                    },
+                    SuppressionCategory {
+                        category: category!("format"),
+                        value: None,
+                        range: range(38, 44),
+                        value_range: None,
+                    }
+                ],
+                reason: "reason",
+                reason_range: range(46, 52),
+                range: range(3, 52),
+                is_legacy: false,
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_missing_reason_range() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore format:").collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                scope: SuppressionScope::Next,
+                categories: vec![SuppressionCategory {
+                    category: category!("format"),
+                    value: None,
+                    range: range(16, 22),
+                    value_range: None,
+                }],
+                reason: "",
+                reason_range: range(23, 23),
+                range: range(3, 23),
+                is_legacy: false,
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_range_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-start lint/style/noVar: reason")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                scope: SuppressionScope::RangeStart,
+                categories: vec![SuppressionCategory {
+                    category: category!("lint/style/noVar"),
+                    value: None,
+                    range: range(22, 38),
+                    value_range: None,
+                }],
+                reason: "reason",
+                reason_range: range(40, 46),
+                range: range(3, 46),
+                is_legacy: false,
+            })],
+        );
+
+        let scopes = parse_suppression_comment(
+            "/**
+              * biome-ignore-end format: reason
+              * biome-ignore-start format: reason
+              */",
+        )
+        .map(|suppression| suppression.map(|suppression| suppression.scope))
+        .collect::<Vec<_>>();
+        assert_eq!(
+            scopes,
+            vec![
+                Ok(SuppressionScope::RangeEnd),
+                Ok(SuppressionScope::RangeStart)
+            ]
+        );
+    }
+
+    #[test]
+    fn diagnostic_unknown_scope() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-starting format: reason")
+                .collect::<Vec<_>>(),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::ParseCategory(String::from("-starting")),
+                span: range(15, 24)
+            })],
+        );
+    }
+}
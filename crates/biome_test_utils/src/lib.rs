@@ -4,6 +4,7 @@ use biome_console::markup;
 use biome_diagnostics::termcolor::Buffer;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
 use biome_json_parser::{JsonParserOptions, ParseDiagnostic};
+use biome_project::PackageJson;
 use biome_rowan::{SyntaxKind, SyntaxNode, SyntaxSlot};
 use biome_service::configuration::to_analyzer_rules;
 use biome_service::settings::{Language, WorkspaceSettings};
@@ -61,6 +62,11 @@ pub fn create_analyzer_options(
             let configuration = AnalyzerConfiguration {
                 rules: to_analyzer_rules(&settings, input_file),
                 globals: vec![],
+                path_aliases: vec![],
+                declared_packages: None,
+                project_packages: vec![],
+                node_engine: None,
+                browserslist: vec![],
             };
             options = AnalyzerOptions {
                 configuration,
@@ -73,6 +79,30 @@ pub fn create_analyzer_options(
         None
     };
 
+    // Some rules depend on the name of the file, e.g. the rules of the tests
+    options.file_path = input_file.to_path_buf();
+
+    // The `package.json` of a test file is a file with the same name as the test but with
+    // extension ".package.json"
+    let package_json_file = input_file.with_extension("package.json");
+    if let Some(manifest) = std::fs::read_to_string(package_json_file)
+        .ok()
+        .and_then(|json| PackageJson::parse(&json))
+    {
+        options.configuration.project_packages = manifest.name.iter().cloned().collect();
+        options.configuration.declared_packages = Some(
+            manifest
+                .name
+                .iter()
+                .map(String::as_str)
+                .chain(manifest.dependency_names())
+                .map(str::to_string)
+                .collect(),
+        );
+        options.configuration.node_engine = manifest.node_engine;
+        options.configuration.browserslist = manifest.browserslist;
+    }
+
     options
 }
 
@@ -8,12 +8,12 @@
     semicolon_in_expressions_from_macros
 )]
 
-use std::{cmp::Ordering, num::NonZeroU32};
+use std::{cmp::Ordering, num::NonZeroU32, ops::Range};
 
 use biome_text_size::{TextRange, TextSize};
 use serde::{Deserialize, Serialize};
 pub use similar::ChangeTag;
-use similar::{utils::TextDiffRemapper, TextDiff};
+use similar::{utils::TextDiffRemapper, Algorithm, DiffTag, TextDiff};
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -61,6 +61,8 @@ impl DiffOp {
 pub struct TextEditBuilder {
     index: Vec<TextRange>,
     edit: TextEdit,
+    /// Overrides [COMPRESSED_DIFFS_CONTEXT_LINES]
+    context_lines: Option<usize>,
 }
 
 impl TextEdit {
@@ -69,26 +71,67 @@ impl TextEdit {
         TextEditBuilder::default()
     }
 
-    /// Create a diff of `old` to `new`, tokenized by Unicode words
+    /// Create a diff of `old` to `new`, aligned on lines and refined by Unicode
+    /// words, see [TextEdit::from_unicode_words_with_context]
     pub fn from_unicode_words(old: &str, new: &str) -> Self {
-        let mut builder = Self::builder();
-
-        let diff = TextDiff::configure()
-            .newline_terminated(true)
-            .diff_unicode_words(old, new);
+        Self::from_unicode_words_with_context(old, new, COMPRESSED_DIFFS_CONTEXT_LINES)
+    }
 
-        let remapper = TextDiffRemapper::from_text_diff(&diff, old, new);
+    /// Create a diff of `old` to `new` that retains `context_lines` lines of
+    /// equal content around the changes.
+    ///
+    /// The lines are diffed first with the patience algorithm, which aligns the
+    /// lines that are unique in both texts, so an edit doesn't spill over the
+    /// unchanged lines around it. Each group of replaced lines is then diffed by
+    /// Unicode words with the Myers algorithm, so only the words that changed
+    /// are deleted and inserted.
+    pub fn from_unicode_words_with_context(old: &str, new: &str, context_lines: usize) -> Self {
+        let line_diff = TextDiff::configure()
+            .algorithm(Algorithm::Patience)
+            .diff_lines(old, new);
+        let old_offsets = line_offsets(line_diff.old_slices());
+        let new_offsets = line_offsets(line_diff.new_slices());
+
+        let mut changes = ChangeList::default();
+        for op in line_diff.ops() {
+            let old_range = op.old_range();
+            let old_text = &old[old_offsets[old_range.start]..old_offsets[old_range.end]];
+            let new_range = op.new_range();
+            let new_text = &new[new_offsets[new_range.start]..new_offsets[new_range.end]];
+
+            match op.tag() {
+                DiffTag::Equal => changes.push(ChangeTag::Equal, old_text.len()),
+                DiffTag::Delete => changes.push(ChangeTag::Delete, old_text.len()),
+                DiffTag::Insert => changes.push(ChangeTag::Insert, new_text.len()),
+                DiffTag::Replace => {
+                    let word_diff = TextDiff::configure()
+                        .algorithm(Algorithm::Myers)
+                        .newline_terminated(true)
+                        .diff_unicode_words(old_text, new_text);
+                    let remapper = TextDiffRemapper::from_text_diff(&word_diff, old_text, new_text);
+
+                    for (tag, text) in word_diff
+                        .ops()
+                        .iter()
+                        .flat_map(|op| remapper.iter_slices(op))
+                    {
+                        changes.push(tag, text.len());
+                    }
+                }
+            }
+        }
 
-        for (tag, text) in diff.ops().iter().flat_map(|op| remapper.iter_slices(op)) {
+        let mut builder = Self::builder().with_context_lines(context_lines);
+        for (tag, range) in changes.changes {
             match tag {
                 ChangeTag::Equal => {
-                    builder.equal(text);
+                    builder.equal(&old[range]);
                 }
                 ChangeTag::Delete => {
-                    builder.delete(text);
+                    builder.delete(&old[range]);
                 }
                 ChangeTag::Insert => {
-                    builder.insert(text);
+                    builder.insert(&new[range]);
                 }
             }
         }
@@ -172,6 +215,12 @@ impl<'a> IntoIterator for &'a TextEdit {
 }
 
 impl TextEditBuilder {
+    /// Sets the number of lines of equal content retained around the changes
+    pub fn with_context_lines(mut self, context_lines: usize) -> Self {
+        self.context_lines = Some(context_lines);
+        self
+    }
+
     pub fn is_empty(&self) -> bool {
         self.edit.ops.is_empty()
     }
@@ -218,7 +267,9 @@ impl TextEditBuilder {
     }
 
     pub fn equal(&mut self, text: &str) {
-        match compress_equal_op(text) {
+        let context_lines = self.context_lines.unwrap_or(COMPRESSED_DIFFS_CONTEXT_LINES);
+
+        match compress_equal_op(text, context_lines) {
             Some((start, mid, end)) => {
                 let start = self.intern(start);
                 self.edit
@@ -267,6 +318,51 @@ impl TextEditBuilder {
     }
 }
 
+/// The changes of a diff, as byte ranges of the old text for the equal and
+/// deleted text, and of the new text for the inserted text
+#[derive(Debug, Default)]
+struct ChangeList {
+    old_position: usize,
+    new_position: usize,
+    changes: Vec<(ChangeTag, Range<usize>)>,
+}
+
+impl ChangeList {
+    /// Adds a change of `len` bytes. It's merged with the previous change when
+    /// they have the same tag, so the diff of the lines and the diff of the
+    /// words don't produce consecutive operations of the same kind.
+    fn push(&mut self, tag: ChangeTag, len: usize) {
+        let position = match tag {
+            ChangeTag::Equal | ChangeTag::Delete => &mut self.old_position,
+            ChangeTag::Insert => &mut self.new_position,
+        };
+        let range = *position..*position + len;
+        *position += len;
+        if tag == ChangeTag::Equal {
+            self.new_position += len;
+        }
+
+        match self.changes.last_mut() {
+            Some((last_tag, last_range)) if *last_tag == tag => last_range.end = range.end,
+            _ if len > 0 => self.changes.push((tag, range)),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the byte offset of the start of each line, followed by the length
+/// of the text
+fn line_offsets(lines: &[&str]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(lines.len() + 1);
+    let mut offset = 0;
+    offsets.push(offset);
+    for line in lines {
+        offset += line.len();
+        offsets.push(offset);
+    }
+    offsets
+}
+
 /// Number of lines to keep as [DiffOp::Equal] operations around a
 /// [CompressedOp::EqualCompressedLines] operation. This has the effect of
 /// making the compressed diff retain a few line of equal content around
@@ -275,18 +371,21 @@ impl TextEditBuilder {
 /// around.
 const COMPRESSED_DIFFS_CONTEXT_LINES: usize = 2;
 
-fn compress_equal_op(text: &str) -> Option<(&str, NonZeroU32, &str)> {
+fn compress_equal_op(text: &str, context_lines: usize) -> Option<(&str, NonZeroU32, &str)> {
     let mut iter = text.split('\n');
 
-    let mut leading_len = COMPRESSED_DIFFS_CONTEXT_LINES;
-    for _ in 0..=COMPRESSED_DIFFS_CONTEXT_LINES {
+    let mut leading_len = 0;
+    for _ in 0..=context_lines {
         leading_len += iter.next()?.len();
     }
+    // The newlines between the leading lines
+    leading_len += context_lines;
 
-    let mut trailing_len = COMPRESSED_DIFFS_CONTEXT_LINES;
-    for _ in 0..=COMPRESSED_DIFFS_CONTEXT_LINES {
+    let mut trailing_len = 0;
+    for _ in 0..=context_lines {
         trailing_len += iter.next_back()?.len();
     }
+    trailing_len += context_lines;
 
     let mid_count = iter.count();
     let mid_count = u32::try_from(mid_count).ok()?;
@@ -301,7 +400,9 @@ fn compress_equal_op(text: &str) -> Option<(&str, NonZeroU32, &str)> {
 mod tests {
     use std::num::NonZeroU32;
 
-    use crate::{compress_equal_op, TextEdit};
+    use crate::{
+        compress_equal_op, ChangeTag, CompressedOp, TextEdit, COMPRESSED_DIFFS_CONTEXT_LINES,
+    };
 
     #[test]
     fn compress_short() {
@@ -312,6 +413,7 @@ start 2
 end 1
 end 2
 ",
+            COMPRESSED_DIFFS_CONTEXT_LINES,
         );
 
         assert_eq!(output, None);
@@ -329,6 +431,7 @@ mid 3
 end 1
 end 2
 ",
+            COMPRESSED_DIFFS_CONTEXT_LINES,
         );
 
         assert_eq!(
@@ -341,6 +444,82 @@ end 2
         );
     }
 
+    #[test]
+    fn compress_with_context() {
+        let output = compress_equal_op(
+            "
+start 1
+start 2
+mid 1
+mid 2
+mid 3
+end 1
+end 2
+",
+            3,
+        );
+
+        assert_eq!(
+            output,
+            Some((
+                "\nstart 1\nstart 2\nmid 1",
+                NonZeroU32::new(1).unwrap(),
+                "mid 3\nend 1\nend 2\n"
+            ))
+        );
+
+        let output = compress_equal_op("a\nb\nc\nd\ne\nf\ng\nh", usize::MAX);
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn diff_words_of_replaced_lines() {
+        let diff = TextEdit::from_unicode_words("a\nfoo(b)\nc\n", "a\nfoo(d)\nc\n");
+        let ops: Vec<_> = diff
+            .iter()
+            .map(|op| match op {
+                CompressedOp::DiffOp(op) => (op.tag(), op.text(&diff)),
+                CompressedOp::EqualLines { .. } => unreachable!(),
+            })
+            .collect();
+
+        assert_eq!(
+            ops,
+            [
+                (ChangeTag::Equal, "a\nfoo("),
+                (ChangeTag::Delete, "b"),
+                (ChangeTag::Insert, "d"),
+                (ChangeTag::Equal, ")\nc\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn new_string_moved_lines() {
+        const OLD: &str = "function a() {
+    return 1;
+}
+
+function b() {
+    return 2;
+}
+";
+
+        const NEW: &str = "function b() {
+    return 2;
+}
+
+function a() {
+    return 10;
+}
+";
+
+        let diff = TextEdit::from_unicode_words(OLD, NEW);
+        let new_string = diff.new_string(OLD);
+
+        assert_eq!(new_string, NEW);
+    }
+
     #[test]
     fn new_string_compressed() {
         const OLD: &str = "line 1 old
@@ -19,6 +19,7 @@
 #![forbid(unsafe_code)]
 #![warn(missing_debug_implementations, missing_docs)]
 
+mod line_index;
 mod range;
 mod size;
 mod traits;
@@ -28,7 +29,12 @@ mod schemars_impls;
 #[cfg(feature = "serde")]
 mod serde_impls;
 
-pub use crate::{range::TextRange, size::TextSize, traits::TextLen};
+pub use crate::{
+    line_index::{LineCol, LineIndex, WideEncoding, WideLineCol},
+    range::TextRange,
+    size::TextSize,
+    traits::TextLen,
+};
 
 #[cfg(target_pointer_width = "16")]
 compile_error!("text-size assumes usize >= u32 and does not work on 16-bit targets");
@@ -0,0 +1,238 @@
+//! [`LineIndex`] maps flat [`TextSize`] offsets into `(line, column)` positions,
+//! with the columns counted in UTF-8, UTF-16 or UTF-32 code units.
+
+use crate::TextSize;
+
+/// The encoding of the columns of a [`WideLineCol`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WideEncoding {
+    /// Columns are counted in UTF-16 code units, e.g. by the LSP clients by default
+    Utf16,
+    /// Columns are counted in characters
+    Utf32,
+}
+
+/// A position whose column is a UTF-8 offset inside the line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LineCol {
+    /// Zero-based
+    pub line: u32,
+    /// Zero-based utf8 offset
+    pub col: u32,
+}
+
+/// A position whose column is counted in the code units of a [`WideEncoding`].
+///
+/// Deliberately not a generic type and different from [`LineCol`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WideLineCol {
+    /// Zero-based
+    pub line: u32,
+    /// Zero-based
+    pub col: u32,
+}
+
+/// A non-ASCII character of the text
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct WideChar {
+    /// Start offset of the character inside the text
+    start: TextSize,
+    /// End offset of the character inside the text
+    end: TextSize,
+    /// The number of UTF-16 code units saved by the preceding wide characters,
+    /// compared to UTF-8 code units
+    utf16_savings: u32,
+    /// Same as `utf16_savings`, for UTF-32
+    utf32_savings: u32,
+}
+
+impl WideChar {
+    /// Returns the length in 8-bit UTF-8 code units.
+    fn len(&self) -> u32 {
+        u32::from(self.end - self.start)
+    }
+
+    /// Returns the length in UTF-16 or UTF-32 code units.
+    fn wide_len(&self, enc: WideEncoding) -> u32 {
+        match enc {
+            WideEncoding::Utf16 => {
+                if self.len() == 4 {
+                    2
+                } else {
+                    1
+                }
+            }
+
+            WideEncoding::Utf32 => 1,
+        }
+    }
+
+    /// Returns the code units of `enc` saved by the preceding wide characters
+    fn savings(&self, enc: WideEncoding) -> u32 {
+        match enc {
+            WideEncoding::Utf16 => self.utf16_savings,
+            WideEncoding::Utf32 => self.utf32_savings,
+        }
+    }
+}
+
+/// Converts the offsets of a text into positions, and back.
+///
+/// Only `\n` is a line break: a `\r` that precedes it is the last character of its line.
+/// All the conversions take `O(log n)` time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use biome_text_size::*;
+/// let line_index = LineIndex::new("let a;\nlet 🦀 = 'ü';");
+///
+/// let line_col = line_index.line_col(TextSize::from(21)).unwrap();
+/// assert_eq!(line_col, LineCol { line: 1, col: 14 });
+///
+/// let wide_line_col = line_index.to_wide(WideEncoding::Utf16, line_col).unwrap();
+/// assert_eq!(wide_line_col, WideLineCol { line: 1, col: 11 });
+/// assert_eq!(line_index.to_utf8(WideEncoding::Utf16, wide_line_col), line_col);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Offset the beginning of each line, zero-based.
+    newlines: Vec<TextSize>,
+    /// The non-ASCII characters, in the order of the text.
+    wide_chars: Vec<WideChar>,
+    /// The code units saved by all the wide characters, in UTF-16 and UTF-32
+    total_savings: (u32, u32),
+}
+
+impl LineIndex {
+    /// Creates the index of `text`.
+    pub fn new(text: &str) -> LineIndex {
+        let mut newlines = vec![TextSize::from(0)];
+        let mut wide_chars = Vec::new();
+        let mut utf16_savings = 0;
+        let mut utf32_savings = 0;
+
+        for (offset, char) in text.char_indices() {
+            if char.is_ascii() && char != '\n' {
+                continue;
+            }
+
+            // SAFETY: the conversion from `usize` to `TextSize` can fail if `offset`
+            // is larger than 2^32. We don't support such large files.
+            let start = TextSize::try_from(offset).expect("TextSize overflow");
+            let end = start + TextSize::of(char);
+            if char == '\n' {
+                newlines.push(end);
+                continue;
+            }
+
+            let wide_char = WideChar {
+                start,
+                end,
+                utf16_savings,
+                utf32_savings,
+            };
+            utf16_savings += wide_char.len() - wide_char.wide_len(WideEncoding::Utf16);
+            utf32_savings += wide_char.len() - wide_char.wide_len(WideEncoding::Utf32);
+            wide_chars.push(wide_char);
+        }
+
+        LineIndex {
+            newlines,
+            wide_chars,
+            total_savings: (utf16_savings, utf32_savings),
+        }
+    }
+
+    /// Returns the number of lines in the index, clamped to [u32::MAX]
+    pub fn len(&self) -> u32 {
+        self.newlines.len().try_into().unwrap_or(u32::MAX)
+    }
+
+    /// Returns `true` if the index has no lines, which never happens: an
+    /// empty text has a single empty line.
+    pub fn is_empty(&self) -> bool {
+        self.newlines.is_empty()
+    }
+
+    /// Returns the offset of the start of `line`, or `None` if the text has
+    /// fewer lines.
+    pub fn line_start(&self, line: u32) -> Option<TextSize> {
+        self.newlines.get(line as usize).copied()
+    }
+
+    /// Returns the position of `offset`, with the column in UTF-8 code units.
+    pub fn line_col(&self, offset: TextSize) -> Option<LineCol> {
+        let line = self.newlines.partition_point(|&it| it <= offset) - 1;
+        let line_start_offset = self.newlines.get(line)?;
+        let col = offset - line_start_offset;
+
+        Some(LineCol {
+            line: u32::try_from(line).ok()?,
+            col: col.into(),
+        })
+    }
+
+    /// Returns the offset of a position whose column is in UTF-8 code units.
+    pub fn offset(&self, line_col: LineCol) -> Option<TextSize> {
+        self.newlines
+            .get(line_col.line as usize)
+            .map(|offset| offset + TextSize::from(line_col.col))
+    }
+
+    /// Converts the column of `line_col` to the code units of `enc`.
+    pub fn to_wide(&self, enc: WideEncoding, line_col: LineCol) -> Option<WideLineCol> {
+        let line_start = self.line_start(line_col.line)?;
+        let offset = line_start + TextSize::from(line_col.col);
+        let first = self.wide_chars.partition_point(|c| c.start < line_start);
+        // The wide characters of the line that end before the offset are the ones
+        // between `first` and `last`
+        let last = self.wide_chars.partition_point(|c| c.end <= offset);
+        let saved = self.savings_before(enc, last) - self.savings_before(enc, first);
+
+        Some(WideLineCol {
+            line: line_col.line,
+            col: line_col.col - saved,
+        })
+    }
+
+    /// Converts the column of `line_col` from the code units of `enc` to UTF-8 code units.
+    pub fn to_utf8(&self, enc: WideEncoding, line_col: WideLineCol) -> LineCol {
+        let Some(line_start) = self.line_start(line_col.line) else {
+            return LineCol {
+                line: line_col.line,
+                col: line_col.col,
+            };
+        };
+        let line_end = line_col
+            .line
+            .checked_add(1)
+            .and_then(|line| self.line_start(line))
+            .unwrap_or(TextSize::from(u32::MAX));
+        let first = self.wide_chars.partition_point(|c| c.start < line_start);
+        let last = self.wide_chars.partition_point(|c| c.start < line_end);
+        let savings_before_line = self.savings_before(enc, first);
+        // The column of the wide characters in code units of `enc` increases with their offset,
+        // so the characters of the line that start before the column are found by a binary search
+        let count = self.wide_chars[first..last].partition_point(|c| {
+            u32::from(c.start - line_start) - (c.savings(enc) - savings_before_line) < line_col.col
+        });
+        let saved = self.savings_before(enc, first + count) - savings_before_line;
+
+        LineCol {
+            line: line_col.line,
+            col: line_col.col + saved,
+        }
+    }
+
+    /// Returns the code units of `enc` saved by the wide characters before the one at `index`
+    fn savings_before(&self, enc: WideEncoding, index: usize) -> u32 {
+        match self.wide_chars.get(index) {
+            Some(wide_char) => wide_char.savings(enc),
+            None => match enc {
+                WideEncoding::Utf16 => self.total_savings.0,
+                WideEncoding::Utf32 => self.total_savings.1,
+            },
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use biome_text_size::*;
+
+fn size(x: u32) -> TextSize {
+    TextSize::from(x)
+}
+
+#[test]
+fn line_col() {
+    let line_index = LineIndex::new("abc\r\ndef\n\nghi");
+
+    assert_eq!(line_index.len(), 4);
+    assert_eq!(
+        line_index.line_col(size(4)),
+        Some(LineCol { line: 0, col: 4 })
+    );
+    assert_eq!(
+        line_index.line_col(size(5)),
+        Some(LineCol { line: 1, col: 0 })
+    );
+    assert_eq!(
+        line_index.line_col(size(13)),
+        Some(LineCol { line: 3, col: 3 })
+    );
+    assert_eq!(
+        line_index.offset(LineCol { line: 2, col: 0 }),
+        Some(size(9))
+    );
+    assert_eq!(line_index.offset(LineCol { line: 4, col: 0 }), None);
+}
+
+#[test]
+fn wide_columns() {
+    // `é` takes 2 bytes and 1 UTF-16 code unit, `🦀` takes 4 bytes and 2 UTF-16 code units
+    let line_index = LineIndex::new("é🦀a\nb🦀é🦀c");
+
+    let cases = [
+        // (utf8, utf16, utf32)
+        (LineCol { line: 0, col: 0 }, 0, 0),
+        (LineCol { line: 0, col: 2 }, 1, 1),
+        (LineCol { line: 0, col: 6 }, 3, 2),
+        (LineCol { line: 0, col: 7 }, 4, 3),
+        (LineCol { line: 1, col: 1 }, 1, 1),
+        (LineCol { line: 1, col: 5 }, 3, 2),
+        (LineCol { line: 1, col: 7 }, 4, 3),
+        (LineCol { line: 1, col: 11 }, 6, 4),
+        (LineCol { line: 1, col: 12 }, 7, 5),
+    ];
+
+    for (line_col, utf16, utf32) in cases {
+        for (enc, col) in [(WideEncoding::Utf16, utf16), (WideEncoding::Utf32, utf32)] {
+            let wide_line_col = line_index.to_wide(enc, line_col);
+            assert_eq!(
+                wide_line_col,
+                Some(WideLineCol {
+                    line: line_col.line,
+                    col
+                }),
+                "{line_col:?} in {enc:?}"
+            );
+            assert_eq!(line_index.to_utf8(enc, wide_line_col.unwrap()), line_col);
+        }
+    }
+}
+
+#[test]
+fn wide_columns_past_the_line() {
+    let line_index = LineIndex::new("🦀\n");
+
+    assert_eq!(
+        line_index.to_utf8(WideEncoding::Utf16, WideLineCol { line: 1, col: 2 }),
+        LineCol { line: 1, col: 2 }
+    );
+    assert_eq!(
+        line_index.to_wide(WideEncoding::Utf16, LineCol { line: 2, col: 0 }),
+        None
+    );
+}
@@ -2,10 +2,10 @@ use js_sys::Error;
 use wasm_bindgen::prelude::*;
 
 use biome_service::workspace::{
-    self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
-    FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RenameParams, UpdateSettingsParams,
+    self, ChangeFileParams, CloseFileParams, FileCapabilitiesParams, FixFileParams,
+    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
+    GetFileContentParams, GetFormatterIRParams, GetSyntaxTreeParams, OrganizeImportsParams,
+    PullActionsParams, PullDiagnosticsParams, RenameParams, RuleDocsParams, UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -194,6 +194,28 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    #[wasm_bindgen(js_name = ruleDocs)]
+    pub fn rule_docs(&self, params: IRuleDocsParams) -> Result<IRuleDocsResult, Error> {
+        let params: RuleDocsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.rule_docs(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IRuleDocsResult::from)
+            .map_err(into_error)
+    }
+
+    pub fn capabilities(
+        &self,
+        params: IFileCapabilitiesParams,
+    ) -> Result<IFileCapabilitiesResult, Error> {
+        let params: FileCapabilitiesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.capabilities(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IFileCapabilitiesResult::from)
+            .map_err(into_error)
+    }
 }
 
 fn to_value<T: serde::ser::Serialize + ?Sized>(
@@ -4,7 +4,7 @@ export interface SupportsFeatureParams {
 	feature: FeatureName[];
 	path: RomePath;
 }
-export type FeatureName = "Format" | "Lint" | "OrganizeImports";
+export type FeatureName = "Format" | "Lint" | "OrganizeImports" | "Search";
 export interface RomePath {
 	path: string;
 }
@@ -18,6 +18,18 @@ export type SupportKind =
 	| "FileNotSupported";
 export interface UpdateSettingsParams {
 	configuration: Configuration;
+	/**
+	 * The `package.json` of the project. Its dependencies are the packages that the files of the project can import.
+	 */
+	package_json_files?: PackageJsonFile[];
+	/**
+	 * The `tsconfig.json` or the `jsconfig.json` of the project, followed by the files it extends. The patterns of its `paths` option are the path aliases of the analyzer, e.g. to sort the imports.
+	 */
+	tsconfig_files?: TsConfigFile[];
+	/**
+	 * The ignore files of the VCS, from the least to the most specific: the patterns of a file take precedence over the patterns of the files before it. They replace the ignore files of the previous update.
+	 */
+	vcs_ignore_files?: VcsIgnoreFile[];
 }
 /**
  * The configuration that is contained inside the file `biome.json`
@@ -28,7 +40,7 @@ export interface Configuration {
 	 */
 	$schema?: string;
 	/**
-	 * A list of paths to other JSON files, used to extends the current configuration.
+	 * A list of paths to other JSON files, or of npm packages that export one, used to extends the current configuration.
 	 */
 	extends?: StringSet;
 	/**
@@ -59,16 +71,47 @@ export interface Configuration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * Named sets of options that are merged over the configuration when they are selected with `--profile`
+	 */
+	profiles?: Profiles;
+	/**
+	 * The configuration of the project analysis
+	 */
+	project?: ProjectConfiguration;
 	/**
 	 * The configuration of the VCS integration
 	 */
 	vcs?: VcsConfiguration;
 }
+/**
+ * An ignore file of the VCS, e.g. a `.gitignore` file
+ */
+export interface PackageJsonFile {
+	content: string;
+	path: string;
+}
+export interface TsConfigFile {
+	content: string;
+	path: string;
+}
+export interface VcsIgnoreFile {
+	content: string;
+	/**
+	 * The directory the patterns of the file are relative to, usually the directory of the file. The global excludes of git are relative to the root of the repository.
+	 */
+	directory: string;
+	path: string;
+}
 export type StringSet = string[];
 /**
  * The configuration of the filesystem
  */
 export interface FilesConfiguration {
+	/**
+	 * Whether Biome should follow the symbolic links when it traverses the file system. Defaults to `true`
+	 */
+	followSymlinks?: boolean;
 	/**
 	 * A list of Unix shell style patterns. Biome will ignore files/folders that will match these patterns.
 	 */
@@ -123,6 +166,10 @@ export interface FormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Use the `.editorconfig` file of the project to set the options of the formatter that the configuration doesn't set. `false` by default.
+	 */
+	useEditorconfig?: boolean;
 }
 /**
  * A set of options applied to the JavaScript files
@@ -190,6 +237,27 @@ export interface OrganizeImports {
 	include?: StringSet;
 }
 export type Overrides = OverridePattern[];
+/**
+ * Named sets of options, merged over the configuration when a profile is selected with `--profile` or `BIOME_PROFILE`
+ */
+export interface Profiles {}
+/**
+ * The configuration of the project analysis
+ */
+export interface ProjectConfiguration {
+	/**
+	 * The constraints on the tags of the modules that the tagged modules can import
+	 */
+	boundaries?: BoundaryConstraint[];
+	/**
+	 * The severity of the diagnostics of the project rules, `"off"` disables a rule
+	 */
+	rules?: ProjectRules;
+	/**
+	 * The tags of the modules, e.g. `scope:ui`, assigned to the files and the directories that match the patterns
+	 */
+	tags?: ModuleTags[];
+}
 /**
  * Set of properties to integrate Biome with a VCS software.
  */
@@ -198,6 +266,10 @@ export interface VcsConfiguration {
 	 * The kind of client.
 	 */
 	clientKind?: VcsClientKind;
+	/**
+	 * The main branch of the project. Biome uses it as base when `--changed` is passed without `--since`.
+	 */
+	defaultBranch?: string;
 	/**
 	 * Whether Biome should integrate itself with the VCS client
 	 */
@@ -229,6 +301,14 @@ export interface JavascriptFormatter {
 	 * Whether to add non-necessary parentheses to arrow functions. Defaults to "always".
 	 */
 	arrowParentheses?: ArrowParentheses;
+	/**
+	 * Whether to add a blank line after the imports.
+	 */
+	blankLineAfterImports?: boolean;
+	/**
+	 * Whether to add a blank line before return statements.
+	 */
+	blankLineBeforeReturn?: boolean;
 	/**
 	 * Whether to hug the closing bracket of multiline HTML/JSX tags to the end of the last line, rather than being alone on the following line. Defaults to false.
 	 */
@@ -257,6 +337,10 @@ export interface JavascriptFormatter {
 	 * The type of quotes used in JSX. Defaults to double.
 	 */
 	jsxQuoteStyle?: QuoteStyle;
+	/**
+	 * Whether the formatter keeps the rendered text of the JSX elements unchanged.
+	 */
+	jsxWhitespaceFidelity?: boolean;
 	/**
 	 * The type of line ending applied to JavaScript (and its super languages) files.
 	 */
@@ -277,10 +361,22 @@ export interface JavascriptFormatter {
 	 * Whether the formatter prints semicolons for all statements or only in for statements where it is necessary because of ASI.
 	 */
 	semicolons?: Semicolons;
+	/**
+	 * Whether to keep short if statements on a single line.
+	 */
+	singleLineIfStatements?: boolean;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
 	 */
 	trailingComma?: TrailingComma;
+	/**
+	 * Unions with more members than this always break.
+	 */
+	unionBreakThreshold?: number;
+	/**
+	 * Whether multiline unions start with a `|`.
+	 */
+	unionLeadingSeparator?: boolean;
 }
 export interface JavascriptOrganizeImports {}
 /**
@@ -319,6 +415,10 @@ export interface JsonFormatter {
 	 * What's the max width of a line applied to JSON (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Sort the keys of `package.json` files.
+	 */
+	sortPackageJsonKeys?: boolean;
 }
 /**
  * Options that changes how the JSON parser behaves
@@ -341,7 +441,7 @@ export interface Rules {
 	all?: boolean;
 	complexity?: Complexity;
 	correctness?: Correctness;
-	nursery?: Nursery;
+	nursery?: NurseryConfiguration;
 	performance?: Performance;
 	/**
 	 * It enables the lint rules recommended by Biome. `true` by default.
@@ -352,6 +452,10 @@ export interface Rules {
 	suspicious?: Suspicious;
 }
 export interface OverridePattern {
+	/**
+	 * Specific configuration for the filesystem
+	 */
+	files?: OverrideFilesConfiguration;
 	/**
 	 * Specific configuration for the Json language
 	 */
@@ -381,10 +485,73 @@ export interface OverridePattern {
 	 */
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
+/**
+ * A constraint on the modules that the modules with a tag can import
+ */
+export interface BoundaryConstraint {
+	/**
+	 * The modules with the tag can't import the modules that have one of these tags
+	 */
+	notDependOnTags?: StringSet;
+	/**
+	 * The modules with the tag can only import the modules that have one of these tags
+	 */
+	onlyDependOnTags?: StringSet;
+	/**
+	 * The tag of the modules that the constraint applies to
+	 */
+	sourceTag: string;
+}
+/**
+ * Tags assigned to the modules that match a list of patterns
+ */
+export interface ModuleTags {
+	/**
+	 * A list of Unix shell style patterns. The modules that match them, or whose directory matches them, have the tags.
+	 */
+	include: StringSet;
+	/**
+	 * The tags of the modules, e.g. `scope:ui` or `type:feature`
+	 */
+	tags: StringSet;
+}
+/**
+ * The levels of the project rules, e.g. `"importCycle": "error"`. The diagnostics of a rule can also be suppressed with a comment, e.g. `// biome-ignore project/importCycle: <explanation>`
+ */
+export interface ProjectRules {
+	/**
+	 * The imports of the modules that re-export all the exports of other modules. Defaults to `"warn"`.
+	 */
+	barrelImport?: RulePlainConfiguration;
+	/**
+	 * The static imports that are part of an import cycle. Defaults to `"warn"`.
+	 */
+	importCycle?: RulePlainConfiguration;
+	/**
+	 * The imports that break the constraints of `boundaries`. Defaults to `"error"`.
+	 */
+	moduleBoundary?: RulePlainConfiguration;
+	/**
+	 * The imports of the client modules that load a module importing `server-only`. Defaults to `"error"`.
+	 */
+	serverOnlyImport?: RulePlainConfiguration;
+	/**
+	 * The classes read on the imports of CSS modules that don't declare them. Defaults to `"warn"`.
+	 */
+	unknownClass?: RulePlainConfiguration;
+	/**
+	 * The classes of the CSS modules that the modules importing them don't read. Defaults to `"warn"`.
+	 */
+	unusedClass?: RulePlainConfiguration;
+	/**
+	 * The exports that no module of the project imports. The entry points of the packages aren't checked. Defaults to `"warn"`.
+	 */
+	unusedExport?: RulePlainConfiguration;
+}
 export type VcsClientKind = "git";
 export type ArrowParentheses = "always" | "asNeeded";
 export type QuoteStyle = "double" | "single";
-export type QuoteProperties = "asNeeded" | "preserve";
+export type QuoteProperties = "asNeeded" | "preserve" | "consistent";
 export type Semicolons = "always" | "asNeeded";
 /**
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
@@ -398,6 +565,10 @@ export interface A11y {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * The severity of the rules of this group that don't set their own level
+	 */
+	level?: RuleGroupLevel;
 	/**
 	 * Enforce that the accessKey attribute is not used on any HTML element.
 	 */
@@ -523,6 +694,10 @@ export interface Complexity {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * The severity of the rules of this group that don't set their own level
+	 */
+	level?: RuleGroupLevel;
 	/**
 	 * Disallow primitive type aliases and misleading types.
 	 */
@@ -632,6 +807,10 @@ export interface Correctness {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * The severity of the rules of this group that don't set their own level
+	 */
+	level?: RuleGroupLevel;
 	/**
 	 * Prevent passing of children as props.
 	 */
@@ -769,6 +948,10 @@ export interface Correctness {
 	 */
 	useYield?: RuleConfiguration;
 }
+/**
+ * The configuration of the `nursery` group: its rules, or a preset
+ */
+export type NurseryConfiguration = NurseryPreset | Nursery;
 /**
  * A list of rules that belong to this group
  */
@@ -777,26 +960,102 @@ export interface Nursery {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * The severity of the rules of this group that don't set their own level
+	 */
+	level?: RuleGroupLevel;
 	/**
 	 * Enforce that aria-hidden="true" is not set on focusable elements.
 	 */
 	noAriaHiddenOnFocusable?: RuleConfiguration;
+	/**
+	 * Disallow barrel files.
+	 */
+	noBarrelFile?: RuleConfiguration;
+	/**
+	 * Disallow calling `expect` conditionally in the tests.
+	 */
+	noConditionalExpect?: RuleConfiguration;
 	/**
 	 * Disallow default exports.
 	 */
 	noDefaultExport?: RuleConfiguration;
+	/**
+	 * Disallow calling the functions and the classes marked with the JSDoc tag @deprecated.
+	 */
+	noDeprecatedUsage?: RuleConfiguration;
+	/**
+	 * Disallow the `done` callback of the tests and the hooks.
+	 */
+	noDoneCallback?: RuleConfiguration;
 	/**
 	 * Disallow two keys with the same name inside a JSON object.
 	 */
 	noDuplicateJsonKeys?: RuleConfiguration;
+	/**
+	 * Disallow calling the `Function` constructor with arguments that aren't literals.
+	 */
+	noDynamicFunctionConstructor?: RuleConfiguration;
 	/**
 	 * Disallow empty block statements and static blocks.
 	 */
 	noEmptyBlockStatements?: RuleConfiguration;
+	/**
+	 * Disallow the files that have more lines than a given maximum.
+	 */
+	noExcessiveFileLength?: RuleConfiguration;
+	/**
+	 * Disallow the functions that have more parameters than a given maximum.
+	 */
+	noExcessiveParams?: RuleConfiguration;
+	/**
+	 * Disallow the functions that have more lines than a given maximum.
+	 */
+	noExcessivelyLongFunctions?: RuleConfiguration;
+	/**
+	 * Disallow the hooks of React that need a client in the Server Components.
+	 */
+	noHooksInServerComponents?: RuleConfiguration;
+	/**
+	 * Disallow the tests and the `describe` blocks that have the title of a sibling.
+	 */
+	noIdenticalTestTitles?: RuleConfiguration;
 	/**
 	 * Disallow use of implicit any type on variable declarations.
 	 */
 	noImplicitAnyLet?: RuleConfiguration;
+	/**
+	 * Disallow the literal texts in JSX that aren't localized.
+	 */
+	noJsxLiteralText?: RuleConfiguration;
+	/**
+	 * Disallow promises in places that aren't designed to handle them.
+	 */
+	noMisusedPromises?: RuleConfiguration;
+	/**
+	 * Disallow export * re-exports.
+	 */
+	noReExportAll?: RuleConfiguration;
+	/**
+	 * Disallow the `javascript:` URLs in the links and the sources of the JSX elements.
+	 */
+	noScriptUrl?: RuleConfiguration;
+	/**
+	 * Disallow the imports of packages that aren't declared in the `package.json`.
+	 */
+	noUndeclaredDependencies?: RuleConfiguration;
+	/**
+	 * Disallow assigning the HTML built with a template literal to `innerHTML` or `outerHTML`.
+	 */
+	noUnsafeInnerHtml?: RuleConfiguration;
+	/**
+	 * Disallow the syntax and the APIs that the browsers targeted by the project don't support.
+	 */
+	noUnsupportedBrowserApis?: RuleConfiguration;
+	/**
+	 * Disallow the built-in modules and APIs of Node.js that aren't available in the versions that the project supports.
+	 */
+	noUnsupportedNodeBuiltins?: RuleConfiguration;
 	/**
 	 * Disallow unused imports.
 	 */
@@ -817,6 +1076,14 @@ export interface Nursery {
 	 * Ensure async functions utilize await.
 	 */
 	useAwait?: RuleConfiguration;
+	/**
+	 * Require a consistent syntax for the array types.
+	 */
+	useConsistentArrayType?: RuleConfiguration;
+	/**
+	 * Require a consistent syntax for the object types that only have an index signature.
+	 */
+	useConsistentRecordType?: RuleConfiguration;
 	/**
 	 * Enforce the use of import type when an import only has specifiers with type qualifier.
 	 */
@@ -825,14 +1092,26 @@ export interface Nursery {
 	 * Disallows package private imports.
 	 */
 	useImportRestrictions?: RuleConfiguration;
+	/**
+	 * Enforce that the @param tags of a JSDoc comment match the parameters of the function.
+	 */
+	useJsdocParamMatch?: RuleConfiguration;
 	/**
 	 * Enforce the use of the regular expression literals instead of the RegExp constructor if possible.
 	 */
 	useRegexLiterals?: RuleConfiguration;
+	/**
+	 * Require the tests and the hooks to be in a `describe` block.
+	 */
+	useTopLevelDescribe?: RuleConfiguration;
 	/**
 	 * Elements with ARIA roles must use a valid, non-abstract ARIA role.
 	 */
 	useValidAriaRole?: RuleConfiguration;
+	/**
+	 * Enforce the syntax of the tags of the JSDoc comments.
+	 */
+	useValidJsdoc?: RuleConfiguration;
 }
 /**
  * A list of rules that belong to this group
@@ -842,6 +1121,10 @@ export interface Performance {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * The severity of the rules of this group that don't set their own level
+	 */
+	level?: RuleGroupLevel;
 	/**
 	 * Disallow the use of spread (...) syntax on accumulators.
 	 */
@@ -863,6 +1146,10 @@ export interface Security {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * The severity of the rules of this group that don't set their own level
+	 */
+	level?: RuleGroupLevel;
 	/**
 	 * Prevent the usage of dangerous JSX props
 	 */
@@ -884,6 +1171,10 @@ export interface Style {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * The severity of the rules of this group that don't set their own level
+	 */
+	level?: RuleGroupLevel;
 	/**
 	 * Disallow the use of arguments
 	 */
@@ -1025,6 +1316,10 @@ export interface Suspicious {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * The severity of the rules of this group that don't set their own level
+	 */
+	level?: RuleGroupLevel;
 	/**
 	 * Usually, the definition in the standard library is more precise than what people come up with or the used constant exceeds the maximum precision of the number type.
 	 */
@@ -1206,6 +1501,12 @@ export interface Suspicious {
 	 */
 	useValidTypeof?: RuleConfiguration;
 }
+export interface OverrideFilesConfiguration {
+	/**
+	 * The maximum allowed size for source code files in bytes. Files above this limit will be ignored for performance reasons.
+	 */
+	maxSize?: number;
+}
 export interface OverrideFormatterConfiguration {
 	enabled?: boolean;
 	/**
@@ -1249,17 +1550,44 @@ export interface OverrideOrganizeImportsConfiguration {
 	 */
 	enabled?: boolean;
 }
+/**
+ * The severity of the rules of a group that don't set their own level
+ */
+export type RuleGroupLevel = "warn" | "error";
 export type RuleConfiguration = RulePlainConfiguration | RuleWithOptions;
+export type NurseryPreset = "warnOnRecommended";
 export type RulePlainConfiguration = "warn" | "error" | "off";
 export interface RuleWithOptions {
+	/**
+	 * The rule only applies to the JavaScript files whose directive prologue contains one of these directives, e.g. `use client`. A directive that starts with `!`, e.g. `!use client`, excludes the files that contain it
+	 */
+	directives?: StringSet;
+	/**
+	 * The fixes of the rule that `--apply` and `--apply-unsafe` apply. By default, `--apply` applies the safe fixes and `--apply-unsafe` applies all of them
+	 */
+	fix?: RuleFixKind;
 	level: RulePlainConfiguration;
 	options?: PossibleOptions;
 }
+/**
+ * The fixes of a rule that are applied
+ */
+export type RuleFixKind = "safe" | "unsafe";
 export type PossibleOptions =
 	| ComplexityOptions
 	| HooksOptions
 	| NamingConventionOptions
+	| NoExcessiveFileLengthOptions
+	| NoExcessiveParamsOptions
+	| NoExcessivelyLongFunctionsOptions
+	| NoJsxLiteralTextOptions
+	| NoUndeclaredDependenciesOptions
+	| NoUnsupportedBrowserApisOptions
+	| NoUnsupportedNodeBuiltinsOptions
 	| RestrictedGlobalsOptions
+	| UseAwaitOptions
+	| UseConsistentArrayTypeOptions
+	| UseConsistentRecordTypeOptions
 	| ValidAriaRoleOptions;
 /**
  * Options for the rule `noExcessiveCognitiveComplexity`.
@@ -1292,6 +1620,81 @@ export interface NamingConventionOptions {
 	 */
 	strictCase: boolean;
 }
+/**
+ * Options for the rule `noExcessiveFileLength`.
+ */
+export interface NoExcessiveFileLengthOptions {
+	/**
+	 * The maximum number of lines of a file.
+	 */
+	maxLines: number;
+	/**
+	 * Don't count the lines that only contain whitespace.
+	 */
+	skipBlankLines: boolean;
+	/**
+	 * Don't count the lines that only contain comments.
+	 */
+	skipComments: boolean;
+}
+/**
+ * Options for the rule `noExcessiveParams`.
+ */
+export interface NoExcessiveParamsOptions {
+	/**
+	 * The maximum number of parameters of a function.
+	 */
+	maxParameters: number;
+}
+/**
+ * Options for the rule `noExcessivelyLongFunctions`.
+ */
+export interface NoExcessivelyLongFunctionsOptions {
+	/**
+	 * The maximum number of lines of a function.
+	 */
+	maxLines: number;
+	/**
+	 * Don't count the lines that only contain whitespace.
+	 */
+	skipBlankLines: boolean;
+	/**
+	 * Don't count the lines that only contain comments.
+	 */
+	skipComments: boolean;
+}
+export interface NoJsxLiteralTextOptions {
+	/**
+	 * The patterns of the texts that don't need to be localized. A `*` matches any text.
+	 */
+	allowedPatterns: string[];
+	/**
+	 * The components whose texts are localized, e.g. `Trans`.
+	 */
+	wrapperComponents: string[];
+	/**
+	 * The functions whose arguments are localized, e.g. `i18n._`.
+	 */
+	wrapperFunctions: string[];
+}
+export interface NoUndeclaredDependenciesOptions {
+	/**
+	 * Allow the imports of the packages of the project that aren't declared.
+	 */
+	allowWorkspacePackages: boolean;
+}
+export interface NoUnsupportedBrowserApisOptions {
+	/**
+	 * The queries of browserslist of the browsers that the project supports, e.g. `chrome >= 100`. It defaults to the `browserslist` field of the closest `package.json`.
+	 */
+	targets?: string[];
+}
+export interface NoUnsupportedNodeBuiltinsOptions {
+	/**
+	 * The range of the versions of Node.js that the project supports, e.g. `>=18.0.0`. It defaults to the `engines` field of the closest `package.json`.
+	 */
+	version?: string;
+}
 /**
  * Options for the rule `noRestrictedGlobals`.
  */
@@ -1301,6 +1704,28 @@ export interface RestrictedGlobalsOptions {
 	 */
 	deniedGlobals?: string[];
 }
+export interface UseAwaitOptions {
+	/**
+	 * Ignore the methods of the classes that implement an interface.
+	 */
+	ignoreInterfaceImplementations: boolean;
+	/**
+	 * Ignore the class methods marked with the `override` modifier.
+	 */
+	ignoreOverriddenMethods: boolean;
+}
+export interface UseConsistentArrayTypeOptions {
+	/**
+	 * The syntax of the array types.
+	 */
+	syntax: ArrayTypeSyntax;
+}
+export interface UseConsistentRecordTypeOptions {
+	/**
+	 * The syntax of the object types that only have an index signature.
+	 */
+	syntax: RecordTypeSyntax;
+}
 export interface ValidAriaRoleOptions {
 	allowedInvalidRoles: string[];
 	ignoreNonDom: boolean;
@@ -1325,6 +1750,42 @@ export interface Hooks {
  * Supported cases for TypeScript `enum` member names.
  */
 export type EnumMemberCase = "PascalCase" | "CONSTANT_CASE" | "camelCase";
+/**
+ * The syntaxes of the array types.
+ */
+export type ArrayTypeSyntax = "shorthand" | "generic";
+/**
+ * The syntaxes of the object types that only have an index signature.
+ */
+export type RecordTypeSyntax = "record" | "indexSignature";
+export interface RegisterNestedConfigurationsParams {
+	/**
+	 * The overrides that apply the nested configuration files to the files beneath their directories, see [crate::configuration::nested]. They are added after the overrides of the settings, until the settings are updated.
+	 */
+	overrides: Overrides;
+	/**
+	 * The `.editorconfig` files, from the shallowest to the deepest, see [crate::configuration::editorconfig]. They are applied before the overrides of the settings, until the settings are updated.
+	 */
+	editorconfig_files?: EditorConfigOverrides[];
+	/**
+	 * The ignore files of the VCS found in the subdirectories, from the least to the most specific. They are added after the ignore files of the settings, until the settings are updated.
+	 */
+	vcs_ignore_files?: VcsIgnoreFile[];
+}
+/**
+ * The sections of an `.editorconfig` file, converted to overrides
+ */
+export interface EditorConfigOverrides {
+	/**
+	 * The directory of the file, spelled like the paths of the files beneath it. It's empty for the files above the directory the paths are relative to.
+	 */
+	directory: string;
+	overrides: Overrides;
+	/**
+	 * Whether the file has `root = true`: the files of the parent directories don't apply to the files beneath it
+	 */
+	root: boolean;
+}
 export interface ProjectFeaturesParams {
 	manifest_path: RomePath;
 }
@@ -1381,9 +1842,12 @@ export interface GetFormatterIRParams {
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
 	max_diagnostics: number;
+	only?: RuleSelector[];
 	path: RomePath;
+	skip?: RuleSelector[];
 }
 export type RuleCategories = RuleCategory[];
+export type RuleSelector = { Group: string } | { Rule: [string, string] };
 export type RuleCategory = "Syntax" | "Lint" | "Action" | "Transformation";
 export interface PullDiagnosticsResult {
 	diagnostics: Diagnostic[];
@@ -1498,19 +1962,42 @@ export type Category =
 	| "lint/correctness/useYield"
 	| "lint/nursery/noApproximativeNumericConstant"
 	| "lint/nursery/noAriaHiddenOnFocusable"
+	| "lint/nursery/noBarrelFile"
+	| "lint/nursery/noConditionalExpect"
 	| "lint/nursery/noDefaultExport"
+	| "lint/nursery/noDeprecatedUsage"
+	| "lint/nursery/noDoneCallback"
 	| "lint/nursery/noDuplicateJsonKeys"
+	| "lint/nursery/noDynamicFunctionConstructor"
 	| "lint/nursery/noEmptyBlockStatements"
+	| "lint/nursery/noExcessiveFileLength"
+	| "lint/nursery/noExcessiveParams"
+	| "lint/nursery/noExcessivelyLongFunctions"
+	| "lint/nursery/noHooksInServerComponents"
+	| "lint/nursery/noIdenticalTestTitles"
 	| "lint/nursery/noImplicitAnyLet"
+	| "lint/nursery/noJsxLiteralText"
+	| "lint/nursery/noMisusedPromises"
+	| "lint/nursery/noReExportAll"
+	| "lint/nursery/noScriptUrl"
+	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUnsafeInnerHtml"
+	| "lint/nursery/noUnsupportedBrowserApis"
+	| "lint/nursery/noUnsupportedNodeBuiltins"
 	| "lint/nursery/noUnusedImports"
 	| "lint/nursery/noUnusedPrivateClassMembers"
 	| "lint/nursery/noUselessLoneBlockStatements"
 	| "lint/nursery/useAwait"
 	| "lint/nursery/useBiomeSuppressionComment"
+	| "lint/nursery/useConsistentArrayType"
+	| "lint/nursery/useConsistentRecordType"
 	| "lint/nursery/useGroupedTypeImport"
 	| "lint/nursery/useImportRestrictions"
+	| "lint/nursery/useJsdocParamMatch"
 	| "lint/nursery/useRegexLiterals"
+	| "lint/nursery/useTopLevelDescribe"
 	| "lint/nursery/useValidAriaRole"
+	| "lint/nursery/useValidJsdoc"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noDelete"
 	| "lint/security/noDangerouslySetInnerHtml"
@@ -1600,9 +2087,16 @@ export type Category =
 	| "migrate"
 	| "deserialize"
 	| "project"
+	| "project/barrelImport"
+	| "project/importCycle"
+	| "project/serverOnlyImport"
+	| "project/unknownClass"
+	| "project/unusedClass"
+	| "project/unusedExport"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
+	| "internalError/cancelled"
 	| "parse"
 	| "parse/noSuperWithoutExtends"
 	| "parse/noInitializerWithDefinite"
@@ -1644,7 +2138,9 @@ export type Advice =
 	| { Log: [LogCategory, MarkupBuf] }
 	| { List: MarkupBuf[] }
 	| { Frame: Location }
+	| { Labels: Label[] }
 	| { Diff: TextEdit }
+	| { Suggestion: [Applicability, MarkupBuf, TextEdit] }
 	| { Backtrace: [MarkupBuf, Backtrace] }
 	| { Command: string }
 	| { Group: [MarkupBuf, Advices] };
@@ -1653,6 +2149,13 @@ export type Advice =
  */
 export type Resource_for_String = "argv" | "memory" | { file: string };
 export type TextRange = [TextSize, TextSize];
+/**
+ * Serializable representation of a [Label](super::Label)
+ */
+export interface Label {
+	location: Location;
+	message: MarkupBuf;
+}
 export interface MarkupNodeBuf {
 	content: string;
 	elements: MarkupElement[];
@@ -1798,8 +2301,14 @@ export interface FormatOnTypeParams {
 }
 export interface FixFileParams {
 	fix_file_mode: FixFileMode;
+	only?: RuleSelector[];
 	path: RomePath;
 	should_format: boolean;
+	skip?: RuleSelector[];
+	/**
+	 * The unsafe fixes of these rules are applied too, even with [FixFileMode::SafeFixes] or when the configuration of the rule sets `fix` to `"safe"`
+	 */
+	unsafe_rules?: RuleSelector[];
 }
 /**
  * Which fixes should be applied during the analyzing phase
@@ -1848,9 +2357,417 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface PrepareRenameParams {
+	path: RomePath;
+	symbol_at: TextSize;
+}
+export interface PrepareRenameResult {
+	/**
+	 * Range of the symbol to rename, or `None` if the symbol can't be renamed
+	 */
+	range?: TextRange;
+}
+export interface InlayHintsParams {
+	path: RomePath;
+	/**
+	 * The hints are computed for the calls inside this range, usually the visible part of the file
+	 */
+	range: TextRange;
+}
+export interface InlayHintsResult {
+	hints: InlayHint[];
+}
+/**
+ * The name of a parameter, displayed before the argument passed to the parameter
+ */
+export interface InlayHint {
+	/**
+	 * The start of the argument
+	 */
+	offset: TextSize;
+	parameter_name: string;
+}
+export interface SearchPatternParams {
+	path: RomePath;
+	/**
+	 * A snippet of code, where the identifiers that start with `$` match any node
+	 */
+	pattern: string;
+}
+export interface SearchResults {
+	/**
+	 * The ranges of the code that matches the pattern
+	 */
+	matches: TextRange[];
+}
+export interface IndexFileParams {
+	/**
+	 * The content of the file on disk
+	 */
+	content: string;
+	path: RomePath;
+}
+export interface AnalyzeProjectParams {
+	/**
+	 * The maximum number of files to analyze, all the queued files are analyzed when it's `None`
+	 */
+	max_files?: number;
+}
+export interface AnalyzeProjectResult {
+	/**
+	 * The open documents whose diagnostics of the project rules may have changed since the last call, e.g. the modules that import a module analyzed by this call
+	 */
+	affected: RomePath[];
+	/**
+	 * The number of files analyzed by this call
+	 */
+	analyzed: number;
+	/**
+	 * The number of files still waiting to be analyzed
+	 */
+	pending: number;
+}
+export interface PullProjectDiagnosticsParams {
+	path: RomePath;
+}
+export interface PullProjectActionsParams {
+	path: RomePath;
+	range: TextRange;
+}
+export interface PullProjectActionsResult {
+	actions: ProjectAction[];
+}
+/**
+ * A code action that edits several files of the project, e.g. a module and the modules that import it
+ */
+export interface ProjectAction {
+	category: ActionCategory;
+	/**
+	 * The edits of each file, starting with the file of the action
+	 */
+	edits: FileEdits[];
+	message: MarkupBuf;
+	rule_name: [string, string];
+}
+export interface FileEdits {
+	path: RomePath;
+	/**
+	 * The replacements of the file, they don't overlap
+	 */
+	replacements: TextReplacement[];
+}
+export interface TextReplacement {
+	range: TextRange;
+	text: string;
+}
+export interface DocumentSymbolsParams {
+	path: RomePath;
+}
+export interface DocumentSymbolsResult {
+	/**
+	 * The symbols declared at the top level of the file
+	 */
+	symbols: DocumentSymbol[];
+}
+/**
+ * A symbol declared in a file, e.g. a class or a function
+ */
+export interface DocumentSymbol {
+	/**
+	 * The symbols declared inside this symbol, e.g. the methods of a class
+	 */
+	children: DocumentSymbol[];
+	kind: SymbolKind;
+	name: string;
+	/**
+	 * The range of the whole declaration
+	 */
+	range: TextRange;
+	/**
+	 * The range of the name of the symbol
+	 */
+	selection_range: TextRange;
+}
+export type SymbolKind =
+	| "Class"
+	| "Constant"
+	| "Constructor"
+	| "Enum"
+	| "EnumMember"
+	| "Function"
+	| "Interface"
+	| "Method"
+	| "Namespace"
+	| "Property"
+	| "TypeAlias"
+	| "Variable";
+export interface WorkspaceSymbolsParams {
+	/**
+	 * The maximum number of symbols returned, all the matching symbols are returned when it's `None`
+	 */
+	max_results?: number;
+	/**
+	 * The text matched with the names of the symbols. The characters of the query must appear in the name, in the same order. All the symbols match an empty query
+	 */
+	query: string;
+}
+export interface WorkspaceSymbolsResult {
+	/**
+	 * The matching symbols, the best matches first
+	 */
+	symbols: WorkspaceSymbol[];
+}
+/**
+ * A symbol of a file of the project
+ */
+export interface WorkspaceSymbol {
+	/**
+	 * The name of the symbol that contains this symbol, e.g. the class of a method
+	 */
+	container_name?: string;
+	kind: SymbolKind;
+	name: string;
+	path: RomePath;
+	/**
+	 * The range of the whole declaration
+	 */
+	range: TextRange;
+}
+export interface OpenFilesParams {
+	files: OpenFileParams[];
+}
+/**
+ * The result of an operation on many files. A file that fails doesn't stop the operation, the other files are still processed
+ */
+export interface FilesResult {
+	errors: FileError[];
+}
+/**
+ * The error of a file processed by an operation on many files
+ */
+export interface FileError {
+	error: Diagnostic;
+	path: RomePath;
+}
+export interface ChangeFilesParams {
+	files: ChangeFileParams[];
+}
+export interface CloseFilesParams {
+	paths: RomePath[];
+}
+export interface PullFilesDiagnosticsParams {
+	categories: RuleCategories;
+	/**
+	 * The maximum number of diagnostics returned for each file
+	 */
+	max_diagnostics: number;
+	/**
+	 * When not empty, only these rules are run, even if the configuration disables them
+	 */
+	only?: RuleSelector[];
+	paths: RomePath[];
+	/**
+	 * These rules are never run, even if the configuration enables them
+	 */
+	skip?: RuleSelector[];
+}
+export interface PullFilesDiagnosticsResult {
+	/**
+	 * The errors of the files whose diagnostics couldn't be computed
+	 */
+	errors: FileError[];
+	/**
+	 * The diagnostics of the files, in the order of the paths of the request
+	 */
+	files: FileDiagnostics[];
+}
+export interface FileDiagnostics {
+	path: RomePath;
+	result: PullDiagnosticsResult;
+}
+export interface FixFilesParams {
+	fix_file_mode: FixFileMode;
+	/**
+	 * When not empty, only the fixes of these rules are applied, even if the configuration disables them
+	 */
+	only?: RuleSelector[];
+	paths: RomePath[];
+	should_format: boolean;
+	/**
+	 * The fixes of these rules are never applied
+	 */
+	skip?: RuleSelector[];
+	/**
+	 * The unsafe fixes of these rules are applied too, even with [FixFileMode::SafeFixes] or when the configuration of the rule sets `fix` to `"safe"`
+	 */
+	unsafe_rules?: RuleSelector[];
+}
+export interface FixFilesResult {
+	/**
+	 * The errors of the files that couldn't be fixed
+	 */
+	errors: FileError[];
+	/**
+	 * The files changed by the fixes, in the order of the paths of the request. The files without fixes aren't listed
+	 */
+	files: FixedFile[];
+	/**
+	 * The number of errors left in the files after the fixes
+	 */
+	remaining_errors: number;
+	/**
+	 * The number of suggested fixes that weren't applied
+	 */
+	skipped_suggested_fixes: number;
+}
+/**
+ * A file changed by an operation that fixes many files
+ */
+export interface FixedFile {
+	/**
+	 * The code actions applied to the file
+	 */
+	actions: FixAction[];
+	path: RomePath;
+	/**
+	 * Replaces the text between the first and the last change of the file. Each file has a single replacement, so the edits of a batch can be applied together
+	 */
+	replacement: TextReplacement;
+}
+export interface ExplainIgnoredPathParams {
+	feature: FeatureName;
+	path: RomePath;
+}
+export interface ExplainIgnoredPathResult {
+	/**
+	 * Why the path is ignored, `None` when it isn't
+	 */
+	reason?: IgnoreReason;
+}
+/**
+ * Why a path is ignored
+ */
+export type IgnoreReason =
+	| "Overrides"
+	| { Ignored: { section: string } }
+	| { NotIncluded: { section: string } }
+	| { VcsIgnoreFile: { line: number; path: string; pattern: string } };
+export interface EnabledRulesParams {
+	path: RomePath;
+}
+export interface EnabledRulesResult {
+	rules: EnabledRule[];
+}
+/**
+ * A lint rule enabled for a file
+ */
+export interface EnabledRule {
+	group: string;
+	rule: string;
+	/**
+	 * The severity of the diagnostics of the rule
+	 */
+	severity: Severity;
+}
+export interface RuleDocsParams {}
+export interface RuleDocsResult {
+	rules: RuleDoc[];
+}
+/**
+ * The metadata and the documentation of a lint rule
+ */
+export interface RuleDoc {
+	/**
+	 * Why the rule is deprecated, and what replaces it
+	 */
+	deprecated?: string;
+	/**
+	 * The documentation of the rule, in Markdown
+	 */
+	docs: string;
+	/**
+	 * The kind of code fix of the rule, `safe` or `unsafe`
+	 */
+	fix_kind?: string;
+	group: string;
+	name: string;
+	/**
+	 * The JSON schema of the options of the rule, `None` when the rule doesn't accept any options, or when Biome is built without the `schema` feature
+	 */
+	options_schema?: any;
+	/**
+	 * Whether the rule is enabled by the recommended configuration
+	 */
+	recommended: boolean;
+	/**
+	 * The version of Biome that introduced the rule, `next` when it isn't released yet
+	 */
+	version: string;
+}
+export interface FileCapabilitiesParams {
+	path: RomePath;
+}
+/**
+ * The features that a file supports, once the configuration and the overrides are applied
+ */
+export interface FileCapabilitiesResult {
+	/**
+	 * The code actions of the file, they follow the `ignore` and `include` of the linter
+	 */
+	assists: SupportKind;
+	/**
+	 * The syntax tree, the control flow graph and the formatter IR of the file
+	 */
+	debug: SupportKind;
+	format: SupportKind;
+	lint: SupportKind;
+	organize_imports: SupportKind;
+}
+export interface MetricsParams {}
+export interface MetricsResult {
+	caches: CacheMetrics[];
+	/**
+	 * Whether the workspace records the metrics, they're only recorded when they're enabled, e.g. with the variable `BIOME_METRICS` when the daemon starts
+	 */
+	enabled: boolean;
+	/**
+	 * The durations of the spans, such as the requests, the parsing, the analysis and the formatting of the files
+	 */
+	spans: SpanMetrics[];
+}
+/**
+ * How many times a cache of the workspace had the requested entry
+ */
+export interface CacheMetrics {
+	hits: number;
+	misses: number;
+	/**
+	 * The name of the cache, e.g. `syntax` for the syntax trees of the files, or `js_references` for the references of the bindings shared by the lint rules
+	 */
+	name: string;
+}
+/**
+ * The durations of the spans of a callsite, in nanoseconds
+ */
+export interface SpanMetrics {
+	count: number;
+	max: number;
+	mean: number;
+	name: string;
+	p50: number;
+	p90: number;
+	p99: number;
+	/**
+	 * The module of the span, e.g. `biome_service::workspace::server`
+	 */
+	target: string;
+}
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
+	registerNestedConfigurations(
+		params: RegisterNestedConfigurationsParams,
+	): Promise<void>;
 	projectFeatures(
 		params: ProjectFeaturesParams,
 	): Promise<ProjectFeaturesResult>;
@@ -1873,6 +2790,35 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	prepareRename(params: PrepareRenameParams): Promise<PrepareRenameResult>;
+	inlayHints(params: InlayHintsParams): Promise<InlayHintsResult>;
+	searchPattern(params: SearchPatternParams): Promise<SearchResults>;
+	indexFile(params: IndexFileParams): Promise<void>;
+	analyzeProject(params: AnalyzeProjectParams): Promise<AnalyzeProjectResult>;
+	pullProjectDiagnostics(
+		params: PullProjectDiagnosticsParams,
+	): Promise<PullDiagnosticsResult>;
+	pullProjectActions(
+		params: PullProjectActionsParams,
+	): Promise<PullProjectActionsResult>;
+	documentSymbols(params: DocumentSymbolsParams): Promise<DocumentSymbolsResult>;
+	workspaceSymbols(
+		params: WorkspaceSymbolsParams,
+	): Promise<WorkspaceSymbolsResult>;
+	openFiles(params: OpenFilesParams): Promise<FilesResult>;
+	changeFiles(params: ChangeFilesParams): Promise<FilesResult>;
+	closeFiles(params: CloseFilesParams): Promise<FilesResult>;
+	pullFilesDiagnostics(
+		params: PullFilesDiagnosticsParams,
+	): Promise<PullFilesDiagnosticsResult>;
+	fixFiles(params: FixFilesParams): Promise<FixFilesResult>;
+	explainIgnoredPath(
+		params: ExplainIgnoredPathParams,
+	): Promise<ExplainIgnoredPathResult>;
+	enabledRules(params: EnabledRulesParams): Promise<EnabledRulesResult>;
+	ruleDocs(params: RuleDocsParams): Promise<RuleDocsResult>;
+	capabilities(params: FileCapabilitiesParams): Promise<FileCapabilitiesResult>;
+	metrics(params: MetricsParams): Promise<MetricsResult>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -1883,6 +2829,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		updateSettings(params) {
 			return transport.request("biome/update_settings", params);
 		},
+		registerNestedConfigurations(params) {
+			return transport.request("biome/register_nested_configurations", params);
+		},
 		projectFeatures(params) {
 			return transport.request("biome/project_features", params);
 		},
@@ -1931,6 +2880,63 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		prepareRename(params) {
+			return transport.request("biome/prepare_rename", params);
+		},
+		inlayHints(params) {
+			return transport.request("biome/inlay_hints", params);
+		},
+		searchPattern(params) {
+			return transport.request("biome/search_pattern", params);
+		},
+		indexFile(params) {
+			return transport.request("biome/index_file", params);
+		},
+		analyzeProject(params) {
+			return transport.request("biome/analyze_project", params);
+		},
+		pullProjectDiagnostics(params) {
+			return transport.request("biome/pull_project_diagnostics", params);
+		},
+		pullProjectActions(params) {
+			return transport.request("biome/pull_project_actions", params);
+		},
+		documentSymbols(params) {
+			return transport.request("biome/document_symbols", params);
+		},
+		workspaceSymbols(params) {
+			return transport.request("biome/workspace_symbols", params);
+		},
+		openFiles(params) {
+			return transport.request("biome/open_files", params);
+		},
+		changeFiles(params) {
+			return transport.request("biome/change_files", params);
+		},
+		closeFiles(params) {
+			return transport.request("biome/close_files", params);
+		},
+		pullFilesDiagnostics(params) {
+			return transport.request("biome/pull_files_diagnostics", params);
+		},
+		fixFiles(params) {
+			return transport.request("biome/fix_files", params);
+		},
+		explainIgnoredPath(params) {
+			return transport.request("biome/explain_ignored_path", params);
+		},
+		enabledRules(params) {
+			return transport.request("biome/enabled_rules", params);
+		},
+		ruleDocs(params) {
+			return transport.request("biome/rule_docs", params);
+		},
+		capabilities(params) {
+			return transport.request("biome/capabilities", params);
+		},
+		metrics(params) {
+			return transport.request("biome/metrics", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
@@ -171,6 +171,7 @@ export default defineConfig({
 					items: [
 						{ label: "CLI", link: "/reference/cli" },
 						{ label: "Configuration", link: "/reference/configuration" },
+						{ label: "JSON reporter", link: "/reference/json-reporter" },
 						{ label: "VSCode extension", link: "/reference/vscode" },
 					],
 				},
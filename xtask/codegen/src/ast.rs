@@ -2,6 +2,7 @@
 //! This is derived from rust-analyzer/xtask/codegen
 
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
 use std::str::FromStr;
 use std::vec;
 
@@ -9,11 +10,9 @@ use super::{
     kinds_src::{AstSrc, Field},
     to_lower_snake_case, Mode,
 };
-use crate::css_kinds_src::CSS_KINDS_SRC;
 use crate::generate_node_factory::generate_node_factory;
 use crate::generate_nodes_mut::generate_nodes_mut;
 use crate::generate_syntax_factory::generate_syntax_factory;
-use crate::json_kinds_src::JSON_KINDS_SRC;
 use crate::kinds_src::{AstListSeparatorConfiguration, AstListSrc, TokenKind};
 use crate::termcolorful::{println_string_with_fg_color, Color};
 use crate::ALL_LANGUAGE_KIND;
@@ -21,8 +20,8 @@ use crate::{
     generate_macros::generate_macros,
     generate_nodes::generate_nodes,
     generate_syntax_kinds::generate_syntax_kinds,
-    kinds_src::{AstEnumSrc, AstNodeSrc, JS_KINDS_SRC},
-    update, LanguageKind,
+    kinds_src::{AstEnumSrc, AstNodeSrc},
+    update, LanguageKind, LanguageSrc,
 };
 use std::fmt::Write;
 use ungrammar::{Grammar, Rule, Token};
@@ -69,6 +68,32 @@ pub(crate) fn load_ast(language: LanguageKind) -> AstSrc {
     }
 }
 
+/// Generates the syntax kinds, the AST nodes and the factories of a language
+/// that isn't part of Biome, from its `.ungram` grammar.
+///
+/// The files are written in the `generated` directories of the syntax crate and
+/// of the factory crate, e.g. `crates/my_syntax/src/generated`: `kind.rs`, `nodes.rs`,
+/// `nodes_mut.rs` and `macros.rs` for the syntax crate, `syntax_factory.rs` and
+/// `node_factory.rs` for the factory crate.
+pub fn generate_language_syntax(
+    language: &LanguageSrc,
+    syntax_generated_path: &Path,
+    factory_generated_path: &Path,
+    mode: &Mode,
+) -> Result<()> {
+    let grammar: Grammar = language.grammar.parse()?;
+    let mut ast = make_ast(&grammar);
+    check_unions(&ast.unions);
+    ast.sort();
+    write_syntax(
+        &ast,
+        language,
+        syntax_generated_path,
+        factory_generated_path,
+        mode,
+    )
+}
+
 pub(crate) fn generate_syntax(ast: AstSrc, mode: &Mode, language_kind: LanguageKind) -> Result<()> {
     let syntax_generated_path = project_root()
         .join("crates")
@@ -79,34 +104,44 @@ pub(crate) fn generate_syntax(ast: AstSrc, mode: &Mode, language_kind: LanguageK
         .join(language_kind.factory_crate_name())
         .join("src/generated");
 
-    let kind_src = match language_kind {
-        LanguageKind::Js => JS_KINDS_SRC,
-        LanguageKind::Css => CSS_KINDS_SRC,
-        LanguageKind::Json => JSON_KINDS_SRC,
-    };
+    write_syntax(
+        &ast,
+        &language_kind.language_src(),
+        &syntax_generated_path,
+        &factory_generated_path,
+        mode,
+    )
+}
 
+fn write_syntax(
+    ast: &AstSrc,
+    language: &LanguageSrc,
+    syntax_generated_path: &Path,
+    factory_generated_path: &Path,
+    mode: &Mode,
+) -> Result<()> {
     let ast_nodes_file = syntax_generated_path.join("nodes.rs");
-    let contents = generate_nodes(&ast, language_kind)?;
+    let contents = generate_nodes(ast, language)?;
     update(ast_nodes_file.as_path(), &contents, mode)?;
 
     let ast_nodes_mut_file = syntax_generated_path.join("nodes_mut.rs");
-    let contents = generate_nodes_mut(&ast, language_kind)?;
+    let contents = generate_nodes_mut(ast, language)?;
     update(ast_nodes_mut_file.as_path(), &contents, mode)?;
 
     let syntax_kinds_file = syntax_generated_path.join("kind.rs");
-    let contents = generate_syntax_kinds(kind_src, language_kind)?;
+    let contents = generate_syntax_kinds(language)?;
     update(syntax_kinds_file.as_path(), &contents, mode)?;
 
     let syntax_factory_file = factory_generated_path.join("syntax_factory.rs");
-    let contents = generate_syntax_factory(&ast, language_kind)?;
+    let contents = generate_syntax_factory(ast, language)?;
     update(syntax_factory_file.as_path(), &contents, mode)?;
 
     let node_factory_file = factory_generated_path.join("node_factory.rs");
-    let contents = generate_node_factory(&ast, language_kind)?;
+    let contents = generate_node_factory(ast, language)?;
     update(node_factory_file.as_path(), &contents, mode)?;
 
     let ast_macros_file = syntax_generated_path.join("macros.rs");
-    let contents = generate_macros(&ast, language_kind)?;
+    let contents = generate_macros(ast, language)?;
     update(ast_macros_file.as_path(), &contents, mode)?;
 
     Ok(())
@@ -164,22 +199,19 @@ fn check_unions(unions: &[AstEnumSrc]) {
 }
 
 pub(crate) fn load_js_ast() -> AstSrc {
-    let grammar_src = include_str!("../js.ungram");
-    let grammar: Grammar = grammar_src.parse().unwrap();
+    let grammar: Grammar = LanguageKind::Js.language_src().grammar.parse().unwrap();
     let ast: AstSrc = make_ast(&grammar);
     check_unions(&ast.unions);
     ast
 }
 
 pub(crate) fn load_css_ast() -> AstSrc {
-    let grammar_src = include_str!("../css.ungram");
-    let grammar: Grammar = grammar_src.parse().unwrap();
+    let grammar: Grammar = LanguageKind::Css.language_src().grammar.parse().unwrap();
     make_ast(&grammar)
 }
 
 pub(crate) fn load_json_ast() -> AstSrc {
-    let grammar_src = include_str!("../json.ungram");
-    let grammar: Grammar = grammar_src.parse().unwrap();
+    let grammar: Grammar = LanguageKind::Json.language_src().grammar.parse().unwrap();
     make_ast(&grammar)
 }
 
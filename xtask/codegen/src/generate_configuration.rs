@@ -76,10 +76,12 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     let mut group_as_disabled_rules = Vec::new();
     let mut group_match_code = Vec::new();
     let mut group_get_severity = Vec::new();
+    let mut group_get_rule_configuration = Vec::new();
     let mut group_name_list = vec!["recommended", "all"];
     let mut rule_visitor_call = Vec::new();
     let mut visitor_rule_list = Vec::new();
     let mut push_rule_list = Vec::new();
+    let mut group_rules_list = Vec::new();
     for (group, rules) in groups {
         group_name_list.push(group);
         let property_group_name = Ident::new(&to_lower_snake_case(group), Span::call_site());
@@ -89,11 +91,21 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         struct_groups.push(generate_struct(group, &rules));
         visitor_rule_list.push(generate_visitor(group, &rules));
         push_rule_list.push(generate_push_to_analyzer_rules(group));
-        line_groups.push(quote! {
-            #[serde(skip_serializing_if = "Option::is_none")]
-            #[bpaf(external, hide, optional)]
-            pub #property_group_name: Option<#group_struct_name>
-        });
+        if group == "nursery" {
+            // The nursery group also accepts the name of a preset
+            line_groups.push(quote! {
+                #[serde(default, deserialize_with = "deserialize_nursery", skip_serializing_if = "Option::is_none")]
+                #[cfg_attr(feature = "schema", schemars(with = "Option<crate::configuration::linter::NurseryConfiguration>"))]
+                #[bpaf(external, hide, optional)]
+                pub #property_group_name: Option<#group_struct_name>
+            });
+        } else {
+            line_groups.push(quote! {
+                #[serde(skip_serializing_if = "Option::is_none")]
+                #[bpaf(external, hide, optional)]
+                pub #property_group_name: Option<#group_struct_name>
+            });
+        }
         default_for_groups.push(quote! {
             #property_group_name: None
         });
@@ -113,7 +125,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
 
         group_as_default_rules.push(quote! {
             if let Some(group) = self.#property_group_name.as_ref() {
-                group.collect_preset_rules(self.is_recommended(), &mut enabled_rules, &mut disabled_rules);
+                group.collect_preset_rules(self.is_all(), self.is_recommended(), &mut enabled_rules, &mut disabled_rules);
                 enabled_rules.extend(&group.get_enabled_rules());
                 disabled_rules.extend(&group.get_disabled_rules());
             } else if self.is_all() {
@@ -135,8 +147,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
             #group => self
                 .#property_group_name
                 .as_ref()
-                .and_then(|#property_group_name| #property_group_name.get_rule_configuration(rule_name))
-                .map(|rule_setting| rule_setting.into())
+                .and_then(|#property_group_name| #property_group_name.get_rule_severity(rule_name))
                 .unwrap_or_else(|| {
                     if #group_struct_name::is_recommended_rule(rule_name) {
                         Severity::Error
@@ -145,10 +156,20 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                     }
                 })
         });
+        group_get_rule_configuration.push(quote! {
+            #group => self
+                .#property_group_name
+                .as_ref()
+                .and_then(|#property_group_name| #property_group_name.get_rule_configuration(rule_name))
+        });
         group_match_code.push(quote! {
            #group => #group_struct_name::has_rule(rule_name).then_some((category, rule_name))
         });
 
+        group_rules_list.push(quote! {
+            (#group, &#group_struct_name::GROUP_RULES)
+        });
+
         rule_visitor_call.push(quote! {
             #group_name_string_literal => {
                 result.#property_group_name = Deserializable::deserialize(&value, &key_text, diagnostics);
@@ -161,6 +182,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         #[cfg(feature = "schema")]
         use schemars::JsonSchema;
         use crate::{MergeWith, RuleConfiguration};
+        use crate::configuration::linter::{deserialize_nursery, RuleGroupLevel};
         use biome_analyze::RuleFilter;
         use indexmap::IndexSet;
         use bpaf::Bpaf;
@@ -219,6 +241,10 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
 
 
         impl Rules {
+            /// The groups of rules, with the names of their rules
+            pub(crate) const GROUPS: &'static [(&'static str, &'static [&'static str])] = &[
+                #( #group_rules_list ),*
+            ];
 
             /// Checks if the code coming from [biome_diagnostics::Diagnostic] corresponds to a rule.
             /// Usually the code is built like {category}/{rule_name}
@@ -262,6 +288,15 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                 }
             }
 
+            /// Returns the configuration of the rule `rule_name` of the group `group`, if the
+            /// configuration sets it
+            pub(crate) fn get_rule_configuration(&self, group: &str, rule_name: &str) -> Option<&RuleConfiguration> {
+                match group {
+                    #( #group_get_rule_configuration ),*,
+                    _ => None
+                }
+            }
+
             pub(crate) const fn is_recommended(&self) -> bool {
                 // It is only considered _not_ recommended when
                 // the configuration is `"recommended": false`.
@@ -305,6 +340,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         use crate::configuration::linter::*;
         use crate::Rules;
         use biome_console::markup;
+        use crate::configuration::parse::json::unknown_rules::{unknown_group_diagnostic, unknown_rule_diagnostic};
         use biome_deserialize::{Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text, VisitableType};
         use biome_rowan::TextRange;
 
@@ -341,7 +377,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                                 }
                                 #( #rule_visitor_call ),*,
                                 unknown_key => {
-                                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                                    diagnostics.push(unknown_group_diagnostic(
                                         unknown_key,
                                         key.range(),
                                         &[#( #group_name_list ),*],
@@ -530,15 +566,26 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
     let group_struct_name = Ident::new(&group.to_capitalized(), Span::call_site());
 
     let number_of_recommended_rules = Literal::u8_unsuffixed(number_of_recommended_rules);
-    let (group_recommended, parent_parameter) = if group == "nursery" {
+    let (group_all, group_recommended, parent_parameters, inherits_all) = if group == "nursery" {
+        // `rules.all` doesn't enable the nursery rules
         (
+            quote! { self.is_all() },
             quote! { self.is_recommended() },
-            quote! { _parent_is_recommended: bool, },
+            quote! { _parent_is_all: bool, _parent_is_recommended: bool, },
+            quote! {},
         )
     } else {
         (
+            quote! { self.is_all() || (parent_is_all && self.inherits_all()) },
             quote! { parent_is_recommended || self.is_recommended() },
-            quote! { parent_is_recommended: bool, },
+            quote! { parent_is_all: bool, parent_is_recommended: bool, },
+            quote! {
+                /// Whether the group enables all its rules when `rules.all` is `true`: it doesn't
+                /// set `all` nor `recommended` itself
+                pub(crate) fn inherits_all(&self) -> bool {
+                    self.all.is_none() && self.recommended.is_none()
+                }
+            },
         )
     };
     quote! {
@@ -557,11 +604,25 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
             #[bpaf(hide)]
             pub all: Option<bool>,
 
+            /// The severity of the rules of this group that don't set their own level
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[bpaf(hide)]
+            pub level: Option<RuleGroupLevel>,
+
             #( #schema_lines_rules ),*
         }
 
         impl MergeWith<#group_struct_name> for #group_struct_name {
             fn merge_with(&mut self, other: #group_struct_name) {
+                if let Some(recommended) = other.recommended {
+                    self.recommended = Some(recommended);
+                }
+                if let Some(all) = other.all {
+                    self.all = Some(all);
+                }
+                if let Some(level) = other.level {
+                    self.level = Some(level);
+                }
                 #( #merge_with_lines_rules )*
             }
 
@@ -609,6 +670,8 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
                 matches!(self.all, Some(false))
             }
 
+            #inherits_all
+
             pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
                let mut index_set = IndexSet::new();
                #( #rule_enabled_check_line )*
@@ -642,11 +705,11 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
             /// Select preset rules
             pub(crate) fn collect_preset_rules(
                 &self,
-                #parent_parameter
+                #parent_parameters
                 enabled_rules: &mut IndexSet<RuleFilter>,
                 disabled_rules: &mut IndexSet<RuleFilter>,
             ) {
-                if self.is_all() {
+                if #group_all {
                     enabled_rules.extend(Self::all_rules_as_filters());
                 } else if #group_recommended {
                     enabled_rules.extend(Self::recommended_rules_as_filters());
@@ -664,13 +727,26 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
                     _ => None
                 }
             }
+
+            /// The severity of a rule of this group: its own level, or the level of the group
+            pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+                self.get_rule_configuration(rule_name)
+                    // A rule turned off can still run when it's selected with `--rule`
+                    .filter(|rule_setting| rule_setting.is_enabled())
+                    .map(Severity::from)
+                    .or_else(|| self.level.map(Severity::from))
+            }
         }
     }
 }
 
 fn generate_visitor(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) -> TokenStream {
     let group_struct_name = Ident::new(&group.to_capitalized(), Span::call_site());
-    let mut group_rules = vec![Literal::string("recommended"), Literal::string("all")];
+    let mut group_rules = vec![
+        Literal::string("recommended"),
+        Literal::string("all"),
+        Literal::string("level"),
+    ];
     let mut visitor_rule_line = Vec::new();
 
     for rule_name in rules.keys() {
@@ -683,6 +759,26 @@ fn generate_visitor(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) -
         });
     }
 
+    let (expected_type, visit_str) = if group == "nursery" {
+        (
+            quote! { VisitableType::MAP.union(VisitableType::STR) },
+            quote! {
+                fn visit_str(
+                    self,
+                    value: Text,
+                    range: TextRange,
+                    _name: &str,
+                    diagnostics: &mut Vec<DeserializationDiagnostic>,
+                ) -> Option<Self::Output> {
+                    NurseryPreset::deserialize_from_str(value, range, diagnostics)
+                        .map(|preset| NurseryConfiguration::Preset(preset).into())
+                }
+            },
+        )
+    } else {
+        (quote! { VisitableType::MAP }, quote! {})
+    };
+
     quote! {
         impl Deserializable for #group_struct_name {
             fn deserialize(
@@ -693,7 +789,8 @@ fn generate_visitor(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) -
                 struct Visitor;
                 impl DeserializationVisitor for Visitor  {
                     type Output =#group_struct_name;
-                    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+                    const EXPECTED_TYPE: VisitableType = #expected_type;
+                    #visit_str
                     fn visit_map(
                         self,
                         members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
@@ -715,9 +812,13 @@ fn generate_visitor(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) -
                                 "all" => {
                                     result.all = Deserializable::deserialize(&value, &key_text, diagnostics);
                                 }
+                                "level" => {
+                                    result.level = Deserializable::deserialize(&value, &key_text, diagnostics);
+                                }
                                 #( #visitor_rule_line ),*,
                                 unknown_key => {
-                                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                                    diagnostics.push(unknown_rule_diagnostic(
+                                        #group,
                                         unknown_key,
                                         key.range(),
                                         &[#( #group_rules ),*],
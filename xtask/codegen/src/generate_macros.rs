@@ -1,10 +1,10 @@
 use super::kinds_src::AstSrc;
-use crate::{to_upper_snake_case, LanguageKind, Result};
+use crate::{to_upper_snake_case, LanguageSrc, Result};
 use quote::{format_ident, quote};
 
-pub fn generate_macros(ast: &AstSrc, language_kind: LanguageKind) -> Result<String> {
-    let syntax_kind = language_kind.syntax_kind();
-    let syntax_node = language_kind.syntax_node();
+pub fn generate_macros(ast: &AstSrc, language: &LanguageSrc) -> Result<String> {
+    let syntax_kind = language.syntax_kind();
+    let syntax_node = language.syntax_node();
 
     let match_arms: Vec<_> = ast
         .nodes
@@ -1,15 +1,15 @@
 use super::kinds_src::AstSrc;
 use crate::to_lower_snake_case;
-use crate::{kinds_src::Field, to_upper_snake_case, LanguageKind};
+use crate::{kinds_src::Field, to_upper_snake_case, LanguageSrc};
 use quote::{format_ident, quote};
 use xtask::Result;
 
-pub fn generate_node_factory(ast: &AstSrc, language_kind: LanguageKind) -> Result<String> {
-    let syntax_crate = language_kind.syntax_crate_ident();
-    let syntax_kind = language_kind.syntax_kind();
-    let syntax_token = language_kind.syntax_token();
-    let syntax_node = language_kind.syntax_node();
-    let syntax_element = language_kind.syntax_element();
+pub fn generate_node_factory(ast: &AstSrc, language: &LanguageSrc) -> Result<String> {
+    let syntax_crate = language.syntax_crate_ident();
+    let syntax_kind = language.syntax_kind();
+    let syntax_token = language.syntax_token();
+    let syntax_node = language.syntax_node();
+    let syntax_element = language.syntax_element();
 
     let nodes =
         ast.nodes.iter().map(|node| {
@@ -24,7 +24,7 @@ pub fn generate_node_factory(ast: &AstSrc, language_kind: LanguageKind) -> Resul
                 let (args, slots): (Vec<_>, Vec<_>) = required
                     .into_iter()
                     .map(|field| {
-                        let name = field.method_name(language_kind);
+                        let name = field.method_name(language);
                         let type_name = field.ty();
 
                         let arg = quote! { #name: #type_name };
@@ -57,7 +57,7 @@ pub fn generate_node_factory(ast: &AstSrc, language_kind: LanguageKind) -> Resul
             let (required_args, required_fields): (Vec<_>, Vec<_>) = required
                 .into_iter()
                 .map(|field| {
-                    let name = field.method_name(language_kind);
+                    let name = field.method_name(language);
                     let type_name = field.ty();
 
                     let arg = quote! { #name: #type_name };
@@ -70,7 +70,7 @@ pub fn generate_node_factory(ast: &AstSrc, language_kind: LanguageKind) -> Resul
             let (optional_builder, optional_methods): (Vec<_>, Vec<_>) = optional
                 .into_iter()
                 .map(|field| {
-                    let name = field.method_name(language_kind);
+                    let name = field.method_name(language);
                     let method_name = format_ident!("with_{}", name);
                     let type_name = field.ty();
 
@@ -95,7 +95,7 @@ pub fn generate_node_factory(ast: &AstSrc, language_kind: LanguageKind) -> Resul
                 .fields
                 .iter()
                 .map(|field| {
-                    let name = field.method_name(language_kind);
+                    let name = field.method_name(language);
                     match field {
                         Field::Token { optional, .. } => if *optional {
                             quote! { self.#name.map(|token| SyntaxElement::Token(token)) }
@@ -1,13 +1,11 @@
-use crate::css_kinds_src::CSS_KINDS_SRC;
-use crate::json_kinds_src::JSON_KINDS_SRC;
-use crate::kinds_src::{AstSrc, Field, TokenKind, JS_KINDS_SRC};
-use crate::{to_lower_snake_case, to_upper_snake_case, LanguageKind};
+use crate::kinds_src::{AstSrc, Field, TokenKind};
+use crate::{to_lower_snake_case, to_upper_snake_case, LanguageSrc};
 use proc_macro2::Literal;
 use quote::{format_ident, quote};
 use std::collections::HashMap;
 use xtask::Result;
 
-pub fn generate_nodes(ast: &AstSrc, language_kind: LanguageKind) -> Result<String> {
+pub fn generate_nodes(ast: &AstSrc, language: &LanguageSrc) -> Result<String> {
     let (node_defs, node_boilerplate_impls): (Vec<_>, Vec<_>) = ast
         .nodes
         .iter()
@@ -26,7 +24,7 @@ pub fn generate_nodes(ast: &AstSrc, language_kind: LanguageKind) -> Result<Strin
                         let method_name = if many {
                             format_ident!("{}", name)
                         } else {
-                            field.method_name(language_kind)
+                            field.method_name(language)
                         };
 
                         let is_optional = field.is_optional();
@@ -49,7 +47,7 @@ pub fn generate_nodes(ast: &AstSrc, language_kind: LanguageKind) -> Result<Strin
                         let is_list = ast.is_list(ty);
                         let ty = format_ident!("{}", &ty);
 
-                        let method_name = field.method_name(language_kind);
+                        let method_name = field.method_name(language);
                         if is_list {
                             quote! {
                                 pub fn #method_name(&self) -> #ty {
@@ -79,7 +77,7 @@ pub fn generate_nodes(ast: &AstSrc, language_kind: LanguageKind) -> Result<Strin
                         kind: TokenKind::Many(_),
                         ..
                     } => format_ident!("{}", name),
-                    _ => field.method_name(language_kind),
+                    _ => field.method_name(language),
                 };
 
                 let is_list = match field {
@@ -118,7 +116,7 @@ pub fn generate_nodes(ast: &AstSrc, language_kind: LanguageKind) -> Result<Strin
                         let method_name = if many {
                             format_ident!("{}", name)
                         } else {
-                            field.method_name(language_kind)
+                            field.method_name(language)
                         };
 
                         let is_optional = field.is_optional();
@@ -135,7 +133,7 @@ pub fn generate_nodes(ast: &AstSrc, language_kind: LanguageKind) -> Result<Strin
                         let is_list = ast.is_list(ty);
                         let ty = format_ident!("{}", &ty);
 
-                        let method_name = field.method_name(language_kind);
+                        let method_name = field.method_name(language);
                         let field = if is_list {
                             quote! { #method_name: #ty }
                         } else if *optional {
@@ -783,13 +781,13 @@ pub fn generate_nodes(ast: &AstSrc, language_kind: LanguageKind) -> Result<Strin
         }
     });
 
-    let syntax_kind = language_kind.syntax_kind();
-    let syntax_node = language_kind.syntax_node();
-    let syntax_element = language_kind.syntax_element();
-    let syntax_element_children = language_kind.syntax_element_children();
-    let syntax_list = language_kind.syntax_list();
-    let syntax_token = language_kind.syntax_token();
-    let language = language_kind.language();
+    let syntax_kind = language.syntax_kind();
+    let syntax_node = language.syntax_node();
+    let syntax_element = language.syntax_element();
+    let syntax_element_children = language.syntax_element_children();
+    let syntax_list = language.syntax_list();
+    let syntax_token = language.syntax_token();
+    let language = language.language();
 
     let serde_import = quote! {
         #[cfg(feature = "serde")]
@@ -859,17 +857,10 @@ pub fn generate_nodes(ast: &AstSrc, language_kind: LanguageKind) -> Result<Strin
     Ok(pretty)
 }
 
-pub(crate) fn token_kind_to_code(
-    name: &str,
-    language_kind: LanguageKind,
-) -> proc_macro2::TokenStream {
+pub(crate) fn token_kind_to_code(name: &str, language: &LanguageSrc) -> proc_macro2::TokenStream {
     let kind_variant_name = to_upper_snake_case(name);
 
-    let kind_source = match language_kind {
-        LanguageKind::Js => JS_KINDS_SRC,
-        LanguageKind::Css => CSS_KINDS_SRC,
-        LanguageKind::Json => JSON_KINDS_SRC,
-    };
+    let kind_source = language.kinds;
     if kind_source.literals.contains(&kind_variant_name.as_str())
         || kind_source.tokens.contains(&kind_variant_name.as_str())
     {
@@ -1,9 +1,9 @@
 use crate::kinds_src::{AstSrc, Field};
-use crate::LanguageKind;
+use crate::LanguageSrc;
 use quote::{format_ident, quote};
 use xtask::Result;
 
-pub fn generate_nodes_mut(ast: &AstSrc, language_kind: LanguageKind) -> Result<String> {
+pub fn generate_nodes_mut(ast: &AstSrc, language: &LanguageSrc) -> Result<String> {
     let node_boilerplate_impls: Vec<_> = ast
         .nodes
         .iter()
@@ -15,7 +15,7 @@ pub fn generate_nodes_mut(ast: &AstSrc, language_kind: LanguageKind) -> Result<S
                 .iter()
                 .enumerate()
                 .map(|(index, field)| {
-                    let method_name = format_ident!("with_{}", field.method_name(language_kind));
+                    let method_name = format_ident!("with_{}", field.method_name(language));
                     let type_name = field.ty();
 
                     let element = match field {
@@ -54,7 +54,7 @@ pub fn generate_nodes_mut(ast: &AstSrc, language_kind: LanguageKind) -> Result<S
         })
         .collect();
 
-    let syntax_token = language_kind.syntax_token();
+    let syntax_token = language.syntax_token();
 
     let ast = quote! {
         use std::iter::once;
@@ -1,28 +1,14 @@
 use super::kinds_src::AstSrc;
 use crate::generate_nodes::token_kind_to_code;
 use crate::kinds_src::TokenKind;
-use crate::{kinds_src::Field, to_upper_snake_case, LanguageKind};
+use crate::{kinds_src::Field, to_upper_snake_case, LanguageSrc};
 use quote::{format_ident, quote};
 use xtask::Result;
 
-pub fn generate_syntax_factory(ast: &AstSrc, language_kind: LanguageKind) -> Result<String> {
-    let (syntax_crate, syntax_kind, factory_kind) = match language_kind {
-        LanguageKind::Js => (
-            quote! { biome_js_syntax },
-            quote! { JsSyntaxKind },
-            quote! { JsSyntaxFactory },
-        ),
-        LanguageKind::Css => (
-            quote! { biome_css_syntax },
-            quote! { CssSyntaxKind },
-            quote! { CssSyntaxFactory },
-        ),
-        LanguageKind::Json => (
-            quote! { biome_json_syntax },
-            quote! { JsonSyntaxKind },
-            quote! { JsonSyntaxFactory },
-        ),
-    };
+pub fn generate_syntax_factory(ast: &AstSrc, language: &LanguageSrc) -> Result<String> {
+    let syntax_crate = language.syntax_crate_ident();
+    let syntax_kind = language.syntax_kind();
+    let factory_kind = language.syntax_factory();
     let normal_node_arms = ast.nodes.iter().map(|node| {
         let kind = format_ident!("{}", to_upper_snake_case(&node.name));
         let expected_len = node.fields.len();
@@ -38,13 +24,13 @@ pub fn generate_syntax_factory(ast: &AstSrc, language_kind: LanguageKind) -> Res
                 }
                 Field::Token { kind, .. } => match kind {
                     TokenKind::Single(expected) => {
-                        let expected_kind = token_kind_to_code(expected, language_kind);
+                        let expected_kind = token_kind_to_code(expected, language);
                         quote! { element.kind() == #expected_kind}
                     }
                     TokenKind::Many(expected) => {
                         let expected_kinds = expected
                             .iter()
-                            .map(|kind| token_kind_to_code(kind, language_kind));
+                            .map(|kind| token_kind_to_code(kind, language));
                         quote! {
                             matches!(element.kind(), #(#expected_kinds)|*)
                         }
@@ -89,7 +75,7 @@ pub fn generate_syntax_factory(ast: &AstSrc, language_kind: LanguageKind) -> Res
         let kind = format_ident!("{}", to_upper_snake_case(name));
         if let Some(separator) = &data.separator {
             let allow_trailing = separator.allow_trailing;
-            let separator_kind = token_kind_to_code(&separator.separator_token, language_kind);
+            let separator_kind = token_kind_to_code(&separator.separator_token, language);
             quote! {
                 #kind => Self::make_separated_list_syntax(kind, children, #element_type::can_cast, #separator_kind, #allow_trailing)
             }
@@ -1,11 +1,10 @@
-use crate::{to_upper_snake_case, LanguageKind, Result};
+use crate::{to_upper_snake_case, LanguageSrc, Result};
 use proc_macro2::{Literal, Punct, Spacing};
 use quote::{format_ident, quote};
 
-use super::kinds_src::KindsSrc;
-
-pub fn generate_syntax_kinds(grammar: KindsSrc, language_kind: LanguageKind) -> Result<String> {
-    let syntax_kind = language_kind.syntax_kind();
+pub fn generate_syntax_kinds(language: &LanguageSrc) -> Result<String> {
+    let grammar = language.kinds;
+    let syntax_kind = language.syntax_kind();
     let punctuation_values = grammar.punct.iter().map(|(token, _name)| {
         // These tokens, when parsed to proc_macro2::TokenStream, generates a stream of bytes
         // that can't be recognized by [quote].
@@ -81,47 +80,10 @@ pub fn generate_syntax_kinds(grammar: KindsSrc, language_kind: LanguageKind) ->
         })
         .collect::<Vec<_>>();
 
-    let syntax_kind_impl = match language_kind {
-        LanguageKind::Js => {
-            quote! {
-                pub const fn to_string(&self) -> Option<&'static str> {
-                    let tok = match self {
-                        #(#punctuation => #punctuation_strings,)*
-                        #(#all_keywords => #all_keyword_strings,)*
-                        JS_STRING_LITERAL => "string literal",
-                        _ => return None,
-                    };
-                    Some(tok)
-                }
-            }
-        }
-        LanguageKind::Css => {
-            quote! {
-                pub const fn to_string(&self) -> Option<&'static str> {
-                    let tok = match self {
-                        #(#punctuation => #punctuation_strings,)*
-                        #(#all_keywords => #all_keyword_strings,)*
-                        CSS_STRING_LITERAL => "string literal",
-                        _ => return None,
-                    };
-                    Some(tok)
-                }
-            }
-        }
-        LanguageKind::Json => {
-            quote! {
-                pub const fn to_string(&self) -> Option<&'static str> {
-                    let tok = match self {
-                        #(#punctuation => #punctuation_strings,)*
-                        #(#all_keywords => #all_keyword_strings,)*
-                        JSON_STRING_LITERAL => "string literal",
-                        _ => return None,
-                    };
-                    Some(tok)
-                }
-            }
-        }
-    };
+    let string_literal = language.string_literal_kind();
+    let string_literal_string = literals
+        .contains(&string_literal)
+        .then(|| quote! { #string_literal => "string literal", });
 
     let ast = quote! {
         #![allow(clippy::all)]
@@ -181,7 +143,15 @@ pub fn generate_syntax_kinds(grammar: KindsSrc, language_kind: LanguageKind) ->
                 Some(kw)
             }
 
-            #syntax_kind_impl
+            pub const fn to_string(&self) -> Option<&'static str> {
+                let tok = match self {
+                    #(#punctuation => #punctuation_strings,)*
+                    #(#all_keywords => #all_keyword_strings,)*
+                    #string_literal_string
+                    _ => return None,
+                };
+                Some(tok)
+            }
 
         }
 
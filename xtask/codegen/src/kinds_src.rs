@@ -1,16 +1,16 @@
 //! Definitions for the ECMAScript AST used for codegen
 //! Based on the rust analyzer parser and ast definitions
 
-use crate::css_kinds_src::CSS_KINDS_SRC;
-use crate::json_kinds_src::JSON_KINDS_SRC;
-use crate::LanguageKind;
+use crate::LanguageSrc;
 use quote::format_ident;
 use std::collections::BTreeMap;
 
-const LANGUAGE_PREFIXES: [&str; 6] = ["js_", "ts_", "jsx_", "tsx_", "css_", "json_"];
-
+/// The syntax kinds of a language
+#[derive(Clone, Copy)]
 pub struct KindsSrc<'a> {
+    /// The punctuation tokens and the names of their kinds, e.g. `(";", "SEMICOLON")`
     pub punct: &'a [(&'a str, &'a str)],
+    /// The keywords, whose kinds are named after them, e.g. `FUNCTION_KW` for `function`
     pub keywords: &'a [&'a str],
     pub literals: &'a [&'a str],
     pub tokens: &'a [&'a str],
@@ -618,10 +618,16 @@ pub struct AstEnumSrc {
 }
 
 impl Field {
-    pub fn method_name(&self, language_kind: LanguageKind) -> proc_macro2::Ident {
+    pub fn method_name(&self, language: &LanguageSrc) -> proc_macro2::Ident {
         match self {
             Field::Token { name, .. } => {
-                let name = match (name.as_str(), language_kind) {
+                let token_method = language
+                    .token_methods
+                    .iter()
+                    .find(|(token, _)| *token == name.as_str())
+                    .map(|(_, method)| *method);
+                let name = match (name.as_str(), token_method) {
+                    (_, Some(method)) => method,
                     (";", _) => "semicolon",
                     ("'{'", _) => "l_curly",
                     ("'}'", _) => "r_curly",
@@ -656,10 +662,6 @@ impl Field {
                     (">>>=", _) => "unsigned_right_shift_assign",
                     ("~", _) => "bitwise_not",
                     ("&=", _) => "bitwise_and_assign",
-                    ("|=", LanguageKind::Js) => "bitwise_or_assign",
-                    ("|=", LanguageKind::Css) => "exactly_or_hyphen",
-                    ("^=", LanguageKind::Js) => "bitwise_xor_assign",
-                    ("^=", LanguageKind::Css) => "prefix",
                     ("&&=", _) => "bitwise_logical_and_assign",
                     ("||=", _) => "bitwise_logical_or_assign",
                     ("??=", _) => "bitwise_nullish_coalescing_assign",
@@ -688,15 +690,9 @@ impl Field {
                     _ => name,
                 };
 
-                let kind_source = match language_kind {
-                    LanguageKind::Js => JS_KINDS_SRC,
-                    LanguageKind::Css => CSS_KINDS_SRC,
-                    LanguageKind::Json => JSON_KINDS_SRC,
-                };
-
                 // we need to replace "-" with "_" for the keywords
                 // e.g. we have `color-profile` in css but it's an invalid ident in rust code
-                if kind_source.keywords.contains(&name) {
+                if language.kinds.keywords.contains(&name) {
                     format_ident!("{}_token", name.replace('-', "_"))
                 } else {
                     format_ident!("{}_token", name)
@@ -704,7 +700,7 @@ impl Field {
             }
             Field::Node { name, .. } => {
                 let (prefix, tail) = name.split_once('_').unwrap_or(("", name));
-                let final_name = if LANGUAGE_PREFIXES.contains(&prefix) {
+                let final_name = if language.node_prefixes.contains(&prefix) {
                     tail
                 } else {
                     name.as_str()
@@ -0,0 +1,122 @@
+//! Describes the languages whose syntax is generated from an `.ungram` grammar.
+//!
+//! The languages of Biome are listed by [LanguageKind], and a crate outside of
+//! the workspace can describe its own language with a [LanguageSrc] and pass it
+//! to [generate_language_syntax](crate::generate_language_syntax).
+
+use crate::css_kinds_src::CSS_KINDS_SRC;
+use crate::json_kinds_src::JSON_KINDS_SRC;
+use crate::kinds_src::{KindsSrc, JS_KINDS_SRC};
+use crate::{to_upper_snake_case, LanguageKind};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+
+/// The description of a language, used to generate its syntax kinds, its AST
+/// nodes and its factories.
+///
+/// The syntax crate must define the types `{prefix}Language`, `{prefix}SyntaxNode`,
+/// `{prefix}SyntaxToken`, `{prefix}SyntaxElement`, `{prefix}SyntaxElementChildren`
+/// and `{prefix}SyntaxList` on top of `biome_rowan`, and the factory crate must
+/// define the type `{prefix}SyntaxFactory`.
+#[derive(Clone, Copy)]
+pub struct LanguageSrc<'a> {
+    /// The prefix of the types of the syntax crate, e.g. `Js` for `JsSyntaxKind`
+    pub prefix: &'a str,
+    /// The name of the crate that contains the syntax kinds and the AST nodes
+    pub syntax_crate: &'a str,
+    /// The name of the crate that contains the syntax and the node factories
+    pub factory_crate: &'a str,
+    /// The source of the `.ungram` grammar
+    pub grammar: &'a str,
+    /// The tokens and the nodes of the language
+    pub kinds: KindsSrc<'a>,
+    /// The prefixes removed from the names of the node types to name the methods
+    /// that return them, e.g. `js` to name `expression` the method that returns a `JsExpression`
+    pub node_prefixes: &'a [&'a str],
+    /// The names of the methods that return a token, when they differ from the
+    /// default name of the token, e.g. `("|=", "exactly_or_hyphen")`
+    pub token_methods: &'a [(&'a str, &'a str)],
+}
+
+impl LanguageSrc<'_> {
+    pub(crate) fn syntax_crate_ident(&self) -> Ident {
+        Ident::new(self.syntax_crate, Span::call_site())
+    }
+
+    pub(crate) fn syntax_kind(&self) -> TokenStream {
+        self.prefixed("SyntaxKind")
+    }
+
+    pub(crate) fn syntax_node(&self) -> TokenStream {
+        self.prefixed("SyntaxNode")
+    }
+
+    pub(crate) fn syntax_element(&self) -> TokenStream {
+        self.prefixed("SyntaxElement")
+    }
+
+    pub(crate) fn syntax_token(&self) -> TokenStream {
+        self.prefixed("SyntaxToken")
+    }
+
+    pub(crate) fn syntax_element_children(&self) -> TokenStream {
+        self.prefixed("SyntaxElementChildren")
+    }
+
+    pub(crate) fn syntax_list(&self) -> TokenStream {
+        self.prefixed("SyntaxList")
+    }
+
+    pub(crate) fn syntax_factory(&self) -> TokenStream {
+        self.prefixed("SyntaxFactory")
+    }
+
+    pub(crate) fn language(&self) -> TokenStream {
+        self.prefixed("Language")
+    }
+
+    /// The kind of the string literals, e.g. `JS_STRING_LITERAL`
+    pub(crate) fn string_literal_kind(&self) -> Ident {
+        format_ident!("{}_STRING_LITERAL", to_upper_snake_case(self.prefix))
+    }
+
+    fn prefixed(&self, name: &str) -> TokenStream {
+        let ident = format_ident!("{}{}", self.prefix, name);
+        quote! { #ident }
+    }
+}
+
+impl LanguageKind {
+    /// Returns the description of the language
+    pub fn language_src(&self) -> LanguageSrc<'static> {
+        match self {
+            LanguageKind::Js => LanguageSrc {
+                prefix: "Js",
+                syntax_crate: "biome_js_syntax",
+                factory_crate: "biome_js_factory",
+                grammar: include_str!("../js.ungram"),
+                kinds: JS_KINDS_SRC,
+                node_prefixes: &["js", "ts", "jsx", "tsx"],
+                token_methods: &[("|=", "bitwise_or_assign"), ("^=", "bitwise_xor_assign")],
+            },
+            LanguageKind::Css => LanguageSrc {
+                prefix: "Css",
+                syntax_crate: "biome_css_syntax",
+                factory_crate: "biome_css_factory",
+                grammar: include_str!("../css.ungram"),
+                kinds: CSS_KINDS_SRC,
+                node_prefixes: &["css"],
+                token_methods: &[("|=", "exactly_or_hyphen"), ("^=", "prefix")],
+            },
+            LanguageKind::Json => LanguageSrc {
+                prefix: "Json",
+                syntax_crate: "biome_json_syntax",
+                factory_crate: "biome_json_factory",
+                grammar: include_str!("../json.ungram"),
+                kinds: JSON_KINDS_SRC,
+                node_prefixes: &["json"],
+                token_methods: &[],
+            },
+        }
+    }
+}
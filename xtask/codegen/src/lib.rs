@@ -13,21 +13,23 @@ mod generate_syntax_factory;
 mod generate_syntax_kinds;
 mod json_kinds_src;
 mod kinds_src;
+mod language_src;
 mod parser_tests;
 pub mod promote_rule;
 mod termcolorful;
 mod unicode;
 
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use proc_macro2::{Ident, Span};
 use std::path::Path;
 use std::str::FromStr;
 
 use xtask::{glue::fs2, Mode, Result};
 
-pub use self::ast::generate_ast;
+pub use self::ast::{generate_ast, generate_language_syntax};
 pub use self::formatter::generate_formatters;
 pub use self::generate_analyzer::generate_analyzer;
+pub use self::kinds_src::KindsSrc;
+pub use self::language_src::LanguageSrc;
 pub use self::parser_tests::generate_parser_tests;
 pub use self::unicode::generate_tables;
 
@@ -77,62 +79,6 @@ impl LanguageKind {
         Ident::new(self.syntax_crate_name(), Span::call_site())
     }
 
-    pub(crate) fn syntax_kind(&self) -> TokenStream {
-        match self {
-            LanguageKind::Js => quote! { JsSyntaxKind },
-            LanguageKind::Css => quote! { CssSyntaxKind },
-            LanguageKind::Json => quote! { JsonSyntaxKind },
-        }
-    }
-
-    pub(crate) fn syntax_node(&self) -> TokenStream {
-        match self {
-            LanguageKind::Js => quote! { JsSyntaxNode },
-            LanguageKind::Css => quote! { CssSyntaxNode },
-            LanguageKind::Json => quote! { JsonSyntaxNode },
-        }
-    }
-
-    pub(crate) fn syntax_element(&self) -> TokenStream {
-        match self {
-            LanguageKind::Js => quote! { JsSyntaxElement },
-            LanguageKind::Css => quote! { CssSyntaxElement },
-            LanguageKind::Json => quote! { JsonSyntaxElement },
-        }
-    }
-
-    pub(crate) fn syntax_token(&self) -> TokenStream {
-        match self {
-            LanguageKind::Js => quote! { JsSyntaxToken },
-            LanguageKind::Css => quote! { CssSyntaxToken },
-            LanguageKind::Json => quote! { JsonSyntaxToken },
-        }
-    }
-
-    pub(crate) fn syntax_element_children(&self) -> TokenStream {
-        match self {
-            LanguageKind::Js => quote! { JsSyntaxElementChildren },
-            LanguageKind::Css => quote! { CssSyntaxElementChildren },
-            LanguageKind::Json => quote! { JsonSyntaxElementChildren },
-        }
-    }
-
-    pub(crate) fn syntax_list(&self) -> TokenStream {
-        match self {
-            LanguageKind::Js => quote! { JsSyntaxList },
-            LanguageKind::Css => quote! { CssSyntaxList },
-            LanguageKind::Json => quote! { JsonSyntaxList },
-        }
-    }
-
-    pub(crate) fn language(&self) -> TokenStream {
-        match self {
-            LanguageKind::Js => quote! { JsLanguage },
-            LanguageKind::Css => quote! { CssLanguage },
-            LanguageKind::Json => quote! { JsonLanguage },
-        }
-    }
-
     pub fn formatter_crate_name(&self) -> &'static str {
         match self {
             LanguageKind::Js => "biome_js_formatter",
@@ -142,19 +88,11 @@ impl LanguageKind {
     }
 
     pub fn syntax_crate_name(&self) -> &'static str {
-        match self {
-            LanguageKind::Js => "biome_js_syntax",
-            LanguageKind::Css => "biome_css_syntax",
-            LanguageKind::Json => "biome_json_syntax",
-        }
+        self.language_src().syntax_crate
     }
 
     pub fn factory_crate_name(&self) -> &'static str {
-        match self {
-            LanguageKind::Js => "biome_js_factory",
-            LanguageKind::Css => "biome_css_factory",
-            LanguageKind::Json => "biome_json_factory",
-        }
+        self.language_src().factory_crate
     }
 }
 